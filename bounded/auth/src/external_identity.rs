@@ -0,0 +1,178 @@
+use education_platform_common::{Email, EmailError};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for ExternalIdentity validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExternalIdentityError {
+    #[error("Provider '{0}' must be lowercase letters, digits or dashes")]
+    ProviderNotValid(String),
+
+    #[error("Subject cannot be empty")]
+    EmptySubject,
+
+    #[error("Email not valid: {0}")]
+    EmailError(#[from] EmailError),
+}
+
+/// An account at an external identity provider, such as Google or GitHub,
+/// that a [`User`](crate::User) can sign in with.
+///
+/// The provider's subject is the stable key of the account: providers let
+/// people change the email address, so a returning user is matched by
+/// `(provider, subject)` and the email is only kept for display.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_auth::ExternalIdentity;
+///
+/// let identity = ExternalIdentity::new(
+///     "google",
+///     "110248495921238986420",
+///     "ana@gmail.com".to_string(),
+/// ).unwrap();
+///
+/// assert_eq!(identity.provider(), "google");
+/// assert_eq!(identity.to_string(), "google:110248495921238986420");
+/// assert!(ExternalIdentity::new("Google", "1", "ana@gmail.com".to_string()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalIdentity {
+    provider: String,
+    subject: String,
+    email: Email,
+}
+
+impl ExternalIdentity {
+    /// Creates an identity from the provider's name, the account's subject
+    /// and its email address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExternalIdentityError::ProviderNotValid` if the provider is
+    /// not a lowercase slug, `ExternalIdentityError::EmptySubject` if the
+    /// subject is blank, or `ExternalIdentityError::EmailError` if the
+    /// email is invalid.
+    pub fn new(
+        provider: &str,
+        subject: &str,
+        email: String,
+    ) -> Result<Self, ExternalIdentityError> {
+        if !is_provider_name(provider) {
+            return Err(ExternalIdentityError::ProviderNotValid(provider.to_string()));
+        }
+
+        let subject = subject.trim();
+        if subject.is_empty() {
+            return Err(ExternalIdentityError::EmptySubject);
+        }
+
+        Ok(Self {
+            provider: provider.to_string(),
+            subject: subject.to_string(),
+            email: Email::new(email)?,
+        })
+    }
+
+    /// Returns the provider's name, such as `google`.
+    #[inline]
+    #[must_use]
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Returns the provider's identifier for the account.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Returns the email address the provider reported.
+    #[inline]
+    #[must_use]
+    pub const fn email(&self) -> &Email {
+        &self.email
+    }
+
+    /// Returns `true` if both identities name the same account, whatever
+    /// email address each reported.
+    #[inline]
+    #[must_use]
+    pub fn is_same_account(&self, other: &Self) -> bool {
+        self.provider == other.provider && self.subject == other.subject
+    }
+}
+
+impl fmt::Display for ExternalIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.provider, self.subject)
+    }
+}
+
+/// Returns `true` if `name` can name an identity provider.
+pub(crate) fn is_provider_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod constructor {
+        use super::*;
+
+        #[test]
+        fn test_trims_subject() {
+            let identity =
+                ExternalIdentity::new("github", " 583231 ", "ana@example.com".to_string()).unwrap();
+
+            assert_eq!(identity.subject(), "583231");
+            assert_eq!(identity.email().address(), "ana@example.com");
+        }
+
+        #[test]
+        fn test_invalid_provider() {
+            for provider in ["", "Google", "git hub", "azure_ad"] {
+                assert_eq!(
+                    ExternalIdentity::new(provider, "1", "ana@example.com".to_string()),
+                    Err(ExternalIdentityError::ProviderNotValid(provider.to_string()))
+                );
+            }
+        }
+
+        #[test]
+        fn test_empty_subject() {
+            let result = ExternalIdentity::new("github", "  ", "ana@example.com".to_string());
+
+            assert_eq!(result, Err(ExternalIdentityError::EmptySubject));
+        }
+
+        #[test]
+        fn test_invalid_email() {
+            let result = ExternalIdentity::new("github", "1", "not-an-email".to_string());
+
+            assert!(matches!(result, Err(ExternalIdentityError::EmailError(_))));
+        }
+    }
+
+    mod is_same_account {
+        use super::*;
+
+        #[test]
+        fn test_ignores_email() {
+            let before =
+                ExternalIdentity::new("google", "42", "ana@gmail.com".to_string()).unwrap();
+            let after = ExternalIdentity::new("google", "42", "ana@work.com".to_string()).unwrap();
+            let other = ExternalIdentity::new("github", "42", "ana@gmail.com".to_string()).unwrap();
+
+            assert!(before.is_same_account(&after));
+            assert!(!before.is_same_account(&other));
+        }
+    }
+}
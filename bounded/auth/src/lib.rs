@@ -1,3 +1,13 @@
+mod external_identity;
+mod lockout_policy;
+#[cfg(feature = "oidc")]
+mod oidc;
+mod password_verifier;
 mod user;
 
+pub use external_identity::*;
+pub use lockout_policy::*;
+#[cfg(feature = "oidc")]
+pub use oidc::*;
+pub use password_verifier::*;
 pub use user::*;
@@ -0,0 +1,105 @@
+use education_platform_common::Duration;
+use thiserror::Error;
+
+/// Error types for LockoutPolicy validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LockoutPolicyError {
+    #[error("Lockout threshold must be at least one failed attempt")]
+    ThresholdIsZero,
+
+    #[error("Lockout cool-down must be longer than zero")]
+    CoolDownIsZero,
+}
+
+/// How many failed sign-ins in a row lock an account, and for how long.
+///
+/// Used with [`User::record_failed_attempt`](crate::User::record_failed_attempt)
+/// to slow down password guessing. The default locks the account for 15
+/// minutes after 5 failures.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_auth::LockoutPolicy;
+/// use education_platform_common::Duration;
+///
+/// let policy = LockoutPolicy::new(3, Duration::from_minutes(30)).unwrap();
+/// assert_eq!(policy.threshold(), 3);
+///
+/// assert_eq!(LockoutPolicy::default().threshold(), 5);
+/// assert!(LockoutPolicy::new(0, Duration::from_minutes(30)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockoutPolicy {
+    threshold: u32,
+    cool_down: Duration,
+}
+
+impl LockoutPolicy {
+    /// Creates a policy locking the account for `cool_down` after
+    /// `threshold` failed attempts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LockoutPolicyError::ThresholdIsZero` or
+    /// `LockoutPolicyError::CoolDownIsZero` for a policy that would lock
+    /// every account or none.
+    pub fn new(threshold: u32, cool_down: Duration) -> Result<Self, LockoutPolicyError> {
+        if threshold == 0 {
+            return Err(LockoutPolicyError::ThresholdIsZero);
+        }
+        if cool_down.total_seconds() == 0 {
+            return Err(LockoutPolicyError::CoolDownIsZero);
+        }
+        Ok(Self { threshold, cool_down })
+    }
+
+    /// Returns the failed attempts in a row that lock the account.
+    #[inline]
+    #[must_use]
+    pub const fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns how long a locked account stays locked.
+    #[inline]
+    #[must_use]
+    pub const fn cool_down(&self) -> Duration {
+        self.cool_down
+    }
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            threshold: 5,
+            cool_down: Duration::from_minutes(15),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_values_are_rejected() {
+        assert_eq!(
+            LockoutPolicy::new(0, Duration::from_minutes(1)),
+            Err(LockoutPolicyError::ThresholdIsZero)
+        );
+        assert_eq!(
+            LockoutPolicy::new(3, Duration::default()),
+            Err(LockoutPolicyError::CoolDownIsZero)
+        );
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let policy = LockoutPolicy::default();
+
+        assert_eq!(policy.threshold(), 5);
+        assert_eq!(policy.cool_down(), Duration::from_minutes(15));
+    }
+}
@@ -0,0 +1,7 @@
+mod claims;
+mod client;
+mod provider;
+
+pub use claims::*;
+pub use client::*;
+pub use provider::*;
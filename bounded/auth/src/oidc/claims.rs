@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+/// Claims about a signed-in account, as returned by a
+/// [`CodeExchanger`](crate::CodeExchanger).
+///
+/// Values are kept as text: booleans such as `email_verified` are `"true"`
+/// or `"false"`, and numeric IDs such as GitHub's are written in decimal.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_auth::Claims;
+///
+/// let claims = Claims::new()
+///     .with("sub", "110248495921238986420")
+///     .with("email", "ana@gmail.com");
+///
+/// assert_eq!(claims.get("email"), Some("ana@gmail.com"));
+/// assert_eq!(claims.get("name"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Claims(BTreeMap<String, String>);
+
+impl Claims {
+    /// Creates an empty set of claims.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the claims with `key` set to `value`.
+    #[must_use]
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Sets `key` to `value`, replacing any previous value.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    /// Returns the value of `key`, if present.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Claims {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
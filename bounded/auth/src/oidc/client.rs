@@ -0,0 +1,591 @@
+use super::provider::endpoint_url;
+use crate::{Claims, ExternalIdentity, ExternalIdentityError, OidcProvider};
+use education_platform_common::{Url, UrlError};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for external sign-in failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OidcError {
+    #[error("Provider name '{0}' must be lowercase letters, digits or dashes")]
+    ProviderNameNotValid(String),
+
+    #[error("URL '{url}' is not a valid HTTPS URL: {source}")]
+    UrlNotValid { url: String, source: UrlError },
+
+    #[error("Client ID cannot be empty")]
+    EmptyClientId,
+
+    #[error("State and nonce cannot be empty")]
+    EmptyState,
+
+    #[error("Returned state does not match the sign-in request")]
+    StateMismatch,
+
+    #[error("ID token nonce does not match the sign-in request")]
+    NonceMismatch,
+
+    #[error("Claim '{0}' is missing")]
+    MissingClaim(String),
+
+    #[error("Provider has not verified the email address")]
+    EmailNotVerified,
+
+    #[error("Code exchange failed: {0}")]
+    Exchange(String),
+
+    #[error("External identity not valid: {0}")]
+    Identity(#[from] ExternalIdentityError),
+}
+
+/// A sign-in started with [`OidcClient::authorization_request`].
+///
+/// Keep it, for example in the user's session, until the provider redirects
+/// back: its state and nonce tie the callback to this request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationRequest {
+    url: String,
+    state: String,
+    nonce: Option<String>,
+}
+
+impl AuthorizationRequest {
+    /// Returns the provider URL to redirect the user to.
+    #[inline]
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the state the provider must send back.
+    #[inline]
+    #[must_use]
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// Returns the nonce the ID token must carry, if the provider uses one.
+    #[inline]
+    #[must_use]
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+}
+
+/// Request exchanging an authorization code at the provider's token
+/// endpoint, handed to a [`CodeExchanger`].
+///
+/// Its `Debug` output leaves out the client secret, so requests can be
+/// logged.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TokenRequest {
+    token_endpoint: String,
+    client_id: String,
+    client_secret: String,
+    code: String,
+    redirect_uri: String,
+}
+
+impl TokenRequest {
+    /// Returns the URL to post the request to.
+    #[inline]
+    #[must_use]
+    pub fn token_endpoint(&self) -> &str {
+        &self.token_endpoint
+    }
+
+    /// Returns the authorization code the provider sent back.
+    #[inline]
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the client ID, to check as the ID token's audience.
+    #[inline]
+    #[must_use]
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Returns the `application/x-www-form-urlencoded` body to post.
+    ///
+    /// The client credentials travel in the body, which every provider
+    /// accepts for confidential clients.
+    #[must_use]
+    pub fn form_body(&self) -> String {
+        Url::encode_query(&[
+            ("grant_type", "authorization_code"),
+            ("code", self.code.as_str()),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ])
+    }
+}
+
+impl fmt::Debug for TokenRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenRequest")
+            .field("token_endpoint", &self.token_endpoint)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED)
+            .field("code", &self.code)
+            .field("redirect_uri", &self.redirect_uri)
+            .finish()
+    }
+}
+
+/// Stands in for the client secret in `Debug` output.
+const REDACTED: &str = "<redacted>";
+
+/// Port exchanging an authorization code for the claims of the account
+/// that signed in.
+///
+/// Implementations post [`TokenRequest::form_body`] to the token endpoint.
+/// For OpenID Connect providers they verify the returned ID token's
+/// signature, issuer, audience and expiry before returning its claims; for
+/// plain OAuth2 providers they use the access token to read the user's
+/// profile. The HTTP client and JWT library live in the application, so the
+/// auth context stays free of network dependencies.
+pub trait CodeExchanger {
+    /// Exchanges the code and returns the verified claims.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OidcError::Exchange` if the provider rejects the code, cannot
+    /// be reached, or returns a token that fails verification.
+    fn exchange(&mut self, request: &TokenRequest) -> Result<Claims, OidcError>;
+}
+
+/// Signs users in with an [`OidcProvider`] using the authorization code
+/// flow.
+///
+/// The flow has two steps: [`OidcClient::authorization_request`] builds
+/// the URL the user is redirected to, and [`OidcClient::complete`] turns
+/// the code the provider sends back into an [`ExternalIdentity`], which is
+/// then looked up among, or linked to, the platform's users. Its `Debug`
+/// output leaves out the client secret.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_auth::{
+///     Claims, CodeExchanger, OidcClient, OidcError, OidcProvider, TokenRequest,
+/// };
+///
+/// /// Stands in for the HTTP call, answering as Google would.
+/// struct FakeGoogle;
+///
+/// impl CodeExchanger for FakeGoogle {
+///     fn exchange(&mut self, request: &TokenRequest) -> Result<Claims, OidcError> {
+///         assert_eq!(request.code(), "4/0AX4XfWh");
+///         Ok(Claims::new()
+///             .with("sub", "110248495921238986420")
+///             .with("email", "ana@gmail.com")
+///             .with("email_verified", "true")
+///             .with("nonce", "n-0S6_WzA2Mj"))
+///     }
+/// }
+///
+/// let client = OidcClient::new(
+///     OidcProvider::google(),
+///     "platform.apps.googleusercontent.com",
+///     "client-secret",
+///     "https://learn.example.com/auth/callback",
+/// ).unwrap();
+///
+/// let request = client.authorization_request("af0ifjsldkj", "n-0S6_WzA2Mj").unwrap();
+/// assert!(request.url().starts_with("https://accounts.google.com/o/oauth2/v2/auth?"));
+///
+/// let identity = client
+///     .complete(&mut FakeGoogle, &request, "af0ifjsldkj", "4/0AX4XfWh")
+///     .unwrap();
+/// assert_eq!(identity.to_string(), "google:110248495921238986420");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct OidcClient {
+    provider: OidcProvider,
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+}
+
+impl OidcClient {
+    /// Creates a client registered at `provider` as `client_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OidcError::EmptyClientId` if the client ID is blank, or
+    /// `OidcError::UrlNotValid` if the redirect URI is not an HTTPS URL.
+    pub fn new(
+        provider: OidcProvider,
+        client_id: &str,
+        client_secret: &str,
+        redirect_uri: &str,
+    ) -> Result<Self, OidcError> {
+        if client_id.trim().is_empty() {
+            return Err(OidcError::EmptyClientId);
+        }
+        let redirect_uri = endpoint_url(redirect_uri)?;
+
+        Ok(Self {
+            provider,
+            client_id: client_id.trim().to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.into_inner(),
+        })
+    }
+
+    /// Returns the provider users sign in with.
+    #[inline]
+    #[must_use]
+    pub const fn provider(&self) -> &OidcProvider {
+        &self.provider
+    }
+
+    /// Returns the client ID registered at the provider.
+    #[inline]
+    #[must_use]
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Returns the URL the provider redirects back to.
+    #[inline]
+    #[must_use]
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Starts a sign-in, building the URL to redirect the user to.
+    ///
+    /// `state` and `nonce` must be fresh random values for every sign-in;
+    /// they are what stops forged callbacks and replayed ID tokens. The
+    /// nonce is dropped for providers that do not use one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OidcError::EmptyState` if `state` is empty, or if `nonce`
+    /// is empty for a provider that uses nonces.
+    pub fn authorization_request(
+        &self,
+        state: &str,
+        nonce: &str,
+    ) -> Result<AuthorizationRequest, OidcError> {
+        let nonce = self.provider.uses_nonce().then_some(nonce);
+        if state.is_empty() || nonce.is_some_and(str::is_empty) {
+            return Err(OidcError::EmptyState);
+        }
+
+        let scope = self.provider.scopes().join(" ");
+        let mut parameters = vec![
+            ("response_type", "code"),
+            ("client_id", self.client_id.as_str()),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("scope", scope.as_str()),
+            ("state", state),
+        ];
+        if let Some(nonce) = nonce {
+            parameters.push(("nonce", nonce));
+        }
+
+        let endpoint = self.provider.authorization_endpoint();
+        let separator = if endpoint.contains('?') { '&' } else { '?' };
+        let query = Url::encode_query(&parameters);
+
+        Ok(AuthorizationRequest {
+            url: format!("{endpoint}{separator}{query}"),
+            state: state.to_string(),
+            nonce: nonce.map(str::to_string),
+        })
+    }
+
+    /// Finishes a sign-in when the provider redirects back with `state`
+    /// and `code`, returning the account that signed in.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OidcError::StateMismatch` if `state` is not the one of
+    /// `request`, the exchanger's error if the code is rejected,
+    /// `OidcError::NonceMismatch` if the claims carry another nonce, or
+    /// the errors of [`ClaimMapping::identity`](crate::ClaimMapping::identity).
+    pub fn complete(
+        &self,
+        exchanger: &mut impl CodeExchanger,
+        request: &AuthorizationRequest,
+        state: &str,
+        code: &str,
+    ) -> Result<ExternalIdentity, OidcError> {
+        if state != request.state() {
+            return Err(OidcError::StateMismatch);
+        }
+
+        let claims = exchanger.exchange(&TokenRequest {
+            token_endpoint: self.provider.token_endpoint().to_string(),
+            client_id: self.client_id.clone(),
+            client_secret: self.client_secret.clone(),
+            code: code.to_string(),
+            redirect_uri: self.redirect_uri.clone(),
+        })?;
+
+        if let Some(nonce) = request.nonce()
+            && claims.get("nonce") != Some(nonce)
+        {
+            return Err(OidcError::NonceMismatch);
+        }
+        self.provider
+            .claims()
+            .identity(self.provider.name(), &claims)
+    }
+}
+
+impl fmt::Debug for OidcClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OidcClient")
+            .field("provider", &self.provider)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED)
+            .field("redirect_uri", &self.redirect_uri)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClaimMapping;
+
+    struct StaticExchanger {
+        claims: Result<Claims, OidcError>,
+        requests: Vec<TokenRequest>,
+    }
+
+    impl StaticExchanger {
+        fn returning(claims: Claims) -> Self {
+            Self {
+                claims: Ok(claims),
+                requests: Vec::new(),
+            }
+        }
+    }
+
+    impl CodeExchanger for StaticExchanger {
+        fn exchange(&mut self, request: &TokenRequest) -> Result<Claims, OidcError> {
+            self.requests.push(request.clone());
+            self.claims.clone()
+        }
+    }
+
+    fn google_client() -> OidcClient {
+        OidcClient::new(
+            OidcProvider::google(),
+            "app-id",
+            "s3cr3t&=",
+            "https://learn.example.com/callback",
+        )
+        .unwrap()
+    }
+
+    fn google_claims() -> Claims {
+        Claims::new()
+            .with("sub", "42")
+            .with("email", "ana@gmail.com")
+            .with("email_verified", "true")
+            .with("nonce", "n1")
+    }
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_empty_client_id() {
+            let result = OidcClient::new(OidcProvider::google(), " ", "", "https://a.example/cb");
+
+            assert_eq!(result, Err(OidcError::EmptyClientId));
+        }
+
+        #[test]
+        fn test_insecure_redirect_uri() {
+            let result =
+                OidcClient::new(OidcProvider::google(), "app", "", "http://learn.example.com/cb");
+
+            assert!(matches!(
+                result,
+                Err(OidcError::UrlNotValid {
+                    source: UrlError::SchemeNotValid(_),
+                    ..
+                })
+            ));
+        }
+    }
+
+    mod authorization_request {
+        use super::*;
+
+        #[test]
+        fn test_builds_encoded_url() {
+            let request = google_client().authorization_request("s 1", "n1").unwrap();
+
+            assert_eq!(
+                request.url(),
+                "https://accounts.google.com/o/oauth2/v2/auth?response_type=code\
+                 &client_id=app-id\
+                 &redirect_uri=https%3A%2F%2Flearn.example.com%2Fcallback\
+                 &scope=openid%20email&state=s%201&nonce=n1"
+            );
+            assert_eq!(request.state(), "s 1");
+            assert_eq!(request.nonce(), Some("n1"));
+        }
+
+        #[test]
+        fn test_keeps_existing_query() {
+            let provider = OidcProvider::new(
+                "azure",
+                "https://login.example.com/authorize?p=b2c_signin",
+                "https://login.example.com/token",
+            )
+            .unwrap();
+            let client = OidcClient::new(provider, "app", "", "https://a.example/cb").unwrap();
+
+            let request = client.authorization_request("s", "n").unwrap();
+
+            assert!(
+                request
+                    .url()
+                    .starts_with("https://login.example.com/authorize?p=b2c_signin&response_type=")
+            );
+        }
+
+        #[test]
+        fn test_provider_without_nonce() {
+            let client =
+                OidcClient::new(OidcProvider::github(), "app", "", "https://a.example/cb").unwrap();
+
+            let request = client.authorization_request("s", "").unwrap();
+
+            assert_eq!(request.nonce(), None);
+            assert!(!request.url().contains("nonce="));
+            assert!(request.url().contains("scope=read%3Auser%20user%3Aemail"));
+        }
+
+        #[test]
+        fn test_empty_state_or_nonce() {
+            let client = google_client();
+
+            assert_eq!(client.authorization_request("", "n"), Err(OidcError::EmptyState));
+            assert_eq!(client.authorization_request("s", ""), Err(OidcError::EmptyState));
+        }
+    }
+
+    mod complete {
+        use super::*;
+
+        #[test]
+        fn test_returns_identity() {
+            let client = google_client();
+            let request = client.authorization_request("s1", "n1").unwrap();
+            let mut exchanger = StaticExchanger::returning(google_claims());
+
+            let identity = client
+                .complete(&mut exchanger, &request, "s1", "code/1")
+                .unwrap();
+
+            assert_eq!(identity.provider(), "google");
+            assert_eq!(identity.subject(), "42");
+            assert_eq!(exchanger.requests.len(), 1);
+            assert_eq!(
+                exchanger.requests[0].form_body(),
+                "grant_type=authorization_code&code=code%2F1\
+                 &redirect_uri=https%3A%2F%2Flearn.example.com%2Fcallback\
+                 &client_id=app-id&client_secret=s3cr3t%26%3D"
+            );
+        }
+
+        #[test]
+        fn test_state_mismatch_skips_exchange() {
+            let client = google_client();
+            let request = client.authorization_request("s1", "n1").unwrap();
+            let mut exchanger = StaticExchanger::returning(google_claims());
+
+            let result = client.complete(&mut exchanger, &request, "forged", "code");
+
+            assert_eq!(result, Err(OidcError::StateMismatch));
+            assert!(exchanger.requests.is_empty());
+        }
+
+        #[test]
+        fn test_nonce_mismatch() {
+            let client = google_client();
+            let request = client.authorization_request("s1", "n2").unwrap();
+            let mut exchanger = StaticExchanger::returning(google_claims());
+
+            let result = client.complete(&mut exchanger, &request, "s1", "code");
+
+            assert_eq!(result, Err(OidcError::NonceMismatch));
+        }
+
+        #[test]
+        fn test_exchange_error_is_returned() {
+            let client = google_client();
+            let request = client.authorization_request("s1", "n1").unwrap();
+            let mut exchanger = StaticExchanger {
+                claims: Err(OidcError::Exchange("invalid_grant".to_string())),
+                requests: Vec::new(),
+            };
+
+            let result = client.complete(&mut exchanger, &request, "s1", "code");
+
+            assert_eq!(result, Err(OidcError::Exchange("invalid_grant".to_string())));
+        }
+
+        #[test]
+        fn test_uses_provider_claim_mapping() {
+            let provider = OidcProvider::google()
+                .with_claims(ClaimMapping::new("oid", "upn", None))
+                .without_nonce();
+            let client = OidcClient::new(provider, "app", "", "https://a.example/cb").unwrap();
+            let request = client.authorization_request("s1", "").unwrap();
+            let mut exchanger = StaticExchanger::returning(
+                Claims::new()
+                    .with("oid", "9f1c")
+                    .with("upn", "ana@school.edu"),
+            );
+
+            let identity = client
+                .complete(&mut exchanger, &request, "s1", "code")
+                .unwrap();
+
+            assert_eq!(identity.subject(), "9f1c");
+            assert_eq!(identity.email().address(), "ana@school.edu");
+        }
+    }
+
+    mod debug {
+        use super::*;
+
+        #[test]
+        fn test_client_hides_secret() {
+            let output = format!("{:?}", google_client());
+
+            assert!(output.contains("app-id"));
+            assert!(!output.contains("s3cr3t"));
+        }
+
+        #[test]
+        fn test_token_request_hides_secret() {
+            let client = google_client();
+            let request = client.authorization_request("s1", "n1").unwrap();
+            let mut exchanger = StaticExchanger::returning(google_claims());
+            client
+                .complete(&mut exchanger, &request, "s1", "code/1")
+                .unwrap();
+
+            let output = format!("{:?}", exchanger.requests[0]);
+
+            assert!(output.contains("code/1"));
+            assert!(!output.contains("s3cr3t"));
+        }
+    }
+}
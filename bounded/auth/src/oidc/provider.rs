@@ -0,0 +1,365 @@
+use crate::external_identity::is_provider_name;
+use crate::{Claims, ExternalIdentity, OidcError};
+use education_platform_common::{Url, UrlPolicy};
+
+/// Which claims hold the account's subject and email at a provider.
+///
+/// OpenID Connect providers use the standard `sub`, `email` and
+/// `email_verified` claims; plain OAuth2 providers such as GitHub return
+/// their own user profile, so the names differ.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimMapping {
+    subject: String,
+    email: String,
+    email_verified: Option<String>,
+}
+
+impl ClaimMapping {
+    /// Creates a mapping reading the given claims.
+    ///
+    /// With `email_verified` set, sign-ins whose claim is not `"true"` are
+    /// refused; without it, the provider is trusted to report only
+    /// verified addresses.
+    #[must_use]
+    pub fn new(subject: &str, email: &str, email_verified: Option<&str>) -> Self {
+        Self {
+            subject: subject.to_string(),
+            email: email.to_string(),
+            email_verified: email_verified.map(str::to_string),
+        }
+    }
+
+    /// Returns the mapping for the standard OpenID Connect claims.
+    #[must_use]
+    pub fn openid() -> Self {
+        Self::new("sub", "email", Some("email_verified"))
+    }
+
+    /// Builds the identity of the account described by `claims` at
+    /// `provider`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OidcError::MissingClaim` if the subject or email claim is
+    /// absent, `OidcError::EmailNotVerified` if the provider has not
+    /// verified the email, or `OidcError::Identity` if the values are not
+    /// a valid identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_auth::{ClaimMapping, Claims};
+    ///
+    /// let claims = Claims::new()
+    ///     .with("sub", "42")
+    ///     .with("email", "ana@gmail.com")
+    ///     .with("email_verified", "true");
+    ///
+    /// let identity = ClaimMapping::openid().identity("google", &claims).unwrap();
+    /// assert_eq!(identity.subject(), "42");
+    ///
+    /// let unverified = claims.with("email_verified", "false");
+    /// assert!(ClaimMapping::openid().identity("google", &unverified).is_err());
+    /// ```
+    pub fn identity(&self, provider: &str, claims: &Claims) -> Result<ExternalIdentity, OidcError> {
+        let claim = |key: &str| {
+            claims
+                .get(key)
+                .ok_or_else(|| OidcError::MissingClaim(key.to_string()))
+        };
+
+        if let Some(key) = &self.email_verified
+            && claims.get(key) != Some("true")
+        {
+            return Err(OidcError::EmailNotVerified);
+        }
+
+        let subject = claim(&self.subject)?;
+        let email = claim(&self.email)?;
+        Ok(ExternalIdentity::new(provider, subject, email.to_string())?)
+    }
+}
+
+/// An identity provider users can sign in with, such as Google or GitHub.
+///
+/// Holds the provider's endpoints, the scopes to request and how to read
+/// its claims. [`OidcProvider::google`] and [`OidcProvider::github`] cover
+/// the common cases; other providers are described with
+/// [`OidcProvider::new`] and the `with_*` methods.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_auth::OidcProvider;
+///
+/// let provider = OidcProvider::new(
+///     "keycloak",
+///     "https://sso.example.com/realms/school/protocol/openid-connect/auth",
+///     "https://sso.example.com/realms/school/protocol/openid-connect/token",
+/// )
+/// .unwrap()
+/// .with_scopes(&["openid", "email", "roles"]);
+///
+/// assert_eq!(provider.name(), "keycloak");
+/// assert_eq!(provider.scopes().len(), 3);
+/// assert!(OidcProvider::new("sso", "ftp://sso.example.com", "https://sso.example.com").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcProvider {
+    name: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    scopes: Vec<String>,
+    claims: ClaimMapping,
+    uses_nonce: bool,
+}
+
+impl OidcProvider {
+    /// Creates an OpenID Connect provider requesting the `openid` and
+    /// `email` scopes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OidcError::ProviderNameNotValid` if the name is not a
+    /// lowercase slug, or `OidcError::UrlNotValid` if an endpoint is not an
+    /// HTTPS URL. Plain HTTP is accepted for `localhost` only.
+    pub fn new(
+        name: &str,
+        authorization_endpoint: &str,
+        token_endpoint: &str,
+    ) -> Result<Self, OidcError> {
+        if !is_provider_name(name) {
+            return Err(OidcError::ProviderNameNotValid(name.to_string()));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            authorization_endpoint: endpoint_url(authorization_endpoint)?.into_inner(),
+            token_endpoint: endpoint_url(token_endpoint)?.into_inner(),
+            scopes: vec!["openid".to_string(), "email".to_string()],
+            claims: ClaimMapping::openid(),
+            uses_nonce: true,
+        })
+    }
+
+    /// Returns Google's OpenID Connect provider.
+    #[must_use]
+    pub fn google() -> Self {
+        Self {
+            name: "google".to_string(),
+            authorization_endpoint: "https://accounts.google.com/o/oauth2/v2/auth".to_string(),
+            token_endpoint: "https://oauth2.googleapis.com/token".to_string(),
+            scopes: vec!["openid".to_string(), "email".to_string()],
+            claims: ClaimMapping::openid(),
+            uses_nonce: true,
+        }
+    }
+
+    /// Returns GitHub's OAuth2 provider.
+    ///
+    /// GitHub does not issue ID tokens, so its [`CodeExchanger`] reads the
+    /// `id` of the `/user` profile and the primary verified address of
+    /// `/user/emails`, and no nonce is sent.
+    ///
+    /// [`CodeExchanger`]: crate::CodeExchanger
+    #[must_use]
+    pub fn github() -> Self {
+        Self {
+            name: "github".to_string(),
+            authorization_endpoint: "https://github.com/login/oauth/authorize".to_string(),
+            token_endpoint: "https://github.com/login/oauth/access_token".to_string(),
+            scopes: vec!["read:user".to_string(), "user:email".to_string()],
+            claims: ClaimMapping::new("id", "email", None),
+            uses_nonce: false,
+        }
+    }
+
+    /// Returns the provider with `scopes` requested instead of the current
+    /// ones.
+    #[must_use]
+    pub fn with_scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+        self
+    }
+
+    /// Returns the provider reading its claims with `claims`.
+    #[must_use]
+    pub fn with_claims(mut self, claims: ClaimMapping) -> Self {
+        self.claims = claims;
+        self
+    }
+
+    /// Returns the provider without nonces, for plain OAuth2 providers that
+    /// would not echo one back.
+    #[must_use]
+    pub fn without_nonce(mut self) -> Self {
+        self.uses_nonce = false;
+        self
+    }
+
+    /// Returns the provider's name, used as the
+    /// [`ExternalIdentity`] provider.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the URL users are sent to for signing in.
+    #[inline]
+    #[must_use]
+    pub fn authorization_endpoint(&self) -> &str {
+        &self.authorization_endpoint
+    }
+
+    /// Returns the URL authorization codes are exchanged at.
+    #[inline]
+    #[must_use]
+    pub fn token_endpoint(&self) -> &str {
+        &self.token_endpoint
+    }
+
+    /// Returns the scopes requested.
+    #[inline]
+    #[must_use]
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    /// Returns how the provider's claims are read.
+    #[inline]
+    #[must_use]
+    pub const fn claims(&self) -> &ClaimMapping {
+        &self.claims
+    }
+
+    /// Returns `true` if a nonce is sent and checked on the way back.
+    #[inline]
+    #[must_use]
+    pub const fn uses_nonce(&self) -> bool {
+        self.uses_nonce
+    }
+}
+
+/// Parses `url` as an HTTPS URL, or an HTTP one on the local machine.
+pub(super) fn endpoint_url(url: &str) -> Result<Url, OidcError> {
+    let policy = UrlPolicy::default().allow_loopback_http();
+    Url::with_policy(url.to_string(), &policy).map_err(|source| OidcError::UrlNotValid {
+        url: url.to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod claim_mapping {
+        use super::*;
+
+        #[test]
+        fn test_github_claims() {
+            let claims = Claims::new()
+                .with("id", "583231")
+                .with("email", "ana@example.com");
+
+            let identity = OidcProvider::github()
+                .claims()
+                .identity("github", &claims)
+                .unwrap();
+
+            assert_eq!(identity.to_string(), "github:583231");
+        }
+
+        #[test]
+        fn test_missing_claims() {
+            let mapping = ClaimMapping::new("id", "email", None);
+
+            assert_eq!(
+                mapping.identity("github", &Claims::new().with("email", "ana@example.com")),
+                Err(OidcError::MissingClaim("id".to_string()))
+            );
+            assert_eq!(
+                mapping.identity("github", &Claims::new().with("id", "1")),
+                Err(OidcError::MissingClaim("email".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_verification_is_required_when_mapped() {
+            let claims = Claims::new()
+                .with("sub", "42")
+                .with("email", "ana@gmail.com");
+
+            assert_eq!(
+                ClaimMapping::openid().identity("google", &claims),
+                Err(OidcError::EmailNotVerified)
+            );
+        }
+    }
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_defaults_to_openid() {
+            let provider = OidcProvider::new(
+                "local",
+                "http://localhost:8080/auth",
+                "http://127.0.0.1:8080/token",
+            )
+            .unwrap();
+
+            assert_eq!(provider.scopes(), ["openid", "email"]);
+            assert_eq!(provider.claims(), &ClaimMapping::openid());
+            assert!(provider.uses_nonce());
+        }
+
+        #[test]
+        fn test_invalid_name() {
+            let result = OidcProvider::new("My SSO", "https://a.example", "https://b.example");
+
+            assert_eq!(result, Err(OidcError::ProviderNameNotValid("My SSO".to_string())));
+        }
+
+        #[test]
+        fn test_invalid_urls() {
+            for url in [
+                "sso.example.com/auth",
+                "https://",
+                "http://sso.example.com/auth",
+                "http://localhost.example.com/auth",
+                "https://sso.example.com/a b",
+            ] {
+                assert!(
+                    matches!(
+                        OidcProvider::new("sso", url, "https://sso.example.com/token"),
+                        Err(OidcError::UrlNotValid { url: ref rejected, .. }) if rejected == url
+                    ),
+                    "{url} should be rejected"
+                );
+            }
+        }
+    }
+
+    mod presets {
+        use super::*;
+
+        #[test]
+        fn test_presets_have_valid_endpoints() {
+            for provider in [OidcProvider::google(), OidcProvider::github()] {
+                assert!(endpoint_url(provider.authorization_endpoint()).is_ok());
+                assert!(endpoint_url(provider.token_endpoint()).is_ok());
+                assert!(is_provider_name(provider.name()));
+            }
+        }
+
+        #[test]
+        fn test_github_is_plain_oauth() {
+            let github = OidcProvider::github();
+
+            assert!(!github.uses_nonce());
+            assert!(!github.scopes().iter().any(|scope| scope == "openid"));
+        }
+    }
+}
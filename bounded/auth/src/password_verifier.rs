@@ -0,0 +1,41 @@
+use education_platform_common::HashedPassword;
+
+/// Port checking a plaintext password against a stored hash.
+///
+/// The auth context only stores hashes; the hashing library lives in the
+/// application that implements this trait.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_auth::{PasswordVerifier, User};
+/// use education_platform_common::HashedPassword;
+///
+/// /// Accepts the password "secret" for any hash, for demonstration only.
+/// struct AcceptSecret;
+///
+/// impl PasswordVerifier for AcceptSecret {
+///     fn verify(&self, password: &str, _hash: &HashedPassword) -> bool {
+///         password == "secret"
+///     }
+/// }
+///
+/// let user = User::new(
+///     "Ana".to_string(),
+///     None,
+///     "Lopez".to_string(),
+///     None,
+///     "12345678-1".to_string(),
+///     "ana@example.com".to_string(),
+///     Some("$argon2id$v=19$m=65536,t=3,p=4$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG".to_string()),
+/// ).unwrap();
+///
+/// assert!(user.verify_password("secret", &AcceptSecret).is_ok());
+/// assert!(user.verify_password("guess", &AcceptSecret).is_err());
+/// ```
+pub trait PasswordVerifier {
+    /// Returns whether `password` produces `hash`.
+    ///
+    /// Implementations return `false` for hashes they cannot parse.
+    fn verify(&self, password: &str, hash: &HashedPassword) -> bool;
+}
@@ -1,6 +1,9 @@
+use crate::{ExternalIdentity, LockoutPolicy, PasswordVerifier};
 use education_platform_common::{
-    ArgonVariant, Dni, DniError, Email, EmailError, Entity, HashedPassword, HashedPasswordError,
-    HashingAlgorithm, Id, IdError, PersonName, PersonNameError,
+    ArgonVariant, Auditable, CustomFieldError, CustomFieldSchema, CustomFieldTarget, CustomFields,
+    DateTime, Dni, DniError, Document, DocumentError, DocumentKind, Email, EmailError, Entity,
+    HashedPassword, HashedPasswordError, HashingAlgorithm, Id, IdError, PersonName,
+    PersonNameError, Timestamps,
 };
 use thiserror::Error;
 
@@ -18,18 +21,36 @@ pub enum UserError {
     #[error("DNI not valid: {0}")]
     DniError(#[from] DniError),
 
+    #[error("Document not valid: {0}")]
+    DocumentError(#[from] DocumentError),
+
     #[error("Email not valid: {0}")]
     EmailError(#[from] EmailError),
 
     #[error("Password doesn't match the security requirements: {0}")]
     HashedPasswordError(#[from] HashedPasswordError),
+
+    #[error("Custom field not valid: {0}")]
+    CustomFieldError(#[from] CustomFieldError),
+
+    #[error("User has no password; sign in with the external provider instead")]
+    NoPasswordSet,
+
+    #[error("Password does not match")]
+    CredentialsNotValid,
+
+    #[error("User already has a {0} account linked")]
+    ProviderAlreadyLinked(String),
+
+    #[error("User has no {0} account linked")]
+    ProviderNotLinked(String),
 }
 
 /// Represents a user entity in the authentication bounded context.
 ///
 /// A `User` is an entity (not a value object) with unique identity that persists
 /// over time. Users are identified by their `Id` and contain validated personal
-/// information including name, document (DNI), email, and optional password hash.
+/// information including name, identity document, email, and optional password hash.
 ///
 /// # Domain-Driven Design
 ///
@@ -61,15 +82,25 @@ pub enum UserError {
 pub struct User {
     id: Id,
     name: PersonName,
-    document: Dni,
+    document: Document,
     email: Email,
     password: Option<HashedPassword>,
+    custom_fields: CustomFields,
+    external_identities: Vec<ExternalIdentity>,
+    failed_attempts: u32,
+    locked_until: Option<DateTime>,
+    timestamps: Timestamps,
 }
 
+/// Identifier of a [`User`].
+pub type UserId = Id<User>;
+
 impl User {
     /// Creates a new `User` entity with validated components.
     ///
     /// Generates a new unique `Id` for the user and validates all input fields.
+    /// The document is read as a Peruvian DNI; use [`User::with_document`]
+    /// for the other supported kinds.
     /// The password, if provided, must be a properly formatted hash string for
     /// the Argon2id algorithm.
     ///
@@ -131,8 +162,57 @@ impl User {
         email: String,
         password: Option<String>,
     ) -> Result<Self, UserError> {
-        let name = PersonName::new(first_name, middle_name, last_name, second_last_name)?;
         let document = Dni::new(document)?;
+        Self::with_document(
+            first_name,
+            middle_name,
+            last_name,
+            second_last_name,
+            document.into(),
+            email,
+            password,
+        )
+    }
+
+    /// Creates a new `User` entity identified by any supported document.
+    ///
+    /// Behaves like [`User::new`], but takes an already validated document,
+    /// so users from outside Peru can register with their own ID or a
+    /// passport.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the name, email, or password hash is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_auth::User;
+    /// use education_platform_common::{Document, DocumentKind};
+    ///
+    /// let user = User::with_document(
+    ///     "João".to_string(),
+    ///     None,
+    ///     "Silva".to_string(),
+    ///     None,
+    ///     Document::parse(DocumentKind::Cpf, "529.982.247-25").unwrap(),
+    ///     "joao@example.com".to_string(),
+    ///     None,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(user.document_kind(), DocumentKind::Cpf);
+    /// assert_eq!(user.document().to_string(), "529.982.247-25");
+    /// ```
+    pub fn with_document(
+        first_name: String,
+        middle_name: Option<String>,
+        last_name: String,
+        second_last_name: Option<String>,
+        document: Document,
+        email: String,
+        password: Option<String>,
+    ) -> Result<Self, UserError> {
+        let name = PersonName::new(first_name, middle_name, last_name, second_last_name)?;
         let email = Email::new(email)?;
         let password = password
             .map(|p| HashedPassword::new(p, HashingAlgorithm::Argon(ArgonVariant::Argon2id)))
@@ -145,6 +225,11 @@ impl User {
             document,
             email,
             password,
+            custom_fields: CustomFields::new(),
+            external_identities: Vec::new(),
+            failed_attempts: 0,
+            locked_until: None,
+            timestamps: Timestamps::now(),
         })
     }
 
@@ -173,7 +258,7 @@ impl User {
         &self.name
     }
 
-    /// Returns a reference to the user's document (DNI).
+    /// Returns a reference to the user's identity document.
     ///
     /// # Examples
     ///
@@ -190,15 +275,22 @@ impl User {
     ///     None,
     /// ).unwrap();
     ///
-    /// assert_eq!(user.document().with_verification_char(), "00000001-I");
-    /// assert_eq!(user.document().value(), "00000001");
+    /// assert_eq!(user.document().to_string(), "00000001-I");
+    /// assert_eq!(user.document().as_dni().unwrap().value(), "00000001");
     /// ```
     #[inline]
     #[must_use]
-    pub const fn document(&self) -> &Dni {
+    pub const fn document(&self) -> &Document {
         &self.document
     }
 
+    /// Returns which kind of identity document the user registered with.
+    #[inline]
+    #[must_use]
+    pub const fn document_kind(&self) -> DocumentKind {
+        self.document.kind()
+    }
+
     /// Returns a reference to the user's email address.
     ///
     /// # Examples
@@ -302,6 +394,260 @@ impl User {
     pub const fn has_password(&self) -> bool {
         self.password.is_some()
     }
+
+    /// Checks a password entered at sign-in against the stored hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserError::NoPasswordSet` if the user has no password, or
+    /// `UserError::CredentialsNotValid` if the verifier rejects the password.
+    pub fn verify_password(
+        &self,
+        password: &str,
+        verifier: &impl PasswordVerifier,
+    ) -> Result<(), UserError> {
+        let hash = self.password.as_ref().ok_or(UserError::NoPasswordSet)?;
+        if verifier.verify(password, hash) {
+            Ok(())
+        } else {
+            Err(UserError::CredentialsNotValid)
+        }
+    }
+
+    /// Returns the external accounts the user can sign in with.
+    #[inline]
+    #[must_use]
+    pub fn external_identities(&self) -> &[ExternalIdentity] {
+        &self.external_identities
+    }
+
+    /// Returns the account linked at `provider`, if any.
+    #[must_use]
+    pub fn external_identity(&self, provider: &str) -> Option<&ExternalIdentity> {
+        self.external_identities
+            .iter()
+            .find(|identity| identity.provider() == provider)
+    }
+
+    /// Returns `true` if `identity` names an account linked to the user.
+    #[must_use]
+    pub fn is_linked_to(&self, identity: &ExternalIdentity) -> bool {
+        self.external_identities
+            .iter()
+            .any(|linked| linked.is_same_account(identity))
+    }
+
+    /// Links an external account so the user can sign in with it.
+    ///
+    /// Linking the same account again refreshes the email the provider
+    /// reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserError::ProviderAlreadyLinked` if another account at the
+    /// same provider is linked already.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_auth::{ExternalIdentity, User};
+    ///
+    /// let mut user = User::new(
+    ///     "Ana".to_string(),
+    ///     None,
+    ///     "Lopez".to_string(),
+    ///     None,
+    ///     "12345678-1".to_string(),
+    ///     "ana@example.com".to_string(),
+    ///     None,
+    /// ).unwrap();
+    /// let google = ExternalIdentity::new("google", "42", "ana@gmail.com".to_string()).unwrap();
+    /// let other = ExternalIdentity::new("google", "43", "ana.l@gmail.com".to_string()).unwrap();
+    ///
+    /// user.link_external_identity(google.clone()).unwrap();
+    ///
+    /// assert!(user.is_linked_to(&google));
+    /// assert!(user.link_external_identity(other).is_err());
+    /// ```
+    pub fn link_external_identity(&mut self, identity: ExternalIdentity) -> Result<(), UserError> {
+        match self
+            .external_identities
+            .iter_mut()
+            .find(|linked| linked.provider() == identity.provider())
+        {
+            Some(linked) if linked.is_same_account(&identity) => *linked = identity,
+            Some(_) => {
+                return Err(UserError::ProviderAlreadyLinked(identity.provider().to_string()));
+            }
+            None => self.external_identities.push(identity),
+        }
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Unlinks the account at `provider` and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserError::ProviderNotLinked` if no account at `provider`
+    /// is linked.
+    pub fn unlink_external_identity(
+        &mut self,
+        provider: &str,
+    ) -> Result<ExternalIdentity, UserError> {
+        let index = self
+            .external_identities
+            .iter()
+            .position(|identity| identity.provider() == provider)
+            .ok_or_else(|| UserError::ProviderNotLinked(provider.to_string()))?;
+        self.timestamps.touch();
+        Ok(self.external_identities.remove(index))
+    }
+
+    /// Returns the failed sign-ins in a row since the last successful one
+    /// or the end of the last lockout.
+    #[inline]
+    #[must_use]
+    pub const fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+
+    /// Returns when the last lockout ends, if the account was ever locked
+    /// since the last successful sign-in.
+    #[inline]
+    #[must_use]
+    pub const fn locked_until(&self) -> Option<DateTime> {
+        self.locked_until
+    }
+
+    /// Returns `true` if sign-ins are refused at `now` because of too many
+    /// failed attempts.
+    #[must_use]
+    pub fn is_locked(&self, now: DateTime) -> bool {
+        self.locked_until.is_some_and(|until| now < until)
+    }
+
+    /// Records a failed sign-in and returns whether the account is locked
+    /// afterwards.
+    ///
+    /// The account locks for the policy's cool-down once the failures in a
+    /// row reach its threshold. Attempts made while locked change nothing,
+    /// so they cannot extend the lockout; the first failure after it ends
+    /// starts counting from scratch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_auth::{LockoutPolicy, User};
+    /// use education_platform_common::{DateTime, Duration};
+    ///
+    /// let mut user = User::new(
+    ///     "Iris".to_string(),
+    ///     None,
+    ///     "Vega".to_string(),
+    ///     None,
+    ///     "12345678-1".to_string(),
+    ///     "iris@example.com".to_string(),
+    ///     None,
+    /// ).unwrap();
+    /// let policy = LockoutPolicy::new(2, Duration::from_minutes(10)).unwrap();
+    /// let at = DateTime::new(2025, 3, 4, 9, 0, 0).unwrap();
+    ///
+    /// assert!(!user.record_failed_attempt(&policy, at));
+    /// assert!(user.record_failed_attempt(&policy, at));
+    /// assert!(user.is_locked(at.add_seconds(599)));
+    /// assert!(!user.is_locked(at.add_seconds(600)));
+    /// ```
+    pub fn record_failed_attempt(&mut self, policy: &LockoutPolicy, at: DateTime) -> bool {
+        if self.is_locked(at) {
+            return true;
+        }
+        if self.locked_until.take().is_some() {
+            self.failed_attempts = 0;
+        }
+
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        if self.failed_attempts >= policy.threshold() {
+            let seconds = i64::try_from(policy.cool_down().total_seconds()).unwrap_or(i64::MAX);
+            self.locked_until = Some(at.saturating_add_seconds(seconds));
+        }
+        self.timestamps.touch();
+        self.locked_until.is_some()
+    }
+
+    /// Records a successful sign-in, clearing the failed attempts and any
+    /// ended lockout.
+    ///
+    /// Callers check [`User::is_locked`] before accepting a sign-in, so a
+    /// correct password does not lift a lockout early.
+    pub fn record_successful_login(&mut self) {
+        if self.failed_attempts > 0 || self.locked_until.is_some() {
+            self.failed_attempts = 0;
+            self.locked_until = None;
+            self.timestamps.touch();
+        }
+    }
+
+    /// Returns the organization-specific fields recorded for the user.
+    #[inline]
+    #[must_use]
+    pub const fn custom_fields(&self) -> &CustomFields {
+        &self.custom_fields
+    }
+
+    /// Sets or clears one of the organization's user fields.
+    ///
+    /// A blank value clears the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserError::CustomFieldError` if the schema has no such user
+    /// field or the value breaks its rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_auth::User;
+    /// use education_platform_common::{
+    ///     CustomFieldDefinition, CustomFieldKind, CustomFieldSchema, CustomFieldTarget, Id,
+    /// };
+    ///
+    /// let mut schema = CustomFieldSchema::new(Id::new());
+    /// schema.add_definition(CustomFieldDefinition::new(
+    ///     "student_number",
+    ///     "Student number",
+    ///     CustomFieldKind::Text { max_length: 12 },
+    ///     CustomFieldTarget::User,
+    ///     true,
+    /// ).unwrap()).unwrap();
+    /// let mut user = User::new(
+    ///     "Hana".to_string(),
+    ///     None,
+    ///     "Ito".to_string(),
+    ///     None,
+    ///     "12345678-1".to_string(),
+    ///     "hana@example.com".to_string(),
+    ///     None,
+    /// ).unwrap();
+    ///
+    /// user.set_custom_field(&schema, "student_number", "A-1024").unwrap();
+    ///
+    /// assert_eq!(
+    ///     user.custom_fields().get("student_number").unwrap().to_string(),
+    ///     "A-1024"
+    /// );
+    /// assert!(user.set_custom_field(&schema, "department", "Physics").is_err());
+    /// ```
+    pub fn set_custom_field(
+        &mut self,
+        schema: &CustomFieldSchema,
+        key: &str,
+        value: &str,
+    ) -> Result<(), UserError> {
+        schema.set_value(CustomFieldTarget::User, &mut self.custom_fields, key, value)?;
+        self.timestamps.touch();
+        Ok(())
+    }
 }
 
 impl Entity for User {
@@ -310,6 +656,16 @@ impl Entity for User {
     }
 }
 
+impl Auditable for User {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
 impl PartialEq for User {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -455,7 +811,7 @@ mod tests {
             assert!(result.is_ok());
             let user = result.unwrap();
             assert_eq!(user.name().first_name(), "John");
-            assert_eq!(user.name().last_name(), "Doe");
+            assert_eq!(user.name().last_name(), Some("Doe"));
         }
 
         #[test]
@@ -505,7 +861,7 @@ mod tests {
             assert_eq!(user.name().full_name(), "Alice Marie Wonder");
             assert_eq!(user.name().first_name(), "Alice");
             assert_eq!(user.name().middle_name(), Some("Marie"));
-            assert_eq!(user.name().last_name(), "Wonder");
+            assert_eq!(user.name().last_name(), Some("Wonder"));
         }
 
         #[test]
@@ -521,8 +877,8 @@ mod tests {
             )
             .unwrap();
 
-            assert_eq!(user.document().with_verification_char(), "00000001-I");
-            assert_eq!(user.document().value(), "00000001");
+            assert_eq!(user.document().to_string(), "00000001-I");
+            assert_eq!(user.document().as_dni().unwrap().value(), "00000001");
         }
 
         #[test]
@@ -770,7 +1126,366 @@ mod tests {
             )
             .unwrap();
 
-            assert_eq!(user.document().with_verification_char(), "98765432-1");
+            assert_eq!(user.document().to_string(), "98765432-1");
+            assert_eq!(user.document_kind(), DocumentKind::Dni);
+        }
+
+        #[test]
+        fn test_create_user_with_chilean_rut() {
+            let user = User::with_document(
+                "Camila".to_string(),
+                None,
+                "Soto".to_string(),
+                Some("Muñoz".to_string()),
+                Document::parse(DocumentKind::Rut, "12.345.678-5").unwrap(),
+                "camila.soto@example.cl".to_string(),
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(user.document_kind(), DocumentKind::Rut);
+            assert_eq!(user.document().to_string(), "12.345.678-5");
+        }
+
+        #[test]
+        fn test_create_user_with_passport() {
+            let user = User::with_document(
+                "Anna".to_string(),
+                None,
+                "Schmidt".to_string(),
+                None,
+                Document::parse(DocumentKind::Passport, "C01X00T47").unwrap(),
+                "anna.schmidt@example.de".to_string(),
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(user.document_kind(), DocumentKind::Passport);
+            assert!(user.document().as_dni().is_none());
+        }
+
+        #[test]
+        fn test_with_document_still_validates_other_fields() {
+            let result = User::with_document(
+                "Camila".to_string(),
+                None,
+                "Soto".to_string(),
+                None,
+                Document::parse(DocumentKind::Cedula, "1023456789").unwrap(),
+                "not-an-email".to_string(),
+                None,
+            );
+
+            assert!(matches!(result, Err(UserError::EmailError(_))));
+        }
+    }
+
+    mod custom_fields {
+        use super::*;
+        use education_platform_common::{CustomFieldDefinition, CustomFieldKind};
+
+        fn create_test_user() -> User {
+            User::new(
+                "Ana".to_string(),
+                None,
+                "Torres".to_string(),
+                None,
+                "12345678-1".to_string(),
+                "ana@example.com".to_string(),
+                None,
+            )
+            .unwrap()
+        }
+
+        fn create_test_schema() -> CustomFieldSchema {
+            let mut schema = CustomFieldSchema::new(Id::new());
+            schema
+                .add_definition(
+                    CustomFieldDefinition::new(
+                        "campus",
+                        "Campus",
+                        CustomFieldKind::Select(vec!["North".to_string(), "South".to_string()]),
+                        CustomFieldTarget::Course,
+                        false,
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+            schema
+        }
+
+        #[test]
+        fn test_new_user_has_no_custom_fields() {
+            assert!(create_test_user().custom_fields().is_empty());
+        }
+
+        #[test]
+        fn test_course_field_cannot_be_set_on_user() {
+            let mut user = create_test_user();
+
+            let result = user.set_custom_field(&create_test_schema(), "campus", "North");
+
+            assert_eq!(
+                result,
+                Err(UserError::CustomFieldError(CustomFieldError::UnknownField(
+                    "campus".to_string()
+                )))
+            );
+        }
+
+        #[test]
+        fn test_setting_field_records_modification() {
+            let mut user = create_test_user();
+            let mut schema = CustomFieldSchema::new(Id::new());
+            schema
+                .add_definition(
+                    CustomFieldDefinition::new(
+                        "student_number",
+                        "Student number",
+                        CustomFieldKind::Text { max_length: 20 },
+                        CustomFieldTarget::User,
+                        false,
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            user.set_custom_field(&schema, "student_number", "A-1024")
+                .unwrap();
+
+            assert!(user.updated_at() > user.created_at());
+        }
+
+        #[test]
+        fn test_rejected_field_keeps_timestamps() {
+            let mut user = create_test_user();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            let _ = user.set_custom_field(&create_test_schema(), "campus", "North");
+
+            assert_eq!(user.updated_at(), user.created_at());
+        }
+    }
+    mod verify_password {
+        use super::*;
+
+        struct MatchesHash;
+
+        impl PasswordVerifier for MatchesHash {
+            fn verify(&self, password: &str, hash: &HashedPassword) -> bool {
+                hash.value().ends_with(password)
+            }
+        }
+
+        fn create_test_user(password: Option<&str>) -> User {
+            User::new(
+                "Ana".to_string(),
+                None,
+                "Torres".to_string(),
+                None,
+                "12345678-1".to_string(),
+                "ana@example.com".to_string(),
+                password.map(str::to_string),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_matching_password_is_accepted() {
+            let user = create_test_user(Some(VALID_ARGON2ID_HASH));
+
+            assert_eq!(user.verify_password("JObG", &MatchesHash), Ok(()));
+        }
+
+        #[test]
+        fn test_wrong_password_is_rejected() {
+            let user = create_test_user(Some(VALID_ARGON2ID_HASH));
+
+            assert_eq!(
+                user.verify_password("guess", &MatchesHash),
+                Err(UserError::CredentialsNotValid)
+            );
+        }
+
+        #[test]
+        fn test_user_without_password_cannot_verify() {
+            let user = create_test_user(None);
+
+            assert_eq!(user.verify_password("", &MatchesHash), Err(UserError::NoPasswordSet));
+        }
+    }
+
+    mod lockout {
+        use super::*;
+        use education_platform_common::Duration;
+
+        fn create_test_user() -> User {
+            User::new(
+                "Ana".to_string(),
+                None,
+                "Torres".to_string(),
+                None,
+                "12345678-1".to_string(),
+                "ana@example.com".to_string(),
+                None,
+            )
+            .unwrap()
+        }
+
+        fn policy() -> LockoutPolicy {
+            LockoutPolicy::new(3, Duration::from_minutes(15)).unwrap()
+        }
+
+        fn at(minute: u32) -> DateTime {
+            DateTime::new(2025, 3, 4, 9, minute, 0).unwrap()
+        }
+
+        #[test]
+        fn test_new_user_is_not_locked() {
+            let user = create_test_user();
+
+            assert_eq!(user.failed_attempts(), 0);
+            assert_eq!(user.locked_until(), None);
+            assert!(!user.is_locked(at(0)));
+        }
+
+        #[test]
+        fn test_locks_at_threshold() {
+            let mut user = create_test_user();
+
+            let locked: Vec<bool> = (0..3)
+                .map(|minute| user.record_failed_attempt(&policy(), at(minute)))
+                .collect();
+
+            assert_eq!(locked, vec![false, false, true]);
+            assert_eq!(user.locked_until(), Some(at(17)));
+            assert!(user.is_locked(at(16)));
+            assert!(!user.is_locked(at(17)));
+        }
+
+        #[test]
+        fn test_huge_cool_down_locks_without_overflow() {
+            let mut user = create_test_user();
+            let policy = LockoutPolicy::new(1, Duration::from_seconds(u64::MAX)).unwrap();
+
+            assert!(user.record_failed_attempt(&policy, at(0)));
+
+            assert!(user.is_locked(DateTime::new(9999, 12, 31, 23, 59, 59).unwrap()));
+        }
+
+        #[test]
+        fn test_attempts_while_locked_do_not_extend_lockout() {
+            let mut user = create_test_user();
+            for minute in 0..3 {
+                user.record_failed_attempt(&policy(), at(minute));
+            }
+
+            assert!(user.record_failed_attempt(&policy(), at(10)));
+
+            assert_eq!(user.failed_attempts(), 3);
+            assert_eq!(user.locked_until(), Some(at(17)));
+        }
+
+        #[test]
+        fn test_counting_restarts_after_cool_down() {
+            let mut user = create_test_user();
+            for minute in 0..3 {
+                user.record_failed_attempt(&policy(), at(minute));
+            }
+
+            let locked = user.record_failed_attempt(&policy(), at(20));
+
+            assert!(!locked);
+            assert_eq!(user.failed_attempts(), 1);
+            assert_eq!(user.locked_until(), None);
+        }
+
+        #[test]
+        fn test_successful_login_clears_failures() {
+            let mut user = create_test_user();
+            user.record_failed_attempt(&policy(), at(0));
+            user.record_failed_attempt(&policy(), at(1));
+
+            user.record_successful_login();
+
+            assert_eq!(user.failed_attempts(), 0);
+            assert!(!user.record_failed_attempt(&policy(), at(2)));
+            assert!(!user.record_failed_attempt(&policy(), at(3)));
+        }
+    }
+
+    mod external_identities {
+        use super::*;
+
+        fn create_test_user() -> User {
+            User::new(
+                "Ana".to_string(),
+                None,
+                "Torres".to_string(),
+                None,
+                "12345678-1".to_string(),
+                "ana@example.com".to_string(),
+                None,
+            )
+            .unwrap()
+        }
+
+        fn identity(provider: &str, subject: &str, email: &str) -> ExternalIdentity {
+            ExternalIdentity::new(provider, subject, email.to_string()).unwrap()
+        }
+
+        #[test]
+        fn test_new_user_has_no_identities() {
+            let user = create_test_user();
+
+            assert!(user.external_identities().is_empty());
+            assert_eq!(user.external_identity("google"), None);
+        }
+
+        #[test]
+        fn test_link_one_account_per_provider() {
+            let mut user = create_test_user();
+
+            user.link_external_identity(identity("google", "42", "ana@gmail.com"))
+                .unwrap();
+            user.link_external_identity(identity("github", "7", "ana@example.com"))
+                .unwrap();
+            let result = user.link_external_identity(identity("google", "43", "ana@gmail.com"));
+
+            assert_eq!(result, Err(UserError::ProviderAlreadyLinked("google".to_string())));
+            assert_eq!(user.external_identities().len(), 2);
+            assert_eq!(user.external_identity("google").unwrap().subject(), "42");
+        }
+
+        #[test]
+        fn test_relinking_refreshes_email() {
+            let mut user = create_test_user();
+            user.link_external_identity(identity("google", "42", "ana@gmail.com"))
+                .unwrap();
+
+            user.link_external_identity(identity("google", "42", "ana@work.com"))
+                .unwrap();
+
+            assert_eq!(user.external_identities().len(), 1);
+            assert_eq!(
+                user.external_identity("google").unwrap().email().address(),
+                "ana@work.com"
+            );
+        }
+
+        #[test]
+        fn test_unlink() {
+            let mut user = create_test_user();
+            let google = identity("google", "42", "ana@gmail.com");
+            user.link_external_identity(google.clone()).unwrap();
+
+            assert_eq!(user.unlink_external_identity("google"), Ok(google.clone()));
+            assert!(!user.is_linked_to(&google));
+            assert_eq!(
+                user.unlink_external_identity("google"),
+                Err(UserError::ProviderNotLinked("google".to_string()))
+            );
         }
     }
 }
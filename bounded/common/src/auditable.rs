@@ -0,0 +1,267 @@
+use crate::{DateTime, Entity};
+use thiserror::Error;
+
+/// Error types for Timestamps validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimestampsError {
+    #[error("Update time {updated_at} is before creation time {created_at}")]
+    UpdatedBeforeCreated {
+        created_at: String,
+        updated_at: String,
+    },
+}
+
+/// When an entity was created and when it last changed.
+///
+/// `Timestamps` keeps the update time at or after the creation time, so an
+/// entity never looks modified before it existed.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{DateTime, Timestamps};
+///
+/// let created = DateTime::new(2024, 3, 15, 9, 0, 0).unwrap();
+/// let mut timestamps = Timestamps::since(created);
+/// assert_eq!(timestamps.updated_at(), created);
+///
+/// let edited = DateTime::new(2024, 3, 16, 10, 30, 0).unwrap();
+/// timestamps.touch_at(edited);
+/// assert_eq!(timestamps.created_at(), created);
+/// assert_eq!(timestamps.updated_at(), edited);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timestamps {
+    created_at: DateTime,
+    updated_at: DateTime,
+}
+
+impl Timestamps {
+    /// Creates timestamps for an entity created right now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Timestamps;
+    ///
+    /// let timestamps = Timestamps::now();
+    /// assert_eq!(timestamps.created_at(), timestamps.updated_at());
+    /// ```
+    #[must_use]
+    pub fn now() -> Self {
+        Self::since(DateTime::today())
+    }
+
+    /// Creates timestamps for an entity created at `at` and not changed since.
+    #[inline]
+    #[must_use]
+    pub const fn since(at: DateTime) -> Self {
+        Self {
+            created_at: at,
+            updated_at: at,
+        }
+    }
+
+    /// Restores timestamps, for example from persistence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimestampsError::UpdatedBeforeCreated` if `updated_at` comes
+    /// before `created_at`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, Timestamps};
+    ///
+    /// let created = DateTime::new(2024, 3, 15, 9, 0, 0).unwrap();
+    /// let updated = DateTime::new(2024, 4, 1, 12, 0, 0).unwrap();
+    ///
+    /// assert!(Timestamps::new(created, updated).is_ok());
+    /// assert!(Timestamps::new(updated, created).is_err());
+    /// ```
+    pub fn new(created_at: DateTime, updated_at: DateTime) -> Result<Self, TimestampsError> {
+        if updated_at < created_at {
+            return Err(TimestampsError::UpdatedBeforeCreated {
+                created_at: created_at.to_string(),
+                updated_at: updated_at.to_string(),
+            });
+        }
+        Ok(Self {
+            created_at,
+            updated_at,
+        })
+    }
+
+    /// Returns when the entity was created.
+    #[inline]
+    #[must_use]
+    pub const fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    /// Returns when the entity last changed.
+    #[inline]
+    #[must_use]
+    pub const fn updated_at(&self) -> DateTime {
+        self.updated_at
+    }
+
+    /// Records a change made right now.
+    pub fn touch(&mut self) {
+        self.touch_at(DateTime::today());
+    }
+
+    /// Records a change made at `at`.
+    ///
+    /// The update time never moves backwards, so replaying changes out of
+    /// order or with a skewed clock keeps the latest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, Timestamps};
+    ///
+    /// let created = DateTime::new(2024, 3, 15, 9, 0, 0).unwrap();
+    /// let later = DateTime::new(2024, 3, 20, 9, 0, 0).unwrap();
+    /// let mut timestamps = Timestamps::since(created);
+    ///
+    /// timestamps.touch_at(later);
+    /// timestamps.touch_at(created);
+    /// assert_eq!(timestamps.updated_at(), later);
+    /// ```
+    pub fn touch_at(&mut self, at: DateTime) {
+        self.updated_at = self.updated_at.max(at);
+    }
+}
+
+/// An [`Entity`] that records when it was created and last modified.
+///
+/// Implementors update the modification time on every operation that
+/// changes them, and only when the operation succeeds.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Auditable, DateTime, Entity, Id, Timestamps};
+///
+/// struct Tag {
+///     id: Id,
+///     label: String,
+///     timestamps: Timestamps,
+/// }
+///
+/// impl Tag {
+///     fn rename(&mut self, label: &str) {
+///         self.label = label.to_string();
+///         self.timestamps.touch();
+///     }
+/// }
+///
+/// impl Entity for Tag {
+///     fn id(&self) -> Id {
+///         self.id
+///     }
+/// }
+///
+/// impl Auditable for Tag {
+///     fn created_at(&self) -> DateTime {
+///         self.timestamps.created_at()
+///     }
+///
+///     fn updated_at(&self) -> DateTime {
+///         self.timestamps.updated_at()
+///     }
+/// }
+///
+/// let created = DateTime::new(2024, 3, 15, 9, 0, 0).unwrap();
+/// let mut tag = Tag {
+///     id: Id::new(),
+///     label: "rust".to_string(),
+///     timestamps: Timestamps::since(created),
+/// };
+/// tag.rename("Rust");
+///
+/// assert_eq!(tag.created_at(), created);
+/// assert!(tag.updated_at() > created);
+/// ```
+pub trait Auditable: Entity {
+    /// Returns when the entity was created.
+    fn created_at(&self) -> DateTime;
+
+    /// Returns when the entity was last modified; equal to the creation
+    /// time until the first change.
+    fn updated_at(&self) -> DateTime;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> DateTime {
+        DateTime::new(2024, 3, day, hour, 0, 0).unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_since_sets_both_times() {
+            let timestamps = Timestamps::since(at(15, 9));
+
+            assert_eq!(timestamps.created_at(), at(15, 9));
+            assert_eq!(timestamps.updated_at(), at(15, 9));
+        }
+
+        #[test]
+        fn test_now_is_not_in_the_past() {
+            let before = DateTime::today();
+            let timestamps = Timestamps::now();
+
+            assert!(timestamps.created_at() >= before);
+        }
+
+        #[test]
+        fn test_new_accepts_equal_times() {
+            assert!(Timestamps::new(at(15, 9), at(15, 9)).is_ok());
+        }
+
+        #[test]
+        fn test_new_rejects_update_before_creation() {
+            let result = Timestamps::new(at(15, 9), at(14, 9));
+
+            assert!(matches!(result, Err(TimestampsError::UpdatedBeforeCreated { .. })));
+        }
+    }
+
+    mod touching {
+        use super::*;
+
+        #[test]
+        fn test_touch_at_moves_update_time_forward() {
+            let mut timestamps = Timestamps::since(at(15, 9));
+            timestamps.touch_at(at(16, 9));
+
+            assert_eq!(timestamps.created_at(), at(15, 9));
+            assert_eq!(timestamps.updated_at(), at(16, 9));
+        }
+
+        #[test]
+        fn test_touch_at_ignores_earlier_time() {
+            let mut timestamps = Timestamps::since(at(15, 9));
+            timestamps.touch_at(at(16, 9));
+            timestamps.touch_at(at(15, 12));
+
+            assert_eq!(timestamps.updated_at(), at(16, 9));
+        }
+
+        #[test]
+        fn test_touch_uses_current_time() {
+            let mut timestamps = Timestamps::since(at(15, 9));
+            timestamps.touch();
+
+            assert!(timestamps.updated_at() > at(15, 9));
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{Date, DateTime};
+use std::fmt;
+use thiserror::Error;
+
+/// How many days ahead [`CronSchedule::next_after`] looks before giving up.
+///
+/// Four years and a day always include a February 29th, so any valid day
+/// and month combination is found.
+const SEARCH_DAYS: i64 = 4 * 366;
+
+/// Error types for cron expression parsing failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CronError {
+    #[error("Cron expression needs 5 fields, found {0}")]
+    FieldCount(usize),
+
+    #[error("Cron {field} field '{value}' is not valid")]
+    FieldNotValid { field: &'static str, value: String },
+}
+
+struct FieldSpec {
+    name: &'static str,
+    min: u32,
+    max: u32,
+}
+
+const FIELDS: [FieldSpec; 5] = [
+    FieldSpec {
+        name: "minute",
+        min: 0,
+        max: 59,
+    },
+    FieldSpec {
+        name: "hour",
+        min: 0,
+        max: 23,
+    },
+    FieldSpec {
+        name: "day of month",
+        min: 1,
+        max: 31,
+    },
+    FieldSpec {
+        name: "month",
+        min: 1,
+        max: 12,
+    },
+    FieldSpec {
+        name: "day of week",
+        min: 0,
+        max: 7,
+    },
+];
+
+/// A recurring schedule in the five-field cron format:
+/// `minute hour day-of-month month day-of-week`.
+///
+/// Each field takes `*`, a number, a range `a-b` or a comma-separated list
+/// of them, each optionally stepped with `/n`. Days of the week run from 0
+/// (Sunday) to 6, and 7 is Sunday too. As in classic cron, when both day
+/// fields are restricted a day matching either one qualifies.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{CronSchedule, DateTime};
+///
+/// // Every Monday at 08:00.
+/// let schedule = CronSchedule::new("0 8 * * 1").unwrap();
+/// let friday = DateTime::new(2025, 3, 7, 12, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     schedule.next_after(friday),
+///     Some(DateTime::new(2025, 3, 10, 8, 0, 0).unwrap())
+/// );
+/// assert!(CronSchedule::new("0 8 * *").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parses a five-field cron expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CronError::FieldCount` if the expression does not have five
+    /// whitespace-separated fields, or `CronError::FieldNotValid` for the
+    /// first field that does not parse or is out of range.
+    pub fn new(expression: &str) -> Result<Self, CronError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != FIELDS.len() {
+            return Err(CronError::FieldCount(fields.len()));
+        }
+
+        let mut masks = [0_u64; 5];
+        for ((mask, value), spec) in masks.iter_mut().zip(&fields).zip(&FIELDS) {
+            *mask = parse_field(value, spec).ok_or_else(|| CronError::FieldNotValid {
+                field: spec.name,
+                value: (*value).to_string(),
+            })?;
+        }
+
+        let [minutes, hours, days_of_month, months, mut days_of_week] = masks;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week |= 1;
+        }
+
+        Ok(Self {
+            expression: fields.join(" "),
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            day_of_month_restricted: fields[2] != "*",
+            day_of_week_restricted: fields[4] != "*",
+        })
+    }
+
+    /// Returns the expression, with whitespace normalized.
+    #[inline]
+    #[must_use]
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Returns whether the schedule fires at the minute of `at`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{CronSchedule, DateTime};
+    ///
+    /// let schedule = CronSchedule::new("*/15 9-17 * * 1-5").unwrap();
+    ///
+    /// assert!(schedule.matches(DateTime::new(2025, 3, 4, 9, 45, 30).unwrap()));
+    /// assert!(!schedule.matches(DateTime::new(2025, 3, 8, 9, 45, 0).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, at: DateTime) -> bool {
+        self.matches_day(at.date()) && has(self.hours, at.hour()) && has(self.minutes, at.minute())
+    }
+
+    /// Returns the first time the schedule fires strictly after `after`,
+    /// at the start of a minute.
+    ///
+    /// Returns `None` for schedules that never fire, like February 30th.
+    #[must_use]
+    pub fn next_after(&self, after: DateTime) -> Option<DateTime> {
+        let start = after.add_seconds(60 - i64::from(after.second()));
+        let first_day = start.date();
+
+        for offset in 0..SEARCH_DAYS {
+            let day = first_day.add_days(offset);
+            if !self.matches_day(day) {
+                continue;
+            }
+
+            let fire_time = (0..24)
+                .filter(|&hour| has(self.hours, hour))
+                .flat_map(|hour| {
+                    (0..60)
+                        .filter(|&minute| has(self.minutes, minute))
+                        .map(move |minute| (hour, minute))
+                })
+                .filter_map(|(hour, minute)| {
+                    DateTime::new(day.year(), day.month(), day.day(), hour, minute, 0).ok()
+                })
+                .find(|candidate| *candidate >= start);
+            if fire_time.is_some() {
+                return fire_time;
+            }
+        }
+        None
+    }
+
+    fn matches_day(&self, date: Date) -> bool {
+        if !has(self.months, date.month()) {
+            return false;
+        }
+
+        let day_of_month = has(self.days_of_month, date.day());
+        let day_of_week = has(self.days_of_week, date.weekday() % 7);
+        if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+const fn has(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn parse_field(field: &str, spec: &FieldSpec) -> Option<u64> {
+    field.split(',').try_fold(0_u64, |mask, item| {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|&s| s > 0)?),
+            None => (item, 1),
+        };
+        let (start, end) = match range {
+            "*" => (spec.min, spec.max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+                None => {
+                    let value = range.parse().ok()?;
+                    // `5/15` means "from 5 to the end, every 15".
+                    let end = if item.contains('/') { spec.max } else { value };
+                    (value, end)
+                }
+            },
+        };
+        if start < spec.min || end > spec.max || start > end {
+            return None;
+        }
+
+        let stepped = (start..=end)
+            .step_by(usize::try_from(step).ok()?)
+            .fold(0_u64, |bits, value| bits | (1 << value));
+        Some(mask | stepped)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_time(day: u32, hour: u32, minute: u32) -> DateTime {
+        DateTime::new(2025, 3, day, hour, minute, 0).unwrap()
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_whitespace_is_normalized() {
+            let schedule = CronSchedule::new("  0   8 * *  1 ").unwrap();
+
+            assert_eq!(schedule.expression(), "0 8 * * 1");
+            assert_eq!(schedule.to_string(), "0 8 * * 1");
+        }
+
+        #[test]
+        fn test_out_of_range_values_are_rejected() {
+            for expression in [
+                "60 * * * *",
+                "* 24 * * *",
+                "* * 0 * *",
+                "* * * 13 *",
+                "* * * * 8",
+            ] {
+                assert!(
+                    matches!(CronSchedule::new(expression), Err(CronError::FieldNotValid { .. })),
+                    "{expression}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_malformed_fields_are_rejected() {
+            for expression in ["*/0 * * * *", "5-1 * * * *", "a * * * *", "1,,2 * * * *"] {
+                assert!(CronSchedule::new(expression).is_err(), "{expression}");
+            }
+        }
+
+        #[test]
+        fn test_field_count_is_reported() {
+            assert_eq!(CronSchedule::new("* * * * * *"), Err(CronError::FieldCount(6)));
+        }
+    }
+
+    mod matches {
+        use super::*;
+
+        #[test]
+        fn test_lists_ranges_and_steps_combine() {
+            let schedule = CronSchedule::new("0,30 8-10/2 * * *").unwrap();
+
+            assert!(schedule.matches(create_time(4, 8, 30)));
+            assert!(schedule.matches(create_time(4, 10, 0)));
+            assert!(!schedule.matches(create_time(4, 9, 0)));
+        }
+
+        #[test]
+        fn test_seven_is_sunday() {
+            let schedule = CronSchedule::new("0 9 * * 7").unwrap();
+
+            assert!(schedule.matches(create_time(9, 9, 0)));
+        }
+
+        #[test]
+        fn test_restricted_day_fields_match_either() {
+            let schedule = CronSchedule::new("0 9 1 * 1").unwrap();
+
+            assert!(schedule.matches(create_time(1, 9, 0)));
+            assert!(schedule.matches(create_time(3, 9, 0)));
+            assert!(!schedule.matches(create_time(4, 9, 0)));
+        }
+    }
+
+    mod next_after {
+        use super::*;
+
+        #[test]
+        fn test_current_minute_is_skipped() {
+            let schedule = CronSchedule::new("* * * * *").unwrap();
+
+            assert_eq!(
+                schedule.next_after(DateTime::new(2025, 3, 4, 9, 0, 0).unwrap()),
+                Some(create_time(4, 9, 1))
+            );
+            assert_eq!(
+                schedule.next_after(DateTime::new(2025, 3, 4, 9, 0, 59).unwrap()),
+                Some(create_time(4, 9, 1))
+            );
+        }
+
+        #[test]
+        fn test_month_end_rolls_to_next_matching_month() {
+            let schedule = CronSchedule::new("0 6 31 * *").unwrap();
+
+            assert_eq!(
+                schedule.next_after(DateTime::new(2025, 3, 31, 7, 0, 0).unwrap()),
+                Some(DateTime::new(2025, 5, 31, 6, 0, 0).unwrap())
+            );
+        }
+
+        #[test]
+        fn test_leap_day_is_found() {
+            let schedule = CronSchedule::new("0 0 29 2 *").unwrap();
+
+            assert_eq!(
+                schedule.next_after(create_time(1, 0, 0)),
+                Some(DateTime::new(2028, 2, 29, 0, 0, 0).unwrap())
+            );
+        }
+
+        #[test]
+        fn test_impossible_date_never_fires() {
+            let schedule = CronSchedule::new("0 0 30 2 *").unwrap();
+
+            assert_eq!(schedule.next_after(create_time(1, 0, 0)), None);
+        }
+    }
+}
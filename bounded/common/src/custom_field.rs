@@ -0,0 +1,8 @@
+mod csv;
+mod definition;
+mod fields;
+mod schema;
+
+pub use definition::*;
+pub use fields::*;
+pub use schema::*;
@@ -0,0 +1,125 @@
+use crate::{CustomFieldDefinition, CustomFieldSchema, CustomFieldTarget, CustomFields, Id};
+
+impl CustomFieldSchema {
+    /// Exports the custom field values of users or courses as CSV.
+    ///
+    /// The first column is the record ID and each field defined for the
+    /// target gets a column headed by its key, in definition order. Missing
+    /// values are empty cells. Cells are quoted as RFC 4180 describes and
+    /// rows end in CRLF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{
+    ///     CustomFieldDefinition, CustomFieldKind, CustomFieldSchema, CustomFieldTarget,
+    ///     CustomFields, Id,
+    /// };
+    ///
+    /// let mut schema = CustomFieldSchema::new(Id::new());
+    /// schema.add_definition(CustomFieldDefinition::new(
+    ///     "department",
+    ///     "Department",
+    ///     CustomFieldKind::Text { max_length: 50 },
+    ///     CustomFieldTarget::User,
+    ///     false,
+    /// ).unwrap()).unwrap();
+    ///
+    /// let user_id = Id::new();
+    /// let mut fields = CustomFields::new();
+    /// schema.set_value(CustomFieldTarget::User, &mut fields, "department", "Physics, Applied").unwrap();
+    ///
+    /// let csv = schema.to_csv(CustomFieldTarget::User, [(user_id, &fields)]);
+    /// assert_eq!(csv, format!("id,department\r\n{user_id},\"Physics, Applied\"\r\n"));
+    /// ```
+    #[must_use]
+    pub fn to_csv<'a>(
+        &self,
+        target: CustomFieldTarget,
+        records: impl IntoIterator<Item = (Id, &'a CustomFields)>,
+    ) -> String {
+        let definitions: Vec<&CustomFieldDefinition> = self.definitions(target).collect();
+
+        let mut csv = String::from("id");
+        for definition in &definitions {
+            csv.push(',');
+            csv.push_str(&escape_cell(definition.key()));
+        }
+        csv.push_str("\r\n");
+
+        for (id, fields) in records {
+            csv.push_str(&id.to_string());
+            for definition in &definitions {
+                csv.push(',');
+                if let Some(value) = fields.get(definition.key()) {
+                    csv.push_str(&escape_cell(&value.to_string()));
+                }
+            }
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+fn escape_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CustomFieldKind;
+
+    fn create_test_schema() -> CustomFieldSchema {
+        let mut schema = CustomFieldSchema::new(Id::new());
+        for (key, target) in [
+            ("nickname", CustomFieldTarget::User),
+            ("campus", CustomFieldTarget::Course),
+            ("start_date", CustomFieldTarget::User),
+        ] {
+            let kind = if key == "start_date" {
+                CustomFieldKind::Date
+            } else {
+                CustomFieldKind::Text { max_length: 50 }
+            };
+            schema
+                .add_definition(CustomFieldDefinition::new(key, key, kind, target, false).unwrap())
+                .unwrap();
+        }
+        schema
+    }
+
+    #[test]
+    fn test_only_target_fields_become_columns() {
+        let schema = create_test_schema();
+
+        let csv = schema.to_csv(CustomFieldTarget::User, []);
+
+        assert_eq!(csv, "id,nickname,start_date\r\n");
+    }
+
+    #[test]
+    fn test_missing_values_are_empty_cells() {
+        let schema = create_test_schema();
+        let id = Id::new();
+        let mut fields = CustomFields::new();
+        schema
+            .set_value(CustomFieldTarget::User, &mut fields, "start_date", "2025-09-01")
+            .unwrap();
+
+        let csv = schema.to_csv(CustomFieldTarget::User, [(id, &fields)]);
+
+        assert_eq!(csv.lines().nth(1), Some(format!("{id},,2025-09-01").as_str()));
+    }
+
+    #[test]
+    fn test_quotes_and_line_breaks_are_escaped() {
+        assert_eq!(escape_cell("The \"Doc\""), "\"The \"\"Doc\"\"\"");
+        assert_eq!(escape_cell("two\nlines"), "\"two\nlines\"");
+        assert_eq!(escape_cell("plain"), "plain");
+    }
+}
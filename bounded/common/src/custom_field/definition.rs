@@ -0,0 +1,475 @@
+use crate::Date;
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for custom field definitions and values.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CustomFieldError {
+    #[error(
+        "Field key '{0}' must start with a lowercase letter and contain only lowercase letters, digits and underscores"
+    )]
+    KeyNotValid(String),
+
+    #[error("Field label must not be blank or longer than 100 characters")]
+    LabelNotValid,
+
+    #[error("Field '{key}' is not valid: {reason}")]
+    KindNotValid { key: String, reason: String },
+
+    #[error("Field '{0}' is already defined")]
+    DuplicateField(String),
+
+    #[error("Field '{0}' is not defined")]
+    UnknownField(String),
+
+    #[error("Field '{0}' is required")]
+    MissingValue(String),
+
+    #[error("Field '{key}' must be at most {max_length} characters")]
+    TextTooLong { key: String, max_length: usize },
+
+    #[error("Field '{key}' expects a whole number, got '{value}'")]
+    NotANumber { key: String, value: String },
+
+    #[error("Field '{key}' value {value} is out of range")]
+    OutOfRange { key: String, value: i64 },
+
+    #[error("Field '{key}' expects a date as YYYY-MM-DD, got '{value}'")]
+    DateNotValid { key: String, value: String },
+
+    #[error("Field '{key}' does not offer the option '{value}'")]
+    UnknownOption { key: String, value: String },
+}
+
+/// The entity a custom field is recorded on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CustomFieldTarget {
+    User,
+    Course,
+}
+
+/// The type of a custom field and the rules its values follow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomFieldKind {
+    /// Free text up to a number of characters.
+    Text { max_length: usize },
+    /// A whole number, optionally bounded on either side.
+    Number { min: Option<i64>, max: Option<i64> },
+    /// A calendar date.
+    Date,
+    /// One of a fixed list of options.
+    Select(Vec<String>),
+}
+
+/// A validated custom field value.
+///
+/// Values display in the same form they are parsed from, so a value can be
+/// written to an export and read back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomFieldValue {
+    Text(String),
+    Number(i64),
+    Date(Date),
+    Choice(String),
+}
+
+impl fmt::Display for CustomFieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) | Self::Choice(text) => write!(f, "{text}"),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Date(date) => write!(f, "{date}"),
+        }
+    }
+}
+
+/// An extra field an organization records on its users or courses, such as
+/// a student number or a department.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{
+///     CustomFieldDefinition, CustomFieldKind, CustomFieldTarget, CustomFieldValue,
+/// };
+///
+/// let department = CustomFieldDefinition::new(
+///     "department",
+///     "Department",
+///     CustomFieldKind::Select(vec!["Physics".to_string(), "History".to_string()]),
+///     CustomFieldTarget::User,
+///     true,
+/// ).unwrap();
+///
+/// assert_eq!(
+///     department.parse(" Physics "),
+///     Ok(CustomFieldValue::Choice("Physics".to_string()))
+/// );
+/// assert!(department.parse("Chemistry").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomFieldDefinition {
+    key: String,
+    label: String,
+    kind: CustomFieldKind,
+    target: CustomFieldTarget,
+    required: bool,
+}
+
+impl CustomFieldDefinition {
+    pub const MAX_KEY_LENGTH: usize = 40;
+    pub const MAX_LABEL_LENGTH: usize = 100;
+
+    /// Creates a field definition.
+    ///
+    /// The key identifies the field in stored values and export columns; the
+    /// label is what people see. Both are trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CustomFieldError::KeyNotValid` if the key is not snake_case or
+    /// longer than [`CustomFieldDefinition::MAX_KEY_LENGTH`].
+    /// Returns `CustomFieldError::LabelNotValid` if the label is blank or longer
+    /// than [`CustomFieldDefinition::MAX_LABEL_LENGTH`].
+    /// Returns `CustomFieldError::KindNotValid` for a zero text length, an
+    /// inverted number range, or a select without options or with blank or
+    /// repeated options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{CustomFieldDefinition, CustomFieldKind, CustomFieldTarget};
+    ///
+    /// let kind = CustomFieldKind::Number { min: Some(1), max: None };
+    /// assert!(CustomFieldDefinition::new("cohort_year", "Cohort year", kind.clone(), CustomFieldTarget::User, false).is_ok());
+    /// assert!(CustomFieldDefinition::new("Cohort Year", "Cohort year", kind, CustomFieldTarget::User, false).is_err());
+    /// ```
+    pub fn new(
+        key: &str,
+        label: &str,
+        kind: CustomFieldKind,
+        target: CustomFieldTarget,
+        required: bool,
+    ) -> Result<Self, CustomFieldError> {
+        let key = key.trim();
+        if !Self::is_valid_key(key) {
+            return Err(CustomFieldError::KeyNotValid(key.to_string()));
+        }
+
+        let label = label.trim();
+        if label.is_empty() || label.chars().count() > Self::MAX_LABEL_LENGTH {
+            return Err(CustomFieldError::LabelNotValid);
+        }
+
+        if let Some(reason) = Self::kind_problem(&kind) {
+            return Err(CustomFieldError::KindNotValid {
+                key: key.to_string(),
+                reason,
+            });
+        }
+
+        Ok(Self {
+            key: key.to_string(),
+            label: label.to_string(),
+            kind,
+            target,
+            required,
+        })
+    }
+
+    /// Returns the key values are stored under.
+    #[inline]
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the human-readable label.
+    #[inline]
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the field type.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> &CustomFieldKind {
+        &self.kind
+    }
+
+    /// Returns the entity the field is recorded on.
+    #[inline]
+    #[must_use]
+    pub const fn target(&self) -> CustomFieldTarget {
+        self.target
+    }
+
+    /// Returns whether every record must have a value.
+    #[inline]
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Parses and validates a raw value, as typed in a form or read from an
+    /// import, after trimming it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `CustomFieldError` variant matching the rule the value
+    /// breaks: `TextTooLong`, `NotANumber`, `OutOfRange`, `DateNotValid` or
+    /// `UnknownOption`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{
+    ///     CustomFieldDefinition, CustomFieldKind, CustomFieldTarget, CustomFieldValue, Date,
+    /// };
+    ///
+    /// let start = CustomFieldDefinition::new(
+    ///     "contract_start",
+    ///     "Contract start",
+    ///     CustomFieldKind::Date,
+    ///     CustomFieldTarget::User,
+    ///     false,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     start.parse("2025-09-01"),
+    ///     Ok(CustomFieldValue::Date(Date::new(2025, 9, 1).unwrap()))
+    /// );
+    /// assert!(start.parse("01/09/2025").is_err());
+    /// ```
+    pub fn parse(&self, raw: &str) -> Result<CustomFieldValue, CustomFieldError> {
+        let raw = raw.trim();
+        match &self.kind {
+            CustomFieldKind::Text { max_length } => {
+                if raw.chars().count() > *max_length {
+                    return Err(CustomFieldError::TextTooLong {
+                        key: self.key.clone(),
+                        max_length: *max_length,
+                    });
+                }
+                Ok(CustomFieldValue::Text(raw.to_string()))
+            }
+            CustomFieldKind::Number { min, max } => {
+                let value = raw
+                    .parse::<i64>()
+                    .map_err(|_| CustomFieldError::NotANumber {
+                        key: self.key.clone(),
+                        value: raw.to_string(),
+                    })?;
+                if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+                    return Err(CustomFieldError::OutOfRange {
+                        key: self.key.clone(),
+                        value,
+                    });
+                }
+                Ok(CustomFieldValue::Number(value))
+            }
+            CustomFieldKind::Date => {
+                Date::from_iso(raw)
+                    .map(CustomFieldValue::Date)
+                    .map_err(|_| CustomFieldError::DateNotValid {
+                        key: self.key.clone(),
+                        value: raw.to_string(),
+                    })
+            }
+            CustomFieldKind::Select(options) => options
+                .iter()
+                .find(|option| option.as_str() == raw)
+                .map(|option| CustomFieldValue::Choice(option.clone()))
+                .ok_or_else(|| CustomFieldError::UnknownOption {
+                    key: self.key.clone(),
+                    value: raw.to_string(),
+                }),
+        }
+    }
+
+    fn is_valid_key(key: &str) -> bool {
+        key.len() <= Self::MAX_KEY_LENGTH
+            && key.starts_with(|c: char| c.is_ascii_lowercase())
+            && key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    }
+
+    fn kind_problem(kind: &CustomFieldKind) -> Option<String> {
+        match kind {
+            CustomFieldKind::Text { max_length: 0 } => {
+                Some("text fields must allow at least one character".to_string())
+            }
+            CustomFieldKind::Number {
+                min: Some(min),
+                max: Some(max),
+            } if min > max => Some(format!("minimum {min} is above maximum {max}")),
+            CustomFieldKind::Select(options) if options.is_empty() => {
+                Some("select fields need at least one option".to_string())
+            }
+            CustomFieldKind::Select(options) => {
+                options.iter().enumerate().find_map(|(i, option)| {
+                    if option.trim().is_empty() || option.trim() != option {
+                        Some(format!("option '{option}' is blank or padded"))
+                    } else if options[..i].contains(option) {
+                        Some(format!("option '{option}' is listed twice"))
+                    } else {
+                        None
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_definition(kind: CustomFieldKind) -> CustomFieldDefinition {
+        CustomFieldDefinition::new("field", "Field", kind, CustomFieldTarget::User, false).unwrap()
+    }
+
+    mod constructor {
+        use super::*;
+
+        #[test]
+        fn test_key_and_label_are_trimmed() {
+            let definition = CustomFieldDefinition::new(
+                " student_number ",
+                " Student number ",
+                CustomFieldKind::Text { max_length: 20 },
+                CustomFieldTarget::User,
+                true,
+            )
+            .unwrap();
+
+            assert_eq!(definition.key(), "student_number");
+            assert_eq!(definition.label(), "Student number");
+            assert!(definition.is_required());
+        }
+
+        #[test]
+        fn test_key_must_be_snake_case() {
+            for key in ["", "1st_year", "student-number", "Student", "département"] {
+                let result = CustomFieldDefinition::new(
+                    key,
+                    "Label",
+                    CustomFieldKind::Date,
+                    CustomFieldTarget::User,
+                    false,
+                );
+
+                assert_eq!(result, Err(CustomFieldError::KeyNotValid(key.to_string())), "{key}");
+            }
+        }
+
+        #[test]
+        fn test_blank_label_is_rejected() {
+            let result = CustomFieldDefinition::new(
+                "department",
+                "  ",
+                CustomFieldKind::Date,
+                CustomFieldTarget::Course,
+                false,
+            );
+
+            assert_eq!(result, Err(CustomFieldError::LabelNotValid));
+        }
+
+        #[test]
+        fn test_invalid_kinds_are_rejected() {
+            let kinds = [
+                CustomFieldKind::Text { max_length: 0 },
+                CustomFieldKind::Number {
+                    min: Some(5),
+                    max: Some(1),
+                },
+                CustomFieldKind::Select(vec![]),
+                CustomFieldKind::Select(vec!["A".to_string(), "A".to_string()]),
+                CustomFieldKind::Select(vec![" A".to_string()]),
+            ];
+
+            for kind in kinds {
+                let result = CustomFieldDefinition::new(
+                    "field",
+                    "Field",
+                    kind.clone(),
+                    CustomFieldTarget::User,
+                    false,
+                );
+
+                assert!(
+                    matches!(result, Err(CustomFieldError::KindNotValid { .. })),
+                    "{kind:?}"
+                );
+            }
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_text_counts_characters() {
+            let definition = create_test_definition(CustomFieldKind::Text { max_length: 4 });
+
+            assert_eq!(
+                definition.parse("ñandú"),
+                Err(CustomFieldError::TextTooLong {
+                    key: "field".to_string(),
+                    max_length: 4,
+                })
+            );
+            assert_eq!(
+                definition.parse("ñand"),
+                Ok(CustomFieldValue::Text("ñand".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_number_respects_bounds() {
+            let definition = create_test_definition(CustomFieldKind::Number {
+                min: Some(2000),
+                max: Some(2100),
+            });
+
+            assert_eq!(definition.parse("2025"), Ok(CustomFieldValue::Number(2025)));
+            assert_eq!(
+                definition.parse("1999"),
+                Err(CustomFieldError::OutOfRange {
+                    key: "field".to_string(),
+                    value: 1999,
+                })
+            );
+            assert!(matches!(
+                definition.parse("2025.5"),
+                Err(CustomFieldError::NotANumber { .. })
+            ));
+        }
+
+        #[test]
+        fn test_value_displays_as_parsed() {
+            let definition = create_test_definition(CustomFieldKind::Date);
+
+            let value = definition.parse("2025-03-04").unwrap();
+
+            assert_eq!(value.to_string(), "2025-03-04");
+            assert_eq!(definition.parse(&value.to_string()), Ok(value));
+        }
+
+        #[test]
+        fn test_select_is_case_sensitive() {
+            let definition =
+                create_test_definition(CustomFieldKind::Select(vec!["Physics".to_string()]));
+
+            assert!(matches!(
+                definition.parse("physics"),
+                Err(CustomFieldError::UnknownOption { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{CustomFieldDefinition, CustomFieldError, CustomFieldValue};
+use std::collections::BTreeMap;
+
+/// The custom field values recorded on one user or course, by field key.
+///
+/// New values get in through [`CustomFields::set`], so each one passed its
+/// definition's rules when it was stored; [`CustomFields::restore`] reads
+/// stored values back as they are. Use
+/// [`CustomFieldSchema::validate`](crate::CustomFieldSchema::validate) to
+/// check them again after the definitions change.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{
+///     CustomFieldDefinition, CustomFieldKind, CustomFieldTarget, CustomFieldValue, CustomFields,
+/// };
+///
+/// let year = CustomFieldDefinition::new(
+///     "cohort_year",
+///     "Cohort year",
+///     CustomFieldKind::Number { min: Some(2000), max: None },
+///     CustomFieldTarget::User,
+///     false,
+/// ).unwrap();
+/// let mut fields = CustomFields::new();
+///
+/// fields.set(&year, "2025").unwrap();
+/// assert_eq!(fields.get("cohort_year"), Some(&CustomFieldValue::Number(2025)));
+///
+/// fields.set(&year, "").unwrap();
+/// assert!(fields.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomFields {
+    values: BTreeMap<String, CustomFieldValue>,
+}
+
+impl CustomFields {
+    /// Creates an empty set of values.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuilds values read back from storage, keyed by field.
+    ///
+    /// The values are trusted as stored, since the definitions they were
+    /// checked against may not be at hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{CustomFieldValue, CustomFields};
+    ///
+    /// let fields = CustomFields::restore([("cohort_year".to_string(), CustomFieldValue::Number(2025))]);
+    ///
+    /// assert_eq!(fields.get("cohort_year"), Some(&CustomFieldValue::Number(2025)));
+    /// ```
+    #[must_use]
+    pub fn restore(values: impl IntoIterator<Item = (String, CustomFieldValue)>) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+        }
+    }
+
+    /// Stores the value for a field, or clears it when the raw value is blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CustomFieldError::MissingValue` when clearing a required
+    /// field, or the error from [`CustomFieldDefinition::parse`] when the
+    /// value breaks the field's rules. The stored value is left unchanged.
+    pub fn set(
+        &mut self,
+        definition: &CustomFieldDefinition,
+        raw: &str,
+    ) -> Result<(), CustomFieldError> {
+        if raw.trim().is_empty() {
+            if definition.is_required() {
+                return Err(CustomFieldError::MissingValue(definition.key().to_string()));
+            }
+            self.values.remove(definition.key());
+            return Ok(());
+        }
+
+        let value = definition.parse(raw)?;
+        self.values.insert(definition.key().to_string(), value);
+        Ok(())
+    }
+
+    /// Removes the value of a field, e.g. after its definition was deleted.
+    pub fn remove(&mut self, key: &str) -> Option<CustomFieldValue> {
+        self.values.remove(key)
+    }
+
+    /// Returns the value of a field, if one is stored.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&CustomFieldValue> {
+        self.values.get(key)
+    }
+
+    /// Returns the stored values ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &CustomFieldValue)> {
+        self.values.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Returns the number of stored values.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true when no value is stored.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomFieldKind, CustomFieldTarget};
+
+    fn create_test_definition(required: bool) -> CustomFieldDefinition {
+        CustomFieldDefinition::new(
+            "student_number",
+            "Student number",
+            CustomFieldKind::Text { max_length: 10 },
+            CustomFieldTarget::User,
+            required,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_invalid_value_keeps_previous_value() {
+        let definition = create_test_definition(false);
+        let mut fields = CustomFields::new();
+        fields.set(&definition, "A-1024").unwrap();
+
+        let result = fields.set(&definition, "A-1024-0000-99");
+
+        assert!(matches!(result, Err(CustomFieldError::TextTooLong { .. })));
+        assert_eq!(
+            fields.get("student_number"),
+            Some(&CustomFieldValue::Text("A-1024".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_required_value_cannot_be_cleared() {
+        let definition = create_test_definition(true);
+        let mut fields = CustomFields::new();
+        fields.set(&definition, "A-1024").unwrap();
+
+        let result = fields.set(&definition, "   ");
+
+        assert_eq!(
+            result,
+            Err(CustomFieldError::MissingValue("student_number".to_string()))
+        );
+        assert_eq!(fields.len(), 1);
+    }
+
+    #[test]
+    fn test_restore_round_trips_iter() {
+        let definition = create_test_definition(false);
+        let mut fields = CustomFields::new();
+        fields.set(&definition, "A-1024").unwrap();
+
+        let restored = CustomFields::restore(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone())),
+        );
+
+        assert_eq!(restored, fields);
+    }
+
+    #[test]
+    fn test_remove_returns_value() {
+        let definition = create_test_definition(false);
+        let mut fields = CustomFields::new();
+        fields.set(&definition, "A-1024").unwrap();
+
+        assert_eq!(
+            fields.remove("student_number"),
+            Some(CustomFieldValue::Text("A-1024".to_string()))
+        );
+        assert!(fields.is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{CustomFieldDefinition, CustomFieldError, CustomFieldTarget, CustomFields, Id};
+
+/// The custom fields one organization defines for its users and courses.
+///
+/// Definitions keep the order they were added in, which is the order forms
+/// and exports list them.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{
+///     CustomFieldDefinition, CustomFieldKind, CustomFieldSchema, CustomFieldTarget,
+///     CustomFields, Id,
+/// };
+///
+/// let mut schema = CustomFieldSchema::new(Id::new());
+/// schema.add_definition(CustomFieldDefinition::new(
+///     "student_number",
+///     "Student number",
+///     CustomFieldKind::Text { max_length: 12 },
+///     CustomFieldTarget::User,
+///     true,
+/// ).unwrap()).unwrap();
+///
+/// let mut fields = CustomFields::new();
+/// assert!(schema.validate(CustomFieldTarget::User, &fields).is_err());
+///
+/// schema.set_value(CustomFieldTarget::User, &mut fields, "student_number", "A-1024").unwrap();
+/// assert!(schema.validate(CustomFieldTarget::User, &fields).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomFieldSchema {
+    organization_id: Id,
+    definitions: Vec<CustomFieldDefinition>,
+}
+
+impl CustomFieldSchema {
+    /// Creates a schema without fields for an organization.
+    #[must_use]
+    pub const fn new(organization_id: Id) -> Self {
+        Self {
+            organization_id,
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Returns the organization the fields belong to.
+    #[inline]
+    #[must_use]
+    pub const fn organization_id(&self) -> Id {
+        self.organization_id
+    }
+
+    /// Adds a field definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CustomFieldError::DuplicateField` if a field with the same key
+    /// is already defined, for either target.
+    pub fn add_definition(
+        &mut self,
+        definition: CustomFieldDefinition,
+    ) -> Result<(), CustomFieldError> {
+        if self.definitions.iter().any(|d| d.key() == definition.key()) {
+            return Err(CustomFieldError::DuplicateField(definition.key().to_string()));
+        }
+
+        self.definitions.push(definition);
+        Ok(())
+    }
+
+    /// Removes a field definition and returns it.
+    ///
+    /// Values already stored for the field are not touched; the next
+    /// [`CustomFieldSchema::validate`] reports them as unknown.
+    pub fn remove_definition(&mut self, key: &str) -> Option<CustomFieldDefinition> {
+        let position = self.definitions.iter().position(|d| d.key() == key)?;
+        Some(self.definitions.remove(position))
+    }
+
+    /// Returns the definition of a field on the given target.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CustomFieldError::UnknownField` if no such field is defined
+    /// for that target.
+    pub fn definition(
+        &self,
+        target: CustomFieldTarget,
+        key: &str,
+    ) -> Result<&CustomFieldDefinition, CustomFieldError> {
+        self.definitions
+            .iter()
+            .find(|d| d.target() == target && d.key() == key)
+            .ok_or_else(|| CustomFieldError::UnknownField(key.to_string()))
+    }
+
+    /// Returns the definitions for a target, in the order they were added.
+    pub fn definitions(
+        &self,
+        target: CustomFieldTarget,
+    ) -> impl Iterator<Item = &CustomFieldDefinition> {
+        self.definitions
+            .iter()
+            .filter(move |d| d.target() == target)
+    }
+
+    /// Stores or clears the value of a field defined for the given target.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CustomFieldError::UnknownField` if the field is not defined
+    /// for the target, or the error from [`CustomFields::set`].
+    pub fn set_value(
+        &self,
+        target: CustomFieldTarget,
+        fields: &mut CustomFields,
+        key: &str,
+        raw: &str,
+    ) -> Result<(), CustomFieldError> {
+        fields.set(self.definition(target, key)?, raw)
+    }
+
+    /// Checks stored values against the current definitions.
+    ///
+    /// Definitions can change after values were stored: an option may be
+    /// dropped from a select or a field may become required. This reports
+    /// the first value that no longer fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CustomFieldError::UnknownField` for a value without a
+    /// definition, `CustomFieldError::MissingValue` for a required field
+    /// without a value, or the parse error of a value that breaks its rules.
+    pub fn validate(
+        &self,
+        target: CustomFieldTarget,
+        fields: &CustomFields,
+    ) -> Result<(), CustomFieldError> {
+        for (key, value) in fields.iter() {
+            self.definition(target, key)?.parse(&value.to_string())?;
+        }
+
+        match self
+            .definitions(target)
+            .find(|d| d.is_required() && fields.get(d.key()).is_none())
+        {
+            Some(missing) => Err(CustomFieldError::MissingValue(missing.key().to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CustomFieldKind;
+
+    fn create_test_schema() -> CustomFieldSchema {
+        let mut schema = CustomFieldSchema::new(Id::new());
+        schema
+            .add_definition(
+                CustomFieldDefinition::new(
+                    "department",
+                    "Department",
+                    CustomFieldKind::Select(vec!["Physics".to_string(), "History".to_string()]),
+                    CustomFieldTarget::User,
+                    false,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        schema
+            .add_definition(
+                CustomFieldDefinition::new(
+                    "credits",
+                    "Credits",
+                    CustomFieldKind::Number {
+                        min: Some(1),
+                        max: Some(10),
+                    },
+                    CustomFieldTarget::Course,
+                    true,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        schema
+    }
+
+    #[test]
+    fn test_duplicate_key_is_rejected_across_targets() {
+        let mut schema = create_test_schema();
+        let duplicate = CustomFieldDefinition::new(
+            "credits",
+            "Credits earned",
+            CustomFieldKind::Date,
+            CustomFieldTarget::User,
+            false,
+        )
+        .unwrap();
+
+        let result = schema.add_definition(duplicate);
+
+        assert_eq!(result, Err(CustomFieldError::DuplicateField("credits".to_string())));
+    }
+
+    #[test]
+    fn test_field_of_other_target_is_unknown() {
+        let schema = create_test_schema();
+        let mut fields = CustomFields::new();
+
+        let result =
+            schema.set_value(CustomFieldTarget::Course, &mut fields, "department", "Physics");
+
+        assert_eq!(result, Err(CustomFieldError::UnknownField("department".to_string())));
+    }
+
+    #[test]
+    fn test_definitions_are_filtered_by_target() {
+        let schema = create_test_schema();
+
+        let keys: Vec<&str> = schema
+            .definitions(CustomFieldTarget::Course)
+            .map(CustomFieldDefinition::key)
+            .collect();
+
+        assert_eq!(keys, vec!["credits"]);
+    }
+
+    #[test]
+    fn test_validate_reports_missing_required_value() {
+        let schema = create_test_schema();
+
+        let result = schema.validate(CustomFieldTarget::Course, &CustomFields::new());
+
+        assert_eq!(result, Err(CustomFieldError::MissingValue("credits".to_string())));
+    }
+
+    #[test]
+    fn test_validate_reports_values_of_removed_fields() {
+        let mut schema = create_test_schema();
+        let mut fields = CustomFields::new();
+        schema
+            .set_value(CustomFieldTarget::User, &mut fields, "department", "History")
+            .unwrap();
+
+        schema.remove_definition("department");
+
+        assert_eq!(
+            schema.validate(CustomFieldTarget::User, &fields),
+            Err(CustomFieldError::UnknownField("department".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_validate_rechecks_stored_values() {
+        let mut schema = create_test_schema();
+        let mut fields = CustomFields::new();
+        schema
+            .set_value(CustomFieldTarget::User, &mut fields, "department", "History")
+            .unwrap();
+        schema.remove_definition("department");
+        schema
+            .add_definition(
+                CustomFieldDefinition::new(
+                    "department",
+                    "Department",
+                    CustomFieldKind::Select(vec!["Physics".to_string()]),
+                    CustomFieldTarget::User,
+                    false,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        let result = schema.validate(CustomFieldTarget::User, &fields);
+
+        assert!(matches!(result, Err(CustomFieldError::UnknownOption { .. })));
+    }
+}
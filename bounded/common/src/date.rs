@@ -355,6 +355,127 @@ impl Date {
         self.add_days(-days)
     }
 
+    /// Adds a number of months, clamping the day to the end of a shorter month.
+    ///
+    /// Negative values go back in time. Dates beyond the supported range
+    /// stop at its first or last day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Date;
+    ///
+    /// let date = Date::new(2024, 1, 31).unwrap();
+    /// assert_eq!(date.add_months(1), Date::new(2024, 2, 29).unwrap());
+    /// assert_eq!(date.add_months(2), Date::new(2024, 3, 31).unwrap());
+    /// assert_eq!(date.add_months(-2), Date::new(2023, 11, 30).unwrap());
+    /// ```
+    #[must_use]
+    pub fn add_months(&self, months: i32) -> Self {
+        let month_index =
+            i64::from(self.year()) * 12 + i64::from(self.inner.month0()) + i64::from(months);
+        let year = i32::try_from(month_index.div_euclid(12)).ok();
+        let month = u32::try_from(month_index.rem_euclid(12)).unwrap_or(0) + 1;
+        let inner = year
+            .and_then(|year| {
+                let day = self.day().min(Self::days_in_month(year, month));
+                NaiveDate::from_ymd_opt(year, month, day)
+            })
+            .unwrap_or(if months > 0 {
+                NaiveDate::MAX
+            } else {
+                NaiveDate::MIN
+            });
+        Self { inner }
+    }
+
+    /// Adds a number of years, moving February 29 to February 28 in
+    /// common years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Date;
+    ///
+    /// let leap_day = Date::new(2024, 2, 29).unwrap();
+    /// assert_eq!(leap_day.add_years(1), Date::new(2025, 2, 28).unwrap());
+    /// assert_eq!(leap_day.add_years(4), leap_day.add_months(48));
+    /// ```
+    #[must_use]
+    pub fn add_years(&self, years: i32) -> Self {
+        self.add_months(years.saturating_mul(12))
+    }
+
+    /// Returns the first date after this one falling on `weekday`.
+    ///
+    /// A date already on `weekday` moves a full week ahead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::Date;
+    ///
+    /// let wednesday = Date::new(2024, 12, 11).unwrap();
+    /// assert_eq!(wednesday.next_weekday(Weekday::Mon), Date::new(2024, 12, 16).unwrap());
+    /// assert_eq!(wednesday.next_weekday(Weekday::Wed), Date::new(2024, 12, 18).unwrap());
+    /// ```
+    #[must_use]
+    pub fn next_weekday(&self, weekday: Weekday) -> Self {
+        let from = self.inner.weekday().num_days_from_monday();
+        let to = weekday.num_days_from_monday();
+        let ahead = (to + 7 - from) % 7;
+        self.add_days(if ahead == 0 { 7 } else { i64::from(ahead) })
+    }
+
+    /// Returns true if the date falls on a Saturday or Sunday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Date;
+    ///
+    /// assert!(Date::new(2024, 12, 14).unwrap().is_weekend());
+    /// assert!(!Date::new(2024, 12, 13).unwrap().is_weekend());
+    /// ```
+    #[must_use]
+    pub fn is_weekend(&self) -> bool {
+        matches!(self.inner.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+
+    /// Counts the Monday-to-Friday dates from this one up to, but not
+    /// including, `other`.
+    ///
+    /// Like [`Date::days_until`], the result is negative when `other` is
+    /// earlier; holidays are not taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Date;
+    ///
+    /// let friday = Date::new(2024, 12, 13).unwrap();
+    /// let next_friday = Date::new(2024, 12, 20).unwrap();
+    /// assert_eq!(friday.business_days_until(&next_friday), 5);
+    /// assert_eq!(next_friday.business_days_until(&friday), -5);
+    ///
+    /// let saturday = Date::new(2024, 12, 14).unwrap();
+    /// let monday = Date::new(2024, 12, 16).unwrap();
+    /// assert_eq!(saturday.business_days_until(&monday), 0);
+    /// ```
+    #[must_use]
+    pub fn business_days_until(&self, other: &Self) -> i64 {
+        if other < self {
+            return -other.business_days_until(self);
+        }
+        let days = self.days_until(other);
+        let full_weeks = days / 7;
+        let remainder = (0..days % 7)
+            .filter(|&offset| !self.add_days(full_weeks * 7 + offset).is_weekend())
+            .count();
+        full_weeks * 5 + i64::try_from(remainder).unwrap_or(0)
+    }
+
     /// Returns the number of days between two dates.
     ///
     /// Returns a positive number if `other` is after `self`, negative otherwise.
@@ -878,6 +999,136 @@ mod tests {
         }
     }
 
+    mod calendar_arithmetic {
+        use super::*;
+
+        fn date(year: i32, month: u32, day: u32) -> Date {
+            Date::new(year, month, day).unwrap()
+        }
+
+        #[test]
+        fn test_add_months_keeps_day() {
+            assert_eq!(date(2024, 3, 15).add_months(2), date(2024, 5, 15));
+        }
+
+        #[test]
+        fn test_add_months_clamps_to_month_end() {
+            assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+            assert_eq!(date(2023, 1, 31).add_months(1), date(2023, 2, 28));
+            assert_eq!(date(2024, 3, 31).add_months(1), date(2024, 4, 30));
+        }
+
+        #[test]
+        fn test_add_months_cross_year() {
+            assert_eq!(date(2024, 11, 30).add_months(3), date(2025, 2, 28));
+        }
+
+        #[test]
+        fn test_add_months_negative() {
+            assert_eq!(date(2024, 3, 31).add_months(-1), date(2024, 2, 29));
+            assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+        }
+
+        #[test]
+        fn test_add_months_zero_is_identity() {
+            assert_eq!(date(2024, 2, 29).add_months(0), date(2024, 2, 29));
+        }
+
+        #[test]
+        fn test_add_months_saturates_at_range_end() {
+            assert_eq!(date(2024, 1, 1).add_months(i32::MAX).inner, NaiveDate::MAX);
+            assert_eq!(date(2024, 1, 1).add_months(i32::MIN).inner, NaiveDate::MIN);
+        }
+
+        #[test]
+        fn test_add_years() {
+            assert_eq!(date(2024, 6, 15).add_years(2), date(2026, 6, 15));
+            assert_eq!(date(2024, 6, 15).add_years(-1), date(2023, 6, 15));
+        }
+
+        #[test]
+        fn test_add_years_from_leap_day() {
+            assert_eq!(date(2024, 2, 29).add_years(1), date(2025, 2, 28));
+            assert_eq!(date(2024, 2, 29).add_years(4), date(2028, 2, 29));
+        }
+
+        #[test]
+        fn test_next_weekday_later_in_week() {
+            let wednesday = date(2024, 12, 11);
+            assert_eq!(wednesday.next_weekday(Weekday::Fri), date(2024, 12, 13));
+        }
+
+        #[test]
+        fn test_next_weekday_wraps_to_next_week() {
+            let wednesday = date(2024, 12, 11);
+            assert_eq!(wednesday.next_weekday(Weekday::Tue), date(2024, 12, 17));
+        }
+
+        #[test]
+        fn test_next_weekday_same_day_moves_a_week() {
+            let sunday = date(2024, 12, 15);
+            assert_eq!(sunday.next_weekday(Weekday::Sun), date(2024, 12, 22));
+        }
+
+        #[test]
+        fn test_is_weekend() {
+            let weekend: Vec<bool> = (9..=15)
+                .map(|day| date(2024, 12, day).is_weekend())
+                .collect();
+            assert_eq!(weekend, [false, false, false, false, false, true, true]);
+        }
+
+        #[test]
+        fn test_business_days_until_same_date() {
+            let monday = date(2024, 12, 9);
+            assert_eq!(monday.business_days_until(&monday), 0);
+        }
+
+        #[test]
+        fn test_business_days_until_within_week() {
+            assert_eq!(date(2024, 12, 9).business_days_until(&date(2024, 12, 12)), 3);
+        }
+
+        #[test]
+        fn test_business_days_until_skips_weekend() {
+            let friday = date(2024, 12, 13);
+            let tuesday = date(2024, 12, 17);
+            assert_eq!(friday.business_days_until(&tuesday), 2);
+        }
+
+        #[test]
+        fn test_business_days_until_from_weekend() {
+            let saturday = date(2024, 12, 14);
+            assert_eq!(saturday.business_days_until(&date(2024, 12, 17)), 1);
+        }
+
+        #[test]
+        fn test_business_days_until_many_weeks() {
+            let start = date(2024, 1, 1);
+            let end = date(2025, 1, 1);
+            assert_eq!(start.business_days_until(&end), 262);
+        }
+
+        #[test]
+        fn test_business_days_until_matches_day_by_day_count() {
+            let start = date(2024, 12, 4);
+            for length in 0..30 {
+                let end = start.add_days(length);
+                let expected = (0..length)
+                    .filter(|&offset| !start.add_days(offset).is_weekend())
+                    .count();
+                assert_eq!(start.business_days_until(&end), expected as i64);
+            }
+        }
+
+        #[test]
+        fn test_business_days_until_negative() {
+            let monday = date(2024, 12, 16);
+            let friday = date(2024, 12, 13);
+            assert_eq!(monday.business_days_until(&friday), -1);
+        }
+    }
+
     mod formatting {
         use super::*;
 
@@ -0,0 +1,209 @@
+use crate::Date;
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for DateRange validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateRangeError {
+    #[error("Date range start {start} must not be after its end {end}")]
+    StartAfterEnd { start: String, end: String },
+}
+
+/// A span of calendar days, both the first and the last day included.
+///
+/// Unlike [`TimeRange`](crate::TimeRange), which spans instants, a
+/// `DateRange` spans whole days, so a range may start and end on the same
+/// day.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Date, DateRange};
+///
+/// let start = Date::new(2025, 3, 1).unwrap();
+/// let end = Date::new(2025, 3, 31).unwrap();
+/// let march = DateRange::new(start, end).unwrap();
+///
+/// assert_eq!(march.days(), 31);
+/// assert!(march.contains(end));
+/// assert_eq!(march.to_string(), "2025-03-01/2025-03-31");
+///
+/// // The start must not come after the end
+/// assert!(DateRange::new(end, start).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+impl DateRange {
+    /// Creates a new `DateRange` with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DateRangeError::StartAfterEnd` if `start` is after `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Date, DateRange};
+    ///
+    /// let day = Date::new(2025, 3, 1).unwrap();
+    ///
+    /// assert!(DateRange::new(day, day).is_ok());
+    /// assert!(DateRange::new(day, day.sub_days(1)).is_err());
+    /// ```
+    pub fn new(start: Date, end: Date) -> Result<Self, DateRangeError> {
+        match start <= end {
+            true => Ok(Self { start, end }),
+            false => Err(DateRangeError::StartAfterEnd {
+                start: start.to_string(),
+                end: end.to_string(),
+            }),
+        }
+    }
+
+    /// Returns the first day of the range.
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Returns the last day of the range.
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+
+    /// Returns the number of days in the range, counting both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Date, DateRange};
+    ///
+    /// let day = Date::new(2025, 3, 1).unwrap();
+    /// assert_eq!(DateRange::new(day, day).unwrap().days(), 1);
+    /// ```
+    #[must_use]
+    pub fn days(&self) -> u64 {
+        self.start.days_until(&self.end).unsigned_abs() + 1
+    }
+
+    /// Returns true if the day falls inside the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Date, DateRange};
+    ///
+    /// let start = Date::new(2025, 3, 1).unwrap();
+    /// let range = DateRange::new(start, start.add_days(6)).unwrap();
+    ///
+    /// assert!(range.contains(start));
+    /// assert!(!range.contains(start.add_days(7)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, day: Date) -> bool {
+        self.start <= day && day <= self.end
+    }
+
+    /// Returns true if the two ranges share any day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Date, DateRange};
+    ///
+    /// let day = |d| Date::new(2025, 3, d).unwrap();
+    /// let first_week = DateRange::new(day(1), day(7)).unwrap();
+    /// let second_week = DateRange::new(day(8), day(14)).unwrap();
+    /// let midweek = DateRange::new(day(7), day(9)).unwrap();
+    ///
+    /// assert!(!first_week.overlaps(&second_week));
+    /// assert!(first_week.overlaps(&midweek));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day: u32) -> Date {
+        Date::new(2025, 3, day).unwrap()
+    }
+
+    fn range(from: u32, to: u32) -> DateRange {
+        DateRange::new(day(from), day(to)).unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_single_day_range() {
+            let range = DateRange::new(day(5), day(5)).unwrap();
+
+            assert_eq!(range.start(), range.end());
+            assert_eq!(range.days(), 1);
+        }
+
+        #[test]
+        fn test_reversed_days_are_rejected() {
+            let result = DateRange::new(day(10), day(9));
+
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "Date range start 2025-03-10 must not be after its end 2025-03-09"
+            );
+        }
+    }
+
+    mod queries {
+        use super::*;
+
+        #[test]
+        fn test_days_across_months() {
+            let range = DateRange::new(day(30), Date::new(2025, 4, 2).unwrap()).unwrap();
+
+            assert_eq!(range.days(), 4);
+        }
+
+        #[test]
+        fn test_contains_includes_both_ends() {
+            let range = range(3, 5);
+
+            assert!(range.contains(day(3)));
+            assert!(range.contains(day(5)));
+            assert!(!range.contains(day(2)));
+            assert!(!range.contains(day(6)));
+        }
+
+        #[test]
+        fn test_ranges_sharing_a_day_overlap() {
+            assert!(range(1, 5).overlaps(&range(5, 9)));
+            assert!(range(1, 9).overlaps(&range(3, 4)));
+        }
+
+        #[test]
+        fn test_consecutive_ranges_do_not_overlap() {
+            assert!(!range(1, 4).overlaps(&range(5, 9)));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::Date;
 use chrono::{Datelike, Local, NaiveDateTime, Timelike};
 use std::fmt;
 use std::str::FromStr;
@@ -120,6 +121,29 @@ impl DateTime {
         s.parse()
     }
 
+    /// Converts milliseconds since the Unix epoch to local time, the zone
+    /// [`DateTime::today`] reads the clock in.
+    ///
+    /// Returns `None` if the instant is beyond the supported range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let created = DateTime::from_timestamp_ms(Id::new().timestamp_ms()).unwrap();
+    /// assert!(created.year() >= 2024);
+    /// assert!(DateTime::from_timestamp_ms(u64::MAX).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_timestamp_ms(timestamp_ms: u64) -> Option<Self> {
+        let millis = i64::try_from(timestamp_ms).ok()?;
+        let utc = chrono::DateTime::from_timestamp_millis(millis)?;
+        Some(Self {
+            inner: utc.with_timezone(&Local).naive_local(),
+        })
+    }
+
     /// Returns the current datetime based on system time.
     ///
     /// # Examples
@@ -292,6 +316,50 @@ impl DateTime {
         }
     }
 
+    /// Adds a number of seconds, or returns `None` if the result is out of
+    /// range.
+    ///
+    /// Use it instead of [`DateTime::add_seconds`] when the offset comes
+    /// from configuration or user input and may be arbitrarily large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::DateTime;
+    ///
+    /// let dt = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+    /// assert_eq!(dt.checked_add_seconds(60), Some(dt.add_seconds(60)));
+    /// assert_eq!(dt.checked_add_seconds(i64::MAX), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add_seconds(&self, seconds: i64) -> Option<Self> {
+        chrono::Duration::try_seconds(seconds)
+            .and_then(|duration| self.inner.checked_add_signed(duration))
+            .map(Self::from_naive_datetime)
+    }
+
+    /// Adds a number of seconds, stopping at the latest (or earliest)
+    /// representable datetime instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::DateTime;
+    ///
+    /// let dt = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+    /// let far = dt.saturating_add_seconds(i64::MAX);
+    /// assert!(far > dt);
+    /// assert_eq!(far.saturating_add_seconds(1), far);
+    /// ```
+    #[must_use]
+    pub fn saturating_add_seconds(&self, seconds: i64) -> Self {
+        self.checked_add_seconds(seconds)
+            .unwrap_or_else(|| match seconds {
+                0.. => Self::from_naive_datetime(NaiveDateTime::MAX),
+                _ => Self::from_naive_datetime(NaiveDateTime::MIN),
+            })
+    }
+
     /// Subtracts a number of seconds from the datetime.
     ///
     /// # Examples
@@ -310,6 +378,21 @@ impl DateTime {
         self.add_seconds(-seconds)
     }
 
+    /// Returns the calendar date part, discarding the time of day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Date, DateTime};
+    ///
+    /// let dt = DateTime::new(2024, 3, 15, 23, 59, 59).unwrap();
+    /// assert_eq!(dt.date(), Date::new(2024, 3, 15).unwrap());
+    /// ```
+    #[must_use]
+    pub fn date(&self) -> Date {
+        Date::from_naive_date(self.inner.date())
+    }
+
     /// Formats the datetime as ISO 8601 (YYYY-MM-DDTHH:MM:SS).
     ///
     /// # Examples
@@ -552,6 +635,27 @@ mod tests {
             assert_eq!(later.second(), 30);
         }
 
+        #[test]
+        fn test_checked_add_seconds_out_of_range() {
+            let dt = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+
+            assert_eq!(dt.checked_add_seconds(i64::MAX), None);
+            assert_eq!(dt.checked_add_seconds(i64::MIN), None);
+            assert_eq!(dt.checked_add_seconds(-60), Some(dt.sub_seconds(60)));
+        }
+
+        #[test]
+        fn test_saturating_add_seconds_clamps() {
+            let dt = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+
+            let latest = dt.saturating_add_seconds(i64::MAX);
+            let earliest = dt.saturating_add_seconds(i64::MIN);
+
+            assert!(latest > dt && earliest < dt);
+            assert_eq!(latest.saturating_add_seconds(3600), latest);
+            assert_eq!(dt.saturating_add_seconds(90), dt.add_seconds(90));
+        }
+
         #[test]
         fn test_sub_seconds() {
             let dt = DateTime::new(2024, 1, 1, 10, 1, 30).unwrap();
@@ -632,6 +736,7 @@ mod tests {
 
     mod conversions {
         use super::*;
+        use std::time::{SystemTime, UNIX_EPOCH};
 
         #[test]
         fn test_from_naive_datetime() {
@@ -650,5 +755,22 @@ mod tests {
             assert_eq!(naive.hour(), 10);
             assert_eq!(naive.second(), 45);
         }
+
+        #[test]
+        fn test_from_timestamp_ms_matches_the_clock() {
+            let before = DateTime::today().sub_seconds(1);
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+            let dt = DateTime::from_timestamp_ms(now.as_millis() as u64).unwrap();
+
+            assert!(dt >= before);
+            assert!(dt <= DateTime::today().add_seconds(1));
+        }
+
+        #[test]
+        fn test_date_drops_time_of_day() {
+            let dt = DateTime::new(2024, 6, 15, 23, 59, 59).unwrap();
+            assert_eq!(dt.date(), Date::new(2024, 6, 15).unwrap());
+        }
     }
 }
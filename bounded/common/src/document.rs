@@ -1,3 +1,15 @@
+mod carne_extranjeria;
+mod cedula;
+mod cpf;
 mod dni;
+mod identity;
+mod passport;
+mod rut;
 
+pub use carne_extranjeria::*;
+pub use cedula::*;
+pub use cpf::*;
 pub use dni::*;
+pub use identity::*;
+pub use passport::*;
+pub use rut::*;
@@ -0,0 +1,144 @@
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error types for Carné de Extranjería validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CarneExtranjeriaError {
+    #[error("Invalid length: {0}, should be between {1} and {2} characters")]
+    LengthIsIncorrect(usize, usize, usize),
+
+    #[error("Invalid character: {0}")]
+    CharacterNotValid(char),
+}
+
+/// Cards issued by Migraciones since 2013 carry 9 digits.
+const MIN_LENGTH: usize = 9;
+/// Older cards and the registry's own field allow up to 12 characters.
+const MAX_LENGTH: usize = 12;
+
+/// Peruvian Carné de Extranjería (foreign resident card) as a Value Object.
+///
+/// Unlike the DNI, the carné number has no published check character, so
+/// only its shape is validated: 9 to 12 letters and digits, stored in
+/// uppercase.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::CarneExtranjeria;
+///
+/// let carne = CarneExtranjeria::new("001234567".to_string()).unwrap();
+/// assert_eq!(carne.value(), "001234567");
+///
+/// let legacy: CarneExtranjeria = "n00123456".parse().unwrap();
+/// assert_eq!(legacy.to_string(), "N00123456");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CarneExtranjeria {
+    value: String,
+}
+
+impl CarneExtranjeria {
+    /// Creates a new Carné de Extranjería Value Object with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CarneExtranjeriaError::CharacterNotValid` for anything other
+    /// than ASCII letters and digits.
+    /// Returns `CarneExtranjeriaError::LengthIsIncorrect` if the number has
+    /// fewer than 9 or more than 12 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::CarneExtranjeria;
+    ///
+    /// assert!(CarneExtranjeria::new("001234567".to_string()).is_ok());
+    /// assert!(CarneExtranjeria::new("12345678".to_string()).is_err());
+    /// assert!(CarneExtranjeria::new("0012-34567".to_string()).is_err());
+    /// ```
+    pub fn new(carne: String) -> Result<Self, CarneExtranjeriaError> {
+        let value = carne.trim().to_ascii_uppercase();
+
+        if let Some(invalid) = value.chars().find(|c| !c.is_ascii_alphanumeric()) {
+            return Err(CarneExtranjeriaError::CharacterNotValid(invalid));
+        }
+        if !(MIN_LENGTH..=MAX_LENGTH).contains(&value.len()) {
+            return Err(CarneExtranjeriaError::LengthIsIncorrect(
+                value.len(),
+                MIN_LENGTH,
+                MAX_LENGTH,
+            ));
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Returns the card number in uppercase.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for CarneExtranjeria {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl FromStr for CarneExtranjeria {
+    type Err = CarneExtranjeriaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_carne_new_with_current_nine_digits() {
+        let carne = CarneExtranjeria::new("001234567".to_string()).unwrap();
+        assert_eq!(carne.value(), "001234567");
+    }
+
+    #[test]
+    fn test_carne_new_trims_and_uppercases() {
+        let carne = CarneExtranjeria::new(" n00123456 ".to_string()).unwrap();
+        assert_eq!(carne.value(), "N00123456");
+    }
+
+    #[test]
+    fn test_carne_accepts_twelve_characters() {
+        assert!(CarneExtranjeria::new("000001234567".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_carne_too_short() {
+        let result = CarneExtranjeria::new("12345678".to_string());
+        assert_eq!(result, Err(CarneExtranjeriaError::LengthIsIncorrect(8, 9, 12)));
+    }
+
+    #[test]
+    fn test_carne_too_long() {
+        let result = CarneExtranjeria::new("0000012345678".to_string());
+        assert_eq!(result, Err(CarneExtranjeriaError::LengthIsIncorrect(13, 9, 12)));
+    }
+
+    #[test]
+    fn test_carne_invalid_character() {
+        let result = CarneExtranjeria::new("00123-4567".to_string());
+        assert_eq!(result, Err(CarneExtranjeriaError::CharacterNotValid('-')));
+    }
+
+    #[test]
+    fn test_carne_is_not_a_dni() {
+        assert!(CarneExtranjeria::new("12345678-1".to_string()).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::ValidatorError;
+use regex::Regex;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use thiserror::Error;
+
+/// Error types for Colombian cédula validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CedulaError {
+    #[error("Validation error: {0}")]
+    ValidationError(#[from] ValidatorError),
+
+    #[error("Incorrect cédula format (expected: 6 to 10 digits, optionally dotted)")]
+    IncorrectFormat,
+
+    #[error("Cédula cannot start with zero")]
+    LeadingZero,
+}
+
+static CEDULA_REGEX: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r"^(\d{1,3}(\.\d{3}){1,3}|\d{6,10})$"));
+
+const MIN_DIGITS: usize = 6;
+const MAX_DIGITS: usize = 10;
+
+/// Colombian cédula de ciudadanía as a Value Object.
+///
+/// The Registraduría issues cédulas as plain sequential numbers without a
+/// check digit, so validation is limited to the format: 6 to 10 digits with no
+/// leading zero. Older cédulas are shorter than the 10-digit numbers issued
+/// since 2003.
+///
+/// Accepts the number with or without thousands separators; displays it dotted.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Cedula;
+///
+/// let cedula = Cedula::new("1.023.456.789".to_string()).unwrap();
+/// assert_eq!(cedula.value(), "1023456789");
+///
+/// let cedula: Cedula = "79456123".parse().unwrap();
+/// assert_eq!(cedula.to_string(), "79.456.123");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cedula {
+    value: String,
+}
+
+impl Cedula {
+    /// Creates a new cédula Value Object with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CedulaError::IncorrectFormat` if the input isn't 6 to 10
+    /// digits, bare or grouped in threes with dots.
+    /// Returns `CedulaError::LeadingZero` if the number starts with zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Cedula;
+    ///
+    /// assert!(Cedula::new("79456123".to_string()).is_ok());
+    /// assert!(Cedula::new("12345".to_string()).is_err());
+    /// assert!(Cedula::new("0123456789".to_string()).is_err());
+    /// ```
+    pub fn new(cedula: String) -> Result<Self, CedulaError> {
+        let trimmed = cedula.trim();
+        Self::validate_format(trimmed)?;
+
+        let value: String = trimmed.chars().filter(char::is_ascii_digit).collect();
+        if !(MIN_DIGITS..=MAX_DIGITS).contains(&value.len()) {
+            return Err(CedulaError::IncorrectFormat);
+        }
+        if value.starts_with('0') {
+            return Err(CedulaError::LeadingZero);
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Returns the digits without separators.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    fn validate_format(cedula: &str) -> Result<(), CedulaError> {
+        let regex = CEDULA_REGEX
+            .as_ref()
+            .map_err(|e| ValidatorError::RegexError(e.to_string()))?;
+
+        if !regex.is_match(cedula) {
+            return Err(CedulaError::IncorrectFormat);
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Cedula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let head = match self.value.len() % 3 {
+            0 => 3,
+            rest => rest,
+        };
+        f.write_str(&self.value[..head])?;
+        for start in (head..self.value.len()).step_by(3) {
+            write!(f, ".{}", &self.value[start..start + 3])?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Cedula {
+    type Err = CedulaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cedula_new_with_bare_digits() {
+        let cedula = Cedula::new("1023456789".to_string()).unwrap();
+        assert_eq!(cedula.value(), "1023456789");
+    }
+
+    #[test]
+    fn test_cedula_new_with_dots() {
+        let cedula = Cedula::new("1.023.456.789".to_string()).unwrap();
+        assert_eq!(cedula.value(), "1023456789");
+    }
+
+    #[test]
+    fn test_cedula_accepts_short_legacy_numbers() {
+        let cedula = Cedula::new("345.678".to_string()).unwrap();
+        assert_eq!(cedula.value(), "345678");
+    }
+
+    #[test]
+    fn test_cedula_display_groups_thousands() {
+        let cases = [
+            ("345678", "345.678"),
+            ("7945612", "7.945.612"),
+            ("79456123", "79.456.123"),
+            ("1023456789", "1.023.456.789"),
+        ];
+        for (input, expected) in cases {
+            let cedula: Cedula = input.parse().unwrap();
+            assert_eq!(cedula.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_cedula_forms_are_equal() {
+        let dotted: Cedula = "79.456.123".parse().unwrap();
+        let bare: Cedula = "79456123".parse().unwrap();
+        assert_eq!(dotted, bare);
+    }
+
+    #[test]
+    fn test_cedula_leading_zero() {
+        let result = Cedula::new("0123456".to_string());
+        assert_eq!(result, Err(CedulaError::LeadingZero));
+    }
+
+    #[test]
+    fn test_cedula_incorrect_format() {
+        for cedula in [
+            "",
+            "12345",
+            "12345678901",
+            "1.234",
+            "12.34.567",
+            "79456123A",
+        ] {
+            let result = Cedula::new(cedula.to_string());
+            assert_eq!(result, Err(CedulaError::IncorrectFormat), "input {:?}", cedula);
+        }
+    }
+}
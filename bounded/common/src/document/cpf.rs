@@ -0,0 +1,229 @@
+use crate::ValidatorError;
+use regex::Regex;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use thiserror::Error;
+
+/// Error types for CPF validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CpfError {
+    #[error("Validation error: {0}")]
+    ValidationError(#[from] ValidatorError),
+
+    #[error("Incorrect CPF format (expected: XXX.XXX.XXX-YY or 11 digits)")]
+    IncorrectFormat,
+
+    #[error("CPF cannot repeat a single digit")]
+    RepeatedDigits,
+
+    #[error("Incorrect check digits: {0}")]
+    IncorrectCheckDigits(String),
+}
+
+const CPF_SIZE: usize = 11;
+const BASE_SIZE: usize = 9;
+
+static CPF_REGEX: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r"^(\d{3}\.\d{3}\.\d{3}-\d{2}|\d{11})$"));
+
+/// Brazilian CPF (Cadastro de Pessoas Físicas) as a Value Object.
+///
+/// A CPF has 9 base digits followed by 2 check digits, each computed with a
+/// weighted modulo 11 sum over the digits before it. Numbers made of a single
+/// repeated digit pass the checksum but are never issued, so they are rejected.
+///
+/// Accepts both the punctuated form `XXX.XXX.XXX-YY` and the bare 11 digits;
+/// displays the punctuated form.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Cpf;
+///
+/// let cpf = Cpf::new("529.982.247-25".to_string()).unwrap();
+/// assert_eq!(cpf.value(), "52998224725");
+/// assert_eq!(cpf.check_digits(), "25");
+///
+/// let cpf: Cpf = "52998224725".parse().unwrap();
+/// assert_eq!(cpf.to_string(), "529.982.247-25");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cpf {
+    value: String,
+}
+
+impl Cpf {
+    /// Creates a new CPF Value Object with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CpfError::IncorrectFormat` if the input is neither punctuated
+    /// nor 11 bare digits.
+    /// Returns `CpfError::RepeatedDigits` if every digit is the same.
+    /// Returns `CpfError::IncorrectCheckDigits` if the check digits don't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Cpf;
+    ///
+    /// assert!(Cpf::new("529.982.247-25".to_string()).is_ok());
+    /// assert!(Cpf::new("529.982.247-26".to_string()).is_err());
+    /// assert!(Cpf::new("111.111.111-11".to_string()).is_err());
+    /// ```
+    pub fn new(cpf: String) -> Result<Self, CpfError> {
+        let trimmed = cpf.trim();
+        Self::validate_format(trimmed)?;
+
+        let digits: Vec<u32> = trimmed.chars().filter_map(|c| c.to_digit(10)).collect();
+        if digits.iter().all(|&d| d == digits[0]) {
+            return Err(CpfError::RepeatedDigits);
+        }
+
+        let first = Self::check_digit(&digits[..BASE_SIZE]);
+        let second = Self::check_digit(&digits[..=BASE_SIZE]);
+        if digits[BASE_SIZE] != first || digits[BASE_SIZE + 1] != second {
+            return Err(CpfError::IncorrectCheckDigits(format!(
+                "{}{}",
+                digits[BASE_SIZE],
+                digits[BASE_SIZE + 1]
+            )));
+        }
+
+        Ok(Self {
+            value: trimmed.chars().filter(char::is_ascii_digit).collect(),
+        })
+    }
+
+    /// Returns the 11 digits without punctuation, check digits included.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the two check digits.
+    #[inline]
+    #[must_use]
+    pub fn check_digits(&self) -> &str {
+        &self.value[BASE_SIZE..]
+    }
+
+    fn validate_format(cpf: &str) -> Result<(), CpfError> {
+        let regex = CPF_REGEX
+            .as_ref()
+            .map_err(|e| ValidatorError::RegexError(e.to_string()))?;
+
+        if !regex.is_match(cpf) {
+            return Err(CpfError::IncorrectFormat);
+        }
+
+        Ok(())
+    }
+
+    /// Weights run down from `digits.len() + 1` to 2; remainders under 2 give 0.
+    fn check_digit(digits: &[u32]) -> u32 {
+        let sum: usize = digits
+            .iter()
+            .enumerate()
+            .map(|(position, &digit)| digit as usize * (digits.len() + 1 - position))
+            .sum();
+
+        match sum % CPF_SIZE {
+            0 | 1 => 0,
+            remainder => (CPF_SIZE - remainder) as u32,
+        }
+    }
+}
+
+impl fmt::Display for Cpf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let v = &self.value;
+        write!(f, "{}.{}.{}-{}", &v[..3], &v[3..6], &v[6..9], &v[9..])
+    }
+}
+
+impl FromStr for Cpf {
+    type Err = CpfError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpf_new_with_punctuated_form() {
+        let cpf = Cpf::new("529.982.247-25".to_string()).unwrap();
+        assert_eq!(cpf.value(), "52998224725");
+    }
+
+    #[test]
+    fn test_cpf_new_with_bare_digits() {
+        let cpf = Cpf::new("52998224725".to_string()).unwrap();
+        assert_eq!(cpf.check_digits(), "25");
+    }
+
+    #[test]
+    fn test_cpf_new_trims_whitespace() {
+        assert!(Cpf::new("  529.982.247-25  ".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_cpf_known_valid_examples() {
+        for cpf in ["111.444.777-35", "390.533.447-05", "000.000.001-91"] {
+            let result = Cpf::new(cpf.to_string());
+            assert!(result.is_ok(), "CPF {} should be valid: {:?}", cpf, result.err());
+        }
+    }
+
+    #[test]
+    fn test_cpf_display_is_punctuated() {
+        let cpf = Cpf::new("52998224725".to_string()).unwrap();
+        assert_eq!(cpf.to_string(), "529.982.247-25");
+    }
+
+    #[test]
+    fn test_cpf_forms_are_equal() {
+        let punctuated: Cpf = "529.982.247-25".parse().unwrap();
+        let bare: Cpf = "52998224725".parse().unwrap();
+        assert_eq!(punctuated, bare);
+    }
+
+    #[test]
+    fn test_cpf_wrong_first_check_digit() {
+        let result = Cpf::new("529.982.247-35".to_string());
+        assert_eq!(result, Err(CpfError::IncorrectCheckDigits("35".to_string())));
+    }
+
+    #[test]
+    fn test_cpf_wrong_second_check_digit() {
+        let result = Cpf::new("529.982.247-26".to_string());
+        assert!(matches!(result, Err(CpfError::IncorrectCheckDigits(_))));
+    }
+
+    #[test]
+    fn test_cpf_repeated_digits() {
+        let result = Cpf::new("111.111.111-11".to_string());
+        assert_eq!(result, Err(CpfError::RepeatedDigits));
+    }
+
+    #[test]
+    fn test_cpf_incorrect_format() {
+        for cpf in [
+            "",
+            "5299822472",
+            "529982247255",
+            "529.982.24725",
+            "529-982-247-25",
+        ] {
+            let result = Cpf::new(cpf.to_string());
+            assert_eq!(result, Err(CpfError::IncorrectFormat), "input {:?}", cpf);
+        }
+    }
+}
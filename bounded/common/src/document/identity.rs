@@ -0,0 +1,388 @@
+use super::{
+    CarneExtranjeria, CarneExtranjeriaError, Cedula, CedulaError, Cpf, CpfError, Dni, DniError,
+    Passport, PassportError, Rut, RutError,
+};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for identity document validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DocumentError {
+    #[error("DNI not valid: {0}")]
+    Dni(#[from] DniError),
+
+    #[error("Carné de Extranjería not valid: {0}")]
+    CarneExtranjeria(#[from] CarneExtranjeriaError),
+
+    #[error("CPF not valid: {0}")]
+    Cpf(#[from] CpfError),
+
+    #[error("Cédula not valid: {0}")]
+    Cedula(#[from] CedulaError),
+
+    #[error("RUT not valid: {0}")]
+    Rut(#[from] RutError),
+
+    #[error("Passport not valid: {0}")]
+    Passport(#[from] PassportError),
+}
+
+/// Broad reason a document number was rejected.
+///
+/// A mistyped character usually still has the right shape but fails the
+/// check digit, so telling the two apart lets a form point the user at a
+/// typo rather than at the expected layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentFailure {
+    /// The number doesn't have the layout its kind requires.
+    Format,
+    /// The number is well formed, but its check digit doesn't match.
+    CheckDigit,
+}
+
+impl DocumentError {
+    /// Classifies the error as a format or a check-digit failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Document, DocumentFailure, DocumentKind};
+    ///
+    /// let typo = Document::parse(DocumentKind::Dni, "12345678-9").unwrap_err();
+    /// assert_eq!(typo.failure(), DocumentFailure::CheckDigit);
+    ///
+    /// let short = Document::parse(DocumentKind::Dni, "1234567-1").unwrap_err();
+    /// assert_eq!(short.failure(), DocumentFailure::Format);
+    /// ```
+    #[must_use]
+    pub const fn failure(&self) -> DocumentFailure {
+        match self {
+            Self::Dni(DniError::IncorrectValidationDigit(_))
+            | Self::Cpf(CpfError::IncorrectCheckDigits(_))
+            | Self::Rut(RutError::IncorrectVerificationChar(_)) => DocumentFailure::CheckDigit,
+            _ => DocumentFailure::Format,
+        }
+    }
+}
+
+/// Which kind of identity document a [`Document`] holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum DocumentKind {
+    /// Peruvian Documento Nacional de Identidad.
+    Dni,
+    /// Peruvian Carné de Extranjería, held by foreign residents.
+    CarneExtranjeria,
+    /// Brazilian Cadastro de Pessoas Físicas.
+    Cpf,
+    /// Colombian cédula de ciudadanía.
+    Cedula,
+    /// Chilean Rol Único Tributario.
+    Rut,
+    /// Passport from any country.
+    Passport,
+}
+
+impl DocumentKind {
+    /// Every kind, in declaration order.
+    pub const ALL: [Self; 6] = [
+        Self::Dni,
+        Self::CarneExtranjeria,
+        Self::Cpf,
+        Self::Cedula,
+        Self::Rut,
+        Self::Passport,
+    ];
+}
+
+impl fmt::Display for DocumentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Dni => "DNI",
+            Self::CarneExtranjeria => "Carné de Extranjería",
+            Self::Cpf => "CPF",
+            Self::Cedula => "Cédula",
+            Self::Rut => "RUT",
+            Self::Passport => "Passport",
+        })
+    }
+}
+
+/// An identity document from one of the supported countries.
+///
+/// Each variant wraps a Value Object that applies its own country's rules, so
+/// a `Document` is always valid for its kind. Use [`Document::parse`] when the
+/// kind is chosen separately from the number, such as in a form.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Document, DocumentKind};
+///
+/// let document = Document::parse(DocumentKind::Cpf, "529.982.247-25").unwrap();
+/// assert_eq!(document.kind(), DocumentKind::Cpf);
+/// assert_eq!(document.to_string(), "529.982.247-25");
+///
+/// assert!(Document::parse(DocumentKind::Rut, "529.982.247-25").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Document {
+    Dni(Dni),
+    CarneExtranjeria(CarneExtranjeria),
+    Cpf(Cpf),
+    Cedula(Cedula),
+    Rut(Rut),
+    Passport(Passport),
+}
+
+impl Document {
+    /// Validates `value` as a document of the given kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `DocumentError` variant for `kind`, wrapping the reason the
+    /// value was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Document, DocumentError, DocumentKind};
+    ///
+    /// let rut = Document::parse(DocumentKind::Rut, "12.345.678-5").unwrap();
+    /// assert_eq!(rut.kind(), DocumentKind::Rut);
+    ///
+    /// let result = Document::parse(DocumentKind::Dni, "12.345.678-5");
+    /// assert!(matches!(result, Err(DocumentError::Dni(_))));
+    /// ```
+    pub fn parse(kind: DocumentKind, value: &str) -> Result<Self, DocumentError> {
+        let value = value.to_string();
+        Ok(match kind {
+            DocumentKind::Dni => Self::Dni(Dni::new(value)?),
+            DocumentKind::CarneExtranjeria => Self::CarneExtranjeria(CarneExtranjeria::new(value)?),
+            DocumentKind::Cpf => Self::Cpf(Cpf::new(value)?),
+            DocumentKind::Cedula => Self::Cedula(Cedula::new(value)?),
+            DocumentKind::Rut => Self::Rut(Rut::new(value)?),
+            DocumentKind::Passport => Self::Passport(Passport::new(value)?),
+        })
+    }
+
+    /// Returns which kind of document this is.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> DocumentKind {
+        match self {
+            Self::Dni(_) => DocumentKind::Dni,
+            Self::CarneExtranjeria(_) => DocumentKind::CarneExtranjeria,
+            Self::Cpf(_) => DocumentKind::Cpf,
+            Self::Cedula(_) => DocumentKind::Cedula,
+            Self::Rut(_) => DocumentKind::Rut,
+            Self::Passport(_) => DocumentKind::Passport,
+        }
+    }
+
+    /// Returns the Peruvian DNI, if this is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Document, DocumentKind};
+    ///
+    /// let document = Document::parse(DocumentKind::Dni, "12345678-1").unwrap();
+    /// assert_eq!(document.as_dni().unwrap().value(), "12345678");
+    ///
+    /// let passport = Document::parse(DocumentKind::Passport, "AB123456").unwrap();
+    /// assert!(passport.as_dni().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_dni(&self) -> Option<&Dni> {
+        match self {
+            Self::Dni(dni) => Some(dni),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dni(dni) => dni.fmt(f),
+            Self::CarneExtranjeria(carne) => carne.fmt(f),
+            Self::Cpf(cpf) => cpf.fmt(f),
+            Self::Cedula(cedula) => cedula.fmt(f),
+            Self::Rut(rut) => rut.fmt(f),
+            Self::Passport(passport) => passport.fmt(f),
+        }
+    }
+}
+
+impl From<Dni> for Document {
+    fn from(dni: Dni) -> Self {
+        Self::Dni(dni)
+    }
+}
+
+impl From<CarneExtranjeria> for Document {
+    fn from(carne: CarneExtranjeria) -> Self {
+        Self::CarneExtranjeria(carne)
+    }
+}
+
+impl From<Cpf> for Document {
+    fn from(cpf: Cpf) -> Self {
+        Self::Cpf(cpf)
+    }
+}
+
+impl From<Cedula> for Document {
+    fn from(cedula: Cedula) -> Self {
+        Self::Cedula(cedula)
+    }
+}
+
+impl From<Rut> for Document {
+    fn from(rut: Rut) -> Self {
+        Self::Rut(rut)
+    }
+}
+
+impl From<Passport> for Document {
+    fn from(passport: Passport) -> Self {
+        Self::Passport(passport)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_parse_each_kind() {
+            let cases = [
+                (DocumentKind::Dni, "12345678-1", "12345678-1"),
+                (DocumentKind::CarneExtranjeria, "001234567", "001234567"),
+                (DocumentKind::Cpf, "52998224725", "529.982.247-25"),
+                (DocumentKind::Cedula, "1023456789", "1.023.456.789"),
+                (DocumentKind::Rut, "12345678-5", "12.345.678-5"),
+                (DocumentKind::Passport, "fa1234567", "FA1234567"),
+            ];
+            for (kind, input, displayed) in cases {
+                let document = Document::parse(kind, input).unwrap();
+                assert_eq!(document.kind(), kind);
+                assert_eq!(document.to_string(), displayed);
+            }
+        }
+
+        #[test]
+        fn test_parse_reports_error_for_requested_kind() {
+            assert!(matches!(
+                Document::parse(DocumentKind::Dni, "bad"),
+                Err(DocumentError::Dni(_))
+            ));
+            assert!(matches!(
+                Document::parse(DocumentKind::CarneExtranjeria, "bad"),
+                Err(DocumentError::CarneExtranjeria(_))
+            ));
+            assert!(matches!(
+                Document::parse(DocumentKind::Cpf, "bad"),
+                Err(DocumentError::Cpf(_))
+            ));
+            assert!(matches!(
+                Document::parse(DocumentKind::Cedula, "bad"),
+                Err(DocumentError::Cedula(_))
+            ));
+            assert!(matches!(
+                Document::parse(DocumentKind::Rut, "bad"),
+                Err(DocumentError::Rut(_))
+            ));
+            assert!(matches!(
+                Document::parse(DocumentKind::Passport, "bad"),
+                Err(DocumentError::Passport(_))
+            ));
+        }
+
+        #[test]
+        fn test_same_number_can_be_valid_for_one_kind_only() {
+            assert!(Document::parse(DocumentKind::Cedula, "12345678").is_ok());
+            assert!(Document::parse(DocumentKind::Dni, "12345678").is_err());
+        }
+    }
+
+    mod conversions {
+        use super::*;
+
+        #[test]
+        fn test_from_value_object_sets_kind() {
+            let rut = Rut::new("12345678-5".to_string()).unwrap();
+            assert_eq!(Document::from(rut).kind(), DocumentKind::Rut);
+        }
+
+        #[test]
+        fn test_as_dni() {
+            let dni = Dni::new("12345678-1".to_string()).unwrap();
+            let document = Document::from(dni.clone());
+            assert_eq!(document.as_dni(), Some(&dni));
+
+            let cpf = Document::parse(DocumentKind::Cpf, "52998224725").unwrap();
+            assert_eq!(cpf.as_dni(), None);
+        }
+
+        #[test]
+        fn test_kind_display() {
+            let labels: Vec<String> = DocumentKind::ALL.iter().map(ToString::to_string).collect();
+            assert_eq!(
+                labels,
+                [
+                    "DNI",
+                    "Carné de Extranjería",
+                    "CPF",
+                    "Cédula",
+                    "RUT",
+                    "Passport"
+                ]
+            );
+        }
+    }
+
+    mod failure {
+        use super::*;
+
+        fn failure_of(kind: DocumentKind, value: &str) -> DocumentFailure {
+            Document::parse(kind, value).unwrap_err().failure()
+        }
+
+        #[test]
+        fn test_wrong_check_digit_is_check_digit_failure() {
+            let cases = [
+                (DocumentKind::Dni, "12345678-9"),
+                (DocumentKind::Cpf, "529.982.247-26"),
+                (DocumentKind::Rut, "12.345.678-K"),
+            ];
+            for (kind, value) in cases {
+                assert_eq!(failure_of(kind, value), DocumentFailure::CheckDigit, "{}", value);
+            }
+        }
+
+        #[test]
+        fn test_bad_layout_is_format_failure() {
+            let cases = [
+                (DocumentKind::Dni, "12345678"),
+                (DocumentKind::Dni, "12345678-Z"),
+                (DocumentKind::CarneExtranjeria, "1234"),
+                (DocumentKind::Cpf, "111.111.111-11"),
+                (DocumentKind::Cedula, "0123456"),
+                (DocumentKind::Rut, "12345678"),
+                (DocumentKind::Passport, "AB-12345"),
+            ];
+            for (kind, value) in cases {
+                assert_eq!(failure_of(kind, value), DocumentFailure::Format, "{}", value);
+            }
+        }
+    }
+}
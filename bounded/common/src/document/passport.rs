@@ -0,0 +1,137 @@
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error types for passport number validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PassportError {
+    #[error("Invalid length: {0}, should be between {1} and {2} characters")]
+    LengthIsIncorrect(usize, usize, usize),
+
+    #[error("Invalid character: {0}")]
+    CharacterNotValid(char),
+}
+
+const MIN_LENGTH: usize = 6;
+/// Width of the document number field in the machine-readable zone (ICAO 9303).
+const MAX_LENGTH: usize = 9;
+
+/// Passport number as a Value Object.
+///
+/// Each country numbers its passports its own way, so validation follows
+/// the machine-readable zone instead: 6 to 9 letters and digits. Letters are
+/// stored in uppercase, as printed in the zone.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Passport;
+///
+/// let passport = Passport::new("fa1234567".to_string()).unwrap();
+/// assert_eq!(passport.value(), "FA1234567");
+/// assert_eq!(passport.to_string(), "FA1234567");
+///
+/// assert!(Passport::new("FA-12345".to_string()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Passport {
+    value: String,
+}
+
+impl Passport {
+    /// Creates a new passport number Value Object with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PassportError::LengthIsIncorrect` if the number has fewer than
+    /// 6 or more than 9 characters.
+    /// Returns `PassportError::CharacterNotValid` for anything other than ASCII
+    /// letters and digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Passport;
+    ///
+    /// assert!(Passport::new("AB123456".to_string()).is_ok());
+    /// assert!(Passport::new("AB12".to_string()).is_err());
+    /// ```
+    pub fn new(passport: String) -> Result<Self, PassportError> {
+        let value = passport.trim().to_ascii_uppercase();
+
+        if let Some(invalid) = value.chars().find(|c| !c.is_ascii_alphanumeric()) {
+            return Err(PassportError::CharacterNotValid(invalid));
+        }
+        if !(MIN_LENGTH..=MAX_LENGTH).contains(&value.len()) {
+            return Err(PassportError::LengthIsIncorrect(value.len(), MIN_LENGTH, MAX_LENGTH));
+        }
+
+        Ok(Self { value })
+    }
+
+    /// Returns the passport number in uppercase.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for Passport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl FromStr for Passport {
+    type Err = PassportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passport_new_uppercases() {
+        let passport = Passport::new("  ab123456 ".to_string()).unwrap();
+        assert_eq!(passport.value(), "AB123456");
+    }
+
+    #[test]
+    fn test_passport_accepts_boundary_lengths() {
+        assert!(Passport::new("123456".to_string()).is_ok());
+        assert!(Passport::new("A12345678".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_passport_too_short() {
+        let result = Passport::new("A1234".to_string());
+        assert_eq!(result, Err(PassportError::LengthIsIncorrect(5, 6, 9)));
+    }
+
+    #[test]
+    fn test_passport_too_long() {
+        let result = Passport::new("A123456789".to_string());
+        assert_eq!(result, Err(PassportError::LengthIsIncorrect(10, 6, 9)));
+    }
+
+    #[test]
+    fn test_passport_invalid_character() {
+        let result = Passport::new("AB 123456".to_string());
+        assert_eq!(result, Err(PassportError::CharacterNotValid(' ')));
+
+        let result = Passport::new("ÑA123456".to_string());
+        assert_eq!(result, Err(PassportError::CharacterNotValid('Ñ')));
+    }
+
+    #[test]
+    fn test_passport_from_str() {
+        let passport: Passport = "fa1234567".parse().unwrap();
+        assert_eq!(passport.to_string(), "FA1234567");
+    }
+}
@@ -0,0 +1,231 @@
+use crate::ValidatorError;
+use regex::Regex;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use thiserror::Error;
+
+/// Error types for RUT validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RutError {
+    #[error("Validation error: {0}")]
+    ValidationError(#[from] ValidatorError),
+
+    #[error("Incorrect RUT format (expected: XX.XXX.XXX-Y where Y is 0-9 or K)")]
+    IncorrectFormat,
+
+    #[error("Incorrect verification character: {0}")]
+    IncorrectVerificationChar(String),
+}
+
+const BASE: u32 = 11;
+const MAX_WEIGHT: u32 = 7;
+
+static RUT_REGEX: LazyLock<Result<Regex, regex::Error>> =
+    LazyLock::new(|| Regex::new(r"^(\d{1,2}\.\d{3}\.\d{3}|\d{7,8})-[0-9K]$"));
+
+/// Chilean RUT (Rol Único Tributario) as a Value Object.
+///
+/// A RUT is a 7 or 8 digit body followed by a verification character. The
+/// character comes from the "módulo 11" algorithm: the body digits, read from
+/// the right, are weighted 2 through 7 in a repeating cycle; a result of 10 is
+/// written `K` and 11 is written `0`.
+///
+/// Accepts the body with or without thousands separators and a lowercase `k`;
+/// displays the dotted form.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Rut;
+///
+/// let rut = Rut::new("12.345.678-5".to_string()).unwrap();
+/// assert_eq!(rut.body(), "12345678");
+/// assert_eq!(rut.verification_char(), '5');
+///
+/// let rut: Rut = "10000013-k".parse().unwrap();
+/// assert_eq!(rut.to_string(), "10.000.013-K");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rut {
+    body: String,
+    verification_char: char,
+}
+
+impl Rut {
+    /// Creates a new RUT Value Object with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RutError::IncorrectFormat` if the body isn't 7 or 8 digits
+    /// (optionally dotted) followed by a dash and a 0-9 or K character.
+    /// Returns `RutError::IncorrectVerificationChar` if the character doesn't
+    /// match the body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Rut;
+    ///
+    /// assert!(Rut::new("12345678-5".to_string()).is_ok());
+    /// assert!(Rut::new("12345678-4".to_string()).is_err());
+    /// assert!(Rut::new("12345678".to_string()).is_err());
+    /// ```
+    pub fn new(rut: String) -> Result<Self, RutError> {
+        let normalized = rut.trim().to_ascii_uppercase();
+        Self::validate_format(&normalized)?;
+
+        let (body, verification) = normalized
+            .split_once('-')
+            .ok_or(RutError::IncorrectFormat)?;
+        let body: String = body.chars().filter(char::is_ascii_digit).collect();
+        let verification_char = verification
+            .chars()
+            .next()
+            .ok_or(RutError::IncorrectFormat)?;
+
+        if Self::expected_verification_char(&body) != verification_char {
+            return Err(RutError::IncorrectVerificationChar(verification_char.to_string()));
+        }
+
+        Ok(Self {
+            body,
+            verification_char,
+        })
+    }
+
+    /// Returns the body digits without separators or verification character.
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the verification character, a digit or `K`.
+    #[inline]
+    #[must_use]
+    pub const fn verification_char(&self) -> char {
+        self.verification_char
+    }
+
+    fn validate_format(rut: &str) -> Result<(), RutError> {
+        let regex = RUT_REGEX
+            .as_ref()
+            .map_err(|e| ValidatorError::RegexError(e.to_string()))?;
+
+        if !regex.is_match(rut) {
+            return Err(RutError::IncorrectFormat);
+        }
+
+        Ok(())
+    }
+
+    fn expected_verification_char(body: &str) -> char {
+        let sum: u32 = body
+            .chars()
+            .rev()
+            .filter_map(|c| c.to_digit(10))
+            .zip((2..=MAX_WEIGHT).cycle())
+            .map(|(digit, weight)| digit * weight)
+            .sum();
+
+        match BASE - sum % BASE {
+            11 => '0',
+            10 => 'K',
+            digit => char::from_digit(digit, 10).unwrap_or('0'),
+        }
+    }
+}
+
+impl fmt::Display for Rut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (millions, rest) = self.body.split_at(self.body.len() - 6);
+        write!(
+            f,
+            "{}.{}.{}-{}",
+            millions,
+            &rest[..3],
+            &rest[3..],
+            self.verification_char
+        )
+    }
+}
+
+impl FromStr for Rut {
+    type Err = RutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rut_new_with_dotted_body() {
+        let rut = Rut::new("12.345.678-5".to_string()).unwrap();
+        assert_eq!(rut.body(), "12345678");
+        assert_eq!(rut.verification_char(), '5');
+    }
+
+    #[test]
+    fn test_rut_new_with_bare_body() {
+        let rut = Rut::new("12345678-5".to_string()).unwrap();
+        assert_eq!(rut.body(), "12345678");
+    }
+
+    #[test]
+    fn test_rut_new_with_seven_digit_body() {
+        let rut = Rut::new("1.000.005-K".to_string()).unwrap();
+        assert_eq!(rut.body(), "1000005");
+        assert_eq!(rut.to_string(), "1.000.005-K");
+    }
+
+    #[test]
+    fn test_rut_accepts_lowercase_k() {
+        let rut = Rut::new("10000013-k".to_string()).unwrap();
+        assert_eq!(rut.verification_char(), 'K');
+    }
+
+    #[test]
+    fn test_rut_zero_verification_char() {
+        assert!(Rut::new("10.000.021-0".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_rut_display_is_dotted() {
+        let rut: Rut = "12345678-5".parse().unwrap();
+        assert_eq!(rut.to_string(), "12.345.678-5");
+    }
+
+    #[test]
+    fn test_rut_forms_are_equal() {
+        let dotted: Rut = "12.345.678-5".parse().unwrap();
+        let bare: Rut = "12345678-5".parse().unwrap();
+        assert_eq!(dotted, bare);
+    }
+
+    #[test]
+    fn test_rut_incorrect_verification_char() {
+        let result = Rut::new("12.345.678-K".to_string());
+        assert_eq!(result, Err(RutError::IncorrectVerificationChar("K".to_string())));
+    }
+
+    #[test]
+    fn test_rut_incorrect_format() {
+        for rut in [
+            "",
+            "12345678",
+            "123456-0",
+            "123456789-0",
+            "12.345678-5",
+            "12345678-X",
+        ] {
+            let result = Rut::new(rut.to_string());
+            assert_eq!(result, Err(RutError::IncorrectFormat), "input {:?}", rut);
+        }
+    }
+}
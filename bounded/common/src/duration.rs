@@ -1,3 +1,6 @@
+use std::{fmt, iter::Sum, ops, str::FromStr, time};
+use thiserror::Error;
+
 /// A duration value object representing a time span in seconds.
 ///
 /// `Duration` is an immutable value object that represents a time duration.
@@ -17,6 +20,9 @@
 /// let d2 = Duration::from_seconds(30);
 /// let sum = d1.add(&d2);
 /// assert_eq!(sum.total_seconds(), 90);
+///
+/// let parsed: Duration = "1h 30m".parse().unwrap();
+/// assert_eq!(parsed, Duration::from_minutes(90));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Duration {
@@ -184,6 +190,71 @@ impl Duration {
         }
     }
 
+    /// Subtracts `other`, stopping at zero instead of going negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let watched = Duration::from_minutes(50);
+    /// let remaining = Duration::from_hours(1).saturating_sub(&watched);
+    /// assert_eq!(remaining.total_seconds(), 600);
+    ///
+    /// let overrun = Duration::from_minutes(5).saturating_sub(&watched);
+    /// assert!(overrun.is_zero());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub(&self, other: &Self) -> Self {
+        Self {
+            seconds: self.seconds.saturating_sub(other.seconds),
+        }
+    }
+
+    /// Multiplies the duration by `factor`, stopping at the largest
+    /// representable duration instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let session = Duration::from_minutes(45);
+    /// assert_eq!(session.saturating_mul(4), Duration::from_hours(3));
+    ///
+    /// let huge = Duration::from_seconds(u64::MAX).saturating_mul(2);
+    /// assert_eq!(huge.total_seconds(), u64::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_mul(&self, factor: u64) -> Self {
+        Self {
+            seconds: self.seconds.saturating_mul(factor),
+        }
+    }
+
+    /// Multiplies the duration by `factor`, returning `None` if the result
+    /// does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let session = Duration::from_minutes(45);
+    /// assert_eq!(session.checked_mul(4), Some(Duration::from_hours(3)));
+    /// assert_eq!(Duration::from_seconds(u64::MAX).checked_mul(2), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_mul(&self, factor: u64) -> Option<Self> {
+        match self.seconds.checked_mul(factor) {
+            Some(seconds) => Some(Self { seconds }),
+            None => None,
+        }
+    }
+
     /// Formats the duration as "HHh MMm" (hours and minutes only).
     ///
     /// # Examples
@@ -277,6 +348,271 @@ impl Default for Duration {
     }
 }
 
+impl fmt::Display for Duration {
+    /// Writes the duration as [`Duration::format_hours`] does, a form
+    /// [`FromStr`] reads back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let duration = Duration::from_hms(1, 30, 15);
+    /// assert_eq!(duration.to_string(), "01h 30m 15s");
+    /// assert_eq!(duration.to_string().parse::<Duration>().unwrap(), duration);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_hours())
+    }
+}
+
+impl From<u64> for Duration {
+    /// Reads a whole number of seconds, as [`Duration::from_seconds`] does.
+    #[inline]
+    fn from(seconds: u64) -> Self {
+        Self::from_seconds(seconds)
+    }
+}
+
+impl From<time::Duration> for Duration {
+    /// Converts a standard library duration, dropping any fraction of a second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let elapsed = std::time::Duration::from_millis(90_750);
+    /// assert_eq!(Duration::from(elapsed).total_seconds(), 90);
+    /// ```
+    #[inline]
+    fn from(duration: time::Duration) -> Self {
+        Self::from_seconds(duration.as_secs())
+    }
+}
+
+impl From<Duration> for time::Duration {
+    /// Converts to a standard library duration without loss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let std_duration = std::time::Duration::from(Duration::from_minutes(2));
+    /// assert_eq!(std_duration.as_secs(), 120);
+    /// ```
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Self::from_secs(duration.seconds)
+    }
+}
+
+impl From<chrono::Duration> for Duration {
+    /// Converts a chrono duration, dropping any fraction of a second.
+    ///
+    /// A `Duration` cannot be negative, so negative spans become zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let span = chrono::Duration::minutes(90);
+    /// assert_eq!(Duration::from(span), Duration::from_minutes(90));
+    ///
+    /// let backwards = chrono::Duration::minutes(-5);
+    /// assert!(Duration::from(backwards).is_zero());
+    /// ```
+    #[inline]
+    fn from(duration: chrono::Duration) -> Self {
+        Self::from_seconds(u64::try_from(duration.num_seconds()).unwrap_or(0))
+    }
+}
+
+impl ops::Add for Duration {
+    type Output = Self;
+
+    /// Adds two durations, as [`Duration::add`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let total = Duration::from_minutes(5) + Duration::from_seconds(30);
+    /// assert_eq!(total.total_seconds(), 330);
+    /// ```
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Duration::add(&self, &other)
+    }
+}
+
+impl ops::Mul<u64> for Duration {
+    type Output = Self;
+
+    /// Multiplies the duration by a whole factor, saturating as
+    /// [`Duration::saturating_mul`] does; use [`Duration::checked_mul`] to
+    /// detect overflow instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let weekly = Duration::from_minutes(90) * 4;
+    /// assert_eq!(weekly, Duration::from_hours(6));
+    ///
+    /// let huge = Duration::from_seconds(u64::MAX) * 2;
+    /// assert_eq!(huge.total_seconds(), u64::MAX);
+    /// ```
+    #[inline]
+    fn mul(self, factor: u64) -> Self {
+        self.saturating_mul(factor)
+    }
+}
+
+impl Sum for Duration {
+    /// Adds up durations, starting from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let lessons = [Duration::from_minutes(20), Duration::from_minutes(25)];
+    /// let total: Duration = lessons.into_iter().sum();
+    /// assert_eq!(total, Duration::from_minutes(45));
+    ///
+    /// let none: Duration = std::iter::empty::<Duration>().sum();
+    /// assert!(none.is_zero());
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |total, duration| total.add(&duration))
+    }
+}
+
+impl<'a> Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl FromStr for Duration {
+    type Err = DurationError;
+
+    /// Parses a duration from either unit form or clock form.
+    ///
+    /// - Unit form: numbers followed by `h`, `m` or `s`, in that order and
+    ///   each at most once, as in `"1h 30m 15s"`, `"45m"` or `"2h5s"`.
+    ///   The output of [`Duration::format_hours`] parses back.
+    /// - Clock form: `"HH:MM:SS"`, with minutes and seconds below 60 and
+    ///   hours of any length.
+    ///
+    /// Surrounding whitespace is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DurationError::ParseError` if the string is empty, matches
+    /// neither form or does not fit in a `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Duration;
+    ///
+    /// let units: Duration = "1h 30m 15s".parse().unwrap();
+    /// let clock: Duration = "01:30:15".parse().unwrap();
+    /// assert_eq!(units, clock);
+    /// assert_eq!(units.total_seconds(), 5415);
+    ///
+    /// assert!("90 minutes".parse::<Duration>().is_err());
+    /// assert!("01:75:00".parse::<Duration>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let seconds = match trimmed {
+            "" => Err("the value is empty"),
+            clock if clock.contains(':') => parse_clock(clock),
+            units => parse_units(units),
+        };
+        seconds
+            .map(Self::from_seconds)
+            .map_err(|reason| DurationError::ParseError {
+                value: s.to_string(),
+                reason: reason.to_string(),
+            })
+    }
+}
+
+const TOO_LARGE: &str = "the duration is too large";
+
+/// Parses `"HH:MM:SS"` into seconds.
+fn parse_clock(value: &str) -> Result<u64, &'static str> {
+    let parts: Vec<&str> = value.split(':').collect();
+    let [hours, minutes, seconds] = parts.as_slice() else {
+        return Err("expected HH:MM:SS");
+    };
+    let number = |part: &str| {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err("expected HH:MM:SS");
+        }
+        part.parse::<u64>().map_err(|_| TOO_LARGE)
+    };
+    let (hours, minutes, seconds) = (number(hours)?, number(minutes)?, number(seconds)?);
+    if minutes >= SECONDS_PER_MINUTE || seconds >= SECONDS_PER_MINUTE {
+        return Err("minutes and seconds must be below 60");
+    }
+
+    hours
+        .checked_mul(SECONDS_PER_HOUR)
+        .and_then(|total| total.checked_add(minutes * SECONDS_PER_MINUTE + seconds))
+        .ok_or(TOO_LARGE)
+}
+
+/// Parses `"1h 30m 15s"` and its shorter forms into seconds.
+fn parse_units(value: &str) -> Result<u64, &'static str> {
+    let mut rest = value;
+    let mut total: u64 = 0;
+    // Units still allowed: each may appear once, larger ones first.
+    let mut allowed: &[(char, u64)] =
+        &[('h', SECONDS_PER_HOUR), ('m', SECONDS_PER_MINUTE), ('s', 1)];
+
+    while !rest.is_empty() {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return Err("expected a number followed by h, m or s");
+        }
+        let amount: u64 = rest[..digits].parse().map_err(|_| TOO_LARGE)?;
+        rest = &rest[digits..];
+
+        let Some(unit) = rest.chars().next() else {
+            return Err("a number is missing its unit (h, m or s)");
+        };
+        let Some(position) = allowed.iter().position(|&(name, _)| name == unit) else {
+            return Err("units must be h, m or s, each once and in that order");
+        };
+        let (_, scale) = allowed[position];
+        allowed = &allowed[position + 1..];
+        rest = rest[unit.len_utf8()..].trim_start();
+
+        total = amount
+            .checked_mul(scale)
+            .and_then(|seconds| total.checked_add(seconds))
+            .ok_or(TOO_LARGE)?;
+    }
+    Ok(total)
+}
+
+/// Error types for Duration operations.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurationError {
+    #[error("Failed to parse duration from '{value}': {reason}")]
+    ParseError { value: String, reason: String },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -472,6 +808,289 @@ mod tests {
         }
     }
 
+    mod operators {
+        use super::*;
+
+        #[test]
+        fn test_saturating_sub_returns_difference() {
+            let d = Duration::from_minutes(10).saturating_sub(&Duration::from_minutes(4));
+            assert_eq!(d, Duration::from_minutes(6));
+        }
+
+        #[test]
+        fn test_saturating_sub_stops_at_zero() {
+            let d = Duration::from_minutes(4).saturating_sub(&Duration::from_minutes(10));
+            assert!(d.is_zero());
+        }
+
+        #[test]
+        fn test_saturating_mul_multiplies() {
+            assert_eq!(
+                Duration::from_minutes(15).saturating_mul(3),
+                Duration::from_minutes(45)
+            );
+        }
+
+        #[test]
+        fn test_saturating_mul_stops_at_max() {
+            let d = Duration::from_seconds(u64::MAX / 2 + 1).saturating_mul(2);
+            assert_eq!(d.total_seconds(), u64::MAX);
+        }
+
+        #[test]
+        fn test_saturating_mul_by_zero_is_zero() {
+            assert!(Duration::from_hours(3).saturating_mul(0).is_zero());
+        }
+
+        #[test]
+        fn test_add_operator_matches_add_method() {
+            let d1 = Duration::from_minutes(5);
+            let d2 = Duration::from_seconds(30);
+            assert_eq!(d1 + d2, d1.add(&d2));
+        }
+
+        #[test]
+        fn test_mul_operator_multiplies() {
+            assert_eq!(Duration::from_minutes(20) * 3, Duration::from_hours(1));
+        }
+
+        #[test]
+        fn test_mul_operator_saturates() {
+            let d = Duration::from_seconds(u64::MAX / 2 + 1) * 2;
+            assert_eq!(d.total_seconds(), u64::MAX);
+        }
+
+        #[test]
+        fn test_checked_mul_multiplies() {
+            assert_eq!(
+                Duration::from_minutes(15).checked_mul(4),
+                Some(Duration::from_hours(1))
+            );
+        }
+
+        #[test]
+        fn test_checked_mul_reports_overflow() {
+            assert_eq!(Duration::from_seconds(u64::MAX / 2 + 1).checked_mul(2), None);
+            assert_eq!(
+                Duration::from_seconds(u64::MAX).checked_mul(1),
+                Some(Duration::from_seconds(u64::MAX))
+            );
+        }
+
+        #[test]
+        fn test_sum_of_owned_durations() {
+            let total: Duration = vec![
+                Duration::from_minutes(10),
+                Duration::from_minutes(20),
+                Duration::from_seconds(5),
+            ]
+            .into_iter()
+            .sum();
+            assert_eq!(total.total_seconds(), 1805);
+        }
+
+        #[test]
+        fn test_sum_of_borrowed_durations() {
+            let durations = [Duration::from_hours(1), Duration::from_minutes(30)];
+            let total: Duration = durations.iter().sum();
+            assert_eq!(total, Duration::from_minutes(90));
+        }
+
+        #[test]
+        fn test_sum_of_nothing_is_zero() {
+            let total: Duration = Vec::<Duration>::new().into_iter().sum();
+            assert!(total.is_zero());
+        }
+    }
+
+    mod conversions {
+        use super::*;
+
+        #[test]
+        fn test_display_matches_format_hours() {
+            for seconds in [0, 125, 3600, 3660, 3665] {
+                let duration = Duration::from_seconds(seconds);
+                assert_eq!(duration.to_string(), duration.format_hours());
+            }
+        }
+
+        #[test]
+        fn test_display_round_trips_through_from_str() {
+            let duration = Duration::from_hms(26, 3, 9);
+            assert_eq!(duration.to_string().parse::<Duration>().unwrap(), duration);
+        }
+
+        #[test]
+        fn test_from_u64_reads_seconds() {
+            assert_eq!(Duration::from(1800_u64), Duration::from_minutes(30));
+        }
+
+        #[test]
+        fn test_from_std_duration_truncates_fraction() {
+            let std_duration = time::Duration::from_millis(5_999);
+            assert_eq!(Duration::from(std_duration).total_seconds(), 5);
+        }
+
+        #[test]
+        fn test_into_std_duration() {
+            let std_duration: time::Duration = Duration::from_hours(1).into();
+            assert_eq!(std_duration, time::Duration::from_secs(3600));
+        }
+
+        #[test]
+        fn test_std_duration_round_trip() {
+            let duration = Duration::from_seconds(u64::MAX);
+            assert_eq!(Duration::from(time::Duration::from(duration)), duration);
+        }
+
+        #[test]
+        fn test_from_chrono_duration() {
+            let span = chrono::Duration::hours(2) + chrono::Duration::seconds(5);
+            assert_eq!(Duration::from(span), Duration::from_hms(2, 0, 5));
+        }
+
+        #[test]
+        fn test_from_chrono_duration_truncates_fraction() {
+            let span = chrono::Duration::milliseconds(2_500);
+            assert_eq!(Duration::from(span).total_seconds(), 2);
+        }
+
+        #[test]
+        fn test_from_negative_chrono_duration_is_zero() {
+            assert!(Duration::from(chrono::Duration::seconds(-30)).is_zero());
+        }
+    }
+
+    mod parsing {
+        use super::*;
+
+        #[test]
+        fn test_parse_all_units() {
+            let d: Duration = "1h 30m 15s".parse().unwrap();
+            assert_eq!(d, Duration::from_hms(1, 30, 15));
+        }
+
+        #[test]
+        fn test_parse_single_unit() {
+            assert_eq!("45m".parse::<Duration>().unwrap(), Duration::from_minutes(45));
+            assert_eq!("2h".parse::<Duration>().unwrap(), Duration::from_hours(2));
+            assert_eq!("90s".parse::<Duration>().unwrap(), Duration::from_seconds(90));
+        }
+
+        #[test]
+        fn test_parse_skipped_unit() {
+            let d: Duration = "2h 5s".parse().unwrap();
+            assert_eq!(d, Duration::from_hms(2, 0, 5));
+        }
+
+        #[test]
+        fn test_parse_units_without_spaces() {
+            let d: Duration = "1h30m".parse().unwrap();
+            assert_eq!(d, Duration::from_minutes(90));
+        }
+
+        #[test]
+        fn test_parse_units_allows_values_over_sixty() {
+            let d: Duration = "90m".parse().unwrap();
+            assert_eq!(d, Duration::from_minutes(90));
+        }
+
+        #[test]
+        fn test_parse_trims_whitespace() {
+            let d: Duration = "  1h  30m \n".parse().unwrap();
+            assert_eq!(d, Duration::from_minutes(90));
+        }
+
+        #[test]
+        fn test_parse_round_trips_format_hours() {
+            for seconds in [0, 59, 125, 3600, 3660, 3665, 90061] {
+                let d = Duration::from_seconds(seconds);
+                assert_eq!(d.format_hours().parse::<Duration>().unwrap(), d);
+            }
+        }
+
+        #[test]
+        fn test_parse_clock() {
+            let d: Duration = "01:30:15".parse().unwrap();
+            assert_eq!(d, Duration::from_hms(1, 30, 15));
+        }
+
+        #[test]
+        fn test_parse_clock_allows_long_hours() {
+            let d: Duration = "120:00:00".parse().unwrap();
+            assert_eq!(d, Duration::from_hours(120));
+        }
+
+        #[test]
+        fn test_parse_rejects_empty() {
+            assert!("".parse::<Duration>().is_err());
+            assert!("   ".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_rejects_missing_unit() {
+            assert!("90".parse::<Duration>().is_err());
+            assert!("1h 30".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_rejects_unknown_unit() {
+            assert!("3d".parse::<Duration>().is_err());
+            assert!("90 minutes".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_rejects_units_out_of_order() {
+            assert!("30m 1h".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_rejects_repeated_unit() {
+            assert!("10m 5m".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_rejects_negative() {
+            assert!("-5m".parse::<Duration>().is_err());
+            assert!("-01:00:00".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_clock_rejects_wrong_part_count() {
+            assert!("01:30".parse::<Duration>().is_err());
+            assert!("01:30:15:00".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_clock_rejects_minutes_or_seconds_over_59() {
+            assert!("01:60:00".parse::<Duration>().is_err());
+            assert!("01:00:60".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_clock_rejects_blank_part() {
+            assert!("01::15".parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_rejects_overflow() {
+            let too_many_hours = format!("{}h", u64::MAX);
+            assert!(too_many_hours.parse::<Duration>().is_err());
+            let too_many_seconds = format!("{}0s", u64::MAX);
+            assert!(too_many_seconds.parse::<Duration>().is_err());
+        }
+
+        #[test]
+        fn test_parse_error_reports_value() {
+            let error = "soon".parse::<Duration>().unwrap_err();
+            assert!(matches!(
+                &error,
+                DurationError::ParseError { value, .. } if value == "soon"
+            ));
+            assert!(error.to_string().contains("'soon'"));
+        }
+    }
+
     mod formatting {
         use super::*;
 
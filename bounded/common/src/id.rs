@@ -1,4 +1,7 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 use thiserror::Error;
@@ -19,6 +22,14 @@ use thiserror::Error;
 /// String representation: 26 characters in Crockford's Base32
 /// Example: 01ARZ3NDEKTSV4RRFFQ69G5FAV
 ///
+/// [`Id::new_v7`] generates a UUIDv7 instead, which keeps the timestamp
+/// prefix but sets the UUID version bits. Parsing accepts either form.
+///
+/// The type parameter names the entity the ID belongs to, so that a
+/// `Id<Lesson>` cannot be passed where an `Id<Chapter>` is expected. It
+/// defaults to [`Untyped`], which keeps plain `Id` usable for any entity;
+/// [`Id::cast`] and [`Id::untyped`] convert between the two.
+///
 /// As a Value Object:
 /// - Immutable once created
 /// - Compared by value
@@ -41,11 +52,14 @@ use thiserror::Error;
 /// let parsed: Id = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().unwrap();
 /// assert_eq!(parsed.to_string(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Id {
+pub struct Id<T: ?Sized = Untyped> {
     bytes: [u8; 16],
+    kind: PhantomData<fn() -> T>,
 }
 
+/// The kind of an [`Id`] that is not tied to any entity type.
+pub enum Untyped {}
+
 impl Id {
     /// Creates a new ULID with the current timestamp and random data.
     ///
@@ -68,72 +82,80 @@ impl Id {
         Self::from_parts(timestamp_ms, random_bytes)
     }
 
-    /// Creates a ULID from timestamp and random bytes.
+    /// Creates a new UUIDv7 with the current timestamp and random data.
     ///
-    /// Useful for testing or when using external random sources.
+    /// A UUIDv7 starts with the same 48-bit millisecond timestamp as a ULID,
+    /// so both kinds sort by creation time and can share a column. The
+    /// difference is the version and variant bits, which databases with a
+    /// native UUID type expect.
     ///
     /// # Examples
     ///
     /// ```
     /// use education_platform_common::Id;
     ///
-    /// let timestamp = 1234567890123u64;
-    /// let random = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-    /// let id = Id::from_parts(timestamp, random);
-    /// assert_eq!(id.timestamp_ms(), timestamp);
+    /// let id = Id::new_v7();
+    /// assert!(id.is_uuid_v7());
+    /// assert_eq!(id.to_uuid_string().len(), 36);
     /// ```
     #[must_use]
-    pub fn from_parts(timestamp_ms: u64, random: [u8; 10]) -> Self {
-        let mut bytes = [0u8; 16];
-
-        bytes[0] = (timestamp_ms >> 40) as u8;
-        bytes[1] = (timestamp_ms >> 32) as u8;
-        bytes[2] = (timestamp_ms >> 24) as u8;
-        bytes[3] = (timestamp_ms >> 16) as u8;
-        bytes[4] = (timestamp_ms >> 8) as u8;
-        bytes[5] = timestamp_ms as u8;
-
-        bytes[6..16].copy_from_slice(&random);
-
-        Self { bytes }
+    pub fn new_v7() -> Self {
+        let mut bytes = *Self::new().as_bytes();
+        bytes[6] = 0x70 | (bytes[6] & 0x0F);
+        bytes[8] = 0x80 | (bytes[8] & 0x3F);
+        Self::from(bytes)
     }
 
-    /// Returns the timestamp component in milliseconds since Unix epoch.
+    /// Creates a new ID in the given format.
+    ///
+    /// Lets the format be chosen once, in configuration, instead of at each
+    /// call site.
     ///
     /// # Examples
     ///
     /// ```
-    /// use education_platform_common::Id;
+    /// use education_platform_common::{Id, IdFormat};
     ///
-    /// let id = Id::new();
-    /// let timestamp = id.timestamp_ms();
-    /// assert!(timestamp > 0);
+    /// assert!(Id::generate(IdFormat::UuidV7).is_uuid_v7());
+    /// assert_eq!(Id::generate(IdFormat::default()).to_string().len(), 26);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn timestamp_ms(&self) -> u64 {
-        ((self.bytes[0] as u64) << 40)
-            | ((self.bytes[1] as u64) << 32)
-            | ((self.bytes[2] as u64) << 24)
-            | ((self.bytes[3] as u64) << 16)
-            | ((self.bytes[4] as u64) << 8)
-            | (self.bytes[5] as u64)
+    pub fn generate(format: IdFormat) -> Self {
+        match format {
+            IdFormat::Ulid => Self::new(),
+            IdFormat::UuidV7 => Self::new_v7(),
+        }
     }
 
-    /// Returns the raw bytes of the ULID.
+    /// Creates a ULID from timestamp and random bytes.
+    ///
+    /// Useful for testing or when using external random sources.
     ///
     /// # Examples
     ///
     /// ```
     /// use education_platform_common::Id;
     ///
-    /// let id = Id::new();
-    /// assert_eq!(id.as_bytes().len(), 16);
+    /// let timestamp = 1234567890123u64;
+    /// let random = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let id = Id::from_parts(timestamp, random);
+    /// assert_eq!(id.timestamp_ms(), timestamp);
     /// ```
-    #[inline]
     #[must_use]
-    pub const fn as_bytes(&self) -> &[u8; 16] {
-        &self.bytes
+    pub fn from_parts(timestamp_ms: u64, random: [u8; 10]) -> Self {
+        let mut bytes = [0u8; 16];
+
+        bytes[0] = (timestamp_ms >> 40) as u8;
+        bytes[1] = (timestamp_ms >> 32) as u8;
+        bytes[2] = (timestamp_ms >> 24) as u8;
+        bytes[3] = (timestamp_ms >> 16) as u8;
+        bytes[4] = (timestamp_ms >> 8) as u8;
+        bytes[5] = timestamp_ms as u8;
+
+        bytes[6..16].copy_from_slice(&random);
+
+        Self::from(bytes)
     }
 
     fn current_timestamp_ms() -> u64 {
@@ -188,48 +210,6 @@ impl Id {
         bytes
     }
 
-    /// Encodes the ULID as a 26-character Crockford Base32 string.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use education_platform_common::Id;
-    ///
-    /// let id = Id::new();
-    /// let s = id.to_string();
-    /// assert_eq!(s.len(), 26);
-    /// assert!(s.chars().all(|c| "0123456789ABCDEFGHJKMNPQRSTVWXYZ".contains(c)));
-    /// ```
-    #[must_use]
-    pub fn to_crockford_base32(&self) -> String {
-        const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
-
-        let mut result = String::with_capacity(26);
-
-        // Encode timestamp (48 bits = 10 characters)
-        let timestamp = self.timestamp_ms();
-        [45u32, 40, 35, 30, 25, 20, 15, 10, 5, 0]
-            .iter()
-            .for_each(|&shift| {
-                result.push(ALPHABET[((timestamp >> shift) & 0x1F) as usize] as char);
-            });
-
-        // Encode randomness (80 bits = 16 characters)
-        // Combine all random bytes into u128 for easier bit manipulation
-        let random_bits: u128 = self.bytes[6..16]
-            .iter()
-            .fold(0u128, |acc, &byte| (acc << 8) | u128::from(byte));
-
-        // Extract 16 characters (5 bits each = 80 bits)
-        (0..16).for_each(|i| {
-            let shift = 5 * (15 - i);
-            let index = ((random_bits >> shift) & 0x1F) as usize;
-            result.push(ALPHABET[index] as char);
-        });
-
-        result
-    }
-
     /// Decodes a Crockford Base32 string into a ULID.
     ///
     /// # Errors
@@ -357,6 +337,233 @@ impl Id {
     pub fn from_string(s: String) -> Result<Self, IdError> {
         Self::from_crockford_base32(&s)
     }
+
+    /// Parses a UUID in its hyphenated form, in either case.
+    ///
+    /// Any UUID version is accepted, so IDs stored by other systems can be
+    /// read back; only the bytes are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IdError::InvalidLength` if the string is not 36 characters.
+    /// Returns `IdError::InvalidCharacter` if a hyphen is misplaced or a
+    /// digit is not hexadecimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// let id = Id::from_uuid_str("01890A5D-AC96-774B-BCCE-B302099A8057").unwrap();
+    /// assert_eq!(id.to_uuid_string(), "01890a5d-ac96-774b-bcce-b302099a8057");
+    /// assert_eq!(id.timestamp_ms(), 1_688_096_058_518);
+    /// ```
+    pub fn from_uuid_str(s: &str) -> Result<Self, IdError> {
+        if s.len() != 36 {
+            return Err(IdError::InvalidLength);
+        }
+
+        let mut bytes = [0u8; 16];
+        let mut digits = Vec::with_capacity(32);
+        for (position, c) in s.chars().enumerate() {
+            match (position, c) {
+                (8 | 13 | 18 | 23, '-') => {}
+                (8 | 13 | 18 | 23, _) => return Err(IdError::InvalidCharacter),
+                (_, c) => digits.push(c.to_digit(16).ok_or(IdError::InvalidCharacter)? as u8),
+            }
+        }
+        for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+            *byte = (pair[0] << 4) | pair[1];
+        }
+
+        Ok(Self::from(bytes))
+    }
+}
+
+impl<T: ?Sized> Id<T> {
+    /// Returns the timestamp component in milliseconds since Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// let id = Id::new();
+    /// let timestamp = id.timestamp_ms();
+    /// assert!(timestamp > 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn timestamp_ms(&self) -> u64 {
+        ((self.bytes[0] as u64) << 40)
+            | ((self.bytes[1] as u64) << 32)
+            | ((self.bytes[2] as u64) << 24)
+            | ((self.bytes[3] as u64) << 16)
+            | ((self.bytes[4] as u64) << 8)
+            | (self.bytes[5] as u64)
+    }
+
+    /// Returns the raw bytes of the ULID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// let id = Id::new();
+    /// assert_eq!(id.as_bytes().len(), 16);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.bytes
+    }
+
+    /// Encodes the ULID as a 26-character Crockford Base32 string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// let id = Id::new();
+    /// let s = id.to_string();
+    /// assert_eq!(s.len(), 26);
+    /// assert!(s.chars().all(|c| "0123456789ABCDEFGHJKMNPQRSTVWXYZ".contains(c)));
+    /// ```
+    #[must_use]
+    pub fn to_crockford_base32(&self) -> String {
+        const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+        let mut result = String::with_capacity(26);
+
+        // Encode timestamp (48 bits = 10 characters)
+        let timestamp = self.timestamp_ms();
+        [45u32, 40, 35, 30, 25, 20, 15, 10, 5, 0]
+            .iter()
+            .for_each(|&shift| {
+                result.push(ALPHABET[((timestamp >> shift) & 0x1F) as usize] as char);
+            });
+
+        // Encode randomness (80 bits = 16 characters)
+        // Combine all random bytes into u128 for easier bit manipulation
+        let random_bits: u128 = self.bytes[6..16]
+            .iter()
+            .fold(0u128, |acc, &byte| (acc << 8) | u128::from(byte));
+
+        // Extract 16 characters (5 bits each = 80 bits)
+        (0..16).for_each(|i| {
+            let shift = 5 * (15 - i);
+            let index = ((random_bits >> shift) & 0x1F) as usize;
+            result.push(ALPHABET[index] as char);
+        });
+
+        result
+    }
+
+    /// Formats the ID as a lowercase, hyphenated UUID.
+    ///
+    /// Works for every ID, including ULIDs, since both are 128 bits; use
+    /// [`Id::is_uuid_v7`] to tell whether the version bits are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// let id = Id::from_parts(0, [0; 10]);
+    /// assert_eq!(id.to_uuid_string(), "00000000-0000-0000-0000-000000000000");
+    /// ```
+    #[must_use]
+    pub fn to_uuid_string(&self) -> String {
+        let mut uuid = String::with_capacity(36);
+        for (index, byte) in self.bytes.iter().enumerate() {
+            if matches!(index, 4 | 6 | 8 | 10) {
+                uuid.push('-');
+            }
+            uuid.push_str(&format!("{:02x}", byte));
+        }
+        uuid
+    }
+
+    /// Formats the ID as a string in the given format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Id, IdFormat};
+    ///
+    /// let id = Id::new_v7();
+    /// assert_eq!(id.format_as(IdFormat::Ulid), id.to_string());
+    /// assert_eq!(id.format_as(IdFormat::UuidV7), id.to_uuid_string());
+    /// ```
+    #[must_use]
+    pub fn format_as(&self, format: IdFormat) -> String {
+        match format {
+            IdFormat::Ulid => self.to_crockford_base32(),
+            IdFormat::UuidV7 => self.to_uuid_string(),
+        }
+    }
+
+    /// Returns `true` if the version and variant bits mark a UUIDv7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// assert!(Id::new_v7().is_uuid_v7());
+    /// assert!(!Id::from_parts(0, [0; 10]).is_uuid_v7());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_uuid_v7(&self) -> bool {
+        self.bytes[6] >> 4 == 0x7 && self.bytes[8] >> 6 == 0b10
+    }
+
+    /// Reinterprets the ID as identifying another kind of entity.
+    ///
+    /// Use it at the edges where an untyped ID is known to belong to a
+    /// specific entity, such as after parsing a request path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// struct Lesson;
+    ///
+    /// let id = Id::new();
+    /// let lesson_id: Id<Lesson> = id.cast();
+    /// assert_eq!(lesson_id.to_string(), id.to_string());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cast<U: ?Sized>(self) -> Id<U> {
+        Id {
+            bytes: self.bytes,
+            kind: PhantomData,
+        }
+    }
+
+    /// Drops the entity type, for APIs that take any [`Id`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    ///
+    /// struct Course;
+    ///
+    /// let course_id: Id<Course> = Id::new().cast();
+    /// let id: Id = course_id.untyped();
+    /// assert_eq!(id.cast::<Course>(), course_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn untyped(self) -> Id {
+        self.cast()
+    }
 }
 
 impl Default for Id {
@@ -365,37 +572,103 @@ impl Default for Id {
     }
 }
 
-impl fmt::Display for Id {
+impl<T: ?Sized> fmt::Display for Id<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.to_crockford_base32())
     }
 }
 
-impl FromStr for Id {
+impl<T: ?Sized> FromStr for Id<T> {
     type Err = IdError;
 
+    /// Parses a 26-character ULID or a 36-character hyphenated UUID.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_crockford_base32(s)
+        let id = if s.len() == 36 {
+            Id::from_uuid_str(s)?
+        } else {
+            Id::from_crockford_base32(s)?
+        };
+        Ok(id.cast())
     }
 }
 
 impl From<[u8; 16]> for Id {
     fn from(bytes: [u8; 16]) -> Self {
-        Self { bytes }
+        Self {
+            bytes,
+            kind: PhantomData,
+        }
     }
 }
 
-impl From<Id> for [u8; 16] {
-    fn from(id: Id) -> Self {
+impl<T: ?Sized> From<Id<T>> for [u8; 16] {
+    fn from(id: Id<T>) -> Self {
         id.bytes
     }
 }
 
+// Implemented by hand because derives would require `T` itself to be
+// `Clone`, `Eq` and so on, while only the bytes matter.
+
+impl<T: ?Sized> fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Id").field("bytes", &self.bytes).finish()
+    }
+}
+
+impl<T: ?Sized> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Id<T> {}
+
+impl<T: ?Sized> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<T: ?Sized> Eq for Id<T> {}
+
+impl<T: ?Sized> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+    }
+}
+
+impl<T: ?Sized> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ?Sized> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+/// How new IDs are generated and written out.
+///
+/// Both formats are time-ordered and parse back with [`str::parse`], so
+/// switching formats does not invalidate IDs already stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdFormat {
+    /// A ULID written in 26 characters of Crockford Base32.
+    #[default]
+    Ulid,
+    /// A UUIDv7 written as 36 hyphenated hexadecimal characters.
+    UuidV7,
+}
+
 /// Error types for ID operations.
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum IdError {
-    #[error("Invalid ID length: expected 26 characters")]
+    #[error("Invalid ID length: expected a 26-character ULID or a 36-character UUID")]
     InvalidLength,
 
     #[error("Invalid character in ID string")]
@@ -672,4 +945,133 @@ mod tests {
 
         assert_eq!(with_o, with_zero);
     }
+
+    struct Lesson;
+    struct Chapter;
+
+    #[test]
+    fn test_cast_keeps_bytes() {
+        let id = Id::new();
+        let lesson_id: Id<Lesson> = id.cast();
+
+        assert_eq!(lesson_id.as_bytes(), id.as_bytes());
+        assert_eq!(lesson_id.timestamp_ms(), id.timestamp_ms());
+    }
+
+    #[test]
+    fn test_untyped_roundtrip() {
+        let lesson_id: Id<Lesson> = Id::new().cast();
+
+        assert_eq!(lesson_id.untyped().cast::<Lesson>(), lesson_id);
+    }
+
+    #[test]
+    fn test_typed_id_display_matches_untyped() {
+        let id = Id::from_parts(1234567890123, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let chapter_id: Id<Chapter> = id.cast();
+
+        assert_eq!(chapter_id.to_string(), id.to_string());
+        assert_eq!(format!("{:?}", chapter_id), format!("{:?}", id));
+    }
+
+    #[test]
+    fn test_parse_typed_id() {
+        let lesson_id: Id<Lesson> = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().unwrap();
+
+        assert_eq!(lesson_id.to_string(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    }
+
+    #[test]
+    fn test_typed_ids_order_by_timestamp() {
+        let earlier: Id<Lesson> = Id::from_parts(1000, [0; 10]).cast();
+        let later: Id<Lesson> = Id::from_parts(2000, [0; 10]).cast();
+
+        assert!(earlier < later);
+    }
+
+    #[test]
+    fn test_new_v7_sets_version_and_variant() {
+        let id = Id::new_v7();
+        let uuid = id.to_uuid_string();
+
+        assert!(id.is_uuid_v7());
+        assert_eq!(&uuid[14..15], "7");
+        assert!(matches!(&uuid[19..20], "8" | "9" | "a" | "b"));
+    }
+
+    #[test]
+    fn test_new_v7_keeps_current_timestamp() {
+        let before = Id::new().timestamp_ms();
+        let id = Id::new_v7();
+        let after = Id::new().timestamp_ms();
+
+        assert!((before..=after).contains(&id.timestamp_ms()));
+    }
+
+    #[test]
+    fn test_v7_ids_sort_by_timestamp() {
+        let mut earlier = *Id::new_v7().as_bytes();
+        let mut later = earlier;
+        earlier[..6].copy_from_slice(&[0, 0, 0, 0, 0x03, 0xE8]);
+        later[..6].copy_from_slice(&[0, 0, 0, 0, 0x07, 0xD0]);
+
+        assert!(Id::from(earlier) < Id::from(later));
+        assert_eq!(Id::from(earlier).timestamp_ms(), 1000);
+    }
+
+    #[test]
+    fn test_generate_uses_format() {
+        assert!(Id::generate(IdFormat::UuidV7).is_uuid_v7());
+        assert_eq!(IdFormat::default(), IdFormat::Ulid);
+    }
+
+    #[test]
+    fn test_uuid_string_roundtrip() {
+        let id = Id::new_v7();
+        let parsed = Id::from_uuid_str(&id.to_uuid_string()).unwrap();
+
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn test_from_uuid_str_is_case_insensitive() {
+        let upper = Id::from_uuid_str("01890A5D-AC96-774B-BCCE-B302099A8057").unwrap();
+        let lower = Id::from_uuid_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+
+        assert_eq!(upper, lower);
+    }
+
+    #[test]
+    fn test_from_uuid_str_invalid_length() {
+        let result = Id::from_uuid_str("01890a5d-ac96-774b-bcce-b302099a805");
+        assert_eq!(result, Err(IdError::InvalidLength));
+    }
+
+    #[test]
+    fn test_from_uuid_str_misplaced_hyphen() {
+        let result = Id::from_uuid_str("01890a5da-c96-774b-bcce-b302099a8057");
+        assert_eq!(result, Err(IdError::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_from_uuid_str_non_hex_digit() {
+        let result = Id::from_uuid_str("01890a5d-ac96-774b-bcce-b302099a805g");
+        assert_eq!(result, Err(IdError::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_parse_accepts_ulid_and_uuid() {
+        let id = Id::new_v7();
+
+        assert_eq!(id.to_string().parse::<Id>(), Ok(id));
+        assert_eq!(id.to_uuid_string().parse::<Id>(), Ok(id));
+    }
+
+    #[test]
+    fn test_format_as_matches_encoders() {
+        let id = Id::new();
+
+        assert_eq!(id.format_as(IdFormat::Ulid), id.to_string());
+        assert_eq!(id.format_as(IdFormat::UuidV7), id.to_uuid_string());
+    }
 }
@@ -1,4 +1,8 @@
+mod auditable;
+mod cron_schedule;
+mod custom_field;
 mod date;
+mod date_range;
 mod datetime;
 mod document;
 mod domain_event;
@@ -7,14 +11,27 @@ mod email;
 mod entity;
 mod id;
 mod index;
+mod locale;
+mod localized_text;
+mod money;
 mod name;
 mod password;
+mod percentage;
 mod person_name;
+mod projection;
+mod schedule;
+mod score;
 mod simple_name;
+mod time_range;
 mod url;
+mod url_policy;
 mod validator;
 
+pub use auditable::*;
+pub use cron_schedule::*;
+pub use custom_field::*;
 pub use date::*;
+pub use date_range::*;
 pub use datetime::*;
 pub use document::*;
 pub use domain_event::*;
@@ -23,9 +40,18 @@ pub use email::*;
 pub use entity::*;
 pub use id::*;
 pub use index::*;
+pub use locale::*;
+pub use localized_text::*;
+pub use money::*;
 pub use name::*;
 pub use password::*;
+pub use percentage::*;
 pub use person_name::*;
+pub use projection::*;
+pub use schedule::*;
+pub use score::*;
 pub use simple_name::*;
+pub use time_range::*;
 pub use url::*;
+pub use url_policy::*;
 pub use validator::*;
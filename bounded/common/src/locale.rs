@@ -0,0 +1,243 @@
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error types for Locale validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LocaleError {
+    #[error("Locale tag must not be empty")]
+    Empty,
+
+    #[error("Locale subtag '{0}' is not valid")]
+    SubtagNotValid(String),
+}
+
+/// A BCP-47 language tag such as `en`, `pt-BR` or `zh-Hant-TW`.
+///
+/// The language, optional script, optional region and variant subtags are
+/// supported; extensions and private-use tags are rejected. Tags are stored
+/// in canonical case (`pt-BR`, `zh-Hant`) and `_` is accepted as a separator,
+/// so `pt_br` and `pt-BR` are the same locale.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Locale;
+///
+/// let locale = Locale::new("pt_br").unwrap();
+/// assert_eq!(locale.as_str(), "pt-BR");
+/// assert_eq!(locale.language(), "pt");
+/// assert_eq!(locale.parent(), Some(Locale::new("pt").unwrap()));
+///
+/// assert!(Locale::new("english").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Locale(String);
+
+impl Locale {
+    /// Parses and canonicalizes a language tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LocaleError::Empty` for a blank tag, or
+    /// `LocaleError::SubtagNotValid` for the first subtag that does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Locale;
+    ///
+    /// assert_eq!(Locale::new("ZH-hant-tw").unwrap().as_str(), "zh-Hant-TW");
+    /// assert_eq!(Locale::new("es-419").unwrap().as_str(), "es-419");
+    /// assert!(Locale::new("").is_err());
+    /// assert!(Locale::new("en-x-private").is_err());
+    /// ```
+    pub fn new(tag: &str) -> Result<Self, LocaleError> {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(LocaleError::Empty);
+        }
+
+        let mut subtags = tag.split(['-', '_']);
+        let mut canonical = match subtags.next() {
+            Some(language) if is_language(language) => language.to_ascii_lowercase(),
+            Some(language) => return Err(LocaleError::SubtagNotValid(language.to_string())),
+            None => return Err(LocaleError::Empty),
+        };
+
+        let mut position = Position::Script;
+        for subtag in subtags {
+            let (next, normalized) = match position {
+                Position::Script if is_script(subtag) => (Position::Region, title_case(subtag)),
+                Position::Script | Position::Region if is_region(subtag) => {
+                    (Position::Variant, subtag.to_ascii_uppercase())
+                }
+                _ if is_variant(subtag) => (Position::Variant, subtag.to_ascii_lowercase()),
+                _ => return Err(LocaleError::SubtagNotValid(subtag.to_string())),
+            };
+            position = next;
+            canonical.push('-');
+            canonical.push_str(&normalized);
+        }
+
+        Ok(Self(canonical))
+    }
+
+    /// Returns the canonical tag.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the primary language subtag, e.g. `pt` for `pt-BR`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+
+    /// Returns the tag with its last subtag removed, or `None` for a bare language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Locale;
+    ///
+    /// let locale = Locale::new("zh-Hant-TW").unwrap();
+    /// assert_eq!(locale.parent().unwrap().as_str(), "zh-Hant");
+    /// assert!(Locale::new("zh").unwrap().parent().is_none());
+    /// ```
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        self.0
+            .rsplit_once('-')
+            .map(|(parent, _)| Self(parent.to_string()))
+    }
+
+    /// Returns this locale followed by each of its parents, most specific first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Locale;
+    ///
+    /// let chain = Locale::new("pt-BR").unwrap().fallback_chain();
+    /// let tags: Vec<&str> = chain.iter().map(Locale::as_str).collect();
+    /// assert_eq!(tags, ["pt-BR", "pt"]);
+    /// ```
+    #[must_use]
+    pub fn fallback_chain(&self) -> Vec<Self> {
+        std::iter::successors(Some(self.clone()), Self::parent).collect()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Position {
+    Script,
+    Region,
+    Variant,
+}
+
+fn is_language(subtag: &str) -> bool {
+    (2..=3).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_script(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_region(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_variant(subtag: &str) -> bool {
+    let alphanumeric = subtag.bytes().all(|b| b.is_ascii_alphanumeric());
+    match subtag.len() {
+        5..=8 => alphanumeric,
+        4 => alphanumeric && subtag.starts_with(|c: char| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+fn title_case(subtag: &str) -> String {
+    let lower = subtag.to_ascii_lowercase();
+    let mut chars = lower.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+impl FromStr for Locale {
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_language_only() {
+            assert_eq!(Locale::new("EN").unwrap().as_str(), "en");
+            assert_eq!(Locale::new("fil").unwrap().as_str(), "fil");
+        }
+
+        #[test]
+        fn test_script_region_and_variant_are_canonicalized() {
+            assert_eq!(Locale::new("sr-latn-rs").unwrap().as_str(), "sr-Latn-RS");
+            assert_eq!(Locale::new("de-CH-1996").unwrap().as_str(), "de-CH-1996");
+            assert_eq!(Locale::new("sl-ROZAJ").unwrap().as_str(), "sl-rozaj");
+        }
+
+        #[test]
+        fn test_invalid_tags_are_rejected() {
+            assert_eq!(Locale::new("  "), Err(LocaleError::Empty));
+            assert_eq!(Locale::new("e"), Err(LocaleError::SubtagNotValid("e".to_string())));
+            assert_eq!(
+                Locale::new("en-US-Latn"),
+                Err(LocaleError::SubtagNotValid("Latn".to_string()))
+            );
+            assert_eq!(Locale::new("en--US"), Err(LocaleError::SubtagNotValid(String::new())));
+        }
+
+        #[test]
+        fn test_from_str_matches_new() {
+            let parsed: Locale = "es_mx".parse().unwrap();
+
+            assert_eq!(parsed, Locale::new("es-MX").unwrap());
+            assert_eq!(parsed.to_string(), "es-MX");
+        }
+    }
+
+    mod fallback {
+        use super::*;
+
+        #[test]
+        fn test_chain_drops_one_subtag_at_a_time() {
+            let chain = Locale::new("zh-Hant-TW").unwrap().fallback_chain();
+
+            let tags: Vec<&str> = chain.iter().map(Locale::as_str).collect();
+            assert_eq!(tags, ["zh-Hant-TW", "zh-Hant", "zh"]);
+        }
+
+        #[test]
+        fn test_language_of_regional_locale() {
+            assert_eq!(Locale::new("fr-CA").unwrap().language(), "fr");
+        }
+    }
+}
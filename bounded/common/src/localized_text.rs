@@ -0,0 +1,234 @@
+use crate::Locale;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Error types for LocalizedText validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LocalizedTextError {
+    #[error("Text for locale {0} must not be empty")]
+    EmptyText(Locale),
+}
+
+/// The same text in several locales.
+///
+/// Lookups walk a fallback chain: the requested locale, then its parents
+/// (`pt-BR` → `pt`), then the fallback locale and its parents, if one is
+/// set. Texts are trimmed and must not be blank.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Locale, LocalizedText};
+///
+/// let en = Locale::new("en").unwrap();
+/// let mut title = LocalizedText::new().with_fallback(en.clone());
+/// title.set(en, "Ownership").unwrap();
+/// title.set(Locale::new("es").unwrap(), "Propiedad").unwrap();
+///
+/// assert_eq!(title.resolve(&Locale::new("es-MX").unwrap()), Some("Propiedad"));
+/// assert_eq!(title.resolve(&Locale::new("de").unwrap()), Some("Ownership"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LocalizedText {
+    texts: BTreeMap<Locale, String>,
+    fallback: Option<Locale>,
+}
+
+impl LocalizedText {
+    /// Creates an empty text with no fallback locale.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the locale to fall back to when no locale in the requested chain has a text.
+    #[must_use]
+    pub fn with_fallback(mut self, locale: Locale) -> Self {
+        self.fallback = Some(locale);
+        self
+    }
+
+    /// Sets the text for a locale, returning the text it replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LocalizedTextError::EmptyText` if the text is blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Locale, LocalizedText};
+    ///
+    /// let fr = Locale::new("fr").unwrap();
+    /// let mut text = LocalizedText::new();
+    ///
+    /// assert_eq!(text.set(fr.clone(), " Bonjour ").unwrap(), None);
+    /// assert_eq!(text.set(fr.clone(), "Salut").unwrap(), Some("Bonjour".to_string()));
+    /// assert!(text.set(fr, "   ").is_err());
+    /// ```
+    pub fn set(
+        &mut self,
+        locale: Locale,
+        text: &str,
+    ) -> Result<Option<String>, LocalizedTextError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(LocalizedTextError::EmptyText(locale));
+        }
+        Ok(self.texts.insert(locale, text.to_string()))
+    }
+
+    /// Removes the text for a locale, returning it.
+    pub fn remove(&mut self, locale: &Locale) -> Option<String> {
+        self.texts.remove(locale)
+    }
+
+    /// Returns the text stored for exactly this locale.
+    #[must_use]
+    pub fn get(&self, locale: &Locale) -> Option<&str> {
+        self.texts.get(locale).map(String::as_str)
+    }
+
+    /// Returns the best text for a locale, following the fallback chain.
+    #[must_use]
+    pub fn resolve(&self, locale: &Locale) -> Option<&str> {
+        self.fallback_chain(locale)
+            .iter()
+            .find_map(|candidate| self.get(candidate))
+    }
+
+    /// Returns the locales [`LocalizedText::resolve`] tries, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Locale, LocalizedText};
+    ///
+    /// let text = LocalizedText::new().with_fallback(Locale::new("en-GB").unwrap());
+    /// let chain = text.fallback_chain(&Locale::new("pt-BR").unwrap());
+    /// let tags: Vec<&str> = chain.iter().map(Locale::as_str).collect();
+    ///
+    /// assert_eq!(tags, ["pt-BR", "pt", "en-GB", "en"]);
+    /// ```
+    #[must_use]
+    pub fn fallback_chain(&self, locale: &Locale) -> Vec<Locale> {
+        let mut chain = locale.fallback_chain();
+        if let Some(fallback) = &self.fallback {
+            for candidate in fallback.fallback_chain() {
+                if !chain.contains(&candidate) {
+                    chain.push(candidate);
+                }
+            }
+        }
+        chain
+    }
+
+    /// Returns the fallback locale, if one is set.
+    #[inline]
+    #[must_use]
+    pub const fn fallback(&self) -> Option<&Locale> {
+        self.fallback.as_ref()
+    }
+
+    /// Returns the locales that have a text, in tag order.
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.texts.keys()
+    }
+
+    /// Returns the number of locales with a text.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.texts.len()
+    }
+
+    /// Returns true if no locale has a text.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_locale(tag: &str) -> Locale {
+        Locale::new(tag).unwrap()
+    }
+
+    mod set {
+        use super::*;
+
+        #[test]
+        fn test_text_is_trimmed_and_stored_per_locale() {
+            let mut text = LocalizedText::new();
+
+            text.set(create_locale("en"), "  Hello  ").unwrap();
+            text.set(create_locale("es"), "Hola").unwrap();
+
+            assert_eq!(text.get(&create_locale("en")), Some("Hello"));
+            assert_eq!(text.len(), 2);
+        }
+
+        #[test]
+        fn test_blank_text_is_rejected_and_not_stored() {
+            let mut text = LocalizedText::new();
+
+            let result = text.set(create_locale("en"), "");
+
+            assert_eq!(result, Err(LocalizedTextError::EmptyText(create_locale("en"))));
+            assert!(text.is_empty());
+        }
+
+        #[test]
+        fn test_remove_returns_previous_text() {
+            let mut text = LocalizedText::new();
+            text.set(create_locale("en"), "Hello").unwrap();
+
+            assert_eq!(text.remove(&create_locale("en")), Some("Hello".to_string()));
+            assert_eq!(text.remove(&create_locale("en")), None);
+        }
+    }
+
+    mod resolve {
+        use super::*;
+
+        #[test]
+        fn test_exact_locale_wins_over_parent() {
+            let mut text = LocalizedText::new();
+            text.set(create_locale("pt"), "Olá").unwrap();
+            text.set(create_locale("pt-BR"), "Oi").unwrap();
+
+            assert_eq!(text.resolve(&create_locale("pt-BR")), Some("Oi"));
+            assert_eq!(text.resolve(&create_locale("pt-PT")), Some("Olá"));
+        }
+
+        #[test]
+        fn test_missing_locale_without_fallback_is_none() {
+            let mut text = LocalizedText::new();
+            text.set(create_locale("en"), "Hello").unwrap();
+
+            assert_eq!(text.resolve(&create_locale("ja")), None);
+        }
+
+        #[test]
+        fn test_fallback_parents_are_tried() {
+            let mut text = LocalizedText::new().with_fallback(create_locale("en-US"));
+            text.set(create_locale("en"), "Hello").unwrap();
+
+            assert_eq!(text.resolve(&create_locale("ja")), Some("Hello"));
+        }
+
+        #[test]
+        fn test_chain_does_not_repeat_locales() {
+            let text = LocalizedText::new().with_fallback(create_locale("en"));
+
+            let chain = text.fallback_chain(&create_locale("en-AU"));
+
+            assert_eq!(chain, vec![create_locale("en-AU"), create_locale("en")]);
+        }
+    }
+}
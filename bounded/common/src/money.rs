@@ -0,0 +1,363 @@
+use crate::Percentage;
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Money validation and arithmetic failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MoneyError {
+    #[error("Currency code '{0}' must be three ASCII letters")]
+    CurrencyNotValid(String),
+
+    #[error("Cannot combine {left} with {right}")]
+    CurrencyMismatch { left: String, right: String },
+
+    #[error("Amount is too large")]
+    Overflow,
+}
+
+/// An ISO 4217 currency code such as `USD`, `EUR` or `CLP`.
+///
+/// Codes are three ASCII letters stored in upper case. The code is not
+/// checked against the list of active currencies, but the number of minor
+/// units (decimals) is known for the currencies that do not use two.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Currency;
+///
+/// let dollars = Currency::new("usd").unwrap();
+/// assert_eq!(dollars.as_str(), "USD");
+/// assert_eq!(dollars.minor_units(), 2);
+/// assert_eq!(Currency::new("CLP").unwrap().minor_units(), 0);
+///
+/// assert!(Currency::new("US$").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    /// Currencies without minor units.
+    const WITHOUT_DECIMALS: [&'static str; 8] =
+        ["CLP", "ISK", "JPY", "KRW", "PYG", "UGX", "VND", "XAF"];
+
+    /// Currencies with three minor units.
+    const WITH_THREE_DECIMALS: [&'static str; 6] = ["BHD", "IQD", "JOD", "KWD", "OMR", "TND"];
+
+    /// Validates and upper-cases a currency code.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MoneyError::CurrencyNotValid` if the code is not exactly
+    /// three ASCII letters.
+    pub fn new(code: &str) -> Result<Self, MoneyError> {
+        match code.trim().as_bytes() {
+            &[a, b, c] if [a, b, c].iter().all(u8::is_ascii_alphabetic) => Ok(Self([
+                a.to_ascii_uppercase(),
+                b.to_ascii_uppercase(),
+                c.to_ascii_uppercase(),
+            ])),
+            _ => Err(MoneyError::CurrencyNotValid(code.to_string())),
+        }
+    }
+
+    /// Returns the three-letter code.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Returns how many decimals the currency's minor unit has.
+    #[must_use]
+    pub fn minor_units(&self) -> u32 {
+        let code = self.as_str();
+        if Self::WITHOUT_DECIMALS.contains(&code) {
+            0
+        } else if Self::WITH_THREE_DECIMALS.contains(&code) {
+            3
+        } else {
+            2
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An amount of money in a given currency.
+///
+/// The amount is a whole number of the currency's minor unit, such as
+/// cents, so adding prices never loses precision. Amounts are never
+/// negative, and combining amounts in different currencies is an error.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Currency, Money, Percentage};
+///
+/// let usd = Currency::new("USD").unwrap();
+/// let course = Money::new(4_999, usd);
+/// let book = Money::new(1_500, usd);
+///
+/// let total = course.checked_add(book).unwrap();
+/// assert_eq!(total.to_string(), "64.99 USD");
+/// assert_eq!(total.percentage(Percentage::new(10.0).unwrap()).amount(), 650);
+///
+/// let yen = Money::new(500, Currency::new("JPY").unwrap());
+/// assert!(course.checked_add(yen).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: u64,
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates an amount of money from a number of minor units.
+    #[inline]
+    #[must_use]
+    pub const fn new(amount: u64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Creates a zero amount in the currency.
+    #[inline]
+    #[must_use]
+    pub const fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    /// Returns the amount in minor units.
+    #[inline]
+    #[must_use]
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the currency.
+    #[inline]
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns `true` if the amount is zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Adds two amounts in the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MoneyError::CurrencyMismatch` if the currencies differ, or
+    /// `MoneyError::Overflow` if the sum does not fit.
+    pub fn checked_add(self, other: Self) -> Result<Self, MoneyError> {
+        self.check_currency(other)?;
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| Self::new(amount, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Subtracts an amount in the same currency, stopping at zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MoneyError::CurrencyMismatch` if the currencies differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Currency, Money};
+    ///
+    /// let eur = Currency::new("EUR").unwrap();
+    /// let price = Money::new(1_000, eur);
+    ///
+    /// assert_eq!(price.saturating_sub(Money::new(300, eur)).unwrap().amount(), 700);
+    /// assert!(price.saturating_sub(Money::new(5_000, eur)).unwrap().is_zero());
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Result<Self, MoneyError> {
+        self.check_currency(other)?;
+        Ok(Self::new(self.amount.saturating_sub(other.amount), self.currency))
+    }
+
+    /// Returns the share of the amount, rounded half up to the minor unit.
+    ///
+    /// See [`Percentage::share_of`].
+    #[must_use]
+    pub fn percentage(&self, percentage: Percentage) -> Self {
+        Self::new(percentage.share_of(self.amount), self.currency)
+    }
+
+    /// Adds up amounts in `currency`; no amounts add up to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of [`Money::checked_add`].
+    pub fn sum(
+        currency: Currency,
+        amounts: impl IntoIterator<Item = Self>,
+    ) -> Result<Self, MoneyError> {
+        amounts
+            .into_iter()
+            .try_fold(Self::zero(currency), Self::checked_add)
+    }
+
+    fn check_currency(&self, other: Self) -> Result<(), MoneyError> {
+        match self.currency == other.currency {
+            true => Ok(()),
+            false => Err(MoneyError::CurrencyMismatch {
+                left: self.currency.to_string(),
+                right: other.currency.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = self.currency.minor_units();
+        let scale = 10u64.pow(decimals);
+        match decimals {
+            0 => write!(f, "{} {}", self.amount, self.currency),
+            _ => write!(
+                f,
+                "{}.{:0width$} {}",
+                self.amount / scale,
+                self.amount % scale,
+                self.currency,
+                width = decimals as usize
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd() -> Currency {
+        Currency::new("USD").unwrap()
+    }
+
+    mod currency {
+        use super::*;
+
+        #[test]
+        fn test_code_is_upper_cased() {
+            assert_eq!(Currency::new(" eur ").unwrap().as_str(), "EUR");
+        }
+
+        #[test]
+        fn test_invalid_codes() {
+            for code in ["", "US", "USDT", "U5D", "€UR"] {
+                assert!(
+                    matches!(Currency::new(code), Err(MoneyError::CurrencyNotValid(_))),
+                    "{code} should be rejected"
+                );
+            }
+        }
+
+        #[test]
+        fn test_minor_units() {
+            assert_eq!(usd().minor_units(), 2);
+            assert_eq!(Currency::new("JPY").unwrap().minor_units(), 0);
+            assert_eq!(Currency::new("KWD").unwrap().minor_units(), 3);
+        }
+    }
+
+    mod arithmetic {
+        use super::*;
+
+        #[test]
+        fn test_add_same_currency() {
+            let sum = Money::new(150, usd())
+                .checked_add(Money::new(250, usd()))
+                .unwrap();
+
+            assert_eq!(sum, Money::new(400, usd()));
+        }
+
+        #[test]
+        fn test_add_other_currency() {
+            let eur = Currency::new("EUR").unwrap();
+
+            let result = Money::new(150, usd()).checked_add(Money::new(250, eur));
+
+            assert_eq!(
+                result,
+                Err(MoneyError::CurrencyMismatch {
+                    left: "USD".to_string(),
+                    right: "EUR".to_string(),
+                })
+            );
+        }
+
+        #[test]
+        fn test_add_overflow() {
+            let result = Money::new(u64::MAX, usd()).checked_add(Money::new(1, usd()));
+
+            assert_eq!(result, Err(MoneyError::Overflow));
+        }
+
+        #[test]
+        fn test_percentage_rounds() {
+            let price = Money::new(999, usd());
+
+            assert_eq!(price.percentage(Percentage::new(50.0).unwrap()).amount(), 500);
+            assert_eq!(price.percentage(Percentage::ZERO).amount(), 0);
+            assert_eq!(price.percentage(Percentage::FULL), price);
+        }
+
+        #[test]
+        fn test_percentage_rounding_boundaries() {
+            let vat = Percentage::new(21.0).unwrap();
+
+            assert_eq!(Money::new(2, usd()).percentage(vat).amount(), 0);
+            assert_eq!(Money::new(3, usd()).percentage(vat).amount(), 1);
+            assert_eq!(Money::new(50, usd()).percentage(vat).amount(), 11);
+        }
+
+        #[test]
+        fn test_percentage_of_large_amount_is_exact() {
+            let amount = Money::new(u64::MAX - 1, usd());
+
+            let half = amount.percentage(Percentage::new(50.0).unwrap());
+
+            assert_eq!(half.amount(), u64::MAX / 2);
+        }
+
+        #[test]
+        fn test_sum() {
+            let amounts = [Money::new(100, usd()), Money::new(250, usd())];
+
+            assert_eq!(Money::sum(usd(), amounts).unwrap().amount(), 350);
+            assert!(Money::sum(usd(), []).unwrap().is_zero());
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn test_uses_currency_decimals() {
+            assert_eq!(Money::new(4_905, usd()).to_string(), "49.05 USD");
+            assert_eq!(Money::new(7, usd()).to_string(), "0.07 USD");
+            assert_eq!(
+                Money::new(15_000, Currency::new("CLP").unwrap()).to_string(),
+                "15000 CLP"
+            );
+            assert_eq!(
+                Money::new(1_500, Currency::new("KWD").unwrap()).to_string(),
+                "1.500 KWD"
+            );
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod hash;
+mod policy;
 mod strong;
 
 pub use hash::*;
+pub use policy::*;
 pub use strong::*;
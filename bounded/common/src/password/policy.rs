@@ -0,0 +1,285 @@
+use std::fmt;
+
+const DEFAULT_MIN_LENGTH: usize = 8;
+
+/// Passwords rejected outright no matter how they score.
+const COMMON_PASSWORDS: [&str; 12] = [
+    "password",
+    "password1",
+    "password123",
+    "123456789",
+    "12345678",
+    "qwertyuiop",
+    "qwerty123",
+    "iloveyou",
+    "letmein123",
+    "welcome123",
+    "admin123",
+    "abc12345",
+];
+
+/// How hard a password is to guess, from weakest to strongest.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::PasswordStrength;
+///
+/// assert!(PasswordStrength::Strong > PasswordStrength::Fair);
+/// assert_eq!(PasswordStrength::Fair.level(), 2);
+/// assert_eq!(PasswordStrength::Fair.to_string(), "fair");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    VeryWeak,
+    Weak,
+    Fair,
+    Strong,
+    VeryStrong,
+}
+
+impl PasswordStrength {
+    /// The highest [`level`](Self::level).
+    pub const MAX_LEVEL: u8 = 4;
+
+    /// Returns the strength as a number from 0 (very weak) to [`Self::MAX_LEVEL`].
+    #[inline]
+    #[must_use]
+    pub const fn level(self) -> u8 {
+        match self {
+            Self::VeryWeak => 0,
+            Self::Weak => 1,
+            Self::Fair => 2,
+            Self::Strong => 3,
+            Self::VeryStrong => 4,
+        }
+    }
+
+    /// Returns a lowercase, human-readable name.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::VeryWeak => "very weak",
+            Self::Weak => "weak",
+            Self::Fair => "fair",
+            Self::Strong => "strong",
+            Self::VeryStrong => "very strong",
+        }
+    }
+}
+
+impl fmt::Display for PasswordStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Scores plaintext passwords and decides whether they are acceptable.
+///
+/// A password earns points for length (reaching the minimum, 12 and 16
+/// characters) and for each kind of character it mixes beyond the first:
+/// lowercase, uppercase, digits and symbols. Passwords shorter than the
+/// minimum never score above [`PasswordStrength::Weak`], and well-known
+/// passwords or ones built from one or two repeated characters are always
+/// [`PasswordStrength::VeryWeak`].
+///
+/// Unlike [`StrongPassword`](crate::StrongPassword), which enforces a fixed
+/// recipe, the policy grades passwords so interfaces can give feedback
+/// while the user types.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{PasswordPolicy, PasswordStrength};
+///
+/// let policy = PasswordPolicy::default();
+///
+/// assert_eq!(policy.score("password123"), PasswordStrength::VeryWeak);
+/// assert_eq!(policy.score("Sunflower7"), PasswordStrength::Fair);
+/// assert_eq!(policy.score("correct horse battery staple"), PasswordStrength::Strong);
+/// assert!(policy.accepts("Sunflower7"));
+/// assert!(!policy.accepts("sunflower"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    min_length: usize,
+    min_strength: PasswordStrength,
+}
+
+impl PasswordPolicy {
+    /// Creates a policy requiring at least `min_length` characters and a
+    /// score of at least `min_strength`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{PasswordPolicy, PasswordStrength};
+    ///
+    /// let strict = PasswordPolicy::new(12, PasswordStrength::Strong);
+    /// assert!(!strict.accepts("Sunflower7"));
+    /// assert!(strict.accepts("Sunflower-Meadow-7"));
+    /// ```
+    #[must_use]
+    pub const fn new(min_length: usize, min_strength: PasswordStrength) -> Self {
+        Self {
+            min_length,
+            min_strength,
+        }
+    }
+
+    /// Returns the minimum number of characters.
+    #[inline]
+    #[must_use]
+    pub const fn min_length(&self) -> usize {
+        self.min_length
+    }
+
+    /// Returns the lowest score the policy accepts.
+    #[inline]
+    #[must_use]
+    pub const fn min_strength(&self) -> PasswordStrength {
+        self.min_strength
+    }
+
+    /// Grades a plaintext password.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{PasswordPolicy, PasswordStrength};
+    ///
+    /// let policy = PasswordPolicy::default();
+    /// assert_eq!(policy.score(""), PasswordStrength::VeryWeak);
+    /// assert_eq!(policy.score("Tr0ub4dor&3-Xyz!"), PasswordStrength::VeryStrong);
+    /// ```
+    #[must_use]
+    pub fn score(&self, password: &str) -> PasswordStrength {
+        let length = password.chars().count();
+        if length == 0 || is_common(password) || distinct_chars(password) <= 2 {
+            return PasswordStrength::VeryWeak;
+        }
+
+        let length_points = [self.min_length, 12, 16]
+            .iter()
+            .filter(|&&threshold| length >= threshold)
+            .count();
+        let variety_points = character_classes(password).saturating_sub(1);
+        let strength = match length_points + variety_points {
+            0 | 1 => PasswordStrength::VeryWeak,
+            2 => PasswordStrength::Weak,
+            3 => PasswordStrength::Fair,
+            4 => PasswordStrength::Strong,
+            _ => PasswordStrength::VeryStrong,
+        };
+
+        if length < self.min_length {
+            strength.min(PasswordStrength::Weak)
+        } else {
+            strength
+        }
+    }
+
+    /// Returns `true` if the password scores at least the policy's minimum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::PasswordPolicy;
+    ///
+    /// assert!(PasswordPolicy::default().accepts("Sunflower7"));
+    /// assert!(!PasswordPolicy::default().accepts("Sun7"));
+    /// ```
+    #[must_use]
+    pub fn accepts(&self, password: &str) -> bool {
+        self.score(password) >= self.min_strength
+    }
+}
+
+impl Default for PasswordPolicy {
+    /// At least eight characters scoring [`PasswordStrength::Fair`].
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_LENGTH, PasswordStrength::Fair)
+    }
+}
+
+fn is_common(password: &str) -> bool {
+    let lowered = password.to_lowercase();
+    COMMON_PASSWORDS.contains(&lowered.as_str())
+}
+
+fn distinct_chars(password: &str) -> usize {
+    let mut chars: Vec<char> = password.chars().collect();
+    chars.sort_unstable();
+    chars.dedup();
+    chars.len()
+}
+
+/// Counts how many of lowercase, uppercase, digits and symbols appear.
+fn character_classes(password: &str) -> usize {
+    let checks: [fn(&char) -> bool; 4] = [
+        |c| c.is_lowercase(),
+        |c| c.is_uppercase(),
+        |c| c.is_ascii_digit(),
+        |c| !c.is_alphanumeric(),
+    ];
+    checks
+        .iter()
+        .filter(|check| password.chars().any(|c| check(&c)))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod score {
+        use super::*;
+
+        #[test]
+        fn test_common_and_repetitive_passwords_are_very_weak() {
+            let policy = PasswordPolicy::default();
+
+            assert_eq!(policy.score("Password123"), PasswordStrength::VeryWeak);
+            assert_eq!(policy.score("aaaaaaaaaaaaaaaaaaaa"), PasswordStrength::VeryWeak);
+            assert_eq!(policy.score("abababababababab"), PasswordStrength::VeryWeak);
+        }
+
+        #[test]
+        fn test_length_and_variety_raise_the_score() {
+            let policy = PasswordPolicy::default();
+
+            assert_eq!(policy.score("sunflower"), PasswordStrength::VeryWeak);
+            assert_eq!(policy.score("Sunflower"), PasswordStrength::Weak);
+            assert_eq!(policy.score("Sunflower7"), PasswordStrength::Fair);
+            assert_eq!(policy.score("Sunflower7meadow"), PasswordStrength::VeryStrong);
+        }
+
+        #[test]
+        fn test_short_passwords_are_capped_at_weak() {
+            let policy = PasswordPolicy::default();
+
+            assert_eq!(policy.score("S7n!x"), PasswordStrength::Weak);
+        }
+
+        #[test]
+        fn test_min_length_counts_characters_not_bytes() {
+            let policy = PasswordPolicy::new(4, PasswordStrength::Weak);
+
+            assert_eq!(policy.score("ñandú"), PasswordStrength::VeryWeak);
+            assert_eq!(policy.score("Ñandú"), PasswordStrength::Weak);
+        }
+    }
+
+    mod accepts {
+        use super::*;
+
+        #[test]
+        fn test_uses_minimum_strength() {
+            let lenient = PasswordPolicy::new(8, PasswordStrength::Weak);
+            let strict = PasswordPolicy::new(8, PasswordStrength::Strong);
+
+            assert!(lenient.accepts("Sunflower"));
+            assert!(!strict.accepts("Sunflower7"));
+        }
+    }
+}
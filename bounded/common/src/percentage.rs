@@ -0,0 +1,331 @@
+use std::cmp::Ordering;
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Percentage validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PercentageError {
+    #[error("Percentage must be a finite number")]
+    NotFinite,
+
+    #[error("Percentage must be between 0 and 100")]
+    OutOfRange,
+}
+
+/// A share of a whole, from 0 to 100.
+///
+/// The value is never NaN, which makes `Percentage` totally ordered. It is
+/// displayed in whole points rounded down, so anything short of the whole
+/// never shows as 100%.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Percentage;
+///
+/// let half = Percentage::new(50.0).unwrap();
+/// assert_eq!(half.value(), 50.0);
+/// assert_eq!(half.to_string(), "50%");
+///
+/// assert_eq!(Percentage::from_fraction(2, 3).to_string(), "66%");
+/// assert_eq!(Percentage::clamped(130.0), Percentage::FULL);
+/// assert!(Percentage::new(-1.0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percentage(f64);
+
+impl Percentage {
+    pub const ZERO: Self = Self(0.0);
+    pub const FULL: Self = Self(100.0);
+
+    /// Creates a new `Percentage` with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PercentageError::NotFinite` for NaN or infinite values, or
+    /// `PercentageError::OutOfRange` if the value is not between 0 and 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// assert!(Percentage::new(0.0).is_ok());
+    /// assert!(Percentage::new(100.0).is_ok());
+    /// assert!(Percentage::new(100.5).is_err());
+    /// assert!(Percentage::new(f64::NAN).is_err());
+    /// ```
+    pub fn new(value: f64) -> Result<Self, PercentageError> {
+        if !value.is_finite() {
+            return Err(PercentageError::NotFinite);
+        }
+        match (0.0..=100.0).contains(&value) {
+            true => Ok(Self(value)),
+            false => Err(PercentageError::OutOfRange),
+        }
+    }
+
+    /// Creates a `Percentage`, forcing the value into range.
+    ///
+    /// NaN becomes zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// assert_eq!(Percentage::clamped(-5.0), Percentage::ZERO);
+    /// assert_eq!(Percentage::clamped(f64::NAN), Percentage::ZERO);
+    /// assert_eq!(Percentage::clamped(42.5).value(), 42.5);
+    /// ```
+    #[must_use]
+    pub fn clamped(value: f64) -> Self {
+        if value.is_nan() {
+            return Self::ZERO;
+        }
+        Self(value.clamp(0.0, 100.0))
+    }
+
+    /// Returns the share `part` is of `whole`, clamped to 100.
+    ///
+    /// An empty whole counts as zero percent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// assert_eq!(Percentage::from_fraction(1, 4).value(), 25.0);
+    /// assert_eq!(Percentage::from_fraction(3, 0), Percentage::ZERO);
+    /// ```
+    #[must_use]
+    pub fn from_fraction(part: u64, whole: u64) -> Self {
+        match whole {
+            0 => Self::ZERO,
+            whole => Self::clamped(part as f64 * 100.0 / whole as f64),
+        }
+    }
+
+    /// Returns the average of the percentages, or zero if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// let values = [Percentage::ZERO, Percentage::FULL];
+    /// assert_eq!(Percentage::average(values).value(), 50.0);
+    /// assert_eq!(Percentage::average([]), Percentage::ZERO);
+    /// ```
+    #[must_use]
+    pub fn average(values: impl IntoIterator<Item = Self>) -> Self {
+        let (total, count) = values
+            .into_iter()
+            .fold((0.0, 0u32), |(total, count), value| (total + value.0, count + 1));
+        match count {
+            0 => Self::ZERO,
+            count => Self::clamped(total / f64::from(count)),
+        }
+    }
+
+    /// Returns the value, from 0.0 to 100.0.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the value as a ratio, from 0.0 to 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// assert_eq!(Percentage::new(25.0).unwrap().ratio(), 0.25);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ratio(&self) -> f64 {
+        self.0 / 100.0
+    }
+
+    /// Returns the value in hundredths of a point, from 0 to 10 000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// assert_eq!(Percentage::new(12.5).unwrap().basis_points(), 1_250);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn basis_points(&self) -> u32 {
+        (self.0 * 100.0).round() as u32
+    }
+
+    /// Returns this share of a whole number of units, rounded half up.
+    ///
+    /// The rate is taken to two decimals and the share is worked out in
+    /// integer arithmetic, so money amounts of any size come out exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// let vat = Percentage::new(19.0).unwrap();
+    /// assert_eq!(vat.share_of(4_999), 950);
+    /// assert_eq!(Percentage::new(50.0).unwrap().share_of(999), 500);
+    /// ```
+    #[must_use]
+    pub fn share_of(&self, amount: u64) -> u64 {
+        let share = (u128::from(amount) * u128::from(self.basis_points()) + 5_000) / 10_000;
+        u64::try_from(share).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the value in whole points, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Percentage;
+    ///
+    /// assert_eq!(Percentage::new(99.9).unwrap().whole_points(), 99);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn whole_points(&self) -> u8 {
+        self.0.floor() as u8
+    }
+
+    /// Returns true if the percentage is exactly 100.
+    #[inline]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.0 >= 100.0
+    }
+}
+
+impl Eq for Percentage {}
+
+impl PartialOrd for Percentage {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Percentage {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Default for Percentage {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.whole_points())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_bounds_are_inclusive() {
+            assert_eq!(Percentage::new(0.0), Ok(Percentage::ZERO));
+            assert_eq!(Percentage::new(100.0), Ok(Percentage::FULL));
+        }
+
+        #[test]
+        fn test_out_of_range_and_non_finite_values_are_rejected() {
+            assert_eq!(Percentage::new(-0.1), Err(PercentageError::OutOfRange));
+            assert_eq!(Percentage::new(100.1), Err(PercentageError::OutOfRange));
+            assert_eq!(Percentage::new(f64::NAN), Err(PercentageError::NotFinite));
+            assert_eq!(Percentage::new(f64::INFINITY), Err(PercentageError::NotFinite));
+        }
+    }
+
+    mod conversions {
+        use super::*;
+
+        #[test]
+        fn test_clamped_forces_values_into_range() {
+            assert_eq!(Percentage::clamped(250.0), Percentage::FULL);
+            assert_eq!(Percentage::clamped(f64::NEG_INFINITY), Percentage::ZERO);
+        }
+
+        #[test]
+        fn test_from_fraction_caps_part_larger_than_whole() {
+            assert_eq!(Percentage::from_fraction(5, 4), Percentage::FULL);
+            assert_eq!(Percentage::from_fraction(1, 2).value(), 50.0);
+        }
+
+        #[test]
+        fn test_average_of_values() {
+            let values = [25.0, 50.0, 75.0].map(Percentage::clamped);
+
+            assert_eq!(Percentage::average(values).value(), 50.0);
+        }
+    }
+
+    mod share_of {
+        use super::*;
+
+        #[test]
+        fn test_rounds_half_up() {
+            let half = Percentage::new(50.0).unwrap();
+
+            assert_eq!(half.share_of(1), 1);
+            assert_eq!(half.share_of(3), 2);
+            assert_eq!(Percentage::new(10.0).unwrap().share_of(14), 1);
+            assert_eq!(Percentage::new(10.0).unwrap().share_of(15), 2);
+        }
+
+        #[test]
+        fn test_keeps_two_decimals_of_the_rate() {
+            assert_eq!(Percentage::new(12.34).unwrap().basis_points(), 1_234);
+            assert_eq!(Percentage::new(12.34).unwrap().share_of(10_000), 1_234);
+        }
+
+        #[test]
+        fn test_exact_on_large_amounts() {
+            let third = Percentage::new(33.33).unwrap();
+
+            assert_eq!(Percentage::FULL.share_of(u64::MAX), u64::MAX);
+            assert_eq!(third.share_of(9_007_199_254_740_993), 3_002_099_511_605_173);
+            assert_eq!(Percentage::ZERO.share_of(u64::MAX), 0);
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn test_almost_full_is_not_shown_as_full() {
+            let almost = Percentage::from_fraction(999, 1000);
+
+            assert_eq!(almost.to_string(), "99%");
+            assert!(!almost.is_full());
+        }
+
+        #[test]
+        fn test_ordering_follows_value() {
+            let mut values = [75.0, 10.0, 50.0].map(Percentage::clamped);
+            values.sort();
+
+            assert_eq!(values.map(|value| value.whole_points()), [10, 50, 75]);
+        }
+    }
+}
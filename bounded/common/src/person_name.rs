@@ -9,11 +9,48 @@ pub enum PersonNameError {
     NameError(#[from] NameError),
 }
 
+/// How a person's name is built and in which order its parts are written.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{NameFormat, PersonName};
+///
+/// let name = PersonName::new(
+///     "María".to_string(),
+///     None,
+///     "García".to_string(),
+///     Some("Rodríguez".to_string())
+/// ).unwrap();
+///
+/// assert_eq!(name.display(NameFormat::Hispanic), "María García Rodríguez");
+/// assert_eq!(name.display(NameFormat::SingleSurname), "María García");
+/// assert_eq!(name.display(NameFormat::Mononym), "María");
+/// assert_eq!(name.display(NameFormat::EastAsian), "García Rodríguez María");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NameFormat {
+    /// Given names, then the paternal and maternal surnames.
+    #[default]
+    Hispanic,
+    /// Given names, then a single surname.
+    SingleSurname,
+    /// One name and no surname.
+    Mononym,
+    /// The family name first, then the given names.
+    EastAsian,
+}
+
 /// Represents a person's name with first, optional middle, last, and optional second last name components.
 ///
 /// All name components are validated to be non-empty and are automatically trimmed.
 /// Name length must be between 1 and 100 characters (inclusive) after trimming.
 ///
+/// Each name remembers its [`NameFormat`], which decides how
+/// [`full_name`](Self::full_name) writes it. [`PersonName::new`] builds the
+/// two-surname Hispanic form; the other formats have their own constructors.
+///
 /// # Examples
 ///
 /// ```
@@ -39,8 +76,9 @@ pub enum PersonNameError {
 pub struct PersonName {
     first_name: Name,
     middle_name: Option<Name>,
-    last_name: Name,
+    last_name: Option<Name>,
     second_last_name: Option<Name>,
+    format: NameFormat,
 }
 
 impl PersonName {
@@ -84,8 +122,95 @@ impl PersonName {
         Ok(Self {
             first_name: Name::new(first_name)?,
             middle_name: middle_name.map(Name::new).transpose()?,
-            last_name: Name::new(last_name)?,
+            last_name: Some(Name::new(last_name)?),
             second_last_name: second_last_name.map(Name::new).transpose()?,
+            format: NameFormat::Hispanic,
+        })
+    }
+
+    /// Creates a name with given names and exactly one surname.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any component is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{NameFormat, PersonName};
+    ///
+    /// let name = PersonName::single_surname(
+    ///     "John".to_string(),
+    ///     Some("Michael".to_string()),
+    ///     "Doe".to_string(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(name.format(), NameFormat::SingleSurname);
+    /// assert_eq!(name.full_name(), "John Michael Doe");
+    /// ```
+    pub fn single_surname(
+        first_name: String,
+        middle_name: Option<String>,
+        surname: String,
+    ) -> Result<Self, PersonNameError> {
+        Ok(Self {
+            format: NameFormat::SingleSurname,
+            ..Self::new(first_name, middle_name, surname, None)?
+        })
+    }
+
+    /// Creates a name for someone known by a single name.
+    ///
+    /// The name is stored as the first name; there is no last name.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the name is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::PersonName;
+    ///
+    /// let name = PersonName::mononym("Suharto".to_string()).unwrap();
+    ///
+    /// assert_eq!(name.full_name(), "Suharto");
+    /// assert_eq!(name.last_name(), None);
+    /// ```
+    pub fn mononym(name: String) -> Result<Self, PersonNameError> {
+        Ok(Self {
+            first_name: Name::new(name)?,
+            middle_name: None,
+            last_name: None,
+            second_last_name: None,
+            format: NameFormat::Mononym,
+        })
+    }
+
+    /// Creates a name written family name first.
+    ///
+    /// The family name is stored as the last name and the given name as the
+    /// first name, so accessors mean the same thing in every format.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either name is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::PersonName;
+    ///
+    /// let name = PersonName::east_asian("Wang".to_string(), "Xiaoming".to_string()).unwrap();
+    ///
+    /// assert_eq!(name.full_name(), "Wang Xiaoming");
+    /// assert_eq!(name.first_name(), "Xiaoming");
+    /// assert_eq!(name.last_name(), Some("Wang"));
+    /// ```
+    pub fn east_asian(family_name: String, given_name: String) -> Result<Self, PersonNameError> {
+        Ok(Self {
+            format: NameFormat::EastAsian,
+            ..Self::new(given_name, None, family_name, None)?
         })
     }
 
@@ -129,7 +254,7 @@ impl PersonName {
         self.middle_name.as_deref()
     }
 
-    /// Returns the last name.
+    /// Returns the last name, or `None` for a mononym.
     ///
     /// # Examples
     ///
@@ -137,12 +262,12 @@ impl PersonName {
     /// use education_platform_common::PersonName;
     ///
     /// let name = PersonName::new("John".to_string(), None, "Doe".to_string(), None).unwrap();
-    /// assert_eq!(name.last_name(), "Doe");
+    /// assert_eq!(name.last_name(), Some("Doe"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn last_name(&self) -> &str {
-        &self.last_name
+    pub fn last_name(&self) -> Option<&str> {
+        self.last_name.as_deref()
     }
 
     /// Returns the second last name if present.
@@ -169,9 +294,17 @@ impl PersonName {
         self.second_last_name.as_deref()
     }
 
+    /// Returns the format the name was created with.
+    #[inline]
+    #[must_use]
+    pub const fn format(&self) -> NameFormat {
+        self.format
+    }
+
     /// Returns the full name formatted as a single string.
     ///
-    /// Includes all name components separated by spaces.
+    /// Includes all name components separated by spaces, in the order of the
+    /// name's own format.
     ///
     /// # Examples
     ///
@@ -196,16 +329,119 @@ impl PersonName {
     /// ```
     #[must_use]
     pub fn full_name(&self) -> String {
-        let mut parts = vec![self.first_name.as_str()];
-        if let Some(middle) = &self.middle_name {
-            parts.push(middle.as_str());
-        }
-        parts.push(self.last_name.as_str());
-        if let Some(second_last) = &self.second_last_name {
-            parts.push(second_last.as_str());
-        }
+        self.display(self.format)
+    }
+
+    /// Writes the name following `format`, whatever format it was created with.
+    ///
+    /// `SingleSurname` drops the second surname, `Mononym` keeps only the
+    /// first name, and `EastAsian` puts the surnames before the given names.
+    /// Missing components are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{NameFormat, PersonName};
+    ///
+    /// let name = PersonName::east_asian("Wang".to_string(), "Xiaoming".to_string()).unwrap();
+    /// assert_eq!(name.display(NameFormat::SingleSurname), "Xiaoming Wang");
+    ///
+    /// let mononym = PersonName::mononym("Suharto".to_string()).unwrap();
+    /// assert_eq!(mononym.display(NameFormat::EastAsian), "Suharto");
+    /// ```
+    #[must_use]
+    pub fn display(&self, format: NameFormat) -> String {
+        let given = self.given_names();
+        let surnames = match format {
+            NameFormat::Hispanic | NameFormat::EastAsian => self.surnames(),
+            NameFormat::SingleSurname => self.last_name.iter().map(Name::as_str).collect(),
+            NameFormat::Mononym => Vec::new(),
+        };
+        let parts = match format {
+            NameFormat::EastAsian => [surnames, given].concat(),
+            NameFormat::Hispanic | NameFormat::SingleSurname => [given, surnames].concat(),
+            NameFormat::Mononym => vec![self.first_name.as_str()],
+        };
         parts.join(" ")
     }
+
+    /// Returns the first letter of each word of the full name, e.g. "M. G. R.".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::PersonName;
+    ///
+    /// let name = PersonName::new(
+    ///     "María".to_string(),
+    ///     None,
+    ///     "García".to_string(),
+    ///     Some("Rodríguez".to_string())
+    /// ).unwrap();
+    /// assert_eq!(name.initials(), "M. G. R.");
+    ///
+    /// let name = PersonName::east_asian("Wang".to_string(), "Xiaoming".to_string()).unwrap();
+    /// assert_eq!(name.initials(), "W. X.");
+    /// ```
+    #[must_use]
+    pub fn initials(&self) -> String {
+        self.full_name()
+            .split_whitespace()
+            .filter_map(|word| word.chars().next())
+            .map(|initial| format!("{initial}."))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns a key for sorting people alphabetically by surname.
+    ///
+    /// The key is the surnames, a comma, and the given names, lowercased so
+    /// capitalization doesn't affect the order. Mononyms sort by their name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::PersonName;
+    ///
+    /// let name = PersonName::new(
+    ///     "María".to_string(),
+    ///     None,
+    ///     "García".to_string(),
+    ///     Some("Rodríguez".to_string())
+    /// ).unwrap();
+    /// assert_eq!(name.sort_key(), "garcía rodríguez, maría");
+    ///
+    /// let mut names = vec![
+    ///     PersonName::new("Ana".to_string(), None, "Torres".to_string(), None).unwrap(),
+    ///     PersonName::east_asian("Wang".to_string(), "Xiaoming".to_string()).unwrap(),
+    ///     PersonName::new("Luis".to_string(), None, "Pérez".to_string(), None).unwrap(),
+    /// ];
+    /// names.sort_by_key(PersonName::sort_key);
+    /// assert_eq!(names[0].full_name(), "Luis Pérez");
+    /// ```
+    #[must_use]
+    pub fn sort_key(&self) -> String {
+        let given = self.given_names().join(" ");
+        let key = match self.surnames().as_slice() {
+            [] => given,
+            surnames => format!("{}, {}", surnames.join(" "), given),
+        };
+        key.to_lowercase()
+    }
+
+    fn given_names(&self) -> Vec<&str> {
+        let mut given = vec![self.first_name.as_str()];
+        given.extend(self.middle_name.as_deref());
+        given
+    }
+
+    fn surnames(&self) -> Vec<&str> {
+        self.last_name
+            .iter()
+            .chain(&self.second_last_name)
+            .map(Name::as_str)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -225,7 +461,7 @@ mod tests {
         let name = result.unwrap();
         assert_eq!(name.first_name(), "John");
         assert_eq!(name.middle_name(), Some("Michael"));
-        assert_eq!(name.last_name(), "Doe");
+        assert_eq!(name.last_name(), Some("Doe"));
     }
 
     #[test]
@@ -236,7 +472,7 @@ mod tests {
         let name = result.unwrap();
         assert_eq!(name.first_name(), "Jane");
         assert_eq!(name.middle_name(), None);
-        assert_eq!(name.last_name(), "Smith");
+        assert_eq!(name.last_name(), Some("Smith"));
     }
 
     #[test]
@@ -252,7 +488,7 @@ mod tests {
         let name = result.unwrap();
         assert_eq!(name.first_name(), "John");
         assert_eq!(name.middle_name(), Some("Michael"));
-        assert_eq!(name.last_name(), "Doe");
+        assert_eq!(name.last_name(), Some("Doe"));
     }
 
     #[test]
@@ -558,7 +794,7 @@ mod tests {
         assert!(result.is_ok());
         let name = result.unwrap();
         assert_eq!(name.first_name(), "María");
-        assert_eq!(name.last_name(), "García");
+        assert_eq!(name.last_name(), Some("García"));
         assert_eq!(name.second_last_name(), Some("Rodríguez"));
         assert_eq!(name.full_name(), "María García Rodríguez");
     }
@@ -583,4 +819,130 @@ mod tests {
 
         assert_eq!(name.full_name(), "Juan Carlos García Rodríguez");
     }
+
+    #[test]
+    fn test_new_uses_hispanic_format() {
+        let name = PersonName::new("Jane".to_string(), None, "Smith".to_string(), None).unwrap();
+
+        assert_eq!(name.format(), NameFormat::Hispanic);
+    }
+
+    #[test]
+    fn test_single_surname_keeps_middle_name() {
+        let name = PersonName::single_surname(
+            "John".to_string(),
+            Some("Michael".to_string()),
+            "Doe".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(name.format(), NameFormat::SingleSurname);
+        assert_eq!(name.second_last_name(), None);
+        assert_eq!(name.full_name(), "John Michael Doe");
+    }
+
+    #[test]
+    fn test_mononym_has_only_first_name() {
+        let name = PersonName::mononym("  Suharto ".to_string()).unwrap();
+
+        assert_eq!(name.first_name(), "Suharto");
+        assert_eq!(name.middle_name(), None);
+        assert_eq!(name.last_name(), None);
+        assert_eq!(name.full_name(), "Suharto");
+    }
+
+    #[test]
+    fn test_mononym_with_empty_name_returns_error() {
+        assert!(PersonName::mononym("   ".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_east_asian_writes_family_name_first() {
+        let name = PersonName::east_asian("Wang".to_string(), "Xiaoming".to_string()).unwrap();
+
+        assert_eq!(name.first_name(), "Xiaoming");
+        assert_eq!(name.last_name(), Some("Wang"));
+        assert_eq!(name.full_name(), "Wang Xiaoming");
+    }
+
+    #[test]
+    fn test_east_asian_with_empty_family_name_returns_error() {
+        assert!(PersonName::east_asian("".to_string(), "Xiaoming".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_display_in_each_format() {
+        let name = PersonName::new(
+            "Juan".to_string(),
+            Some("Carlos".to_string()),
+            "García".to_string(),
+            Some("Rodríguez".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(name.display(NameFormat::Hispanic), "Juan Carlos García Rodríguez");
+        assert_eq!(name.display(NameFormat::SingleSurname), "Juan Carlos García");
+        assert_eq!(name.display(NameFormat::Mononym), "Juan");
+        assert_eq!(name.display(NameFormat::EastAsian), "García Rodríguez Juan Carlos");
+    }
+
+    #[test]
+    fn test_display_mononym_ignores_surname_formats() {
+        let name = PersonName::mononym("Suharto".to_string()).unwrap();
+
+        assert_eq!(name.display(NameFormat::Hispanic), "Suharto");
+        assert_eq!(name.display(NameFormat::EastAsian), "Suharto");
+    }
+
+    #[test]
+    fn test_initials_follow_full_name() {
+        let name = PersonName::new(
+            "Juan".to_string(),
+            Some("Carlos".to_string()),
+            "García".to_string(),
+            None,
+        )
+        .unwrap();
+        let mononym = PersonName::mononym("Suharto".to_string()).unwrap();
+
+        assert_eq!(name.initials(), "J. C. G.");
+        assert_eq!(mononym.initials(), "S.");
+    }
+
+    #[test]
+    fn test_sort_key_puts_surnames_first() {
+        let name = PersonName::single_surname(
+            "John".to_string(),
+            Some("Michael".to_string()),
+            "Doe".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(name.sort_key(), "doe, john michael");
+    }
+
+    #[test]
+    fn test_sort_key_matches_across_formats() {
+        let hispanic =
+            PersonName::new("Xiaoming".to_string(), None, "Wang".to_string(), None).unwrap();
+        let east_asian =
+            PersonName::east_asian("Wang".to_string(), "Xiaoming".to_string()).unwrap();
+
+        assert_eq!(hispanic.sort_key(), east_asian.sort_key());
+    }
+
+    #[test]
+    fn test_sort_key_ignores_case() {
+        let lower = PersonName::new("ana".to_string(), None, "torres".to_string(), None).unwrap();
+        let upper = PersonName::new("ANA".to_string(), None, "TORRES".to_string(), None).unwrap();
+
+        assert_eq!(lower.sort_key(), upper.sort_key());
+    }
+
+    #[test]
+    fn test_sort_key_for_mononym_is_the_name() {
+        let name = PersonName::mononym("Suharto".to_string()).unwrap();
+
+        assert_eq!(name.sort_key(), "suharto");
+    }
 }
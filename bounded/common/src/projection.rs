@@ -0,0 +1,200 @@
+use crate::DomainEventDispatcher;
+use std::sync::{Arc, RwLock};
+
+/// A read model kept up to date by folding domain events into it.
+///
+/// Projections hold denormalized views shaped for reading (dashboards,
+/// listings, reports) so aggregates don't have to answer those queries.
+/// A projection owns its state; it can always be thrown away and rebuilt
+/// by replaying the events it was built from.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Projection;
+///
+/// struct Signup {
+///     plan: &'static str,
+/// }
+///
+/// #[derive(Default)]
+/// struct PaidSignups {
+///     count: usize,
+/// }
+///
+/// impl Projection<Signup> for PaidSignups {
+///     fn apply(&mut self, event: &Signup) {
+///         if event.plan != "free" {
+///             self.count += 1;
+///         }
+///     }
+///
+///     fn reset(&mut self) {
+///         self.count = 0;
+///     }
+/// }
+///
+/// let history = [Signup { plan: "pro" }, Signup { plan: "free" }, Signup { plan: "team" }];
+/// let mut projection = PaidSignups::default();
+/// projection.rebuild(&history);
+///
+/// assert_eq!(projection.count, 2);
+/// ```
+pub trait Projection<E> {
+    /// Folds one event into the read model.
+    fn apply(&mut self, event: &E);
+
+    /// Clears the read model back to its initial, empty state.
+    fn reset(&mut self);
+
+    /// Discards the current state and replays the given events from scratch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Projection;
+    ///
+    /// #[derive(Default)]
+    /// struct Total(u32);
+    ///
+    /// impl Projection<u32> for Total {
+    ///     fn apply(&mut self, event: &u32) { self.0 += event; }
+    ///     fn reset(&mut self) { self.0 = 0; }
+    /// }
+    ///
+    /// let mut total = Total::default();
+    /// total.apply(&100);
+    /// total.rebuild(&[1, 2, 3]);
+    ///
+    /// assert_eq!(total.0, 6);
+    /// ```
+    fn rebuild<'a, I>(&mut self, events: I)
+    where
+        I: IntoIterator<Item = &'a E>,
+        E: 'a,
+    {
+        self.reset();
+        events.into_iter().for_each(|event| self.apply(event));
+    }
+}
+
+impl<E> DomainEventDispatcher<E> {
+    /// Subscribes a shared projection so it applies every event published from now on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DomainEventDispatcher, Projection};
+    /// use std::sync::{Arc, RwLock};
+    ///
+    /// #[derive(Default)]
+    /// struct Total(u32);
+    ///
+    /// impl Projection<u32> for Total {
+    ///     fn apply(&mut self, event: &u32) { self.0 += event; }
+    ///     fn reset(&mut self) { self.0 = 0; }
+    /// }
+    ///
+    /// let dispatcher = DomainEventDispatcher::<u32>::new();
+    /// let total = Arc::new(RwLock::new(Total::default()));
+    /// dispatcher.subscribe_projection(Arc::clone(&total));
+    ///
+    /// dispatcher.notify(&5);
+    /// dispatcher.notify(&7);
+    ///
+    /// assert_eq!(total.read().unwrap().0, 12);
+    /// ```
+    pub fn subscribe_projection<P>(&self, projection: Arc<RwLock<P>>)
+    where
+        P: Projection<E> + Send + Sync + 'static,
+    {
+        self.subscribe(move |event| {
+            projection
+                .write()
+                .unwrap_or_else(|e| e.into_inner())
+                .apply(event);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct EventLog {
+        seen: Vec<u32>,
+    }
+
+    impl Projection<u32> for EventLog {
+        fn apply(&mut self, event: &u32) {
+            self.seen.push(*event);
+        }
+
+        fn reset(&mut self) {
+            self.seen.clear();
+        }
+    }
+
+    mod rebuild {
+        use super::*;
+
+        #[test]
+        fn test_rebuild_replays_events_in_order() {
+            let mut log = EventLog::default();
+
+            log.rebuild(&[3, 1, 2]);
+
+            assert_eq!(log.seen, vec![3, 1, 2]);
+        }
+
+        #[test]
+        fn test_rebuild_discards_previous_state() {
+            let mut log = EventLog::default();
+            log.apply(&99);
+
+            log.rebuild(&[1]);
+
+            assert_eq!(log.seen, vec![1]);
+        }
+
+        #[test]
+        fn test_rebuild_with_no_events_resets() {
+            let mut log = EventLog::default();
+            log.apply(&99);
+
+            log.rebuild(&[]);
+
+            assert!(log.seen.is_empty());
+        }
+    }
+
+    mod subscribe_projection {
+        use super::*;
+
+        #[test]
+        fn test_projection_receives_published_events() {
+            let dispatcher = DomainEventDispatcher::<u32>::new();
+            let log = Arc::new(RwLock::new(EventLog::default()));
+
+            dispatcher.subscribe_projection(Arc::clone(&log));
+            dispatcher.notify(&1);
+            dispatcher.notify(&2);
+
+            assert_eq!(log.read().unwrap().seen, vec![1, 2]);
+            assert_eq!(dispatcher.observer_count(), 1);
+        }
+
+        #[test]
+        fn test_projection_misses_events_published_before_subscribing() {
+            let dispatcher = DomainEventDispatcher::<u32>::new();
+            let log = Arc::new(RwLock::new(EventLog::default()));
+
+            dispatcher.notify(&1);
+            dispatcher.subscribe_projection(Arc::clone(&log));
+            dispatcher.notify(&2);
+
+            assert_eq!(log.read().unwrap().seen, vec![2]);
+        }
+    }
+}
@@ -0,0 +1,512 @@
+use crate::{Date, DateTime, Duration, TimeRange};
+use chrono::Weekday;
+use thiserror::Error;
+
+const MINUTES_PER_DAY: u64 = 24 * 60;
+
+/// Error types for Schedule validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ScheduleError {
+    #[error("Start time {hour:02}:{minute:02} is not a valid time of day")]
+    StartTimeNotValid { hour: u32, minute: u32 },
+
+    #[error("Slot duration must be different from zero")]
+    DurationIsZero,
+
+    #[error("Slot must end by midnight of the day it starts")]
+    SlotEndsAfterMidnight,
+
+    #[error("Slots on {weekday} overlap")]
+    SlotsOverlap { weekday: Weekday },
+}
+
+/// A slot that repeats every week on the same day and time.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use education_platform_common::{Duration, WeeklySlot};
+///
+/// let slot = WeeklySlot::new(Weekday::Tue, 18, 30, Duration::from_minutes(90)).unwrap();
+///
+/// assert_eq!(slot.weekday(), Weekday::Tue);
+/// assert_eq!(slot.start_hour(), 18);
+/// assert_eq!(slot.start_minute(), 30);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeeklySlot {
+    weekday: Weekday,
+    start_minute_of_day: u32,
+    duration: Duration,
+}
+
+impl WeeklySlot {
+    /// Creates a new `WeeklySlot` with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScheduleError::StartTimeNotValid` if the hour or minute is out of range.
+    /// Returns `ScheduleError::DurationIsZero` if the duration is zero.
+    /// Returns `ScheduleError::SlotEndsAfterMidnight` if the slot runs into the next day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Duration, WeeklySlot};
+    ///
+    /// assert!(WeeklySlot::new(Weekday::Mon, 9, 0, Duration::from_hours(1)).is_ok());
+    /// assert!(WeeklySlot::new(Weekday::Mon, 24, 0, Duration::from_hours(1)).is_err());
+    /// assert!(WeeklySlot::new(Weekday::Mon, 23, 30, Duration::from_hours(1)).is_err());
+    /// ```
+    pub fn new(
+        weekday: Weekday,
+        hour: u32,
+        minute: u32,
+        duration: Duration,
+    ) -> Result<Self, ScheduleError> {
+        if hour > 23 || minute > 59 {
+            return Err(ScheduleError::StartTimeNotValid { hour, minute });
+        }
+
+        if duration.is_zero() {
+            return Err(ScheduleError::DurationIsZero);
+        }
+
+        let start_minute_of_day = hour * 60 + minute;
+        let end_second_of_day = u64::from(start_minute_of_day) * 60 + duration.total_seconds();
+        if end_second_of_day > MINUTES_PER_DAY * 60 {
+            return Err(ScheduleError::SlotEndsAfterMidnight);
+        }
+
+        Ok(Self {
+            weekday,
+            start_minute_of_day,
+            duration,
+        })
+    }
+
+    /// Returns the day of the week the slot repeats on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Duration, WeeklySlot};
+    ///
+    /// let slot = WeeklySlot::new(Weekday::Fri, 9, 0, Duration::from_hours(1)).unwrap();
+    /// assert_eq!(slot.weekday(), Weekday::Fri);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// Returns the hour the slot starts at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Duration, WeeklySlot};
+    ///
+    /// let slot = WeeklySlot::new(Weekday::Fri, 9, 15, Duration::from_hours(1)).unwrap();
+    /// assert_eq!(slot.start_hour(), 9);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn start_hour(&self) -> u32 {
+        self.start_minute_of_day / 60
+    }
+
+    /// Returns the minute past the hour the slot starts at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Duration, WeeklySlot};
+    ///
+    /// let slot = WeeklySlot::new(Weekday::Fri, 9, 15, Duration::from_hours(1)).unwrap();
+    /// assert_eq!(slot.start_minute(), 15);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn start_minute(&self) -> u32 {
+        self.start_minute_of_day % 60
+    }
+
+    /// Returns how long each occurrence lasts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Duration, WeeklySlot};
+    ///
+    /// let slot = WeeklySlot::new(Weekday::Fri, 9, 0, Duration::from_minutes(50)).unwrap();
+    /// assert_eq!(slot.duration().total_seconds(), 3000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the occurrence of the slot on the given date, if it falls on the slot's weekday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Date, DateTime, Duration, WeeklySlot};
+    ///
+    /// let slot = WeeklySlot::new(Weekday::Mon, 18, 0, Duration::from_hours(1)).unwrap();
+    /// let monday = Date::new(2024, 12, 9).unwrap();
+    ///
+    /// let occurrence = slot.occurrence_on(monday).unwrap();
+    /// assert_eq!(occurrence.start(), DateTime::new(2024, 12, 9, 18, 0, 0).unwrap());
+    ///
+    /// assert!(slot.occurrence_on(monday.add_days(1)).is_none());
+    /// ```
+    #[must_use]
+    pub fn occurrence_on(&self, date: Date) -> Option<TimeRange> {
+        if date.weekday_chrono() != self.weekday {
+            return None;
+        }
+
+        let start = DateTime::new(
+            date.year(),
+            date.month(),
+            date.day(),
+            self.start_hour(),
+            self.start_minute(),
+            0,
+        )
+        .ok()?;
+        TimeRange::starting_at(start, self.duration).ok()
+    }
+
+    fn sort_key(&self) -> (u32, u32) {
+        (self.weekday.num_days_from_monday(), self.start_minute_of_day)
+    }
+
+    fn end_second_of_day(&self) -> u64 {
+        u64::from(self.start_minute_of_day) * 60 + self.duration.total_seconds()
+    }
+}
+
+/// A recurring weekly timetable, such as class times or office hours.
+///
+/// `Schedule` is an immutable value object holding weekly slots ordered from
+/// Monday to Sunday. Slots on the same day never overlap.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use education_platform_common::{Date, Duration, Schedule, WeeklySlot};
+///
+/// let schedule = Schedule::new(vec![
+///     WeeklySlot::new(Weekday::Thu, 18, 0, Duration::from_hours(2)).unwrap(),
+///     WeeklySlot::new(Weekday::Tue, 18, 0, Duration::from_hours(2)).unwrap(),
+/// ]).unwrap();
+///
+/// assert_eq!(schedule.slots()[0].weekday(), Weekday::Tue);
+///
+/// let from = Date::new(2024, 12, 9).unwrap();
+/// let to = Date::new(2024, 12, 22).unwrap();
+/// assert_eq!(schedule.occurrences_between(from, to).len(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Schedule {
+    slots: Vec<WeeklySlot>,
+}
+
+impl Schedule {
+    /// Creates a new `Schedule`, ordering the slots through the week.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScheduleError::SlotsOverlap` if two slots on the same day overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Duration, Schedule, WeeklySlot};
+    ///
+    /// let nine = WeeklySlot::new(Weekday::Mon, 9, 0, Duration::from_hours(2)).unwrap();
+    /// let ten = WeeklySlot::new(Weekday::Mon, 10, 0, Duration::from_hours(1)).unwrap();
+    ///
+    /// assert!(Schedule::new(vec![nine]).is_ok());
+    /// assert!(Schedule::new(vec![nine, ten]).is_err());
+    /// ```
+    pub fn new(mut slots: Vec<WeeklySlot>) -> Result<Self, ScheduleError> {
+        slots.sort_by_key(WeeklySlot::sort_key);
+
+        let overlapping = slots.windows(2).find(|pair| {
+            pair[0].weekday == pair[1].weekday
+                && pair[0].end_second_of_day() > u64::from(pair[1].start_minute_of_day) * 60
+        });
+
+        match overlapping {
+            Some(pair) => Err(ScheduleError::SlotsOverlap {
+                weekday: pair[0].weekday,
+            }),
+            None => Ok(Self { slots }),
+        }
+    }
+
+    /// Returns the slots ordered from Monday to Sunday and by start time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Schedule;
+    ///
+    /// assert!(Schedule::default().slots().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn slots(&self) -> &[WeeklySlot] {
+        &self.slots
+    }
+
+    /// Returns true if the schedule has no slots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Schedule;
+    ///
+    /// assert!(Schedule::default().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Expands the schedule into concrete occurrences between two dates, both included.
+    ///
+    /// Occurrences are returned in chronological order. An empty list is
+    /// returned when `to` is before `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    /// use education_platform_common::{Date, DateTime, Duration, Schedule, WeeklySlot};
+    ///
+    /// let schedule = Schedule::new(vec![
+    ///     WeeklySlot::new(Weekday::Wed, 17, 0, Duration::from_hours(1)).unwrap(),
+    /// ]).unwrap();
+    ///
+    /// let from = Date::new(2024, 12, 9).unwrap();
+    /// let occurrences = schedule.occurrences_between(from, from.add_days(6));
+    ///
+    /// assert_eq!(occurrences.len(), 1);
+    /// assert_eq!(occurrences[0].start(), DateTime::new(2024, 12, 11, 17, 0, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn occurrences_between(&self, from: Date, to: Date) -> Vec<TimeRange> {
+        let days = from.days_until(&to);
+
+        (0..=days)
+            .map(|offset| from.add_days(offset))
+            .flat_map(|date| {
+                self.slots
+                    .iter()
+                    .filter_map(move |slot| slot.occurrence_on(date))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(weekday: Weekday, hour: u32, minute: u32, minutes: u64) -> WeeklySlot {
+        WeeklySlot::new(weekday, hour, minute, Duration::from_minutes(minutes)).unwrap()
+    }
+
+    fn monday() -> Date {
+        Date::new(2024, 12, 9).unwrap()
+    }
+
+    mod weekly_slot {
+        use super::*;
+
+        #[test]
+        fn test_new_creates_valid_slot() {
+            let slot = slot(Weekday::Wed, 14, 45, 30);
+
+            assert_eq!(slot.weekday(), Weekday::Wed);
+            assert_eq!(slot.start_hour(), 14);
+            assert_eq!(slot.start_minute(), 45);
+            assert_eq!(slot.duration(), Duration::from_minutes(30));
+        }
+
+        #[test]
+        fn test_new_rejects_invalid_hour() {
+            let result = WeeklySlot::new(Weekday::Mon, 24, 0, Duration::from_minutes(30));
+
+            assert_eq!(
+                result.unwrap_err(),
+                ScheduleError::StartTimeNotValid { hour: 24, minute: 0 }
+            );
+        }
+
+        #[test]
+        fn test_new_rejects_invalid_minute() {
+            let result = WeeklySlot::new(Weekday::Mon, 9, 60, Duration::from_minutes(30));
+
+            assert!(matches!(result, Err(ScheduleError::StartTimeNotValid { .. })));
+        }
+
+        #[test]
+        fn test_new_rejects_zero_duration() {
+            let result = WeeklySlot::new(Weekday::Mon, 9, 0, Duration::default());
+
+            assert_eq!(result.unwrap_err(), ScheduleError::DurationIsZero);
+        }
+
+        #[test]
+        fn test_new_accepts_slot_ending_at_midnight() {
+            assert!(WeeklySlot::new(Weekday::Mon, 23, 0, Duration::from_hours(1)).is_ok());
+        }
+
+        #[test]
+        fn test_new_rejects_slot_past_midnight() {
+            let result = WeeklySlot::new(Weekday::Mon, 23, 0, Duration::from_minutes(61));
+
+            assert_eq!(result.unwrap_err(), ScheduleError::SlotEndsAfterMidnight);
+        }
+
+        #[test]
+        fn test_occurrence_on_matching_day() {
+            let occurrence = slot(Weekday::Mon, 18, 30, 90)
+                .occurrence_on(monday())
+                .unwrap();
+
+            assert_eq!(occurrence.start(), DateTime::new(2024, 12, 9, 18, 30, 0).unwrap());
+            assert_eq!(occurrence.end(), DateTime::new(2024, 12, 9, 20, 0, 0).unwrap());
+        }
+
+        #[test]
+        fn test_occurrence_on_other_day_is_none() {
+            assert!(
+                slot(Weekday::Tue, 9, 0, 60)
+                    .occurrence_on(monday())
+                    .is_none()
+            );
+        }
+    }
+
+    mod schedule {
+        use super::*;
+
+        #[test]
+        fn test_new_orders_slots_through_week() {
+            let schedule = Schedule::new(vec![
+                slot(Weekday::Sun, 9, 0, 60),
+                slot(Weekday::Mon, 18, 0, 60),
+                slot(Weekday::Mon, 9, 0, 60),
+            ])
+            .unwrap();
+
+            let order: Vec<(Weekday, u32)> = schedule
+                .slots()
+                .iter()
+                .map(|s| (s.weekday(), s.start_hour()))
+                .collect();
+            assert_eq!(order, vec![(Weekday::Mon, 9), (Weekday::Mon, 18), (Weekday::Sun, 9)]);
+        }
+
+        #[test]
+        fn test_new_accepts_back_to_back_slots() {
+            let result =
+                Schedule::new(vec![slot(Weekday::Mon, 9, 0, 60), slot(Weekday::Mon, 10, 0, 60)]);
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn test_new_rejects_overlapping_slots() {
+            let result =
+                Schedule::new(vec![slot(Weekday::Mon, 10, 0, 60), slot(Weekday::Mon, 9, 0, 61)]);
+
+            assert_eq!(
+                result.unwrap_err(),
+                ScheduleError::SlotsOverlap {
+                    weekday: Weekday::Mon
+                }
+            );
+        }
+
+        #[test]
+        fn test_same_time_on_different_days_is_allowed() {
+            let result =
+                Schedule::new(vec![slot(Weekday::Mon, 9, 0, 60), slot(Weekday::Tue, 9, 0, 60)]);
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn test_occurrences_between_are_chronological() {
+            let schedule =
+                Schedule::new(vec![slot(Weekday::Wed, 9, 0, 60), slot(Weekday::Mon, 18, 0, 60)])
+                    .unwrap();
+
+            let occurrences = schedule.occurrences_between(monday(), monday().add_days(13));
+
+            let starts: Vec<String> = occurrences.iter().map(|o| o.start().to_string()).collect();
+            assert_eq!(
+                starts,
+                vec![
+                    "2024-12-09T18:00:00",
+                    "2024-12-11T09:00:00",
+                    "2024-12-16T18:00:00",
+                    "2024-12-18T09:00:00",
+                ]
+            );
+        }
+
+        #[test]
+        fn test_occurrences_between_includes_both_ends() {
+            let schedule = Schedule::new(vec![slot(Weekday::Mon, 9, 0, 60)]).unwrap();
+
+            let occurrences = schedule.occurrences_between(monday(), monday().add_days(7));
+
+            assert_eq!(occurrences.len(), 2);
+        }
+
+        #[test]
+        fn test_occurrences_between_reversed_dates_is_empty() {
+            let schedule = Schedule::new(vec![slot(Weekday::Mon, 9, 0, 60)]).unwrap();
+
+            assert!(
+                schedule
+                    .occurrences_between(monday().add_days(7), monday())
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn test_empty_schedule() {
+            let schedule = Schedule::new(vec![]).unwrap();
+
+            assert!(schedule.is_empty());
+            assert!(
+                schedule
+                    .occurrences_between(monday(), monday().add_days(30))
+                    .is_empty()
+            );
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::Percentage;
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Score validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ScoreError {
+    #[error("Score points must be finite numbers")]
+    NotFinite,
+
+    #[error("Maximum points must be greater than zero")]
+    MaxPointsNotPositive,
+
+    #[error("Points must be between zero and the maximum")]
+    PointsOutOfRange,
+}
+
+/// Points earned out of a maximum, such as a grade or an average rating.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Score;
+///
+/// let grade = Score::new(18.0, 20.0).unwrap();
+/// assert_eq!(grade.to_string(), "18/20");
+/// assert_eq!(grade.percentage().value(), 90.0);
+///
+/// assert!(Score::new(21.0, 20.0).is_err());
+/// assert!(Score::new(1.0, 0.0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Score {
+    points: f64,
+    max_points: f64,
+}
+
+impl Score {
+    /// Creates a new `Score` with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScoreError::NotFinite` for NaN or infinite values,
+    /// `ScoreError::MaxPointsNotPositive` if the maximum is not above zero,
+    /// or `ScoreError::PointsOutOfRange` if `points` is negative or above
+    /// the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Score;
+    ///
+    /// assert!(Score::new(0.0, 10.0).is_ok());
+    /// assert!(Score::new(4.5, 5.0).is_ok());
+    /// assert!(Score::new(-1.0, 10.0).is_err());
+    /// ```
+    pub fn new(points: f64, max_points: f64) -> Result<Self, ScoreError> {
+        if !points.is_finite() || !max_points.is_finite() {
+            return Err(ScoreError::NotFinite);
+        }
+        if max_points <= 0.0 {
+            return Err(ScoreError::MaxPointsNotPositive);
+        }
+        if !(0.0..=max_points).contains(&points) {
+            return Err(ScoreError::PointsOutOfRange);
+        }
+
+        Ok(Self { points, max_points })
+    }
+
+    /// Returns the points earned.
+    #[inline]
+    #[must_use]
+    pub const fn points(&self) -> f64 {
+        self.points
+    }
+
+    /// Returns the maximum points.
+    #[inline]
+    #[must_use]
+    pub const fn max_points(&self) -> f64 {
+        self.max_points
+    }
+
+    /// Returns the points as a share of the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Score;
+    ///
+    /// assert_eq!(Score::new(3.0, 4.0).unwrap().percentage().value(), 75.0);
+    /// ```
+    #[must_use]
+    pub fn percentage(&self) -> Percentage {
+        Percentage::clamped(self.points * 100.0 / self.max_points)
+    }
+}
+
+impl Eq for Score {}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.points, self.max_points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_points_may_equal_bounds() {
+            assert!(Score::new(0.0, 5.0).is_ok());
+            assert!(Score::new(5.0, 5.0).is_ok());
+        }
+
+        #[test]
+        fn test_invalid_scores_are_rejected() {
+            assert_eq!(Score::new(f64::NAN, 5.0), Err(ScoreError::NotFinite));
+            assert_eq!(Score::new(1.0, -5.0), Err(ScoreError::MaxPointsNotPositive));
+            assert_eq!(Score::new(5.5, 5.0), Err(ScoreError::PointsOutOfRange));
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn test_fractional_points_are_shown() {
+            assert_eq!(Score::new(4.5, 5.0).unwrap().to_string(), "4.5/5");
+        }
+
+        #[test]
+        fn test_percentage_of_full_score() {
+            assert!(Score::new(7.0, 7.0).unwrap().percentage().is_full());
+        }
+    }
+}
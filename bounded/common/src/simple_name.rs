@@ -1,7 +1,12 @@
+mod blocklist;
+
+pub use blocklist::*;
+
 use crate::{Validator, ValidatorError};
 use std::fmt;
 use std::ops::Deref;
 use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
 
 const DEFAULT_MAX_LENGTH: usize = 100;
 const DEFAULT_MIN_LENGTH: usize = 1;
@@ -19,10 +24,17 @@ pub enum SimpleNameError {
 
     #[error("SimpleName contains invalid characters")]
     CharactersNotValid,
+
+    #[error("SimpleName contains a blocked word: {0}")]
+    BlockedWord(String),
 }
 
 /// Configuration for simple name validation rules.
 ///
+/// Lengths count user-perceived characters (grapheme clusters), so "é"
+/// written as `e` plus a combining accent, or an emoji with a skin tone,
+/// each count as one.
+///
 /// # Examples
 ///
 /// ```
@@ -67,14 +79,14 @@ impl SimpleNameConfig {
         SimpleNameConfigBuilder::new()
     }
 
-    /// Returns the minimum allowed length.
+    /// Returns the minimum allowed length, in graphemes.
     #[inline]
     #[must_use]
     pub const fn min_length(&self) -> usize {
         self.min_length
     }
 
-    /// Returns the maximum allowed length.
+    /// Returns the maximum allowed length, in graphemes.
     #[inline]
     #[must_use]
     pub const fn max_length(&self) -> usize {
@@ -153,6 +165,9 @@ impl Default for SimpleNameConfigBuilder {
 /// - Special: @ - _ / . : ' " ! # $ % & ( ) + , ;
 /// - **NOT allowed**: control characters (tabs, newlines), backslash, pipes
 ///
+/// Surrounding whitespace is trimmed and inner runs of spaces collapse to a
+/// single space, so titles typed with stray spacing compare equal.
+///
 /// # Examples
 ///
 /// ```
@@ -179,10 +194,11 @@ pub struct SimpleName {
 impl SimpleName {
     /// Creates a new `SimpleName` with default validation rules.
     ///
-    /// The name will be trimmed and validated to:
+    /// The name will be trimmed, have inner runs of spaces collapsed, and be
+    /// validated to:
     /// - Not be empty after trimming
-    /// - Have length >= 1 character
-    /// - Have length <= 100 characters
+    /// - Have length >= 1 grapheme
+    /// - Have length <= 100 graphemes
     /// - Contain only valid characters (alphanumeric, spaces, common punctuation)
     /// - Not contain control characters (tabs, newlines)
     ///
@@ -198,8 +214,8 @@ impl SimpleName {
     /// let name = SimpleName::new("Introduction to Rust".to_string()).unwrap();
     /// assert_eq!(name.as_str(), "Introduction to Rust");
     ///
-    /// // Whitespace is trimmed
-    /// let trimmed = SimpleName::new("  My Course  ".to_string()).unwrap();
+    /// // Whitespace is trimmed and collapsed
+    /// let trimmed = SimpleName::new("  My   Course  ".to_string()).unwrap();
     /// assert_eq!(trimmed.as_str(), "My Course");
     ///
     /// // Special characters allowed
@@ -234,19 +250,85 @@ impl SimpleName {
     /// assert!(result.is_err());
     /// ```
     pub fn with_config(name: String, config: SimpleNameConfig) -> Result<Self, SimpleNameError> {
-        let trimmed = name.trim();
+        let normalized = Self::normalize_whitespace(&name);
 
-        Validator::is_not_empty(trimmed)?;
-        Validator::has_min_length(trimmed, config.min_length)?;
-        Validator::has_max_length(trimmed, config.max_length)?;
-        Self::is_valid_simple_name(trimmed)?;
+        Validator::is_not_empty(&normalized)?;
+        Self::check_length(&normalized, config)?;
+        Self::is_valid_simple_name(&normalized)?;
 
         Ok(Self {
-            inner: trimmed.to_string(),
+            inner: normalized,
             config,
         })
     }
 
+    /// Creates a new `SimpleName` that must also pass a word blocklist.
+    ///
+    /// The blocklist runs after the regular validation, on the normalized
+    /// name, so it never sees padding or doubled spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`SimpleName::with_config`], or
+    /// `SimpleNameError::BlockedWord` with the first blocked word found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{SimpleName, SimpleNameConfig, SimpleNameError, WordBlocklist};
+    ///
+    /// let blocklist = WordBlocklist::new(["spam"]);
+    /// let config = SimpleNameConfig::default();
+    ///
+    /// assert!(SimpleName::with_blocklist("Rust Basics".to_string(), config, &blocklist).is_ok());
+    ///
+    /// let result = SimpleName::with_blocklist("Free SPAM course".to_string(), config, &blocklist);
+    /// assert_eq!(result, Err(SimpleNameError::BlockedWord("SPAM".to_string())));
+    /// ```
+    pub fn with_blocklist(
+        name: String,
+        config: SimpleNameConfig,
+        blocklist: &impl Blocklist,
+    ) -> Result<Self, SimpleNameError> {
+        let name = Self::with_config(name, config)?;
+
+        match blocklist.find_blocked(name.as_str()) {
+            Some(word) => Err(SimpleNameError::BlockedWord(word)),
+            None => Ok(name),
+        }
+    }
+
+    /// Trims the name and collapses each inner run of spaces into one.
+    ///
+    /// Control characters such as tabs and newlines are left in place so
+    /// that character validation still rejects them.
+    fn normalize_whitespace(name: &str) -> String {
+        name.trim()
+            .split(|c: char| c.is_whitespace() && !c.is_control())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn check_length(name: &str, config: SimpleNameConfig) -> Result<(), ValidatorError> {
+        let actual = name.graphemes(true).count();
+
+        if actual < config.min_length {
+            return Err(ValidatorError::MinLength {
+                min: config.min_length,
+                actual,
+            });
+        }
+        if actual > config.max_length {
+            return Err(ValidatorError::MaxLength {
+                max: config.max_length,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Validates that a name contains only allowed characters.
     ///
     /// Allowed characters:
@@ -368,6 +450,19 @@ mod tests {
             assert_eq!(result.unwrap().as_str(), "api-v1");
         }
 
+        #[test]
+        fn test_new_collapses_inner_spaces() {
+            let name = SimpleName::new("Intro  to \u{a0} Rust".to_string()).unwrap();
+            assert_eq!(name.as_str(), "Intro to Rust");
+        }
+
+        #[test]
+        fn test_collapsed_names_are_equal() {
+            let spaced = SimpleName::new(" Web   Dev ".to_string()).unwrap();
+            let tidy = SimpleName::new("Web Dev".to_string()).unwrap();
+            assert_eq!(spaced, tidy);
+        }
+
         #[test]
         fn test_new_with_empty_string_returns_error() {
             let result = SimpleName::new("".to_string());
@@ -655,6 +750,39 @@ mod tests {
             assert!(result.is_err());
         }
 
+        #[test]
+        fn test_length_counts_graphemes_not_bytes() {
+            let config = SimpleNameConfig::builder().max_length(4).build();
+
+            let name = SimpleName::with_config("Café".to_string(), config).unwrap();
+            assert_eq!(name.len(), 5);
+
+            let combining = SimpleName::with_config("Cafe\u{301}".to_string(), config);
+            assert!(combining.is_ok());
+        }
+
+        #[test]
+        fn test_emoji_sequence_counts_as_one() {
+            let config = SimpleNameConfig::builder().max_length(1).build();
+            let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+            assert!(SimpleName::with_config(family.to_string(), config).is_ok());
+        }
+
+        #[test]
+        fn test_max_length_reports_grapheme_count() {
+            let config = SimpleNameConfig::builder().max_length(3).build();
+            let result = SimpleName::with_config("ñañá".to_string(), config);
+
+            assert_eq!(
+                result,
+                Err(SimpleNameError::ValidationError(ValidatorError::MaxLength {
+                    max: 3,
+                    actual: 4
+                }))
+            );
+        }
+
         #[test]
         fn test_all_allowed_characters() {
             let all_chars = "abcXYZ123@-_/.:";
@@ -685,4 +813,56 @@ mod tests {
             assert!(matches!(result, Err(SimpleNameError::ValidationError(_))));
         }
     }
+
+    mod blocklist {
+        use super::*;
+
+        #[test]
+        fn test_with_blocklist_accepts_clean_name() {
+            let blocklist = WordBlocklist::new(["spam"]);
+            let result = SimpleName::with_blocklist(
+                "Rust for Beginners".to_string(),
+                SimpleNameConfig::default(),
+                &blocklist,
+            );
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn test_with_blocklist_rejects_blocked_word() {
+            let blocklist = WordBlocklist::new(["spam"]);
+            let result = SimpleName::with_blocklist(
+                "Best spam ever".to_string(),
+                SimpleNameConfig::default(),
+                &blocklist,
+            );
+            assert_eq!(result, Err(SimpleNameError::BlockedWord("spam".to_string())));
+        }
+
+        #[test]
+        fn test_with_blocklist_still_validates_first() {
+            let blocklist = WordBlocklist::new(["spam"]);
+            let result = SimpleName::with_blocklist(
+                "spam|eggs".to_string(),
+                SimpleNameConfig::default(),
+                &blocklist,
+            );
+            assert_eq!(result, Err(SimpleNameError::CharactersNotValid));
+        }
+
+        #[test]
+        fn test_with_blocklist_accepts_closure() {
+            let no_digits = |name: &str| {
+                name.split(' ')
+                    .find(|word| word.chars().all(char::is_numeric))
+                    .map(str::to_string)
+            };
+            let result = SimpleName::with_blocklist(
+                "Course 101".to_string(),
+                SimpleNameConfig::default(),
+                &no_digits,
+            );
+            assert_eq!(result, Err(SimpleNameError::BlockedWord("101".to_string())));
+        }
+    }
 }
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+/// A pluggable check for words that must not appear in a [`SimpleName`].
+///
+/// Any `Fn(&str) -> Option<String>` is a blocklist, so a moderation service
+/// can be wrapped in a closure; [`WordBlocklist`] covers the common case of a
+/// fixed word list.
+///
+/// [`SimpleName`]: crate::SimpleName
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Blocklist, WordBlocklist};
+///
+/// let blocklist = WordBlocklist::new(["spam"]);
+///
+/// assert_eq!(blocklist.find_blocked("Cheap SPAM here"), Some("SPAM".to_string()));
+/// assert_eq!(blocklist.find_blocked("Spamalot tickets"), None);
+/// ```
+pub trait Blocklist {
+    /// Returns the first blocked word found in `text`, as it appears there.
+    fn find_blocked(&self, text: &str) -> Option<String>;
+}
+
+impl<F> Blocklist for F
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn find_blocked(&self, text: &str) -> Option<String> {
+        self(text)
+    }
+}
+
+/// A fixed set of blocked words, matched case-insensitively as whole words.
+///
+/// Text is split on anything that isn't a letter or digit, so "spam!" and
+/// "SPAM-free" both contain "spam", while "spamalot" does not.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WordBlocklist {
+    words: HashSet<String>,
+}
+
+impl WordBlocklist {
+    /// Creates a blocklist from the given words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::WordBlocklist;
+    ///
+    /// let blocklist = WordBlocklist::new(["spam", "Scam"]);
+    /// assert_eq!(blocklist.len(), 2);
+    /// assert!(blocklist.contains("SCAM"));
+    /// ```
+    #[must_use]
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            words: words
+                .into_iter()
+                .map(|word| word.as_ref().trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if `word` is blocked, ignoring case.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    /// Returns the number of blocked words.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns `true` if no word is blocked.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl Blocklist for WordBlocklist {
+    fn find_blocked(&self, text: &str) -> Option<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .find(|word| self.contains(word))
+            .map(str::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_whole_words_only() {
+        let blocklist = WordBlocklist::new(["spam"]);
+
+        assert!(blocklist.find_blocked("spam").is_some());
+        assert!(blocklist.find_blocked("spamalot").is_none());
+        assert!(blocklist.find_blocked("antispam").is_none());
+    }
+
+    #[test]
+    fn test_ignores_case() {
+        let blocklist = WordBlocklist::new(["Spam"]);
+
+        assert_eq!(blocklist.find_blocked("no SPAM"), Some("SPAM".to_string()));
+    }
+
+    #[test]
+    fn test_splits_on_punctuation() {
+        let blocklist = WordBlocklist::new(["spam"]);
+
+        assert_eq!(blocklist.find_blocked("eggs,spam!"), Some("spam".to_string()));
+        assert_eq!(blocklist.find_blocked("spam-free"), Some("spam".to_string()));
+    }
+
+    #[test]
+    fn test_matches_accented_words() {
+        let blocklist = WordBlocklist::new(["estafa"]);
+
+        assert_eq!(blocklist.find_blocked("Curso ESTAFA"), Some("ESTAFA".to_string()));
+        assert!(blocklist.find_blocked("estafá").is_none());
+    }
+
+    #[test]
+    fn test_returns_first_blocked_word() {
+        let blocklist = WordBlocklist::new(["scam", "spam"]);
+
+        assert_eq!(blocklist.find_blocked("spam and scam"), Some("spam".to_string()));
+    }
+
+    #[test]
+    fn test_blank_words_are_ignored() {
+        let blocklist = WordBlocklist::new(["", "  ", "spam"]);
+
+        assert_eq!(blocklist.len(), 1);
+        assert!(!blocklist.is_empty());
+    }
+
+    #[test]
+    fn test_empty_blocklist_blocks_nothing() {
+        let blocklist = WordBlocklist::default();
+
+        assert!(blocklist.is_empty());
+        assert!(blocklist.find_blocked("anything goes").is_none());
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{DateTime, Duration};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for TimeRange validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeRangeError {
+    #[error("Time range start {start} must be before its end {end}")]
+    StartNotBeforeEnd { start: String, end: String },
+}
+
+/// A span of time between two instants, start inclusive and end exclusive.
+///
+/// `TimeRange` is an immutable value object that guarantees its start comes
+/// strictly before its end, so every range has a positive duration.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{DateTime, TimeRange};
+///
+/// let start = DateTime::new(2024, 3, 15, 18, 0, 0).unwrap();
+/// let end = DateTime::new(2024, 3, 15, 19, 30, 0).unwrap();
+/// let range = TimeRange::new(start, end).unwrap();
+///
+/// assert_eq!(range.duration().total_seconds(), 5400);
+/// assert_eq!(range.to_string(), "2024-03-15T18:00:00/2024-03-15T19:30:00");
+///
+/// // The end must come after the start
+/// assert!(TimeRange::new(end, start).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeRange {
+    start: DateTime,
+    end: DateTime,
+}
+
+impl TimeRange {
+    /// Creates a new `TimeRange` with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimeRangeError::StartNotBeforeEnd` if `start` is not strictly before `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 3, 15, 18, 0, 0).unwrap();
+    /// let end = DateTime::new(2024, 3, 15, 19, 0, 0).unwrap();
+    ///
+    /// assert!(TimeRange::new(start, end).is_ok());
+    /// assert!(TimeRange::new(start, start).is_err());
+    /// ```
+    pub fn new(start: DateTime, end: DateTime) -> Result<Self, TimeRangeError> {
+        match start < end {
+            true => Ok(Self { start, end }),
+            false => Err(TimeRangeError::StartNotBeforeEnd {
+                start: start.to_string(),
+                end: end.to_string(),
+            }),
+        }
+    }
+
+    /// Creates a range starting at `start` and lasting `duration`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TimeRangeError::StartNotBeforeEnd` if `duration` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, Duration, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 3, 15, 18, 0, 0).unwrap();
+    /// let range = TimeRange::starting_at(start, Duration::from_minutes(45)).unwrap();
+    ///
+    /// assert_eq!(range.end(), DateTime::new(2024, 3, 15, 18, 45, 0).unwrap());
+    /// ```
+    pub fn starting_at(start: DateTime, duration: Duration) -> Result<Self, TimeRangeError> {
+        let seconds = i64::try_from(duration.total_seconds()).unwrap_or(i64::MAX);
+        Self::new(start, start.add_seconds(seconds))
+    }
+
+    /// Returns the start of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 3, 15, 18, 0, 0).unwrap();
+    /// let end = DateTime::new(2024, 3, 15, 19, 0, 0).unwrap();
+    /// assert_eq!(TimeRange::new(start, end).unwrap().start(), start);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> DateTime {
+        self.start
+    }
+
+    /// Returns the end of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 3, 15, 18, 0, 0).unwrap();
+    /// let end = DateTime::new(2024, 3, 15, 19, 0, 0).unwrap();
+    /// assert_eq!(TimeRange::new(start, end).unwrap().end(), end);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> DateTime {
+        self.end
+    }
+
+    /// Returns the length of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 3, 15, 18, 0, 0).unwrap();
+    /// let end = DateTime::new(2024, 3, 15, 18, 0, 30).unwrap();
+    /// assert_eq!(TimeRange::new(start, end).unwrap().duration().total_seconds(), 30);
+    /// ```
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        Duration::from_seconds(self.start.seconds_until(&self.end).unsigned_abs())
+    }
+
+    /// Returns true if the instant falls inside the range.
+    ///
+    /// The start is included and the end is not, so back-to-back ranges
+    /// never both contain the same instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 3, 15, 18, 0, 0).unwrap();
+    /// let end = DateTime::new(2024, 3, 15, 19, 0, 0).unwrap();
+    /// let range = TimeRange::new(start, end).unwrap();
+    ///
+    /// assert!(range.contains(start));
+    /// assert!(!range.contains(end));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, instant: DateTime) -> bool {
+        self.start <= instant && instant < self.end
+    }
+
+    /// Returns true if the two ranges share any instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{DateTime, TimeRange};
+    ///
+    /// let at = |h| DateTime::new(2024, 3, 15, h, 0, 0).unwrap();
+    /// let morning = TimeRange::new(at(9), at(12)).unwrap();
+    /// let lunch = TimeRange::new(at(12), at(13)).unwrap();
+    /// let late_morning = TimeRange::new(at(11), at(13)).unwrap();
+    ///
+    /// assert!(!morning.overlaps(&lunch));
+    /// assert!(morning.overlaps(&late_morning));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+impl fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> DateTime {
+        DateTime::new(2024, 3, 15, hour, minute, 0).unwrap()
+    }
+
+    fn range(from: (u32, u32), to: (u32, u32)) -> TimeRange {
+        TimeRange::new(at(from.0, from.1), at(to.0, to.1)).unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_creates_valid_range() {
+            let range = TimeRange::new(at(9, 0), at(10, 0)).unwrap();
+
+            assert_eq!(range.start(), at(9, 0));
+            assert_eq!(range.end(), at(10, 0));
+        }
+
+        #[test]
+        fn test_new_rejects_equal_instants() {
+            let result = TimeRange::new(at(9, 0), at(9, 0));
+
+            assert!(matches!(result, Err(TimeRangeError::StartNotBeforeEnd { .. })));
+        }
+
+        #[test]
+        fn test_new_rejects_reversed_instants() {
+            let result = TimeRange::new(at(10, 0), at(9, 0));
+
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "Time range start 2024-03-15T10:00:00 must be before its end 2024-03-15T09:00:00"
+            );
+        }
+
+        #[test]
+        fn test_starting_at_adds_duration() {
+            let range = TimeRange::starting_at(at(23, 30), Duration::from_hours(1)).unwrap();
+
+            assert_eq!(range.end(), DateTime::new(2024, 3, 16, 0, 30, 0).unwrap());
+        }
+
+        #[test]
+        fn test_starting_at_rejects_zero_duration() {
+            assert!(TimeRange::starting_at(at(9, 0), Duration::default()).is_err());
+        }
+    }
+
+    mod queries {
+        use super::*;
+
+        #[test]
+        fn test_duration_spans_whole_range() {
+            assert_eq!(range((9, 15), (10, 45)).duration(), Duration::from_minutes(90));
+        }
+
+        #[test]
+        fn test_contains_is_start_inclusive_end_exclusive() {
+            let range = range((9, 0), (10, 0));
+
+            assert!(range.contains(at(9, 0)));
+            assert!(range.contains(at(9, 59)));
+            assert!(!range.contains(at(10, 0)));
+            assert!(!range.contains(at(8, 59)));
+        }
+
+        #[test]
+        fn test_overlaps_partial() {
+            assert!(range((9, 0), (10, 0)).overlaps(&range((9, 30), (11, 0))));
+        }
+
+        #[test]
+        fn test_overlaps_nested() {
+            assert!(range((9, 0), (12, 0)).overlaps(&range((10, 0), (11, 0))));
+        }
+
+        #[test]
+        fn test_adjacent_ranges_do_not_overlap() {
+            assert!(!range((9, 0), (10, 0)).overlaps(&range((10, 0), (11, 0))));
+        }
+
+        #[test]
+        fn test_disjoint_ranges_do_not_overlap() {
+            assert!(!range((9, 0), (10, 0)).overlaps(&range((14, 0), (15, 0))));
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn test_display_uses_iso_interval() {
+            assert_eq!(
+                range((9, 0), (10, 0)).to_string(),
+                "2024-03-15T09:00:00/2024-03-15T10:00:00"
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Validator, ValidatorError};
+use crate::{UrlPolicy, Validator, ValidatorError};
 use regex::Regex;
 use std::fmt;
 use std::ops::Deref;
@@ -33,14 +33,14 @@ pub enum UrlError {
     #[error("URL format is invalid")]
     FormatNotValid,
 
-    #[error("URL scheme must be http or https")]
-    SchemeNotValid,
+    #[error("URL scheme '{0}' is not allowed")]
+    SchemeNotValid(String),
 
     #[error("URL host is missing or invalid")]
     HostNotValid,
 
-    #[error("URL is too long (max 2048 characters)")]
-    TooLong,
+    #[error("URL is too long (max {max} characters)")]
+    TooLong { max: usize },
 
     #[error("must start with http:// or https://")]
     StartWithHTTP,
@@ -83,7 +83,7 @@ pub struct Url {
 }
 
 impl Url {
-    const MAX_LENGTH: usize = 2048;
+    pub const MAX_LENGTH: usize = 2048;
 
     /// Creates a new `Url` with validation.
     ///
@@ -127,6 +127,46 @@ impl Url {
         })
     }
 
+    /// Creates a `Url` that must also satisfy a policy.
+    ///
+    /// The URL is first validated as by [`Url::new`]; its scheme must then
+    /// be allowed by the policy and its length must fit the policy's limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UrlError` if validation fails, `UrlError::SchemeNotValid` if
+    /// the scheme is not whitelisted, or `UrlError::TooLong` if the URL is
+    /// longer than the policy allows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Url, UrlError, UrlPolicy};
+    ///
+    /// let policy = UrlPolicy::default();
+    /// assert!(Url::with_policy("https://cdn.example.com/a.mp4".to_string(), &policy).is_ok());
+    /// assert_eq!(
+    ///     Url::with_policy("http://cdn.example.com/a.mp4".to_string(), &policy),
+    ///     Err(UrlError::SchemeNotValid("http".to_string()))
+    /// );
+    /// ```
+    pub fn with_policy(url: String, policy: &UrlPolicy) -> Result<Self, UrlError> {
+        let url = Self::new(url)?;
+
+        let loopback_http =
+            policy.allows_loopback_http() && url.scheme() == "http" && url.is_loopback();
+        if !policy.allows_scheme(url.scheme()) && !loopback_http {
+            return Err(UrlError::SchemeNotValid(url.scheme().to_string()));
+        }
+        if url.inner.chars().count() > policy.max_length() {
+            return Err(UrlError::TooLong {
+                max: policy.max_length(),
+            });
+        }
+
+        Ok(url)
+    }
+
     /// Validates that a URL follows proper HTTP/HTTPS format.
     ///
     /// # Examples
@@ -227,6 +267,45 @@ impl Url {
         self.inner.starts_with("https://")
     }
 
+    /// Returns true if the host is the local machine (`localhost` or
+    /// `127.0.0.1`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Url;
+    ///
+    /// assert!(Url::new("http://localhost:8080/cb".to_string()).unwrap().is_loopback());
+    /// assert!(!Url::new("http://localhost.example.com".to_string()).unwrap().is_loopback());
+    /// ```
+    #[must_use]
+    pub fn is_loopback(&self) -> bool {
+        matches!(self.host(), "localhost" | "127.0.0.1")
+    }
+
+    /// Encodes parameters as a query string, percent-encoding everything
+    /// but the unreserved characters of RFC 3986.
+    ///
+    /// The result suits both a URL query and an
+    /// `application/x-www-form-urlencoded` body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Url;
+    ///
+    /// let query = Url::encode_query(&[("scope", "openid email"), ("next", "/a?b=c")]);
+    /// assert_eq!(query, "scope=openid%20email&next=%2Fa%3Fb%3Dc");
+    /// ```
+    #[must_use]
+    pub fn encode_query(parameters: &[(&str, &str)]) -> String {
+        parameters
+            .iter()
+            .map(|(key, value)| format!("{}={}", encode_component(key), encode_component(value)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
     /// Returns the URL as a string slice.
     #[inline]
     #[must_use]
@@ -242,6 +321,18 @@ impl Url {
     }
 }
 
+fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
 impl Deref for Url {
     type Target = str;
 
@@ -476,6 +567,46 @@ mod tests {
         }
     }
 
+    mod is_loopback {
+        use super::*;
+
+        #[test]
+        fn test_local_hosts() {
+            for url in ["http://localhost", "http://127.0.0.1:3000/cb"] {
+                assert!(Url::new(url.to_string()).unwrap().is_loopback(), "{url}");
+            }
+        }
+
+        #[test]
+        fn test_lookalike_hosts_are_not_local() {
+            for url in ["http://localhost.example.com", "http://127.0.0.10"] {
+                assert!(!Url::new(url.to_string()).unwrap().is_loopback(), "{url}");
+            }
+        }
+    }
+
+    mod encode_query {
+        use super::*;
+
+        #[test]
+        fn test_unreserved_characters_are_kept() {
+            assert_eq!(Url::encode_query(&[("a-b", "x_y.z~1")]), "a-b=x_y.z~1");
+        }
+
+        #[test]
+        fn test_reserved_and_multibyte_characters_are_encoded() {
+            assert_eq!(
+                Url::encode_query(&[("redirect_uri", "https://a.example/cb"), ("name", "Ñu")]),
+                "redirect_uri=https%3A%2F%2Fa.example%2Fcb&name=%C3%91u"
+            );
+        }
+
+        #[test]
+        fn test_no_parameters_is_empty() {
+            assert_eq!(Url::encode_query(&[]), "");
+        }
+    }
+
     mod real_world_examples {
         use super::*;
 
@@ -0,0 +1,168 @@
+use crate::Url;
+
+/// Which URLs a context accepts, checked by [`Url::with_policy`].
+///
+/// The default policy only allows `https` and the general `Url` length
+/// limit; [`UrlPolicy::web`] also allows plain `http`, and
+/// [`UrlPolicy::allow_loopback_http`] allows it for the local machine only.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::UrlPolicy;
+///
+/// let policy = UrlPolicy::default();
+/// assert!(policy.allows_scheme("https"));
+/// assert!(!policy.allows_scheme("http"));
+///
+/// let local = UrlPolicy::web().with_max_length(200);
+/// assert!(local.allows_scheme("http"));
+/// assert_eq!(local.max_length(), 200);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlPolicy {
+    schemes: Vec<String>,
+    max_length: usize,
+    loopback_http: bool,
+}
+
+impl UrlPolicy {
+    /// Returns a policy allowing both `http` and `https`.
+    #[must_use]
+    pub fn web() -> Self {
+        Self::default().allow_scheme("http")
+    }
+
+    /// Adds a scheme to the whitelist. Schemes are compared ignoring ASCII case.
+    #[must_use]
+    pub fn allow_scheme(mut self, scheme: &str) -> Self {
+        let scheme = scheme.to_ascii_lowercase();
+        if !self.schemes.contains(&scheme) {
+            self.schemes.push(scheme);
+        }
+        self
+    }
+
+    /// Allows plain `http` for `localhost` and `127.0.0.1`, so development
+    /// servers work without opening `http` to every host.
+    #[must_use]
+    pub const fn allow_loopback_http(mut self) -> Self {
+        self.loopback_http = true;
+        self
+    }
+
+    /// Lowers the maximum length; it never exceeds [`Url::MAX_LENGTH`].
+    #[must_use]
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length.min(Url::MAX_LENGTH);
+        self
+    }
+
+    /// Returns true if the scheme is whitelisted.
+    #[must_use]
+    pub fn allows_scheme(&self, scheme: &str) -> bool {
+        self.schemes
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+    }
+
+    /// Returns true if plain `http` is allowed for the local machine.
+    #[inline]
+    #[must_use]
+    pub const fn allows_loopback_http(&self) -> bool {
+        self.loopback_http
+    }
+
+    /// Returns the maximum URL length in characters.
+    #[inline]
+    #[must_use]
+    pub const fn max_length(&self) -> usize {
+        self.max_length
+    }
+}
+
+impl Default for UrlPolicy {
+    fn default() -> Self {
+        Self {
+            schemes: vec!["https".to_string()],
+            max_length: Url::MAX_LENGTH,
+            loopback_http: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UrlError;
+
+    mod policy {
+        use super::*;
+
+        #[test]
+        fn test_default_allows_only_https() {
+            let policy = UrlPolicy::default();
+
+            assert!(policy.allows_scheme("https"));
+            assert!(policy.allows_scheme("HTTPS"));
+            assert!(!policy.allows_scheme("http"));
+            assert_eq!(policy.max_length(), Url::MAX_LENGTH);
+        }
+
+        #[test]
+        fn test_allowing_a_scheme_twice_keeps_one_entry() {
+            let policy = UrlPolicy::web().allow_scheme("HTTP");
+
+            assert_eq!(policy, UrlPolicy::web());
+        }
+
+        #[test]
+        fn test_max_length_cannot_exceed_url_limit() {
+            let policy = UrlPolicy::default().with_max_length(10_000);
+
+            assert_eq!(policy.max_length(), Url::MAX_LENGTH);
+        }
+    }
+
+    mod with_policy {
+        use super::*;
+
+        #[test]
+        fn test_web_policy_accepts_http() {
+            let url =
+                Url::with_policy("http://localhost:8080/v.mp4".to_string(), &UrlPolicy::web());
+
+            assert_eq!(url.unwrap().host(), "localhost");
+        }
+
+        #[test]
+        fn test_url_longer_than_policy_is_rejected() {
+            let policy = UrlPolicy::default().with_max_length(25);
+
+            assert_eq!(
+                Url::with_policy("https://example.com/long-path".to_string(), &policy),
+                Err(UrlError::TooLong { max: 25 })
+            );
+        }
+
+        #[test]
+        fn test_loopback_http_allows_only_local_hosts() {
+            let policy = UrlPolicy::default().allow_loopback_http();
+
+            assert!(Url::with_policy("http://localhost:8080/cb".to_string(), &policy).is_ok());
+            assert!(Url::with_policy("http://127.0.0.1/cb".to_string(), &policy).is_ok());
+            assert_eq!(
+                Url::with_policy("http://learn.example.com/cb".to_string(), &policy),
+                Err(UrlError::SchemeNotValid("http".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_malformed_url_fails_before_policy() {
+            assert_eq!(
+                Url::with_policy("https://".to_string(), &UrlPolicy::default()),
+                Err(UrlError::FormatNotValid)
+            );
+        }
+    }
+}
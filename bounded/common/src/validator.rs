@@ -1,3 +1,9 @@
+mod rule;
+mod validate;
+
+pub use rule::*;
+pub use validate::*;
+
 use thiserror::Error;
 
 /// Error types for validation failures.
@@ -22,6 +28,43 @@ pub enum ValidatorError {
         max: usize,
         actual: usize,
     },
+
+    #[error("{0}")]
+    Invalid(String),
+
+    #[error("{field}: {error}")]
+    Field {
+        field: String,
+        error: Box<ValidatorError>,
+    },
+
+    #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    Failures(Vec<ValidatorError>),
+}
+
+impl ValidatorError {
+    /// Returns every individual failure, unpacking `Failures`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Rule, Rules, ValidatorError};
+    ///
+    /// let rule = Rules::all_of()
+    ///     .rule(Rules::not_empty())
+    ///     .rule(Rules::min_length(3));
+    /// let error = rule.check("").unwrap_err();
+    ///
+    /// assert_eq!(error.failures().len(), 2);
+    /// assert_eq!(ValidatorError::EmptyValue.failures(), [&ValidatorError::EmptyValue]);
+    /// ```
+    #[must_use]
+    pub fn failures(&self) -> Vec<&Self> {
+        match self {
+            Self::Failures(failures) => failures.iter().flat_map(Self::failures).collect(),
+            other => vec![other],
+        }
+    }
 }
 
 /// Service for common string validation operations.
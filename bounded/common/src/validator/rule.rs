@@ -0,0 +1,379 @@
+use super::{Validator, ValidatorError};
+use std::borrow::Borrow;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A single check on a value, composable with other rules.
+///
+/// Any `Fn(&T) -> Result<(), ValidatorError>` is a rule, so the existing
+/// [`Validator`] functions and ad-hoc closures plug in directly; [`Rules`]
+/// has ready-made ones for strings.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Rule, Rules, ValidatorError};
+///
+/// let title = Rules::not_empty().and(Rules::max_length(10));
+///
+/// assert!(title.check("Rust").is_ok());
+/// assert_eq!(title.check("  "), Err(ValidatorError::EmptyValue));
+/// assert!(title.check("A very long title").is_err());
+/// ```
+pub trait Rule<T: ?Sized> {
+    /// Checks `value` against the rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `ValidatorError` describing why the value was rejected.
+    fn check(&self, value: &T) -> Result<(), ValidatorError>;
+
+    /// Requires both rules to pass, stopping at the first failure.
+    fn and<R: Rule<T>>(self, other: R) -> And<Self, R>
+    where
+        Self: Sized,
+    {
+        And {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Requires either rule to pass; if both fail, reports the second failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Rule, Rules};
+    ///
+    /// let code = Rules::length_between(3, 3).or(Rules::length_between(5, 5));
+    ///
+    /// assert!(code.check("ABC").is_ok());
+    /// assert!(code.check("ABCDE").is_ok());
+    /// assert!(code.check("ABCD").is_err());
+    /// ```
+    fn or<R: Rule<T>>(self, other: R) -> Or<Self, R>
+    where
+        Self: Sized,
+    {
+        Or {
+            first: self,
+            second: other,
+        }
+    }
+}
+
+impl<T: ?Sized, F> Rule<T> for F
+where
+    F: Fn(&T) -> Result<(), ValidatorError>,
+{
+    fn check(&self, value: &T) -> Result<(), ValidatorError> {
+        self(value)
+    }
+}
+
+/// Rule built by [`Rule::and`].
+#[derive(Debug, Clone, Copy)]
+pub struct And<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<T: ?Sized, A: Rule<T>, B: Rule<T>> Rule<T> for And<A, B> {
+    fn check(&self, value: &T) -> Result<(), ValidatorError> {
+        self.first.check(value)?;
+        self.second.check(value)
+    }
+}
+
+/// Rule built by [`Rule::or`].
+#[derive(Debug, Clone, Copy)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<T: ?Sized, A: Rule<T>, B: Rule<T>> Rule<T> for Or<A, B> {
+    fn check(&self, value: &T) -> Result<(), ValidatorError> {
+        self.first
+            .check(value)
+            .or_else(|_| self.second.check(value))
+    }
+}
+
+/// Rule built by [`Rules::optional`].
+pub struct Optional<R, U: ?Sized> {
+    rule: R,
+    checks: PhantomData<fn(&U)>,
+}
+
+impl<T, U, R> Rule<Option<T>> for Optional<R, U>
+where
+    T: Borrow<U>,
+    U: ?Sized,
+    R: Rule<U>,
+{
+    fn check(&self, value: &Option<T>) -> Result<(), ValidatorError> {
+        value
+            .as_ref()
+            .map_or(Ok(()), |inner| self.rule.check(inner.borrow()))
+    }
+}
+
+/// Rule built by [`Rules::all_of`].
+///
+/// Unlike [`Rule::and`], it runs every rule and reports all failures together
+/// as `ValidatorError::Failures`.
+pub struct AllOf<T: ?Sized> {
+    rules: Vec<Box<dyn Rule<T>>>,
+}
+
+impl<T: ?Sized> AllOf<T> {
+    /// Adds a rule to run.
+    #[must_use]
+    pub fn rule(mut self, rule: impl Rule<T> + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+}
+
+impl<T: ?Sized> Rule<T> for AllOf<T> {
+    fn check(&self, value: &T) -> Result<(), ValidatorError> {
+        let failures: Vec<ValidatorError> = self
+            .rules
+            .iter()
+            .filter_map(|rule| rule.check(value).err())
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidatorError::Failures(failures))
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for AllOf<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AllOf")
+            .field("rules", &self.rules.len())
+            .finish()
+    }
+}
+
+/// Ready-made rules mirroring the [`Validator`] checks, plus the
+/// combinators that don't fit as methods.
+pub struct Rules;
+
+impl Rules {
+    /// Rule form of [`Validator::is_not_empty`].
+    #[must_use]
+    pub fn not_empty() -> impl Rule<str> + Copy {
+        Validator::is_not_empty
+    }
+
+    /// Rule form of [`Validator::has_min_length`].
+    #[must_use]
+    pub fn min_length(min: usize) -> impl Rule<str> + Copy {
+        move |value: &str| Validator::has_min_length(value, min)
+    }
+
+    /// Rule form of [`Validator::has_max_length`].
+    #[must_use]
+    pub fn max_length(max: usize) -> impl Rule<str> + Copy {
+        move |value: &str| Validator::has_max_length(value, max)
+    }
+
+    /// Rule form of [`Validator::has_length_between`].
+    #[must_use]
+    pub fn length_between(min: usize, max: usize) -> impl Rule<str> + Copy {
+        move |value: &str| Validator::has_length_between(value, min, max)
+    }
+
+    /// Applies `rule` to a value that may be absent; `None` always passes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Rule, Rules};
+    ///
+    /// let nickname = Rules::optional(Rules::not_empty().and(Rules::max_length(8)));
+    ///
+    /// assert!(nickname.check(&None::<String>).is_ok());
+    /// assert!(nickname.check(&Some("Ana".to_string())).is_ok());
+    /// assert!(nickname.check(&Some(" ".to_string())).is_err());
+    /// ```
+    #[must_use]
+    pub const fn optional<R: Rule<U>, U: ?Sized>(rule: R) -> Optional<R, U> {
+        Optional {
+            rule,
+            checks: PhantomData,
+        }
+    }
+
+    /// Starts a rule that runs every added rule and reports every failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Rule, Rules, ValidatorError};
+    ///
+    /// let password = Rules::all_of()
+    ///     .rule(Rules::min_length(8))
+    ///     .rule(|value: &str| {
+    ///         if value.chars().any(|c| c.is_ascii_digit()) {
+    ///             Ok(())
+    ///         } else {
+    ///             Err(ValidatorError::Invalid("needs a digit".to_string()))
+    ///         }
+    ///     });
+    ///
+    /// assert!(password.check("hunter42x").is_ok());
+    /// assert_eq!(password.check("short").unwrap_err().failures().len(), 2);
+    /// ```
+    #[must_use]
+    pub fn all_of<T: ?Sized>() -> AllOf<T> {
+        AllOf { rules: Vec::new() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod combinators {
+        use super::*;
+
+        #[test]
+        fn test_and_stops_at_first_failure() {
+            let rule = Rules::not_empty().and(Rules::min_length(3));
+
+            assert_eq!(rule.check(""), Err(ValidatorError::EmptyValue));
+            assert_eq!(rule.check("ab"), Err(ValidatorError::MinLength { min: 3, actual: 2 }));
+            assert!(rule.check("abc").is_ok());
+        }
+
+        #[test]
+        fn test_or_passes_when_either_passes() {
+            let rule = Rules::max_length(2).or(Rules::min_length(5));
+
+            assert!(rule.check("ab").is_ok());
+            assert!(rule.check("abcde").is_ok());
+        }
+
+        #[test]
+        fn test_or_reports_second_failure() {
+            let rule = Rules::max_length(2).or(Rules::min_length(5));
+
+            assert_eq!(
+                rule.check("abc"),
+                Err(ValidatorError::MinLength { min: 5, actual: 3 })
+            );
+        }
+
+        #[test]
+        fn test_closure_is_a_rule() {
+            let positive = |value: &i32| {
+                if *value > 0 {
+                    Ok(())
+                } else {
+                    Err(ValidatorError::EmptyValue)
+                }
+            };
+
+            assert!(positive.check(&1).is_ok());
+            assert!(positive.check(&0).is_err());
+        }
+    }
+
+    mod optional {
+        use super::*;
+
+        #[test]
+        fn test_none_passes() {
+            let rule = Rules::optional(Rules::not_empty());
+
+            assert!(rule.check(&None::<String>).is_ok());
+        }
+
+        #[test]
+        fn test_some_is_checked() {
+            let rule = Rules::optional(Rules::not_empty());
+
+            assert_eq!(rule.check(&Some(String::new())), Err(ValidatorError::EmptyValue));
+            assert!(rule.check(&Some("x".to_string())).is_ok());
+        }
+
+        #[test]
+        fn test_works_with_borrowed_values() {
+            let rule = Rules::optional(Rules::max_length(3));
+
+            assert!(rule.check(&Some("abc")).is_ok());
+            assert!(rule.check(&Some("abcd")).is_err());
+        }
+    }
+
+    mod all_of {
+        use super::*;
+
+        #[test]
+        fn test_empty_all_of_passes() {
+            assert!(Rules::all_of::<str>().check("anything").is_ok());
+        }
+
+        #[test]
+        fn test_reports_every_failure() {
+            let rule = Rules::all_of()
+                .rule(Rules::not_empty())
+                .rule(Rules::min_length(2))
+                .rule(Rules::max_length(10));
+
+            assert_eq!(
+                rule.check(""),
+                Err(ValidatorError::Failures(vec![
+                    ValidatorError::EmptyValue,
+                    ValidatorError::MinLength { min: 2, actual: 0 },
+                ]))
+            );
+        }
+
+        #[test]
+        fn test_single_failure_is_still_wrapped() {
+            let rule = Rules::all_of()
+                .rule(Rules::not_empty())
+                .rule(Rules::max_length(1));
+
+            assert_eq!(
+                rule.check("ab"),
+                Err(ValidatorError::Failures(vec![ValidatorError::MaxLength {
+                    max: 1,
+                    actual: 2
+                }]))
+            );
+        }
+
+        #[test]
+        fn test_failures_flattens_nested_groups() {
+            let inner = Rules::all_of()
+                .rule(Rules::not_empty())
+                .rule(Rules::min_length(2));
+            let outer = Rules::all_of().rule(inner).rule(Rules::min_length(3));
+
+            let error = outer.check("").unwrap_err();
+
+            assert_eq!(error.failures().len(), 3);
+        }
+
+        #[test]
+        fn test_display_joins_failures() {
+            let rule = Rules::all_of()
+                .rule(Rules::not_empty())
+                .rule(Rules::min_length(2));
+
+            assert_eq!(
+                rule.check("").unwrap_err().to_string(),
+                "Value cannot be empty; Length must be at least 2 characters, but got 0"
+            );
+        }
+    }
+}
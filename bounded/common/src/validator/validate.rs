@@ -0,0 +1,190 @@
+use super::{Rule, ValidatorError};
+
+/// A type that can check all of its own fields at once.
+///
+/// The single argument-free method keeps the trait simple to implement by
+/// hand today and to generate from field attributes later. Implementations
+/// usually build a [`Validation`] listing each field with its rule.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Rule, Rules, Validate, Validation, ValidatorError};
+///
+/// struct CourseDraft {
+///     name: String,
+///     summary: Option<String>,
+/// }
+///
+/// impl Validate for CourseDraft {
+///     fn validate(&self) -> Result<(), ValidatorError> {
+///         Validation::new()
+///             .field("name", self.name.as_str(), &Rules::not_empty().and(Rules::max_length(100)))
+///             .field("summary", &self.summary, &Rules::optional(Rules::max_length(500)))
+///             .finish()
+///     }
+/// }
+///
+/// let draft = CourseDraft { name: "Rust 101".to_string(), summary: None };
+/// assert!(draft.validate().is_ok());
+///
+/// let blank = CourseDraft { name: " ".to_string(), summary: Some("x".repeat(501)) };
+/// let error = blank.validate().unwrap_err();
+/// assert_eq!(error.failures().len(), 2);
+/// ```
+pub trait Validate {
+    /// Checks every field, reporting all failures together.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidatorError::Failures` with one `ValidatorError::Field`
+    /// per rejected field.
+    fn validate(&self) -> Result<(), ValidatorError>;
+}
+
+/// Collects field failures for a [`Validate`] implementation.
+///
+/// Every field is checked, even after one fails, so callers can show all
+/// problems at once instead of one per attempt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Validation {
+    failures: Vec<ValidatorError>,
+}
+
+impl Validation {
+    /// Creates an empty validation.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { failures: Vec::new() }
+    }
+
+    /// Checks `value` against `rule`, recording any failure under `field`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Rules, Validation};
+    ///
+    /// let result = Validation::new()
+    ///     .field("title", "", &Rules::not_empty())
+    ///     .finish();
+    ///
+    /// assert_eq!(result.unwrap_err().to_string(), "title: Value cannot be empty");
+    /// ```
+    #[must_use]
+    pub fn field<T: ?Sized>(mut self, field: &str, value: &T, rule: &impl Rule<T>) -> Self {
+        if let Err(error) = rule.check(value) {
+            self.failures.push(ValidatorError::Field {
+                field: field.to_string(),
+                error: Box::new(error),
+            });
+        }
+        self
+    }
+
+    /// Returns `true` if no field has failed so far.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Ends the validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidatorError::Failures` holding every recorded field
+    /// failure, if there was any.
+    pub fn finish(self) -> Result<(), ValidatorError> {
+        if self.failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidatorError::Failures(self.failures))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rules;
+
+    struct Profile {
+        handle: String,
+        bio: Option<String>,
+    }
+
+    impl Validate for Profile {
+        fn validate(&self) -> Result<(), ValidatorError> {
+            Validation::new()
+                .field(
+                    "handle",
+                    self.handle.as_str(),
+                    &Rules::not_empty().and(Rules::max_length(8)),
+                )
+                .field("bio", &self.bio, &Rules::optional(Rules::max_length(20)))
+                .finish()
+        }
+    }
+
+    #[test]
+    fn test_valid_value_passes() {
+        let profile = Profile {
+            handle: "ana".to_string(),
+            bio: Some("Teaches Rust".to_string()),
+        };
+
+        assert!(profile.validate().is_ok());
+    }
+
+    #[test]
+    fn test_every_failed_field_is_reported() {
+        let profile = Profile {
+            handle: String::new(),
+            bio: Some("x".repeat(21)),
+        };
+
+        assert_eq!(
+            profile.validate(),
+            Err(ValidatorError::Failures(vec![
+                ValidatorError::Field {
+                    field: "handle".to_string(),
+                    error: Box::new(ValidatorError::EmptyValue),
+                },
+                ValidatorError::Field {
+                    field: "bio".to_string(),
+                    error: Box::new(ValidatorError::MaxLength { max: 20, actual: 21 }),
+                },
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_only_failed_fields_are_reported() {
+        let profile = Profile {
+            handle: "far-too-long".to_string(),
+            bio: None,
+        };
+
+        let error = profile.validate().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "handle: Length must be the at most 8 characters, but got 12"
+        );
+    }
+
+    #[test]
+    fn test_is_valid_tracks_failures() {
+        let validation = Validation::new().field("a", "x", &Rules::not_empty());
+        assert!(validation.is_valid());
+
+        let validation = validation.field("b", "", &Rules::not_empty());
+        assert!(!validation.is_valid());
+    }
+
+    #[test]
+    fn test_empty_validation_finishes_ok() {
+        assert_eq!(Validation::new().finish(), Ok(()));
+    }
+}
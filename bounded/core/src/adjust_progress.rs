@@ -0,0 +1,543 @@
+use crate::{
+    Assignment, AssignmentError, AuditAction, AuditContext, AuditEntry, Cohort, CourseProgress,
+    CourseProgressError, Notification, NotificationError, NotificationTriggers,
+};
+use education_platform_common::{DateTime, Email, Entity, Id};
+use thiserror::Error;
+
+/// Error types for administrative progress adjustments.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AdjustProgressError {
+    #[error("Progress records belong to different courses")]
+    CourseMismatch,
+
+    #[error("Cohort belongs to another course")]
+    CohortCourseMismatch,
+
+    #[error("Cannot transfer progress from {0} to itself")]
+    SameAccount(String),
+
+    #[error("Progress could not be adjusted: {0}")]
+    CourseProgressError(#[from] CourseProgressError),
+
+    #[error("Deadline could not be extended: {0}")]
+    AssignmentError(#[from] AssignmentError),
+
+    #[error("Learners could not be notified: {0}")]
+    NotificationError(#[from] NotificationError),
+}
+
+/// The result of an administrative adjustment: what to store in the audit
+/// log and what to send to the affected learners.
+#[derive(Debug, Clone)]
+pub struct AdjustmentOutcome {
+    entry: AuditEntry,
+    notifications: Vec<Notification>,
+}
+
+impl AdjustmentOutcome {
+    /// Returns the audit entry describing the adjustment.
+    #[inline]
+    #[must_use]
+    pub const fn entry(&self) -> &AuditEntry {
+        &self.entry
+    }
+
+    /// Returns the notifications for the learners whose data changed.
+    #[inline]
+    #[must_use]
+    pub fn notifications(&self) -> &[Notification] {
+        &self.notifications
+    }
+}
+
+/// Use case for admins correcting learner progress in bulk.
+///
+/// Every adjustment is all-or-nothing: the inputs are checked and the
+/// notifications rendered before anything changes. Learners who were not
+/// actually affected, such as those who had already completed the lesson,
+/// are left out of both the audit entry and the notifications.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     AdjustProgress, AuditContext, Chapter, Course, CourseProgress, Lesson,
+///     NotificationChannel, NotificationTriggers,
+/// };
+/// use education_platform_common::{DateTime, Email, Entity};
+///
+/// let lesson = Lesson::new(
+///     "Live workshop".to_string(),
+///     5400,
+///     "https://example.com/workshop.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let lesson_id = lesson.id();
+/// let chapter = Chapter::new("Workshops".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+/// let mut progresses = vec![
+///     CourseProgress::from_course(&course, "ana@example.com").unwrap(),
+///     CourseProgress::from_course(&course, "luis@example.com").unwrap(),
+/// ];
+///
+/// let context = AuditContext::new(
+///     Email::new("admin@example.com".to_string()).unwrap(),
+///     "Attended the live workshop".to_string(),
+///     DateTime::new(2025, 3, 4, 18, 0, 0).unwrap(),
+/// ).unwrap();
+/// let use_case = AdjustProgress::new(NotificationTriggers::new(NotificationChannel::Email));
+///
+/// let outcome = use_case.mark_lesson_complete(&mut progresses, lesson_id, &context).unwrap();
+///
+/// assert!(progresses.iter().all(CourseProgress::is_completed));
+/// assert_eq!(outcome.entry().affected().len(), 2);
+/// assert_eq!(outcome.notifications().len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdjustProgress {
+    triggers: NotificationTriggers,
+}
+
+impl AdjustProgress {
+    /// Creates a new use case instance rendering notifications with the given triggers.
+    #[must_use]
+    pub const fn new(triggers: NotificationTriggers) -> Self {
+        Self { triggers }
+    }
+
+    /// Marks a lesson as complete for a set of learners, e.g. after they
+    /// attended a live workshop covering it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdjustProgressError::CourseMismatch` if the progress records
+    /// belong to different courses.
+    /// Returns `AdjustProgressError::CourseProgressError` if a record does not
+    /// contain the lesson.
+    /// Returns `AdjustProgressError::NotificationError` if a notification
+    /// cannot be rendered.
+    pub fn mark_lesson_complete(
+        &self,
+        progresses: &mut [CourseProgress],
+        lesson_id: Id,
+        context: &AuditContext,
+    ) -> Result<AdjustmentOutcome, AdjustProgressError> {
+        let course_id = progresses.first().and_then(CourseProgress::course_id);
+        if progresses
+            .iter()
+            .any(|progress| progress.course_id() != course_id)
+        {
+            return Err(AdjustProgressError::CourseMismatch);
+        }
+
+        let mut affected = Vec::new();
+        let mut names = None;
+        for progress in progresses.iter() {
+            let lesson = progress
+                .lesson_progress()
+                .iter()
+                .find(|lesson| lesson.id() == lesson_id)
+                .ok_or_else(|| CourseProgressError::LessonNotFound(lesson_id.to_string()))?;
+            names.get_or_insert((progress.course_name(), lesson.lesson_name()));
+            if !lesson.is_completed() {
+                affected.push(progress.user_email().clone());
+            }
+        }
+
+        let notifications = match names {
+            Some((course_name, lesson_name)) => self.triggers.lesson_marked_complete(
+                &affected,
+                course_name.as_str(),
+                lesson_name.as_str(),
+                context.reason(),
+            )?,
+            None => Vec::new(),
+        };
+
+        for progress in progresses.iter_mut() {
+            progress.mark_lesson_completed(lesson_id, context.at())?;
+        }
+
+        let action = AuditAction::LessonMarkedComplete { course_id, lesson_id };
+        Ok(AdjustmentOutcome {
+            entry: AuditEntry::new(context, action, affected),
+            notifications,
+        })
+    }
+
+    /// Extends an assignment's due date for every member of a cohort.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdjustProgressError::CohortCourseMismatch` if the cohort
+    /// belongs to another course than the assignment.
+    /// Returns `AdjustProgressError::AssignmentError` if the new date is not
+    /// after a member's current due date.
+    /// Returns `AdjustProgressError::NotificationError` if a notification
+    /// cannot be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     AdjustProgress, Assignment, AuditContext, Cohort, NotificationChannel,
+    ///     NotificationTriggers, StudyGroup,
+    /// };
+    /// use education_platform_common::{DateTime, Email, Entity, Id};
+    ///
+    /// let course_id = Id::new();
+    /// let due_at = DateTime::new(2025, 3, 10, 23, 59, 0).unwrap();
+    /// let mut assignment = Assignment::new(course_id, "Essay".to_string(), due_at, 10).unwrap();
+    ///
+    /// let ana = Email::new("ana@example.com".to_string()).unwrap();
+    /// let mut cohort = Cohort::new(course_id, "Spring 2025").unwrap();
+    /// let group = StudyGroup::new("Team A", "Finish the course", 5).unwrap();
+    /// let group_id = group.id();
+    /// cohort.add_group(group).unwrap();
+    /// cohort.join_group(group_id, ana.clone()).unwrap();
+    ///
+    /// let context = AuditContext::new(
+    ///     Email::new("admin@example.com".to_string()).unwrap(),
+    ///     "Platform outage".to_string(),
+    ///     DateTime::new(2025, 3, 9, 12, 0, 0).unwrap(),
+    /// ).unwrap();
+    /// let until = DateTime::new(2025, 3, 12, 23, 59, 0).unwrap();
+    /// let use_case = AdjustProgress::new(NotificationTriggers::new(NotificationChannel::Email));
+    ///
+    /// let outcome = use_case
+    ///     .extend_deadline(&mut assignment, &cohort, until, "Rust Basics", &context)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(assignment.due_at_for(&ana), until);
+    /// assert_eq!(outcome.notifications().len(), 1);
+    /// ```
+    pub fn extend_deadline(
+        &self,
+        assignment: &mut Assignment,
+        cohort: &Cohort,
+        until: DateTime,
+        course_name: &str,
+        context: &AuditContext,
+    ) -> Result<AdjustmentOutcome, AdjustProgressError> {
+        if cohort.course_id() != assignment.course_id() {
+            return Err(AdjustProgressError::CohortCourseMismatch);
+        }
+
+        let mut students: Vec<Email> = Vec::new();
+        for member in cohort.groups().iter().flat_map(|group| group.members()) {
+            if !students.contains(member) {
+                students.push(member.clone());
+            }
+        }
+
+        let mut updated = assignment.clone();
+        for student in &students {
+            updated.extend_due_date(student.clone(), until)?;
+        }
+        let notifications =
+            self.triggers
+                .deadline_extended(&students, course_name, until, context.reason())?;
+        *assignment = updated;
+
+        let action = AuditAction::DeadlineExtended {
+            assignment_id: assignment.id(),
+            until,
+        };
+        Ok(AdjustmentOutcome {
+            entry: AuditEntry::new(context, action, students),
+            notifications,
+        })
+    }
+
+    /// Moves progress recorded on a duplicate account onto the learner's
+    /// primary account.
+    ///
+    /// The duplicate record is left untouched so it can be archived or
+    /// deleted separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AdjustProgressError::SameAccount` if both records belong to
+    /// the same learner.
+    /// Returns `AdjustProgressError::CourseProgressError` if the records track
+    /// different courses.
+    /// Returns `AdjustProgressError::NotificationError` if the notification
+    /// cannot be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     AdjustProgress, AuditAction, AuditContext, Chapter, Course, CourseProgress, Lesson,
+    ///     NotificationChannel, NotificationTriggers,
+    /// };
+    /// use education_platform_common::{DateTime, Email, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let mut duplicate = CourseProgress::from_course(&course, "ana.old@example.com").unwrap();
+    /// duplicate
+    ///     .mark_lesson_completed(lesson_id, DateTime::new(2025, 3, 1, 10, 0, 0).unwrap())
+    ///     .unwrap();
+    /// let mut primary = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+    ///
+    /// let context = AuditContext::new(
+    ///     Email::new("admin@example.com".to_string()).unwrap(),
+    ///     "Duplicate account".to_string(),
+    ///     DateTime::new(2025, 3, 4, 18, 0, 0).unwrap(),
+    /// ).unwrap();
+    /// let use_case = AdjustProgress::new(NotificationTriggers::new(NotificationChannel::Email));
+    ///
+    /// let outcome = use_case.transfer_progress(&duplicate, &mut primary, &context).unwrap();
+    ///
+    /// assert!(primary.is_completed());
+    /// assert!(matches!(outcome.entry().action(), AuditAction::ProgressTransferred { .. }));
+    /// ```
+    pub fn transfer_progress(
+        &self,
+        duplicate: &CourseProgress,
+        primary: &mut CourseProgress,
+        context: &AuditContext,
+    ) -> Result<AdjustmentOutcome, AdjustProgressError> {
+        let from = duplicate.user_email().clone();
+        let to = primary.user_email().clone();
+        if from == to {
+            return Err(AdjustProgressError::SameAccount(to.address().to_string()));
+        }
+
+        let mut updated = primary.clone();
+        updated.merge_from(duplicate)?;
+        let notification = self.triggers.progress_transferred(
+            &to,
+            &from,
+            primary.course_name().as_str(),
+            context.reason(),
+        )?;
+        *primary = updated;
+
+        let affected = vec![from.clone(), to.clone()];
+        let action = AuditAction::ProgressTransferred { from, to };
+        Ok(AdjustmentOutcome {
+            entry: AuditEntry::new(context, action, affected),
+            notifications: vec![notification],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Course, Lesson, NotificationChannel, StudyGroup};
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let lessons = ["Introduction", "Live workshop"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                Lesson::new(
+                    name.to_string(),
+                    1800,
+                    format!("https://example.com/{index}.mp4"),
+                    index,
+                )
+                .unwrap()
+            })
+            .collect();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, lessons).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_test_context() -> AuditContext {
+        AuditContext::new(
+            create_email("admin@example.com"),
+            "Attended the live workshop".to_string(),
+            DateTime::new(2025, 3, 4, 18, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn create_use_case() -> AdjustProgress {
+        AdjustProgress::new(NotificationTriggers::new(NotificationChannel::InApp))
+    }
+
+    mod mark_lesson_complete {
+        use super::*;
+
+        #[test]
+        fn test_learners_who_already_completed_are_not_affected() {
+            let course = create_test_course();
+            let mut progresses = vec![
+                CourseProgress::from_course(&course, "ana@example.com").unwrap(),
+                CourseProgress::from_course(&course, "luis@example.com").unwrap(),
+            ];
+            let lesson_id = progresses[0].lesson_progress()[1].id();
+            let earlier = DateTime::new(2025, 3, 1, 10, 0, 0).unwrap();
+            progresses[0]
+                .mark_lesson_completed(lesson_id, earlier)
+                .unwrap();
+
+            let outcome = create_use_case()
+                .mark_lesson_complete(&mut progresses, lesson_id, &create_test_context())
+                .unwrap();
+
+            assert_eq!(outcome.entry().affected(), &[create_email("luis@example.com")]);
+            assert_eq!(outcome.notifications().len(), 1);
+            assert_eq!(progresses[0].lesson_progress()[1].end_date(), Some(&earlier));
+        }
+
+        #[test]
+        fn test_missing_lesson_changes_nobody() {
+            let course = create_test_course();
+            let other = create_test_course();
+            let mut progresses = vec![
+                CourseProgress::from_course(&course, "ana@example.com").unwrap(),
+                CourseProgress::from_course(&other, "luis@example.com").unwrap(),
+            ];
+            let lesson_id = progresses[0].lesson_progress()[1].id();
+
+            let result = create_use_case().mark_lesson_complete(
+                &mut progresses,
+                lesson_id,
+                &create_test_context(),
+            );
+
+            assert_eq!(result.unwrap_err(), AdjustProgressError::CourseMismatch);
+            assert!(!progresses[0].lesson_progress()[1].is_completed());
+        }
+
+        #[test]
+        fn test_no_learners_records_empty_entry() {
+            let outcome = create_use_case()
+                .mark_lesson_complete(&mut [], Id::new(), &create_test_context())
+                .unwrap();
+
+            assert!(outcome.entry().affected().is_empty());
+            assert!(outcome.notifications().is_empty());
+        }
+    }
+
+    mod extend_deadline {
+        use super::*;
+
+        fn create_test_cohort(course_id: Id, members: &[&str]) -> Cohort {
+            let mut cohort = Cohort::new(course_id, "Spring 2025").unwrap();
+            let group = StudyGroup::new("Team A", "Finish the course", 10).unwrap();
+            let group_id = group.id();
+            cohort.add_group(group).unwrap();
+            for member in members {
+                cohort.join_group(group_id, create_email(member)).unwrap();
+            }
+            cohort
+        }
+
+        fn create_test_assignment(course_id: Id) -> Assignment {
+            let due_at = DateTime::new(2025, 3, 10, 23, 59, 0).unwrap();
+            Assignment::new(course_id, "Essay".to_string(), due_at, 10).unwrap()
+        }
+
+        #[test]
+        fn test_every_member_gets_the_extension() {
+            let course_id = Id::new();
+            let mut assignment = create_test_assignment(course_id);
+            let cohort = create_test_cohort(course_id, &["ana@example.com", "luis@example.com"]);
+            let until = DateTime::new(2025, 3, 12, 23, 59, 0).unwrap();
+
+            let outcome = create_use_case()
+                .extend_deadline(&mut assignment, &cohort, until, "Rust", &create_test_context())
+                .unwrap();
+
+            assert_eq!(assignment.due_at_for(&create_email("luis@example.com")), until);
+            assert_eq!(outcome.entry().affected().len(), 2);
+            assert_eq!(outcome.notifications().len(), 2);
+        }
+
+        #[test]
+        fn test_earlier_date_leaves_assignment_unchanged() {
+            let course_id = Id::new();
+            let mut assignment = create_test_assignment(course_id);
+            let cohort = create_test_cohort(course_id, &["ana@example.com"]);
+            let until = DateTime::new(2025, 3, 9, 0, 0, 0).unwrap();
+
+            let result = create_use_case().extend_deadline(
+                &mut assignment,
+                &cohort,
+                until,
+                "Rust",
+                &create_test_context(),
+            );
+
+            assert!(matches!(result, Err(AdjustProgressError::AssignmentError(_))));
+            assert_eq!(assignment.extended_due_at(&create_email("ana@example.com")), None);
+        }
+
+        #[test]
+        fn test_cohort_of_other_course_is_rejected() {
+            let mut assignment = create_test_assignment(Id::new());
+            let cohort = create_test_cohort(Id::new(), &["ana@example.com"]);
+            let until = DateTime::new(2025, 3, 12, 23, 59, 0).unwrap();
+
+            let result = create_use_case().extend_deadline(
+                &mut assignment,
+                &cohort,
+                until,
+                "Rust",
+                &create_test_context(),
+            );
+
+            assert_eq!(result.unwrap_err(), AdjustProgressError::CohortCourseMismatch);
+        }
+    }
+
+    mod transfer_progress {
+        use super::*;
+
+        #[test]
+        fn test_notifies_primary_account() {
+            let course = create_test_course();
+            let duplicate = CourseProgress::from_course(&course, "ana.old@example.com").unwrap();
+            let mut primary = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+
+            let outcome = create_use_case()
+                .transfer_progress(&duplicate, &mut primary, &create_test_context())
+                .unwrap();
+
+            assert_eq!(
+                outcome.notifications()[0].recipient(),
+                &create_email("ana@example.com")
+            );
+            assert_eq!(outcome.entry().affected().len(), 2);
+        }
+
+        #[test]
+        fn test_same_account_is_rejected() {
+            let course = create_test_course();
+            let duplicate = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let mut primary = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+
+            let result = create_use_case().transfer_progress(
+                &duplicate,
+                &mut primary,
+                &create_test_context(),
+            );
+
+            assert_eq!(
+                result.unwrap_err(),
+                AdjustProgressError::SameAccount("ana@example.com".to_string())
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod assignment;
+mod submission;
+
+pub use assignment::*;
+pub use submission::*;
@@ -0,0 +1,798 @@
+use crate::{Submission, SubmissionError};
+use education_platform_common::{DateTime, Email, Entity, Id, Score, Validator, ValidatorError};
+use thiserror::Error;
+
+/// Error types for Assignment validation and grading failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AssignmentError {
+    #[error("Assignment prompt validation failed: {0}")]
+    PromptError(#[from] ValidatorError),
+
+    #[error("Max score must be different from zero")]
+    MaxScoreIsZero,
+
+    #[error("Submission operation failed: {0}")]
+    SubmissionError(#[from] SubmissionError),
+
+    #[error("Submission does not exist")]
+    SubmissionDoesNotExist,
+
+    #[error("{0} already has a submission for this assignment")]
+    AlreadySubmitted(String),
+
+    #[error("Score {score} exceeds the maximum of {max_score}")]
+    ScoreOutOfRange { score: u32, max_score: u32 },
+
+    #[error("Extended due date {until} is not after the current due date {due_at}")]
+    ExtensionNotLater { until: String, due_at: String },
+}
+
+/// A piece of work students hand in for a course, graded out of a maximum score.
+///
+/// `Assignment` is the aggregate root for its submissions: each student has
+/// at most one, and grading goes through the assignment so scores are kept
+/// within [`Assignment::max_score`].
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Assignment, Submission, SubmissionContent};
+/// use education_platform_common::{DateTime, Entity, Id};
+///
+/// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+/// let mut assignment = Assignment::new(
+///     Id::new(),
+///     "Implement a linked list".to_string(),
+///     due_at,
+///     100,
+/// ).unwrap();
+///
+/// let content = SubmissionContent::link("https://github.com/me/list".to_string()).unwrap();
+/// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+/// let submission_id = submission.id();
+/// assignment.add_submission(submission).unwrap();
+///
+/// assignment
+///     .submit(submission_id, DateTime::new(2024, 12, 14, 20, 0, 0).unwrap())
+///     .unwrap();
+/// assignment.grade_submission(submission_id, 92, None).unwrap();
+///
+/// assert_eq!(assignment.late_submissions().count(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Assignment {
+    id: Id,
+    course_id: Id,
+    prompt: String,
+    due_at: DateTime,
+    max_score: u32,
+    submissions: Vec<Submission>,
+    extensions: Vec<(Email, DateTime)>,
+}
+
+impl Assignment {
+    pub const MAX_PROMPT_LENGTH: usize = 10_000;
+
+    /// Creates a new `Assignment` without submissions.
+    ///
+    /// The prompt is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AssignmentError::PromptError` if the prompt is empty or longer
+    /// than [`Assignment::MAX_PROMPT_LENGTH`] bytes.
+    /// Returns `AssignmentError::MaxScoreIsZero` if the max score is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    ///
+    /// assert!(Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).is_ok());
+    /// assert!(Assignment::new(Id::new(), "Essay".to_string(), due_at, 0).is_err());
+    /// ```
+    pub fn new(
+        course_id: Id,
+        prompt: String,
+        due_at: DateTime,
+        max_score: u32,
+    ) -> Result<Self, AssignmentError> {
+        Self::with_id(Id::default(), course_id, prompt, due_at, max_score, Vec::new())
+    }
+
+    /// Creates an `Assignment` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Assignment::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let assignment = Assignment::with_id(
+    ///     id,
+    ///     Id::new(),
+    ///     "Essay".to_string(),
+    ///     DateTime::new(2024, 12, 15, 23, 59, 59).unwrap(),
+    ///     10,
+    ///     vec![],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(assignment.id(), id);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        course_id: Id,
+        prompt: String,
+        due_at: DateTime,
+        max_score: u32,
+        submissions: Vec<Submission>,
+    ) -> Result<Self, AssignmentError> {
+        let prompt = prompt.trim().to_string();
+        Validator::is_not_empty(&prompt)?;
+        Validator::has_max_length(&prompt, Self::MAX_PROMPT_LENGTH)?;
+
+        if max_score == 0 {
+            return Err(AssignmentError::MaxScoreIsZero);
+        }
+
+        Ok(Self {
+            id,
+            course_id,
+            prompt,
+            due_at,
+            max_score,
+            submissions,
+            extensions: Vec::new(),
+        })
+    }
+
+    /// Adds a student's submission to the assignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AssignmentError::AlreadySubmitted` if the student already has a
+    /// submission for this assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, Submission, SubmissionContent};
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// assignment.add_submission(submission.clone()).unwrap();
+    /// assert!(assignment.add_submission(submission).is_err());
+    /// ```
+    pub fn add_submission(&mut self, submission: Submission) -> Result<(), AssignmentError> {
+        if self.submission_of(submission.student_email()).is_some() {
+            return Err(AssignmentError::AlreadySubmitted(
+                submission.student_email().address().to_string(),
+            ));
+        }
+
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Hands in a draft submission at the given time.
+    ///
+    /// Work handed in after the due date is accepted and flagged as late.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AssignmentError::SubmissionDoesNotExist` if no submission has the given ID.
+    /// Returns `AssignmentError::SubmissionError` if the submission is not a draft.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, Submission, SubmissionContent};
+    /// use education_platform_common::{DateTime, Entity, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// let submission_id = submission.id();
+    /// assignment.add_submission(submission).unwrap();
+    ///
+    /// assignment
+    ///     .submit(submission_id, DateTime::new(2024, 12, 16, 9, 0, 0).unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(assignment.late_submissions().count(), 1);
+    /// ```
+    pub fn submit(&mut self, submission_id: Id, at: DateTime) -> Result<(), AssignmentError> {
+        self.find_submission_mut(submission_id)?.submit(at)?;
+        Ok(())
+    }
+
+    /// Grades a submitted piece of work.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AssignmentError::ScoreOutOfRange` if the score exceeds the max score.
+    /// Returns `AssignmentError::SubmissionDoesNotExist` if no submission has the given ID.
+    /// Returns `AssignmentError::SubmissionError` if the submission is not in the
+    /// `Submitted` state or the feedback is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, Submission, SubmissionContent};
+    /// use education_platform_common::{DateTime, Entity, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// let submission_id = submission.id();
+    /// assignment.add_submission(submission).unwrap();
+    /// assignment
+    ///     .submit(submission_id, DateTime::new(2024, 12, 14, 9, 0, 0).unwrap())
+    ///     .unwrap();
+    ///
+    /// assert!(assignment.grade_submission(submission_id, 11, None).is_err());
+    /// assignment
+    ///     .grade_submission(submission_id, 9, Some("Clear structure".to_string()))
+    ///     .unwrap();
+    /// ```
+    pub fn grade_submission(
+        &mut self,
+        submission_id: Id,
+        score: u32,
+        feedback: Option<String>,
+    ) -> Result<(), AssignmentError> {
+        let score = Score::new(f64::from(score), f64::from(self.max_score)).map_err(|_| {
+            AssignmentError::ScoreOutOfRange {
+                score,
+                max_score: self.max_score,
+            }
+        })?;
+
+        self.find_submission_mut(submission_id)?
+            .grade(score, feedback)?;
+        Ok(())
+    }
+
+    /// Returns a graded submission to its student.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AssignmentError::SubmissionDoesNotExist` if no submission has the given ID.
+    /// Returns `AssignmentError::SubmissionError` if the submission has not been graded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, Submission, SubmissionContent, SubmissionStatus};
+    /// use education_platform_common::{DateTime, Entity, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// let submission_id = submission.id();
+    /// assignment.add_submission(submission).unwrap();
+    /// assignment
+    ///     .submit(submission_id, DateTime::new(2024, 12, 14, 9, 0, 0).unwrap())
+    ///     .unwrap();
+    /// assignment.grade_submission(submission_id, 8, None).unwrap();
+    ///
+    /// assignment.return_submission(submission_id).unwrap();
+    /// assert_eq!(assignment.submissions()[0].status(), SubmissionStatus::Returned);
+    /// ```
+    pub fn return_submission(&mut self, submission_id: Id) -> Result<(), AssignmentError> {
+        self.find_submission_mut(submission_id)?
+            .return_to_student()?;
+        Ok(())
+    }
+
+    /// Returns the submission of a student, if they have one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, Submission, SubmissionContent};
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// assignment.add_submission(submission).unwrap();
+    ///
+    /// let student = Email::new("student@example.com".to_string()).unwrap();
+    /// assert!(assignment.submission_of(&student).is_some());
+    /// ```
+    #[must_use]
+    pub fn submission_of(&self, student_email: &Email) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .find(|submission| submission.student_email() == student_email)
+    }
+
+    /// Returns an iterator over submissions handed in after the due date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, Submission, SubmissionContent};
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// assignment.add_submission(submission).unwrap();
+    ///
+    /// // Drafts are never late
+    /// assert_eq!(assignment.late_submissions().count(), 0);
+    /// ```
+    pub fn late_submissions(&self) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(|submission| submission.is_late(self.due_at_for(submission.student_email())))
+    }
+
+    /// Gives one student more time to hand in their work.
+    ///
+    /// Only the given student's deadline moves; submissions are judged late
+    /// against it from then on. A later extension replaces an earlier one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AssignmentError::ExtensionNotLater` if the new date is not
+    /// after the student's current due date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let student = Email::new("student@example.com".to_string()).unwrap();
+    /// let until = DateTime::new(2024, 12, 20, 23, 59, 59).unwrap();
+    ///
+    /// assignment.extend_due_date(student.clone(), until).unwrap();
+    ///
+    /// assert_eq!(assignment.due_at_for(&student), until);
+    /// assert!(assignment.extend_due_date(student, due_at).is_err());
+    /// ```
+    pub fn extend_due_date(
+        &mut self,
+        student: Email,
+        until: DateTime,
+    ) -> Result<(), AssignmentError> {
+        let current = self.due_at_for(&student);
+        if until <= current {
+            return Err(AssignmentError::ExtensionNotLater {
+                until: until.to_string(),
+                due_at: current.to_string(),
+            });
+        }
+
+        self.extensions.retain(|(email, _)| *email != student);
+        self.extensions.push((student, until));
+        Ok(())
+    }
+
+    /// Returns the extended due date granted to a student, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let student = Email::new("student@example.com".to_string()).unwrap();
+    ///
+    /// assert_eq!(assignment.extended_due_at(&student), None);
+    /// ```
+    #[must_use]
+    pub fn extended_due_at(&self, student: &Email) -> Option<DateTime> {
+        self.extensions
+            .iter()
+            .find(|(email, _)| email == student)
+            .map(|(_, until)| *until)
+    }
+
+    /// Returns the date a student's work is due, taking extensions into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    /// let student = Email::new("student@example.com".to_string()).unwrap();
+    ///
+    /// assert_eq!(assignment.due_at_for(&student), due_at);
+    /// ```
+    #[must_use]
+    pub fn due_at_for(&self, student: &Email) -> DateTime {
+        self.extended_due_at(student).unwrap_or(self.due_at)
+    }
+
+    /// Returns the ID of the course the assignment belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let course_id = Id::new();
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let assignment = Assignment::new(course_id, "Essay".to_string(), due_at, 10).unwrap();
+    ///
+    /// assert_eq!(assignment.course_id(), course_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns the assignment prompt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let assignment = Assignment::new(Id::new(), "  Essay  ".to_string(), due_at, 10).unwrap();
+    ///
+    /// assert_eq!(assignment.prompt(), "Essay");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn prompt(&self) -> &str {
+        &self.prompt
+    }
+
+    /// Returns the due date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    ///
+    /// assert_eq!(assignment.due_at(), due_at);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn due_at(&self) -> DateTime {
+        self.due_at
+    }
+
+    /// Returns the highest score a submission can get.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 20).unwrap();
+    ///
+    /// assert_eq!(assignment.max_score(), 20);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_score(&self) -> u32 {
+        self.max_score
+    }
+
+    /// Returns all submissions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Assignment;
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 15, 23, 59, 59).unwrap();
+    /// let assignment = Assignment::new(Id::new(), "Essay".to_string(), due_at, 10).unwrap();
+    ///
+    /// assert!(assignment.submissions().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    fn find_submission_mut(
+        &mut self,
+        submission_id: Id,
+    ) -> Result<&mut Submission, AssignmentError> {
+        self.submissions
+            .iter_mut()
+            .find(|submission| submission.id() == submission_id)
+            .ok_or(AssignmentError::SubmissionDoesNotExist)
+    }
+}
+
+impl Entity for Assignment {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for Assignment {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Assignment {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SubmissionContent, SubmissionStatus};
+
+    fn create_test_assignment() -> Assignment {
+        Assignment::new(
+            Id::new(),
+            "Write a parser".to_string(),
+            DateTime::new(2024, 12, 15, 23, 59, 59).unwrap(),
+            100,
+        )
+        .unwrap()
+    }
+
+    fn create_test_submission(email: &str) -> Submission {
+        let content = SubmissionContent::text("My answer".to_string()).unwrap();
+        Submission::new(email.to_string(), content).unwrap()
+    }
+
+    fn create_submitted_assignment(at: DateTime) -> (Assignment, Id) {
+        let mut assignment = create_test_assignment();
+        let submission = create_test_submission("student@example.com");
+        let submission_id = submission.id();
+        assignment.add_submission(submission).unwrap();
+        assignment.submit(submission_id, at).unwrap();
+        (assignment, submission_id)
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_trims_prompt() {
+            let assignment = Assignment::new(
+                Id::new(),
+                "  Write a parser  ".to_string(),
+                DateTime::new(2024, 12, 15, 23, 59, 59).unwrap(),
+                100,
+            )
+            .unwrap();
+
+            assert_eq!(assignment.prompt(), "Write a parser");
+        }
+
+        #[test]
+        fn test_new_rejects_blank_prompt() {
+            let result = Assignment::new(
+                Id::new(),
+                "   ".to_string(),
+                DateTime::new(2024, 12, 15, 23, 59, 59).unwrap(),
+                100,
+            );
+
+            assert!(matches!(result, Err(AssignmentError::PromptError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_zero_max_score() {
+            let result = Assignment::new(
+                Id::new(),
+                "Write a parser".to_string(),
+                DateTime::new(2024, 12, 15, 23, 59, 59).unwrap(),
+                0,
+            );
+
+            assert_eq!(result.unwrap_err(), AssignmentError::MaxScoreIsZero);
+        }
+    }
+
+    mod submissions {
+        use super::*;
+
+        #[test]
+        fn test_add_submission_rejects_second_submission_from_student() {
+            let mut assignment = create_test_assignment();
+            assignment
+                .add_submission(create_test_submission("student@example.com"))
+                .unwrap();
+
+            let result = assignment.add_submission(create_test_submission("student@example.com"));
+
+            assert_eq!(
+                result.unwrap_err(),
+                AssignmentError::AlreadySubmitted("student@example.com".to_string())
+            );
+        }
+
+        #[test]
+        fn test_submit_unknown_submission_fails() {
+            let mut assignment = create_test_assignment();
+
+            let result = assignment.submit(Id::new(), DateTime::new(2024, 12, 1, 0, 0, 0).unwrap());
+
+            assert_eq!(result.unwrap_err(), AssignmentError::SubmissionDoesNotExist);
+        }
+
+        #[test]
+        fn test_submit_twice_surfaces_submission_error() {
+            let at = DateTime::new(2024, 12, 1, 0, 0, 0).unwrap();
+            let (mut assignment, submission_id) = create_submitted_assignment(at);
+
+            let result = assignment.submit(submission_id, at);
+
+            assert!(matches!(result, Err(AssignmentError::SubmissionError(_))));
+        }
+    }
+
+    mod grading {
+        use super::*;
+
+        #[test]
+        fn test_grade_within_max_score() {
+            let (mut assignment, submission_id) =
+                create_submitted_assignment(DateTime::new(2024, 12, 1, 0, 0, 0).unwrap());
+
+            assignment
+                .grade_submission(submission_id, 100, Some("Perfect".to_string()))
+                .unwrap();
+
+            let submission = &assignment.submissions()[0];
+            assert_eq!(submission.status(), SubmissionStatus::Graded);
+            assert_eq!(submission.score().map(|score| score.points()), Some(100.0));
+        }
+
+        #[test]
+        fn test_grade_above_max_score_fails() {
+            let (mut assignment, submission_id) =
+                create_submitted_assignment(DateTime::new(2024, 12, 1, 0, 0, 0).unwrap());
+
+            let result = assignment.grade_submission(submission_id, 101, None);
+
+            assert_eq!(
+                result.unwrap_err(),
+                AssignmentError::ScoreOutOfRange {
+                    score: 101,
+                    max_score: 100,
+                }
+            );
+            assert_eq!(assignment.submissions()[0].status(), SubmissionStatus::Submitted);
+        }
+
+        #[test]
+        fn test_return_graded_submission() {
+            let (mut assignment, submission_id) =
+                create_submitted_assignment(DateTime::new(2024, 12, 1, 0, 0, 0).unwrap());
+            assignment
+                .grade_submission(submission_id, 60, None)
+                .unwrap();
+
+            assignment.return_submission(submission_id).unwrap();
+
+            assert_eq!(assignment.submissions()[0].status(), SubmissionStatus::Returned);
+        }
+    }
+
+    mod late_submissions {
+        use super::*;
+
+        #[test]
+        fn test_late_submissions_only_lists_work_after_due_date() {
+            let mut assignment = create_test_assignment();
+            let on_time = create_test_submission("on-time@example.com");
+            let late = create_test_submission("late@example.com");
+            let on_time_id = on_time.id();
+            let late_id = late.id();
+            assignment.add_submission(on_time).unwrap();
+            assignment.add_submission(late).unwrap();
+
+            assignment
+                .submit(on_time_id, DateTime::new(2024, 12, 15, 23, 59, 59).unwrap())
+                .unwrap();
+            assignment
+                .submit(late_id, DateTime::new(2024, 12, 16, 0, 0, 0).unwrap())
+                .unwrap();
+
+            let late: Vec<Id> = assignment.late_submissions().map(Entity::id).collect();
+            assert_eq!(late, vec![late_id]);
+        }
+
+        #[test]
+        fn test_extension_keeps_submission_on_time() {
+            let mut assignment = create_test_assignment();
+            let student = Email::new("student@example.com".to_string()).unwrap();
+            assignment
+                .extend_due_date(student, DateTime::new(2024, 12, 18, 0, 0, 0).unwrap())
+                .unwrap();
+
+            let submission = create_test_submission("student@example.com");
+            let submission_id = submission.id();
+            assignment.add_submission(submission).unwrap();
+            assignment
+                .submit(submission_id, DateTime::new(2024, 12, 17, 0, 0, 0).unwrap())
+                .unwrap();
+
+            assert_eq!(assignment.late_submissions().count(), 0);
+        }
+    }
+
+    mod extensions {
+        use super::*;
+
+        #[test]
+        fn test_later_extension_replaces_earlier_one() {
+            let mut assignment = create_test_assignment();
+            let student = Email::new("student@example.com".to_string()).unwrap();
+            let first = DateTime::new(2024, 12, 18, 0, 0, 0).unwrap();
+            let second = DateTime::new(2024, 12, 20, 0, 0, 0).unwrap();
+
+            assignment.extend_due_date(student.clone(), first).unwrap();
+            assignment.extend_due_date(student.clone(), second).unwrap();
+
+            assert_eq!(assignment.extended_due_at(&student), Some(second));
+        }
+
+        #[test]
+        fn test_extension_cannot_shorten_deadline() {
+            let mut assignment = create_test_assignment();
+            let student = Email::new("student@example.com".to_string()).unwrap();
+            assignment
+                .extend_due_date(student.clone(), DateTime::new(2024, 12, 20, 0, 0, 0).unwrap())
+                .unwrap();
+
+            let result =
+                assignment.extend_due_date(student, DateTime::new(2024, 12, 18, 0, 0, 0).unwrap());
+
+            assert!(matches!(result, Err(AssignmentError::ExtensionNotLater { .. })));
+        }
+
+        #[test]
+        fn test_other_students_keep_original_deadline() {
+            let mut assignment = create_test_assignment();
+            let student = Email::new("student@example.com".to_string()).unwrap();
+            let other = Email::new("other@example.com".to_string()).unwrap();
+            assignment
+                .extend_due_date(student, DateTime::new(2024, 12, 20, 0, 0, 0).unwrap())
+                .unwrap();
+
+            assert_eq!(assignment.due_at_for(&other), assignment.due_at());
+        }
+    }
+}
@@ -0,0 +1,699 @@
+use education_platform_common::{
+    DateTime, Email, EmailError, Entity, Id, Score, Url, UrlError, Validator, ValidatorError,
+};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Submission validation and lifecycle failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SubmissionError {
+    #[error("Student email is not valid: {0}")]
+    StudentEmailError(#[from] EmailError),
+
+    #[error("Submission content validation failed: {0}")]
+    ContentError(#[from] ValidatorError),
+
+    #[error("Submission link is not valid: {0}")]
+    LinkError(#[from] UrlError),
+
+    #[error("Submission feedback validation failed: {0}")]
+    FeedbackError(ValidatorError),
+
+    #[error("Submission cannot move from {from} to {to}")]
+    TransitionNotValid {
+        from: SubmissionStatus,
+        to: SubmissionStatus,
+    },
+}
+
+/// Where a submission stands in its lifecycle.
+///
+/// Submissions move strictly forward: `Draft` → `Submitted` → `Graded` →
+/// `Returned`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::SubmissionStatus;
+///
+/// assert_eq!(SubmissionStatus::default(), SubmissionStatus::Draft);
+/// assert_eq!(SubmissionStatus::Graded.to_string(), "graded");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SubmissionStatus {
+    #[default]
+    Draft,
+    Submitted,
+    Graded,
+    Returned,
+}
+
+impl fmt::Display for SubmissionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Draft => "draft",
+            Self::Submitted => "submitted",
+            Self::Graded => "graded",
+            Self::Returned => "returned",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// What a student hands in: written text or a link to their work.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::SubmissionContent;
+///
+/// let text = SubmissionContent::text("My essay".to_string()).unwrap();
+/// assert_eq!(text.as_str(), "My essay");
+///
+/// let link = SubmissionContent::link("https://github.com/me/homework".to_string()).unwrap();
+/// assert!(matches!(link, SubmissionContent::Link(_)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionContent {
+    Text(String),
+    Link(Url),
+}
+
+impl SubmissionContent {
+    pub const MAX_TEXT_LENGTH: usize = 20_000;
+
+    /// Creates text content. The text is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::ContentError` if the text is empty or longer
+    /// than [`SubmissionContent::MAX_TEXT_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SubmissionContent;
+    ///
+    /// assert!(SubmissionContent::text("Answer".to_string()).is_ok());
+    /// assert!(SubmissionContent::text("   ".to_string()).is_err());
+    /// ```
+    pub fn text(text: String) -> Result<Self, SubmissionError> {
+        let text = text.trim().to_string();
+        Validator::is_not_empty(&text)?;
+        Validator::has_max_length(&text, Self::MAX_TEXT_LENGTH)?;
+        Ok(Self::Text(text))
+    }
+
+    /// Creates link content pointing at the student's work.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::LinkError` if the URL is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SubmissionContent;
+    ///
+    /// assert!(SubmissionContent::link("https://example.com/work".to_string()).is_ok());
+    /// assert!(SubmissionContent::link("not a url".to_string()).is_err());
+    /// ```
+    pub fn link(url: String) -> Result<Self, SubmissionError> {
+        Ok(Self::Link(Url::new(url)?))
+    }
+
+    /// Returns the text or the URL as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SubmissionContent;
+    ///
+    /// let link = SubmissionContent::link("https://example.com/work".to_string()).unwrap();
+    /// assert_eq!(link.as_str(), "https://example.com/work");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Text(text) => text,
+            Self::Link(url) => url.as_str(),
+        }
+    }
+}
+
+/// A student's work on an assignment.
+///
+/// `Submission` is an entity that belongs to an `Assignment` aggregate. It
+/// starts as a `Draft` the student can keep editing, is then submitted, and
+/// finally graded and returned to the student. The submission time is kept
+/// so late work can be detected against the assignment's due date.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Submission, SubmissionContent, SubmissionStatus};
+/// use education_platform_common::DateTime;
+///
+/// let content = SubmissionContent::text("Draft answer".to_string()).unwrap();
+/// let mut submission = Submission::new("student@example.com".to_string(), content).unwrap();
+/// assert_eq!(submission.status(), SubmissionStatus::Draft);
+///
+/// submission.submit(DateTime::new(2024, 12, 1, 10, 0, 0).unwrap()).unwrap();
+/// assert_eq!(submission.status(), SubmissionStatus::Submitted);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Submission {
+    id: Id,
+    student_email: Email,
+    content: SubmissionContent,
+    status: SubmissionStatus,
+    submitted_at: Option<DateTime>,
+    score: Option<Score>,
+    feedback: Option<String>,
+}
+
+impl Submission {
+    pub const MAX_FEEDBACK_LENGTH: usize = 5000;
+
+    /// Creates a new draft `Submission`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::StudentEmailError` if the email is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    ///
+    /// assert!(Submission::new("student@example.com".to_string(), content.clone()).is_ok());
+    /// assert!(Submission::new("invalid".to_string(), content).is_err());
+    /// ```
+    pub fn new(student_email: String, content: SubmissionContent) -> Result<Self, SubmissionError> {
+        Self::with_id(
+            Id::default(),
+            student_email,
+            content,
+            SubmissionStatus::Draft,
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Creates a `Submission` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::StudentEmailError` if the email is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent, SubmissionStatus};
+    /// use education_platform_common::{DateTime, Entity, Id, Score};
+    ///
+    /// let id = Id::new();
+    /// let submission = Submission::with_id(
+    ///     id,
+    ///     "student@example.com".to_string(),
+    ///     SubmissionContent::text("Answer".to_string()).unwrap(),
+    ///     SubmissionStatus::Graded,
+    ///     Some(DateTime::new(2024, 12, 1, 10, 0, 0).unwrap()),
+    ///     Some(Score::new(85.0, 100.0).unwrap()),
+    ///     Some("Well argued".to_string()),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(submission.id(), id);
+    /// assert_eq!(submission.score().unwrap().to_string(), "85/100");
+    /// ```
+    pub fn with_id(
+        id: Id,
+        student_email: String,
+        content: SubmissionContent,
+        status: SubmissionStatus,
+        submitted_at: Option<DateTime>,
+        score: Option<Score>,
+        feedback: Option<String>,
+    ) -> Result<Self, SubmissionError> {
+        let student_email = Email::new(student_email)?;
+
+        Ok(Self {
+            id,
+            student_email,
+            content,
+            status,
+            submitted_at,
+            score,
+            feedback,
+        })
+    }
+
+    /// Replaces the content of a draft.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::TransitionNotValid` if the submission is no
+    /// longer a draft.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    ///
+    /// let content = SubmissionContent::text("First try".to_string()).unwrap();
+    /// let mut submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// let revised = SubmissionContent::text("Second try".to_string()).unwrap();
+    /// submission.update_content(revised).unwrap();
+    ///
+    /// assert_eq!(submission.content().as_str(), "Second try");
+    /// ```
+    pub fn update_content(&mut self, content: SubmissionContent) -> Result<(), SubmissionError> {
+        self.ensure_status(SubmissionStatus::Draft, SubmissionStatus::Draft)?;
+        self.content = content;
+        Ok(())
+    }
+
+    /// Hands the draft in at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::TransitionNotValid` if the submission is not a draft.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    /// use education_platform_common::DateTime;
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let mut submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// let at = DateTime::new(2024, 12, 1, 10, 0, 0).unwrap();
+    ///
+    /// submission.submit(at).unwrap();
+    /// assert_eq!(submission.submitted_at(), Some(at));
+    /// assert!(submission.submit(at).is_err());
+    /// ```
+    pub fn submit(&mut self, at: DateTime) -> Result<(), SubmissionError> {
+        self.ensure_status(SubmissionStatus::Draft, SubmissionStatus::Submitted)?;
+        self.status = SubmissionStatus::Submitted;
+        self.submitted_at = Some(at);
+        Ok(())
+    }
+
+    /// Records the grade of a submitted piece of work.
+    ///
+    /// The score carries its own maximum; grade through
+    /// [`crate::Assignment::grade_submission`] to score out of the
+    /// assignment's maximum. Feedback is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::TransitionNotValid` if the submission is not
+    /// in the `Submitted` state.
+    /// Returns `SubmissionError::FeedbackError` if the feedback is blank or longer
+    /// than [`Submission::MAX_FEEDBACK_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent, SubmissionStatus};
+    /// use education_platform_common::{DateTime, Score};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let mut submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// let score = Score::new(90.0, 100.0).unwrap();
+    /// assert!(submission.grade(score, None).is_err());
+    ///
+    /// submission.submit(DateTime::new(2024, 12, 1, 10, 0, 0).unwrap()).unwrap();
+    /// submission.grade(score, Some("Nice work".to_string())).unwrap();
+    ///
+    /// assert_eq!(submission.status(), SubmissionStatus::Graded);
+    /// assert_eq!(submission.feedback(), Some("Nice work"));
+    /// ```
+    pub fn grade(&mut self, score: Score, feedback: Option<String>) -> Result<(), SubmissionError> {
+        self.ensure_status(SubmissionStatus::Submitted, SubmissionStatus::Graded)?;
+
+        let feedback = feedback
+            .map(|text| Self::validate_feedback(&text))
+            .transpose()?;
+
+        self.status = SubmissionStatus::Graded;
+        self.score = Some(score);
+        self.feedback = feedback;
+        Ok(())
+    }
+
+    /// Returns the graded work to the student.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SubmissionError::TransitionNotValid` if the submission has not
+    /// been graded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent, SubmissionStatus};
+    /// use education_platform_common::{DateTime, Score};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let mut submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// submission.submit(DateTime::new(2024, 12, 1, 10, 0, 0).unwrap()).unwrap();
+    /// submission.grade(Score::new(70.0, 100.0).unwrap(), None).unwrap();
+    ///
+    /// submission.return_to_student().unwrap();
+    /// assert_eq!(submission.status(), SubmissionStatus::Returned);
+    /// ```
+    pub fn return_to_student(&mut self) -> Result<(), SubmissionError> {
+        self.ensure_status(SubmissionStatus::Graded, SubmissionStatus::Returned)?;
+        self.status = SubmissionStatus::Returned;
+        Ok(())
+    }
+
+    /// Returns true if the work was handed in after the due date.
+    ///
+    /// Drafts are never late; they have not been handed in yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    /// use education_platform_common::DateTime;
+    ///
+    /// let due_at = DateTime::new(2024, 12, 1, 23, 59, 59).unwrap();
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let mut submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    /// assert!(!submission.is_late(due_at));
+    ///
+    /// submission.submit(DateTime::new(2024, 12, 2, 8, 0, 0).unwrap()).unwrap();
+    /// assert!(submission.is_late(due_at));
+    /// ```
+    #[must_use]
+    pub fn is_late(&self, due_at: DateTime) -> bool {
+        self.submitted_at.is_some_and(|at| at > due_at)
+    }
+
+    /// Returns the student's email.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// assert_eq!(submission.student_email().address(), "student@example.com");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn student_email(&self) -> &Email {
+        &self.student_email
+    }
+
+    /// Returns the submitted content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// assert_eq!(submission.content().as_str(), "Answer");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn content(&self) -> &SubmissionContent {
+        &self.content
+    }
+
+    /// Returns the lifecycle status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent, SubmissionStatus};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// assert_eq!(submission.status(), SubmissionStatus::Draft);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> SubmissionStatus {
+        self.status
+    }
+
+    /// Returns when the work was handed in, if it has been.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// assert!(submission.submitted_at().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn submitted_at(&self) -> Option<DateTime> {
+        self.submitted_at
+    }
+
+    /// Returns the score, once graded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// assert!(submission.score().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn score(&self) -> Option<Score> {
+        self.score
+    }
+
+    /// Returns the grader's feedback, if any was given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Submission, SubmissionContent};
+    ///
+    /// let content = SubmissionContent::text("Answer".to_string()).unwrap();
+    /// let submission = Submission::new("student@example.com".to_string(), content).unwrap();
+    ///
+    /// assert!(submission.feedback().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn feedback(&self) -> Option<&str> {
+        self.feedback.as_deref()
+    }
+
+    fn ensure_status(
+        &self,
+        expected: SubmissionStatus,
+        to: SubmissionStatus,
+    ) -> Result<(), SubmissionError> {
+        match self.status == expected {
+            true => Ok(()),
+            false => Err(SubmissionError::TransitionNotValid {
+                from: self.status,
+                to,
+            }),
+        }
+    }
+
+    fn validate_feedback(text: &str) -> Result<String, SubmissionError> {
+        let text = text.trim().to_string();
+        Validator::is_not_empty(&text).map_err(SubmissionError::FeedbackError)?;
+        Validator::has_max_length(&text, Self::MAX_FEEDBACK_LENGTH)
+            .map_err(SubmissionError::FeedbackError)?;
+        Ok(text)
+    }
+}
+
+impl Entity for Submission {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for Submission {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Submission {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_submission() -> Submission {
+        let content = SubmissionContent::text("My answer".to_string()).unwrap();
+        Submission::new("student@example.com".to_string(), content).unwrap()
+    }
+
+    fn create_test_datetime(day: u32) -> DateTime {
+        DateTime::new(2024, 12, day, 12, 0, 0).unwrap()
+    }
+
+    fn create_score(points: u32) -> Score {
+        Score::new(f64::from(points), 100.0).unwrap()
+    }
+
+    mod content {
+        use super::*;
+
+        #[test]
+        fn test_text_is_trimmed() {
+            let content = SubmissionContent::text("  answer  ".to_string()).unwrap();
+
+            assert_eq!(content, SubmissionContent::Text("answer".to_string()));
+        }
+
+        #[test]
+        fn test_text_rejects_too_long_input() {
+            let text = "a".repeat(SubmissionContent::MAX_TEXT_LENGTH + 1);
+
+            let result = SubmissionContent::text(text);
+
+            assert!(matches!(result, Err(SubmissionError::ContentError(_))));
+        }
+
+        #[test]
+        fn test_link_rejects_invalid_url() {
+            let result = SubmissionContent::link("nope".to_string());
+
+            assert!(matches!(result, Err(SubmissionError::LinkError(_))));
+        }
+    }
+
+    mod lifecycle {
+        use super::*;
+
+        #[test]
+        fn test_full_lifecycle() {
+            let mut submission = create_test_submission();
+
+            submission.submit(create_test_datetime(1)).unwrap();
+            submission
+                .grade(create_score(80), Some("Good".to_string()))
+                .unwrap();
+            submission.return_to_student().unwrap();
+
+            assert_eq!(submission.status(), SubmissionStatus::Returned);
+            assert_eq!(submission.score(), Some(create_score(80)));
+            assert_eq!(submission.feedback(), Some("Good"));
+        }
+
+        #[test]
+        fn test_update_content_after_submit_fails() {
+            let mut submission = create_test_submission();
+            submission.submit(create_test_datetime(1)).unwrap();
+
+            let content = SubmissionContent::text("Changed".to_string()).unwrap();
+            let result = submission.update_content(content);
+
+            assert_eq!(
+                result.unwrap_err(),
+                SubmissionError::TransitionNotValid {
+                    from: SubmissionStatus::Submitted,
+                    to: SubmissionStatus::Draft,
+                }
+            );
+            assert_eq!(submission.content().as_str(), "My answer");
+        }
+
+        #[test]
+        fn test_grade_draft_fails() {
+            let mut submission = create_test_submission();
+
+            let result = submission.grade(create_score(50), None);
+
+            assert_eq!(
+                result.unwrap_err(),
+                SubmissionError::TransitionNotValid {
+                    from: SubmissionStatus::Draft,
+                    to: SubmissionStatus::Graded,
+                }
+            );
+        }
+
+        #[test]
+        fn test_grade_twice_fails() {
+            let mut submission = create_test_submission();
+            submission.submit(create_test_datetime(1)).unwrap();
+            submission.grade(create_score(50), None).unwrap();
+
+            assert!(submission.grade(create_score(60), None).is_err());
+            assert_eq!(submission.score(), Some(create_score(50)));
+        }
+
+        #[test]
+        fn test_grade_with_blank_feedback_leaves_submission_untouched() {
+            let mut submission = create_test_submission();
+            submission.submit(create_test_datetime(1)).unwrap();
+
+            let result = submission.grade(create_score(50), Some("  ".to_string()));
+
+            assert!(matches!(result, Err(SubmissionError::FeedbackError(_))));
+            assert_eq!(submission.status(), SubmissionStatus::Submitted);
+            assert!(submission.score().is_none());
+        }
+
+        #[test]
+        fn test_return_before_grading_fails() {
+            let mut submission = create_test_submission();
+            submission.submit(create_test_datetime(1)).unwrap();
+
+            assert!(submission.return_to_student().is_err());
+        }
+    }
+
+    mod lateness {
+        use super::*;
+
+        #[test]
+        fn test_on_time_submission_is_not_late() {
+            let mut submission = create_test_submission();
+            submission.submit(create_test_datetime(1)).unwrap();
+
+            assert!(!submission.is_late(create_test_datetime(2)));
+        }
+
+        #[test]
+        fn test_submission_at_due_time_is_not_late() {
+            let mut submission = create_test_submission();
+            submission.submit(create_test_datetime(2)).unwrap();
+
+            assert!(!submission.is_late(create_test_datetime(2)));
+        }
+
+        #[test]
+        fn test_submission_after_due_time_is_late() {
+            let mut submission = create_test_submission();
+            submission.submit(create_test_datetime(3)).unwrap();
+
+            assert!(submission.is_late(create_test_datetime(2)));
+        }
+    }
+}
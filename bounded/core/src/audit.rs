@@ -0,0 +1,3 @@
+mod audit_entry;
+
+pub use audit_entry::*;
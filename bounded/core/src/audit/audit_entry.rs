@@ -0,0 +1,301 @@
+use education_platform_common::{DateTime, Email, Entity, Id, Validator, ValidatorError};
+use thiserror::Error;
+
+/// Error types for recording administrative actions.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AuditError {
+    #[error("Audit reason validation failed: {0}")]
+    ReasonError(#[from] ValidatorError),
+}
+
+/// What an administrator changed on behalf of learners.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditAction {
+    /// A lesson was completed without the learners watching it.
+    LessonMarkedComplete {
+        course_id: Option<Id>,
+        lesson_id: Id,
+    },
+    /// Students were given until a later date to hand in an assignment.
+    DeadlineExtended { assignment_id: Id, until: DateTime },
+    /// Progress recorded on a duplicate account was moved to the primary one.
+    ProgressTransferred { from: Email, to: Email },
+}
+
+/// Who is making an administrative change, when and why.
+///
+/// The same context is stamped on every audit entry produced by one
+/// operation, so a bulk change can be traced back as a whole.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::AuditContext;
+/// use education_platform_common::{DateTime, Email};
+///
+/// let admin = Email::new("admin@example.com".to_string()).unwrap();
+/// let at = DateTime::new(2025, 3, 4, 18, 0, 0).unwrap();
+///
+/// let context = AuditContext::new(admin, "  Attended the live workshop ".to_string(), at).unwrap();
+///
+/// assert_eq!(context.reason(), "Attended the live workshop");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditContext {
+    actor: Email,
+    reason: String,
+    at: DateTime,
+}
+
+impl AuditContext {
+    pub const MAX_REASON_LENGTH: usize = 500;
+
+    /// Creates a context for an administrative change.
+    ///
+    /// The reason is trimmed and is required: learners see it in the
+    /// notification that tells them about the change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::ReasonError` if the reason is empty or longer than
+    /// [`AuditContext::MAX_REASON_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::AuditContext;
+    /// use education_platform_common::{DateTime, Email};
+    ///
+    /// let admin = Email::new("admin@example.com".to_string()).unwrap();
+    /// let at = DateTime::new(2025, 3, 4, 18, 0, 0).unwrap();
+    ///
+    /// assert!(AuditContext::new(admin, "   ".to_string(), at).is_err());
+    /// ```
+    pub fn new(actor: Email, reason: String, at: DateTime) -> Result<Self, AuditError> {
+        let reason = reason.trim().to_string();
+        Validator::is_not_empty(&reason)?;
+        Validator::has_max_length(&reason, Self::MAX_REASON_LENGTH)?;
+
+        Ok(Self { actor, reason, at })
+    }
+
+    /// Returns the administrator making the change.
+    #[inline]
+    #[must_use]
+    pub const fn actor(&self) -> &Email {
+        &self.actor
+    }
+
+    /// Returns why the change is made.
+    #[inline]
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns when the change is made.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self) -> DateTime {
+        self.at
+    }
+}
+
+/// A record of an administrative change to learner data.
+///
+/// Entries are append-only: they are created once the change has been
+/// applied and are never edited afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{AuditAction, AuditContext, AuditEntry};
+/// use education_platform_common::{DateTime, Email, Id};
+///
+/// let admin = Email::new("admin@example.com".to_string()).unwrap();
+/// let learner = Email::new("ana@example.com".to_string()).unwrap();
+/// let at = DateTime::new(2025, 3, 4, 18, 0, 0).unwrap();
+/// let context = AuditContext::new(admin, "Attended the live workshop".to_string(), at).unwrap();
+///
+/// let entry = AuditEntry::new(
+///     &context,
+///     AuditAction::LessonMarkedComplete { course_id: None, lesson_id: Id::new() },
+///     vec![learner.clone()],
+/// );
+///
+/// assert_eq!(entry.actor().address(), "admin@example.com");
+/// assert_eq!(entry.affected(), &[learner]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    id: Id,
+    actor: Email,
+    reason: String,
+    at: DateTime,
+    action: AuditAction,
+    affected: Vec<Email>,
+}
+
+impl AuditEntry {
+    /// Records an action taken in the given context.
+    #[must_use]
+    pub fn new(context: &AuditContext, action: AuditAction, affected: Vec<Email>) -> Self {
+        Self::with_id(Id::default(), context, action, affected)
+    }
+
+    /// Creates an `AuditEntry` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{AuditAction, AuditContext, AuditEntry};
+    /// use education_platform_common::{DateTime, Email, Entity, Id};
+    ///
+    /// let admin = Email::new("admin@example.com".to_string()).unwrap();
+    /// let at = DateTime::new(2025, 3, 4, 18, 0, 0).unwrap();
+    /// let context = AuditContext::new(admin, "Platform outage".to_string(), at).unwrap();
+    /// let id = Id::new();
+    ///
+    /// let entry = AuditEntry::with_id(
+    ///     id,
+    ///     &context,
+    ///     AuditAction::DeadlineExtended { assignment_id: Id::new(), until: at },
+    ///     vec![],
+    /// );
+    ///
+    /// assert_eq!(entry.id(), id);
+    /// ```
+    #[must_use]
+    pub fn with_id(
+        id: Id,
+        context: &AuditContext,
+        action: AuditAction,
+        affected: Vec<Email>,
+    ) -> Self {
+        Self {
+            id,
+            actor: context.actor.clone(),
+            reason: context.reason.clone(),
+            at: context.at,
+            action,
+            affected,
+        }
+    }
+
+    /// Returns the administrator who made the change.
+    #[inline]
+    #[must_use]
+    pub const fn actor(&self) -> &Email {
+        &self.actor
+    }
+
+    /// Returns why the change was made.
+    #[inline]
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns when the change was made.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self) -> DateTime {
+        self.at
+    }
+
+    /// Returns what was changed.
+    #[inline]
+    #[must_use]
+    pub const fn action(&self) -> &AuditAction {
+        &self.action
+    }
+
+    /// Returns the learners whose data changed.
+    #[inline]
+    #[must_use]
+    pub fn affected(&self) -> &[Email] {
+        &self.affected
+    }
+}
+
+impl Entity for AuditEntry {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for AuditEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for AuditEntry {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_test_context() -> AuditContext {
+        AuditContext::new(
+            create_email("admin@example.com"),
+            "Duplicate account".to_string(),
+            DateTime::new(2025, 3, 4, 18, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    mod audit_context {
+        use super::*;
+
+        #[test]
+        fn test_overlong_reason_is_rejected() {
+            let result = AuditContext::new(
+                create_email("admin@example.com"),
+                "x".repeat(AuditContext::MAX_REASON_LENGTH + 1),
+                DateTime::new(2025, 3, 4, 18, 0, 0).unwrap(),
+            );
+
+            assert!(matches!(result, Err(AuditError::ReasonError(_))));
+        }
+    }
+
+    mod audit_entry {
+        use super::*;
+
+        #[test]
+        fn test_entry_copies_context() {
+            let context = create_test_context();
+            let action = AuditAction::ProgressTransferred {
+                from: create_email("ana.old@example.com"),
+                to: create_email("ana@example.com"),
+            };
+
+            let entry = AuditEntry::new(&context, action.clone(), vec![]);
+
+            assert_eq!(entry.actor(), context.actor());
+            assert_eq!(entry.reason(), "Duplicate account");
+            assert_eq!(entry.at(), context.at());
+            assert_eq!(entry.action(), &action);
+        }
+
+        #[test]
+        fn test_entries_from_same_context_are_distinct() {
+            let context = create_test_context();
+            let action = AuditAction::LessonMarkedComplete {
+                course_id: None,
+                lesson_id: Id::new(),
+            };
+
+            let first = AuditEntry::new(&context, action.clone(), vec![]);
+            let second = AuditEntry::new(&context, action, vec![]);
+
+            assert_ne!(first, second);
+        }
+    }
+}
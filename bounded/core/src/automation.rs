@@ -0,0 +1,5 @@
+mod automation_event;
+mod script_engine;
+
+pub use automation_event::*;
+pub use script_engine::*;
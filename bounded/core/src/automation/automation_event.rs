@@ -0,0 +1,122 @@
+use crate::CourseEnded;
+use education_platform_common::{Email, Id, Percentage, Score};
+use std::fmt;
+
+/// What happened, as seen by automation scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AutomationEventKind {
+    CourseCompleted,
+    AssignmentGraded,
+}
+
+impl AutomationEventKind {
+    /// Returns the name scripts compare `event.kind` against.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::CourseCompleted => "course_completed",
+            Self::AssignmentGraded => "assignment_graded",
+        }
+    }
+}
+
+impl fmt::Display for AutomationEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Read-only facts about a domain event handed to automation scripts.
+///
+/// Scripts only ever see these values, never the aggregates themselves.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{AutomationEvent, AutomationEventKind, CourseEnded};
+/// use education_platform_common::{Email, Id, Percentage};
+///
+/// let ended = CourseEnded::new(Email::new("ana@example.com".to_string()).unwrap(), Id::new());
+/// let event = AutomationEvent::course_completed(&ended, Some(Percentage::new(95.0).unwrap()));
+///
+/// assert_eq!(event.kind(), AutomationEventKind::CourseCompleted);
+/// assert_eq!(event.subject_id(), ended.course_id());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutomationEvent {
+    kind: AutomationEventKind,
+    learner: Email,
+    subject_id: Id,
+    score: Option<Percentage>,
+}
+
+impl AutomationEvent {
+    /// Describes a learner completing a course, with their final score if
+    /// the course is graded.
+    #[must_use]
+    pub fn course_completed(event: &CourseEnded, score: Option<Percentage>) -> Self {
+        Self {
+            kind: AutomationEventKind::CourseCompleted,
+            learner: event.user_email().clone(),
+            subject_id: event.course_id(),
+            score,
+        }
+    }
+
+    /// Describes an assignment submission receiving a grade.
+    #[must_use]
+    pub fn assignment_graded(learner: Email, assignment_id: Id, score: Score) -> Self {
+        Self {
+            kind: AutomationEventKind::AssignmentGraded,
+            learner,
+            subject_id: assignment_id,
+            score: Some(score.percentage()),
+        }
+    }
+
+    /// Returns what happened.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> AutomationEventKind {
+        self.kind
+    }
+
+    /// Returns the learner the event is about.
+    #[inline]
+    #[must_use]
+    pub const fn learner(&self) -> &Email {
+        &self.learner
+    }
+
+    /// Returns the ID of the course or assignment the event is about.
+    #[inline]
+    #[must_use]
+    pub const fn subject_id(&self) -> Id {
+        self.subject_id
+    }
+
+    /// Returns the learner's score, if the event carries one.
+    #[inline]
+    #[must_use]
+    pub const fn score(&self) -> Option<Percentage> {
+        self.score
+    }
+}
+
+/// Something a script asked the host application to do.
+///
+/// Scripts cannot act on their own; the host decides how to carry out
+/// each requested action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutomationAction {
+    GrantBadge {
+        learner: Email,
+        badge: String,
+    },
+    Notify {
+        learner: Email,
+        subject: String,
+        body: String,
+    },
+}
@@ -0,0 +1,412 @@
+use crate::{AutomationAction, AutomationEvent};
+use education_platform_common::Email;
+use rhai::{AST, Dynamic, Engine, EvalAltResult, Map, Scope};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Error types for automation script failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AutomationError {
+    #[error("Script '{script}' does not compile: {message}")]
+    CompileFailed { script: String, message: String },
+
+    #[error("Script '{script}' failed: {message}")]
+    ExecutionFailed { script: String, message: String },
+
+    #[error("Script '{script}' ran longer than its time limit")]
+    TimeLimitExceeded { script: String },
+
+    #[error("Script '{script}' ran more operations than allowed")]
+    OperationLimitExceeded { script: String },
+}
+
+/// Resource caps applied to every script run.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::ScriptLimits;
+/// use std::time::Duration;
+///
+/// let limits = ScriptLimits::new(10_000, Duration::from_millis(20));
+/// assert_eq!(limits.max_operations(), 10_000);
+/// assert_eq!(ScriptLimits::default().time_limit(), Duration::from_millis(50));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptLimits {
+    max_operations: u64,
+    time_limit: Duration,
+}
+
+impl Default for ScriptLimits {
+    fn default() -> Self {
+        Self::new(100_000, Duration::from_millis(50))
+    }
+}
+
+impl ScriptLimits {
+    /// Creates limits; zero operations means the operation count is not capped.
+    #[inline]
+    #[must_use]
+    pub const fn new(max_operations: u64, time_limit: Duration) -> Self {
+        Self {
+            max_operations,
+            time_limit,
+        }
+    }
+
+    /// Returns how many operations a single run may perform.
+    #[inline]
+    #[must_use]
+    pub const fn max_operations(&self) -> u64 {
+        self.max_operations
+    }
+
+    /// Returns how long a single run may take.
+    #[inline]
+    #[must_use]
+    pub const fn time_limit(&self) -> Duration {
+        self.time_limit
+    }
+}
+
+/// Runs admin-provided Rhai scripts against domain events.
+///
+/// Scripts run in a sandbox: they cannot read files, reach the network or
+/// call `eval`, their output is discarded, and every run is capped by
+/// [`ScriptLimits`]. Each script receives an `event` object map with
+/// `kind`, `learner`, `subject_id` and `score` (a percentage, or `()`), and
+/// can only ask the host for the actions below, which are returned in the
+/// order they were requested:
+///
+/// - `grant_badge(badge)` requests an [`AutomationAction::GrantBadge`]
+/// - `notify(subject, body)` requests an [`AutomationAction::Notify`]
+///
+/// Both act on the event's learner.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     AutomationAction, AutomationEngine, AutomationEvent, CourseEnded,
+/// };
+/// use education_platform_common::{Email, Id, Percentage};
+///
+/// let mut engine = AutomationEngine::default();
+/// engine
+///     .add_script(
+///         "honours",
+///         r#"
+///             if event.kind == "course_completed" && event.score > 90.0 {
+///                 grant_badge("honours");
+///             }
+///         "#,
+///     )
+///     .unwrap();
+///
+/// let learner = Email::new("ana@example.com".to_string()).unwrap();
+/// let ended = CourseEnded::new(learner.clone(), Id::new());
+/// let event = AutomationEvent::course_completed(&ended, Some(Percentage::new(95.0).unwrap()));
+///
+/// assert_eq!(
+///     engine.run(&event).unwrap(),
+///     vec![AutomationAction::GrantBadge { learner, badge: "honours".to_string() }]
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct AutomationEngine {
+    scripts: Vec<(String, AST)>,
+    limits: ScriptLimits,
+}
+
+impl AutomationEngine {
+    /// Creates an engine with no scripts and the given limits.
+    #[must_use]
+    pub fn new(limits: ScriptLimits) -> Self {
+        Self {
+            scripts: Vec::new(),
+            limits,
+        }
+    }
+
+    /// Compiles a script and adds it after the existing ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AutomationError::CompileFailed` if the script has a syntax error
+    /// or uses a disabled keyword.
+    pub fn add_script(&mut self, name: &str, source: &str) -> Result<(), AutomationError> {
+        let ast = Self::sandbox(&self.limits).compile(source).map_err(|e| {
+            AutomationError::CompileFailed {
+                script: name.to_string(),
+                message: e.to_string(),
+            }
+        })?;
+        self.scripts.push((name.to_string(), ast));
+        Ok(())
+    }
+
+    /// Returns the names of the scripts, in run order.
+    #[must_use]
+    pub fn script_names(&self) -> Vec<&str> {
+        self.scripts.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Runs every script against an event and collects the requested actions.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first script that fails; actions requested
+    /// by earlier scripts are discarded.
+    pub fn run(&self, event: &AutomationEvent) -> Result<Vec<AutomationAction>, AutomationError> {
+        let actions = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = Self::sandbox(&self.limits);
+        Self::register_api(&mut engine, event.learner(), &actions);
+
+        for (name, ast) in &self.scripts {
+            let deadline = Instant::now() + self.limits.time_limit;
+            engine.on_progress(move |_| (Instant::now() >= deadline).then_some(Dynamic::UNIT));
+
+            let mut scope = Scope::new();
+            scope.push_constant("event", Self::event_map(event));
+            engine
+                .run_ast_with_scope(&mut scope, ast)
+                .map_err(|e| Self::execution_error(name, *e))?;
+        }
+
+        let actions = actions.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(actions.clone())
+    }
+
+    fn sandbox(limits: &ScriptLimits) -> Engine {
+        let mut engine = Engine::new();
+        engine
+            .disable_symbol("eval")
+            .on_print(|_| {})
+            .on_debug(|_, _, _| {})
+            .set_max_operations(limits.max_operations)
+            .set_max_call_levels(16)
+            .set_max_expr_depths(32, 16)
+            .set_max_string_size(10_000)
+            .set_max_array_size(1_000)
+            .set_max_map_size(1_000);
+        engine
+    }
+
+    fn register_api(
+        engine: &mut Engine,
+        learner: &Email,
+        actions: &Arc<Mutex<Vec<AutomationAction>>>,
+    ) {
+        let (badge_learner, badge_actions) = (learner.clone(), Arc::clone(actions));
+        engine.register_fn("grant_badge", move |badge: &str| -> Result<(), Box<EvalAltResult>> {
+            let badge = badge.trim();
+            if badge.is_empty() {
+                return Err("grant_badge needs a badge name".into());
+            }
+            badge_actions
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(AutomationAction::GrantBadge {
+                    learner: badge_learner.clone(),
+                    badge: badge.to_string(),
+                });
+            Ok(())
+        });
+
+        let (notify_learner, notify_actions) = (learner.clone(), Arc::clone(actions));
+        engine.register_fn(
+            "notify",
+            move |subject: &str, body: &str| -> Result<(), Box<EvalAltResult>> {
+                if subject.trim().is_empty() {
+                    return Err("notify needs a subject".into());
+                }
+                notify_actions
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push(AutomationAction::Notify {
+                        learner: notify_learner.clone(),
+                        subject: subject.trim().to_string(),
+                        body: body.to_string(),
+                    });
+                Ok(())
+            },
+        );
+    }
+
+    fn event_map(event: &AutomationEvent) -> Map {
+        let mut map = Map::new();
+        map.insert("kind".into(), event.kind().as_str().into());
+        map.insert("learner".into(), event.learner().address().into());
+        map.insert("subject_id".into(), event.subject_id().to_string().into());
+        map.insert(
+            "score".into(),
+            event
+                .score()
+                .map_or(Dynamic::UNIT, |score| score.value().into()),
+        );
+        map
+    }
+
+    fn execution_error(script: &str, error: EvalAltResult) -> AutomationError {
+        let script = script.to_string();
+        match error {
+            EvalAltResult::ErrorTerminated(..) => AutomationError::TimeLimitExceeded { script },
+            EvalAltResult::ErrorTooManyOperations(..) => {
+                AutomationError::OperationLimitExceeded { script }
+            }
+            other => AutomationError::ExecutionFailed {
+                script,
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CourseEnded;
+    use education_platform_common::{Id, Percentage, Score};
+
+    fn create_email() -> Email {
+        Email::new("ana@example.com".to_string()).unwrap()
+    }
+
+    fn create_completion(score: Option<f64>) -> AutomationEvent {
+        let ended = CourseEnded::new(create_email(), Id::new());
+        AutomationEvent::course_completed(&ended, score.map(|s| Percentage::new(s).unwrap()))
+    }
+
+    fn create_engine(source: &str) -> AutomationEngine {
+        let mut engine = AutomationEngine::default();
+        engine.add_script("test", source).unwrap();
+        engine
+    }
+
+    mod run {
+        use super::*;
+
+        #[test]
+        fn test_condition_not_met_requests_nothing() {
+            let engine = create_engine(
+                r#"if event.score != () && event.score > 90.0 { grant_badge("honours"); }"#,
+            );
+
+            assert_eq!(engine.run(&create_completion(Some(80.0))), Ok(vec![]));
+            assert_eq!(engine.run(&create_completion(None)), Ok(vec![]));
+        }
+
+        #[test]
+        fn test_actions_from_all_scripts_are_collected_in_order() {
+            let mut engine = create_engine(r#"grant_badge("finisher");"#);
+            engine
+                .add_script("welcome", r#"notify("Well done", "See you in the next course");"#)
+                .unwrap();
+
+            let actions = engine.run(&create_completion(None)).unwrap();
+
+            assert_eq!(
+                actions,
+                vec![
+                    AutomationAction::GrantBadge {
+                        learner: create_email(),
+                        badge: "finisher".to_string(),
+                    },
+                    AutomationAction::Notify {
+                        learner: create_email(),
+                        subject: "Well done".to_string(),
+                        body: "See you in the next course".to_string(),
+                    },
+                ]
+            );
+            assert_eq!(engine.script_names(), vec!["test", "welcome"]);
+        }
+
+        #[test]
+        fn test_graded_assignment_exposes_percentage() {
+            let engine = create_engine(
+                r#"if event.kind == "assignment_graded" && event.score == 75.0 { grant_badge("b"); }"#,
+            );
+            let event = AutomationEvent::assignment_graded(
+                create_email(),
+                Id::new(),
+                Score::new(3.0, 4.0).unwrap(),
+            );
+
+            assert_eq!(engine.run(&event).unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_invalid_action_fails_the_run() {
+            let engine = create_engine(r#"grant_badge("  ");"#);
+
+            let result = engine.run(&create_completion(None));
+
+            assert!(matches!(
+                result,
+                Err(AutomationError::ExecutionFailed { script, .. }) if script == "test"
+            ));
+        }
+    }
+
+    mod sandbox {
+        use super::*;
+
+        #[test]
+        fn test_syntax_error_is_reported_on_add() {
+            let mut engine = AutomationEngine::default();
+
+            let result = engine.add_script("broken", "if {");
+
+            assert!(matches!(result, Err(AutomationError::CompileFailed { .. })));
+            assert!(engine.script_names().is_empty());
+        }
+
+        #[test]
+        fn test_eval_is_disabled() {
+            let mut engine = AutomationEngine::default();
+
+            let result = engine.add_script("eval", r#"eval("grant_badge(\"x\")");"#);
+
+            assert!(matches!(result, Err(AutomationError::CompileFailed { .. })));
+        }
+
+        #[test]
+        fn test_operation_limit_stops_endless_loop() {
+            let engine = create_engine("loop {}");
+
+            assert_eq!(
+                engine.run(&create_completion(None)),
+                Err(AutomationError::OperationLimitExceeded {
+                    script: "test".to_string()
+                })
+            );
+        }
+
+        #[test]
+        fn test_time_limit_stops_endless_loop() {
+            let mut engine = AutomationEngine::new(ScriptLimits::new(0, Duration::from_millis(10)));
+            engine.add_script("spin", "loop {}").unwrap();
+
+            assert_eq!(
+                engine.run(&create_completion(None)),
+                Err(AutomationError::TimeLimitExceeded {
+                    script: "spin".to_string()
+                })
+            );
+        }
+
+        #[test]
+        fn test_event_cannot_be_modified() {
+            let engine = create_engine(r#"event.kind = "other";"#);
+
+            assert!(matches!(
+                engine.run(&create_completion(None)),
+                Err(AutomationError::ExecutionFailed { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod certificate;
+mod registry;
+
+pub use certificate::*;
+pub use registry::*;
@@ -0,0 +1,151 @@
+use education_platform_common::{DateTime, Email, Entity, Id, IdError, PersonName, ValidatorError};
+use thiserror::Error;
+
+/// Error types for certificate issuance, revocation and verification.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CertificateError {
+    #[error("Certificate ID is not valid: {0}")]
+    IdNotValid(#[from] IdError),
+
+    #[error("Course must be completed before a certificate is issued")]
+    CourseNotCompleted,
+
+    #[error("Progress {0} is withheld pending integrity review")]
+    ProgressWithheld(Id),
+
+    #[error("Progress {progress_id} already has certificate {certificate_id}")]
+    AlreadyIssued { progress_id: Id, certificate_id: Id },
+
+    #[error("Certificate with id {0} not found")]
+    CertificateNotFound(Id),
+
+    #[error("Certificate {0} has already been revoked")]
+    AlreadyRevoked(Id),
+
+    #[error("Revocation reason validation failed: {0}")]
+    RevocationReasonError(ValidatorError),
+}
+
+/// Computes the tamper-evidence digest stored with each certificate.
+///
+/// The hashing algorithm is an infrastructure choice, so the domain only
+/// fixes the content that is hashed (see [`Certificate::digest_content`]).
+pub trait CertificateDigest {
+    /// Returns the digest of the content, as the string published to verifiers.
+    fn digest(&self, content: &str) -> String;
+}
+
+/// Proof that a learner completed a course, as recorded in the registry.
+///
+/// Certificates are only created by `CertificateRegistry::issue` and never
+/// change afterwards; revocation is recorded next to them in the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Certificate {
+    id: Id,
+    progress_id: Id,
+    course_id: Option<Id>,
+    course_name: String,
+    learner_name: PersonName,
+    learner_email: Email,
+    issued_at: DateTime,
+    digest: String,
+}
+
+impl Certificate {
+    pub(crate) fn issue(
+        progress_id: Id,
+        course_id: Option<Id>,
+        course_name: String,
+        learner_name: PersonName,
+        learner_email: Email,
+        issued_at: DateTime,
+    ) -> Self {
+        Self {
+            id: Id::default(),
+            progress_id,
+            course_id,
+            course_name,
+            learner_name,
+            learner_email,
+            issued_at,
+            digest: String::new(),
+        }
+    }
+
+    pub(crate) fn seal(mut self, digest: &impl CertificateDigest) -> Self {
+        self.digest = digest.digest(&self.digest_content());
+        self
+    }
+
+    /// Returns the text the digest is computed from: the certificate ID,
+    /// course ID, course name, learner name, learner email and issue date,
+    /// separated by `|`.
+    #[must_use]
+    pub fn digest_content(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}",
+            self.id,
+            self.course_id.map(|id| id.to_string()).unwrap_or_default(),
+            self.course_name,
+            self.learner_name.full_name(),
+            self.learner_email.address(),
+            self.issued_at.format_iso(),
+        )
+    }
+
+    /// Returns the ID of the course progress the certificate was earned with.
+    #[inline]
+    #[must_use]
+    pub const fn progress_id(&self) -> Id {
+        self.progress_id
+    }
+
+    /// Returns the ID of the completed course, if the progress knew it.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Option<Id> {
+        self.course_id
+    }
+
+    /// Returns the course name as printed on the certificate.
+    #[inline]
+    #[must_use]
+    pub fn course_name(&self) -> &str {
+        &self.course_name
+    }
+
+    /// Returns the learner name as printed on the certificate.
+    #[inline]
+    #[must_use]
+    pub const fn learner_name(&self) -> &PersonName {
+        &self.learner_name
+    }
+
+    /// Returns the learner's email.
+    #[inline]
+    #[must_use]
+    pub const fn learner_email(&self) -> &Email {
+        &self.learner_email
+    }
+
+    /// Returns when the certificate was issued.
+    #[inline]
+    #[must_use]
+    pub const fn issued_at(&self) -> DateTime {
+        self.issued_at
+    }
+
+    /// Returns the digest computed at issuance.
+    #[inline]
+    #[must_use]
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+}
+
+impl Entity for Certificate {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
@@ -0,0 +1,506 @@
+use crate::{
+    Certificate, CertificateDigest, CertificateError, CourseProgress, IntegrityReviewQueue,
+};
+use education_platform_common::{DateTime, Entity, Id, PersonName, Validator};
+
+/// A revocation recorded against an issued certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Revocation {
+    certificate_id: Id,
+    revoked_at: DateTime,
+    reason: String,
+}
+
+impl Revocation {
+    /// Returns the ID of the revoked certificate.
+    #[inline]
+    #[must_use]
+    pub const fn certificate_id(&self) -> Id {
+        self.certificate_id
+    }
+
+    /// Returns when the certificate was revoked.
+    #[inline]
+    #[must_use]
+    pub const fn revoked_at(&self) -> DateTime {
+        self.revoked_at
+    }
+
+    /// Returns why the certificate was revoked.
+    #[inline]
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// One line of the registry log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryEntry {
+    Issued(Box<Certificate>),
+    Revoked(Revocation),
+}
+
+/// Whether a certificate can still be relied on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CertificateStatus {
+    Valid,
+    Revoked(Revocation),
+}
+
+/// Append-only log of certificate issuances and revocations.
+///
+/// Entries are never edited or removed: revoking a certificate appends a
+/// `Revoked` entry, so the registry always shows what was issued and when
+/// it stopped being valid.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     CertificateDigest, CertificateRegistry, CertificateStatus, CourseProgress,
+///     IntegrityReviewQueue, LessonProgress,
+/// };
+/// use education_platform_common::{DateTime, Entity, PersonName};
+///
+/// struct LengthDigest;
+///
+/// impl CertificateDigest for LengthDigest {
+///     fn digest(&self, content: &str) -> String {
+///         content.len().to_string()
+///     }
+/// }
+///
+/// let start = DateTime::new(2024, 5, 1, 9, 0, 0).unwrap();
+/// let end = DateTime::new(2024, 5, 1, 10, 0, 0).unwrap();
+/// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+/// let progress = CourseProgress::builder()
+///     .course_name("Rust Course")
+///     .user_email("ana@example.com")
+///     .lessons(vec![lesson])
+///     .build()
+///     .unwrap();
+/// let name = PersonName::new("Ana".to_string(), None, "Torres".to_string(), None).unwrap();
+///
+/// let review = IntegrityReviewQueue::default();
+///
+/// let mut registry = CertificateRegistry::new();
+/// let certificate_id = registry
+///     .issue(&progress, &review, name, end, &LengthDigest)
+///     .unwrap()
+///     .id();
+/// assert_eq!(registry.status(certificate_id), Some(CertificateStatus::Valid));
+///
+/// registry.revoke(certificate_id, "Issued in error", end).unwrap();
+/// assert!(matches!(registry.status(certificate_id), Some(CertificateStatus::Revoked(_))));
+/// assert_eq!(registry.entries().len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CertificateRegistry {
+    entries: Vec<RegistryEntry>,
+}
+
+impl CertificateRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issues a certificate for a completed course progress.
+    ///
+    /// A progress earns at most one certificate unless the earlier one was
+    /// revoked, and none while `review` holds an open or confirmed
+    /// integrity flag against it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CertificateError::CourseNotCompleted` if a lesson is still
+    /// pending, `CertificateError::ProgressWithheld` if the progress is
+    /// withheld by integrity review, or `CertificateError::AlreadyIssued`
+    /// if the progress holds a valid certificate.
+    pub fn issue(
+        &mut self,
+        progress: &CourseProgress,
+        review: &IntegrityReviewQueue,
+        learner_name: PersonName,
+        issued_at: DateTime,
+        digest: &impl CertificateDigest,
+    ) -> Result<&Certificate, CertificateError> {
+        if !progress.is_completed() {
+            return Err(CertificateError::CourseNotCompleted);
+        }
+        if review.is_withheld(progress.id()) {
+            return Err(CertificateError::ProgressWithheld(progress.id()));
+        }
+        if let Some(existing) = self.certificates().find(|certificate| {
+            certificate.progress_id() == progress.id()
+                && self.status(certificate.id()) == Some(CertificateStatus::Valid)
+        }) {
+            return Err(CertificateError::AlreadyIssued {
+                progress_id: progress.id(),
+                certificate_id: existing.id(),
+            });
+        }
+
+        let certificate = Certificate::issue(
+            progress.id(),
+            progress.course_id(),
+            progress.course_name().as_str().to_string(),
+            learner_name,
+            progress.user_email().clone(),
+            issued_at,
+        )
+        .seal(digest);
+        let certificate_id = certificate.id();
+        self.entries
+            .push(RegistryEntry::Issued(Box::new(certificate)));
+
+        self.certificate(certificate_id)
+            .ok_or(CertificateError::CertificateNotFound(certificate_id))
+    }
+
+    /// Revokes a certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CertificateError::RevocationReasonError` if the reason is
+    /// blank, `CertificateError::CertificateNotFound` if no certificate has
+    /// the ID, or `CertificateError::AlreadyRevoked` if it was revoked before.
+    pub fn revoke(
+        &mut self,
+        certificate_id: Id,
+        reason: &str,
+        at: DateTime,
+    ) -> Result<(), CertificateError> {
+        let reason = reason.trim();
+        Validator::is_not_empty(reason).map_err(CertificateError::RevocationReasonError)?;
+        match self.status(certificate_id) {
+            None => Err(CertificateError::CertificateNotFound(certificate_id)),
+            Some(CertificateStatus::Revoked(_)) => {
+                Err(CertificateError::AlreadyRevoked(certificate_id))
+            }
+            Some(CertificateStatus::Valid) => {
+                self.entries.push(RegistryEntry::Revoked(Revocation {
+                    certificate_id,
+                    revoked_at: at,
+                    reason: reason.to_string(),
+                }));
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the certificate with the ID.
+    #[must_use]
+    pub fn certificate(&self, certificate_id: Id) -> Option<&Certificate> {
+        self.certificates()
+            .find(|certificate| certificate.id() == certificate_id)
+    }
+
+    /// Returns the status of a certificate, or `None` if it was never issued.
+    #[must_use]
+    pub fn status(&self, certificate_id: Id) -> Option<CertificateStatus> {
+        self.certificate(certificate_id)?;
+        let revocation = self.entries.iter().find_map(|entry| match entry {
+            RegistryEntry::Revoked(revocation) if revocation.certificate_id == certificate_id => {
+                Some(revocation.clone())
+            }
+            _ => None,
+        });
+        match revocation {
+            Some(revocation) => Some(CertificateStatus::Revoked(revocation)),
+            None => Some(CertificateStatus::Valid),
+        }
+    }
+
+    /// Returns every issued certificate, in issue order.
+    pub fn certificates(&self) -> impl Iterator<Item = &Certificate> {
+        self.entries.iter().filter_map(|entry| match entry {
+            RegistryEntry::Issued(certificate) => Some(certificate.as_ref()),
+            RegistryEntry::Revoked(_) => None,
+        })
+    }
+
+    /// Returns the full log, oldest entry first.
+    #[inline]
+    #[must_use]
+    pub fn entries(&self) -> &[RegistryEntry] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LessonProgress;
+
+    struct PrefixDigest;
+
+    impl CertificateDigest for PrefixDigest {
+        fn digest(&self, content: &str) -> String {
+            format!("digest:{content}")
+        }
+    }
+
+    fn create_time(hour: u32) -> DateTime {
+        DateTime::new(2024, 5, 1, hour, 0, 0).unwrap()
+    }
+
+    fn create_name() -> PersonName {
+        PersonName::new("Ana".to_string(), None, "Torres".to_string(), None).unwrap()
+    }
+
+    fn create_progress(completed: bool) -> CourseProgress {
+        let end = completed.then(|| create_time(10));
+        let lesson =
+            LessonProgress::new("Intro".to_string(), 1800, Some(create_time(9)), end).unwrap();
+        CourseProgress::builder()
+            .course_id(Id::new())
+            .course_name("Rust Course")
+            .user_email("ana@example.com")
+            .lessons(vec![lesson])
+            .build()
+            .unwrap()
+    }
+
+    fn create_rushed_progress() -> CourseProgress {
+        let lessons = (0..3)
+            .map(|day| {
+                let start = DateTime::new(2024, 4, 1 + day, 9, 0, 0).unwrap();
+                LessonProgress::new(
+                    format!("Lesson {day}"),
+                    7200,
+                    Some(start),
+                    Some(start.add_seconds(180)),
+                )
+                .unwrap()
+            })
+            .collect();
+        CourseProgress::builder()
+            .course_name("Rust Course")
+            .user_email("ana@example.com")
+            .lessons(lessons)
+            .build()
+            .unwrap()
+    }
+
+    mod issue {
+        use super::*;
+        use education_platform_common::Email;
+
+        #[test]
+        fn test_copies_progress_details_and_seals_digest() {
+            let progress = create_progress(true);
+            let mut registry = CertificateRegistry::new();
+
+            let certificate = registry
+                .issue(
+                    &progress,
+                    &IntegrityReviewQueue::default(),
+                    create_name(),
+                    create_time(11),
+                    &PrefixDigest,
+                )
+                .unwrap();
+
+            assert_eq!(certificate.progress_id(), progress.id());
+            assert_eq!(certificate.course_id(), progress.course_id());
+            assert_eq!(certificate.course_name(), "Rust Course");
+            assert_eq!(certificate.learner_email().address(), "ana@example.com");
+            assert_eq!(
+                certificate.digest(),
+                format!("digest:{}", certificate.digest_content())
+            );
+            assert!(
+                certificate
+                    .digest_content()
+                    .ends_with("|Rust Course|Ana Torres|ana@example.com|2024-05-01T11:00:00")
+            );
+        }
+
+        #[test]
+        fn test_incomplete_course_is_rejected() {
+            let mut registry = CertificateRegistry::new();
+
+            let result = registry.issue(
+                &create_progress(false),
+                &IntegrityReviewQueue::default(),
+                create_name(),
+                create_time(11),
+                &PrefixDigest,
+            );
+
+            assert_eq!(result.unwrap_err(), CertificateError::CourseNotCompleted);
+        }
+
+        #[test]
+        fn test_withheld_progress_is_rejected_until_dismissed() {
+            let progress = create_rushed_progress();
+            let mut review = IntegrityReviewQueue::default();
+            let raised = review.screen(&progress, create_time(11));
+            let mut registry = CertificateRegistry::new();
+
+            let result =
+                registry.issue(&progress, &review, create_name(), create_time(12), &PrefixDigest);
+            assert_eq!(result.unwrap_err(), CertificateError::ProgressWithheld(progress.id()));
+            assert_eq!(registry.certificates().count(), 0);
+
+            let admin = Email::new("admin@example.com".to_string()).unwrap();
+            review.dismiss(raised[0], &admin, create_time(13)).unwrap();
+
+            assert!(
+                registry
+                    .issue(&progress, &review, create_name(), create_time(14), &PrefixDigest)
+                    .is_ok()
+            );
+        }
+
+        #[test]
+        fn test_confirmed_flag_keeps_progress_withheld() {
+            let progress = create_rushed_progress();
+            let mut review = IntegrityReviewQueue::default();
+            let raised = review.screen(&progress, create_time(11));
+            let admin = Email::new("admin@example.com".to_string()).unwrap();
+            review.confirm(raised[0], &admin, create_time(12)).unwrap();
+            let mut registry = CertificateRegistry::new();
+
+            let result =
+                registry.issue(&progress, &review, create_name(), create_time(13), &PrefixDigest);
+
+            assert_eq!(result.unwrap_err(), CertificateError::ProgressWithheld(progress.id()));
+        }
+
+        #[test]
+        fn test_second_certificate_for_same_progress_is_rejected() {
+            let progress = create_progress(true);
+            let mut registry = CertificateRegistry::new();
+            let first = registry
+                .issue(
+                    &progress,
+                    &IntegrityReviewQueue::default(),
+                    create_name(),
+                    create_time(11),
+                    &PrefixDigest,
+                )
+                .unwrap()
+                .id();
+
+            let result = registry.issue(
+                &progress,
+                &IntegrityReviewQueue::default(),
+                create_name(),
+                create_time(12),
+                &PrefixDigest,
+            );
+
+            assert_eq!(
+                result.unwrap_err(),
+                CertificateError::AlreadyIssued {
+                    progress_id: progress.id(),
+                    certificate_id: first,
+                }
+            );
+        }
+
+        #[test]
+        fn test_reissue_allowed_after_revocation() {
+            let progress = create_progress(true);
+            let mut registry = CertificateRegistry::new();
+            let first = registry
+                .issue(
+                    &progress,
+                    &IntegrityReviewQueue::default(),
+                    create_name(),
+                    create_time(11),
+                    &PrefixDigest,
+                )
+                .unwrap()
+                .id();
+            registry
+                .revoke(first, "Name misspelled", create_time(12))
+                .unwrap();
+
+            let second = registry
+                .issue(
+                    &progress,
+                    &IntegrityReviewQueue::default(),
+                    create_name(),
+                    create_time(13),
+                    &PrefixDigest,
+                )
+                .unwrap()
+                .id();
+
+            assert_ne!(first, second);
+            assert_eq!(registry.certificates().count(), 2);
+        }
+    }
+
+    mod revoke {
+        use super::*;
+
+        #[test]
+        fn test_revocation_is_appended_with_reason() {
+            let mut registry = CertificateRegistry::new();
+            let id = registry
+                .issue(
+                    &create_progress(true),
+                    &IntegrityReviewQueue::default(),
+                    create_name(),
+                    create_time(11),
+                    &PrefixDigest,
+                )
+                .unwrap()
+                .id();
+
+            registry
+                .revoke(id, "  Integrity review  ", create_time(12))
+                .unwrap();
+
+            let Some(CertificateStatus::Revoked(revocation)) = registry.status(id) else {
+                panic!("certificate should be revoked");
+            };
+            assert_eq!(revocation.reason(), "Integrity review");
+            assert_eq!(revocation.revoked_at(), create_time(12));
+            assert!(registry.certificate(id).is_some());
+        }
+
+        #[test]
+        fn test_revoking_twice_is_rejected() {
+            let mut registry = CertificateRegistry::new();
+            let id = registry
+                .issue(
+                    &create_progress(true),
+                    &IntegrityReviewQueue::default(),
+                    create_name(),
+                    create_time(11),
+                    &PrefixDigest,
+                )
+                .unwrap()
+                .id();
+            registry.revoke(id, "Fraud", create_time(12)).unwrap();
+
+            assert_eq!(
+                registry.revoke(id, "Fraud", create_time(13)),
+                Err(CertificateError::AlreadyRevoked(id))
+            );
+            assert_eq!(registry.entries().len(), 2);
+        }
+
+        #[test]
+        fn test_unknown_certificate_and_blank_reason_are_rejected() {
+            let mut registry = CertificateRegistry::new();
+            let id = Id::new();
+
+            assert_eq!(
+                registry.revoke(id, "Fraud", create_time(12)),
+                Err(CertificateError::CertificateNotFound(id))
+            );
+            assert!(matches!(
+                registry.revoke(id, " ", create_time(12)),
+                Err(CertificateError::RevocationReasonError(_))
+            ));
+        }
+    }
+}
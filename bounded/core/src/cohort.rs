@@ -0,0 +1,11 @@
+mod classroom;
+mod course_cohort;
+mod gradebook;
+mod live_session;
+mod study_group;
+
+pub use classroom::*;
+pub use course_cohort::*;
+pub use gradebook::*;
+pub use live_session::*;
+pub use study_group::*;
@@ -0,0 +1,803 @@
+use crate::CourseId;
+use education_platform_common::{Auditable, Date, DateTime, Email, Entity, Id, Timestamps};
+use thiserror::Error;
+
+/// Error types for Classroom operations.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ClassroomError {
+    #[error("Capacity must be different from zero")]
+    CapacityIsZero,
+
+    #[error("Classroom ends on {end}, before it starts on {start}")]
+    EndsBeforeStart { start: Date, end: Date },
+
+    #[error("Classroom is full, capacity is {capacity}")]
+    ClassroomFull { capacity: u32 },
+
+    #[error("Capacity {capacity} is below the {enrolled} enrolled students")]
+    CapacityBelowRoster { capacity: u32, enrolled: usize },
+
+    #[error("Student {0} is already enrolled")]
+    AlreadyEnrolled(String),
+
+    #[error("Student {0} is not enrolled")]
+    NotEnrolled(String),
+
+    #[error("{0} teaches the classroom and cannot be enrolled in it")]
+    InstructorCannotEnroll(String),
+
+    #[error("Students can only be transferred between classrooms of the same course")]
+    DifferentCourse,
+
+    #[error("Students cannot be transferred to the classroom they are in")]
+    SameClassroom,
+}
+
+/// A teacher-led group of students taking a course together between two
+/// dates, such as a school class or a company training batch.
+///
+/// `Classroom` is an aggregate root. The instructor manages the roster
+/// directly instead of students enrolling themselves: the roster never
+/// exceeds the capacity, never lists the same student twice and never
+/// lists the instructor.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Classroom;
+/// use education_platform_common::{Date, Email, Id};
+///
+/// let instructor = Email::new("teacher@school.edu".to_string()).unwrap();
+/// let mut classroom = Classroom::new(
+///     Id::new().cast(),
+///     instructor,
+///     Date::new(2025, 2, 3).unwrap(),
+///     Date::new(2025, 6, 27).unwrap(),
+///     1,
+/// ).unwrap();
+///
+/// let ana = Email::new("ana@school.edu".to_string()).unwrap();
+/// let ben = Email::new("ben@school.edu".to_string()).unwrap();
+///
+/// classroom.enroll(ana).unwrap();
+/// assert!(classroom.is_full());
+/// assert!(classroom.enroll(ben).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Classroom {
+    id: Id,
+    course_id: CourseId,
+    instructor: Email,
+    start_date: Date,
+    end_date: Date,
+    capacity: u32,
+    roster: Vec<Email>,
+    timestamps: Timestamps,
+}
+
+/// Identifier of a [`Classroom`].
+pub type ClassroomId = Id<Classroom>;
+
+impl Classroom {
+    /// Creates a new `Classroom` with an empty roster.
+    ///
+    /// A classroom may start and end on the same day, as a one-day workshop
+    /// does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClassroomError::EndsBeforeStart` if `end_date` is before
+    /// `start_date`, or `ClassroomError::CapacityIsZero` if the capacity is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Classroom;
+    /// use education_platform_common::{Date, Email, Id};
+    ///
+    /// let instructor = Email::new("teacher@school.edu".to_string()).unwrap();
+    /// let start = Date::new(2025, 2, 3).unwrap();
+    /// let end = Date::new(2025, 6, 27).unwrap();
+    ///
+    /// let classroom = Classroom::new(Id::new().cast(), instructor.clone(), start, end, 30).unwrap();
+    /// assert_eq!(classroom.available_seats(), 30);
+    ///
+    /// assert!(Classroom::new(Id::new().cast(), instructor, end, start, 30).is_err());
+    /// ```
+    pub fn new(
+        course_id: CourseId,
+        instructor: Email,
+        start_date: Date,
+        end_date: Date,
+        capacity: u32,
+    ) -> Result<Self, ClassroomError> {
+        Self::with_id(
+            Id::default(),
+            course_id,
+            instructor,
+            start_date,
+            end_date,
+            capacity,
+            Vec::new(),
+        )
+    }
+
+    /// Creates a `Classroom` with a specific ID (for reconstruction from
+    /// persistence).
+    ///
+    /// Students are enrolled in the given order, so the roster is held to
+    /// the same rules as when they are enrolled one by one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Classroom::new`], plus the errors of
+    /// [`Classroom::enroll`] for the first student that cannot be enrolled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Classroom;
+    /// use education_platform_common::{Date, Email, Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let student = Email::new("ana@school.edu".to_string()).unwrap();
+    ///
+    /// let classroom = Classroom::with_id(
+    ///     id,
+    ///     Id::new().cast(),
+    ///     Email::new("teacher@school.edu".to_string()).unwrap(),
+    ///     Date::new(2025, 2, 3).unwrap(),
+    ///     Date::new(2025, 6, 27).unwrap(),
+    ///     30,
+    ///     vec![student],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(classroom.id(), id);
+    /// assert_eq!(classroom.student_count(), 1);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        course_id: CourseId,
+        instructor: Email,
+        start_date: Date,
+        end_date: Date,
+        capacity: u32,
+        roster: Vec<Email>,
+    ) -> Result<Self, ClassroomError> {
+        if end_date < start_date {
+            return Err(ClassroomError::EndsBeforeStart {
+                start: start_date,
+                end: end_date,
+            });
+        }
+        if capacity == 0 {
+            return Err(ClassroomError::CapacityIsZero);
+        }
+
+        let mut classroom = Self {
+            id,
+            course_id,
+            instructor,
+            start_date,
+            end_date,
+            capacity,
+            roster: Vec::with_capacity(roster.len()),
+            timestamps: Timestamps::now(),
+        };
+        for student in roster {
+            classroom.enroll(student)?;
+        }
+
+        Ok(classroom)
+    }
+
+    /// Enrolls a student in the classroom.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClassroomError::InstructorCannotEnroll` if the student is the
+    /// instructor, `ClassroomError::AlreadyEnrolled` if they are already on
+    /// the roster, or `ClassroomError::ClassroomFull` if there are no seats
+    /// left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Classroom;
+    /// use education_platform_common::{Date, Email, Id};
+    ///
+    /// let instructor = Email::new("teacher@school.edu".to_string()).unwrap();
+    /// let date = Date::new(2025, 3, 14).unwrap();
+    /// let mut classroom =
+    ///     Classroom::new(Id::new().cast(), instructor.clone(), date, date, 20).unwrap();
+    /// let student = Email::new("ana@school.edu".to_string()).unwrap();
+    ///
+    /// classroom.enroll(student.clone()).unwrap();
+    /// assert!(classroom.is_enrolled(&student));
+    ///
+    /// assert!(classroom.enroll(student).is_err());
+    /// assert!(classroom.enroll(instructor).is_err());
+    /// ```
+    pub fn enroll(&mut self, student: Email) -> Result<(), ClassroomError> {
+        if student == self.instructor {
+            return Err(ClassroomError::InstructorCannotEnroll(student.address().to_string()));
+        }
+
+        if self.is_enrolled(&student) {
+            return Err(ClassroomError::AlreadyEnrolled(student.address().to_string()));
+        }
+
+        if self.is_full() {
+            return Err(ClassroomError::ClassroomFull {
+                capacity: self.capacity,
+            });
+        }
+
+        self.roster.push(student);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes a student from the roster, freeing their seat.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClassroomError::NotEnrolled` if the student is not on the
+    /// roster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Classroom;
+    /// use education_platform_common::{Date, Email, Id};
+    ///
+    /// let instructor = Email::new("teacher@school.edu".to_string()).unwrap();
+    /// let date = Date::new(2025, 3, 14).unwrap();
+    /// let mut classroom = Classroom::new(Id::new().cast(), instructor, date, date, 20).unwrap();
+    /// let student = Email::new("ana@school.edu".to_string()).unwrap();
+    ///
+    /// classroom.enroll(student.clone()).unwrap();
+    /// classroom.remove(&student).unwrap();
+    ///
+    /// assert!(!classroom.is_enrolled(&student));
+    /// assert!(classroom.remove(&student).is_err());
+    /// ```
+    pub fn remove(&mut self, student: &Email) -> Result<(), ClassroomError> {
+        let position = self
+            .roster
+            .iter()
+            .position(|enrolled| enrolled == student)
+            .ok_or_else(|| ClassroomError::NotEnrolled(student.address().to_string()))?;
+
+        self.roster.remove(position);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Moves a student from this classroom to another classroom of the same
+    /// course.
+    ///
+    /// The transfer is all or nothing: if the student cannot join `target`,
+    /// both rosters stay as they were.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClassroomError::DifferentCourse` if `target` teaches another
+    /// course, `ClassroomError::SameClassroom` if `target` is this classroom,
+    /// `ClassroomError::NotEnrolled` if the student is not on this roster, or
+    /// the errors of [`Classroom::enroll`] if `target` does not accept them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Classroom;
+    /// use education_platform_common::{Date, Email, Id};
+    ///
+    /// let course_id = Id::new().cast();
+    /// let start = Date::new(2025, 2, 3).unwrap();
+    /// let end = Date::new(2025, 6, 27).unwrap();
+    /// let create_classroom = |instructor: &str| {
+    ///     let instructor = Email::new(instructor.to_string()).unwrap();
+    ///     Classroom::new(course_id, instructor, start, end, 25).unwrap()
+    /// };
+    /// let mut morning = create_classroom("morning@school.edu");
+    /// let mut evening = create_classroom("evening@school.edu");
+    ///
+    /// let student = Email::new("ana@school.edu".to_string()).unwrap();
+    /// morning.enroll(student.clone()).unwrap();
+    ///
+    /// morning.transfer(&student, &mut evening).unwrap();
+    ///
+    /// assert!(!morning.is_enrolled(&student));
+    /// assert!(evening.is_enrolled(&student));
+    /// ```
+    pub fn transfer(&mut self, student: &Email, target: &mut Self) -> Result<(), ClassroomError> {
+        if target.course_id != self.course_id {
+            return Err(ClassroomError::DifferentCourse);
+        }
+        if target.id == self.id {
+            return Err(ClassroomError::SameClassroom);
+        }
+        if !self.is_enrolled(student) {
+            return Err(ClassroomError::NotEnrolled(student.address().to_string()));
+        }
+
+        target.enroll(student.clone())?;
+        self.remove(student)
+    }
+
+    /// Changes the number of seats in the classroom.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClassroomError::CapacityIsZero` if the capacity is zero, or
+    /// `ClassroomError::CapacityBelowRoster` if fewer seats than enrolled
+    /// students are requested; students have to be removed or transferred
+    /// first.
+    pub fn set_capacity(&mut self, capacity: u32) -> Result<(), ClassroomError> {
+        if capacity == 0 {
+            return Err(ClassroomError::CapacityIsZero);
+        }
+        if (capacity as usize) < self.roster.len() {
+            return Err(ClassroomError::CapacityBelowRoster {
+                capacity,
+                enrolled: self.roster.len(),
+            });
+        }
+
+        if capacity != self.capacity {
+            self.capacity = capacity;
+            self.timestamps.touch();
+        }
+        Ok(())
+    }
+
+    /// Hands the classroom over to another instructor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClassroomError::AlreadyEnrolled` if the new instructor is a
+    /// student of the classroom.
+    pub fn change_instructor(&mut self, instructor: Email) -> Result<(), ClassroomError> {
+        if self.is_enrolled(&instructor) {
+            return Err(ClassroomError::AlreadyEnrolled(instructor.address().to_string()));
+        }
+
+        if instructor != self.instructor {
+            self.instructor = instructor;
+            self.timestamps.touch();
+        }
+        Ok(())
+    }
+
+    /// Returns the classroom ID typed as a [`ClassroomId`].
+    #[inline]
+    #[must_use]
+    pub const fn classroom_id(&self) -> ClassroomId {
+        self.id.cast()
+    }
+
+    /// Returns the ID of the course taught in the classroom.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> CourseId {
+        self.course_id
+    }
+
+    /// Returns the instructor teaching the classroom.
+    #[inline]
+    #[must_use]
+    pub const fn instructor(&self) -> &Email {
+        &self.instructor
+    }
+
+    /// Returns the first day of class.
+    #[inline]
+    #[must_use]
+    pub const fn start_date(&self) -> Date {
+        self.start_date
+    }
+
+    /// Returns the last day of class.
+    #[inline]
+    #[must_use]
+    pub const fn end_date(&self) -> Date {
+        self.end_date
+    }
+
+    /// Returns the maximum number of students.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Returns the enrolled students, in enrollment order.
+    #[inline]
+    #[must_use]
+    pub fn roster(&self) -> &[Email] {
+        &self.roster
+    }
+
+    /// Returns the number of enrolled students.
+    #[inline]
+    #[must_use]
+    pub fn student_count(&self) -> usize {
+        self.roster.len()
+    }
+
+    /// Returns the number of seats still available.
+    #[inline]
+    #[must_use]
+    pub fn available_seats(&self) -> u32 {
+        let enrolled = u32::try_from(self.roster.len()).unwrap_or(u32::MAX);
+        self.capacity.saturating_sub(enrolled)
+    }
+
+    /// Returns `true` if no seats are left.
+    #[inline]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.available_seats() == 0
+    }
+
+    /// Returns `true` if the student is on the roster.
+    #[inline]
+    #[must_use]
+    pub fn is_enrolled(&self, student: &Email) -> bool {
+        self.roster.contains(student)
+    }
+
+    /// Returns `true` if classes are held on `date`, start and end dates
+    /// included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Classroom;
+    /// use education_platform_common::{Date, Email, Id};
+    ///
+    /// let instructor = Email::new("teacher@school.edu".to_string()).unwrap();
+    /// let start = Date::new(2025, 2, 3).unwrap();
+    /// let end = Date::new(2025, 6, 27).unwrap();
+    /// let classroom = Classroom::new(Id::new().cast(), instructor, start, end, 30).unwrap();
+    ///
+    /// assert!(classroom.is_in_session(end));
+    /// assert!(!classroom.is_in_session(Date::new(2025, 7, 1).unwrap()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_in_session(&self, date: Date) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+}
+
+impl Entity for Classroom {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Auditable for Classroom {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_test_classroom(course_id: CourseId, capacity: u32) -> Classroom {
+        Classroom::new(
+            course_id,
+            email("teacher@school.edu"),
+            Date::new(2025, 2, 3).unwrap(),
+            Date::new(2025, 6, 27).unwrap(),
+            capacity,
+        )
+        .unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_starts_empty() {
+            let course_id = Id::new().cast();
+
+            let classroom = create_test_classroom(course_id, 25);
+
+            assert_eq!(classroom.course_id(), course_id);
+            assert_eq!(classroom.instructor().address(), "teacher@school.edu");
+            assert_eq!(classroom.capacity(), 25);
+            assert!(classroom.roster().is_empty());
+            assert_eq!(classroom.classroom_id().untyped(), classroom.id());
+        }
+
+        #[test]
+        fn test_same_day_is_allowed() {
+            let date = Date::new(2025, 3, 14).unwrap();
+
+            let result = Classroom::new(Id::new().cast(), email("t@school.edu"), date, date, 5);
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn test_ends_before_start() {
+            let start = Date::new(2025, 6, 27).unwrap();
+            let end = Date::new(2025, 2, 3).unwrap();
+
+            let result = Classroom::new(Id::new().cast(), email("t@school.edu"), start, end, 5);
+
+            assert_eq!(result.unwrap_err(), ClassroomError::EndsBeforeStart { start, end });
+        }
+
+        #[test]
+        fn test_zero_capacity() {
+            let date = Date::new(2025, 3, 14).unwrap();
+
+            let result = Classroom::new(Id::new().cast(), email("t@school.edu"), date, date, 0);
+
+            assert_eq!(result.unwrap_err(), ClassroomError::CapacityIsZero);
+        }
+
+        #[test]
+        fn test_with_id_enforces_roster_rules() {
+            let date = Date::new(2025, 3, 14).unwrap();
+            let roster = vec![email("ana@school.edu"), email("ben@school.edu")];
+
+            let result = Classroom::with_id(
+                Id::new(),
+                Id::new().cast(),
+                email("t@school.edu"),
+                date,
+                date,
+                1,
+                roster,
+            );
+
+            assert_eq!(result.unwrap_err(), ClassroomError::ClassroomFull { capacity: 1 });
+        }
+    }
+
+    mod enroll {
+        use super::*;
+
+        #[test]
+        fn test_fills_seats() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 2);
+
+            classroom.enroll(email("ana@school.edu")).unwrap();
+
+            assert_eq!(classroom.student_count(), 1);
+            assert_eq!(classroom.available_seats(), 1);
+            assert!(!classroom.is_full());
+        }
+
+        #[test]
+        fn test_duplicate_student() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 2);
+            classroom.enroll(email("ana@school.edu")).unwrap();
+
+            let result = classroom.enroll(email("ana@school.edu"));
+
+            assert_eq!(
+                result,
+                Err(ClassroomError::AlreadyEnrolled("ana@school.edu".to_string()))
+            );
+            assert_eq!(classroom.student_count(), 1);
+        }
+
+        #[test]
+        fn test_full_classroom() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 1);
+            classroom.enroll(email("ana@school.edu")).unwrap();
+
+            let result = classroom.enroll(email("ben@school.edu"));
+
+            assert_eq!(result, Err(ClassroomError::ClassroomFull { capacity: 1 }));
+        }
+
+        #[test]
+        fn test_instructor_cannot_enroll() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 2);
+
+            let result = classroom.enroll(email("teacher@school.edu"));
+
+            assert!(matches!(result, Err(ClassroomError::InstructorCannotEnroll(_))));
+            assert!(classroom.roster().is_empty());
+        }
+    }
+
+    mod remove {
+        use super::*;
+
+        #[test]
+        fn test_keeps_other_students_in_order() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 3);
+            for student in ["ana@school.edu", "ben@school.edu", "cleo@school.edu"] {
+                classroom.enroll(email(student)).unwrap();
+            }
+
+            classroom.remove(&email("ben@school.edu")).unwrap();
+
+            assert_eq!(
+                classroom.roster(),
+                &[email("ana@school.edu"), email("cleo@school.edu")]
+            );
+        }
+
+        #[test]
+        fn test_student_not_enrolled() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 3);
+
+            let result = classroom.remove(&email("ana@school.edu"));
+
+            assert_eq!(result, Err(ClassroomError::NotEnrolled("ana@school.edu".to_string())));
+        }
+    }
+
+    mod transfer {
+        use super::*;
+
+        #[test]
+        fn test_moves_the_student() {
+            let course_id = Id::new().cast();
+            let mut from = create_test_classroom(course_id, 2);
+            let mut to = create_test_classroom(course_id, 2);
+            let student = email("ana@school.edu");
+            from.enroll(student.clone()).unwrap();
+
+            from.transfer(&student, &mut to).unwrap();
+
+            assert!(from.roster().is_empty());
+            assert_eq!(to.roster(), &[student]);
+        }
+
+        #[test]
+        fn test_full_target_keeps_both_rosters() {
+            let course_id = Id::new().cast();
+            let mut from = create_test_classroom(course_id, 2);
+            let mut to = create_test_classroom(course_id, 1);
+            let student = email("ana@school.edu");
+            from.enroll(student.clone()).unwrap();
+            to.enroll(email("ben@school.edu")).unwrap();
+
+            let result = from.transfer(&student, &mut to);
+
+            assert_eq!(result, Err(ClassroomError::ClassroomFull { capacity: 1 }));
+            assert!(from.is_enrolled(&student));
+            assert!(!to.is_enrolled(&student));
+        }
+
+        #[test]
+        fn test_different_course() {
+            let mut from = create_test_classroom(Id::new().cast(), 2);
+            let mut to = create_test_classroom(Id::new().cast(), 2);
+            let student = email("ana@school.edu");
+            from.enroll(student.clone()).unwrap();
+
+            let result = from.transfer(&student, &mut to);
+
+            assert_eq!(result, Err(ClassroomError::DifferentCourse));
+            assert!(from.is_enrolled(&student));
+        }
+
+        #[test]
+        fn test_same_classroom() {
+            let mut from = create_test_classroom(Id::new().cast(), 2);
+            let student = email("ana@school.edu");
+            from.enroll(student.clone()).unwrap();
+            let mut copy = from.clone();
+
+            let result = from.transfer(&student, &mut copy);
+
+            assert_eq!(result, Err(ClassroomError::SameClassroom));
+        }
+
+        #[test]
+        fn test_student_not_enrolled() {
+            let course_id = Id::new().cast();
+            let mut from = create_test_classroom(course_id, 2);
+            let mut to = create_test_classroom(course_id, 2);
+
+            let result = from.transfer(&email("ana@school.edu"), &mut to);
+
+            assert!(matches!(result, Err(ClassroomError::NotEnrolled(_))));
+            assert!(to.roster().is_empty());
+        }
+    }
+
+    mod set_capacity {
+        use super::*;
+
+        #[test]
+        fn test_grows_and_shrinks() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 2);
+            classroom.enroll(email("ana@school.edu")).unwrap();
+
+            classroom.set_capacity(10).unwrap();
+            assert_eq!(classroom.available_seats(), 9);
+
+            classroom.set_capacity(1).unwrap();
+            assert!(classroom.is_full());
+        }
+
+        #[test]
+        fn test_below_roster() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 3);
+            classroom.enroll(email("ana@school.edu")).unwrap();
+            classroom.enroll(email("ben@school.edu")).unwrap();
+
+            let result = classroom.set_capacity(1);
+
+            assert_eq!(
+                result,
+                Err(ClassroomError::CapacityBelowRoster {
+                    capacity: 1,
+                    enrolled: 2
+                })
+            );
+            assert_eq!(classroom.capacity(), 3);
+        }
+
+        #[test]
+        fn test_zero() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 3);
+
+            assert_eq!(classroom.set_capacity(0), Err(ClassroomError::CapacityIsZero));
+        }
+    }
+
+    mod change_instructor {
+        use super::*;
+
+        #[test]
+        fn test_new_instructor() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 3);
+
+            classroom
+                .change_instructor(email("substitute@school.edu"))
+                .unwrap();
+
+            assert_eq!(classroom.instructor().address(), "substitute@school.edu");
+        }
+
+        #[test]
+        fn test_student_cannot_become_instructor() {
+            let mut classroom = create_test_classroom(Id::new().cast(), 3);
+            classroom.enroll(email("ana@school.edu")).unwrap();
+
+            let result = classroom.change_instructor(email("ana@school.edu"));
+
+            assert!(matches!(result, Err(ClassroomError::AlreadyEnrolled(_))));
+            assert_eq!(classroom.instructor().address(), "teacher@school.edu");
+        }
+    }
+
+    mod is_in_session {
+        use super::*;
+
+        #[test]
+        fn test_bounds_are_inclusive() {
+            let classroom = create_test_classroom(Id::new().cast(), 3);
+
+            assert!(classroom.is_in_session(Date::new(2025, 2, 3).unwrap()));
+            assert!(classroom.is_in_session(Date::new(2025, 6, 27).unwrap()));
+            assert!(!classroom.is_in_session(Date::new(2025, 2, 2).unwrap()));
+            assert!(!classroom.is_in_session(Date::new(2025, 6, 28).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,438 @@
+use crate::{StudyGroup, StudyGroupError};
+use education_platform_common::{Email, Entity, Id, SimpleName, SimpleNameError};
+use thiserror::Error;
+
+/// Error types for Cohort operations.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CohortError {
+    #[error("Cohort name validation failed: {0}")]
+    NameError(#[from] SimpleNameError),
+
+    #[error("Study group error: {0}")]
+    StudyGroupError(#[from] StudyGroupError),
+
+    #[error("Study group {0} not found in cohort")]
+    GroupNotFound(String),
+
+    #[error("{0} is already in a study group of this cohort")]
+    AlreadyInGroup(String),
+}
+
+/// A group of learners taking a course together on the same timeline.
+///
+/// A cohort owns its study groups and makes sure every learner belongs to
+/// at most one of them.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Cohort, StudyGroup};
+/// use education_platform_common::{Email, Entity, Id};
+///
+/// let mut cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+/// let first = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+/// let second = StudyGroup::new("Early Birds", "Finish by May", 5).unwrap();
+/// let (first_id, second_id) = (first.id(), second.id());
+/// cohort.add_group(first).unwrap();
+/// cohort.add_group(second).unwrap();
+///
+/// let learner = Email::new("a@example.com".to_string()).unwrap();
+/// cohort.join_group(first_id, learner.clone()).unwrap();
+///
+/// assert!(cohort.join_group(second_id, learner).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cohort {
+    id: Id,
+    course_id: Id,
+    name: SimpleName,
+    groups: Vec<StudyGroup>,
+}
+
+impl Cohort {
+    /// Creates a new `Cohort` with no study groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CohortError::NameError` if the name is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Cohort;
+    /// use education_platform_common::Id;
+    ///
+    /// let cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+    /// assert_eq!(cohort.name().as_str(), "Spring 2025");
+    /// assert!(cohort.groups().is_empty());
+    /// ```
+    pub fn new(course_id: Id, name: impl Into<String>) -> Result<Self, CohortError> {
+        Self::with_id(Id::default(), course_id, name, Vec::new())
+    }
+
+    /// Creates a `Cohort` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CohortError::NameError` if the name is not valid.
+    /// Returns `CohortError::AlreadyInGroup` if a learner is in more than one group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Cohort, StudyGroup};
+    /// use education_platform_common::{Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// let cohort = Cohort::with_id(id, Id::new(), "Spring 2025", vec![group]).unwrap();
+    ///
+    /// assert_eq!(cohort.id(), id);
+    /// assert_eq!(cohort.groups().len(), 1);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        course_id: Id,
+        name: impl Into<String>,
+        groups: Vec<StudyGroup>,
+    ) -> Result<Self, CohortError> {
+        let mut cohort = Self {
+            id,
+            course_id,
+            name: SimpleName::new(name.into())?,
+            groups: Vec::with_capacity(groups.len()),
+        };
+        for group in groups {
+            cohort.add_group(group)?;
+        }
+
+        Ok(cohort)
+    }
+
+    /// Adds a study group to the cohort.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CohortError::AlreadyInGroup` if one of the group's members is
+    /// already in another group of the cohort.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Cohort, StudyGroup};
+    /// use education_platform_common::Id;
+    ///
+    /// let mut cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+    /// cohort.add_group(StudyGroup::new("Night Owls", "Finish by June", 5).unwrap()).unwrap();
+    ///
+    /// assert_eq!(cohort.groups().len(), 1);
+    /// ```
+    pub fn add_group(&mut self, group: StudyGroup) -> Result<(), CohortError> {
+        if let Some(member) = group
+            .members()
+            .iter()
+            .find(|member| self.group_of(member).is_some())
+        {
+            return Err(CohortError::AlreadyInGroup(member.address().to_string()));
+        }
+
+        self.groups.push(group);
+        Ok(())
+    }
+
+    /// Adds a learner to one of the cohort's study groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CohortError::GroupNotFound` if no group has the given ID.
+    /// Returns `CohortError::AlreadyInGroup` if the learner is already in a group.
+    /// Returns `CohortError::StudyGroupError` if the group is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Cohort, StudyGroup};
+    /// use education_platform_common::{Email, Entity, Id};
+    ///
+    /// let mut cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// let group_id = group.id();
+    /// cohort.add_group(group).unwrap();
+    ///
+    /// let learner = Email::new("a@example.com".to_string()).unwrap();
+    /// cohort.join_group(group_id, learner.clone()).unwrap();
+    ///
+    /// assert_eq!(cohort.group_of(&learner).unwrap().id(), group_id);
+    /// ```
+    pub fn join_group(&mut self, group_id: Id, learner: Email) -> Result<(), CohortError> {
+        if self.group_of(&learner).is_some() {
+            return Err(CohortError::AlreadyInGroup(learner.address().to_string()));
+        }
+
+        self.find_group_mut(group_id)?.join(learner)?;
+        Ok(())
+    }
+
+    /// Removes a learner from one of the cohort's study groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CohortError::GroupNotFound` if no group has the given ID.
+    /// Returns `CohortError::StudyGroupError` if the learner is not in that group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Cohort, StudyGroup};
+    /// use education_platform_common::{Email, Entity, Id};
+    ///
+    /// let mut cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// let group_id = group.id();
+    /// cohort.add_group(group).unwrap();
+    /// let learner = Email::new("a@example.com".to_string()).unwrap();
+    /// cohort.join_group(group_id, learner.clone()).unwrap();
+    ///
+    /// cohort.leave_group(group_id, &learner).unwrap();
+    /// assert!(cohort.group_of(&learner).is_none());
+    /// ```
+    pub fn leave_group(&mut self, group_id: Id, learner: &Email) -> Result<(), CohortError> {
+        self.find_group_mut(group_id)?.leave(learner)?;
+        Ok(())
+    }
+
+    /// Returns the study group the learner belongs to, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Cohort;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+    /// let learner = Email::new("a@example.com".to_string()).unwrap();
+    ///
+    /// assert!(cohort.group_of(&learner).is_none());
+    /// ```
+    #[must_use]
+    pub fn group_of(&self, learner: &Email) -> Option<&StudyGroup> {
+        self.groups.iter().find(|group| group.is_member(learner))
+    }
+
+    /// Returns the ID of the course the cohort takes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Cohort;
+    /// use education_platform_common::Id;
+    ///
+    /// let course_id = Id::new();
+    /// let cohort = Cohort::new(course_id, "Spring 2025").unwrap();
+    /// assert_eq!(cohort.course_id(), course_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns the cohort name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Cohort;
+    /// use education_platform_common::Id;
+    ///
+    /// let cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+    /// assert_eq!(cohort.name().as_str(), "Spring 2025");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &SimpleName {
+        &self.name
+    }
+
+    /// Returns the study groups, in creation order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Cohort;
+    /// use education_platform_common::Id;
+    ///
+    /// let cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+    /// assert!(cohort.groups().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn groups(&self) -> &[StudyGroup] {
+        &self.groups
+    }
+
+    fn find_group_mut(&mut self, group_id: Id) -> Result<&mut StudyGroup, CohortError> {
+        self.groups
+            .iter_mut()
+            .find(|group| group.id() == group_id)
+            .ok_or_else(|| CohortError::GroupNotFound(group_id.to_string()))
+    }
+}
+
+impl Entity for Cohort {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_cohort_with_groups(count: usize) -> (Cohort, Vec<Id>) {
+        let mut cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+        let ids = (0..count)
+            .map(|index| {
+                let group = StudyGroup::new(format!("Group {index}"), "Finish", 2).unwrap();
+                let id = group.id();
+                cohort.add_group(group).unwrap();
+                id
+            })
+            .collect();
+        (cohort, ids)
+    }
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    mod groups {
+        use super::*;
+
+        #[test]
+        fn test_add_group_keeps_creation_order() {
+            let (cohort, ids) = create_cohort_with_groups(2);
+
+            let group_ids: Vec<Id> = cohort.groups().iter().map(Entity::id).collect();
+
+            assert_eq!(group_ids, ids);
+        }
+
+        #[test]
+        fn test_add_group_rejects_member_of_another_group() {
+            let (mut cohort, ids) = create_cohort_with_groups(1);
+            cohort
+                .join_group(ids[0], create_email("a@example.com"))
+                .unwrap();
+            let mut group = StudyGroup::new("Late Group", "Finish", 2).unwrap();
+            group.join(create_email("a@example.com")).unwrap();
+
+            assert_eq!(
+                cohort.add_group(group).unwrap_err(),
+                CohortError::AlreadyInGroup("a@example.com".to_string())
+            );
+            assert_eq!(cohort.groups().len(), 1);
+        }
+
+        #[test]
+        fn test_with_id_rejects_learner_in_two_groups() {
+            let mut first = StudyGroup::new("First", "Finish", 2).unwrap();
+            let mut second = StudyGroup::new("Second", "Finish", 2).unwrap();
+            first.join(create_email("a@example.com")).unwrap();
+            second.join(create_email("a@example.com")).unwrap();
+
+            let result = Cohort::with_id(Id::new(), Id::new(), "Spring", vec![first, second]);
+
+            assert!(matches!(result, Err(CohortError::AlreadyInGroup(_))));
+        }
+    }
+
+    mod membership {
+        use super::*;
+
+        #[test]
+        fn test_join_group_adds_learner() {
+            let (mut cohort, ids) = create_cohort_with_groups(1);
+
+            cohort
+                .join_group(ids[0], create_email("a@example.com"))
+                .unwrap();
+
+            assert_eq!(
+                cohort
+                    .group_of(&create_email("a@example.com"))
+                    .unwrap()
+                    .id(),
+                ids[0]
+            );
+        }
+
+        #[test]
+        fn test_join_second_group_in_same_cohort_is_rejected() {
+            let (mut cohort, ids) = create_cohort_with_groups(2);
+            cohort
+                .join_group(ids[0], create_email("a@example.com"))
+                .unwrap();
+
+            let result = cohort.join_group(ids[1], create_email("a@example.com"));
+
+            assert_eq!(
+                result.unwrap_err(),
+                CohortError::AlreadyInGroup("a@example.com".to_string())
+            );
+            assert!(!cohort.groups()[1].is_member(&create_email("a@example.com")));
+        }
+
+        #[test]
+        fn test_learner_can_switch_groups_after_leaving() {
+            let (mut cohort, ids) = create_cohort_with_groups(2);
+            let learner = create_email("a@example.com");
+            cohort.join_group(ids[0], learner.clone()).unwrap();
+
+            cohort.leave_group(ids[0], &learner).unwrap();
+            cohort.join_group(ids[1], learner.clone()).unwrap();
+
+            assert_eq!(cohort.group_of(&learner).unwrap().id(), ids[1]);
+        }
+
+        #[test]
+        fn test_join_unknown_group_returns_error() {
+            let (mut cohort, _) = create_cohort_with_groups(1);
+
+            let result = cohort.join_group(Id::new(), create_email("a@example.com"));
+
+            assert!(matches!(result, Err(CohortError::GroupNotFound(_))));
+        }
+
+        #[test]
+        fn test_join_full_group_surfaces_group_error() {
+            let (mut cohort, ids) = create_cohort_with_groups(1);
+            cohort
+                .join_group(ids[0], create_email("a@example.com"))
+                .unwrap();
+            cohort
+                .join_group(ids[0], create_email("b@example.com"))
+                .unwrap();
+
+            let result = cohort.join_group(ids[0], create_email("c@example.com"));
+
+            assert_eq!(
+                result.unwrap_err(),
+                CohortError::StudyGroupError(StudyGroupError::GroupFull { member_cap: 2 })
+            );
+        }
+
+        #[test]
+        fn test_leave_group_learner_not_in_it_returns_error() {
+            let (mut cohort, ids) = create_cohort_with_groups(1);
+
+            let result = cohort.leave_group(ids[0], &create_email("a@example.com"));
+
+            assert!(matches!(
+                result,
+                Err(CohortError::StudyGroupError(StudyGroupError::NotMember(_)))
+            ));
+        }
+    }
+}
@@ -0,0 +1,792 @@
+use crate::{Assignment, Classroom, CourseId, CourseProgress};
+use education_platform_common::{
+    Email, Percentage, Score, SimpleName, SimpleNameConfig, SimpleNameError,
+};
+use std::fmt::Write;
+use thiserror::Error;
+
+/// Error types for Gradebook operations.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GradebookError {
+    #[error("Column title validation failed: {0}")]
+    TitleError(#[from] SimpleNameError),
+
+    #[error("Gradebook already has a column titled {0}")]
+    DuplicateColumn(String),
+
+    #[error("Assignment belongs to a different course than the classroom")]
+    DifferentCourse,
+}
+
+/// What a gradebook column is computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GradeKind {
+    Quiz,
+    Assignment,
+    Progress,
+}
+
+impl GradeKind {
+    /// Returns the kind as a lowercase word, such as `"quiz"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Quiz => "quiz",
+            Self::Assignment => "assignment",
+            Self::Progress => "progress",
+        }
+    }
+}
+
+/// A student's score in one quiz attempt, as handed over by the quiz
+/// engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuizResult {
+    student: Email,
+    score: Score,
+}
+
+impl QuizResult {
+    /// Creates a quiz result.
+    #[must_use]
+    pub const fn new(student: Email, score: Score) -> Self {
+        Self { student, score }
+    }
+
+    /// Returns the student who took the quiz.
+    #[inline]
+    #[must_use]
+    pub const fn student(&self) -> &Email {
+        &self.student
+    }
+
+    /// Returns the score of the attempt.
+    #[inline]
+    #[must_use]
+    pub const fn score(&self) -> Score {
+        self.score
+    }
+}
+
+/// One column of the gradebook, with a grade per student in roster order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradeColumn {
+    title: SimpleName,
+    kind: GradeKind,
+    grades: Vec<Option<Percentage>>,
+}
+
+impl GradeColumn {
+    /// Returns the column title.
+    #[inline]
+    #[must_use]
+    pub const fn title(&self) -> &SimpleName {
+        &self.title
+    }
+
+    /// Returns what the column is computed from.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> GradeKind {
+        self.kind
+    }
+
+    /// Returns the grades in roster order; `None` marks a student without
+    /// a grade yet.
+    #[inline]
+    #[must_use]
+    pub fn grades(&self) -> &[Option<Percentage>] {
+        &self.grades
+    }
+}
+
+/// One student's standing across every column of a [`Gradebook`].
+///
+/// Averages only cover the grades the student has; missing grades are
+/// counted apart instead of weighing as zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudentSummary {
+    student: Email,
+    quiz_average: Option<Percentage>,
+    assignment_average: Option<Percentage>,
+    progress: Option<Percentage>,
+    overall: Option<Percentage>,
+    missing_grades: usize,
+}
+
+impl StudentSummary {
+    /// Returns the student.
+    #[inline]
+    #[must_use]
+    pub const fn student(&self) -> &Email {
+        &self.student
+    }
+
+    /// Returns the average of the student's quiz grades.
+    #[inline]
+    #[must_use]
+    pub const fn quiz_average(&self) -> Option<Percentage> {
+        self.quiz_average
+    }
+
+    /// Returns the average of the student's assignment grades.
+    #[inline]
+    #[must_use]
+    pub const fn assignment_average(&self) -> Option<Percentage> {
+        self.assignment_average
+    }
+
+    /// Returns how much of the course the student has completed.
+    #[inline]
+    #[must_use]
+    pub const fn progress(&self) -> Option<Percentage> {
+        self.progress
+    }
+
+    /// Returns the average of every grade the student has, whatever its
+    /// column kind.
+    #[inline]
+    #[must_use]
+    pub const fn overall(&self) -> Option<Percentage> {
+        self.overall
+    }
+
+    /// Returns how many columns have no grade for the student.
+    #[inline]
+    #[must_use]
+    pub const fn missing_grades(&self) -> usize {
+        self.missing_grades
+    }
+}
+
+/// A grade matrix for a [`Classroom`]: one row per student on the roster
+/// and one column per quiz, assignment or progress snapshot.
+///
+/// Every grade is kept as a percentage so quizzes and assignments scored
+/// out of different maximums can be compared and averaged. Records about
+/// people outside the roster are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Classroom, Gradebook, QuizResult};
+/// use education_platform_common::{Date, Email, Id, Score};
+///
+/// let date = Date::new(2025, 3, 14).unwrap();
+/// let teacher = Email::new("teacher@school.edu".to_string()).unwrap();
+/// let ana = Email::new("ana@school.edu".to_string()).unwrap();
+/// let mut classroom = Classroom::new(Id::new().cast(), teacher, date, date, 30).unwrap();
+/// classroom.enroll(ana.clone()).unwrap();
+///
+/// let mut gradebook = Gradebook::new(&classroom);
+/// gradebook
+///     .add_quiz("Ownership".to_string(), &[QuizResult::new(ana.clone(), Score::new(8.0, 10.0).unwrap())])
+///     .unwrap();
+///
+/// assert_eq!(gradebook.to_csv(), "student,Ownership,overall\r\nana@school.edu,80%,80%\r\n");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradebook {
+    course_id: CourseId,
+    students: Vec<Email>,
+    columns: Vec<GradeColumn>,
+}
+
+impl Gradebook {
+    /// Title of the column added by [`Gradebook::add_progress`].
+    pub const PROGRESS_TITLE: &'static str = "Course progress";
+
+    /// Creates an empty gradebook with a row per student of the classroom,
+    /// in roster order.
+    #[must_use]
+    pub fn new(classroom: &Classroom) -> Self {
+        Self {
+            course_id: classroom.course_id(),
+            students: classroom.roster().to_vec(),
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds a column with the results of a quiz.
+    ///
+    /// When a student took the quiz several times, their best attempt
+    /// counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GradebookError::TitleError` if the title is empty or longer
+    /// than 50 characters, or `GradebookError::DuplicateColumn` if a column
+    /// already has the title.
+    pub fn add_quiz(
+        &mut self,
+        title: String,
+        results: &[QuizResult],
+    ) -> Result<(), GradebookError> {
+        let grades = self.best_per_student(
+            results
+                .iter()
+                .map(|result| (result.student(), result.score().percentage())),
+        );
+        self.push_column(title, GradeKind::Quiz, grades)
+    }
+
+    /// Adds a column with the graded submissions of an assignment.
+    ///
+    /// Submissions that are not graded yet leave the cell empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GradebookError::DifferentCourse` if the assignment is not
+    /// from the classroom's course, plus the title errors of
+    /// [`Gradebook::add_quiz`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, Classroom, Gradebook, Submission, SubmissionContent};
+    /// use education_platform_common::{Date, DateTime, Email, Entity, Id};
+    ///
+    /// let date = Date::new(2025, 3, 14).unwrap();
+    /// let teacher = Email::new("teacher@school.edu".to_string()).unwrap();
+    /// let mut classroom = Classroom::new(Id::new().cast(), teacher, date, date, 30).unwrap();
+    /// classroom.enroll(Email::new("ana@school.edu".to_string()).unwrap()).unwrap();
+    ///
+    /// let due_at = DateTime::new(2025, 3, 14, 23, 59, 59).unwrap();
+    /// let mut essay = Assignment::new(
+    ///     classroom.course_id().untyped(),
+    ///     "Essay".to_string(),
+    ///     due_at,
+    ///     20,
+    /// ).unwrap();
+    /// let content = SubmissionContent::text("My essay".to_string()).unwrap();
+    /// let submission = Submission::new("ana@school.edu".to_string(), content).unwrap();
+    /// let submission_id = submission.id();
+    /// essay.add_submission(submission).unwrap();
+    /// essay.submit(submission_id, DateTime::new(2025, 3, 14, 20, 0, 0).unwrap()).unwrap();
+    /// essay.grade_submission(submission_id, 15, None).unwrap();
+    ///
+    /// let mut gradebook = Gradebook::new(&classroom);
+    /// gradebook.add_assignment("Essay".to_string(), &essay).unwrap();
+    ///
+    /// assert_eq!(gradebook.columns()[0].grades()[0].unwrap().value(), 75.0);
+    /// ```
+    pub fn add_assignment(
+        &mut self,
+        title: String,
+        assignment: &Assignment,
+    ) -> Result<(), GradebookError> {
+        if assignment.course_id() != self.course_id.untyped() {
+            return Err(GradebookError::DifferentCourse);
+        }
+
+        let grades =
+            self.best_per_student(assignment.submissions().iter().filter_map(|submission| {
+                submission
+                    .score()
+                    .map(|score| (submission.student_email(), score.percentage()))
+            }));
+        self.push_column(title, GradeKind::Assignment, grades)
+    }
+
+    /// Adds a column, titled [`Gradebook::PROGRESS_TITLE`], with how much
+    /// of the course each student has completed.
+    ///
+    /// Enrollments in other courses are ignored, and when a student has
+    /// several enrollments in the course the most advanced one counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GradebookError::DuplicateColumn` if the progress column was
+    /// already added.
+    pub fn add_progress(&mut self, enrollments: &[CourseProgress]) -> Result<(), GradebookError> {
+        let course_id = self.course_id.untyped();
+        let grades = self.best_per_student(
+            enrollments
+                .iter()
+                .filter(|enrollment| enrollment.course_id() == Some(course_id))
+                .map(|enrollment| (enrollment.user_email(), enrollment.percentage_completed())),
+        );
+        self.push_column(Self::PROGRESS_TITLE.to_string(), GradeKind::Progress, grades)
+    }
+
+    /// Returns the students, in row order.
+    #[inline]
+    #[must_use]
+    pub fn students(&self) -> &[Email] {
+        &self.students
+    }
+
+    /// Returns the columns, in the order they were added.
+    #[inline]
+    #[must_use]
+    pub fn columns(&self) -> &[GradeColumn] {
+        &self.columns
+    }
+
+    /// Returns a student's grade in the column with the given title.
+    ///
+    /// Returns `None` if the student or the column is unknown, or if the
+    /// student has no grade in it.
+    #[must_use]
+    pub fn grade(&self, student: &Email, column_title: &str) -> Option<Percentage> {
+        let row = self.row_of(student)?;
+        self.columns
+            .iter()
+            .find(|column| column.title.as_str() == column_title)
+            .and_then(|column| column.grades[row])
+    }
+
+    /// Computes a student's averages across the gradebook, or `None` if
+    /// they are not on the roster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Classroom, Gradebook, QuizResult};
+    /// use education_platform_common::{Date, Email, Id, Score};
+    ///
+    /// let date = Date::new(2025, 3, 14).unwrap();
+    /// let teacher = Email::new("teacher@school.edu".to_string()).unwrap();
+    /// let ana = Email::new("ana@school.edu".to_string()).unwrap();
+    /// let mut classroom = Classroom::new(Id::new().cast(), teacher, date, date, 30).unwrap();
+    /// classroom.enroll(ana.clone()).unwrap();
+    ///
+    /// let mut gradebook = Gradebook::new(&classroom);
+    /// let result = |points| [QuizResult::new(ana.clone(), Score::new(points, 10.0).unwrap())];
+    /// gradebook.add_quiz("Quiz 1".to_string(), &result(6.0)).unwrap();
+    /// gradebook.add_quiz("Quiz 2".to_string(), &result(10.0)).unwrap();
+    /// gradebook.add_quiz("Quiz 3".to_string(), &[]).unwrap();
+    ///
+    /// let summary = gradebook.summary(&ana).unwrap();
+    /// assert_eq!(summary.quiz_average().unwrap().value(), 80.0);
+    /// assert_eq!(summary.missing_grades(), 1);
+    /// ```
+    #[must_use]
+    pub fn summary(&self, student: &Email) -> Option<StudentSummary> {
+        self.row_of(student).map(|row| self.summarize(row))
+    }
+
+    /// Computes the summary of every student, in roster order.
+    #[must_use]
+    pub fn summaries(&self) -> Vec<StudentSummary> {
+        (0..self.students.len())
+            .map(|row| self.summarize(row))
+            .collect()
+    }
+
+    /// Exports the gradebook as CSV.
+    ///
+    /// The first column is the student email, then one column per
+    /// gradebook column headed by its title, and a last `overall` column.
+    /// Grades are written as whole percentages and missing grades are empty
+    /// cells. Cells are quoted as RFC 4180 describes and rows end in CRLF.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("student");
+        for column in &self.columns {
+            csv.push(',');
+            csv.push_str(&escape_cell(column.title.as_str()));
+        }
+        csv.push_str(",overall\r\n");
+
+        for (row, student) in self.students.iter().enumerate() {
+            csv.push_str(&escape_cell(student.address()));
+            for column in &self.columns {
+                csv.push(',');
+                if let Some(grade) = column.grades[row] {
+                    let _ = write!(csv, "{grade}");
+                }
+            }
+            csv.push(',');
+            if let Some(overall) = self.summarize(row).overall {
+                let _ = write!(csv, "{overall}");
+            }
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    fn push_column(
+        &mut self,
+        title: String,
+        kind: GradeKind,
+        grades: Vec<Option<Percentage>>,
+    ) -> Result<(), GradebookError> {
+        let title = SimpleName::with_config(title, SimpleNameConfig::new(1, 50))?;
+        if self
+            .columns
+            .iter()
+            .any(|column| column.title.as_str() == title.as_str())
+        {
+            return Err(GradebookError::DuplicateColumn(title.as_str().to_string()));
+        }
+
+        self.columns.push(GradeColumn { title, kind, grades });
+        Ok(())
+    }
+
+    fn best_per_student<'a>(
+        &self,
+        grades: impl Iterator<Item = (&'a Email, Percentage)>,
+    ) -> Vec<Option<Percentage>> {
+        let mut best: Vec<Option<Percentage>> = vec![None; self.students.len()];
+        for (student, grade) in grades {
+            if let Some(row) = self.row_of(student) {
+                let cell = &mut best[row];
+                if cell.is_none_or(|current| grade.value() > current.value()) {
+                    *cell = Some(grade);
+                }
+            }
+        }
+        best
+    }
+
+    fn row_of(&self, student: &Email) -> Option<usize> {
+        self.students
+            .iter()
+            .position(|enrolled| enrolled == student)
+    }
+
+    fn summarize(&self, row: usize) -> StudentSummary {
+        let grades_of = |kind: Option<GradeKind>| {
+            self.columns
+                .iter()
+                .filter(move |column| kind.is_none_or(|kind| column.kind == kind))
+                .filter_map(move |column| column.grades[row])
+        };
+        let average = |kind: Option<GradeKind>| {
+            let grades: Vec<Percentage> = grades_of(kind).collect();
+            (!grades.is_empty()).then(|| Percentage::average(grades))
+        };
+
+        StudentSummary {
+            student: self.students[row].clone(),
+            quiz_average: average(Some(GradeKind::Quiz)),
+            assignment_average: average(Some(GradeKind::Assignment)),
+            progress: average(Some(GradeKind::Progress)),
+            overall: average(None),
+            missing_grades: self
+                .columns
+                .iter()
+                .filter(|column| column.grades[row].is_none())
+                .count(),
+        }
+    }
+}
+
+/// Quotes a cell containing a delimiter, quote or line break (RFC 4180).
+fn escape_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Course, Lesson, Submission, SubmissionContent};
+    use education_platform_common::{Date, DateTime, Entity, Id};
+
+    fn email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_test_classroom(course_id: CourseId, students: &[&str]) -> Classroom {
+        let date = Date::new(2025, 3, 14).unwrap();
+        let mut classroom =
+            Classroom::new(course_id, email("teacher@school.edu"), date, date, 30).unwrap();
+        for student in students {
+            classroom.enroll(email(student)).unwrap();
+        }
+        classroom
+    }
+
+    fn quiz_result(student: &str, points: f64) -> QuizResult {
+        QuizResult::new(email(student), Score::new(points, 10.0).unwrap())
+    }
+
+    fn create_test_assignment(course_id: CourseId, grades: &[(&str, Option<u32>)]) -> Assignment {
+        let due_at = DateTime::new(2025, 3, 14, 23, 59, 59).unwrap();
+        let mut assignment =
+            Assignment::new(course_id.untyped(), "Essay".to_string(), due_at, 20).unwrap();
+        for (student, grade) in grades {
+            let content = SubmissionContent::text("Answer".to_string()).unwrap();
+            let submission = Submission::new(student.to_string(), content).unwrap();
+            let submission_id = submission.id();
+            assignment.add_submission(submission).unwrap();
+            assignment
+                .submit(submission_id, DateTime::new(2025, 3, 14, 20, 0, 0).unwrap())
+                .unwrap();
+            if let Some(grade) = grade {
+                assignment
+                    .grade_submission(submission_id, *grade, None)
+                    .unwrap();
+            }
+        }
+        assignment
+    }
+
+    fn percentage(value: f64) -> Option<Percentage> {
+        Some(Percentage::new(value).unwrap())
+    }
+
+    mod add_quiz {
+        use super::*;
+
+        #[test]
+        fn test_best_attempt_counts() {
+            let classroom = create_test_classroom(Id::new().cast(), &["ana@school.edu"]);
+            let mut gradebook = Gradebook::new(&classroom);
+
+            gradebook
+                .add_quiz(
+                    "Ownership".to_string(),
+                    &[
+                        quiz_result("ana@school.edu", 4.0),
+                        quiz_result("ana@school.edu", 9.0),
+                        quiz_result("ana@school.edu", 7.0),
+                    ],
+                )
+                .unwrap();
+
+            assert_eq!(
+                gradebook.grade(&email("ana@school.edu"), "Ownership"),
+                percentage(90.0)
+            );
+        }
+
+        #[test]
+        fn test_students_outside_roster_are_ignored() {
+            let classroom = create_test_classroom(Id::new().cast(), &["ana@school.edu"]);
+            let mut gradebook = Gradebook::new(&classroom);
+
+            gradebook
+                .add_quiz("Ownership".to_string(), &[quiz_result("eve@school.edu", 10.0)])
+                .unwrap();
+
+            assert_eq!(gradebook.columns()[0].grades(), &[None]);
+            assert_eq!(gradebook.grade(&email("eve@school.edu"), "Ownership"), None);
+        }
+
+        #[test]
+        fn test_duplicate_title() {
+            let classroom = create_test_classroom(Id::new().cast(), &[]);
+            let mut gradebook = Gradebook::new(&classroom);
+            gradebook.add_quiz("Ownership".to_string(), &[]).unwrap();
+
+            let result = gradebook.add_quiz("Ownership".to_string(), &[]);
+
+            assert_eq!(result, Err(GradebookError::DuplicateColumn("Ownership".to_string())));
+            assert_eq!(gradebook.columns().len(), 1);
+        }
+
+        #[test]
+        fn test_empty_title() {
+            let classroom = create_test_classroom(Id::new().cast(), &[]);
+            let mut gradebook = Gradebook::new(&classroom);
+
+            let result = gradebook.add_quiz("   ".to_string(), &[]);
+
+            assert!(matches!(result, Err(GradebookError::TitleError(_))));
+        }
+    }
+
+    mod add_assignment {
+        use super::*;
+
+        #[test]
+        fn test_ungraded_submissions_are_empty() {
+            let course_id = Id::new().cast();
+            let classroom = create_test_classroom(course_id, &["ana@school.edu", "ben@school.edu"]);
+            let assignment = create_test_assignment(
+                course_id,
+                &[("ana@school.edu", Some(18)), ("ben@school.edu", None)],
+            );
+            let mut gradebook = Gradebook::new(&classroom);
+
+            gradebook
+                .add_assignment("Essay".to_string(), &assignment)
+                .unwrap();
+
+            assert_eq!(gradebook.columns()[0].kind(), GradeKind::Assignment);
+            assert_eq!(gradebook.columns()[0].grades(), &[percentage(90.0), None]);
+        }
+
+        #[test]
+        fn test_other_course_is_rejected() {
+            let classroom = create_test_classroom(Id::new().cast(), &["ana@school.edu"]);
+            let assignment = create_test_assignment(Id::new().cast(), &[]);
+            let mut gradebook = Gradebook::new(&classroom);
+
+            let result = gradebook.add_assignment("Essay".to_string(), &assignment);
+
+            assert_eq!(result, Err(GradebookError::DifferentCourse));
+            assert!(gradebook.columns().is_empty());
+        }
+    }
+
+    mod add_progress {
+        use super::*;
+
+        fn create_test_course() -> Course {
+            let lessons = (0..4)
+                .map(|index| {
+                    Lesson::new(
+                        format!("Lesson {}", index),
+                        600,
+                        format!("https://example.com/{}.mp4", index),
+                        index,
+                    )
+                    .unwrap()
+                })
+                .collect();
+            let chapter = Chapter::new("Chapter One".to_string(), 0, lessons).unwrap();
+            Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+        }
+
+        fn enroll(course: &Course, student: &str, lessons_ended: usize) -> CourseProgress {
+            let mut progress = CourseProgress::from_course(course, student).unwrap();
+            for lesson in course.chapters()[0].lessons().iter().take(lessons_ended) {
+                progress.start_lesson(lesson.lesson_id());
+                progress.end_lesson(lesson.lesson_id()).unwrap();
+            }
+            progress
+        }
+
+        #[test]
+        fn test_most_advanced_enrollment_of_the_course_counts() {
+            let course = create_test_course();
+            let other = create_test_course();
+            let classroom = create_test_classroom(course.course_id(), &["ana@school.edu"]);
+            let mut gradebook = Gradebook::new(&classroom);
+
+            gradebook
+                .add_progress(&[
+                    enroll(&course, "ana@school.edu", 1),
+                    enroll(&course, "ana@school.edu", 3),
+                    enroll(&other, "ana@school.edu", 4),
+                ])
+                .unwrap();
+
+            assert_eq!(
+                gradebook.grade(&email("ana@school.edu"), Gradebook::PROGRESS_TITLE),
+                percentage(75.0)
+            );
+        }
+
+        #[test]
+        fn test_added_only_once() {
+            let classroom = create_test_classroom(Id::new().cast(), &[]);
+            let mut gradebook = Gradebook::new(&classroom);
+            gradebook.add_progress(&[]).unwrap();
+
+            assert!(matches!(
+                gradebook.add_progress(&[]),
+                Err(GradebookError::DuplicateColumn(_))
+            ));
+        }
+    }
+
+    mod summary {
+        use super::*;
+
+        #[test]
+        fn test_averages_per_kind() {
+            let course_id = Id::new().cast();
+            let classroom = create_test_classroom(course_id, &["ana@school.edu"]);
+            let assignment = create_test_assignment(course_id, &[("ana@school.edu", Some(6))]);
+            let mut gradebook = Gradebook::new(&classroom);
+            gradebook
+                .add_quiz("Quiz 1".to_string(), &[quiz_result("ana@school.edu", 10.0)])
+                .unwrap();
+            gradebook
+                .add_quiz("Quiz 2".to_string(), &[quiz_result("ana@school.edu", 8.0)])
+                .unwrap();
+            gradebook
+                .add_assignment("Essay".to_string(), &assignment)
+                .unwrap();
+            gradebook.add_progress(&[]).unwrap();
+
+            let summary = gradebook.summary(&email("ana@school.edu")).unwrap();
+
+            assert_eq!(summary.quiz_average(), percentage(90.0));
+            assert_eq!(summary.assignment_average(), percentage(30.0));
+            assert_eq!(summary.progress(), None);
+            assert_eq!(summary.overall(), percentage(70.0));
+            assert_eq!(summary.missing_grades(), 1);
+        }
+
+        #[test]
+        fn test_student_without_grades() {
+            let classroom = create_test_classroom(Id::new().cast(), &["ana@school.edu"]);
+            let mut gradebook = Gradebook::new(&classroom);
+            gradebook.add_quiz("Quiz 1".to_string(), &[]).unwrap();
+
+            let summary = gradebook.summary(&email("ana@school.edu")).unwrap();
+
+            assert_eq!(summary.overall(), None);
+            assert_eq!(summary.missing_grades(), 1);
+        }
+
+        #[test]
+        fn test_unknown_student() {
+            let classroom = create_test_classroom(Id::new().cast(), &["ana@school.edu"]);
+            let gradebook = Gradebook::new(&classroom);
+
+            assert!(gradebook.summary(&email("eve@school.edu")).is_none());
+        }
+
+        #[test]
+        fn test_summaries_follow_roster_order() {
+            let classroom =
+                create_test_classroom(Id::new().cast(), &["ben@school.edu", "ana@school.edu"]);
+            let gradebook = Gradebook::new(&classroom);
+
+            let summaries = gradebook.summaries();
+            let students: Vec<&str> = summaries
+                .iter()
+                .map(|summary| summary.student().address())
+                .collect();
+
+            assert_eq!(students, vec!["ben@school.edu", "ana@school.edu"]);
+        }
+    }
+
+    mod to_csv {
+        use super::*;
+
+        #[test]
+        fn test_matrix_with_empty_cells() {
+            let classroom =
+                create_test_classroom(Id::new().cast(), &["ana@school.edu", "ben@school.edu"]);
+            let mut gradebook = Gradebook::new(&classroom);
+            gradebook
+                .add_quiz("Quiz 1".to_string(), &[quiz_result("ana@school.edu", 7.0)])
+                .unwrap();
+            gradebook
+                .add_quiz("Quiz 2".to_string(), &[quiz_result("ana@school.edu", 9.0)])
+                .unwrap();
+
+            assert_eq!(
+                gradebook.to_csv(),
+                "student,Quiz 1,Quiz 2,overall\r\n\
+                 ana@school.edu,70%,90%,80%\r\n\
+                 ben@school.edu,,,\r\n"
+            );
+        }
+
+        #[test]
+        fn test_titles_are_escaped() {
+            let classroom = create_test_classroom(Id::new().cast(), &[]);
+            let mut gradebook = Gradebook::new(&classroom);
+            gradebook
+                .add_quiz("Traits, Generics".to_string(), &[])
+                .unwrap();
+
+            assert_eq!(gradebook.to_csv(), "student,\"Traits, Generics\",overall\r\n");
+        }
+    }
+}
@@ -0,0 +1,840 @@
+use education_platform_common::{Email, Entity, Id, TimeRange, Url, UrlError};
+use thiserror::Error;
+
+/// Error types for LiveSession operations.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LiveSessionError {
+    #[error("Meeting URL validation failed: {0}")]
+    MeetingUrlError(#[from] UrlError),
+
+    #[error("Capacity must be different from zero")]
+    CapacityIsZero,
+
+    #[error("Session is full, capacity is {capacity}")]
+    SessionFull { capacity: u32 },
+
+    #[error("Attendee {0} is already registered")]
+    AlreadyRegistered(String),
+
+    #[error("Attendee {0} is not registered")]
+    NotRegistered(String),
+
+    #[error("Session is cancelled")]
+    SessionCancelled,
+}
+
+/// A scheduled live class that learners of a course register to attend.
+///
+/// `LiveSession` is the building block of cohort-based courses: a meeting
+/// held at a fixed time with a limited number of seats. The attendee list
+/// never exceeds the capacity, never holds the same learner twice, and is
+/// frozen once the session is cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::LiveSession;
+/// use education_platform_common::{DateTime, Email, Id, TimeRange};
+///
+/// let scheduled = TimeRange::new(
+///     DateTime::new(2024, 12, 9, 18, 0, 0).unwrap(),
+///     DateTime::new(2024, 12, 9, 19, 0, 0).unwrap(),
+/// ).unwrap();
+/// let mut session = LiveSession::new(
+///     Id::new(),
+///     scheduled,
+///     "https://meet.example.com/rust-101".to_string(),
+///     1,
+/// ).unwrap();
+///
+/// let alice = Email::new("alice@example.com".to_string()).unwrap();
+/// let bob = Email::new("bob@example.com".to_string()).unwrap();
+///
+/// session.register(alice).unwrap();
+/// assert!(session.is_full());
+/// assert!(session.register(bob).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiveSession {
+    id: Id,
+    course_id: Id,
+    scheduled: TimeRange,
+    meeting_url: Url,
+    capacity: u32,
+    attendees: Vec<Email>,
+    cancelled: bool,
+}
+
+impl LiveSession {
+    /// Creates a new `LiveSession` with no attendees.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LiveSessionError::MeetingUrlError` if the meeting URL is not valid.
+    /// Returns `LiveSessionError::CapacityIsZero` if the capacity is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    ///
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     30,
+    /// ).unwrap();
+    /// assert_eq!(session.available_seats(), 30);
+    ///
+    /// let invalid = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     0,
+    /// );
+    /// assert!(invalid.is_err());
+    /// ```
+    pub fn new(
+        course_id: Id,
+        scheduled: TimeRange,
+        meeting_url: String,
+        capacity: u32,
+    ) -> Result<Self, LiveSessionError> {
+        Self::with_id(
+            Id::default(),
+            course_id,
+            scheduled,
+            meeting_url,
+            capacity,
+            Vec::new(),
+            false,
+        )
+    }
+
+    /// Creates a `LiveSession` with a specific ID (for reconstruction from persistence).
+    ///
+    /// Attendees are registered in the given order, so the same invariants
+    /// apply as when they register one by one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LiveSessionError::MeetingUrlError` if the meeting URL is not valid.
+    /// Returns `LiveSessionError::CapacityIsZero` if the capacity is zero.
+    /// Returns `LiveSessionError::SessionFull` if there are more attendees than seats.
+    /// Returns `LiveSessionError::AlreadyRegistered` if an attendee appears twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Email, Entity, Id, TimeRange};
+    ///
+    /// let id = Id::new();
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let attendee = Email::new("alice@example.com".to_string()).unwrap();
+    ///
+    /// let session = LiveSession::with_id(
+    ///     id,
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    ///     vec![attendee],
+    ///     false,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(session.id(), id);
+    /// assert_eq!(session.attendee_count(), 1);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        course_id: Id,
+        scheduled: TimeRange,
+        meeting_url: String,
+        capacity: u32,
+        attendees: Vec<Email>,
+        cancelled: bool,
+    ) -> Result<Self, LiveSessionError> {
+        let meeting_url = Url::new(meeting_url)?;
+        if capacity == 0 {
+            return Err(LiveSessionError::CapacityIsZero);
+        }
+
+        let mut session = Self {
+            id,
+            course_id,
+            scheduled,
+            meeting_url,
+            capacity,
+            attendees: Vec::with_capacity(attendees.len()),
+            cancelled: false,
+        };
+        for attendee in attendees {
+            session.register(attendee)?;
+        }
+        session.cancelled = cancelled;
+
+        Ok(session)
+    }
+
+    /// Registers a learner to attend the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LiveSessionError::SessionCancelled` if the session was cancelled.
+    /// Returns `LiveSessionError::AlreadyRegistered` if the learner is already registered.
+    /// Returns `LiveSessionError::SessionFull` if there are no seats left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Email, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let mut session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    /// let attendee = Email::new("alice@example.com".to_string()).unwrap();
+    ///
+    /// session.register(attendee.clone()).unwrap();
+    /// assert!(session.is_registered(&attendee));
+    ///
+    /// // Registering twice is rejected
+    /// assert!(session.register(attendee).is_err());
+    /// ```
+    pub fn register(&mut self, attendee: Email) -> Result<(), LiveSessionError> {
+        self.ensure_not_cancelled()?;
+
+        if self.is_registered(&attendee) {
+            return Err(LiveSessionError::AlreadyRegistered(attendee.address().to_string()));
+        }
+
+        if self.is_full() {
+            return Err(LiveSessionError::SessionFull {
+                capacity: self.capacity,
+            });
+        }
+
+        self.attendees.push(attendee);
+        Ok(())
+    }
+
+    /// Removes a learner from the attendee list, freeing their seat.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LiveSessionError::SessionCancelled` if the session was cancelled.
+    /// Returns `LiveSessionError::NotRegistered` if the learner is not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Email, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let mut session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     1,
+    /// ).unwrap();
+    /// let attendee = Email::new("alice@example.com".to_string()).unwrap();
+    ///
+    /// session.register(attendee.clone()).unwrap();
+    /// session.unregister(&attendee).unwrap();
+    ///
+    /// assert!(!session.is_full());
+    /// ```
+    pub fn unregister(&mut self, attendee: &Email) -> Result<(), LiveSessionError> {
+        self.ensure_not_cancelled()?;
+
+        let position = self
+            .attendees
+            .iter()
+            .position(|registered| registered == attendee)
+            .ok_or_else(|| LiveSessionError::NotRegistered(attendee.address().to_string()))?;
+
+        self.attendees.remove(position);
+        Ok(())
+    }
+
+    /// Cancels the session.
+    ///
+    /// The attendee list is kept so learners can be told about the
+    /// cancellation, but it can no longer change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LiveSessionError::SessionCancelled` if the session was already cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Email, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let mut session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    ///
+    /// session.cancel().unwrap();
+    /// assert!(session.is_cancelled());
+    ///
+    /// let attendee = Email::new("alice@example.com".to_string()).unwrap();
+    /// assert!(session.register(attendee).is_err());
+    /// ```
+    pub fn cancel(&mut self) -> Result<(), LiveSessionError> {
+        self.ensure_not_cancelled()?;
+        self.cancelled = true;
+        Ok(())
+    }
+
+    /// Returns the ID of the course the session belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let course_id = Id::new();
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     course_id,
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(session.course_id(), course_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns when the session takes place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(session.scheduled().start(), start);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn scheduled(&self) -> TimeRange {
+        self.scheduled
+    }
+
+    /// Returns the URL learners use to join the session.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(session.meeting_url().host(), "meet.example.com");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn meeting_url(&self) -> &Url {
+        &self.meeting_url
+    }
+
+    /// Returns the maximum number of attendees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     25,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(session.capacity(), 25);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Returns the registered attendees, in registration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Email, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let mut session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    /// let attendee = Email::new("alice@example.com".to_string()).unwrap();
+    /// session.register(attendee.clone()).unwrap();
+    ///
+    /// assert_eq!(session.attendees(), &[attendee]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn attendees(&self) -> &[Email] {
+        &self.attendees
+    }
+
+    /// Returns the number of registered attendees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(session.attendee_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn attendee_count(&self) -> usize {
+        self.attendees.len()
+    }
+
+    /// Returns the number of seats still free.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Email, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let mut session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    /// session.register(Email::new("alice@example.com".to_string()).unwrap()).unwrap();
+    ///
+    /// assert_eq!(session.available_seats(), 9);
+    /// ```
+    #[must_use]
+    pub fn available_seats(&self) -> u32 {
+        let taken = u32::try_from(self.attendees.len()).unwrap_or(u32::MAX);
+        self.capacity.saturating_sub(taken)
+    }
+
+    /// Returns true if every seat is taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    ///
+    /// assert!(!session.is_full());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.available_seats() == 0
+    }
+
+    /// Returns true if the learner is registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Email, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    /// let attendee = Email::new("alice@example.com".to_string()).unwrap();
+    ///
+    /// assert!(!session.is_registered(&attendee));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_registered(&self, attendee: &Email) -> bool {
+        self.attendees.contains(attendee)
+    }
+
+    /// Returns true if the session was cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LiveSession;
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+    /// let session = LiveSession::new(
+    ///     Id::new(),
+    ///     scheduled,
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     10,
+    /// ).unwrap();
+    ///
+    /// assert!(!session.is_cancelled());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    fn ensure_not_cancelled(&self) -> Result<(), LiveSessionError> {
+        match self.cancelled {
+            true => Err(LiveSessionError::SessionCancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+impl Entity for LiveSession {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for LiveSession {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for LiveSession {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::{DateTime, Duration};
+
+    fn create_test_session(capacity: u32) -> LiveSession {
+        let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+        let scheduled = TimeRange::starting_at(start, Duration::from_hours(1)).unwrap();
+        LiveSession::new(
+            Id::new(),
+            scheduled,
+            "https://meet.example.com/rust-101".to_string(),
+            capacity,
+        )
+        .unwrap()
+    }
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_starts_empty_and_scheduled() {
+            let session = create_test_session(5);
+
+            assert_eq!(session.capacity(), 5);
+            assert_eq!(session.attendee_count(), 0);
+            assert!(!session.is_cancelled());
+        }
+
+        #[test]
+        fn test_new_rejects_zero_capacity() {
+            let result = LiveSession::new(
+                Id::new(),
+                create_test_session(1).scheduled(),
+                "https://meet.example.com/rust-101".to_string(),
+                0,
+            );
+
+            assert_eq!(result.unwrap_err(), LiveSessionError::CapacityIsZero);
+        }
+
+        #[test]
+        fn test_new_rejects_invalid_meeting_url() {
+            let result = LiveSession::new(
+                Id::new(),
+                create_test_session(1).scheduled(),
+                "not a url".to_string(),
+                10,
+            );
+
+            assert!(matches!(result, Err(LiveSessionError::MeetingUrlError(_))));
+        }
+
+        #[test]
+        fn test_with_id_restores_state() {
+            let id = Id::new();
+            let attendees = vec![create_email("a@example.com"), create_email("b@example.com")];
+
+            let session = LiveSession::with_id(
+                id,
+                Id::new(),
+                create_test_session(1).scheduled(),
+                "https://meet.example.com/rust-101".to_string(),
+                2,
+                attendees.clone(),
+                true,
+            )
+            .unwrap();
+
+            assert_eq!(session.id(), id);
+            assert_eq!(session.attendees(), attendees.as_slice());
+            assert!(session.is_full());
+            assert!(session.is_cancelled());
+        }
+
+        #[test]
+        fn test_with_id_rejects_more_attendees_than_capacity() {
+            let result = LiveSession::with_id(
+                Id::new(),
+                Id::new(),
+                create_test_session(1).scheduled(),
+                "https://meet.example.com/rust-101".to_string(),
+                1,
+                vec![create_email("a@example.com"), create_email("b@example.com")],
+                false,
+            );
+
+            assert_eq!(result.unwrap_err(), LiveSessionError::SessionFull { capacity: 1 });
+        }
+
+        #[test]
+        fn test_with_id_rejects_duplicate_attendees() {
+            let result = LiveSession::with_id(
+                Id::new(),
+                Id::new(),
+                create_test_session(1).scheduled(),
+                "https://meet.example.com/rust-101".to_string(),
+                5,
+                vec![create_email("a@example.com"), create_email("a@example.com")],
+                false,
+            );
+
+            assert_eq!(
+                result.unwrap_err(),
+                LiveSessionError::AlreadyRegistered("a@example.com".to_string())
+            );
+        }
+    }
+
+    mod register {
+        use super::*;
+
+        #[test]
+        fn test_register_adds_attendee() {
+            let mut session = create_test_session(2);
+            let email = create_email("a@example.com");
+
+            session.register(email.clone()).unwrap();
+
+            assert!(session.is_registered(&email));
+            assert_eq!(session.available_seats(), 1);
+        }
+
+        #[test]
+        fn test_register_rejects_duplicate() {
+            let mut session = create_test_session(2);
+            session.register(create_email("a@example.com")).unwrap();
+
+            let result = session.register(create_email("a@example.com"));
+
+            assert_eq!(
+                result.unwrap_err(),
+                LiveSessionError::AlreadyRegistered("a@example.com".to_string())
+            );
+            assert_eq!(session.attendee_count(), 1);
+        }
+
+        #[test]
+        fn test_register_rejects_when_full() {
+            let mut session = create_test_session(1);
+            session.register(create_email("a@example.com")).unwrap();
+
+            let result = session.register(create_email("b@example.com"));
+
+            assert_eq!(result.unwrap_err(), LiveSessionError::SessionFull { capacity: 1 });
+        }
+
+        #[test]
+        fn test_register_rejects_when_cancelled() {
+            let mut session = create_test_session(5);
+            session.cancel().unwrap();
+
+            let result = session.register(create_email("a@example.com"));
+
+            assert_eq!(result.unwrap_err(), LiveSessionError::SessionCancelled);
+        }
+    }
+
+    mod unregister {
+        use super::*;
+
+        #[test]
+        fn test_unregister_frees_seat() {
+            let mut session = create_test_session(1);
+            let email = create_email("a@example.com");
+            session.register(email.clone()).unwrap();
+
+            session.unregister(&email).unwrap();
+
+            assert!(!session.is_registered(&email));
+            assert!(!session.is_full());
+            assert!(session.register(create_email("b@example.com")).is_ok());
+        }
+
+        #[test]
+        fn test_unregister_keeps_order_of_remaining_attendees() {
+            let mut session = create_test_session(3);
+            let a = create_email("a@example.com");
+            let b = create_email("b@example.com");
+            let c = create_email("c@example.com");
+            session.register(a.clone()).unwrap();
+            session.register(b.clone()).unwrap();
+            session.register(c.clone()).unwrap();
+
+            session.unregister(&b).unwrap();
+
+            assert_eq!(session.attendees(), &[a, c]);
+        }
+
+        #[test]
+        fn test_unregister_unknown_attendee_returns_error() {
+            let mut session = create_test_session(1);
+
+            let result = session.unregister(&create_email("a@example.com"));
+
+            assert_eq!(
+                result.unwrap_err(),
+                LiveSessionError::NotRegistered("a@example.com".to_string())
+            );
+        }
+
+        #[test]
+        fn test_unregister_rejects_when_cancelled() {
+            let mut session = create_test_session(1);
+            let email = create_email("a@example.com");
+            session.register(email.clone()).unwrap();
+            session.cancel().unwrap();
+
+            let result = session.unregister(&email);
+
+            assert_eq!(result.unwrap_err(), LiveSessionError::SessionCancelled);
+            assert!(session.is_registered(&email));
+        }
+    }
+
+    mod cancel {
+        use super::*;
+
+        #[test]
+        fn test_cancel_marks_session_cancelled() {
+            let mut session = create_test_session(1);
+
+            session.cancel().unwrap();
+
+            assert!(session.is_cancelled());
+        }
+
+        #[test]
+        fn test_cancel_twice_returns_error() {
+            let mut session = create_test_session(1);
+            session.cancel().unwrap();
+
+            assert_eq!(session.cancel().unwrap_err(), LiveSessionError::SessionCancelled);
+        }
+    }
+
+    mod equality {
+        use super::*;
+
+        #[test]
+        fn test_sessions_are_equal_by_id_only() {
+            let session = create_test_session(1);
+            let mut changed = session.clone();
+            changed.register(create_email("a@example.com")).unwrap();
+
+            assert_eq!(session, changed);
+            assert_ne!(session, create_test_session(1));
+        }
+    }
+}
@@ -0,0 +1,658 @@
+use crate::CourseProgress;
+use education_platform_common::{
+    Email, Entity, Id, Percentage, SimpleName, SimpleNameError, Validator, ValidatorError,
+};
+use thiserror::Error;
+
+/// Error types for StudyGroup operations.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StudyGroupError {
+    #[error("Study group name validation failed: {0}")]
+    NameError(#[from] SimpleNameError),
+
+    #[error("Study group goal validation failed: {0}")]
+    GoalError(#[from] ValidatorError),
+
+    #[error("Member cap must be different from zero")]
+    MemberCapIsZero,
+
+    #[error("Study group is full, member cap is {member_cap}")]
+    GroupFull { member_cap: u32 },
+
+    #[error("{0} is already a member of the group")]
+    AlreadyMember(String),
+
+    #[error("{0} is not a member of the group")]
+    NotMember(String),
+}
+
+/// How far a study group has got through the course, taken together.
+///
+/// Only members with a `CourseProgress` are counted in the averages, so a
+/// member who never opened the course does not drag them down.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseProgress, LessonProgress, StudyGroup};
+/// use education_platform_common::{DateTime, Email};
+///
+/// let mut group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+/// group.join(Email::new("a@example.com".to_string()).unwrap()).unwrap();
+/// group.join(Email::new("b@example.com".to_string()).unwrap()).unwrap();
+///
+/// let start = DateTime::new(2024, 1, 1, 9, 0, 0).unwrap();
+/// let end = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+/// let done = LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+/// let progress = CourseProgress::builder()
+///     .course_name("My Course")
+///     .user_email("a@example.com")
+///     .lessons(vec![done])
+///     .build()
+///     .unwrap();
+///
+/// let summary = group.progress_summary(&[progress]);
+/// assert_eq!(summary.member_count(), 2);
+/// assert_eq!(summary.members_tracked(), 1);
+/// assert_eq!(summary.members_completed(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupProgressSummary {
+    member_count: usize,
+    members_tracked: usize,
+    members_completed: usize,
+    average_percentage_completed: Percentage,
+}
+
+impl GroupProgressSummary {
+    /// Returns the number of members in the group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert_eq!(group.progress_summary(&[]).member_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn member_count(&self) -> usize {
+        self.member_count
+    }
+
+    /// Returns the number of members whose progress was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert_eq!(group.progress_summary(&[]).members_tracked(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn members_tracked(&self) -> usize {
+        self.members_tracked
+    }
+
+    /// Returns the number of members who completed the course.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert_eq!(group.progress_summary(&[]).members_completed(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn members_completed(&self) -> usize {
+        self.members_completed
+    }
+
+    /// Returns the mean completion percentage of the tracked members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    /// use education_platform_common::Percentage;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert_eq!(group.progress_summary(&[]).average_percentage_completed(), Percentage::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn average_percentage_completed(&self) -> Percentage {
+        self.average_percentage_completed
+    }
+}
+
+/// A small group of learners in a cohort working towards a shared goal.
+///
+/// The member list never exceeds the member cap and never holds the same
+/// learner twice. Keeping a learner in a single group per cohort is up to
+/// the owning `Cohort`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::StudyGroup;
+/// use education_platform_common::Email;
+///
+/// let mut group = StudyGroup::new("Night Owls", "Finish chapter 3 this week", 2).unwrap();
+///
+/// group.join(Email::new("a@example.com".to_string()).unwrap()).unwrap();
+/// group.join(Email::new("b@example.com".to_string()).unwrap()).unwrap();
+///
+/// assert!(group.is_full());
+/// assert!(group.join(Email::new("c@example.com".to_string()).unwrap()).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct StudyGroup {
+    id: Id,
+    name: SimpleName,
+    goal: String,
+    member_cap: u32,
+    members: Vec<Email>,
+}
+
+impl StudyGroup {
+    pub const MAX_GOAL_LENGTH: usize = 500;
+
+    /// Creates a new empty `StudyGroup`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StudyGroupError::NameError` if the name is not valid.
+    /// Returns `StudyGroupError::GoalError` if the goal is empty or longer than
+    /// [`StudyGroup::MAX_GOAL_LENGTH`] bytes.
+    /// Returns `StudyGroupError::MemberCapIsZero` if the member cap is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert_eq!(group.name().as_str(), "Night Owls");
+    ///
+    /// assert!(StudyGroup::new("Night Owls", "Finish by June", 0).is_err());
+    /// ```
+    pub fn new(
+        name: impl Into<String>,
+        goal: impl Into<String>,
+        member_cap: u32,
+    ) -> Result<Self, StudyGroupError> {
+        Self::with_id(Id::default(), name, goal, member_cap, Vec::new())
+    }
+
+    /// Creates a `StudyGroup` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`StudyGroup::new`], plus
+    /// `StudyGroupError::GroupFull` or `StudyGroupError::AlreadyMember` if the
+    /// members break the group invariants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    /// use education_platform_common::{Email, Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let member = Email::new("a@example.com".to_string()).unwrap();
+    /// let group = StudyGroup::with_id(id, "Night Owls", "Finish by June", 5, vec![member])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(group.id(), id);
+    /// assert_eq!(group.members().len(), 1);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        name: impl Into<String>,
+        goal: impl Into<String>,
+        member_cap: u32,
+        members: Vec<Email>,
+    ) -> Result<Self, StudyGroupError> {
+        let name = SimpleName::new(name.into())?;
+        let goal = Self::validate_goal(&goal.into())?;
+        if member_cap == 0 {
+            return Err(StudyGroupError::MemberCapIsZero);
+        }
+
+        let mut group = Self {
+            id,
+            name,
+            goal,
+            member_cap,
+            members: Vec::with_capacity(members.len()),
+        };
+        for member in members {
+            group.join(member)?;
+        }
+
+        Ok(group)
+    }
+
+    /// Adds a learner to the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StudyGroupError::AlreadyMember` if the learner is already a member.
+    /// Returns `StudyGroupError::GroupFull` if the member cap is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    /// use education_platform_common::Email;
+    ///
+    /// let mut group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// let member = Email::new("a@example.com".to_string()).unwrap();
+    ///
+    /// group.join(member.clone()).unwrap();
+    /// assert!(group.is_member(&member));
+    /// ```
+    pub fn join(&mut self, member: Email) -> Result<(), StudyGroupError> {
+        if self.is_member(&member) {
+            return Err(StudyGroupError::AlreadyMember(member.address().to_string()));
+        }
+
+        if self.is_full() {
+            return Err(StudyGroupError::GroupFull {
+                member_cap: self.member_cap,
+            });
+        }
+
+        self.members.push(member);
+        Ok(())
+    }
+
+    /// Removes a learner from the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StudyGroupError::NotMember` if the learner is not a member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    /// use education_platform_common::Email;
+    ///
+    /// let mut group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// let member = Email::new("a@example.com".to_string()).unwrap();
+    /// group.join(member.clone()).unwrap();
+    ///
+    /// group.leave(&member).unwrap();
+    /// assert!(!group.is_member(&member));
+    /// ```
+    pub fn leave(&mut self, member: &Email) -> Result<(), StudyGroupError> {
+        let position = self
+            .members
+            .iter()
+            .position(|existing| existing == member)
+            .ok_or_else(|| StudyGroupError::NotMember(member.address().to_string()))?;
+
+        self.members.remove(position);
+        Ok(())
+    }
+
+    /// Summarizes the group's progress from its members' course progress.
+    ///
+    /// Progress of learners outside the group is ignored, so the progress of
+    /// the whole cohort can be passed in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, StudyGroup};
+    /// use education_platform_common::Email;
+    ///
+    /// let mut group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// group.join(Email::new("a@example.com".to_string()).unwrap()).unwrap();
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let outsider = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("outsider@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(group.progress_summary(&[outsider]).members_tracked(), 0);
+    /// ```
+    #[must_use]
+    pub fn progress_summary(&self, progresses: &[CourseProgress]) -> GroupProgressSummary {
+        let tracked: Vec<&CourseProgress> = progresses
+            .iter()
+            .filter(|progress| self.is_member(progress.user_email()))
+            .collect();
+
+        let average_percentage_completed = Percentage::average(
+            tracked
+                .iter()
+                .map(|progress| progress.percentage_completed()),
+        );
+
+        GroupProgressSummary {
+            member_count: self.members.len(),
+            members_tracked: tracked.len(),
+            members_completed: tracked
+                .iter()
+                .filter(|progress| progress.is_completed())
+                .count(),
+            average_percentage_completed,
+        }
+    }
+
+    /// Returns the group name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert_eq!(group.name().as_str(), "Night Owls");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &SimpleName {
+        &self.name
+    }
+
+    /// Returns the goal the group shares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "  Finish by June ", 5).unwrap();
+    /// assert_eq!(group.goal(), "Finish by June");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn goal(&self) -> &str {
+        &self.goal
+    }
+
+    /// Returns the maximum number of members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert_eq!(group.member_cap(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn member_cap(&self) -> u32 {
+        self.member_cap
+    }
+
+    /// Returns the members, in joining order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert!(group.members().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn members(&self) -> &[Email] {
+        &self.members
+    }
+
+    /// Returns true if the learner is a member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    /// use education_platform_common::Email;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert!(!group.is_member(&Email::new("a@example.com".to_string()).unwrap()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_member(&self, learner: &Email) -> bool {
+        self.members.contains(learner)
+    }
+
+    /// Returns true if the member cap is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::StudyGroup;
+    ///
+    /// let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+    /// assert!(!group.is_full());
+    /// ```
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        u32::try_from(self.members.len()).map_or(true, |count| count >= self.member_cap)
+    }
+
+    fn validate_goal(goal: &str) -> Result<String, StudyGroupError> {
+        let trimmed = goal.trim();
+        Validator::is_not_empty(trimmed)?;
+        Validator::has_max_length(trimmed, Self::MAX_GOAL_LENGTH)?;
+        Ok(trimmed.to_string())
+    }
+}
+
+impl Entity for StudyGroup {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LessonProgress;
+    use education_platform_common::DateTime;
+
+    fn create_group(member_cap: u32) -> StudyGroup {
+        StudyGroup::new("Night Owls", "Finish the course", member_cap).unwrap()
+    }
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_progress(user_email: &str, completed: usize, total: usize) -> CourseProgress {
+        let start = DateTime::new(2024, 1, 1, 9, 0, 0).unwrap();
+        let end = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+        let lessons = (0..total)
+            .map(|index| match index < completed {
+                true => {
+                    LessonProgress::new(format!("Lesson {index}"), 1800, Some(start), Some(end))
+                }
+                false => LessonProgress::new(format!("Lesson {index}"), 1800, None, None),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        CourseProgress::builder()
+            .course_name("Course")
+            .user_email(user_email)
+            .lessons(lessons)
+            .build()
+            .unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_creates_empty_group() {
+            let group = create_group(4);
+
+            assert_eq!(group.member_cap(), 4);
+            assert!(group.members().is_empty());
+        }
+
+        #[test]
+        fn test_new_rejects_zero_member_cap() {
+            assert_eq!(
+                StudyGroup::new("Night Owls", "Goal", 0).unwrap_err(),
+                StudyGroupError::MemberCapIsZero
+            );
+        }
+
+        #[test]
+        fn test_new_rejects_blank_goal() {
+            let result = StudyGroup::new("Night Owls", "   ", 4);
+
+            assert!(matches!(result, Err(StudyGroupError::GoalError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_too_long_goal() {
+            let result =
+                StudyGroup::new("Night Owls", "a".repeat(StudyGroup::MAX_GOAL_LENGTH + 1), 4);
+
+            assert!(matches!(result, Err(StudyGroupError::GoalError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_invalid_name() {
+            let result = StudyGroup::new("", "Goal", 4);
+
+            assert!(matches!(result, Err(StudyGroupError::NameError(_))));
+        }
+
+        #[test]
+        fn test_with_id_rejects_members_over_cap() {
+            let result = StudyGroup::with_id(
+                Id::new(),
+                "Night Owls",
+                "Goal",
+                1,
+                vec![create_email("a@example.com"), create_email("b@example.com")],
+            );
+
+            assert_eq!(result.unwrap_err(), StudyGroupError::GroupFull { member_cap: 1 });
+        }
+    }
+
+    mod membership {
+        use super::*;
+
+        #[test]
+        fn test_join_adds_member() {
+            let mut group = create_group(2);
+
+            group.join(create_email("a@example.com")).unwrap();
+
+            assert!(group.is_member(&create_email("a@example.com")));
+        }
+
+        #[test]
+        fn test_join_rejects_existing_member() {
+            let mut group = create_group(2);
+            group.join(create_email("a@example.com")).unwrap();
+
+            assert_eq!(
+                group.join(create_email("a@example.com")).unwrap_err(),
+                StudyGroupError::AlreadyMember("a@example.com".to_string())
+            );
+        }
+
+        #[test]
+        fn test_join_rejects_when_full() {
+            let mut group = create_group(1);
+            group.join(create_email("a@example.com")).unwrap();
+
+            assert_eq!(
+                group.join(create_email("b@example.com")).unwrap_err(),
+                StudyGroupError::GroupFull { member_cap: 1 }
+            );
+        }
+
+        #[test]
+        fn test_leave_frees_a_place() {
+            let mut group = create_group(1);
+            group.join(create_email("a@example.com")).unwrap();
+
+            group.leave(&create_email("a@example.com")).unwrap();
+
+            assert!(group.join(create_email("b@example.com")).is_ok());
+        }
+
+        #[test]
+        fn test_leave_unknown_member_returns_error() {
+            let mut group = create_group(1);
+
+            assert_eq!(
+                group.leave(&create_email("a@example.com")).unwrap_err(),
+                StudyGroupError::NotMember("a@example.com".to_string())
+            );
+        }
+    }
+
+    mod progress_summary {
+        use super::*;
+
+        #[test]
+        fn test_summary_of_group_without_progress() {
+            let mut group = create_group(3);
+            group.join(create_email("a@example.com")).unwrap();
+
+            let summary = group.progress_summary(&[]);
+
+            assert_eq!(summary.member_count(), 1);
+            assert_eq!(summary.members_tracked(), 0);
+            assert_eq!(summary.average_percentage_completed(), Percentage::ZERO);
+        }
+
+        #[test]
+        fn test_summary_averages_tracked_members() {
+            let mut group = create_group(3);
+            group.join(create_email("a@example.com")).unwrap();
+            group.join(create_email("b@example.com")).unwrap();
+            group.join(create_email("c@example.com")).unwrap();
+
+            let summary = group.progress_summary(&[
+                create_progress("a@example.com", 2, 2),
+                create_progress("b@example.com", 0, 2),
+            ]);
+
+            assert_eq!(summary.member_count(), 3);
+            assert_eq!(summary.members_tracked(), 2);
+            assert_eq!(summary.members_completed(), 1);
+            assert_eq!(summary.average_percentage_completed().value(), 50.0);
+        }
+
+        #[test]
+        fn test_summary_ignores_non_members() {
+            let mut group = create_group(3);
+            group.join(create_email("a@example.com")).unwrap();
+
+            let summary = group.progress_summary(&[
+                create_progress("a@example.com", 1, 2),
+                create_progress("outsider@example.com", 2, 2),
+            ]);
+
+            assert_eq!(summary.members_tracked(), 1);
+            assert_eq!(summary.members_completed(), 0);
+            assert_eq!(summary.average_percentage_completed().value(), 50.0);
+        }
+    }
+}
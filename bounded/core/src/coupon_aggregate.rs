@@ -0,0 +1,7 @@
+mod coupon;
+mod coupon_code;
+mod discount;
+
+pub use coupon::*;
+pub use coupon_code::*;
+pub use discount::*;
@@ -0,0 +1,624 @@
+use crate::{CouponCode, CouponCodeError, CourseId, Discount};
+use education_platform_common::{
+    Auditable, Date, DateRange, DateTime, Email, Entity, Id, Timestamps,
+};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Error types for Coupon validation and redemption failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CouponError {
+    #[error("Coupon code validation failed: {0}")]
+    CodeError(#[from] CouponCodeError),
+
+    #[error("Coupon discount must take something off")]
+    DiscountIsZero,
+
+    #[error("Coupon usage limit must be different from zero")]
+    UsageLimitIsZero,
+
+    #[error("Code {0} does not match the coupon")]
+    WrongCode(String),
+
+    #[error("Coupon is not valid before {0}")]
+    NotYetValid(String),
+
+    #[error("Coupon expired on {0}")]
+    Expired(String),
+
+    #[error("Coupon does not apply to course {0}")]
+    NotApplicable(String),
+
+    #[error("Coupon was already redeemed by {0}")]
+    AlreadyRedeemed(String),
+
+    #[error("Coupon reached its limit of {0} redemptions")]
+    UsageLimitReached(u32),
+}
+
+/// One use of a [`Coupon`]: who redeemed it, for which course and when.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redemption {
+    user: Email,
+    course_id: CourseId,
+    redeemed_on: Date,
+}
+
+impl Redemption {
+    /// Creates a redemption record (for reconstruction from persistence).
+    #[must_use]
+    pub const fn new(user: Email, course_id: CourseId, redeemed_on: Date) -> Self {
+        Self {
+            user,
+            course_id,
+            redeemed_on,
+        }
+    }
+
+    /// Returns the user who redeemed the coupon.
+    #[inline]
+    #[must_use]
+    pub const fn user(&self) -> &Email {
+        &self.user
+    }
+
+    /// Returns the course the coupon was redeemed for.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> CourseId {
+        self.course_id
+    }
+
+    /// Returns the day the coupon was redeemed.
+    #[inline]
+    #[must_use]
+    pub const fn redeemed_on(&self) -> Date {
+        self.redeemed_on
+    }
+}
+
+/// A discount users unlock by typing a code when buying a course.
+///
+/// `Coupon` is an aggregate root. It can be redeemed on the days of its
+/// validity range, for its applicable courses, at most once per user and
+/// no more times overall than its usage limit. A coupon without applicable
+/// courses applies to every course.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Coupon, CouponError, Discount};
+/// use education_platform_common::{Date, DateRange, Email, Id, Percentage};
+///
+/// let march = DateRange::new(Date::new(2025, 3, 1).unwrap(), Date::new(2025, 3, 31).unwrap()).unwrap();
+/// let discount = Discount::Percent(Percentage::new(20.0).unwrap());
+/// let mut coupon = Coupon::new("spring-2025", discount, 100, march, vec![]).unwrap();
+///
+/// let ana = Email::new("ana@example.com".to_string()).unwrap();
+/// let course = Id::new().cast();
+/// let today = Date::new(2025, 3, 10).unwrap();
+///
+/// assert_eq!(coupon.redeem("SPRING-2025", &ana, course, today), Ok(discount));
+/// assert_eq!(
+///     coupon.redeem("SPRING-2025", &ana, course, today),
+///     Err(CouponError::AlreadyRedeemed("ana@example.com".to_string()))
+/// );
+/// assert_eq!(coupon.remaining_uses(), 99);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Coupon {
+    id: Id,
+    code: CouponCode,
+    discount: Discount,
+    usage_limit: u32,
+    validity: DateRange,
+    courses: Vec<CourseId>,
+    redemptions: Vec<Redemption>,
+    timestamps: Timestamps,
+}
+
+/// Identifier of a [`Coupon`].
+pub type CouponId = Id<Coupon>;
+
+impl Coupon {
+    /// Creates a new coupon that nobody has redeemed yet.
+    ///
+    /// Courses listed more than once are kept once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CouponError::CodeError` if the code validation fails,
+    /// `CouponError::DiscountIsZero` if the discount takes nothing off, or
+    /// `CouponError::UsageLimitIsZero` if the coupon could never be used.
+    pub fn new(
+        code: &str,
+        discount: Discount,
+        usage_limit: u32,
+        validity: DateRange,
+        courses: Vec<CourseId>,
+    ) -> Result<Self, CouponError> {
+        Self::with_id(
+            Id::default(),
+            code,
+            discount,
+            usage_limit,
+            validity,
+            courses,
+            Vec::new(),
+        )
+    }
+
+    /// Creates a `Coupon` with a specific ID and past redemptions (for
+    /// reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Coupon::new`], plus
+    /// `CouponError::AlreadyRedeemed` if a user appears in two redemptions
+    /// and `CouponError::UsageLimitReached` if there are more redemptions
+    /// than the usage limit allows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Coupon, Discount, Redemption};
+    /// use education_platform_common::{Currency, Date, DateRange, Email, Entity, Id, Money};
+    ///
+    /// let day = Date::new(2025, 3, 1).unwrap();
+    /// let validity = DateRange::new(day, day.add_days(30)).unwrap();
+    /// let ana = Email::new("ana@example.com".to_string()).unwrap();
+    /// let redemption = Redemption::new(ana.clone(), Id::new().cast(), day);
+    /// let id = Id::new();
+    ///
+    /// let coupon = Coupon::with_id(id, "WELCOME", Discount::Amount(Money::new(500, Currency::new("USD").unwrap())), 1, validity, vec![], vec![redemption]).unwrap();
+    ///
+    /// assert_eq!(coupon.id(), id);
+    /// assert!(coupon.has_redeemed(&ana));
+    /// assert_eq!(coupon.remaining_uses(), 0);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        code: &str,
+        discount: Discount,
+        usage_limit: u32,
+        validity: DateRange,
+        mut courses: Vec<CourseId>,
+        redemptions: Vec<Redemption>,
+    ) -> Result<Self, CouponError> {
+        let code = CouponCode::new(code)?;
+        if discount.is_zero() {
+            return Err(CouponError::DiscountIsZero);
+        }
+        if usage_limit == 0 {
+            return Err(CouponError::UsageLimitIsZero);
+        }
+
+        let mut seen = HashSet::with_capacity(courses.len());
+        courses.retain(|course| seen.insert(*course));
+
+        let mut users = HashSet::with_capacity(redemptions.len());
+        if let Some(repeated) = redemptions
+            .iter()
+            .find(|redemption| !users.insert(redemption.user().address().to_lowercase()))
+        {
+            return Err(CouponError::AlreadyRedeemed(repeated.user().address().to_string()));
+        }
+        if redemptions.len() > usage_limit as usize {
+            return Err(CouponError::UsageLimitReached(usage_limit));
+        }
+
+        Ok(Self {
+            id,
+            code,
+            discount,
+            usage_limit,
+            validity,
+            courses,
+            redemptions,
+            timestamps: Timestamps::now(),
+        })
+    }
+
+    /// Returns the coupon ID typed as a [`CouponId`].
+    #[inline]
+    #[must_use]
+    pub const fn coupon_id(&self) -> CouponId {
+        self.id.cast()
+    }
+
+    /// Returns the code that redeems the coupon.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> &CouponCode {
+        &self.code
+    }
+
+    /// Returns what the coupon takes off the price.
+    #[inline]
+    #[must_use]
+    pub const fn discount(&self) -> Discount {
+        self.discount
+    }
+
+    /// Returns how many times the coupon can be redeemed in total.
+    #[inline]
+    #[must_use]
+    pub const fn usage_limit(&self) -> u32 {
+        self.usage_limit
+    }
+
+    /// Returns the days on which the coupon can be redeemed.
+    #[inline]
+    #[must_use]
+    pub const fn validity(&self) -> DateRange {
+        self.validity
+    }
+
+    /// Returns the courses the coupon applies to; empty means every course.
+    #[inline]
+    #[must_use]
+    pub fn courses(&self) -> &[CourseId] {
+        &self.courses
+    }
+
+    /// Returns the redemptions so far, oldest first.
+    #[inline]
+    #[must_use]
+    pub fn redemptions(&self) -> &[Redemption] {
+        &self.redemptions
+    }
+
+    /// Returns how many more times the coupon can be redeemed.
+    #[must_use]
+    pub fn remaining_uses(&self) -> u32 {
+        let used = u32::try_from(self.redemptions.len()).unwrap_or(u32::MAX);
+        self.usage_limit.saturating_sub(used)
+    }
+
+    /// Returns `true` if the coupon can be used for the course.
+    #[must_use]
+    pub fn applies_to(&self, course_id: CourseId) -> bool {
+        self.courses.is_empty() || self.courses.contains(&course_id)
+    }
+
+    /// Returns `true` if the user has already redeemed the coupon.
+    ///
+    /// Addresses are compared ignoring case, so `Ana@Example.com` cannot
+    /// use the coupon again after `ana@example.com` did.
+    #[must_use]
+    pub fn has_redeemed(&self, user: &Email) -> bool {
+        let address = user.address().to_lowercase();
+        self.redemptions
+            .iter()
+            .any(|redemption| redemption.user().address().to_lowercase() == address)
+    }
+
+    /// Returns the discount the coupon would give a user buying a course,
+    /// without using it up.
+    ///
+    /// Lets an order show the discount while it is still being built; the
+    /// use is only spent by [`Coupon::redeem`] once the order is paid.
+    /// The typed code is compared after normalization, so case and
+    /// surrounding whitespace do not matter.
+    ///
+    /// # Errors
+    ///
+    /// Returns, checked in this order:
+    /// - `CouponError::WrongCode` if the code is not this coupon's
+    /// - `CouponError::NotYetValid` or `CouponError::Expired` if `on` falls
+    ///   outside the validity range
+    /// - `CouponError::NotApplicable` if the coupon does not cover the course
+    /// - `CouponError::AlreadyRedeemed` if the user has used it before
+    /// - `CouponError::UsageLimitReached` if it has no uses left
+    pub fn check(
+        &self,
+        code: &str,
+        user: &Email,
+        course_id: CourseId,
+        on: Date,
+    ) -> Result<Discount, CouponError> {
+        if CouponCode::new(code).ok().as_ref() != Some(&self.code) {
+            return Err(CouponError::WrongCode(code.trim().to_string()));
+        }
+        if on < self.validity.start() {
+            return Err(CouponError::NotYetValid(self.validity.start().to_string()));
+        }
+        if on > self.validity.end() {
+            return Err(CouponError::Expired(self.validity.end().to_string()));
+        }
+        if !self.applies_to(course_id) {
+            return Err(CouponError::NotApplicable(course_id.to_string()));
+        }
+        if self.has_redeemed(user) {
+            return Err(CouponError::AlreadyRedeemed(user.address().to_string()));
+        }
+        if self.remaining_uses() == 0 {
+            return Err(CouponError::UsageLimitReached(self.usage_limit));
+        }
+        Ok(self.discount)
+    }
+
+    /// Redeems the coupon for a user buying a course and returns the
+    /// discount to apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Coupon::check`]; the coupon is left
+    /// unchanged on error.
+    pub fn redeem(
+        &mut self,
+        code: &str,
+        user: &Email,
+        course_id: CourseId,
+        on: Date,
+    ) -> Result<Discount, CouponError> {
+        let discount = self.check(code, user, course_id, on)?;
+        self.redemptions
+            .push(Redemption::new(user.clone(), course_id, on));
+        self.timestamps.touch();
+        Ok(discount)
+    }
+}
+
+impl Entity for Coupon {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Auditable for Coupon {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::{Currency, Money, Percentage};
+
+    fn day(day: u32) -> Date {
+        Date::new(2025, 3, day).unwrap()
+    }
+
+    fn email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn half_off() -> Discount {
+        Discount::Percent(Percentage::new(50.0).unwrap())
+    }
+
+    fn create_test_coupon(usage_limit: u32, courses: Vec<CourseId>) -> Coupon {
+        let validity = DateRange::new(day(10), day(20)).unwrap();
+        Coupon::new("HALF-OFF", half_off(), usage_limit, validity, courses).unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_normalizes_code_and_dedups_courses() {
+            let course = Id::new().cast();
+
+            let coupon = create_test_coupon(5, vec![course, course]);
+
+            assert_eq!(coupon.code().as_str(), "HALF-OFF");
+            assert_eq!(coupon.courses(), &[course]);
+            assert_eq!(coupon.remaining_uses(), 5);
+            assert_eq!(coupon.coupon_id().untyped(), coupon.id());
+        }
+
+        #[test]
+        fn test_invalid_values_are_rejected() {
+            let validity = DateRange::new(day(1), day(2)).unwrap();
+
+            assert!(matches!(
+                Coupon::new("HALF OFF", half_off(), 1, validity, vec![]),
+                Err(CouponError::CodeError(_))
+            ));
+            assert!(matches!(
+                Coupon::new(
+                    "FREEBIE",
+                    Discount::Amount(Money::zero(Currency::new("USD").unwrap())),
+                    1,
+                    validity,
+                    vec![]
+                ),
+                Err(CouponError::DiscountIsZero)
+            ));
+            assert!(matches!(
+                Coupon::new("HALF-OFF", half_off(), 0, validity, vec![]),
+                Err(CouponError::UsageLimitIsZero)
+            ));
+        }
+
+        #[test]
+        fn test_with_id_rejects_repeated_user() {
+            let validity = DateRange::new(day(1), day(2)).unwrap();
+            let redemptions = vec![
+                Redemption::new(email("ana@example.com"), Id::new().cast(), day(1)),
+                Redemption::new(email("Ana@Example.com"), Id::new().cast(), day(2)),
+            ];
+
+            let result = Coupon::with_id(
+                Id::new(),
+                "HALF-OFF",
+                half_off(),
+                5,
+                validity,
+                vec![],
+                redemptions,
+            );
+
+            assert!(matches!(
+                result,
+                Err(CouponError::AlreadyRedeemed(user)) if user == "Ana@Example.com"
+            ));
+        }
+
+        #[test]
+        fn test_with_id_rejects_redemptions_over_limit() {
+            let validity = DateRange::new(day(1), day(2)).unwrap();
+            let redemptions = vec![
+                Redemption::new(email("ana@example.com"), Id::new().cast(), day(1)),
+                Redemption::new(email("bob@example.com"), Id::new().cast(), day(1)),
+            ];
+
+            let result = Coupon::with_id(
+                Id::new(),
+                "HALF-OFF",
+                half_off(),
+                1,
+                validity,
+                vec![],
+                redemptions,
+            );
+
+            assert!(matches!(result, Err(CouponError::UsageLimitReached(1))));
+        }
+    }
+
+    mod check {
+        use super::*;
+
+        #[test]
+        fn test_does_not_use_coupon() {
+            let coupon = create_test_coupon(1, vec![]);
+            let ana = email("ana@example.com");
+
+            let discount = coupon.check("HALF-OFF", &ana, Id::new().cast(), day(10));
+
+            assert_eq!(discount, Ok(half_off()));
+            assert_eq!(coupon.remaining_uses(), 1);
+            assert!(!coupon.has_redeemed(&ana));
+        }
+    }
+
+    mod redeem {
+        use super::*;
+
+        #[test]
+        fn test_records_redemption() {
+            let mut coupon = create_test_coupon(5, vec![]);
+            let course = Id::new().cast();
+
+            let discount = coupon
+                .redeem(" half-off ", &email("ana@example.com"), course, day(10))
+                .unwrap();
+
+            assert_eq!(discount, half_off());
+            assert_eq!(coupon.redemptions()[0].course_id(), course);
+            assert_eq!(coupon.redemptions()[0].redeemed_on(), day(10));
+            assert!(coupon.has_redeemed(&email("ana@example.com")));
+        }
+
+        #[test]
+        fn test_wrong_code() {
+            let mut coupon = create_test_coupon(5, vec![]);
+
+            let result =
+                coupon.redeem("FULL-OFF", &email("ana@example.com"), Id::new().cast(), day(10));
+
+            assert_eq!(result, Err(CouponError::WrongCode("FULL-OFF".to_string())));
+        }
+
+        #[test]
+        fn test_validity_includes_both_ends() {
+            let mut coupon = create_test_coupon(5, vec![]);
+            let course = Id::new().cast();
+
+            assert!(
+                coupon
+                    .redeem("HALF-OFF", &email("ana@example.com"), course, day(10))
+                    .is_ok()
+            );
+            assert!(
+                coupon
+                    .redeem("HALF-OFF", &email("bob@example.com"), course, day(20))
+                    .is_ok()
+            );
+        }
+
+        #[test]
+        fn test_outside_validity() {
+            let mut coupon = create_test_coupon(5, vec![]);
+            let ana = email("ana@example.com");
+            let course = Id::new().cast();
+
+            assert_eq!(
+                coupon.redeem("HALF-OFF", &ana, course, day(9)),
+                Err(CouponError::NotYetValid("2025-03-10".to_string()))
+            );
+            assert_eq!(
+                coupon.redeem("HALF-OFF", &ana, course, day(21)),
+                Err(CouponError::Expired("2025-03-20".to_string()))
+            );
+            assert!(coupon.redemptions().is_empty());
+        }
+
+        #[test]
+        fn test_course_not_covered() {
+            let covered = Id::new().cast();
+            let other: CourseId = Id::new().cast();
+            let mut coupon = create_test_coupon(5, vec![covered]);
+
+            let result = coupon.redeem("HALF-OFF", &email("ana@example.com"), other, day(10));
+
+            assert_eq!(result, Err(CouponError::NotApplicable(other.to_string())));
+        }
+
+        #[test]
+        fn test_single_use_per_user_across_courses() {
+            let mut coupon = create_test_coupon(5, vec![]);
+            let ana = email("ana@example.com");
+            coupon
+                .redeem("HALF-OFF", &ana, Id::new().cast(), day(10))
+                .unwrap();
+
+            let result = coupon.redeem("HALF-OFF", &ana, Id::new().cast(), day(11));
+
+            assert_eq!(
+                result,
+                Err(CouponError::AlreadyRedeemed("ana@example.com".to_string()))
+            );
+            assert_eq!(coupon.redemptions().len(), 1);
+        }
+
+        #[test]
+        fn test_single_use_ignores_address_case() {
+            let mut coupon = create_test_coupon(5, vec![]);
+            coupon
+                .redeem("HALF-OFF", &email("ana@example.com"), Id::new().cast(), day(10))
+                .unwrap();
+            let shouting = email("ANA@Example.COM");
+
+            let result = coupon.redeem("HALF-OFF", &shouting, Id::new().cast(), day(11));
+
+            assert!(coupon.has_redeemed(&shouting));
+            assert_eq!(
+                result,
+                Err(CouponError::AlreadyRedeemed("ANA@Example.COM".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_usage_limit() {
+            let mut coupon = create_test_coupon(1, vec![]);
+            let course = Id::new().cast();
+            coupon
+                .redeem("HALF-OFF", &email("ana@example.com"), course, day(10))
+                .unwrap();
+
+            let result = coupon.redeem("HALF-OFF", &email("bob@example.com"), course, day(10));
+
+            assert_eq!(result, Err(CouponError::UsageLimitReached(1)));
+            assert_eq!(coupon.remaining_uses(), 0);
+        }
+    }
+}
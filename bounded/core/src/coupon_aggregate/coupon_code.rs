@@ -0,0 +1,131 @@
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for CouponCode validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CouponCodeError {
+    #[error("Coupon code must be between {min} and {max} characters, got {actual}")]
+    LengthNotValid {
+        min: usize,
+        max: usize,
+        actual: usize,
+    },
+
+    #[error("Coupon code may only contain letters, digits and single dashes between them")]
+    CharactersNotValid,
+}
+
+/// The code a user types to redeem a [`Coupon`](crate::Coupon), such as
+/// `SPRING-2025`.
+///
+/// Codes are made of ASCII letters and digits, optionally split into groups
+/// by single dashes. They are stored in upper case, so `spring-2025` and
+/// `SPRING-2025` are the same code; surrounding whitespace is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::CouponCode;
+///
+/// let code = CouponCode::new(" spring-2025 ").unwrap();
+/// assert_eq!(code.as_str(), "SPRING-2025");
+///
+/// assert!(CouponCode::new("NO").is_err());
+/// assert!(CouponCode::new("HALF OFF").is_err());
+/// assert!(CouponCode::new("-SPRING").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CouponCode(String);
+
+impl CouponCode {
+    /// Fewest characters a code can have.
+    pub const MIN_LENGTH: usize = 4;
+
+    /// Most characters a code can have.
+    pub const MAX_LENGTH: usize = 32;
+
+    /// Validates and normalizes a coupon code.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CouponCodeError::LengthNotValid` if the trimmed code is too
+    /// short or too long, or `CouponCodeError::CharactersNotValid` if it
+    /// contains anything but letters, digits and single inner dashes.
+    pub fn new(code: &str) -> Result<Self, CouponCodeError> {
+        let code = code.trim();
+        let length = code.chars().count();
+        if !(Self::MIN_LENGTH..=Self::MAX_LENGTH).contains(&length) {
+            return Err(CouponCodeError::LengthNotValid {
+                min: Self::MIN_LENGTH,
+                max: Self::MAX_LENGTH,
+                actual: length,
+            });
+        }
+
+        let well_formed = code
+            .split('-')
+            .all(|group| !group.is_empty() && group.chars().all(|c| c.is_ascii_alphanumeric()));
+        if !well_formed {
+            return Err(CouponCodeError::CharactersNotValid);
+        }
+
+        Ok(Self(code.to_ascii_uppercase()))
+    }
+
+    /// Returns the normalized code.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CouponCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_is_upper_cased_and_trimmed() {
+        let code = CouponCode::new("  welcome10\n").unwrap();
+
+        assert_eq!(code.as_str(), "WELCOME10");
+        assert_eq!(code, CouponCode::new("WELCOME10").unwrap());
+    }
+
+    #[test]
+    fn test_length_limits() {
+        assert!(CouponCode::new("ABCD").is_ok());
+        assert!(CouponCode::new(&"A".repeat(32)).is_ok());
+        assert_eq!(
+            CouponCode::new("ABC"),
+            Err(CouponCodeError::LengthNotValid {
+                min: 4,
+                max: 32,
+                actual: 3,
+            })
+        );
+        assert!(CouponCode::new(&"A".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn test_dashes_only_between_groups() {
+        assert!(CouponCode::new("BACK-TO-SCHOOL").is_ok());
+        for code in ["-SPRING", "SPRING-", "SPRING--2025"] {
+            assert_eq!(CouponCode::new(code), Err(CouponCodeError::CharactersNotValid));
+        }
+    }
+
+    #[test]
+    fn test_other_characters_are_rejected() {
+        for code in ["HALF OFF", "50%OFF", "ÑANDÚ10"] {
+            assert_eq!(CouponCode::new(code), Err(CouponCodeError::CharactersNotValid));
+        }
+    }
+}
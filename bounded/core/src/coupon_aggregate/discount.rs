@@ -0,0 +1,164 @@
+use education_platform_common::{Currency, Money, MoneyError, Percentage};
+use std::fmt;
+
+/// How much a [`Coupon`](crate::Coupon) takes off a course price.
+///
+/// Fixed amounts are [`Money`], in the smallest unit of their currency such
+/// as cents, so no rounding happens on them and they only apply to prices
+/// in the same currency.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Discount;
+/// use education_platform_common::{Currency, Money, Percentage};
+///
+/// let usd = Currency::new("USD").unwrap();
+/// let quarter_off = Discount::Percent(Percentage::new(25.0).unwrap());
+/// let ten_off = Discount::Amount(Money::new(1_000, usd));
+///
+/// assert_eq!(quarter_off.apply(Money::new(4_999, usd)).unwrap().amount(), 3_749);
+/// assert_eq!(ten_off.apply(Money::new(4_999, usd)).unwrap().amount(), 3_999);
+/// assert!(ten_off.apply(Money::new(500, usd)).unwrap().is_zero());
+///
+/// let euros = Money::new(4_999, Currency::new("EUR").unwrap());
+/// assert!(ten_off.apply(euros).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Discount {
+    /// A share of the price.
+    Percent(Percentage),
+    /// A fixed amount, never taking the price below zero.
+    Amount(Money),
+}
+
+impl Discount {
+    /// Returns the price left to pay once the discount is taken off.
+    ///
+    /// The share taken off by a percentage discount is worked out with
+    /// [`Percentage::share_of`], so it is exact and halves go to the buyer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MoneyError::CurrencyMismatch` if a fixed amount is in
+    /// another currency than the price.
+    pub fn apply(&self, price: Money) -> Result<Money, MoneyError> {
+        match *self {
+            Self::Percent(percent) => price.saturating_sub(price.percentage(percent)),
+            Self::Amount(amount) => price.saturating_sub(amount),
+        }
+    }
+
+    /// Returns the currency of a fixed amount, or `None` for a percentage,
+    /// which applies to any currency.
+    #[inline]
+    #[must_use]
+    pub const fn currency(&self) -> Option<Currency> {
+        match self {
+            Self::Percent(_) => None,
+            Self::Amount(amount) => Some(amount.currency()),
+        }
+    }
+
+    /// Returns `true` if the discount takes nothing off.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        match *self {
+            Self::Percent(percent) => percent.value() == 0.0,
+            Self::Amount(amount) => amount.is_zero(),
+        }
+    }
+}
+
+impl fmt::Display for Discount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Percent(percent) => write!(f, "{percent} off"),
+            Self::Amount(amount) => write!(f, "{amount} off"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd(amount: u64) -> Money {
+        Money::new(amount, Currency::new("USD").unwrap())
+    }
+
+    fn apply(discount: Discount, price: u64) -> u64 {
+        discount.apply(usd(price)).unwrap().amount()
+    }
+
+    #[test]
+    fn test_full_percent_makes_it_free() {
+        assert_eq!(apply(Discount::Percent(Percentage::FULL), 12_345), 0);
+    }
+
+    #[test]
+    fn test_percent_rounds_halves_for_the_buyer() {
+        let discount = Discount::Percent(Percentage::new(50.0).unwrap());
+
+        assert_eq!(apply(discount, 999), 499);
+    }
+
+    #[test]
+    fn test_percent_rounding_boundaries() {
+        let fifteen_off = Discount::Percent(Percentage::new(15.0).unwrap());
+
+        assert_eq!(apply(fifteen_off, 3), 3);
+        assert_eq!(apply(fifteen_off, 4), 3);
+        assert_eq!(apply(fifteen_off, 10), 8);
+    }
+
+    #[test]
+    fn test_percent_on_large_price_is_exact() {
+        let discount = Discount::Percent(Percentage::new(10.0).unwrap());
+
+        assert_eq!(apply(discount, 90_071_992_547_409_930), 81_064_793_292_668_937);
+    }
+
+    #[test]
+    fn test_percent_keeps_price_currency() {
+        let yen = Money::new(1_000, Currency::new("JPY").unwrap());
+        let discount = Discount::Percent(Percentage::new(10.0).unwrap());
+
+        assert_eq!(discount.apply(yen).unwrap().currency(), yen.currency());
+    }
+
+    #[test]
+    fn test_amount_in_other_currency_is_rejected() {
+        let euros = Money::new(4_999, Currency::new("EUR").unwrap());
+
+        assert!(matches!(
+            Discount::Amount(usd(1_000)).apply(euros),
+            Err(MoneyError::CurrencyMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(Discount::Percent(Percentage::FULL).currency(), None);
+        assert_eq!(
+            Discount::Amount(usd(500)).currency(),
+            Some(Currency::new("USD").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_is_zero() {
+        assert!(Discount::Percent(Percentage::ZERO).is_zero());
+        assert!(Discount::Amount(usd(0)).is_zero());
+        assert!(!Discount::Amount(usd(1)).is_zero());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Discount::Percent(Percentage::new(20.0).unwrap()).to_string(),
+            "20% off"
+        );
+        assert_eq!(Discount::Amount(usd(500)).to_string(), "5.00 USD off");
+    }
+}
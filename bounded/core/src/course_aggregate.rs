@@ -1,7 +1,27 @@
 mod chapter;
 mod course;
+mod course_json;
+mod course_markdown;
+mod course_version;
+mod difficulty;
 mod lesson;
+mod provenance;
+mod rating;
+mod release_schedule;
+mod review;
+mod stats_snapshot;
+mod transcript;
 
 pub use chapter::*;
 pub use course::*;
+pub use course_json::*;
+pub use course_markdown::*;
+pub use course_version::*;
+pub use difficulty::*;
 pub use lesson::*;
+pub use provenance::*;
+pub use rating::*;
+pub use release_schedule::*;
+pub use review::*;
+pub use stats_snapshot::*;
+pub use transcript::*;
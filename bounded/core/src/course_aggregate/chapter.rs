@@ -2,11 +2,13 @@ mod add_lesson;
 mod delete_lesson;
 mod getters;
 mod lesson_operations;
+mod localization;
 mod move_lesson;
 
 use crate::Lesson;
 use education_platform_common::{
-    Duration, Entity, Id, Index, SimpleName, SimpleNameConfig, SimpleNameError,
+    Auditable, DateTime, Duration, Entity, Id, Index, LocalizedText, LocalizedTextError, Rule,
+    Rules, SimpleName, SimpleNameConfig, SimpleNameError, Timestamps, ValidatorError,
 };
 use thiserror::Error;
 
@@ -22,6 +24,12 @@ pub enum ChapterError {
 
     #[error("Lesson does not exist")]
     LessonDoesNotExist,
+
+    #[error("Chapter translation failed: {0}")]
+    TranslationError(#[from] LocalizedTextError),
+
+    #[error("Chapter description validation failed: {0}")]
+    DescriptionError(ValidatorError),
 }
 
 /// A chapter within a course, containing multiple lessons.
@@ -55,11 +63,19 @@ pub enum ChapterError {
 pub struct Chapter {
     id: Id,
     name: SimpleName,
+    description: Option<String>,
     index: Index,
     lessons: Vec<Lesson>,
+    localized_names: LocalizedText,
+    timestamps: Timestamps,
 }
 
+/// Identifier of a [`Chapter`].
+pub type ChapterId = Id<Chapter>;
+
 impl Chapter {
+    pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
+
     /// Creates a new `Chapter` with the provided parameters.
     ///
     /// # Arguments
@@ -94,19 +110,152 @@ impl Chapter {
     /// assert_eq!(chapter.name().as_str(), "Chapter 1: Basics");
     /// ```
     pub fn new(name: String, index: usize, lessons: Vec<Lesson>) -> Result<Self, ChapterError> {
+        Self::with_id(Id::default(), name, index, lessons)
+    }
+
+    /// Creates a `Chapter` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChapterError::NameError` if the name validation fails.
+    /// Returns `ChapterError::ChapterWithEmptyLessons` if no lessons are provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Lesson};
+    /// use education_platform_common::{Entity, Id};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Lesson One".to_string(),
+    ///     3600,
+    ///     "https://example.com/lesson1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// let id = Id::new();
+    /// let chapter = Chapter::with_id(id, "Chapter 1: Basics".to_string(), 0, vec![lesson]).unwrap();
+    ///
+    /// assert_eq!(chapter.id(), id);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        name: String,
+        index: usize,
+        lessons: Vec<Lesson>,
+    ) -> Result<Self, ChapterError> {
         let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
         let lessons = Self::order_lessons(lessons)?;
         let index = Index::new(index);
-        let id = Id::default();
 
         Ok(Self {
             id,
             name,
+            description: None,
             index,
             lessons,
+            localized_names: LocalizedText::new(),
+            timestamps: Timestamps::now(),
         })
     }
 
+    /// Updates the chapter name in place, keeping its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChapterError::NameError` if the name validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Lesson};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// let mut chapter = Chapter::new("Module 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let original_id = chapter.id();
+    ///
+    /// chapter.update_name("Chapter 1".to_string()).unwrap();
+    ///
+    /// assert_eq!(chapter.name().as_str(), "Chapter 1");
+    /// assert_eq!(chapter.id(), original_id);
+    /// assert!(chapter.update_name("C".to_string()).is_err());
+    /// ```
+    pub fn update_name(&mut self, name: String) -> Result<(), ChapterError> {
+        self.name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Sets or clears the chapter description.
+    ///
+    /// The description is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChapterError::DescriptionError` if the description is blank or
+    /// longer than [`Chapter::MAX_DESCRIPTION_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let mut chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    ///
+    /// chapter
+    ///     .update_description(Some("Set up Rust and write a first program.".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(chapter.description(), Some("Set up Rust and write a first program."));
+    ///
+    /// chapter.update_description(None).unwrap();
+    /// assert!(chapter.description().is_none());
+    /// ```
+    pub fn update_description(&mut self, description: Option<String>) -> Result<(), ChapterError> {
+        let rule = Rules::not_empty().and(Rules::max_length(Self::MAX_DESCRIPTION_LENGTH));
+        self.description = description
+            .map(|text| {
+                let trimmed = text.trim();
+                rule.check(trimmed).map(|()| trimmed.to_string())
+            })
+            .transpose()
+            .map_err(ChapterError::DescriptionError)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Keeps the description of the chapter this one was rebuilt from.
+    pub(crate) fn with_description_of(mut self, previous: &Chapter) -> Self {
+        self.description.clone_from(&previous.description);
+        self
+    }
+
+    /// Gives the owning course in-place access to the lessons, for edits
+    /// that keep their IDs and order.
+    pub(crate) fn lessons_mut(&mut self) -> &mut [Lesson] {
+        &mut self.lessons
+    }
+
+    /// Keeps the creation time of the chapter this one was rebuilt from and
+    /// records the rebuild as a change.
+    pub(crate) fn with_timestamps_of(mut self, previous: &Chapter) -> Self {
+        self.timestamps = previous.timestamps;
+        self.timestamps.touch();
+        self
+    }
+
     /// Updates the position index of this chapter within the course.
     ///
     /// # Examples
@@ -134,7 +283,10 @@ impl Chapter {
     /// ```
     #[inline]
     pub fn update_index(&mut self, index: usize) {
-        self.index = Index::new(index);
+        if self.index.value() != index {
+            self.index = Index::new(index);
+            self.timestamps.touch();
+        }
     }
 }
 
@@ -144,6 +296,16 @@ impl Entity for Chapter {
     }
 }
 
+impl Auditable for Chapter {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,6 +344,21 @@ mod tests {
             assert_ne!(chapter1.id(), chapter2.id());
         }
 
+        #[test]
+        fn test_with_id_keeps_id() {
+            let id = Id::new();
+
+            let chapter = Chapter::with_id(
+                id,
+                "Test Chapter".to_string(),
+                0,
+                vec![create_test_lesson("Test Lesson", 0)],
+            )
+            .unwrap();
+
+            assert_eq!(chapter.id(), id);
+        }
+
         #[test]
         fn test_new_with_different_index() {
             let lesson = create_test_lesson("Test Lesson", 0);
@@ -257,6 +434,69 @@ mod tests {
         }
     }
 
+    mod update_description {
+        use super::*;
+
+        fn create_test_chapter() -> Chapter {
+            let lesson = create_test_lesson("Test Lesson", 0);
+            Chapter::new("Test Chapter".to_string(), 0, vec![lesson]).unwrap()
+        }
+
+        #[test]
+        fn test_new_chapter_has_no_description() {
+            assert!(create_test_chapter().description().is_none());
+        }
+
+        #[test]
+        fn test_sets_trimmed_description() {
+            let mut chapter = create_test_chapter();
+
+            chapter
+                .update_description(Some("  Traits and generics. ".to_string()))
+                .unwrap();
+
+            assert_eq!(chapter.description(), Some("Traits and generics."));
+        }
+
+        #[test]
+        fn test_none_clears_description() {
+            let mut chapter = create_test_chapter();
+            chapter
+                .update_description(Some("Traits and generics.".to_string()))
+                .unwrap();
+
+            chapter.update_description(None).unwrap();
+
+            assert!(chapter.description().is_none());
+        }
+
+        #[test]
+        fn test_blank_description_is_rejected() {
+            let mut chapter = create_test_chapter();
+
+            let result = chapter.update_description(Some(String::new()));
+
+            assert_eq!(
+                result,
+                Err(ChapterError::DescriptionError(ValidatorError::EmptyValue))
+            );
+        }
+
+        #[test]
+        fn test_too_long_description_keeps_previous() {
+            let mut chapter = create_test_chapter();
+            chapter
+                .update_description(Some("Short".to_string()))
+                .unwrap();
+
+            let result =
+                chapter.update_description(Some("x".repeat(Chapter::MAX_DESCRIPTION_LENGTH + 1)));
+
+            assert!(matches!(result, Err(ChapterError::DescriptionError(_))));
+            assert_eq!(chapter.description(), Some("Short"));
+        }
+    }
+
     mod entity_trait {
         use super::*;
 
@@ -365,4 +605,44 @@ mod tests {
             assert_eq!(chapter.index().value(), 3);
         }
     }
+
+    mod auditable {
+        use super::*;
+
+        fn create_test_chapter() -> Chapter {
+            let lesson = create_test_lesson("Test Lesson", 0);
+            Chapter::new("Test Chapter".to_string(), 0, vec![lesson]).unwrap()
+        }
+
+        #[test]
+        fn test_update_name_records_modification() {
+            let mut chapter = create_test_chapter();
+            let created_at = chapter.created_at();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            chapter.update_name("Renamed Chapter".to_string()).unwrap();
+
+            assert_eq!(chapter.created_at(), created_at);
+            assert!(chapter.updated_at() > created_at);
+        }
+
+        #[test]
+        fn test_same_index_is_not_a_modification() {
+            let mut chapter = create_test_chapter();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            chapter.update_index(0);
+
+            assert_eq!(chapter.updated_at(), chapter.created_at());
+        }
+
+        #[test]
+        fn test_invalid_name_keeps_timestamps() {
+            let mut chapter = create_test_chapter();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            assert!(chapter.update_name(String::new()).is_err());
+            assert_eq!(chapter.updated_at(), chapter.created_at());
+        }
+    }
 }
@@ -49,6 +49,7 @@ impl Chapter {
         lessons.extend_from_slice(&self.lessons[position..]);
 
         self.lessons = Self::reassign_index_lessons(&lessons)?;
+        self.timestamps.touch();
 
         Ok(())
     }
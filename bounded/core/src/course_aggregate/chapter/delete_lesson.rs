@@ -50,6 +50,7 @@ impl Chapter {
             .collect();
 
         self.lessons = Self::reassign_index_lessons(&lessons)?;
+        self.timestamps.touch();
 
         Ok(())
     }
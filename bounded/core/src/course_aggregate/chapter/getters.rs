@@ -1,7 +1,32 @@
-use super::{Chapter, ChapterError, Duration, Lesson, SimpleName};
-use education_platform_common::Index;
+use super::{Chapter, ChapterError, ChapterId, Duration, Lesson, SimpleName};
+use crate::LessonId;
+use education_platform_common::{Entity, Index};
 
 impl Chapter {
+    /// Returns the chapter ID typed as a [`ChapterId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Entity;
+    /// use education_platform_core::{Chapter, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     600,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Fundamentals".to_string(), 0, vec![lesson]).unwrap();
+    ///
+    /// assert_eq!(chapter.chapter_id().untyped(), chapter.id());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn chapter_id(&self) -> ChapterId {
+        self.id.cast()
+    }
+
     /// Returns the chapter name.
     ///
     /// # Examples
@@ -30,6 +55,13 @@ impl Chapter {
         &self.name
     }
 
+    /// Returns the chapter description, if set.
+    #[inline]
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
     /// Returns the chapter index (position within the course).
     ///
     /// # Examples
@@ -125,9 +157,7 @@ impl Chapter {
     /// ```
     #[must_use]
     pub fn total_duration(&self) -> Duration {
-        self.lessons
-            .iter()
-            .fold(Duration::default(), |acc, lesson| acc.add(&lesson.duration()))
+        self.lessons.iter().map(|lesson| lesson.duration()).sum()
     }
 
     /// Returns the number of lessons in this chapter.
@@ -243,6 +273,34 @@ impl Chapter {
             .last()
             .ok_or(ChapterError::ChapterWithEmptyLessons)
     }
+
+    /// Returns the lesson of this chapter with the given ID, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Id;
+    /// use education_platform_core::{Chapter, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Borrowing".to_string(),
+    ///     900,
+    ///     "https://example.com/borrowing.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.lesson_id();
+    /// let chapter = Chapter::new("Ownership".to_string(), 0, vec![lesson]).unwrap();
+    ///
+    /// let found = chapter.find_lesson(lesson_id).unwrap();
+    /// assert_eq!(found.name().as_str(), "Borrowing");
+    /// assert!(chapter.find_lesson(Id::new().cast()).is_none());
+    /// ```
+    #[must_use]
+    pub fn find_lesson(&self, lesson_id: LessonId) -> Option<&Lesson> {
+        self.lessons
+            .iter()
+            .find(|lesson| lesson.id() == lesson_id.untyped())
+    }
 }
 
 #[cfg(test)]
@@ -530,4 +588,44 @@ mod tests {
             assert_ne!(first.id(), last.id());
         }
     }
+
+    mod find_lesson {
+        use super::*;
+        use education_platform_common::Id;
+
+        #[test]
+        fn test_finds_each_lesson_by_id() {
+            let lessons = vec![
+                create_test_lesson("Lesson 1", 0),
+                create_test_lesson("Lesson 2", 1),
+            ];
+            let ids: Vec<LessonId> = lessons.iter().map(Lesson::lesson_id).collect();
+            let chapter = Chapter::new("Test Chapter".to_string(), 0, lessons).unwrap();
+
+            assert_eq!(
+                chapter.find_lesson(ids[0]).map(|l| l.name().as_str()),
+                Some("Lesson 1")
+            );
+            assert_eq!(
+                chapter.find_lesson(ids[1]).map(|l| l.name().as_str()),
+                Some("Lesson 2")
+            );
+        }
+
+        #[test]
+        fn test_unknown_id_returns_none() {
+            let lesson = create_test_lesson("Lesson 1", 0);
+            let chapter = Chapter::new("Test Chapter".to_string(), 0, vec![lesson]).unwrap();
+
+            assert!(chapter.find_lesson(Id::new().cast()).is_none());
+        }
+
+        #[test]
+        fn test_chapter_id_is_not_a_lesson_id() {
+            let lesson = create_test_lesson("Lesson 1", 0);
+            let chapter = Chapter::new("Test Chapter".to_string(), 0, vec![lesson]).unwrap();
+
+            assert!(chapter.find_lesson(chapter.chapter_id().cast()).is_none());
+        }
+    }
 }
@@ -0,0 +1,117 @@
+use super::{Chapter, ChapterError};
+use education_platform_common::{Locale, LocalizedText, SimpleName, SimpleNameConfig};
+
+impl Chapter {
+    /// Sets the chapter name shown to learners of a locale.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChapterError::NameError` if the name validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Lesson};
+    /// use education_platform_common::Locale;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let mut chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    ///
+    /// chapter
+    ///     .set_localized_name(Locale::new("es").unwrap(), "Primeros pasos".to_string())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(chapter.localized_name(&Locale::new("es").unwrap()), "Primeros pasos");
+    /// assert_eq!(chapter.localized_name(&Locale::new("en").unwrap()), "Getting Started");
+    /// ```
+    pub fn set_localized_name(&mut self, locale: Locale, name: String) -> Result<(), ChapterError> {
+        let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        self.localized_names.set(locale, name.as_str())?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes the name translation of a locale, returning true if there was one.
+    pub fn remove_localized_name(&mut self, locale: &Locale) -> bool {
+        let removed = self.localized_names.remove(locale).is_some();
+        if removed {
+            self.timestamps.touch();
+        }
+        removed
+    }
+
+    /// Returns the name for a locale, falling back to the chapter name when
+    /// no translation matches.
+    #[must_use]
+    pub fn localized_name(&self, locale: &Locale) -> &str {
+        self.localized_names
+            .resolve(locale)
+            .unwrap_or(self.name.as_str())
+    }
+
+    /// Returns every name translation.
+    #[inline]
+    #[must_use]
+    pub const fn localized_names(&self) -> &LocalizedText {
+        &self.localized_names
+    }
+
+    /// Carries the translations over to a rebuilt copy of this chapter.
+    pub(crate) fn with_localized_names(mut self, localized_names: LocalizedText) -> Self {
+        self.localized_names = localized_names;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lesson;
+
+    fn create_test_chapter() -> Chapter {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap()
+    }
+
+    fn create_locale(tag: &str) -> Locale {
+        Locale::new(tag).unwrap()
+    }
+
+    mod localized_name {
+        use super::*;
+
+        #[test]
+        fn test_translation_replaces_previous_one() {
+            let mut chapter = create_test_chapter();
+            chapter
+                .set_localized_name(create_locale("de"), "Erste Schritte".to_string())
+                .unwrap();
+            chapter
+                .set_localized_name(create_locale("de"), "Einstieg".to_string())
+                .unwrap();
+
+            assert_eq!(chapter.localized_name(&create_locale("de-AT")), "Einstieg");
+            assert_eq!(chapter.localized_names().len(), 1);
+        }
+
+        #[test]
+        fn test_too_long_translation_is_rejected() {
+            let mut chapter = create_test_chapter();
+
+            let result = chapter.set_localized_name(create_locale("de"), "a".repeat(51));
+
+            assert!(matches!(result, Err(ChapterError::NameError(_))));
+        }
+    }
+}
@@ -1,13 +1,28 @@
 mod add_chapter;
+mod add_lesson;
 mod chapter_operations;
+mod custom_fields;
 mod delete_chapter;
+mod find_replace;
 mod getters;
+mod localization;
+mod metadata;
 mod move_chapter;
+mod preview;
+mod release;
+mod rename;
+mod reviews;
 mod update_lesson;
 
-use crate::{Chapter, ChapterError};
+pub use find_replace::*;
+
+use crate::{
+    Chapter, ChapterError, DifficultyLevel, LessonError, ReleaseSchedule, Review, ReviewError,
+};
 use education_platform_common::{
-    Date, Duration, Entity, Id, SimpleName, SimpleNameConfig, SimpleNameError,
+    Auditable, CustomFieldError, CustomFields, Date, DateTime, Duration, Entity, Id, LocalizedText,
+    LocalizedTextError, SimpleName, SimpleNameConfig, SimpleNameError, Timestamps, Url, UrlError,
+    ValidatorError,
 };
 use thiserror::Error;
 
@@ -21,6 +36,9 @@ pub enum CourseError {
     #[error("Chapter error: {0}")]
     ChapterError(#[from] ChapterError),
 
+    #[error("Lesson error: {0}")]
+    LessonError(#[from] LessonError),
+
     #[error("Course must have at least one chapter")]
     CourseWithEmptyChapters,
 
@@ -35,13 +53,64 @@ pub enum CourseError {
 
     #[error("The number of lessons is zero")]
     NumberOfLessonsIsZero,
+
+    #[error("Review error: {0}")]
+    ReviewError(#[from] ReviewError),
+
+    #[error("Review does not exist")]
+    ReviewDoesNotExist,
+
+    #[error("{0} has already reviewed this course")]
+    AlreadyReviewed(String),
+
+    #[error("Invalid find pattern: {0}")]
+    PatternNotValid(String),
+
+    #[error("Renaming '{from}' to '{to}' gives an invalid name: {source}")]
+    RenameNotValid {
+        from: String,
+        to: String,
+        source: SimpleNameError,
+    },
+
+    #[error("Replacing '{from}' with '{to}' gives an invalid description: {source}")]
+    DescriptionReplaceNotValid {
+        from: String,
+        to: String,
+        source: ValidatorError,
+    },
+
+    #[error("Course translation failed: {0}")]
+    TranslationError(#[from] LocalizedTextError),
+
+    #[error("Course description validation failed: {0}")]
+    DescriptionError(ValidatorError),
+
+    #[error("Target audience validation failed: {0}")]
+    TargetAudienceError(ValidatorError),
+
+    #[error("Learning objective validation failed: {0}")]
+    LearningObjectiveError(ValidatorError),
+
+    #[error("A course can have at most {max} learning objectives")]
+    TooManyLearningObjectives { max: usize },
+
+    #[error("Learning objective does not exist")]
+    LearningObjectiveDoesNotExist,
+
+    #[error("Invalid cover image URL: {0}")]
+    CoverImageUrlError(UrlError),
+
+    #[error("Custom field not valid: {0}")]
+    CustomFieldError(#[from] CustomFieldError),
 }
 
 /// A course containing multiple chapters.
 ///
 /// `Course` is an aggregate root that groups related chapters together.
 /// Each course has a name, date, chapters, and computed totals for duration
-/// and number of lessons.
+/// and number of lessons, plus optional catalog metadata such as a
+/// description, difficulty level and cover image.
 ///
 /// # Examples
 ///
@@ -79,8 +148,23 @@ pub struct Course {
     chapters: Vec<Chapter>,
     duration: Duration,
     number_of_lessons: u32,
+    reviews: Vec<Review>,
+    localized_names: LocalizedText,
+    description: Option<String>,
+    localized_descriptions: LocalizedText,
+    difficulty: Option<DifficultyLevel>,
+    target_audience: Option<String>,
+    learning_objectives: Vec<String>,
+    cover_image_url: Option<Url>,
+    is_paid: bool,
+    release_schedule: ReleaseSchedule,
+    custom_fields: CustomFields,
+    timestamps: Timestamps,
 }
 
+/// Identifier of a [`Course`].
+pub type CourseId = Id<Course>;
+
 impl Course {
     /// Creates a new `Course` with the provided parameters.
     ///
@@ -131,6 +215,43 @@ impl Course {
         date: Option<Date>,
         duration: u64,
         chapters: Vec<Chapter>,
+    ) -> Result<Self, CourseError> {
+        Self::with_id(Id::default(), name, date, duration, chapters)
+    }
+
+    /// Creates a `Course` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::NameError` if the name validation fails.
+    /// Returns `CourseError::CourseWithEmptyChapters` if no chapters are provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    /// use education_platform_common::{Entity, Id};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    ///
+    /// let id = Id::new();
+    /// let course = Course::with_id(id, "Rust Programming".to_string(), None, 0, vec![chapter])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(course.id(), id);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        name: String,
+        date: Option<Date>,
+        duration: u64,
+        chapters: Vec<Chapter>,
     ) -> Result<Self, CourseError> {
         let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
         let base_duration = Duration::from_seconds(duration);
@@ -138,12 +259,24 @@ impl Course {
         let (total_duration, number_of_lessons) = Self::calculate_totals(&chapters, base_duration);
 
         Ok(Self {
-            id: Id::default(),
+            id,
             name,
             date: date.unwrap_or_else(Date::today),
             duration: total_duration,
             chapters,
             number_of_lessons,
+            reviews: Vec::new(),
+            localized_names: LocalizedText::new(),
+            description: None,
+            localized_descriptions: LocalizedText::new(),
+            difficulty: None,
+            target_audience: None,
+            learning_objectives: Vec::new(),
+            cover_image_url: None,
+            is_paid: false,
+            release_schedule: ReleaseSchedule::AllAtOnce,
+            custom_fields: CustomFields::new(),
+            timestamps: Timestamps::now(),
         })
     }
 }
@@ -154,6 +287,16 @@ impl Entity for Course {
     }
 }
 
+impl Auditable for Course {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,6 +340,17 @@ mod tests {
             assert_ne!(course1.id(), course2.id());
         }
 
+        #[test]
+        fn test_with_id_keeps_id() {
+            let id = Id::new();
+            let chapter = create_test_chapter("Chapter One", 0);
+
+            let course =
+                Course::with_id(id, "Test Course".to_string(), None, 0, vec![chapter]).unwrap();
+
+            assert_eq!(course.id(), id);
+        }
+
         #[test]
         fn test_new_with_empty_chapters_returns_error() {
             let result = Course::new("Test Course".to_string(), None, 0, vec![]);
@@ -349,4 +503,57 @@ mod tests {
             assert!(!id.to_string().is_empty());
         }
     }
+
+    mod auditable {
+        use super::*;
+
+        fn create_test_course() -> Course {
+            let chapter = create_test_chapter("Chapter One", 0);
+            Course::new("Test Course".to_string(), None, 0, vec![chapter]).unwrap()
+        }
+
+        #[test]
+        fn test_new_course_is_unmodified() {
+            let course = create_test_course();
+
+            assert_eq!(course.created_at(), course.updated_at());
+        }
+
+        #[test]
+        fn test_update_records_modification() {
+            let mut course = create_test_course();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            course.update_difficulty(None);
+
+            assert!(course.updated_at() > course.created_at());
+        }
+
+        #[test]
+        fn test_nested_change_touches_course_and_chapter() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            course
+                .add_lesson(chapter_id, create_test_lesson("Second Lesson", 1), None)
+                .unwrap();
+
+            assert!(course.updated_at() > course.created_at());
+            let chapter = &course.chapters()[0];
+            assert!(chapter.updated_at() > chapter.created_at());
+        }
+
+        #[test]
+        fn test_failed_update_keeps_timestamps() {
+            let mut course = create_test_course();
+            let before = course.updated_at();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            let result = course.remove_learning_objective(0);
+
+            assert!(result.is_err());
+            assert_eq!(course.updated_at(), before);
+        }
+    }
 }
@@ -57,6 +57,7 @@ impl Course {
             Self::calculate_totals(&self.chapters, Duration::default());
         self.duration = duration;
         self.number_of_lessons = number_of_lessons;
+        self.timestamps.touch();
 
         Ok(())
     }
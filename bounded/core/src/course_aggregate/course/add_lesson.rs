@@ -0,0 +1,146 @@
+use crate::{Course, CourseError, Lesson};
+use education_platform_common::{Duration, Entity, Id, Index};
+
+impl Course {
+    /// Adds a lesson to one of the course chapters at the specified position.
+    ///
+    /// The position follows [`Chapter::add_lesson`](crate::Chapter::add_lesson):
+    /// `None` appends the lesson at the end of the chapter. The course duration
+    /// and lesson count are recalculated afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::ChapterDoesNotExist` if no chapter has the given ID.
+    /// Returns `CourseError::ChapterError` if the chapter rejects the lesson.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, Chapter, Lesson};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     1800,
+    ///     "https://example.com/1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let chapter_id = chapter.id();
+    /// let mut course = Course::new("My Course".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let recording = Lesson::new(
+    ///     "Live Q&A".to_string(),
+    ///     3600,
+    ///     "https://example.com/2.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// course.add_lesson(chapter_id, recording, None).unwrap();
+    /// assert_eq!(course.number_of_lessons(), 2);
+    /// assert_eq!(course.duration().total_seconds(), 5400);
+    /// ```
+    pub fn add_lesson(
+        &mut self,
+        chapter_id: Id,
+        lesson: Lesson,
+        index: Option<Index>,
+    ) -> Result<(), CourseError> {
+        let chapter = self
+            .chapters
+            .iter_mut()
+            .find(|chapter| chapter.id() == chapter_id)
+            .ok_or(CourseError::ChapterDoesNotExist)?;
+
+        chapter.add_lesson(lesson, index)?;
+        let (duration, number_of_lessons) =
+            Self::calculate_totals(&self.chapters, Duration::default());
+        self.duration = duration;
+        self.number_of_lessons = number_of_lessons;
+        self.timestamps.touch();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Chapter;
+
+    fn create_test_lesson(name: &str, duration: u64, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            duration,
+            format!("https://example.com/{index}.mp4"),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let first =
+            Chapter::new("Chapter 1".to_string(), 0, vec![create_test_lesson("First", 600, 0)])
+                .unwrap();
+        let second =
+            Chapter::new("Chapter 2".to_string(), 1, vec![create_test_lesson("Second", 600, 0)])
+                .unwrap();
+        Course::new("Test Course".to_string(), None, 0, vec![first, second]).unwrap()
+    }
+
+    mod add_lesson {
+        use super::*;
+
+        #[test]
+        fn test_add_lesson_appends_to_chapter() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[1].id();
+
+            course
+                .add_lesson(chapter_id, create_test_lesson("Recording", 900, 0), None)
+                .unwrap();
+
+            let lessons = course.chapters()[1].lessons();
+            assert_eq!(lessons.len(), 2);
+            assert_eq!(lessons[1].name().as_str(), "Recording");
+            assert_eq!(lessons[1].index().value(), 1);
+            assert_eq!(course.chapters()[0].lessons().len(), 1);
+        }
+
+        #[test]
+        fn test_add_lesson_recalculates_totals() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+
+            course
+                .add_lesson(chapter_id, create_test_lesson("Recording", 900, 0), None)
+                .unwrap();
+
+            assert_eq!(course.number_of_lessons(), 3);
+            assert_eq!(course.duration().total_seconds(), 2100);
+        }
+
+        #[test]
+        fn test_add_lesson_at_position() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+
+            course
+                .add_lesson(chapter_id, create_test_lesson("Warm-up", 300, 0), Some(Index::new(0)))
+                .unwrap();
+
+            assert_eq!(course.chapters()[0].lessons()[0].name().as_str(), "Warm-up");
+        }
+
+        #[test]
+        fn test_add_lesson_to_unknown_chapter_returns_error() {
+            let mut course = create_test_course();
+
+            let result =
+                course.add_lesson(Id::new(), create_test_lesson("Recording", 900, 0), None);
+
+            assert_eq!(result.err(), Some(CourseError::ChapterDoesNotExist));
+            assert_eq!(course.number_of_lessons(), 2);
+        }
+    }
+}
@@ -16,7 +16,7 @@ impl Course {
         let duration = chapters
             .iter()
             .map(|chapter| chapter.total_duration())
-            .fold(Duration::default(), |acc, d| acc.add(&d));
+            .sum();
 
         let lessons = chapters
             .iter()
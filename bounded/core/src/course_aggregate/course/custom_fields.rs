@@ -0,0 +1,140 @@
+use crate::{Course, CourseError};
+use education_platform_common::{CustomFieldSchema, CustomFieldTarget, CustomFields};
+
+impl Course {
+    /// Returns the organization-specific fields recorded for the course.
+    #[inline]
+    #[must_use]
+    pub const fn custom_fields(&self) -> &CustomFields {
+        &self.custom_fields
+    }
+
+    /// Sets or clears one of the organization's course fields.
+    ///
+    /// A blank value clears the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::CustomFieldError` if the schema has no such
+    /// course field or the value breaks its rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    /// use education_platform_common::{
+    ///     CustomFieldDefinition, CustomFieldKind, CustomFieldSchema, CustomFieldTarget,
+    ///     CustomFieldValue, Id,
+    /// };
+    ///
+    /// let mut schema = CustomFieldSchema::new(Id::new());
+    /// schema.add_definition(CustomFieldDefinition::new(
+    ///     "credits",
+    ///     "Credits",
+    ///     CustomFieldKind::Number { min: Some(1), max: Some(12) },
+    ///     CustomFieldTarget::Course,
+    ///     false,
+    /// ).unwrap()).unwrap();
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.set_custom_field(&schema, "credits", "6").unwrap();
+    ///
+    /// assert_eq!(course.custom_fields().get("credits"), Some(&CustomFieldValue::Number(6)));
+    /// assert!(course.set_custom_field(&schema, "credits", "20").is_err());
+    /// ```
+    pub fn set_custom_field(
+        &mut self,
+        schema: &CustomFieldSchema,
+        key: &str,
+        value: &str,
+    ) -> Result<(), CourseError> {
+        schema.set_value(CustomFieldTarget::Course, &mut self.custom_fields, key, value)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Returns the course with `custom_fields` replacing its values, when
+    /// reading a stored course back.
+    pub(crate) fn with_custom_fields(mut self, custom_fields: CustomFields) -> Self {
+        self.custom_fields = custom_fields;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson};
+    use education_platform_common::{
+        CustomFieldDefinition, CustomFieldError, CustomFieldKind, Entity, Id,
+    };
+
+    fn create_test_course() -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_test_schema() -> CustomFieldSchema {
+        let mut schema = CustomFieldSchema::new(Id::new());
+        schema
+            .add_definition(
+                CustomFieldDefinition::new(
+                    "department",
+                    "Department",
+                    CustomFieldKind::Text { max_length: 40 },
+                    CustomFieldTarget::Course,
+                    true,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        schema
+    }
+
+    #[test]
+    fn test_required_field_cannot_be_cleared() {
+        let schema = create_test_schema();
+        let mut course = create_test_course();
+        course
+            .set_custom_field(&schema, "department", "Computer Science")
+            .unwrap();
+
+        let result = course.set_custom_field(&schema, "department", "");
+
+        assert_eq!(
+            result,
+            Err(CourseError::CustomFieldError(CustomFieldError::MissingValue(
+                "department".to_string()
+            )))
+        );
+        assert_eq!(course.custom_fields().len(), 1);
+    }
+
+    #[test]
+    fn test_course_fields_export_to_csv() {
+        let schema = create_test_schema();
+        let mut course = create_test_course();
+        course
+            .set_custom_field(&schema, "department", "Computer Science")
+            .unwrap();
+
+        let csv = schema.to_csv(CustomFieldTarget::Course, [(course.id(), course.custom_fields())]);
+
+        assert_eq!(csv, format!("id,department\r\n{},Computer Science\r\n", course.id()));
+    }
+}
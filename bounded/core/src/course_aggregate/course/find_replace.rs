@@ -0,0 +1,681 @@
+use crate::{Chapter, Course, CourseError, Lesson};
+use education_platform_common::{Entity, Id, Rule, Rules, SimpleName, SimpleNameConfig};
+use regex::{NoExpand, Regex};
+use std::borrow::Cow;
+
+/// What to search for in course item names and descriptions.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::FindPattern;
+///
+/// assert!(FindPattern::literal("Module (draft)").is_ok());
+/// assert!(FindPattern::regex(r"^Module (\d+)").is_ok());
+/// assert!(FindPattern::regex("(unclosed").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FindPattern {
+    regex: Regex,
+    literal: bool,
+}
+
+impl FindPattern {
+    /// Matches the text exactly; the replacement is inserted verbatim.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::PatternNotValid` if the text is empty.
+    pub fn literal(text: &str) -> Result<Self, CourseError> {
+        if text.is_empty() {
+            return Err(CourseError::PatternNotValid("pattern must not be empty".to_string()));
+        }
+        Self::build(&regex::escape(text), true)
+    }
+
+    /// Matches a regular expression; the replacement may refer to capture
+    /// groups as `$1` or `${name}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::PatternNotValid` if the expression does not compile
+    /// or is empty.
+    pub fn regex(pattern: &str) -> Result<Self, CourseError> {
+        if pattern.is_empty() {
+            return Err(CourseError::PatternNotValid("pattern must not be empty".to_string()));
+        }
+        Self::build(pattern, false)
+    }
+
+    fn build(pattern: &str, literal: bool) -> Result<Self, CourseError> {
+        Regex::new(pattern)
+            .map(|regex| Self { regex, literal })
+            .map_err(|error| CourseError::PatternNotValid(error.to_string()))
+    }
+
+    fn replace<'a>(&self, text: &'a str, replacement: &str) -> Cow<'a, str> {
+        match self.literal {
+            true => self.regex.replace_all(text, NoExpand(replacement)),
+            false => self.regex.replace_all(text, replacement),
+        }
+    }
+}
+
+/// Which course texts a find-and-replace touches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ReplaceScope {
+    ChapterNames,
+    LessonNames,
+    ChapterDescriptions,
+    LessonDescriptions,
+    #[default]
+    All,
+}
+
+impl ReplaceScope {
+    const fn includes_chapter_names(self) -> bool {
+        matches!(self, Self::ChapterNames | Self::All)
+    }
+
+    const fn includes_lesson_names(self) -> bool {
+        matches!(self, Self::LessonNames | Self::All)
+    }
+
+    const fn includes_chapter_descriptions(self) -> bool {
+        matches!(self, Self::ChapterDescriptions | Self::All)
+    }
+
+    const fn includes_lesson_descriptions(self) -> bool {
+        matches!(self, Self::LessonDescriptions | Self::All)
+    }
+}
+
+/// The course text a rename applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenameTarget {
+    Chapter(Id),
+    Lesson(Id),
+    ChapterDescription(Id),
+    LessonDescription(Id),
+}
+
+/// One name or description a find-and-replace changes, before and after.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rename {
+    target: RenameTarget,
+    before: String,
+    after: String,
+}
+
+impl Rename {
+    /// Returns the chapter or lesson text that changes.
+    #[inline]
+    #[must_use]
+    pub const fn target(&self) -> RenameTarget {
+        self.target
+    }
+
+    /// Returns the current text.
+    #[inline]
+    #[must_use]
+    pub fn before(&self) -> &str {
+        &self.before
+    }
+
+    /// Returns the text after replacement.
+    #[inline]
+    #[must_use]
+    pub fn after(&self) -> &str {
+        &self.after
+    }
+}
+
+impl Course {
+    /// Lists the names and descriptions a find-and-replace would change,
+    /// without changing them.
+    ///
+    /// Items are listed chapter by chapter, each chapter before its lessons,
+    /// and each name before its description. Every resulting text is
+    /// validated, so a preview that succeeds can be applied with
+    /// [`Course::find_replace`] without errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::RenameNotValid` if a resulting name would be
+    /// invalid, for instance too short, or
+    /// `CourseError::DescriptionReplaceNotValid` if a resulting description
+    /// would be blank or too long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, FindPattern, Lesson, ReplaceScope};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Module 1 Intro".to_string(),
+    ///     600,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Module 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let pattern = FindPattern::literal("Module").unwrap();
+    /// let preview = course.preview_find_replace(&pattern, "Chapter", ReplaceScope::All).unwrap();
+    ///
+    /// assert_eq!(preview.len(), 2);
+    /// assert_eq!(preview[1].after(), "Chapter 1 Intro");
+    /// assert_eq!(course.chapters()[0].name().as_str(), "Module 1");
+    /// ```
+    pub fn preview_find_replace(
+        &self,
+        pattern: &FindPattern,
+        replacement: &str,
+        scope: ReplaceScope,
+    ) -> Result<Vec<Rename>, CourseError> {
+        let mut renames = Vec::new();
+
+        for chapter in &self.chapters {
+            if scope.includes_chapter_names() {
+                let target = RenameTarget::Chapter(chapter.id());
+                renames.extend(Self::rename(
+                    target,
+                    chapter.name().as_str(),
+                    pattern,
+                    replacement,
+                )?);
+            }
+            if scope.includes_chapter_descriptions()
+                && let Some(description) = chapter.description()
+            {
+                let target = RenameTarget::ChapterDescription(chapter.id());
+                renames.extend(Self::replace_description(
+                    target,
+                    description,
+                    Chapter::MAX_DESCRIPTION_LENGTH,
+                    pattern,
+                    replacement,
+                )?);
+            }
+            for lesson in chapter.lessons() {
+                if scope.includes_lesson_names() {
+                    let target = RenameTarget::Lesson(lesson.id());
+                    renames.extend(Self::rename(
+                        target,
+                        lesson.name().as_str(),
+                        pattern,
+                        replacement,
+                    )?);
+                }
+                if scope.includes_lesson_descriptions()
+                    && let Some(description) = lesson.description()
+                {
+                    let target = RenameTarget::LessonDescription(lesson.id());
+                    renames.extend(Self::replace_description(
+                        target,
+                        description,
+                        Lesson::MAX_DESCRIPTION_LENGTH,
+                        pattern,
+                        replacement,
+                    )?);
+                }
+            }
+        }
+        Ok(renames)
+    }
+
+    /// Replaces every match of the pattern in the chapter and lesson names
+    /// and descriptions the scope covers.
+    ///
+    /// Either every text is changed or, if any resulting text is invalid,
+    /// none is. IDs, order and everything else about the items is kept.
+    /// Returns the applied renames, as [`Course::preview_find_replace`] lists them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::RenameNotValid` if a resulting name would be
+    /// invalid, or `CourseError::DescriptionReplaceNotValid` if a resulting
+    /// description would be.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, FindPattern, Lesson, ReplaceScope};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Module 1 Intro".to_string(),
+    ///     600,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Module 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let pattern = FindPattern::regex(r"^Module (\d+)$").unwrap();
+    /// course.find_replace(&pattern, "Chapter $1", ReplaceScope::ChapterNames).unwrap();
+    ///
+    /// assert_eq!(course.chapters()[0].name().as_str(), "Chapter 1");
+    /// assert_eq!(course.chapters()[0].lessons()[0].name().as_str(), "Module 1 Intro");
+    /// ```
+    pub fn find_replace(
+        &mut self,
+        pattern: &FindPattern,
+        replacement: &str,
+        scope: ReplaceScope,
+    ) -> Result<Vec<Rename>, CourseError> {
+        let renames = self.preview_find_replace(pattern, replacement, scope)?;
+
+        for rename in &renames {
+            let after = rename.after.clone();
+            match rename.target {
+                RenameTarget::Chapter(id) => {
+                    if let Some(chapter) = self.chapter_mut(id) {
+                        chapter.update_name(after)?;
+                    }
+                }
+                RenameTarget::ChapterDescription(id) => {
+                    if let Some(chapter) = self.chapter_mut(id) {
+                        chapter.update_description(Some(after))?;
+                    }
+                }
+                RenameTarget::Lesson(id) => {
+                    if let Some(lesson) = self.lesson_mut(id) {
+                        lesson.update_name(after)?;
+                    }
+                }
+                RenameTarget::LessonDescription(id) => {
+                    if let Some(lesson) = self.lesson_mut(id) {
+                        lesson.update_description(Some(after))?;
+                    }
+                }
+            }
+        }
+        if !renames.is_empty() {
+            self.timestamps.touch();
+        }
+        Ok(renames)
+    }
+
+    fn rename(
+        target: RenameTarget,
+        name: &str,
+        pattern: &FindPattern,
+        replacement: &str,
+    ) -> Result<Option<Rename>, CourseError> {
+        let after = pattern.replace(name, replacement);
+        if after == name {
+            return Ok(None);
+        }
+
+        let after = after.into_owned();
+        SimpleName::with_config(after.clone(), SimpleNameConfig::new(3, 50)).map_err(|source| {
+            CourseError::RenameNotValid {
+                from: name.to_string(),
+                to: after.clone(),
+                source,
+            }
+        })?;
+
+        Ok(Some(Rename {
+            target,
+            before: name.to_string(),
+            after,
+        }))
+    }
+
+    fn replace_description(
+        target: RenameTarget,
+        description: &str,
+        max_length: usize,
+        pattern: &FindPattern,
+        replacement: &str,
+    ) -> Result<Option<Rename>, CourseError> {
+        let replaced = pattern.replace(description, replacement);
+        let after = replaced.trim();
+        if after == description {
+            return Ok(None);
+        }
+
+        Rules::not_empty()
+            .and(Rules::max_length(max_length))
+            .check(after)
+            .map_err(|source| CourseError::DescriptionReplaceNotValid {
+                from: description.to_string(),
+                to: after.to_string(),
+                source,
+            })?;
+
+        Ok(Some(Rename {
+            target,
+            before: description.to_string(),
+            after: after.to_string(),
+        }))
+    }
+
+    fn chapter_mut(&mut self, id: Id) -> Option<&mut Chapter> {
+        self.chapters.iter_mut().find(|chapter| chapter.id() == id)
+    }
+
+    fn lesson_mut(&mut self, id: Id) -> Option<&mut Lesson> {
+        self.chapters
+            .iter_mut()
+            .flat_map(|chapter| chapter.lessons_mut().iter_mut())
+            .find(|lesson| lesson.id() == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson};
+
+    fn create_test_lesson(name: &str, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            600,
+            format!("https://example.com/{index}.mp4"),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let first = Chapter::new(
+            "Module 1: Basics".to_string(),
+            0,
+            vec![
+                create_test_lesson("Module 1 Overview", 0),
+                create_test_lesson("Variables", 1),
+            ],
+        )
+        .unwrap();
+        let second = Chapter::new(
+            "Module 2: Traits".to_string(),
+            1,
+            vec![create_test_lesson("Module 2 Overview", 0)],
+        )
+        .unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![first, second]).unwrap()
+    }
+
+    fn create_described_course() -> Course {
+        let mut lesson = create_test_lesson("Overview", 0);
+        lesson
+            .update_description(Some("What Module 1 covers.".to_string()))
+            .unwrap();
+        let mut chapter = Chapter::new("Module 1".to_string(), 0, vec![lesson]).unwrap();
+        chapter
+            .update_description(Some("Module 1 introduces Rust.".to_string()))
+            .unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn names(course: &Course) -> Vec<String> {
+        course
+            .chapters()
+            .iter()
+            .flat_map(|chapter| {
+                std::iter::once(chapter.name().to_string())
+                    .chain(chapter.lessons().iter().map(|l| l.name().to_string()))
+            })
+            .collect()
+    }
+
+    mod find_pattern {
+        use super::*;
+
+        #[test]
+        fn test_literal_escapes_regex_syntax() {
+            let pattern = FindPattern::literal("1.").unwrap();
+
+            assert_eq!(pattern.replace("1x 1.", "one"), "1x one");
+        }
+
+        #[test]
+        fn test_literal_replacement_is_not_expanded() {
+            let pattern = FindPattern::literal("cost").unwrap();
+
+            assert_eq!(pattern.replace("cost", "$1"), "$1");
+        }
+
+        #[test]
+        fn test_empty_pattern_is_rejected() {
+            assert!(matches!(
+                FindPattern::literal(""),
+                Err(CourseError::PatternNotValid(_))
+            ));
+            assert!(matches!(FindPattern::regex(""), Err(CourseError::PatternNotValid(_))));
+        }
+    }
+
+    mod preview_find_replace {
+        use super::*;
+
+        #[test]
+        fn test_lists_matches_in_course_order() {
+            let course = create_test_course();
+            let pattern = FindPattern::literal("Module").unwrap();
+
+            let preview = course
+                .preview_find_replace(&pattern, "Chapter", ReplaceScope::All)
+                .unwrap();
+
+            let afters: Vec<&str> = preview.iter().map(Rename::after).collect();
+            assert_eq!(
+                afters,
+                vec![
+                    "Chapter 1: Basics",
+                    "Chapter 1 Overview",
+                    "Chapter 2: Traits",
+                    "Chapter 2 Overview",
+                ]
+            );
+        }
+
+        #[test]
+        fn test_scope_limits_targets() {
+            let course = create_test_course();
+            let pattern = FindPattern::literal("Module").unwrap();
+
+            let preview = course
+                .preview_find_replace(&pattern, "Unit", ReplaceScope::LessonNames)
+                .unwrap();
+
+            assert_eq!(preview.len(), 2);
+            assert!(
+                preview
+                    .iter()
+                    .all(|rename| matches!(rename.target(), RenameTarget::Lesson(_)))
+            );
+        }
+
+        #[test]
+        fn test_all_includes_descriptions() {
+            let course = create_described_course();
+            let chapter_id = course.chapters()[0].id();
+            let lesson_id = course.chapters()[0].lessons()[0].id();
+
+            let preview = course
+                .preview_find_replace(
+                    &FindPattern::literal("Module").unwrap(),
+                    "Unit",
+                    ReplaceScope::All,
+                )
+                .unwrap();
+
+            let targets: Vec<RenameTarget> = preview.iter().map(Rename::target).collect();
+            assert_eq!(
+                targets,
+                vec![
+                    RenameTarget::Chapter(chapter_id),
+                    RenameTarget::ChapterDescription(chapter_id),
+                    RenameTarget::LessonDescription(lesson_id),
+                ]
+            );
+            assert_eq!(preview[1].before(), "Module 1 introduces Rust.");
+            assert_eq!(preview[1].after(), "Unit 1 introduces Rust.");
+        }
+
+        #[test]
+        fn test_description_scopes_limit_targets() {
+            let course = create_described_course();
+            let pattern = FindPattern::literal("Module").unwrap();
+
+            let chapters = course
+                .preview_find_replace(&pattern, "Unit", ReplaceScope::ChapterDescriptions)
+                .unwrap();
+            let lessons = course
+                .preview_find_replace(&pattern, "Unit", ReplaceScope::LessonDescriptions)
+                .unwrap();
+
+            assert_eq!(chapters.len(), 1);
+            assert!(matches!(chapters[0].target(), RenameTarget::ChapterDescription(_)));
+            assert_eq!(lessons.len(), 1);
+            assert_eq!(lessons[0].after(), "What Unit 1 covers.");
+        }
+
+        #[test]
+        fn test_items_without_description_are_skipped() {
+            let course = create_test_course();
+
+            let preview = course
+                .preview_find_replace(
+                    &FindPattern::literal("Module").unwrap(),
+                    "Unit",
+                    ReplaceScope::ChapterDescriptions,
+                )
+                .unwrap();
+
+            assert!(preview.is_empty());
+        }
+
+        #[test]
+        fn test_does_not_modify_course() {
+            let course = create_test_course();
+            let before = names(&course);
+
+            course
+                .preview_find_replace(
+                    &FindPattern::literal("Module").unwrap(),
+                    "Unit",
+                    ReplaceScope::All,
+                )
+                .unwrap();
+
+            assert_eq!(names(&course), before);
+        }
+    }
+
+    mod find_replace {
+        use super::*;
+
+        #[test]
+        fn test_renames_and_keeps_ids() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let lesson_id = course.chapters()[0].lessons()[0].id();
+
+            course
+                .find_replace(
+                    &FindPattern::regex(r"Module (\d)").unwrap(),
+                    "Part $1",
+                    ReplaceScope::All,
+                )
+                .unwrap();
+
+            assert_eq!(course.chapters()[0].name().as_str(), "Part 1: Basics");
+            assert_eq!(course.chapters()[0].id(), chapter_id);
+            assert_eq!(course.chapters()[0].lessons()[0].name().as_str(), "Part 1 Overview");
+            assert_eq!(course.chapters()[0].lessons()[0].id(), lesson_id);
+        }
+
+        #[test]
+        fn test_replaces_descriptions() {
+            let mut course = create_described_course();
+
+            course
+                .find_replace(
+                    &FindPattern::regex(r"Module (\d)").unwrap(),
+                    "Part $1",
+                    ReplaceScope::All,
+                )
+                .unwrap();
+
+            let chapter = &course.chapters()[0];
+            assert_eq!(chapter.name().as_str(), "Part 1");
+            assert_eq!(chapter.description(), Some("Part 1 introduces Rust."));
+            assert_eq!(chapter.lessons()[0].description(), Some("What Part 1 covers."));
+        }
+
+        #[test]
+        fn test_blank_description_is_rejected() {
+            let mut course = create_described_course();
+
+            let result = course.find_replace(
+                &FindPattern::regex(r"^Module 1 introduces Rust\.$|What").unwrap(),
+                " ",
+                ReplaceScope::All,
+            );
+
+            assert!(matches!(
+                result,
+                Err(CourseError::DescriptionReplaceNotValid { ref from, ref to, .. })
+                    if from == "Module 1 introduces Rust." && to.is_empty()
+            ));
+            assert_eq!(course.chapters()[0].description(), Some("Module 1 introduces Rust."));
+            assert_eq!(
+                course.chapters()[0].lessons()[0].description(),
+                Some("What Module 1 covers.")
+            );
+        }
+
+        #[test]
+        fn test_no_match_changes_nothing() {
+            let mut course = create_test_course();
+
+            let renames = course
+                .find_replace(
+                    &FindPattern::literal("Section").unwrap(),
+                    "Chapter",
+                    ReplaceScope::All,
+                )
+                .unwrap();
+
+            assert!(renames.is_empty());
+        }
+
+        #[test]
+        fn test_invalid_result_aborts_every_rename() {
+            let mut course = create_test_course();
+            let before = names(&course);
+
+            let result = course.find_replace(
+                &FindPattern::regex("^Variables$").unwrap(),
+                "V",
+                ReplaceScope::All,
+            );
+
+            assert!(matches!(
+                result,
+                Err(CourseError::RenameNotValid { ref from, ref to, .. })
+                    if from == "Variables" && to == "V"
+            ));
+            assert_eq!(names(&course), before);
+        }
+
+        #[test]
+        fn test_failed_rename_leaves_earlier_matches_untouched() {
+            let mut course = create_test_course();
+            let before = names(&course);
+
+            let result = course.find_replace(
+                &FindPattern::regex("Module 2 Overview|Module").unwrap(),
+                "M",
+                ReplaceScope::All,
+            );
+
+            assert!(result.is_err());
+            assert_eq!(names(&course), before);
+        }
+    }
+}
@@ -1,7 +1,32 @@
-use super::{Chapter, Course, Date, Duration, SimpleName};
-use crate::{CourseError, Lesson};
+use super::{Chapter, Course, Date, Duration, Entity, SimpleName};
+use crate::{ChapterId, CourseError, CourseId, Lesson, LessonId};
 
 impl Course {
+    /// Returns the course ID typed as a [`CourseId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Entity;
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// assert_eq!(course.course_id().untyped(), course.id());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> CourseId {
+        self.id.cast()
+    }
+
     /// Returns the course name.
     ///
     /// # Examples
@@ -397,6 +422,114 @@ impl Course {
             .cloned()
             .collect())
     }
+
+    /// Returns the chapter with the given ID, if the course has it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let chapter_id = chapter.chapter_id();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let found = course.find_chapter(chapter_id).unwrap();
+    /// assert_eq!(found.name().as_str(), "Getting Started");
+    /// ```
+    #[must_use]
+    pub fn find_chapter(&self, chapter_id: ChapterId) -> Option<&Chapter> {
+        self.chapters
+            .iter()
+            .find(|chapter| chapter.id() == chapter_id.untyped())
+    }
+
+    /// Returns the lesson with the given ID together with the chapter that
+    /// holds it, searching every chapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let intro = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let traits = Lesson::new(
+    ///     "Traits".to_string(),
+    ///     2400,
+    ///     "https://example.com/traits.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let traits_id = traits.lesson_id();
+    /// let chapters = vec![
+    ///     Chapter::new("Getting Started".to_string(), 0, vec![intro]).unwrap(),
+    ///     Chapter::new("Abstractions".to_string(), 1, vec![traits]).unwrap(),
+    /// ];
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, chapters).unwrap();
+    ///
+    /// let (chapter, lesson) = course.find_lesson(traits_id).unwrap();
+    /// assert_eq!(chapter.name().as_str(), "Abstractions");
+    /// assert_eq!(lesson.name().as_str(), "Traits");
+    /// ```
+    #[must_use]
+    pub fn find_lesson(&self, lesson_id: LessonId) -> Option<(&Chapter, &Lesson)> {
+        self.chapters.iter().find_map(|chapter| {
+            chapter
+                .find_lesson(lesson_id)
+                .map(|lesson| (chapter, lesson))
+        })
+    }
+
+    /// Returns the lessons whose content involved AI, in course order.
+    ///
+    /// These are the lessons a syllabus must flag with their
+    /// [`ContentProvenance`](crate::ContentProvenance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{AiModel, Chapter, ContentProvenance, Course, Lesson};
+    ///
+    /// let written = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let mut generated = Lesson::new(
+    ///     "Recap".to_string(),
+    ///     600,
+    ///     "https://example.com/recap.mp4".to_string(),
+    ///     1,
+    /// ).unwrap();
+    /// generated.update_provenance(ContentProvenance::AiGenerated(
+    ///     AiModel::new("Mistral Large").unwrap(),
+    /// ));
+    /// let chapter = Chapter::new("Basics".to_string(), 0, vec![written, generated]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let disclosed = course.lessons_requiring_disclosure();
+    /// assert_eq!(disclosed.len(), 1);
+    /// assert_eq!(disclosed[0].name().as_str(), "Recap");
+    /// ```
+    #[must_use]
+    pub fn lessons_requiring_disclosure(&self) -> Vec<&Lesson> {
+        self.chapters
+            .iter()
+            .flat_map(|chapter| chapter.lessons())
+            .filter(|lesson| lesson.provenance().requires_disclosure())
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -420,6 +553,46 @@ mod tests {
         Chapter::new(name.to_string(), index, vec![lesson]).unwrap()
     }
 
+    mod lessons_requiring_disclosure {
+        use super::*;
+        use crate::{AiModel, ContentProvenance};
+
+        #[test]
+        fn test_human_authored_course_has_no_disclosures() {
+            let course = Course::new(
+                "Rust Programming".to_string(),
+                None,
+                0,
+                vec![create_test_chapter("Chapter One", 0)],
+            )
+            .unwrap();
+
+            assert!(course.lessons_requiring_disclosure().is_empty());
+        }
+
+        #[test]
+        fn test_lists_ai_lessons_across_chapters() {
+            let model = AiModel::new("Mistral Large").unwrap();
+            let mut assisted = create_test_lesson("Assisted", 0);
+            assisted.update_provenance(ContentProvenance::AiAssisted(model.clone()));
+            let mut generated = create_test_lesson("Generated", 0);
+            generated.update_provenance(ContentProvenance::AiGenerated(model));
+            let chapters = vec![
+                Chapter::new("Chapter One".to_string(), 0, vec![assisted]).unwrap(),
+                create_test_chapter("Chapter Two", 1),
+                Chapter::new("Chapter Three".to_string(), 2, vec![generated]).unwrap(),
+            ];
+            let course = Course::new("Rust Programming".to_string(), None, 0, chapters).unwrap();
+
+            let names: Vec<&str> = course
+                .lessons_requiring_disclosure()
+                .iter()
+                .map(|lesson| lesson.name().as_str())
+                .collect();
+            assert_eq!(names, vec!["Assisted", "Generated"]);
+        }
+    }
+
     mod name {
         use super::*;
 
@@ -935,4 +1108,83 @@ mod tests {
             assert_eq!(lessons1[0].id(), lessons2[0].id());
         }
     }
+
+    mod find_chapter {
+        use super::*;
+        use education_platform_common::Id;
+
+        #[test]
+        fn test_finds_chapter_by_id() {
+            let first = create_test_chapter("Chapter One", 0);
+            let second = create_test_chapter("Chapter Two", 1);
+            let second_id = second.chapter_id();
+            let course =
+                Course::new("Rust Programming".to_string(), None, 0, vec![first, second]).unwrap();
+
+            let found = course.find_chapter(second_id).unwrap();
+
+            assert_eq!(found.name().as_str(), "Chapter Two");
+        }
+
+        #[test]
+        fn test_unknown_id_returns_none() {
+            let course = Course::new(
+                "Rust Programming".to_string(),
+                None,
+                0,
+                vec![create_test_chapter("Chapter One", 0)],
+            )
+            .unwrap();
+
+            assert!(course.find_chapter(Id::new().cast()).is_none());
+        }
+    }
+
+    mod find_lesson {
+        use super::*;
+        use education_platform_common::Id;
+
+        #[test]
+        fn test_returns_lesson_with_owning_chapter() {
+            let first = create_test_chapter("Chapter One", 0);
+            let target = create_test_lesson("Target", 1);
+            let target_id = target.lesson_id();
+            let second = Chapter::new(
+                "Chapter Two".to_string(),
+                1,
+                vec![create_test_lesson("Other", 0), target],
+            )
+            .unwrap();
+            let course =
+                Course::new("Rust Programming".to_string(), None, 0, vec![first, second]).unwrap();
+
+            let (chapter, lesson) = course.find_lesson(target_id).unwrap();
+
+            assert_eq!(chapter.name().as_str(), "Chapter Two");
+            assert_eq!(lesson.id(), target_id.untyped());
+        }
+
+        #[test]
+        fn test_unknown_id_returns_none() {
+            let course = Course::new(
+                "Rust Programming".to_string(),
+                None,
+                0,
+                vec![create_test_chapter("Chapter One", 0)],
+            )
+            .unwrap();
+
+            assert!(course.find_lesson(Id::new().cast()).is_none());
+        }
+
+        #[test]
+        fn test_chapter_id_does_not_match_a_lesson() {
+            let chapter = create_test_chapter("Chapter One", 0);
+            let chapter_id = chapter.chapter_id();
+            let course =
+                Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+
+            assert!(course.find_lesson(chapter_id.cast()).is_none());
+        }
+    }
 }
@@ -0,0 +1,358 @@
+use crate::{Course, CourseError};
+use education_platform_common::{Entity, Id, Locale, LocalizedText, SimpleName, SimpleNameConfig};
+
+impl Course {
+    /// Sets the course name shown to learners of a locale.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::NameError` if the name validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    /// use education_platform_common::Locale;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course
+    ///     .set_localized_name(Locale::new("es").unwrap(), "Programación en Rust".to_string())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(course.localized_name(&Locale::new("es-AR").unwrap()), "Programación en Rust");
+    /// assert_eq!(course.localized_name(&Locale::new("it").unwrap()), "Rust Programming");
+    /// ```
+    pub fn set_localized_name(&mut self, locale: Locale, name: String) -> Result<(), CourseError> {
+        let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        self.localized_names.set(locale, name.as_str())?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes the name translation of a locale, returning true if there was one.
+    pub fn remove_localized_name(&mut self, locale: &Locale) -> bool {
+        let removed = self.localized_names.remove(locale).is_some();
+        if removed {
+            self.timestamps.touch();
+        }
+        removed
+    }
+
+    /// Returns the name for a locale, falling back to the course name when
+    /// no translation matches.
+    #[must_use]
+    pub fn localized_name(&self, locale: &Locale) -> &str {
+        self.localized_names
+            .resolve(locale)
+            .unwrap_or(self.name.as_str())
+    }
+
+    /// Returns every name translation.
+    #[inline]
+    #[must_use]
+    pub const fn localized_names(&self) -> &LocalizedText {
+        &self.localized_names
+    }
+
+    /// Sets the course description for a locale.
+    ///
+    /// Translations follow the same rules as the course description.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::DescriptionError` if the description is blank or
+    /// longer than [`Course::MAX_DESCRIPTION_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    /// use education_platform_common::Locale;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let es = Locale::new("es").unwrap();
+    ///
+    /// assert_eq!(course.localized_description(&es), None);
+    ///
+    /// course.update_description(Some("Learn Rust from scratch.".to_string())).unwrap();
+    /// assert_eq!(course.localized_description(&es), Some("Learn Rust from scratch."));
+    ///
+    /// course
+    ///     .set_localized_description(es.clone(), "Aprende Rust desde cero.".to_string())
+    ///     .unwrap();
+    /// assert_eq!(course.localized_description(&es), Some("Aprende Rust desde cero."));
+    /// assert!(course.set_localized_description(es, " ".to_string()).is_err());
+    /// ```
+    pub fn set_localized_description(
+        &mut self,
+        locale: Locale,
+        description: String,
+    ) -> Result<(), CourseError> {
+        let description = Self::validate_text(&description, Self::MAX_DESCRIPTION_LENGTH)
+            .map_err(CourseError::DescriptionError)?;
+        self.localized_descriptions.set(locale, &description)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes the description translation of a locale, returning true if
+    /// there was one.
+    pub fn remove_localized_description(&mut self, locale: &Locale) -> bool {
+        let removed = self.localized_descriptions.remove(locale).is_some();
+        if removed {
+            self.timestamps.touch();
+        }
+        removed
+    }
+
+    /// Returns the description for a locale, falling back to the course
+    /// description when no translation matches.
+    #[must_use]
+    pub fn localized_description(&self, locale: &Locale) -> Option<&str> {
+        self.localized_descriptions
+            .resolve(locale)
+            .or(self.description.as_deref())
+    }
+
+    /// Returns every description translation.
+    #[inline]
+    #[must_use]
+    pub const fn localized_descriptions(&self) -> &LocalizedText {
+        &self.localized_descriptions
+    }
+
+    /// Sets the name of one of the course's chapters for a locale.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::ChapterDoesNotExist` if no chapter has the ID, or
+    /// `CourseError::ChapterError` if the name validation fails.
+    pub fn set_chapter_localized_name(
+        &mut self,
+        chapter_id: Id,
+        locale: Locale,
+        name: String,
+    ) -> Result<(), CourseError> {
+        self.chapters
+            .iter_mut()
+            .find(|chapter| chapter.id() == chapter_id)
+            .ok_or(CourseError::ChapterDoesNotExist)?
+            .set_localized_name(locale, name)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Sets the name of one of the course's lessons for a locale.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::LessonDoesNotExist` if no lesson has the ID, or
+    /// `CourseError::LessonError` if the name validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    /// use education_platform_common::{Entity, Locale};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let fr = Locale::new("fr").unwrap();
+    ///
+    /// course.set_lesson_localized_name(lesson_id, fr.clone(), "Présentation".to_string()).unwrap();
+    ///
+    /// assert_eq!(course.chapters()[0].lessons()[0].localized_name(&fr), "Présentation");
+    /// ```
+    pub fn set_lesson_localized_name(
+        &mut self,
+        lesson_id: Id,
+        locale: Locale,
+        name: String,
+    ) -> Result<(), CourseError> {
+        self.chapters
+            .iter_mut()
+            .flat_map(|chapter| chapter.lessons_mut().iter_mut())
+            .find(|lesson| lesson.id() == lesson_id)
+            .ok_or(CourseError::LessonDoesNotExist)?
+            .set_localized_name(locale, name)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, ChapterError, Lesson};
+
+    fn create_test_lesson(name: &str, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            1800,
+            format!("https://example.com/{index}.mp4"),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let chapter = Chapter::new(
+            "Getting Started".to_string(),
+            0,
+            vec![
+                create_test_lesson("Introduction", 0),
+                create_test_lesson("Setup", 1),
+            ],
+        )
+        .unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_locale(tag: &str) -> Locale {
+        Locale::new(tag).unwrap()
+    }
+
+    mod names {
+        use super::*;
+
+        #[test]
+        fn test_invalid_course_translation_is_rejected() {
+            let mut course = create_test_course();
+
+            let result = course.set_localized_name(create_locale("es"), "Rs".to_string());
+
+            assert!(matches!(result, Err(CourseError::NameError(_))));
+            assert!(course.localized_names().is_empty());
+        }
+
+        #[test]
+        fn test_chapter_translation_by_id() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+
+            course
+                .set_chapter_localized_name(chapter_id, create_locale("es"), "Inicio".to_string())
+                .unwrap();
+
+            assert_eq!(course.chapters()[0].localized_name(&create_locale("es")), "Inicio");
+        }
+
+        #[test]
+        fn test_unknown_items_are_reported() {
+            let mut course = create_test_course();
+
+            assert_eq!(
+                course.set_chapter_localized_name(
+                    Id::new(),
+                    create_locale("es"),
+                    "Inicio".to_string()
+                ),
+                Err(CourseError::ChapterDoesNotExist)
+            );
+            assert_eq!(
+                course.set_lesson_localized_name(
+                    Id::new(),
+                    create_locale("es"),
+                    "Inicio".to_string()
+                ),
+                Err(CourseError::LessonDoesNotExist)
+            );
+        }
+
+        #[test]
+        fn test_invalid_chapter_translation_is_wrapped() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+
+            let result =
+                course.set_chapter_localized_name(chapter_id, create_locale("es"), "I".to_string());
+
+            assert!(matches!(
+                result,
+                Err(CourseError::ChapterError(ChapterError::NameError(_)))
+            ));
+        }
+
+        #[test]
+        fn test_chapter_translations_survive_lesson_update() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            course
+                .set_chapter_localized_name(chapter_id, create_locale("es"), "Inicio".to_string())
+                .unwrap();
+            let mut lesson = course.chapters()[0].lessons()[1].clone();
+            lesson.update_name("Installation".to_string()).unwrap();
+
+            course.update_lesson(lesson).unwrap();
+
+            assert_eq!(course.chapters()[0].localized_name(&create_locale("es")), "Inicio");
+        }
+    }
+
+    mod descriptions {
+        use super::*;
+
+        #[test]
+        fn test_description_follows_fallback_chain() {
+            let mut course = create_test_course();
+            course
+                .set_localized_description(create_locale("pt"), "Aprenda Rust.".to_string())
+                .unwrap();
+
+            assert_eq!(
+                course.localized_description(&create_locale("pt-BR")),
+                Some("Aprenda Rust.")
+            );
+            assert_eq!(course.localized_description(&create_locale("en")), None);
+        }
+
+        #[test]
+        fn test_removing_description() {
+            let mut course = create_test_course();
+            course
+                .set_localized_description(create_locale("en"), "Learn Rust.".to_string())
+                .unwrap();
+
+            assert!(course.remove_localized_description(&create_locale("en")));
+            assert!(course.localized_descriptions().is_empty());
+        }
+
+        #[test]
+        fn test_too_long_translation_is_rejected() {
+            let mut course = create_test_course();
+
+            let result = course.set_localized_description(
+                create_locale("en"),
+                "a".repeat(Course::MAX_DESCRIPTION_LENGTH + 1),
+            );
+
+            assert!(matches!(result, Err(CourseError::DescriptionError(_))));
+            assert!(course.localized_descriptions().is_empty());
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use crate::{ChapterId, Course, CourseError, DifficultyLevel};
+use education_platform_common::{Entity, Url, UrlPolicy, Validator, ValidatorError};
+
+impl Course {
+    pub const MAX_DESCRIPTION_LENGTH: usize = 2000;
+    pub const MAX_TARGET_AUDIENCE_LENGTH: usize = 300;
+    pub const MAX_LEARNING_OBJECTIVE_LENGTH: usize = 200;
+    pub const MAX_LEARNING_OBJECTIVES: usize = 20;
+
+    /// Sets or clears the course description.
+    ///
+    /// The description is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::DescriptionError` if the description is blank or
+    /// longer than [`Course::MAX_DESCRIPTION_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.update_description(Some("  Learn Rust from scratch. ".to_string())).unwrap();
+    /// assert_eq!(course.description(), Some("Learn Rust from scratch."));
+    ///
+    /// course.update_description(None).unwrap();
+    /// assert_eq!(course.description(), None);
+    /// ```
+    pub fn update_description(&mut self, description: Option<String>) -> Result<(), CourseError> {
+        self.description = description
+            .map(|text| Self::validate_text(&text, Self::MAX_DESCRIPTION_LENGTH))
+            .transpose()
+            .map_err(CourseError::DescriptionError)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Sets or clears the description of one of the course's chapters.
+    ///
+    /// Lesson descriptions are edited on a copy of the lesson and applied
+    /// with [`Course::update_lesson`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::ChapterDoesNotExist` if no chapter has the ID, or
+    /// `CourseError::ChapterError` if the description validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let chapter_id = chapter.chapter_id();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course
+    ///     .update_chapter_description(chapter_id, Some("Tooling and first steps.".to_string()))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(course.chapters()[0].description(), Some("Tooling and first steps."));
+    /// ```
+    pub fn update_chapter_description(
+        &mut self,
+        chapter_id: ChapterId,
+        description: Option<String>,
+    ) -> Result<(), CourseError> {
+        self.chapters
+            .iter_mut()
+            .find(|chapter| chapter.id() == chapter_id.untyped())
+            .ok_or(CourseError::ChapterDoesNotExist)?
+            .update_description(description)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Sets or clears the difficulty level.
+    #[inline]
+    pub fn update_difficulty(&mut self, difficulty: Option<DifficultyLevel>) {
+        self.difficulty = difficulty;
+        self.timestamps.touch();
+    }
+
+    /// Sets or clears who the course is meant for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::TargetAudienceError` if the text is blank or
+    /// longer than [`Course::MAX_TARGET_AUDIENCE_LENGTH`] bytes.
+    pub fn update_target_audience(
+        &mut self,
+        target_audience: Option<String>,
+    ) -> Result<(), CourseError> {
+        self.target_audience = target_audience
+            .map(|text| Self::validate_text(&text, Self::MAX_TARGET_AUDIENCE_LENGTH))
+            .transpose()
+            .map_err(CourseError::TargetAudienceError)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Appends a learning objective.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::LearningObjectiveError` if the objective is blank
+    /// or longer than [`Course::MAX_LEARNING_OBJECTIVE_LENGTH`] bytes, or
+    /// `CourseError::TooManyLearningObjectives` if the course already has
+    /// [`Course::MAX_LEARNING_OBJECTIVES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.add_learning_objective("Write safe concurrent code".to_string()).unwrap();
+    /// course.add_learning_objective("Use the borrow checker".to_string()).unwrap();
+    ///
+    /// assert_eq!(course.remove_learning_objective(0).unwrap(), "Write safe concurrent code");
+    /// assert_eq!(course.learning_objectives(), ["Use the borrow checker"]);
+    /// ```
+    pub fn add_learning_objective(&mut self, objective: String) -> Result<(), CourseError> {
+        if self.learning_objectives.len() >= Self::MAX_LEARNING_OBJECTIVES {
+            return Err(CourseError::TooManyLearningObjectives {
+                max: Self::MAX_LEARNING_OBJECTIVES,
+            });
+        }
+        let objective = Self::validate_text(&objective, Self::MAX_LEARNING_OBJECTIVE_LENGTH)
+            .map_err(CourseError::LearningObjectiveError)?;
+        self.learning_objectives.push(objective);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Replaces every learning objective; nothing changes if one is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Course::add_learning_objective`].
+    pub fn update_learning_objectives(
+        &mut self,
+        objectives: Vec<String>,
+    ) -> Result<(), CourseError> {
+        if objectives.len() > Self::MAX_LEARNING_OBJECTIVES {
+            return Err(CourseError::TooManyLearningObjectives {
+                max: Self::MAX_LEARNING_OBJECTIVES,
+            });
+        }
+        self.learning_objectives = objectives
+            .iter()
+            .map(|objective| Self::validate_text(objective, Self::MAX_LEARNING_OBJECTIVE_LENGTH))
+            .collect::<Result<_, _>>()
+            .map_err(CourseError::LearningObjectiveError)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes the learning objective at a position and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::LearningObjectiveDoesNotExist` if the position is
+    /// out of range.
+    pub fn remove_learning_objective(&mut self, position: usize) -> Result<String, CourseError> {
+        if position >= self.learning_objectives.len() {
+            return Err(CourseError::LearningObjectiveDoesNotExist);
+        }
+        let removed = self.learning_objectives.remove(position);
+        self.timestamps.touch();
+        Ok(removed)
+    }
+
+    /// Sets or clears the cover image.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::CoverImageUrlError` if the URL is not a valid
+    /// HTTPS URL.
+    pub fn update_cover_image_url(&mut self, url: Option<String>) -> Result<(), CourseError> {
+        self.cover_image_url = url
+            .map(|url| Url::with_policy(url, &UrlPolicy::default()))
+            .transpose()
+            .map_err(CourseError::CoverImageUrlError)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Returns the course description, if any.
+    #[inline]
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the difficulty level, if set.
+    #[inline]
+    #[must_use]
+    pub const fn difficulty(&self) -> Option<DifficultyLevel> {
+        self.difficulty
+    }
+
+    /// Returns who the course is meant for, if set.
+    #[inline]
+    #[must_use]
+    pub fn target_audience(&self) -> Option<&str> {
+        self.target_audience.as_deref()
+    }
+
+    /// Returns the learning objectives, in the order they were added.
+    #[inline]
+    #[must_use]
+    pub fn learning_objectives(&self) -> &[String] {
+        &self.learning_objectives
+    }
+
+    /// Returns the cover image URL, if set.
+    #[inline]
+    #[must_use]
+    pub const fn cover_image_url(&self) -> Option<&Url> {
+        self.cover_image_url.as_ref()
+    }
+
+    pub(super) fn validate_text(text: &str, max: usize) -> Result<String, ValidatorError> {
+        let trimmed = text.trim();
+        Validator::is_not_empty(trimmed)?;
+        Validator::has_max_length(trimmed, max)?;
+        Ok(trimmed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson};
+
+    fn create_test_course() -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    mod description {
+        use super::*;
+
+        #[test]
+        fn test_new_course_has_no_metadata() {
+            let course = create_test_course();
+
+            assert_eq!(course.description(), None);
+            assert_eq!(course.difficulty(), None);
+            assert_eq!(course.target_audience(), None);
+            assert!(course.learning_objectives().is_empty());
+            assert!(course.cover_image_url().is_none());
+        }
+
+        #[test]
+        fn test_too_long_description_is_rejected() {
+            let mut course = create_test_course();
+            course
+                .update_description(Some("Learn Rust.".to_string()))
+                .unwrap();
+
+            let result =
+                course.update_description(Some("a".repeat(Course::MAX_DESCRIPTION_LENGTH + 1)));
+
+            assert!(matches!(result, Err(CourseError::DescriptionError(_))));
+            assert_eq!(course.description(), Some("Learn Rust."));
+        }
+
+        #[test]
+        fn test_blank_target_audience_is_rejected() {
+            let mut course = create_test_course();
+
+            let result = course.update_target_audience(Some("   ".to_string()));
+
+            assert_eq!(
+                result,
+                Err(CourseError::TargetAudienceError(ValidatorError::EmptyValue))
+            );
+        }
+
+        #[test]
+        fn test_difficulty_and_audience_are_stored() {
+            let mut course = create_test_course();
+
+            course.update_difficulty(Some(DifficultyLevel::Beginner));
+            course
+                .update_target_audience(Some("Developers new to systems programming".to_string()))
+                .unwrap();
+
+            assert_eq!(course.difficulty(), Some(DifficultyLevel::Beginner));
+            assert_eq!(
+                course.target_audience(),
+                Some("Developers new to systems programming")
+            );
+        }
+    }
+
+    mod chapter_description {
+        use super::*;
+        use crate::ChapterError;
+        use education_platform_common::Id;
+
+        #[test]
+        fn test_sets_chapter_description() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].chapter_id();
+
+            course
+                .update_chapter_description(chapter_id, Some("Tooling.".to_string()))
+                .unwrap();
+
+            assert_eq!(course.chapters()[0].description(), Some("Tooling."));
+        }
+
+        #[test]
+        fn test_unknown_chapter() {
+            let mut course = create_test_course();
+
+            let result = course.update_chapter_description(Id::new().cast(), None);
+
+            assert_eq!(result, Err(CourseError::ChapterDoesNotExist));
+        }
+
+        #[test]
+        fn test_invalid_description() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].chapter_id();
+
+            let result = course.update_chapter_description(chapter_id, Some(" ".to_string()));
+
+            assert_eq!(
+                result,
+                Err(CourseError::ChapterError(ChapterError::DescriptionError(
+                    ValidatorError::EmptyValue
+                )))
+            );
+        }
+    }
+
+    mod learning_objectives {
+        use super::*;
+
+        #[test]
+        fn test_objectives_are_capped() {
+            let mut course = create_test_course();
+            for i in 0..Course::MAX_LEARNING_OBJECTIVES {
+                course
+                    .add_learning_objective(format!("Objective {i}"))
+                    .unwrap();
+            }
+
+            let result = course.add_learning_objective("One more".to_string());
+
+            assert_eq!(
+                result,
+                Err(CourseError::TooManyLearningObjectives {
+                    max: Course::MAX_LEARNING_OBJECTIVES
+                })
+            );
+        }
+
+        #[test]
+        fn test_replacing_with_an_invalid_objective_changes_nothing() {
+            let mut course = create_test_course();
+            course
+                .add_learning_objective("Read Rust code".to_string())
+                .unwrap();
+
+            let result = course
+                .update_learning_objectives(vec!["Write Rust code".to_string(), String::new()]);
+
+            assert!(matches!(result, Err(CourseError::LearningObjectiveError(_))));
+            assert_eq!(course.learning_objectives(), ["Read Rust code"]);
+        }
+
+        #[test]
+        fn test_removing_missing_objective_fails() {
+            let mut course = create_test_course();
+
+            assert_eq!(
+                course.remove_learning_objective(0),
+                Err(CourseError::LearningObjectiveDoesNotExist)
+            );
+        }
+    }
+
+    mod cover_image_url {
+        use super::*;
+
+        #[test]
+        fn test_https_cover_is_accepted_and_cleared() {
+            let mut course = create_test_course();
+
+            course
+                .update_cover_image_url(Some("https://cdn.example.com/rust.png".to_string()))
+                .unwrap();
+            assert_eq!(
+                course.cover_image_url().map(Url::as_str),
+                Some("https://cdn.example.com/rust.png")
+            );
+
+            course.update_cover_image_url(None).unwrap();
+            assert!(course.cover_image_url().is_none());
+        }
+
+        #[test]
+        fn test_insecure_cover_is_rejected() {
+            let mut course = create_test_course();
+
+            let result =
+                course.update_cover_image_url(Some("http://cdn.example.com/rust.png".to_string()));
+
+            assert!(matches!(result, Err(CourseError::CoverImageUrlError(_))));
+        }
+    }
+}
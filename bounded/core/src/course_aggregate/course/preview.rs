@@ -0,0 +1,196 @@
+use crate::{Course, CourseError, Lesson, LessonId};
+use education_platform_common::Entity;
+
+impl Course {
+    /// Marks the course as paid, or as free to everyone.
+    ///
+    /// A paid course needs at least one preview lesson before it can be
+    /// published; see [`Course::preview_lessons`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// assert!(!course.is_paid());
+    ///
+    /// course.set_paid(true);
+    ///
+    /// assert!(course.is_paid());
+    /// ```
+    #[inline]
+    pub fn set_paid(&mut self, is_paid: bool) {
+        if self.is_paid != is_paid {
+            self.is_paid = is_paid;
+            self.timestamps.touch();
+        }
+    }
+
+    /// Returns `true` if learners must pay to enroll in the course.
+    #[inline]
+    #[must_use]
+    pub const fn is_paid(&self) -> bool {
+        self.is_paid
+    }
+
+    /// Marks one of the course's lessons as a free preview, or takes the
+    /// mark off.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::LessonDoesNotExist` if no lesson has the ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.lesson_id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.set_lesson_preview(lesson_id, true).unwrap();
+    ///
+    /// assert_eq!(course.preview_lessons()[0].lesson_id(), lesson_id);
+    /// ```
+    pub fn set_lesson_preview(
+        &mut self,
+        lesson_id: LessonId,
+        is_preview: bool,
+    ) -> Result<(), CourseError> {
+        let lesson = self
+            .chapters
+            .iter_mut()
+            .flat_map(|chapter| chapter.lessons_mut().iter_mut())
+            .find(|lesson| lesson.id() == lesson_id.untyped())
+            .ok_or(CourseError::LessonDoesNotExist)?;
+
+        if lesson.is_preview() != is_preview {
+            lesson.set_preview(is_preview);
+            self.timestamps.touch();
+        }
+        Ok(())
+    }
+
+    /// Returns the lessons anyone can watch without enrolling, in course
+    /// order.
+    #[must_use]
+    pub fn preview_lessons(&self) -> Vec<&Lesson> {
+        self.chapters
+            .iter()
+            .flat_map(|chapter| chapter.lessons())
+            .filter(|lesson| lesson.is_preview())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Chapter;
+    use education_platform_common::{Auditable, Id};
+
+    fn create_test_lesson(name: &str, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            1800,
+            format!("https://example.com/{}.mp4", index),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let chapters = vec![
+            Chapter::new(
+                "Chapter One".to_string(),
+                0,
+                vec![create_test_lesson("Lesson One", 0)],
+            )
+            .unwrap(),
+            Chapter::new(
+                "Chapter Two".to_string(),
+                1,
+                vec![
+                    create_test_lesson("Lesson Two", 0),
+                    create_test_lesson("Lesson Three", 1),
+                ],
+            )
+            .unwrap(),
+        ];
+        Course::new("Test Course".to_string(), None, 0, chapters).unwrap()
+    }
+
+    #[test]
+    fn test_new_course_is_free_without_previews() {
+        let course = create_test_course();
+
+        assert!(!course.is_paid());
+        assert!(course.preview_lessons().is_empty());
+    }
+
+    #[test]
+    fn test_preview_lessons_in_course_order() {
+        let mut course = create_test_course();
+        let third = course.chapters()[1].lessons()[1].lesson_id();
+        let first = course.chapters()[0].lessons()[0].lesson_id();
+
+        course.set_lesson_preview(third, true).unwrap();
+        course.set_lesson_preview(first, true).unwrap();
+
+        let previews: Vec<LessonId> = course
+            .preview_lessons()
+            .iter()
+            .map(|lesson| lesson.lesson_id())
+            .collect();
+        assert_eq!(previews, vec![first, third]);
+    }
+
+    #[test]
+    fn test_unmark_preview() {
+        let mut course = create_test_course();
+        let lesson_id = course.chapters()[0].lessons()[0].lesson_id();
+        course.set_lesson_preview(lesson_id, true).unwrap();
+
+        course.set_lesson_preview(lesson_id, false).unwrap();
+
+        assert!(course.preview_lessons().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_lesson() {
+        let mut course = create_test_course();
+
+        let result = course.set_lesson_preview(Id::new().cast(), true);
+
+        assert_eq!(result, Err(CourseError::LessonDoesNotExist));
+    }
+
+    #[test]
+    fn test_unchanged_flags_do_not_touch() {
+        let mut course = create_test_course();
+        let lesson_id = course.chapters()[0].lessons()[0].lesson_id();
+        let before = course.updated_at();
+
+        course.set_paid(false);
+        course.set_lesson_preview(lesson_id, false).unwrap();
+
+        assert_eq!(course.updated_at(), before);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{Chapter, Course, ReleaseSchedule};
+use education_platform_common::{Date, Id};
+
+impl Course {
+    /// Replaces the schedule on which chapters are released to learners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson, ReleaseSchedule};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.update_release_schedule(ReleaseSchedule::DaysAfterEnrollment(vec![0, 7]));
+    ///
+    /// assert_eq!(
+    ///     course.release_schedule(),
+    ///     &ReleaseSchedule::DaysAfterEnrollment(vec![0, 7])
+    /// );
+    /// ```
+    #[inline]
+    pub fn update_release_schedule(&mut self, schedule: ReleaseSchedule) {
+        self.release_schedule = schedule;
+        self.timestamps.touch();
+    }
+
+    /// Returns the schedule on which chapters are released.
+    #[inline]
+    #[must_use]
+    pub const fn release_schedule(&self) -> &ReleaseSchedule {
+        &self.release_schedule
+    }
+
+    /// Returns whether a chapter is unlocked for a learner on a given day.
+    ///
+    /// The chapter's position in the course decides which entry of the
+    /// release schedule applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson, ReleaseSchedule};
+    /// use education_platform_common::Date;
+    ///
+    /// let chapters = ["Getting Started", "Ownership"]
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(index, name)| {
+    ///         let lesson = Lesson::new(
+    ///             format!("{name} Lesson"),
+    ///             1800,
+    ///             format!("https://example.com/{index}.mp4"),
+    ///             0,
+    ///         ).unwrap();
+    ///         Chapter::new(name.to_string(), index, vec![lesson]).unwrap()
+    ///     })
+    ///     .collect();
+    /// let mut course = Course::new("Rust Programming".to_string(), None, 0, chapters).unwrap();
+    /// course.update_release_schedule(ReleaseSchedule::DaysAfterEnrollment(vec![0, 7]));
+    ///
+    /// let enrolled_on = Date::new(2025, 3, 3).unwrap();
+    /// let second = &course.chapters()[1];
+    ///
+    /// assert!(!course.is_available(second, enrolled_on, Date::new(2025, 3, 9).unwrap()));
+    /// assert!(course.is_available(second, enrolled_on, Date::new(2025, 3, 10).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn is_available(&self, chapter: &Chapter, enrolled_on: Date, today: Date) -> bool {
+        self.release_schedule
+            .is_released(chapter.index().value(), enrolled_on, today)
+    }
+
+    /// Returns the day a lesson unlocks for a learner, or `None` if the
+    /// lesson is not part of the course.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson, ReleaseSchedule};
+    /// use education_platform_common::{Date, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let launch = Date::new(2025, 4, 1).unwrap();
+    /// course.update_release_schedule(ReleaseSchedule::FixedDates(vec![launch]));
+    ///
+    /// let enrolled_on = Date::new(2025, 3, 3).unwrap();
+    /// assert_eq!(course.lesson_release_date(lesson_id, enrolled_on), Some(launch));
+    /// ```
+    #[must_use]
+    pub fn lesson_release_date(&self, lesson_id: Id, enrolled_on: Date) -> Option<Date> {
+        self.find_lesson(lesson_id.cast()).map(|(chapter, _)| {
+            self.release_schedule
+                .release_date(chapter.index().value(), enrolled_on)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lesson;
+    use education_platform_common::{Entity, Index};
+
+    fn create_test_course() -> Course {
+        let chapters = (0..3)
+            .map(|index| {
+                let lesson = Lesson::new(
+                    format!("Lesson {index}"),
+                    1800,
+                    format!("https://example.com/{index}.mp4"),
+                    0,
+                )
+                .unwrap();
+                Chapter::new(format!("Chapter {index}"), index, vec![lesson]).unwrap()
+            })
+            .collect();
+        Course::new("Rust Programming".to_string(), None, 0, chapters).unwrap()
+    }
+
+    fn create_date(day: u32) -> Date {
+        Date::new(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_new_course_releases_everything_at_once() {
+        let course = create_test_course();
+
+        assert_eq!(course.release_schedule(), &ReleaseSchedule::AllAtOnce);
+        assert!(course.chapters().iter().all(|chapter| course.is_available(
+            chapter,
+            create_date(3),
+            create_date(3)
+        )));
+    }
+
+    #[test]
+    fn test_moved_chapter_follows_its_new_position() {
+        let mut course = create_test_course();
+        course.update_release_schedule(ReleaseSchedule::DaysAfterEnrollment(vec![0, 7, 14]));
+        let last = course.chapters()[2].clone();
+
+        course.move_chapter(&last, Index::new(0)).unwrap();
+
+        let lesson_id = course.chapters()[0].lessons()[0].id();
+        assert_eq!(
+            course.lesson_release_date(lesson_id, create_date(3)),
+            Some(create_date(3))
+        );
+    }
+
+    #[test]
+    fn test_unknown_lesson_has_no_release_date() {
+        let course = create_test_course();
+
+        assert_eq!(course.lesson_release_date(Id::new(), create_date(3)), None);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{ChapterId, Course, CourseError, LessonId};
+use education_platform_common::{Date, Entity, SimpleName, SimpleNameConfig};
+
+impl Course {
+    /// Renames the course, keeping its ID.
+    ///
+    /// The new name follows the same rules as in [`Course::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::NameError` if the name validation fails; the
+    /// course keeps its previous name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let original_id = course.id();
+    ///
+    /// course.update_name("Rust in Practice".to_string()).unwrap();
+    ///
+    /// assert_eq!(course.name().as_str(), "Rust in Practice");
+    /// assert_eq!(course.id(), original_id);
+    /// assert!(course.update_name("R".to_string()).is_err());
+    /// ```
+    pub fn update_name(&mut self, name: String) -> Result<(), CourseError> {
+        self.name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Replaces the course date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.update_date(Date::new(2025, 9, 1).unwrap());
+    ///
+    /// assert_eq!(course.date().month(), 9);
+    /// ```
+    #[inline]
+    pub fn update_date(&mut self, date: Date) {
+        self.date = date;
+        self.timestamps.touch();
+    }
+
+    /// Renames one of the course's chapters, keeping its ID and lessons.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::ChapterDoesNotExist` if no chapter has the ID, or
+    /// `CourseError::ChapterError` if the name validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let chapter_id = chapter.chapter_id();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.rename_chapter(chapter_id, "First Steps".to_string()).unwrap();
+    ///
+    /// assert_eq!(course.chapters()[0].name().as_str(), "First Steps");
+    /// ```
+    pub fn rename_chapter(
+        &mut self,
+        chapter_id: ChapterId,
+        name: String,
+    ) -> Result<(), CourseError> {
+        self.chapters
+            .iter_mut()
+            .find(|chapter| chapter.id() == chapter_id.untyped())
+            .ok_or(CourseError::ChapterDoesNotExist)?
+            .update_name(name)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Renames one of the course's lessons, keeping its ID and content.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::LessonDoesNotExist` if no lesson has the ID, or
+    /// `CourseError::LessonError` if the name validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.lesson_id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// course.rename_lesson(lesson_id, "Welcome".to_string()).unwrap();
+    ///
+    /// let (_, lesson) = course.find_lesson(lesson_id).unwrap();
+    /// assert_eq!(lesson.name().as_str(), "Welcome");
+    /// ```
+    pub fn rename_lesson(&mut self, lesson_id: LessonId, name: String) -> Result<(), CourseError> {
+        self.chapters
+            .iter_mut()
+            .flat_map(|chapter| chapter.lessons_mut().iter_mut())
+            .find(|lesson| lesson.id() == lesson_id.untyped())
+            .ok_or(CourseError::LessonDoesNotExist)?
+            .update_name(name)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson};
+    use education_platform_common::{Auditable, Id};
+
+    fn create_test_lesson(name: &str, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            1800,
+            format!("https://example.com/{}.mp4", index),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let chapters = vec![
+            Chapter::new(
+                "Chapter One".to_string(),
+                0,
+                vec![create_test_lesson("Lesson One", 0)],
+            )
+            .unwrap(),
+            Chapter::new(
+                "Chapter Two".to_string(),
+                1,
+                vec![
+                    create_test_lesson("Lesson Two", 0),
+                    create_test_lesson("Lesson Three", 1),
+                ],
+            )
+            .unwrap(),
+        ];
+        Course::new("Test Course".to_string(), None, 0, chapters).unwrap()
+    }
+
+    mod update_name {
+        use super::*;
+
+        #[test]
+        fn test_renames_and_keeps_id() {
+            let mut course = create_test_course();
+            let original_id = course.id();
+
+            course.update_name("Renamed Course".to_string()).unwrap();
+
+            assert_eq!(course.name().as_str(), "Renamed Course");
+            assert_eq!(course.id(), original_id);
+        }
+
+        #[test]
+        fn test_invalid_name_keeps_previous() {
+            let mut course = create_test_course();
+
+            let result = course.update_name("x".repeat(51));
+
+            assert!(matches!(result, Err(CourseError::NameError(_))));
+            assert_eq!(course.name().as_str(), "Test Course");
+        }
+
+        #[test]
+        fn test_records_modification() {
+            let mut course = create_test_course();
+            let before = course.updated_at();
+
+            course.update_name("Renamed Course".to_string()).unwrap();
+
+            assert!(course.updated_at() >= before);
+            assert!(course.created_at() <= course.updated_at());
+        }
+    }
+
+    mod update_date {
+        use super::*;
+
+        #[test]
+        fn test_replaces_date() {
+            let mut course = create_test_course();
+            let date = Date::new(2024, 2, 29).unwrap();
+
+            course.update_date(date);
+
+            assert_eq!(course.date(), &date);
+        }
+    }
+
+    mod rename_chapter {
+        use super::*;
+
+        #[test]
+        fn test_renames_only_that_chapter() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[1].chapter_id();
+
+            course
+                .rename_chapter(chapter_id, "Deep Dive".to_string())
+                .unwrap();
+
+            assert_eq!(course.chapters()[0].name().as_str(), "Chapter One");
+            assert_eq!(course.chapters()[1].name().as_str(), "Deep Dive");
+            assert_eq!(course.chapters()[1].chapter_id(), chapter_id);
+            assert_eq!(course.chapters()[1].lessons().len(), 2);
+        }
+
+        #[test]
+        fn test_unknown_chapter() {
+            let mut course = create_test_course();
+
+            let result = course.rename_chapter(Id::new().cast(), "Deep Dive".to_string());
+
+            assert_eq!(result, Err(CourseError::ChapterDoesNotExist));
+        }
+
+        #[test]
+        fn test_invalid_name() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].chapter_id();
+
+            let result = course.rename_chapter(chapter_id, "C".to_string());
+
+            assert!(matches!(result, Err(CourseError::ChapterError(_))));
+            assert_eq!(course.chapters()[0].name().as_str(), "Chapter One");
+        }
+    }
+
+    mod rename_lesson {
+        use super::*;
+
+        #[test]
+        fn test_renames_lesson_in_any_chapter() {
+            let mut course = create_test_course();
+            let lesson_id = course.chapters()[1].lessons()[1].lesson_id();
+
+            course
+                .rename_lesson(lesson_id, "Wrap Up".to_string())
+                .unwrap();
+
+            let (chapter, lesson) = course.find_lesson(lesson_id).unwrap();
+            assert_eq!(chapter.name().as_str(), "Chapter Two");
+            assert_eq!(lesson.name().as_str(), "Wrap Up");
+        }
+
+        #[test]
+        fn test_keeps_totals() {
+            let mut course = create_test_course();
+            let lesson_id = course.chapters()[0].lessons()[0].lesson_id();
+
+            course
+                .rename_lesson(lesson_id, "Welcome".to_string())
+                .unwrap();
+
+            assert_eq!(course.number_of_lessons(), 3);
+            assert_eq!(course.duration().total_seconds(), 5400);
+        }
+
+        #[test]
+        fn test_unknown_lesson() {
+            let mut course = create_test_course();
+
+            let result = course.rename_lesson(Id::new().cast(), "Welcome".to_string());
+
+            assert_eq!(result, Err(CourseError::LessonDoesNotExist));
+        }
+
+        #[test]
+        fn test_invalid_name() {
+            let mut course = create_test_course();
+            let lesson_id = course.chapters()[0].lessons()[0].lesson_id();
+
+            let result = course.rename_lesson(lesson_id, "L".to_string());
+
+            assert!(matches!(result, Err(CourseError::LessonError(_))));
+        }
+    }
+}
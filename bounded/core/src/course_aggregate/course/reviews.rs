@@ -0,0 +1,376 @@
+use crate::{Course, CourseError, Rating, Review};
+use education_platform_common::{Entity, Id, Score};
+
+impl Course {
+    /// Adds a learner's review to the course.
+    ///
+    /// Each learner can review a course only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::AlreadyReviewed` if the author already reviewed the course.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, Chapter, Lesson, Review};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     1800,
+    ///     "https://example.com/1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("My Course".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let review = Review::new("a@example.com".to_string(), 5, "Great".to_string()).unwrap();
+    /// course.add_review(review.clone()).unwrap();
+    ///
+    /// assert_eq!(course.reviews().len(), 1);
+    /// assert!(course.add_review(review).is_err());
+    /// ```
+    pub fn add_review(&mut self, review: Review) -> Result<(), CourseError> {
+        if self
+            .reviews
+            .iter()
+            .any(|existing| existing.author_email() == review.author_email())
+        {
+            return Err(CourseError::AlreadyReviewed(
+                review.author_email().address().to_string(),
+            ));
+        }
+
+        self.reviews.push(review);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Approves a review so it shows up and counts towards the rating.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::ReviewDoesNotExist` if no review has the given ID.
+    /// Returns `CourseError::ReviewError` if the review is already approved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, Chapter, Lesson, Review};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     1800,
+    ///     "https://example.com/1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("My Course".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let review = Review::new("a@example.com".to_string(), 4, "Good".to_string()).unwrap();
+    /// let review_id = review.id();
+    /// course.add_review(review).unwrap();
+    ///
+    /// course.approve_review(review_id).unwrap();
+    /// assert_eq!(course.average_rating().map(|rating| rating.points()), Some(4.0));
+    /// ```
+    pub fn approve_review(&mut self, review_id: Id) -> Result<(), CourseError> {
+        self.find_review_mut(review_id)?.approve()?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Rejects a review so it is hidden and no longer counts towards the rating.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseError::ReviewDoesNotExist` if no review has the given ID.
+    /// Returns `CourseError::ReviewError` if the review is already rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, Chapter, Lesson, ModerationStatus, Review};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     1800,
+    ///     "https://example.com/1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("My Course".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let review = Review::new("a@example.com".to_string(), 1, "Spam".to_string()).unwrap();
+    /// let review_id = review.id();
+    /// course.add_review(review).unwrap();
+    ///
+    /// course.reject_review(review_id).unwrap();
+    /// assert_eq!(course.reviews()[0].status(), ModerationStatus::Rejected);
+    /// ```
+    pub fn reject_review(&mut self, review_id: Id) -> Result<(), CourseError> {
+        self.find_review_mut(review_id)?.reject()?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Returns every review of the course, whatever its moderation status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, Chapter, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     1800,
+    ///     "https://example.com/1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("My Course".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// assert!(course.reviews().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reviews(&self) -> &[Review] {
+        &self.reviews
+    }
+
+    /// Returns the reviews that passed moderation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, Chapter, Lesson, Review};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     1800,
+    ///     "https://example.com/1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("My Course".to_string(), None, 0, vec![chapter]).unwrap();
+    /// course
+    ///     .add_review(Review::new("a@example.com".to_string(), 5, "Great".to_string()).unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(course.approved_reviews().count(), 0);
+    /// ```
+    pub fn approved_reviews(&self) -> impl Iterator<Item = &Review> {
+        self.reviews.iter().filter(|review| review.is_approved())
+    }
+
+    /// Returns the mean star rating of the approved reviews.
+    ///
+    /// Returns `None` while no review has been approved, so an unrated
+    /// course is not mistaken for a badly rated one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, Chapter, Lesson, Review};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Intro".to_string(),
+    ///     1800,
+    ///     "https://example.com/1.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("My Course".to_string(), None, 0, vec![chapter]).unwrap();
+    /// assert_eq!(course.average_rating(), None);
+    ///
+    /// for (email, stars) in [("a@example.com", 5), ("b@example.com", 4)] {
+    ///     let review = Review::new(email.to_string(), stars, "Review".to_string()).unwrap();
+    ///     let review_id = review.id();
+    ///     course.add_review(review).unwrap();
+    ///     course.approve_review(review_id).unwrap();
+    /// }
+    ///
+    /// assert_eq!(course.average_rating().unwrap().to_string(), "4.5/5");
+    /// ```
+    #[must_use]
+    pub fn average_rating(&self) -> Option<Score> {
+        let (total, count) = self
+            .approved_reviews()
+            .fold((0u32, 0u32), |(total, count), review| {
+                (total + u32::from(review.rating().stars()), count + 1)
+            });
+
+        match count {
+            0 => None,
+            _ => Score::new(f64::from(total) / f64::from(count), f64::from(Rating::MAX)).ok(),
+        }
+    }
+
+    fn find_review_mut(&mut self, review_id: Id) -> Result<&mut Review, CourseError> {
+        self.reviews
+            .iter_mut()
+            .find(|review| review.id() == review_id)
+            .ok_or(CourseError::ReviewDoesNotExist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson, ModerationStatus, ReviewError};
+
+    fn create_test_course() -> Course {
+        let lesson =
+            Lesson::new("Intro".to_string(), 1800, "https://example.com/1.mp4".to_string(), 0)
+                .unwrap();
+        let chapter = Chapter::new("Chapter 1".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Test Course".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_review(email: &str, stars: u8) -> Review {
+        Review::new(email.to_string(), stars, "Review comment".to_string()).unwrap()
+    }
+
+    fn add_approved(course: &mut Course, email: &str, stars: u8) -> Id {
+        let review = create_review(email, stars);
+        let review_id = review.id();
+        course.add_review(review).unwrap();
+        course.approve_review(review_id).unwrap();
+        review_id
+    }
+
+    mod add_review {
+        use super::*;
+
+        #[test]
+        fn test_add_review_keeps_it_pending() {
+            let mut course = create_test_course();
+
+            course
+                .add_review(create_review("a@example.com", 5))
+                .unwrap();
+
+            assert_eq!(course.reviews()[0].status(), ModerationStatus::Pending);
+        }
+
+        #[test]
+        fn test_add_review_rejects_second_review_from_same_author() {
+            let mut course = create_test_course();
+            course
+                .add_review(create_review("a@example.com", 5))
+                .unwrap();
+
+            let result = course.add_review(create_review("a@example.com", 1));
+
+            assert_eq!(
+                result.unwrap_err(),
+                CourseError::AlreadyReviewed("a@example.com".to_string())
+            );
+            assert_eq!(course.reviews().len(), 1);
+        }
+
+        #[test]
+        fn test_add_review_accepts_different_authors() {
+            let mut course = create_test_course();
+
+            course
+                .add_review(create_review("a@example.com", 5))
+                .unwrap();
+            course
+                .add_review(create_review("b@example.com", 3))
+                .unwrap();
+
+            assert_eq!(course.reviews().len(), 2);
+        }
+    }
+
+    mod moderation {
+        use super::*;
+
+        #[test]
+        fn test_approve_unknown_review_returns_error() {
+            let mut course = create_test_course();
+
+            assert_eq!(
+                course.approve_review(Id::new()).unwrap_err(),
+                CourseError::ReviewDoesNotExist
+            );
+        }
+
+        #[test]
+        fn test_reject_unknown_review_returns_error() {
+            let mut course = create_test_course();
+
+            assert_eq!(
+                course.reject_review(Id::new()).unwrap_err(),
+                CourseError::ReviewDoesNotExist
+            );
+        }
+
+        #[test]
+        fn test_approve_twice_surfaces_review_error() {
+            let mut course = create_test_course();
+            let review_id = add_approved(&mut course, "a@example.com", 5);
+
+            assert_eq!(
+                course.approve_review(review_id).unwrap_err(),
+                CourseError::ReviewError(ReviewError::AlreadyModerated(ModerationStatus::Approved))
+            );
+        }
+
+        #[test]
+        fn test_reject_hides_approved_review() {
+            let mut course = create_test_course();
+            let review_id = add_approved(&mut course, "a@example.com", 5);
+
+            course.reject_review(review_id).unwrap();
+
+            assert_eq!(course.approved_reviews().count(), 0);
+        }
+    }
+
+    mod average_rating {
+        use super::*;
+
+        #[test]
+        fn test_average_rating_is_none_without_reviews() {
+            assert_eq!(create_test_course().average_rating(), None);
+        }
+
+        #[test]
+        fn test_average_rating_ignores_pending_reviews() {
+            let mut course = create_test_course();
+            course
+                .add_review(create_review("a@example.com", 1))
+                .unwrap();
+
+            assert_eq!(course.average_rating(), None);
+        }
+
+        #[test]
+        fn test_average_rating_of_approved_reviews() {
+            let mut course = create_test_course();
+            add_approved(&mut course, "a@example.com", 5);
+            add_approved(&mut course, "b@example.com", 4);
+            add_approved(&mut course, "c@example.com", 2);
+
+            let average = course.average_rating().unwrap();
+
+            assert!((average.points() - 11.0 / 3.0).abs() < f64::EPSILON);
+            assert_eq!(average.max_points(), 5.0);
+        }
+
+        #[test]
+        fn test_average_rating_excludes_rejected_reviews() {
+            let mut course = create_test_course();
+            add_approved(&mut course, "a@example.com", 5);
+            let rejected = add_approved(&mut course, "b@example.com", 1);
+            course.reject_review(rejected).unwrap();
+
+            assert_eq!(course.average_rating(), Score::new(5.0, 5.0).ok());
+        }
+    }
+}
@@ -79,6 +79,12 @@ impl Course {
                     .collect();
 
                 Chapter::new(chapter.name().to_string(), chapter.index().value(), updated_lessons)
+                    .map(|rebuilt| {
+                        rebuilt
+                            .with_localized_names(chapter.localized_names().clone())
+                            .with_description_of(chapter)
+                            .with_timestamps_of(chapter)
+                    })
                     .map_err(CourseError::from)
             })
             .collect();
@@ -88,6 +94,7 @@ impl Course {
             Self::calculate_totals(&self.chapters, Duration::default());
         self.duration = duration;
         self.number_of_lessons = number_of_lessons;
+        self.timestamps.touch();
 
         Ok(())
     }
@@ -235,6 +242,29 @@ mod tests {
             assert_eq!(course.chapters()[0].lessons()[2].name().as_str(), "Lesson 3");
         }
 
+        #[test]
+        fn test_update_lesson_preserves_descriptions() {
+            let lesson = create_test_lesson("Lesson", 1800, 0);
+            let mut chapter = create_test_chapter("Chapter One", 0, vec![lesson]);
+            chapter
+                .update_description(Some("Chapter summary".to_string()))
+                .unwrap();
+            let mut course =
+                Course::new("Test Course".to_string(), None, 0, vec![chapter]).unwrap();
+
+            let mut updated_lesson = course.chapters()[0].lessons()[0].clone();
+            updated_lesson
+                .update_description(Some("Lesson summary".to_string()))
+                .unwrap();
+            course.update_lesson(updated_lesson).unwrap();
+
+            assert_eq!(course.chapters()[0].description(), Some("Chapter summary"));
+            assert_eq!(
+                course.chapters()[0].lessons()[0].description(),
+                Some("Lesson summary")
+            );
+        }
+
         #[test]
         fn test_update_lesson_preserves_lesson_id() {
             let lesson = create_test_lesson("Lesson", 1800, 0);
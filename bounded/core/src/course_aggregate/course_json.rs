@@ -0,0 +1,1083 @@
+use crate::{
+    AiModel, Chapter, ChapterError, ContentProvenance, Course, CourseError, DifficultyLevelError,
+    Lesson, LessonError, ProvenanceError, ReleaseSchedule,
+};
+use education_platform_common::{
+    CustomFieldValue, CustomFields, Date, DateError, Duration, Entity, Id, IdError, Locale,
+    LocaleError, LocalizedText, Url, UrlPolicy,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashSet};
+use thiserror::Error;
+
+/// Current version of the course JSON schema written by [`Course::to_json`].
+pub const COURSE_JSON_SCHEMA_VERSION: u32 = 2;
+
+/// Upgrades a raw document by one schema version.
+type Migration = fn(Value) -> Result<Value, CourseJsonError>;
+
+/// Migration hooks, applied in order when reading older documents.
+///
+/// `MIGRATIONS[n]` upgrades a document from version `n + 1` to `n + 2`.
+/// When the schema changes, bump [`COURSE_JSON_SCHEMA_VERSION`] and append
+/// the function that rewrites the previous shape into the new one.
+const MIGRATIONS: [Migration; (COURSE_JSON_SCHEMA_VERSION - 1) as usize] = [add_course_metadata];
+
+/// Error types for course JSON import and export failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CourseJsonError {
+    #[error("Course JSON is malformed: {0}")]
+    Malformed(String),
+
+    #[error("Course JSON has no schema_version field")]
+    MissingSchemaVersion,
+
+    #[error("Course JSON schema version {found} is not supported (latest is {latest})")]
+    UnsupportedSchemaVersion { found: u64, latest: u32 },
+
+    #[error("ID is not valid: {0}")]
+    IdNotValid(#[from] IdError),
+
+    #[error("ID {0} is used more than once")]
+    DuplicateId(String),
+
+    #[error("Locale is not valid: {0}")]
+    LocaleNotValid(#[from] LocaleError),
+
+    #[error("Difficulty is not valid: {0}")]
+    DifficultyNotValid(#[from] DifficultyLevelError),
+
+    #[error("Course date is not valid: {0}")]
+    DateError(#[from] DateError),
+
+    #[error("Course is not valid: {0}")]
+    CourseError(#[from] CourseError),
+
+    #[error("Chapter is not valid: {0}")]
+    ChapterError(#[from] ChapterError),
+
+    #[error("Lesson is not valid: {0}")]
+    LessonError(#[from] LessonError),
+
+    #[error("Lesson provenance is not valid: {0}")]
+    ProvenanceError(#[from] ProvenanceError),
+}
+
+impl From<serde_json::Error> for CourseJsonError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Malformed(error.to_string())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CourseDocument {
+    schema_version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    name: String,
+    date: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    difficulty: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    target_audience: Option<String>,
+    learning_objectives: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cover_image_url: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    paid: bool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    localized_names: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    localized_descriptions: BTreeMap<String, String>,
+    release_schedule: ReleaseScheduleDocument,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    custom_fields: BTreeMap<String, CustomFieldDocument>,
+    chapters: Vec<ChapterDocument>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ChapterDocument {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    name: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    localized_names: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    lessons: Vec<LessonDocument>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LessonDocument {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    name: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    localized_names: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    duration_seconds: u64,
+    video_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    provenance: Option<ProvenanceDocument>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    preview: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ProvenanceDocument {
+    HumanAuthored,
+    AiAssisted { model: String },
+    AiGenerated { model: String },
+}
+
+impl ProvenanceDocument {
+    fn from_provenance(provenance: &ContentProvenance) -> Option<Self> {
+        match provenance {
+            ContentProvenance::HumanAuthored => None,
+            ContentProvenance::AiAssisted(model) => Some(Self::AiAssisted {
+                model: model.name().to_string(),
+            }),
+            ContentProvenance::AiGenerated(model) => Some(Self::AiGenerated {
+                model: model.name().to_string(),
+            }),
+        }
+    }
+
+    fn into_provenance(self) -> Result<ContentProvenance, ProvenanceError> {
+        Ok(match self {
+            Self::HumanAuthored => ContentProvenance::HumanAuthored,
+            Self::AiAssisted { model } => ContentProvenance::AiAssisted(AiModel::new(&model)?),
+            Self::AiGenerated { model } => ContentProvenance::AiGenerated(AiModel::new(&model)?),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ReleaseScheduleDocument {
+    AllAtOnce,
+    DaysAfterEnrollment { days: Vec<u32> },
+    FixedDates { dates: Vec<String> },
+}
+
+impl ReleaseScheduleDocument {
+    fn from_schedule(schedule: &ReleaseSchedule) -> Self {
+        match schedule {
+            ReleaseSchedule::AllAtOnce => Self::AllAtOnce,
+            ReleaseSchedule::DaysAfterEnrollment(days) => {
+                Self::DaysAfterEnrollment { days: days.clone() }
+            }
+            ReleaseSchedule::FixedDates(dates) => Self::FixedDates {
+                dates: dates.iter().map(Date::format_iso).collect(),
+            },
+        }
+    }
+
+    fn into_schedule(self) -> Result<ReleaseSchedule, DateError> {
+        Ok(match self {
+            Self::AllAtOnce => ReleaseSchedule::AllAtOnce,
+            Self::DaysAfterEnrollment { days } => ReleaseSchedule::DaysAfterEnrollment(days),
+            Self::FixedDates { dates } => ReleaseSchedule::FixedDates(
+                dates
+                    .iter()
+                    .map(|date| Date::from_iso(date))
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+enum CustomFieldDocument {
+    Text(String),
+    Number(i64),
+    Date(String),
+    Choice(String),
+}
+
+impl CustomFieldDocument {
+    fn from_value(value: &CustomFieldValue) -> Self {
+        match value {
+            CustomFieldValue::Text(text) => Self::Text(text.clone()),
+            CustomFieldValue::Number(number) => Self::Number(*number),
+            CustomFieldValue::Date(date) => Self::Date(date.format_iso()),
+            CustomFieldValue::Choice(choice) => Self::Choice(choice.clone()),
+        }
+    }
+
+    fn into_value(self) -> Result<CustomFieldValue, DateError> {
+        Ok(match self {
+            Self::Text(text) => CustomFieldValue::Text(text),
+            Self::Number(number) => CustomFieldValue::Number(number),
+            Self::Date(date) => CustomFieldValue::Date(Date::from_iso(&date)?),
+            Self::Choice(choice) => CustomFieldValue::Choice(choice),
+        })
+    }
+}
+
+impl Course {
+    /// Serializes the course structure to pretty-printed JSON.
+    ///
+    /// The document has this shape, with chapters and lessons in course
+    /// order:
+    ///
+    /// ```json
+    /// {
+    ///   "schema_version": 2,
+    ///   "id": "01HV4Z6Q8Y3KJ7N2M5R9T0WXCD",
+    ///   "name": "Rust Programming",
+    ///   "date": "2024-03-05",
+    ///   "learning_objectives": ["Write safe concurrent code"],
+    ///   "release_schedule": { "kind": "days_after_enrollment", "days": [0, 7] },
+    ///   "chapters": [
+    ///     {
+    ///       "id": "01HV4Z6Q8Y9B1C2D3E4F5G6H7J",
+    ///       "name": "Getting Started",
+    ///       "lessons": [
+    ///         {
+    ///           "id": "01HV4Z6Q8YA8B7C6D5E4F3G2H1",
+    ///           "name": "Introduction",
+    ///           "duration_seconds": 1800,
+    ///           "video_url": "https://example.com/intro.mp4"
+    ///         }
+    ///       ]
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// Lessons involving AI also carry a `provenance` object, such as
+    /// `{"kind": "ai_generated", "model": "Mistral Large"}`; it is omitted for
+    /// human-authored lessons. Chapters and lessons with a description carry
+    /// it in a `description` string, and free preview lessons are marked with
+    /// `"preview": true`.
+    ///
+    /// The course's `description`, `difficulty`, `target_audience` and
+    /// `cover_image_url` are written when set, and paid courses carry
+    /// `"paid": true`. Translations go in `localized_names` (on the course,
+    /// chapters and lessons) and `localized_descriptions`, keyed by locale
+    /// tag. Custom fields go in `custom_fields`, keyed by field, such as
+    /// `{"credits": {"kind": "number", "value": 6}}`.
+    ///
+    /// IDs are exported so that re-importing an edited file keeps the course,
+    /// chapter and lesson IDs, and with them the learners' progress. Reviews
+    /// are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseJsonError::Malformed` if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let json = course.to_json().unwrap();
+    /// assert!(json.contains("\"schema_version\": 2"));
+    /// ```
+    pub fn to_json(&self) -> Result<String, CourseJsonError> {
+        let chapters: Vec<ChapterDocument> = self
+            .chapters()
+            .iter()
+            .map(|chapter| ChapterDocument {
+                id: Some(chapter.id().to_string()),
+                name: chapter.name().as_str().to_string(),
+                localized_names: localized_document(chapter.localized_names()),
+                description: chapter.description().map(str::to_string),
+                lessons: chapter
+                    .lessons()
+                    .iter()
+                    .map(|lesson| LessonDocument {
+                        id: Some(lesson.id().to_string()),
+                        name: lesson.name().as_str().to_string(),
+                        localized_names: localized_document(lesson.localized_names()),
+                        description: lesson.description().map(str::to_string),
+                        duration_seconds: lesson.duration().total_seconds(),
+                        video_url: lesson.video_url().as_str().to_string(),
+                        provenance: ProvenanceDocument::from_provenance(lesson.provenance()),
+                        preview: lesson.is_preview(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let document = CourseDocument {
+            schema_version: COURSE_JSON_SCHEMA_VERSION,
+            id: Some(self.id().to_string()),
+            name: self.name().as_str().to_string(),
+            date: self.date().format_iso(),
+            description: self.description().map(str::to_string),
+            difficulty: self.difficulty().map(|difficulty| difficulty.to_string()),
+            target_audience: self.target_audience().map(str::to_string),
+            learning_objectives: self.learning_objectives().to_vec(),
+            cover_image_url: self.cover_image_url().map(|url| url.as_str().to_string()),
+            paid: self.is_paid(),
+            localized_names: localized_document(self.localized_names()),
+            localized_descriptions: localized_document(self.localized_descriptions()),
+            release_schedule: ReleaseScheduleDocument::from_schedule(self.release_schedule()),
+            custom_fields: self
+                .custom_fields()
+                .iter()
+                .map(|(key, value)| (key.to_string(), CustomFieldDocument::from_value(value)))
+                .collect(),
+            chapters,
+        };
+
+        Ok(serde_json::to_string_pretty(&document)?)
+    }
+
+    /// Creates a new course from JSON written by [`Course::to_json`] or by hand.
+    ///
+    /// Documents from older schema versions are migrated to the current one
+    /// before being read. The course, chapters and lessons keep the IDs in
+    /// the document; entries without an `id` get a fresh one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseJsonError::Malformed` if the input is not valid JSON or
+    /// does not match the schema.
+    /// Returns `CourseJsonError::MissingSchemaVersion` if `schema_version` is absent.
+    /// Returns `CourseJsonError::UnsupportedSchemaVersion` if the version is zero
+    /// or newer than [`COURSE_JSON_SCHEMA_VERSION`].
+    /// Returns `CourseJsonError::IdNotValid` if an `id` cannot be parsed, or
+    /// `CourseJsonError::DuplicateId` if two entries share one.
+    /// Returns `CourseJsonError::LocaleNotValid` if a translation's locale tag
+    /// is not valid, or `CourseJsonError::DifficultyNotValid` if the
+    /// difficulty is unknown.
+    /// Returns `CourseJsonError::DateError`, `CourseError`, `ChapterError`,
+    /// `LessonError` or `ProvenanceError` if the content fails domain validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Course;
+    ///
+    /// let json = r#"{
+    ///     "schema_version": 2,
+    ///     "name": "Rust Programming",
+    ///     "date": "2024-03-05",
+    ///     "learning_objectives": [],
+    ///     "release_schedule": { "kind": "all_at_once" },
+    ///     "chapters": [{
+    ///         "name": "Getting Started",
+    ///         "lessons": [{
+    ///             "name": "Introduction",
+    ///             "duration_seconds": 1800,
+    ///             "video_url": "https://example.com/intro.mp4"
+    ///         }]
+    ///     }]
+    /// }"#;
+    ///
+    /// let course = Course::from_json(json).unwrap();
+    /// assert_eq!(course.number_of_lessons(), 1);
+    ///
+    /// assert!(Course::from_json(r#"{"name": "No version"}"#).is_err());
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, CourseJsonError> {
+        let value: Value = serde_json::from_str(json)?;
+        let document: CourseDocument = serde_json::from_value(migrate(value)?)?;
+        let mut ids = HashSet::new();
+
+        let course_id = restore_id(document.id, &mut ids)?;
+        let chapters = document
+            .chapters
+            .into_iter()
+            .enumerate()
+            .map(|(index, chapter)| chapter.into_chapter(index, &mut ids))
+            .collect::<Result<Vec<_>, CourseJsonError>>()?;
+
+        let custom_fields = document
+            .custom_fields
+            .into_iter()
+            .map(|(key, value)| Ok((key, value.into_value()?)))
+            .collect::<Result<Vec<_>, CourseJsonError>>()?;
+
+        let mut course = Self::with_id(
+            course_id,
+            document.name,
+            Some(Date::from_iso(&document.date)?),
+            0,
+            chapters,
+        )?
+        .with_custom_fields(CustomFields::restore(custom_fields));
+        if document.description.is_some() {
+            course.update_description(document.description)?;
+        }
+        course.update_difficulty(document.difficulty.as_deref().map(str::parse).transpose()?);
+        course.update_target_audience(document.target_audience)?;
+        course.update_learning_objectives(document.learning_objectives)?;
+        course.update_cover_image_url(document.cover_image_url)?;
+        course.set_paid(document.paid);
+        course.update_release_schedule(document.release_schedule.into_schedule()?);
+        for (locale, name) in document.localized_names {
+            course.set_localized_name(Locale::new(&locale)?, name)?;
+        }
+        for (locale, description) in document.localized_descriptions {
+            course.set_localized_description(Locale::new(&locale)?, description)?;
+        }
+        Ok(course)
+    }
+}
+
+impl ChapterDocument {
+    fn into_chapter(self, index: usize, ids: &mut HashSet<Id>) -> Result<Chapter, CourseJsonError> {
+        let id = restore_id(self.id, ids)?;
+        let lessons = self
+            .lessons
+            .into_iter()
+            .enumerate()
+            .map(|(index, lesson)| lesson.into_lesson(index, ids))
+            .collect::<Result<Vec<_>, CourseJsonError>>()?;
+
+        let mut chapter = Chapter::with_id(id, self.name, index, lessons)?;
+        for (locale, name) in self.localized_names {
+            chapter.set_localized_name(Locale::new(&locale)?, name)?;
+        }
+        if self.description.is_some() {
+            chapter.update_description(self.description)?;
+        }
+        Ok(chapter)
+    }
+}
+
+impl LessonDocument {
+    fn into_lesson(self, index: usize, ids: &mut HashSet<Id>) -> Result<Lesson, CourseJsonError> {
+        let id = restore_id(self.id, ids)?;
+        let video_url =
+            Url::with_policy(self.video_url, &UrlPolicy::default()).map_err(LessonError::from)?;
+
+        let mut lesson = Lesson::with_video_url(
+            id,
+            self.name,
+            Duration::from_seconds(self.duration_seconds),
+            video_url,
+            index,
+        )?;
+        if let Some(provenance) = self.provenance {
+            lesson.update_provenance(provenance.into_provenance()?);
+        }
+        if self.description.is_some() {
+            lesson.update_description(self.description)?;
+        }
+        lesson.set_preview(self.preview);
+        for (locale, name) in self.localized_names {
+            lesson.set_localized_name(Locale::new(&locale)?, name)?;
+        }
+        Ok(lesson)
+    }
+}
+
+/// Parses the `id` of a document entry, or makes a fresh one when it has
+/// none. Every ID in a course must be unique, or lookups by ID would pick
+/// the wrong chapter or lesson.
+fn restore_id(id: Option<String>, seen: &mut HashSet<Id>) -> Result<Id, CourseJsonError> {
+    let id = match id {
+        Some(id) => id.trim().parse()?,
+        None => Id::new(),
+    };
+    if !seen.insert(id) {
+        return Err(CourseJsonError::DuplicateId(id.to_string()));
+    }
+    Ok(id)
+}
+
+/// Collects the translations of a text by locale tag, so the document lists
+/// them in a stable order.
+fn localized_document(text: &LocalizedText) -> BTreeMap<String, String> {
+    text.locales()
+        .filter_map(|locale| {
+            text.get(locale)
+                .map(|value| (locale.as_str().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Version 2 added the course metadata, translations, release schedule and
+/// custom fields. Only the objectives and the schedule are required, so a
+/// version 1 course gets none of either.
+fn add_course_metadata(mut value: Value) -> Result<Value, CourseJsonError> {
+    let document = value
+        .as_object_mut()
+        .ok_or_else(|| CourseJsonError::Malformed("expected a JSON object".to_string()))?;
+    document.insert("schema_version".to_string(), json!(2));
+    document.insert("learning_objectives".to_string(), json!([]));
+    document.insert("release_schedule".to_string(), json!({"kind": "all_at_once"}));
+    Ok(value)
+}
+
+fn migrate(mut value: Value) -> Result<Value, CourseJsonError> {
+    let found = value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .ok_or(CourseJsonError::MissingSchemaVersion)?;
+
+    let unsupported = CourseJsonError::UnsupportedSchemaVersion {
+        found,
+        latest: COURSE_JSON_SCHEMA_VERSION,
+    };
+    let pending = usize::try_from(found)
+        .ok()
+        .and_then(|version| version.checked_sub(1))
+        .and_then(|start| MIGRATIONS.get(start..))
+        .ok_or(unsupported)?;
+
+    for migration in pending {
+        value = migration(value)?;
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CourseProgress, DifficultyLevel};
+    use education_platform_common::Duration;
+
+    fn create_test_lesson(name: &str, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            600 + index as u64,
+            format!("https://example.com/{index}.mp4"),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let first = Chapter::new(
+            "Basics".to_string(),
+            0,
+            vec![
+                create_test_lesson("Intro", 0),
+                create_test_lesson("Setup", 1),
+            ],
+        )
+        .unwrap();
+        let second =
+            Chapter::new("Ownership".to_string(), 1, vec![create_test_lesson("Borrowing", 0)])
+                .unwrap();
+
+        Course::new(
+            "Rust Programming".to_string(),
+            Some(Date::new(2024, 3, 5).unwrap()),
+            0,
+            vec![first, second],
+        )
+        .unwrap()
+    }
+
+    fn ids(course: &Course) -> Vec<Id> {
+        let mut ids = vec![course.id()];
+        for chapter in course.chapters() {
+            ids.push(chapter.id());
+            ids.extend(chapter.lessons().iter().map(Entity::id));
+        }
+        ids
+    }
+
+    fn round_trip(course: &Course) -> Course {
+        Course::from_json(&course.to_json().unwrap()).unwrap()
+    }
+
+    fn lesson_names(course: &Course) -> Vec<String> {
+        course
+            .chapters()
+            .iter()
+            .flat_map(|chapter| chapter.lessons())
+            .map(|lesson| lesson.name().as_str().to_string())
+            .collect()
+    }
+
+    mod round_trip {
+        use super::*;
+
+        #[test]
+        fn test_round_trip_preserves_structure() {
+            let course = create_test_course();
+
+            let restored = Course::from_json(&course.to_json().unwrap()).unwrap();
+
+            assert_eq!(restored.name(), course.name());
+            assert_eq!(restored.date(), course.date());
+            assert_eq!(restored.duration(), course.duration());
+            assert_eq!(restored.chapter_quantity(), 2);
+            assert_eq!(lesson_names(&restored), lesson_names(&course));
+            assert_eq!(
+                restored.chapters()[1].lessons()[0].video_url(),
+                course.chapters()[1].lessons()[0].video_url()
+            );
+        }
+
+        #[test]
+        fn test_round_trip_preserves_ids() {
+            let course = create_test_course();
+
+            let restored = Course::from_json(&course.to_json().unwrap()).unwrap();
+
+            assert_eq!(ids(&restored), ids(&course));
+        }
+
+        #[test]
+        fn test_reimport_keeps_learner_progress() {
+            let course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let lesson_id = course.chapters()[0].lessons()[1].lesson_id();
+            progress.start_lesson(lesson_id);
+            progress.end_lesson(lesson_id).unwrap();
+            let json = course
+                .to_json()
+                .unwrap()
+                .replace("\"name\": \"Intro\"", "\"name\": \"Welcome\"");
+
+            let edited = Course::from_json(&json).unwrap();
+            progress.reconcile(&edited).unwrap();
+
+            assert_eq!(edited.chapters()[0].lessons()[0].name().as_str(), "Welcome");
+            let lesson = progress
+                .lesson_progress()
+                .iter()
+                .find(|lesson| lesson.lesson_id() == lesson_id)
+                .unwrap();
+            assert!(lesson.has_ended());
+        }
+
+        #[test]
+        fn test_round_trip_preserves_provenance() {
+            let model = AiModel::new("Mistral Large").unwrap();
+            let mut lesson = create_test_lesson("Recap", 0);
+            lesson.update_provenance(ContentProvenance::AiGenerated(model.clone()));
+            let chapter = Chapter::new("Review".to_string(), 0, vec![lesson]).unwrap();
+            let course =
+                Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+
+            let restored = Course::from_json(&course.to_json().unwrap()).unwrap();
+
+            assert_eq!(
+                restored.chapters()[0].lessons()[0].provenance(),
+                &ContentProvenance::AiGenerated(model)
+            );
+        }
+
+        #[test]
+        fn test_round_trip_preserves_descriptions() {
+            let mut lesson = create_test_lesson("Recap", 0);
+            lesson
+                .update_description(Some("What we covered.".to_string()))
+                .unwrap();
+            let mut chapter = Chapter::new("Review".to_string(), 0, vec![lesson]).unwrap();
+            chapter
+                .update_description(Some("Wrapping up.".to_string()))
+                .unwrap();
+            let course =
+                Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+
+            let restored = Course::from_json(&course.to_json().unwrap()).unwrap();
+
+            assert_eq!(restored.chapters()[0].description(), Some("Wrapping up."));
+            assert_eq!(
+                restored.chapters()[0].lessons()[0].description(),
+                Some("What we covered.")
+            );
+        }
+
+        #[test]
+        fn test_round_trip_preserves_preview_lessons() {
+            let mut lesson = create_test_lesson("Teaser", 0);
+            lesson.set_preview(true);
+            let chapter = Chapter::new("Basics".to_string(), 0, vec![lesson]).unwrap();
+            let course =
+                Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+
+            let json = course.to_json().unwrap();
+            let restored = Course::from_json(&json).unwrap();
+
+            assert!(json.contains("\"preview\": true"));
+            assert!(restored.chapters()[0].lessons()[0].is_preview());
+            assert!(!create_test_course().to_json().unwrap().contains("preview"));
+        }
+
+        #[test]
+        fn test_round_trip_preserves_course_description() {
+            let mut course = create_test_course();
+            course
+                .update_description(Some("Learn Rust from scratch.".to_string()))
+                .unwrap();
+
+            assert_eq!(round_trip(&course).description(), Some("Learn Rust from scratch."));
+        }
+
+        #[test]
+        fn test_round_trip_preserves_difficulty() {
+            let mut course = create_test_course();
+            course.update_difficulty(Some(DifficultyLevel::Advanced));
+
+            assert_eq!(round_trip(&course).difficulty(), Some(DifficultyLevel::Advanced));
+        }
+
+        #[test]
+        fn test_round_trip_preserves_target_audience() {
+            let mut course = create_test_course();
+            course
+                .update_target_audience(Some("Backend developers".to_string()))
+                .unwrap();
+
+            assert_eq!(round_trip(&course).target_audience(), Some("Backend developers"));
+        }
+
+        #[test]
+        fn test_round_trip_preserves_learning_objectives() {
+            let mut course = create_test_course();
+            let objectives = vec![
+                "Understand ownership".to_string(),
+                "Write safe concurrent code".to_string(),
+            ];
+            course
+                .update_learning_objectives(objectives.clone())
+                .unwrap();
+
+            assert_eq!(round_trip(&course).learning_objectives(), objectives);
+        }
+
+        #[test]
+        fn test_round_trip_preserves_cover_image_url() {
+            let mut course = create_test_course();
+            course
+                .update_cover_image_url(Some("https://example.com/cover.png".to_string()))
+                .unwrap();
+
+            assert_eq!(round_trip(&course).cover_image_url(), course.cover_image_url());
+            assert!(course.cover_image_url().is_some());
+        }
+
+        #[test]
+        fn test_round_trip_preserves_paid_flag() {
+            let mut course = create_test_course();
+            course.set_paid(true);
+
+            assert!(round_trip(&course).is_paid());
+            assert!(!round_trip(&create_test_course()).is_paid());
+        }
+
+        #[test]
+        fn test_round_trip_preserves_localized_names() {
+            let es = Locale::new("es").unwrap();
+            let mut course = create_test_course();
+            course
+                .set_localized_name(es.clone(), "Programación en Rust".to_string())
+                .unwrap();
+
+            let restored = round_trip(&course);
+
+            assert_eq!(restored.localized_names(), course.localized_names());
+            assert_eq!(restored.localized_name(&es), "Programación en Rust");
+        }
+
+        #[test]
+        fn test_round_trip_preserves_chapter_and_lesson_localized_names() {
+            let es = Locale::new("es").unwrap();
+            let mut lesson = create_test_lesson("Intro", 0);
+            lesson
+                .set_localized_name(es.clone(), "Introducción".to_string())
+                .unwrap();
+            let mut chapter = Chapter::new("Basics".to_string(), 0, vec![lesson]).unwrap();
+            chapter
+                .set_localized_name(es.clone(), "Conceptos básicos".to_string())
+                .unwrap();
+            let course =
+                Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+
+            let restored = round_trip(&course);
+
+            let chapter = &restored.chapters()[0];
+            assert_eq!(chapter.localized_names().get(&es), Some("Conceptos básicos"));
+            assert_eq!(chapter.lessons()[0].localized_names().get(&es), Some("Introducción"));
+        }
+
+        #[test]
+        fn test_round_trip_preserves_localized_descriptions() {
+            let es = Locale::new("es").unwrap();
+            let mut course = create_test_course();
+            course
+                .set_localized_description(es.clone(), "Aprende Rust desde cero.".to_string())
+                .unwrap();
+
+            let restored = round_trip(&course);
+
+            assert_eq!(restored.localized_description(&es), Some("Aprende Rust desde cero."));
+        }
+
+        #[test]
+        fn test_round_trip_preserves_release_schedule_days() {
+            let mut course = create_test_course();
+            course.update_release_schedule(ReleaseSchedule::DaysAfterEnrollment(vec![0, 7]));
+
+            assert_eq!(
+                round_trip(&course).release_schedule(),
+                &ReleaseSchedule::DaysAfterEnrollment(vec![0, 7])
+            );
+        }
+
+        #[test]
+        fn test_round_trip_preserves_release_schedule_dates() {
+            let dates = vec![
+                Date::new(2024, 3, 5).unwrap(),
+                Date::new(2024, 3, 12).unwrap(),
+            ];
+            let mut course = create_test_course();
+            course.update_release_schedule(ReleaseSchedule::FixedDates(dates.clone()));
+
+            assert_eq!(
+                round_trip(&course).release_schedule(),
+                &ReleaseSchedule::FixedDates(dates)
+            );
+        }
+
+        #[test]
+        fn test_round_trip_preserves_custom_fields() {
+            let fields = CustomFields::restore([
+                ("credits".to_string(), CustomFieldValue::Number(6)),
+                ("level".to_string(), CustomFieldValue::Choice("core".to_string())),
+                (
+                    "owner".to_string(),
+                    CustomFieldValue::Text("Platform team".to_string()),
+                ),
+                (
+                    "review_on".to_string(),
+                    CustomFieldValue::Date(Date::new(2025, 1, 15).unwrap()),
+                ),
+            ]);
+            let course = create_test_course().with_custom_fields(fields.clone());
+
+            assert_eq!(round_trip(&course).custom_fields(), &fields);
+        }
+
+        #[test]
+        fn test_missing_descriptions_are_omitted() {
+            let json = create_test_course().to_json().unwrap();
+
+            assert!(!json.contains("description"));
+        }
+
+        #[test]
+        fn test_human_authored_lessons_omit_provenance() {
+            let json = create_test_course().to_json().unwrap();
+
+            assert!(!json.contains("provenance"));
+        }
+
+        #[test]
+        fn test_to_json_writes_current_schema_version() {
+            let json = create_test_course().to_json().unwrap();
+
+            let value: Value = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(value["schema_version"], Value::from(COURSE_JSON_SCHEMA_VERSION));
+        }
+    }
+
+    mod from_json {
+        use super::*;
+
+        fn document(schema_version: &str, lesson_duration: u64) -> String {
+            format!(
+                r#"{{
+                    "schema_version": {schema_version},
+                    "name": "Rust Programming",
+                    "date": "2024-03-05",
+                    "chapters": [{{
+                        "name": "Basics",
+                        "lessons": [{{
+                            "name": "Intro",
+                            "duration_seconds": {lesson_duration},
+                            "video_url": "https://example.com/0.mp4"
+                        }}]
+                    }}]
+                }}"#
+            )
+        }
+
+        #[test]
+        fn test_version_1_document_is_migrated() {
+            let course = Course::from_json(&document("1", 900)).unwrap();
+
+            assert!(course.learning_objectives().is_empty());
+            assert_eq!(course.release_schedule(), &ReleaseSchedule::AllAtOnce);
+            assert_eq!(course.description(), None);
+            assert!(course.custom_fields().is_empty());
+        }
+
+        #[test]
+        fn test_version_2_requires_release_schedule() {
+            let result = Course::from_json(&document("2", 900));
+
+            assert!(matches!(result, Err(CourseJsonError::Malformed(_))));
+        }
+
+        #[test]
+        fn test_invalid_locale_is_rejected() {
+            let json = document("1", 900).replace(
+                r#""name": "Basics","#,
+                r#""name": "Basics", "localized_names": {"": "Básicos"},"#,
+            );
+
+            assert!(matches!(
+                Course::from_json(&json),
+                Err(CourseJsonError::LocaleNotValid(_))
+            ));
+        }
+
+        #[test]
+        fn test_unknown_difficulty_is_rejected() {
+            let json = document("1", 900).replace(
+                r#""date": "2024-03-05","#,
+                r#""date": "2024-03-05", "difficulty": "legendary","#,
+            );
+
+            assert!(matches!(
+                Course::from_json(&json),
+                Err(CourseJsonError::DifficultyNotValid(_))
+            ));
+        }
+
+        #[test]
+        fn test_missing_ids_are_generated() {
+            let first = Course::from_json(&document("1", 900)).unwrap();
+            let second = Course::from_json(&document("1", 900)).unwrap();
+
+            assert_ne!(first.id(), second.id());
+            assert_ne!(
+                first.chapters()[0].lessons()[0].id(),
+                second.chapters()[0].lessons()[0].id()
+            );
+        }
+
+        #[test]
+        fn test_invalid_id_is_rejected() {
+            let json = document("1", 900)
+                .replace(r#""name": "Basics","#, r#""id": "not-an-id", "name": "Basics","#);
+
+            assert!(matches!(
+                Course::from_json(&json),
+                Err(CourseJsonError::IdNotValid(_))
+            ));
+        }
+
+        #[test]
+        fn test_duplicate_id_is_rejected() {
+            let id = Id::new().to_string();
+            let json = document("1", 900)
+                .replace(
+                    r#""name": "Rust Programming","#,
+                    &format!(r#""id": "{id}", "name": "Rust Programming","#),
+                )
+                .replace(r#""name": "Basics","#, &format!(r#""id": "{id}", "name": "Basics","#));
+
+            assert_eq!(Course::from_json(&json).err(), Some(CourseJsonError::DuplicateId(id)));
+        }
+
+        #[test]
+        fn test_blank_description_is_rejected() {
+            let json = document("1", 900)
+                .replace(r#""name": "Basics","#, r#""name": "Basics", "description": "  ","#);
+
+            let result = Course::from_json(&json);
+
+            assert!(matches!(result, Err(CourseJsonError::ChapterError(_))));
+        }
+
+        #[test]
+        fn test_duration_is_computed_from_lessons() {
+            let course = Course::from_json(&document("1", 900)).unwrap();
+
+            assert_eq!(*course.duration(), Duration::from_seconds(900));
+        }
+
+        #[test]
+        fn test_invalid_json_is_malformed() {
+            let result = Course::from_json("{ not json");
+
+            assert!(matches!(result, Err(CourseJsonError::Malformed(_))));
+        }
+
+        #[test]
+        fn test_missing_field_is_malformed() {
+            let result = Course::from_json(r#"{"schema_version": 1, "name": "Rust"}"#);
+
+            assert!(matches!(result, Err(CourseJsonError::Malformed(_))));
+        }
+
+        #[test]
+        fn test_missing_schema_version_is_rejected() {
+            let result = Course::from_json(r#"{"name": "Rust"}"#);
+
+            assert_eq!(result.err(), Some(CourseJsonError::MissingSchemaVersion));
+        }
+
+        #[test]
+        fn test_future_schema_version_is_rejected() {
+            let result = Course::from_json(&document("3", 900));
+
+            assert_eq!(
+                result.err(),
+                Some(CourseJsonError::UnsupportedSchemaVersion {
+                    found: 3,
+                    latest: COURSE_JSON_SCHEMA_VERSION,
+                })
+            );
+        }
+
+        #[test]
+        fn test_schema_version_zero_is_rejected() {
+            let result = Course::from_json(&document("0", 900));
+
+            assert!(matches!(
+                result,
+                Err(CourseJsonError::UnsupportedSchemaVersion { found: 0, .. })
+            ));
+        }
+
+        #[test]
+        fn test_domain_validation_still_applies() {
+            let result = Course::from_json(&document("1", 0));
+
+            assert!(matches!(result, Err(CourseJsonError::LessonError(_))));
+        }
+
+        #[test]
+        fn test_provenance_without_model_is_rejected() {
+            let json = document("1", 900).replace(
+                r#""video_url": "https://example.com/0.mp4""#,
+                r#""video_url": "https://example.com/0.mp4",
+                   "provenance": {"kind": "ai_assisted", "model": " "}"#,
+            );
+
+            assert!(matches!(
+                Course::from_json(&json),
+                Err(CourseJsonError::ProvenanceError(_))
+            ));
+        }
+
+        #[test]
+        fn test_invalid_date_is_rejected() {
+            let json = document("1", 900).replace("2024-03-05", "2024-13-05");
+
+            assert!(matches!(Course::from_json(&json), Err(CourseJsonError::DateError(_))));
+        }
+    }
+}
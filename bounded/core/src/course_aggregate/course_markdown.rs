@@ -0,0 +1,410 @@
+use crate::{Chapter, ChapterError, Course, CourseError, Lesson, LessonError};
+use education_platform_common::Duration;
+use thiserror::Error;
+
+/// Error types for Markdown course outline import failures.
+///
+/// Every variant tied to a place in the outline carries its 1-based line number.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CourseMarkdownError {
+    #[error("Outline has no `# Course` title")]
+    MissingCourseTitle,
+
+    #[error("Line {line}: the course title was already given on line {first}")]
+    DuplicateCourseTitle { line: usize, first: usize },
+
+    #[error("Line {line}: content must come after the `# Course` title")]
+    ContentBeforeTitle { line: usize },
+
+    #[error("Line {line}: lesson must be inside a `## Chapter`")]
+    LessonOutsideChapter { line: usize },
+
+    #[error("Line {line}: expected `- Lesson | mm:ss | url`")]
+    LessonNotValid { line: usize },
+
+    #[error("Line {line}: `{value}` is not a duration in mm:ss or h:mm:ss")]
+    DurationNotValid { line: usize, value: String },
+
+    #[error("Line {line}: unexpected content `{content}`")]
+    UnexpectedLine { line: usize, content: String },
+
+    #[error("Line {line}: lesson is not valid: {source}")]
+    LessonError { line: usize, source: LessonError },
+
+    #[error("Line {line}: chapter is not valid: {source}")]
+    ChapterError { line: usize, source: ChapterError },
+
+    #[error("Line {line}: course is not valid: {source}")]
+    CourseError { line: usize, source: CourseError },
+}
+
+struct ChapterOutline {
+    line: usize,
+    name: String,
+    lessons: Vec<Lesson>,
+}
+
+impl ChapterOutline {
+    fn build(self, index: usize) -> Result<Chapter, CourseMarkdownError> {
+        Chapter::new(self.name, index, self.lessons).map_err(|source| {
+            CourseMarkdownError::ChapterError {
+                line: self.line,
+                source,
+            }
+        })
+    }
+}
+
+impl Course {
+    /// Creates a new course from a plain-text Markdown outline.
+    ///
+    /// The outline uses one line per element:
+    ///
+    /// ```markdown
+    /// # Rust Programming
+    ///
+    /// ## Getting Started
+    /// - Introduction | 15:30 | https://example.com/intro.mp4
+    /// - Installing Rust | 1:02:00 | https://example.com/install.mp4
+    /// ```
+    ///
+    /// `# ` gives the course name, `## ` starts a chapter and `- ` (or `* `)
+    /// adds a lesson as `name | duration | video url` to the current chapter.
+    /// Durations are written as `mm:ss` or `h:mm:ss`. Blank lines are ignored;
+    /// anything else is rejected so typos do not go unnoticed. The course is
+    /// dated today and gets fresh IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first problem found, as a `CourseMarkdownError` carrying the
+    /// line number it occurred on. Domain validation failures are reported as
+    /// `LessonError`, `ChapterError` or `CourseError` on the line of the
+    /// lesson, chapter heading or course title respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Course, CourseMarkdownError};
+    ///
+    /// let outline = [
+    ///     "# Rust Programming",
+    ///     "",
+    ///     "## Getting Started",
+    ///     "- Introduction | 15:30 | https://example.com/intro.mp4",
+    /// ]
+    /// .join("\n");
+    ///
+    /// let course = Course::from_markdown(&outline).unwrap();
+    /// assert_eq!(course.name().as_str(), "Rust Programming");
+    /// assert_eq!(course.duration().total_seconds(), 930);
+    ///
+    /// let typo = outline.replace("15:30", "15m");
+    /// assert!(matches!(
+    ///     Course::from_markdown(&typo),
+    ///     Err(CourseMarkdownError::DurationNotValid { line: 4, .. })
+    /// ));
+    /// ```
+    pub fn from_markdown(markdown: &str) -> Result<Self, CourseMarkdownError> {
+        let mut title: Option<(usize, String)> = None;
+        let mut outlines: Vec<ChapterOutline> = Vec::new();
+
+        for (index, raw) in markdown.lines().enumerate() {
+            let line = index + 1;
+            let content = raw.trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = content.strip_prefix("# ") {
+                if let Some((first, _)) = title {
+                    return Err(CourseMarkdownError::DuplicateCourseTitle { line, first });
+                }
+                title = Some((line, name.trim().to_string()));
+                continue;
+            }
+
+            if title.is_none() {
+                return Err(CourseMarkdownError::ContentBeforeTitle { line });
+            }
+
+            if let Some(name) = content.strip_prefix("## ") {
+                outlines.push(ChapterOutline {
+                    line,
+                    name: name.trim().to_string(),
+                    lessons: Vec::new(),
+                });
+                continue;
+            }
+
+            let Some(lesson) = content
+                .strip_prefix("- ")
+                .or_else(|| content.strip_prefix("* "))
+            else {
+                return Err(CourseMarkdownError::UnexpectedLine {
+                    line,
+                    content: content.to_string(),
+                });
+            };
+
+            let Some(chapter) = outlines.last_mut() else {
+                return Err(CourseMarkdownError::LessonOutsideChapter { line });
+            };
+            let lesson = parse_lesson(lesson, line, chapter.lessons.len())?;
+            chapter.lessons.push(lesson);
+        }
+
+        let Some((title_line, name)) = title else {
+            return Err(CourseMarkdownError::MissingCourseTitle);
+        };
+
+        let chapters = outlines
+            .into_iter()
+            .enumerate()
+            .map(|(index, outline)| outline.build(index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(name, None, 0, chapters).map_err(|source| CourseMarkdownError::CourseError {
+            line: title_line,
+            source,
+        })
+    }
+}
+
+fn parse_lesson(text: &str, line: usize, index: usize) -> Result<Lesson, CourseMarkdownError> {
+    let parts: Vec<&str> = text.split('|').map(str::trim).collect();
+    let [name, duration, video_url] = parts.as_slice() else {
+        return Err(CourseMarkdownError::LessonNotValid { line });
+    };
+
+    let duration =
+        parse_duration(duration).ok_or_else(|| CourseMarkdownError::DurationNotValid {
+            line,
+            value: (*duration).to_string(),
+        })?;
+
+    Lesson::new(name.to_string(), duration, video_url.to_string(), index)
+        .map_err(|source| CourseMarkdownError::LessonError { line, source })
+}
+
+/// Parses `mm:ss` or `h:mm:ss` into a duration.
+///
+/// Seconds must be below 60, and so must minutes when hours are given.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let parts = value
+        .split(':')
+        .map(|part| {
+            part.bytes()
+                .all(|byte| byte.is_ascii_digit())
+                .then(|| part.parse::<u64>().ok())
+                .flatten()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // Checked, so an absurd number of hours is a bad line rather than a panic.
+    let seconds = match parts.as_slice() {
+        [minutes, seconds] if *seconds < 60 => minutes.checked_mul(60)?.checked_add(*seconds),
+        [hours, minutes, seconds] if *minutes < 60 && *seconds < 60 => {
+            hours.checked_mul(3600)?.checked_add(minutes * 60 + seconds)
+        }
+        _ => None,
+    }?;
+
+    Some(Duration::from_seconds(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTLINE: &str = "\
+# Rust Programming
+
+## Basics
+- Intro | 10:00 | https://example.com/intro.mp4
+* Setup | 1:00:30 | https://example.com/setup.mp4
+
+## Ownership
+- Borrowing | 0:45 | https://example.com/borrowing.mp4
+";
+
+    mod from_markdown {
+        use super::*;
+
+        #[test]
+        fn test_parses_chapters_and_lessons_in_order() {
+            let course = Course::from_markdown(OUTLINE).unwrap();
+
+            assert_eq!(course.name().as_str(), "Rust Programming");
+            assert_eq!(course.chapter_quantity(), 2);
+            assert_eq!(course.chapters()[0].name().as_str(), "Basics");
+            let names: Vec<&str> = course.chapters()[0]
+                .lessons()
+                .iter()
+                .map(|lesson| lesson.name().as_str())
+                .collect();
+            assert_eq!(names, vec!["Intro", "Setup"]);
+            assert_eq!(
+                course.chapters()[1].lessons()[0].video_url().as_str(),
+                "https://example.com/borrowing.mp4"
+            );
+        }
+
+        #[test]
+        fn test_duration_sums_lessons() {
+            let course = Course::from_markdown(OUTLINE).unwrap();
+
+            assert_eq!(course.duration().total_seconds(), 600 + 3630 + 45);
+        }
+
+        #[test]
+        fn test_missing_title_is_rejected() {
+            assert_eq!(
+                Course::from_markdown("\n\n").err(),
+                Some(CourseMarkdownError::MissingCourseTitle)
+            );
+        }
+
+        #[test]
+        fn test_second_title_is_rejected() {
+            let outline = format!("{OUTLINE}# Another course\n");
+
+            assert_eq!(
+                Course::from_markdown(&outline).err(),
+                Some(CourseMarkdownError::DuplicateCourseTitle { line: 9, first: 1 })
+            );
+        }
+
+        #[test]
+        fn test_content_before_title_is_rejected() {
+            let outline = format!("## Basics\n{OUTLINE}");
+
+            assert_eq!(
+                Course::from_markdown(&outline).err(),
+                Some(CourseMarkdownError::ContentBeforeTitle { line: 1 })
+            );
+        }
+
+        #[test]
+        fn test_lesson_outside_chapter_is_rejected() {
+            let outline = "# Rust\n- Intro | 10:00 | https://example.com/intro.mp4\n";
+
+            assert_eq!(
+                Course::from_markdown(outline).err(),
+                Some(CourseMarkdownError::LessonOutsideChapter { line: 2 })
+            );
+        }
+
+        #[test]
+        fn test_lesson_with_missing_fields_is_rejected() {
+            let outline = OUTLINE.replace(" | https://example.com/intro.mp4", "");
+
+            assert_eq!(
+                Course::from_markdown(&outline).err(),
+                Some(CourseMarkdownError::LessonNotValid { line: 4 })
+            );
+        }
+
+        #[test]
+        fn test_unexpected_line_is_rejected() {
+            let outline = OUTLINE.replace("## Ownership", "### Ownership");
+
+            assert!(matches!(
+                Course::from_markdown(&outline),
+                Err(CourseMarkdownError::UnexpectedLine { line: 7, .. })
+            ));
+        }
+
+        #[test]
+        fn test_lesson_validation_reports_line() {
+            let outline = OUTLINE.replace("https://example.com/borrowing.mp4", "not a url");
+
+            assert!(matches!(
+                Course::from_markdown(&outline),
+                Err(CourseMarkdownError::LessonError { line: 8, .. })
+            ));
+        }
+
+        #[test]
+        fn test_zero_duration_reports_line() {
+            let outline = OUTLINE.replace("0:45", "0:00");
+
+            assert_eq!(
+                Course::from_markdown(&outline).err(),
+                Some(CourseMarkdownError::LessonError {
+                    line: 8,
+                    source: LessonError::DurationIsZero,
+                })
+            );
+        }
+
+        #[test]
+        fn test_overflowing_duration_reports_line() {
+            let outline = OUTLINE.replace("1:00:30", "9999999999999999:00:00");
+
+            assert_eq!(
+                Course::from_markdown(&outline).err(),
+                Some(CourseMarkdownError::DurationNotValid {
+                    line: 5,
+                    value: "9999999999999999:00:00".to_string(),
+                })
+            );
+        }
+
+        #[test]
+        fn test_empty_chapter_reports_heading_line() {
+            let outline = format!("{OUTLINE}\n## Empty\n");
+
+            assert_eq!(
+                Course::from_markdown(&outline).err(),
+                Some(CourseMarkdownError::ChapterError {
+                    line: 10,
+                    source: ChapterError::ChapterWithEmptyLessons,
+                })
+            );
+        }
+
+        #[test]
+        fn test_course_without_chapters_reports_title_line() {
+            assert!(matches!(
+                Course::from_markdown("\n# Rust\n"),
+                Err(CourseMarkdownError::CourseError { line: 2, .. })
+            ));
+        }
+    }
+
+    mod parse_duration {
+        use super::*;
+
+        #[test]
+        fn test_minutes_and_seconds() {
+            assert_eq!(parse_duration("15:30"), Some(Duration::from_seconds(930)));
+        }
+
+        #[test]
+        fn test_hours_minutes_and_seconds() {
+            assert_eq!(parse_duration("1:02:03"), Some(Duration::from_hms(1, 2, 3)));
+        }
+
+        #[test]
+        fn test_rejects_out_of_range_parts() {
+            assert_eq!(parse_duration("10:60"), None);
+            assert_eq!(parse_duration("1:60:00"), None);
+        }
+
+        #[test]
+        fn test_rejects_values_that_overflow() {
+            assert_eq!(parse_duration("9999999999999999:00:00"), None);
+            assert_eq!(parse_duration("18446744073709551615:00"), None);
+        }
+
+        #[test]
+        fn test_rejects_malformed_values() {
+            assert_eq!(parse_duration("930"), None);
+            assert_eq!(parse_duration("15m"), None);
+            assert_eq!(parse_duration(":30"), None);
+            assert_eq!(parse_duration("+1:30"), None);
+            assert_eq!(parse_duration("1:2:3:4"), None);
+        }
+    }
+}
@@ -0,0 +1,559 @@
+use crate::Course;
+use education_platform_common::{DateTime, Duration, Entity, Id};
+use thiserror::Error;
+
+/// Error types for course version history failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CourseVersionError {
+    #[error("History belongs to course {expected}, not to course {actual}")]
+    CourseMismatch { expected: String, actual: String },
+
+    #[error("Version {0} has not been published")]
+    VersionNotFound(u32),
+
+    #[error("A paid course needs at least one preview lesson to be published")]
+    MissingPreviewLesson,
+}
+
+/// A lesson as it was when a course version was published.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LessonSnapshot {
+    id: Id,
+    chapter_id: Id,
+    name: String,
+    duration: Duration,
+}
+
+impl LessonSnapshot {
+    /// Returns the ID of the lesson.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the ID of the chapter the lesson was in.
+    #[inline]
+    #[must_use]
+    pub const fn chapter_id(&self) -> Id {
+        self.chapter_id
+    }
+
+    /// Returns the lesson name at publish time.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the lesson duration at publish time.
+    #[inline]
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Frozen copy of a course's structure, numbered from 1 in publish order.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Chapter, Course, CourseVersionHistory, Lesson};
+/// use education_platform_common::{DateTime, Entity};
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// let mut history = CourseVersionHistory::new(course.id());
+/// let version = history
+///     .publish(&course, DateTime::new(2025, 1, 6, 9, 0, 0).unwrap())
+///     .unwrap();
+///
+/// assert_eq!(version.number(), 1);
+/// assert_eq!(version.lessons()[0].name(), "Introduction");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseVersion {
+    course_id: Id,
+    number: u32,
+    published_at: DateTime,
+    course_name: String,
+    lessons: Vec<LessonSnapshot>,
+}
+
+impl CourseVersion {
+    fn snapshot(course: &Course, number: u32, published_at: DateTime) -> Self {
+        let lessons = course
+            .chapters()
+            .iter()
+            .flat_map(|chapter| {
+                chapter.lessons().iter().map(|lesson| LessonSnapshot {
+                    id: lesson.id(),
+                    chapter_id: chapter.id(),
+                    name: lesson.name().to_string(),
+                    duration: lesson.duration(),
+                })
+            })
+            .collect();
+
+        Self {
+            course_id: course.id(),
+            number,
+            published_at,
+            course_name: course.name().to_string(),
+            lessons,
+        }
+    }
+
+    /// Returns the ID of the versioned course.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns the version number.
+    #[inline]
+    #[must_use]
+    pub const fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Returns when the version was published.
+    #[inline]
+    #[must_use]
+    pub const fn published_at(&self) -> DateTime {
+        self.published_at
+    }
+
+    /// Returns the course name at publish time.
+    #[inline]
+    #[must_use]
+    pub fn course_name(&self) -> &str {
+        &self.course_name
+    }
+
+    /// Returns the lessons in course order.
+    #[inline]
+    #[must_use]
+    pub fn lessons(&self) -> &[LessonSnapshot] {
+        &self.lessons
+    }
+
+    /// Lists the lesson changes needed to go from this version to another.
+    ///
+    /// Lessons are matched by ID, so a renamed lesson is reported as
+    /// renamed rather than as removed and added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseVersionHistory, Lesson};
+    /// use education_platform_common::{DateTime, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let mut history = CourseVersionHistory::new(course.id());
+    /// let now = DateTime::new(2025, 1, 6, 9, 0, 0).unwrap();
+    /// history.publish(&course, now).unwrap();
+    ///
+    /// let mut renamed = course.chapters()[0].lessons()[0].clone();
+    /// renamed.update_name("Welcome".to_string()).unwrap();
+    /// course.update_lesson(renamed).unwrap();
+    /// history.publish(&course, now).unwrap();
+    ///
+    /// let diff = history.diff(1, 2).unwrap();
+    /// assert_eq!(diff.renamed()[0].from(), "Introduction");
+    /// assert_eq!(diff.renamed()[0].to(), "Welcome");
+    /// assert!(diff.added().is_empty() && diff.removed().is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, newer: &Self) -> CourseVersionDiff {
+        let find = |lessons: &[LessonSnapshot], id: Id| {
+            lessons.iter().find(|lesson| lesson.id == id).cloned()
+        };
+
+        let added = newer
+            .lessons
+            .iter()
+            .filter(|lesson| find(&self.lessons, lesson.id).is_none())
+            .cloned()
+            .collect();
+        let removed = self
+            .lessons
+            .iter()
+            .filter(|lesson| find(&newer.lessons, lesson.id).is_none())
+            .cloned()
+            .collect();
+        let renamed = self
+            .lessons
+            .iter()
+            .filter_map(|old| {
+                find(&newer.lessons, old.id)
+                    .filter(|new| new.name != old.name)
+                    .map(|new| LessonRename {
+                        lesson_id: old.id,
+                        from: old.name.clone(),
+                        to: new.name,
+                    })
+            })
+            .collect();
+
+        CourseVersionDiff {
+            added,
+            removed,
+            renamed,
+        }
+    }
+}
+
+/// A lesson whose name changed between two versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LessonRename {
+    lesson_id: Id,
+    from: String,
+    to: String,
+}
+
+impl LessonRename {
+    /// Returns the ID of the renamed lesson.
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> Id {
+        self.lesson_id
+    }
+
+    /// Returns the name in the older version.
+    #[inline]
+    #[must_use]
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    /// Returns the name in the newer version.
+    #[inline]
+    #[must_use]
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+}
+
+/// Lesson changes between two course versions.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CourseVersionDiff {
+    added: Vec<LessonSnapshot>,
+    removed: Vec<LessonSnapshot>,
+    renamed: Vec<LessonRename>,
+}
+
+impl CourseVersionDiff {
+    /// Returns the lessons only in the newer version, in its order.
+    #[inline]
+    #[must_use]
+    pub fn added(&self) -> &[LessonSnapshot] {
+        &self.added
+    }
+
+    /// Returns the lessons only in the older version, in its order.
+    #[inline]
+    #[must_use]
+    pub fn removed(&self) -> &[LessonSnapshot] {
+        &self.removed
+    }
+
+    /// Returns the lessons present in both versions under different names.
+    #[inline]
+    #[must_use]
+    pub fn renamed(&self) -> &[LessonRename] {
+        &self.renamed
+    }
+
+    /// Returns true if no lesson was added, removed or renamed.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.renamed.is_empty()
+    }
+}
+
+/// Published versions of one course, oldest first.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Chapter, Course, CourseVersionHistory, Lesson};
+/// use education_platform_common::{DateTime, Entity};
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// let mut history = CourseVersionHistory::new(course.id());
+/// assert!(history.latest().is_none());
+///
+/// let now = DateTime::new(2025, 1, 6, 9, 0, 0).unwrap();
+/// history.publish(&course, now).unwrap();
+/// history.publish(&course, now).unwrap();
+///
+/// assert_eq!(history.latest().map(|version| version.number()), Some(2));
+/// assert!(history.diff(1, 2).unwrap().is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseVersionHistory {
+    course_id: Id,
+    versions: Vec<CourseVersion>,
+}
+
+impl CourseVersionHistory {
+    /// Creates an empty history for a course.
+    #[inline]
+    #[must_use]
+    pub const fn new(course_id: Id) -> Self {
+        Self {
+            course_id,
+            versions: Vec::new(),
+        }
+    }
+
+    /// Snapshots the course as the next version and returns it.
+    ///
+    /// Non-enrolled learners only see a paid course through its preview
+    /// lessons, so a paid course must have at least one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseVersionError::CourseMismatch` if the course is not the
+    /// one this history tracks, or `CourseVersionError::MissingPreviewLesson`
+    /// if it is paid and has no preview lesson.
+    pub fn publish(
+        &mut self,
+        course: &Course,
+        published_at: DateTime,
+    ) -> Result<&CourseVersion, CourseVersionError> {
+        if course.id() != self.course_id {
+            return Err(CourseVersionError::CourseMismatch {
+                expected: self.course_id.to_string(),
+                actual: course.id().to_string(),
+            });
+        }
+        if course.is_paid() && course.preview_lessons().is_empty() {
+            return Err(CourseVersionError::MissingPreviewLesson);
+        }
+
+        let number = self.latest().map_or(1, |version| version.number + 1);
+        self.versions
+            .push(CourseVersion::snapshot(course, number, published_at));
+        self.latest()
+            .ok_or(CourseVersionError::VersionNotFound(number))
+    }
+
+    /// Returns the ID of the tracked course.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns every published version, oldest first.
+    #[inline]
+    #[must_use]
+    pub fn versions(&self) -> &[CourseVersion] {
+        &self.versions
+    }
+
+    /// Returns the most recently published version.
+    #[inline]
+    #[must_use]
+    pub fn latest(&self) -> Option<&CourseVersion> {
+        self.versions.last()
+    }
+
+    /// Returns a version by number.
+    #[must_use]
+    pub fn version(&self, number: u32) -> Option<&CourseVersion> {
+        self.versions
+            .iter()
+            .find(|version| version.number == number)
+    }
+
+    /// Lists the lesson changes between two published versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseVersionError::VersionNotFound` if either version has
+    /// not been published.
+    pub fn diff(&self, from: u32, to: u32) -> Result<CourseVersionDiff, CourseVersionError> {
+        let from = self
+            .version(from)
+            .ok_or(CourseVersionError::VersionNotFound(from))?;
+        let to = self
+            .version(to)
+            .ok_or(CourseVersionError::VersionNotFound(to))?;
+        Ok(from.diff(to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson};
+
+    fn create_test_lesson(name: &str, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            1800,
+            format!("https://example.com/{index}.mp4"),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let chapter = Chapter::new(
+            "Getting Started".to_string(),
+            0,
+            vec![
+                create_test_lesson("Introduction", 0),
+                create_test_lesson("Setup", 1),
+            ],
+        )
+        .unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_time() -> DateTime {
+        DateTime::new(2025, 1, 6, 9, 0, 0).unwrap()
+    }
+
+    mod publish {
+        use super::*;
+
+        #[test]
+        fn test_versions_are_numbered_in_order() {
+            let course = create_test_course();
+            let mut history = CourseVersionHistory::new(course.id());
+
+            history.publish(&course, create_time()).unwrap();
+            history.publish(&course, create_time()).unwrap();
+
+            let numbers: Vec<u32> = history
+                .versions()
+                .iter()
+                .map(CourseVersion::number)
+                .collect();
+            assert_eq!(numbers, vec![1, 2]);
+        }
+
+        #[test]
+        fn test_snapshot_is_not_affected_by_later_edits() {
+            let mut course = create_test_course();
+            let mut history = CourseVersionHistory::new(course.id());
+            history.publish(&course, create_time()).unwrap();
+
+            let chapter_id = course.chapters()[0].id();
+            course
+                .add_lesson(chapter_id, create_test_lesson("Tooling", 2), None)
+                .unwrap();
+
+            assert_eq!(history.version(1).unwrap().lessons().len(), 2);
+        }
+
+        #[test]
+        fn test_other_course_is_rejected() {
+            let mut history = CourseVersionHistory::new(Id::new());
+
+            let result = history.publish(&create_test_course(), create_time());
+
+            assert!(matches!(result, Err(CourseVersionError::CourseMismatch { .. })));
+            assert!(history.versions().is_empty());
+        }
+
+        #[test]
+        fn test_paid_course_needs_a_preview_lesson() {
+            let mut course = create_test_course();
+            course.set_paid(true);
+            let mut history = CourseVersionHistory::new(course.id());
+
+            let result = history.publish(&course, create_time());
+
+            assert_eq!(result, Err(CourseVersionError::MissingPreviewLesson));
+            assert!(history.versions().is_empty());
+
+            let lesson_id = course.chapters()[0].lessons()[1].lesson_id();
+            course.set_lesson_preview(lesson_id, true).unwrap();
+
+            assert!(history.publish(&course, create_time()).is_ok());
+        }
+    }
+
+    mod diff {
+        use super::*;
+
+        #[test]
+        fn test_added_and_renamed_lessons() {
+            let mut course = create_test_course();
+            let mut history = CourseVersionHistory::new(course.id());
+            history.publish(&course, create_time()).unwrap();
+            let chapter_id = course.chapters()[0].id();
+            course
+                .add_lesson(chapter_id, create_test_lesson("Tooling", 2), None)
+                .unwrap();
+            let mut setup = course.chapters()[0].lessons()[1].clone();
+            setup.update_name("Installation".to_string()).unwrap();
+            course.update_lesson(setup.clone()).unwrap();
+            history.publish(&course, create_time()).unwrap();
+
+            let diff = history.diff(1, 2).unwrap();
+
+            assert_eq!(diff.added()[0].name(), "Tooling");
+            assert!(diff.removed().is_empty());
+            assert_eq!(diff.renamed()[0].lesson_id(), setup.id());
+            assert_eq!(diff.renamed()[0].from(), "Setup");
+        }
+
+        #[test]
+        fn test_reverse_diff_swaps_sides() {
+            let mut course = create_test_course();
+            let mut history = CourseVersionHistory::new(course.id());
+            history.publish(&course, create_time()).unwrap();
+            let chapter_id = course.chapters()[0].id();
+            course
+                .add_lesson(chapter_id, create_test_lesson("Tooling", 2), None)
+                .unwrap();
+            history.publish(&course, create_time()).unwrap();
+
+            let diff = history.diff(2, 1).unwrap();
+
+            assert!(diff.added().is_empty());
+            assert_eq!(diff.removed()[0].name(), "Tooling");
+        }
+
+        #[test]
+        fn test_unknown_version_is_reported() {
+            let course = create_test_course();
+            let mut history = CourseVersionHistory::new(course.id());
+            history.publish(&course, create_time()).unwrap();
+
+            assert_eq!(history.diff(1, 3), Err(CourseVersionError::VersionNotFound(3)));
+        }
+    }
+}
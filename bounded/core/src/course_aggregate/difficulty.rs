@@ -0,0 +1,91 @@
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error types for DifficultyLevel parsing failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DifficultyLevelError {
+    #[error("Unknown difficulty level '{0}'")]
+    Unknown(String),
+}
+
+/// How much prior knowledge a course expects.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::DifficultyLevel;
+///
+/// let level: DifficultyLevel = "Intermediate".parse().unwrap();
+/// assert_eq!(level, DifficultyLevel::Intermediate);
+/// assert_eq!(level.to_string(), "intermediate");
+/// assert!(DifficultyLevel::Beginner < DifficultyLevel::Advanced);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DifficultyLevel {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl DifficultyLevel {
+    /// Returns the lowercase name of the level.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Beginner => "beginner",
+            Self::Intermediate => "intermediate",
+            Self::Advanced => "advanced",
+        }
+    }
+}
+
+impl FromStr for DifficultyLevel {
+    type Err = DifficultyLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "beginner" => Ok(Self::Beginner),
+            "intermediate" => Ok(Self::Intermediate),
+            "advanced" => Ok(Self::Advanced),
+            _ => Err(DifficultyLevelError::Unknown(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for DifficultyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ignores_case_and_whitespace() {
+        assert_eq!(" ADVANCED ".parse(), Ok(DifficultyLevel::Advanced));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_level() {
+        assert_eq!(
+            "expert".parse::<DifficultyLevel>(),
+            Err(DifficultyLevelError::Unknown("expert".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for level in [
+            DifficultyLevel::Beginner,
+            DifficultyLevel::Intermediate,
+            DifficultyLevel::Advanced,
+        ] {
+            assert_eq!(level.to_string().parse(), Ok(level));
+        }
+    }
+}
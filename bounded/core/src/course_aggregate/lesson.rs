@@ -1,9 +1,13 @@
 mod getters;
+mod localization;
+mod transcripts;
 mod update;
 
+use crate::{ContentProvenance, Transcript, TranscriptError};
 use education_platform_common::{
-    Duration, Entity, Id, Index, IndexError, SimpleName, SimpleNameConfig, SimpleNameError, Url,
-    UrlError,
+    Auditable, DateTime, Duration, Entity, Id, Index, IndexError, LocalizedText,
+    LocalizedTextError, SimpleName, SimpleNameConfig, SimpleNameError, Timestamps, Url, UrlError,
+    UrlPolicy, ValidatorError,
 };
 use thiserror::Error;
 
@@ -17,11 +21,23 @@ pub enum LessonError {
     #[error("Lesson video URL validation failed: {0}")]
     VideoUrlError(#[from] UrlError),
 
+    #[error("Lesson transcript URL validation failed: {0}")]
+    TranscriptUrlError(UrlError),
+
     #[error("Lesson index error: {0}")]
     IndexError(#[from] IndexError),
 
     #[error("Duration must be different from zero")]
     DurationIsZero,
+
+    #[error("Lesson translation failed: {0}")]
+    TranslationError(#[from] LocalizedTextError),
+
+    #[error("Lesson description validation failed: {0}")]
+    DescriptionError(ValidatorError),
+
+    #[error("Lesson transcript is not valid: {0}")]
+    TranscriptError(#[from] TranscriptError),
 }
 
 /// A lesson within a course, representing a single video or learning unit.
@@ -33,11 +49,12 @@ pub enum LessonError {
 /// # Examples
 ///
 /// ```
+/// use education_platform_common::Duration;
 /// use education_platform_core::Lesson;
 ///
 /// let lesson = Lesson::new(
 ///     "Introduction to Rust".to_string(),
-///     3600,
+///     Duration::from_hours(1),
 ///     "https://example.com/videos/intro.mp4".to_string(),
 ///     0,
 /// ).unwrap();
@@ -51,107 +68,158 @@ pub enum LessonError {
 pub struct Lesson {
     id: Id,
     name: SimpleName,
+    description: Option<String>,
     duration: Duration,
     video_url: Url,
+    transcript_url: Option<Url>,
+    transcripts: Vec<Transcript>,
+    provenance: ContentProvenance,
+    is_preview: bool,
     index: Index,
+    localized_names: LocalizedText,
+    timestamps: Timestamps,
 }
 
+/// Identifier of a [`Lesson`].
+///
+/// Lesson progress shares the ID of the lesson it tracks, so this is also
+/// the key for starting and ending lessons in a `CourseProgress`.
+pub type LessonId = Id<Lesson>;
+
 impl Lesson {
     /// Creates a new `Lesson` with the provided parameters.
     ///
     /// # Arguments
     ///
     /// * `name` - The lesson name (will be validated as a SimpleName)
-    /// * `duration_seconds` - Duration of the lesson in seconds
-    /// * `video_url` - URL to the lesson video (must be valid HTTP/HTTPS)
+    /// * `duration` - Length of the lesson; a bare integer is read as seconds
+    /// * `video_url` - URL to the lesson video (must be valid HTTPS)
     /// * `index` - Position of this lesson within the course (zero-based)
     ///
     /// # Errors
     ///
     /// Returns `LessonError::NameError` if the name validation fails.
-    /// Returns `LessonError::VideoUrlError` if the URL validation fails.
+    /// Returns `LessonError::VideoUrlError` if the URL validation fails or
+    /// the URL is not HTTPS.
     ///
     /// # Examples
     ///
     /// ```
+    /// use education_platform_common::Duration;
     /// use education_platform_core::Lesson;
     ///
     /// let lesson = Lesson::new(
     ///     "Getting Started with Rust".to_string(),
-    ///     1800,
+    ///     Duration::from_minutes(30),
     ///     "https://example.com/videos/lesson1.mp4".to_string(),
     ///     0,
     /// ).unwrap();
     ///
     /// assert_eq!(lesson.name().as_str(), "Getting Started with Rust");
+    /// assert_eq!(lesson.duration().total_seconds(), 1800);
+    ///
+    /// let insecure = Lesson::new(
+    ///     "Getting Started with Rust".to_string(),
+    ///     1800,
+    ///     "http://example.com/videos/lesson1.mp4".to_string(),
+    ///     0,
+    /// );
+    /// assert!(insecure.is_err());
     /// ```
     pub fn new(
         name: String,
-        duration_seconds: u64,
+        duration: impl Into<Duration>,
         video_url: String,
         index: usize,
     ) -> Result<Self, LessonError> {
-        Self::with_id(Id::default(), name, duration_seconds, video_url, index)
+        let video_url = Url::with_policy(video_url, &UrlPolicy::default())?;
+        Self::with_video_url(Id::default(), name, duration, video_url, index)
     }
 
     /// Creates a `Lesson` with a specific ID (for reconstruction from persistence).
     ///
+    /// # Errors
+    ///
+    /// Returns `LessonError::NameError` if the name validation fails.
+    /// Returns `LessonError::VideoUrlError` if the URL validation fails.
+    /// Returns `LessonError::DurationIsZero` if duration is zero.
+    #[deprecated(note = "parse the URL once and use `Lesson::with_video_url`")]
+    pub fn with_id(
+        id: Id,
+        name: String,
+        duration: impl Into<Duration>,
+        video_url: String,
+        index: usize,
+    ) -> Result<Self, LessonError> {
+        Self::with_video_url(id, name, duration, Url::new(video_url)?, index)
+    }
+
+    /// Creates a `Lesson` with a specific ID from an already validated video URL.
+    ///
     /// Use this constructor when reconstructing a Lesson from storage where
     /// the ID already exists. For creating new lessons, use [`Lesson::new`].
+    /// The URL is taken as is, so the caller decides which policy it passed.
     ///
     /// # Arguments
     ///
     /// * `id` - The existing lesson ID
     /// * `name` - The lesson name (will be validated as a SimpleName)
-    /// * `duration_seconds` - Duration of the lesson in seconds
-    /// * `video_url` - URL to the lesson video (must be valid HTTP/HTTPS)
+    /// * `duration` - Length of the lesson; a bare integer is read as seconds
+    /// * `video_url` - URL to the lesson video
     /// * `index` - Position of this lesson within the course (zero-based)
     ///
     /// # Errors
     ///
     /// Returns `LessonError::NameError` if the name validation fails.
-    /// Returns `LessonError::VideoUrlError` if the URL validation fails.
     /// Returns `LessonError::DurationIsZero` if duration is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use education_platform_core::Lesson;
-    /// use education_platform_common::{Entity, Id};
+    /// use education_platform_common::{Duration, Entity, Id, Url};
     ///
     /// let id = Id::default();
-    /// let lesson = Lesson::with_id(
+    /// let video_url = Url::new("https://example.com/videos/lesson.mp4".to_string()).unwrap();
+    /// let lesson = Lesson::with_video_url(
     ///     id,
     ///     "Reconstructed Lesson".to_string(),
-    ///     1800,
-    ///     "https://example.com/videos/lesson.mp4".to_string(),
+    ///     Duration::from_minutes(30),
+    ///     video_url,
     ///     0,
     /// ).unwrap();
     ///
     /// assert_eq!(lesson.id(), id);
+    /// assert_eq!(lesson.video_url().host(), "example.com");
     /// ```
-    pub fn with_id(
+    pub fn with_video_url(
         id: Id,
         name: String,
-        duration_seconds: u64,
-        video_url: String,
+        duration: impl Into<Duration>,
+        video_url: Url,
         index: usize,
     ) -> Result<Self, LessonError> {
-        let duration = Duration::from_seconds(duration_seconds);
+        let duration = duration.into();
         if duration.is_zero() {
             return Err(LessonError::DurationIsZero);
         }
 
         let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
-        let video_url = Url::new(video_url)?;
         let index = Index::new(index);
 
         Ok(Self {
             id,
             name,
+            description: None,
             duration,
             video_url,
+            transcript_url: None,
+            transcripts: Vec::new(),
+            provenance: ContentProvenance::default(),
+            is_preview: false,
             index,
+            localized_names: LocalizedText::new(),
+            timestamps: Timestamps::now(),
         })
     }
 }
@@ -162,10 +230,24 @@ impl Entity for Lesson {
     }
 }
 
+impl Auditable for Lesson {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn create_url(url: &str) -> Url {
+        Url::new(url.to_string()).unwrap()
+    }
+
     mod constructors {
         use super::*;
 
@@ -251,6 +333,31 @@ mod tests {
             assert!(matches!(result, Err(LessonError::VideoUrlError(_))));
         }
 
+        #[test]
+        fn test_new_accepts_duration_value() {
+            let lesson = Lesson::new(
+                "Valid Name".to_string(),
+                Duration::from_hms(1, 5, 0),
+                "https://example.com/video.mp4".to_string(),
+                0,
+            )
+            .unwrap();
+
+            assert_eq!(lesson.duration().total_seconds(), 3900);
+        }
+
+        #[test]
+        fn test_new_with_zero_duration_value_returns_error() {
+            let result = Lesson::new(
+                "Valid Name".to_string(),
+                Duration::default(),
+                "https://example.com/video.mp4".to_string(),
+                0,
+            );
+
+            assert!(matches!(result, Err(LessonError::DurationIsZero)));
+        }
+
         #[test]
         fn test_new_with_zero_duration_returns_error() {
             let result = Lesson::new(
@@ -296,6 +403,23 @@ mod tests {
             assert!(matches!(result, Err(LessonError::NameError(_))));
         }
 
+        #[test]
+        fn test_new_with_http_url_returns_error() {
+            let result = Lesson::new(
+                "Valid Name".to_string(),
+                3600,
+                "http://example.com/video.mp4".to_string(),
+                0,
+            );
+
+            assert_eq!(
+                result.err(),
+                Some(LessonError::VideoUrlError(UrlError::SchemeNotValid(
+                    "http".to_string()
+                )))
+            );
+        }
+
         #[test]
         fn test_new_with_name_at_max_length() {
             let max_name = "A".repeat(50);
@@ -307,13 +431,13 @@ mod tests {
         }
 
         #[test]
-        fn test_with_id_creates_lesson_with_provided_id() {
+        fn test_with_video_url_creates_lesson_with_provided_id() {
             let id = Id::default();
-            let lesson = Lesson::with_id(
+            let lesson = Lesson::with_video_url(
                 id,
                 "Reconstructed Lesson".to_string(),
                 1800,
-                "https://example.com/video.mp4".to_string(),
+                create_url("https://example.com/video.mp4"),
                 0,
             )
             .unwrap();
@@ -324,24 +448,24 @@ mod tests {
         }
 
         #[test]
-        fn test_with_id_preserves_exact_id() {
+        fn test_with_video_url_preserves_exact_id() {
             let id1 = Id::default();
             let id2 = Id::default();
 
-            let lesson1 = Lesson::with_id(
+            let lesson1 = Lesson::with_video_url(
                 id1,
                 "Lesson 1".to_string(),
                 600,
-                "https://example.com/l1.mp4".to_string(),
+                create_url("https://example.com/l1.mp4"),
                 0,
             )
             .unwrap();
 
-            let lesson2 = Lesson::with_id(
+            let lesson2 = Lesson::with_video_url(
                 id2,
                 "Lesson 2".to_string(),
                 600,
-                "https://example.com/l2.mp4".to_string(),
+                create_url("https://example.com/l2.mp4"),
                 1,
             )
             .unwrap();
@@ -352,13 +476,13 @@ mod tests {
         }
 
         #[test]
-        fn test_with_id_validates_name() {
+        fn test_with_video_url_validates_name() {
             let id = Id::default();
-            let result = Lesson::with_id(
+            let result = Lesson::with_video_url(
                 id,
                 "AB".to_string(),
                 1800,
-                "https://example.com/video.mp4".to_string(),
+                create_url("https://example.com/video.mp4"),
                 0,
             );
 
@@ -367,23 +491,32 @@ mod tests {
         }
 
         #[test]
-        fn test_with_id_validates_url() {
+        #[allow(deprecated)]
+        fn test_deprecated_with_id_still_parses_web_urls() {
             let id = Id::default();
-            let result =
+            let lesson = Lesson::with_id(
+                id,
+                "Valid Name".to_string(),
+                1800,
+                "http://legacy.example.com/video.mp4".to_string(),
+                0,
+            )
+            .unwrap();
+            let invalid =
                 Lesson::with_id(id, "Valid Name".to_string(), 1800, "invalid-url".to_string(), 0);
 
-            assert!(result.is_err());
-            assert!(matches!(result, Err(LessonError::VideoUrlError(_))));
+            assert!(!lesson.video_url().is_secure());
+            assert!(matches!(invalid, Err(LessonError::VideoUrlError(_))));
         }
 
         #[test]
-        fn test_with_id_validates_duration() {
+        fn test_with_video_url_validates_duration() {
             let id = Id::default();
-            let result = Lesson::with_id(
+            let result = Lesson::with_video_url(
                 id,
                 "Valid Name".to_string(),
                 0,
-                "https://example.com/video.mp4".to_string(),
+                create_url("https://example.com/video.mp4"),
                 0,
             );
 
@@ -456,4 +589,46 @@ mod tests {
             assert_eq!(lesson.duration().format_hours(), "10m 00s");
         }
     }
+
+    mod auditable {
+        use super::*;
+        use education_platform_common::Locale;
+
+        fn create_test_lesson() -> Lesson {
+            Lesson::new(
+                "Test Lesson".to_string(),
+                1200,
+                "https://example.com/test.mp4".to_string(),
+                0,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_new_lesson_is_unmodified() {
+            let lesson = create_test_lesson();
+
+            assert_eq!(lesson.created_at(), lesson.updated_at());
+        }
+
+        #[test]
+        fn test_update_duration_records_modification() {
+            let mut lesson = create_test_lesson();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            lesson.update_duration(600);
+
+            assert!(lesson.updated_at() > lesson.created_at());
+        }
+
+        #[test]
+        fn test_removing_missing_translation_keeps_timestamps() {
+            let mut lesson = create_test_lesson();
+            let locale = Locale::new("es").unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            assert!(!lesson.remove_localized_name(&locale));
+            assert_eq!(lesson.updated_at(), lesson.created_at());
+        }
+    }
 }
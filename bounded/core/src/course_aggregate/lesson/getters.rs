@@ -1,7 +1,31 @@
-use super::Lesson;
+use super::{Lesson, LessonId};
+use crate::ContentProvenance;
 use education_platform_common::{Duration, Index, SimpleName, Url};
 
 impl Lesson {
+    /// Returns the lesson ID typed as a [`LessonId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Entity;
+    /// use education_platform_core::Lesson;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Ownership".to_string(),
+    ///     900,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(lesson.lesson_id().untyped(), lesson.id());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> LessonId {
+        self.id.cast()
+    }
+
     /// Returns the lesson name.
     ///
     /// # Examples
@@ -24,6 +48,28 @@ impl Lesson {
         &self.name
     }
 
+    /// Returns the lesson description, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Lesson;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// assert!(lesson.description().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
     /// Returns the lesson duration.
     ///
     /// # Examples
@@ -69,6 +115,50 @@ impl Lesson {
         &self.video_url
     }
 
+    /// Returns the URL of the lesson transcript, if one has been attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Lesson;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// assert!(lesson.transcript_url().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn transcript_url(&self) -> Option<&Url> {
+        self.transcript_url.as_ref()
+    }
+
+    /// Returns who produced the lesson content, for AI disclosure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ContentProvenance, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(lesson.provenance(), &ContentProvenance::HumanAuthored);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn provenance(&self) -> &ContentProvenance {
+        &self.provenance
+    }
+
     /// Returns the lesson index (position within the course).
     ///
     /// # Examples
@@ -90,6 +180,28 @@ impl Lesson {
     pub const fn index(&self) -> Index {
         self.index
     }
+
+    /// Returns `true` if learners can watch the lesson without enrolling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Lesson;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// assert!(!lesson.is_preview());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_preview(&self) -> bool {
+        self.is_preview
+    }
 }
 
 #[cfg(test)]
@@ -157,5 +269,12 @@ mod tests {
 
             assert!(lesson.index().is_first());
         }
+
+        #[test]
+        fn test_new_lesson_is_not_a_preview() {
+            let lesson = create_test_lesson("Test Lesson", 1200, 0);
+
+            assert!(!lesson.is_preview());
+        }
     }
 }
@@ -0,0 +1,119 @@
+use super::{Lesson, LessonError};
+use education_platform_common::{Locale, LocalizedText, SimpleName, SimpleNameConfig};
+
+impl Lesson {
+    /// Sets the lesson name shown to learners of a locale.
+    ///
+    /// Translations follow the same rules as the lesson name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LessonError::NameError` if the name validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Lesson;
+    /// use education_platform_common::Locale;
+    ///
+    /// let mut lesson = Lesson::new(
+    ///     "Ownership".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let es = Locale::new("es").unwrap();
+    ///
+    /// lesson.set_localized_name(es.clone(), "Propiedad".to_string()).unwrap();
+    ///
+    /// assert_eq!(lesson.localized_name(&Locale::new("es-PE").unwrap()), "Propiedad");
+    /// assert_eq!(lesson.localized_name(&Locale::new("de").unwrap()), "Ownership");
+    /// assert!(lesson.set_localized_name(es, "P".to_string()).is_err());
+    /// ```
+    pub fn set_localized_name(&mut self, locale: Locale, name: String) -> Result<(), LessonError> {
+        let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        self.localized_names.set(locale, name.as_str())?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes the name translation of a locale, returning true if there was one.
+    pub fn remove_localized_name(&mut self, locale: &Locale) -> bool {
+        let removed = self.localized_names.remove(locale).is_some();
+        if removed {
+            self.timestamps.touch();
+        }
+        removed
+    }
+
+    /// Returns the name for a locale, falling back to the lesson name when
+    /// no translation matches.
+    #[must_use]
+    pub fn localized_name(&self, locale: &Locale) -> &str {
+        self.localized_names
+            .resolve(locale)
+            .unwrap_or(self.name.as_str())
+    }
+
+    /// Returns every name translation.
+    #[inline]
+    #[must_use]
+    pub const fn localized_names(&self) -> &LocalizedText {
+        &self.localized_names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_lesson() -> Lesson {
+        Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/video.mp4".to_string(),
+            0,
+        )
+        .unwrap()
+    }
+
+    fn create_locale(tag: &str) -> Locale {
+        Locale::new(tag).unwrap()
+    }
+
+    mod localized_name {
+        use super::*;
+
+        #[test]
+        fn test_regional_locale_uses_language_translation() {
+            let mut lesson = create_test_lesson();
+            lesson
+                .set_localized_name(create_locale("pt"), "Introdução".to_string())
+                .unwrap();
+
+            assert_eq!(lesson.localized_name(&create_locale("pt-BR")), "Introdução");
+        }
+
+        #[test]
+        fn test_removed_translation_falls_back_to_name() {
+            let mut lesson = create_test_lesson();
+            lesson
+                .set_localized_name(create_locale("fr"), "Présentation".to_string())
+                .unwrap();
+
+            assert!(lesson.remove_localized_name(&create_locale("fr")));
+            assert!(!lesson.remove_localized_name(&create_locale("fr")));
+            assert_eq!(lesson.localized_name(&create_locale("fr")), "Introduction");
+        }
+
+        #[test]
+        fn test_invalid_translation_leaves_lesson_untouched() {
+            let mut lesson = create_test_lesson();
+
+            let result = lesson.set_localized_name(create_locale("es"), "  ".to_string());
+
+            assert!(matches!(result, Err(LessonError::NameError(_))));
+            assert!(lesson.localized_names().is_empty());
+        }
+    }
+}
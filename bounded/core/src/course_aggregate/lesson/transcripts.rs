@@ -0,0 +1,195 @@
+use super::{Lesson, LessonError};
+use crate::Transcript;
+use education_platform_common::Locale;
+
+impl Lesson {
+    /// Attaches a transcript, replacing any other one in the same locale.
+    ///
+    /// The transcript must fit in the lesson video. The check happens here,
+    /// so a later [`Lesson::update_duration`] that shortens the lesson should
+    /// be followed by attaching re-timed transcripts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LessonError::TranscriptError` if a cue ends after the lesson
+    /// does; the lesson keeps its current transcripts in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Locale;
+    /// use education_platform_core::{Lesson, Transcript};
+    ///
+    /// let mut lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     60,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let en = Locale::new("en").unwrap();
+    ///
+    /// let srt = "1\n00:00:01,000 --> 00:00:04,000\nWelcome!\n";
+    /// lesson.set_transcript(Transcript::parse(en.clone(), srt).unwrap()).unwrap();
+    /// assert_eq!(lesson.transcript(&en).unwrap().plain_text(), "Welcome!");
+    ///
+    /// let too_long = "1\n00:00:01,000 --> 00:02:00,000\nStill talking\n";
+    /// assert!(lesson.set_transcript(Transcript::parse(en, too_long).unwrap()).is_err());
+    /// ```
+    pub fn set_transcript(&mut self, transcript: Transcript) -> Result<(), LessonError> {
+        transcript.check_fits(self.duration)?;
+
+        match self
+            .transcripts
+            .iter_mut()
+            .find(|existing| existing.locale() == transcript.locale())
+        {
+            Some(existing) => *existing = transcript,
+            None => self.transcripts.push(transcript),
+        }
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes the transcript of a locale, returning true if there was one.
+    pub fn remove_transcript(&mut self, locale: &Locale) -> bool {
+        let before = self.transcripts.len();
+        self.transcripts
+            .retain(|transcript| transcript.locale() != locale);
+
+        let removed = self.transcripts.len() != before;
+        if removed {
+            self.timestamps.touch();
+        }
+        removed
+    }
+
+    /// Returns the transcript for a locale, falling back to its parent
+    /// locales, so a `pt` transcript serves `pt-BR` learners.
+    #[must_use]
+    pub fn transcript(&self, locale: &Locale) -> Option<&Transcript> {
+        locale.fallback_chain().iter().find_map(|candidate| {
+            self.transcripts
+                .iter()
+                .find(|transcript| transcript.locale() == candidate)
+        })
+    }
+
+    /// Returns every attached transcript, in the order they were added.
+    #[inline]
+    #[must_use]
+    pub fn transcripts(&self) -> &[Transcript] {
+        &self.transcripts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cue, TranscriptError};
+
+    fn create_test_lesson(duration_seconds: u64) -> Lesson {
+        Lesson::new(
+            "Introduction".to_string(),
+            duration_seconds,
+            "https://example.com/video.mp4".to_string(),
+            0,
+        )
+        .unwrap()
+    }
+
+    fn create_transcript(tag: &str, end_ms: u64, text: &str) -> Transcript {
+        let cue = Cue::new(0, end_ms, text.to_string()).unwrap();
+        Transcript::new(Locale::new(tag).unwrap(), vec![cue]).unwrap()
+    }
+
+    #[test]
+    fn test_new_lesson_has_no_transcripts() {
+        assert!(create_test_lesson(60).transcripts().is_empty());
+    }
+
+    #[test]
+    fn test_set_transcript_per_locale() {
+        let mut lesson = create_test_lesson(60);
+
+        lesson
+            .set_transcript(create_transcript("en", 1000, "Hello"))
+            .unwrap();
+        lesson
+            .set_transcript(create_transcript("es", 1000, "Hola"))
+            .unwrap();
+
+        assert_eq!(lesson.transcripts().len(), 2);
+    }
+
+    #[test]
+    fn test_set_transcript_replaces_same_locale() {
+        let mut lesson = create_test_lesson(60);
+        lesson
+            .set_transcript(create_transcript("en", 1000, "Hello"))
+            .unwrap();
+
+        lesson
+            .set_transcript(create_transcript("en", 2000, "Hi"))
+            .unwrap();
+
+        assert_eq!(lesson.transcripts().len(), 1);
+        assert_eq!(lesson.transcripts()[0].plain_text(), "Hi");
+    }
+
+    #[test]
+    fn test_transcript_longer_than_lesson_is_rejected() {
+        let mut lesson = create_test_lesson(10);
+
+        let result = lesson.set_transcript(create_transcript("en", 12_000, "Hello"));
+
+        assert_eq!(
+            result,
+            Err(LessonError::TranscriptError(TranscriptError::ExceedsLessonDuration {
+                end_ms: 12_000,
+                lesson_seconds: 10
+            }))
+        );
+        assert!(lesson.transcripts().is_empty());
+    }
+
+    #[test]
+    fn test_transcript_falls_back_to_parent_locale() {
+        let mut lesson = create_test_lesson(60);
+        lesson
+            .set_transcript(create_transcript("pt", 1000, "Olá"))
+            .unwrap();
+
+        let pt_br = Locale::new("pt-BR").unwrap();
+
+        assert_eq!(lesson.transcript(&pt_br).unwrap().plain_text(), "Olá");
+        assert!(lesson.transcript(&Locale::new("de").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_transcript_prefers_exact_locale() {
+        let mut lesson = create_test_lesson(60);
+        lesson
+            .set_transcript(create_transcript("pt", 1000, "Olá"))
+            .unwrap();
+        lesson
+            .set_transcript(create_transcript("pt-BR", 1000, "Oi"))
+            .unwrap();
+
+        let pt_br = Locale::new("pt-BR").unwrap();
+
+        assert_eq!(lesson.transcript(&pt_br).unwrap().plain_text(), "Oi");
+    }
+
+    #[test]
+    fn test_remove_transcript() {
+        let mut lesson = create_test_lesson(60);
+        let en = Locale::new("en").unwrap();
+        lesson
+            .set_transcript(create_transcript("en", 1000, "Hello"))
+            .unwrap();
+
+        assert!(lesson.remove_transcript(&en));
+        assert!(!lesson.remove_transcript(&en));
+        assert!(lesson.transcripts().is_empty());
+    }
+}
@@ -1,7 +1,10 @@
 use super::{Lesson, LessonError};
-use education_platform_common::{Duration, Index, SimpleName, SimpleNameConfig, Url};
+use crate::ContentProvenance;
+use education_platform_common::{Duration, Index, Rule, Rules, SimpleName, SimpleNameConfig, Url};
 
 impl Lesson {
+    pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
+
     /// Updates the lesson name in place.
     ///
     /// # Errors
@@ -29,6 +32,52 @@ impl Lesson {
     /// ```
     pub fn update_name(&mut self, name: String) -> Result<(), LessonError> {
         self.name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Sets or clears the lesson description shown on preview screens.
+    ///
+    /// The description is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LessonError::DescriptionError` if the description is blank or
+    /// longer than [`Lesson::MAX_DESCRIPTION_LENGTH`] bytes; the current
+    /// description is kept in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Lesson;
+    ///
+    /// let mut lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// lesson
+    ///     .update_description(Some(" Installing the toolchain. ".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(lesson.description(), Some("Installing the toolchain."));
+    ///
+    /// assert!(lesson.update_description(Some("   ".to_string())).is_err());
+    ///
+    /// lesson.update_description(None).unwrap();
+    /// assert!(lesson.description().is_none());
+    /// ```
+    pub fn update_description(&mut self, description: Option<String>) -> Result<(), LessonError> {
+        let rule = Rules::not_empty().and(Rules::max_length(Self::MAX_DESCRIPTION_LENGTH));
+        self.description = description
+            .map(|text| {
+                let trimmed = text.trim();
+                rule.check(trimmed).map(|()| trimmed.to_string())
+            })
+            .transpose()
+            .map_err(LessonError::DescriptionError)?;
+        self.timestamps.touch();
         Ok(())
     }
 
@@ -56,6 +105,7 @@ impl Lesson {
     #[inline]
     pub fn update_duration(&mut self, duration_seconds: u64) {
         self.duration = Duration::from_seconds(duration_seconds);
+        self.timestamps.touch();
     }
 
     /// Updates the lesson video URL in place.
@@ -63,12 +113,19 @@ impl Lesson {
     /// # Errors
     ///
     /// Returns `LessonError::VideoUrlError` if the URL validation fails.
+    #[deprecated(note = "parse the URL with a `UrlPolicy` and use `Lesson::set_video_url`")]
+    pub fn update_video_url(&mut self, video_url: String) -> Result<(), LessonError> {
+        self.set_video_url(Url::new(video_url)?);
+        Ok(())
+    }
+
+    /// Replaces the lesson video URL.
     ///
     /// # Examples
     ///
     /// ```
     /// use education_platform_core::Lesson;
-    /// use education_platform_common::Entity;
+    /// use education_platform_common::{Entity, Url, UrlPolicy};
     ///
     /// let mut lesson = Lesson::new(
     ///     "Introduction".to_string(),
@@ -78,16 +135,117 @@ impl Lesson {
     /// ).unwrap();
     ///
     /// let original_id = lesson.id();
-    /// lesson.update_video_url("https://cdn.example.com/new-video.mp4".to_string()).unwrap();
+    /// let video_url = Url::with_policy(
+    ///     "https://cdn.example.com/new-video.mp4".to_string(),
+    ///     &UrlPolicy::default(),
+    /// ).unwrap();
+    /// lesson.set_video_url(video_url);
     ///
     /// assert_eq!(lesson.video_url().as_str(), "https://cdn.example.com/new-video.mp4");
     /// assert_eq!(lesson.id(), original_id);
     /// ```
-    pub fn update_video_url(&mut self, video_url: String) -> Result<(), LessonError> {
-        self.video_url = Url::new(video_url)?;
+    #[inline]
+    pub fn set_video_url(&mut self, video_url: Url) {
+        self.video_url = video_url;
+        self.timestamps.touch();
+    }
+
+    /// Attaches a transcript to the lesson, or removes it when `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LessonError::TranscriptUrlError` if the URL validation fails;
+    /// the current transcript is kept in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Lesson;
+    ///
+    /// let mut lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// lesson
+    ///     .update_transcript_url(Some("https://example.com/video.vtt".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     lesson.transcript_url().map(|url| url.as_str()),
+    ///     Some("https://example.com/video.vtt")
+    /// );
+    ///
+    /// lesson.update_transcript_url(None).unwrap();
+    /// assert!(lesson.transcript_url().is_none());
+    /// ```
+    pub fn update_transcript_url(
+        &mut self,
+        transcript_url: Option<String>,
+    ) -> Result<(), LessonError> {
+        self.transcript_url = transcript_url
+            .map(Url::new)
+            .transpose()
+            .map_err(LessonError::TranscriptUrlError)?;
+        self.timestamps.touch();
         Ok(())
     }
 
+    /// Declares who produced the lesson content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{AiModel, ContentProvenance, Lesson};
+    ///
+    /// let mut lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// let model = AiModel::new("Mistral Large").unwrap();
+    /// lesson.update_provenance(ContentProvenance::AiAssisted(model));
+    ///
+    /// assert!(lesson.provenance().requires_disclosure());
+    /// ```
+    #[inline]
+    pub fn update_provenance(&mut self, provenance: ContentProvenance) {
+        self.provenance = provenance;
+        self.timestamps.touch();
+    }
+
+    /// Marks the lesson as a free preview, or takes the mark off.
+    ///
+    /// Preview lessons can be watched by learners who have not enrolled,
+    /// so catalogs can show sample content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Lesson;
+    ///
+    /// let mut lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// lesson.set_preview(true);
+    ///
+    /// assert!(lesson.is_preview());
+    /// ```
+    #[inline]
+    pub fn set_preview(&mut self, is_preview: bool) {
+        if self.is_preview != is_preview {
+            self.is_preview = is_preview;
+            self.timestamps.touch();
+        }
+    }
+
     /// Updates the lesson index in place.
     ///
     /// # Examples
@@ -111,7 +269,10 @@ impl Lesson {
     /// ```
     #[inline]
     pub fn update_index(&mut self, index: usize) {
-        self.index = Index::new(index);
+        if self.index.value() != index {
+            self.index = Index::new(index);
+            self.timestamps.touch();
+        }
     }
 }
 
@@ -213,50 +374,86 @@ mod tests {
         }
     }
 
-    mod update_video_url {
+    mod set_video_url {
         use super::*;
 
         #[test]
-        fn test_update_video_url_modifies_url() {
+        fn test_set_video_url_modifies_url() {
             let mut lesson = create_test_lesson("Lesson", 1800, 0);
 
-            lesson
-                .update_video_url("https://example.com/new.mp4".to_string())
-                .unwrap();
+            lesson.set_video_url(Url::new("https://example.com/new.mp4".to_string()).unwrap());
 
             assert_eq!(lesson.video_url().as_str(), "https://example.com/new.mp4");
         }
 
         #[test]
-        fn test_update_video_url_preserves_id() {
+        fn test_set_video_url_preserves_id() {
             let mut lesson = create_test_lesson("Lesson", 1800, 0);
             let original_id = lesson.id();
 
-            lesson
-                .update_video_url("https://example.com/new.mp4".to_string())
-                .unwrap();
+            lesson.set_video_url(Url::new("https://example.com/new.mp4".to_string()).unwrap());
 
             assert_eq!(lesson.id(), original_id);
         }
 
         #[test]
-        fn test_update_video_url_invalid_returns_error() {
+        #[allow(deprecated)]
+        fn test_deprecated_update_video_url_keeps_original_on_error() {
             let mut lesson = create_test_lesson("Lesson", 1800, 0);
+            let original_url = lesson.video_url().as_str().to_string();
 
             let result = lesson.update_video_url("not-a-url".to_string());
 
-            assert!(result.is_err());
             assert!(matches!(result, Err(LessonError::VideoUrlError(_))));
+            assert_eq!(lesson.video_url().as_str(), original_url);
         }
+    }
+
+    mod update_transcript_url {
+        use super::*;
 
         #[test]
-        fn test_update_video_url_invalid_preserves_original() {
+        fn test_update_transcript_url_attaches_transcript() {
             let mut lesson = create_test_lesson("Lesson", 1800, 0);
-            let original_url = lesson.video_url().as_str().to_string();
 
-            let _ = lesson.update_video_url("not-a-url".to_string());
+            lesson
+                .update_transcript_url(Some("https://example.com/0.vtt".to_string()))
+                .unwrap();
+
+            assert_eq!(
+                lesson.transcript_url().map(Url::as_str),
+                Some("https://example.com/0.vtt")
+            );
+        }
 
-            assert_eq!(lesson.video_url().as_str(), original_url);
+        #[test]
+        fn test_update_transcript_url_invalid_preserves_original() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+            lesson
+                .update_transcript_url(Some("https://example.com/0.vtt".to_string()))
+                .unwrap();
+
+            let result = lesson.update_transcript_url(Some("not-a-url".to_string()));
+
+            assert!(matches!(result, Err(LessonError::TranscriptUrlError(_))));
+            assert!(lesson.transcript_url().is_some());
+        }
+    }
+
+    mod update_provenance {
+        use super::*;
+        use crate::AiModel;
+
+        #[test]
+        fn test_update_provenance_preserves_id() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+            let original_id = lesson.id();
+            let provenance = ContentProvenance::AiGenerated(AiModel::new("Mistral Large").unwrap());
+
+            lesson.update_provenance(provenance.clone());
+
+            assert_eq!(lesson.provenance(), &provenance);
+            assert_eq!(lesson.id(), original_id);
         }
     }
 
@@ -291,4 +488,91 @@ mod tests {
             assert_eq!(lesson.name().as_str(), "My Lesson");
         }
     }
+
+    mod update_description {
+        use super::*;
+        use education_platform_common::ValidatorError;
+
+        #[test]
+        fn test_sets_trimmed_description() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+
+            lesson
+                .update_description(Some("  Ownership in practice.\n".to_string()))
+                .unwrap();
+
+            assert_eq!(lesson.description(), Some("Ownership in practice."));
+        }
+
+        #[test]
+        fn test_none_clears_description() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+            lesson
+                .update_description(Some("Something".to_string()))
+                .unwrap();
+
+            lesson.update_description(None).unwrap();
+
+            assert!(lesson.description().is_none());
+        }
+
+        #[test]
+        fn test_blank_description_is_rejected() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+
+            let result = lesson.update_description(Some("  ".to_string()));
+
+            assert_eq!(result, Err(LessonError::DescriptionError(ValidatorError::EmptyValue)));
+        }
+
+        #[test]
+        fn test_too_long_description_keeps_previous() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+            lesson
+                .update_description(Some("Short".to_string()))
+                .unwrap();
+
+            let result =
+                lesson.update_description(Some("x".repeat(Lesson::MAX_DESCRIPTION_LENGTH + 1)));
+
+            assert!(matches!(result, Err(LessonError::DescriptionError(_))));
+            assert_eq!(lesson.description(), Some("Short"));
+        }
+
+        #[test]
+        fn test_at_max_length_is_accepted() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+
+            let result =
+                lesson.update_description(Some("x".repeat(Lesson::MAX_DESCRIPTION_LENGTH)));
+
+            assert!(result.is_ok());
+        }
+    }
+
+    mod set_preview {
+        use super::*;
+        use education_platform_common::Auditable;
+
+        #[test]
+        fn test_toggles_preview() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+
+            lesson.set_preview(true);
+            assert!(lesson.is_preview());
+
+            lesson.set_preview(false);
+            assert!(!lesson.is_preview());
+        }
+
+        #[test]
+        fn test_same_value_does_not_touch() {
+            let mut lesson = create_test_lesson("Lesson", 1800, 0);
+            let before = lesson.updated_at();
+
+            lesson.set_preview(false);
+
+            assert_eq!(lesson.updated_at(), before);
+        }
+    }
 }
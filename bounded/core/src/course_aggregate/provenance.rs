@@ -0,0 +1,202 @@
+use education_platform_common::{Validator, ValidatorError};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for AiModel validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProvenanceError {
+    #[error("AI model attribution validation failed: {0}")]
+    ModelError(#[from] ValidatorError),
+}
+
+/// The name of the AI model some content is attributed to, such as `"Llama 3 70B"`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::AiModel;
+///
+/// let model = AiModel::new("  Llama 3 70B ").unwrap();
+/// assert_eq!(model.name(), "Llama 3 70B");
+///
+/// assert!(AiModel::new("   ").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AiModel(String);
+
+impl AiModel {
+    pub const MAX_LENGTH: usize = 100;
+
+    /// Creates a new `AiModel` from its name, trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProvenanceError::ModelError` if the name is blank or longer
+    /// than [`AiModel::MAX_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::AiModel;
+    ///
+    /// assert!(AiModel::new("Mistral Large").is_ok());
+    /// assert!(AiModel::new(&"m".repeat(101)).is_err());
+    /// ```
+    pub fn new(name: &str) -> Result<Self, ProvenanceError> {
+        let name = name.trim();
+        Validator::is_not_empty(name)?;
+        Validator::has_max_length(name, Self::MAX_LENGTH)?;
+        Ok(Self(name.to_string()))
+    }
+
+    /// Returns the model name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::AiModel;
+    ///
+    /// assert_eq!(AiModel::new("Mistral Large").unwrap().name(), "Mistral Large");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for AiModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Who produced a piece of course content, disclosed to learners.
+///
+/// Content involving AI always carries the model it is attributed to, so a
+/// disclosure never reads "AI-generated" without saying by what.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{AiModel, ContentProvenance};
+///
+/// let provenance = ContentProvenance::AiGenerated(AiModel::new("Llama 3 70B").unwrap());
+///
+/// assert!(provenance.requires_disclosure());
+/// assert_eq!(provenance.to_string(), "AI-generated (Llama 3 70B)");
+///
+/// assert!(!ContentProvenance::HumanAuthored.requires_disclosure());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum ContentProvenance {
+    /// Written and produced by people.
+    #[default]
+    HumanAuthored,
+    /// Written by people with help from an AI model.
+    AiAssisted(AiModel),
+    /// Produced mainly by an AI model.
+    AiGenerated(AiModel),
+}
+
+impl ContentProvenance {
+    /// Returns the attributed AI model, if AI was involved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{AiModel, ContentProvenance};
+    ///
+    /// let model = AiModel::new("Mistral Large").unwrap();
+    ///
+    /// assert_eq!(ContentProvenance::AiAssisted(model.clone()).model(), Some(&model));
+    /// assert_eq!(ContentProvenance::HumanAuthored.model(), None);
+    /// ```
+    #[must_use]
+    pub const fn model(&self) -> Option<&AiModel> {
+        match self {
+            Self::HumanAuthored => None,
+            Self::AiAssisted(model) | Self::AiGenerated(model) => Some(model),
+        }
+    }
+
+    /// Returns true if learners must be told AI was involved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{AiModel, ContentProvenance};
+    ///
+    /// let model = AiModel::new("Mistral Large").unwrap();
+    /// assert!(ContentProvenance::AiAssisted(model).requires_disclosure());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn requires_disclosure(&self) -> bool {
+        !matches!(self, Self::HumanAuthored)
+    }
+}
+
+impl fmt::Display for ContentProvenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HumanAuthored => write!(f, "Human-authored"),
+            Self::AiAssisted(model) => write!(f, "AI-assisted ({model})"),
+            Self::AiGenerated(model) => write!(f, "AI-generated ({model})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_model(name: &str) -> AiModel {
+        AiModel::new(name).unwrap()
+    }
+
+    mod ai_model {
+        use super::*;
+
+        #[test]
+        fn test_new_trims_name() {
+            assert_eq!(create_model("  Mistral Large ").name(), "Mistral Large");
+        }
+
+        #[test]
+        fn test_blank_name_is_rejected() {
+            assert!(matches!(AiModel::new(""), Err(ProvenanceError::ModelError(_))));
+        }
+
+        #[test]
+        fn test_name_at_max_length_is_accepted() {
+            assert!(AiModel::new(&"m".repeat(AiModel::MAX_LENGTH)).is_ok());
+        }
+    }
+
+    mod content_provenance {
+        use super::*;
+
+        #[test]
+        fn test_default_is_human_authored() {
+            assert_eq!(ContentProvenance::default(), ContentProvenance::HumanAuthored);
+        }
+
+        #[test]
+        fn test_ai_generated_requires_disclosure() {
+            let provenance = ContentProvenance::AiGenerated(create_model("Mistral Large"));
+
+            assert!(provenance.requires_disclosure());
+            assert_eq!(provenance.model(), Some(&create_model("Mistral Large")));
+        }
+
+        #[test]
+        fn test_display_names_model() {
+            let provenance = ContentProvenance::AiAssisted(create_model("Mistral Large"));
+
+            assert_eq!(provenance.to_string(), "AI-assisted (Mistral Large)");
+            assert_eq!(ContentProvenance::HumanAuthored.to_string(), "Human-authored");
+        }
+    }
+}
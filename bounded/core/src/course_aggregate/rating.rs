@@ -0,0 +1,127 @@
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Rating validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RatingError {
+    #[error("Rating must be between {min} and {max} stars, got {value}")]
+    OutOfRange { value: u8, min: u8, max: u8 },
+}
+
+/// A star rating from one to five.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Rating;
+///
+/// let rating = Rating::new(4).unwrap();
+/// assert_eq!(rating.stars(), 4);
+/// assert_eq!(rating.to_string(), "4/5");
+///
+/// assert!(Rating::new(0).is_err());
+/// assert!(Rating::new(6).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rating(u8);
+
+impl Rating {
+    pub const MIN: u8 = 1;
+    pub const MAX: u8 = 5;
+
+    /// Creates a new `Rating` with validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RatingError::OutOfRange` if `stars` is not between
+    /// [`Rating::MIN`] and [`Rating::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Rating;
+    ///
+    /// assert!(Rating::new(1).is_ok());
+    /// assert!(Rating::new(5).is_ok());
+    /// assert!(Rating::new(9).is_err());
+    /// ```
+    pub fn new(stars: u8) -> Result<Self, RatingError> {
+        match (Self::MIN..=Self::MAX).contains(&stars) {
+            true => Ok(Self(stars)),
+            false => Err(RatingError::OutOfRange {
+                value: stars,
+                min: Self::MIN,
+                max: Self::MAX,
+            }),
+        }
+    }
+
+    /// Returns the number of stars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Rating;
+    ///
+    /// assert_eq!(Rating::new(3).unwrap().stars(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn stars(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Rating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.0, Self::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_accepts_every_star_count_in_range() {
+        for stars in Rating::MIN..=Rating::MAX {
+            assert_eq!(Rating::new(stars).unwrap().stars(), stars);
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_zero() {
+        assert_eq!(
+            Rating::new(0).unwrap_err(),
+            RatingError::OutOfRange {
+                value: 0,
+                min: 1,
+                max: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_new_rejects_above_max() {
+        assert!(Rating::new(6).is_err());
+    }
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            Rating::new(7).unwrap_err().to_string(),
+            "Rating must be between 1 and 5 stars, got 7"
+        );
+    }
+
+    #[test]
+    fn test_ratings_are_ordered_by_stars() {
+        assert!(Rating::new(2).unwrap() < Rating::new(4).unwrap());
+    }
+
+    #[test]
+    fn test_display_shows_out_of_max() {
+        assert_eq!(Rating::new(5).unwrap().to_string(), "5/5");
+    }
+}
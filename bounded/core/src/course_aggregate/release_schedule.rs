@@ -0,0 +1,116 @@
+use education_platform_common::Date;
+
+/// When the chapters of a course become available to a learner.
+///
+/// Delays and dates are listed by chapter position: the first entry applies
+/// to the first chapter, and so on. Chapters past the end of the list follow
+/// the last entry, so a schedule only needs to list the chapters that unlock
+/// at different times. An empty list releases everything at once.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::ReleaseSchedule;
+/// use education_platform_common::Date;
+///
+/// let schedule = ReleaseSchedule::DaysAfterEnrollment(vec![0, 7, 14]);
+/// let enrolled_on = Date::new(2025, 3, 3).unwrap();
+///
+/// assert_eq!(schedule.release_date(1, enrolled_on), Date::new(2025, 3, 10).unwrap());
+/// assert_eq!(schedule.release_date(5, enrolled_on), Date::new(2025, 3, 17).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ReleaseSchedule {
+    /// Every chapter is available from enrollment.
+    #[default]
+    AllAtOnce,
+    /// Each chapter unlocks a number of days after the learner enrolled.
+    DaysAfterEnrollment(Vec<u32>),
+    /// Each chapter unlocks on a calendar date, for every learner.
+    FixedDates(Vec<Date>),
+}
+
+impl ReleaseSchedule {
+    /// Returns the first day the chapter at `position` is available to a
+    /// learner who enrolled on `enrolled_on`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::ReleaseSchedule;
+    /// use education_platform_common::Date;
+    ///
+    /// let launch = Date::new(2025, 4, 1).unwrap();
+    /// let schedule = ReleaseSchedule::FixedDates(vec![launch]);
+    /// let enrolled_on = Date::new(2025, 3, 3).unwrap();
+    ///
+    /// assert_eq!(schedule.release_date(0, enrolled_on), launch);
+    /// assert_eq!(ReleaseSchedule::AllAtOnce.release_date(3, enrolled_on), enrolled_on);
+    /// ```
+    #[must_use]
+    pub fn release_date(&self, position: usize, enrolled_on: Date) -> Date {
+        match self {
+            Self::AllAtOnce => enrolled_on,
+            Self::DaysAfterEnrollment(delays) => Self::entry_for(delays, position)
+                .map_or(enrolled_on, |days| enrolled_on.add_days(i64::from(*days))),
+            Self::FixedDates(dates) => Self::entry_for(dates, position)
+                .copied()
+                .unwrap_or(enrolled_on),
+        }
+    }
+
+    /// Returns whether the chapter at `position` is available on `today`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::ReleaseSchedule;
+    /// use education_platform_common::Date;
+    ///
+    /// let schedule = ReleaseSchedule::DaysAfterEnrollment(vec![0, 7]);
+    /// let enrolled_on = Date::new(2025, 3, 3).unwrap();
+    ///
+    /// assert!(!schedule.is_released(1, enrolled_on, Date::new(2025, 3, 9).unwrap()));
+    /// assert!(schedule.is_released(1, enrolled_on, Date::new(2025, 3, 10).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn is_released(&self, position: usize, enrolled_on: Date, today: Date) -> bool {
+        self.release_date(position, enrolled_on) <= today
+    }
+
+    fn entry_for<T>(entries: &[T], position: usize) -> Option<&T> {
+        entries.get(position).or_else(|| entries.last())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_date(day: u32) -> Date {
+        Date::new(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_all_at_once_is_released_on_enrollment() {
+        let schedule = ReleaseSchedule::AllAtOnce;
+
+        assert!(schedule.is_released(10, create_date(3), create_date(3)));
+    }
+
+    #[test]
+    fn test_empty_delays_release_everything_at_once() {
+        let schedule = ReleaseSchedule::DaysAfterEnrollment(vec![]);
+
+        assert_eq!(schedule.release_date(2, create_date(3)), create_date(3));
+    }
+
+    #[test]
+    fn test_fixed_dates_ignore_enrollment() {
+        let schedule = ReleaseSchedule::FixedDates(vec![create_date(1), create_date(15)]);
+
+        assert_eq!(schedule.release_date(1, create_date(3)), create_date(15));
+        assert_eq!(schedule.release_date(1, create_date(20)), create_date(15));
+        assert!(schedule.is_released(0, create_date(20), create_date(20)));
+    }
+}
@@ -0,0 +1,492 @@
+use crate::{Rating, RatingError};
+use education_platform_common::{Date, Email, EmailError, Entity, Id, Validator, ValidatorError};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Review validation and moderation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReviewError {
+    #[error("Review author email is not valid: {0}")]
+    AuthorEmailError(#[from] EmailError),
+
+    #[error("Review rating is not valid: {0}")]
+    RatingError(#[from] RatingError),
+
+    #[error("Review comment validation failed: {0}")]
+    CommentError(#[from] ValidatorError),
+
+    #[error("Review is already {0}")]
+    AlreadyModerated(ModerationStatus),
+}
+
+/// Where a review stands in moderation.
+///
+/// Reviews start out `Pending` and only count towards a course's rating
+/// once `Approved`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::ModerationStatus;
+///
+/// assert_eq!(ModerationStatus::default(), ModerationStatus::Pending);
+/// assert_eq!(ModerationStatus::Approved.to_string(), "approved");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ModerationStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl fmt::Display for ModerationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Rejected => "rejected",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// A learner's rating and opinion of a course.
+///
+/// `Review` is an entity that belongs to a `Course` aggregate. The comment
+/// is trimmed and may not be empty.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{ModerationStatus, Review};
+///
+/// let mut review = Review::new(
+///     "learner@example.com".to_string(),
+///     5,
+///     "Clear explanations and great exercises".to_string(),
+/// ).unwrap();
+///
+/// assert_eq!(review.rating().stars(), 5);
+/// assert_eq!(review.status(), ModerationStatus::Pending);
+///
+/// review.approve().unwrap();
+/// assert!(review.is_approved());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Review {
+    id: Id,
+    author_email: Email,
+    rating: Rating,
+    comment: String,
+    date: Date,
+    status: ModerationStatus,
+}
+
+impl Review {
+    pub const MAX_COMMENT_LENGTH: usize = 2000;
+
+    /// Creates a new pending review dated today.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReviewError::AuthorEmailError` if the author email is not valid.
+    /// Returns `ReviewError::RatingError` if the rating is not between 1 and 5.
+    /// Returns `ReviewError::CommentError` if the comment is empty or longer than
+    /// [`Review::MAX_COMMENT_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Review;
+    ///
+    /// let review = Review::new("learner@example.com".to_string(), 4, " Good ".to_string())
+    ///     .unwrap();
+    /// assert_eq!(review.comment(), "Good");
+    ///
+    /// assert!(Review::new("learner@example.com".to_string(), 0, "Bad".to_string()).is_err());
+    /// ```
+    pub fn new(author_email: String, rating: u8, comment: String) -> Result<Self, ReviewError> {
+        Self::with_id(
+            Id::default(),
+            author_email,
+            rating,
+            comment,
+            Date::today(),
+            ModerationStatus::Pending,
+        )
+    }
+
+    /// Creates a `Review` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReviewError::AuthorEmailError` if the author email is not valid.
+    /// Returns `ReviewError::RatingError` if the rating is not between 1 and 5.
+    /// Returns `ReviewError::CommentError` if the comment is empty or too long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ModerationStatus, Review};
+    /// use education_platform_common::{Date, Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let review = Review::with_id(
+    ///     id,
+    ///     "learner@example.com".to_string(),
+    ///     3,
+    ///     "Decent".to_string(),
+    ///     Date::new(2024, 6, 1).unwrap(),
+    ///     ModerationStatus::Approved,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(review.id(), id);
+    /// assert!(review.is_approved());
+    /// ```
+    pub fn with_id(
+        id: Id,
+        author_email: String,
+        rating: u8,
+        comment: String,
+        date: Date,
+        status: ModerationStatus,
+    ) -> Result<Self, ReviewError> {
+        let author_email = Email::new(author_email)?;
+        let rating = Rating::new(rating)?;
+        let comment = Self::validate_comment(&comment)?;
+
+        Ok(Self {
+            id,
+            author_email,
+            rating,
+            comment,
+            date,
+            status,
+        })
+    }
+
+    /// Returns the email of the learner who wrote the review.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Review;
+    ///
+    /// let review = Review::new("learner@example.com".to_string(), 4, "Good".to_string())
+    ///     .unwrap();
+    /// assert_eq!(review.author_email().address(), "learner@example.com");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn author_email(&self) -> &Email {
+        &self.author_email
+    }
+
+    /// Returns the star rating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Review;
+    ///
+    /// let review = Review::new("learner@example.com".to_string(), 4, "Good".to_string())
+    ///     .unwrap();
+    /// assert_eq!(review.rating().stars(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rating(&self) -> Rating {
+        self.rating
+    }
+
+    /// Returns the review comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Review;
+    ///
+    /// let review = Review::new("learner@example.com".to_string(), 4, "Good".to_string())
+    ///     .unwrap();
+    /// assert_eq!(review.comment(), "Good");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    /// Returns the date the review was written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Review;
+    /// use education_platform_common::Date;
+    ///
+    /// let review = Review::new("learner@example.com".to_string(), 4, "Good".to_string())
+    ///     .unwrap();
+    /// assert_eq!(review.date(), Date::today());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Returns the moderation status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ModerationStatus, Review};
+    ///
+    /// let review = Review::new("learner@example.com".to_string(), 4, "Good".to_string())
+    ///     .unwrap();
+    /// assert_eq!(review.status(), ModerationStatus::Pending);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> ModerationStatus {
+        self.status
+    }
+
+    /// Returns true if the review passed moderation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Review;
+    ///
+    /// let review = Review::new("learner@example.com".to_string(), 4, "Good".to_string())
+    ///     .unwrap();
+    /// assert!(!review.is_approved());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_approved(&self) -> bool {
+        self.status == ModerationStatus::Approved
+    }
+
+    /// Publishes the review.
+    ///
+    /// Rejected reviews can be approved later, for example after an appeal.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReviewError::AlreadyModerated` if the review is already approved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Review;
+    ///
+    /// let mut review = Review::new("learner@example.com".to_string(), 4, "Good".to_string())
+    ///     .unwrap();
+    ///
+    /// review.approve().unwrap();
+    /// assert!(review.approve().is_err());
+    /// ```
+    pub fn approve(&mut self) -> Result<(), ReviewError> {
+        self.moderate(ModerationStatus::Approved)
+    }
+
+    /// Hides the review.
+    ///
+    /// Approved reviews can be rejected later, for example after a report.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReviewError::AlreadyModerated` if the review is already rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ModerationStatus, Review};
+    ///
+    /// let mut review = Review::new("learner@example.com".to_string(), 1, "Spam".to_string())
+    ///     .unwrap();
+    ///
+    /// review.reject().unwrap();
+    /// assert_eq!(review.status(), ModerationStatus::Rejected);
+    /// ```
+    pub fn reject(&mut self) -> Result<(), ReviewError> {
+        self.moderate(ModerationStatus::Rejected)
+    }
+
+    fn moderate(&mut self, status: ModerationStatus) -> Result<(), ReviewError> {
+        if self.status == status {
+            return Err(ReviewError::AlreadyModerated(status));
+        }
+
+        self.status = status;
+        Ok(())
+    }
+
+    fn validate_comment(comment: &str) -> Result<String, ReviewError> {
+        let trimmed = comment.trim();
+        Validator::is_not_empty(trimmed)?;
+        Validator::has_max_length(trimmed, Self::MAX_COMMENT_LENGTH)?;
+        Ok(trimmed.to_string())
+    }
+}
+
+impl Entity for Review {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_review(stars: u8) -> Review {
+        Review::new(
+            "learner@example.com".to_string(),
+            stars,
+            "Well structured course".to_string(),
+        )
+        .unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_starts_pending_and_dated_today() {
+            let review = create_test_review(4);
+
+            assert_eq!(review.status(), ModerationStatus::Pending);
+            assert_eq!(review.date(), Date::today());
+        }
+
+        #[test]
+        fn test_new_trims_comment() {
+            let review =
+                Review::new("learner@example.com".to_string(), 4, "  Nice  ".to_string()).unwrap();
+
+            assert_eq!(review.comment(), "Nice");
+        }
+
+        #[test]
+        fn test_new_rejects_invalid_email() {
+            let result = Review::new("invalid".to_string(), 4, "Nice".to_string());
+
+            assert!(matches!(result, Err(ReviewError::AuthorEmailError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_out_of_range_rating() {
+            let result = Review::new("learner@example.com".to_string(), 6, "Nice".to_string());
+
+            assert!(matches!(result, Err(ReviewError::RatingError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_blank_comment() {
+            let result = Review::new("learner@example.com".to_string(), 4, "   ".to_string());
+
+            assert!(matches!(result, Err(ReviewError::CommentError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_too_long_comment() {
+            let comment = "a".repeat(Review::MAX_COMMENT_LENGTH + 1);
+
+            let result = Review::new("learner@example.com".to_string(), 4, comment);
+
+            assert!(matches!(result, Err(ReviewError::CommentError(_))));
+        }
+
+        #[test]
+        fn test_new_accepts_comment_at_max_length() {
+            let comment = "a".repeat(Review::MAX_COMMENT_LENGTH);
+
+            assert!(Review::new("learner@example.com".to_string(), 4, comment).is_ok());
+        }
+
+        #[test]
+        fn test_with_id_restores_status_and_date() {
+            let id = Id::new();
+            let date = Date::new(2024, 6, 1).unwrap();
+
+            let review = Review::with_id(
+                id,
+                "learner@example.com".to_string(),
+                2,
+                "Too fast".to_string(),
+                date,
+                ModerationStatus::Rejected,
+            )
+            .unwrap();
+
+            assert_eq!(review.id(), id);
+            assert_eq!(review.date(), date);
+            assert_eq!(review.status(), ModerationStatus::Rejected);
+        }
+    }
+
+    mod moderation {
+        use super::*;
+
+        #[test]
+        fn test_pending_can_be_approved() {
+            let mut review = create_test_review(4);
+
+            review.approve().unwrap();
+
+            assert_eq!(review.status(), ModerationStatus::Approved);
+        }
+
+        #[test]
+        fn test_pending_can_be_rejected() {
+            let mut review = create_test_review(4);
+
+            review.reject().unwrap();
+
+            assert_eq!(review.status(), ModerationStatus::Rejected);
+        }
+
+        #[test]
+        fn test_approved_can_be_rejected() {
+            let mut review = create_test_review(4);
+            review.approve().unwrap();
+
+            review.reject().unwrap();
+
+            assert!(!review.is_approved());
+        }
+
+        #[test]
+        fn test_rejected_can_be_approved() {
+            let mut review = create_test_review(4);
+            review.reject().unwrap();
+
+            review.approve().unwrap();
+
+            assert!(review.is_approved());
+        }
+
+        #[test]
+        fn test_approving_twice_returns_error() {
+            let mut review = create_test_review(4);
+            review.approve().unwrap();
+
+            assert_eq!(
+                review.approve().unwrap_err(),
+                ReviewError::AlreadyModerated(ModerationStatus::Approved)
+            );
+        }
+
+        #[test]
+        fn test_rejecting_twice_returns_error() {
+            let mut review = create_test_review(4);
+            review.reject().unwrap();
+
+            assert_eq!(review.reject().unwrap_err().to_string(), "Review is already rejected");
+        }
+    }
+}
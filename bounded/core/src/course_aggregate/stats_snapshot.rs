@@ -0,0 +1,415 @@
+use crate::{Course, CourseProgress};
+use education_platform_common::{DateTime, Entity, Id, Percentage, Score};
+use std::fmt;
+
+/// Why a course's statistics were frozen.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::SnapshotReason;
+///
+/// assert_eq!(SnapshotReason::Superseded.to_string(), "superseded");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapshotReason {
+    /// A newer version of the course replaced this one.
+    Superseded,
+    /// The course was withdrawn from the catalog.
+    Archived,
+}
+
+impl fmt::Display for SnapshotReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Superseded => "superseded",
+            Self::Archived => "archived",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// The analytics of a course frozen at the moment it left the catalog.
+///
+/// A snapshot copies every figure it reports, so it stays valid after the
+/// course, its reviews or its learners' progress are deleted, and it is
+/// never touched when projections are rebuilt from events.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     Chapter, Course, CourseEnded, CourseProgress, CourseStatsSnapshot, Lesson,
+///     LessonProgress, SnapshotReason,
+/// };
+/// use education_platform_common::{DateTime, DomainEventDispatcher, Entity, Percentage};
+/// use std::sync::Arc;
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     600,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Basics".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// let progress = CourseProgress::builder()
+///     .course_id(course.id())
+///     .course_name("Rust Programming")
+///     .user_email("ana@example.com")
+///     .lessons(vec![LessonProgress::new("Introduction".to_string(), 600, None, None).unwrap()])
+///     .event_dispatcher(Arc::new(DomainEventDispatcher::<CourseEnded>::new()))
+///     .build()
+///     .unwrap();
+///
+/// let taken_at = DateTime::new(2025, 1, 31, 18, 0, 0).unwrap();
+/// let snapshot =
+///     CourseStatsSnapshot::capture(&course, &[progress], SnapshotReason::Archived, taken_at);
+///
+/// assert_eq!(snapshot.enrollments(), 1);
+/// assert_eq!(snapshot.completion_rate(), Some(Percentage::ZERO));
+/// assert_eq!(snapshot.average_rating(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CourseStatsSnapshot {
+    course_id: Id,
+    course_name: String,
+    reason: SnapshotReason,
+    taken_at: DateTime,
+    enrollments: u32,
+    completions: u32,
+    average_rating: Option<Score>,
+    review_count: u32,
+}
+
+impl CourseStatsSnapshot {
+    /// Freezes the statistics of a course.
+    ///
+    /// Enrollments are the progress records belonging to the course;
+    /// records of other courses, or with no course ID, are ignored. Ratings
+    /// come from approved reviews only, as on the course page.
+    #[must_use]
+    pub fn capture(
+        course: &Course,
+        progresses: &[CourseProgress],
+        reason: SnapshotReason,
+        taken_at: DateTime,
+    ) -> Self {
+        let (enrollments, completions) = progresses
+            .iter()
+            .filter(|progress| progress.course_id() == Some(course.id()))
+            .fold((0u32, 0u32), |(enrollments, completions), progress| {
+                (enrollments + 1, completions + u32::from(progress.is_completed()))
+            });
+
+        Self {
+            course_id: course.id(),
+            course_name: course.name().to_string(),
+            reason,
+            taken_at,
+            enrollments,
+            completions,
+            average_rating: course.average_rating(),
+            review_count: u32::try_from(course.approved_reviews().count()).unwrap_or(u32::MAX),
+        }
+    }
+
+    /// Returns the ID of the course the snapshot describes.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns the course name at the time of the snapshot.
+    #[inline]
+    #[must_use]
+    pub fn course_name(&self) -> &str {
+        &self.course_name
+    }
+
+    /// Returns why the snapshot was taken.
+    #[inline]
+    #[must_use]
+    pub const fn reason(&self) -> SnapshotReason {
+        self.reason
+    }
+
+    /// Returns when the snapshot was taken.
+    #[inline]
+    #[must_use]
+    pub const fn taken_at(&self) -> DateTime {
+        self.taken_at
+    }
+
+    /// Returns the number of learners enrolled.
+    #[inline]
+    #[must_use]
+    pub const fn enrollments(&self) -> u32 {
+        self.enrollments
+    }
+
+    /// Returns the number of learners who completed the course.
+    #[inline]
+    #[must_use]
+    pub const fn completions(&self) -> u32 {
+        self.completions
+    }
+
+    /// Returns the share of enrolled learners who completed the course, or
+    /// `None` if nobody enrolled.
+    #[must_use]
+    pub fn completion_rate(&self) -> Option<Percentage> {
+        match self.enrollments {
+            0 => None,
+            enrollments => Some(Percentage::from_fraction(
+                u64::from(self.completions),
+                u64::from(enrollments),
+            )),
+        }
+    }
+
+    /// Returns the average star rating of approved reviews, if any.
+    #[inline]
+    #[must_use]
+    pub const fn average_rating(&self) -> Option<Score> {
+        self.average_rating
+    }
+
+    /// Returns the number of approved reviews the rating is based on.
+    #[inline]
+    #[must_use]
+    pub const fn review_count(&self) -> u32 {
+        self.review_count
+    }
+}
+
+/// Append-only record of course statistics snapshots, for historical reporting.
+///
+/// Snapshots can only be added, never changed or removed, and the history
+/// is deliberately not a `Projection`: rebuilding projections from events
+/// leaves it as it is.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     Chapter, Course, CourseStatsHistory, CourseStatsSnapshot, Lesson, SnapshotReason,
+/// };
+/// use education_platform_common::{DateTime, Entity};
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     600,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Basics".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+/// let taken_at = DateTime::new(2025, 1, 31, 18, 0, 0).unwrap();
+///
+/// let mut history = CourseStatsHistory::new();
+/// history.record(CourseStatsSnapshot::capture(&course, &[], SnapshotReason::Superseded, taken_at));
+///
+/// assert_eq!(history.snapshots_of(course.id()).count(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CourseStatsHistory {
+    snapshots: Vec<CourseStatsSnapshot>,
+}
+
+impl CourseStatsHistory {
+    /// Creates an empty history.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a snapshot.
+    pub fn record(&mut self, snapshot: CourseStatsSnapshot) {
+        self.snapshots.push(snapshot);
+    }
+
+    /// Returns every snapshot of a course, oldest first.
+    pub fn snapshots_of(&self, course_id: Id) -> impl Iterator<Item = &CourseStatsSnapshot> {
+        self.snapshots
+            .iter()
+            .filter(move |snapshot| snapshot.course_id() == course_id)
+    }
+
+    /// Returns the most recent snapshot of a course.
+    #[must_use]
+    pub fn latest_of(&self, course_id: Id) -> Option<&CourseStatsSnapshot> {
+        self.snapshots_of(course_id)
+            .max_by_key(|snapshot| snapshot.taken_at())
+    }
+
+    /// Returns the number of snapshots recorded.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns true if no snapshot was recorded.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, CourseEnded, Lesson, LessonProgress, Review};
+    use education_platform_common::DomainEventDispatcher;
+    use std::sync::Arc;
+
+    fn create_test_course() -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            600,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new("Basics".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_test_progress(course_id: Option<Id>, email: &str, completed: bool) -> CourseProgress {
+        let start = DateTime::new(2025, 1, 10, 9, 0, 0).unwrap();
+        let end = completed.then(|| DateTime::new(2025, 1, 10, 9, 20, 0).unwrap());
+        let lesson =
+            LessonProgress::new("Introduction".to_string(), 600, Some(start), end).unwrap();
+        let builder = CourseProgress::builder()
+            .course_name("Rust Programming")
+            .user_email(email)
+            .lessons(vec![lesson])
+            .event_dispatcher(Arc::new(DomainEventDispatcher::<CourseEnded>::new()));
+        match course_id {
+            Some(id) => builder.course_id(id).build().unwrap(),
+            None => builder.build().unwrap(),
+        }
+    }
+
+    fn create_time(day: u32) -> DateTime {
+        DateTime::new(2025, 2, day, 12, 0, 0).unwrap()
+    }
+
+    mod capture {
+        use super::*;
+
+        #[test]
+        fn test_counts_only_progress_of_the_course() {
+            let course = create_test_course();
+            let progresses = [
+                create_test_progress(Some(course.id()), "a@example.com", true),
+                create_test_progress(Some(course.id()), "b@example.com", false),
+                create_test_progress(Some(course.id()), "c@example.com", true),
+                create_test_progress(Some(Id::new()), "d@example.com", true),
+                create_test_progress(None, "e@example.com", true),
+            ];
+
+            let snapshot = CourseStatsSnapshot::capture(
+                &course,
+                &progresses,
+                SnapshotReason::Archived,
+                create_time(1),
+            );
+
+            assert_eq!(snapshot.enrollments(), 3);
+            assert_eq!(snapshot.completions(), 2);
+            assert_eq!(snapshot.completion_rate().map(|rate| rate.whole_points()), Some(66));
+        }
+
+        #[test]
+        fn test_no_enrollments_has_no_completion_rate() {
+            let snapshot = CourseStatsSnapshot::capture(
+                &create_test_course(),
+                &[],
+                SnapshotReason::Superseded,
+                create_time(1),
+            );
+
+            assert_eq!(snapshot.completion_rate(), None);
+        }
+
+        #[test]
+        fn test_rating_uses_approved_reviews_only() {
+            let mut course = create_test_course();
+            let approved = Review::new("a@example.com".to_string(), 4, "Good".to_string()).unwrap();
+            let approved_id = approved.id();
+            course.add_review(approved).unwrap();
+            course.approve_review(approved_id).unwrap();
+            course
+                .add_review(Review::new("b@example.com".to_string(), 1, "Bad".to_string()).unwrap())
+                .unwrap();
+
+            let snapshot = CourseStatsSnapshot::capture(
+                &course,
+                &[],
+                SnapshotReason::Archived,
+                create_time(1),
+            );
+
+            assert_eq!(snapshot.average_rating(), Score::new(4.0, 5.0).ok());
+            assert_eq!(snapshot.review_count(), 1);
+        }
+
+        #[test]
+        fn test_snapshot_outlives_course_changes() {
+            let mut course = create_test_course();
+            let snapshot = CourseStatsSnapshot::capture(
+                &course,
+                &[],
+                SnapshotReason::Superseded,
+                create_time(1),
+            );
+
+            let review = Review::new("a@example.com".to_string(), 5, "Great".to_string()).unwrap();
+            let review_id = review.id();
+            course.add_review(review).unwrap();
+            course.approve_review(review_id).unwrap();
+
+            assert_eq!(snapshot.course_name(), "Rust Programming");
+            assert_eq!(snapshot.average_rating(), None);
+        }
+    }
+
+    mod history {
+        use super::*;
+
+        #[test]
+        fn test_latest_of_returns_most_recent_snapshot() {
+            let course = create_test_course();
+            let mut history = CourseStatsHistory::new();
+            for (day, reason) in [
+                (5, SnapshotReason::Archived),
+                (1, SnapshotReason::Superseded),
+            ] {
+                history.record(CourseStatsSnapshot::capture(
+                    &course,
+                    &[],
+                    reason,
+                    create_time(day),
+                ));
+            }
+
+            let latest = history.latest_of(course.id()).unwrap();
+
+            assert_eq!(latest.reason(), SnapshotReason::Archived);
+            assert_eq!(history.len(), 2);
+        }
+
+        #[test]
+        fn test_unknown_course_has_no_snapshots() {
+            let history = CourseStatsHistory::new();
+
+            assert!(history.latest_of(Id::new()).is_none());
+            assert!(history.is_empty());
+        }
+    }
+}
@@ -0,0 +1,421 @@
+mod cue;
+mod subtitles;
+
+pub use cue::*;
+
+use education_platform_common::{Duration, Locale};
+use thiserror::Error;
+
+/// Error types for transcript and subtitle failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TranscriptError {
+    #[error("Transcript must have at least one cue")]
+    NoCues,
+
+    #[error("Cue text must not be empty")]
+    EmptyCueText,
+
+    #[error("Cue ends at {end_ms} ms, which is not after its start at {start_ms} ms")]
+    CueTimingNotValid { start_ms: u64, end_ms: u64 },
+
+    #[error("Cue {0} starts before the cue preceding it")]
+    CuesOutOfOrder(usize),
+
+    #[error("WebVTT file must start with a WEBVTT header")]
+    MissingVttHeader,
+
+    #[error("Cue {0} is malformed")]
+    MalformedCue(usize),
+
+    #[error("Invalid cue timestamp: {0}")]
+    TimestampNotValid(String),
+
+    #[error("Transcript runs until {end_ms} ms, past the lesson length of {lesson_seconds} s")]
+    ExceedsLessonDuration { end_ms: u64, lesson_seconds: u64 },
+}
+
+/// Subtitle file formats a [`Transcript`] can be read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubtitleFormat {
+    /// SubRip (`.srt`): numbered cues with `00:00:01,000` timestamps.
+    Srt,
+    /// WebVTT (`.vtt`): a `WEBVTT` header and `00:00:01.000` timestamps.
+    WebVtt,
+}
+
+impl SubtitleFormat {
+    /// Guesses the format from the file contents: a `WEBVTT` header means
+    /// WebVTT, anything else is read as SubRip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SubtitleFormat;
+    ///
+    /// assert_eq!(SubtitleFormat::detect("WEBVTT\n\n00:01.000 --> 00:02.000\nHi"), SubtitleFormat::WebVtt);
+    /// assert_eq!(SubtitleFormat::detect("1\n00:00:01,000 --> 00:00:02,000\nHi"), SubtitleFormat::Srt);
+    /// ```
+    #[must_use]
+    pub fn detect(text: &str) -> Self {
+        if subtitles::has_vtt_header(text) {
+            Self::WebVtt
+        } else {
+            Self::Srt
+        }
+    }
+}
+
+/// The timed text of a lesson's video in one language, as a Value Object.
+///
+/// A transcript is a non-empty list of [`Cue`]s ordered by start time.
+/// Cues may overlap, as they do when two people speak at once.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::Locale;
+/// use education_platform_core::Transcript;
+///
+/// let srt = "1\n00:00:00,500 --> 00:00:02,000\nHello!\n\n2\n00:00:02,500 --> 00:00:04,250\nWelcome to Rust.\n";
+/// let transcript = Transcript::parse(Locale::new("en").unwrap(), srt).unwrap();
+///
+/// assert_eq!(transcript.cues().len(), 2);
+/// assert_eq!(transcript.end_ms(), 4250);
+/// assert_eq!(transcript.plain_text(), "Hello!\nWelcome to Rust.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    locale: Locale,
+    cues: Vec<Cue>,
+}
+
+impl Transcript {
+    /// Creates a transcript from cues that are already timed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TranscriptError::NoCues` if `cues` is empty, or
+    /// `TranscriptError::CuesOutOfOrder` with the 1-based number of the first
+    /// cue that starts before the one preceding it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Locale;
+    /// use education_platform_core::{Cue, Transcript};
+    ///
+    /// let cues = vec![
+    ///     Cue::new(0, 1500, "Hola".to_string()).unwrap(),
+    ///     Cue::new(1500, 3000, "y bienvenidos".to_string()).unwrap(),
+    /// ];
+    /// let transcript = Transcript::new(Locale::new("es").unwrap(), cues).unwrap();
+    /// assert_eq!(transcript.locale().as_str(), "es");
+    ///
+    /// assert!(Transcript::new(Locale::new("es").unwrap(), vec![]).is_err());
+    /// ```
+    pub fn new(locale: Locale, cues: Vec<Cue>) -> Result<Self, TranscriptError> {
+        if cues.is_empty() {
+            return Err(TranscriptError::NoCues);
+        }
+        if let Some(position) = cues
+            .windows(2)
+            .position(|pair| pair[1].start_ms() < pair[0].start_ms())
+        {
+            return Err(TranscriptError::CuesOutOfOrder(position + 2));
+        }
+
+        Ok(Self { locale, cues })
+    }
+
+    /// Parses an SRT or WebVTT file, detecting which one it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Transcript::parse_as`].
+    pub fn parse(locale: Locale, text: &str) -> Result<Self, TranscriptError> {
+        Self::parse_as(locale, text, SubtitleFormat::detect(text))
+    }
+
+    /// Parses a subtitle file in the given format.
+    ///
+    /// Cue numbers and identifiers are optional. WebVTT cue settings and
+    /// `NOTE`, `STYLE` and `REGION` blocks are skipped. Hours may be left out
+    /// of timestamps, and either `,` or `.` may separate the milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TranscriptError::MissingVttHeader` for WebVTT without its
+    /// header, `TranscriptError::MalformedCue` for a block with no timing
+    /// line or no text, `TranscriptError::TimestampNotValid` for an unreadable
+    /// time, and the errors of [`Cue::new`] and [`Transcript::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Locale;
+    /// use education_platform_core::{SubtitleFormat, Transcript};
+    ///
+    /// let vtt = "WEBVTT\n\nintro\n00:01.000 --> 00:03.000 align:start\n<v Ana>Hi there\n";
+    /// let transcript =
+    ///     Transcript::parse_as(Locale::new("en").unwrap(), vtt, SubtitleFormat::WebVtt).unwrap();
+    ///
+    /// assert_eq!(transcript.cues()[0].start_ms(), 1000);
+    /// assert_eq!(transcript.cues()[0].text(), "<v Ana>Hi there");
+    /// ```
+    pub fn parse_as(
+        locale: Locale,
+        text: &str,
+        format: SubtitleFormat,
+    ) -> Result<Self, TranscriptError> {
+        let cues = match format {
+            SubtitleFormat::Srt => subtitles::parse_srt(text)?,
+            SubtitleFormat::WebVtt => subtitles::parse_vtt(text)?,
+        };
+        Self::new(locale, cues)
+    }
+
+    /// Returns the language of the transcript.
+    #[inline]
+    #[must_use]
+    pub const fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Returns the cues, ordered by start time.
+    #[inline]
+    #[must_use]
+    pub fn cues(&self) -> &[Cue] {
+        &self.cues
+    }
+
+    /// Returns when the last cue ends, in milliseconds from the start.
+    #[must_use]
+    pub fn end_ms(&self) -> u64 {
+        self.cues.iter().map(Cue::end_ms).max().unwrap_or(0)
+    }
+
+    /// Returns the cue text without timings, one cue per line.
+    #[must_use]
+    pub fn plain_text(&self) -> String {
+        self.cues
+            .iter()
+            .map(Cue::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the cues showing at `position_ms`, for captions in a player.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Locale;
+    /// use education_platform_core::{Cue, Transcript};
+    ///
+    /// let cues = vec![Cue::new(1000, 2000, "Hello".to_string()).unwrap()];
+    /// let transcript = Transcript::new(Locale::new("en").unwrap(), cues).unwrap();
+    ///
+    /// assert_eq!(transcript.cues_at(1500).len(), 1);
+    /// assert!(transcript.cues_at(2000).is_empty());
+    /// ```
+    #[must_use]
+    pub fn cues_at(&self, position_ms: u64) -> Vec<&Cue> {
+        self.cues
+            .iter()
+            .take_while(|cue| cue.start_ms() <= position_ms)
+            .filter(|cue| cue.contains(position_ms))
+            .collect()
+    }
+
+    /// Checks that the transcript fits in a video of the given length.
+    ///
+    /// Lesson lengths are whole seconds, so a cue may end anywhere within the
+    /// last second.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TranscriptError::ExceedsLessonDuration` if a cue ends after
+    /// the video does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Duration, Locale};
+    /// use education_platform_core::{Cue, Transcript};
+    ///
+    /// let cues = vec![Cue::new(0, 60_400, "The end".to_string()).unwrap()];
+    /// let transcript = Transcript::new(Locale::new("en").unwrap(), cues).unwrap();
+    ///
+    /// assert!(transcript.check_fits(Duration::from_seconds(60)).is_ok());
+    /// assert!(transcript.check_fits(Duration::from_seconds(59)).is_err());
+    /// ```
+    pub fn check_fits(&self, duration: Duration) -> Result<(), TranscriptError> {
+        let end_ms = self.end_ms();
+        let limit_ms = duration
+            .total_seconds()
+            .saturating_add(1)
+            .saturating_mul(1000);
+
+        if end_ms >= limit_ms {
+            return Err(TranscriptError::ExceedsLessonDuration {
+                end_ms,
+                lesson_seconds: duration.total_seconds(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn english() -> Locale {
+        Locale::new("en").unwrap()
+    }
+
+    fn cue(start_ms: u64, end_ms: u64, text: &str) -> Cue {
+        Cue::new(start_ms, end_ms, text.to_string()).unwrap()
+    }
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_keeps_cues_in_order() {
+            let transcript =
+                Transcript::new(english(), vec![cue(0, 1000, "a"), cue(1000, 2000, "b")]).unwrap();
+
+            assert_eq!(transcript.cues().len(), 2);
+            assert_eq!(transcript.locale(), &english());
+        }
+
+        #[test]
+        fn test_empty_cues_are_rejected() {
+            assert_eq!(Transcript::new(english(), vec![]), Err(TranscriptError::NoCues));
+        }
+
+        #[test]
+        fn test_out_of_order_cues_are_rejected() {
+            let result = Transcript::new(
+                english(),
+                vec![
+                    cue(0, 1000, "a"),
+                    cue(2000, 3000, "b"),
+                    cue(1500, 2500, "c"),
+                ],
+            );
+
+            assert_eq!(result, Err(TranscriptError::CuesOutOfOrder(3)));
+        }
+
+        #[test]
+        fn test_overlapping_cues_are_allowed() {
+            let result = Transcript::new(english(), vec![cue(0, 2000, "a"), cue(1000, 3000, "b")]);
+
+            assert!(result.is_ok());
+        }
+    }
+
+    mod queries {
+        use super::*;
+
+        #[test]
+        fn test_end_is_latest_cue_end() {
+            let transcript =
+                Transcript::new(english(), vec![cue(0, 5000, "a"), cue(1000, 3000, "b")]).unwrap();
+
+            assert_eq!(transcript.end_ms(), 5000);
+        }
+
+        #[test]
+        fn test_plain_text_joins_cues() {
+            let transcript =
+                Transcript::new(english(), vec![cue(0, 1000, "One"), cue(1000, 2000, "Two")])
+                    .unwrap();
+
+            assert_eq!(transcript.plain_text(), "One\nTwo");
+        }
+
+        #[test]
+        fn test_cues_at_returns_overlapping_cues() {
+            let transcript = Transcript::new(
+                english(),
+                vec![
+                    cue(0, 2000, "a"),
+                    cue(1000, 3000, "b"),
+                    cue(4000, 5000, "c"),
+                ],
+            )
+            .unwrap();
+
+            let texts: Vec<&str> = transcript.cues_at(1500).iter().map(|c| c.text()).collect();
+
+            assert_eq!(texts, vec!["a", "b"]);
+            assert!(transcript.cues_at(3500).is_empty());
+        }
+    }
+
+    mod check_fits {
+        use super::*;
+
+        #[test]
+        fn test_fits_within_last_second() {
+            let transcript = Transcript::new(english(), vec![cue(0, 10_999, "a")]).unwrap();
+
+            assert!(transcript.check_fits(Duration::from_seconds(10)).is_ok());
+        }
+
+        #[test]
+        fn test_too_long_for_lesson() {
+            let transcript = Transcript::new(english(), vec![cue(0, 11_000, "a")]).unwrap();
+
+            assert_eq!(
+                transcript.check_fits(Duration::from_seconds(10)),
+                Err(TranscriptError::ExceedsLessonDuration {
+                    end_ms: 11_000,
+                    lesson_seconds: 10
+                })
+            );
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_detects_vtt() {
+            let text = "WEBVTT\n\n00:00.000 --> 00:01.000\nHi\n";
+
+            let transcript = Transcript::parse(english(), text).unwrap();
+
+            assert_eq!(transcript.cues()[0].text(), "Hi");
+        }
+
+        #[test]
+        fn test_detects_srt() {
+            let text = "1\n00:00:00,000 --> 00:00:01,000\nHi\n";
+
+            let transcript = Transcript::parse(english(), text).unwrap();
+
+            assert_eq!(transcript.cues()[0].end_ms(), 1000);
+        }
+
+        #[test]
+        fn test_vtt_without_header_is_rejected() {
+            let text = "00:00.000 --> 00:01.000\nHi\n";
+
+            let result = Transcript::parse_as(english(), text, SubtitleFormat::WebVtt);
+
+            assert_eq!(result, Err(TranscriptError::MissingVttHeader));
+        }
+
+        #[test]
+        fn test_empty_file_has_no_cues() {
+            assert_eq!(Transcript::parse(english(), "\n\n"), Err(TranscriptError::NoCues));
+        }
+    }
+}
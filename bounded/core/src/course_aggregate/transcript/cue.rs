@@ -0,0 +1,135 @@
+use super::TranscriptError;
+
+/// One timed piece of a [`Transcript`](super::Transcript), shown from
+/// `start_ms` until just before `end_ms`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Cue;
+///
+/// let cue = Cue::new(1000, 3500, "  Hello!  ".to_string()).unwrap();
+///
+/// assert_eq!(cue.text(), "Hello!");
+/// assert_eq!(cue.duration_ms(), 2500);
+/// assert!(cue.contains(1000));
+/// assert!(!cue.contains(3500));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cue {
+    start_ms: u64,
+    end_ms: u64,
+    text: String,
+}
+
+impl Cue {
+    /// Creates a cue, trimming its text.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TranscriptError::CueTimingNotValid` if the cue does not end
+    /// after it starts, or `TranscriptError::EmptyCueText` if the text is
+    /// blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Cue;
+    ///
+    /// assert!(Cue::new(0, 1000, "Hi".to_string()).is_ok());
+    /// assert!(Cue::new(1000, 1000, "Hi".to_string()).is_err());
+    /// assert!(Cue::new(0, 1000, " ".to_string()).is_err());
+    /// ```
+    pub fn new(start_ms: u64, end_ms: u64, text: String) -> Result<Self, TranscriptError> {
+        if end_ms <= start_ms {
+            return Err(TranscriptError::CueTimingNotValid { start_ms, end_ms });
+        }
+
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(TranscriptError::EmptyCueText);
+        }
+
+        Ok(Self {
+            start_ms,
+            end_ms,
+            text: text.to_string(),
+        })
+    }
+
+    /// Returns when the cue appears, in milliseconds from the start.
+    #[inline]
+    #[must_use]
+    pub const fn start_ms(&self) -> u64 {
+        self.start_ms
+    }
+
+    /// Returns when the cue disappears, in milliseconds from the start.
+    #[inline]
+    #[must_use]
+    pub const fn end_ms(&self) -> u64 {
+        self.end_ms
+    }
+
+    /// Returns how long the cue is shown, in milliseconds.
+    #[inline]
+    #[must_use]
+    pub const fn duration_ms(&self) -> u64 {
+        self.end_ms - self.start_ms
+    }
+
+    /// Returns the cue text, which may span several lines.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns `true` if the cue is showing at `position_ms`.
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, position_ms: u64) -> bool {
+        self.start_ms <= position_ms && position_ms < self.end_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_keeps_multiline_text() {
+        let cue = Cue::new(0, 1000, "first\nsecond".to_string()).unwrap();
+
+        assert_eq!(cue.text(), "first\nsecond");
+    }
+
+    #[test]
+    fn test_end_before_start_is_rejected() {
+        assert_eq!(
+            Cue::new(2000, 1000, "Hi".to_string()),
+            Err(TranscriptError::CueTimingNotValid {
+                start_ms: 2000,
+                end_ms: 1000
+            })
+        );
+    }
+
+    #[test]
+    fn test_blank_text_is_rejected() {
+        assert_eq!(
+            Cue::new(0, 1000, "\n \n".to_string()),
+            Err(TranscriptError::EmptyCueText)
+        );
+    }
+
+    #[test]
+    fn test_contains_is_half_open() {
+        let cue = Cue::new(1000, 2000, "Hi".to_string()).unwrap();
+
+        assert!(!cue.contains(999));
+        assert!(cue.contains(1000));
+        assert!(cue.contains(1999));
+        assert!(!cue.contains(2000));
+    }
+}
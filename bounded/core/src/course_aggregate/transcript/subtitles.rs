@@ -0,0 +1,322 @@
+//! Readers for SubRip and WebVTT files.
+//!
+//! Both formats are blocks of lines separated by blank lines, where a cue
+//! block is an optional number or identifier, a `start --> end` timing line
+//! and one or more lines of text.
+
+use super::{Cue, TranscriptError};
+
+const TIMING_ARROW: &str = "-->";
+const VTT_HEADER: &str = "WEBVTT";
+const VTT_METADATA_BLOCKS: [&str; 3] = ["NOTE", "STYLE", "REGION"];
+
+pub(super) fn has_vtt_header(text: &str) -> bool {
+    let first_line = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .next()
+        .unwrap_or_default();
+
+    first_line
+        .strip_prefix(VTT_HEADER)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+}
+
+pub(super) fn parse_srt(text: &str) -> Result<Vec<Cue>, TranscriptError> {
+    blocks(text)
+        .iter()
+        .enumerate()
+        .map(|(position, block)| parse_cue(block, position + 1))
+        .collect()
+}
+
+pub(super) fn parse_vtt(text: &str) -> Result<Vec<Cue>, TranscriptError> {
+    if !has_vtt_header(text) {
+        return Err(TranscriptError::MissingVttHeader);
+    }
+
+    blocks(text)
+        .iter()
+        .skip(1)
+        .filter(|block| !is_vtt_metadata(block))
+        .enumerate()
+        .map(|(position, block)| parse_cue(block, position + 1))
+        .collect()
+}
+
+/// Splits the text into groups of non-blank lines, whatever the line endings.
+fn blocks(text: &str) -> Vec<Vec<&str>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+
+    for line in text.trim_start_matches('\u{feff}').lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    blocks
+}
+
+fn is_vtt_metadata(block: &[&str]) -> bool {
+    block.first().is_some_and(|first| {
+        VTT_METADATA_BLOCKS.iter().any(|keyword| {
+            first
+                .strip_prefix(keyword)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+        })
+    })
+}
+
+fn parse_cue(block: &[&str], number: usize) -> Result<Cue, TranscriptError> {
+    let timing = block
+        .iter()
+        .take(2)
+        .position(|line| line.contains(TIMING_ARROW))
+        .ok_or(TranscriptError::MalformedCue(number))?;
+
+    let text = &block[timing + 1..];
+    if text.is_empty() {
+        return Err(TranscriptError::MalformedCue(number));
+    }
+
+    let (start, end) = block[timing]
+        .split_once(TIMING_ARROW)
+        .ok_or(TranscriptError::MalformedCue(number))?;
+    // WebVTT allows cue settings such as `align:start` after the end time.
+    let end = end
+        .split_whitespace()
+        .next()
+        .ok_or(TranscriptError::MalformedCue(number))?;
+
+    Cue::new(parse_timestamp(start.trim())?, parse_timestamp(end)?, text.join("\n"))
+}
+
+/// Reads `[hh:]mm:ss,mmm` or `[hh:]mm:ss.mmm` as milliseconds.
+fn parse_timestamp(timestamp: &str) -> Result<u64, TranscriptError> {
+    let invalid = || TranscriptError::TimestampNotValid(timestamp.to_string());
+
+    let (clock, millis) = timestamp.rsplit_once([',', '.']).ok_or_else(invalid)?;
+    let parts: Vec<&str> = clock.split(':').collect();
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [minutes, seconds] => ("0", *minutes, *seconds),
+        [hours, minutes, seconds] => (*hours, *minutes, *seconds),
+        _ => return Err(invalid()),
+    };
+
+    let field = |digits: &str, width: Option<usize>| {
+        let well_formed = !digits.is_empty()
+            && digits.bytes().all(|b| b.is_ascii_digit())
+            && width.is_none_or(|width| digits.len() == width);
+        well_formed
+            .then(|| digits.parse::<u64>().ok())
+            .flatten()
+            .ok_or_else(invalid)
+    };
+
+    let hours = field(hours, None)?;
+    let minutes = field(minutes, Some(2))?;
+    let seconds = field(seconds, Some(2))?;
+    let millis = field(millis, Some(3))?;
+    if minutes >= 60 || seconds >= 60 {
+        return Err(invalid());
+    }
+
+    hours
+        .checked_mul(60)
+        .and_then(|total| total.checked_add(minutes))
+        .and_then(|total| total.checked_mul(60))
+        .and_then(|total| total.checked_add(seconds))
+        .and_then(|total| total.checked_mul(1000))
+        .and_then(|total| total.checked_add(millis))
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod timestamps {
+        use super::*;
+
+        #[test]
+        fn test_srt_timestamp() {
+            assert_eq!(parse_timestamp("01:02:03,456"), Ok(3_723_456));
+        }
+
+        #[test]
+        fn test_vtt_timestamp_without_hours() {
+            assert_eq!(parse_timestamp("02:03.456"), Ok(123_456));
+        }
+
+        #[test]
+        fn test_long_hours_are_allowed() {
+            assert_eq!(parse_timestamp("100:00:00.000"), Ok(360_000_000));
+        }
+
+        #[test]
+        fn test_overflowing_hours_are_invalid() {
+            for timestamp in [
+                "99999999999999999:00:00,000",
+                "18446744073709551615:00:00,000",
+            ] {
+                assert_eq!(
+                    parse_timestamp(timestamp),
+                    Err(TranscriptError::TimestampNotValid(timestamp.to_string()))
+                );
+            }
+        }
+
+        #[test]
+        fn test_invalid_timestamps() {
+            for timestamp in [
+                "",
+                "00:00:01",
+                "1:00.000",
+                "00:60.000",
+                "00:00:60,000",
+                "00:00:01,00",
+                "00:00:+1,000",
+                "a:00:01,000",
+                "00:00:00:01,000",
+            ] {
+                assert_eq!(
+                    parse_timestamp(timestamp),
+                    Err(TranscriptError::TimestampNotValid(timestamp.to_string())),
+                    "timestamp {timestamp:?}"
+                );
+            }
+        }
+    }
+
+    mod srt {
+        use super::*;
+
+        #[test]
+        fn test_parses_numbered_cues() {
+            let text = "1\n00:00:01,000 --> 00:00:02,500\nHello\nworld\n\n2\n00:00:03,000 --> 00:00:04,000\nBye\n";
+
+            let cues = parse_srt(text).unwrap();
+
+            assert_eq!(cues.len(), 2);
+            assert_eq!(cues[0].start_ms(), 1000);
+            assert_eq!(cues[0].end_ms(), 2500);
+            assert_eq!(cues[0].text(), "Hello\nworld");
+            assert_eq!(cues[1].text(), "Bye");
+        }
+
+        #[test]
+        fn test_accepts_windows_line_endings() {
+            let text = "1\r\n00:00:01,000 --> 00:00:02,000\r\nHello\r\n\r\n2\r\n00:00:02,000 --> 00:00:03,000\r\nAgain\r\n";
+
+            assert_eq!(parse_srt(text).unwrap().len(), 2);
+        }
+
+        #[test]
+        fn test_cue_number_is_optional() {
+            let cues = parse_srt("00:00:01,000 --> 00:00:02,000\nHello\n").unwrap();
+
+            assert_eq!(cues[0].text(), "Hello");
+        }
+
+        #[test]
+        fn test_extra_blank_lines_are_ignored() {
+            let text = "\n\n1\n00:00:01,000 --> 00:00:02,000\nHello\n\n\n\n";
+
+            assert_eq!(parse_srt(text).unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_block_without_timing_is_malformed() {
+            let text = "1\n00:00:01,000 --> 00:00:02,000\nHello\n\n2\nNo timing here\n";
+
+            assert_eq!(parse_srt(text), Err(TranscriptError::MalformedCue(2)));
+        }
+
+        #[test]
+        fn test_block_without_text_is_malformed() {
+            let text = "1\n00:00:01,000 --> 00:00:02,000\n";
+
+            assert_eq!(parse_srt(text), Err(TranscriptError::MalformedCue(1)));
+        }
+
+        #[test]
+        fn test_reversed_timing_is_rejected() {
+            let text = "1\n00:00:05,000 --> 00:00:02,000\nHello\n";
+
+            assert!(matches!(
+                parse_srt(text),
+                Err(TranscriptError::CueTimingNotValid { .. })
+            ));
+        }
+
+        #[test]
+        fn test_overflowing_timestamp_is_rejected() {
+            let text = "1\n99999999999999999:00:00,000 --> 99999999999999999:00:01,000\nHello\n";
+
+            assert_eq!(
+                parse_srt(text),
+                Err(TranscriptError::TimestampNotValid(
+                    "99999999999999999:00:00,000".to_string()
+                ))
+            );
+        }
+    }
+
+    mod vtt {
+        use super::*;
+
+        #[test]
+        fn test_header_detection() {
+            assert!(has_vtt_header("WEBVTT"));
+            assert!(has_vtt_header("WEBVTT - Lesson 1\n"));
+            assert!(has_vtt_header("\u{feff}WEBVTT\n"));
+            assert!(!has_vtt_header("WEBVTTX\n"));
+            assert!(!has_vtt_header("1\n00:00:01,000 --> 00:00:02,000\n"));
+        }
+
+        #[test]
+        fn test_parses_cues_with_identifiers_and_settings() {
+            let text = "WEBVTT\n\nintro\n00:01.000 --> 00:02.000 line:0 align:start\nHello\n\n00:00:02.000 --> 00:00:03.000\nWorld\n";
+
+            let cues = parse_vtt(text).unwrap();
+
+            assert_eq!(cues.len(), 2);
+            assert_eq!(cues[0].end_ms(), 2000);
+            assert_eq!(cues[1].text(), "World");
+        }
+
+        #[test]
+        fn test_skips_metadata_blocks() {
+            let text = "WEBVTT\nKind: captions\n\nNOTE written by hand\n\nSTYLE\n::cue { color: white }\n\n00:01.000 --> 00:02.000\nHello\n";
+
+            let cues = parse_vtt(text).unwrap();
+
+            assert_eq!(cues.len(), 1);
+            assert_eq!(cues[0].text(), "Hello");
+        }
+
+        #[test]
+        fn test_cue_numbers_skip_metadata() {
+            let text = "WEBVTT\n\nNOTE first\n\n00:01.000 --> 00:02.000\nHello\n\nbroken\n";
+
+            assert_eq!(parse_vtt(text), Err(TranscriptError::MalformedCue(2)));
+        }
+
+        #[test]
+        fn test_missing_header() {
+            assert_eq!(
+                parse_vtt("00:01.000 --> 00:02.000\nHello\n"),
+                Err(TranscriptError::MissingVttHeader)
+            );
+        }
+    }
+}
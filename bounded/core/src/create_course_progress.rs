@@ -1,4 +1,7 @@
-use crate::{Course, CourseEnded, CourseProgress, CourseProgressError, Lesson, LessonProgress};
+use crate::{
+    Course, CourseEnded, CourseProgress, CourseProgressError, ExtensionRegistry, Lesson,
+    LessonProgress,
+};
 use education_platform_common::{DomainEventDispatcher, Entity, Id};
 use std::sync::Arc;
 
@@ -93,6 +96,13 @@ impl CreateCourseProgress {
         }
     }
 
+    /// Creates a new service instance whose progress reports completions to
+    /// the registry's course ended subscribers.
+    #[must_use]
+    pub fn with_extensions(course: Course, extensions: &ExtensionRegistry) -> Self {
+        Self::with_dispatcher(course, extensions.course_ended_dispatcher())
+    }
+
     /// Creates a new course progress for a user starting the course.
     ///
     /// This creates fresh lesson progress records for all lessons in the course,
@@ -636,5 +646,24 @@ mod tests {
             let events = received_events.lock().unwrap();
             assert_eq!(events.len(), 0);
         }
+
+        #[test]
+        fn test_extension_subscribers_receive_course_ended_event() {
+            let lesson = create_test_lesson("Intro", 1800, 0);
+            let chapter = create_test_chapter("Chapter 1", 0, vec![lesson]);
+            let course = create_test_course("Test Course", vec![chapter]);
+            let extensions = ExtensionRegistry::new();
+            let received = Arc::new(Mutex::new(Vec::new()));
+            let sink = Arc::clone(&received);
+            extensions.on_course_ended(move |event| sink.lock().unwrap().push(event.course_id()));
+
+            let service = CreateCourseProgress::with_extensions(course, &extensions);
+            let progress = service
+                .new_progress("student@example.com".to_string())
+                .unwrap();
+            progress.publish_ended();
+
+            assert_eq!(*received.lock().unwrap(), vec![progress.id()]);
+        }
     }
 }
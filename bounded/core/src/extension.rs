@@ -0,0 +1,7 @@
+mod content_policy;
+mod course_rule;
+mod registry;
+
+pub use content_policy::*;
+pub use course_rule::*;
+pub use registry::*;
@@ -0,0 +1,119 @@
+/// Check applied to text that authors or learners publish on the platform.
+///
+/// Policies see the text before it is stored, so an institution can keep
+/// out terms or formats it does not allow.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::ContentPolicy;
+///
+/// /// Rejects shouting.
+/// struct NoAllCaps;
+///
+/// impl ContentPolicy for NoAllCaps {
+///     fn name(&self) -> &str {
+///         "no-all-caps"
+///     }
+///
+///     fn check(&self, text: &str) -> Result<(), String> {
+///         if text.chars().any(char::is_lowercase) {
+///             Ok(())
+///         } else {
+///             Err("text is written in capitals".to_string())
+///         }
+///     }
+/// }
+///
+/// assert!(NoAllCaps.check("Week 1 recap").is_ok());
+/// assert!(NoAllCaps.check("WEEK 1 RECAP").is_err());
+/// ```
+pub trait ContentPolicy: Send + Sync {
+    /// Returns the name reported when the policy rejects a text.
+    fn name(&self) -> &str;
+
+    /// Checks a text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason when the text is not allowed.
+    fn check(&self, text: &str) -> Result<(), String>;
+}
+
+/// Policy rejecting texts that contain any of a list of terms, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{BlockedTermsPolicy, ContentPolicy};
+///
+/// let policy = BlockedTermsPolicy::new("competitors", ["AcmeLearn"]);
+///
+/// assert!(policy.check("Week 1 live Q&A").is_ok());
+/// assert_eq!(
+///     policy.check("Migrating from acmelearn"),
+///     Err("contains blocked term 'acmelearn'".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedTermsPolicy {
+    name: String,
+    terms: Vec<String>,
+}
+
+impl BlockedTermsPolicy {
+    /// Creates a policy; blank terms are ignored.
+    #[must_use]
+    pub fn new<I, S>(name: &str, terms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let terms = terms
+            .into_iter()
+            .map(|term| term.as_ref().trim().to_lowercase())
+            .filter(|term| !term.is_empty())
+            .collect();
+        Self {
+            name: name.to_string(),
+            terms,
+        }
+    }
+}
+
+impl ContentPolicy for BlockedTermsPolicy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, text: &str) -> Result<(), String> {
+        let text = text.to_lowercase();
+        match self.terms.iter().find(|term| text.contains(term.as_str())) {
+            Some(term) => Err(format!("contains blocked term '{term}'")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod blocked_terms_policy {
+        use super::*;
+
+        #[test]
+        fn test_blank_terms_are_ignored() {
+            let policy = BlockedTermsPolicy::new("empty", ["", "  "]);
+
+            assert_eq!(policy.check("anything goes"), Ok(()));
+        }
+
+        #[test]
+        fn test_match_ignores_case() {
+            let policy = BlockedTermsPolicy::new("words", ["Spoiler"]);
+
+            assert!(policy.check("SPOILER: the answer is 42").is_err());
+        }
+    }
+}
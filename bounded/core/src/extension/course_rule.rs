@@ -0,0 +1,171 @@
+use crate::{Course, ExtensionError};
+use regex::Regex;
+
+/// Institution-specific check a course must pass.
+///
+/// Rules are registered on an [`ExtensionRegistry`](crate::ExtensionRegistry)
+/// at startup and run by the use cases that change course content, after
+/// the built-in validation has accepted the change.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Chapter, Course, CourseRule, Lesson};
+///
+/// /// Caps courses at a fixed number of chapters.
+/// struct MaxChapters(usize);
+///
+/// impl CourseRule for MaxChapters {
+///     fn name(&self) -> &str {
+///         "max-chapters"
+///     }
+///
+///     fn check(&self, course: &Course) -> Result<(), String> {
+///         if course.chapters().len() > self.0 {
+///             return Err(format!("more than {} chapters", self.0));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// assert!(MaxChapters(1).check(&course).is_ok());
+/// assert!(MaxChapters(0).check(&course).is_err());
+/// ```
+pub trait CourseRule: Send + Sync {
+    /// Returns the name reported when the rule is violated.
+    fn name(&self) -> &str;
+
+    /// Checks a course.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason when the course breaks the rule.
+    fn check(&self, course: &Course) -> Result<(), String>;
+}
+
+/// Naming convention every course, chapter and lesson name must match.
+///
+/// The pattern is not anchored implicitly; use `^` and `$` to require a
+/// full match.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Chapter, Course, CourseRule, Lesson, NamePatternRule};
+///
+/// let rule = NamePatternRule::new("capitalized", r"^[A-Z]").unwrap();
+///
+/// let lesson = Lesson::new(
+///     "introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// assert_eq!(
+///     rule.check(&course),
+///     Err("lesson 'introduction' does not match ^[A-Z]".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamePatternRule {
+    name: String,
+    pattern: Regex,
+}
+
+impl NamePatternRule {
+    /// Creates a rule from a regular expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExtensionError::PatternNotValid` if the pattern does not compile.
+    pub fn new(name: &str, pattern: &str) -> Result<Self, ExtensionError> {
+        Regex::new(pattern)
+            .map(|pattern| Self {
+                name: name.to_string(),
+                pattern,
+            })
+            .map_err(|e| ExtensionError::PatternNotValid(e.to_string()))
+    }
+
+    fn check_name(&self, kind: &str, name: &str) -> Result<(), String> {
+        if self.pattern.is_match(name) {
+            Ok(())
+        } else {
+            Err(format!("{kind} '{name}' does not match {}", self.pattern))
+        }
+    }
+}
+
+impl CourseRule for NamePatternRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, course: &Course) -> Result<(), String> {
+        self.check_name("course", course.name().as_str())?;
+        for chapter in course.chapters() {
+            self.check_name("chapter", chapter.name().as_str())?;
+            for lesson in chapter.lessons() {
+                self.check_name("lesson", lesson.name().as_str())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson};
+
+    fn create_test_course(chapter_name: &str) -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new(chapter_name.to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    mod name_pattern_rule {
+        use super::*;
+
+        #[test]
+        fn test_matching_course_passes() {
+            let rule = NamePatternRule::new("no-digits", r"^\D+$").unwrap();
+
+            assert_eq!(rule.check(&create_test_course("Getting Started")), Ok(()));
+        }
+
+        #[test]
+        fn test_chapter_violation_is_reported() {
+            let rule = NamePatternRule::new("no-digits", r"^\D+$").unwrap();
+
+            let result = rule.check(&create_test_course("Week 1"));
+
+            assert_eq!(result, Err(r"chapter 'Week 1' does not match ^\D+$".to_string()));
+        }
+
+        #[test]
+        fn test_invalid_pattern_is_rejected() {
+            let result = NamePatternRule::new("broken", "(unclosed");
+
+            assert!(matches!(result, Err(ExtensionError::PatternNotValid(_))));
+        }
+    }
+}
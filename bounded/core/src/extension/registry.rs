@@ -0,0 +1,259 @@
+use crate::{ContentPolicy, Course, CourseEnded, CourseRule, RecordingPublished};
+use education_platform_common::DomainEventDispatcher;
+use std::fmt;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Error types for extension registration and checks.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExtensionError {
+    #[error("Invalid rule pattern: {0}")]
+    PatternNotValid(String),
+
+    #[error("Course rule '{rule}' failed: {reason}")]
+    RuleViolated { rule: String, reason: String },
+
+    #[error("Content policy '{policy}' rejected the text: {reason}")]
+    ContentRejected { policy: String, reason: String },
+}
+
+/// Rules, policies and event subscribers a host application plugs in.
+///
+/// The registry is filled once at startup and then shared with the use
+/// cases, which run the registered checks and publish their events through
+/// the registry's dispatchers. Institution-specific behaviour therefore
+/// lives in the host application instead of in a fork of the domain.
+///
+/// - course rules and content policies are run by
+///   [`PublishRecording`](crate::PublishRecording) before the recording
+///   lesson is added;
+/// - course ended subscribers hear from the progress created by
+///   [`CreateCourseProgress`](crate::CreateCourseProgress);
+/// - recording published subscribers hear from `PublishRecording`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     BlockedTermsPolicy, Chapter, Course, ExtensionError, ExtensionRegistry, Lesson,
+///     NamePatternRule,
+/// };
+///
+/// let mut registry = ExtensionRegistry::new();
+/// registry.register_course_rule(NamePatternRule::new("capitalized", "^[A-Z]").unwrap());
+/// registry.register_content_policy(BlockedTermsPolicy::new("competitors", ["AcmeLearn"]));
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// assert!(registry.check_course(&course).is_ok());
+/// assert!(matches!(
+///     registry.check_content("AcmeLearn comparison"),
+///     Err(ExtensionError::ContentRejected { .. })
+/// ));
+/// ```
+#[derive(Default)]
+pub struct ExtensionRegistry {
+    course_rules: Vec<Box<dyn CourseRule>>,
+    content_policies: Vec<Box<dyn ContentPolicy>>,
+    course_ended: Arc<DomainEventDispatcher<CourseEnded>>,
+    recording_published: Arc<DomainEventDispatcher<RecordingPublished>>,
+}
+
+impl fmt::Debug for ExtensionRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<&str> = self.course_rules.iter().map(|rule| rule.name()).collect();
+        let policies: Vec<&str> = self
+            .content_policies
+            .iter()
+            .map(|policy| policy.name())
+            .collect();
+        f.debug_struct("ExtensionRegistry")
+            .field("course_rules", &rules)
+            .field("content_policies", &policies)
+            .field("course_ended", &self.course_ended)
+            .field("recording_published", &self.recording_published)
+            .finish()
+    }
+}
+
+impl ExtensionRegistry {
+    /// Creates a registry with no extensions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule every course must pass.
+    pub fn register_course_rule<R>(&mut self, rule: R)
+    where
+        R: CourseRule + 'static,
+    {
+        self.course_rules.push(Box::new(rule));
+    }
+
+    /// Adds a policy published text must pass.
+    pub fn register_content_policy<P>(&mut self, policy: P)
+    where
+        P: ContentPolicy + 'static,
+    {
+        self.content_policies.push(Box::new(policy));
+    }
+
+    /// Subscribes to learners completing a course.
+    pub fn on_course_ended<F>(&self, subscriber: F)
+    where
+        F: Fn(&CourseEnded) + Send + Sync + 'static,
+    {
+        self.course_ended.subscribe(subscriber);
+    }
+
+    /// Subscribes to live session recordings being published as lessons.
+    pub fn on_recording_published<F>(&self, subscriber: F)
+    where
+        F: Fn(&RecordingPublished) + Send + Sync + 'static,
+    {
+        self.recording_published.subscribe(subscriber);
+    }
+
+    /// Runs every course rule in registration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExtensionError::RuleViolated` for the first rule the course breaks.
+    pub fn check_course(&self, course: &Course) -> Result<(), ExtensionError> {
+        self.course_rules.iter().try_for_each(|rule| {
+            rule.check(course)
+                .map_err(|reason| ExtensionError::RuleViolated {
+                    rule: rule.name().to_string(),
+                    reason,
+                })
+        })
+    }
+
+    /// Runs every content policy in registration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExtensionError::ContentRejected` for the first policy the text breaks.
+    pub fn check_content(&self, text: &str) -> Result<(), ExtensionError> {
+        self.content_policies.iter().try_for_each(|policy| {
+            policy
+                .check(text)
+                .map_err(|reason| ExtensionError::ContentRejected {
+                    policy: policy.name().to_string(),
+                    reason,
+                })
+        })
+    }
+
+    /// Returns the dispatcher course progress publishes completions to.
+    #[inline]
+    #[must_use]
+    pub fn course_ended_dispatcher(&self) -> Arc<DomainEventDispatcher<CourseEnded>> {
+        Arc::clone(&self.course_ended)
+    }
+
+    /// Returns the dispatcher recording publications are announced on.
+    #[inline]
+    #[must_use]
+    pub fn recording_published_dispatcher(&self) -> Arc<DomainEventDispatcher<RecordingPublished>> {
+        Arc::clone(&self.recording_published)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlockedTermsPolicy, Chapter, Lesson, NamePatternRule};
+    use education_platform_common::{Email, Id};
+    use std::sync::Mutex;
+
+    fn create_test_course() -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    mod checks {
+        use super::*;
+
+        #[test]
+        fn test_empty_registry_accepts_everything() {
+            let registry = ExtensionRegistry::new();
+
+            assert_eq!(registry.check_course(&create_test_course()), Ok(()));
+            assert_eq!(registry.check_content("anything"), Ok(()));
+        }
+
+        #[test]
+        fn test_first_failing_rule_is_reported() {
+            let mut registry = ExtensionRegistry::new();
+            registry.register_course_rule(NamePatternRule::new("any", ".").unwrap());
+            registry.register_course_rule(NamePatternRule::new("short", "^.{0,10}$").unwrap());
+            registry.register_course_rule(NamePatternRule::new("never", "^$").unwrap());
+
+            let result = registry.check_course(&create_test_course());
+
+            assert!(matches!(
+                result,
+                Err(ExtensionError::RuleViolated { rule, .. }) if rule == "short"
+            ));
+        }
+
+        #[test]
+        fn test_content_rejection_names_policy() {
+            let mut registry = ExtensionRegistry::new();
+            registry.register_content_policy(BlockedTermsPolicy::new("spoilers", ["answer"]));
+
+            assert_eq!(
+                registry.check_content("The answer is 42"),
+                Err(ExtensionError::ContentRejected {
+                    policy: "spoilers".to_string(),
+                    reason: "contains blocked term 'answer'".to_string(),
+                })
+            );
+        }
+    }
+
+    mod subscribers {
+        use super::*;
+
+        #[test]
+        fn test_subscribers_receive_events_from_shared_dispatcher() {
+            let registry = ExtensionRegistry::new();
+            let received = Arc::new(Mutex::new(Vec::new()));
+            let sink = Arc::clone(&received);
+            registry.on_course_ended(move |event| sink.lock().unwrap().push(event.course_id()));
+            let course_id = Id::new();
+
+            registry.course_ended_dispatcher().notify(&CourseEnded::new(
+                Email::new("ana@example.com".to_string()).unwrap(),
+                course_id,
+            ));
+
+            assert_eq!(*received.lock().unwrap(), vec![course_id]);
+        }
+
+        #[test]
+        fn test_debug_lists_extension_names() {
+            let mut registry = ExtensionRegistry::new();
+            registry.register_course_rule(NamePatternRule::new("capitalized", "^[A-Z]").unwrap());
+
+            assert!(format!("{registry:?}").contains("capitalized"));
+        }
+    }
+}
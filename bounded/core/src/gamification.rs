@@ -0,0 +1,15 @@
+mod badge;
+mod gamification_engine;
+mod gamification_event;
+mod gamification_profile;
+mod leaderboard;
+mod level_curve;
+mod points_policy;
+
+pub use badge::*;
+pub use gamification_engine::*;
+pub use gamification_event::*;
+pub use gamification_profile::*;
+pub use leaderboard::*;
+pub use level_curve::*;
+pub use points_policy::*;
@@ -0,0 +1,147 @@
+use crate::{GamificationError, GamificationProfile};
+use education_platform_common::{DateTime, Percentage, SimpleName, SimpleNameConfig};
+
+/// The condition a learner has to meet to earn a [`Badge`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BadgeRule {
+    /// Active on this many consecutive days.
+    Streak { days: u32 },
+    /// Completed at least this many distinct lessons.
+    LessonsCompleted(u32),
+    /// Completed at least this many distinct courses.
+    CoursesCompleted(u32),
+    /// Scored at least this much on a quiz.
+    QuizScore(Percentage),
+    /// Collected at least this many points.
+    Points(u64),
+}
+
+impl BadgeRule {
+    /// Returns `true` if the learner's profile meets the rule.
+    #[must_use]
+    pub fn is_met_by(&self, profile: &GamificationProfile) -> bool {
+        match *self {
+            Self::Streak { days } => profile.longest_streak() >= days,
+            Self::LessonsCompleted(count) => profile.lessons_completed() >= count as usize,
+            Self::CoursesCompleted(count) => profile.courses_completed() >= count as usize,
+            Self::QuizScore(threshold) => profile
+                .best_quiz_score()
+                .is_some_and(|best| best.value() >= threshold.value()),
+            Self::Points(points) => profile.points() >= points,
+        }
+    }
+
+    const fn is_valid(&self) -> bool {
+        match *self {
+            Self::Streak { days: count }
+            | Self::LessonsCompleted(count)
+            | Self::CoursesCompleted(count) => count > 0,
+            Self::Points(points) => points > 0,
+            Self::QuizScore(_) => true,
+        }
+    }
+}
+
+/// An achievement learners earn once, such as "7-day streak" or "First
+/// course completed".
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Badge, BadgeRule};
+///
+/// let badge = Badge::new("7-day streak".to_string(), BadgeRule::Streak { days: 7 }).unwrap();
+///
+/// assert_eq!(badge.name().as_str(), "7-day streak");
+/// assert!(Badge::new("Never".to_string(), BadgeRule::CoursesCompleted(0)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Badge {
+    name: SimpleName,
+    rule: BadgeRule,
+}
+
+impl Badge {
+    /// Creates a badge awarded when `rule` is met.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GamificationError::BadgeNameError` if the name validation
+    /// fails, or `GamificationError::BadgeRuleNotValid` if the rule asks for
+    /// a count of zero, which every learner would meet.
+    pub fn new(name: String, rule: BadgeRule) -> Result<Self, GamificationError> {
+        let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        if !rule.is_valid() {
+            return Err(GamificationError::BadgeRuleNotValid);
+        }
+        Ok(Self { name, rule })
+    }
+
+    /// Returns the badge name, which identifies it.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &SimpleName {
+        &self.name
+    }
+
+    /// Returns the condition to earn the badge.
+    #[inline]
+    #[must_use]
+    pub const fn rule(&self) -> BadgeRule {
+        self.rule
+    }
+}
+
+/// A badge a learner has earned, and when.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EarnedBadge {
+    name: SimpleName,
+    earned_at: DateTime,
+}
+
+impl EarnedBadge {
+    pub(crate) const fn new(name: SimpleName, earned_at: DateTime) -> Self {
+        Self { name, earned_at }
+    }
+
+    /// Returns the name of the badge.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &SimpleName {
+        &self.name
+    }
+
+    /// Returns when the activity that earned the badge happened.
+    #[inline]
+    #[must_use]
+    pub const fn earned_at(&self) -> DateTime {
+        self.earned_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_thresholds_are_rejected() {
+        for rule in [
+            BadgeRule::Streak { days: 0 },
+            BadgeRule::LessonsCompleted(0),
+            BadgeRule::CoursesCompleted(0),
+            BadgeRule::Points(0),
+        ] {
+            assert_eq!(
+                Badge::new("Freebie".to_string(), rule),
+                Err(GamificationError::BadgeRuleNotValid)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_name() {
+        let result = Badge::new("x".to_string(), BadgeRule::Points(10));
+
+        assert!(matches!(result, Err(GamificationError::BadgeNameError(_))));
+    }
+}
@@ -0,0 +1,353 @@
+use crate::{Badge, EarnedBadge, GamificationEvent, GamificationProfile, LevelCurve, PointsPolicy};
+use education_platform_common::{Email, SimpleName, SimpleNameError};
+use thiserror::Error;
+
+/// Error types for gamification failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GamificationError {
+    #[error("Badge name validation failed: {0}")]
+    BadgeNameError(#[from] SimpleNameError),
+
+    #[error("Badge rule must ask for more than zero")]
+    BadgeRuleNotValid,
+
+    #[error("Badge {0} is already defined")]
+    DuplicateBadge(String),
+
+    #[error("Level curve base points must be different from zero")]
+    LevelBaseIsZero,
+
+    #[error("Event is about {event}, not the profile owner {profile}")]
+    LearnerMismatch { event: String, profile: String },
+}
+
+/// What recording one event changed in a learner's profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reward {
+    points: u64,
+    badges: Vec<SimpleName>,
+    level_before: u32,
+    level_after: u32,
+}
+
+impl Reward {
+    /// Returns the points the event earned.
+    #[inline]
+    #[must_use]
+    pub const fn points(&self) -> u64 {
+        self.points
+    }
+
+    /// Returns the names of the badges the event earned.
+    #[inline]
+    #[must_use]
+    pub fn badges(&self) -> &[SimpleName] {
+        &self.badges
+    }
+
+    /// Returns the learner's level after the event.
+    #[inline]
+    #[must_use]
+    pub const fn level(&self) -> u32 {
+        self.level_after
+    }
+
+    /// Returns `true` if the event took the learner to a higher level.
+    #[inline]
+    #[must_use]
+    pub const fn leveled_up(&self) -> bool {
+        self.level_after > self.level_before
+    }
+}
+
+/// Awards points, badges and levels to learners from their activity.
+///
+/// The engine holds the rules — the [`PointsPolicy`], the [`LevelCurve`]
+/// and the defined [`Badge`]s — while each learner's standing lives in
+/// their [`GamificationProfile`]. Badges are checked after every event and
+/// earned at most once.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     Badge, BadgeRule, GamificationEngine, GamificationEvent, GamificationProfile, LevelCurve,
+///     PointsPolicy,
+/// };
+/// use education_platform_common::{DateTime, Email, Id};
+///
+/// let mut engine = GamificationEngine::new(PointsPolicy::default(), LevelCurve::default());
+/// engine.define_badge(Badge::new("2-day streak".to_string(), BadgeRule::Streak { days: 2 }).unwrap()).unwrap();
+///
+/// let ana = Email::new("ana@example.com".to_string()).unwrap();
+/// let mut profile = GamificationProfile::new(ana.clone());
+/// for day in [3, 4] {
+///     let at = DateTime::new(2025, 3, day, 18, 0, 0).unwrap();
+///     let event = GamificationEvent::lesson_completed(ana.clone(), None, Id::new(), at);
+///     engine.record(&mut profile, &event).unwrap();
+/// }
+///
+/// assert_eq!(profile.points(), 25);
+/// assert!(profile.has_badge("2-day streak"));
+/// assert_eq!(engine.level_of(&profile), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GamificationEngine {
+    policy: PointsPolicy,
+    curve: LevelCurve,
+    badges: Vec<Badge>,
+}
+
+impl GamificationEngine {
+    /// Creates an engine without badges.
+    #[must_use]
+    pub const fn new(policy: PointsPolicy, curve: LevelCurve) -> Self {
+        Self {
+            policy,
+            curve,
+            badges: Vec::new(),
+        }
+    }
+
+    /// Adds a badge learners can earn from now on.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GamificationError::DuplicateBadge` if a badge with the same
+    /// name is already defined.
+    pub fn define_badge(&mut self, badge: Badge) -> Result<(), GamificationError> {
+        if self
+            .badges
+            .iter()
+            .any(|defined| defined.name() == badge.name())
+        {
+            return Err(GamificationError::DuplicateBadge(badge.name().as_str().to_string()));
+        }
+
+        self.badges.push(badge);
+        Ok(())
+    }
+
+    /// Returns the defined badges.
+    #[inline]
+    #[must_use]
+    pub fn badges(&self) -> &[Badge] {
+        &self.badges
+    }
+
+    /// Returns the points policy.
+    #[inline]
+    #[must_use]
+    pub const fn policy(&self) -> &PointsPolicy {
+        &self.policy
+    }
+
+    /// Returns the level curve.
+    #[inline]
+    #[must_use]
+    pub const fn curve(&self) -> &LevelCurve {
+        &self.curve
+    }
+
+    /// Records a learner's activity in their profile, paying points and
+    /// awarding every badge whose rule is now met.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GamificationError::LearnerMismatch` if the event is about
+    /// another learner; the profile is left unchanged.
+    pub fn record(
+        &self,
+        profile: &mut GamificationProfile,
+        event: &GamificationEvent,
+    ) -> Result<Reward, GamificationError> {
+        if event.learner() != profile.learner() {
+            return Err(GamificationError::LearnerMismatch {
+                event: event.learner().address().to_string(),
+                profile: profile.learner().address().to_string(),
+            });
+        }
+
+        let level_before = self.level_of(profile);
+        let points = profile.apply(event, &self.policy);
+
+        let mut badges = Vec::new();
+        for badge in &self.badges {
+            if !profile.has_badge(badge.name().as_str()) && badge.rule().is_met_by(profile) {
+                profile.award(EarnedBadge::new(badge.name().clone(), event.occurred_at()));
+                badges.push(badge.name().clone());
+            }
+        }
+
+        Ok(Reward {
+            points,
+            badges,
+            level_before,
+            level_after: self.level_of(profile),
+        })
+    }
+
+    /// Builds a learner's profile by recording their events in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GamificationError::LearnerMismatch` for the first event
+    /// about another learner.
+    pub fn replay<'a>(
+        &self,
+        learner: Email,
+        events: impl IntoIterator<Item = &'a GamificationEvent>,
+    ) -> Result<GamificationProfile, GamificationError> {
+        let mut profile = GamificationProfile::new(learner);
+        for event in events {
+            self.record(&mut profile, event)?;
+        }
+        Ok(profile)
+    }
+
+    /// Returns the learner's level.
+    #[inline]
+    #[must_use]
+    pub fn level_of(&self, profile: &GamificationProfile) -> u32 {
+        self.curve.level_for(profile.points())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BadgeRule, CourseEnded};
+    use education_platform_common::{DateTime, Id, Percentage, Score};
+
+    fn ana() -> Email {
+        Email::new("ana@example.com".to_string()).unwrap()
+    }
+
+    fn at(day: u32) -> DateTime {
+        DateTime::new(2025, 3, day, 18, 0, 0).unwrap()
+    }
+
+    fn create_test_engine() -> GamificationEngine {
+        let mut engine = GamificationEngine::new(PointsPolicy::default(), LevelCurve::default());
+        for (name, rule) in [
+            ("7-day streak", BadgeRule::Streak { days: 7 }),
+            ("First course completed", BadgeRule::CoursesCompleted(1)),
+            ("Quiz ace", BadgeRule::QuizScore(Percentage::FULL)),
+        ] {
+            engine
+                .define_badge(Badge::new(name.to_string(), rule).unwrap())
+                .unwrap();
+        }
+        engine
+    }
+
+    mod define_badge {
+        use super::*;
+
+        #[test]
+        fn test_duplicate_name() {
+            let mut engine = create_test_engine();
+
+            let result = engine
+                .define_badge(Badge::new("Quiz ace".to_string(), BadgeRule::Points(10)).unwrap());
+
+            assert_eq!(result, Err(GamificationError::DuplicateBadge("Quiz ace".to_string())));
+            assert_eq!(engine.badges().len(), 3);
+        }
+    }
+
+    mod record {
+        use super::*;
+
+        #[test]
+        fn test_seven_day_streak_badge() {
+            let engine = create_test_engine();
+            let mut profile = GamificationProfile::new(ana());
+
+            let rewards: Vec<Reward> = (1..=7)
+                .map(|day| {
+                    let event =
+                        GamificationEvent::lesson_completed(ana(), None, Id::new(), at(day));
+                    engine.record(&mut profile, &event).unwrap()
+                })
+                .collect();
+
+            assert!(rewards[..6].iter().all(|reward| reward.badges().is_empty()));
+            assert_eq!(rewards[6].badges()[0].as_str(), "7-day streak");
+            assert_eq!(profile.badges()[0].earned_at(), at(7));
+            assert_eq!(profile.points(), 7 * 10 + 6 * 5);
+        }
+
+        #[test]
+        fn test_badge_is_earned_once() {
+            let engine = create_test_engine();
+            let mut profile = GamificationProfile::new(ana());
+            let score = Score::new(10.0, 10.0).unwrap();
+
+            let first = engine
+                .record(
+                    &mut profile,
+                    &GamificationEvent::quiz_scored(ana(), Id::new(), score, at(1)),
+                )
+                .unwrap();
+            let second = engine
+                .record(
+                    &mut profile,
+                    &GamificationEvent::quiz_scored(ana(), Id::new(), score, at(1)),
+                )
+                .unwrap();
+
+            assert_eq!(first.badges().len(), 1);
+            assert!(second.badges().is_empty());
+            assert_eq!(profile.badges().len(), 1);
+        }
+
+        #[test]
+        fn test_course_completion_levels_up() {
+            let engine = create_test_engine();
+            let mut profile = GamificationProfile::new(ana());
+            let ended = CourseEnded::new(ana(), Id::new());
+
+            let reward = engine
+                .record(&mut profile, &GamificationEvent::course_completed(&ended, at(1)))
+                .unwrap();
+
+            assert_eq!(reward.points(), 100);
+            assert!(reward.leveled_up());
+            assert_eq!(reward.level(), 2);
+            assert!(profile.has_badge("First course completed"));
+        }
+
+        #[test]
+        fn test_other_learner_is_rejected() {
+            let engine = create_test_engine();
+            let mut profile = GamificationProfile::new(ana());
+            let bob = Email::new("bob@example.com".to_string()).unwrap();
+            let event = GamificationEvent::lesson_completed(bob, None, Id::new(), at(1));
+
+            let result = engine.record(&mut profile, &event);
+
+            assert!(matches!(result, Err(GamificationError::LearnerMismatch { .. })));
+            assert_eq!(profile.points(), 0);
+        }
+    }
+
+    mod replay {
+        use super::*;
+
+        #[test]
+        fn test_builds_profile_from_events() {
+            let engine = create_test_engine();
+            let events = [
+                GamificationEvent::lesson_completed(ana(), None, Id::new(), at(1)),
+                GamificationEvent::lesson_completed(ana(), None, Id::new(), at(2)),
+            ];
+
+            let profile = engine.replay(ana(), &events).unwrap();
+
+            assert_eq!(profile.points(), 25);
+            assert_eq!(profile.lessons_completed(), 2);
+        }
+    }
+}
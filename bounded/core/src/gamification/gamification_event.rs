@@ -0,0 +1,233 @@
+use crate::{CourseEnded, ProgressEvent};
+use education_platform_common::{DateTime, Email, Id, Score};
+
+/// What a learner did that can earn points or badges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GamificationEventKind {
+    /// A lesson of a course was completed.
+    LessonCompleted {
+        course_id: Option<Id>,
+        lesson_id: Id,
+    },
+    /// A whole course was completed.
+    CourseCompleted { course_id: Id },
+    /// A quiz attempt was scored.
+    QuizScored { quiz_id: Id, score: Score },
+}
+
+/// A learner activity fed to the [`GamificationEngine`](crate::GamificationEngine).
+///
+/// Events are built from the domain events the rest of the platform
+/// already emits, so gamification never reaches into the aggregates.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseEnded, GamificationEvent, GamificationEventKind};
+/// use education_platform_common::{DateTime, Email, Id};
+///
+/// let ended = CourseEnded::new(Email::new("ana@example.com".to_string()).unwrap(), Id::new());
+/// let at = DateTime::new(2025, 3, 3, 18, 0, 0).unwrap();
+///
+/// let event = GamificationEvent::course_completed(&ended, at);
+///
+/// assert_eq!(event.learner().address(), "ana@example.com");
+/// assert_eq!(event.kind(), &GamificationEventKind::CourseCompleted { course_id: ended.course_id() });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GamificationEvent {
+    learner: Email,
+    occurred_at: DateTime,
+    kind: GamificationEventKind,
+}
+
+impl GamificationEvent {
+    /// Describes a learner completing a lesson.
+    #[must_use]
+    pub const fn lesson_completed(
+        learner: Email,
+        course_id: Option<Id>,
+        lesson_id: Id,
+        occurred_at: DateTime,
+    ) -> Self {
+        Self {
+            learner,
+            occurred_at,
+            kind: GamificationEventKind::LessonCompleted { course_id, lesson_id },
+        }
+    }
+
+    /// Describes a learner completing a course.
+    #[must_use]
+    pub fn course_completed(event: &CourseEnded, occurred_at: DateTime) -> Self {
+        Self {
+            learner: event.user_email().clone(),
+            occurred_at,
+            kind: GamificationEventKind::CourseCompleted {
+                course_id: event.course_id(),
+            },
+        }
+    }
+
+    /// Describes a learner's quiz attempt receiving a score.
+    #[must_use]
+    pub const fn quiz_scored(
+        learner: Email,
+        quiz_id: Id,
+        score: Score,
+        occurred_at: DateTime,
+    ) -> Self {
+        Self {
+            learner,
+            occurred_at,
+            kind: GamificationEventKind::QuizScored { quiz_id, score },
+        }
+    }
+
+    /// Extracts the lesson completions of a progress event stream.
+    ///
+    /// The learner and course are read from the opening
+    /// [`ProgressEvent::Enrolled`]; a stream that does not start with it
+    /// yields no events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{GamificationEvent, LessonOutline, ProgressEvent};
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let lesson_id = Id::new();
+    /// let at = DateTime::new(2025, 3, 3, 18, 0, 0).unwrap();
+    /// let stream = [
+    ///     ProgressEvent::Enrolled {
+    ///         progress_id: Id::new(),
+    ///         course_id: Some(Id::new()),
+    ///         course_version: None,
+    ///         course_name: "Rust Programming".to_string(),
+    ///         user_email: Email::new("ana@example.com".to_string()).unwrap(),
+    ///         lessons: vec![LessonOutline::new(lesson_id, "Introduction", 600)],
+    ///     },
+    ///     ProgressEvent::LessonStarted { lesson_id, at },
+    ///     ProgressEvent::LessonCompleted { lesson_id, at },
+    /// ];
+    ///
+    /// let events = GamificationEvent::from_progress_events(&stream);
+    ///
+    /// assert_eq!(events.len(), 1);
+    /// assert_eq!(events[0].occurred_at(), at);
+    /// ```
+    #[must_use]
+    pub fn from_progress_events(events: &[ProgressEvent]) -> Vec<Self> {
+        let Some(ProgressEvent::Enrolled {
+            course_id,
+            user_email,
+            ..
+        }) = events.first()
+        else {
+            return Vec::new();
+        };
+
+        events
+            .iter()
+            .filter_map(|event| match event {
+                ProgressEvent::LessonCompleted { lesson_id, at } => Some(Self::lesson_completed(
+                    user_email.clone(),
+                    *course_id,
+                    *lesson_id,
+                    *at,
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the learner the event is about.
+    #[inline]
+    #[must_use]
+    pub const fn learner(&self) -> &Email {
+        &self.learner
+    }
+
+    /// Returns when the activity happened.
+    #[inline]
+    #[must_use]
+    pub const fn occurred_at(&self) -> DateTime {
+        self.occurred_at
+    }
+
+    /// Returns what the learner did.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> &GamificationEventKind {
+        &self.kind
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LessonOutline;
+
+    fn enrolled(course_id: Id, lesson_ids: &[Id]) -> ProgressEvent {
+        ProgressEvent::Enrolled {
+            progress_id: Id::new(),
+            course_id: Some(course_id),
+            course_version: None,
+            course_name: "Rust Programming".to_string(),
+            user_email: Email::new("ana@example.com".to_string()).unwrap(),
+            lessons: lesson_ids
+                .iter()
+                .map(|id| LessonOutline::new(*id, "Lesson", 600))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_only_completions_are_extracted() {
+        let course_id = Id::new();
+        let lessons = [Id::new(), Id::new()];
+        let at = DateTime::new(2025, 3, 3, 18, 0, 0).unwrap();
+        let stream = [
+            enrolled(course_id, &lessons),
+            ProgressEvent::LessonStarted {
+                lesson_id: lessons[0],
+                at,
+            },
+            ProgressEvent::LessonCompleted {
+                lesson_id: lessons[0],
+                at,
+            },
+            ProgressEvent::LessonRestarted {
+                lesson_id: lessons[0],
+                at,
+            },
+            ProgressEvent::LessonCompleted {
+                lesson_id: lessons[1],
+                at,
+            },
+        ];
+
+        let events = GamificationEvent::from_progress_events(&stream);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].learner().address(), "ana@example.com");
+        assert_eq!(
+            events[1].kind(),
+            &GamificationEventKind::LessonCompleted {
+                course_id: Some(course_id),
+                lesson_id: lessons[1],
+            }
+        );
+    }
+
+    #[test]
+    fn test_stream_without_enrollment_is_ignored() {
+        let at = DateTime::new(2025, 3, 3, 18, 0, 0).unwrap();
+        let stream = [ProgressEvent::LessonCompleted {
+            lesson_id: Id::new(),
+            at,
+        }];
+
+        assert!(GamificationEvent::from_progress_events(&stream).is_empty());
+    }
+}
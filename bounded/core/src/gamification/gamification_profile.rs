@@ -0,0 +1,254 @@
+use crate::{EarnedBadge, GamificationEvent, GamificationEventKind, PointsPolicy};
+use education_platform_common::{Date, Email, Id, Percentage};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// A learner's points, activity and badges, built up from their
+/// [`GamificationEvent`]s by the [`GamificationEngine`](crate::GamificationEngine).
+///
+/// Repeated activity does not pay twice: a lesson or course completed
+/// again earns nothing, and retaking a quiz only earns the points the new
+/// score adds over the best one so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamificationProfile {
+    learner: Email,
+    points: u64,
+    lessons: HashSet<Id>,
+    courses: HashSet<Id>,
+    quiz_points: HashMap<Id, u64>,
+    best_quiz_score: Option<Percentage>,
+    active_days: BTreeSet<Date>,
+    longest_streak: u32,
+    badges: Vec<EarnedBadge>,
+}
+
+impl GamificationProfile {
+    /// Creates an empty profile for a learner.
+    #[must_use]
+    pub fn new(learner: Email) -> Self {
+        Self {
+            learner,
+            points: 0,
+            lessons: HashSet::new(),
+            courses: HashSet::new(),
+            quiz_points: HashMap::new(),
+            best_quiz_score: None,
+            active_days: BTreeSet::new(),
+            longest_streak: 0,
+            badges: Vec::new(),
+        }
+    }
+
+    /// Returns the learner the profile belongs to.
+    #[inline]
+    #[must_use]
+    pub const fn learner(&self) -> &Email {
+        &self.learner
+    }
+
+    /// Returns the points collected so far.
+    #[inline]
+    #[must_use]
+    pub const fn points(&self) -> u64 {
+        self.points
+    }
+
+    /// Returns how many distinct lessons the learner has completed.
+    #[inline]
+    #[must_use]
+    pub fn lessons_completed(&self) -> usize {
+        self.lessons.len()
+    }
+
+    /// Returns how many distinct courses the learner has completed.
+    #[inline]
+    #[must_use]
+    pub fn courses_completed(&self) -> usize {
+        self.courses.len()
+    }
+
+    /// Returns the learner's best score on any quiz.
+    #[inline]
+    #[must_use]
+    pub const fn best_quiz_score(&self) -> Option<Percentage> {
+        self.best_quiz_score
+    }
+
+    /// Returns the longest run of consecutive days with activity.
+    #[inline]
+    #[must_use]
+    pub const fn longest_streak(&self) -> u32 {
+        self.longest_streak
+    }
+
+    /// Returns the badges earned, in the order they were earned.
+    #[inline]
+    #[must_use]
+    pub fn badges(&self) -> &[EarnedBadge] {
+        &self.badges
+    }
+
+    /// Returns `true` if the learner has earned the badge with this name.
+    #[must_use]
+    pub fn has_badge(&self, name: &str) -> bool {
+        self.badges
+            .iter()
+            .any(|badge| badge.name().as_str() == name)
+    }
+
+    /// Records the activity and returns the points it earned.
+    pub(crate) fn apply(&mut self, event: &GamificationEvent, policy: &PointsPolicy) -> u64 {
+        let mut earned = self.mark_active(event.occurred_at().date(), policy);
+
+        earned += match event.kind() {
+            GamificationEventKind::LessonCompleted { lesson_id, .. } => {
+                match self.lessons.insert(*lesson_id) {
+                    true => policy.lesson_completed(),
+                    false => 0,
+                }
+            }
+            GamificationEventKind::CourseCompleted { course_id } => {
+                match self.courses.insert(*course_id) {
+                    true => policy.course_completed(),
+                    false => 0,
+                }
+            }
+            GamificationEventKind::QuizScored { quiz_id, score } => {
+                let percentage = score.percentage();
+                if self
+                    .best_quiz_score
+                    .is_none_or(|best| percentage.value() > best.value())
+                {
+                    self.best_quiz_score = Some(percentage);
+                }
+
+                let worth = policy.quiz(percentage);
+                let previous = self.quiz_points.entry(*quiz_id).or_insert(0);
+                let improvement = worth.saturating_sub(*previous);
+                *previous = (*previous).max(worth);
+                improvement
+            }
+        };
+
+        self.points = self.points.saturating_add(earned);
+        earned
+    }
+
+    pub(crate) fn award(&mut self, badge: EarnedBadge) {
+        self.badges.push(badge);
+    }
+
+    /// Adds the day to the active days and pays the streak bonus when it
+    /// is a new day following an active one.
+    fn mark_active(&mut self, day: Date, policy: &PointsPolicy) -> u64 {
+        if !self.active_days.insert(day) {
+            return 0;
+        }
+
+        let streak = self.streak_through(day);
+        self.longest_streak = self.longest_streak.max(streak);
+        match self.active_days.contains(&day.sub_days(1)) {
+            true => policy.streak_day(),
+            false => 0,
+        }
+    }
+
+    /// Returns the length of the run of active days containing `day`.
+    fn streak_through(&self, day: Date) -> u32 {
+        let mut streak = 1;
+        let mut before = day.sub_days(1);
+        while self.active_days.contains(&before) {
+            streak += 1;
+            before = before.sub_days(1);
+        }
+        let mut after = day.add_days(1);
+        while self.active_days.contains(&after) {
+            streak += 1;
+            after = after.add_days(1);
+        }
+        streak
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::{DateTime, Score};
+
+    fn learner() -> Email {
+        Email::new("ana@example.com".to_string()).unwrap()
+    }
+
+    fn at(day: u32) -> DateTime {
+        DateTime::new(2025, 3, day, 18, 0, 0).unwrap()
+    }
+
+    fn lesson(lesson_id: Id, day: u32) -> GamificationEvent {
+        GamificationEvent::lesson_completed(learner(), None, lesson_id, at(day))
+    }
+
+    fn quiz(quiz_id: Id, points: f64, day: u32) -> GamificationEvent {
+        GamificationEvent::quiz_scored(
+            learner(),
+            quiz_id,
+            Score::new(points, 10.0).unwrap(),
+            at(day),
+        )
+    }
+
+    mod apply {
+        use super::*;
+
+        #[test]
+        fn test_lesson_pays_once() {
+            let mut profile = GamificationProfile::new(learner());
+            let policy = PointsPolicy::default();
+            let lesson_id = Id::new();
+
+            assert_eq!(profile.apply(&lesson(lesson_id, 1), &policy), 10);
+            assert_eq!(profile.apply(&lesson(lesson_id, 1), &policy), 0);
+            assert_eq!(profile.lessons_completed(), 1);
+            assert_eq!(profile.points(), 10);
+        }
+
+        #[test]
+        fn test_quiz_retake_pays_the_improvement() {
+            let mut profile = GamificationProfile::new(learner());
+            let policy = PointsPolicy::default();
+            let quiz_id = Id::new();
+
+            assert_eq!(profile.apply(&quiz(quiz_id, 5.0, 1), &policy), 10);
+            assert_eq!(profile.apply(&quiz(quiz_id, 3.0, 1), &policy), 0);
+            assert_eq!(profile.apply(&quiz(quiz_id, 9.0, 1), &policy), 8);
+            assert_eq!(profile.points(), 18);
+            assert_eq!(profile.best_quiz_score(), Some(Percentage::new(90.0).unwrap()));
+        }
+
+        #[test]
+        fn test_streak_bonus_on_consecutive_days() {
+            let mut profile = GamificationProfile::new(learner());
+            let policy = PointsPolicy::default();
+
+            profile.apply(&lesson(Id::new(), 1), &policy);
+            let second_day = profile.apply(&lesson(Id::new(), 2), &policy);
+            let same_day = profile.apply(&lesson(Id::new(), 2), &policy);
+            let after_gap = profile.apply(&lesson(Id::new(), 5), &policy);
+
+            assert_eq!(second_day, 15);
+            assert_eq!(same_day, 10);
+            assert_eq!(after_gap, 10);
+            assert_eq!(profile.longest_streak(), 2);
+        }
+
+        #[test]
+        fn test_late_event_joins_two_runs() {
+            let mut profile = GamificationProfile::new(learner());
+            let policy = PointsPolicy::default();
+
+            profile.apply(&lesson(Id::new(), 1), &policy);
+            profile.apply(&lesson(Id::new(), 3), &policy);
+            profile.apply(&lesson(Id::new(), 2), &policy);
+
+            assert_eq!(profile.longest_streak(), 3);
+        }
+    }
+}
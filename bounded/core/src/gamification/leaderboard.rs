@@ -0,0 +1,533 @@
+use crate::{Classroom, GamificationEngine, GamificationEvent, GamificationProfile};
+use education_platform_common::{Date, Email};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Error types for leaderboard pagination.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LeaderboardError {
+    #[error("Page numbers start at 1")]
+    PageIsZero,
+
+    #[error("Page size must be different from zero")]
+    PageSizeIsZero,
+}
+
+/// What learners are ranked by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeaderboardMetric {
+    /// Points earned, as paid by the engine's points policy.
+    Points,
+    /// Distinct lessons completed.
+    LessonsCompleted,
+}
+
+impl LeaderboardMetric {
+    fn score(self, profile: &GamificationProfile) -> u64 {
+        match self {
+            Self::Points => profile.points(),
+            Self::LessonsCompleted => profile.lessons_completed() as u64,
+        }
+    }
+}
+
+/// The period a leaderboard covers, counted in calendar terms so every
+/// learner starts the week or month from zero on the same day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeWindow {
+    /// Since Monday of the current week.
+    Weekly,
+    /// Since the first day of the current month.
+    Monthly,
+    /// Since the beginning.
+    AllTime,
+}
+
+impl TimeWindow {
+    /// Returns the first day of the window containing `today`, or `None`
+    /// for [`TimeWindow::AllTime`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::TimeWindow;
+    /// use education_platform_common::Date;
+    ///
+    /// let thursday = Date::new(2025, 3, 13).unwrap();
+    ///
+    /// assert_eq!(TimeWindow::Weekly.starts_on(thursday), Some(Date::new(2025, 3, 10).unwrap()));
+    /// assert_eq!(TimeWindow::Monthly.starts_on(thursday), Some(Date::new(2025, 3, 1).unwrap()));
+    /// assert_eq!(TimeWindow::AllTime.starts_on(thursday), None);
+    /// ```
+    #[must_use]
+    pub fn starts_on(&self, today: Date) -> Option<Date> {
+        match self {
+            Self::Weekly => Some(today.sub_days(i64::from(today.weekday()) - 1)),
+            Self::Monthly => Some(today.sub_days(i64::from(today.day()) - 1)),
+            Self::AllTime => None,
+        }
+    }
+
+    /// Returns `true` if `day` falls in the window containing `today`.
+    /// Days after `today` are outside every window.
+    #[must_use]
+    pub fn contains(&self, day: Date, today: Date) -> bool {
+        day <= today && self.starts_on(today).is_none_or(|start| start <= day)
+    }
+}
+
+/// A learner's position on a leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    rank: u32,
+    learner: Email,
+    score: u64,
+}
+
+impl LeaderboardEntry {
+    /// Returns the rank, starting at 1. Tied learners share a rank.
+    #[inline]
+    #[must_use]
+    pub const fn rank(&self) -> u32 {
+        self.rank
+    }
+
+    /// Returns the learner.
+    #[inline]
+    #[must_use]
+    pub const fn learner(&self) -> &Email {
+        &self.learner
+    }
+
+    /// Returns the learner's score in the ranked metric.
+    #[inline]
+    #[must_use]
+    pub const fn score(&self) -> u64 {
+        self.score
+    }
+}
+
+/// One page of a [`Ranking`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardPage<'a> {
+    entries: &'a [LeaderboardEntry],
+    page: usize,
+    page_size: usize,
+    total_entries: usize,
+}
+
+impl LeaderboardPage<'_> {
+    /// Returns the entries on the page; empty past the last page.
+    #[inline]
+    #[must_use]
+    pub const fn entries(&self) -> &[LeaderboardEntry] {
+        self.entries
+    }
+
+    /// Returns the page number, starting at 1.
+    #[inline]
+    #[must_use]
+    pub const fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns how many entries are shown per page.
+    #[inline]
+    #[must_use]
+    pub const fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns how many entries the whole ranking has.
+    #[inline]
+    #[must_use]
+    pub const fn total_entries(&self) -> usize {
+        self.total_entries
+    }
+
+    /// Returns how many pages the whole ranking spans.
+    #[inline]
+    #[must_use]
+    pub const fn total_pages(&self) -> usize {
+        self.total_entries.div_ceil(self.page_size)
+    }
+
+    /// Returns `true` if later pages have entries.
+    #[inline]
+    #[must_use]
+    pub const fn has_next(&self) -> bool {
+        self.page < self.total_pages()
+    }
+}
+
+/// Learners ordered by score, as computed by a [`Leaderboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    entries: Vec<LeaderboardEntry>,
+}
+
+impl Ranking {
+    /// Returns every entry, best first.
+    #[inline]
+    #[must_use]
+    pub fn entries(&self) -> &[LeaderboardEntry] {
+        &self.entries
+    }
+
+    /// Returns the entry of a learner, if they are ranked.
+    #[must_use]
+    pub fn entry_of(&self, learner: &Email) -> Option<&LeaderboardEntry> {
+        self.entries.iter().find(|entry| entry.learner() == learner)
+    }
+
+    /// Returns one page of the ranking; pages are numbered from 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LeaderboardError::PageIsZero` if `page` is zero, or
+    /// `LeaderboardError::PageSizeIsZero` if `page_size` is zero.
+    pub fn page(
+        &self,
+        page: usize,
+        page_size: usize,
+    ) -> Result<LeaderboardPage<'_>, LeaderboardError> {
+        if page == 0 {
+            return Err(LeaderboardError::PageIsZero);
+        }
+        if page_size == 0 {
+            return Err(LeaderboardError::PageSizeIsZero);
+        }
+
+        let start = (page - 1).saturating_mul(page_size).min(self.entries.len());
+        let end = start.saturating_add(page_size).min(self.entries.len());
+        Ok(LeaderboardPage {
+            entries: &self.entries[start..end],
+            page,
+            page_size,
+            total_entries: self.entries.len(),
+        })
+    }
+}
+
+/// Ranks learners by their gamification activity over a time window,
+/// either everyone or the students of one classroom.
+///
+/// Scores only count activity inside the window: each learner's events in
+/// the window are replayed through the [`GamificationEngine`], so points
+/// follow the same policy as their profile. Tied learners share a rank
+/// and the next rank is skipped (1, 1, 3); within a tie learners are
+/// listed by email so pages stay stable.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     GamificationEngine, GamificationEvent, Leaderboard, LeaderboardMetric, TimeWindow,
+/// };
+/// use education_platform_common::{Date, DateTime, Email, Id};
+///
+/// let ana = Email::new("ana@example.com".to_string()).unwrap();
+/// let ben = Email::new("ben@example.com".to_string()).unwrap();
+/// let at = DateTime::new(2025, 3, 12, 18, 0, 0).unwrap();
+/// let events = [
+///     GamificationEvent::lesson_completed(ana.clone(), None, Id::new(), at),
+///     GamificationEvent::lesson_completed(ben.clone(), None, Id::new(), at),
+///     GamificationEvent::lesson_completed(ben.clone(), None, Id::new(), at),
+/// ];
+///
+/// let leaderboard = Leaderboard::new(LeaderboardMetric::LessonsCompleted, TimeWindow::Weekly);
+/// let ranking =
+///     leaderboard.rank(&GamificationEngine::default(), &events, Date::new(2025, 3, 13).unwrap());
+///
+/// assert_eq!(ranking.entries()[0].learner(), &ben);
+/// assert_eq!(ranking.entry_of(&ana).unwrap().rank(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    metric: LeaderboardMetric,
+    window: TimeWindow,
+    roster: Option<Vec<Email>>,
+}
+
+impl Leaderboard {
+    /// Creates a leaderboard over every learner with activity in the
+    /// window.
+    #[must_use]
+    pub const fn new(metric: LeaderboardMetric, window: TimeWindow) -> Self {
+        Self {
+            metric,
+            window,
+            roster: None,
+        }
+    }
+
+    /// Restricts the leaderboard to the students of a classroom.
+    ///
+    /// Every student on the roster is ranked, with a score of zero when
+    /// they had no activity in the window.
+    #[must_use]
+    pub fn for_classroom(mut self, classroom: &Classroom) -> Self {
+        self.roster = Some(classroom.roster().to_vec());
+        self
+    }
+
+    /// Returns the metric learners are ranked by.
+    #[inline]
+    #[must_use]
+    pub const fn metric(&self) -> LeaderboardMetric {
+        self.metric
+    }
+
+    /// Returns the period the leaderboard covers.
+    #[inline]
+    #[must_use]
+    pub const fn window(&self) -> TimeWindow {
+        self.window
+    }
+
+    /// Ranks learners by the events that fall in the window containing
+    /// `today`.
+    #[must_use]
+    pub fn rank(
+        &self,
+        engine: &GamificationEngine,
+        events: &[GamificationEvent],
+        today: Date,
+    ) -> Ranking {
+        let mut by_learner: BTreeMap<&str, (&Email, Vec<&GamificationEvent>)> = BTreeMap::new();
+        if let Some(roster) = &self.roster {
+            for student in roster {
+                by_learner.insert(student.address(), (student, Vec::new()));
+            }
+        }
+
+        for event in events {
+            if !self.window.contains(event.occurred_at().date(), today) {
+                continue;
+            }
+            let learner = event.learner();
+            match (&self.roster, by_learner.get_mut(learner.address())) {
+                (_, Some((_, learner_events))) => learner_events.push(event),
+                (None, None) => {
+                    by_learner.insert(learner.address(), (learner, vec![event]));
+                }
+                (Some(_), None) => {}
+            }
+        }
+
+        let mut scores: Vec<(&Email, u64)> = by_learner
+            .into_values()
+            .map(|(learner, mut learner_events)| {
+                learner_events.sort_by_key(|event| event.occurred_at());
+                let score = engine
+                    .replay(learner.clone(), learner_events)
+                    .map(|profile| self.metric.score(&profile))
+                    .unwrap_or(0);
+                (learner, score)
+            })
+            .collect();
+        // Learners come out of the map sorted by email, and the stable sort
+        // keeps that order within ties.
+        scores.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+
+        let mut entries: Vec<LeaderboardEntry> = Vec::with_capacity(scores.len());
+        for (position, (learner, score)) in scores.into_iter().enumerate() {
+            let rank = match entries.last() {
+                Some(previous) if previous.score == score => previous.rank,
+                _ => u32::try_from(position + 1).unwrap_or(u32::MAX),
+            };
+            entries.push(LeaderboardEntry {
+                rank,
+                learner: learner.clone(),
+                score,
+            });
+        }
+        Ranking { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::{DateTime, Id};
+
+    fn email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn lesson(learner: &str, month: u32, day: u32) -> GamificationEvent {
+        let at = DateTime::new(2025, month, day, 18, 0, 0).unwrap();
+        GamificationEvent::lesson_completed(email(learner), None, Id::new(), at)
+    }
+
+    fn today() -> Date {
+        Date::new(2025, 3, 13).unwrap()
+    }
+
+    fn lessons_ranking(window: TimeWindow, events: &[GamificationEvent]) -> Ranking {
+        Leaderboard::new(LeaderboardMetric::LessonsCompleted, window).rank(
+            &GamificationEngine::default(),
+            events,
+            today(),
+        )
+    }
+
+    mod time_window {
+        use super::*;
+
+        #[test]
+        fn test_weekly_starts_on_monday() {
+            let monday = Date::new(2025, 3, 10).unwrap();
+            let sunday = Date::new(2025, 3, 16).unwrap();
+
+            assert_eq!(TimeWindow::Weekly.starts_on(monday), Some(monday));
+            assert_eq!(TimeWindow::Weekly.starts_on(sunday), Some(monday));
+        }
+
+        #[test]
+        fn test_future_days_are_outside() {
+            let tomorrow = today().add_days(1);
+
+            assert!(!TimeWindow::AllTime.contains(tomorrow, today()));
+            assert!(TimeWindow::AllTime.contains(Date::new(2001, 1, 1).unwrap(), today()));
+        }
+    }
+
+    mod rank {
+        use super::*;
+
+        #[test]
+        fn test_only_events_in_window_count() {
+            let events = [
+                lesson("ana@example.com", 3, 3),
+                lesson("ana@example.com", 3, 11),
+                lesson("ben@example.com", 2, 27),
+            ];
+
+            let weekly = lessons_ranking(TimeWindow::Weekly, &events);
+            let monthly = lessons_ranking(TimeWindow::Monthly, &events);
+            let all_time = lessons_ranking(TimeWindow::AllTime, &events);
+
+            assert_eq!(weekly.entries().len(), 1);
+            assert_eq!(weekly.entries()[0].score(), 1);
+            assert_eq!(monthly.entries()[0].score(), 2);
+            assert_eq!(all_time.entries().len(), 2);
+        }
+
+        #[test]
+        fn test_ties_share_rank_and_skip_the_next() {
+            let events = [
+                lesson("cleo@example.com", 3, 11),
+                lesson("ben@example.com", 3, 11),
+                lesson("ana@example.com", 3, 11),
+                lesson("ana@example.com", 3, 12),
+                lesson("ben@example.com", 3, 12),
+            ];
+
+            let ranking = lessons_ranking(TimeWindow::Weekly, &events);
+
+            let standings: Vec<(u32, &str)> = ranking
+                .entries()
+                .iter()
+                .map(|entry| (entry.rank(), entry.learner().address()))
+                .collect();
+            assert_eq!(
+                standings,
+                vec![
+                    (1, "ana@example.com"),
+                    (1, "ben@example.com"),
+                    (3, "cleo@example.com"),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_points_follow_the_engine_policy() {
+            let events = [
+                lesson("ana@example.com", 3, 11),
+                lesson("ana@example.com", 3, 12),
+                lesson("ben@example.com", 3, 12),
+            ];
+
+            let ranking = Leaderboard::new(LeaderboardMetric::Points, TimeWindow::Weekly).rank(
+                &GamificationEngine::default(),
+                &events,
+                today(),
+            );
+
+            assert_eq!(ranking.entry_of(&email("ana@example.com")).unwrap().score(), 25);
+            assert_eq!(ranking.entry_of(&email("ben@example.com")).unwrap().score(), 10);
+        }
+
+        #[test]
+        fn test_classroom_ranks_whole_roster_only() {
+            let date = Date::new(2025, 3, 1).unwrap();
+            let mut classroom = Classroom::new(
+                Id::new().cast(),
+                email("teacher@example.com"),
+                date,
+                date.add_days(90),
+                10,
+            )
+            .unwrap();
+            classroom.enroll(email("ana@example.com")).unwrap();
+            classroom.enroll(email("ben@example.com")).unwrap();
+            let events = [
+                lesson("ana@example.com", 3, 11),
+                lesson("eve@example.com", 3, 11),
+            ];
+
+            let ranking = Leaderboard::new(LeaderboardMetric::LessonsCompleted, TimeWindow::Weekly)
+                .for_classroom(&classroom)
+                .rank(&GamificationEngine::default(), &events, today());
+
+            assert_eq!(ranking.entries().len(), 2);
+            assert_eq!(ranking.entry_of(&email("ben@example.com")).unwrap().score(), 0);
+            assert!(ranking.entry_of(&email("eve@example.com")).is_none());
+        }
+    }
+
+    mod page {
+        use super::*;
+
+        fn create_test_ranking(learners: usize) -> Ranking {
+            let events: Vec<GamificationEvent> = (0..learners)
+                .map(|index| lesson(&format!("learner{index}@example.com"), 3, 11))
+                .collect();
+            lessons_ranking(TimeWindow::AllTime, &events)
+        }
+
+        #[test]
+        fn test_pages_split_the_ranking() {
+            let ranking = create_test_ranking(5);
+
+            let second = ranking.page(2, 2).unwrap();
+            let last = ranking.page(3, 2).unwrap();
+
+            assert_eq!(second.entries(), &ranking.entries()[2..4]);
+            assert_eq!(second.total_pages(), 3);
+            assert!(second.has_next());
+            assert_eq!(last.entries().len(), 1);
+            assert!(!last.has_next());
+        }
+
+        #[test]
+        fn test_page_past_the_end_is_empty() {
+            let ranking = create_test_ranking(3);
+
+            let page = ranking.page(10, 2).unwrap();
+
+            assert!(page.entries().is_empty());
+            assert_eq!(page.total_entries(), 3);
+        }
+
+        #[test]
+        fn test_zero_page_or_size() {
+            let ranking = create_test_ranking(1);
+
+            assert_eq!(ranking.page(0, 10), Err(LeaderboardError::PageIsZero));
+            assert_eq!(ranking.page(1, 0), Err(LeaderboardError::PageSizeIsZero));
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::GamificationError;
+
+/// Turns points into levels, each level costing more than the previous.
+///
+/// Everyone starts at level 1. Reaching level `n + 1` from level `n` takes
+/// `n` times the base points, so with a base of 100 the levels start at 0,
+/// 100, 300, 600 and 1000 points.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::LevelCurve;
+///
+/// let curve = LevelCurve::new(100).unwrap();
+///
+/// assert_eq!(curve.level_for(0), 1);
+/// assert_eq!(curve.level_for(299), 2);
+/// assert_eq!(curve.level_for(300), 3);
+/// assert_eq!(curve.points_for_level(4), 600);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelCurve {
+    base_points: u64,
+}
+
+impl Default for LevelCurve {
+    fn default() -> Self {
+        Self { base_points: 100 }
+    }
+}
+
+impl LevelCurve {
+    /// Creates a curve where level 2 takes `base_points`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GamificationError::LevelBaseIsZero` if `base_points` is zero.
+    pub const fn new(base_points: u64) -> Result<Self, GamificationError> {
+        if base_points == 0 {
+            return Err(GamificationError::LevelBaseIsZero);
+        }
+        Ok(Self { base_points })
+    }
+
+    /// Returns the points needed to reach a level; levels 0 and 1 need
+    /// none. Saturates at `u64::MAX` for levels out of reach.
+    #[must_use]
+    pub fn points_for_level(&self, level: u32) -> u64 {
+        u64::try_from(self.exact_points_for_level(level)).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the level reached with the given points.
+    #[must_use]
+    pub fn level_for(&self, points: u64) -> u32 {
+        // Solve n * (n - 1) / 2 <= points / base for the largest n, then
+        // correct the floating point estimate.
+        let steps = (points / self.base_points) as f64;
+        let estimate = ((1.0 + (1.0 + 8.0 * steps).sqrt()) / 2.0).floor();
+        let mut level = u32::try_from(estimate as u64).unwrap_or(u32::MAX).max(1);
+        while level > 1 && self.exact_points_for_level(level) > u128::from(points) {
+            level -= 1;
+        }
+        while level < u32::MAX && self.exact_points_for_level(level + 1) <= u128::from(points) {
+            level += 1;
+        }
+        level
+    }
+
+    /// Returns how many more points are needed for the next level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LevelCurve;
+    ///
+    /// let curve = LevelCurve::new(100).unwrap();
+    ///
+    /// assert_eq!(curve.points_to_next_level(250), 50);
+    /// ```
+    #[must_use]
+    pub fn points_to_next_level(&self, points: u64) -> u64 {
+        let next = self.points_for_level(self.level_for(points).saturating_add(1));
+        next.saturating_sub(points)
+    }
+
+    fn exact_points_for_level(&self, level: u32) -> u128 {
+        let level = u128::from(level.max(1));
+        u128::from(self.base_points) * (level * (level - 1) / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_base_is_rejected() {
+        assert_eq!(LevelCurve::new(0), Err(GamificationError::LevelBaseIsZero));
+    }
+
+    #[test]
+    fn test_each_level_costs_more() {
+        let curve = LevelCurve::new(50).unwrap();
+
+        let costs: Vec<u64> = (1..5)
+            .map(|level| curve.points_for_level(level + 1) - curve.points_for_level(level))
+            .collect();
+
+        assert_eq!(costs, vec![50, 100, 150, 200]);
+    }
+
+    #[test]
+    fn test_level_boundaries() {
+        let curve = LevelCurve::default();
+
+        assert_eq!(curve.level_for(99), 1);
+        assert_eq!(curve.level_for(100), 2);
+        assert_eq!(curve.level_for(999), 4);
+        assert_eq!(curve.level_for(1000), 5);
+    }
+
+    #[test]
+    fn test_huge_point_totals_do_not_overflow() {
+        let steep = LevelCurve::new(u64::MAX).unwrap();
+        let flat = LevelCurve::new(1).unwrap();
+
+        assert_eq!(steep.level_for(u64::MAX), 2);
+        assert_eq!(steep.points_for_level(3), u64::MAX);
+        assert_eq!(flat.level_for(u64::MAX), 4_294_967_295);
+    }
+
+    #[test]
+    fn test_level_matches_point_thresholds() {
+        let curve = LevelCurve::new(7).unwrap();
+
+        for level in 1..200 {
+            let threshold = curve.points_for_level(level);
+            assert_eq!(curve.level_for(threshold), level);
+            if threshold > 0 {
+                assert_eq!(curve.level_for(threshold - 1), level - 1);
+            }
+        }
+    }
+}
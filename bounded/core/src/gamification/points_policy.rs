@@ -0,0 +1,118 @@
+use education_platform_common::Percentage;
+
+/// How many points each kind of activity is worth.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::PointsPolicy;
+/// use education_platform_common::Percentage;
+///
+/// let policy = PointsPolicy::default().with_quiz_points(50);
+///
+/// assert_eq!(policy.quiz(Percentage::new(80.0).unwrap()), 40);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointsPolicy {
+    lesson_completed: u64,
+    course_completed: u64,
+    streak_day: u64,
+    quiz_points: u64,
+}
+
+impl Default for PointsPolicy {
+    /// Ten points per lesson, a hundred per course, five per day a streak
+    /// is kept going, and up to twenty per quiz.
+    fn default() -> Self {
+        Self {
+            lesson_completed: 10,
+            course_completed: 100,
+            streak_day: 5,
+            quiz_points: 20,
+        }
+    }
+}
+
+impl PointsPolicy {
+    /// Sets the points for completing a lesson.
+    #[must_use]
+    pub const fn with_lesson_points(mut self, points: u64) -> Self {
+        self.lesson_completed = points;
+        self
+    }
+
+    /// Sets the points for completing a course.
+    #[must_use]
+    pub const fn with_course_points(mut self, points: u64) -> Self {
+        self.course_completed = points;
+        self
+    }
+
+    /// Sets the bonus for each day that extends a streak.
+    #[must_use]
+    pub const fn with_streak_points(mut self, points: u64) -> Self {
+        self.streak_day = points;
+        self
+    }
+
+    /// Sets the points for a perfect quiz score.
+    #[must_use]
+    pub const fn with_quiz_points(mut self, points: u64) -> Self {
+        self.quiz_points = points;
+        self
+    }
+
+    /// Returns the points for completing a lesson.
+    #[inline]
+    #[must_use]
+    pub const fn lesson_completed(&self) -> u64 {
+        self.lesson_completed
+    }
+
+    /// Returns the points for completing a course.
+    #[inline]
+    #[must_use]
+    pub const fn course_completed(&self) -> u64 {
+        self.course_completed
+    }
+
+    /// Returns the bonus for each day that extends a streak.
+    #[inline]
+    #[must_use]
+    pub const fn streak_day(&self) -> u64 {
+        self.streak_day
+    }
+
+    /// Returns the points a quiz score is worth, proportional to the score
+    /// and rounded to the nearest point.
+    #[must_use]
+    pub fn quiz(&self, score: Percentage) -> u64 {
+        (self.quiz_points as f64 * score.ratio()).round() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quiz_points_are_proportional() {
+        let policy = PointsPolicy::default();
+
+        assert_eq!(policy.quiz(Percentage::FULL), 20);
+        assert_eq!(policy.quiz(Percentage::ZERO), 0);
+        assert_eq!(policy.quiz(Percentage::new(72.0).unwrap()), 14);
+    }
+
+    #[test]
+    fn test_builders_override_defaults() {
+        let policy = PointsPolicy::default()
+            .with_lesson_points(1)
+            .with_course_points(2)
+            .with_streak_points(3);
+
+        assert_eq!(policy.lesson_completed(), 1);
+        assert_eq!(policy.course_completed(), 2);
+        assert_eq!(policy.streak_day(), 3);
+    }
+}
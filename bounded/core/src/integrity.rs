@@ -0,0 +1,7 @@
+mod anomaly_detector;
+mod review_queue;
+mod thresholds;
+
+pub use anomaly_detector::*;
+pub use review_queue::*;
+pub use thresholds::*;
@@ -0,0 +1,284 @@
+use crate::{CourseProgress, IntegrityThresholds};
+use education_platform_common::{DateTime, Duration};
+
+/// An implausible pattern found in a learner's progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anomaly {
+    /// Several lessons were finished in a fraction of their length.
+    RushedLessons { count: usize },
+    /// Lessons followed each other without a real break for too long.
+    ContinuousActivity {
+        started_at: DateTime,
+        length: Duration,
+    },
+}
+
+/// Looks for implausible patterns in lesson start and end times.
+///
+/// Only lessons with both a start and an end date are considered, so a
+/// lesson that is still in progress never counts against the learner.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{AnomalyDetector, CourseProgress, LessonProgress};
+/// use education_platform_common::DateTime;
+///
+/// // Three two-hour lessons, each finished three minutes after it started.
+/// let lessons = (0..3)
+///     .map(|day| {
+///         let start = DateTime::new(2024, 3, 1 + day, 10, 0, 0).unwrap();
+///         LessonProgress::new(format!("Lesson {day}"), 7200, Some(start), Some(start.add_seconds(180)))
+///             .unwrap()
+///     })
+///     .collect();
+/// let progress = CourseProgress::builder()
+///     .course_name("Rust Course")
+///     .user_email("user@example.com")
+///     .lessons(lessons)
+///     .build()
+///     .unwrap();
+///
+/// let anomalies = AnomalyDetector::default().detect(&progress);
+///
+/// assert_eq!(anomalies.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AnomalyDetector {
+    thresholds: IntegrityThresholds,
+}
+
+impl AnomalyDetector {
+    /// Creates a detector using the given thresholds.
+    #[must_use]
+    pub const fn new(thresholds: IntegrityThresholds) -> Self {
+        Self { thresholds }
+    }
+
+    /// Returns the thresholds in use.
+    #[inline]
+    #[must_use]
+    pub const fn thresholds(&self) -> &IntegrityThresholds {
+        &self.thresholds
+    }
+
+    /// Returns every anomaly found in the progress, rushed lessons first and
+    /// then each over-long session in chronological order.
+    #[must_use]
+    pub fn detect(&self, progress: &CourseProgress) -> Vec<Anomaly> {
+        let intervals: Vec<(DateTime, DateTime)> = progress
+            .lesson_progress()
+            .iter()
+            .filter_map(|lesson| Some((*lesson.start_date()?, *lesson.end_date()?)))
+            .collect();
+
+        let rushed = progress
+            .lesson_progress()
+            .iter()
+            .filter(|lesson| {
+                self.is_rushed(lesson.duration(), lesson.start_date(), lesson.end_date())
+            })
+            .count();
+
+        let mut anomalies = Vec::new();
+        if rushed >= self.thresholds.rushed_lessons() {
+            anomalies.push(Anomaly::RushedLessons { count: rushed });
+        }
+        anomalies.extend(self.long_sessions(intervals));
+        anomalies
+    }
+
+    fn is_rushed(
+        &self,
+        length: Duration,
+        start: Option<&DateTime>,
+        end: Option<&DateTime>,
+    ) -> bool {
+        let (Some(start), Some(end)) = (start, end) else {
+            return false;
+        };
+        let spent = start.seconds_until(end).max(0) as f64;
+        spent < length.total_seconds() as f64 * self.thresholds.min_watch_ratio()
+    }
+
+    fn long_sessions(&self, mut intervals: Vec<(DateTime, DateTime)>) -> Vec<Anomaly> {
+        intervals.sort();
+        let gap = self.thresholds.session_gap().total_seconds() as i64;
+        let max = self.thresholds.max_continuous_activity().total_seconds() as i64;
+
+        let mut sessions: Vec<(DateTime, DateTime)> = Vec::new();
+        for (start, end) in intervals {
+            match sessions.last_mut() {
+                Some((_, session_end)) if session_end.seconds_until(&start) <= gap => {
+                    *session_end = (*session_end).max(end);
+                }
+                _ => sessions.push((start, end)),
+            }
+        }
+
+        sessions
+            .into_iter()
+            .filter_map(|(start, end)| {
+                let length = start.seconds_until(&end);
+                (length >= max).then(|| Anomaly::ContinuousActivity {
+                    started_at: start,
+                    length: Duration::from_seconds(length.unsigned_abs()),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LessonProgress;
+
+    const DURATION_2_HOURS: u64 = 7200;
+
+    fn create_time(day: u32, hour: u32, minute: u32) -> DateTime {
+        DateTime::new(2024, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn create_lesson(name: &str, duration: u64, start: DateTime, minutes: i64) -> LessonProgress {
+        LessonProgress::new(
+            name.to_string(),
+            duration,
+            Some(start),
+            Some(start.add_seconds(minutes * 60)),
+        )
+        .unwrap()
+    }
+
+    fn create_progress(lessons: Vec<LessonProgress>) -> CourseProgress {
+        CourseProgress::builder()
+            .course_name("Rust Course")
+            .user_email("student@example.com")
+            .lessons(lessons)
+            .build()
+            .unwrap()
+    }
+
+    mod rushed_lessons {
+        use super::*;
+
+        #[test]
+        fn test_below_repeat_threshold_is_not_flagged() {
+            let progress = create_progress(vec![
+                create_lesson("Lesson One", DURATION_2_HOURS, create_time(1, 10, 0), 3),
+                create_lesson("Lesson Two", DURATION_2_HOURS, create_time(2, 10, 0), 3),
+            ]);
+
+            assert!(AnomalyDetector::default().detect(&progress).is_empty());
+        }
+
+        #[test]
+        fn test_repeated_rushing_is_flagged() {
+            let progress = create_progress(vec![
+                create_lesson("Lesson One", DURATION_2_HOURS, create_time(1, 10, 0), 3),
+                create_lesson("Lesson Two", DURATION_2_HOURS, create_time(2, 10, 0), 3),
+                create_lesson("Lesson Three", DURATION_2_HOURS, create_time(3, 10, 0), 5),
+                create_lesson("Lesson Four", DURATION_2_HOURS, create_time(4, 10, 0), 90),
+            ]);
+
+            assert_eq!(
+                AnomalyDetector::default().detect(&progress),
+                vec![Anomaly::RushedLessons { count: 3 }]
+            );
+        }
+
+        #[test]
+        fn test_unfinished_lessons_are_ignored() {
+            let start = create_time(1, 10, 0);
+            let lessons = (0..3)
+                .map(|n| {
+                    LessonProgress::new(format!("Lesson {n}"), DURATION_2_HOURS, Some(start), None)
+                        .unwrap()
+                })
+                .collect();
+
+            assert!(
+                AnomalyDetector::default()
+                    .detect(&create_progress(lessons))
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn test_custom_thresholds_apply() {
+            let thresholds = IntegrityThresholds::new(
+                0.5,
+                1,
+                Duration::from_hours(24),
+                Duration::from_minutes(30),
+            )
+            .unwrap();
+            let progress = create_progress(vec![create_lesson(
+                "Lesson One",
+                DURATION_2_HOURS,
+                create_time(1, 10, 0),
+                50,
+            )]);
+
+            assert_eq!(
+                AnomalyDetector::new(thresholds).detect(&progress),
+                vec![Anomaly::RushedLessons { count: 1 }]
+            );
+        }
+    }
+
+    mod continuous_activity {
+        use super::*;
+
+        fn create_marathon(lesson_count: u32, break_minutes: i64) -> CourseProgress {
+            let mut start = create_time(1, 0, 0);
+            let mut lessons = Vec::new();
+            for n in 0..lesson_count {
+                lessons.push(create_lesson(&format!("Lesson {n}"), DURATION_2_HOURS, start, 120));
+                start = start.add_seconds((120 + break_minutes) * 60);
+            }
+            create_progress(lessons)
+        }
+
+        #[test]
+        fn test_day_long_session_is_flagged() {
+            let progress = create_marathon(12, 10);
+
+            let anomalies = AnomalyDetector::default().detect(&progress);
+
+            assert_eq!(
+                anomalies,
+                vec![Anomaly::ContinuousActivity {
+                    started_at: create_time(1, 0, 0),
+                    length: Duration::from_minutes(12 * 120 + 11 * 10),
+                }]
+            );
+        }
+
+        #[test]
+        fn test_real_breaks_split_sessions() {
+            let progress = create_marathon(12, 45);
+
+            assert!(AnomalyDetector::default().detect(&progress).is_empty());
+        }
+
+        #[test]
+        fn test_overlapping_lessons_extend_one_session() {
+            let progress = create_progress(vec![
+                create_lesson("Lesson One", 60, create_time(1, 0, 0), 20 * 60),
+                create_lesson("Lesson Two", 60, create_time(1, 2, 0), 60),
+                create_lesson("Lesson Three", 60, create_time(1, 20, 10), 4 * 60),
+            ]);
+
+            let anomalies = AnomalyDetector::default().detect(&progress);
+
+            assert_eq!(
+                anomalies,
+                vec![Anomaly::ContinuousActivity {
+                    started_at: create_time(1, 0, 0),
+                    length: Duration::from_minutes(24 * 60 + 10),
+                }]
+            );
+        }
+    }
+}
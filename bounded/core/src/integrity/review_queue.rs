@@ -0,0 +1,391 @@
+use crate::{Anomaly, AnomalyDetector, CourseProgress, IntegrityError};
+use education_platform_common::{DateTime, Email, Entity, Id};
+use std::fmt;
+
+/// Where an integrity flag stands in admin review.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::FlagStatus;
+///
+/// assert_eq!(FlagStatus::default(), FlagStatus::Open);
+/// assert_eq!(FlagStatus::Dismissed.to_string(), "dismissed");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FlagStatus {
+    #[default]
+    Open,
+    Confirmed,
+    Dismissed,
+}
+
+impl fmt::Display for FlagStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Open => "open",
+            Self::Confirmed => "confirmed",
+            Self::Dismissed => "dismissed",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// An anomaly raised against a learner's course progress, awaiting or
+/// carrying an admin decision.
+#[derive(Debug, Clone)]
+pub struct IntegrityFlag {
+    id: Id,
+    progress_id: Id,
+    course_id: Option<Id>,
+    user_email: Email,
+    anomaly: Anomaly,
+    raised_at: DateTime,
+    status: FlagStatus,
+    reviewed_by: Option<Email>,
+    reviewed_at: Option<DateTime>,
+}
+
+impl IntegrityFlag {
+    /// Returns the ID of the flagged course progress.
+    #[inline]
+    #[must_use]
+    pub const fn progress_id(&self) -> Id {
+        self.progress_id
+    }
+
+    /// Returns the ID of the course the progress belongs to, if known.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Option<Id> {
+        self.course_id
+    }
+
+    /// Returns the email of the learner whose progress was flagged.
+    #[inline]
+    #[must_use]
+    pub const fn user_email(&self) -> &Email {
+        &self.user_email
+    }
+
+    /// Returns the anomaly that raised the flag.
+    #[inline]
+    #[must_use]
+    pub const fn anomaly(&self) -> Anomaly {
+        self.anomaly
+    }
+
+    /// Returns when the flag was raised.
+    #[inline]
+    #[must_use]
+    pub const fn raised_at(&self) -> DateTime {
+        self.raised_at
+    }
+
+    /// Returns the review status.
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> FlagStatus {
+        self.status
+    }
+
+    /// Returns the admin who reviewed the flag, if it was reviewed.
+    #[inline]
+    #[must_use]
+    pub const fn reviewed_by(&self) -> Option<&Email> {
+        self.reviewed_by.as_ref()
+    }
+
+    /// Returns when the flag was reviewed, if it was.
+    #[inline]
+    #[must_use]
+    pub const fn reviewed_at(&self) -> Option<DateTime> {
+        self.reviewed_at
+    }
+}
+
+impl Entity for IntegrityFlag {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+/// Raises integrity flags on course progress and tracks their review.
+///
+/// Progress with an open or confirmed flag must not earn a certificate;
+/// an admin either confirms the flag or dismisses it as legitimate. The
+/// same anomaly is never raised twice for one progress, so a dismissed
+/// flag stays dismissed when the progress is screened again.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseProgress, FlagStatus, IntegrityReviewQueue, LessonProgress};
+/// use education_platform_common::{DateTime, Email, Entity};
+///
+/// let lessons = (0..3)
+///     .map(|day| {
+///         let start = DateTime::new(2024, 3, 1 + day, 10, 0, 0).unwrap();
+///         LessonProgress::new(format!("Lesson {day}"), 7200, Some(start), Some(start.add_seconds(180)))
+///             .unwrap()
+///     })
+///     .collect();
+/// let progress = CourseProgress::builder()
+///     .course_name("Rust Course")
+///     .user_email("user@example.com")
+///     .lessons(lessons)
+///     .build()
+///     .unwrap();
+/// let now = DateTime::new(2024, 3, 4, 9, 0, 0).unwrap();
+///
+/// let mut queue = IntegrityReviewQueue::default();
+/// let raised = queue.screen(&progress, now);
+/// assert!(queue.is_withheld(progress.id()));
+///
+/// let admin = Email::new("admin@example.com".to_string()).unwrap();
+/// queue.dismiss(raised[0], &admin, now).unwrap();
+/// assert!(!queue.is_withheld(progress.id()));
+/// assert_eq!(queue.open_flags().count(), 0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityReviewQueue {
+    detector: AnomalyDetector,
+    flags: Vec<IntegrityFlag>,
+}
+
+impl IntegrityReviewQueue {
+    /// Creates an empty queue screening with the given detector.
+    #[must_use]
+    pub const fn new(detector: AnomalyDetector) -> Self {
+        Self {
+            detector,
+            flags: Vec::new(),
+        }
+    }
+
+    /// Runs the detector on the progress and raises a flag for each new
+    /// anomaly, returning the IDs of the flags raised.
+    pub fn screen(&mut self, progress: &CourseProgress, at: DateTime) -> Vec<Id> {
+        let mut raised = Vec::new();
+        for anomaly in self.detector.detect(progress) {
+            let known = self
+                .flags_for(progress.id())
+                .any(|flag| flag.anomaly == anomaly);
+            if known {
+                continue;
+            }
+
+            let flag = IntegrityFlag {
+                id: Id::default(),
+                progress_id: progress.id(),
+                course_id: progress.course_id(),
+                user_email: progress.user_email().clone(),
+                anomaly,
+                raised_at: at,
+                status: FlagStatus::Open,
+                reviewed_by: None,
+                reviewed_at: None,
+            };
+            raised.push(flag.id);
+            self.flags.push(flag);
+        }
+        raised
+    }
+
+    /// Returns the flags awaiting review, oldest first.
+    pub fn open_flags(&self) -> impl Iterator<Item = &IntegrityFlag> {
+        self.flags
+            .iter()
+            .filter(|flag| flag.status == FlagStatus::Open)
+    }
+
+    /// Returns every flag raised against a course progress.
+    pub fn flags_for(&self, progress_id: Id) -> impl Iterator<Item = &IntegrityFlag> {
+        self.flags
+            .iter()
+            .filter(move |flag| flag.progress_id == progress_id)
+    }
+
+    /// Returns true if the progress has a flag that is open or confirmed,
+    /// meaning no certificate should be issued for it.
+    #[must_use]
+    pub fn is_withheld(&self, progress_id: Id) -> bool {
+        self.flags_for(progress_id)
+            .any(|flag| flag.status != FlagStatus::Dismissed)
+    }
+
+    /// Confirms that the flagged progress is not legitimate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IntegrityError::FlagNotFound` if no flag has the ID, or
+    /// `IntegrityError::AlreadyReviewed` if the flag is no longer open.
+    pub fn confirm(
+        &mut self,
+        flag_id: Id,
+        reviewer: &Email,
+        at: DateTime,
+    ) -> Result<(), IntegrityError> {
+        self.review(flag_id, FlagStatus::Confirmed, reviewer, at)
+    }
+
+    /// Clears a flag after the admin found the progress legitimate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IntegrityError::FlagNotFound` if no flag has the ID, or
+    /// `IntegrityError::AlreadyReviewed` if the flag is no longer open.
+    pub fn dismiss(
+        &mut self,
+        flag_id: Id,
+        reviewer: &Email,
+        at: DateTime,
+    ) -> Result<(), IntegrityError> {
+        self.review(flag_id, FlagStatus::Dismissed, reviewer, at)
+    }
+
+    fn review(
+        &mut self,
+        flag_id: Id,
+        status: FlagStatus,
+        reviewer: &Email,
+        at: DateTime,
+    ) -> Result<(), IntegrityError> {
+        let flag = self
+            .flags
+            .iter_mut()
+            .find(|flag| flag.id == flag_id)
+            .ok_or(IntegrityError::FlagNotFound(flag_id))?;
+        if flag.status != FlagStatus::Open {
+            return Err(IntegrityError::AlreadyReviewed(flag_id));
+        }
+
+        flag.status = status;
+        flag.reviewed_by = Some(reviewer.clone());
+        flag.reviewed_at = Some(at);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LessonProgress;
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_time(day: u32) -> DateTime {
+        DateTime::new(2024, 3, day, 10, 0, 0).unwrap()
+    }
+
+    fn create_rushed_progress(lesson_count: u32) -> CourseProgress {
+        let lessons = (0..lesson_count)
+            .map(|n| {
+                let start = create_time(1 + n);
+                LessonProgress::new(
+                    format!("Lesson {n}"),
+                    7200,
+                    Some(start),
+                    Some(start.add_seconds(180)),
+                )
+                .unwrap()
+            })
+            .collect();
+        CourseProgress::builder()
+            .course_id(Id::new())
+            .course_name("Rust Course")
+            .user_email("student@example.com")
+            .lessons(lessons)
+            .build()
+            .unwrap()
+    }
+
+    mod screen {
+        use super::*;
+
+        #[test]
+        fn test_raises_open_flag_with_progress_details() {
+            let progress = create_rushed_progress(3);
+            let mut queue = IntegrityReviewQueue::default();
+
+            let raised = queue.screen(&progress, create_time(10));
+
+            let flag = queue.open_flags().next().unwrap();
+            assert_eq!(raised, vec![flag.id()]);
+            assert_eq!(flag.course_id(), progress.course_id());
+            assert_eq!(flag.user_email().address(), "student@example.com");
+            assert_eq!(flag.anomaly(), Anomaly::RushedLessons { count: 3 });
+            assert_eq!(flag.raised_at(), create_time(10));
+        }
+
+        #[test]
+        fn test_plausible_progress_raises_nothing() {
+            let mut queue = IntegrityReviewQueue::default();
+
+            assert!(
+                queue
+                    .screen(&create_rushed_progress(2), create_time(10))
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn test_same_anomaly_is_not_raised_twice() {
+            let progress = create_rushed_progress(3);
+            let mut queue = IntegrityReviewQueue::default();
+            let raised = queue.screen(&progress, create_time(10));
+            queue
+                .dismiss(raised[0], &create_email("admin@example.com"), create_time(11))
+                .unwrap();
+
+            assert!(queue.screen(&progress, create_time(12)).is_empty());
+            assert!(!queue.is_withheld(progress.id()));
+        }
+    }
+
+    mod review {
+        use super::*;
+
+        #[test]
+        fn test_confirmed_flag_keeps_progress_withheld() {
+            let progress = create_rushed_progress(3);
+            let mut queue = IntegrityReviewQueue::default();
+            let raised = queue.screen(&progress, create_time(10));
+            let admin = create_email("admin@example.com");
+
+            queue.confirm(raised[0], &admin, create_time(11)).unwrap();
+
+            let flag = queue.flags_for(progress.id()).next().unwrap();
+            assert_eq!(flag.status(), FlagStatus::Confirmed);
+            assert_eq!(flag.reviewed_by(), Some(&admin));
+            assert_eq!(flag.reviewed_at(), Some(create_time(11)));
+            assert!(queue.is_withheld(progress.id()));
+        }
+
+        #[test]
+        fn test_reviewed_flag_cannot_be_reviewed_again() {
+            let mut queue = IntegrityReviewQueue::default();
+            let raised = queue.screen(&create_rushed_progress(3), create_time(10));
+            let admin = create_email("admin@example.com");
+            queue.confirm(raised[0], &admin, create_time(11)).unwrap();
+
+            assert_eq!(
+                queue.dismiss(raised[0], &admin, create_time(12)),
+                Err(IntegrityError::AlreadyReviewed(raised[0]))
+            );
+        }
+
+        #[test]
+        fn test_unknown_flag_is_rejected() {
+            let mut queue = IntegrityReviewQueue::default();
+            let id = Id::new();
+
+            assert_eq!(
+                queue.confirm(id, &create_email("admin@example.com"), create_time(1)),
+                Err(IntegrityError::FlagNotFound(id))
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use education_platform_common::{Duration, Id};
+use thiserror::Error;
+
+/// Error types for progress integrity checks and reviews.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntegrityError {
+    #[error("Minimum watch ratio must be greater than 0 and at most 1")]
+    WatchRatioNotValid,
+
+    #[error("At least one rushed lesson is needed to raise a flag")]
+    RushedLessonCountNotValid,
+
+    #[error("Maximum continuous activity must be longer than the session gap")]
+    ActivityWindowNotValid,
+
+    #[error("Integrity flag with id {0} not found")]
+    FlagNotFound(Id),
+
+    #[error("Integrity flag {0} has already been reviewed")]
+    AlreadyReviewed(Id),
+}
+
+/// Limits beyond which a learner's progress is considered implausible.
+///
+/// - A lesson is *rushed* when it was finished in less than
+///   `min_watch_ratio` of its length; a flag is raised once
+///   `rushed_lessons` lessons of the same course were rushed.
+/// - Lessons separated by no more than `session_gap` belong to one
+///   session; a session lasting `max_continuous_activity` or longer is
+///   flagged.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::IntegrityThresholds;
+/// use education_platform_common::Duration;
+///
+/// let thresholds = IntegrityThresholds::new(
+///     0.05,
+///     2,
+///     Duration::from_hours(12),
+///     Duration::from_minutes(15),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(thresholds.rushed_lessons(), 2);
+/// assert!(IntegrityThresholds::new(1.5, 2, Duration::from_hours(12), Duration::from_minutes(15)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntegrityThresholds {
+    min_watch_ratio: f64,
+    rushed_lessons: usize,
+    max_continuous_activity: Duration,
+    session_gap: Duration,
+}
+
+impl IntegrityThresholds {
+    pub const DEFAULT_MIN_WATCH_RATIO: f64 = 0.1;
+    pub const DEFAULT_RUSHED_LESSONS: usize = 3;
+
+    /// Creates thresholds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IntegrityError::WatchRatioNotValid` if the ratio is not in
+    /// `(0, 1]`, `IntegrityError::RushedLessonCountNotValid` if the count is
+    /// zero, or `IntegrityError::ActivityWindowNotValid` if the maximum
+    /// activity is not longer than the session gap.
+    pub fn new(
+        min_watch_ratio: f64,
+        rushed_lessons: usize,
+        max_continuous_activity: Duration,
+        session_gap: Duration,
+    ) -> Result<Self, IntegrityError> {
+        if !(min_watch_ratio > 0.0 && min_watch_ratio <= 1.0) {
+            return Err(IntegrityError::WatchRatioNotValid);
+        }
+        if rushed_lessons == 0 {
+            return Err(IntegrityError::RushedLessonCountNotValid);
+        }
+        if max_continuous_activity <= session_gap {
+            return Err(IntegrityError::ActivityWindowNotValid);
+        }
+
+        Ok(Self {
+            min_watch_ratio,
+            rushed_lessons,
+            max_continuous_activity,
+            session_gap,
+        })
+    }
+
+    /// Returns the smallest share of a lesson's length a learner must spend on it.
+    #[inline]
+    #[must_use]
+    pub const fn min_watch_ratio(&self) -> f64 {
+        self.min_watch_ratio
+    }
+
+    /// Returns how many rushed lessons raise a flag.
+    #[inline]
+    #[must_use]
+    pub const fn rushed_lessons(&self) -> usize {
+        self.rushed_lessons
+    }
+
+    /// Returns the session length that raises a flag.
+    #[inline]
+    #[must_use]
+    pub const fn max_continuous_activity(&self) -> Duration {
+        self.max_continuous_activity
+    }
+
+    /// Returns the longest pause that still continues a session.
+    #[inline]
+    #[must_use]
+    pub const fn session_gap(&self) -> Duration {
+        self.session_gap
+    }
+}
+
+impl Default for IntegrityThresholds {
+    /// A lesson finished in under a tenth of its length is rushed, three
+    /// rushed lessons raise a flag, and 24 hours of activity without a
+    /// 30-minute break is flagged.
+    fn default() -> Self {
+        Self {
+            min_watch_ratio: Self::DEFAULT_MIN_WATCH_RATIO,
+            rushed_lessons: Self::DEFAULT_RUSHED_LESSONS,
+            max_continuous_activity: Duration::from_hours(24),
+            session_gap: Duration::from_minutes(30),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_thresholds(ratio: f64, rushed: usize) -> Result<IntegrityThresholds, IntegrityError> {
+        IntegrityThresholds::new(
+            ratio,
+            rushed,
+            Duration::from_hours(24),
+            Duration::from_minutes(30),
+        )
+    }
+
+    #[test]
+    fn test_ratio_must_be_positive_and_at_most_one() {
+        assert_eq!(create_thresholds(0.0, 3), Err(IntegrityError::WatchRatioNotValid));
+        assert_eq!(create_thresholds(1.01, 3), Err(IntegrityError::WatchRatioNotValid));
+        assert_eq!(
+            create_thresholds(f64::NAN, 3),
+            Err(IntegrityError::WatchRatioNotValid)
+        );
+        assert!(create_thresholds(1.0, 3).is_ok());
+    }
+
+    #[test]
+    fn test_rushed_lesson_count_cannot_be_zero() {
+        assert_eq!(
+            create_thresholds(0.1, 0),
+            Err(IntegrityError::RushedLessonCountNotValid)
+        );
+    }
+
+    #[test]
+    fn test_activity_window_must_exceed_session_gap() {
+        let result = IntegrityThresholds::new(
+            0.1,
+            3,
+            Duration::from_minutes(30),
+            Duration::from_minutes(30),
+        );
+
+        assert_eq!(result, Err(IntegrityError::ActivityWindowNotValid));
+    }
+
+    #[test]
+    fn test_default_thresholds() {
+        let thresholds = IntegrityThresholds::default();
+
+        assert_eq!(thresholds.rushed_lessons(), 3);
+        assert_eq!(thresholds.max_continuous_activity(), Duration::from_hours(24));
+        assert_eq!(thresholds.session_gap(), Duration::from_minutes(30));
+    }
+}
@@ -0,0 +1,7 @@
+mod icalendar;
+mod open_badges;
+mod xapi;
+
+pub use icalendar::*;
+pub use open_badges::*;
+pub use xapi::*;
@@ -0,0 +1,568 @@
+use crate::{Assignment, CourseProgress, LiveSession, ProgressReport};
+use education_platform_common::{Date, DateTime, Entity, Id};
+
+const PRODUCT_ID: &str = "-//Education Platform//Calendar Export//EN";
+const UID_DOMAIN: &str = "education-platform";
+const MAX_LINE_OCTETS: usize = 75;
+const SUMMARY_PROMPT_CHARS: usize = 60;
+
+/// When a calendar event happens: at a precise time, or on a whole day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarTime {
+    /// A local date and time, exported as floating time.
+    At(DateTime),
+    /// A whole day, with no time attached.
+    AllDay(Date),
+}
+
+impl CalendarTime {
+    fn property(&self, name: &str) -> String {
+        match self {
+            Self::At(at) => format!("{name}:{}", at.format("%Y%m%dT%H%M%S")),
+            Self::AllDay(date) => format!("{name};VALUE=DATE:{}", date.format("%Y%m%d")),
+        }
+    }
+}
+
+/// A single entry of an exported calendar.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CalendarTime, CourseCalendar};
+/// use education_platform_common::{DateTime, Id};
+///
+/// let due_at = DateTime::new(2024, 12, 20, 23, 59, 0).unwrap();
+/// let mut calendar = CourseCalendar::new("Rust", DateTime::new(2024, 12, 1, 9, 0, 0).unwrap());
+/// calendar.add_deadline(Id::new(), "Final project", due_at);
+///
+/// let event = &calendar.events()[0];
+/// assert_eq!(event.summary(), "Final project");
+/// assert_eq!(event.start(), CalendarTime::At(due_at));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    uid: String,
+    summary: String,
+    start: CalendarTime,
+    end: Option<CalendarTime>,
+    url: Option<String>,
+    cancelled: bool,
+}
+
+impl CalendarEvent {
+    /// Returns the identifier that lets calendar apps update the event on re-import.
+    #[inline]
+    #[must_use]
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+
+    /// Returns the event title.
+    #[inline]
+    #[must_use]
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// Returns when the event starts.
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> CalendarTime {
+        self.start
+    }
+
+    /// Returns when the event ends, for events that span time.
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> Option<CalendarTime> {
+        self.end
+    }
+
+    /// Returns the link attached to the event, such as a meeting URL.
+    #[inline]
+    #[must_use]
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Returns true if the event was cancelled.
+    #[inline]
+    #[must_use]
+    pub const fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    fn write_to(&self, out: &mut String, stamp: &DateTime) {
+        write_line(out, "BEGIN:VEVENT");
+        write_line(out, &format!("UID:{}", self.uid));
+        write_line(out, &CalendarTime::At(*stamp).property("DTSTAMP"));
+        write_line(out, &self.start.property("DTSTART"));
+        if let Some(end) = &self.end {
+            write_line(out, &end.property("DTEND"));
+        }
+        write_line(out, &format!("SUMMARY:{}", escape_text(&self.summary)));
+        if let Some(url) = &self.url {
+            write_line(out, &format!("URL:{url}"));
+        }
+        if self.cancelled {
+            write_line(out, "STATUS:CANCELLED");
+        }
+        write_line(out, "END:VEVENT");
+    }
+}
+
+/// iCalendar (`.ics`) export of a learner's sessions, deadlines and milestones.
+///
+/// Events are collected from the domain objects and rendered as an RFC 5545
+/// calendar by [`CourseCalendar::to_ics`]. Each event has a stable UID derived
+/// from its source, so importing an updated export moves events instead of
+/// duplicating them, and cancelled live sessions are kept with a cancelled
+/// status so calendar apps remove them.
+///
+/// Times are exported as floating local times, like the `DateTime` values
+/// they come from.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseCalendar, LiveSession};
+/// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+///
+/// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+/// let session = LiveSession::new(
+///     Id::new(),
+///     TimeRange::starting_at(start, Duration::from_hours(1)).unwrap(),
+///     "https://meet.example.com/rust-101".to_string(),
+///     30,
+/// ).unwrap();
+///
+/// let mut calendar = CourseCalendar::new("Rust", DateTime::new(2024, 12, 1, 9, 0, 0).unwrap());
+/// calendar.add_live_session(&session, "Week 1 live Q&A");
+///
+/// let ics = calendar.to_ics();
+/// assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert!(ics.contains("DTSTART:20241209T180000\r\n"));
+/// assert!(ics.contains("DTEND:20241209T190000\r\n"));
+/// assert!(ics.ends_with("END:VCALENDAR\r\n"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseCalendar {
+    name: String,
+    generated_at: DateTime,
+    events: Vec<CalendarEvent>,
+}
+
+impl CourseCalendar {
+    /// Creates an empty calendar.
+    ///
+    /// `generated_at` is written as the timestamp of every event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::CourseCalendar;
+    /// use education_platform_common::DateTime;
+    ///
+    /// let calendar = CourseCalendar::new("Rust", DateTime::new(2024, 12, 1, 9, 0, 0).unwrap());
+    /// assert!(calendar.events().is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(name: impl Into<String>, generated_at: DateTime) -> Self {
+        Self {
+            name: name.into(),
+            generated_at,
+            events: Vec::new(),
+        }
+    }
+
+    /// Adds a live session, spanning its scheduled time and linking its meeting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseCalendar, LiveSession};
+    /// use education_platform_common::{DateTime, Duration, Id, TimeRange};
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let mut session = LiveSession::new(
+    ///     Id::new(),
+    ///     TimeRange::starting_at(start, Duration::from_hours(1)).unwrap(),
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     30,
+    /// ).unwrap();
+    /// session.cancel().unwrap();
+    ///
+    /// let mut calendar = CourseCalendar::new("Rust", start);
+    /// calendar.add_live_session(&session, "Week 1 live Q&A");
+    ///
+    /// assert!(calendar.events()[0].is_cancelled());
+    /// assert_eq!(calendar.events()[0].url(), Some("https://meet.example.com/rust-101"));
+    /// ```
+    pub fn add_live_session(&mut self, session: &LiveSession, summary: &str) {
+        let scheduled = session.scheduled();
+        self.events.push(CalendarEvent {
+            uid: uid(session.id(), "session"),
+            summary: summary.to_string(),
+            start: CalendarTime::At(scheduled.start()),
+            end: Some(CalendarTime::At(scheduled.end())),
+            url: Some(session.meeting_url().as_str().to_string()),
+            cancelled: session.is_cancelled(),
+        });
+    }
+
+    /// Adds the due date of an assignment, titled after the start of its prompt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Assignment, CalendarTime, CourseCalendar};
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let due_at = DateTime::new(2024, 12, 20, 23, 59, 0).unwrap();
+    /// let assignment = Assignment::new(
+    ///     Id::new(),
+    ///     "Implement a linked list".to_string(),
+    ///     due_at,
+    ///     100,
+    /// ).unwrap();
+    ///
+    /// let mut calendar = CourseCalendar::new("Rust", DateTime::new(2024, 12, 1, 9, 0, 0).unwrap());
+    /// calendar.add_assignment(&assignment);
+    ///
+    /// assert_eq!(calendar.events()[0].summary(), "Due: Implement a linked list");
+    /// assert_eq!(calendar.events()[0].start(), CalendarTime::At(due_at));
+    /// ```
+    pub fn add_assignment(&mut self, assignment: &Assignment) {
+        let title = assignment.prompt().lines().next().unwrap_or_default();
+        let summary = match title.chars().count() > SUMMARY_PROMPT_CHARS {
+            true => {
+                let cut: String = title.chars().take(SUMMARY_PROMPT_CHARS).collect();
+                format!("Due: {}…", cut.trim_end())
+            }
+            false => format!("Due: {title}"),
+        };
+
+        self.events.push(CalendarEvent {
+            uid: uid(assignment.id(), "due"),
+            summary,
+            start: CalendarTime::At(assignment.due_at()),
+            end: None,
+            url: None,
+            cancelled: false,
+        });
+    }
+
+    /// Adds a deadline that is not tied to an assignment, such as an enrollment cutoff.
+    ///
+    /// `source_id` identifies what the deadline belongs to and keeps its UID stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::CourseCalendar;
+    /// use education_platform_common::{DateTime, Id};
+    ///
+    /// let mut calendar = CourseCalendar::new("Rust", DateTime::new(2024, 12, 1, 9, 0, 0).unwrap());
+    /// calendar.add_deadline(Id::new(), "Enrollment closes", DateTime::new(2024, 12, 5, 0, 0, 0).unwrap());
+    ///
+    /// assert_eq!(calendar.events().len(), 1);
+    /// ```
+    pub fn add_deadline(&mut self, source_id: Id, summary: &str, at: DateTime) {
+        self.events.push(CalendarEvent {
+            uid: uid(source_id, "deadline"),
+            summary: summary.to_string(),
+            start: CalendarTime::At(at),
+            end: None,
+            url: None,
+            cancelled: false,
+        });
+    }
+
+    /// Adds the estimated completion date of a course as an all-day milestone.
+    ///
+    /// The estimate is the one from [`ProgressReport`] as of `today`. Nothing
+    /// is added while no lesson is completed, since there is no pace to
+    /// project, nor once the course is finished. Returns true if a milestone
+    /// was added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CalendarTime, CourseCalendar, CourseProgress, LessonProgress};
+    /// use education_platform_common::{Date, DateTime};
+    ///
+    /// let start = DateTime::new(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let end = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+    /// let done = LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+    /// let pending = LessonProgress::new("Basics".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![done, pending])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut calendar = CourseCalendar::new("Rust", start);
+    /// assert!(calendar.add_completion_estimate(&progress, Date::new(2024, 1, 1).unwrap()));
+    ///
+    /// assert_eq!(
+    ///     calendar.events()[0].start(),
+    ///     CalendarTime::AllDay(Date::new(2024, 1, 2).unwrap())
+    /// );
+    /// ```
+    pub fn add_completion_estimate(&mut self, progress: &CourseProgress, today: Date) -> bool {
+        if progress.end_date().is_some() {
+            return false;
+        }
+        let Some(finish) = ProgressReport::from_progress(progress, today).estimated_finish_date()
+        else {
+            return false;
+        };
+
+        self.events.push(CalendarEvent {
+            uid: uid(progress.id(), "finish"),
+            summary: format!("Estimated completion: {}", progress.course_name().as_str()),
+            start: CalendarTime::AllDay(finish),
+            end: None,
+            url: None,
+            cancelled: false,
+        });
+        true
+    }
+
+    /// Returns the collected events, in insertion order.
+    #[inline]
+    #[must_use]
+    pub fn events(&self) -> &[CalendarEvent] {
+        &self.events
+    }
+
+    /// Renders the calendar as iCalendar text.
+    ///
+    /// Lines end with CRLF and are folded at 75 octets, and text values are
+    /// escaped, as RFC 5545 requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::CourseCalendar;
+    /// use education_platform_common::DateTime;
+    ///
+    /// let calendar = CourseCalendar::new("Rust, advanced", DateTime::new(2024, 12, 1, 9, 0, 0).unwrap());
+    ///
+    /// assert!(calendar.to_ics().contains("X-WR-CALNAME:Rust\\, advanced\r\n"));
+    /// ```
+    #[must_use]
+    pub fn to_ics(&self) -> String {
+        let mut out = String::new();
+        write_line(&mut out, "BEGIN:VCALENDAR");
+        write_line(&mut out, "VERSION:2.0");
+        write_line(&mut out, &format!("PRODID:{PRODUCT_ID}"));
+        write_line(&mut out, "CALSCALE:GREGORIAN");
+        write_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(&self.name)));
+        for event in &self.events {
+            event.write_to(&mut out, &self.generated_at);
+        }
+        write_line(&mut out, "END:VCALENDAR");
+        out
+    }
+}
+
+fn uid(id: Id, kind: &str) -> String {
+    format!("{id}-{kind}@{UID_DOMAIN}")
+}
+
+/// Escapes a TEXT value: backslashes, separators and line breaks.
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends a content line, folded so no physical line exceeds 75 octets.
+///
+/// Continuation lines start with a space, which counts towards their length.
+/// Lines are only split between characters, never inside a UTF-8 sequence.
+fn write_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LessonProgress;
+    use education_platform_common::{Duration, TimeRange};
+
+    fn create_datetime(day: u32, hour: u32) -> DateTime {
+        DateTime::new(2024, 12, day, hour, 0, 0).unwrap()
+    }
+
+    fn create_test_calendar() -> CourseCalendar {
+        CourseCalendar::new("Rust", create_datetime(1, 9))
+    }
+
+    fn create_test_session() -> LiveSession {
+        LiveSession::new(
+            Id::new(),
+            TimeRange::starting_at(create_datetime(9, 18), Duration::from_hours(1)).unwrap(),
+            "https://meet.example.com/rust-101".to_string(),
+            30,
+        )
+        .unwrap()
+    }
+
+    mod events {
+        use super::*;
+
+        #[test]
+        fn test_live_session_uid_is_stable() {
+            let session = create_test_session();
+            let mut first = create_test_calendar();
+            let mut second = create_test_calendar();
+
+            first.add_live_session(&session, "Q&A");
+            second.add_live_session(&session, "Q&A (moved)");
+
+            assert_eq!(first.events()[0].uid(), second.events()[0].uid());
+            assert!(first.events()[0].uid().contains(&session.id().to_string()));
+        }
+
+        #[test]
+        fn test_long_assignment_prompt_is_shortened() {
+            let prompt = format!("{}\nDetails follow.", "x".repeat(80));
+            let assignment =
+                Assignment::new(Id::new(), prompt, create_datetime(20, 23), 100).unwrap();
+            let mut calendar = create_test_calendar();
+
+            calendar.add_assignment(&assignment);
+
+            let summary = calendar.events()[0].summary();
+            assert_eq!(summary, format!("Due: {}…", "x".repeat(SUMMARY_PROMPT_CHARS)));
+        }
+
+        #[test]
+        fn test_no_estimate_without_completed_lessons() {
+            let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+            let progress = CourseProgress::builder()
+                .course_name("My Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson])
+                .build()
+                .unwrap();
+            let mut calendar = create_test_calendar();
+
+            assert!(!calendar.add_completion_estimate(&progress, Date::new(2024, 12, 1).unwrap()));
+            assert!(calendar.events().is_empty());
+        }
+
+        #[test]
+        fn test_no_estimate_for_finished_course() {
+            let lesson = LessonProgress::new(
+                "Intro".to_string(),
+                1800,
+                Some(create_datetime(1, 9)),
+                Some(create_datetime(1, 10)),
+            )
+            .unwrap();
+            let progress = CourseProgress::builder()
+                .course_name("My Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson])
+                .build()
+                .unwrap();
+            let mut calendar = create_test_calendar();
+
+            assert!(!calendar.add_completion_estimate(&progress, Date::new(2024, 12, 1).unwrap()));
+        }
+    }
+
+    mod to_ics {
+        use super::*;
+
+        #[test]
+        fn test_event_properties_are_rendered() {
+            let mut session = create_test_session();
+            session.cancel().unwrap();
+            let mut calendar = create_test_calendar();
+            calendar.add_live_session(&session, "Q&A; week 1");
+
+            let ics = calendar.to_ics();
+
+            assert!(ics.contains("BEGIN:VEVENT\r\n"));
+            assert!(ics.contains("DTSTAMP:20241201T090000\r\n"));
+            assert!(ics.contains("SUMMARY:Q&A\\; week 1\r\n"));
+            assert!(ics.contains("URL:https://meet.example.com/rust-101\r\n"));
+            assert!(ics.contains("STATUS:CANCELLED\r\n"));
+            assert!(ics.contains("END:VEVENT\r\n"));
+        }
+
+        #[test]
+        fn test_all_day_event_uses_date_value() {
+            assert_eq!(
+                CalendarTime::AllDay(Date::new(2024, 1, 2).unwrap()).property("DTSTART"),
+                "DTSTART;VALUE=DATE:20240102"
+            );
+        }
+
+        #[test]
+        fn test_every_line_ends_with_crlf() {
+            let mut calendar = create_test_calendar();
+            calendar.add_live_session(&create_test_session(), "Q&A");
+
+            let ics = calendar.to_ics();
+
+            assert_eq!(ics.matches('\n').count(), ics.matches("\r\n").count());
+        }
+    }
+
+    mod helpers {
+        use super::*;
+
+        #[test]
+        fn test_escape_text() {
+            assert_eq!(escape_text("a\\b;c,d\r\ne"), "a\\\\b\\;c\\,d\\ne");
+        }
+
+        #[test]
+        fn test_write_line_folds_long_lines() {
+            let mut out = String::new();
+
+            write_line(&mut out, &"a".repeat(160));
+
+            let lines: Vec<&str> = out.trim_end_matches("\r\n").split("\r\n").collect();
+            assert_eq!(lines.len(), 3);
+            assert_eq!(lines[0].len(), 75);
+            assert_eq!(lines[1].len(), 75);
+            assert!(lines[1].starts_with(' '));
+            assert_eq!(lines.iter().map(|l| l.trim_start().len()).sum::<usize>(), 160);
+        }
+
+        #[test]
+        fn test_write_line_never_splits_characters() {
+            let mut out = String::new();
+
+            write_line(&mut out, &"é".repeat(60));
+
+            assert!(out.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        }
+    }
+}
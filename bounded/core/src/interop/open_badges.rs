@@ -0,0 +1,643 @@
+use crate::{Certificate, CertificateRegistry, CertificateStatus};
+use education_platform_common::{Entity, Id, Url, UrlError, UrlPolicy, Validator, ValidatorError};
+use serde::Serialize;
+use thiserror::Error;
+
+const CREDENTIALS_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+const OPEN_BADGES_CONTEXT: &str = "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.3.json";
+
+/// Error types for Open Badges export failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OpenBadgesError {
+    #[error("Issuer URL validation failed: {0}")]
+    UrlError(#[from] UrlError),
+
+    #[error("Issuer name validation failed: {0}")]
+    IssuerNameError(ValidatorError),
+
+    #[error("No issuer profile is configured for organization '{0}'")]
+    IssuerNotConfigured(String),
+
+    #[error("Certificate with id {0} not found")]
+    CertificateNotFound(Id),
+
+    #[error("Certificate {0} is revoked and cannot be exported")]
+    CertificateRevoked(Id),
+
+    #[error("Credential could not be signed: {0}")]
+    SigningFailed(String),
+
+    #[error("Credential could not be serialized: {0}")]
+    Serialization(String),
+}
+
+impl From<serde_json::Error> for OpenBadgesError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Serialization(error.to_string())
+    }
+}
+
+/// The organization that issues credentials, as shown in wallets.
+///
+/// The profile ID and homepage must be HTTPS, since verifiers dereference
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::IssuerProfile;
+///
+/// let issuer = IssuerProfile::new("https://academy.example.com/issuer", "Example Academy")
+///     .unwrap()
+///     .with_email("badges@example.com");
+///
+/// assert_eq!(issuer.name(), "Example Academy");
+/// assert!(IssuerProfile::new("http://academy.example.com/issuer", "Example Academy").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssuerProfile {
+    id: String,
+    #[serde(rename = "type")]
+    profile_type: [&'static str; 1],
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+}
+
+impl IssuerProfile {
+    /// Creates a profile from its public ID URL and display name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenBadgesError::UrlError` if the ID is not an HTTPS URL, or
+    /// `OpenBadgesError::IssuerNameError` if the name is blank.
+    pub fn new(id: &str, name: &str) -> Result<Self, OpenBadgesError> {
+        let id = Url::with_policy(id.to_string(), &UrlPolicy::default())?;
+        let name = name.trim();
+        Validator::is_not_empty(name).map_err(OpenBadgesError::IssuerNameError)?;
+
+        Ok(Self {
+            id: id.into_inner(),
+            profile_type: ["Profile"],
+            name: name.to_string(),
+            url: None,
+            email: None,
+        })
+    }
+
+    /// Sets the organization's homepage.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenBadgesError::UrlError` if the homepage is not an HTTPS URL.
+    pub fn with_homepage(mut self, url: &str) -> Result<Self, OpenBadgesError> {
+        self.url = Some(Url::with_policy(url.to_string(), &UrlPolicy::default())?.into_inner());
+        Ok(self)
+    }
+
+    /// Sets the contact email shown with the issuer.
+    #[must_use]
+    pub fn with_email(mut self, email: &str) -> Self {
+        self.email = Some(email.trim().to_string());
+        self
+    }
+
+    /// Returns the public ID URL of the profile.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the display name.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Produces the Data Integrity proof attached to each credential.
+///
+/// Key management and canonicalization belong to the adapter. The signer
+/// receives the credential serialized to JSON without its `proof`.
+pub trait CredentialSigner {
+    /// Returns the cryptosuite name, e.g. `eddsa-rdfc-2022`.
+    fn cryptosuite(&self) -> &str;
+
+    /// Returns the URL of the public key verifiers should use.
+    fn verification_method(&self) -> &str;
+
+    /// Signs the unsigned credential and returns the encoded proof value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenBadgesError::SigningFailed` if the signature cannot be produced.
+    fn sign(&self, unsigned_credential: &str) -> Result<String, OpenBadgesError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Criteria {
+    narrative: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Achievement {
+    id: String,
+    #[serde(rename = "type")]
+    achievement_type_tag: [&'static str; 1],
+    achievement_type: &'static str,
+    name: String,
+    description: String,
+    criteria: Criteria,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct IdentityObject {
+    #[serde(rename = "type")]
+    object_type: &'static str,
+    identity_hash: String,
+    identity_type: &'static str,
+    hashed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct AchievementSubject {
+    #[serde(rename = "type")]
+    subject_type: [&'static str; 1],
+    identifier: [IdentityObject; 1],
+    achievement: Achievement,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Proof {
+    #[serde(rename = "type")]
+    proof_type: &'static str,
+    cryptosuite: String,
+    created: String,
+    verification_method: String,
+    proof_purpose: &'static str,
+    proof_value: String,
+}
+
+/// A signed Open Badges 3.0 `OpenBadgeCredential`, ready for a wallet.
+///
+/// Dates are written in UTC, as platform dates are.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenBadgeCredential {
+    #[serde(rename = "@context")]
+    context: [&'static str; 2],
+    id: String,
+    #[serde(rename = "type")]
+    credential_type: [&'static str; 2],
+    issuer: IssuerProfile,
+    valid_from: String,
+    name: String,
+    credential_subject: AchievementSubject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof: Option<Proof>,
+}
+
+impl OpenBadgeCredential {
+    /// Returns the credential ID URL.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the issuer profile embedded in the credential.
+    #[inline]
+    #[must_use]
+    pub const fn issuer(&self) -> &IssuerProfile {
+        &self.issuer
+    }
+
+    /// Returns the encoded proof value.
+    #[inline]
+    #[must_use]
+    pub fn proof_value(&self) -> Option<&str> {
+        self.proof.as_ref().map(|proof| proof.proof_value.as_str())
+    }
+
+    /// Serializes the credential to JSON-LD.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenBadgesError::Serialization` if serialization fails.
+    pub fn to_json(&self) -> Result<String, OpenBadgesError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// Exports issued certificates as Open Badges 3.0 verifiable credentials.
+///
+/// Each organization configures its own issuer profile. Credentials are
+/// identified as `{base}/credentials/{certificate}` and achievements as
+/// `{base}/achievements/{course}`, falling back to the certificate ID when
+/// the certificate has no course ID.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     CertificateDigest, CertificateRegistry, CourseProgress, CredentialSigner,
+///     IntegrityReviewQueue, IssuerProfile, LessonProgress, OpenBadgesError, OpenBadgesExporter,
+/// };
+/// use education_platform_common::{DateTime, Entity, PersonName};
+///
+/// struct PlainDigest;
+///
+/// impl CertificateDigest for PlainDigest {
+///     fn digest(&self, content: &str) -> String {
+///         content.to_string()
+///     }
+/// }
+///
+/// struct FakeSigner;
+///
+/// impl CredentialSigner for FakeSigner {
+///     fn cryptosuite(&self) -> &str {
+///         "eddsa-rdfc-2022"
+///     }
+///
+///     fn verification_method(&self) -> &str {
+///         "https://academy.example.com/issuer#key-1"
+///     }
+///
+///     fn sign(&self, unsigned_credential: &str) -> Result<String, OpenBadgesError> {
+///         Ok(format!("z{}", unsigned_credential.len()))
+///     }
+/// }
+///
+/// let start = DateTime::new(2024, 5, 1, 9, 0, 0).unwrap();
+/// let end = DateTime::new(2024, 5, 1, 10, 0, 0).unwrap();
+/// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+/// let progress = CourseProgress::builder()
+///     .course_name("Rust Course")
+///     .user_email("ana@example.com")
+///     .lessons(vec![lesson])
+///     .build()
+///     .unwrap();
+/// let name = PersonName::new("Ana".to_string(), None, "Torres".to_string(), None).unwrap();
+/// let review = IntegrityReviewQueue::default();
+/// let mut registry = CertificateRegistry::new();
+/// let certificate_id = registry
+///     .issue(&progress, &review, name, end, &PlainDigest)
+///     .unwrap()
+///     .id();
+///
+/// let mut exporter = OpenBadgesExporter::new("https://academy.example.com").unwrap();
+/// exporter.configure_issuer(
+///     "academy",
+///     IssuerProfile::new("https://academy.example.com/issuer", "Example Academy").unwrap(),
+/// );
+///
+/// let credential = exporter.export("academy", &registry, certificate_id, &FakeSigner).unwrap();
+/// let json = credential.to_json().unwrap();
+///
+/// assert!(json.contains(r#""type":["VerifiableCredential","OpenBadgeCredential"]"#));
+/// assert!(json.contains(r#""validFrom":"2024-05-01T10:00:00Z""#));
+/// assert!(credential.proof_value().unwrap().starts_with('z'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenBadgesExporter {
+    base: String,
+    issuers: Vec<(String, IssuerProfile)>,
+}
+
+impl OpenBadgesExporter {
+    /// Creates an exporter publishing credentials under the given HTTPS base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenBadgesError::UrlError` if the base is not an HTTPS URL.
+    pub fn new(base: &str) -> Result<Self, OpenBadgesError> {
+        let base = Url::with_policy(base.to_string(), &UrlPolicy::default())?;
+
+        Ok(Self {
+            base: base.as_str().trim_end_matches('/').to_string(),
+            issuers: Vec::new(),
+        })
+    }
+
+    /// Sets the issuer profile of an organization, replacing any previous one.
+    pub fn configure_issuer(&mut self, organization: &str, profile: IssuerProfile) {
+        let organization = organization.trim();
+        self.issuers.retain(|(key, _)| key != organization);
+        self.issuers.push((organization.to_string(), profile));
+    }
+
+    /// Returns the issuer profile of an organization.
+    #[must_use]
+    pub fn issuer(&self, organization: &str) -> Option<&IssuerProfile> {
+        self.issuers
+            .iter()
+            .find(|(key, _)| key == organization.trim())
+            .map(|(_, profile)| profile)
+    }
+
+    /// Builds and signs the credential for a certificate of the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenBadgesError::IssuerNotConfigured` if the organization
+    /// has no profile, `OpenBadgesError::CertificateNotFound` or
+    /// `OpenBadgesError::CertificateRevoked` if the certificate cannot be
+    /// vouched for, or the signer's error.
+    pub fn export(
+        &self,
+        organization: &str,
+        registry: &CertificateRegistry,
+        certificate_id: Id,
+        signer: &impl CredentialSigner,
+    ) -> Result<OpenBadgeCredential, OpenBadgesError> {
+        let issuer = self
+            .issuer(organization)
+            .ok_or_else(|| OpenBadgesError::IssuerNotConfigured(organization.to_string()))?;
+        let certificate = registry
+            .certificate(certificate_id)
+            .ok_or(OpenBadgesError::CertificateNotFound(certificate_id))?;
+        if let Some(CertificateStatus::Revoked(_)) = registry.status(certificate_id) {
+            return Err(OpenBadgesError::CertificateRevoked(certificate_id));
+        }
+
+        let mut credential = self.credential(issuer, certificate);
+        let proof_value = signer.sign(&credential.to_json()?)?;
+        credential.proof = Some(Proof {
+            proof_type: "DataIntegrityProof",
+            cryptosuite: signer.cryptosuite().to_string(),
+            created: credential.valid_from.clone(),
+            verification_method: signer.verification_method().to_string(),
+            proof_purpose: "assertionMethod",
+            proof_value,
+        });
+        Ok(credential)
+    }
+
+    fn credential(&self, issuer: &IssuerProfile, certificate: &Certificate) -> OpenBadgeCredential {
+        let achievement_key = certificate.course_id().unwrap_or(certificate.id());
+
+        OpenBadgeCredential {
+            context: [CREDENTIALS_CONTEXT, OPEN_BADGES_CONTEXT],
+            id: format!("{}/credentials/{}", self.base, certificate.id()),
+            credential_type: ["VerifiableCredential", "OpenBadgeCredential"],
+            issuer: issuer.clone(),
+            valid_from: format!("{}Z", certificate.issued_at().format_iso()),
+            name: certificate.course_name().to_string(),
+            credential_subject: AchievementSubject {
+                subject_type: ["AchievementSubject"],
+                identifier: [IdentityObject {
+                    object_type: "IdentityObject",
+                    identity_hash: certificate.learner_email().address().to_string(),
+                    identity_type: "emailAddress",
+                    hashed: false,
+                }],
+                achievement: Achievement {
+                    id: format!("{}/achievements/{achievement_key}", self.base),
+                    achievement_type_tag: ["Achievement"],
+                    achievement_type: "Certificate",
+                    name: certificate.course_name().to_string(),
+                    description: format!(
+                        "Awarded to {} for completing {}.",
+                        certificate.learner_name().full_name(),
+                        certificate.course_name()
+                    ),
+                    criteria: Criteria {
+                        narrative: format!(
+                            "Complete every lesson of {}.",
+                            certificate.course_name()
+                        ),
+                    },
+                },
+            },
+            proof: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CertificateDigest, CourseProgress, IntegrityReviewQueue, LessonProgress};
+    use education_platform_common::{DateTime, PersonName};
+    use std::cell::RefCell;
+
+    struct PlainDigest;
+
+    impl CertificateDigest for PlainDigest {
+        fn digest(&self, content: &str) -> String {
+            content.to_string()
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingSigner {
+        signed: RefCell<Vec<String>>,
+    }
+
+    impl CredentialSigner for RecordingSigner {
+        fn cryptosuite(&self) -> &str {
+            "eddsa-rdfc-2022"
+        }
+
+        fn verification_method(&self) -> &str {
+            "https://academy.example.com/issuer#key-1"
+        }
+
+        fn sign(&self, unsigned_credential: &str) -> Result<String, OpenBadgesError> {
+            self.signed
+                .borrow_mut()
+                .push(unsigned_credential.to_string());
+            Ok("z3FXQ".to_string())
+        }
+    }
+
+    struct FailingSigner;
+
+    impl CredentialSigner for FailingSigner {
+        fn cryptosuite(&self) -> &str {
+            "eddsa-rdfc-2022"
+        }
+
+        fn verification_method(&self) -> &str {
+            "https://academy.example.com/issuer#key-1"
+        }
+
+        fn sign(&self, _: &str) -> Result<String, OpenBadgesError> {
+            Err(OpenBadgesError::SigningFailed("key unavailable".to_string()))
+        }
+    }
+
+    fn create_time(hour: u32) -> DateTime {
+        DateTime::new(2024, 5, 1, hour, 0, 0).unwrap()
+    }
+
+    fn create_registry() -> (CertificateRegistry, Id, Id) {
+        let course_id = Id::new();
+        let lesson = LessonProgress::new(
+            "Intro".to_string(),
+            1800,
+            Some(create_time(9)),
+            Some(create_time(10)),
+        )
+        .unwrap();
+        let progress = CourseProgress::builder()
+            .course_id(course_id)
+            .course_name("Rust Course")
+            .user_email("ana@example.com")
+            .lessons(vec![lesson])
+            .build()
+            .unwrap();
+        let name = PersonName::new("Ana".to_string(), None, "Torres".to_string(), None).unwrap();
+        let mut registry = CertificateRegistry::new();
+        let certificate_id = registry
+            .issue(
+                &progress,
+                &IntegrityReviewQueue::default(),
+                name,
+                create_time(11),
+                &PlainDigest,
+            )
+            .unwrap()
+            .id();
+        (registry, certificate_id, course_id)
+    }
+
+    fn create_exporter() -> OpenBadgesExporter {
+        let mut exporter = OpenBadgesExporter::new("https://academy.example.com/").unwrap();
+        exporter.configure_issuer(
+            "academy",
+            IssuerProfile::new("https://academy.example.com/issuer", "Example Academy")
+                .unwrap()
+                .with_homepage("https://academy.example.com")
+                .unwrap(),
+        );
+        exporter
+    }
+
+    mod export {
+        use super::*;
+
+        #[test]
+        fn test_credential_json_follows_open_badges_shape() {
+            let (registry, certificate_id, course_id) = create_registry();
+            let signer = RecordingSigner::default();
+
+            let credential = create_exporter()
+                .export("academy", &registry, certificate_id, &signer)
+                .unwrap();
+            let json: serde_json::Value =
+                serde_json::from_str(&credential.to_json().unwrap()).unwrap();
+
+            assert_eq!(json["@context"][1], OPEN_BADGES_CONTEXT);
+            assert_eq!(
+                json["id"],
+                format!("https://academy.example.com/credentials/{certificate_id}")
+            );
+            assert_eq!(json["issuer"]["type"][0], "Profile");
+            assert_eq!(json["issuer"]["url"], "https://academy.example.com");
+            assert_eq!(
+                json["credentialSubject"]["identifier"][0]["identityHash"],
+                "ana@example.com"
+            );
+            assert_eq!(
+                json["credentialSubject"]["achievement"]["id"],
+                format!("https://academy.example.com/achievements/{course_id}")
+            );
+            assert_eq!(
+                json["credentialSubject"]["achievement"]["achievementType"],
+                "Certificate"
+            );
+            assert_eq!(json["proof"]["type"], "DataIntegrityProof");
+            assert_eq!(json["proof"]["created"], "2024-05-01T11:00:00Z");
+        }
+
+        #[test]
+        fn test_signer_receives_credential_without_proof() {
+            let (registry, certificate_id, _) = create_registry();
+            let signer = RecordingSigner::default();
+
+            let credential = create_exporter()
+                .export("academy", &registry, certificate_id, &signer)
+                .unwrap();
+
+            let signed = signer.signed.borrow();
+            assert_eq!(signed.len(), 1);
+            assert!(!signed[0].contains("\"proof\""));
+            assert_eq!(credential.proof_value(), Some("z3FXQ"));
+        }
+
+        #[test]
+        fn test_revoked_certificate_is_not_exported() {
+            let (mut registry, certificate_id, _) = create_registry();
+            registry
+                .revoke(certificate_id, "Fraud", create_time(12))
+                .unwrap();
+
+            let result = create_exporter().export(
+                "academy",
+                &registry,
+                certificate_id,
+                &RecordingSigner::default(),
+            );
+
+            assert_eq!(result, Err(OpenBadgesError::CertificateRevoked(certificate_id)));
+        }
+
+        #[test]
+        fn test_unknown_organization_is_rejected() {
+            let (registry, certificate_id, _) = create_registry();
+
+            let result = create_exporter().export(
+                "other",
+                &registry,
+                certificate_id,
+                &RecordingSigner::default(),
+            );
+
+            assert_eq!(result, Err(OpenBadgesError::IssuerNotConfigured("other".to_string())));
+        }
+
+        #[test]
+        fn test_signing_failure_is_surfaced() {
+            let (registry, certificate_id, _) = create_registry();
+
+            let result =
+                create_exporter().export("academy", &registry, certificate_id, &FailingSigner);
+
+            assert!(matches!(result, Err(OpenBadgesError::SigningFailed(_))));
+        }
+    }
+
+    mod issuers {
+        use super::*;
+
+        #[test]
+        fn test_configuring_again_replaces_profile() {
+            let mut exporter = create_exporter();
+
+            exporter.configure_issuer(
+                " academy ",
+                IssuerProfile::new("https://academy.example.com/v2/issuer", "Academy").unwrap(),
+            );
+
+            assert_eq!(exporter.issuer("academy").unwrap().name(), "Academy");
+        }
+
+        #[test]
+        fn test_blank_issuer_name_is_rejected() {
+            assert!(matches!(
+                IssuerProfile::new("https://academy.example.com/issuer", "  "),
+                Err(OpenBadgesError::IssuerNameError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,683 @@
+use crate::{CourseProgress, LessonProgress};
+use education_platform_common::{DateTime, Entity, Url, UrlError};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+const LANGUAGE: &str = "en-US";
+const COURSE_ACTIVITY_TYPE: &str = "http://adlnet.gov/expapi/activities/course";
+const LESSON_ACTIVITY_TYPE: &str = "http://adlnet.gov/expapi/activities/lesson";
+
+/// Error types for xAPI statement emission failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum XapiError {
+    #[error("Activity base URL validation failed: {0}")]
+    ActivityBaseError(#[from] UrlError),
+
+    #[error("Statements could not be serialized: {0}")]
+    Serialization(String),
+}
+
+impl From<serde_json::Error> for XapiError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Serialization(error.to_string())
+    }
+}
+
+/// ADL verbs used to report learning progress.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::XapiVerb;
+///
+/// assert_eq!(XapiVerb::Completed.iri(), "http://adlnet.gov/expapi/verbs/completed");
+/// assert_eq!(XapiVerb::Attempted.display(), "attempted");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XapiVerb {
+    /// The learner started a lesson.
+    Attempted,
+    /// The learner finished a lesson or a course.
+    Completed,
+}
+
+impl XapiVerb {
+    /// Returns the verb IRI from the ADL vocabulary.
+    #[must_use]
+    pub const fn iri(&self) -> &'static str {
+        match self {
+            Self::Attempted => "http://adlnet.gov/expapi/verbs/attempted",
+            Self::Completed => "http://adlnet.gov/expapi/verbs/completed",
+        }
+    }
+
+    /// Returns the human readable verb name.
+    #[must_use]
+    pub const fn display(&self) -> &'static str {
+        match self {
+            Self::Attempted => "attempted",
+            Self::Completed => "completed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Actor {
+    object_type: &'static str,
+    mbox: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Verb {
+    id: &'static str,
+    display: BTreeMap<&'static str, &'static str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct ActivityDefinition {
+    name: BTreeMap<&'static str, String>,
+    #[serde(rename = "type")]
+    activity_type: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Activity {
+    object_type: &'static str,
+    id: String,
+    definition: ActivityDefinition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct ParentActivity {
+    id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContextActivities {
+    parent: Vec<ParentActivity>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Context {
+    context_activities: ContextActivities,
+}
+
+/// A single xAPI (Tin Can) statement: "actor verb object at timestamp".
+///
+/// Statements are produced by [`XapiStatementMapper`] and serialize to the
+/// JSON expected by a Learning Record Store. The statement `id` is left for
+/// the LRS to assign.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseProgress, LessonProgress, XapiStatementMapper, XapiVerb};
+/// use education_platform_common::DateTime;
+///
+/// let started = DateTime::new(2024, 3, 5, 9, 0, 0).unwrap();
+/// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(started), None).unwrap();
+/// let progress = CourseProgress::builder()
+///     .course_name("Rust Course")
+///     .user_email("user@example.com")
+///     .lessons(vec![lesson])
+///     .build()
+///     .unwrap();
+///
+/// let mapper = XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap();
+/// let statement = mapper.lesson_started(&progress, &progress.lesson_progress()[0]).unwrap();
+///
+/// assert_eq!(statement.verb(), XapiVerb::Attempted);
+/// assert_eq!(statement.actor_mbox(), "mailto:user@example.com");
+/// assert_eq!(statement.timestamp(), "2024-03-05T09:00:00");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct XapiStatement {
+    actor: Actor,
+    verb: Verb,
+    object: Activity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<Context>,
+    timestamp: String,
+    #[serde(skip)]
+    verb_kind: XapiVerb,
+}
+
+impl XapiStatement {
+    /// Returns the actor mailbox, as a `mailto:` IRI.
+    #[inline]
+    #[must_use]
+    pub fn actor_mbox(&self) -> &str {
+        &self.actor.mbox
+    }
+
+    /// Returns the statement verb.
+    #[inline]
+    #[must_use]
+    pub const fn verb(&self) -> XapiVerb {
+        self.verb_kind
+    }
+
+    /// Returns the IRI of the activity the statement is about.
+    #[inline]
+    #[must_use]
+    pub fn object_id(&self) -> &str {
+        &self.object.id
+    }
+
+    /// Returns the IRI of the parent activity, the course for lesson statements.
+    #[inline]
+    #[must_use]
+    pub fn parent_id(&self) -> Option<&str> {
+        self.context
+            .as_ref()
+            .and_then(|context| context.context_activities.parent.first())
+            .map(|parent| parent.id.as_str())
+    }
+
+    /// Returns when the event happened, as an ISO 8601 local date time.
+    #[inline]
+    #[must_use]
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+
+    /// Serializes the statement to the JSON accepted by an LRS.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XapiError::Serialization` if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, XapiStatementMapper};
+    /// use education_platform_common::DateTime;
+    ///
+    /// let started = DateTime::new(2024, 3, 5, 9, 0, 0).unwrap();
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(started), None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mapper = XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap();
+    /// let statement = mapper.lesson_started(&progress, &progress.lesson_progress()[0]).unwrap();
+    ///
+    /// let json = statement.to_json().unwrap();
+    /// assert!(json.contains(r#""id":"http://adlnet.gov/expapi/verbs/attempted""#));
+    /// ```
+    pub fn to_json(&self) -> Result<String, XapiError> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// Converts learner progress into xAPI statements for an external LRS.
+///
+/// Activities are identified by IRIs under the given base URL:
+/// `{base}/courses/{course}` for the course and
+/// `{base}/courses/{course}/lessons/{lesson}` for its lessons, where
+/// `{course}` is the course ID (or the progress ID when the progress is not
+/// linked to a course) and `{lesson}` is a slug of the lesson name, which is
+/// what identifies lessons across progress records.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseProgress, LessonProgress, XapiStatementMapper};
+/// use education_platform_common::DateTime;
+///
+/// let started = DateTime::new(2024, 3, 5, 9, 0, 0).unwrap();
+/// let ended = DateTime::new(2024, 3, 5, 9, 30, 0).unwrap();
+/// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(started), Some(ended)).unwrap();
+/// let progress = CourseProgress::builder()
+///     .course_name("Rust Course")
+///     .user_email("user@example.com")
+///     .lessons(vec![lesson])
+///     .build()
+///     .unwrap();
+///
+/// let mapper = XapiStatementMapper::new("https://lms.example.com/".to_string()).unwrap();
+/// let statements = mapper.statements(&progress);
+///
+/// // Started and completed the only lesson, which completed the course
+/// assert_eq!(statements.len(), 3);
+/// assert!(statements[0].object_id().ends_with("/lessons/intro"));
+/// assert_eq!(statements[2].parent_id(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XapiStatementMapper {
+    activity_base: String,
+}
+
+impl XapiStatementMapper {
+    /// Creates a mapper that identifies activities under the given base URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XapiError::ActivityBaseError` if the base URL is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::XapiStatementMapper;
+    ///
+    /// assert!(XapiStatementMapper::new("https://lms.example.com".to_string()).is_ok());
+    /// assert!(XapiStatementMapper::new("not a url".to_string()).is_err());
+    /// ```
+    pub fn new(activity_base: String) -> Result<Self, XapiError> {
+        let activity_base = Url::new(activity_base)?;
+
+        Ok(Self {
+            activity_base: activity_base.as_str().trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Returns the "attempted" statement for a lesson, if the learner started it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, XapiStatementMapper};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mapper = XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap();
+    /// assert!(mapper.lesson_started(&progress, &progress.lesson_progress()[0]).is_none());
+    /// ```
+    #[must_use]
+    pub fn lesson_started(
+        &self,
+        progress: &CourseProgress,
+        lesson: &LessonProgress,
+    ) -> Option<XapiStatement> {
+        lesson
+            .start_date()
+            .map(|at| self.lesson_statement(progress, lesson, XapiVerb::Attempted, at))
+    }
+
+    /// Returns the "completed" statement for a lesson, if the learner finished it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     CourseProgress, LessonProgress, XapiStatementMapper, XapiVerb,
+    /// };
+    /// use education_platform_common::DateTime;
+    ///
+    /// let started = DateTime::new(2024, 3, 5, 9, 0, 0).unwrap();
+    /// let ended = DateTime::new(2024, 3, 5, 9, 30, 0).unwrap();
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(started), Some(ended)).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mapper = XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap();
+    /// let statement = mapper.lesson_completed(&progress, &progress.lesson_progress()[0]).unwrap();
+    ///
+    /// assert_eq!(statement.verb(), XapiVerb::Completed);
+    /// assert_eq!(statement.timestamp(), "2024-03-05T09:30:00");
+    /// ```
+    #[must_use]
+    pub fn lesson_completed(
+        &self,
+        progress: &CourseProgress,
+        lesson: &LessonProgress,
+    ) -> Option<XapiStatement> {
+        lesson
+            .end_date()
+            .map(|at| self.lesson_statement(progress, lesson, XapiVerb::Completed, at))
+    }
+
+    /// Returns the "completed" statement for the course, if the learner finished it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, XapiStatementMapper};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mapper = XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap();
+    /// assert!(mapper.course_completed(&progress).is_none());
+    /// ```
+    #[must_use]
+    pub fn course_completed(&self, progress: &CourseProgress) -> Option<XapiStatement> {
+        progress.end_date().map(|at| {
+            Self::statement(
+                progress,
+                XapiVerb::Completed,
+                self.course_activity(progress),
+                None,
+                &at,
+            )
+        })
+    }
+
+    /// Returns every statement the progress supports, oldest first.
+    ///
+    /// Lesson starts and completions come first within the same instant, so
+    /// the course completion is always reported after its last lesson.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, XapiStatementMapper};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mapper = XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap();
+    /// assert!(mapper.statements(&progress).is_empty());
+    /// ```
+    #[must_use]
+    pub fn statements(&self, progress: &CourseProgress) -> Vec<XapiStatement> {
+        let mut statements: Vec<XapiStatement> = progress
+            .lesson_progress()
+            .iter()
+            .flat_map(|lesson| {
+                [
+                    self.lesson_started(progress, lesson),
+                    self.lesson_completed(progress, lesson),
+                ]
+            })
+            .chain([self.course_completed(progress)])
+            .flatten()
+            .collect();
+
+        statements.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+        statements
+    }
+
+    /// Serializes every statement of the progress as a JSON array, ready to
+    /// be posted to an LRS statements endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XapiError::Serialization` if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, XapiStatementMapper};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mapper = XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap();
+    /// assert_eq!(mapper.statements_json(&progress).unwrap(), "[]");
+    /// ```
+    pub fn statements_json(&self, progress: &CourseProgress) -> Result<String, XapiError> {
+        Ok(serde_json::to_string(&self.statements(progress))?)
+    }
+
+    fn lesson_statement(
+        &self,
+        progress: &CourseProgress,
+        lesson: &LessonProgress,
+        verb: XapiVerb,
+        at: &DateTime,
+    ) -> XapiStatement {
+        let course = self.course_activity(progress);
+        let activity = Activity {
+            object_type: "Activity",
+            id: format!("{}/lessons/{}", course.id, slug(lesson.lesson_name().as_str())),
+            definition: ActivityDefinition {
+                name: BTreeMap::from([(LANGUAGE, lesson.lesson_name().as_str().to_string())]),
+                activity_type: LESSON_ACTIVITY_TYPE,
+            },
+        };
+        let context = Context {
+            context_activities: ContextActivities {
+                parent: vec![ParentActivity { id: course.id }],
+            },
+        };
+
+        Self::statement(progress, verb, activity, Some(context), at)
+    }
+
+    fn course_activity(&self, progress: &CourseProgress) -> Activity {
+        let course_id = progress.course_id().unwrap_or_else(|| progress.id());
+
+        Activity {
+            object_type: "Activity",
+            id: format!("{}/courses/{course_id}", self.activity_base),
+            definition: ActivityDefinition {
+                name: BTreeMap::from([(LANGUAGE, progress.course_name().as_str().to_string())]),
+                activity_type: COURSE_ACTIVITY_TYPE,
+            },
+        }
+    }
+
+    fn statement(
+        progress: &CourseProgress,
+        verb: XapiVerb,
+        object: Activity,
+        context: Option<Context>,
+        at: &DateTime,
+    ) -> XapiStatement {
+        XapiStatement {
+            actor: Actor {
+                object_type: "Agent",
+                mbox: format!("mailto:{}", progress.user_email().address()),
+            },
+            verb: Verb {
+                id: verb.iri(),
+                display: BTreeMap::from([(LANGUAGE, verb.display())]),
+            },
+            object,
+            context,
+            timestamp: at.format_iso(),
+            verb_kind: verb,
+        }
+    }
+}
+
+/// Lowercases a name and joins its alphanumeric runs with dashes.
+fn slug(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::Id;
+    use serde_json::Value;
+
+    fn create_datetime(hour: u32, minute: u32) -> DateTime {
+        DateTime::new(2024, 3, 5, hour, minute, 0).unwrap()
+    }
+
+    fn create_test_mapper() -> XapiStatementMapper {
+        XapiStatementMapper::new("https://lms.example.com".to_string()).unwrap()
+    }
+
+    fn create_test_progress(lessons: Vec<LessonProgress>) -> CourseProgress {
+        CourseProgress::builder()
+            .course_name("Rust Course")
+            .user_email("user@example.com")
+            .lessons(lessons)
+            .build()
+            .unwrap()
+    }
+
+    fn create_finished_progress() -> CourseProgress {
+        let lesson = LessonProgress::new(
+            "Intro".to_string(),
+            1800,
+            Some(create_datetime(9, 0)),
+            Some(create_datetime(9, 30)),
+        )
+        .unwrap();
+        CourseProgress::builder()
+            .course_name("Rust Course")
+            .user_email("user@example.com")
+            .lessons(vec![lesson])
+            .end_date(create_datetime(9, 30))
+            .build()
+            .unwrap()
+    }
+
+    mod statements {
+        use super::*;
+
+        #[test]
+        fn test_lesson_statement_is_nested_under_course() {
+            let course_id = Id::new();
+            let lesson = LessonProgress::new(
+                "Ownership & Borrowing".to_string(),
+                1800,
+                Some(create_datetime(9, 0)),
+                None,
+            )
+            .unwrap();
+            let progress = CourseProgress::builder()
+                .course_id(course_id)
+                .course_name("Rust Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson])
+                .build()
+                .unwrap();
+
+            let statement = create_test_mapper()
+                .lesson_started(&progress, &progress.lesson_progress()[0])
+                .unwrap();
+
+            let course_iri = format!("https://lms.example.com/courses/{course_id}");
+            assert_eq!(statement.parent_id(), Some(course_iri.as_str()));
+            assert_eq!(
+                statement.object_id(),
+                format!("{course_iri}/lessons/ownership-borrowing")
+            );
+        }
+
+        #[test]
+        fn test_unlinked_progress_uses_progress_id() {
+            let progress = create_finished_progress();
+
+            let statement = create_test_mapper().course_completed(&progress).unwrap();
+
+            assert_eq!(
+                statement.object_id(),
+                format!("https://lms.example.com/courses/{}", progress.id())
+            );
+            assert_eq!(statement.parent_id(), None);
+        }
+
+        #[test]
+        fn test_statements_are_ordered_by_time() {
+            let first = LessonProgress::new(
+                "Second".to_string(),
+                1800,
+                Some(create_datetime(10, 0)),
+                Some(create_datetime(10, 30)),
+            )
+            .unwrap();
+            let second =
+                LessonProgress::new("First".to_string(), 1800, Some(create_datetime(9, 0)), None)
+                    .unwrap();
+            let progress = create_test_progress(vec![first, second]);
+
+            let statements = create_test_mapper().statements(&progress);
+
+            let timestamps: Vec<&str> = statements.iter().map(XapiStatement::timestamp).collect();
+            assert_eq!(
+                timestamps,
+                vec![
+                    "2024-03-05T09:00:00",
+                    "2024-03-05T10:00:00",
+                    "2024-03-05T10:30:00"
+                ]
+            );
+        }
+    }
+
+    mod json {
+        use super::*;
+
+        #[test]
+        fn test_statement_matches_xapi_shape() {
+            let lesson = LessonProgress::new(
+                "Intro".to_string(),
+                1800,
+                Some(create_datetime(9, 0)),
+                Some(create_datetime(9, 30)),
+            )
+            .unwrap();
+            let progress = create_test_progress(vec![lesson]);
+            let statement = create_test_mapper()
+                .lesson_completed(&progress, &progress.lesson_progress()[0])
+                .unwrap();
+
+            let value: Value = serde_json::from_str(&statement.to_json().unwrap()).unwrap();
+
+            assert_eq!(value["actor"]["objectType"], "Agent");
+            assert_eq!(value["actor"]["mbox"], "mailto:user@example.com");
+            assert_eq!(value["verb"]["id"], XapiVerb::Completed.iri());
+            assert_eq!(value["verb"]["display"]["en-US"], "completed");
+            assert_eq!(value["object"]["objectType"], "Activity");
+            assert_eq!(value["object"]["definition"]["name"]["en-US"], "Intro");
+            assert_eq!(value["object"]["definition"]["type"], LESSON_ACTIVITY_TYPE);
+            assert!(value["context"]["contextActivities"]["parent"][0]["id"].is_string());
+            assert_eq!(value["timestamp"], "2024-03-05T09:30:00");
+            assert!(value.get("verb_kind").is_none());
+        }
+
+        #[test]
+        fn test_course_statement_has_no_context() {
+            let progress = create_finished_progress();
+            let statement = create_test_mapper().course_completed(&progress).unwrap();
+
+            let value: Value = serde_json::from_str(&statement.to_json().unwrap()).unwrap();
+
+            assert!(value.get("context").is_none());
+            assert_eq!(value["object"]["definition"]["type"], COURSE_ACTIVITY_TYPE);
+        }
+    }
+
+    mod slug {
+        use super::*;
+
+        #[test]
+        fn test_slug_collapses_separators() {
+            assert_eq!(slug("  Traits: Part 2 "), "traits-part-2");
+        }
+    }
+}
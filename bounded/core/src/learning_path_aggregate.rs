@@ -0,0 +1,7 @@
+mod learning_path;
+mod milestone;
+mod path_progress;
+
+pub use learning_path::*;
+pub use milestone::*;
+pub use path_progress::*;
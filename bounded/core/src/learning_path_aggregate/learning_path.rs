@@ -0,0 +1,316 @@
+mod courses;
+mod milestones;
+
+use crate::{CourseId, Milestone};
+use education_platform_common::{
+    Auditable, DateTime, Entity, Id, SimpleName, SimpleNameConfig, SimpleNameError, Timestamps,
+};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Error types for LearningPath validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LearningPathError {
+    #[error("Learning path name validation failed: {0}")]
+    NameError(#[from] SimpleNameError),
+
+    #[error("Learning path must have at least one course")]
+    LearningPathWithoutCourses,
+
+    #[error("Course {0} is already in the learning path")]
+    DuplicateCourse(String),
+
+    #[error("Course is not in the learning path")]
+    CourseNotInPath,
+
+    #[error("Milestone name validation failed: {0}")]
+    MilestoneNameError(SimpleNameError),
+
+    #[error("Milestone does not exist")]
+    MilestoneDoesNotExist,
+}
+
+/// An ordered sequence of courses taken one after another, such as
+/// "Backend Developer" made of an introductory course, a web course and a
+/// databases course.
+///
+/// `LearningPath` is an aggregate root. It only refers to its courses by ID,
+/// so courses keep evolving on their own, and learner progress along the
+/// path is computed from their [`CourseProgress`](crate::CourseProgress)
+/// records with [`LearningPath::progress`]. [`Milestone`]s mark points
+/// along the way, each reached once every course up to it is completed.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::LearningPath;
+/// use education_platform_common::Id;
+///
+/// let basics = Id::new().cast();
+/// let web = Id::new().cast();
+///
+/// let mut path = LearningPath::new("Backend Developer".to_string(), vec![basics, web]).unwrap();
+/// path.add_milestone("Fundamentals".to_string(), basics).unwrap();
+///
+/// assert_eq!(path.course_quantity(), 2);
+/// assert_eq!(path.milestones()[0].after_course(), basics);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LearningPath {
+    id: Id,
+    name: SimpleName,
+    courses: Vec<CourseId>,
+    milestones: Vec<Milestone>,
+    timestamps: Timestamps,
+}
+
+/// Identifier of a [`LearningPath`].
+pub type LearningPathId = Id<LearningPath>;
+
+impl LearningPath {
+    /// Creates a new `LearningPath` taking the courses in the given order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::NameError` if the name validation fails,
+    /// `LearningPathError::LearningPathWithoutCourses` if no course is given,
+    /// or `LearningPathError::DuplicateCourse` if a course is listed twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::Id;
+    ///
+    /// let course = Id::new().cast();
+    ///
+    /// assert!(LearningPath::new("Data Analyst".to_string(), vec![course]).is_ok());
+    /// assert!(LearningPath::new("Data Analyst".to_string(), vec![]).is_err());
+    /// assert!(LearningPath::new("Data Analyst".to_string(), vec![course, course]).is_err());
+    /// ```
+    pub fn new(name: String, courses: Vec<CourseId>) -> Result<Self, LearningPathError> {
+        Self::with_id(Id::default(), name, courses)
+    }
+
+    /// Creates a `LearningPath` with a specific ID (for reconstruction from
+    /// persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`LearningPath::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::{Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let path =
+    ///     LearningPath::with_id(id, "Data Analyst".to_string(), vec![Id::new().cast()]).unwrap();
+    ///
+    /// assert_eq!(path.id(), id);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        name: String,
+        courses: Vec<CourseId>,
+    ) -> Result<Self, LearningPathError> {
+        let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        if courses.is_empty() {
+            return Err(LearningPathError::LearningPathWithoutCourses);
+        }
+
+        let mut seen = HashSet::with_capacity(courses.len());
+        if let Some(duplicate) = courses.iter().find(|course| !seen.insert(**course)) {
+            return Err(LearningPathError::DuplicateCourse(duplicate.to_string()));
+        }
+
+        Ok(Self {
+            id,
+            name,
+            courses,
+            milestones: Vec::new(),
+            timestamps: Timestamps::now(),
+        })
+    }
+
+    /// Returns the learning path ID typed as a [`LearningPathId`].
+    #[inline]
+    #[must_use]
+    pub const fn learning_path_id(&self) -> LearningPathId {
+        self.id.cast()
+    }
+
+    /// Returns the learning path name.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &SimpleName {
+        &self.name
+    }
+
+    /// Returns the courses in the order they are meant to be taken.
+    #[inline]
+    #[must_use]
+    pub fn courses(&self) -> &[CourseId] {
+        &self.courses
+    }
+
+    /// Returns the number of courses in the path.
+    #[inline]
+    #[must_use]
+    pub fn course_quantity(&self) -> usize {
+        self.courses.len()
+    }
+
+    /// Returns `true` if the course is part of the path.
+    #[inline]
+    #[must_use]
+    pub fn contains_course(&self, course_id: CourseId) -> bool {
+        self.courses.contains(&course_id)
+    }
+
+    /// Returns the milestones, in the order learners reach them.
+    #[inline]
+    #[must_use]
+    pub fn milestones(&self) -> &[Milestone] {
+        &self.milestones
+    }
+
+    /// Renames the learning path.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::NameError` if the name validation fails;
+    /// the path keeps its previous name.
+    pub fn update_name(&mut self, name: String) -> Result<(), LearningPathError> {
+        self.name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    fn position_of(&self, course_id: CourseId) -> Result<usize, LearningPathError> {
+        self.courses
+            .iter()
+            .position(|course| *course == course_id)
+            .ok_or(LearningPathError::CourseNotInPath)
+    }
+}
+
+impl Entity for LearningPath {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Auditable for LearningPath {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_course_ids(quantity: usize) -> Vec<CourseId> {
+        (0..quantity).map(|_| Id::new().cast()).collect()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_keeps_course_order() {
+            let courses = create_course_ids(3);
+
+            let path = LearningPath::new("Backend Developer".to_string(), courses.clone()).unwrap();
+
+            assert_eq!(path.name().as_str(), "Backend Developer");
+            assert_eq!(path.courses(), courses.as_slice());
+            assert!(path.milestones().is_empty());
+        }
+
+        #[test]
+        fn test_new_without_courses() {
+            let result = LearningPath::new("Backend Developer".to_string(), vec![]);
+
+            assert!(matches!(result, Err(LearningPathError::LearningPathWithoutCourses)));
+        }
+
+        #[test]
+        fn test_new_with_duplicate_course() {
+            let courses = create_course_ids(2);
+
+            let result = LearningPath::new(
+                "Backend Developer".to_string(),
+                vec![courses[0], courses[1], courses[0]],
+            );
+
+            assert!(matches!(
+                result,
+                Err(LearningPathError::DuplicateCourse(id)) if id == courses[0].to_string()
+            ));
+        }
+
+        #[test]
+        fn test_new_with_invalid_name() {
+            let result = LearningPath::new("BD".to_string(), create_course_ids(1));
+
+            assert!(matches!(result, Err(LearningPathError::NameError(_))));
+        }
+
+        #[test]
+        fn test_typed_id_matches_entity_id() {
+            let path =
+                LearningPath::new("Backend Developer".to_string(), create_course_ids(1)).unwrap();
+
+            assert_eq!(path.learning_path_id().untyped(), path.id());
+        }
+    }
+
+    mod getters {
+        use super::*;
+
+        #[test]
+        fn test_contains_course() {
+            let courses = create_course_ids(2);
+            let path =
+                LearningPath::new("Backend Developer".to_string(), vec![courses[0]]).unwrap();
+
+            assert!(path.contains_course(courses[0]));
+            assert!(!path.contains_course(courses[1]));
+            assert_eq!(path.course_quantity(), 1);
+        }
+    }
+
+    mod update_name {
+        use super::*;
+
+        #[test]
+        fn test_renames_and_keeps_courses() {
+            let courses = create_course_ids(2);
+            let mut path =
+                LearningPath::new("Backend Developer".to_string(), courses.clone()).unwrap();
+
+            path.update_name("Backend Engineer".to_string()).unwrap();
+
+            assert_eq!(path.name().as_str(), "Backend Engineer");
+            assert_eq!(path.courses(), courses.as_slice());
+        }
+
+        #[test]
+        fn test_invalid_name_keeps_previous() {
+            let mut path =
+                LearningPath::new("Backend Developer".to_string(), create_course_ids(1)).unwrap();
+
+            assert!(path.update_name("x".repeat(51)).is_err());
+            assert_eq!(path.name().as_str(), "Backend Developer");
+        }
+    }
+}
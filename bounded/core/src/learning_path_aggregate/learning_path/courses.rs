@@ -0,0 +1,411 @@
+use super::{LearningPath, LearningPathError};
+use crate::CourseId;
+use education_platform_common::Index;
+
+impl LearningPath {
+    /// Adds a course to the path at the specified position.
+    ///
+    /// If `index` is `None`, the course is appended at the end; an index past
+    /// the end appends as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::DuplicateCourse` if the course is already
+    /// in the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::{Id, Index};
+    ///
+    /// let web = Id::new().cast();
+    /// let mut path = LearningPath::new("Backend Developer".to_string(), vec![web]).unwrap();
+    ///
+    /// let basics = Id::new().cast();
+    /// path.add_course(basics, Some(Index::new(0))).unwrap();
+    ///
+    /// assert_eq!(path.courses(), &[basics, web]);
+    /// assert!(path.add_course(web, None).is_err());
+    /// ```
+    pub fn add_course(
+        &mut self,
+        course_id: CourseId,
+        index: Option<Index>,
+    ) -> Result<(), LearningPathError> {
+        if self.contains_course(course_id) {
+            return Err(LearningPathError::DuplicateCourse(course_id.to_string()));
+        }
+
+        let position = index
+            .map(|idx| idx.value().min(self.courses.len()))
+            .unwrap_or(self.courses.len());
+        self.courses.insert(position, course_id);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes a course from the path, along with the milestones reached
+    /// after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::CourseNotInPath` if the course is not in
+    /// the path, or `LearningPathError::LearningPathWithoutCourses` if it is
+    /// the only one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::Id;
+    ///
+    /// let basics = Id::new().cast();
+    /// let web = Id::new().cast();
+    /// let mut path =
+    ///     LearningPath::new("Backend Developer".to_string(), vec![basics, web]).unwrap();
+    ///
+    /// path.remove_course(basics).unwrap();
+    ///
+    /// assert_eq!(path.courses(), &[web]);
+    /// assert!(path.remove_course(web).is_err());
+    /// ```
+    pub fn remove_course(&mut self, course_id: CourseId) -> Result<(), LearningPathError> {
+        let position = self.position_of(course_id)?;
+        if self.courses.len() == 1 {
+            return Err(LearningPathError::LearningPathWithoutCourses);
+        }
+
+        self.courses.remove(position);
+        self.milestones
+            .retain(|milestone| milestone.after_course() != course_id);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Moves a course to a specific index position in the path.
+    ///
+    /// The course is taken out of its current position and inserted at the
+    /// target index; an index past the end moves it last. Milestones stay
+    /// attached to their course.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::CourseNotInPath` if the course is not in
+    /// the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::{Id, Index};
+    ///
+    /// let courses: Vec<_> = (0..3).map(|_| Id::new().cast()).collect();
+    /// let mut path = LearningPath::new("Backend Developer".to_string(), courses.clone()).unwrap();
+    ///
+    /// path.move_course(courses[0], Index::new(2)).unwrap();
+    ///
+    /// assert_eq!(path.courses(), &[courses[1], courses[2], courses[0]]);
+    /// ```
+    pub fn move_course(
+        &mut self,
+        course_id: CourseId,
+        to_index: Index,
+    ) -> Result<(), LearningPathError> {
+        let position = self.position_of(course_id)?;
+
+        self.courses.remove(position);
+        let target = to_index.value().min(self.courses.len());
+        self.courses.insert(target, course_id);
+        if target != position {
+            self.sort_milestones();
+            self.timestamps.touch();
+        }
+        Ok(())
+    }
+
+    /// Moves a course one position up (towards the beginning) in the path.
+    ///
+    /// The first course stays where it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::CourseNotInPath` if the course is not in
+    /// the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::Id;
+    ///
+    /// let basics = Id::new().cast();
+    /// let web = Id::new().cast();
+    /// let mut path =
+    ///     LearningPath::new("Backend Developer".to_string(), vec![basics, web]).unwrap();
+    ///
+    /// path.move_course_up(web).unwrap();
+    ///
+    /// assert_eq!(path.courses(), &[web, basics]);
+    /// ```
+    pub fn move_course_up(&mut self, course_id: CourseId) -> Result<(), LearningPathError> {
+        let current_position = self.position_of(course_id)?;
+
+        if current_position == 0 {
+            return Ok(());
+        }
+
+        self.move_course(course_id, Index::new(current_position - 1))
+    }
+
+    /// Moves a course one position down (towards the end) in the path.
+    ///
+    /// The last course stays where it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::CourseNotInPath` if the course is not in
+    /// the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::Id;
+    ///
+    /// let basics = Id::new().cast();
+    /// let web = Id::new().cast();
+    /// let mut path =
+    ///     LearningPath::new("Backend Developer".to_string(), vec![basics, web]).unwrap();
+    ///
+    /// path.move_course_down(basics).unwrap();
+    ///
+    /// assert_eq!(path.courses(), &[web, basics]);
+    /// ```
+    pub fn move_course_down(&mut self, course_id: CourseId) -> Result<(), LearningPathError> {
+        let current_position = self.position_of(course_id)?;
+
+        if current_position >= self.courses.len() - 1 {
+            return Ok(());
+        }
+
+        self.move_course(course_id, Index::new(current_position + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::Id;
+
+    fn create_test_path(quantity: usize) -> (LearningPath, Vec<CourseId>) {
+        let courses: Vec<CourseId> = (0..quantity).map(|_| Id::new().cast()).collect();
+        let path = LearningPath::new("Backend Developer".to_string(), courses.clone()).unwrap();
+        (path, courses)
+    }
+
+    mod add_course {
+        use super::*;
+
+        #[test]
+        fn test_appends_without_index() {
+            let (mut path, courses) = create_test_path(2);
+            let new_course = Id::new().cast();
+
+            path.add_course(new_course, None).unwrap();
+
+            assert_eq!(path.courses(), &[courses[0], courses[1], new_course]);
+        }
+
+        #[test]
+        fn test_inserts_at_index() {
+            let (mut path, courses) = create_test_path(2);
+            let new_course = Id::new().cast();
+
+            path.add_course(new_course, Some(Index::new(1))).unwrap();
+
+            assert_eq!(path.courses(), &[courses[0], new_course, courses[1]]);
+        }
+
+        #[test]
+        fn test_index_past_end_appends() {
+            let (mut path, courses) = create_test_path(1);
+            let new_course = Id::new().cast();
+
+            path.add_course(new_course, Some(Index::new(10))).unwrap();
+
+            assert_eq!(path.courses(), &[courses[0], new_course]);
+        }
+
+        #[test]
+        fn test_duplicate_is_rejected() {
+            let (mut path, courses) = create_test_path(2);
+
+            let result = path.add_course(courses[1], Some(Index::new(0)));
+
+            assert_eq!(
+                result,
+                Err(LearningPathError::DuplicateCourse(courses[1].to_string()))
+            );
+            assert_eq!(path.courses(), courses.as_slice());
+        }
+    }
+
+    mod remove_course {
+        use super::*;
+
+        #[test]
+        fn test_removes_course_and_its_milestones() {
+            let (mut path, courses) = create_test_path(3);
+            path.add_milestone("Fundamentals".to_string(), courses[0])
+                .unwrap();
+            path.add_milestone("Web Ready".to_string(), courses[1])
+                .unwrap();
+
+            path.remove_course(courses[0]).unwrap();
+
+            assert_eq!(path.courses(), &[courses[1], courses[2]]);
+            assert_eq!(path.milestones().len(), 1);
+            assert_eq!(path.milestones()[0].name().as_str(), "Web Ready");
+        }
+
+        #[test]
+        fn test_unknown_course() {
+            let (mut path, _) = create_test_path(2);
+
+            let result = path.remove_course(Id::new().cast());
+
+            assert_eq!(result, Err(LearningPathError::CourseNotInPath));
+        }
+
+        #[test]
+        fn test_last_course_cannot_be_removed() {
+            let (mut path, courses) = create_test_path(1);
+
+            let result = path.remove_course(courses[0]);
+
+            assert_eq!(result, Err(LearningPathError::LearningPathWithoutCourses));
+            assert_eq!(path.course_quantity(), 1);
+        }
+    }
+
+    mod move_course {
+        use super::*;
+
+        #[test]
+        fn test_moves_forward() {
+            let (mut path, courses) = create_test_path(3);
+
+            path.move_course(courses[0], Index::new(2)).unwrap();
+
+            assert_eq!(path.courses(), &[courses[1], courses[2], courses[0]]);
+        }
+
+        #[test]
+        fn test_moves_backward() {
+            let (mut path, courses) = create_test_path(3);
+
+            path.move_course(courses[2], Index::new(0)).unwrap();
+
+            assert_eq!(path.courses(), &[courses[2], courses[0], courses[1]]);
+        }
+
+        #[test]
+        fn test_index_past_end_moves_last() {
+            let (mut path, courses) = create_test_path(3);
+
+            path.move_course(courses[0], Index::new(99)).unwrap();
+
+            assert_eq!(path.courses(), &[courses[1], courses[2], courses[0]]);
+        }
+
+        #[test]
+        fn test_unknown_course() {
+            let (mut path, _) = create_test_path(2);
+
+            let result = path.move_course(Id::new().cast(), Index::new(0));
+
+            assert_eq!(result, Err(LearningPathError::CourseNotInPath));
+        }
+
+        #[test]
+        fn test_milestones_follow_their_course() {
+            let (mut path, courses) = create_test_path(3);
+            path.add_milestone("First".to_string(), courses[0]).unwrap();
+            path.add_milestone("Last".to_string(), courses[2]).unwrap();
+
+            path.move_course(courses[2], Index::new(0)).unwrap();
+
+            let names: Vec<&str> = path
+                .milestones()
+                .iter()
+                .map(|milestone| milestone.name().as_str())
+                .collect();
+            assert_eq!(names, vec!["Last", "First"]);
+        }
+    }
+
+    mod move_course_up {
+        use super::*;
+
+        #[test]
+        fn test_swaps_with_previous() {
+            let (mut path, courses) = create_test_path(3);
+
+            path.move_course_up(courses[1]).unwrap();
+
+            assert_eq!(path.courses(), &[courses[1], courses[0], courses[2]]);
+        }
+
+        #[test]
+        fn test_first_course_stays() {
+            let (mut path, courses) = create_test_path(2);
+
+            path.move_course_up(courses[0]).unwrap();
+
+            assert_eq!(path.courses(), courses.as_slice());
+        }
+
+        #[test]
+        fn test_unknown_course() {
+            let (mut path, _) = create_test_path(2);
+
+            assert_eq!(
+                path.move_course_up(Id::new().cast()),
+                Err(LearningPathError::CourseNotInPath)
+            );
+        }
+    }
+
+    mod move_course_down {
+        use super::*;
+
+        #[test]
+        fn test_swaps_with_next() {
+            let (mut path, courses) = create_test_path(3);
+
+            path.move_course_down(courses[1]).unwrap();
+
+            assert_eq!(path.courses(), &[courses[0], courses[2], courses[1]]);
+        }
+
+        #[test]
+        fn test_last_course_stays() {
+            let (mut path, courses) = create_test_path(2);
+
+            path.move_course_down(courses[1]).unwrap();
+
+            assert_eq!(path.courses(), courses.as_slice());
+        }
+
+        #[test]
+        fn test_unknown_course() {
+            let (mut path, _) = create_test_path(2);
+
+            assert_eq!(
+                path.move_course_down(Id::new().cast()),
+                Err(LearningPathError::CourseNotInPath)
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use super::{LearningPath, LearningPathError};
+use crate::{CourseId, Milestone, MilestoneId};
+use education_platform_common::{SimpleName, SimpleNameConfig};
+
+impl LearningPath {
+    /// Adds a milestone reached after completing `after_course` and every
+    /// course before it, returning its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::CourseNotInPath` if the course is not in
+    /// the path, or `LearningPathError::MilestoneNameError` if the name
+    /// validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearningPath;
+    /// use education_platform_common::Id;
+    ///
+    /// let basics = Id::new().cast();
+    /// let web = Id::new().cast();
+    /// let mut path =
+    ///     LearningPath::new("Backend Developer".to_string(), vec![basics, web]).unwrap();
+    ///
+    /// path.add_milestone("Job Ready".to_string(), web).unwrap();
+    /// path.add_milestone("Fundamentals".to_string(), basics).unwrap();
+    ///
+    /// assert_eq!(path.milestones()[0].name().as_str(), "Fundamentals");
+    /// ```
+    pub fn add_milestone(
+        &mut self,
+        name: String,
+        after_course: CourseId,
+    ) -> Result<MilestoneId, LearningPathError> {
+        self.position_of(after_course)?;
+        let name = SimpleName::with_config(name, SimpleNameConfig::new(3, 50))
+            .map_err(LearningPathError::MilestoneNameError)?;
+
+        let milestone = Milestone::new(name, after_course);
+        let id = milestone.id();
+        self.milestones.push(milestone);
+        self.sort_milestones();
+        self.timestamps.touch();
+        Ok(id)
+    }
+
+    /// Removes a milestone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LearningPathError::MilestoneDoesNotExist` if no milestone
+    /// has the ID.
+    pub fn remove_milestone(&mut self, milestone_id: MilestoneId) -> Result<(), LearningPathError> {
+        let position = self
+            .milestones
+            .iter()
+            .position(|milestone| milestone.id() == milestone_id)
+            .ok_or(LearningPathError::MilestoneDoesNotExist)?;
+
+        self.milestones.remove(position);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Keeps milestones in course order; milestones after the same course
+    /// stay in the order they were added.
+    pub(super) fn sort_milestones(&mut self) {
+        let courses = &self.courses;
+        self.milestones.sort_by_key(|milestone| {
+            courses
+                .iter()
+                .position(|course| *course == milestone.after_course())
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::Id;
+
+    fn create_test_path(quantity: usize) -> (LearningPath, Vec<CourseId>) {
+        let courses: Vec<CourseId> = (0..quantity).map(|_| Id::new().cast()).collect();
+        let path = LearningPath::new("Backend Developer".to_string(), courses.clone()).unwrap();
+        (path, courses)
+    }
+
+    mod add_milestone {
+        use super::*;
+
+        #[test]
+        fn test_milestones_are_kept_in_course_order() {
+            let (mut path, courses) = create_test_path(3);
+
+            path.add_milestone("Third".to_string(), courses[2]).unwrap();
+            path.add_milestone("First".to_string(), courses[0]).unwrap();
+            path.add_milestone("First Again".to_string(), courses[0])
+                .unwrap();
+
+            let names: Vec<&str> = path
+                .milestones()
+                .iter()
+                .map(|milestone| milestone.name().as_str())
+                .collect();
+            assert_eq!(names, vec!["First", "First Again", "Third"]);
+        }
+
+        #[test]
+        fn test_returns_the_new_id() {
+            let (mut path, courses) = create_test_path(1);
+
+            let id = path.add_milestone("Done".to_string(), courses[0]).unwrap();
+
+            assert_eq!(path.milestones()[0].id(), id);
+            assert_eq!(path.milestones()[0].after_course(), courses[0]);
+        }
+
+        #[test]
+        fn test_course_outside_the_path() {
+            let (mut path, _) = create_test_path(1);
+
+            let result = path.add_milestone("Done".to_string(), Id::new().cast());
+
+            assert_eq!(result, Err(LearningPathError::CourseNotInPath));
+        }
+
+        #[test]
+        fn test_invalid_name() {
+            let (mut path, courses) = create_test_path(1);
+
+            let result = path.add_milestone("  ".to_string(), courses[0]);
+
+            assert!(matches!(result, Err(LearningPathError::MilestoneNameError(_))));
+            assert!(path.milestones().is_empty());
+        }
+    }
+
+    mod remove_milestone {
+        use super::*;
+
+        #[test]
+        fn test_removes_only_that_milestone() {
+            let (mut path, courses) = create_test_path(2);
+            let first = path.add_milestone("First".to_string(), courses[0]).unwrap();
+            path.add_milestone("Second".to_string(), courses[1])
+                .unwrap();
+
+            path.remove_milestone(first).unwrap();
+
+            assert_eq!(path.milestones().len(), 1);
+            assert_eq!(path.milestones()[0].name().as_str(), "Second");
+        }
+
+        #[test]
+        fn test_unknown_milestone() {
+            let (mut path, _) = create_test_path(1);
+
+            let result = path.remove_milestone(Id::new().cast());
+
+            assert_eq!(result, Err(LearningPathError::MilestoneDoesNotExist));
+        }
+    }
+}
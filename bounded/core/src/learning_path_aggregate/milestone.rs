@@ -0,0 +1,50 @@
+use crate::CourseId;
+use education_platform_common::{Id, SimpleName};
+
+/// A named point along a [`LearningPath`](crate::LearningPath), reached
+/// once the learner has completed every course up to and including
+/// `after_course`.
+///
+/// Milestones follow their course when the path is reordered, and are
+/// dropped with it when it leaves the path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Milestone {
+    id: MilestoneId,
+    name: SimpleName,
+    after_course: CourseId,
+}
+
+/// Identifier of a [`Milestone`].
+pub type MilestoneId = Id<Milestone>;
+
+impl Milestone {
+    pub(crate) fn new(name: SimpleName, after_course: CourseId) -> Self {
+        Self {
+            id: Id::new().cast(),
+            name,
+            after_course,
+        }
+    }
+
+    /// Returns the milestone ID.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> MilestoneId {
+        self.id
+    }
+
+    /// Returns the milestone name, such as "Fundamentals".
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &SimpleName {
+        &self.name
+    }
+
+    /// Returns the last course a learner has to complete to reach the
+    /// milestone.
+    #[inline]
+    #[must_use]
+    pub const fn after_course(&self) -> CourseId {
+        self.after_course
+    }
+}
@@ -0,0 +1,317 @@
+use crate::{CourseId, CourseProgress, LearningPath, MilestoneId};
+use education_platform_common::Percentage;
+
+/// A learner's progress along a [`LearningPath`], computed from their
+/// course progress records.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathProgress {
+    courses: Vec<(CourseId, Percentage)>,
+    completed_courses: usize,
+    next_course: Option<CourseId>,
+    reached_milestones: Vec<MilestoneId>,
+}
+
+impl PathProgress {
+    /// Returns how far the learner is along the path.
+    ///
+    /// Every course weighs the same, whatever its length, and courses the
+    /// learner has not enrolled in count as not started.
+    #[must_use]
+    pub fn percentage(&self) -> Percentage {
+        Percentage::average(self.courses.iter().map(|(_, percentage)| *percentage))
+    }
+
+    /// Returns the progress in each course, in path order.
+    #[inline]
+    #[must_use]
+    pub fn courses(&self) -> &[(CourseId, Percentage)] {
+        &self.courses
+    }
+
+    /// Returns the progress in one course, or `None` if it is not in the
+    /// path.
+    #[must_use]
+    pub fn course_percentage(&self, course_id: CourseId) -> Option<Percentage> {
+        self.courses
+            .iter()
+            .find(|(course, _)| *course == course_id)
+            .map(|(_, percentage)| *percentage)
+    }
+
+    /// Returns how many courses of the path the learner has completed.
+    #[inline]
+    #[must_use]
+    pub const fn completed_courses(&self) -> usize {
+        self.completed_courses
+    }
+
+    /// Returns how many courses the path has.
+    #[inline]
+    #[must_use]
+    pub fn total_courses(&self) -> usize {
+        self.courses.len()
+    }
+
+    /// Returns `true` if every course of the path is completed.
+    #[inline]
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.next_course.is_none()
+    }
+
+    /// Returns the first course in path order the learner has not
+    /// completed, which is where they should continue.
+    #[inline]
+    #[must_use]
+    pub const fn next_course(&self) -> Option<CourseId> {
+        self.next_course
+    }
+
+    /// Returns the milestones the learner has reached, in path order.
+    #[inline]
+    #[must_use]
+    pub fn reached_milestones(&self) -> &[MilestoneId] {
+        &self.reached_milestones
+    }
+}
+
+impl LearningPath {
+    /// Aggregates one learner's progress across the courses of the path.
+    ///
+    /// `enrollments` are the learner's course progress records; records for
+    /// courses outside the path are ignored, and when a course has several
+    /// records the most advanced one counts. A milestone is reached once its
+    /// course and every course before it are completed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseProgress, LearningPath, Lesson};
+    /// use education_platform_common::Percentage;
+    ///
+    /// let create_course = |name: &str| {
+    ///     let lesson = Lesson::new(
+    ///         "Introduction".to_string(),
+    ///         600,
+    ///         "https://example.com/intro.mp4".to_string(),
+    ///         0,
+    ///     ).unwrap();
+    ///     let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    ///     Course::new(name.to_string(), None, 0, vec![chapter]).unwrap()
+    /// };
+    /// let basics = create_course("Rust Basics");
+    /// let web = create_course("Rust on the Web");
+    ///
+    /// let mut path = LearningPath::new(
+    ///     "Backend Developer".to_string(),
+    ///     vec![basics.course_id(), web.course_id()],
+    /// ).unwrap();
+    /// let milestone = path.add_milestone("Fundamentals".to_string(), basics.course_id()).unwrap();
+    ///
+    /// let mut enrollment = CourseProgress::from_course(&basics, "ana@example.com").unwrap();
+    /// let lesson_id = basics.chapters()[0].lessons()[0].lesson_id();
+    /// enrollment.start_lesson(lesson_id);
+    /// enrollment.end_lesson(lesson_id).unwrap();
+    ///
+    /// let progress = path.progress(&[enrollment]);
+    ///
+    /// assert_eq!(progress.percentage(), Percentage::new(50.0).unwrap());
+    /// assert_eq!(progress.next_course(), Some(web.course_id()));
+    /// assert_eq!(progress.reached_milestones(), &[milestone]);
+    /// ```
+    #[must_use]
+    pub fn progress(&self, enrollments: &[CourseProgress]) -> PathProgress {
+        let standings: Vec<(CourseId, bool, Percentage)> = self
+            .courses()
+            .iter()
+            .map(|course_id| {
+                let (completed, percentage) = enrollments
+                    .iter()
+                    .filter(|enrollment| enrollment.course_id() == Some(course_id.untyped()))
+                    .map(|enrollment| {
+                        if enrollment.is_completed() {
+                            (true, Percentage::FULL)
+                        } else {
+                            (false, enrollment.percentage_completed())
+                        }
+                    })
+                    .fold((false, Percentage::ZERO), |best, current| {
+                        if (current.0, current.1.value()) > (best.0, best.1.value()) {
+                            current
+                        } else {
+                            best
+                        }
+                    });
+                (*course_id, completed, percentage)
+            })
+            .collect();
+
+        // Milestones count completed courses from the start of the path, so
+        // a course finished out of order does not skip ahead.
+        let completed_prefix = standings
+            .iter()
+            .take_while(|(_, completed, _)| *completed)
+            .count();
+        let reached_milestones = self
+            .milestones()
+            .iter()
+            .filter(|milestone| {
+                self.courses()[..completed_prefix].contains(&milestone.after_course())
+            })
+            .map(|milestone| milestone.id())
+            .collect();
+
+        PathProgress {
+            completed_courses: standings
+                .iter()
+                .filter(|(_, completed, _)| *completed)
+                .count(),
+            next_course: standings
+                .iter()
+                .find(|(_, completed, _)| !completed)
+                .map(|(course_id, _, _)| *course_id),
+            courses: standings
+                .into_iter()
+                .map(|(course_id, _, percentage)| (course_id, percentage))
+                .collect(),
+            reached_milestones,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Course, Lesson};
+    use education_platform_common::Id;
+
+    fn create_test_course(name: &str, lessons: usize) -> Course {
+        let lessons = (0..lessons)
+            .map(|index| {
+                Lesson::new(
+                    format!("Lesson {}", index),
+                    600,
+                    format!("https://example.com/{}.mp4", index),
+                    index,
+                )
+                .unwrap()
+            })
+            .collect();
+        let chapter = Chapter::new("Chapter One".to_string(), 0, lessons).unwrap();
+        Course::new(name.to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn enroll(course: &Course, lessons_ended: usize) -> CourseProgress {
+        let mut progress = CourseProgress::from_course(course, "ana@example.com").unwrap();
+        for lesson in course.chapters()[0].lessons().iter().take(lessons_ended) {
+            progress.start_lesson(lesson.lesson_id());
+            progress.end_lesson(lesson.lesson_id()).unwrap();
+        }
+        progress
+    }
+
+    fn create_test_path(courses: &[&Course]) -> LearningPath {
+        LearningPath::new(
+            "Backend Developer".to_string(),
+            courses.iter().map(|course| course.course_id()).collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_no_enrollments() {
+        let first = create_test_course("First Course", 1);
+        let second = create_test_course("Second Course", 1);
+        let path = create_test_path(&[&first, &second]);
+
+        let progress = path.progress(&[]);
+
+        assert_eq!(progress.percentage(), Percentage::ZERO);
+        assert_eq!(progress.completed_courses(), 0);
+        assert_eq!(progress.total_courses(), 2);
+        assert_eq!(progress.next_course(), Some(first.course_id()));
+        assert!(!progress.is_completed());
+    }
+
+    #[test]
+    fn test_courses_weigh_the_same() {
+        let short = create_test_course("Short Course", 1);
+        let long = create_test_course("Long Course", 4);
+        let path = create_test_path(&[&short, &long]);
+
+        let progress = path.progress(&[enroll(&short, 1), enroll(&long, 1)]);
+
+        assert_eq!(progress.course_percentage(short.course_id()), Some(Percentage::FULL));
+        assert_eq!(
+            progress.course_percentage(long.course_id()),
+            Some(Percentage::new(25.0).unwrap())
+        );
+        assert_eq!(progress.percentage(), Percentage::new(62.5).unwrap());
+        assert_eq!(progress.completed_courses(), 1);
+        assert_eq!(progress.next_course(), Some(long.course_id()));
+    }
+
+    #[test]
+    fn test_completed_path() {
+        let first = create_test_course("First Course", 1);
+        let second = create_test_course("Second Course", 2);
+        let path = create_test_path(&[&first, &second]);
+
+        let progress = path.progress(&[enroll(&second, 2), enroll(&first, 1)]);
+
+        assert!(progress.is_completed());
+        assert_eq!(progress.percentage(), Percentage::FULL);
+        assert_eq!(progress.next_course(), None);
+    }
+
+    #[test]
+    fn test_other_courses_are_ignored() {
+        let first = create_test_course("First Course", 1);
+        let outside = create_test_course("Outside Course", 1);
+        let path = create_test_path(&[&first]);
+
+        let progress = path.progress(&[enroll(&outside, 1)]);
+
+        assert_eq!(progress.percentage(), Percentage::ZERO);
+        assert_eq!(progress.course_percentage(outside.course_id()), None);
+    }
+
+    #[test]
+    fn test_most_advanced_enrollment_counts() {
+        let course = create_test_course("First Course", 2);
+        let path = create_test_path(&[&course]);
+
+        let progress = path.progress(&[enroll(&course, 1), enroll(&course, 0)]);
+
+        assert_eq!(progress.percentage(), Percentage::new(50.0).unwrap());
+    }
+
+    #[test]
+    fn test_milestones_need_every_earlier_course() {
+        let first = create_test_course("First Course", 1);
+        let second = create_test_course("Second Course", 1);
+        let third = create_test_course("Third Course", 1);
+        let mut path = create_test_path(&[&first, &second, &third]);
+        let after_first = path
+            .add_milestone("Fundamentals".to_string(), first.course_id())
+            .unwrap();
+        path.add_milestone("Halfway".to_string(), second.course_id())
+            .unwrap();
+        path.add_milestone("Job Ready".to_string(), third.course_id())
+            .unwrap();
+
+        let progress = path.progress(&[enroll(&first, 1), enroll(&third, 1)]);
+
+        assert_eq!(progress.reached_milestones(), &[after_first]);
+        assert_eq!(progress.completed_courses(), 2);
+        assert_eq!(progress.next_course(), Some(second.course_id()));
+    }
+
+    #[test]
+    fn test_unknown_course_has_no_percentage() {
+        let course = create_test_course("First Course", 1);
+        let path = create_test_path(&[&course]);
+
+        assert_eq!(path.progress(&[]).course_percentage(Id::new().cast()), None);
+    }
+}
@@ -1,9 +1,63 @@
+mod adjust_progress;
+mod assignment_aggregate;
+mod audit;
+#[cfg(feature = "scripting")]
+mod automation;
+mod certification;
+mod cohort;
+mod coupon_aggregate;
 mod course_aggregate;
 mod create_course_progress;
+mod extension;
+mod gamification;
+mod integrity;
+mod interop;
+mod learning_path_aggregate;
+mod media;
+mod mentoring;
+mod notification_aggregate;
+mod order_aggregate;
 mod person;
+mod presence;
 mod progress;
+mod projection;
+mod publish_recording;
+mod query;
+mod reporting;
+mod retrieval;
+mod segment;
+mod skills;
+mod webhook;
+mod wishlist_aggregate;
 
+pub use adjust_progress::*;
+pub use assignment_aggregate::*;
+pub use audit::*;
+#[cfg(feature = "scripting")]
+pub use automation::*;
+pub use certification::*;
+pub use cohort::*;
+pub use coupon_aggregate::*;
 pub use course_aggregate::*;
 pub use create_course_progress::*;
+pub use extension::*;
+pub use gamification::*;
+pub use integrity::*;
+pub use interop::*;
+pub use learning_path_aggregate::*;
+pub use media::*;
+pub use mentoring::*;
+pub use notification_aggregate::*;
+pub use order_aggregate::*;
 pub use person::*;
+pub use presence::*;
 pub use progress::*;
+pub use projection::*;
+pub use publish_recording::*;
+pub use query::*;
+pub use reporting::*;
+pub use retrieval::*;
+pub use segment::*;
+pub use skills::*;
+pub use webhook::*;
+pub use wishlist_aggregate::*;
@@ -0,0 +1,5 @@
+mod video_check;
+mod video_metadata;
+
+pub use video_check::*;
+pub use video_metadata::*;
@@ -0,0 +1,415 @@
+use crate::{Course, Lesson, LessonId, ProbeError, VideoMetadata};
+use education_platform_common::Duration;
+
+/// Something wrong with a lesson video.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VideoIssue {
+    /// The video URL could not be fetched.
+    Unreachable(String),
+
+    /// The media is longer or shorter than the lesson says.
+    DurationMismatch {
+        declared: Duration,
+        actual: Duration,
+    },
+
+    /// The probe could not run, so the video was not checked.
+    ProbeFailed(String),
+}
+
+/// A video issue found on one lesson.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoDiscrepancy {
+    lesson_id: LessonId,
+    issue: VideoIssue,
+}
+
+impl VideoDiscrepancy {
+    /// Returns the lesson whose video has the issue.
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> LessonId {
+        self.lesson_id
+    }
+
+    /// Returns what is wrong with the video.
+    #[inline]
+    #[must_use]
+    pub const fn issue(&self) -> &VideoIssue {
+        &self.issue
+    }
+}
+
+/// Outcome of checking the videos of a course.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VideoReport {
+    checked: usize,
+    duration_unverified: usize,
+    discrepancies: Vec<VideoDiscrepancy>,
+}
+
+impl VideoReport {
+    /// Returns how many lessons were probed.
+    #[inline]
+    #[must_use]
+    pub const fn checked(&self) -> usize {
+        self.checked
+    }
+
+    /// Returns how many reachable videos the probe could not read a length
+    /// from, so their declared durations were taken on trust.
+    #[inline]
+    #[must_use]
+    pub const fn duration_unverified(&self) -> usize {
+        self.duration_unverified
+    }
+
+    /// Returns the issues found, in course order.
+    #[inline]
+    #[must_use]
+    pub fn discrepancies(&self) -> &[VideoDiscrepancy] {
+        &self.discrepancies
+    }
+
+    /// Returns `true` if no lesson had an issue.
+    #[inline]
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Checks lesson videos against a [`VideoMetadata`] probe.
+///
+/// A video passes if it is reachable and, when the probe reads its length,
+/// that length is within the tolerance of the lesson's declared duration.
+/// Lesson durations are whole seconds while media lengths rarely are, so a
+/// zero tolerance is seldom useful.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Duration, Url};
+/// use education_platform_core::{
+///     Chapter, Course, Lesson, ProbeError, ProbedVideo, VideoIssue, VideoMetadata,
+///     VideoVerifier,
+/// };
+///
+/// struct FixedProbe;
+///
+/// impl VideoMetadata for FixedProbe {
+///     fn probe(&self, _url: &Url) -> Result<ProbedVideo, ProbeError> {
+///         Ok(ProbedVideo::with_duration(Duration::from_seconds(600)))
+///     }
+/// }
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// let report = VideoVerifier::default().verify_course(&FixedProbe, &course);
+///
+/// assert_eq!(report.checked(), 1);
+/// assert_eq!(
+///     report.discrepancies()[0].issue(),
+///     &VideoIssue::DurationMismatch {
+///         declared: Duration::from_seconds(1800),
+///         actual: Duration::from_seconds(600),
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoVerifier {
+    tolerance: Duration,
+}
+
+impl VideoVerifier {
+    /// How far a video may be from its declared duration by default.
+    pub const DEFAULT_TOLERANCE: Duration = Duration::from_seconds(2);
+
+    /// Creates a verifier accepting videos within `tolerance` of their
+    /// declared duration, in either direction.
+    #[inline]
+    #[must_use]
+    pub const fn new(tolerance: Duration) -> Self {
+        Self { tolerance }
+    }
+
+    /// Returns how far a video may be from its declared duration.
+    #[inline]
+    #[must_use]
+    pub const fn tolerance(&self) -> Duration {
+        self.tolerance
+    }
+
+    /// Probes one lesson video, returning its issue if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::{Duration, Url};
+    /// use education_platform_core::{
+    ///     Lesson, ProbeError, ProbedVideo, VideoIssue, VideoMetadata, VideoVerifier,
+    /// };
+    ///
+    /// struct DownProbe;
+    ///
+    /// impl VideoMetadata for DownProbe {
+    ///     fn probe(&self, _url: &Url) -> Result<ProbedVideo, ProbeError> {
+    ///         Err(ProbeError::Unreachable("HTTP 404".to_string()))
+    ///     }
+    /// }
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     60,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     VideoVerifier::default().verify_lesson(&DownProbe, &lesson),
+    ///     Some(VideoIssue::Unreachable("HTTP 404".to_string()))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn verify_lesson(&self, probe: &impl VideoMetadata, lesson: &Lesson) -> Option<VideoIssue> {
+        self.check(probe, lesson).err()
+    }
+
+    /// Probes every lesson video of a course, one after another.
+    #[must_use]
+    pub fn verify_course(&self, probe: &impl VideoMetadata, course: &Course) -> VideoReport {
+        let mut report = VideoReport::default();
+
+        for lesson in course
+            .chapters()
+            .iter()
+            .flat_map(|chapter| chapter.lessons())
+        {
+            report.checked += 1;
+            match self.check(probe, lesson) {
+                Ok(true) => {}
+                Ok(false) => report.duration_unverified += 1,
+                Err(issue) => report.discrepancies.push(VideoDiscrepancy {
+                    lesson_id: lesson.lesson_id(),
+                    issue,
+                }),
+            }
+        }
+
+        report
+    }
+
+    /// Returns whether the duration could be verified, or the issue found.
+    fn check(&self, probe: &impl VideoMetadata, lesson: &Lesson) -> Result<bool, VideoIssue> {
+        let video = probe.probe(lesson.video_url()).map_err(|e| match e {
+            ProbeError::Unreachable(reason) => VideoIssue::Unreachable(reason),
+            ProbeError::Failed(reason) => VideoIssue::ProbeFailed(reason),
+        })?;
+
+        let Some(actual) = video.duration() else {
+            return Ok(false);
+        };
+        let declared = lesson.duration();
+        let difference = actual.total_seconds().abs_diff(declared.total_seconds());
+        if difference > self.tolerance.total_seconds() {
+            return Err(VideoIssue::DurationMismatch { declared, actual });
+        }
+
+        Ok(true)
+    }
+}
+
+impl Default for VideoVerifier {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_TOLERANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, ProbedVideo};
+    use education_platform_common::Url;
+    use std::collections::HashMap;
+
+    /// Answers from a table keyed by URL; unknown URLs are unreachable.
+    struct TableProbe(HashMap<String, Result<ProbedVideo, ProbeError>>);
+
+    impl TableProbe {
+        fn new(entries: &[(&str, Result<ProbedVideo, ProbeError>)]) -> Self {
+            Self(
+                entries
+                    .iter()
+                    .map(|(url, outcome)| (url.to_string(), outcome.clone()))
+                    .collect(),
+            )
+        }
+    }
+
+    impl VideoMetadata for TableProbe {
+        fn probe(&self, url: &Url) -> Result<ProbedVideo, ProbeError> {
+            self.0
+                .get(url.as_str())
+                .cloned()
+                .unwrap_or_else(|| Err(ProbeError::Unreachable("HTTP 404".to_string())))
+        }
+    }
+
+    fn lasting(seconds: u64) -> Result<ProbedVideo, ProbeError> {
+        Ok(ProbedVideo::with_duration(Duration::from_seconds(seconds)))
+    }
+
+    fn create_test_lesson(index: usize, duration: u64) -> Lesson {
+        Lesson::new(
+            format!("Lesson {}", index),
+            duration,
+            format!("https://example.com/{}.mp4", index),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course(lessons: Vec<Lesson>) -> Course {
+        let chapter = Chapter::new("Chapter One".to_string(), 0, lessons).unwrap();
+        Course::new("Test Course".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    mod verify_lesson {
+        use super::*;
+
+        #[test]
+        fn test_matching_duration_passes() {
+            let probe = TableProbe::new(&[("https://example.com/0.mp4", lasting(600))]);
+
+            let issue = VideoVerifier::default().verify_lesson(&probe, &create_test_lesson(0, 600));
+
+            assert_eq!(issue, None);
+        }
+
+        #[test]
+        fn test_tolerance_is_inclusive_both_ways() {
+            let verifier = VideoVerifier::new(Duration::from_seconds(2));
+            let lesson = create_test_lesson(0, 600);
+
+            for seconds in [598, 602] {
+                let probe = TableProbe::new(&[("https://example.com/0.mp4", lasting(seconds))]);
+                assert_eq!(verifier.verify_lesson(&probe, &lesson), None);
+            }
+            for seconds in [597, 603] {
+                let probe = TableProbe::new(&[("https://example.com/0.mp4", lasting(seconds))]);
+                assert_eq!(
+                    verifier.verify_lesson(&probe, &lesson),
+                    Some(VideoIssue::DurationMismatch {
+                        declared: Duration::from_seconds(600),
+                        actual: Duration::from_seconds(seconds),
+                    })
+                );
+            }
+        }
+
+        #[test]
+        fn test_zero_tolerance_needs_exact_match() {
+            let probe = TableProbe::new(&[("https://example.com/0.mp4", lasting(601))]);
+
+            let issue = VideoVerifier::new(Duration::from_seconds(0))
+                .verify_lesson(&probe, &create_test_lesson(0, 600));
+
+            assert!(matches!(issue, Some(VideoIssue::DurationMismatch { .. })));
+        }
+
+        #[test]
+        fn test_unknown_duration_passes() {
+            let probe =
+                TableProbe::new(&[("https://example.com/0.mp4", Ok(ProbedVideo::reachable()))]);
+
+            let issue = VideoVerifier::default().verify_lesson(&probe, &create_test_lesson(0, 600));
+
+            assert_eq!(issue, None);
+        }
+
+        #[test]
+        fn test_probe_errors_become_issues() {
+            let probe = TableProbe::new(&[(
+                "https://example.com/1.mp4",
+                Err(ProbeError::Failed("ffprobe not found".to_string())),
+            )]);
+            let verifier = VideoVerifier::default();
+
+            assert_eq!(
+                verifier.verify_lesson(&probe, &create_test_lesson(0, 600)),
+                Some(VideoIssue::Unreachable("HTTP 404".to_string()))
+            );
+            assert_eq!(
+                verifier.verify_lesson(&probe, &create_test_lesson(1, 600)),
+                Some(VideoIssue::ProbeFailed("ffprobe not found".to_string()))
+            );
+        }
+    }
+
+    mod verify_course {
+        use super::*;
+
+        #[test]
+        fn test_clean_course() {
+            let probe = TableProbe::new(&[
+                ("https://example.com/0.mp4", lasting(600)),
+                ("https://example.com/1.mp4", lasting(301)),
+            ]);
+            let course =
+                create_test_course(vec![create_test_lesson(0, 600), create_test_lesson(1, 300)]);
+
+            let report = VideoVerifier::default().verify_course(&probe, &course);
+
+            assert!(report.is_clean());
+            assert_eq!(report.checked(), 2);
+            assert_eq!(report.duration_unverified(), 0);
+        }
+
+        #[test]
+        fn test_reports_each_problem_lesson() {
+            let probe = TableProbe::new(&[
+                ("https://example.com/0.mp4", lasting(600)),
+                ("https://example.com/1.mp4", lasting(30)),
+                ("https://example.com/3.mp4", Ok(ProbedVideo::reachable())),
+            ]);
+            let course = create_test_course(vec![
+                create_test_lesson(0, 600),
+                create_test_lesson(1, 300),
+                create_test_lesson(2, 300),
+                create_test_lesson(3, 300),
+            ]);
+            let lessons = course.chapters()[0].lessons();
+
+            let report = VideoVerifier::default().verify_course(&probe, &course);
+
+            assert!(!report.is_clean());
+            assert_eq!(report.checked(), 4);
+            assert_eq!(report.duration_unverified(), 1);
+            assert_eq!(
+                report.discrepancies(),
+                &[
+                    VideoDiscrepancy {
+                        lesson_id: lessons[1].lesson_id(),
+                        issue: VideoIssue::DurationMismatch {
+                            declared: Duration::from_seconds(300),
+                            actual: Duration::from_seconds(30),
+                        },
+                    },
+                    VideoDiscrepancy {
+                        lesson_id: lessons[2].lesson_id(),
+                        issue: VideoIssue::Unreachable("HTTP 404".to_string()),
+                    },
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use education_platform_common::{Duration, Url};
+use thiserror::Error;
+
+/// Error types for probing a lesson video.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProbeError {
+    /// The video could not be fetched, for example because the host is down
+    /// or the server answered with an error status.
+    #[error("Video is unreachable: {0}")]
+    Unreachable(String),
+
+    /// The probe itself failed, so nothing is known about the video.
+    #[error("Probe failed: {0}")]
+    Failed(String),
+}
+
+/// What a probe found out about a reachable video.
+///
+/// Probes that only check reachability, such as an HTTP `HEAD` request,
+/// leave the duration unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProbedVideo {
+    duration: Option<Duration>,
+}
+
+impl ProbedVideo {
+    /// Records a reachable video whose length the probe did not read.
+    #[inline]
+    #[must_use]
+    pub const fn reachable() -> Self {
+        Self { duration: None }
+    }
+
+    /// Records a reachable video of a known length.
+    #[inline]
+    #[must_use]
+    pub const fn with_duration(duration: Duration) -> Self {
+        Self {
+            duration: Some(duration),
+        }
+    }
+
+    /// Returns the length of the media, if the probe read it.
+    #[inline]
+    #[must_use]
+    pub const fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+}
+
+/// Port reading the metadata of the media behind a lesson video URL.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_common::{Duration, Url};
+/// use education_platform_core::{ProbeError, ProbedVideo, VideoMetadata};
+///
+/// /// Pretends every `.mp4` lasts ten minutes.
+/// struct FixedProbe;
+///
+/// impl VideoMetadata for FixedProbe {
+///     fn probe(&self, url: &Url) -> Result<ProbedVideo, ProbeError> {
+///         if url.as_str().ends_with(".mp4") {
+///             Ok(ProbedVideo::with_duration(Duration::from_minutes(10)))
+///         } else {
+///             Err(ProbeError::Unreachable("404 Not Found".to_string()))
+///         }
+///     }
+/// }
+///
+/// let url = Url::new("https://example.com/intro.mp4".to_string()).unwrap();
+/// assert_eq!(
+///     FixedProbe.probe(&url).unwrap().duration(),
+///     Some(Duration::from_minutes(10))
+/// );
+/// ```
+pub trait VideoMetadata {
+    /// Fetches the video, or enough of it to tell it is there.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProbeError::Unreachable` if the video cannot be fetched, or
+    /// `ProbeError::Failed` if the probe could not run at all.
+    fn probe(&self, url: &Url) -> Result<ProbedVideo, ProbeError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reachable_has_no_duration() {
+        assert_eq!(ProbedVideo::reachable().duration(), None);
+        assert_eq!(ProbedVideo::default(), ProbedVideo::reachable());
+    }
+
+    #[test]
+    fn test_with_duration() {
+        let video = ProbedVideo::with_duration(Duration::from_seconds(90));
+
+        assert_eq!(video.duration(), Some(Duration::from_seconds(90)));
+    }
+}
@@ -0,0 +1,7 @@
+mod mentor_matcher;
+mod mentor_profile;
+mod mentorship;
+
+pub use mentor_matcher::*;
+pub use mentor_profile::*;
+pub use mentorship::*;
@@ -0,0 +1,368 @@
+use crate::{MentorProfile, Mentorship};
+use education_platform_common::{Email, Entity, Id};
+use std::cmp::Reverse;
+use thiserror::Error;
+
+/// Error types for mentor matching failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MentorMatchingError {
+    #[error("No available mentor covers the course tags")]
+    NoMentorAvailable,
+
+    #[error("{0} already has an active mentorship for this course")]
+    AlreadyMentored(String),
+}
+
+/// Service pairing learners with mentors.
+///
+/// A mentor is a candidate when they have free capacity and share at least
+/// one expertise tag with the course. Among candidates, the one sharing the
+/// most tags wins; ties go to the mentor with the fewest active mentees,
+/// then to the mentor email in alphabetical order so matching is
+/// deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{MentorMatcher, MentorProfile};
+/// use education_platform_common::{Email, Entity, Id};
+///
+/// let rust = MentorProfile::new(
+///     "rust@example.com".to_string(),
+///     vec!["rust".to_string(), "systems".to_string()],
+///     2,
+/// ).unwrap();
+/// let web = MentorProfile::new("web@example.com".to_string(), vec!["web".to_string()], 2)
+///     .unwrap();
+/// let rust_id = rust.id();
+/// let matcher = MentorMatcher::new(vec![rust, web]);
+///
+/// let learner = Email::new("learner@example.com".to_string()).unwrap();
+/// let mentorship = matcher.pair(learner, Id::new(), &["rust"], &[]).unwrap();
+///
+/// assert_eq!(mentorship.mentor_id(), rust_id);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MentorMatcher {
+    mentors: Vec<MentorProfile>,
+}
+
+impl MentorMatcher {
+    /// Creates a matcher over the given mentor pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorMatcher;
+    ///
+    /// let matcher = MentorMatcher::new(vec![]);
+    /// assert!(matcher.mentors().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new(mentors: Vec<MentorProfile>) -> Self {
+        Self { mentors }
+    }
+
+    /// Returns the mentor pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{MentorMatcher, MentorProfile};
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     1,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(MentorMatcher::new(vec![mentor]).mentors().len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mentors(&self) -> &[MentorProfile] {
+        &self.mentors
+    }
+
+    /// Returns the number of active mentorships of a mentor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{MentorMatcher, Mentorship};
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentor_id = Id::new();
+    /// let mentorship = Mentorship::new(
+    ///     mentor_id,
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert_eq!(MentorMatcher::active_mentees(mentor_id, &[mentorship]), 1);
+    /// ```
+    #[must_use]
+    pub fn active_mentees(mentor_id: Id, mentorships: &[Mentorship]) -> usize {
+        mentorships
+            .iter()
+            .filter(|mentorship| mentorship.mentor_id() == mentor_id && mentorship.is_active())
+            .count()
+    }
+
+    /// Returns the best available mentor for a course, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{MentorMatcher, MentorProfile};
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     1,
+    /// ).unwrap();
+    /// let matcher = MentorMatcher::new(vec![mentor]);
+    ///
+    /// assert!(matcher.best_match(&["rust"], &[]).is_some());
+    /// assert!(matcher.best_match(&["cooking"], &[]).is_none());
+    /// ```
+    #[must_use]
+    pub fn best_match(
+        &self,
+        course_tags: &[&str],
+        mentorships: &[Mentorship],
+    ) -> Option<&MentorProfile> {
+        self.mentors
+            .iter()
+            .filter_map(|mentor| {
+                let matching = mentor.matching_tags(course_tags);
+                let load = Self::active_mentees(mentor.id(), mentorships);
+                let has_room = u32::try_from(load).is_ok_and(|load| load < mentor.capacity());
+
+                match matching > 0 && has_room {
+                    true => Some((mentor, matching, load)),
+                    false => None,
+                }
+            })
+            .min_by_key(|(mentor, matching, load)| {
+                (Reverse(*matching), *load, mentor.mentor_email().address().to_string())
+            })
+            .map(|(mentor, _, _)| mentor)
+    }
+
+    /// Pairs a learner with the best available mentor for a course.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MentorMatchingError::AlreadyMentored` if the learner already has
+    /// an active mentorship for the course.
+    /// Returns `MentorMatchingError::NoMentorAvailable` if no mentor with free
+    /// capacity covers any of the course tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{MentorMatcher, MentorProfile};
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     1,
+    /// ).unwrap();
+    /// let matcher = MentorMatcher::new(vec![mentor]);
+    /// let course_id = Id::new();
+    ///
+    /// let first = Email::new("first@example.com".to_string()).unwrap();
+    /// let mentorship = matcher.pair(first, course_id, &["rust"], &[]).unwrap();
+    ///
+    /// // The only mentor is now at capacity
+    /// let second = Email::new("second@example.com".to_string()).unwrap();
+    /// assert!(matcher.pair(second, course_id, &["rust"], &[mentorship]).is_err());
+    /// ```
+    pub fn pair(
+        &self,
+        learner_email: Email,
+        course_id: Id,
+        course_tags: &[&str],
+        mentorships: &[Mentorship],
+    ) -> Result<Mentorship, MentorMatchingError> {
+        let already_mentored = mentorships.iter().any(|mentorship| {
+            mentorship.is_active()
+                && mentorship.course_id() == course_id
+                && mentorship.learner_email() == &learner_email
+        });
+        if already_mentored {
+            return Err(MentorMatchingError::AlreadyMentored(
+                learner_email.address().to_string(),
+            ));
+        }
+
+        let mentor = self
+            .best_match(course_tags, mentorships)
+            .ok_or(MentorMatchingError::NoMentorAvailable)?;
+
+        Ok(Mentorship::new(mentor.id(), learner_email, course_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::DateTime;
+
+    fn create_mentor(email: &str, tags: &[&str], capacity: u32) -> MentorProfile {
+        MentorProfile::new(
+            email.to_string(),
+            tags.iter().map(ToString::to_string).collect(),
+            capacity,
+        )
+        .unwrap()
+    }
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    mod best_match {
+        use super::*;
+
+        #[test]
+        fn test_prefers_mentor_with_most_matching_tags() {
+            let generalist = create_mentor("generalist@example.com", &["rust"], 5);
+            let specialist = create_mentor("specialist@example.com", &["rust", "async"], 5);
+            let specialist_id = specialist.id();
+            let matcher = MentorMatcher::new(vec![generalist, specialist]);
+
+            let mentor = matcher.best_match(&["rust", "async"], &[]).unwrap();
+
+            assert_eq!(mentor.id(), specialist_id);
+        }
+
+        #[test]
+        fn test_ties_go_to_least_loaded_mentor() {
+            let busy = create_mentor("a@example.com", &["rust"], 5);
+            let free = create_mentor("b@example.com", &["rust"], 5);
+            let busy_id = busy.id();
+            let free_id = free.id();
+            let matcher = MentorMatcher::new(vec![busy, free]);
+            let mentorships = vec![Mentorship::new(
+                busy_id,
+                create_email("learner@example.com"),
+                Id::new(),
+            )];
+
+            let mentor = matcher.best_match(&["rust"], &mentorships).unwrap();
+
+            assert_eq!(mentor.id(), free_id);
+        }
+
+        #[test]
+        fn test_full_ties_go_to_alphabetical_email() {
+            let second = create_mentor("b@example.com", &["rust"], 5);
+            let first = create_mentor("a@example.com", &["rust"], 5);
+            let matcher = MentorMatcher::new(vec![second, first]);
+
+            let mentor = matcher.best_match(&["rust"], &[]).unwrap();
+
+            assert_eq!(mentor.mentor_email().address(), "a@example.com");
+        }
+
+        #[test]
+        fn test_skips_mentors_without_matching_tags() {
+            let matcher = MentorMatcher::new(vec![create_mentor("a@example.com", &["web"], 5)]);
+
+            assert!(matcher.best_match(&["rust"], &[]).is_none());
+        }
+
+        #[test]
+        fn test_skips_mentors_at_capacity() {
+            let mentor = create_mentor("a@example.com", &["rust"], 1);
+            let mentorships = vec![Mentorship::new(
+                mentor.id(),
+                create_email("learner@example.com"),
+                Id::new(),
+            )];
+            let matcher = MentorMatcher::new(vec![mentor]);
+
+            assert!(matcher.best_match(&["rust"], &mentorships).is_none());
+        }
+
+        #[test]
+        fn test_ended_mentorships_free_capacity() {
+            let mentor = create_mentor("a@example.com", &["rust"], 1);
+            let mut ended =
+                Mentorship::new(mentor.id(), create_email("learner@example.com"), Id::new());
+            ended
+                .end(DateTime::new(2024, 12, 20, 18, 0, 0).unwrap())
+                .unwrap();
+            let matcher = MentorMatcher::new(vec![mentor]);
+
+            assert!(matcher.best_match(&["rust"], &[ended]).is_some());
+        }
+    }
+
+    mod pair {
+        use super::*;
+
+        #[test]
+        fn test_pair_creates_active_mentorship() {
+            let mentor = create_mentor("a@example.com", &["rust"], 1);
+            let mentor_id = mentor.id();
+            let course_id = Id::new();
+            let matcher = MentorMatcher::new(vec![mentor]);
+
+            let mentorship = matcher
+                .pair(create_email("learner@example.com"), course_id, &["Rust"], &[])
+                .unwrap();
+
+            assert_eq!(mentorship.mentor_id(), mentor_id);
+            assert_eq!(mentorship.course_id(), course_id);
+            assert!(mentorship.is_active());
+        }
+
+        #[test]
+        fn test_pair_without_candidates_returns_error() {
+            let matcher = MentorMatcher::new(vec![]);
+
+            let result =
+                matcher.pair(create_email("learner@example.com"), Id::new(), &["rust"], &[]);
+
+            assert_eq!(result.unwrap_err(), MentorMatchingError::NoMentorAvailable);
+        }
+
+        #[test]
+        fn test_pair_rejects_learner_already_mentored_in_course() {
+            let matcher = MentorMatcher::new(vec![create_mentor("a@example.com", &["rust"], 5)]);
+            let course_id = Id::new();
+            let learner = create_email("learner@example.com");
+            let existing = matcher
+                .pair(learner.clone(), course_id, &["rust"], &[])
+                .unwrap();
+
+            let result = matcher.pair(learner, course_id, &["rust"], &[existing]);
+
+            assert_eq!(
+                result.unwrap_err(),
+                MentorMatchingError::AlreadyMentored("learner@example.com".to_string())
+            );
+        }
+
+        #[test]
+        fn test_pair_allows_same_learner_in_another_course() {
+            let matcher = MentorMatcher::new(vec![create_mentor("a@example.com", &["rust"], 5)]);
+            let learner = create_email("learner@example.com");
+            let existing = matcher
+                .pair(learner.clone(), Id::new(), &["rust"], &[])
+                .unwrap();
+
+            assert!(
+                matcher
+                    .pair(learner, Id::new(), &["rust"], &[existing])
+                    .is_ok()
+            );
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use education_platform_common::{Email, EmailError, Entity, Id, Validator, ValidatorError};
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+/// Error types for MentorProfile validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MentorProfileError {
+    #[error("Mentor email is not valid: {0}")]
+    EmailError(#[from] EmailError),
+
+    #[error("Expertise tag validation failed: {0}")]
+    TagError(#[from] ValidatorError),
+
+    #[error("Mentor must have at least one expertise tag")]
+    ExpertiseIsEmpty,
+
+    #[error("Capacity must be different from zero")]
+    CapacityIsZero,
+}
+
+/// A mentor's areas of expertise and how many learners they can take on.
+///
+/// Expertise tags are trimmed and lowercased, so `"Rust"` and `" rust "`
+/// name the same tag.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::MentorProfile;
+///
+/// let mentor = MentorProfile::new(
+///     "mentor@example.com".to_string(),
+///     vec!["Rust".to_string(), "Systems".to_string()],
+///     3,
+/// ).unwrap();
+///
+/// assert!(mentor.has_expertise("rust"));
+/// assert_eq!(mentor.capacity(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MentorProfile {
+    id: Id,
+    mentor_email: Email,
+    expertise: BTreeSet<String>,
+    capacity: u32,
+}
+
+impl MentorProfile {
+    pub const MAX_TAG_LENGTH: usize = 50;
+
+    /// Creates a new `MentorProfile`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MentorProfileError::EmailError` if the email is not valid.
+    /// Returns `MentorProfileError::TagError` if a tag is empty or longer than
+    /// [`MentorProfile::MAX_TAG_LENGTH`] bytes.
+    /// Returns `MentorProfileError::ExpertiseIsEmpty` if no tag is given.
+    /// Returns `MentorProfileError::CapacityIsZero` if the capacity is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorProfile;
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     2,
+    /// );
+    /// assert!(mentor.is_ok());
+    ///
+    /// let no_tags = MentorProfile::new("mentor@example.com".to_string(), vec![], 2);
+    /// assert!(no_tags.is_err());
+    /// ```
+    pub fn new(
+        mentor_email: String,
+        expertise: Vec<String>,
+        capacity: u32,
+    ) -> Result<Self, MentorProfileError> {
+        Self::with_id(Id::default(), mentor_email, expertise, capacity)
+    }
+
+    /// Creates a `MentorProfile` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MentorProfile::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorProfile;
+    /// use education_platform_common::{Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let mentor = MentorProfile::with_id(
+    ///     id,
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     2,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(mentor.id(), id);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        mentor_email: String,
+        expertise: Vec<String>,
+        capacity: u32,
+    ) -> Result<Self, MentorProfileError> {
+        let mentor_email = Email::new(mentor_email)?;
+        let expertise = expertise
+            .iter()
+            .map(|tag| Self::normalize_tag(tag))
+            .collect::<Result<BTreeSet<String>, _>>()?;
+
+        if expertise.is_empty() {
+            return Err(MentorProfileError::ExpertiseIsEmpty);
+        }
+
+        if capacity == 0 {
+            return Err(MentorProfileError::CapacityIsZero);
+        }
+
+        Ok(Self {
+            id,
+            mentor_email,
+            expertise,
+            capacity,
+        })
+    }
+
+    /// Returns the mentor's email.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorProfile;
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     2,
+    /// ).unwrap();
+    /// assert_eq!(mentor.mentor_email().address(), "mentor@example.com");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn mentor_email(&self) -> &Email {
+        &self.mentor_email
+    }
+
+    /// Returns the normalized expertise tags, in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorProfile;
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["Web".to_string(), "Rust".to_string()],
+    ///     2,
+    /// ).unwrap();
+    ///
+    /// let tags: Vec<&str> = mentor.expertise().iter().map(String::as_str).collect();
+    /// assert_eq!(tags, vec!["rust", "web"]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn expertise(&self) -> &BTreeSet<String> {
+        &self.expertise
+    }
+
+    /// Returns the maximum number of learners mentored at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorProfile;
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     4,
+    /// ).unwrap();
+    /// assert_eq!(mentor.capacity(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Returns true if the mentor has the given expertise tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorProfile;
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string()],
+    ///     2,
+    /// ).unwrap();
+    ///
+    /// assert!(mentor.has_expertise(" RUST "));
+    /// assert!(!mentor.has_expertise("go"));
+    /// ```
+    #[must_use]
+    pub fn has_expertise(&self, tag: &str) -> bool {
+        self.expertise.contains(&tag.trim().to_lowercase())
+    }
+
+    /// Returns how many of the given tags the mentor is an expert in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::MentorProfile;
+    ///
+    /// let mentor = MentorProfile::new(
+    ///     "mentor@example.com".to_string(),
+    ///     vec!["rust".to_string(), "web".to_string()],
+    ///     2,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(mentor.matching_tags(&["rust", "async", "web"]), 2);
+    /// ```
+    #[must_use]
+    pub fn matching_tags(&self, tags: &[&str]) -> usize {
+        tags.iter().filter(|tag| self.has_expertise(tag)).count()
+    }
+
+    fn normalize_tag(tag: &str) -> Result<String, MentorProfileError> {
+        let normalized = tag.trim().to_lowercase();
+        Validator::is_not_empty(&normalized)?;
+        Validator::has_max_length(&normalized, Self::MAX_TAG_LENGTH)?;
+        Ok(normalized)
+    }
+}
+
+impl Entity for MentorProfile {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_mentor(tags: &[&str]) -> MentorProfile {
+        MentorProfile::new(
+            "mentor@example.com".to_string(),
+            tags.iter().map(ToString::to_string).collect(),
+            2,
+        )
+        .unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_normalizes_and_deduplicates_tags() {
+            let mentor = create_mentor(&["Rust", " rust ", "WEB"]);
+
+            let tags: Vec<&str> = mentor.expertise().iter().map(String::as_str).collect();
+
+            assert_eq!(tags, vec!["rust", "web"]);
+        }
+
+        #[test]
+        fn test_new_rejects_blank_tag() {
+            let result = MentorProfile::new(
+                "mentor@example.com".to_string(),
+                vec!["rust".to_string(), "  ".to_string()],
+                2,
+            );
+
+            assert!(matches!(result, Err(MentorProfileError::TagError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_empty_expertise() {
+            let result = MentorProfile::new("mentor@example.com".to_string(), vec![], 2);
+
+            assert_eq!(result.unwrap_err(), MentorProfileError::ExpertiseIsEmpty);
+        }
+
+        #[test]
+        fn test_new_rejects_zero_capacity() {
+            let result =
+                MentorProfile::new("mentor@example.com".to_string(), vec!["rust".to_string()], 0);
+
+            assert_eq!(result.unwrap_err(), MentorProfileError::CapacityIsZero);
+        }
+
+        #[test]
+        fn test_new_rejects_invalid_email() {
+            let result = MentorProfile::new("invalid".to_string(), vec!["rust".to_string()], 2);
+
+            assert!(matches!(result, Err(MentorProfileError::EmailError(_))));
+        }
+    }
+
+    mod expertise {
+        use super::*;
+
+        #[test]
+        fn test_has_expertise_ignores_case_and_spaces() {
+            let mentor = create_mentor(&["rust"]);
+
+            assert!(mentor.has_expertise("Rust"));
+            assert!(mentor.has_expertise("  rust"));
+        }
+
+        #[test]
+        fn test_matching_tags_counts_overlap() {
+            let mentor = create_mentor(&["rust", "web", "databases"]);
+
+            assert_eq!(mentor.matching_tags(&["web", "databases", "ml"]), 2);
+            assert_eq!(mentor.matching_tags(&[]), 0);
+        }
+    }
+}
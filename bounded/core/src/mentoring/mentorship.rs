@@ -0,0 +1,776 @@
+use crate::{Rating, RatingError};
+use education_platform_common::{DateTime, Duration, Email, Entity, Id, Validator, ValidatorError};
+use thiserror::Error;
+
+/// Error types for Mentorship operations.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MentorshipError {
+    #[error("Session summary validation failed: {0}")]
+    SummaryError(#[from] ValidatorError),
+
+    #[error("Feedback rating is not valid: {0}")]
+    RatingError(#[from] RatingError),
+
+    #[error("Session duration must be different from zero")]
+    SessionDurationIsZero,
+
+    #[error("Mentorship has already ended")]
+    AlreadyEnded,
+
+    #[error("Feedback can only be given once the mentorship has ended")]
+    NotEnded,
+
+    #[error("Feedback was already given")]
+    FeedbackAlreadyGiven,
+}
+
+/// A meeting between mentor and learner, logged after it took place.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Mentorship;
+/// use education_platform_common::{DateTime, Duration, Email, Id};
+///
+/// let mut mentorship = Mentorship::new(
+///     Id::new(),
+///     Email::new("learner@example.com".to_string()).unwrap(),
+///     Id::new(),
+/// );
+/// let held_at = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+/// mentorship
+///     .log_session(held_at, Duration::from_minutes(45), "Reviewed ownership exercises")
+///     .unwrap();
+///
+/// let session = &mentorship.sessions()[0];
+/// assert_eq!(session.held_at(), held_at);
+/// assert_eq!(session.summary(), "Reviewed ownership exercises");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentorshipSession {
+    held_at: DateTime,
+    duration: Duration,
+    summary: String,
+}
+
+impl MentorshipSession {
+    /// Returns when the session took place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// let held_at = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// mentorship.log_session(held_at, Duration::from_minutes(45), "Lifetimes").unwrap();
+    ///
+    /// assert_eq!(mentorship.sessions()[0].held_at(), held_at);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn held_at(&self) -> DateTime {
+        self.held_at
+    }
+
+    /// Returns how long the session lasted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// let held_at = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// mentorship.log_session(held_at, Duration::from_minutes(45), "Lifetimes").unwrap();
+    ///
+    /// assert_eq!(mentorship.sessions()[0].duration(), Duration::from_minutes(45));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns what was covered in the session.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// let held_at = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// mentorship.log_session(held_at, Duration::from_minutes(45), "Lifetimes").unwrap();
+    ///
+    /// assert_eq!(mentorship.sessions()[0].summary(), "Lifetimes");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+}
+
+/// The learner's assessment of a mentorship once it is over.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Mentorship;
+/// use education_platform_common::{DateTime, Email, Id};
+///
+/// let mut mentorship = Mentorship::new(
+///     Id::new(),
+///     Email::new("learner@example.com".to_string()).unwrap(),
+///     Id::new(),
+/// );
+/// mentorship.end(DateTime::new(2024, 12, 20, 18, 0, 0).unwrap()).unwrap();
+/// mentorship.give_feedback(5, Some("Very helpful".to_string())).unwrap();
+///
+/// let feedback = mentorship.feedback().unwrap();
+/// assert_eq!(feedback.rating().stars(), 5);
+/// assert_eq!(feedback.comment(), Some("Very helpful"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentorshipFeedback {
+    rating: Rating,
+    comment: Option<String>,
+}
+
+impl MentorshipFeedback {
+    /// Returns the star rating given to the mentor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// mentorship.end(DateTime::new(2024, 12, 20, 18, 0, 0).unwrap()).unwrap();
+    /// mentorship.give_feedback(3, None).unwrap();
+    ///
+    /// assert_eq!(mentorship.feedback().unwrap().rating().stars(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rating(&self) -> Rating {
+        self.rating
+    }
+
+    /// Returns the optional written comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// mentorship.end(DateTime::new(2024, 12, 20, 18, 0, 0).unwrap()).unwrap();
+    /// mentorship.give_feedback(3, None).unwrap();
+    ///
+    /// assert!(mentorship.feedback().unwrap().comment().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+}
+
+/// A mentor guiding a learner through a course.
+///
+/// A mentorship is active from the moment it is created until it is ended.
+/// Sessions can only be logged while it is active, and the learner can
+/// leave feedback once, after it has ended.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Mentorship;
+/// use education_platform_common::{DateTime, Duration, Email, Id};
+///
+/// let mut mentorship = Mentorship::new(
+///     Id::new(),
+///     Email::new("learner@example.com".to_string()).unwrap(),
+///     Id::new(),
+/// );
+/// assert!(mentorship.is_active());
+///
+/// let held_at = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+/// mentorship.log_session(held_at, Duration::from_minutes(30), "Kick-off").unwrap();
+/// mentorship.end(held_at.add_seconds(86_400)).unwrap();
+///
+/// assert!(!mentorship.is_active());
+/// assert!(mentorship.log_session(held_at, Duration::from_minutes(30), "Late").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mentorship {
+    id: Id,
+    mentor_id: Id,
+    learner_email: Email,
+    course_id: Id,
+    started_at: DateTime,
+    ended_at: Option<DateTime>,
+    sessions: Vec<MentorshipSession>,
+    feedback: Option<MentorshipFeedback>,
+}
+
+impl Mentorship {
+    pub const MAX_SUMMARY_LENGTH: usize = 2000;
+    pub const MAX_FEEDBACK_LENGTH: usize = 2000;
+
+    /// Starts a mentorship now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentor_id = Id::new();
+    /// let mentorship = Mentorship::new(
+    ///     mentor_id,
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert_eq!(mentorship.mentor_id(), mentor_id);
+    /// assert!(mentorship.sessions().is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(mentor_id: Id, learner_email: Email, course_id: Id) -> Self {
+        Self {
+            id: Id::default(),
+            mentor_id,
+            learner_email,
+            course_id,
+            started_at: DateTime::today(),
+            ended_at: None,
+            sessions: Vec::new(),
+            feedback: None,
+        }
+    }
+
+    /// Records a session that took place.
+    ///
+    /// The summary is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MentorshipError::AlreadyEnded` if the mentorship has ended.
+    /// Returns `MentorshipError::SessionDurationIsZero` if the duration is zero.
+    /// Returns `MentorshipError::SummaryError` if the summary is empty or longer
+    /// than [`Mentorship::MAX_SUMMARY_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// let held_at = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    ///
+    /// mentorship.log_session(held_at, Duration::from_minutes(30), "Kick-off").unwrap();
+    /// assert!(mentorship.log_session(held_at, Duration::default(), "Empty").is_err());
+    /// ```
+    pub fn log_session(
+        &mut self,
+        held_at: DateTime,
+        duration: Duration,
+        summary: &str,
+    ) -> Result<(), MentorshipError> {
+        if !self.is_active() {
+            return Err(MentorshipError::AlreadyEnded);
+        }
+
+        if duration.is_zero() {
+            return Err(MentorshipError::SessionDurationIsZero);
+        }
+
+        let summary = Self::validate_text(summary, Self::MAX_SUMMARY_LENGTH)?;
+        self.sessions.push(MentorshipSession {
+            held_at,
+            duration,
+            summary,
+        });
+        Ok(())
+    }
+
+    /// Ends the mentorship, freeing a place in the mentor's capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MentorshipError::AlreadyEnded` if the mentorship has already ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// let ended_at = DateTime::new(2024, 12, 20, 18, 0, 0).unwrap();
+    ///
+    /// mentorship.end(ended_at).unwrap();
+    /// assert_eq!(mentorship.ended_at(), Some(ended_at));
+    /// ```
+    pub fn end(&mut self, at: DateTime) -> Result<(), MentorshipError> {
+        match self.ended_at {
+            Some(_) => Err(MentorshipError::AlreadyEnded),
+            None => {
+                self.ended_at = Some(at);
+                Ok(())
+            }
+        }
+    }
+
+    /// Records the learner's feedback on an ended mentorship.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MentorshipError::NotEnded` if the mentorship is still active.
+    /// Returns `MentorshipError::FeedbackAlreadyGiven` if feedback was already recorded.
+    /// Returns `MentorshipError::RatingError` if the rating is not between 1 and 5.
+    /// Returns `MentorshipError::SummaryError` if the comment is blank or longer
+    /// than [`Mentorship::MAX_FEEDBACK_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// assert!(mentorship.give_feedback(4, None).is_err());
+    ///
+    /// mentorship.end(DateTime::new(2024, 12, 20, 18, 0, 0).unwrap()).unwrap();
+    /// mentorship.give_feedback(4, None).unwrap();
+    /// assert!(mentorship.give_feedback(5, None).is_err());
+    /// ```
+    pub fn give_feedback(
+        &mut self,
+        rating: u8,
+        comment: Option<String>,
+    ) -> Result<(), MentorshipError> {
+        if self.is_active() {
+            return Err(MentorshipError::NotEnded);
+        }
+
+        if self.feedback.is_some() {
+            return Err(MentorshipError::FeedbackAlreadyGiven);
+        }
+
+        let rating = Rating::new(rating)?;
+        let comment = comment
+            .map(|text| Self::validate_text(&text, Self::MAX_FEEDBACK_LENGTH))
+            .transpose()?;
+
+        self.feedback = Some(MentorshipFeedback { rating, comment });
+        Ok(())
+    }
+
+    /// Returns the ID of the mentor's profile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentor_id = Id::new();
+    /// let mentorship = Mentorship::new(
+    ///     mentor_id,
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert_eq!(mentorship.mentor_id(), mentor_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn mentor_id(&self) -> Id {
+        self.mentor_id
+    }
+
+    /// Returns the email of the learner being mentored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert_eq!(mentorship.learner_email().address(), "learner@example.com");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn learner_email(&self) -> &Email {
+        &self.learner_email
+    }
+
+    /// Returns the ID of the course the mentorship is about.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let course_id = Id::new();
+    /// let mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     course_id,
+    /// );
+    ///
+    /// assert_eq!(mentorship.course_id(), course_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns when the mentorship started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert!(mentorship.started_at() <= DateTime::today());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn started_at(&self) -> DateTime {
+        self.started_at
+    }
+
+    /// Returns when the mentorship ended, if it has.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert!(mentorship.ended_at().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ended_at(&self) -> Option<DateTime> {
+        self.ended_at
+    }
+
+    /// Returns the logged sessions, in logging order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert!(mentorship.sessions().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sessions(&self) -> &[MentorshipSession] {
+        &self.sessions
+    }
+
+    /// Returns the learner's feedback, if given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert!(mentorship.feedback().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn feedback(&self) -> Option<&MentorshipFeedback> {
+        self.feedback.as_ref()
+    }
+
+    /// Returns true until the mentorship is ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    ///
+    /// assert!(mentorship.is_active());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Returns the combined length of all logged sessions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Mentorship;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut mentorship = Mentorship::new(
+    ///     Id::new(),
+    ///     Email::new("learner@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    /// );
+    /// let held_at = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// mentorship.log_session(held_at, Duration::from_minutes(30), "One").unwrap();
+    /// mentorship.log_session(held_at, Duration::from_minutes(45), "Two").unwrap();
+    ///
+    /// assert_eq!(mentorship.total_session_time(), Duration::from_minutes(75));
+    /// ```
+    #[must_use]
+    pub fn total_session_time(&self) -> Duration {
+        self.sessions.iter().map(|session| session.duration).sum()
+    }
+
+    fn validate_text(text: &str, max_length: usize) -> Result<String, MentorshipError> {
+        let trimmed = text.trim();
+        Validator::is_not_empty(trimmed)?;
+        Validator::has_max_length(trimmed, max_length)?;
+        Ok(trimmed.to_string())
+    }
+}
+
+impl Entity for Mentorship {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_mentorship() -> Mentorship {
+        Mentorship::new(
+            Id::new(),
+            Email::new("learner@example.com".to_string()).unwrap(),
+            Id::new(),
+        )
+    }
+
+    fn at(day: u32) -> DateTime {
+        DateTime::new(2024, 12, day, 18, 0, 0).unwrap()
+    }
+
+    mod sessions {
+        use super::*;
+
+        #[test]
+        fn test_log_session_records_trimmed_summary() {
+            let mut mentorship = create_mentorship();
+
+            mentorship
+                .log_session(at(9), Duration::from_minutes(30), "  Kick-off  ")
+                .unwrap();
+
+            assert_eq!(mentorship.sessions()[0].summary(), "Kick-off");
+            assert_eq!(mentorship.sessions()[0].duration(), Duration::from_minutes(30));
+        }
+
+        #[test]
+        fn test_log_session_rejects_zero_duration() {
+            let mut mentorship = create_mentorship();
+
+            let result = mentorship.log_session(at(9), Duration::default(), "Kick-off");
+
+            assert_eq!(result.unwrap_err(), MentorshipError::SessionDurationIsZero);
+        }
+
+        #[test]
+        fn test_log_session_rejects_blank_summary() {
+            let mut mentorship = create_mentorship();
+
+            let result = mentorship.log_session(at(9), Duration::from_minutes(30), "  ");
+
+            assert!(matches!(result, Err(MentorshipError::SummaryError(_))));
+        }
+
+        #[test]
+        fn test_log_session_after_end_is_rejected() {
+            let mut mentorship = create_mentorship();
+            mentorship.end(at(10)).unwrap();
+
+            let result = mentorship.log_session(at(11), Duration::from_minutes(30), "Late");
+
+            assert_eq!(result.unwrap_err(), MentorshipError::AlreadyEnded);
+        }
+
+        #[test]
+        fn test_total_session_time_sums_sessions() {
+            let mut mentorship = create_mentorship();
+            mentorship
+                .log_session(at(9), Duration::from_minutes(30), "One")
+                .unwrap();
+            mentorship
+                .log_session(at(10), Duration::from_hours(1), "Two")
+                .unwrap();
+
+            assert_eq!(mentorship.total_session_time(), Duration::from_minutes(90));
+        }
+    }
+
+    mod end {
+        use super::*;
+
+        #[test]
+        fn test_end_records_end_time() {
+            let mut mentorship = create_mentorship();
+
+            mentorship.end(at(20)).unwrap();
+
+            assert!(!mentorship.is_active());
+            assert_eq!(mentorship.ended_at(), Some(at(20)));
+        }
+
+        #[test]
+        fn test_end_twice_is_rejected() {
+            let mut mentorship = create_mentorship();
+            mentorship.end(at(20)).unwrap();
+
+            assert_eq!(mentorship.end(at(21)).unwrap_err(), MentorshipError::AlreadyEnded);
+            assert_eq!(mentorship.ended_at(), Some(at(20)));
+        }
+    }
+
+    mod feedback {
+        use super::*;
+
+        #[test]
+        fn test_feedback_requires_ended_mentorship() {
+            let mut mentorship = create_mentorship();
+
+            assert_eq!(
+                mentorship.give_feedback(5, None).unwrap_err(),
+                MentorshipError::NotEnded
+            );
+        }
+
+        #[test]
+        fn test_feedback_is_recorded_once() {
+            let mut mentorship = create_mentorship();
+            mentorship.end(at(20)).unwrap();
+
+            mentorship
+                .give_feedback(4, Some(" Helpful ".to_string()))
+                .unwrap();
+
+            let feedback = mentorship.feedback().unwrap();
+            assert_eq!(feedback.rating().stars(), 4);
+            assert_eq!(feedback.comment(), Some("Helpful"));
+            assert_eq!(
+                mentorship.give_feedback(5, None).unwrap_err(),
+                MentorshipError::FeedbackAlreadyGiven
+            );
+        }
+
+        #[test]
+        fn test_feedback_rejects_invalid_rating() {
+            let mut mentorship = create_mentorship();
+            mentorship.end(at(20)).unwrap();
+
+            let result = mentorship.give_feedback(0, None);
+
+            assert!(matches!(result, Err(MentorshipError::RatingError(_))));
+            assert!(mentorship.feedback().is_none());
+        }
+
+        #[test]
+        fn test_feedback_rejects_blank_comment() {
+            let mut mentorship = create_mentorship();
+            mentorship.end(at(20)).unwrap();
+
+            let result = mentorship.give_feedback(3, Some("   ".to_string()));
+
+            assert!(matches!(result, Err(MentorshipError::SummaryError(_))));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod attachment;
+mod notification;
+mod template;
+mod triggers;
+
+pub use attachment::*;
+pub use notification::*;
+pub use template::*;
+pub use triggers::*;
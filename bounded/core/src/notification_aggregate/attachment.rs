@@ -0,0 +1,122 @@
+use crate::NotificationError;
+
+const MAX_FILENAME_LENGTH: usize = 255;
+
+/// A file sent along with an email notification.
+///
+/// The filename is what the recipient sees, so it must be a plain name
+/// without directory separators.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Attachment;
+///
+/// let attachment = Attachment::csv("completion.csv", "group,completed\r\nA,3\r\n").unwrap();
+///
+/// assert_eq!(attachment.content_type(), "text/csv");
+/// assert_eq!(attachment.size(), 22);
+/// assert!(Attachment::csv("../completion.csv", "").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    filename: String,
+    content_type: String,
+    content: Vec<u8>,
+}
+
+impl Attachment {
+    /// Creates an attachment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::AttachmentNameNotValid` if the filename is
+    /// blank, longer than 255 characters, or contains a path separator.
+    pub fn new(
+        filename: &str,
+        content_type: &str,
+        content: Vec<u8>,
+    ) -> Result<Self, NotificationError> {
+        let trimmed = filename.trim();
+        if trimmed.is_empty()
+            || trimmed.chars().count() > MAX_FILENAME_LENGTH
+            || trimmed.contains(['/', '\\'])
+        {
+            return Err(NotificationError::AttachmentNameNotValid(filename.to_string()));
+        }
+
+        Ok(Self {
+            filename: trimmed.to_string(),
+            content_type: content_type.to_string(),
+            content,
+        })
+    }
+
+    /// Creates a `text/csv` attachment from UTF-8 text.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::AttachmentNameNotValid` under the same
+    /// conditions as [`Attachment::new`].
+    pub fn csv(filename: &str, content: &str) -> Result<Self, NotificationError> {
+        Self::new(filename, "text/csv", content.as_bytes().to_vec())
+    }
+
+    /// Returns the filename shown to the recipient.
+    #[inline]
+    #[must_use]
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Returns the MIME type of the content.
+    #[inline]
+    #[must_use]
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    /// Returns the raw content.
+    #[inline]
+    #[must_use]
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+
+    /// Returns the content size in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.content.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filename_is_trimmed() {
+        let attachment = Attachment::new(" report.pdf ", "application/pdf", vec![1, 2]).unwrap();
+
+        assert_eq!(attachment.filename(), "report.pdf");
+        assert_eq!(attachment.content(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_invalid_filenames_are_rejected() {
+        let too_long = "a".repeat(256);
+
+        for filename in [
+            "  ",
+            "reports/june.csv",
+            "reports\\june.csv",
+            too_long.as_str(),
+        ] {
+            assert_eq!(
+                Attachment::csv(filename, ""),
+                Err(NotificationError::AttachmentNameNotValid(filename.to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,509 @@
+use crate::{Attachment, RenderedMessage};
+use education_platform_common::{DateTime, Email, EmailError, Entity, Id, ValidatorError};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Notification and template failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NotificationError {
+    #[error("Recipient email is not valid: {0}")]
+    RecipientError(#[from] EmailError),
+
+    #[error("Template ID validation failed: {0}")]
+    TemplateIdError(ValidatorError),
+
+    #[error("Placeholder opened at byte {position} is never closed")]
+    UnclosedPlaceholder { position: usize },
+
+    #[error("No value for placeholder '{0}'")]
+    MissingValue(String),
+
+    #[error("Notification template not found: {0}")]
+    TemplateNotFound(String),
+
+    #[error("Submission has not been graded yet")]
+    SubmissionNotGraded,
+
+    #[error("Notification cannot move from {from} to {to}")]
+    TransitionNotValid {
+        from: NotificationStatus,
+        to: NotificationStatus,
+    },
+
+    #[error("Attachment filename is not valid: '{0}'")]
+    AttachmentNameNotValid(String),
+
+    #[error("The {0} channel does not carry attachments")]
+    AttachmentsNotSupported(NotificationChannel),
+}
+
+/// How a notification reaches its recipient.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::NotificationChannel;
+///
+/// assert_eq!(NotificationChannel::InApp.to_string(), "in_app");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NotificationChannel {
+    #[default]
+    Email,
+    InApp,
+}
+
+impl fmt::Display for NotificationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Email => "email",
+            Self::InApp => "in_app",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// Where a notification stands: waiting to be sent, sent, or given up on.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::NotificationStatus;
+///
+/// assert_eq!(NotificationStatus::default(), NotificationStatus::Pending);
+/// assert_eq!(NotificationStatus::Failed.to_string(), "failed");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NotificationStatus {
+    #[default]
+    Pending,
+    Sent,
+    Failed,
+}
+
+impl fmt::Display for NotificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Pending => "pending",
+            Self::Sent => "sent",
+            Self::Failed => "failed",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// A message rendered from a template for one recipient, tracked until sent.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     Notification, NotificationChannel, NotificationStatus, RenderedMessage,
+/// };
+/// use education_platform_common::DateTime;
+///
+/// let mut notification = Notification::new(
+///     "student@example.com".to_string(),
+///     NotificationChannel::Email,
+///     RenderedMessage::new("course_completed", "Well done", "You finished Rust Basics."),
+/// )
+/// .unwrap();
+///
+/// notification.mark_sent(DateTime::new(2024, 6, 1, 9, 0, 0).unwrap()).unwrap();
+/// assert_eq!(notification.status(), NotificationStatus::Sent);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Notification {
+    id: Id,
+    recipient: Email,
+    channel: NotificationChannel,
+    message: RenderedMessage,
+    status: NotificationStatus,
+    sent_at: Option<DateTime>,
+    failure_reason: Option<String>,
+    attachments: Vec<Attachment>,
+}
+
+impl Notification {
+    /// Creates a pending notification.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::RecipientError` if the email is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Notification, NotificationChannel, RenderedMessage};
+    ///
+    /// let message = RenderedMessage::new("welcome", "Hi", "Body");
+    ///
+    /// assert!(Notification::new("invalid".to_string(), NotificationChannel::Email, message).is_err());
+    /// ```
+    pub fn new(
+        recipient: String,
+        channel: NotificationChannel,
+        message: RenderedMessage,
+    ) -> Result<Self, NotificationError> {
+        Self::with_id(
+            Id::default(),
+            recipient,
+            channel,
+            message,
+            NotificationStatus::Pending,
+            None,
+            None,
+        )
+    }
+
+    /// Creates a `Notification` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::RecipientError` if the email is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     Notification, NotificationChannel, NotificationStatus, RenderedMessage,
+    /// };
+    /// use education_platform_common::{Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let notification = Notification::with_id(
+    ///     id,
+    ///     "student@example.com".to_string(),
+    ///     NotificationChannel::InApp,
+    ///     RenderedMessage::new("lesson_published", "New lesson", "Traits is out."),
+    ///     NotificationStatus::Failed,
+    ///     None,
+    ///     Some("mailbox full".to_string()),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(notification.id(), id);
+    /// assert_eq!(notification.failure_reason(), Some("mailbox full"));
+    /// ```
+    pub fn with_id(
+        id: Id,
+        recipient: String,
+        channel: NotificationChannel,
+        message: RenderedMessage,
+        status: NotificationStatus,
+        sent_at: Option<DateTime>,
+        failure_reason: Option<String>,
+    ) -> Result<Self, NotificationError> {
+        let recipient = Email::new(recipient)?;
+
+        Ok(Self {
+            id,
+            recipient,
+            channel,
+            message,
+            status,
+            sent_at,
+            failure_reason,
+            attachments: Vec::new(),
+        })
+    }
+
+    /// Records that the notification was handed to its channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TransitionNotValid` if the notification is not pending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Notification, NotificationChannel, RenderedMessage};
+    /// use education_platform_common::DateTime;
+    ///
+    /// let mut notification = Notification::new(
+    ///     "student@example.com".to_string(),
+    ///     NotificationChannel::Email,
+    ///     RenderedMessage::new("welcome", "Hi", "Body"),
+    /// )
+    /// .unwrap();
+    /// let at = DateTime::new(2024, 6, 1, 9, 0, 0).unwrap();
+    ///
+    /// notification.mark_sent(at).unwrap();
+    /// assert_eq!(notification.sent_at(), Some(at));
+    /// assert!(notification.mark_sent(at).is_err());
+    /// ```
+    pub fn mark_sent(&mut self, at: DateTime) -> Result<(), NotificationError> {
+        self.ensure_pending(NotificationStatus::Sent)?;
+        self.status = NotificationStatus::Sent;
+        self.sent_at = Some(at);
+        Ok(())
+    }
+
+    /// Records that the channel could not deliver the notification.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TransitionNotValid` if the notification is not pending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     Notification, NotificationChannel, NotificationStatus, RenderedMessage,
+    /// };
+    ///
+    /// let mut notification = Notification::new(
+    ///     "student@example.com".to_string(),
+    ///     NotificationChannel::Email,
+    ///     RenderedMessage::new("welcome", "Hi", "Body"),
+    /// )
+    /// .unwrap();
+    ///
+    /// notification.mark_failed("mailbox full".to_string()).unwrap();
+    /// assert_eq!(notification.status(), NotificationStatus::Failed);
+    /// ```
+    pub fn mark_failed(&mut self, reason: String) -> Result<(), NotificationError> {
+        self.ensure_pending(NotificationStatus::Failed)?;
+        self.status = NotificationStatus::Failed;
+        self.failure_reason = Some(reason);
+        Ok(())
+    }
+
+    /// Adds a file to send with the notification.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::AttachmentsNotSupported` unless the
+    /// notification goes out by email, or `NotificationError::TransitionNotValid`
+    /// if it is no longer pending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     Attachment, Notification, NotificationChannel, RenderedMessage,
+    /// };
+    ///
+    /// let mut notification = Notification::new(
+    ///     "admin@example.com".to_string(),
+    ///     NotificationChannel::Email,
+    ///     RenderedMessage::new("report_ready", "Weekly report", "See attached."),
+    /// )
+    /// .unwrap();
+    ///
+    /// notification.attach(Attachment::csv("weekly.csv", "a,b\r\n").unwrap()).unwrap();
+    /// assert_eq!(notification.attachments()[0].filename(), "weekly.csv");
+    /// ```
+    pub fn attach(&mut self, attachment: Attachment) -> Result<(), NotificationError> {
+        if self.channel != NotificationChannel::Email {
+            return Err(NotificationError::AttachmentsNotSupported(self.channel));
+        }
+        self.ensure_pending(NotificationStatus::Pending)?;
+
+        self.attachments.push(attachment);
+        Ok(())
+    }
+
+    fn ensure_pending(&self, to: NotificationStatus) -> Result<(), NotificationError> {
+        match self.status {
+            NotificationStatus::Pending => Ok(()),
+            from => Err(NotificationError::TransitionNotValid { from, to }),
+        }
+    }
+
+    /// Returns the recipient email.
+    #[inline]
+    #[must_use]
+    pub const fn recipient(&self) -> &Email {
+        &self.recipient
+    }
+
+    /// Returns the delivery channel.
+    #[inline]
+    #[must_use]
+    pub const fn channel(&self) -> NotificationChannel {
+        self.channel
+    }
+
+    /// Returns the ID of the template the notification was rendered from.
+    #[inline]
+    #[must_use]
+    pub fn template_id(&self) -> &str {
+        self.message.template_id()
+    }
+
+    /// Returns the rendered subject.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        self.message.subject()
+    }
+
+    /// Returns the rendered body.
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> &str {
+        self.message.body()
+    }
+
+    /// Returns the current status.
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> NotificationStatus {
+        self.status
+    }
+
+    /// Returns when the notification was sent, if it was.
+    #[inline]
+    #[must_use]
+    pub const fn sent_at(&self) -> Option<DateTime> {
+        self.sent_at
+    }
+
+    /// Returns why delivery failed, if it did.
+    #[inline]
+    #[must_use]
+    pub fn failure_reason(&self) -> Option<&str> {
+        self.failure_reason.as_deref()
+    }
+
+    /// Returns the files sent with the notification.
+    #[inline]
+    #[must_use]
+    pub fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+}
+
+impl Entity for Notification {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for Notification {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Notification {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_notification() -> Notification {
+        Notification::new(
+            "student@example.com".to_string(),
+            NotificationChannel::Email,
+            RenderedMessage::new("course_completed", "Subject", "Body"),
+        )
+        .unwrap()
+    }
+
+    fn create_time() -> DateTime {
+        DateTime::new(2024, 6, 1, 9, 0, 0).unwrap()
+    }
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_new_notification_is_pending() {
+            let notification = create_test_notification();
+
+            assert_eq!(notification.status(), NotificationStatus::Pending);
+            assert_eq!(notification.recipient().address(), "student@example.com");
+            assert_eq!(notification.template_id(), "course_completed");
+            assert_eq!(notification.subject(), "Subject");
+            assert_eq!(notification.body(), "Body");
+            assert_eq!(notification.sent_at(), None);
+        }
+
+        #[test]
+        fn test_invalid_recipient_is_rejected() {
+            let result = Notification::new(
+                "nope".to_string(),
+                NotificationChannel::InApp,
+                RenderedMessage::new("t", "s", "b"),
+            );
+
+            assert!(matches!(result, Err(NotificationError::RecipientError(_))));
+        }
+    }
+
+    mod lifecycle {
+        use super::*;
+
+        #[test]
+        fn test_failed_notification_cannot_be_sent() {
+            let mut notification = create_test_notification();
+            notification.mark_failed("bounced".to_string()).unwrap();
+
+            assert_eq!(
+                notification.mark_sent(create_time()),
+                Err(NotificationError::TransitionNotValid {
+                    from: NotificationStatus::Failed,
+                    to: NotificationStatus::Sent,
+                })
+            );
+        }
+
+        #[test]
+        fn test_sent_notification_cannot_fail() {
+            let mut notification = create_test_notification();
+            notification.mark_sent(create_time()).unwrap();
+
+            assert!(notification.mark_failed("late bounce".to_string()).is_err());
+            assert_eq!(notification.failure_reason(), None);
+        }
+    }
+
+    mod attach {
+        use super::*;
+
+        fn create_test_attachment() -> Attachment {
+            Attachment::csv("report.csv", "a,b\r\n").unwrap()
+        }
+
+        #[test]
+        fn test_in_app_notification_rejects_attachments() {
+            let mut notification = Notification::new(
+                "student@example.com".to_string(),
+                NotificationChannel::InApp,
+                RenderedMessage::new("t", "s", "b"),
+            )
+            .unwrap();
+
+            assert_eq!(
+                notification.attach(create_test_attachment()),
+                Err(NotificationError::AttachmentsNotSupported(NotificationChannel::InApp))
+            );
+            assert!(notification.attachments().is_empty());
+        }
+
+        #[test]
+        fn test_sent_notification_rejects_attachments() {
+            let mut notification = create_test_notification();
+            notification.mark_sent(create_time()).unwrap();
+
+            assert!(notification.attach(create_test_attachment()).is_err());
+        }
+    }
+
+    mod equality {
+        use super::*;
+
+        #[test]
+        fn test_equality_is_by_id() {
+            let notification = create_test_notification();
+            let mut copy = notification.clone();
+            copy.mark_sent(create_time()).unwrap();
+
+            assert_eq!(notification, copy);
+            assert_ne!(notification, create_test_notification());
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use crate::NotificationError;
+use education_platform_common::Validator;
+
+/// The subject and body of a template after placeholder substitution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedMessage {
+    template_id: String,
+    subject: String,
+    body: String,
+}
+
+impl RenderedMessage {
+    /// Creates a rendered message from an already final subject and body.
+    #[must_use]
+    pub fn new(
+        template_id: impl Into<String>,
+        subject: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self {
+            template_id: template_id.into(),
+            subject: subject.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Returns the ID of the template the message was rendered from.
+    #[inline]
+    #[must_use]
+    pub fn template_id(&self) -> &str {
+        &self.template_id
+    }
+
+    /// Returns the rendered subject.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Returns the rendered body.
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+/// A reusable message with `{{placeholder}}` slots filled in at send time.
+///
+/// Placeholders are checked when the template is created, so a template
+/// with an unclosed `{{` never reaches the renderer. Whitespace inside the
+/// braces is ignored: `{{ name }}` and `{{name}}` are the same slot.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::NotificationTemplate;
+///
+/// let template = NotificationTemplate::new(
+///     "course_completed",
+///     "You finished {{course}}!",
+///     "Congratulations, you completed {{ course }}.",
+/// )
+/// .unwrap();
+///
+/// let message = template.render(&[("course", "Rust Basics")]).unwrap();
+///
+/// assert_eq!(message.subject(), "You finished Rust Basics!");
+/// assert_eq!(message.body(), "Congratulations, you completed Rust Basics.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationTemplate {
+    id: String,
+    subject: String,
+    body: String,
+}
+
+impl NotificationTemplate {
+    pub const MAX_ID_LENGTH: usize = 100;
+
+    /// Creates a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TemplateIdError` if the ID is blank or
+    /// too long, or `NotificationError::UnclosedPlaceholder` if the subject
+    /// or body opens a placeholder it never closes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{NotificationError, NotificationTemplate};
+    ///
+    /// assert_eq!(
+    ///     NotificationTemplate::new("welcome", "Hi {{name", "Welcome!"),
+    ///     Err(NotificationError::UnclosedPlaceholder { position: 3 })
+    /// );
+    /// ```
+    pub fn new(id: &str, subject: &str, body: &str) -> Result<Self, NotificationError> {
+        let id = id.trim();
+        Validator::is_not_empty(id).map_err(NotificationError::TemplateIdError)?;
+        Validator::has_max_length(id, Self::MAX_ID_LENGTH)
+            .map_err(NotificationError::TemplateIdError)?;
+        placeholders(subject)?;
+        placeholders(body)?;
+
+        Ok(Self {
+            id: id.to_string(),
+            subject: subject.to_string(),
+            body: body.to_string(),
+        })
+    }
+
+    /// Returns the template ID notifications refer to.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the unrendered subject.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Returns the unrendered body.
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the distinct placeholder names used by the subject and body, in order of appearance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::NotificationTemplate;
+    ///
+    /// let template = NotificationTemplate::new("graded", "{{course}}", "{{score}}/{{max}} in {{course}}")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(template.placeholders(), vec!["course", "score", "max"]);
+    /// ```
+    #[must_use]
+    pub fn placeholders(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for placeholder in [&self.subject, &self.body]
+            .into_iter()
+            .flat_map(|text| placeholders(text).unwrap_or_default())
+        {
+            if !names.contains(&placeholder.name) {
+                names.push(placeholder.name);
+            }
+        }
+        names
+    }
+
+    /// Substitutes every placeholder with its value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::MissingValue` if a placeholder has no value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{NotificationError, NotificationTemplate};
+    ///
+    /// let template = NotificationTemplate::new("welcome", "Hi {{name}}", "Welcome!").unwrap();
+    ///
+    /// assert_eq!(
+    ///     template.render(&[]),
+    ///     Err(NotificationError::MissingValue("name".to_string()))
+    /// );
+    /// ```
+    pub fn render(&self, values: &[(&str, &str)]) -> Result<RenderedMessage, NotificationError> {
+        Ok(RenderedMessage {
+            template_id: self.id.clone(),
+            subject: substitute(&self.subject, values)?,
+            body: substitute(&self.body, values)?,
+        })
+    }
+}
+
+/// A `{{name}}` slot found in a template text, with its byte range.
+struct Placeholder<'a> {
+    start: usize,
+    end: usize,
+    name: &'a str,
+}
+
+/// Returns every placeholder in the text, with its name trimmed.
+fn placeholders(text: &str) -> Result<Vec<Placeholder<'_>>, NotificationError> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+
+    while let Some(open) = text[offset..].find("{{") {
+        let start = offset + open;
+        let close = text[start + 2..]
+            .find("}}")
+            .ok_or(NotificationError::UnclosedPlaceholder { position: start })?;
+        let end = start + 2 + close + 2;
+        found.push(Placeholder {
+            start,
+            end,
+            name: text[start + 2..end - 2].trim(),
+        });
+        offset = end;
+    }
+    Ok(found)
+}
+
+fn substitute(text: &str, values: &[(&str, &str)]) -> Result<String, NotificationError> {
+    let mut rendered = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for placeholder in placeholders(text)? {
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == placeholder.name)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| NotificationError::MissingValue(placeholder.name.to_string()))?;
+        rendered.push_str(&text[last..placeholder.start]);
+        rendered.push_str(value);
+        last = placeholder.end;
+    }
+    rendered.push_str(&text[last..]);
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_template(subject: &str, body: &str) -> NotificationTemplate {
+        NotificationTemplate::new("test", subject, body).unwrap()
+    }
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_trims_id() {
+            assert_eq!(create_test_template("s", "b").id(), "test");
+            assert_eq!(
+                NotificationTemplate::new("  padded ", "s", "b")
+                    .unwrap()
+                    .id(),
+                "padded"
+            );
+        }
+
+        #[test]
+        fn test_blank_id_is_rejected() {
+            assert!(matches!(
+                NotificationTemplate::new(" ", "s", "b"),
+                Err(NotificationError::TemplateIdError(_))
+            ));
+        }
+
+        #[test]
+        fn test_unclosed_placeholder_in_body_is_rejected() {
+            assert_eq!(
+                NotificationTemplate::new("t", "ok", "Score: {{score"),
+                Err(NotificationError::UnclosedPlaceholder { position: 7 })
+            );
+        }
+    }
+
+    mod render {
+        use super::*;
+
+        #[test]
+        fn test_text_without_placeholders_is_unchanged() {
+            let message = create_test_template("Hello", "Plain body")
+                .render(&[])
+                .unwrap();
+
+            assert_eq!(message, RenderedMessage::new("test", "Hello", "Plain body"));
+        }
+
+        #[test]
+        fn test_repeated_placeholder_is_replaced_everywhere() {
+            let template = create_test_template("{{x}}", "{{x}} and {{ x }}");
+
+            let message = template.render(&[("x", "1")]).unwrap();
+
+            assert_eq!(message.subject(), "1");
+            assert_eq!(message.body(), "1 and 1");
+        }
+
+        #[test]
+        fn test_values_are_not_rescanned() {
+            let template = create_test_template("{{name}}", "");
+
+            let message = template.render(&[("name", "{{evil}}")]).unwrap();
+
+            assert_eq!(message.subject(), "{{evil}}");
+        }
+
+        #[test]
+        fn test_extra_values_are_ignored() {
+            let template = create_test_template("{{a}}", "");
+
+            assert!(template.render(&[("a", "1"), ("b", "2")]).is_ok());
+        }
+
+        #[test]
+        fn test_handles_multibyte_text() {
+            let template = create_test_template("¡Hola {{nombre}}!", "");
+
+            let message = template.render(&[("nombre", "Ñandú")]).unwrap();
+
+            assert_eq!(message.subject(), "¡Hola Ñandú!");
+        }
+    }
+}
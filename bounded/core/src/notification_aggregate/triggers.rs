@@ -0,0 +1,609 @@
+use crate::{
+    Assignment, CourseEnded, Notification, NotificationChannel, NotificationError,
+    NotificationTemplate, Submission, SubmissionStatus,
+};
+use education_platform_common::{DateTime, Email};
+
+/// Builds notifications for the platform events learners are told about.
+///
+/// Each trigger renders a template by ID, so wording can be changed by
+/// registering a replacement template without touching the trigger:
+///
+/// | Trigger                 | Template ID              | Placeholders                               |
+/// |-------------------------|--------------------------|--------------------------------------------|
+/// | course completion       | `course_completed`       | `course`                                   |
+/// | new lesson published    | `lesson_published`       | `course`, `lesson`                         |
+/// | assignment graded       | `assignment_graded`      | `course`, `score`, `max_score`, `feedback` |
+/// | lesson marked complete  | `lesson_marked_complete` | `course`, `lesson`, `reason`               |
+/// | deadline extended       | `deadline_extended`      | `course`, `due_at`, `reason`               |
+/// | progress transferred    | `progress_transferred`   | `course`, `from`, `reason`                 |
+/// | scheduled report ready  | `report_ready`           | `report`, `generated_at`                   |
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseEnded, NotificationChannel, NotificationTriggers};
+/// use education_platform_common::{Email, Id};
+///
+/// let triggers = NotificationTriggers::new(NotificationChannel::Email);
+/// let event = CourseEnded::new(Email::new("ana@example.com".to_string()).unwrap(), Id::new());
+///
+/// let notification = triggers.course_completed(&event, "Rust Basics").unwrap();
+///
+/// assert_eq!(notification.recipient().address(), "ana@example.com");
+/// assert_eq!(notification.subject(), "You completed Rust Basics");
+/// ```
+#[derive(Debug, Clone)]
+pub struct NotificationTriggers {
+    channel: NotificationChannel,
+    templates: Vec<NotificationTemplate>,
+}
+
+impl NotificationTriggers {
+    pub const COURSE_COMPLETED: &'static str = "course_completed";
+    pub const LESSON_PUBLISHED: &'static str = "lesson_published";
+    pub const ASSIGNMENT_GRADED: &'static str = "assignment_graded";
+    pub const LESSON_MARKED_COMPLETE: &'static str = "lesson_marked_complete";
+    pub const DEADLINE_EXTENDED: &'static str = "deadline_extended";
+    pub const PROGRESS_TRANSFERRED: &'static str = "progress_transferred";
+    pub const REPORT_READY: &'static str = "report_ready";
+
+    /// Creates triggers sending on the given channel with the built-in templates.
+    #[must_use]
+    pub fn new(channel: NotificationChannel) -> Self {
+        let templates = [
+            (
+                Self::COURSE_COMPLETED,
+                "You completed {{course}}",
+                "Congratulations! You have completed every lesson of {{course}}.",
+            ),
+            (
+                Self::LESSON_PUBLISHED,
+                "New lesson in {{course}}",
+                "\"{{lesson}}\" is now available in {{course}}.",
+            ),
+            (
+                Self::ASSIGNMENT_GRADED,
+                "Your assignment in {{course}} was graded",
+                "You scored {{score}}/{{max_score}}.\n\n{{feedback}}",
+            ),
+            (
+                Self::LESSON_MARKED_COMPLETE,
+                "\"{{lesson}}\" was marked complete",
+                "An administrator marked \"{{lesson}}\" in {{course}} as complete: {{reason}}",
+            ),
+            (
+                Self::DEADLINE_EXTENDED,
+                "More time for your assignment in {{course}}",
+                "Your assignment in {{course}} is now due {{due_at}}: {{reason}}",
+            ),
+            (
+                Self::PROGRESS_TRANSFERRED,
+                "Your progress in {{course}} was moved",
+                "Your progress in {{course}} from {{from}} is now on this account: {{reason}}",
+            ),
+            (
+                Self::REPORT_READY,
+                "Your {{report}} report",
+                "The {{report}} report generated on {{generated_at}} is attached.",
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(id, subject, body)| NotificationTemplate::new(id, subject, body).ok())
+        .collect();
+
+        Self { channel, templates }
+    }
+
+    /// Registers a template, replacing any template with the same ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     CourseEnded, NotificationChannel, NotificationTemplate, NotificationTriggers,
+    /// };
+    /// use education_platform_common::{Email, Id};
+    ///
+    /// let mut triggers = NotificationTriggers::new(NotificationChannel::InApp);
+    /// triggers.register(
+    ///     NotificationTemplate::new("course_completed", "🎓 {{course}}", "All done!").unwrap(),
+    /// );
+    ///
+    /// let event = CourseEnded::new(Email::new("ana@example.com".to_string()).unwrap(), Id::new());
+    /// let notification = triggers.course_completed(&event, "Rust Basics").unwrap();
+    ///
+    /// assert_eq!(notification.subject(), "🎓 Rust Basics");
+    /// ```
+    pub fn register(&mut self, template: NotificationTemplate) {
+        self.templates
+            .retain(|existing| existing.id() != template.id());
+        self.templates.push(template);
+    }
+
+    /// Returns the template registered under an ID.
+    #[must_use]
+    pub fn template(&self, template_id: &str) -> Option<&NotificationTemplate> {
+        self.templates
+            .iter()
+            .find(|template| template.id() == template_id)
+    }
+
+    /// Notifies a learner that they completed a course.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TemplateNotFound` or
+    /// `NotificationError::MissingValue` if the registered template cannot be rendered.
+    pub fn course_completed(
+        &self,
+        event: &CourseEnded,
+        course_name: &str,
+    ) -> Result<Notification, NotificationError> {
+        self.notify(event.user_email(), Self::COURSE_COMPLETED, &[("course", course_name)])
+    }
+
+    /// Notifies every enrolled learner that a lesson was published.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TemplateNotFound` or
+    /// `NotificationError::MissingValue` if the registered template cannot be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{NotificationChannel, NotificationTriggers};
+    /// use education_platform_common::Email;
+    ///
+    /// let triggers = NotificationTriggers::new(NotificationChannel::Email);
+    /// let learners = [
+    ///     Email::new("ana@example.com".to_string()).unwrap(),
+    ///     Email::new("luis@example.com".to_string()).unwrap(),
+    /// ];
+    ///
+    /// let notifications = triggers.lesson_published(&learners, "Rust Basics", "Traits").unwrap();
+    ///
+    /// assert_eq!(notifications.len(), 2);
+    /// assert_eq!(notifications[1].body(), "\"Traits\" is now available in Rust Basics.");
+    /// ```
+    pub fn lesson_published(
+        &self,
+        recipients: &[Email],
+        course_name: &str,
+        lesson_name: &str,
+    ) -> Result<Vec<Notification>, NotificationError> {
+        recipients
+            .iter()
+            .map(|recipient| {
+                self.notify(
+                    recipient,
+                    Self::LESSON_PUBLISHED,
+                    &[("course", course_name), ("lesson", lesson_name)],
+                )
+            })
+            .collect()
+    }
+
+    /// Notifies a student that their submission was graded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::SubmissionNotGraded` if the submission has
+    /// no grade yet, or a template error as for the other triggers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     Assignment, NotificationChannel, NotificationTriggers, Submission, SubmissionContent,
+    /// };
+    /// use education_platform_common::{DateTime, Entity, Id};
+    ///
+    /// let due = DateTime::new(2024, 12, 10, 23, 59, 0).unwrap();
+    /// let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due, 100).unwrap();
+    /// let content = SubmissionContent::text("My essay".to_string()).unwrap();
+    /// let submission = Submission::new("ana@example.com".to_string(), content).unwrap();
+    /// let submission_id = submission.id();
+    /// assignment.add_submission(submission).unwrap();
+    /// assignment.submit(submission_id, DateTime::new(2024, 12, 1, 10, 0, 0).unwrap()).unwrap();
+    /// assignment.grade_submission(submission_id, 92, Some("Great work".to_string())).unwrap();
+    ///
+    /// let triggers = NotificationTriggers::new(NotificationChannel::Email);
+    /// let submission = &assignment.submissions()[0];
+    /// let notification = triggers.assignment_graded(&assignment, submission, "Rust Basics").unwrap();
+    ///
+    /// assert_eq!(notification.body(), "You scored 92/100.\n\nGreat work");
+    /// ```
+    pub fn assignment_graded(
+        &self,
+        assignment: &Assignment,
+        submission: &Submission,
+        course_name: &str,
+    ) -> Result<Notification, NotificationError> {
+        let score = match (submission.status(), submission.score()) {
+            (SubmissionStatus::Graded | SubmissionStatus::Returned, Some(score)) => score,
+            _ => return Err(NotificationError::SubmissionNotGraded),
+        };
+
+        self.notify(
+            submission.student_email(),
+            Self::ASSIGNMENT_GRADED,
+            &[
+                ("course", course_name),
+                ("score", &score.points().to_string()),
+                ("max_score", &assignment.max_score().to_string()),
+                ("feedback", submission.feedback().unwrap_or_default()),
+            ],
+        )
+    }
+
+    /// Notifies learners that an administrator completed a lesson for them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TemplateNotFound` or
+    /// `NotificationError::MissingValue` if the registered template cannot be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{NotificationChannel, NotificationTriggers};
+    /// use education_platform_common::Email;
+    ///
+    /// let triggers = NotificationTriggers::new(NotificationChannel::InApp);
+    /// let learners = [Email::new("ana@example.com".to_string()).unwrap()];
+    ///
+    /// let notifications = triggers
+    ///     .lesson_marked_complete(&learners, "Rust Basics", "Traits", "Attended the workshop")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(notifications[0].subject(), "\"Traits\" was marked complete");
+    /// ```
+    pub fn lesson_marked_complete(
+        &self,
+        recipients: &[Email],
+        course_name: &str,
+        lesson_name: &str,
+        reason: &str,
+    ) -> Result<Vec<Notification>, NotificationError> {
+        recipients
+            .iter()
+            .map(|recipient| {
+                self.notify(
+                    recipient,
+                    Self::LESSON_MARKED_COMPLETE,
+                    &[
+                        ("course", course_name),
+                        ("lesson", lesson_name),
+                        ("reason", reason),
+                    ],
+                )
+            })
+            .collect()
+    }
+
+    /// Notifies students that their assignment deadline was extended.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TemplateNotFound` or
+    /// `NotificationError::MissingValue` if the registered template cannot be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{NotificationChannel, NotificationTriggers};
+    /// use education_platform_common::{DateTime, Email};
+    ///
+    /// let triggers = NotificationTriggers::new(NotificationChannel::Email);
+    /// let students = [Email::new("ana@example.com".to_string()).unwrap()];
+    /// let until = DateTime::new(2024, 12, 20, 23, 59, 0).unwrap();
+    ///
+    /// let notifications = triggers
+    ///     .deadline_extended(&students, "Rust Basics", until, "Platform outage")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(notifications[0].subject(), "More time for your assignment in Rust Basics");
+    /// ```
+    pub fn deadline_extended(
+        &self,
+        recipients: &[Email],
+        course_name: &str,
+        until: DateTime,
+        reason: &str,
+    ) -> Result<Vec<Notification>, NotificationError> {
+        let due_at = until.to_string();
+        recipients
+            .iter()
+            .map(|recipient| {
+                self.notify(
+                    recipient,
+                    Self::DEADLINE_EXTENDED,
+                    &[
+                        ("course", course_name),
+                        ("due_at", &due_at),
+                        ("reason", reason),
+                    ],
+                )
+            })
+            .collect()
+    }
+
+    /// Notifies a learner that progress from another account was moved to theirs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TemplateNotFound` or
+    /// `NotificationError::MissingValue` if the registered template cannot be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{NotificationChannel, NotificationTriggers};
+    /// use education_platform_common::Email;
+    ///
+    /// let triggers = NotificationTriggers::new(NotificationChannel::Email);
+    /// let to = Email::new("ana@example.com".to_string()).unwrap();
+    /// let from = Email::new("ana.old@example.com".to_string()).unwrap();
+    ///
+    /// let notification = triggers
+    ///     .progress_transferred(&to, &from, "Rust Basics", "Duplicate account")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(notification.recipient().address(), "ana@example.com");
+    /// ```
+    pub fn progress_transferred(
+        &self,
+        recipient: &Email,
+        from: &Email,
+        course_name: &str,
+        reason: &str,
+    ) -> Result<Notification, NotificationError> {
+        self.notify(
+            recipient,
+            Self::PROGRESS_TRANSFERRED,
+            &[
+                ("course", course_name),
+                ("from", from.address()),
+                ("reason", reason),
+            ],
+        )
+    }
+
+    /// Notifies a subscriber that a scheduled report was generated.
+    ///
+    /// The report itself is attached by the caller with [`Notification::attach`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::TemplateNotFound` or
+    /// `NotificationError::MissingValue` if the registered template cannot be rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{NotificationChannel, NotificationTriggers};
+    /// use education_platform_common::{DateTime, Email};
+    ///
+    /// let triggers = NotificationTriggers::new(NotificationChannel::Email);
+    /// let admin = Email::new("admin@example.com".to_string()).unwrap();
+    /// let at = DateTime::new(2025, 3, 3, 8, 0, 0).unwrap();
+    ///
+    /// let notification = triggers.report_ready(&admin, "Cohort completion", at).unwrap();
+    ///
+    /// assert_eq!(notification.subject(), "Your Cohort completion report");
+    /// ```
+    pub fn report_ready(
+        &self,
+        recipient: &Email,
+        report_name: &str,
+        generated_at: DateTime,
+    ) -> Result<Notification, NotificationError> {
+        self.notify(
+            recipient,
+            Self::REPORT_READY,
+            &[
+                ("report", report_name),
+                ("generated_at", &generated_at.to_string()),
+            ],
+        )
+    }
+
+    fn notify(
+        &self,
+        recipient: &Email,
+        template_id: &str,
+        values: &[(&str, &str)],
+    ) -> Result<Notification, NotificationError> {
+        let template = self
+            .template(template_id)
+            .ok_or_else(|| NotificationError::TemplateNotFound(template_id.to_string()))?;
+        let message = template.render(values)?;
+        Notification::new(recipient.address().to_string(), self.channel, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NotificationStatus, SubmissionContent};
+    use education_platform_common::{DateTime, Entity, Id};
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_test_assignment() -> (Assignment, Id) {
+        let due = DateTime::new(2024, 12, 10, 23, 59, 0).unwrap();
+        let mut assignment = Assignment::new(Id::new(), "Essay".to_string(), due, 50).unwrap();
+        let content = SubmissionContent::text("Answer".to_string()).unwrap();
+        let submission = Submission::new("ana@example.com".to_string(), content).unwrap();
+        let submission_id = submission.id();
+        assignment.add_submission(submission).unwrap();
+        assignment
+            .submit(submission_id, DateTime::new(2024, 12, 1, 10, 0, 0).unwrap())
+            .unwrap();
+        (assignment, submission_id)
+    }
+
+    mod course_completed {
+        use super::*;
+
+        #[test]
+        fn test_creates_pending_notification_on_channel() {
+            let triggers = NotificationTriggers::new(NotificationChannel::InApp);
+            let event = CourseEnded::new(create_email("ana@example.com"), Id::new());
+
+            let notification = triggers.course_completed(&event, "Rust").unwrap();
+
+            assert_eq!(notification.channel(), NotificationChannel::InApp);
+            assert_eq!(notification.status(), NotificationStatus::Pending);
+            assert_eq!(notification.template_id(), NotificationTriggers::COURSE_COMPLETED);
+        }
+
+        #[test]
+        fn test_custom_template_with_unknown_placeholder_fails() {
+            let mut triggers = NotificationTriggers::new(NotificationChannel::Email);
+            triggers.register(
+                NotificationTemplate::new("course_completed", "{{student}}", "").unwrap(),
+            );
+            let event = CourseEnded::new(create_email("ana@example.com"), Id::new());
+
+            assert_eq!(
+                triggers.course_completed(&event, "Rust").unwrap_err(),
+                NotificationError::MissingValue("student".to_string())
+            );
+        }
+    }
+
+    mod lesson_published {
+        use super::*;
+
+        #[test]
+        fn test_no_recipients_yields_no_notifications() {
+            let triggers = NotificationTriggers::new(NotificationChannel::Email);
+
+            let notifications = triggers.lesson_published(&[], "Rust", "Traits").unwrap();
+
+            assert!(notifications.is_empty());
+        }
+    }
+
+    mod assignment_graded {
+        use super::*;
+
+        #[test]
+        fn test_ungraded_submission_is_rejected() {
+            let triggers = NotificationTriggers::new(NotificationChannel::Email);
+            let (assignment, _) = create_test_assignment();
+
+            assert_eq!(
+                triggers
+                    .assignment_graded(&assignment, &assignment.submissions()[0], "Rust")
+                    .unwrap_err(),
+                NotificationError::SubmissionNotGraded
+            );
+        }
+
+        #[test]
+        fn test_missing_feedback_renders_empty() {
+            let triggers = NotificationTriggers::new(NotificationChannel::Email);
+            let (mut assignment, submission_id) = create_test_assignment();
+            assignment
+                .grade_submission(submission_id, 40, None)
+                .unwrap();
+
+            let notification = triggers
+                .assignment_graded(&assignment, &assignment.submissions()[0], "Rust")
+                .unwrap();
+
+            assert_eq!(notification.recipient().address(), "ana@example.com");
+            assert_eq!(notification.subject(), "Your assignment in Rust was graded");
+            assert_eq!(notification.body(), "You scored 40/50.\n\n");
+        }
+    }
+
+    mod admin_adjustments {
+        use super::*;
+
+        #[test]
+        fn test_deadline_extended_renders_due_date_and_reason() {
+            let triggers = NotificationTriggers::new(NotificationChannel::Email);
+            let until = DateTime::new(2024, 12, 20, 23, 59, 0).unwrap();
+
+            let notifications = triggers
+                .deadline_extended(&[create_email("ana@example.com")], "Rust", until, "Outage")
+                .unwrap();
+
+            assert_eq!(
+                notifications[0].body(),
+                format!("Your assignment in Rust is now due {until}: Outage")
+            );
+        }
+
+        #[test]
+        fn test_progress_transferred_names_previous_account() {
+            let triggers = NotificationTriggers::new(NotificationChannel::Email);
+
+            let notification = triggers
+                .progress_transferred(
+                    &create_email("ana@example.com"),
+                    &create_email("ana.old@example.com"),
+                    "Rust",
+                    "Duplicate account",
+                )
+                .unwrap();
+
+            assert_eq!(
+                notification.body(),
+                "Your progress in Rust from ana.old@example.com is now on this account: Duplicate account"
+            );
+        }
+    }
+
+    mod templates {
+        use super::*;
+
+        #[test]
+        fn test_built_in_templates_are_registered() {
+            let triggers = NotificationTriggers::new(NotificationChannel::Email);
+
+            assert!(
+                triggers
+                    .template(NotificationTriggers::COURSE_COMPLETED)
+                    .is_some()
+            );
+            assert!(
+                triggers
+                    .template(NotificationTriggers::LESSON_PUBLISHED)
+                    .is_some()
+            );
+            assert!(
+                triggers
+                    .template(NotificationTriggers::ASSIGNMENT_GRADED)
+                    .is_some()
+            );
+            assert!(
+                triggers
+                    .template(NotificationTriggers::LESSON_MARKED_COMPLETE)
+                    .is_some()
+            );
+            assert!(
+                triggers
+                    .template(NotificationTriggers::DEADLINE_EXTENDED)
+                    .is_some()
+            );
+            assert!(
+                triggers
+                    .template(NotificationTriggers::PROGRESS_TRANSFERRED)
+                    .is_some()
+            );
+            assert!(
+                triggers
+                    .template(NotificationTriggers::REPORT_READY)
+                    .is_some()
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod order;
+mod order_line;
+mod payment_gateway;
+mod tax_policy;
+
+pub use order::*;
+pub use order_line::*;
+pub use payment_gateway::*;
+pub use tax_policy::*;
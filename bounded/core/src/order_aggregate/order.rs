@@ -0,0 +1,1035 @@
+use crate::{
+    Coupon, CouponCode, CouponError, CouponId, CourseId, Discount, OrderLine, PaymentError,
+    PaymentGateway, PaymentReceipt, TaxPolicy,
+};
+use education_platform_common::{
+    Auditable, Currency, Date, DateTime, Email, Entity, Id, Money, MoneyError, Timestamps,
+};
+use thiserror::Error;
+
+/// Error types for Order failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OrderError {
+    #[error("Order amount calculation failed: {0}")]
+    MoneyError(#[from] MoneyError),
+
+    #[error("Coupon redemption failed: {0}")]
+    CouponError(#[from] CouponError),
+
+    #[error("Payment failed: {0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Order is {0}, so it can no longer change")]
+    NotPending(String),
+
+    #[error("Order is {0}, so it cannot be refunded")]
+    NotPaid(String),
+
+    #[error("Order is in {order}, but got an amount in {found}")]
+    CurrencyMismatch { order: String, found: String },
+
+    #[error("Course {0} is already in the order")]
+    DuplicateCourse(String),
+
+    #[error("Course is not in the order")]
+    CourseNotInOrder,
+
+    #[error("Order has no lines to pay for")]
+    EmptyOrder,
+
+    #[error("Order already has a coupon")]
+    CouponAlreadyApplied,
+
+    #[error("Coupon does not apply to any course in the order")]
+    CouponNotApplicable,
+
+    #[error("Order was given another coupon than the one applied to it")]
+    CouponMismatch,
+}
+
+/// Where an [`Order`] is in its life: it is built while pending, then paid
+/// and possibly refunded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderStatus {
+    /// Lines and coupon can still change.
+    Pending,
+    /// The customer paid for the order.
+    Paid { at: DateTime },
+    /// The payment was given back.
+    Refunded { at: DateTime },
+}
+
+impl OrderStatus {
+    /// Returns the status name in lower case.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Paid { .. } => "paid",
+            Self::Refunded { .. } => "refunded",
+        }
+    }
+}
+
+/// A coupon applied to an [`Order`], kept so the discount can be worked
+/// out again when the lines change.
+///
+/// The coupon is only redeemed, using up one of its uses, when the order
+/// is paid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedCoupon {
+    coupon_id: CouponId,
+    code: CouponCode,
+    discount: Discount,
+    courses: Vec<CourseId>,
+}
+
+impl AppliedCoupon {
+    /// Returns the ID of the coupon.
+    #[inline]
+    #[must_use]
+    pub const fn coupon_id(&self) -> CouponId {
+        self.coupon_id
+    }
+
+    /// Returns the code that was applied.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> &CouponCode {
+        &self.code
+    }
+
+    /// Returns the discount the coupon gives.
+    #[inline]
+    #[must_use]
+    pub const fn discount(&self) -> Discount {
+        self.discount
+    }
+
+    fn covers(&self, course_id: CourseId) -> bool {
+        self.courses.is_empty() || self.courses.contains(&course_id)
+    }
+}
+
+/// What an [`Order`] costs, from the price of its lines to the amount
+/// charged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderTotals {
+    subtotal: Money,
+    discount: Money,
+    tax: Money,
+    total: Money,
+}
+
+impl OrderTotals {
+    /// Returns the sum of the line prices.
+    #[inline]
+    #[must_use]
+    pub const fn subtotal(&self) -> Money {
+        self.subtotal
+    }
+
+    /// Returns the amount the coupon takes off.
+    #[inline]
+    #[must_use]
+    pub const fn discount(&self) -> Money {
+        self.discount
+    }
+
+    /// Returns the tax on the discounted amount.
+    #[inline]
+    #[must_use]
+    pub const fn tax(&self) -> Money {
+        self.tax
+    }
+
+    /// Returns the amount the customer pays.
+    #[inline]
+    #[must_use]
+    pub const fn total(&self) -> Money {
+        self.total
+    }
+}
+
+/// A customer buying one or more courses.
+///
+/// `Order` is an aggregate root moving from `Pending` to `Paid` to
+/// `Refunded`. While pending, courses are added as [`OrderLine`]s in the
+/// order's currency and at most one [`Coupon`] can be applied; its
+/// discount covers the lines of the courses it applies to. Paying fixes
+/// the totals, with tax from a [`TaxPolicy`], charges them through a
+/// [`PaymentGateway`] and only then redeems the coupon, so an abandoned
+/// or declined order does not spend a use. An order the coupon makes free
+/// is paid without going through the gateway.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     Coupon, Discount, FlatTax, MockPaymentGateway, Order, OrderLine, OrderStatus,
+/// };
+/// use education_platform_common::{Currency, Date, DateRange, DateTime, Email, Id, Money, Percentage};
+///
+/// let usd = Currency::new("USD").unwrap();
+/// let customer = Email::new("ana@example.com".to_string()).unwrap();
+/// let today = Date::new(2025, 3, 10).unwrap();
+///
+/// let mut order = Order::new(customer, usd);
+/// order.add_line(OrderLine::new(Id::new().cast(), "Rust Programming".to_string(), Money::new(10_000, usd)).unwrap()).unwrap();
+///
+/// let validity = DateRange::new(today, today.add_days(30)).unwrap();
+/// let mut coupon = Coupon::new("SPRING", Discount::Percent(Percentage::new(20.0).unwrap()), 100, validity, vec![]).unwrap();
+/// order.apply_coupon(&coupon, "spring", today).unwrap();
+///
+/// let mut gateway = MockPaymentGateway::new();
+/// let at = DateTime::new(2025, 3, 10, 12, 0, 0).unwrap();
+/// let vat = FlatTax::new(Percentage::new(10.0).unwrap());
+/// let totals = order.pay(&mut gateway, &vat, Some(&mut coupon), at).unwrap();
+///
+/// assert_eq!(totals.discount().amount(), 2_000);
+/// assert_eq!(totals.tax().amount(), 800);
+/// assert_eq!(totals.total().amount(), 8_800);
+/// assert_eq!(order.status(), OrderStatus::Paid { at });
+/// assert_eq!(coupon.remaining_uses(), 99);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Order {
+    id: Id,
+    customer: Email,
+    currency: Currency,
+    lines: Vec<OrderLine>,
+    coupon: Option<AppliedCoupon>,
+    status: OrderStatus,
+    totals: Option<OrderTotals>,
+    receipt: Option<PaymentReceipt>,
+    timestamps: Timestamps,
+}
+
+/// Identifier of an [`Order`].
+pub type OrderId = Id<Order>;
+
+impl Order {
+    /// Creates an empty pending order for a customer paying in `currency`.
+    #[must_use]
+    pub fn new(customer: Email, currency: Currency) -> Self {
+        Self {
+            id: Id::default(),
+            customer,
+            currency,
+            lines: Vec::new(),
+            coupon: None,
+            status: OrderStatus::Pending,
+            totals: None,
+            receipt: None,
+            timestamps: Timestamps::now(),
+        }
+    }
+
+    /// Returns the order ID typed as an [`OrderId`].
+    #[inline]
+    #[must_use]
+    pub const fn order_id(&self) -> OrderId {
+        self.id.cast()
+    }
+
+    /// Returns the customer buying the courses.
+    #[inline]
+    #[must_use]
+    pub const fn customer(&self) -> &Email {
+        &self.customer
+    }
+
+    /// Returns the currency every line is priced in.
+    #[inline]
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns the lines in the order they were added.
+    #[inline]
+    #[must_use]
+    pub fn lines(&self) -> &[OrderLine] {
+        &self.lines
+    }
+
+    /// Returns the coupon applied to the order, if any.
+    #[inline]
+    #[must_use]
+    pub const fn coupon(&self) -> Option<&AppliedCoupon> {
+        self.coupon.as_ref()
+    }
+
+    /// Returns where the order is in its life.
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> OrderStatus {
+        self.status
+    }
+
+    /// Returns the totals fixed when the order was paid.
+    #[inline]
+    #[must_use]
+    pub const fn totals(&self) -> Option<&OrderTotals> {
+        self.totals.as_ref()
+    }
+
+    /// Returns the gateway receipt, unless the order is unpaid or was free.
+    #[inline]
+    #[must_use]
+    pub const fn receipt(&self) -> Option<&PaymentReceipt> {
+        self.receipt.as_ref()
+    }
+
+    /// Adds a course to the order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::NotPending` once the order is paid,
+    /// `OrderError::CurrencyMismatch` if the line is priced in another
+    /// currency, or `OrderError::DuplicateCourse` if the course is already
+    /// in the order.
+    pub fn add_line(&mut self, line: OrderLine) -> Result<(), OrderError> {
+        self.ensure_pending()?;
+        self.ensure_currency(line.price().currency())?;
+        if self
+            .lines
+            .iter()
+            .any(|existing| existing.course_id() == line.course_id())
+        {
+            return Err(OrderError::DuplicateCourse(line.course_id().to_string()));
+        }
+
+        self.lines.push(line);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes a course from the order.
+    ///
+    /// The applied coupon is dropped when none of the remaining courses is
+    /// one it covers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::NotPending` once the order is paid, or
+    /// `OrderError::CourseNotInOrder` if the course is not in the order.
+    pub fn remove_line(&mut self, course_id: CourseId) -> Result<(), OrderError> {
+        self.ensure_pending()?;
+        let position = self
+            .lines
+            .iter()
+            .position(|line| line.course_id() == course_id)
+            .ok_or(OrderError::CourseNotInOrder)?;
+
+        self.lines.remove(position);
+        if let Some(coupon) = &self.coupon
+            && !self
+                .lines
+                .iter()
+                .any(|line| coupon.covers(line.course_id()))
+        {
+            self.coupon = None;
+        }
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Applies a coupon's discount to the order.
+    ///
+    /// The coupon is checked for the customer but not redeemed yet; that
+    /// happens in [`Order::pay`], which needs the same coupon back.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::NotPending` once the order is paid,
+    /// `OrderError::CouponAlreadyApplied` if the order has a coupon,
+    /// `OrderError::CurrencyMismatch` if the coupon takes off a fixed amount
+    /// in another currency, `OrderError::CouponNotApplicable` if the coupon
+    /// covers none of the courses, or `OrderError::CouponError` if the
+    /// customer cannot use it.
+    pub fn apply_coupon(
+        &mut self,
+        coupon: &Coupon,
+        code: &str,
+        on: Date,
+    ) -> Result<(), OrderError> {
+        self.ensure_pending()?;
+        if self.coupon.is_some() {
+            return Err(OrderError::CouponAlreadyApplied);
+        }
+        if let Some(currency) = coupon.discount().currency() {
+            self.ensure_currency(currency)?;
+        }
+        let course_id = self
+            .first_course_covered_by(coupon)
+            .ok_or(OrderError::CouponNotApplicable)?;
+
+        let discount = coupon.check(code, &self.customer, course_id, on)?;
+        self.coupon = Some(AppliedCoupon {
+            coupon_id: coupon.coupon_id(),
+            code: coupon.code().clone(),
+            discount,
+            courses: coupon.courses().to_vec(),
+        });
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Works out what the order costs now, without paying for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::CurrencyMismatch` if the applied coupon takes off
+    /// a fixed amount in another currency, or `OrderError::MoneyError` if
+    /// the tax policy answers in another currency or an amount overflows.
+    pub fn quote(&self, taxes: &impl TaxPolicy) -> Result<OrderTotals, OrderError> {
+        let prices = |covered: bool| {
+            self.lines
+                .iter()
+                .filter(move |line| {
+                    !covered
+                        || self
+                            .coupon
+                            .as_ref()
+                            .is_some_and(|c| c.covers(line.course_id()))
+                })
+                .map(OrderLine::price)
+        };
+        let subtotal = Money::sum(self.currency, prices(false))?;
+        let discount = match &self.coupon {
+            Some(coupon) => {
+                if let Some(currency) = coupon.discount().currency() {
+                    self.ensure_currency(currency)?;
+                }
+                let covered = Money::sum(self.currency, prices(true))?;
+                covered.saturating_sub(coupon.discount().apply(covered)?)?
+            }
+            None => Money::zero(self.currency),
+        };
+        let taxable = subtotal.saturating_sub(discount)?;
+        let tax = taxes.tax(&self.lines, taxable);
+
+        Ok(OrderTotals {
+            subtotal,
+            discount,
+            tax,
+            total: taxable.checked_add(tax)?,
+        })
+    }
+
+    /// Charges the customer and marks the order as paid, fixing its totals.
+    ///
+    /// An order with a coupon applied needs that coupon back: it is checked
+    /// again before the charge and redeemed, against the first course of
+    /// the order it covers, once the charge went through.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::NotPending` if the order was already paid,
+    /// `OrderError::EmptyOrder` if it has no lines,
+    /// `OrderError::CouponMismatch` if `coupon` is not the applied one,
+    /// `OrderError::CouponError` if the coupon can no longer be used, the
+    /// errors of [`Order::quote`], or `OrderError::PaymentError` if the
+    /// charge fails. The order stays pending and the coupon unused on error.
+    pub fn pay(
+        &mut self,
+        gateway: &mut impl PaymentGateway,
+        taxes: &impl TaxPolicy,
+        coupon: Option<&mut Coupon>,
+        at: DateTime,
+    ) -> Result<&OrderTotals, OrderError> {
+        self.ensure_pending()?;
+        if self.lines.is_empty() {
+            return Err(OrderError::EmptyOrder);
+        }
+
+        let redemption = match (&self.coupon, coupon) {
+            (None, None) => None,
+            (Some(applied), Some(coupon)) if applied.coupon_id() == coupon.coupon_id() => {
+                let course_id = self
+                    .first_course_covered_by(coupon)
+                    .ok_or(OrderError::CouponNotApplicable)?;
+                coupon.check(applied.code().as_str(), &self.customer, course_id, at.date())?;
+                Some((coupon, course_id))
+            }
+            _ => return Err(OrderError::CouponMismatch),
+        };
+
+        let totals = self.quote(taxes)?;
+        if !totals.total().is_zero() {
+            self.receipt = Some(gateway.charge(&self.customer, totals.total(), self.id)?);
+        }
+        if let Some((coupon, course_id)) = redemption {
+            // Checked above with the same arguments while holding the only
+            // reference to the coupon, so this cannot be refused.
+            let code = coupon.code().as_str().to_string();
+            coupon.redeem(&code, &self.customer, course_id, at.date())?;
+        }
+        self.status = OrderStatus::Paid { at };
+        self.timestamps.touch();
+        Ok(self.totals.insert(totals))
+    }
+
+    /// Gives the payment back to the customer and marks the order as
+    /// refunded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::NotPaid` unless the order is paid, or
+    /// `OrderError::PaymentError` if the gateway refuses the refund; the
+    /// order stays paid on error.
+    pub fn refund(
+        &mut self,
+        gateway: &mut impl PaymentGateway,
+        at: DateTime,
+    ) -> Result<(), OrderError> {
+        if !matches!(self.status, OrderStatus::Paid { .. }) {
+            return Err(OrderError::NotPaid(self.status.as_str().to_string()));
+        }
+        if let Some(receipt) = &self.receipt {
+            gateway.refund(receipt)?;
+        }
+
+        self.status = OrderStatus::Refunded { at };
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    fn first_course_covered_by(&self, coupon: &Coupon) -> Option<CourseId> {
+        self.lines
+            .iter()
+            .map(OrderLine::course_id)
+            .find(|course_id| coupon.applies_to(*course_id))
+    }
+
+    fn ensure_pending(&self) -> Result<(), OrderError> {
+        match self.status {
+            OrderStatus::Pending => Ok(()),
+            status => Err(OrderError::NotPending(status.as_str().to_string())),
+        }
+    }
+
+    fn ensure_currency(&self, currency: Currency) -> Result<(), OrderError> {
+        match currency == self.currency {
+            true => Ok(()),
+            false => Err(OrderError::CurrencyMismatch {
+                order: self.currency.to_string(),
+                found: currency.to_string(),
+            }),
+        }
+    }
+}
+
+impl Entity for Order {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Auditable for Order {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FlatTax, MockPaymentGateway, NoTax};
+    use education_platform_common::{DateRange, Percentage};
+
+    fn usd() -> Currency {
+        Currency::new("USD").unwrap()
+    }
+
+    fn customer() -> Email {
+        Email::new("ana@example.com".to_string()).unwrap()
+    }
+
+    fn today() -> Date {
+        Date::new(2025, 3, 10).unwrap()
+    }
+
+    fn at() -> DateTime {
+        DateTime::new(2025, 3, 10, 12, 0, 0).unwrap()
+    }
+
+    fn line(course_id: CourseId, amount: u64) -> OrderLine {
+        OrderLine::new(course_id, "Rust Programming".to_string(), Money::new(amount, usd()))
+            .unwrap()
+    }
+
+    fn amount_off(amount: u64) -> Discount {
+        Discount::Amount(Money::new(amount, usd()))
+    }
+
+    fn create_test_coupon(discount: Discount, courses: Vec<CourseId>) -> Coupon {
+        let validity = DateRange::new(today(), today().add_days(30)).unwrap();
+        Coupon::new("SPRING", discount, 10, validity, courses).unwrap()
+    }
+
+    fn create_test_order(amounts: &[u64]) -> (Order, Vec<CourseId>) {
+        let mut order = Order::new(customer(), usd());
+        let courses: Vec<CourseId> = amounts.iter().map(|_| Id::new().cast()).collect();
+        for (course_id, amount) in courses.iter().zip(amounts) {
+            order.add_line(line(*course_id, *amount)).unwrap();
+        }
+        (order, courses)
+    }
+
+    mod add_line {
+        use super::*;
+
+        #[test]
+        fn test_other_currency_is_rejected() {
+            let mut order = Order::new(customer(), usd());
+            let euros = Money::new(100, Currency::new("EUR").unwrap());
+            let line =
+                OrderLine::new(Id::new().cast(), "Rust Programming".to_string(), euros).unwrap();
+
+            let result = order.add_line(line);
+
+            assert!(matches!(result, Err(OrderError::CurrencyMismatch { .. })));
+            assert!(order.lines().is_empty());
+        }
+
+        #[test]
+        fn test_duplicate_course_is_rejected() {
+            let (mut order, courses) = create_test_order(&[1_000]);
+
+            let result = order.add_line(line(courses[0], 2_000));
+
+            assert_eq!(result, Err(OrderError::DuplicateCourse(courses[0].to_string())));
+        }
+
+        #[test]
+        fn test_paid_order_cannot_change() {
+            let (mut order, courses) = create_test_order(&[1_000]);
+            order
+                .pay(&mut MockPaymentGateway::new(), &NoTax, None, at())
+                .unwrap();
+
+            assert_eq!(
+                order.add_line(line(Id::new().cast(), 500)),
+                Err(OrderError::NotPending("paid".to_string()))
+            );
+            assert_eq!(
+                order.remove_line(courses[0]),
+                Err(OrderError::NotPending("paid".to_string()))
+            );
+        }
+    }
+
+    mod remove_line {
+        use super::*;
+
+        #[test]
+        fn test_drops_coupon_without_covered_courses() {
+            let (mut order, courses) = create_test_order(&[1_000, 2_000]);
+            let coupon = create_test_coupon(amount_off(300), vec![courses[1]]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+
+            order.remove_line(courses[1]).unwrap();
+
+            assert!(order.coupon().is_none());
+            assert!(order.quote(&NoTax).unwrap().discount().is_zero());
+            assert!(
+                order
+                    .pay(&mut MockPaymentGateway::new(), &NoTax, None, at())
+                    .is_ok()
+            );
+        }
+
+        #[test]
+        fn test_keeps_coupon_while_a_course_is_covered() {
+            let (mut order, courses) = create_test_order(&[1_000, 2_000]);
+            let coupon = create_test_coupon(amount_off(300), vec![]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+
+            order.remove_line(courses[0]).unwrap();
+
+            assert!(order.coupon().is_some());
+        }
+
+        #[test]
+        fn test_removes_course() {
+            let (mut order, courses) = create_test_order(&[1_000, 2_000]);
+
+            order.remove_line(courses[0]).unwrap();
+
+            assert_eq!(order.lines().len(), 1);
+            assert_eq!(order.lines()[0].course_id(), courses[1]);
+            assert_eq!(order.remove_line(courses[0]), Err(OrderError::CourseNotInOrder));
+        }
+    }
+
+    mod apply_coupon {
+        use super::*;
+
+        #[test]
+        fn test_applies_without_spending_a_use() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let coupon = create_test_coupon(amount_off(300), vec![]);
+
+            order.apply_coupon(&coupon, "spring", today()).unwrap();
+
+            assert_eq!(order.coupon().unwrap().code().as_str(), "SPRING");
+            assert_eq!(order.coupon().unwrap().coupon_id(), coupon.coupon_id());
+            assert!(!coupon.has_redeemed(&customer()));
+        }
+
+        #[test]
+        fn test_second_coupon_is_rejected() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let first = create_test_coupon(amount_off(300), vec![]);
+            let second = create_test_coupon(amount_off(300), vec![]);
+            order.apply_coupon(&first, "SPRING", today()).unwrap();
+
+            let result = order.apply_coupon(&second, "SPRING", today());
+
+            assert_eq!(result, Err(OrderError::CouponAlreadyApplied));
+            assert!(second.redemptions().is_empty());
+        }
+
+        #[test]
+        fn test_coupon_for_other_courses() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let coupon = create_test_coupon(amount_off(300), vec![Id::new().cast()]);
+
+            let result = order.apply_coupon(&coupon, "SPRING", today());
+
+            assert_eq!(result, Err(OrderError::CouponNotApplicable));
+        }
+
+        #[test]
+        fn test_amount_in_other_currency_is_rejected() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let euros = Money::new(300, Currency::new("EUR").unwrap());
+            let coupon = create_test_coupon(Discount::Amount(euros), vec![]);
+
+            let result = order.apply_coupon(&coupon, "SPRING", today());
+
+            assert_eq!(
+                result,
+                Err(OrderError::CurrencyMismatch {
+                    order: "USD".to_string(),
+                    found: "EUR".to_string(),
+                })
+            );
+            assert!(order.coupon().is_none());
+        }
+
+        #[test]
+        fn test_refused_redemption() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let coupon = create_test_coupon(amount_off(300), vec![]);
+
+            let result = order.apply_coupon(&coupon, "SPRING", today().add_days(31));
+
+            assert!(matches!(
+                result,
+                Err(OrderError::CouponError(CouponError::Expired(_)))
+            ));
+            assert!(order.coupon().is_none());
+        }
+    }
+
+    mod quote {
+        use super::*;
+
+        #[test]
+        fn test_without_coupon_or_tax() {
+            let (order, _) = create_test_order(&[1_000, 2_500]);
+
+            let totals = order.quote(&NoTax).unwrap();
+
+            assert_eq!(totals.subtotal().amount(), 3_500);
+            assert!(totals.discount().is_zero());
+            assert!(totals.tax().is_zero());
+            assert_eq!(totals.total().amount(), 3_500);
+        }
+
+        #[test]
+        fn test_discount_only_covers_its_courses() {
+            let (mut order, courses) = create_test_order(&[1_000, 2_000]);
+            let half_off = Discount::Percent(Percentage::new(50.0).unwrap());
+            let coupon = create_test_coupon(half_off, vec![courses[1]]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+
+            let totals = order.quote(&NoTax).unwrap();
+
+            assert_eq!(totals.discount().amount(), 1_000);
+            assert_eq!(totals.total().amount(), 2_000);
+        }
+
+        #[test]
+        fn test_fixed_discount_stops_at_covered_price() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let coupon = create_test_coupon(amount_off(5_000), vec![]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+
+            let totals = order.quote(&NoTax).unwrap();
+
+            assert_eq!(totals.discount().amount(), 1_000);
+            assert!(totals.total().is_zero());
+        }
+
+        #[test]
+        fn test_tax_on_discounted_amount() {
+            let (mut order, _) = create_test_order(&[10_000]);
+            let coupon = create_test_coupon(amount_off(2_000), vec![]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+
+            let totals = order
+                .quote(&FlatTax::new(Percentage::new(21.0).unwrap()))
+                .unwrap();
+
+            assert_eq!(totals.tax().amount(), 1_680);
+            assert_eq!(totals.total().amount(), 9_680);
+        }
+
+        #[test]
+        fn test_applied_amount_in_other_currency() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let euros = Money::new(300, Currency::new("EUR").unwrap());
+            let coupon = create_test_coupon(Discount::Amount(euros), vec![]);
+            order.coupon = Some(AppliedCoupon {
+                coupon_id: coupon.coupon_id(),
+                code: coupon.code().clone(),
+                discount: coupon.discount(),
+                courses: Vec::new(),
+            });
+
+            assert!(matches!(
+                order.quote(&NoTax),
+                Err(OrderError::CurrencyMismatch { .. })
+            ));
+        }
+
+        #[test]
+        fn test_tax_in_other_currency() {
+            struct EuroTax;
+
+            impl TaxPolicy for EuroTax {
+                fn tax(&self, _lines: &[OrderLine], _taxable: Money) -> Money {
+                    Money::new(100, Currency::new("EUR").unwrap())
+                }
+            }
+
+            let (order, _) = create_test_order(&[1_000]);
+
+            assert!(matches!(
+                order.quote(&EuroTax),
+                Err(OrderError::MoneyError(MoneyError::CurrencyMismatch { .. }))
+            ));
+        }
+    }
+
+    mod pay {
+        use super::*;
+
+        #[test]
+        fn test_charges_total() {
+            let (mut order, _) = create_test_order(&[4_999]);
+            let mut gateway = MockPaymentGateway::new();
+
+            let total = order.pay(&mut gateway, &NoTax, None, at()).unwrap().total();
+
+            assert_eq!(total.amount(), 4_999);
+            assert_eq!(gateway.charges()[0].amount(), total);
+            assert_eq!(order.receipt(), Some(&gateway.charges()[0]));
+            assert_eq!(order.status(), OrderStatus::Paid { at: at() });
+            assert_eq!(order.totals().unwrap().total(), total);
+        }
+
+        #[test]
+        fn test_empty_order() {
+            let mut order = Order::new(customer(), usd());
+
+            let result = order.pay(&mut MockPaymentGateway::new(), &NoTax, None, at());
+
+            assert_eq!(result, Err(OrderError::EmptyOrder));
+        }
+
+        #[test]
+        fn test_declined_payment_keeps_order_pending() {
+            let (mut order, _) = create_test_order(&[4_999]);
+            let mut gateway = MockPaymentGateway::declining("insufficient funds");
+
+            let result = order.pay(&mut gateway, &NoTax, None, at());
+
+            assert_eq!(
+                result,
+                Err(OrderError::PaymentError(PaymentError::Declined(
+                    "insufficient funds".to_string()
+                )))
+            );
+            assert_eq!(order.status(), OrderStatus::Pending);
+            assert!(order.totals().is_none());
+        }
+
+        #[test]
+        fn test_free_order_skips_gateway() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let mut coupon = create_test_coupon(Discount::Percent(Percentage::FULL), vec![]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+            let mut gateway = MockPaymentGateway::declining("unused");
+
+            order
+                .pay(&mut gateway, &NoTax, Some(&mut coupon), at())
+                .unwrap();
+
+            assert!(order.receipt().is_none());
+            assert_eq!(order.status(), OrderStatus::Paid { at: at() });
+        }
+
+        #[test]
+        fn test_redeems_coupon_after_charge() {
+            let (mut order, courses) = create_test_order(&[1_000, 2_000]);
+            let mut coupon = create_test_coupon(amount_off(300), vec![courses[1]]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+
+            order
+                .pay(&mut MockPaymentGateway::new(), &NoTax, Some(&mut coupon), at())
+                .unwrap();
+
+            assert!(coupon.has_redeemed(&customer()));
+            assert_eq!(coupon.redemptions()[0].course_id(), courses[1]);
+            assert_eq!(coupon.redemptions()[0].redeemed_on(), at().date());
+        }
+
+        #[test]
+        fn test_declined_payment_keeps_coupon_unused() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let mut coupon = create_test_coupon(amount_off(300), vec![]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+            let mut declining = MockPaymentGateway::declining("insufficient funds");
+
+            let result = order.pay(&mut declining, &NoTax, Some(&mut coupon), at());
+
+            assert!(matches!(result, Err(OrderError::PaymentError(_))));
+            assert!(coupon.redemptions().is_empty());
+            order
+                .pay(&mut MockPaymentGateway::new(), &NoTax, Some(&mut coupon), at())
+                .unwrap();
+            assert_eq!(coupon.redemptions().len(), 1);
+        }
+
+        #[test]
+        fn test_coupon_used_up_before_payment() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let validity = DateRange::new(today(), today().add_days(30)).unwrap();
+            let mut coupon = Coupon::new("SPRING", amount_off(300), 1, validity, vec![]).unwrap();
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+            let bea = Email::new("bea@example.com".to_string()).unwrap();
+            coupon
+                .redeem("SPRING", &bea, order.lines()[0].course_id(), today())
+                .unwrap();
+            let mut gateway = MockPaymentGateway::new();
+
+            let result = order.pay(&mut gateway, &NoTax, Some(&mut coupon), at());
+
+            assert_eq!(
+                result,
+                Err(OrderError::CouponError(CouponError::UsageLimitReached(1)))
+            );
+            assert!(gateway.charges().is_empty());
+            assert_eq!(order.status(), OrderStatus::Pending);
+        }
+
+        #[test]
+        fn test_applied_coupon_must_be_given_back() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let coupon = create_test_coupon(amount_off(300), vec![]);
+            let mut other = create_test_coupon(amount_off(300), vec![]);
+            order.apply_coupon(&coupon, "SPRING", today()).unwrap();
+            let mut gateway = MockPaymentGateway::new();
+
+            assert_eq!(
+                order.pay(&mut gateway, &NoTax, None, at()),
+                Err(OrderError::CouponMismatch)
+            );
+            assert_eq!(
+                order.pay(&mut gateway, &NoTax, Some(&mut other), at()),
+                Err(OrderError::CouponMismatch)
+            );
+            assert!(gateway.charges().is_empty());
+        }
+
+        #[test]
+        fn test_cannot_pay_twice() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let mut gateway = MockPaymentGateway::new();
+            order.pay(&mut gateway, &NoTax, None, at()).unwrap();
+
+            let result = order.pay(&mut gateway, &NoTax, None, at());
+
+            assert_eq!(result, Err(OrderError::NotPending("paid".to_string())));
+            assert_eq!(gateway.charges().len(), 1);
+        }
+    }
+
+    mod refund {
+        use super::*;
+
+        #[test]
+        fn test_refunds_paid_order() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let mut gateway = MockPaymentGateway::new();
+            order.pay(&mut gateway, &NoTax, None, at()).unwrap();
+            let later = DateTime::new(2025, 3, 12, 9, 0, 0).unwrap();
+
+            order.refund(&mut gateway, later).unwrap();
+
+            assert_eq!(order.status(), OrderStatus::Refunded { at: later });
+            assert_eq!(
+                gateway.refunds(),
+                &[gateway.charges()[0].transaction_id().to_string()]
+            );
+        }
+
+        #[test]
+        fn test_pending_order_cannot_be_refunded() {
+            let (mut order, _) = create_test_order(&[1_000]);
+
+            let result = order.refund(&mut MockPaymentGateway::new(), at());
+
+            assert_eq!(result, Err(OrderError::NotPaid("pending".to_string())));
+        }
+
+        #[test]
+        fn test_refunded_order_cannot_be_refunded_again() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            let mut gateway = MockPaymentGateway::new();
+            order.pay(&mut gateway, &NoTax, None, at()).unwrap();
+            order.refund(&mut gateway, at()).unwrap();
+
+            let result = order.refund(&mut gateway, at());
+
+            assert_eq!(result, Err(OrderError::NotPaid("refunded".to_string())));
+            assert_eq!(gateway.refunds().len(), 1);
+        }
+
+        #[test]
+        fn test_gateway_refusal_keeps_order_paid() {
+            let (mut order, _) = create_test_order(&[1_000]);
+            order
+                .pay(&mut MockPaymentGateway::new(), &NoTax, None, at())
+                .unwrap();
+
+            let result = order.refund(&mut MockPaymentGateway::new(), at());
+
+            assert!(matches!(
+                result,
+                Err(OrderError::PaymentError(PaymentError::UnknownTransaction(_)))
+            ));
+            assert_eq!(order.status(), OrderStatus::Paid { at: at() });
+        }
+    }
+}
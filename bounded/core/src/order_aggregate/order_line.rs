@@ -0,0 +1,60 @@
+use crate::CourseId;
+use education_platform_common::{Money, SimpleName, SimpleNameConfig, SimpleNameError};
+
+/// One course being bought in an [`Order`](crate::Order), at the price it
+/// had when it was added.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::OrderLine;
+/// use education_platform_common::{Currency, Id, Money};
+///
+/// let price = Money::new(4_999, Currency::new("USD").unwrap());
+/// let line = OrderLine::new(Id::new().cast(), "Rust Programming".to_string(), price).unwrap();
+///
+/// assert_eq!(line.title().as_str(), "Rust Programming");
+/// assert_eq!(line.price().amount(), 4_999);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderLine {
+    course_id: CourseId,
+    title: SimpleName,
+    price: Money,
+}
+
+impl OrderLine {
+    /// Creates a line for a course.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SimpleNameError` if the title validation fails.
+    pub fn new(course_id: CourseId, title: String, price: Money) -> Result<Self, SimpleNameError> {
+        Ok(Self {
+            course_id,
+            title: SimpleName::with_config(title, SimpleNameConfig::new(3, 50))?,
+            price,
+        })
+    }
+
+    /// Returns the course being bought.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> CourseId {
+        self.course_id
+    }
+
+    /// Returns the course title shown on the order.
+    #[inline]
+    #[must_use]
+    pub const fn title(&self) -> &SimpleName {
+        &self.title
+    }
+
+    /// Returns the price of the course.
+    #[inline]
+    #[must_use]
+    pub const fn price(&self) -> Money {
+        self.price
+    }
+}
@@ -0,0 +1,162 @@
+use education_platform_common::{Email, Id, Money};
+use thiserror::Error;
+
+/// Error types for payment failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PaymentError {
+    #[error("Payment was declined: {0}")]
+    Declined(String),
+
+    #[error("Payment gateway is unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Transaction {0} is unknown or already refunded")]
+    UnknownTransaction(String),
+}
+
+/// Proof that a payment went through, as returned by a [`PaymentGateway`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentReceipt {
+    transaction_id: String,
+    amount: Money,
+}
+
+impl PaymentReceipt {
+    /// Creates a receipt for a transaction the gateway accepted.
+    #[must_use]
+    pub const fn new(transaction_id: String, amount: Money) -> Self {
+        Self {
+            transaction_id,
+            amount,
+        }
+    }
+
+    /// Returns the gateway's reference for the transaction.
+    #[inline]
+    #[must_use]
+    pub fn transaction_id(&self) -> &str {
+        &self.transaction_id
+    }
+
+    /// Returns the amount charged.
+    #[inline]
+    #[must_use]
+    pub const fn amount(&self) -> Money {
+        self.amount
+    }
+}
+
+/// Port charging and refunding customers through a payment provider.
+pub trait PaymentGateway {
+    /// Charges the customer for an order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaymentError::Declined` if the provider refuses the charge,
+    /// or `PaymentError::Unavailable` if it cannot be reached.
+    fn charge(
+        &mut self,
+        customer: &Email,
+        amount: Money,
+        order_id: Id,
+    ) -> Result<PaymentReceipt, PaymentError>;
+
+    /// Gives back the whole amount of a previous charge.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaymentError::UnknownTransaction` if the charge does not
+    /// exist or was already refunded, or `PaymentError::Unavailable` if the
+    /// provider cannot be reached.
+    fn refund(&mut self, receipt: &PaymentReceipt) -> Result<(), PaymentError>;
+}
+
+/// In-process [`PaymentGateway`] for tests and demos, accepting every
+/// charge unless told to decline them.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{MockPaymentGateway, PaymentGateway};
+/// use education_platform_common::{Currency, Email, Id, Money};
+///
+/// let customer = Email::new("ana@example.com".to_string()).unwrap();
+/// let amount = Money::new(4_999, Currency::new("USD").unwrap());
+/// let mut gateway = MockPaymentGateway::new();
+///
+/// let receipt = gateway.charge(&customer, amount, Id::new()).unwrap();
+/// gateway.refund(&receipt).unwrap();
+///
+/// assert!(gateway.refund(&receipt).is_err());
+/// assert!(MockPaymentGateway::declining("card expired").charge(&customer, amount, Id::new()).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockPaymentGateway {
+    decline_reason: Option<String>,
+    charges: Vec<PaymentReceipt>,
+    refunds: Vec<String>,
+}
+
+impl MockPaymentGateway {
+    /// Creates a gateway accepting every charge.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a gateway declining every charge for `reason`.
+    #[must_use]
+    pub fn declining(reason: impl Into<String>) -> Self {
+        Self {
+            decline_reason: Some(reason.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the accepted charges, oldest first.
+    #[inline]
+    #[must_use]
+    pub fn charges(&self) -> &[PaymentReceipt] {
+        &self.charges
+    }
+
+    /// Returns the transaction IDs refunded, oldest first.
+    #[inline]
+    #[must_use]
+    pub fn refunds(&self) -> &[String] {
+        &self.refunds
+    }
+}
+
+impl PaymentGateway for MockPaymentGateway {
+    fn charge(
+        &mut self,
+        _customer: &Email,
+        amount: Money,
+        order_id: Id,
+    ) -> Result<PaymentReceipt, PaymentError> {
+        if let Some(reason) = &self.decline_reason {
+            return Err(PaymentError::Declined(reason.clone()));
+        }
+
+        let receipt =
+            PaymentReceipt::new(format!("mock-{}-{}", self.charges.len() + 1, order_id), amount);
+        self.charges.push(receipt.clone());
+        Ok(receipt)
+    }
+
+    fn refund(&mut self, receipt: &PaymentReceipt) -> Result<(), PaymentError> {
+        let id = receipt.transaction_id();
+        let charged = self
+            .charges
+            .iter()
+            .any(|charge| charge.transaction_id() == id);
+        if !charged || self.refunds.iter().any(|refunded| refunded == id) {
+            return Err(PaymentError::UnknownTransaction(id.to_string()));
+        }
+
+        self.refunds.push(id.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::OrderLine;
+use education_platform_common::{Money, Percentage};
+
+/// Hook computing the tax owed on an [`Order`](crate::Order).
+///
+/// The order hands over its lines and the amount left after discounts;
+/// implementations can apply a flat rate, or look up rates by course or
+/// by the customer's country.
+pub trait TaxPolicy {
+    /// Returns the tax owed on `taxable`, in its currency.
+    fn tax(&self, lines: &[OrderLine], taxable: Money) -> Money;
+}
+
+/// [`TaxPolicy`] for sales where no tax applies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoTax;
+
+impl TaxPolicy for NoTax {
+    fn tax(&self, _lines: &[OrderLine], taxable: Money) -> Money {
+        Money::zero(taxable.currency())
+    }
+}
+
+/// [`TaxPolicy`] charging the same rate on every order, such as a VAT.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{FlatTax, TaxPolicy};
+/// use education_platform_common::{Currency, Money, Percentage};
+///
+/// let vat = FlatTax::new(Percentage::new(19.0).unwrap());
+/// let taxable = Money::new(10_000, Currency::new("EUR").unwrap());
+///
+/// assert_eq!(vat.tax(&[], taxable).amount(), 1_900);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlatTax {
+    rate: Percentage,
+}
+
+impl FlatTax {
+    /// Creates a policy charging `rate` of the taxable amount.
+    #[must_use]
+    pub const fn new(rate: Percentage) -> Self {
+        Self { rate }
+    }
+
+    /// Returns the rate charged.
+    #[inline]
+    #[must_use]
+    pub const fn rate(&self) -> Percentage {
+        self.rate
+    }
+}
+
+impl TaxPolicy for FlatTax {
+    fn tax(&self, _lines: &[OrderLine], taxable: Money) -> Money {
+        taxable.percentage(self.rate)
+    }
+}
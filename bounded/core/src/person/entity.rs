@@ -64,7 +64,7 @@ impl Person {
     /// ).unwrap();
     ///
     /// assert_eq!(person.name().first_name(), "María");
-    /// assert_eq!(person.name().last_name(), "García");
+    /// assert_eq!(person.name().last_name(), Some("García"));
     ///
     /// // Invalid DNI returns error
     /// let invalid = Person::new(
@@ -195,7 +195,7 @@ mod tests {
         assert!(person.is_ok());
         let person = person.unwrap();
         assert_eq!(person.name().first_name(), "John");
-        assert_eq!(person.name().last_name(), "Doe");
+        assert_eq!(person.name().last_name(), Some("Doe"));
         assert_eq!(person.document().value(), "12345678");
     }
 
@@ -271,7 +271,7 @@ mod tests {
 
         assert_eq!(person.name().first_name(), "John");
         assert_eq!(person.name().middle_name(), Some("Michael"));
-        assert_eq!(person.name().last_name(), "Doe");
+        assert_eq!(person.name().last_name(), Some("Doe"));
     }
 
     #[test]
@@ -497,7 +497,7 @@ mod tests {
 
         assert_eq!(person.name().first_name(), "John");
         assert_eq!(person.name().middle_name(), Some("Michael"));
-        assert_eq!(person.name().last_name(), "Doe");
+        assert_eq!(person.name().last_name(), Some("Doe"));
         assert_eq!(person.document().value(), "12345678");
     }
 
@@ -222,7 +222,7 @@ mod tests {
         assert_eq!(older_persons[0].id(), person1_id);
         assert_eq!(older_persons[0].name().first_name(), "John");
         assert_eq!(older_persons[0].name().middle_name(), Some("Michael"));
-        assert_eq!(older_persons[0].name().last_name(), "Doe");
+        assert_eq!(older_persons[0].name().last_name(), Some("Doe"));
         assert_eq!(older_persons[0].document().value(), "12345678");
     }
 
@@ -0,0 +1,5 @@
+mod learner_presence;
+mod presence_tracker;
+
+pub use learner_presence::*;
+pub use presence_tracker::*;
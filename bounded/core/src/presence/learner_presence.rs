@@ -0,0 +1,241 @@
+use education_platform_common::{DateTime, Duration, Email, Id};
+
+/// The last known whereabouts of a learner who is studying.
+///
+/// A presence is refreshed by heartbeats and is considered live until no
+/// heartbeat arrives for longer than the tracker's time to live.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::LearnerPresence;
+/// use education_platform_common::{DateTime, Duration, Email, Id};
+///
+/// let seen = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+/// let presence = LearnerPresence::new(
+///     Email::new("user@example.com".to_string()).unwrap(),
+///     Id::new(),
+///     None,
+///     seen,
+/// );
+///
+/// let ttl = Duration::from_minutes(2);
+/// assert!(presence.is_live_at(seen.add_seconds(60), ttl));
+/// assert!(!presence.is_live_at(seen.add_seconds(180), ttl));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LearnerPresence {
+    user_email: Email,
+    course_id: Id,
+    lesson_id: Option<Id>,
+    last_seen: DateTime,
+}
+
+impl LearnerPresence {
+    /// Creates a presence seen at the given instant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerPresence;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let lesson_id = Id::new();
+    /// let presence = LearnerPresence::new(
+    ///     Email::new("user@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    ///     Some(lesson_id),
+    ///     DateTime::new(2024, 12, 9, 18, 0, 0).unwrap(),
+    /// );
+    ///
+    /// assert_eq!(presence.lesson_id(), Some(lesson_id));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        user_email: Email,
+        course_id: Id,
+        lesson_id: Option<Id>,
+        last_seen: DateTime,
+    ) -> Self {
+        Self {
+            user_email,
+            course_id,
+            lesson_id,
+            last_seen,
+        }
+    }
+
+    /// Returns the email of the learner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerPresence;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let presence = LearnerPresence::new(
+    ///     Email::new("user@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    ///     None,
+    ///     DateTime::new(2024, 12, 9, 18, 0, 0).unwrap(),
+    /// );
+    ///
+    /// assert_eq!(presence.user_email().address(), "user@example.com");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn user_email(&self) -> &Email {
+        &self.user_email
+    }
+
+    /// Returns the ID of the course being studied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerPresence;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let course_id = Id::new();
+    /// let presence = LearnerPresence::new(
+    ///     Email::new("user@example.com".to_string()).unwrap(),
+    ///     course_id,
+    ///     None,
+    ///     DateTime::new(2024, 12, 9, 18, 0, 0).unwrap(),
+    /// );
+    ///
+    /// assert_eq!(presence.course_id(), course_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns the ID of the lesson being studied, if the learner is inside one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerPresence;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let presence = LearnerPresence::new(
+    ///     Email::new("user@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    ///     None,
+    ///     DateTime::new(2024, 12, 9, 18, 0, 0).unwrap(),
+    /// );
+    ///
+    /// assert!(presence.lesson_id().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> Option<Id> {
+        self.lesson_id
+    }
+
+    /// Returns when the last heartbeat was received.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerPresence;
+    /// use education_platform_common::{DateTime, Email, Id};
+    ///
+    /// let seen = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let presence = LearnerPresence::new(
+    ///     Email::new("user@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    ///     None,
+    ///     seen,
+    /// );
+    ///
+    /// assert_eq!(presence.last_seen(), seen);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn last_seen(&self) -> DateTime {
+        self.last_seen
+    }
+
+    /// Returns true if a heartbeat was received within `ttl` of `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerPresence;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let seen = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let presence = LearnerPresence::new(
+    ///     Email::new("user@example.com".to_string()).unwrap(),
+    ///     Id::new(),
+    ///     None,
+    ///     seen,
+    /// );
+    ///
+    /// assert!(presence.is_live_at(seen.add_seconds(30), Duration::from_seconds(30)));
+    /// assert!(!presence.is_live_at(seen.add_seconds(31), Duration::from_seconds(30)));
+    /// ```
+    #[must_use]
+    pub fn is_live_at(&self, now: DateTime, ttl: Duration) -> bool {
+        let elapsed = self.last_seen.seconds_until(&now);
+        u64::try_from(elapsed).map_or(true, |elapsed| elapsed <= ttl.total_seconds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_presence(last_seen: DateTime) -> LearnerPresence {
+        LearnerPresence::new(
+            Email::new("user@example.com".to_string()).unwrap(),
+            Id::new(),
+            None,
+            last_seen,
+        )
+    }
+
+    mod is_live_at {
+        use super::*;
+
+        #[test]
+        fn test_live_within_ttl() {
+            let seen = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+
+            assert!(
+                create_presence(seen).is_live_at(seen.add_seconds(59), Duration::from_minutes(1))
+            );
+        }
+
+        #[test]
+        fn test_live_exactly_at_ttl() {
+            let seen = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+
+            assert!(
+                create_presence(seen).is_live_at(seen.add_seconds(60), Duration::from_minutes(1))
+            );
+        }
+
+        #[test]
+        fn test_expired_after_ttl() {
+            let seen = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+
+            assert!(
+                !create_presence(seen).is_live_at(seen.add_seconds(61), Duration::from_minutes(1))
+            );
+        }
+
+        #[test]
+        fn test_heartbeat_from_the_future_is_live() {
+            let seen = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+
+            assert!(
+                create_presence(seen).is_live_at(seen.sub_seconds(5), Duration::from_minutes(1))
+            );
+        }
+    }
+}
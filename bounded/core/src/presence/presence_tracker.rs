@@ -0,0 +1,453 @@
+use crate::LearnerPresence;
+use education_platform_common::{DateTime, Duration, Email, Id};
+use std::collections::HashMap;
+
+/// In-memory tracker of who is studying right now.
+///
+/// Clients send a heartbeat every so often while a learner has a course
+/// open. A learner counts as present until no heartbeat arrives for longer
+/// than the time to live, so closed tabs and dropped connections fall off
+/// on their own. Each learner has a single presence: a heartbeat from a
+/// different course or lesson moves them there.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::PresenceTracker;
+/// use education_platform_common::{DateTime, Duration, Email, Id};
+///
+/// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+/// let course_id = Id::new();
+/// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+///
+/// tracker.heartbeat(Email::new("a@example.com".to_string()).unwrap(), course_id, None, now);
+/// tracker.heartbeat(Email::new("b@example.com".to_string()).unwrap(), course_id, None, now);
+///
+/// assert_eq!(tracker.learners_in_course(course_id, now), 2);
+/// assert_eq!(tracker.learners_in_course(course_id, now.add_seconds(300)), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PresenceTracker {
+    ttl: Duration,
+    presences: HashMap<Email, LearnerPresence>,
+}
+
+impl PresenceTracker {
+    /// Creates an empty tracker whose presences expire after `ttl` without a heartbeat.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::Duration;
+    ///
+    /// let tracker = PresenceTracker::new(Duration::from_seconds(90));
+    /// assert_eq!(tracker.ttl().total_seconds(), 90);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            presences: HashMap::new(),
+        }
+    }
+
+    /// Returns how long a presence stays live without a heartbeat.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::Duration;
+    ///
+    /// let tracker = PresenceTracker::new(Duration::from_minutes(1));
+    /// assert_eq!(tracker.ttl(), Duration::from_minutes(1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Records that a learner is studying a course, and optionally a lesson in it.
+    ///
+    /// Heartbeats that arrive out of order never move `last_seen` backwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    /// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    ///
+    /// tracker.heartbeat(email.clone(), Id::new(), None, now);
+    /// assert!(tracker.is_present(&email, now));
+    /// ```
+    pub fn heartbeat(
+        &mut self,
+        user_email: Email,
+        course_id: Id,
+        lesson_id: Option<Id>,
+        at: DateTime,
+    ) {
+        let last_seen = self
+            .presences
+            .get(&user_email)
+            .map_or(at, |previous| previous.last_seen().max(at));
+
+        self.presences.insert(
+            user_email.clone(),
+            LearnerPresence::new(user_email, course_id, lesson_id, last_seen),
+        );
+    }
+
+    /// Removes a learner's presence right away, e.g. when they sign out.
+    ///
+    /// Returns true if the learner had a presence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    /// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// tracker.heartbeat(email.clone(), Id::new(), None, now);
+    ///
+    /// assert!(tracker.leave(&email));
+    /// assert!(!tracker.is_present(&email, now));
+    /// ```
+    pub fn leave(&mut self, user_email: &Email) -> bool {
+        self.presences.remove(user_email).is_some()
+    }
+
+    /// Drops every presence that expired by `now` and returns how many were dropped.
+    ///
+    /// Queries already ignore expired presences; this only reclaims memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// tracker.heartbeat(Email::new("user@example.com".to_string()).unwrap(), Id::new(), None, now);
+    ///
+    /// assert_eq!(tracker.expire(now.add_seconds(600)), 1);
+    /// ```
+    pub fn expire(&mut self, now: DateTime) -> usize {
+        let before = self.presences.len();
+        let ttl = self.ttl;
+        self.presences
+            .retain(|_, presence| presence.is_live_at(now, ttl));
+        before - self.presences.len()
+    }
+
+    /// Returns true if the learner has a live presence at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email};
+    ///
+    /// let tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    ///
+    /// assert!(!tracker.is_present(&email, DateTime::new(2024, 12, 9, 18, 0, 0).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn is_present(&self, user_email: &Email, now: DateTime) -> bool {
+        self.presence(user_email, now).is_some()
+    }
+
+    /// Returns the live presence of a learner at `now`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    /// let course_id = Id::new();
+    /// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// tracker.heartbeat(email.clone(), course_id, None, now);
+    ///
+    /// assert_eq!(tracker.presence(&email, now).unwrap().course_id(), course_id);
+    /// ```
+    #[must_use]
+    pub fn presence(&self, user_email: &Email, now: DateTime) -> Option<&LearnerPresence> {
+        self.presences
+            .get(user_email)
+            .filter(|presence| presence.is_live_at(now, self.ttl))
+    }
+
+    /// Returns the live presences in a course at `now`, most recently seen first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let course_id = Id::new();
+    /// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// tracker.heartbeat(Email::new("a@example.com".to_string()).unwrap(), course_id, None, now);
+    /// tracker.heartbeat(
+    ///     Email::new("b@example.com".to_string()).unwrap(),
+    ///     course_id,
+    ///     None,
+    ///     now.add_seconds(10),
+    /// );
+    ///
+    /// let present = tracker.presences_in_course(course_id, now.add_seconds(10));
+    /// assert_eq!(present[0].user_email().address(), "b@example.com");
+    /// ```
+    #[must_use]
+    pub fn presences_in_course(&self, course_id: Id, now: DateTime) -> Vec<&LearnerPresence> {
+        let mut present: Vec<&LearnerPresence> = self
+            .live_presences(now)
+            .filter(|presence| presence.course_id() == course_id)
+            .collect();
+        present.sort_by(|a, b| {
+            b.last_seen()
+                .cmp(&a.last_seen())
+                .then_with(|| a.user_email().address().cmp(b.user_email().address()))
+        });
+        present
+    }
+
+    /// Returns how many learners are studying a course at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let course_id = Id::new();
+    /// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// tracker.heartbeat(Email::new("a@example.com".to_string()).unwrap(), course_id, None, now);
+    ///
+    /// assert_eq!(tracker.learners_in_course(course_id, now), 1);
+    /// assert_eq!(tracker.learners_in_course(Id::new(), now), 0);
+    /// ```
+    #[must_use]
+    pub fn learners_in_course(&self, course_id: Id, now: DateTime) -> usize {
+        self.live_presences(now)
+            .filter(|presence| presence.course_id() == course_id)
+            .count()
+    }
+
+    /// Returns how many learners are inside a lesson at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PresenceTracker;
+    /// use education_platform_common::{DateTime, Duration, Email, Id};
+    ///
+    /// let mut tracker = PresenceTracker::new(Duration::from_minutes(2));
+    /// let course_id = Id::new();
+    /// let lesson_id = Id::new();
+    /// let now = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// tracker.heartbeat(
+    ///     Email::new("a@example.com".to_string()).unwrap(),
+    ///     course_id,
+    ///     Some(lesson_id),
+    ///     now,
+    /// );
+    /// tracker.heartbeat(Email::new("b@example.com".to_string()).unwrap(), course_id, None, now);
+    ///
+    /// assert_eq!(tracker.learners_in_lesson(lesson_id, now), 1);
+    /// ```
+    #[must_use]
+    pub fn learners_in_lesson(&self, lesson_id: Id, now: DateTime) -> usize {
+        self.live_presences(now)
+            .filter(|presence| presence.lesson_id() == Some(lesson_id))
+            .count()
+    }
+
+    fn live_presences(&self, now: DateTime) -> impl Iterator<Item = &LearnerPresence> {
+        self.presences
+            .values()
+            .filter(move |presence| presence.is_live_at(now, self.ttl))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_tracker() -> PresenceTracker {
+        PresenceTracker::new(Duration::from_minutes(2))
+    }
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn at(minute: u32, second: u32) -> DateTime {
+        DateTime::new(2024, 12, 9, 18, minute, second).unwrap()
+    }
+
+    mod heartbeat {
+        use super::*;
+
+        #[test]
+        fn test_heartbeat_makes_learner_present() {
+            let mut tracker = create_tracker();
+            let email = create_email("a@example.com");
+
+            tracker.heartbeat(email.clone(), Id::new(), None, at(0, 0));
+
+            assert!(tracker.is_present(&email, at(1, 0)));
+        }
+
+        #[test]
+        fn test_heartbeat_refreshes_expiry() {
+            let mut tracker = create_tracker();
+            let email = create_email("a@example.com");
+            let course_id = Id::new();
+
+            tracker.heartbeat(email.clone(), course_id, None, at(0, 0));
+            tracker.heartbeat(email.clone(), course_id, None, at(1, 30));
+
+            assert!(tracker.is_present(&email, at(3, 0)));
+        }
+
+        #[test]
+        fn test_heartbeat_moves_learner_to_new_course() {
+            let mut tracker = create_tracker();
+            let email = create_email("a@example.com");
+            let first = Id::new();
+            let second = Id::new();
+
+            tracker.heartbeat(email.clone(), first, None, at(0, 0));
+            tracker.heartbeat(email, second, None, at(0, 10));
+
+            assert_eq!(tracker.learners_in_course(first, at(0, 10)), 0);
+            assert_eq!(tracker.learners_in_course(second, at(0, 10)), 1);
+        }
+
+        #[test]
+        fn test_out_of_order_heartbeat_keeps_latest_time() {
+            let mut tracker = create_tracker();
+            let email = create_email("a@example.com");
+            let course_id = Id::new();
+            let lesson_id = Id::new();
+
+            tracker.heartbeat(email.clone(), course_id, None, at(1, 0));
+            tracker.heartbeat(email.clone(), course_id, Some(lesson_id), at(0, 0));
+
+            let presence = tracker.presence(&email, at(1, 0)).unwrap();
+            assert_eq!(presence.last_seen(), at(1, 0));
+            assert_eq!(presence.lesson_id(), Some(lesson_id));
+        }
+    }
+
+    mod expiry {
+        use super::*;
+
+        #[test]
+        fn test_presence_expires_after_ttl() {
+            let mut tracker = create_tracker();
+            let email = create_email("a@example.com");
+
+            tracker.heartbeat(email.clone(), Id::new(), None, at(0, 0));
+
+            assert!(tracker.is_present(&email, at(2, 0)));
+            assert!(!tracker.is_present(&email, at(2, 1)));
+        }
+
+        #[test]
+        fn test_expire_drops_only_stale_presences() {
+            let mut tracker = create_tracker();
+            let course_id = Id::new();
+            tracker.heartbeat(create_email("a@example.com"), course_id, None, at(0, 0));
+            tracker.heartbeat(create_email("b@example.com"), course_id, None, at(2, 0));
+
+            let dropped = tracker.expire(at(3, 0));
+
+            assert_eq!(dropped, 1);
+            assert!(tracker.is_present(&create_email("b@example.com"), at(3, 0)));
+        }
+
+        #[test]
+        fn test_leave_removes_presence() {
+            let mut tracker = create_tracker();
+            let email = create_email("a@example.com");
+            tracker.heartbeat(email.clone(), Id::new(), None, at(0, 0));
+
+            assert!(tracker.leave(&email));
+            assert!(!tracker.leave(&email));
+            assert!(!tracker.is_present(&email, at(0, 0)));
+        }
+    }
+
+    mod queries {
+        use super::*;
+
+        #[test]
+        fn test_learners_in_course_counts_only_live_learners() {
+            let mut tracker = create_tracker();
+            let course_id = Id::new();
+            tracker.heartbeat(create_email("a@example.com"), course_id, None, at(0, 0));
+            tracker.heartbeat(create_email("b@example.com"), course_id, None, at(3, 0));
+            tracker.heartbeat(create_email("c@example.com"), Id::new(), None, at(3, 0));
+
+            assert_eq!(tracker.learners_in_course(course_id, at(3, 0)), 1);
+        }
+
+        #[test]
+        fn test_learners_in_lesson_ignores_course_level_presence() {
+            let mut tracker = create_tracker();
+            let course_id = Id::new();
+            let lesson_id = Id::new();
+            tracker.heartbeat(create_email("a@example.com"), course_id, Some(lesson_id), at(0, 0));
+            tracker.heartbeat(create_email("b@example.com"), course_id, Some(lesson_id), at(0, 0));
+            tracker.heartbeat(create_email("c@example.com"), course_id, None, at(0, 0));
+
+            assert_eq!(tracker.learners_in_lesson(lesson_id, at(0, 0)), 2);
+            assert_eq!(tracker.learners_in_course(course_id, at(0, 0)), 3);
+        }
+
+        #[test]
+        fn test_presences_in_course_sorted_most_recent_first() {
+            let mut tracker = create_tracker();
+            let course_id = Id::new();
+            tracker.heartbeat(create_email("a@example.com"), course_id, None, at(0, 0));
+            tracker.heartbeat(create_email("b@example.com"), course_id, None, at(0, 30));
+            tracker.heartbeat(create_email("c@example.com"), course_id, None, at(0, 15));
+
+            let emails: Vec<&str> = tracker
+                .presences_in_course(course_id, at(1, 0))
+                .iter()
+                .map(|presence| presence.user_email().address())
+                .collect();
+
+            assert_eq!(emails, vec!["b@example.com", "c@example.com", "a@example.com"]);
+        }
+
+        #[test]
+        fn test_presence_is_none_once_expired() {
+            let mut tracker = create_tracker();
+            let email = create_email("a@example.com");
+            tracker.heartbeat(email.clone(), Id::new(), None, at(0, 0));
+
+            assert!(tracker.presence(&email, at(5, 0)).is_none());
+        }
+    }
+}
@@ -1,5 +1,15 @@
+mod bookmark;
 mod course_progress;
+mod event_store;
 mod lesson_progress;
+mod note;
+mod progress_event;
+mod stats;
 
+pub use bookmark::*;
 pub use course_progress::*;
+pub use event_store::*;
 pub use lesson_progress::*;
+pub use note::*;
+pub use progress_event::*;
+pub use stats::*;
@@ -0,0 +1,292 @@
+use education_platform_common::{DateTime, Duration, Entity, Id, SimpleName, SimpleNameError};
+use thiserror::Error;
+
+/// Error types for Bookmark validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BookmarkError {
+    #[error("Bookmark label validation failed: {0}")]
+    LabelError(#[from] SimpleNameError),
+}
+
+/// A saved position in a lesson video the student wants to return to.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Bookmark;
+/// use education_platform_common::{Duration, Id};
+///
+/// let bookmark = Bookmark::new(
+///     Id::new(),
+///     Duration::from_hms(0, 12, 31),
+///     Some("Ownership recap".to_string()),
+/// ).unwrap();
+///
+/// assert_eq!(bookmark.video_offset().total_seconds(), 751);
+/// assert_eq!(bookmark.label().unwrap().as_str(), "Ownership recap");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bookmark {
+    id: Id,
+    lesson_id: Id,
+    video_offset: Duration,
+    label: Option<SimpleName>,
+    created_at: DateTime,
+}
+
+impl Bookmark {
+    /// Creates a new bookmark, stamped with the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BookmarkError::LabelError` if a label is given and fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Bookmark;
+    /// use education_platform_common::{Duration, Id};
+    ///
+    /// let bookmark = Bookmark::new(Id::new(), Duration::from_seconds(30), None).unwrap();
+    /// assert!(bookmark.label().is_none());
+    ///
+    /// assert!(Bookmark::new(Id::new(), Duration::from_seconds(30), Some("".to_string())).is_err());
+    /// ```
+    pub fn new(
+        lesson_id: Id,
+        video_offset: Duration,
+        label: Option<String>,
+    ) -> Result<Self, BookmarkError> {
+        Self::with_id(Id::default(), lesson_id, video_offset, label, DateTime::today())
+    }
+
+    /// Creates a `Bookmark` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `BookmarkError::LabelError` if a label is given and fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Bookmark;
+    /// use education_platform_common::{DateTime, Duration, Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let created_at = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+    /// let bookmark = Bookmark::with_id(id, Id::new(), Duration::from_seconds(5), None, created_at)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(bookmark.id(), id);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        lesson_id: Id,
+        video_offset: Duration,
+        label: Option<String>,
+        created_at: DateTime,
+    ) -> Result<Self, BookmarkError> {
+        let label = label.map(SimpleName::new).transpose()?;
+
+        Ok(Self {
+            id,
+            lesson_id,
+            video_offset,
+            label,
+            created_at,
+        })
+    }
+
+    /// Returns the ID of the lesson this bookmark belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Bookmark;
+    /// use education_platform_common::{Duration, Id};
+    ///
+    /// let lesson_id = Id::new();
+    /// let bookmark = Bookmark::new(lesson_id, Duration::from_seconds(5), None).unwrap();
+    /// assert_eq!(bookmark.lesson_id(), lesson_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> Id {
+        self.lesson_id
+    }
+
+    /// Returns the bookmarked position in the lesson video.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Bookmark;
+    /// use education_platform_common::{Duration, Id};
+    ///
+    /// let bookmark = Bookmark::new(Id::new(), Duration::from_seconds(5), None).unwrap();
+    /// assert_eq!(bookmark.video_offset().total_seconds(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn video_offset(&self) -> Duration {
+        self.video_offset
+    }
+
+    /// Returns the bookmark label, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Bookmark;
+    /// use education_platform_common::{Duration, Id};
+    ///
+    /// let bookmark = Bookmark::new(Id::new(), Duration::from_seconds(5), Some("Key idea".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(bookmark.label().unwrap().as_str(), "Key idea");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn label(&self) -> Option<&SimpleName> {
+        self.label.as_ref()
+    }
+
+    /// Returns when the bookmark was created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Bookmark;
+    /// use education_platform_common::{Duration, Id};
+    ///
+    /// let bookmark = Bookmark::new(Id::new(), Duration::from_seconds(5), None).unwrap();
+    /// assert!(bookmark.created_at().year() >= 2024);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    /// Replaces the label, or removes it when `None` is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BookmarkError::LabelError` if the new label fails validation.
+    /// The bookmark is left unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Bookmark;
+    /// use education_platform_common::{Duration, Id};
+    ///
+    /// let mut bookmark = Bookmark::new(Id::new(), Duration::from_seconds(5), None).unwrap();
+    /// bookmark.update_label(Some("Revisit".to_string())).unwrap();
+    ///
+    /// assert_eq!(bookmark.label().unwrap().as_str(), "Revisit");
+    /// ```
+    pub fn update_label(&mut self, label: Option<String>) -> Result<(), BookmarkError> {
+        self.label = label.map(SimpleName::new).transpose()?;
+        Ok(())
+    }
+}
+
+impl Entity for Bookmark {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for Bookmark {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Bookmark {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_bookmark(label: Option<&str>) -> Bookmark {
+        Bookmark::new(Id::new(), Duration::from_seconds(60), label.map(str::to_string)).unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_creates_valid_bookmark() {
+            let lesson_id = Id::new();
+            let offset = Duration::from_seconds(125);
+
+            let bookmark = Bookmark::new(lesson_id, offset, Some("Recap".to_string())).unwrap();
+
+            assert_eq!(bookmark.lesson_id(), lesson_id);
+            assert_eq!(bookmark.video_offset(), offset);
+            assert_eq!(bookmark.label().unwrap().as_str(), "Recap");
+        }
+
+        #[test]
+        fn test_new_without_label() {
+            let bookmark = create_test_bookmark(None);
+
+            assert!(bookmark.label().is_none());
+        }
+
+        #[test]
+        fn test_new_rejects_invalid_label() {
+            let result =
+                Bookmark::new(Id::new(), Duration::from_seconds(5), Some("<b>".to_string()));
+
+            assert!(matches!(result, Err(BookmarkError::LabelError(_))));
+        }
+
+        #[test]
+        fn test_with_id_preserves_fields() {
+            let id = Id::new();
+            let created_at = DateTime::new(2024, 5, 1, 8, 0, 0).unwrap();
+
+            let bookmark =
+                Bookmark::with_id(id, Id::new(), Duration::from_seconds(5), None, created_at)
+                    .unwrap();
+
+            assert_eq!(bookmark.id(), id);
+            assert_eq!(bookmark.created_at(), created_at);
+        }
+    }
+
+    mod update_label {
+        use super::*;
+
+        #[test]
+        fn test_update_label_replaces_label() {
+            let mut bookmark = create_test_bookmark(Some("Old"));
+
+            bookmark.update_label(Some("New".to_string())).unwrap();
+
+            assert_eq!(bookmark.label().unwrap().as_str(), "New");
+        }
+
+        #[test]
+        fn test_update_label_removes_label() {
+            let mut bookmark = create_test_bookmark(Some("Old"));
+
+            bookmark.update_label(None).unwrap();
+
+            assert!(bookmark.label().is_none());
+        }
+
+        #[test]
+        fn test_update_label_with_invalid_value_keeps_label() {
+            let mut bookmark = create_test_bookmark(Some("Old"));
+
+            let result = bookmark.update_label(Some(String::new()));
+
+            assert!(result.is_err());
+            assert_eq!(bookmark.label().unwrap().as_str(), "Old");
+        }
+    }
+}
@@ -1,18 +1,28 @@
+mod adjustments;
+mod annotations;
+mod course_sync;
+mod event_sourcing;
 mod events;
 mod fraud_verification;
 mod getters;
 mod lesson_lifecycle;
 mod lesson_navigation;
 mod progress_calculations;
+mod release_gate;
 mod selected_lesson;
+mod streaks;
 
 pub use events::CourseEnded;
 
-use crate::{CourseError, LessonProgress, LessonProgressError};
+use crate::{
+    Bookmark, BookmarkError, CourseError, EventStoreError, LessonProgress, LessonProgressError,
+    Note, NoteError, ProgressEvent,
+};
 use education_platform_common::{
-    DateTime, DomainEventDispatcher, Duration, Email, EmailError, Entity, Id, SimpleName,
-    SimpleNameConfig, SimpleNameError,
+    Auditable, Date, DateTime, DomainEventDispatcher, Duration, Email, EmailError, Entity, Id,
+    SimpleName, SimpleNameConfig, SimpleNameError, Timestamps,
 };
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -37,6 +47,36 @@ pub enum CourseProgressError {
 
     #[error("Lesson with id {0} not found")]
     LessonNotFound(String),
+
+    #[error("Progress belongs to course {expected}, not to course {actual}")]
+    CourseMismatch { expected: String, actual: String },
+
+    #[error("Note validation failed: {0}")]
+    NoteError(#[from] NoteError),
+
+    #[error("Bookmark validation failed: {0}")]
+    BookmarkError(#[from] BookmarkError),
+
+    #[error("Note with id {0} not found")]
+    NoteNotFound(String),
+
+    #[error("Bookmark with id {0} not found")]
+    BookmarkNotFound(String),
+
+    #[error("Video offset of {offset} seconds is beyond the lesson length of {duration} seconds")]
+    VideoOffsetNotValid { offset: u64, duration: u64 },
+
+    #[error("Event store operation failed: {0}")]
+    EventStoreError(#[from] EventStoreError),
+
+    #[error("Progress event stream is not valid: {0}")]
+    EventStreamNotValid(String),
+
+    #[error("Lesson {lesson_id} is locked until {available_on}")]
+    LessonLocked {
+        lesson_id: String,
+        available_on: String,
+    },
 }
 
 /// Tracks a user's progress through a course.
@@ -69,13 +109,22 @@ pub enum CourseProgressError {
 #[derive(Debug, Clone)]
 pub struct CourseProgress {
     id: Id,
+    course_id: Option<Id>,
+    course_version: Option<u32>,
     course_name: SimpleName,
     user_email: Email,
     creation_date: Option<DateTime>,
     end_date: Option<DateTime>,
+    timestamps: Timestamps,
     lesson_progress: Vec<LessonProgress>,
     selected_lesson: LessonProgress,
+    active_days: BTreeSet<Date>,
+    notes: Vec<Note>,
+    bookmarks: Vec<Bookmark>,
+    favorite: bool,
     event_dispatcher: Arc<DomainEventDispatcher<CourseEnded>>,
+    version: usize,
+    pending_events: Vec<ProgressEvent>,
 }
 
 /// Builder for creating `CourseProgress` instances.
@@ -100,12 +149,20 @@ pub struct CourseProgress {
 /// ```
 #[derive(Debug, Clone)]
 pub struct CourseProgressBuilder {
+    id: Option<Id>,
+    course_id: Option<Id>,
+    course_version: Option<u32>,
     course_name: Option<String>,
     user_email: Option<String>,
     lessons: Option<Vec<LessonProgress>>,
     creation_date: Option<DateTime>,
     end_date: Option<DateTime>,
+    timestamps: Option<Timestamps>,
     selected_lesson_id: Option<Id>,
+    active_days: BTreeSet<Date>,
+    notes: Vec<Note>,
+    bookmarks: Vec<Bookmark>,
+    favorite: bool,
     event_dispatcher: Option<Arc<DomainEventDispatcher<CourseEnded>>>,
 }
 
@@ -121,16 +178,45 @@ impl CourseProgressBuilder {
     #[must_use]
     pub fn new() -> Self {
         Self {
+            id: None,
+            course_id: None,
+            course_version: None,
             course_name: None,
             user_email: None,
             lessons: None,
             creation_date: None,
             end_date: None,
+            timestamps: None,
             selected_lesson_id: None,
+            active_days: BTreeSet::new(),
+            notes: Vec::new(),
+            bookmarks: Vec::new(),
+            favorite: false,
             event_dispatcher: None,
         }
     }
 
+    /// Sets the ID of the progress record (for reconstruction from persistence).
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the ID of the course this progress tracks.
+    #[must_use]
+    pub fn course_id(mut self, id: Id) -> Self {
+        self.course_id = Some(id);
+        self
+    }
+
+    /// Sets the number of the course version the user started.
+    #[must_use]
+    pub fn course_version(mut self, number: u32) -> Self {
+        self.course_version = Some(number);
+        self
+    }
+
     /// Sets the course name.
     #[must_use]
     pub fn course_name(mut self, name: impl Into<String>) -> Self {
@@ -166,6 +252,14 @@ impl CourseProgressBuilder {
         self
     }
 
+    /// Sets when the progress was created and last changed (for
+    /// reconstruction from persistence); defaults to now.
+    #[must_use]
+    pub fn timestamps(mut self, timestamps: Timestamps) -> Self {
+        self.timestamps = Some(timestamps);
+        self
+    }
+
     /// Sets the initially selected lesson by ID.
     #[must_use]
     pub fn selected_lesson_id(mut self, id: Id) -> Self {
@@ -173,6 +267,37 @@ impl CourseProgressBuilder {
         self
     }
 
+    /// Sets days on which the user was active in the course.
+    ///
+    /// The start and end dates of the lessons are always counted as active
+    /// days; use this to restore activity that left no trace on the lessons.
+    #[must_use]
+    pub fn active_days(mut self, days: impl IntoIterator<Item = Date>) -> Self {
+        self.active_days.extend(days);
+        self
+    }
+
+    /// Sets the notes the user has taken on the course lessons.
+    #[must_use]
+    pub fn notes(mut self, notes: Vec<Note>) -> Self {
+        self.notes = notes;
+        self
+    }
+
+    /// Sets the bookmarks the user has placed in the course lessons.
+    #[must_use]
+    pub fn bookmarks(mut self, bookmarks: Vec<Bookmark>) -> Self {
+        self.bookmarks = bookmarks;
+        self
+    }
+
+    /// Sets whether the user marked the course as a favorite.
+    #[must_use]
+    pub fn favorite(mut self, favorite: bool) -> Self {
+        self.favorite = favorite;
+        self
+    }
+
     /// Sets the event dispatcher.
     #[must_use]
     pub fn event_dispatcher(mut self, dispatcher: Arc<DomainEventDispatcher<CourseEnded>>) -> Self {
@@ -205,20 +330,41 @@ impl CourseProgressBuilder {
         let selected_lesson = CourseProgress::find_lesson_by_id(self.selected_lesson_id, &lessons)?;
         let calculated_end_date = CourseProgress::calculate_end_date(&self.end_date, &lessons);
         let should_publish_ended = calculated_end_date.is_some() && self.end_date.is_none();
+        let mut active_days = self.active_days;
+        active_days.extend(CourseProgress::lesson_activity_days(&lessons));
 
         let event_dispatcher = self
             .event_dispatcher
             .unwrap_or_else(|| Arc::new(DomainEventDispatcher::new()));
 
+        let id = self.id.unwrap_or_default();
+        let pending_events = CourseProgress::enrollment_events(
+            id,
+            self.course_id,
+            self.course_version,
+            &course_name,
+            &user_email,
+            &lessons,
+        );
+
         let course_progress = CourseProgress {
-            id: Id::default(),
+            id,
+            course_id: self.course_id,
+            course_version: self.course_version,
             course_name,
             user_email,
             creation_date: self.creation_date,
             end_date: calculated_end_date,
+            timestamps: self.timestamps.unwrap_or_else(Timestamps::now),
             lesson_progress: lessons,
             selected_lesson,
+            active_days,
+            notes: self.notes,
+            bookmarks: self.bookmarks,
+            favorite: self.favorite,
             event_dispatcher,
+            version: 0,
+            pending_events,
         };
 
         if should_publish_ended {
@@ -281,6 +427,16 @@ impl Entity for CourseProgress {
     }
 }
 
+impl Auditable for CourseProgress {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
 impl PartialEq for CourseProgress {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -500,6 +656,55 @@ mod tests {
         }
     }
 
+    mod auditable {
+        use super::*;
+
+        #[test]
+        fn test_new_progress_is_unmodified() {
+            let progress = create_test_progress();
+
+            assert_eq!(progress.created_at(), progress.updated_at());
+        }
+
+        #[test]
+        fn test_builder_restores_timestamps() {
+            let created_at = DateTime::new(2024, 1, 10, 9, 0, 0).unwrap();
+            let updated_at = DateTime::new(2024, 2, 1, 18, 30, 0).unwrap();
+            let progress = CourseProgress::builder()
+                .course_name("Test Course")
+                .user_email("test@example.com")
+                .lessons(vec![create_test_lesson("Lesson 1", 1800)])
+                .timestamps(Timestamps::new(created_at, updated_at).unwrap())
+                .build()
+                .unwrap();
+
+            assert_eq!(progress.created_at(), created_at);
+            assert_eq!(progress.updated_at(), updated_at);
+        }
+
+        #[test]
+        fn test_starting_lesson_records_modification() {
+            let mut progress = create_test_progress();
+            let lesson_id = progress.lesson_progress()[0].lesson_id();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            progress.start_lesson(lesson_id);
+
+            assert!(progress.updated_at() > progress.created_at());
+        }
+
+        #[test]
+        fn test_failed_note_keeps_timestamps() {
+            let mut progress = create_test_progress();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+
+            let result = progress.add_note(Id::new(), "Remember this".to_string(), None);
+
+            assert!(result.is_err());
+            assert_eq!(progress.updated_at(), progress.created_at());
+        }
+    }
+
     mod equality {
         use super::*;
 
@@ -0,0 +1,350 @@
+use crate::{CourseProgress, CourseProgressError, LessonProgress, ProgressEvent};
+use education_platform_common::{DateTime, Entity, Id};
+
+impl CourseProgress {
+    /// Marks a lesson as completed at a given time on the learner's behalf.
+    ///
+    /// A lesson that was never started is also started at that time. The
+    /// adjustment does not count as learner activity for streaks. Returns
+    /// false when the lesson was already completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::LessonNotFound` if the lesson is not part
+    /// of this progress.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{DateTime, Entity};
+    ///
+    /// let lesson = LessonProgress::new("Workshop".to_string(), 3600, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// let at = DateTime::new(2025, 3, 4, 18, 0, 0).unwrap();
+    ///
+    /// assert!(progress.mark_lesson_completed(lesson_id, at).unwrap());
+    /// assert!(!progress.mark_lesson_completed(lesson_id, at).unwrap());
+    /// assert_eq!(progress.end_date(), Some(at));
+    /// ```
+    pub fn mark_lesson_completed(
+        &mut self,
+        lesson_id: Id,
+        at: DateTime,
+    ) -> Result<bool, CourseProgressError> {
+        let lesson = self
+            .lesson_progress
+            .iter()
+            .find(|lp| lp.id() == lesson_id)
+            .ok_or_else(|| CourseProgressError::LessonNotFound(lesson_id.to_string()))?;
+        if lesson.is_completed() {
+            return Ok(false);
+        }
+
+        let start_date = lesson
+            .start_date()
+            .copied()
+            .map_or(at, |start| start.min(at));
+        let completed = Self::with_dates(lesson, Some(start_date), Some(at))?;
+        self.replace_lessons(vec![completed]);
+        self.pending_events
+            .push(ProgressEvent::LessonCompleted { lesson_id, at });
+        self.timestamps.touch();
+        Ok(true)
+    }
+
+    /// Carries over the lesson dates of another progress for the same course.
+    ///
+    /// Used when a learner ended up with two accounts: for each lesson, the
+    /// earliest start and the earliest completion of the two records are
+    /// kept, so nothing the learner did on either account is lost. Active
+    /// days are combined as well. Returns how many lessons changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::CourseMismatch` if the other progress
+    /// tracks a different course.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseProgress, Lesson};
+    /// use education_platform_common::{DateTime, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let mut duplicate = CourseProgress::from_course(&course, "ana.old@example.com").unwrap();
+    /// duplicate
+    ///     .mark_lesson_completed(lesson_id, DateTime::new(2025, 3, 4, 18, 0, 0).unwrap())
+    ///     .unwrap();
+    /// let mut primary = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+    ///
+    /// assert_eq!(primary.merge_from(&duplicate).unwrap(), 1);
+    /// assert!(primary.is_completed());
+    /// ```
+    pub fn merge_from(&mut self, other: &Self) -> Result<usize, CourseProgressError> {
+        let same_course = match (self.course_id, other.course_id) {
+            (Some(own), Some(theirs)) => own == theirs,
+            _ => self.course_name == other.course_name,
+        };
+        if !same_course {
+            return Err(CourseProgressError::CourseMismatch {
+                expected: self.course_reference(),
+                actual: other.course_reference(),
+            });
+        }
+
+        let mut merged = Vec::new();
+        let mut events = Vec::new();
+        for lesson in &self.lesson_progress {
+            let Some(theirs) = other
+                .lesson_progress
+                .iter()
+                .find(|lp| lp.id() == lesson.id())
+            else {
+                continue;
+            };
+            let start_date = Self::earliest(lesson.start_date(), theirs.start_date());
+            let end_date = Self::earliest(lesson.end_date(), theirs.end_date());
+            let lesson_id = lesson.id();
+            let mut changed = false;
+            if let Some(at) = start_date.filter(|at| Some(at) != lesson.start_date()) {
+                events.push(ProgressEvent::LessonStarted { lesson_id, at });
+                changed = true;
+            }
+            if let Some(at) = end_date.filter(|at| Some(at) != lesson.end_date()) {
+                events.push(ProgressEvent::LessonCompleted { lesson_id, at });
+                changed = true;
+            }
+            if changed {
+                merged.push(Self::with_dates(lesson, start_date, end_date)?);
+            }
+        }
+
+        let changed = merged.len();
+        self.active_days.extend(other.active_days.iter().copied());
+        self.replace_lessons(merged);
+        self.pending_events.extend(events);
+        if changed > 0 {
+            self.timestamps.touch();
+        }
+        Ok(changed)
+    }
+
+    pub(super) fn replace_lessons(&mut self, replacements: Vec<LessonProgress>) {
+        let was_ended = self.end_date.is_some();
+        for replacement in replacements {
+            if self.selected_lesson.id() == replacement.id() {
+                self.selected_lesson = replacement.clone();
+            }
+            if let Some(lesson) = self
+                .lesson_progress
+                .iter_mut()
+                .find(|lp| lp.id() == replacement.id())
+            {
+                *lesson = replacement;
+            }
+        }
+
+        self.end_date = Self::calculate_end_date(&self.end_date, &self.lesson_progress);
+        if !was_ended && self.end_date.is_some() {
+            self.publish_ended();
+        }
+    }
+
+    pub(super) fn with_dates(
+        lesson: &LessonProgress,
+        start_date: Option<DateTime>,
+        end_date: Option<DateTime>,
+    ) -> Result<LessonProgress, CourseProgressError> {
+        LessonProgress::with_id(
+            lesson.id(),
+            lesson.lesson_name().to_string(),
+            lesson.duration().total_seconds(),
+            start_date,
+            end_date,
+        )
+        .map_err(CourseProgressError::from)
+    }
+
+    pub(super) fn earliest(own: Option<&DateTime>, theirs: Option<&DateTime>) -> Option<DateTime> {
+        match (own, theirs) {
+            (Some(own), Some(theirs)) => Some(*own.min(theirs)),
+            (own, theirs) => own.or(theirs).copied(),
+        }
+    }
+
+    fn course_reference(&self) -> String {
+        self.course_id
+            .map_or_else(|| self.course_name.to_string(), |id| id.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Course, CourseEnded, Lesson};
+    use education_platform_common::DomainEventDispatcher;
+    use std::sync::{Arc, Mutex};
+
+    fn create_test_course() -> Course {
+        let lessons = ["Introduction", "Ownership"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                Lesson::new(
+                    name.to_string(),
+                    1800,
+                    format!("https://example.com/{index}.mp4"),
+                    index,
+                )
+                .unwrap()
+            })
+            .collect();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, lessons).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_time(day: u32) -> DateTime {
+        DateTime::new(2025, 3, day, 18, 0, 0).unwrap()
+    }
+
+    mod mark_lesson_completed {
+        use super::*;
+
+        #[test]
+        fn test_started_lesson_keeps_start_date() {
+            let course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let lesson_id = progress.lesson_progress()[0].lesson_id();
+            progress.start_lesson(lesson_id);
+            let started = *progress.lesson_progress()[0].start_date().unwrap();
+            let later = DateTime::new(2999, 1, 1, 0, 0, 0).unwrap();
+
+            progress
+                .mark_lesson_completed(lesson_id.untyped(), later)
+                .unwrap();
+
+            assert_eq!(progress.lesson_progress()[0].start_date(), Some(&started));
+            assert_eq!(progress.lesson_progress()[0].end_date(), Some(&later));
+            assert_eq!(progress.selected_lesson().end_date(), Some(&later));
+        }
+
+        #[test]
+        fn test_unknown_lesson_is_reported() {
+            let course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+
+            let result = progress.mark_lesson_completed(Id::new(), create_time(4));
+
+            assert!(matches!(result, Err(CourseProgressError::LessonNotFound(_))));
+        }
+
+        #[test]
+        fn test_completing_last_lesson_publishes_course_ended() {
+            let course = create_test_course();
+            let dispatcher = Arc::new(DomainEventDispatcher::<CourseEnded>::new());
+            let received = Arc::new(Mutex::new(0));
+            let sink = Arc::clone(&received);
+            dispatcher.subscribe(move |_| *sink.lock().unwrap() += 1);
+            let progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let lessons = progress.lesson_progress().to_vec();
+            let mut progress = CourseProgress::builder()
+                .course_id(course.id())
+                .course_name("Rust Programming")
+                .user_email("ana@example.com")
+                .lessons(lessons)
+                .event_dispatcher(dispatcher)
+                .build()
+                .unwrap();
+            let ids: Vec<Id> = progress
+                .lesson_progress()
+                .iter()
+                .map(|lp| lp.id())
+                .collect();
+
+            progress
+                .mark_lesson_completed(ids[0], create_time(3))
+                .unwrap();
+            assert_eq!(*received.lock().unwrap(), 0);
+            progress
+                .mark_lesson_completed(ids[1], create_time(4))
+                .unwrap();
+
+            assert_eq!(*received.lock().unwrap(), 1);
+            assert_eq!(progress.end_date(), Some(create_time(4)));
+            assert!(progress.active_days().is_empty());
+        }
+    }
+
+    mod merge_from {
+        use super::*;
+
+        #[test]
+        fn test_earliest_dates_are_kept() {
+            let course = create_test_course();
+            let mut primary = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let mut duplicate =
+                CourseProgress::from_course(&course, "ana.old@example.com").unwrap();
+            let first = primary.lesson_progress()[0].id();
+            let second = primary.lesson_progress()[1].id();
+            primary
+                .mark_lesson_completed(first, create_time(10))
+                .unwrap();
+            duplicate
+                .mark_lesson_completed(first, create_time(5))
+                .unwrap();
+            duplicate
+                .mark_lesson_completed(second, create_time(6))
+                .unwrap();
+
+            let changed = primary.merge_from(&duplicate).unwrap();
+
+            assert_eq!(changed, 2);
+            assert_eq!(primary.lesson_progress()[0].end_date(), Some(&create_time(5)));
+            assert_eq!(primary.lesson_progress()[1].end_date(), Some(&create_time(6)));
+            assert!(primary.is_completed());
+        }
+
+        #[test]
+        fn test_merging_twice_changes_nothing() {
+            let course = create_test_course();
+            let mut primary = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let mut duplicate =
+                CourseProgress::from_course(&course, "ana.old@example.com").unwrap();
+            let first = duplicate.lesson_progress()[0].id();
+            duplicate
+                .mark_lesson_completed(first, create_time(5))
+                .unwrap();
+            primary.merge_from(&duplicate).unwrap();
+
+            assert_eq!(primary.merge_from(&duplicate), Ok(0));
+        }
+
+        #[test]
+        fn test_other_course_is_rejected() {
+            let mut primary =
+                CourseProgress::from_course(&create_test_course(), "ana@example.com").unwrap();
+            let other =
+                CourseProgress::from_course(&create_test_course(), "ana.old@example.com").unwrap();
+
+            let result = primary.merge_from(&other);
+
+            assert!(matches!(result, Err(CourseProgressError::CourseMismatch { .. })));
+        }
+    }
+}
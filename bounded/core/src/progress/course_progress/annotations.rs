@@ -0,0 +1,712 @@
+use crate::{Bookmark, CourseProgress, CourseProgressError, Note};
+use education_platform_common::{Duration, Entity, Id};
+
+impl CourseProgress {
+    /// Adds a note to a lesson and returns its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::LessonNotFound` if the lesson is not part of the course.
+    /// Returns `CourseProgressError::VideoOffsetNotValid` if the offset is past the end of the lesson.
+    /// Returns `CourseProgressError::NoteError` if the text fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Duration, Entity};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let note_id = progress
+    ///     .add_note(lesson_id, "Key definition".to_string(), Some(Duration::from_minutes(5)))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(progress.notes()[0].id(), note_id);
+    /// assert!(progress.add_note(lesson_id, "Too late".to_string(), Some(Duration::from_hours(1))).is_err());
+    /// ```
+    pub fn add_note(
+        &mut self,
+        lesson_id: Id,
+        text: String,
+        video_offset: Option<Duration>,
+    ) -> Result<Id, CourseProgressError> {
+        self.validate_video_offset(lesson_id, video_offset)?;
+        let note = Note::new(lesson_id, text, video_offset)?;
+        let note_id = note.id();
+        self.notes.push(note);
+        self.timestamps.touch();
+        Ok(note_id)
+    }
+
+    /// Replaces the text and video offset of a note.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::NoteNotFound` if no note has the given ID.
+    /// Returns `CourseProgressError::VideoOffsetNotValid` if the offset is past the end of the lesson.
+    /// Returns `CourseProgressError::NoteError` if the text fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// let note_id = progress.add_note(lesson_id, "Draft".to_string(), None).unwrap();
+    ///
+    /// progress.edit_note(note_id, "Final".to_string(), None).unwrap();
+    ///
+    /// assert_eq!(progress.notes()[0].text(), "Final");
+    /// ```
+    pub fn edit_note(
+        &mut self,
+        note_id: Id,
+        text: String,
+        video_offset: Option<Duration>,
+    ) -> Result<(), CourseProgressError> {
+        let lesson_id = self.find_note(note_id)?.lesson_id();
+        self.validate_video_offset(lesson_id, video_offset)?;
+
+        let note = self
+            .notes
+            .iter_mut()
+            .find(|note| note.id() == note_id)
+            .ok_or_else(|| CourseProgressError::NoteNotFound(note_id.to_string()))?;
+        note.edit(text, video_offset)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes a note.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::NoteNotFound` if no note has the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// let note_id = progress.add_note(lesson_id, "Temporary".to_string(), None).unwrap();
+    ///
+    /// progress.delete_note(note_id).unwrap();
+    ///
+    /// assert!(progress.notes().is_empty());
+    /// ```
+    pub fn delete_note(&mut self, note_id: Id) -> Result<(), CourseProgressError> {
+        let position = self
+            .notes
+            .iter()
+            .position(|note| note.id() == note_id)
+            .ok_or_else(|| CourseProgressError::NoteNotFound(note_id.to_string()))?;
+        self.notes.remove(position);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Returns the notes of a lesson ordered by video position.
+    ///
+    /// Notes without a video offset refer to the whole lesson and come first.
+    /// Notes at the same position keep their creation order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Duration, Entity};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// progress.add_note(lesson_id, "Later".to_string(), Some(Duration::from_minutes(9))).unwrap();
+    /// progress.add_note(lesson_id, "Earlier".to_string(), Some(Duration::from_minutes(2))).unwrap();
+    ///
+    /// let texts: Vec<&str> = progress.lesson_notes(lesson_id).iter().map(|n| n.text()).collect();
+    /// assert_eq!(texts, vec!["Earlier", "Later"]);
+    /// ```
+    #[must_use]
+    pub fn lesson_notes(&self, lesson_id: Id) -> Vec<&Note> {
+        let mut notes: Vec<&Note> = self
+            .notes
+            .iter()
+            .filter(|note| note.lesson_id() == lesson_id)
+            .collect();
+        notes.sort_by_key(|note| note.video_offset());
+        notes
+    }
+
+    /// Adds a bookmark to a lesson and returns its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::LessonNotFound` if the lesson is not part of the course.
+    /// Returns `CourseProgressError::VideoOffsetNotValid` if the offset is past the end of the lesson.
+    /// Returns `CourseProgressError::BookmarkError` if the label fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Duration, Entity};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let bookmark_id = progress
+    ///     .add_bookmark(lesson_id, Duration::from_seconds(751), Some("Ownership".to_string()))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(progress.bookmarks()[0].id(), bookmark_id);
+    /// ```
+    pub fn add_bookmark(
+        &mut self,
+        lesson_id: Id,
+        video_offset: Duration,
+        label: Option<String>,
+    ) -> Result<Id, CourseProgressError> {
+        self.validate_video_offset(lesson_id, Some(video_offset))?;
+        let bookmark = Bookmark::new(lesson_id, video_offset, label)?;
+        let bookmark_id = bookmark.id();
+        self.bookmarks.push(bookmark);
+        self.timestamps.touch();
+        Ok(bookmark_id)
+    }
+
+    /// Replaces the label of a bookmark, or removes it when `None` is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::BookmarkNotFound` if no bookmark has the given ID.
+    /// Returns `CourseProgressError::BookmarkError` if the label fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Duration, Entity};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// let bookmark_id = progress.add_bookmark(lesson_id, Duration::from_seconds(30), None).unwrap();
+    ///
+    /// progress.edit_bookmark(bookmark_id, Some("Revisit".to_string())).unwrap();
+    ///
+    /// assert_eq!(progress.bookmarks()[0].label().unwrap().as_str(), "Revisit");
+    /// ```
+    pub fn edit_bookmark(
+        &mut self,
+        bookmark_id: Id,
+        label: Option<String>,
+    ) -> Result<(), CourseProgressError> {
+        let bookmark = self
+            .bookmarks
+            .iter_mut()
+            .find(|bookmark| bookmark.id() == bookmark_id)
+            .ok_or_else(|| CourseProgressError::BookmarkNotFound(bookmark_id.to_string()))?;
+        bookmark.update_label(label)?;
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes a bookmark.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::BookmarkNotFound` if no bookmark has the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Duration, Entity};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// let bookmark_id = progress.add_bookmark(lesson_id, Duration::from_seconds(30), None).unwrap();
+    ///
+    /// progress.delete_bookmark(bookmark_id).unwrap();
+    ///
+    /// assert!(progress.bookmarks().is_empty());
+    /// ```
+    pub fn delete_bookmark(&mut self, bookmark_id: Id) -> Result<(), CourseProgressError> {
+        let position = self
+            .bookmarks
+            .iter()
+            .position(|bookmark| bookmark.id() == bookmark_id)
+            .ok_or_else(|| CourseProgressError::BookmarkNotFound(bookmark_id.to_string()))?;
+        self.bookmarks.remove(position);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Returns the bookmarks of a lesson ordered by video position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Duration, Entity};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// progress.add_bookmark(lesson_id, Duration::from_seconds(600), None).unwrap();
+    /// progress.add_bookmark(lesson_id, Duration::from_seconds(60), None).unwrap();
+    ///
+    /// let offsets: Vec<u64> = progress
+    ///     .lesson_bookmarks(lesson_id)
+    ///     .iter()
+    ///     .map(|b| b.video_offset().total_seconds())
+    ///     .collect();
+    /// assert_eq!(offsets, vec![60, 600]);
+    /// ```
+    #[must_use]
+    pub fn lesson_bookmarks(&self, lesson_id: Id) -> Vec<&Bookmark> {
+        let mut bookmarks: Vec<&Bookmark> = self
+            .bookmarks
+            .iter()
+            .filter(|bookmark| bookmark.lesson_id() == lesson_id)
+            .collect();
+        bookmarks.sort_by_key(|bookmark| bookmark.video_offset());
+        bookmarks
+    }
+
+    /// Marks the course as a favorite, or unmarks it, and returns whether
+    /// it is a favorite now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(progress.toggle_favorite());
+    /// assert!(!progress.toggle_favorite());
+    /// ```
+    pub fn toggle_favorite(&mut self) -> bool {
+        self.favorite = !self.favorite;
+        self.timestamps.touch();
+        self.favorite
+    }
+
+    fn find_note(&self, note_id: Id) -> Result<&Note, CourseProgressError> {
+        self.notes
+            .iter()
+            .find(|note| note.id() == note_id)
+            .ok_or_else(|| CourseProgressError::NoteNotFound(note_id.to_string()))
+    }
+
+    fn validate_video_offset(
+        &self,
+        lesson_id: Id,
+        video_offset: Option<Duration>,
+    ) -> Result<(), CourseProgressError> {
+        let duration = self.one_lesson_progress(lesson_id)?.duration();
+
+        match video_offset {
+            Some(offset) if offset > duration => Err(CourseProgressError::VideoOffsetNotValid {
+                offset: offset.total_seconds(),
+                duration: duration.total_seconds(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BookmarkError, CourseEnded, LessonProgress, NoteError};
+    use education_platform_common::DomainEventDispatcher;
+    use std::sync::Arc;
+
+    fn create_test_dispatcher() -> Arc<DomainEventDispatcher<CourseEnded>> {
+        Arc::new(DomainEventDispatcher::new())
+    }
+
+    fn create_test_lesson(name: &str, duration: u64) -> LessonProgress {
+        LessonProgress::new(name.to_string(), duration, None, None).unwrap()
+    }
+
+    fn create_progress(lessons: Vec<LessonProgress>) -> CourseProgress {
+        CourseProgress::builder()
+            .course_name("Course")
+            .user_email("user@example.com")
+            .lessons(lessons)
+            .event_dispatcher(create_test_dispatcher())
+            .build()
+            .unwrap()
+    }
+
+    fn create_single_lesson_progress() -> (CourseProgress, Id) {
+        let lesson = create_test_lesson("Lesson 1", 1800);
+        let lesson_id = lesson.id();
+        (create_progress(vec![lesson]), lesson_id)
+    }
+
+    mod notes {
+        use super::*;
+
+        #[test]
+        fn test_add_note_stores_note() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+
+            let note_id = progress
+                .add_note(lesson_id, "Important".to_string(), None)
+                .unwrap();
+
+            assert_eq!(progress.notes().len(), 1);
+            assert_eq!(progress.notes()[0].id(), note_id);
+            assert_eq!(progress.notes()[0].lesson_id(), lesson_id);
+        }
+
+        #[test]
+        fn test_add_note_rejects_unknown_lesson() {
+            let (mut progress, _) = create_single_lesson_progress();
+
+            let result = progress.add_note(Id::new(), "Text".to_string(), None);
+
+            assert!(matches!(result, Err(CourseProgressError::LessonNotFound(_))));
+            assert!(progress.notes().is_empty());
+        }
+
+        #[test]
+        fn test_add_note_accepts_offset_at_lesson_end() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+
+            let result =
+                progress.add_note(lesson_id, "End".to_string(), Some(Duration::from_seconds(1800)));
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn test_add_note_rejects_offset_past_lesson_end() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+
+            let result = progress.add_note(
+                lesson_id,
+                "Late".to_string(),
+                Some(Duration::from_seconds(1801)),
+            );
+
+            assert_eq!(
+                result.unwrap_err(),
+                CourseProgressError::VideoOffsetNotValid {
+                    offset: 1801,
+                    duration: 1800
+                }
+            );
+        }
+
+        #[test]
+        fn test_add_note_rejects_empty_text() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+
+            let result = progress.add_note(lesson_id, " ".to_string(), None);
+
+            assert!(matches!(
+                result,
+                Err(CourseProgressError::NoteError(NoteError::TextError(_)))
+            ));
+        }
+
+        #[test]
+        fn test_edit_note_updates_text_and_offset() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+            let note_id = progress
+                .add_note(lesson_id, "Draft".to_string(), None)
+                .unwrap();
+
+            progress
+                .edit_note(note_id, "Final".to_string(), Some(Duration::from_seconds(10)))
+                .unwrap();
+
+            let note = &progress.notes()[0];
+            assert_eq!(note.text(), "Final");
+            assert_eq!(note.video_offset(), Some(Duration::from_seconds(10)));
+        }
+
+        #[test]
+        fn test_edit_note_rejects_offset_past_lesson_end() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+            let note_id = progress
+                .add_note(lesson_id, "Draft".to_string(), None)
+                .unwrap();
+
+            let result =
+                progress.edit_note(note_id, "Final".to_string(), Some(Duration::from_hours(2)));
+
+            assert!(matches!(result, Err(CourseProgressError::VideoOffsetNotValid { .. })));
+            assert_eq!(progress.notes()[0].text(), "Draft");
+        }
+
+        #[test]
+        fn test_edit_unknown_note_returns_error() {
+            let (mut progress, _) = create_single_lesson_progress();
+
+            let result = progress.edit_note(Id::new(), "Text".to_string(), None);
+
+            assert!(matches!(result, Err(CourseProgressError::NoteNotFound(_))));
+        }
+
+        #[test]
+        fn test_delete_note_removes_only_that_note() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+            let first = progress
+                .add_note(lesson_id, "First".to_string(), None)
+                .unwrap();
+            let second = progress
+                .add_note(lesson_id, "Second".to_string(), None)
+                .unwrap();
+
+            progress.delete_note(first).unwrap();
+
+            assert_eq!(progress.notes().len(), 1);
+            assert_eq!(progress.notes()[0].id(), second);
+        }
+
+        #[test]
+        fn test_delete_unknown_note_returns_error() {
+            let (mut progress, _) = create_single_lesson_progress();
+
+            let result = progress.delete_note(Id::new());
+
+            assert!(matches!(result, Err(CourseProgressError::NoteNotFound(_))));
+        }
+
+        #[test]
+        fn test_lesson_notes_filters_and_orders_by_position() {
+            let lesson1 = create_test_lesson("Lesson 1", 1800);
+            let lesson2 = create_test_lesson("Lesson 2", 1800);
+            let (id1, id2) = (lesson1.id(), lesson2.id());
+            let mut progress = create_progress(vec![lesson1, lesson2]);
+            progress
+                .add_note(id1, "At five".to_string(), Some(Duration::from_minutes(5)))
+                .unwrap();
+            progress
+                .add_note(id2, "Other lesson".to_string(), None)
+                .unwrap();
+            progress.add_note(id1, "General".to_string(), None).unwrap();
+            progress
+                .add_note(id1, "At one".to_string(), Some(Duration::from_minutes(1)))
+                .unwrap();
+
+            let texts: Vec<&str> = progress
+                .lesson_notes(id1)
+                .iter()
+                .map(|n| n.text())
+                .collect();
+
+            assert_eq!(texts, vec!["General", "At one", "At five"]);
+        }
+    }
+
+    mod bookmarks {
+        use super::*;
+
+        #[test]
+        fn test_add_bookmark_stores_bookmark() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+
+            let bookmark_id = progress
+                .add_bookmark(lesson_id, Duration::from_seconds(90), None)
+                .unwrap();
+
+            assert_eq!(progress.bookmarks().len(), 1);
+            assert_eq!(progress.bookmarks()[0].id(), bookmark_id);
+        }
+
+        #[test]
+        fn test_add_bookmark_rejects_unknown_lesson() {
+            let (mut progress, _) = create_single_lesson_progress();
+
+            let result = progress.add_bookmark(Id::new(), Duration::from_seconds(90), None);
+
+            assert!(matches!(result, Err(CourseProgressError::LessonNotFound(_))));
+        }
+
+        #[test]
+        fn test_add_bookmark_rejects_offset_past_lesson_end() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+
+            let result = progress.add_bookmark(lesson_id, Duration::from_hours(1), None);
+
+            assert!(matches!(result, Err(CourseProgressError::VideoOffsetNotValid { .. })));
+        }
+
+        #[test]
+        fn test_add_bookmark_rejects_invalid_label() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+
+            let result = progress.add_bookmark(
+                lesson_id,
+                Duration::from_seconds(5),
+                Some("{x}".to_string()),
+            );
+
+            assert!(matches!(
+                result,
+                Err(CourseProgressError::BookmarkError(BookmarkError::LabelError(_)))
+            ));
+        }
+
+        #[test]
+        fn test_edit_bookmark_updates_label() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+            let bookmark_id = progress
+                .add_bookmark(lesson_id, Duration::from_seconds(5), None)
+                .unwrap();
+
+            progress
+                .edit_bookmark(bookmark_id, Some("Recap".to_string()))
+                .unwrap();
+
+            assert_eq!(progress.bookmarks()[0].label().unwrap().as_str(), "Recap");
+        }
+
+        #[test]
+        fn test_edit_unknown_bookmark_returns_error() {
+            let (mut progress, _) = create_single_lesson_progress();
+
+            let result = progress.edit_bookmark(Id::new(), None);
+
+            assert!(matches!(result, Err(CourseProgressError::BookmarkNotFound(_))));
+        }
+
+        #[test]
+        fn test_delete_bookmark_removes_bookmark() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+            let bookmark_id = progress
+                .add_bookmark(lesson_id, Duration::from_seconds(5), None)
+                .unwrap();
+
+            progress.delete_bookmark(bookmark_id).unwrap();
+
+            assert!(progress.bookmarks().is_empty());
+        }
+
+        #[test]
+        fn test_delete_unknown_bookmark_returns_error() {
+            let (mut progress, _) = create_single_lesson_progress();
+
+            let result = progress.delete_bookmark(Id::new());
+
+            assert!(matches!(result, Err(CourseProgressError::BookmarkNotFound(_))));
+        }
+
+        #[test]
+        fn test_lesson_bookmarks_orders_by_position() {
+            let (mut progress, lesson_id) = create_single_lesson_progress();
+            progress
+                .add_bookmark(lesson_id, Duration::from_seconds(300), None)
+                .unwrap();
+            progress
+                .add_bookmark(lesson_id, Duration::from_seconds(30), None)
+                .unwrap();
+            progress
+                .add_bookmark(lesson_id, Duration::from_seconds(120), None)
+                .unwrap();
+
+            let offsets: Vec<u64> = progress
+                .lesson_bookmarks(lesson_id)
+                .iter()
+                .map(|b| b.video_offset().total_seconds())
+                .collect();
+
+            assert_eq!(offsets, vec![30, 120, 300]);
+        }
+    }
+
+    mod toggle_favorite {
+        use super::*;
+
+        #[test]
+        fn test_new_progress_is_not_favorite() {
+            let (progress, _) = create_single_lesson_progress();
+
+            assert!(!progress.is_favorite());
+        }
+
+        #[test]
+        fn test_toggles_back_and_forth() {
+            let (mut progress, _) = create_single_lesson_progress();
+
+            assert!(progress.toggle_favorite());
+            assert!(progress.is_favorite());
+            assert!(!progress.toggle_favorite());
+            assert!(!progress.is_favorite());
+        }
+
+        #[test]
+        fn test_favorite_is_not_an_event() {
+            let (mut progress, _) = create_single_lesson_progress();
+            let pending = progress.pending_events().len();
+
+            progress.toggle_favorite();
+
+            assert_eq!(progress.pending_events().len(), pending);
+        }
+    }
+}
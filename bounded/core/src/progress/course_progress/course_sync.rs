@@ -0,0 +1,623 @@
+use crate::{
+    Course, CourseProgress, CourseProgressError, CourseVersion, Lesson, LessonOutline,
+    LessonProgress, ProgressEvent,
+};
+use education_platform_common::{Entity, Id, SimpleName, SimpleNameConfig};
+
+impl CourseProgress {
+    /// Creates a fresh progress record for a user starting the given course.
+    ///
+    /// Every lesson progress record reuses the ID of the lesson it tracks, so
+    /// the progress can later be reconciled against a modified course by ID
+    /// rather than by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::EmailError` if the email is not valid.
+    /// Returns `CourseProgressError::CourseError` if the course has no lessons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseProgress, Lesson};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+    ///
+    /// assert_eq!(progress.course_id(), Some(course.id()));
+    /// assert_eq!(progress.lesson_progress()[0].id(), lesson_id);
+    /// ```
+    pub fn from_course(
+        course: &Course,
+        user_email: impl Into<String>,
+    ) -> Result<Self, CourseProgressError> {
+        Self::enroll(course, None, user_email.into())
+    }
+
+    /// Creates a fresh progress record pinned to a published course version.
+    ///
+    /// The progress tracks the course as it is now, and remembers which
+    /// version the user enrolled in.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::CourseMismatch` if the version belongs to
+    /// another course, and the errors of [`CourseProgress::from_course`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseProgress, CourseVersionHistory, Lesson};
+    /// use education_platform_common::{DateTime, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let mut history = CourseVersionHistory::new(course.id());
+    /// let version = history
+    ///     .publish(&course, DateTime::new(2025, 1, 6, 9, 0, 0).unwrap())
+    ///     .unwrap();
+    ///
+    /// let progress = CourseProgress::from_version(&course, version, "user@example.com").unwrap();
+    ///
+    /// assert_eq!(progress.course_version(), Some(1));
+    /// ```
+    pub fn from_version(
+        course: &Course,
+        version: &CourseVersion,
+        user_email: impl Into<String>,
+    ) -> Result<Self, CourseProgressError> {
+        if version.course_id() != course.id() {
+            return Err(CourseProgressError::CourseMismatch {
+                expected: course.id().to_string(),
+                actual: version.course_id().to_string(),
+            });
+        }
+
+        Self::enroll(course, Some(version.number()), user_email.into())
+    }
+
+    /// Brings this progress in line with the current structure of its course.
+    ///
+    /// Lessons are matched by ID:
+    /// - Lessons that still exist keep their start and end dates, and pick up
+    ///   any new name or duration
+    /// - Lessons added to the course get a fresh, unstarted progress record
+    /// - Lessons removed from the course are dropped
+    /// - The resulting order follows the course's current chapter and lesson order
+    ///
+    /// The selected lesson is kept when it still exists, otherwise the first
+    /// lesson is selected. The course end date is recalculated, and a
+    /// `CourseEnded` event is published if reconciliation completes the course.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::CourseMismatch` if this progress tracks a
+    /// different course.
+    /// Returns `CourseProgressError::CourseError` if the course has no lessons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseProgress, Lesson};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let lesson_id = lesson.lesson_id();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+    /// progress.start_lesson(lesson_id);
+    ///
+    /// let extra = Lesson::new(
+    ///     "Ownership".to_string(),
+    ///     2400,
+    ///     "https://example.com/ownership.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let extra_chapter = Chapter::new("Deep Dive".to_string(), 1, vec![extra]).unwrap();
+    /// course.add_chapter(extra_chapter, None).unwrap();
+    ///
+    /// progress.reconcile(&course).unwrap();
+    ///
+    /// assert_eq!(progress.lesson_count(), 2);
+    /// assert!(progress.lesson_progress()[0].has_started());
+    /// assert!(!progress.lesson_progress()[1].has_started());
+    /// ```
+    pub fn reconcile(&mut self, course: &Course) -> Result<(), CourseProgressError> {
+        if let Some(course_id) = self.course_id
+            && course_id != course.id()
+        {
+            return Err(CourseProgressError::CourseMismatch {
+                expected: course_id.to_string(),
+                actual: course.id().to_string(),
+            });
+        }
+
+        let outlines: Vec<LessonOutline> = course.lessons()?.iter().map(Self::outline).collect();
+        self.sync_lessons(course.id(), course.name().as_str(), &outlines)?;
+        self.pending_events.push(ProgressEvent::CourseSynced {
+            course_id: course.id(),
+            course_name: course.name().to_string(),
+            lessons: outlines,
+        });
+        self.timestamps.touch();
+
+        Ok(())
+    }
+
+    /// Replaces the tracked lessons with the given outlines, keeping the
+    /// dates of lessons that are still there.
+    pub(super) fn sync_lessons(
+        &mut self,
+        course_id: Id,
+        course_name: &str,
+        outlines: &[LessonOutline],
+    ) -> Result<(), CourseProgressError> {
+        let lessons = outlines
+            .iter()
+            .map(|outline| {
+                let existing = self
+                    .lesson_progress
+                    .iter()
+                    .find(|lp| lp.id() == outline.id());
+                Self::progress_for_lesson(outline, existing)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let selected_lesson = Self::find_lesson_by_id(Some(self.selected_lesson.id()), &lessons)
+            .or_else(|_| Self::find_lesson_by_id(None, &lessons))?;
+        let was_ended = self.end_date.is_some();
+        let end_date = Self::calculate_end_date(&None, &lessons);
+
+        self.course_id = Some(course_id);
+        self.course_name =
+            SimpleName::with_config(course_name.to_string(), SimpleNameConfig::new(3, 50))?;
+        self.lesson_progress = lessons;
+        self.selected_lesson = selected_lesson;
+        self.end_date = end_date;
+
+        if !was_ended && self.end_date.is_some() {
+            self.publish_ended();
+        }
+
+        Ok(())
+    }
+
+    fn enroll(
+        course: &Course,
+        course_version: Option<u32>,
+        user_email: String,
+    ) -> Result<Self, CourseProgressError> {
+        let lessons = course
+            .lessons()?
+            .iter()
+            .map(|lesson| Self::progress_for_lesson(&Self::outline(lesson), None))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let builder = Self::builder()
+            .course_id(course.id())
+            .course_name(course.name().as_str())
+            .user_email(user_email)
+            .lessons(lessons);
+        match course_version {
+            Some(number) => builder.course_version(number).build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Builds the progress record for a lesson, carrying over the dates of
+    /// an existing record for the same lesson.
+    pub(super) fn progress_for_lesson(
+        outline: &LessonOutline,
+        existing: Option<&LessonProgress>,
+    ) -> Result<LessonProgress, CourseProgressError> {
+        let start_date = existing.and_then(|lp| lp.start_date()).copied();
+        let end_date = existing.and_then(|lp| lp.end_date()).copied();
+
+        LessonProgress::with_id(
+            outline.id(),
+            outline.name().to_string(),
+            outline.duration_seconds(),
+            start_date,
+            end_date,
+        )
+        .map_err(CourseProgressError::from)
+    }
+
+    fn outline(lesson: &Lesson) -> LessonOutline {
+        LessonOutline::new(lesson.id(), lesson.name().as_str(), lesson.duration().total_seconds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, CourseEnded};
+    use education_platform_common::{DomainEventDispatcher, Id};
+    use std::sync::{Arc, Mutex};
+
+    fn create_test_lesson(name: &str, duration: u64, index: usize) -> Lesson {
+        Lesson::new(
+            name.to_string(),
+            duration,
+            format!("https://example.com/{index}.mp4"),
+            index,
+        )
+        .unwrap()
+    }
+
+    fn create_test_course(lessons: Vec<Lesson>) -> Course {
+        let chapter = Chapter::new("Chapter One".to_string(), 0, lessons).unwrap();
+        Course::new("Test Course".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn lesson_ids(progress: &CourseProgress) -> Vec<Id> {
+        progress
+            .lesson_progress()
+            .iter()
+            .map(|lp| lp.id())
+            .collect()
+    }
+
+    mod from_course {
+        use super::*;
+
+        #[test]
+        fn test_from_course_creates_progress_for_every_lesson() {
+            let course = create_test_course(vec![
+                create_test_lesson("Lesson 1", 1800, 0),
+                create_test_lesson("Lesson 2", 2400, 1),
+            ]);
+
+            let progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            assert_eq!(progress.lesson_count(), 2);
+            assert_eq!(progress.course_name().as_str(), "Test Course");
+            assert_eq!(progress.user_email().address(), "user@example.com");
+        }
+
+        #[test]
+        fn test_from_course_reuses_lesson_ids() {
+            let lesson1 = create_test_lesson("Lesson 1", 1800, 0);
+            let lesson2 = create_test_lesson("Lesson 2", 2400, 1);
+            let expected = vec![lesson1.id(), lesson2.id()];
+            let course = create_test_course(vec![lesson1, lesson2]);
+
+            let progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            assert_eq!(lesson_ids(&progress), expected);
+        }
+
+        #[test]
+        fn test_from_course_records_course_id() {
+            let course = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+
+            let progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            assert_eq!(progress.course_id(), Some(course.id()));
+        }
+
+        #[test]
+        fn test_from_course_starts_with_no_activity() {
+            let course = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+
+            let progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            assert!(!progress.selected_lesson().has_started());
+            assert!(progress.end_date().is_none());
+        }
+
+        #[test]
+        fn test_from_course_rejects_invalid_email() {
+            let course = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+
+            let result = CourseProgress::from_course(&course, "not-an-email");
+
+            assert!(matches!(result, Err(CourseProgressError::EmailError(_))));
+        }
+    }
+
+    mod from_version {
+        use super::*;
+        use crate::CourseVersionHistory;
+        use education_platform_common::DateTime;
+
+        fn create_time() -> DateTime {
+            DateTime::new(2025, 1, 6, 9, 0, 0).unwrap()
+        }
+
+        #[test]
+        fn test_version_survives_reconcile() {
+            let mut course = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+            let mut history = CourseVersionHistory::new(course.id());
+            history.publish(&course, create_time()).unwrap();
+            let mut progress =
+                CourseProgress::from_version(&course, &history.versions()[0], "user@example.com")
+                    .unwrap();
+
+            let chapter_id = course.chapters()[0].id();
+            course
+                .add_lesson(chapter_id, create_test_lesson("Lesson 2", 2400, 1), None)
+                .unwrap();
+            history.publish(&course, create_time()).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            assert_eq!(progress.course_version(), Some(1));
+            assert_eq!(progress.lesson_count(), 2);
+        }
+
+        #[test]
+        fn test_version_of_other_course_is_rejected() {
+            let course = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+            let other = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+            let mut history = CourseVersionHistory::new(other.id());
+            let version = history.publish(&other, create_time()).unwrap();
+
+            let result = CourseProgress::from_version(&course, version, "user@example.com");
+
+            assert!(matches!(result, Err(CourseProgressError::CourseMismatch { .. })));
+        }
+    }
+
+    mod reconcile {
+        use super::*;
+
+        #[test]
+        fn test_reconcile_preserves_completion_state() {
+            let lesson = create_test_lesson("Lesson 1", 1800, 0);
+            let lesson_id = lesson.lesson_id();
+            let other = create_test_lesson("Lesson 2", 1800, 1);
+            let course = create_test_course(vec![lesson, other]);
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+            progress.start_lesson(lesson_id);
+            progress.end_lesson(lesson_id).unwrap();
+
+            progress.reconcile(&course).unwrap();
+
+            assert!(
+                progress
+                    .one_lesson_progress(lesson_id.untyped())
+                    .unwrap()
+                    .is_completed()
+            );
+        }
+
+        #[test]
+        fn test_reconcile_adds_new_lessons_unstarted() {
+            let lesson = create_test_lesson("Lesson 1", 1800, 0);
+            let lesson_id = lesson.lesson_id();
+            let mut course = create_test_course(vec![lesson]);
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+            progress.start_lesson(lesson_id);
+
+            let new_lesson = create_test_lesson("Lesson 2", 2400, 0);
+            let new_id = new_lesson.id();
+            let chapter = Chapter::new("Chapter Two".to_string(), 1, vec![new_lesson]).unwrap();
+            course.add_chapter(chapter, None).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            assert_eq!(lesson_ids(&progress), vec![lesson_id.untyped(), new_id]);
+            assert!(!progress.one_lesson_progress(new_id).unwrap().has_started());
+            assert!(
+                progress
+                    .one_lesson_progress(lesson_id.untyped())
+                    .unwrap()
+                    .has_started()
+            );
+        }
+
+        #[test]
+        fn test_reconcile_drops_removed_lessons() {
+            let keep = create_test_lesson("Keep Me", 1800, 0);
+            let keep_id = keep.id();
+            let chapter1 = Chapter::new("Chapter One".to_string(), 0, vec![keep]).unwrap();
+            let chapter2 = Chapter::new(
+                "Chapter Two".to_string(),
+                1,
+                vec![create_test_lesson("Remove Me", 1800, 0)],
+            )
+            .unwrap();
+            let mut course =
+                Course::new("Test Course".to_string(), None, 0, vec![chapter1, chapter2]).unwrap();
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            let removed_chapter = course.chapters()[1].clone();
+            course.delete_chapter(&removed_chapter).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            assert_eq!(lesson_ids(&progress), vec![keep_id]);
+        }
+
+        #[test]
+        fn test_reconcile_follows_new_lesson_order() {
+            let lesson1 = create_test_lesson("Lesson 1", 1800, 0);
+            let lesson2 = create_test_lesson("Lesson 2", 1800, 1);
+            let (id1, id2) = (lesson1.id(), lesson2.id());
+            let chapter1 = Chapter::new("Chapter One".to_string(), 0, vec![lesson1]).unwrap();
+            let chapter2 = Chapter::new("Chapter Two".to_string(), 1, vec![lesson2]).unwrap();
+            let mut course =
+                Course::new("Test Course".to_string(), None, 0, vec![chapter1, chapter2]).unwrap();
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            let second = course.chapters()[1].clone();
+            course.move_chapter_up(&second).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            assert_eq!(lesson_ids(&progress), vec![id2, id1]);
+        }
+
+        #[test]
+        fn test_reconcile_picks_up_renamed_lessons() {
+            let lesson = create_test_lesson("Old Name", 1800, 0);
+            let mut course = create_test_course(vec![lesson.clone()]);
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            let mut renamed = lesson;
+            renamed.update_name("New Name".to_string()).unwrap();
+            renamed.update_duration(3600);
+            course.update_lesson(renamed).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            let reconciled = &progress.lesson_progress()[0];
+            assert_eq!(reconciled.lesson_name().as_str(), "New Name");
+            assert_eq!(reconciled.duration().total_seconds(), 3600);
+        }
+
+        #[test]
+        fn test_reconcile_keeps_selected_lesson() {
+            let lesson1 = create_test_lesson("Lesson 1", 1800, 0);
+            let lesson2 = create_test_lesson("Lesson 2", 1800, 1);
+            let lesson2_id = lesson2.id();
+            let course = create_test_course(vec![lesson1, lesson2]);
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+            progress.select_lesson(lesson2_id).unwrap();
+
+            progress.reconcile(&course).unwrap();
+
+            assert_eq!(progress.selected_lesson().id(), lesson2_id);
+        }
+
+        #[test]
+        fn test_reconcile_selects_first_lesson_when_selected_removed() {
+            let first = create_test_lesson("First", 1800, 0);
+            let first_id = first.id();
+            let chapter1 = Chapter::new("Chapter One".to_string(), 0, vec![first]).unwrap();
+            let removed = create_test_lesson("Removed", 1800, 0);
+            let removed_id = removed.id();
+            let chapter2 = Chapter::new("Chapter Two".to_string(), 1, vec![removed]).unwrap();
+            let mut course =
+                Course::new("Test Course".to_string(), None, 0, vec![chapter1, chapter2]).unwrap();
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+            progress.select_lesson(removed_id).unwrap();
+
+            let chapter = course.chapters()[1].clone();
+            course.delete_chapter(&chapter).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            assert_eq!(progress.selected_lesson().id(), first_id);
+        }
+
+        #[test]
+        fn test_reconcile_clears_end_date_when_lessons_added() {
+            let lesson = create_test_lesson("Lesson 1", 1800, 0);
+            let lesson_id = lesson.lesson_id();
+            let mut course = create_test_course(vec![lesson]);
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+            progress.start_lesson(lesson_id);
+            progress.end_lesson(lesson_id).unwrap();
+            progress.reconcile(&course).unwrap();
+            assert!(progress.end_date().is_some());
+
+            let chapter = Chapter::new(
+                "Chapter Two".to_string(),
+                1,
+                vec![create_test_lesson("Lesson 2", 1800, 0)],
+            )
+            .unwrap();
+            course.add_chapter(chapter, None).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            assert!(progress.end_date().is_none());
+            assert!(!progress.is_completed());
+        }
+
+        #[test]
+        fn test_reconcile_publishes_event_when_course_becomes_completed() {
+            let done = create_test_lesson("Done", 1800, 0);
+            let done_id = done.lesson_id();
+            let chapter1 = Chapter::new("Chapter One".to_string(), 0, vec![done]).unwrap();
+            let chapter2 = Chapter::new(
+                "Chapter Two".to_string(),
+                1,
+                vec![create_test_lesson("Pending", 1800, 0)],
+            )
+            .unwrap();
+            let mut course =
+                Course::new("Test Course".to_string(), None, 0, vec![chapter1, chapter2]).unwrap();
+
+            let dispatcher = Arc::new(DomainEventDispatcher::<CourseEnded>::new());
+            let received = Arc::new(Mutex::new(0));
+            let received_clone = Arc::clone(&received);
+            dispatcher.subscribe(move |_| *received_clone.lock().unwrap() += 1);
+
+            let lessons = course
+                .lessons()
+                .unwrap()
+                .iter()
+                .map(|lesson| {
+                    CourseProgress::progress_for_lesson(&CourseProgress::outline(lesson), None)
+                        .unwrap()
+                })
+                .collect();
+            let mut progress = CourseProgress::builder()
+                .course_id(course.id())
+                .course_name("Test Course")
+                .user_email("user@example.com")
+                .lessons(lessons)
+                .event_dispatcher(dispatcher)
+                .build()
+                .unwrap();
+            progress.start_lesson(done_id);
+            progress.end_lesson(done_id).unwrap();
+
+            let pending_chapter = course.chapters()[1].clone();
+            course.delete_chapter(&pending_chapter).unwrap();
+            progress.reconcile(&course).unwrap();
+
+            assert!(progress.end_date().is_some());
+            assert_eq!(*received.lock().unwrap(), 1);
+        }
+
+        #[test]
+        fn test_reconcile_rejects_other_course() {
+            let course = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+            let other = create_test_course(vec![create_test_lesson("Lesson 1", 1800, 0)]);
+            let mut progress = CourseProgress::from_course(&course, "user@example.com").unwrap();
+
+            let result = progress.reconcile(&other);
+
+            assert!(matches!(result, Err(CourseProgressError::CourseMismatch { .. })));
+            assert_eq!(progress.course_id(), Some(course.id()));
+        }
+
+        #[test]
+        fn test_reconcile_adopts_course_when_unknown() {
+            let lesson = create_test_lesson("Lesson 1", 1800, 0);
+            let course = create_test_course(vec![lesson.clone()]);
+            let lesson_progress =
+                LessonProgress::with_id(lesson.id(), "Lesson 1".to_string(), 1800, None, None)
+                    .unwrap();
+            let mut progress = CourseProgress::builder()
+                .course_name("Test Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson_progress])
+                .build()
+                .unwrap();
+
+            progress.reconcile(&course).unwrap();
+
+            assert_eq!(progress.course_id(), Some(course.id()));
+        }
+    }
+}
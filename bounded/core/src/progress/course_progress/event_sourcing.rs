@@ -0,0 +1,491 @@
+use crate::{
+    CourseProgress, CourseProgressError, EventStore, LessonOutline, LessonProgress, ProgressEvent,
+};
+use education_platform_common::{DateTime, Email, Entity, Id, SimpleName, Timestamps};
+
+impl CourseProgress {
+    /// Returns the events recorded since the progress was created, loaded or
+    /// last saved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressEvent};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.lesson_id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// progress.start_lesson(lesson_id);
+    ///
+    /// assert!(matches!(progress.pending_events()[0], ProgressEvent::Enrolled { .. }));
+    /// assert!(matches!(
+    ///     progress.pending_events()[1],
+    ///     ProgressEvent::LessonStarted { .. }
+    /// ));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn pending_events(&self) -> &[ProgressEvent] {
+        &self.pending_events
+    }
+
+    /// Returns how many events of this progress are already in the store.
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Appends the pending events to the progress stream in a store.
+    ///
+    /// Nothing is written when no events are pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::EventStoreError` if the stream changed
+    /// since this progress was loaded, or the store cannot be written. The
+    /// pending events are kept so the save can be retried after reloading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, InMemoryEventStore, LessonProgress};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// let mut store = InMemoryEventStore::new();
+    ///
+    /// progress.save(&mut store).unwrap();
+    ///
+    /// assert_eq!(progress.version(), 1);
+    /// assert!(progress.pending_events().is_empty());
+    /// ```
+    pub fn save(&mut self, store: &mut impl EventStore) -> Result<(), CourseProgressError> {
+        if self.pending_events.is_empty() {
+            return Ok(());
+        }
+
+        self.version = store.append(self.id, self.version, &self.pending_events)?;
+        self.pending_events.clear();
+        Ok(())
+    }
+
+    /// Loads a progress by replaying its stream from a store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::EventStoreError` if the store cannot be
+    /// read, and `CourseProgressError::EventStreamNotValid` if the stream is
+    /// empty or malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, InMemoryEventStore, LessonProgress};
+    /// use education_platform_common::Entity;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.lesson_id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("Rust Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    /// progress.start_lesson(lesson_id);
+    /// let mut store = InMemoryEventStore::new();
+    /// progress.save(&mut store).unwrap();
+    ///
+    /// let loaded = CourseProgress::load(&store, progress.id()).unwrap();
+    ///
+    /// assert_eq!(loaded.version(), 2);
+    /// assert!(loaded.lesson_progress()[0].has_started());
+    /// ```
+    pub fn load(store: &impl EventStore, id: Id) -> Result<Self, CourseProgressError> {
+        let events = store.load(id)?;
+        Self::replay(&events)
+    }
+
+    /// Rebuilds a progress from its events.
+    ///
+    /// Replaying a prefix of a stream gives the progress as it was after the
+    /// last event of the prefix. The result has no pending events and its
+    /// version is the number of events replayed. Notes, bookmarks and the
+    /// favorite mark are not part of the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::EventStreamNotValid` if the events do not
+    /// start with a single `ProgressEvent::Enrolled`, or refer to a lesson the
+    /// progress does not track.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonOutline, ProgressEvent};
+    /// use education_platform_common::{DateTime, Email, Entity, Id};
+    ///
+    /// let lesson_id = Id::new();
+    /// let at = DateTime::new(2025, 3, 4, 18, 0, 0).unwrap();
+    /// let events = vec![
+    ///     ProgressEvent::Enrolled {
+    ///         progress_id: Id::new(),
+    ///         course_id: None,
+    ///         course_version: None,
+    ///         course_name: "Rust Course".to_string(),
+    ///         user_email: Email::new("user@example.com".to_string()).unwrap(),
+    ///         lessons: vec![LessonOutline::new(lesson_id, "Intro", 1800)],
+    ///     },
+    ///     ProgressEvent::LessonCompleted { lesson_id, at },
+    /// ];
+    ///
+    /// let progress = CourseProgress::replay(&events).unwrap();
+    ///
+    /// assert!(progress.is_completed());
+    /// assert_eq!(progress.end_date(), Some(at));
+    /// ```
+    pub fn replay(events: &[ProgressEvent]) -> Result<Self, CourseProgressError> {
+        let Some((
+            ProgressEvent::Enrolled {
+                progress_id,
+                course_id,
+                course_version,
+                course_name,
+                user_email,
+                lessons,
+            },
+            rest,
+        )) = events.split_first()
+        else {
+            return Err(CourseProgressError::EventStreamNotValid(
+                "stream must start with an enrollment".to_string(),
+            ));
+        };
+
+        let lessons = lessons
+            .iter()
+            .map(|outline| Self::progress_for_lesson(outline, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Enrollment carries no time of its own; the progress ID records
+        // when it was generated.
+        let created_at =
+            DateTime::from_timestamp_ms(progress_id.timestamp_ms()).unwrap_or_else(DateTime::today);
+        let mut builder = Self::builder()
+            .id(*progress_id)
+            .timestamps(Timestamps::since(created_at))
+            .course_name(course_name.as_str())
+            .user_email(user_email.address())
+            .lessons(lessons);
+        if let Some(course_id) = course_id {
+            builder = builder.course_id(*course_id);
+        }
+        if let Some(number) = course_version {
+            builder = builder.course_version(*number);
+        }
+
+        let mut progress = builder.build()?;
+        for event in rest {
+            progress.apply(event)?;
+        }
+        progress.pending_events.clear();
+        progress.version = events.len();
+        Ok(progress)
+    }
+
+    /// Builds the events describing a newly created progress.
+    pub(super) fn enrollment_events(
+        progress_id: Id,
+        course_id: Option<Id>,
+        course_version: Option<u32>,
+        course_name: &SimpleName,
+        user_email: &Email,
+        lessons: &[LessonProgress],
+    ) -> Vec<ProgressEvent> {
+        let outlines = lessons
+            .iter()
+            .map(|lp| {
+                LessonOutline::new(
+                    lp.id(),
+                    lp.lesson_name().as_str(),
+                    lp.duration().total_seconds(),
+                )
+            })
+            .collect();
+        let mut events = vec![ProgressEvent::Enrolled {
+            progress_id,
+            course_id,
+            course_version,
+            course_name: course_name.to_string(),
+            user_email: user_email.clone(),
+            lessons: outlines,
+        }];
+
+        for lesson in lessons {
+            let lesson_id = lesson.id();
+            if let Some(&at) = lesson.start_date() {
+                events.push(ProgressEvent::LessonStarted { lesson_id, at });
+            }
+            if let Some(&at) = lesson.end_date() {
+                events.push(ProgressEvent::LessonCompleted { lesson_id, at });
+            }
+        }
+        events
+    }
+
+    fn apply(&mut self, event: &ProgressEvent) -> Result<(), CourseProgressError> {
+        let (lesson_id, at) = match event {
+            ProgressEvent::Enrolled { .. } => {
+                return Err(CourseProgressError::EventStreamNotValid(
+                    "enrollment can only be the first event".to_string(),
+                ));
+            }
+            ProgressEvent::CourseSynced {
+                course_id,
+                course_name,
+                lessons,
+            } => return self.sync_lessons(*course_id, course_name, lessons),
+            ProgressEvent::LessonStarted { lesson_id, at }
+            | ProgressEvent::LessonCompleted { lesson_id, at }
+            | ProgressEvent::LessonRestarted { lesson_id, at } => (*lesson_id, *at),
+        };
+
+        let lesson = self
+            .lesson_progress
+            .iter()
+            .find(|lp| lp.id() == lesson_id)
+            .ok_or_else(|| {
+                CourseProgressError::EventStreamNotValid(format!("unknown lesson {lesson_id}"))
+            })?;
+        let replayed = match event {
+            ProgressEvent::LessonStarted { .. } => Self::with_dates(
+                lesson,
+                Self::earliest(lesson.start_date(), Some(&at)),
+                lesson.end_date().copied(),
+            )?,
+            ProgressEvent::LessonCompleted { .. } => Self::with_dates(
+                lesson,
+                Self::earliest(lesson.start_date(), Some(&at)),
+                Self::earliest(lesson.end_date(), Some(&at)),
+            )?,
+            _ => Self::with_dates(lesson, None, None)?,
+        };
+
+        self.replace_lessons(vec![replayed]);
+        self.creation_date = Some(at);
+        self.timestamps.touch_at(at);
+        self.record_activity(at.date());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Course, InMemoryEventStore, Lesson};
+    use education_platform_common::Auditable;
+
+    fn create_test_course() -> Course {
+        let lessons = ["Intro", "Ownership"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| {
+                Lesson::new(
+                    name.to_string(),
+                    1800,
+                    format!("https://example.com/{index}.mp4"),
+                    index,
+                )
+                .unwrap()
+            })
+            .collect();
+        let chapter = Chapter::new("Basics".to_string(), 0, lessons).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_time(day: u32) -> DateTime {
+        DateTime::new(2025, 3, day, 18, 0, 0).unwrap()
+    }
+
+    fn lesson_dates(progress: &CourseProgress) -> Vec<(Option<DateTime>, Option<DateTime>)> {
+        progress
+            .lesson_progress()
+            .iter()
+            .map(|lp| (lp.start_date().copied(), lp.end_date().copied()))
+            .collect()
+    }
+
+    mod persistence {
+        use super::*;
+
+        #[test]
+        fn test_loaded_progress_keeps_creation_time() {
+            let course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let mut store = InMemoryEventStore::new();
+            progress.save(&mut store).unwrap();
+
+            let loaded = CourseProgress::load(&store, progress.id()).unwrap();
+
+            let drift = loaded.created_at().seconds_until(&progress.created_at());
+            assert!((0..=1).contains(&drift));
+        }
+
+        #[test]
+        fn test_saved_progress_loads_with_same_lesson_state() {
+            let course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let first = progress.lesson_progress()[0].lesson_id();
+            let second = progress.lesson_progress()[1].lesson_id();
+            let mut store = InMemoryEventStore::new();
+
+            progress.start_lesson(first);
+            progress.end_lesson(first).unwrap();
+            progress.save(&mut store).unwrap();
+            progress.start_lesson(second);
+            progress.restart_lesson(second);
+            progress.save(&mut store).unwrap();
+
+            let loaded = CourseProgress::load(&store, progress.id()).unwrap();
+
+            assert_eq!(loaded.id(), progress.id());
+            assert_eq!(loaded.course_id(), Some(course.id()));
+            assert_eq!(loaded.version(), 5);
+            assert_eq!(lesson_dates(&loaded), lesson_dates(&progress));
+        }
+
+        #[test]
+        fn test_concurrent_save_is_rejected() {
+            let course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let lesson_id = progress.lesson_progress()[0].lesson_id();
+            let mut store = InMemoryEventStore::new();
+            progress.save(&mut store).unwrap();
+            let mut other_device = CourseProgress::load(&store, progress.id()).unwrap();
+
+            other_device.start_lesson(lesson_id);
+            other_device.save(&mut store).unwrap();
+            progress.start_lesson(lesson_id);
+            let result = progress.save(&mut store);
+
+            assert!(matches!(result, Err(CourseProgressError::EventStoreError(_))));
+            assert_eq!(progress.pending_events().len(), 1);
+        }
+
+        #[test]
+        fn test_unknown_stream_cannot_be_loaded() {
+            let store = InMemoryEventStore::new();
+
+            let result = CourseProgress::load(&store, Id::new());
+
+            assert!(matches!(result, Err(CourseProgressError::EventStreamNotValid(_))));
+        }
+    }
+
+    mod replay {
+        use super::*;
+
+        #[test]
+        fn test_prefix_gives_earlier_state() {
+            let course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let lesson_id = progress.lesson_progress()[0].id();
+            progress
+                .mark_lesson_completed(lesson_id, create_time(4))
+                .unwrap();
+            let events = progress.pending_events().to_vec();
+
+            let before = CourseProgress::replay(&events[..1]).unwrap();
+            let after = CourseProgress::replay(&events).unwrap();
+
+            assert!(!before.lesson_progress()[0].has_started());
+            assert_eq!(after.lesson_progress()[0].end_date(), Some(&create_time(4)));
+        }
+
+        #[test]
+        fn test_merged_dates_survive_replay() {
+            let course = create_test_course();
+            let mut primary = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let mut duplicate = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let lesson_id = primary.lesson_progress()[1].id();
+            duplicate
+                .mark_lesson_completed(lesson_id, create_time(2))
+                .unwrap();
+
+            primary.merge_from(&duplicate).unwrap();
+            let replayed = CourseProgress::replay(primary.pending_events()).unwrap();
+
+            assert_eq!(lesson_dates(&replayed), lesson_dates(&primary));
+        }
+
+        #[test]
+        fn test_course_sync_is_replayed() {
+            let mut course = create_test_course();
+            let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let extra = Lesson::new(
+                "Borrowing".to_string(),
+                900,
+                "https://example.com/2.mp4".to_string(),
+                0,
+            )
+            .unwrap();
+            let chapter = Chapter::new("Advanced".to_string(), 1, vec![extra]).unwrap();
+            course.add_chapter(chapter, None).unwrap();
+
+            progress.reconcile(&course).unwrap();
+            let replayed = CourseProgress::replay(progress.pending_events()).unwrap();
+
+            assert_eq!(replayed.lesson_count(), 3);
+        }
+
+        #[test]
+        fn test_stream_must_start_with_enrollment() {
+            let event = ProgressEvent::LessonStarted {
+                lesson_id: Id::new(),
+                at: create_time(4),
+            };
+
+            let result = CourseProgress::replay(&[event]);
+
+            assert!(matches!(result, Err(CourseProgressError::EventStreamNotValid(_))));
+        }
+
+        #[test]
+        fn test_second_enrollment_is_rejected() {
+            let course = create_test_course();
+            let progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let enrolled = progress.pending_events()[0].clone();
+
+            let result = CourseProgress::replay(&[enrolled.clone(), enrolled]);
+
+            assert!(matches!(result, Err(CourseProgressError::EventStreamNotValid(_))));
+        }
+
+        #[test]
+        fn test_unknown_lesson_is_rejected() {
+            let course = create_test_course();
+            let progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+            let events = [
+                progress.pending_events()[0].clone(),
+                ProgressEvent::LessonCompleted {
+                    lesson_id: Id::new(),
+                    at: create_time(4),
+                },
+            ];
+
+            let result = CourseProgress::replay(&events);
+
+            assert!(matches!(result, Err(CourseProgressError::EventStreamNotValid(_))));
+        }
+    }
+}
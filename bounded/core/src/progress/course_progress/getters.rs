@@ -1,7 +1,50 @@
-use super::{CourseProgress, Email, LessonProgress, SimpleName};
-use education_platform_common::DateTime;
+use super::{Bookmark, CourseProgress, Email, LessonProgress, Note, SimpleName};
+use education_platform_common::{Date, DateTime, Id};
+use std::collections::BTreeSet;
 
 impl CourseProgress {
+    /// Returns the ID of the course this progress tracks, if known.
+    ///
+    /// Progress created with [`CourseProgress::from_course`] always records the
+    /// course ID; progress assembled manually through the builder may not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseEnded, CourseProgress, LessonProgress};
+    /// use education_platform_common::{DomainEventDispatcher, Id};
+    /// use std::sync::Arc;
+    ///
+    /// let course_id = Id::new();
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let dispatcher = Arc::new(DomainEventDispatcher::<CourseEnded>::new());
+    /// let progress = CourseProgress::builder()
+    ///     .course_id(course_id)
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .event_dispatcher(dispatcher)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(progress.course_id(), Some(course_id));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Option<Id> {
+        self.course_id
+    }
+
+    /// Returns the number of the course version the user started, if known.
+    ///
+    /// The number stays the same when the progress is reconciled with a
+    /// newer course structure.
+    #[inline]
+    #[must_use]
+    pub const fn course_version(&self) -> Option<u32> {
+        self.course_version
+    }
+
     /// Returns the course name.
     ///
     /// # Examples
@@ -169,6 +212,107 @@ impl CourseProgress {
         &self.selected_lesson
     }
 
+    /// Returns the days on which the user was active in the course, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Date, DateTime};
+    ///
+    /// let start = DateTime::new(2024, 3, 1, 9, 0, 0).unwrap();
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(start), None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(progress.active_days().contains(&Date::new(2024, 3, 1).unwrap()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn active_days(&self) -> &BTreeSet<Date> {
+        &self.active_days
+    }
+
+    /// Returns every note taken in the course, in creation order.
+    ///
+    /// Use [`CourseProgress::lesson_notes`] to list the notes of one lesson
+    /// by video position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(progress.notes().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+
+    /// Returns every bookmark placed in the course, in creation order.
+    ///
+    /// Use [`CourseProgress::lesson_bookmarks`] to list the bookmarks of one
+    /// lesson by video position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(progress.bookmarks().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    /// Returns `true` if the user marked the course as a favorite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .favorite(true)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(progress.is_favorite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_favorite(&self) -> bool {
+        self.favorite
+    }
+
     /// Returns the number of lessons in the course.
     ///
     /// # Examples
@@ -225,6 +369,33 @@ mod tests {
             .unwrap()
     }
 
+    mod course_id {
+        use super::*;
+
+        #[test]
+        fn test_course_id_defaults_to_none() {
+            let progress = create_test_progress();
+
+            assert!(progress.course_id().is_none());
+        }
+
+        #[test]
+        fn test_course_id_returns_builder_value() {
+            let course_id = Id::new();
+            let lesson = create_test_lesson("Intro", 1800);
+            let progress = CourseProgress::builder()
+                .course_id(course_id)
+                .course_name("Test Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson])
+                .event_dispatcher(create_test_dispatcher())
+                .build()
+                .unwrap();
+
+            assert_eq!(progress.course_id(), Some(course_id));
+        }
+    }
+
     mod course_name {
         use super::*;
 
@@ -528,6 +699,45 @@ mod tests {
         }
     }
 
+    mod active_days {
+        use super::*;
+
+        #[test]
+        fn test_active_days_empty_without_activity() {
+            let progress = create_test_progress();
+
+            assert!(progress.active_days().is_empty());
+        }
+
+        #[test]
+        fn test_active_days_include_lesson_dates_and_builder_days() {
+            let start = DateTime::new(2024, 3, 1, 9, 0, 0).unwrap();
+            let end = DateTime::new(2024, 3, 2, 9, 0, 0).unwrap();
+            let lesson =
+                LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+            let extra = Date::new(2024, 2, 28).unwrap();
+
+            let progress = CourseProgress::builder()
+                .course_name("Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson])
+                .active_days([extra])
+                .event_dispatcher(create_test_dispatcher())
+                .build()
+                .unwrap();
+
+            let days: Vec<Date> = progress.active_days().iter().copied().collect();
+            assert_eq!(
+                days,
+                vec![
+                    extra,
+                    Date::new(2024, 3, 1).unwrap(),
+                    Date::new(2024, 3, 2).unwrap()
+                ]
+            );
+        }
+    }
+
     mod lesson_count {
         use super::*;
 
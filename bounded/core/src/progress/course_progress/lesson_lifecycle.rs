@@ -1,5 +1,5 @@
-use crate::{CourseProgress, CourseProgressError};
-use education_platform_common::{DateTime, Entity, Id};
+use crate::{CourseProgress, CourseProgressError, LessonId, ProgressEvent};
+use education_platform_common::{Date, DateTime};
 
 impl CourseProgress {
     /// Starts a lesson by setting its start creation_date today.
@@ -11,11 +11,11 @@ impl CourseProgress {
     ///
     /// ```
     /// use education_platform_core::{CourseEnded, CourseProgress, LessonProgress};
-    /// use education_platform_common::{DomainEventDispatcher, Entity};
+    /// use education_platform_common::DomainEventDispatcher;
     /// use std::sync::Arc;
     ///
     /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
-    /// let lesson_id = lesson.id();
+    /// let lesson_id = lesson.lesson_id();
     /// let dispatcher = Arc::new(DomainEventDispatcher::<CourseEnded>::new());
     /// let mut progress = CourseProgress::builder()
     ///     .course_name("Course")
@@ -28,19 +28,28 @@ impl CourseProgress {
     /// progress.start_lesson(lesson_id);
     /// assert!(progress.lesson_progress()[0].has_started());
     /// ```
-    pub fn start_lesson(&mut self, lesson_id: Id) {
+    pub fn start_lesson(&mut self, lesson_id: LessonId) {
         if let Some(lesson) = self
             .lesson_progress
             .iter_mut()
-            .find(|lp| lp.id() == lesson_id)
+            .find(|lp| lp.lesson_id() == lesson_id)
         {
+            let was_started = lesson.has_started();
             lesson.start();
-            if self.selected_lesson.id() == lesson_id {
+            if self.selected_lesson.lesson_id() == lesson_id {
                 self.selected_lesson = lesson.clone();
             }
+            if let (false, Some(at)) = (was_started, lesson.start_date().copied()) {
+                self.pending_events.push(ProgressEvent::LessonStarted {
+                    lesson_id: lesson_id.untyped(),
+                    at,
+                });
+            }
         }
 
         self.creation_date = Some(DateTime::today());
+        self.timestamps.touch();
+        self.record_activity(Date::today());
     }
 
     /// Ends a lesson by setting its end creation_date today.
@@ -56,11 +65,11 @@ impl CourseProgress {
     ///
     /// ```
     /// use education_platform_core::{CourseEnded, CourseProgress, LessonProgress};
-    /// use education_platform_common::{DomainEventDispatcher, Entity};
+    /// use education_platform_common::DomainEventDispatcher;
     /// use std::sync::Arc;
     ///
     /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
-    /// let lesson_id = lesson.id();
+    /// let lesson_id = lesson.lesson_id();
     /// let dispatcher = Arc::new(DomainEventDispatcher::<CourseEnded>::new());
     /// let mut progress = CourseProgress::builder()
     ///     .course_name("Course")
@@ -75,7 +84,7 @@ impl CourseProgress {
     /// progress.end_lesson(lesson_id).unwrap();
     /// assert!(progress.lesson_progress()[0].has_ended());
     /// ```
-    pub fn end_lesson(&mut self, lesson_id: Id) -> Result<(), CourseProgressError> {
+    pub fn end_lesson(&mut self, lesson_id: LessonId) -> Result<(), CourseProgressError> {
         if self.is_completed() {
             return Ok(());
         }
@@ -83,15 +92,24 @@ impl CourseProgress {
         if let Some(lesson) = self
             .lesson_progress
             .iter_mut()
-            .find(|lp| lp.id() == lesson_id)
+            .find(|lp| lp.lesson_id() == lesson_id)
         {
+            let was_ended = lesson.has_ended();
             lesson.end()?;
-            if self.selected_lesson.id() == lesson_id {
+            if self.selected_lesson.lesson_id() == lesson_id {
                 self.selected_lesson = lesson.clone();
             }
+            if let (false, Some(at)) = (was_ended, lesson.end_date().copied()) {
+                self.pending_events.push(ProgressEvent::LessonCompleted {
+                    lesson_id: lesson_id.untyped(),
+                    at,
+                });
+            }
         }
 
         self.creation_date = Some(DateTime::today());
+        self.timestamps.touch();
+        self.record_activity(Date::today());
         Ok(())
     }
 
@@ -103,11 +121,11 @@ impl CourseProgress {
     ///
     /// ```
     /// use education_platform_core::{CourseEnded, CourseProgress, LessonProgress};
-    /// use education_platform_common::{DomainEventDispatcher, Entity};
+    /// use education_platform_common::DomainEventDispatcher;
     /// use std::sync::Arc;
     ///
     /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
-    /// let lesson_id = lesson.id();
+    /// let lesson_id = lesson.lesson_id();
     /// let dispatcher = Arc::new(DomainEventDispatcher::<CourseEnded>::new());
     /// let mut progress = CourseProgress::builder()
     ///     .course_name("Course")
@@ -121,19 +139,28 @@ impl CourseProgress {
     /// progress.restart_lesson(lesson_id);
     /// assert!(!progress.lesson_progress()[0].has_started());
     /// ```
-    pub fn restart_lesson(&mut self, lesson_id: Id) {
+    pub fn restart_lesson(&mut self, lesson_id: LessonId) {
         if let Some(lesson) = self
             .lesson_progress
             .iter_mut()
-            .find(|lp| lp.id() == lesson_id)
+            .find(|lp| lp.lesson_id() == lesson_id)
         {
+            let was_started = lesson.has_started();
             lesson.restart();
-            if self.selected_lesson.id() == lesson_id {
+            if self.selected_lesson.lesson_id() == lesson_id {
                 self.selected_lesson = lesson.clone();
             }
+            if was_started {
+                self.pending_events.push(ProgressEvent::LessonRestarted {
+                    lesson_id: lesson_id.untyped(),
+                    at: DateTime::today(),
+                });
+            }
         }
 
         self.creation_date = Some(DateTime::today());
+        self.timestamps.touch();
+        self.record_activity(Date::today());
     }
 
     /// Toggles a lesson's completion status.
@@ -155,7 +182,7 @@ impl CourseProgress {
     ///
     /// let start = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
     /// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(start), None).unwrap();
-    /// let lesson_id = lesson.id();
+    /// let lesson_id = lesson.lesson_id();
     /// let dispatcher = Arc::new(DomainEventDispatcher::<CourseEnded>::new());
     /// let mut progress = CourseProgress::builder()
     ///     .course_name("Course")
@@ -169,8 +196,11 @@ impl CourseProgress {
     /// progress.toggle_lesson_completion(lesson_id).unwrap();
     /// assert!(progress.lesson_progress()[0].is_completed());
     /// ```
-    pub fn toggle_lesson_completion(&mut self, lesson_id: Id) -> Result<(), CourseProgressError> {
-        let lesson = self.one_lesson_progress(lesson_id)?;
+    pub fn toggle_lesson_completion(
+        &mut self,
+        lesson_id: LessonId,
+    ) -> Result<(), CourseProgressError> {
+        let lesson = self.one_lesson_progress(lesson_id.untyped())?;
 
         if !lesson.is_completed() {
             return self.end_lesson(lesson_id);
@@ -185,7 +215,7 @@ impl CourseProgress {
 mod tests {
     use super::*;
     use crate::{CourseEnded, LessonProgress};
-    use education_platform_common::{DateTime, DomainEventDispatcher};
+    use education_platform_common::{DateTime, DomainEventDispatcher, Id};
     use std::sync::Arc;
 
     fn create_test_dispatcher() -> Arc<DomainEventDispatcher<CourseEnded>> {
@@ -236,7 +266,7 @@ mod tests {
         #[test]
         fn test_start_lesson_sets_start_date() {
             let lesson = create_test_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.start_lesson(lesson_id);
@@ -247,7 +277,7 @@ mod tests {
         #[test]
         fn test_start_lesson_updates_date() {
             let lesson = create_test_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.start_lesson(lesson_id);
@@ -258,7 +288,7 @@ mod tests {
         #[test]
         fn test_start_lesson_unknown_id_returns_unchanged() {
             let mut progress = create_test_progress();
-            let unknown_id = Id::new();
+            let unknown_id: LessonId = Id::new().cast();
 
             progress.start_lesson(unknown_id);
 
@@ -268,7 +298,7 @@ mod tests {
         #[test]
         fn test_start_lesson_idempotent() {
             let lesson = create_test_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.start_lesson(lesson_id);
@@ -285,7 +315,7 @@ mod tests {
         #[test]
         fn test_end_lesson_sets_end_date() {
             let lesson = create_started_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.end_lesson(lesson_id).unwrap();
@@ -296,7 +326,7 @@ mod tests {
         #[test]
         fn test_end_lesson_fails_if_not_started() {
             let lesson = create_test_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             let result = progress.end_lesson(lesson_id);
@@ -307,7 +337,7 @@ mod tests {
         #[test]
         fn test_end_lesson_returns_unchanged_if_course_completed() {
             let lesson = create_completed_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.end_lesson(lesson_id).unwrap();
@@ -318,7 +348,7 @@ mod tests {
         #[test]
         fn test_end_lesson_updates_date() {
             let lesson = create_started_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.end_lesson(lesson_id).unwrap();
@@ -333,7 +363,7 @@ mod tests {
         #[test]
         fn test_restart_lesson_clears_dates() {
             let lesson = create_completed_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.restart_lesson(lesson_id);
@@ -345,7 +375,7 @@ mod tests {
         #[test]
         fn test_restart_lesson_not_started_returns_unchanged() {
             let lesson = create_test_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.restart_lesson(lesson_id);
@@ -356,7 +386,7 @@ mod tests {
         #[test]
         fn test_restart_lesson_updates_date() {
             let lesson = create_completed_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.restart_lesson(lesson_id);
@@ -371,7 +401,7 @@ mod tests {
         #[test]
         fn test_toggle_ends_started_lesson() {
             let lesson = create_started_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.toggle_lesson_completion(lesson_id).unwrap();
@@ -382,7 +412,7 @@ mod tests {
         #[test]
         fn test_toggle_restarts_completed_lesson() {
             let lesson = create_completed_lesson("Lesson", 1800);
-            let lesson_id = lesson.id();
+            let lesson_id = lesson.lesson_id();
             let mut progress = create_progress(vec![lesson]);
 
             progress.toggle_lesson_completion(lesson_id).unwrap();
@@ -393,7 +423,7 @@ mod tests {
         #[test]
         fn test_toggle_fails_for_unknown_id() {
             let mut progress = create_test_progress();
-            let unknown_id = Id::new();
+            let unknown_id: LessonId = Id::new().cast();
 
             let result = progress.toggle_lesson_completion(unknown_id);
 
@@ -33,6 +33,7 @@ impl CourseProgress {
     /// ```
     pub fn select_lesson(&mut self, lesson_id: Id) -> Result<(), CourseProgressError> {
         self.selected_lesson = Self::find_lesson_by_id(Some(lesson_id), &self.lesson_progress)?;
+        self.timestamps.touch();
         Ok(())
     }
 
@@ -72,6 +73,7 @@ impl CourseProgress {
 
         if let Some(next_lesson) = self.lesson_progress.get(current_index + 1) {
             self.selected_lesson = next_lesson.clone();
+            self.timestamps.touch();
         }
     }
 
@@ -117,6 +119,7 @@ impl CourseProgress {
 
         if let Some(prev_lesson) = self.lesson_progress.get(current_index - 1) {
             self.selected_lesson = prev_lesson.clone();
+            self.timestamps.touch();
         }
     }
 
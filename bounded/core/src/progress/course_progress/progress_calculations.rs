@@ -1,6 +1,6 @@
 use super::{CourseProgress, Duration};
 use crate::LessonProgress;
-use education_platform_common::DateTime;
+use education_platform_common::{DateTime, Percentage};
 
 impl CourseProgress {
     /// Returns true if all lessons in the course have been completed.
@@ -57,9 +57,7 @@ impl CourseProgress {
     /// ```
     #[must_use]
     pub fn total_duration(&self) -> Duration {
-        self.lesson_progress
-            .iter()
-            .fold(Duration::default(), |acc, lp| acc.add(&lp.duration()))
+        self.lesson_progress.iter().map(|lp| lp.duration()).sum()
     }
 
     /// Returns the total duration of all completed (ended) lessons.
@@ -91,7 +89,8 @@ impl CourseProgress {
         self.lesson_progress
             .iter()
             .filter(|lp| lp.has_ended())
-            .fold(Duration::default(), |acc, lp| acc.add(&lp.duration()))
+            .map(|lp| lp.duration())
+            .sum()
     }
 
     /// Returns the percentage of the course that has been completed.
@@ -103,7 +102,7 @@ impl CourseProgress {
     ///
     /// ```
     /// use education_platform_core::{CourseEnded, CourseProgress, LessonProgress};
-    /// use education_platform_common::DomainEventDispatcher;
+    /// use education_platform_common::{DomainEventDispatcher, Percentage};
     /// use std::sync::Arc;
     ///
     /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
@@ -117,17 +116,14 @@ impl CourseProgress {
     ///     .unwrap();
     ///
     /// // No lessons ended yet
-    /// assert_eq!(progress.percentage_completed(), 0);
+    /// assert_eq!(progress.percentage_completed(), Percentage::ZERO);
     /// ```
     #[must_use]
-    pub fn percentage_completed(&self) -> u64 {
-        let total_duration = self.total_duration().total_seconds();
-        if total_duration == 0 {
-            return 0;
-        }
-
-        let duration_lessons_ended = self.duration_lessons_ended().total_seconds();
-        duration_lessons_ended * 100 / total_duration
+    pub fn percentage_completed(&self) -> Percentage {
+        Percentage::from_fraction(
+            self.duration_lessons_ended().total_seconds(),
+            self.total_duration().total_seconds(),
+        )
     }
 
     /// Returns the number of lessons that have been started.
@@ -405,7 +401,7 @@ mod tests {
         fn test_percentage_completed_zero_when_none_ended() {
             let progress = create_test_progress();
 
-            assert_eq!(progress.percentage_completed(), 0);
+            assert_eq!(progress.percentage_completed().whole_points(), 0);
         }
 
         #[test]
@@ -415,7 +411,7 @@ mod tests {
 
             let progress = create_progress(vec![lesson1, lesson2]);
 
-            assert_eq!(progress.percentage_completed(), 100);
+            assert_eq!(progress.percentage_completed().whole_points(), 100);
         }
 
         #[test]
@@ -425,7 +421,7 @@ mod tests {
 
             let progress = create_progress(vec![lesson1, lesson2]);
 
-            assert_eq!(progress.percentage_completed(), 50);
+            assert_eq!(progress.percentage_completed().whole_points(), 50);
         }
 
         #[test]
@@ -435,7 +431,7 @@ mod tests {
 
             let progress = create_progress(vec![lesson1, lesson2]);
 
-            assert_eq!(progress.percentage_completed(), 25);
+            assert_eq!(progress.percentage_completed().whole_points(), 25);
         }
 
         #[test]
@@ -445,7 +441,7 @@ mod tests {
 
             let progress = create_progress(vec![lesson1, lesson2]);
 
-            assert_eq!(progress.percentage_completed(), 21);
+            assert_eq!(progress.percentage_completed().whole_points(), 21);
         }
     }
 
@@ -0,0 +1,214 @@
+use crate::{Course, CourseProgress, CourseProgressError, LessonId};
+use education_platform_common::{Date, Entity, Id};
+
+impl CourseProgress {
+    /// Starts a lesson only if the course's release schedule has unlocked it.
+    ///
+    /// Lessons that are already started can always be resumed. The check
+    /// uses the learner's enrollment date, which the caller provides because
+    /// progress records do not store it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CourseProgressError::CourseMismatch` if this progress tracks
+    /// another course.
+    /// Returns `CourseProgressError::LessonNotFound` if the lesson is not part
+    /// of the course.
+    /// Returns `CourseProgressError::LessonLocked` if the lesson's chapter is
+    /// not released yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     Chapter, Course, CourseProgress, CourseProgressError, Lesson, ReleaseSchedule,
+    /// };
+    /// use education_platform_common::{Date, Entity};
+    ///
+    /// let chapters = ["Getting Started", "Ownership"]
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(index, name)| {
+    ///         let lesson = Lesson::new(
+    ///             format!("{name} Lesson"),
+    ///             1800,
+    ///             format!("https://example.com/{index}.mp4"),
+    ///             0,
+    ///         ).unwrap();
+    ///         Chapter::new(name.to_string(), index, vec![lesson]).unwrap()
+    ///     })
+    ///     .collect();
+    /// let mut course = Course::new("Rust Programming".to_string(), None, 0, chapters).unwrap();
+    /// course.update_release_schedule(ReleaseSchedule::DaysAfterEnrollment(vec![0, 7]));
+    /// let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+    ///
+    /// let enrolled_on = Date::new(2025, 3, 3).unwrap();
+    /// let today = Date::new(2025, 3, 5).unwrap();
+    /// let first = course.chapters()[0].lessons()[0].lesson_id();
+    /// let second = course.chapters()[1].lessons()[0].lesson_id();
+    ///
+    /// progress.start_released_lesson(&course, first, enrolled_on, today).unwrap();
+    /// assert!(matches!(
+    ///     progress.start_released_lesson(&course, second, enrolled_on, today),
+    ///     Err(CourseProgressError::LessonLocked { .. })
+    /// ));
+    /// ```
+    pub fn start_released_lesson(
+        &mut self,
+        course: &Course,
+        lesson_id: LessonId,
+        enrolled_on: Date,
+        today: Date,
+    ) -> Result<(), CourseProgressError> {
+        self.ensure_tracks(course)?;
+        let lesson = self
+            .lesson_progress
+            .iter()
+            .find(|lp| lp.lesson_id() == lesson_id)
+            .ok_or_else(|| CourseProgressError::LessonNotFound(lesson_id.to_string()))?;
+
+        if !lesson.has_started() {
+            let available_on = course
+                .lesson_release_date(lesson_id.untyped(), enrolled_on)
+                .ok_or_else(|| CourseProgressError::LessonNotFound(lesson_id.to_string()))?;
+            if available_on > today {
+                return Err(CourseProgressError::LessonLocked {
+                    lesson_id: lesson_id.to_string(),
+                    available_on: available_on.to_string(),
+                });
+            }
+        }
+
+        self.start_lesson(lesson_id);
+        Ok(())
+    }
+
+    /// Returns the IDs of the lessons a learner cannot start yet, in course order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseProgress, Lesson, ReleaseSchedule};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let mut course =
+    ///     Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let launch = Date::new(2025, 4, 1).unwrap();
+    /// course.update_release_schedule(ReleaseSchedule::FixedDates(vec![launch]));
+    /// let progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+    ///
+    /// let enrolled_on = Date::new(2025, 3, 3).unwrap();
+    /// assert_eq!(progress.locked_lessons(&course, enrolled_on, enrolled_on).len(), 1);
+    /// assert!(progress.locked_lessons(&course, enrolled_on, launch).is_empty());
+    /// ```
+    #[must_use]
+    pub fn locked_lessons(&self, course: &Course, enrolled_on: Date, today: Date) -> Vec<Id> {
+        self.lesson_progress
+            .iter()
+            .filter(|lp| !lp.has_started())
+            .filter(|lp| {
+                course
+                    .lesson_release_date(lp.id(), enrolled_on)
+                    .is_some_and(|available_on| available_on > today)
+            })
+            .map(Entity::id)
+            .collect()
+    }
+
+    fn ensure_tracks(&self, course: &Course) -> Result<(), CourseProgressError> {
+        match self.course_id {
+            Some(course_id) if course_id != course.id() => {
+                Err(CourseProgressError::CourseMismatch {
+                    expected: course_id.to_string(),
+                    actual: course.id().to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Lesson, ReleaseSchedule};
+
+    fn create_test_course(schedule: ReleaseSchedule) -> Course {
+        let chapters = (0..2)
+            .map(|index| {
+                let lesson = Lesson::new(
+                    format!("Lesson {index}"),
+                    1800,
+                    format!("https://example.com/{index}.mp4"),
+                    0,
+                )
+                .unwrap();
+                Chapter::new(format!("Chapter {index}"), index, vec![lesson]).unwrap()
+            })
+            .collect();
+        let mut course = Course::new("Rust Programming".to_string(), None, 0, chapters).unwrap();
+        course.update_release_schedule(schedule);
+        course
+    }
+
+    fn create_date(day: u32) -> Date {
+        Date::new(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_locked_lesson_is_not_started() {
+        let course = create_test_course(ReleaseSchedule::DaysAfterEnrollment(vec![0, 7]));
+        let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+        let lesson_id = course.chapters()[1].lessons()[0].lesson_id();
+
+        let result =
+            progress.start_released_lesson(&course, lesson_id, create_date(3), create_date(9));
+
+        assert_eq!(
+            result,
+            Err(CourseProgressError::LessonLocked {
+                lesson_id: lesson_id.to_string(),
+                available_on: "2025-03-10".to_string(),
+            })
+        );
+        assert!(!progress.lesson_progress()[1].has_started());
+    }
+
+    #[test]
+    fn test_started_lesson_can_be_resumed_after_schedule_change() {
+        let mut course = create_test_course(ReleaseSchedule::AllAtOnce);
+        let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+        let lesson_id = course.chapters()[1].lessons()[0].lesson_id();
+        progress.start_lesson(lesson_id);
+        course.update_release_schedule(ReleaseSchedule::FixedDates(vec![create_date(31)]));
+
+        let result =
+            progress.start_released_lesson(&course, lesson_id, create_date(3), create_date(3));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            progress.locked_lessons(&course, create_date(3), create_date(3)),
+            vec![course.chapters()[0].lessons()[0].id()]
+        );
+    }
+
+    #[test]
+    fn test_other_course_is_rejected() {
+        let course = create_test_course(ReleaseSchedule::AllAtOnce);
+        let other = create_test_course(ReleaseSchedule::AllAtOnce);
+        let mut progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+        let lesson_id = progress.lesson_progress()[0].lesson_id();
+
+        let result =
+            progress.start_released_lesson(&other, lesson_id, create_date(3), create_date(3));
+
+        assert!(matches!(result, Err(CourseProgressError::CourseMismatch { .. })));
+    }
+}
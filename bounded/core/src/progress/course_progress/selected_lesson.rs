@@ -1,5 +1,4 @@
 use crate::{CourseProgress, CourseProgressError};
-use education_platform_common::Entity;
 
 impl CourseProgress {
     /// Starts the currently selected lesson.
@@ -25,7 +24,7 @@ impl CourseProgress {
     /// assert!(progress.selected_lesson().has_started());
     /// ```
     pub fn start_selected_lesson(&mut self) {
-        self.start_lesson(self.selected_lesson.id());
+        self.start_lesson(self.selected_lesson.lesson_id());
     }
 
     /// Ends the currently selected lesson.
@@ -56,7 +55,7 @@ impl CourseProgress {
     /// assert!(progress.selected_lesson().has_ended());
     /// ```
     pub fn end_selected_lesson(&mut self) -> Result<(), CourseProgressError> {
-        self.end_lesson(self.selected_lesson.id())
+        self.end_lesson(self.selected_lesson.lesson_id())
     }
 
     /// Ends the currently selected lesson and selects the next one.
@@ -100,7 +99,7 @@ impl CourseProgress {
 mod tests {
     use super::*;
     use crate::{CourseEnded, LessonProgress};
-    use education_platform_common::{DateTime, DomainEventDispatcher};
+    use education_platform_common::{DateTime, DomainEventDispatcher, Entity};
     use std::sync::Arc;
 
     fn create_test_dispatcher() -> Arc<DomainEventDispatcher<CourseEnded>> {
@@ -0,0 +1,326 @@
+use crate::{CourseProgress, LessonProgress};
+use education_platform_common::Date;
+
+impl CourseProgress {
+    /// Returns true if the user was active in the course on the given day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::{Date, DateTime};
+    ///
+    /// let start = DateTime::new(2024, 3, 1, 9, 0, 0).unwrap();
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(start), None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(progress.was_active_on(Date::new(2024, 3, 1).unwrap()));
+    /// assert!(!progress.was_active_on(Date::new(2024, 3, 2).unwrap()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn was_active_on(&self, date: Date) -> bool {
+        self.active_days.contains(&date)
+    }
+
+    /// Returns the number of consecutive active days leading up to today.
+    ///
+    /// See [`CourseProgress::current_streak_on`] for how the streak is counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let lesson_id = lesson.lesson_id();
+    /// let mut progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(progress.current_streak(), 0);
+    ///
+    /// progress.start_lesson(lesson_id);
+    /// assert_eq!(progress.current_streak(), 1);
+    /// ```
+    #[must_use]
+    pub fn current_streak(&self) -> u32 {
+        self.current_streak_on(Date::today())
+    }
+
+    /// Returns the number of consecutive active days leading up to `today`.
+    ///
+    /// A streak that reached yesterday is still current: the user has the
+    /// rest of `today` to keep it going.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .active_days([
+    ///         Date::new(2024, 3, 1).unwrap(),
+    ///         Date::new(2024, 3, 2).unwrap(),
+    ///     ])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(progress.current_streak_on(Date::new(2024, 3, 2).unwrap()), 2);
+    /// assert_eq!(progress.current_streak_on(Date::new(2024, 3, 3).unwrap()), 2);
+    /// assert_eq!(progress.current_streak_on(Date::new(2024, 3, 4).unwrap()), 0);
+    /// ```
+    #[must_use]
+    pub fn current_streak_on(&self, today: Date) -> u32 {
+        let mut day = match self.was_active_on(today) {
+            true => today,
+            false => today.sub_days(1),
+        };
+
+        let mut streak = 0;
+        while self.was_active_on(day) {
+            streak += 1;
+            day = day.sub_days(1);
+        }
+
+        streak
+    }
+
+    /// Returns the longest run of consecutive active days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .active_days([
+    ///         Date::new(2024, 3, 1).unwrap(),
+    ///         Date::new(2024, 3, 2).unwrap(),
+    ///         Date::new(2024, 3, 5).unwrap(),
+    ///     ])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(progress.longest_streak(), 2);
+    /// ```
+    #[must_use]
+    pub fn longest_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut current = 0;
+        let mut previous: Option<&Date> = None;
+
+        for day in &self.active_days {
+            current = match previous {
+                Some(prev) if prev.days_until(day) == 1 => current + 1,
+                _ => 1,
+            };
+            longest = longest.max(current);
+            previous = Some(day);
+        }
+
+        longest
+    }
+
+    /// Marks the given day as active.
+    pub(super) fn record_activity(&mut self, date: Date) {
+        self.active_days.insert(date);
+    }
+
+    /// Returns the days on which any of the lessons was started or ended.
+    pub(super) fn lesson_activity_days(lessons: &[LessonProgress]) -> impl Iterator<Item = Date> {
+        lessons
+            .iter()
+            .flat_map(|lp| [lp.start_date(), lp.end_date()])
+            .flatten()
+            .map(|date_time| date_time.date())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CourseEnded;
+    use education_platform_common::{DateTime, DomainEventDispatcher};
+    use std::sync::Arc;
+
+    fn create_test_dispatcher() -> Arc<DomainEventDispatcher<CourseEnded>> {
+        Arc::new(DomainEventDispatcher::new())
+    }
+
+    fn create_test_lesson(name: &str) -> LessonProgress {
+        LessonProgress::new(name.to_string(), 1800, None, None).unwrap()
+    }
+
+    fn create_progress(days: &[(u32, u32)]) -> CourseProgress {
+        CourseProgress::builder()
+            .course_name("Course")
+            .user_email("user@example.com")
+            .lessons(vec![create_test_lesson("Lesson 1")])
+            .active_days(days.iter().map(|(month, d)| day(*month, *d)))
+            .event_dispatcher(create_test_dispatcher())
+            .build()
+            .unwrap()
+    }
+
+    fn day(month: u32, day: u32) -> Date {
+        Date::new(2024, month, day).unwrap()
+    }
+
+    mod was_active_on {
+        use super::*;
+
+        #[test]
+        fn test_was_active_on_lesson_start_and_end_days() {
+            let start = DateTime::new(2024, 3, 1, 23, 0, 0).unwrap();
+            let end = DateTime::new(2024, 3, 3, 1, 0, 0).unwrap();
+            let lesson =
+                LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+            let progress = CourseProgress::builder()
+                .course_name("Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson])
+                .event_dispatcher(create_test_dispatcher())
+                .build()
+                .unwrap();
+
+            assert!(progress.was_active_on(day(3, 1)));
+            assert!(!progress.was_active_on(day(3, 2)));
+            assert!(progress.was_active_on(day(3, 3)));
+        }
+
+        #[test]
+        fn test_lifecycle_operations_record_today() {
+            let lesson = create_test_lesson("Lesson 1");
+            let lesson_id = lesson.lesson_id();
+            let mut progress = CourseProgress::builder()
+                .course_name("Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson, create_test_lesson("Lesson 2")])
+                .event_dispatcher(create_test_dispatcher())
+                .build()
+                .unwrap();
+
+            progress.start_lesson(lesson_id);
+
+            assert!(progress.was_active_on(Date::today()));
+        }
+
+        #[test]
+        fn test_restart_records_today() {
+            let lesson = create_test_lesson("Lesson 1");
+            let lesson_id = lesson.lesson_id();
+            let mut progress = CourseProgress::builder()
+                .course_name("Course")
+                .user_email("user@example.com")
+                .lessons(vec![lesson])
+                .event_dispatcher(create_test_dispatcher())
+                .build()
+                .unwrap();
+
+            progress.restart_lesson(lesson_id);
+
+            assert!(progress.was_active_on(Date::today()));
+        }
+    }
+
+    mod current_streak {
+        use super::*;
+
+        #[test]
+        fn test_current_streak_zero_without_activity() {
+            let progress = create_progress(&[]);
+
+            assert_eq!(progress.current_streak_on(day(3, 1)), 0);
+            assert_eq!(progress.current_streak(), 0);
+        }
+
+        #[test]
+        fn test_current_streak_counts_back_from_today() {
+            let progress = create_progress(&[(2, 27), (2, 28), (2, 29), (3, 1)]);
+
+            assert_eq!(progress.current_streak_on(day(3, 1)), 4);
+        }
+
+        #[test]
+        fn test_current_streak_survives_until_end_of_today() {
+            let progress = create_progress(&[(2, 28), (2, 29)]);
+
+            assert_eq!(progress.current_streak_on(day(3, 1)), 2);
+        }
+
+        #[test]
+        fn test_current_streak_broken_by_missed_day() {
+            let progress = create_progress(&[(2, 27), (2, 28)]);
+
+            assert_eq!(progress.current_streak_on(day(3, 1)), 0);
+        }
+
+        #[test]
+        fn test_current_streak_ignores_older_runs() {
+            let progress = create_progress(&[(2, 1), (2, 2), (2, 3), (2, 29), (3, 1)]);
+
+            assert_eq!(progress.current_streak_on(day(3, 1)), 2);
+        }
+    }
+
+    mod longest_streak {
+        use super::*;
+
+        #[test]
+        fn test_longest_streak_zero_without_activity() {
+            let progress = create_progress(&[]);
+
+            assert_eq!(progress.longest_streak(), 0);
+        }
+
+        #[test]
+        fn test_longest_streak_single_day() {
+            let progress = create_progress(&[(3, 1)]);
+
+            assert_eq!(progress.longest_streak(), 1);
+        }
+
+        #[test]
+        fn test_longest_streak_picks_longest_run() {
+            let progress = create_progress(&[(2, 1), (2, 2), (2, 3), (2, 29), (3, 1)]);
+
+            assert_eq!(progress.longest_streak(), 3);
+        }
+
+        #[test]
+        fn test_longest_streak_crosses_year_boundary() {
+            let progress = CourseProgress::builder()
+                .course_name("Course")
+                .user_email("user@example.com")
+                .lessons(vec![create_test_lesson("Lesson 1")])
+                .active_days([
+                    Date::new(2023, 12, 31).unwrap(),
+                    Date::new(2024, 1, 1).unwrap(),
+                ])
+                .event_dispatcher(create_test_dispatcher())
+                .build()
+                .unwrap();
+
+            assert_eq!(progress.longest_streak(), 2);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::ProgressEvent;
+use education_platform_common::Id;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Error types for event store failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventStoreError {
+    #[error("Stream is at version {actual}, expected {expected}")]
+    VersionConflict { expected: usize, actual: usize },
+
+    #[error("Event store is unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// Port persisting progress event streams.
+///
+/// Appends are optimistic: the caller states how many events it has seen,
+/// and the append is refused if someone else wrote to the stream since, so
+/// two devices recording progress at the same time cannot overwrite each
+/// other.
+pub trait EventStore {
+    /// Appends events to a stream and returns its new version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventStoreError::VersionConflict` if the stream does not have
+    /// exactly `expected_version` events.
+    fn append(
+        &mut self,
+        stream_id: Id,
+        expected_version: usize,
+        events: &[ProgressEvent],
+    ) -> Result<usize, EventStoreError>;
+
+    /// Returns every event of a stream in order; empty if the stream does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventStoreError::Unavailable` if the store cannot be read.
+    fn load(&self, stream_id: Id) -> Result<Vec<ProgressEvent>, EventStoreError>;
+}
+
+/// In-process [`EventStore`] keeping every stream in memory.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{EventStore, InMemoryEventStore, ProgressEvent};
+/// use education_platform_common::{DateTime, Id};
+///
+/// let stream_id = Id::new();
+/// let started = ProgressEvent::LessonStarted {
+///     lesson_id: Id::new(),
+///     at: DateTime::new(2025, 3, 4, 18, 0, 0).unwrap(),
+/// };
+/// let mut store = InMemoryEventStore::new();
+///
+/// assert_eq!(store.append(stream_id, 0, &[started.clone()]), Ok(1));
+/// assert!(store.append(stream_id, 0, &[started]).is_err());
+/// assert_eq!(store.load(stream_id).unwrap().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryEventStore {
+    streams: HashMap<Id, Vec<ProgressEvent>>,
+}
+
+impl InMemoryEventStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl EventStore for InMemoryEventStore {
+    fn append(
+        &mut self,
+        stream_id: Id,
+        expected_version: usize,
+        events: &[ProgressEvent],
+    ) -> Result<usize, EventStoreError> {
+        let stream = self.streams.entry(stream_id).or_default();
+        if stream.len() != expected_version {
+            return Err(EventStoreError::VersionConflict {
+                expected: expected_version,
+                actual: stream.len(),
+            });
+        }
+
+        stream.extend_from_slice(events);
+        Ok(stream.len())
+    }
+
+    fn load(&self, stream_id: Id) -> Result<Vec<ProgressEvent>, EventStoreError> {
+        Ok(self.streams.get(&stream_id).cloned().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::DateTime;
+
+    fn create_event() -> ProgressEvent {
+        ProgressEvent::LessonStarted {
+            lesson_id: Id::new(),
+            at: DateTime::new(2025, 3, 4, 18, 0, 0).unwrap(),
+        }
+    }
+
+    mod in_memory_event_store {
+        use super::*;
+
+        #[test]
+        fn test_streams_are_kept_apart() {
+            let mut store = InMemoryEventStore::new();
+            let first = Id::new();
+            let second = Id::new();
+
+            store.append(first, 0, &[create_event()]).unwrap();
+            store
+                .append(second, 0, &[create_event(), create_event()])
+                .unwrap();
+
+            assert_eq!(store.load(first).unwrap().len(), 1);
+            assert_eq!(store.load(second).unwrap().len(), 2);
+        }
+
+        #[test]
+        fn test_stale_version_is_rejected() {
+            let mut store = InMemoryEventStore::new();
+            let stream_id = Id::new();
+            store.append(stream_id, 0, &[create_event()]).unwrap();
+
+            let result = store.append(stream_id, 0, &[create_event()]);
+
+            assert_eq!(
+                result,
+                Err(EventStoreError::VersionConflict {
+                    expected: 0,
+                    actual: 1,
+                })
+            );
+            assert_eq!(store.load(stream_id).unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_unknown_stream_is_empty() {
+            let store = InMemoryEventStore::new();
+
+            assert!(store.load(Id::new()).unwrap().is_empty());
+        }
+    }
+}
@@ -1,7 +1,27 @@
 use super::LessonProgress;
+use crate::LessonId;
 use education_platform_common::{DateTime, Duration, SimpleName};
 
 impl LessonProgress {
+    /// Returns the ID as a [`LessonId`], the key `CourseProgress` uses to
+    /// start, end and restart this lesson.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_common::Entity;
+    /// use education_platform_core::LessonProgress;
+    ///
+    /// let progress = LessonProgress::new("Traits".to_string(), 1800, None, None).unwrap();
+    ///
+    /// assert_eq!(progress.lesson_id().untyped(), progress.id());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> LessonId {
+        self.id.cast()
+    }
+
     /// Returns the lesson name.
     ///
     /// # Examples
@@ -0,0 +1,369 @@
+use education_platform_common::{DateTime, Duration, Entity, Id, Validator, ValidatorError};
+use thiserror::Error;
+
+/// Error types for Note validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NoteError {
+    #[error("Note text validation failed: {0}")]
+    TextError(#[from] ValidatorError),
+}
+
+/// A personal note a student attaches to a lesson.
+///
+/// `Note` is an entity holding free text, optionally pinned to a position in
+/// the lesson video. Notes without a video offset apply to the lesson as a whole.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Note;
+/// use education_platform_common::{Duration, Id};
+///
+/// let lesson_id = Id::new();
+/// let note = Note::new(
+///     lesson_id,
+///     "Borrowing rules explained here".to_string(),
+///     Some(Duration::from_minutes(12)),
+/// ).unwrap();
+///
+/// assert_eq!(note.lesson_id(), lesson_id);
+/// assert_eq!(note.text(), "Borrowing rules explained here");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Note {
+    id: Id,
+    lesson_id: Id,
+    text: String,
+    video_offset: Option<Duration>,
+    created_at: DateTime,
+    updated_at: Option<DateTime>,
+}
+
+impl Note {
+    pub const MAX_TEXT_LENGTH: usize = 5000;
+
+    /// Creates a new note for a lesson, stamped with the current time.
+    ///
+    /// The text is trimmed before validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoteError::TextError` if the text is empty or longer than
+    /// [`Note::MAX_TEXT_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::Id;
+    ///
+    /// let note = Note::new(Id::new(), "  Review later  ".to_string(), None).unwrap();
+    /// assert_eq!(note.text(), "Review later");
+    ///
+    /// assert!(Note::new(Id::new(), "   ".to_string(), None).is_err());
+    /// ```
+    pub fn new(
+        lesson_id: Id,
+        text: String,
+        video_offset: Option<Duration>,
+    ) -> Result<Self, NoteError> {
+        Self::with_id(Id::default(), lesson_id, text, video_offset, DateTime::today(), None)
+    }
+
+    /// Creates a `Note` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoteError::TextError` if the text is empty or too long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::{DateTime, Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let created_at = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+    /// let note = Note::with_id(id, Id::new(), "Saved".to_string(), None, created_at, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(note.id(), id);
+    /// assert_eq!(note.created_at(), created_at);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        lesson_id: Id,
+        text: String,
+        video_offset: Option<Duration>,
+        created_at: DateTime,
+        updated_at: Option<DateTime>,
+    ) -> Result<Self, NoteError> {
+        let text = Self::validate_text(&text)?;
+
+        Ok(Self {
+            id,
+            lesson_id,
+            text,
+            video_offset,
+            created_at,
+            updated_at,
+        })
+    }
+
+    /// Returns the ID of the lesson this note belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::Id;
+    ///
+    /// let lesson_id = Id::new();
+    /// let note = Note::new(lesson_id, "Text".to_string(), None).unwrap();
+    /// assert_eq!(note.lesson_id(), lesson_id);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> Id {
+        self.lesson_id
+    }
+
+    /// Returns the note text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::Id;
+    ///
+    /// let note = Note::new(Id::new(), "Text".to_string(), None).unwrap();
+    /// assert_eq!(note.text(), "Text");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the position in the lesson video the note refers to, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::{Duration, Id};
+    ///
+    /// let offset = Duration::from_seconds(90);
+    /// let note = Note::new(Id::new(), "Text".to_string(), Some(offset)).unwrap();
+    /// assert_eq!(note.video_offset(), Some(offset));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn video_offset(&self) -> Option<Duration> {
+        self.video_offset
+    }
+
+    /// Returns when the note was created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::Id;
+    ///
+    /// let note = Note::new(Id::new(), "Text".to_string(), None).unwrap();
+    /// assert!(note.created_at().year() >= 2024);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    /// Returns when the note was last edited, if it ever was.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::Id;
+    ///
+    /// let note = Note::new(Id::new(), "Text".to_string(), None).unwrap();
+    /// assert!(note.updated_at().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
+    /// Replaces the text and video offset, stamping the edit time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoteError::TextError` if the new text is empty or too long.
+    /// The note is left unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Note;
+    /// use education_platform_common::Id;
+    ///
+    /// let mut note = Note::new(Id::new(), "Draft".to_string(), None).unwrap();
+    /// note.edit("Final".to_string(), None).unwrap();
+    ///
+    /// assert_eq!(note.text(), "Final");
+    /// assert!(note.updated_at().is_some());
+    /// ```
+    pub fn edit(&mut self, text: String, video_offset: Option<Duration>) -> Result<(), NoteError> {
+        self.text = Self::validate_text(&text)?;
+        self.video_offset = video_offset;
+        self.updated_at = Some(DateTime::today());
+        Ok(())
+    }
+
+    fn validate_text(text: &str) -> Result<String, NoteError> {
+        let trimmed = text.trim();
+        Validator::is_not_empty(trimmed)?;
+        Validator::has_max_length(trimmed, Self::MAX_TEXT_LENGTH)?;
+        Ok(trimmed.to_string())
+    }
+}
+
+impl Entity for Note {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for Note {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Note {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_note(text: &str) -> Note {
+        Note::new(Id::new(), text.to_string(), None).unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_creates_valid_note() {
+            let lesson_id = Id::new();
+            let offset = Duration::from_seconds(42);
+
+            let note = Note::new(lesson_id, "Remember this".to_string(), Some(offset)).unwrap();
+
+            assert_eq!(note.lesson_id(), lesson_id);
+            assert_eq!(note.text(), "Remember this");
+            assert_eq!(note.video_offset(), Some(offset));
+            assert!(note.updated_at().is_none());
+        }
+
+        #[test]
+        fn test_new_trims_text() {
+            let note = create_test_note("  padded  ");
+
+            assert_eq!(note.text(), "padded");
+        }
+
+        #[test]
+        fn test_new_rejects_empty_text() {
+            let result = Note::new(Id::new(), "   ".to_string(), None);
+
+            assert_eq!(result.unwrap_err(), NoteError::TextError(ValidatorError::EmptyValue));
+        }
+
+        #[test]
+        fn test_new_accepts_text_at_max_length() {
+            let text = "a".repeat(Note::MAX_TEXT_LENGTH);
+
+            assert!(Note::new(Id::new(), text, None).is_ok());
+        }
+
+        #[test]
+        fn test_new_rejects_text_over_max_length() {
+            let text = "a".repeat(Note::MAX_TEXT_LENGTH + 1);
+
+            let result = Note::new(Id::new(), text, None);
+
+            assert!(matches!(
+                result,
+                Err(NoteError::TextError(ValidatorError::MaxLength { .. }))
+            ));
+        }
+
+        #[test]
+        fn test_with_id_preserves_all_fields() {
+            let id = Id::new();
+            let created_at = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+            let updated_at = DateTime::new(2024, 1, 2, 10, 0, 0).unwrap();
+
+            let note = Note::with_id(
+                id,
+                Id::new(),
+                "Text".to_string(),
+                None,
+                created_at,
+                Some(updated_at),
+            )
+            .unwrap();
+
+            assert_eq!(note.id(), id);
+            assert_eq!(note.created_at(), created_at);
+            assert_eq!(note.updated_at(), Some(updated_at));
+        }
+    }
+
+    mod edit {
+        use super::*;
+
+        #[test]
+        fn test_edit_replaces_text_and_offset() {
+            let mut note = create_test_note("Draft");
+            let offset = Duration::from_minutes(3);
+
+            note.edit("Final".to_string(), Some(offset)).unwrap();
+
+            assert_eq!(note.text(), "Final");
+            assert_eq!(note.video_offset(), Some(offset));
+            assert!(note.updated_at().is_some());
+        }
+
+        #[test]
+        fn test_edit_with_invalid_text_keeps_note_unchanged() {
+            let mut note = create_test_note("Draft");
+
+            let result = note.edit(String::new(), Some(Duration::from_minutes(3)));
+
+            assert!(result.is_err());
+            assert_eq!(note.text(), "Draft");
+            assert!(note.video_offset().is_none());
+            assert!(note.updated_at().is_none());
+        }
+    }
+
+    mod equality {
+        use super::*;
+
+        #[test]
+        fn test_equality_based_on_id() {
+            let note = create_test_note("Text");
+            let mut edited = note.clone();
+            edited.edit("Other".to_string(), None).unwrap();
+
+            assert_eq!(note, edited);
+            assert_ne!(note, create_test_note("Text"));
+        }
+    }
+}
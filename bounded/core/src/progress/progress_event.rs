@@ -0,0 +1,85 @@
+use education_platform_common::{DateTime, Email, Id};
+
+/// A lesson as it appears in the course a progress record tracks.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::LessonOutline;
+/// use education_platform_common::Id;
+///
+/// let outline = LessonOutline::new(Id::new(), "Introduction", 1800);
+///
+/// assert_eq!(outline.name(), "Introduction");
+/// assert_eq!(outline.duration_seconds(), 1800);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LessonOutline {
+    id: Id,
+    name: String,
+    duration_seconds: u64,
+}
+
+impl LessonOutline {
+    /// Creates an outline. Values are validated when the event is replayed.
+    #[must_use]
+    pub fn new(id: Id, name: impl Into<String>, duration_seconds: u64) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            duration_seconds,
+        }
+    }
+
+    /// Returns the ID of the lesson.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the lesson name.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the lesson length in seconds.
+    #[inline]
+    #[must_use]
+    pub const fn duration_seconds(&self) -> u64 {
+        self.duration_seconds
+    }
+}
+
+/// Something that happened to a learner's progress in a course.
+///
+/// The events of one progress record form its stream: the first is always
+/// [`ProgressEvent::Enrolled`], and replaying the stream in order rebuilds
+/// the record. Replaying only a prefix gives the record as it was at that
+/// point, which is what audit views and undo build on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// The learner enrolled in a course with the given lessons.
+    Enrolled {
+        progress_id: Id,
+        course_id: Option<Id>,
+        course_version: Option<u32>,
+        course_name: String,
+        user_email: Email,
+        lessons: Vec<LessonOutline>,
+    },
+    /// A lesson was started.
+    LessonStarted { lesson_id: Id, at: DateTime },
+    /// A lesson was completed.
+    LessonCompleted { lesson_id: Id, at: DateTime },
+    /// A lesson's start and completion were cleared.
+    LessonRestarted { lesson_id: Id, at: DateTime },
+    /// The progress was brought in line with a changed course structure.
+    CourseSynced {
+        course_id: Id,
+        course_name: String,
+        lessons: Vec<LessonOutline>,
+    },
+}
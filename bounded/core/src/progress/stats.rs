@@ -0,0 +1,462 @@
+use crate::CourseProgress;
+use education_platform_common::{Date, Duration, Percentage};
+use std::collections::BTreeSet;
+
+/// Snapshot of a student's progress through a course, ready for display.
+///
+/// `ProgressReport` is an immutable value object computed from a
+/// `CourseProgress` at a given day. A lesson counts as activity on the days it
+/// was started and ended; those days drive the pace and streak figures.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+/// use education_platform_common::{Date, DateTime};
+///
+/// let start = DateTime::new(2024, 1, 1, 9, 0, 0).unwrap();
+/// let end = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+/// let done = LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+/// let pending = LessonProgress::new("Basics".to_string(), 1800, None, None).unwrap();
+/// let progress = CourseProgress::builder()
+///     .course_name("My Course")
+///     .user_email("user@example.com")
+///     .lessons(vec![done, pending])
+///     .build()
+///     .unwrap();
+///
+/// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+///
+/// assert_eq!(report.percentage_completed().to_string(), "50%");
+/// assert_eq!(report.longest_streak(), 1);
+/// assert_eq!(report.estimated_finish_date(), Some(Date::new(2024, 1, 2).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressReport {
+    percentage_completed: Percentage,
+    lessons_completed: usize,
+    total_lessons: usize,
+    average_lessons_per_day: f64,
+    estimated_finish_date: Option<Date>,
+    longest_streak: u32,
+    time_invested: Duration,
+}
+
+impl ProgressReport {
+    /// Computes the report for the given progress as of `today`.
+    ///
+    /// The average pace is the number of completed lessons divided by the
+    /// days elapsed since the first activity, both ends included. The
+    /// estimated finish date projects that pace over the remaining lessons;
+    /// it is the actual end date once the course is completed, and `None`
+    /// while no lesson has been completed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+    ///
+    /// assert_eq!(report.lessons_completed(), 0);
+    /// assert!(report.estimated_finish_date().is_none());
+    /// ```
+    #[must_use]
+    pub fn from_progress(progress: &CourseProgress, today: Date) -> Self {
+        let lessons_completed = progress.lessons_completed_count();
+        let total_lessons = progress.lesson_count();
+        let average_lessons_per_day =
+            average_per_day(lessons_completed, progress.active_days(), today);
+
+        let estimated_finish_date = match progress.end_date() {
+            Some(end_date) => Some(end_date.date()),
+            None => {
+                estimate_finish(total_lessons - lessons_completed, average_lessons_per_day, today)
+            }
+        };
+
+        Self {
+            percentage_completed: progress.percentage_completed(),
+            lessons_completed,
+            total_lessons,
+            average_lessons_per_day,
+            estimated_finish_date,
+            longest_streak: progress.longest_streak(),
+            time_invested: progress.duration_lessons_ended(),
+        }
+    }
+
+    /// Returns the completed share of the course duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+    /// assert_eq!(report.percentage_completed().to_string(), "0%");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn percentage_completed(&self) -> Percentage {
+        self.percentage_completed
+    }
+
+    /// Returns the number of completed lessons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+    /// assert_eq!(report.lessons_completed(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lessons_completed(&self) -> usize {
+        self.lessons_completed
+    }
+
+    /// Returns the number of lessons in the course.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+    /// assert_eq!(report.total_lessons(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn total_lessons(&self) -> usize {
+        self.total_lessons
+    }
+
+    /// Returns the average number of lessons completed per day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::{Date, DateTime};
+    ///
+    /// let start = DateTime::new(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let end = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 2).unwrap());
+    /// assert!((report.average_lessons_per_day() - 0.5).abs() < f64::EPSILON);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn average_lessons_per_day(&self) -> f64 {
+        self.average_lessons_per_day
+    }
+
+    /// Returns the projected or actual date the course is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+    /// assert!(report.estimated_finish_date().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn estimated_finish_date(&self) -> Option<Date> {
+        self.estimated_finish_date
+    }
+
+    /// Returns the longest run of consecutive days with lesson activity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+    /// assert_eq!(report.longest_streak(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn longest_streak(&self) -> u32 {
+        self.longest_streak
+    }
+
+    /// Returns the total duration of the completed lessons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseProgress, LessonProgress, ProgressReport};
+    /// use education_platform_common::Date;
+    ///
+    /// let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+    /// let progress = CourseProgress::builder()
+    ///     .course_name("My Course")
+    ///     .user_email("user@example.com")
+    ///     .lessons(vec![lesson])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let report = ProgressReport::from_progress(&progress, Date::new(2024, 1, 1).unwrap());
+    /// assert!(report.time_invested().is_zero());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn time_invested(&self) -> Duration {
+        self.time_invested
+    }
+}
+
+fn average_per_day(lessons_completed: usize, active_days: &BTreeSet<Date>, today: Date) -> f64 {
+    match active_days.first() {
+        Some(first_day) if lessons_completed > 0 => {
+            let elapsed_days = (first_day.days_until(&today) + 1).max(1);
+            lessons_completed as f64 / elapsed_days as f64
+        }
+        _ => 0.0,
+    }
+}
+
+fn estimate_finish(remaining_lessons: usize, lessons_per_day: f64, today: Date) -> Option<Date> {
+    if lessons_per_day <= 0.0 {
+        return None;
+    }
+
+    let remaining_days = (remaining_lessons as f64 / lessons_per_day).ceil() as i64;
+    Some(today.add_days(remaining_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LessonProgress;
+    use education_platform_common::DateTime;
+
+    fn create_test_lesson(name: &str) -> LessonProgress {
+        LessonProgress::new(name.to_string(), 1800, None, None).unwrap()
+    }
+
+    fn create_completed_lesson(name: &str, start: (u32, u32), end: (u32, u32)) -> LessonProgress {
+        let start = DateTime::new(2024, start.0, start.1, 10, 0, 0).unwrap();
+        let end = DateTime::new(2024, end.0, end.1, 11, 0, 0).unwrap();
+        LessonProgress::new(name.to_string(), 1800, Some(start), Some(end)).unwrap()
+    }
+
+    fn create_progress(lessons: Vec<LessonProgress>) -> CourseProgress {
+        CourseProgress::builder()
+            .course_name("Course")
+            .user_email("user@example.com")
+            .lessons(lessons)
+            .build()
+            .unwrap()
+    }
+
+    fn day(month: u32, day: u32) -> Date {
+        Date::new(2024, month, day).unwrap()
+    }
+
+    mod from_progress {
+        use super::*;
+
+        #[test]
+        fn test_report_without_activity() {
+            let progress = create_progress(vec![create_test_lesson("Lesson 1")]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 1));
+
+            assert_eq!(report.percentage_completed(), Percentage::ZERO);
+            assert_eq!(report.lessons_completed(), 0);
+            assert_eq!(report.total_lessons(), 1);
+            assert!(report.average_lessons_per_day().abs() < f64::EPSILON);
+            assert!(report.estimated_finish_date().is_none());
+            assert_eq!(report.longest_streak(), 0);
+            assert!(report.time_invested().is_zero());
+        }
+
+        #[test]
+        fn test_report_counts_completed_lessons_and_time() {
+            let progress = create_progress(vec![
+                create_completed_lesson("Lesson 1", (1, 1), (1, 1)),
+                create_test_lesson("Lesson 2"),
+            ]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 1));
+
+            assert_eq!(report.percentage_completed().whole_points(), 50);
+            assert_eq!(report.lessons_completed(), 1);
+            assert_eq!(report.time_invested().total_seconds(), 1800);
+        }
+
+        #[test]
+        fn test_completed_course_uses_actual_end_date() {
+            let progress = create_progress(vec![
+                create_completed_lesson("Lesson 1", (1, 1), (1, 1)),
+                create_completed_lesson("Lesson 2", (1, 3), (1, 4)),
+            ]);
+
+            let report = ProgressReport::from_progress(&progress, day(2, 1));
+
+            assert_eq!(report.estimated_finish_date(), Some(day(1, 4)));
+        }
+    }
+
+    mod pace {
+        use super::*;
+
+        #[test]
+        fn test_average_counts_days_since_first_activity() {
+            let progress = create_progress(vec![
+                create_completed_lesson("Lesson 1", (1, 1), (1, 1)),
+                create_completed_lesson("Lesson 2", (1, 2), (1, 2)),
+                create_test_lesson("Lesson 3"),
+            ]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 4));
+
+            assert!((report.average_lessons_per_day() - 0.5).abs() < f64::EPSILON);
+        }
+
+        #[test]
+        fn test_average_is_zero_when_only_started() {
+            let start = DateTime::new(2024, 1, 1, 10, 0, 0).unwrap();
+            let started =
+                LessonProgress::new("Lesson 1".to_string(), 1800, Some(start), None).unwrap();
+            let progress = create_progress(vec![started]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 5));
+
+            assert!(report.average_lessons_per_day().abs() < f64::EPSILON);
+            assert!(report.estimated_finish_date().is_none());
+        }
+
+        #[test]
+        fn test_estimated_finish_rounds_remaining_days_up() {
+            let progress = create_progress(vec![
+                create_completed_lesson("Lesson 1", (1, 1), (1, 1)),
+                create_completed_lesson("Lesson 2", (1, 2), (1, 2)),
+                create_test_lesson("Lesson 3"),
+            ]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 4));
+
+            assert_eq!(report.estimated_finish_date(), Some(day(1, 6)));
+        }
+
+        #[test]
+        fn test_activity_recorded_after_today_counts_as_one_day() {
+            let progress = create_progress(vec![
+                create_completed_lesson("Lesson 1", (1, 10), (1, 10)),
+                create_test_lesson("Lesson 2"),
+            ]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 1));
+
+            assert!((report.average_lessons_per_day() - 1.0).abs() < f64::EPSILON);
+        }
+    }
+
+    mod streak {
+        use super::*;
+
+        #[test]
+        fn test_streak_spans_start_and_end_days() {
+            let progress =
+                create_progress(vec![create_completed_lesson("Lesson 1", (1, 1), (1, 2))]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 3));
+
+            assert_eq!(report.longest_streak(), 2);
+        }
+
+        #[test]
+        fn test_streak_picks_longest_run() {
+            let progress = create_progress(vec![
+                create_completed_lesson("Lesson 1", (1, 1), (1, 2)),
+                create_completed_lesson("Lesson 2", (1, 5), (1, 6)),
+                create_completed_lesson("Lesson 3", (1, 7), (1, 7)),
+            ]);
+
+            let report = ProgressReport::from_progress(&progress, day(1, 7));
+
+            assert_eq!(report.longest_streak(), 3);
+        }
+
+        #[test]
+        fn test_streak_crosses_month_boundary() {
+            let progress = create_progress(vec![
+                create_completed_lesson("Lesson 1", (1, 31), (1, 31)),
+                create_completed_lesson("Lesson 2", (2, 1), (2, 1)),
+            ]);
+
+            let report = ProgressReport::from_progress(&progress, day(2, 1));
+
+            assert_eq!(report.longest_streak(), 2);
+        }
+    }
+}
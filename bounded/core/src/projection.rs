@@ -0,0 +1,5 @@
+mod learner_dashboard;
+mod learner_skills;
+
+pub use learner_dashboard::*;
+pub use learner_skills::*;
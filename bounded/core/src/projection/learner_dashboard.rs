@@ -0,0 +1,266 @@
+use crate::CourseEnded;
+use education_platform_common::{Email, Id, Projection};
+use std::collections::HashMap;
+
+/// Per-learner figures shown on the learner dashboard.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseEnded, LearnerDashboard};
+/// use education_platform_common::{Email, Id, Projection};
+///
+/// let email = Email::new("user@example.com".to_string()).unwrap();
+/// let mut dashboard = LearnerDashboard::new();
+/// dashboard.apply(&CourseEnded::new(email.clone(), Id::new()));
+///
+/// let summary = dashboard.summary(&email).unwrap();
+/// assert_eq!(summary.completed_course_count(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LearnerSummary {
+    completed_courses: Vec<Id>,
+}
+
+impl LearnerSummary {
+    /// Returns the IDs of the completed courses, in completion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseEnded, LearnerDashboard};
+    /// use education_platform_common::{Email, Id, Projection};
+    ///
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    /// let course_id = Id::new();
+    /// let mut dashboard = LearnerDashboard::new();
+    /// dashboard.apply(&CourseEnded::new(email.clone(), course_id));
+    ///
+    /// assert_eq!(dashboard.summary(&email).unwrap().completed_courses(), &[course_id]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn completed_courses(&self) -> &[Id] {
+        &self.completed_courses
+    }
+
+    /// Returns the number of completed courses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerSummary;
+    ///
+    /// assert_eq!(LearnerSummary::default().completed_course_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn completed_course_count(&self) -> usize {
+        self.completed_courses.len()
+    }
+}
+
+/// Read model of learner activity, built from `CourseEnded` events.
+///
+/// Subscribe it to the dispatcher the progress aggregates publish to, or
+/// rebuild it from a stored event history.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseEnded, LearnerDashboard};
+/// use education_platform_common::{DomainEventDispatcher, Email, Id};
+/// use std::sync::{Arc, RwLock};
+///
+/// let dispatcher = DomainEventDispatcher::<CourseEnded>::new();
+/// let dashboard = Arc::new(RwLock::new(LearnerDashboard::new()));
+/// dispatcher.subscribe_projection(Arc::clone(&dashboard));
+///
+/// let email = Email::new("user@example.com".to_string()).unwrap();
+/// dispatcher.notify(&CourseEnded::new(email.clone(), Id::new()));
+///
+/// assert_eq!(dashboard.read().unwrap().learner_count(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LearnerDashboard {
+    learners: HashMap<Email, LearnerSummary>,
+}
+
+impl LearnerDashboard {
+    /// Creates an empty dashboard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerDashboard;
+    ///
+    /// assert_eq!(LearnerDashboard::new().learner_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the summary of a learner, if any of their activity was projected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerDashboard;
+    /// use education_platform_common::Email;
+    ///
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    /// assert!(LearnerDashboard::new().summary(&email).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn summary(&self, user_email: &Email) -> Option<&LearnerSummary> {
+        self.learners.get(user_email)
+    }
+
+    /// Returns the number of learners on the dashboard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::LearnerDashboard;
+    ///
+    /// assert_eq!(LearnerDashboard::new().learner_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn learner_count(&self) -> usize {
+        self.learners.len()
+    }
+
+    /// Returns the number of learners who completed a course.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseEnded, LearnerDashboard};
+    /// use education_platform_common::{Email, Id, Projection};
+    ///
+    /// let course_id = Id::new();
+    /// let mut dashboard = LearnerDashboard::new();
+    /// dashboard.apply(&CourseEnded::new(
+    ///     Email::new("user@example.com".to_string()).unwrap(),
+    ///     course_id,
+    /// ));
+    ///
+    /// assert_eq!(dashboard.completion_count(course_id), 1);
+    /// assert_eq!(dashboard.completion_count(Id::new()), 0);
+    /// ```
+    #[must_use]
+    pub fn completion_count(&self, course_id: Id) -> usize {
+        self.learners
+            .values()
+            .filter(|summary| summary.completed_courses.contains(&course_id))
+            .count()
+    }
+}
+
+impl Projection<CourseEnded> for LearnerDashboard {
+    fn apply(&mut self, event: &CourseEnded) {
+        let summary = self.learners.entry(event.user_email().clone()).or_default();
+        if !summary.completed_courses.contains(&event.course_id()) {
+            summary.completed_courses.push(event.course_id());
+        }
+    }
+
+    fn reset(&mut self) {
+        self.learners.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    mod apply {
+        use super::*;
+
+        #[test]
+        fn test_apply_records_completed_course() {
+            let email = create_email("user@example.com");
+            let course_id = Id::new();
+            let mut dashboard = LearnerDashboard::new();
+
+            dashboard.apply(&CourseEnded::new(email.clone(), course_id));
+
+            assert_eq!(dashboard.summary(&email).unwrap().completed_courses(), &[course_id]);
+        }
+
+        #[test]
+        fn test_apply_ignores_duplicate_completion() {
+            let email = create_email("user@example.com");
+            let event = CourseEnded::new(email.clone(), Id::new());
+            let mut dashboard = LearnerDashboard::new();
+
+            dashboard.apply(&event);
+            dashboard.apply(&event);
+
+            assert_eq!(dashboard.summary(&email).unwrap().completed_course_count(), 1);
+        }
+
+        #[test]
+        fn test_apply_keeps_learners_separate() {
+            let alice = create_email("alice@example.com");
+            let bob = create_email("bob@example.com");
+            let mut dashboard = LearnerDashboard::new();
+
+            dashboard.apply(&CourseEnded::new(alice.clone(), Id::new()));
+            dashboard.apply(&CourseEnded::new(alice.clone(), Id::new()));
+            dashboard.apply(&CourseEnded::new(bob.clone(), Id::new()));
+
+            assert_eq!(dashboard.learner_count(), 2);
+            assert_eq!(dashboard.summary(&alice).unwrap().completed_course_count(), 2);
+            assert_eq!(dashboard.summary(&bob).unwrap().completed_course_count(), 1);
+        }
+    }
+
+    mod completion_count {
+        use super::*;
+
+        #[test]
+        fn test_counts_learners_per_course() {
+            let rust = Id::new();
+            let go = Id::new();
+            let mut dashboard = LearnerDashboard::new();
+
+            dashboard.apply(&CourseEnded::new(create_email("alice@example.com"), rust));
+            dashboard.apply(&CourseEnded::new(create_email("bob@example.com"), rust));
+            dashboard.apply(&CourseEnded::new(create_email("bob@example.com"), go));
+
+            assert_eq!(dashboard.completion_count(rust), 2);
+            assert_eq!(dashboard.completion_count(go), 1);
+        }
+    }
+
+    mod rebuild {
+        use super::*;
+
+        #[test]
+        fn test_rebuild_matches_incremental_state() {
+            let email = create_email("user@example.com");
+            let history = vec![
+                CourseEnded::new(email.clone(), Id::new()),
+                CourseEnded::new(email.clone(), Id::new()),
+            ];
+            let mut incremental = LearnerDashboard::new();
+            history.iter().for_each(|event| incremental.apply(event));
+
+            let mut rebuilt = LearnerDashboard::new();
+            rebuilt.apply(&CourseEnded::new(create_email("stale@example.com"), Id::new()));
+            rebuilt.rebuild(&history);
+
+            assert_eq!(rebuilt.learner_count(), 1);
+            assert_eq!(rebuilt.summary(&email), incremental.summary(&email));
+        }
+    }
+}
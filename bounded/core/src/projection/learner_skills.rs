@@ -0,0 +1,266 @@
+use crate::{CourseEnded, CourseSkillMap, ProficiencyLevel, Skill};
+use education_platform_common::{Email, Projection};
+use std::collections::{BTreeMap, HashMap};
+
+/// Read model of the skills each learner has earned, built from `CourseEnded` events.
+///
+/// Completing a course grants every skill the course teaches. When several
+/// completed courses teach the same skill, the learner keeps the highest
+/// level. Changes to the skill map only affect events applied afterwards;
+/// rebuild from the event history to regrant skills under a new map.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     CourseEnded, CourseSkillMap, LearnerSkills, ProficiencyLevel, Skill,
+/// };
+/// use education_platform_common::{Email, Id, Projection};
+///
+/// let course_id = Id::new();
+/// let rust = Skill::new("rust").unwrap();
+/// let mut map = CourseSkillMap::new();
+/// map.teach(course_id, rust.clone(), ProficiencyLevel::Intermediate);
+///
+/// let email = Email::new("user@example.com".to_string()).unwrap();
+/// let mut skills = LearnerSkills::new(map);
+/// skills.apply(&CourseEnded::new(email.clone(), course_id));
+///
+/// assert_eq!(skills.level_of(&email, &rust), Some(ProficiencyLevel::Intermediate));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LearnerSkills {
+    skill_map: CourseSkillMap,
+    learners: HashMap<Email, BTreeMap<Skill, ProficiencyLevel>>,
+}
+
+impl LearnerSkills {
+    /// Creates an empty projection granting skills according to the given map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, LearnerSkills};
+    ///
+    /// let skills = LearnerSkills::new(CourseSkillMap::new());
+    /// assert_eq!(skills.learner_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(skill_map: CourseSkillMap) -> Self {
+        Self {
+            skill_map,
+            learners: HashMap::new(),
+        }
+    }
+
+    /// Returns the course skill map used to grant skills.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, LearnerSkills};
+    ///
+    /// let skills = LearnerSkills::new(CourseSkillMap::new());
+    /// assert_eq!(skills.skill_map(), &CourseSkillMap::new());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn skill_map(&self) -> &CourseSkillMap {
+        &self.skill_map
+    }
+
+    /// Returns the course skill map for editing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, LearnerSkills, ProficiencyLevel, Skill};
+    /// use education_platform_common::Id;
+    ///
+    /// let course_id = Id::new();
+    /// let mut skills = LearnerSkills::new(CourseSkillMap::new());
+    /// skills
+    ///     .skill_map_mut()
+    ///     .teach(course_id, Skill::new("sql").unwrap(), ProficiencyLevel::Beginner);
+    ///
+    /// assert_eq!(skills.skill_map().skills_of(course_id).len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn skill_map_mut(&mut self) -> &mut CourseSkillMap {
+        &mut self.skill_map
+    }
+
+    /// Returns the skills a learner has earned, in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, LearnerSkills};
+    /// use education_platform_common::Email;
+    ///
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    /// let skills = LearnerSkills::new(CourseSkillMap::new());
+    ///
+    /// assert!(skills.skills_of(&email).is_empty());
+    /// ```
+    #[must_use]
+    pub fn skills_of(&self, user_email: &Email) -> Vec<(&Skill, ProficiencyLevel)> {
+        self.learners
+            .get(user_email)
+            .map(|skills| {
+                skills
+                    .iter()
+                    .map(|(skill, level)| (skill, *level))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the level a learner has reached in a skill, if they have it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, LearnerSkills, Skill};
+    /// use education_platform_common::Email;
+    ///
+    /// let email = Email::new("user@example.com".to_string()).unwrap();
+    /// let skills = LearnerSkills::new(CourseSkillMap::new());
+    ///
+    /// assert!(skills.level_of(&email, &Skill::new("sql").unwrap()).is_none());
+    /// ```
+    #[must_use]
+    pub fn level_of(&self, user_email: &Email, skill: &Skill) -> Option<ProficiencyLevel> {
+        self.learners
+            .get(user_email)
+            .and_then(|skills| skills.get(skill))
+            .copied()
+    }
+
+    /// Returns the number of learners who have earned at least one skill.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, LearnerSkills};
+    ///
+    /// assert_eq!(LearnerSkills::new(CourseSkillMap::new()).learner_count(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn learner_count(&self) -> usize {
+        self.learners.len()
+    }
+}
+
+impl Projection<CourseEnded> for LearnerSkills {
+    fn apply(&mut self, event: &CourseEnded) {
+        let granted = self.skill_map.skills_of(event.course_id());
+        if granted.is_empty() {
+            return;
+        }
+
+        let skills = self.learners.entry(event.user_email().clone()).or_default();
+        for (skill, level) in granted {
+            skills
+                .entry(skill.clone())
+                .and_modify(|current| *current = (*current).max(level))
+                .or_insert(level);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.learners.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform_common::Id;
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_skill(name: &str) -> Skill {
+        Skill::new(name).unwrap()
+    }
+
+    mod apply {
+        use super::*;
+
+        #[test]
+        fn test_completion_grants_all_course_skills() {
+            let course_id = Id::new();
+            let mut map = CourseSkillMap::new();
+            map.teach(course_id, create_skill("rust"), ProficiencyLevel::Beginner);
+            map.teach(course_id, create_skill("cargo"), ProficiencyLevel::Beginner);
+            let email = create_email("user@example.com");
+            let mut skills = LearnerSkills::new(map);
+
+            skills.apply(&CourseEnded::new(email.clone(), course_id));
+
+            let names: Vec<&str> = skills
+                .skills_of(&email)
+                .iter()
+                .map(|(skill, _)| skill.name())
+                .collect();
+            assert_eq!(names, vec!["cargo", "rust"]);
+        }
+
+        #[test]
+        fn test_keeps_highest_level_across_courses() {
+            let intro = Id::new();
+            let advanced = Id::new();
+            let rust = create_skill("rust");
+            let mut map = CourseSkillMap::new();
+            map.teach(intro, rust.clone(), ProficiencyLevel::Beginner);
+            map.teach(advanced, rust.clone(), ProficiencyLevel::Advanced);
+            let email = create_email("user@example.com");
+            let mut skills = LearnerSkills::new(map);
+
+            skills.apply(&CourseEnded::new(email.clone(), advanced));
+            skills.apply(&CourseEnded::new(email.clone(), intro));
+
+            assert_eq!(skills.level_of(&email, &rust), Some(ProficiencyLevel::Advanced));
+        }
+
+        #[test]
+        fn test_course_without_skills_does_not_register_learner() {
+            let mut skills = LearnerSkills::new(CourseSkillMap::new());
+
+            skills.apply(&CourseEnded::new(create_email("user@example.com"), Id::new()));
+
+            assert_eq!(skills.learner_count(), 0);
+        }
+    }
+
+    mod rebuild {
+        use super::*;
+
+        #[test]
+        fn test_rebuild_uses_current_skill_map() {
+            let course_id = Id::new();
+            let email = create_email("user@example.com");
+            let history = vec![CourseEnded::new(email.clone(), course_id)];
+            let mut skills = LearnerSkills::new(CourseSkillMap::new());
+            skills.rebuild(&history);
+            assert!(skills.skills_of(&email).is_empty());
+
+            skills.skill_map_mut().teach(
+                course_id,
+                create_skill("sql"),
+                ProficiencyLevel::Intermediate,
+            );
+            skills.rebuild(&history);
+
+            assert_eq!(
+                skills.level_of(&email, &create_skill("sql")),
+                Some(ProficiencyLevel::Intermediate)
+            );
+        }
+    }
+}
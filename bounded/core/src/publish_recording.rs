@@ -0,0 +1,787 @@
+use crate::{
+    Cohort, Course, CourseError, ExtensionError, ExtensionRegistry, Lesson, LessonError,
+    LiveSession,
+};
+use education_platform_common::{DomainEventDispatcher, Duration, Email, Entity, Id};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Error types for publishing a live session recording as a lesson.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PublishRecordingError {
+    #[error("Live session was cancelled and has no recording")]
+    SessionCancelled,
+
+    #[error("Live session belongs to another course")]
+    SessionCourseMismatch,
+
+    #[error("Cohort belongs to another course")]
+    CohortCourseMismatch,
+
+    #[error("Recording lesson is not valid: {0}")]
+    LessonError(#[from] LessonError),
+
+    #[error("Recording could not be added to the course: {0}")]
+    CourseError(#[from] CourseError),
+
+    #[error("Recording was rejected by an extension: {0}")]
+    ExtensionError(#[from] ExtensionError),
+}
+
+/// A finished live session recording, as reported by the video provider.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::SessionRecording;
+///
+/// let recording = SessionRecording::new(
+///     "Week 1 live Q&A".to_string(),
+///     "https://videos.example.com/week-1.mp4".to_string(),
+///     3600,
+/// )
+/// .with_transcript("https://videos.example.com/week-1.vtt".to_string());
+///
+/// assert_eq!(recording.duration_seconds(), 3600);
+/// assert!(recording.transcript_url().is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionRecording {
+    title: String,
+    video_url: String,
+    duration_seconds: u64,
+    transcript_url: Option<String>,
+}
+
+impl SessionRecording {
+    /// Creates a recording without a transcript.
+    ///
+    /// Values are validated when the recording is published.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SessionRecording;
+    ///
+    /// let recording = SessionRecording::new(
+    ///     "Week 1 live Q&A".to_string(),
+    ///     "https://videos.example.com/week-1.mp4".to_string(),
+    ///     3600,
+    /// );
+    /// assert!(recording.transcript_url().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new(title: String, video_url: String, duration_seconds: u64) -> Self {
+        Self {
+            title,
+            video_url,
+            duration_seconds,
+            transcript_url: None,
+        }
+    }
+
+    /// Attaches the URL of a transcript generated for the recording.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SessionRecording;
+    ///
+    /// let recording = SessionRecording::new(
+    ///     "Week 1 live Q&A".to_string(),
+    ///     "https://videos.example.com/week-1.mp4".to_string(),
+    ///     3600,
+    /// )
+    /// .with_transcript("https://videos.example.com/week-1.vtt".to_string());
+    ///
+    /// assert_eq!(recording.transcript_url(), Some("https://videos.example.com/week-1.vtt"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_transcript(mut self, transcript_url: String) -> Self {
+        self.transcript_url = Some(transcript_url);
+        self
+    }
+
+    /// Returns the title the lesson will be published under.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SessionRecording;
+    ///
+    /// let recording = SessionRecording::new(
+    ///     "Week 1 live Q&A".to_string(),
+    ///     "https://videos.example.com/week-1.mp4".to_string(),
+    ///     3600,
+    /// );
+    /// assert_eq!(recording.title(), "Week 1 live Q&A");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the URL of the recorded video.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SessionRecording;
+    ///
+    /// let recording = SessionRecording::new(
+    ///     "Week 1 live Q&A".to_string(),
+    ///     "https://videos.example.com/week-1.mp4".to_string(),
+    ///     3600,
+    /// );
+    /// assert_eq!(recording.video_url(), "https://videos.example.com/week-1.mp4");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn video_url(&self) -> &str {
+        &self.video_url
+    }
+
+    /// Returns the recording length reported by the provider, in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SessionRecording;
+    ///
+    /// let recording = SessionRecording::new(
+    ///     "Week 1 live Q&A".to_string(),
+    ///     "https://videos.example.com/week-1.mp4".to_string(),
+    ///     3600,
+    /// );
+    /// assert_eq!(recording.duration_seconds(), 3600);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn duration_seconds(&self) -> u64 {
+        self.duration_seconds
+    }
+
+    /// Returns the transcript URL, if the provider generated one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::SessionRecording;
+    ///
+    /// let recording = SessionRecording::new(
+    ///     "Week 1 live Q&A".to_string(),
+    ///     "https://videos.example.com/week-1.mp4".to_string(),
+    ///     3600,
+    /// );
+    /// assert_eq!(recording.transcript_url(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn transcript_url(&self) -> Option<&str> {
+        self.transcript_url.as_deref()
+    }
+}
+
+/// Event emitted when a live session recording becomes a course lesson.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::RecordingPublished;
+/// use education_platform_common::{Email, Id};
+///
+/// let learner = Email::new("alice@example.com".to_string()).unwrap();
+/// let event = RecordingPublished::new(Id::new(), Id::new(), vec![learner]);
+///
+/// assert_eq!(event.recipients().len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordingPublished {
+    course_id: Id,
+    lesson_id: Id,
+    recipients: Vec<Email>,
+}
+
+impl RecordingPublished {
+    /// Creates a new `RecordingPublished` event.
+    #[inline]
+    #[must_use]
+    pub const fn new(course_id: Id, lesson_id: Id, recipients: Vec<Email>) -> Self {
+        Self {
+            course_id,
+            lesson_id,
+            recipients,
+        }
+    }
+
+    /// Returns the ID of the course that received the recording.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns the ID of the lesson created from the recording.
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> Id {
+        self.lesson_id
+    }
+
+    /// Returns the learners to notify: the cohort members and the session attendees.
+    #[inline]
+    #[must_use]
+    pub fn recipients(&self) -> &[Email] {
+        &self.recipients
+    }
+}
+
+/// Use case turning a finished live session recording into an on-demand lesson.
+///
+/// Publishing appends the recording as a lesson of the chosen chapter, with
+/// its transcript when the provider produced one, and emits a
+/// [`RecordingPublished`] event addressed to the cohort so that learners who
+/// missed the session hear about the replay.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     Chapter, Cohort, Course, Lesson, LiveSession, PublishRecording, SessionRecording,
+/// };
+/// use education_platform_common::{DateTime, Duration, Entity, TimeRange};
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Live Sessions".to_string(), 0, vec![lesson]).unwrap();
+/// let chapter_id = chapter.id();
+/// let mut course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+/// let session = LiveSession::new(
+///     course.id(),
+///     TimeRange::starting_at(start, Duration::from_hours(1)).unwrap(),
+///     "https://meet.example.com/rust-101".to_string(),
+///     30,
+/// ).unwrap();
+/// let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+///
+/// let recording = SessionRecording::new(
+///     "Week 1 live Q&A".to_string(),
+///     "https://videos.example.com/week-1.mp4".to_string(),
+///     3600,
+/// );
+///
+/// let lesson_id = PublishRecording::new()
+///     .publish(&mut course, chapter_id, &session, &cohort, recording)
+///     .unwrap();
+///
+/// assert_eq!(course.chapters()[0].lessons()[1].id(), lesson_id);
+/// ```
+#[derive(Debug, Default)]
+pub struct PublishRecording {
+    event_dispatcher: Arc<DomainEventDispatcher<RecordingPublished>>,
+    extensions: Option<Arc<ExtensionRegistry>>,
+}
+
+impl PublishRecording {
+    /// Creates a new use case instance with its own event dispatcher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::PublishRecording;
+    ///
+    /// let use_case = PublishRecording::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new use case instance with a custom event dispatcher.
+    #[must_use]
+    pub const fn with_dispatcher(
+        event_dispatcher: Arc<DomainEventDispatcher<RecordingPublished>>,
+    ) -> Self {
+        Self {
+            event_dispatcher,
+            extensions: None,
+        }
+    }
+
+    /// Creates a new use case instance that runs the registry's checks and
+    /// announces publications to its subscribers.
+    ///
+    /// The recording title must pass every content policy, and the course
+    /// with the new lesson must pass every course rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ExtensionRegistry, NamePatternRule, PublishRecording};
+    /// use std::sync::Arc;
+    ///
+    /// let mut registry = ExtensionRegistry::new();
+    /// registry.register_course_rule(NamePatternRule::new("no-q-and-a", "^[^&]*$").unwrap());
+    ///
+    /// let use_case = PublishRecording::with_extensions(Arc::new(registry));
+    /// ```
+    #[must_use]
+    pub fn with_extensions(extensions: Arc<ExtensionRegistry>) -> Self {
+        Self {
+            event_dispatcher: extensions.recording_published_dispatcher(),
+            extensions: Some(extensions),
+        }
+    }
+
+    /// Publishes a recording as the last lesson of a chapter and notifies the cohort.
+    ///
+    /// The session and the cohort must belong to the course. Nothing is
+    /// changed and no event is emitted when any check fails. Returns the ID
+    /// of the new lesson.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PublishRecordingError::SessionCancelled` if the session was cancelled.
+    /// Returns `PublishRecordingError::SessionCourseMismatch` or `CohortCourseMismatch`
+    /// if the session or the cohort belongs to another course.
+    /// Returns `PublishRecordingError::LessonError` if the recording does not make
+    /// a valid lesson (title, video URL, zero duration or transcript URL).
+    /// Returns `PublishRecordingError::CourseError` if the chapter is not in the course.
+    /// Returns `PublishRecordingError::ExtensionError` if a registered content
+    /// policy or course rule rejects the recording.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     Chapter, Cohort, Course, Lesson, LiveSession, PublishRecording,
+    ///     PublishRecordingError, RecordingPublished, SessionRecording,
+    /// };
+    /// use education_platform_common::{
+    ///     DateTime, DomainEventDispatcher, Duration, Entity, Id, TimeRange,
+    /// };
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Live Sessions".to_string(), 0, vec![lesson]).unwrap();
+    /// let chapter_id = chapter.id();
+    /// let mut course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    ///
+    /// let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+    /// let session = LiveSession::new(
+    ///     course.id(),
+    ///     TimeRange::starting_at(start, Duration::from_hours(1)).unwrap(),
+    ///     "https://meet.example.com/rust-101".to_string(),
+    ///     30,
+    /// ).unwrap();
+    /// let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+    ///
+    /// let published = Arc::new(Mutex::new(Vec::new()));
+    /// let dispatcher = Arc::new(DomainEventDispatcher::<RecordingPublished>::new());
+    /// let sink = Arc::clone(&published);
+    /// dispatcher.subscribe(move |event| sink.lock().unwrap().push(event.lesson_id()));
+    /// let use_case = PublishRecording::with_dispatcher(dispatcher);
+    ///
+    /// let recording = SessionRecording::new(
+    ///     "Week 1 live Q&A".to_string(),
+    ///     "https://videos.example.com/week-1.mp4".to_string(),
+    ///     3600,
+    /// );
+    ///
+    /// let missing_chapter =
+    ///     use_case.publish(&mut course, Id::new(), &session, &cohort, recording.clone());
+    /// assert!(matches!(missing_chapter, Err(PublishRecordingError::CourseError(_))));
+    ///
+    /// let lesson_id = use_case
+    ///     .publish(&mut course, chapter_id, &session, &cohort, recording)
+    ///     .unwrap();
+    /// assert_eq!(*published.lock().unwrap(), vec![lesson_id]);
+    /// ```
+    pub fn publish(
+        &self,
+        course: &mut Course,
+        chapter_id: Id,
+        session: &LiveSession,
+        cohort: &Cohort,
+        recording: SessionRecording,
+    ) -> Result<Id, PublishRecordingError> {
+        if session.is_cancelled() {
+            return Err(PublishRecordingError::SessionCancelled);
+        }
+        if session.course_id() != course.id() {
+            return Err(PublishRecordingError::SessionCourseMismatch);
+        }
+        if cohort.course_id() != course.id() {
+            return Err(PublishRecordingError::CohortCourseMismatch);
+        }
+
+        if let Some(extensions) = &self.extensions {
+            extensions.check_content(&recording.title)?;
+        }
+
+        let mut lesson = Lesson::new(
+            recording.title,
+            Duration::from_seconds(recording.duration_seconds),
+            recording.video_url,
+            0,
+        )?;
+        lesson.update_transcript_url(recording.transcript_url)?;
+
+        let lesson_id = lesson.id();
+        match &self.extensions {
+            Some(extensions) => {
+                let mut updated = course.clone();
+                updated.add_lesson(chapter_id, lesson, None)?;
+                extensions.check_course(&updated)?;
+                *course = updated;
+            }
+            None => course.add_lesson(chapter_id, lesson, None)?,
+        }
+
+        let event =
+            RecordingPublished::new(course.id(), lesson_id, Self::recipients(session, cohort));
+        self.event_dispatcher.notify(&event);
+
+        Ok(lesson_id)
+    }
+
+    fn recipients(session: &LiveSession, cohort: &Cohort) -> Vec<Email> {
+        let members = cohort.groups().iter().flat_map(|group| group.members());
+
+        let mut recipients: Vec<Email> = Vec::new();
+        for learner in members.chain(session.attendees()) {
+            if !recipients.contains(learner) {
+                recipients.push(learner.clone());
+            }
+        }
+        recipients
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, StudyGroup};
+    use education_platform_common::{DateTime, Duration, TimeRange, Url};
+    use std::sync::Mutex;
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn create_test_course() -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new("Live Sessions".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_test_session(course_id: Id) -> LiveSession {
+        let start = DateTime::new(2024, 12, 9, 18, 0, 0).unwrap();
+        LiveSession::new(
+            course_id,
+            TimeRange::starting_at(start, Duration::from_hours(1)).unwrap(),
+            "https://meet.example.com/rust-101".to_string(),
+            30,
+        )
+        .unwrap()
+    }
+
+    fn create_test_recording() -> SessionRecording {
+        SessionRecording::new(
+            "Week 1 live Q&A".to_string(),
+            "https://videos.example.com/week-1.mp4".to_string(),
+            3600,
+        )
+    }
+
+    fn create_recording_use_case() -> (PublishRecording, Arc<Mutex<Vec<RecordingPublished>>>) {
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = Arc::new(DomainEventDispatcher::new());
+        let sink = Arc::clone(&published);
+        dispatcher.subscribe(move |event: &RecordingPublished| {
+            sink.lock().unwrap().push(event.clone());
+        });
+        (PublishRecording::with_dispatcher(dispatcher), published)
+    }
+
+    mod publish {
+        use super::*;
+
+        #[test]
+        fn test_appends_lesson_to_chapter() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let session = create_test_session(course.id());
+            let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+
+            let lesson_id = PublishRecording::new()
+                .publish(&mut course, chapter_id, &session, &cohort, create_test_recording())
+                .unwrap();
+
+            let lesson = &course.chapters()[0].lessons()[1];
+            assert_eq!(lesson.id(), lesson_id);
+            assert_eq!(lesson.name().as_str(), "Week 1 live Q&A");
+            assert_eq!(lesson.duration().total_seconds(), 3600);
+            assert!(lesson.transcript_url().is_none());
+            assert_eq!(course.duration().total_seconds(), 5400);
+        }
+
+        #[test]
+        fn test_attaches_transcript_when_available() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let session = create_test_session(course.id());
+            let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+            let recording = create_test_recording()
+                .with_transcript("https://videos.example.com/week-1.vtt".to_string());
+
+            PublishRecording::new()
+                .publish(&mut course, chapter_id, &session, &cohort, recording)
+                .unwrap();
+
+            assert_eq!(
+                course.chapters()[0].lessons()[1]
+                    .transcript_url()
+                    .map(Url::as_str),
+                Some("https://videos.example.com/week-1.vtt")
+            );
+        }
+
+        #[test]
+        fn test_notifies_cohort_and_attendees_once() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let mut session = create_test_session(course.id());
+            session.register(create_email("alice@example.com")).unwrap();
+            session.register(create_email("carol@example.com")).unwrap();
+            let mut cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+            let group = StudyGroup::new("Night Owls", "Finish by June", 5).unwrap();
+            let group_id = group.id();
+            cohort.add_group(group).unwrap();
+            cohort
+                .join_group(group_id, create_email("alice@example.com"))
+                .unwrap();
+            cohort
+                .join_group(group_id, create_email("bob@example.com"))
+                .unwrap();
+            let (use_case, published) = create_recording_use_case();
+
+            let lesson_id = use_case
+                .publish(&mut course, chapter_id, &session, &cohort, create_test_recording())
+                .unwrap();
+
+            let events = published.lock().unwrap();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].course_id(), course.id());
+            assert_eq!(events[0].lesson_id(), lesson_id);
+            let addresses: Vec<&str> = events[0].recipients().iter().map(Email::address).collect();
+            assert_eq!(
+                addresses,
+                vec!["alice@example.com", "bob@example.com", "carol@example.com"]
+            );
+        }
+    }
+
+    mod rejections {
+        use super::*;
+
+        fn assert_rejected(
+            course: &mut Course,
+            chapter_id: Id,
+            session: &LiveSession,
+            cohort: &Cohort,
+            recording: SessionRecording,
+        ) -> PublishRecordingError {
+            let (use_case, published) = create_recording_use_case();
+
+            let error = use_case
+                .publish(course, chapter_id, session, cohort, recording)
+                .err()
+                .unwrap();
+
+            assert_eq!(course.number_of_lessons(), 1);
+            assert!(published.lock().unwrap().is_empty());
+            error
+        }
+
+        #[test]
+        fn test_cancelled_session_is_rejected() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let mut session = create_test_session(course.id());
+            session.cancel().unwrap();
+            let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+
+            let error = assert_rejected(
+                &mut course,
+                chapter_id,
+                &session,
+                &cohort,
+                create_test_recording(),
+            );
+
+            assert_eq!(error, PublishRecordingError::SessionCancelled);
+        }
+
+        #[test]
+        fn test_session_of_other_course_is_rejected() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let session = create_test_session(Id::new());
+            let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+
+            let error = assert_rejected(
+                &mut course,
+                chapter_id,
+                &session,
+                &cohort,
+                create_test_recording(),
+            );
+
+            assert_eq!(error, PublishRecordingError::SessionCourseMismatch);
+        }
+
+        #[test]
+        fn test_cohort_of_other_course_is_rejected() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let session = create_test_session(course.id());
+            let cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+
+            let error = assert_rejected(
+                &mut course,
+                chapter_id,
+                &session,
+                &cohort,
+                create_test_recording(),
+            );
+
+            assert_eq!(error, PublishRecordingError::CohortCourseMismatch);
+        }
+
+        #[test]
+        fn test_invalid_transcript_is_rejected() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let session = create_test_session(course.id());
+            let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+            let recording = create_test_recording().with_transcript("not-a-url".to_string());
+
+            let error = assert_rejected(&mut course, chapter_id, &session, &cohort, recording);
+
+            assert!(matches!(
+                error,
+                PublishRecordingError::LessonError(LessonError::TranscriptUrlError(_))
+            ));
+        }
+
+        #[test]
+        fn test_zero_duration_is_rejected() {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let session = create_test_session(course.id());
+            let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+            let recording = SessionRecording::new(
+                "Week 1 live Q&A".to_string(),
+                "https://videos.example.com/week-1.mp4".to_string(),
+                0,
+            );
+
+            let error = assert_rejected(&mut course, chapter_id, &session, &cohort, recording);
+
+            assert_eq!(error, PublishRecordingError::LessonError(LessonError::DurationIsZero));
+        }
+    }
+
+    mod extensions {
+        use super::*;
+        use crate::{BlockedTermsPolicy, ExtensionError, NamePatternRule};
+
+        fn publish_with(
+            registry: ExtensionRegistry,
+        ) -> (Course, Result<Id, PublishRecordingError>) {
+            let mut course = create_test_course();
+            let chapter_id = course.chapters()[0].id();
+            let session = create_test_session(course.id());
+            let cohort = Cohort::new(course.id(), "Spring 2025").unwrap();
+            let use_case = PublishRecording::with_extensions(Arc::new(registry));
+
+            let result = use_case.publish(
+                &mut course,
+                chapter_id,
+                &session,
+                &cohort,
+                create_test_recording(),
+            );
+            (course, result)
+        }
+
+        #[test]
+        fn test_subscribers_hear_about_publication() {
+            let registry = ExtensionRegistry::new();
+            let published = Arc::new(Mutex::new(Vec::new()));
+            let sink = Arc::clone(&published);
+            registry
+                .on_recording_published(move |event| sink.lock().unwrap().push(event.lesson_id()));
+
+            let (_, result) = publish_with(registry);
+
+            assert_eq!(*published.lock().unwrap(), vec![result.unwrap()]);
+        }
+
+        #[test]
+        fn test_content_policy_rejects_title() {
+            let mut registry = ExtensionRegistry::new();
+            registry.register_content_policy(BlockedTermsPolicy::new("no-q-and-a", ["Q&A"]));
+
+            let (course, result) = publish_with(registry);
+
+            assert!(matches!(
+                result,
+                Err(PublishRecordingError::ExtensionError(
+                    ExtensionError::ContentRejected { .. }
+                ))
+            ));
+            assert_eq!(course.number_of_lessons(), 1);
+        }
+
+        #[test]
+        fn test_course_rule_failure_leaves_course_unchanged() {
+            let mut registry = ExtensionRegistry::new();
+            registry.register_course_rule(
+                NamePatternRule::new("letters-only", "^[A-Za-z ]+$").unwrap(),
+            );
+
+            let (course, result) = publish_with(registry);
+
+            assert!(matches!(
+                result,
+                Err(PublishRecordingError::ExtensionError(
+                    ExtensionError::RuleViolated { .. }
+                ))
+            ));
+            assert_eq!(course.number_of_lessons(), 1);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod admin_queries;
+mod catalog_queries;
+mod certificate_queries;
+mod dashboard_queries;
+
+pub use admin_queries::*;
+pub use catalog_queries::*;
+pub use certificate_queries::*;
+pub use dashboard_queries::*;
@@ -0,0 +1,262 @@
+use crate::{Course, LearnerDashboard, ModerationStatus};
+use education_platform_common::Entity;
+use std::sync::{Arc, RwLock};
+
+/// Platform-wide totals for the administration overview.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlatformOverviewDto {
+    pub course_count: usize,
+    pub lesson_count: u32,
+    pub learner_count: usize,
+    pub pending_review_count: usize,
+}
+
+/// How many learners completed a course.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseCompletionDto {
+    pub course_id: String,
+    pub course_name: String,
+    pub completed_count: usize,
+}
+
+/// A review waiting for moderation.
+///
+/// `submitted_on` is an ISO 8601 date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingReviewDto {
+    pub course_id: String,
+    pub review_id: String,
+    pub author_email: String,
+    pub rating: u8,
+    pub comment: String,
+    pub submitted_on: String,
+}
+
+/// Read-side queries for platform administrators.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{AdminQueries, LearnerDashboard};
+/// use std::sync::{Arc, RwLock};
+///
+/// let queries = AdminQueries::new(
+///     Arc::new(RwLock::new(Vec::new())),
+///     Arc::new(RwLock::new(LearnerDashboard::new())),
+/// );
+///
+/// let overview = queries.overview();
+/// assert_eq!(overview.course_count, 0);
+/// assert_eq!(overview.learner_count, 0);
+/// ```
+#[derive(Clone)]
+pub struct AdminQueries {
+    courses: Arc<RwLock<Vec<Course>>>,
+    dashboard: Arc<RwLock<LearnerDashboard>>,
+}
+
+impl AdminQueries {
+    /// Creates the query service over the shared courses and dashboard
+    /// projection.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        courses: Arc<RwLock<Vec<Course>>>,
+        dashboard: Arc<RwLock<LearnerDashboard>>,
+    ) -> Self {
+        Self { courses, dashboard }
+    }
+
+    /// Returns the platform-wide totals.
+    #[must_use]
+    pub fn overview(&self) -> PlatformOverviewDto {
+        let courses = self.courses.read().unwrap_or_else(|e| e.into_inner());
+        let dashboard = self.dashboard.read().unwrap_or_else(|e| e.into_inner());
+
+        PlatformOverviewDto {
+            course_count: courses.len(),
+            lesson_count: courses.iter().map(Course::number_of_lessons).sum(),
+            learner_count: dashboard.learner_count(),
+            pending_review_count: courses
+                .iter()
+                .flat_map(Course::reviews)
+                .filter(|review| review.status() == ModerationStatus::Pending)
+                .count(),
+        }
+    }
+
+    /// Returns the completions of every course, most completed first.
+    ///
+    /// Courses with the same number of completions keep catalog order.
+    #[must_use]
+    pub fn course_completions(&self) -> Vec<CourseCompletionDto> {
+        let courses = self.courses.read().unwrap_or_else(|e| e.into_inner());
+        let dashboard = self.dashboard.read().unwrap_or_else(|e| e.into_inner());
+
+        let mut completions: Vec<CourseCompletionDto> = courses
+            .iter()
+            .map(|course| CourseCompletionDto {
+                course_id: course.id().to_string(),
+                course_name: course.name().to_string(),
+                completed_count: dashboard.completion_count(course.id()),
+            })
+            .collect();
+        completions.sort_by_key(|dto| std::cmp::Reverse(dto.completed_count));
+        completions
+    }
+
+    /// Returns the reviews waiting for moderation, oldest first.
+    #[must_use]
+    pub fn pending_reviews(&self) -> Vec<PendingReviewDto> {
+        let courses = self.courses.read().unwrap_or_else(|e| e.into_inner());
+
+        let mut reviews: Vec<_> = courses
+            .iter()
+            .flat_map(|course| course.reviews().iter().map(move |review| (course, review)))
+            .filter(|(_, review)| review.status() == ModerationStatus::Pending)
+            .collect();
+        reviews.sort_by_key(|(_, review)| review.date());
+
+        reviews
+            .into_iter()
+            .map(|(course, review)| PendingReviewDto {
+                course_id: course.id().to_string(),
+                review_id: review.id().to_string(),
+                author_email: review.author_email().address().to_string(),
+                rating: review.rating().stars(),
+                comment: review.comment().to_string(),
+                submitted_on: review.date().format_iso(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, CourseEnded, Lesson, Review};
+    use education_platform_common::{Email, Projection};
+
+    fn create_course(name: &str, lesson_count: usize) -> Course {
+        let lessons = (0..lesson_count)
+            .map(|index| {
+                Lesson::new(
+                    format!("Lesson {}", index + 1),
+                    600,
+                    "https://example.com/video.mp4".to_string(),
+                    index,
+                )
+                .unwrap()
+            })
+            .collect();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, lessons).unwrap();
+        Course::new(name.to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_review(author: &str, rating: u8) -> Review {
+        Review::new(author.to_string(), rating, "Worth it.".to_string()).unwrap()
+    }
+
+    fn completed(address: &str, course: &Course) -> CourseEnded {
+        CourseEnded::new(Email::new(address.to_string()).unwrap(), course.id())
+    }
+
+    fn create_queries(courses: Vec<Course>, events: &[CourseEnded]) -> AdminQueries {
+        let mut dashboard = LearnerDashboard::new();
+        dashboard.rebuild(events);
+        AdminQueries::new(Arc::new(RwLock::new(courses)), Arc::new(RwLock::new(dashboard)))
+    }
+
+    mod overview {
+        use super::*;
+
+        #[test]
+        fn test_totals() {
+            let mut rust = create_course("Rust Programming", 3);
+            let approved = create_review("ana@example.com", 5);
+            let approved_id = approved.id();
+            rust.add_review(approved).unwrap();
+            rust.approve_review(approved_id).unwrap();
+            rust.add_review(create_review("bob@example.com", 2))
+                .unwrap();
+            let gleam = create_course("Gleam Basics", 2);
+            let events = [
+                completed("ana@example.com", &rust),
+                completed("ana@example.com", &gleam),
+                completed("bob@example.com", &rust),
+            ];
+            let queries = create_queries(vec![rust, gleam], &events);
+
+            assert_eq!(
+                queries.overview(),
+                PlatformOverviewDto {
+                    course_count: 2,
+                    lesson_count: 5,
+                    learner_count: 2,
+                    pending_review_count: 1,
+                }
+            );
+        }
+    }
+
+    mod course_completions {
+        use super::*;
+
+        #[test]
+        fn test_most_completed_first() {
+            let rust = create_course("Rust Programming", 1);
+            let gleam = create_course("Gleam Basics", 1);
+            let elixir = create_course("Elixir Basics", 1);
+            let events = [
+                completed("ana@example.com", &gleam),
+                completed("bob@example.com", &gleam),
+                completed("ana@example.com", &elixir),
+            ];
+            let queries = create_queries(vec![rust.clone(), gleam.clone(), elixir], &events);
+
+            let completions = queries.course_completions();
+
+            let counts: Vec<(&str, usize)> = completions
+                .iter()
+                .map(|dto| (dto.course_name.as_str(), dto.completed_count))
+                .collect();
+            assert_eq!(
+                counts,
+                [
+                    ("Gleam Basics", 2),
+                    ("Elixir Basics", 1),
+                    ("Rust Programming", 0)
+                ]
+            );
+            assert_eq!(completions[0].course_id, gleam.id().to_string());
+            assert_eq!(completions[2].course_id, rust.id().to_string());
+        }
+    }
+
+    mod pending_reviews {
+        use super::*;
+
+        #[test]
+        fn test_lists_only_pending_reviews() {
+            let mut course = create_course("Rust Programming", 1);
+            let pending = create_review("bob@example.com", 3);
+            let pending_id = pending.id();
+            let rejected = create_review("eve@example.com", 1);
+            let rejected_id = rejected.id();
+            course.add_review(pending).unwrap();
+            course.add_review(rejected).unwrap();
+            course.reject_review(rejected_id).unwrap();
+            let course_id = course.id().to_string();
+            let queries = create_queries(vec![course], &[]);
+
+            let reviews = queries.pending_reviews();
+
+            assert_eq!(reviews.len(), 1);
+            assert_eq!(reviews[0].course_id, course_id);
+            assert_eq!(reviews[0].review_id, pending_id.to_string());
+            assert_eq!(reviews[0].author_email, "bob@example.com");
+            assert_eq!(reviews[0].rating, 3);
+            assert_eq!(reviews[0].comment, "Worth it.");
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{Chapter, Course, CourseId};
+use education_platform_common::{Entity, IdError};
+use std::sync::{Arc, RwLock};
+
+/// A course as listed in the public catalog.
+///
+/// `duration` is written as `"01h 30m 00s"` and `average_rating` as
+/// `"4.5/5"`, or `None` while no review has been approved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseSummaryDto {
+    pub course_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: Option<String>,
+    pub chapter_count: usize,
+    pub lesson_count: u32,
+    pub duration: String,
+    pub average_rating: Option<String>,
+}
+
+/// A chapter as shown on a course's catalog page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChapterSummaryDto {
+    pub chapter_id: String,
+    pub name: String,
+    pub lesson_count: usize,
+    pub duration: String,
+}
+
+/// A course's catalog page: its summary, objectives and outline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseDetailDto {
+    pub summary: CourseSummaryDto,
+    pub learning_objectives: Vec<String>,
+    pub chapters: Vec<ChapterSummaryDto>,
+}
+
+/// Read-side queries over the course catalog.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CatalogQueries, Chapter, Course, Lesson};
+/// use std::sync::{Arc, RwLock};
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+///
+/// let queries = CatalogQueries::new(Arc::new(RwLock::new(vec![course])));
+///
+/// let courses = queries.courses();
+/// assert_eq!(courses[0].name, "Rust Programming");
+/// assert_eq!(courses[0].duration, "30m 00s");
+/// ```
+#[derive(Clone)]
+pub struct CatalogQueries {
+    courses: Arc<RwLock<Vec<Course>>>,
+}
+
+impl CatalogQueries {
+    /// Creates the query service over a shared collection of courses.
+    #[inline]
+    #[must_use]
+    pub const fn new(courses: Arc<RwLock<Vec<Course>>>) -> Self {
+        Self { courses }
+    }
+
+    /// Returns every course in the catalog, in catalog order.
+    #[must_use]
+    pub fn courses(&self) -> Vec<CourseSummaryDto> {
+        let courses = self.courses.read().unwrap_or_else(|e| e.into_inner());
+
+        courses.iter().map(course_summary).collect()
+    }
+
+    /// Returns the catalog page of a course.
+    ///
+    /// Returns `Ok(None)` for a well-formed ID that is not in the catalog.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IdError` if `course_id` is not a valid ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::CatalogQueries;
+    /// use education_platform_common::Id;
+    /// use std::sync::{Arc, RwLock};
+    ///
+    /// let queries = CatalogQueries::new(Arc::new(RwLock::new(Vec::new())));
+    ///
+    /// assert_eq!(queries.course(&Id::new().to_string()), Ok(None));
+    /// assert!(queries.course("not-an-id").is_err());
+    /// ```
+    pub fn course(&self, course_id: &str) -> Result<Option<CourseDetailDto>, IdError> {
+        let id: CourseId = course_id.trim().parse()?;
+        let courses = self.courses.read().unwrap_or_else(|e| e.into_inner());
+
+        Ok(courses
+            .iter()
+            .find(|course| course.course_id() == id)
+            .map(|course| CourseDetailDto {
+                summary: course_summary(course),
+                learning_objectives: course.learning_objectives().to_vec(),
+                chapters: course.chapters().iter().map(chapter_summary).collect(),
+            }))
+    }
+}
+
+fn course_summary(course: &Course) -> CourseSummaryDto {
+    CourseSummaryDto {
+        course_id: course.id().to_string(),
+        name: course.name().to_string(),
+        description: course.description().map(str::to_string),
+        difficulty: course.difficulty().map(|difficulty| difficulty.to_string()),
+        chapter_count: course.chapter_quantity(),
+        lesson_count: course.number_of_lessons(),
+        duration: course.duration().to_string(),
+        average_rating: course.average_rating().map(|rating| rating.to_string()),
+    }
+}
+
+fn chapter_summary(chapter: &Chapter) -> ChapterSummaryDto {
+    ChapterSummaryDto {
+        chapter_id: chapter.id().to_string(),
+        name: chapter.name().to_string(),
+        lesson_count: chapter.lesson_quantity(),
+        duration: chapter.total_duration().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DifficultyLevel, Lesson, Review};
+    use education_platform_common::Id;
+
+    fn create_course(name: &str, lesson_seconds: &[u64]) -> Course {
+        let lessons = lesson_seconds
+            .iter()
+            .enumerate()
+            .map(|(index, seconds)| {
+                Lesson::new(
+                    format!("Lesson {}", index + 1),
+                    *seconds,
+                    "https://example.com/video.mp4".to_string(),
+                    index,
+                )
+                .unwrap()
+            })
+            .collect();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, lessons).unwrap();
+        Course::new(name.to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_queries(courses: Vec<Course>) -> CatalogQueries {
+        CatalogQueries::new(Arc::new(RwLock::new(courses)))
+    }
+
+    mod courses {
+        use super::*;
+
+        #[test]
+        fn test_lists_courses_in_order() {
+            let queries = create_queries(vec![
+                create_course("Rust Programming", &[600]),
+                create_course("Gleam Basics", &[300, 300]),
+            ]);
+
+            let names: Vec<String> = queries.courses().into_iter().map(|dto| dto.name).collect();
+
+            assert_eq!(names, ["Rust Programming", "Gleam Basics"]);
+        }
+
+        #[test]
+        fn test_summary_uses_plain_values() {
+            let mut course = create_course("Rust Programming", &[1800, 900]);
+            course
+                .update_description(Some("Learn Rust from scratch.".to_string()))
+                .unwrap();
+            course.update_difficulty(Some(DifficultyLevel::Beginner));
+            let review =
+                Review::new("ana@example.com".to_string(), 4, "Clear.".to_string()).unwrap();
+            let review_id = review.id();
+            course.add_review(review).unwrap();
+            course.approve_review(review_id).unwrap();
+            let queries = create_queries(vec![course.clone()]);
+
+            assert_eq!(
+                queries.courses(),
+                [CourseSummaryDto {
+                    course_id: course.id().to_string(),
+                    name: "Rust Programming".to_string(),
+                    description: Some("Learn Rust from scratch.".to_string()),
+                    difficulty: Some(DifficultyLevel::Beginner.to_string()),
+                    chapter_count: 1,
+                    lesson_count: 2,
+                    duration: "45m 00s".to_string(),
+                    average_rating: Some("4/5".to_string()),
+                }]
+            );
+        }
+
+        #[test]
+        fn test_reflects_courses_added_after_creation() {
+            let courses = Arc::new(RwLock::new(Vec::new()));
+            let queries = CatalogQueries::new(Arc::clone(&courses));
+
+            courses
+                .write()
+                .unwrap()
+                .push(create_course("Rust Programming", &[600]));
+
+            assert_eq!(queries.courses().len(), 1);
+        }
+    }
+
+    mod course {
+        use super::*;
+
+        #[test]
+        fn test_returns_outline() {
+            let course = create_course("Rust Programming", &[600, 1200]);
+            let chapter_id = course.chapters()[0].id().to_string();
+            let queries = create_queries(vec![course.clone()]);
+
+            let dto = queries.course(&course.id().to_string()).unwrap().unwrap();
+
+            assert_eq!(dto.summary.name, "Rust Programming");
+            assert!(dto.learning_objectives.is_empty());
+            assert_eq!(
+                dto.chapters,
+                [ChapterSummaryDto {
+                    chapter_id,
+                    name: "Getting Started".to_string(),
+                    lesson_count: 2,
+                    duration: "30m 00s".to_string(),
+                }]
+            );
+        }
+
+        #[test]
+        fn test_unknown_course_is_none() {
+            let queries = create_queries(vec![create_course("Rust Programming", &[600])]);
+
+            assert_eq!(queries.course(&Id::new().to_string()), Ok(None));
+        }
+
+        #[test]
+        fn test_invalid_id_returns_error() {
+            let queries = create_queries(Vec::new());
+
+            assert!(queries.course("not-an-id").is_err());
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{CertificateError, CertificateRegistry, CertificateStatus};
+use education_platform_common::{Id, PersonName};
+use std::sync::{Arc, RwLock};
+
+/// How much of the learner's name a public verification reveals.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::LearnerNameDisclosure;
+///
+/// assert_eq!(LearnerNameDisclosure::default(), LearnerNameDisclosure::Full);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LearnerNameDisclosure {
+    /// The name as printed on the certificate.
+    #[default]
+    Full,
+    /// Only the initial of each name, e.g. "A. T.".
+    Initials,
+    /// No name at all.
+    Hidden,
+}
+
+impl LearnerNameDisclosure {
+    fn disclose(self, name: &PersonName) -> Option<String> {
+        match self {
+            Self::Full => Some(name.full_name()),
+            Self::Initials => Some(name.initials()),
+            Self::Hidden => None,
+        }
+    }
+}
+
+/// Public answer to "is this certificate genuine?".
+///
+/// `status` is `"valid"` or `"revoked"`. `hash_matches` is `None` when the
+/// verifier did not supply a hash. The revocation reason is kept internal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateVerificationDto {
+    pub certificate_id: String,
+    pub status: String,
+    pub course_name: String,
+    pub learner_name: Option<String>,
+    pub issued_at: String,
+    pub revoked_at: Option<String>,
+    pub hash_matches: Option<bool>,
+}
+
+/// Read-side verification of issued certificates, safe to expose publicly.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     CertificateDigest, CertificateQueries, CertificateRegistry, CourseProgress,
+///     IntegrityReviewQueue, LearnerNameDisclosure, LessonProgress,
+/// };
+/// use education_platform_common::{DateTime, Entity, PersonName};
+/// use std::sync::{Arc, RwLock};
+///
+/// struct UpperDigest;
+///
+/// impl CertificateDigest for UpperDigest {
+///     fn digest(&self, content: &str) -> String {
+///         content.to_uppercase()
+///     }
+/// }
+///
+/// let start = DateTime::new(2024, 5, 1, 9, 0, 0).unwrap();
+/// let end = DateTime::new(2024, 5, 1, 10, 0, 0).unwrap();
+/// let lesson = LessonProgress::new("Intro".to_string(), 1800, Some(start), Some(end)).unwrap();
+/// let progress = CourseProgress::builder()
+///     .course_name("Rust Course")
+///     .user_email("ana@example.com")
+///     .lessons(vec![lesson])
+///     .build()
+///     .unwrap();
+/// let name = PersonName::new("Ana".to_string(), None, "Torres".to_string(), None).unwrap();
+///
+/// let review = IntegrityReviewQueue::default();
+/// let registry = Arc::new(RwLock::new(CertificateRegistry::new()));
+/// let id = registry
+///     .write()
+///     .unwrap()
+///     .issue(&progress, &review, name, end, &UpperDigest)
+///     .unwrap()
+///     .id();
+/// let queries = CertificateQueries::new(registry)
+///     .with_disclosure(LearnerNameDisclosure::Initials);
+///
+/// let dto = queries.verify(&id.to_string(), None).unwrap().unwrap();
+///
+/// assert_eq!(dto.status, "valid");
+/// assert_eq!(dto.learner_name.as_deref(), Some("A. T."));
+/// assert_eq!(dto.issued_at, "2024-05-01T10:00:00");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CertificateQueries {
+    registry: Arc<RwLock<CertificateRegistry>>,
+    disclosure: LearnerNameDisclosure,
+}
+
+impl CertificateQueries {
+    /// Creates the query service over a shared registry, disclosing full names.
+    #[inline]
+    #[must_use]
+    pub const fn new(registry: Arc<RwLock<CertificateRegistry>>) -> Self {
+        Self {
+            registry,
+            disclosure: LearnerNameDisclosure::Full,
+        }
+    }
+
+    /// Sets how much of the learner's name verification reveals.
+    #[inline]
+    #[must_use]
+    pub const fn with_disclosure(mut self, disclosure: LearnerNameDisclosure) -> Self {
+        self.disclosure = disclosure;
+        self
+    }
+
+    /// Verifies a certificate by ID, optionally checking its hash.
+    ///
+    /// Returns `Ok(None)` for a well-formed ID that was never issued. Hashes
+    /// are compared ignoring ASCII case and surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CertificateError::IdNotValid` if `certificate_id` is not a valid ID.
+    pub fn verify(
+        &self,
+        certificate_id: &str,
+        hash: Option<&str>,
+    ) -> Result<Option<CertificateVerificationDto>, CertificateError> {
+        let id: Id = certificate_id.trim().parse()?;
+        let registry = self.registry.read().unwrap_or_else(|e| e.into_inner());
+
+        let (Some(certificate), Some(status)) = (registry.certificate(id), registry.status(id))
+        else {
+            return Ok(None);
+        };
+        let (status, revoked_at) = match status {
+            CertificateStatus::Valid => ("valid", None),
+            CertificateStatus::Revoked(revocation) => {
+                ("revoked", Some(revocation.revoked_at().format_iso()))
+            }
+        };
+
+        Ok(Some(CertificateVerificationDto {
+            certificate_id: id.to_string(),
+            status: status.to_string(),
+            course_name: certificate.course_name().to_string(),
+            learner_name: self.disclosure.disclose(certificate.learner_name()),
+            issued_at: certificate.issued_at().format_iso(),
+            revoked_at,
+            hash_matches: hash.map(|hash| hash.trim().eq_ignore_ascii_case(certificate.digest())),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CertificateDigest, CourseProgress, IntegrityReviewQueue, LessonProgress};
+    use education_platform_common::{DateTime, Entity};
+
+    struct ReverseDigest;
+
+    impl CertificateDigest for ReverseDigest {
+        fn digest(&self, content: &str) -> String {
+            content.chars().rev().collect()
+        }
+    }
+
+    fn create_time(hour: u32) -> DateTime {
+        DateTime::new(2024, 5, 1, hour, 0, 0).unwrap()
+    }
+
+    fn create_queries() -> (CertificateQueries, Arc<RwLock<CertificateRegistry>>, Id) {
+        let lesson = LessonProgress::new(
+            "Intro".to_string(),
+            1800,
+            Some(create_time(9)),
+            Some(create_time(10)),
+        )
+        .unwrap();
+        let progress = CourseProgress::builder()
+            .course_name("Rust Course")
+            .user_email("ana@example.com")
+            .lessons(vec![lesson])
+            .build()
+            .unwrap();
+        let name = PersonName::new(
+            "Ana".to_string(),
+            Some("María".to_string()),
+            "Torres".to_string(),
+            None,
+        )
+        .unwrap();
+        let registry = Arc::new(RwLock::new(CertificateRegistry::new()));
+        let id = registry
+            .write()
+            .unwrap()
+            .issue(
+                &progress,
+                &IntegrityReviewQueue::default(),
+                name,
+                create_time(11),
+                &ReverseDigest,
+            )
+            .unwrap()
+            .id();
+        (CertificateQueries::new(Arc::clone(&registry)), registry, id)
+    }
+
+    mod verify {
+        use super::*;
+
+        #[test]
+        fn test_valid_certificate_with_full_name() {
+            let (queries, _, id) = create_queries();
+
+            let dto = queries.verify(&id.to_string(), None).unwrap().unwrap();
+
+            assert_eq!(
+                dto,
+                CertificateVerificationDto {
+                    certificate_id: id.to_string(),
+                    status: "valid".to_string(),
+                    course_name: "Rust Course".to_string(),
+                    learner_name: Some("Ana María Torres".to_string()),
+                    issued_at: "2024-05-01T11:00:00".to_string(),
+                    revoked_at: None,
+                    hash_matches: None,
+                }
+            );
+        }
+
+        #[test]
+        fn test_revoked_certificate_reports_revocation_date() {
+            let (queries, registry, id) = create_queries();
+            registry
+                .write()
+                .unwrap()
+                .revoke(id, "Integrity review", create_time(12))
+                .unwrap();
+
+            let dto = queries.verify(&id.to_string(), None).unwrap().unwrap();
+
+            assert_eq!(dto.status, "revoked");
+            assert_eq!(dto.revoked_at.as_deref(), Some("2024-05-01T12:00:00"));
+        }
+
+        #[test]
+        fn test_hash_is_checked_when_given() {
+            let (queries, registry, id) = create_queries();
+            let digest = registry
+                .read()
+                .unwrap()
+                .certificate(id)
+                .unwrap()
+                .digest()
+                .to_ascii_uppercase();
+
+            let matching = queries
+                .verify(&id.to_string(), Some(&digest))
+                .unwrap()
+                .unwrap();
+            let tampered = queries
+                .verify(&id.to_string(), Some("abc"))
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(matching.hash_matches, Some(true));
+            assert_eq!(tampered.hash_matches, Some(false));
+        }
+
+        #[test]
+        fn test_hidden_name_is_not_disclosed() {
+            let (queries, _, id) = create_queries();
+            let queries = queries.with_disclosure(LearnerNameDisclosure::Hidden);
+
+            let dto = queries.verify(&id.to_string(), None).unwrap().unwrap();
+
+            assert_eq!(dto.learner_name, None);
+        }
+
+        #[test]
+        fn test_unknown_certificate_returns_none() {
+            let (queries, _, _) = create_queries();
+
+            assert_eq!(queries.verify(&Id::new().to_string(), None), Ok(None));
+        }
+
+        #[test]
+        fn test_malformed_id_returns_error() {
+            let (queries, _, _) = create_queries();
+
+            assert!(matches!(
+                queries.verify("not-an-id", None),
+                Err(CertificateError::IdNotValid(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::LearnerDashboard;
+use education_platform_common::{Email, EmailError};
+use std::sync::{Arc, RwLock};
+
+/// Learner dashboard data as exposed to readers outside the domain.
+///
+/// Only plain types are used, so the shape stays stable while the
+/// projection behind it evolves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LearnerDashboardDto {
+    pub user_email: String,
+    pub completed_course_ids: Vec<String>,
+    pub completed_course_count: usize,
+}
+
+/// Read-side queries backed by the learner dashboard projection.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseEnded, DashboardQueries, LearnerDashboard};
+/// use education_platform_common::{DomainEventDispatcher, Email, Id};
+/// use std::sync::{Arc, RwLock};
+///
+/// let dispatcher = DomainEventDispatcher::<CourseEnded>::new();
+/// let dashboard = Arc::new(RwLock::new(LearnerDashboard::new()));
+/// dispatcher.subscribe_projection(Arc::clone(&dashboard));
+/// let queries = DashboardQueries::new(dashboard);
+///
+/// let email = Email::new("user@example.com".to_string()).unwrap();
+/// dispatcher.notify(&CourseEnded::new(email, Id::new()));
+///
+/// let dto = queries.learner_dashboard("user@example.com").unwrap();
+/// assert_eq!(dto.completed_course_count, 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DashboardQueries {
+    dashboard: Arc<RwLock<LearnerDashboard>>,
+}
+
+impl DashboardQueries {
+    /// Creates the query service over a shared dashboard projection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{DashboardQueries, LearnerDashboard};
+    /// use std::sync::{Arc, RwLock};
+    ///
+    /// let queries = DashboardQueries::new(Arc::new(RwLock::new(LearnerDashboard::new())));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new(dashboard: Arc<RwLock<LearnerDashboard>>) -> Self {
+        Self { dashboard }
+    }
+
+    /// Returns the dashboard of a learner.
+    ///
+    /// Learners with no projected activity get an empty dashboard rather
+    /// than an error, since having completed nothing yet is a valid state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EmailError` if `user_email` is not a valid email address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{DashboardQueries, LearnerDashboard};
+    /// use std::sync::{Arc, RwLock};
+    ///
+    /// let queries = DashboardQueries::new(Arc::new(RwLock::new(LearnerDashboard::new())));
+    ///
+    /// let dto = queries.learner_dashboard("new@example.com").unwrap();
+    /// assert_eq!(dto.completed_course_count, 0);
+    ///
+    /// assert!(queries.learner_dashboard("not-an-email").is_err());
+    /// ```
+    pub fn learner_dashboard(&self, user_email: &str) -> Result<LearnerDashboardDto, EmailError> {
+        let email = Email::new(user_email.to_string())?;
+        let dashboard = self.dashboard.read().unwrap_or_else(|e| e.into_inner());
+
+        let completed_course_ids: Vec<String> = dashboard
+            .summary(&email)
+            .map(|summary| {
+                summary
+                    .completed_courses()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(LearnerDashboardDto {
+            user_email: email.address().to_string(),
+            completed_course_count: completed_course_ids.len(),
+            completed_course_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CourseEnded;
+    use education_platform_common::{Id, Projection};
+
+    fn create_queries(events: &[CourseEnded]) -> DashboardQueries {
+        let mut dashboard = LearnerDashboard::new();
+        dashboard.rebuild(events);
+        DashboardQueries::new(Arc::new(RwLock::new(dashboard)))
+    }
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    mod learner_dashboard {
+        use super::*;
+
+        #[test]
+        fn test_returns_completed_courses_as_strings() {
+            let course_id = Id::new();
+            let queries = create_queries(&[CourseEnded::new(
+                create_email("user@example.com"),
+                course_id,
+            )]);
+
+            let dto = queries.learner_dashboard("user@example.com").unwrap();
+
+            assert_eq!(
+                dto,
+                LearnerDashboardDto {
+                    user_email: "user@example.com".to_string(),
+                    completed_course_ids: vec![course_id.to_string()],
+                    completed_course_count: 1,
+                }
+            );
+        }
+
+        #[test]
+        fn test_unknown_learner_gets_empty_dashboard() {
+            let queries = create_queries(&[]);
+
+            let dto = queries.learner_dashboard("nobody@example.com").unwrap();
+
+            assert!(dto.completed_course_ids.is_empty());
+            assert_eq!(dto.completed_course_count, 0);
+        }
+
+        #[test]
+        fn test_invalid_email_returns_error() {
+            let queries = create_queries(&[]);
+
+            assert!(queries.learner_dashboard("invalid").is_err());
+        }
+
+        #[test]
+        fn test_reflects_events_applied_after_creation() {
+            let dashboard = Arc::new(RwLock::new(LearnerDashboard::new()));
+            let queries = DashboardQueries::new(Arc::clone(&dashboard));
+
+            dashboard
+                .write()
+                .unwrap()
+                .apply(&CourseEnded::new(create_email("user@example.com"), Id::new()));
+
+            let dto = queries.learner_dashboard("user@example.com").unwrap();
+            assert_eq!(dto.completed_course_count, 1);
+        }
+    }
+}
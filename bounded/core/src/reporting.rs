@@ -0,0 +1,11 @@
+mod generated_report;
+mod report_kind;
+mod report_subscription;
+mod scheduled_reports;
+mod subscription_store;
+
+pub use generated_report::*;
+pub use report_kind::*;
+pub use report_subscription::*;
+pub use scheduled_reports::*;
+pub use subscription_store::*;
@@ -0,0 +1,80 @@
+use crate::{Attachment, NotificationError};
+use education_platform_common::DateTime;
+
+/// A report rendered as CSV, ready to be attached to an email.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Cohort, ReportKind, ReportSources};
+/// use education_platform_common::{DateTime, Entity, Id};
+///
+/// let cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+/// let kind = ReportKind::CohortCompletion { cohort_id: cohort.id() };
+/// let at = DateTime::new(2025, 3, 3, 8, 0, 0).unwrap();
+///
+/// let report = kind.generate(&ReportSources::new(&[], &[cohort]), at).unwrap();
+///
+/// assert_eq!(report.filename(), "cohort-completion-2025-03-03.csv");
+/// assert_eq!(report.to_attachment().unwrap().content_type(), "text/csv");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedReport {
+    title: String,
+    slug: String,
+    generated_at: DateTime,
+    csv: String,
+}
+
+impl GeneratedReport {
+    pub(crate) const fn new(
+        title: String,
+        slug: String,
+        generated_at: DateTime,
+        csv: String,
+    ) -> Self {
+        Self {
+            title,
+            slug,
+            generated_at,
+            csv,
+        }
+    }
+
+    /// Returns the human-readable report title.
+    #[inline]
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns when the report was generated.
+    #[inline]
+    #[must_use]
+    pub const fn generated_at(&self) -> DateTime {
+        self.generated_at
+    }
+
+    /// Returns the report rows as CSV with a header line.
+    #[inline]
+    #[must_use]
+    pub fn csv(&self) -> &str {
+        &self.csv
+    }
+
+    /// Returns the file name the report is sent under, dated by generation day.
+    #[must_use]
+    pub fn filename(&self) -> String {
+        format!("{}-{}.csv", self.slug, self.generated_at.date())
+    }
+
+    /// Wraps the report in a CSV attachment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::AttachmentNameNotValid` if the file name
+    /// cannot be used, which only happens for a malformed slug.
+    pub fn to_attachment(&self) -> Result<Attachment, NotificationError> {
+        Attachment::csv(&self.filename(), &self.csv)
+    }
+}
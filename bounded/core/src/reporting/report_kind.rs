@@ -0,0 +1,349 @@
+use crate::{Cohort, CourseProgress, GeneratedReport, ReportError, SegmentRule};
+use education_platform_common::{DateTime, Entity, Id};
+use std::fmt::Write;
+
+/// The data reports are generated from.
+///
+/// Reports only read these records; callers load them from wherever they
+/// are stored before a run.
+#[derive(Debug, Clone, Copy)]
+pub struct ReportSources<'a> {
+    enrollments: &'a [CourseProgress],
+    cohorts: &'a [Cohort],
+}
+
+impl<'a> ReportSources<'a> {
+    /// Creates sources from enrollments and cohorts.
+    #[must_use]
+    pub const fn new(enrollments: &'a [CourseProgress], cohorts: &'a [Cohort]) -> Self {
+        Self { enrollments, cohorts }
+    }
+}
+
+/// A report admins can subscribe to, together with its parameters.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::ReportKind;
+/// use education_platform_common::Id;
+///
+/// let kind = ReportKind::AtRiskLearners { course_id: Id::new(), inactive_days: 14 };
+///
+/// assert_eq!(kind.title(), "At-risk learners");
+/// assert!(ReportKind::AtRiskLearners { course_id: Id::new(), inactive_days: 0 }
+///     .validate()
+///     .is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportKind {
+    /// Completion of each study group in a cohort.
+    CohortCompletion { cohort_id: Id },
+    /// Learners of a course who have not finished it and had no activity
+    /// in the given number of days.
+    AtRiskLearners { course_id: Id, inactive_days: u32 },
+}
+
+impl ReportKind {
+    /// Returns the report title used in emails.
+    #[must_use]
+    pub const fn title(&self) -> &'static str {
+        match self {
+            Self::CohortCompletion { .. } => "Cohort completion",
+            Self::AtRiskLearners { .. } => "At-risk learners",
+        }
+    }
+
+    const fn slug(&self) -> &'static str {
+        match self {
+            Self::CohortCompletion { .. } => "cohort-completion",
+            Self::AtRiskLearners { .. } => "at-risk-learners",
+        }
+    }
+
+    /// Rejects parameters the report cannot run with.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReportError::EmptyInactivityWindow` for an at-risk report
+    /// looking back zero days.
+    pub const fn validate(&self) -> Result<(), ReportError> {
+        match self {
+            Self::AtRiskLearners { inactive_days: 0, .. } => {
+                Err(ReportError::EmptyInactivityWindow)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Generates the report from the given sources.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReportError::CohortNotFound` if a cohort report names a
+    /// cohort missing from the sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     Chapter, Course, CourseProgress, Lesson, ReportKind, ReportSources,
+    /// };
+    /// use education_platform_common::{DateTime, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let enrollments = [CourseProgress::from_course(&course, "ana@example.com").unwrap()];
+    ///
+    /// let kind = ReportKind::AtRiskLearners { course_id: course.id(), inactive_days: 7 };
+    /// let at = DateTime::new(2025, 3, 3, 8, 0, 0).unwrap();
+    /// let report = kind.generate(&ReportSources::new(&enrollments, &[]), at).unwrap();
+    ///
+    /// assert_eq!(
+    ///     report.csv(),
+    ///     "user_email,percentage_completed,last_active_on\r\nana@example.com,0%,\r\n"
+    /// );
+    /// ```
+    pub fn generate(
+        &self,
+        sources: &ReportSources<'_>,
+        generated_at: DateTime,
+    ) -> Result<GeneratedReport, ReportError> {
+        let csv = match *self {
+            Self::CohortCompletion { cohort_id } => cohort_completion(sources, cohort_id)?,
+            Self::AtRiskLearners {
+                course_id,
+                inactive_days,
+            } => at_risk_learners(sources, course_id, inactive_days, generated_at),
+        };
+
+        Ok(GeneratedReport::new(
+            self.title().to_string(),
+            self.slug().to_string(),
+            generated_at,
+            csv,
+        ))
+    }
+}
+
+fn cohort_completion(sources: &ReportSources<'_>, cohort_id: Id) -> Result<String, ReportError> {
+    let cohort = sources
+        .cohorts
+        .iter()
+        .find(|cohort| cohort.id() == cohort_id)
+        .ok_or_else(|| ReportError::CohortNotFound(cohort_id.to_string()))?;
+    let enrollments: Vec<CourseProgress> = sources
+        .enrollments
+        .iter()
+        .filter(|progress| progress.course_id() == Some(cohort.course_id()))
+        .cloned()
+        .collect();
+
+    let mut csv = String::from("group,members,tracked,completed,average_completion\r\n");
+    for group in cohort.groups() {
+        let summary = group.progress_summary(&enrollments);
+        let _ = write!(
+            csv,
+            "{},{},{},{},{}\r\n",
+            escape_cell(group.name().as_str()),
+            summary.member_count(),
+            summary.members_tracked(),
+            summary.members_completed(),
+            summary.average_percentage_completed(),
+        );
+    }
+    Ok(csv)
+}
+
+fn at_risk_learners(
+    sources: &ReportSources<'_>,
+    course_id: Id,
+    inactive_days: u32,
+    generated_at: DateTime,
+) -> String {
+    let rule = SegmentRule::IncompleteCourse(course_id)
+        .and(SegmentRule::ActiveWithinDays(inactive_days).negate());
+    let today = generated_at.date();
+    let mut at_risk: Vec<&CourseProgress> = sources
+        .enrollments
+        .iter()
+        .filter(|progress| rule.matches(&[progress], today))
+        .collect();
+    at_risk.sort_by(|a, b| a.user_email().address().cmp(b.user_email().address()));
+
+    let mut csv = String::from("user_email,percentage_completed,last_active_on\r\n");
+    for progress in at_risk {
+        let last_active = progress
+            .active_days()
+            .last()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        let _ = write!(
+            csv,
+            "{},{},{last_active}\r\n",
+            escape_cell(progress.user_email().address()),
+            progress.percentage_completed(),
+        );
+    }
+    csv
+}
+
+/// Quotes a cell containing a delimiter, quote or line break (RFC 4180).
+fn escape_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LessonProgress, StudyGroup};
+    use education_platform_common::{Date, Email};
+
+    fn create_test_progress(course_id: Id, email: &str, lessons_done: usize) -> CourseProgress {
+        let lessons = ["Introduction", "Ownership"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let done = (index < lessons_done).then(|| create_time(1));
+                LessonProgress::new(name.to_string(), 1800, done, done).unwrap()
+            })
+            .collect();
+        let active_days = (lessons_done > 0).then(|| create_time(1).date());
+        CourseProgress::builder()
+            .course_id(course_id)
+            .course_name("Rust Programming")
+            .user_email(email)
+            .lessons(lessons)
+            .active_days(active_days)
+            .build()
+            .unwrap()
+    }
+
+    fn create_time(day: u32) -> DateTime {
+        DateTime::new(2025, 3, day, 8, 0, 0).unwrap()
+    }
+
+    fn create_email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    mod cohort_completion {
+        use super::*;
+
+        #[test]
+        fn test_rows_summarize_each_group() {
+            let course_id = Id::new();
+            let mut cohort = Cohort::new(course_id, "Spring 2025").unwrap();
+            let group = StudyGroup::with_id(
+                Id::new(),
+                "Owls, Night",
+                "Finish by June",
+                5,
+                vec![
+                    create_email("ana@example.com"),
+                    create_email("luis@example.com"),
+                ],
+            )
+            .unwrap();
+            cohort.add_group(group).unwrap();
+            let enrollments = [
+                create_test_progress(course_id, "ana@example.com", 2),
+                create_test_progress(course_id, "luis@example.com", 0),
+            ];
+            let kind = ReportKind::CohortCompletion {
+                cohort_id: cohort.id(),
+            };
+
+            let report = kind
+                .generate(&ReportSources::new(&enrollments, &[cohort]), create_time(3))
+                .unwrap();
+
+            assert_eq!(
+                report.csv(),
+                "group,members,tracked,completed,average_completion\r\n\
+                 \"Owls, Night\",2,2,1,50%\r\n"
+            );
+        }
+
+        #[test]
+        fn test_enrollments_in_other_courses_are_ignored() {
+            let course_id = Id::new();
+            let group = StudyGroup::with_id(
+                Id::new(),
+                "Owls",
+                "Finish by June",
+                5,
+                vec![create_email("ana@example.com")],
+            )
+            .unwrap();
+            let cohort = Cohort::with_id(Id::new(), course_id, "Spring", vec![group]).unwrap();
+            let enrollments = [create_test_progress(Id::new(), "ana@example.com", 2)];
+            let kind = ReportKind::CohortCompletion {
+                cohort_id: cohort.id(),
+            };
+
+            let report = kind
+                .generate(&ReportSources::new(&enrollments, &[cohort]), create_time(3))
+                .unwrap();
+
+            assert!(report.csv().ends_with("Owls,1,0,0,0%\r\n"));
+        }
+
+        #[test]
+        fn test_unknown_cohort_is_reported() {
+            let cohort_id = Id::new();
+            let kind = ReportKind::CohortCompletion { cohort_id };
+
+            assert_eq!(
+                kind.generate(&ReportSources::new(&[], &[]), create_time(3)),
+                Err(ReportError::CohortNotFound(cohort_id.to_string()))
+            );
+        }
+    }
+
+    mod at_risk_learners {
+        use super::*;
+
+        #[test]
+        fn test_recently_active_and_finished_learners_are_left_out() {
+            let course_id = Id::new();
+            let enrollments = [
+                create_test_progress(course_id, "zoe@example.com", 1),
+                create_test_progress(course_id, "ana@example.com", 0),
+                create_test_progress(course_id, "luis@example.com", 2),
+            ];
+            let kind = ReportKind::AtRiskLearners {
+                course_id,
+                inactive_days: 7,
+            };
+
+            let early = kind
+                .generate(&ReportSources::new(&enrollments, &[]), create_time(3))
+                .unwrap();
+            let late = kind
+                .generate(&ReportSources::new(&enrollments, &[]), create_time(20))
+                .unwrap();
+
+            assert_eq!(early.csv().lines().count(), 2);
+            assert_eq!(
+                late.csv(),
+                format!(
+                    "user_email,percentage_completed,last_active_on\r\n\
+                     ana@example.com,0%,\r\n\
+                     zoe@example.com,50%,{}\r\n",
+                    Date::new(2025, 3, 1).unwrap()
+                )
+            );
+        }
+    }
+}
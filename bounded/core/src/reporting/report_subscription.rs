@@ -0,0 +1,255 @@
+use crate::{NotificationError, ReportKind};
+use education_platform_common::{CronSchedule, DateTime, Email, EmailError, Entity, Id};
+use thiserror::Error;
+
+/// Error types for report subscriptions and report generation.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReportError {
+    #[error("Subscriber email is not valid: {0}")]
+    SubscriberError(#[from] EmailError),
+
+    #[error("An at-risk report must look back at least one day")]
+    EmptyInactivityWindow,
+
+    #[error("Cohort with id {0} not found")]
+    CohortNotFound(String),
+
+    #[error("Report subscription with id {0} not found")]
+    SubscriptionNotFound(String),
+
+    #[error("Report could not be delivered: {0}")]
+    DeliveryError(#[from] NotificationError),
+}
+
+/// An admin's standing request to receive a report on a cron schedule.
+///
+/// The subscription stores the report parameters and tracks when it is
+/// next due, so a scheduler only has to ask which subscriptions are due.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{ReportKind, ReportSubscription};
+/// use education_platform_common::{CronSchedule, DateTime, Id};
+///
+/// let created_at = DateTime::new(2025, 3, 5, 12, 0, 0).unwrap();
+/// let mut subscription = ReportSubscription::new(
+///     "admin@example.com".to_string(),
+///     ReportKind::CohortCompletion { cohort_id: Id::new() },
+///     CronSchedule::new("0 8 * * 1").unwrap(),
+///     created_at,
+/// )
+/// .unwrap();
+///
+/// let monday = DateTime::new(2025, 3, 10, 8, 0, 0).unwrap();
+/// assert_eq!(subscription.next_run_at(), Some(monday));
+/// assert!(!subscription.is_due(created_at));
+///
+/// subscription.record_run(monday);
+/// assert_eq!(subscription.next_run_at(), Some(DateTime::new(2025, 3, 17, 8, 0, 0).unwrap()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReportSubscription {
+    id: Id,
+    subscriber: Email,
+    kind: ReportKind,
+    schedule: CronSchedule,
+    next_run_at: Option<DateTime>,
+    last_run_at: Option<DateTime>,
+}
+
+impl ReportSubscription {
+    /// Creates a subscription first due at the next scheduled time after `now`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReportError::SubscriberError` if the email is not valid, or
+    /// the error from [`ReportKind::validate`].
+    pub fn new(
+        subscriber: String,
+        kind: ReportKind,
+        schedule: CronSchedule,
+        now: DateTime,
+    ) -> Result<Self, ReportError> {
+        let next_run_at = schedule.next_after(now);
+        Self::with_id(Id::default(), subscriber, kind, schedule, next_run_at, None)
+    }
+
+    /// Creates a `ReportSubscription` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ReportSubscription::new`].
+    pub fn with_id(
+        id: Id,
+        subscriber: String,
+        kind: ReportKind,
+        schedule: CronSchedule,
+        next_run_at: Option<DateTime>,
+        last_run_at: Option<DateTime>,
+    ) -> Result<Self, ReportError> {
+        kind.validate()?;
+
+        Ok(Self {
+            id,
+            subscriber: Email::new(subscriber)?,
+            kind,
+            schedule,
+            next_run_at,
+            last_run_at,
+        })
+    }
+
+    /// Replaces the report parameters, keeping the schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`ReportKind::validate`].
+    pub fn update_kind(&mut self, kind: ReportKind) -> Result<(), ReportError> {
+        kind.validate()?;
+        self.kind = kind;
+        Ok(())
+    }
+
+    /// Moves the subscription to a new schedule, due next after `now`.
+    pub fn reschedule(&mut self, schedule: CronSchedule, now: DateTime) {
+        self.next_run_at = schedule.next_after(now);
+        self.schedule = schedule;
+    }
+
+    /// Returns whether a run is due at `now`.
+    ///
+    /// Runs missed while the scheduler was down stay due, and catch up as a
+    /// single run.
+    #[must_use]
+    pub fn is_due(&self, now: DateTime) -> bool {
+        self.next_run_at
+            .is_some_and(|next_run_at| next_run_at <= now)
+    }
+
+    /// Records a run and moves the next run past it.
+    pub fn record_run(&mut self, at: DateTime) {
+        self.last_run_at = Some(at);
+        self.next_run_at = self.schedule.next_after(at);
+    }
+
+    /// Returns the admin receiving the report.
+    #[inline]
+    #[must_use]
+    pub const fn subscriber(&self) -> &Email {
+        &self.subscriber
+    }
+
+    /// Returns the report and its parameters.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> &ReportKind {
+        &self.kind
+    }
+
+    /// Returns the delivery schedule.
+    #[inline]
+    #[must_use]
+    pub const fn schedule(&self) -> &CronSchedule {
+        &self.schedule
+    }
+
+    /// Returns when the report is next due, or `None` if the schedule never fires again.
+    #[inline]
+    #[must_use]
+    pub const fn next_run_at(&self) -> Option<DateTime> {
+        self.next_run_at
+    }
+
+    /// Returns when the report last ran.
+    #[inline]
+    #[must_use]
+    pub const fn last_run_at(&self) -> Option<DateTime> {
+        self.last_run_at
+    }
+}
+
+impl Entity for ReportSubscription {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for ReportSubscription {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for ReportSubscription {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_time(day: u32, hour: u32) -> DateTime {
+        DateTime::new(2025, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn create_test_subscription() -> ReportSubscription {
+        ReportSubscription::new(
+            "admin@example.com".to_string(),
+            ReportKind::CohortCompletion { cohort_id: Id::new() },
+            CronSchedule::new("0 8 * * *").unwrap(),
+            create_time(3, 12),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_invalid_parameters_are_rejected() {
+        let result = ReportSubscription::new(
+            "admin@example.com".to_string(),
+            ReportKind::AtRiskLearners {
+                course_id: Id::new(),
+                inactive_days: 0,
+            },
+            CronSchedule::new("0 8 * * *").unwrap(),
+            create_time(3, 12),
+        );
+
+        assert_eq!(result.unwrap_err(), ReportError::EmptyInactivityWindow);
+    }
+
+    #[test]
+    fn test_missed_runs_catch_up_once() {
+        let mut subscription = create_test_subscription();
+
+        assert!(subscription.is_due(create_time(6, 9)));
+        subscription.record_run(create_time(6, 9));
+
+        assert_eq!(subscription.last_run_at(), Some(create_time(6, 9)));
+        assert_eq!(subscription.next_run_at(), Some(create_time(7, 8)));
+        assert!(!subscription.is_due(create_time(6, 23)));
+    }
+
+    #[test]
+    fn test_reschedule_recomputes_next_run() {
+        let mut subscription = create_test_subscription();
+
+        subscription.reschedule(CronSchedule::new("0 18 * * *").unwrap(), create_time(4, 9));
+
+        assert_eq!(subscription.next_run_at(), Some(create_time(4, 18)));
+        assert_eq!(subscription.schedule().expression(), "0 18 * * *");
+    }
+
+    #[test]
+    fn test_update_kind_keeps_previous_parameters_on_error() {
+        let mut subscription = create_test_subscription();
+        let before = *subscription.kind();
+
+        let result = subscription.update_kind(ReportKind::AtRiskLearners {
+            course_id: Id::new(),
+            inactive_days: 0,
+        });
+
+        assert!(result.is_err());
+        assert_eq!(*subscription.kind(), before);
+    }
+}
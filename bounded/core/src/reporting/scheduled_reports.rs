@@ -0,0 +1,202 @@
+use crate::{
+    Notification, NotificationChannel, NotificationTriggers, ReportError, ReportSources,
+    ReportSubscription, ReportSubscriptionStore,
+};
+use education_platform_common::{DateTime, Entity, Id};
+
+/// The outcome of one scheduler pass.
+#[derive(Debug, Clone, Default)]
+pub struct ReportRun {
+    notifications: Vec<Notification>,
+    failures: Vec<(Id, ReportError)>,
+}
+
+impl ReportRun {
+    /// Returns the emails to send, one per delivered report.
+    #[inline]
+    #[must_use]
+    pub fn notifications(&self) -> &[Notification] {
+        &self.notifications
+    }
+
+    /// Returns the subscriptions that could not be served, with the reason.
+    #[inline]
+    #[must_use]
+    pub fn failures(&self) -> &[(Id, ReportError)] {
+        &self.failures
+    }
+}
+
+/// Generates due reports and wraps them in email notifications.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     Cohort, InMemoryReportSubscriptionStore, ReportKind, ReportSources, ReportSubscription,
+///     ReportSubscriptionStore, ScheduledReports,
+/// };
+/// use education_platform_common::{CronSchedule, DateTime, Entity, Id};
+///
+/// let cohort = Cohort::new(Id::new(), "Spring 2025").unwrap();
+/// let mut store = InMemoryReportSubscriptionStore::new();
+/// store.save(ReportSubscription::new(
+///     "admin@example.com".to_string(),
+///     ReportKind::CohortCompletion { cohort_id: cohort.id() },
+///     CronSchedule::new("0 8 * * 1").unwrap(),
+///     DateTime::new(2025, 3, 5, 12, 0, 0).unwrap(),
+/// ).unwrap());
+///
+/// let cohorts = [cohort];
+/// let sources = ReportSources::new(&[], &cohorts);
+/// let monday = DateTime::new(2025, 3, 10, 8, 0, 0).unwrap();
+/// let run = ScheduledReports::new().run_due(&mut store, &sources, monday);
+///
+/// let email = &run.notifications()[0];
+/// assert_eq!(email.subject(), "Your Cohort completion report");
+/// assert_eq!(email.attachments()[0].filename(), "cohort-completion-2025-03-10.csv");
+/// assert!(store.due(monday).is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScheduledReports {
+    triggers: NotificationTriggers,
+}
+
+impl ScheduledReports {
+    /// Creates a runner with the built-in email templates.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_triggers(NotificationTriggers::new(NotificationChannel::Email))
+    }
+
+    /// Creates a runner rendering emails with customized triggers.
+    ///
+    /// Reports only go out as attachments, so the triggers must send by email;
+    /// on any other channel every run fails with
+    /// `NotificationError::AttachmentsNotSupported`.
+    #[must_use]
+    pub const fn with_triggers(triggers: NotificationTriggers) -> Self {
+        Self { triggers }
+    }
+
+    /// Runs every subscription due at `now` and moves each served one to its
+    /// next scheduled time.
+    ///
+    /// A subscription that fails stays due, so it is retried on the next pass
+    /// without holding up the others.
+    pub fn run_due(
+        &self,
+        store: &mut impl ReportSubscriptionStore,
+        sources: &ReportSources<'_>,
+        now: DateTime,
+    ) -> ReportRun {
+        let mut run = ReportRun::default();
+        for mut subscription in store.due(now) {
+            match self.deliver(&subscription, sources, now) {
+                Ok(notification) => {
+                    subscription.record_run(now);
+                    store.save(subscription);
+                    run.notifications.push(notification);
+                }
+                Err(error) => run.failures.push((subscription.id(), error)),
+            }
+        }
+        run
+    }
+
+    fn deliver(
+        &self,
+        subscription: &ReportSubscription,
+        sources: &ReportSources<'_>,
+        now: DateTime,
+    ) -> Result<Notification, ReportError> {
+        let report = subscription.kind().generate(sources, now)?;
+        let mut notification =
+            self.triggers
+                .report_ready(subscription.subscriber(), report.title(), now)?;
+        notification.attach(report.to_attachment()?)?;
+        Ok(notification)
+    }
+}
+
+impl Default for ScheduledReports {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cohort, InMemoryReportSubscriptionStore, NotificationError, ReportKind};
+    use education_platform_common::CronSchedule;
+
+    fn create_time(day: u32) -> DateTime {
+        DateTime::new(2025, 3, day, 8, 0, 0).unwrap()
+    }
+
+    fn create_test_subscription(cohort_id: Id) -> ReportSubscription {
+        ReportSubscription::new(
+            "admin@example.com".to_string(),
+            ReportKind::CohortCompletion { cohort_id },
+            CronSchedule::new("0 8 * * *").unwrap(),
+            create_time(2),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_failed_subscription_stays_due() {
+        let cohort = Cohort::new(Id::new(), "Spring").unwrap();
+        let missing = create_test_subscription(Id::new());
+        let missing_id = missing.id();
+        let mut store = InMemoryReportSubscriptionStore::new();
+        store.save(missing);
+        store.save(create_test_subscription(cohort.id()));
+        let cohorts = [cohort];
+
+        let run = ScheduledReports::new().run_due(
+            &mut store,
+            &ReportSources::new(&[], &cohorts),
+            create_time(3),
+        );
+
+        assert_eq!(run.notifications().len(), 1);
+        assert_eq!(run.failures()[0].0, missing_id);
+        assert_eq!(store.due(create_time(3)).len(), 1);
+    }
+
+    #[test]
+    fn test_in_app_triggers_cannot_deliver_reports() {
+        let cohort = Cohort::new(Id::new(), "Spring").unwrap();
+        let mut store = InMemoryReportSubscriptionStore::new();
+        store.save(create_test_subscription(cohort.id()));
+        let cohorts = [cohort];
+        let reports =
+            ScheduledReports::with_triggers(NotificationTriggers::new(NotificationChannel::InApp));
+
+        let run = reports.run_due(&mut store, &ReportSources::new(&[], &cohorts), create_time(3));
+
+        assert_eq!(
+            run.failures()[0].1,
+            ReportError::DeliveryError(NotificationError::AttachmentsNotSupported(
+                NotificationChannel::InApp
+            ))
+        );
+    }
+
+    #[test]
+    fn test_nothing_runs_before_schedule() {
+        let mut store = InMemoryReportSubscriptionStore::new();
+        store.save(create_test_subscription(Id::new()));
+
+        let run = ScheduledReports::new().run_due(
+            &mut store,
+            &ReportSources::new(&[], &[]),
+            DateTime::new(2025, 3, 3, 7, 59, 0).unwrap(),
+        );
+
+        assert!(run.notifications().is_empty());
+        assert!(run.failures().is_empty());
+    }
+}
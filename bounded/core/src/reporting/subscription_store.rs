@@ -0,0 +1,183 @@
+use crate::{ReportError, ReportSubscription};
+use education_platform_common::{DateTime, Email, Entity, Id};
+
+/// Port persisting report subscriptions and their parameters.
+pub trait ReportSubscriptionStore {
+    /// Saves a new subscription or replaces the saved version with the same ID.
+    fn save(&mut self, subscription: ReportSubscription);
+
+    /// Returns a saved subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReportError::SubscriptionNotFound` if no subscription has the ID.
+    fn find(&self, subscription_id: Id) -> Result<ReportSubscription, ReportError>;
+
+    /// Returns the subscriptions of one admin.
+    fn for_subscriber(&self, subscriber: &Email) -> Vec<ReportSubscription>;
+
+    /// Returns the subscriptions due at `now`, earliest first.
+    fn due(&self, now: DateTime) -> Vec<ReportSubscription>;
+
+    /// Deletes a saved subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReportError::SubscriptionNotFound` if no subscription has the ID.
+    fn delete(&mut self, subscription_id: Id) -> Result<(), ReportError>;
+}
+
+/// In-process [`ReportSubscriptionStore`].
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     InMemoryReportSubscriptionStore, ReportKind, ReportSubscription, ReportSubscriptionStore,
+/// };
+/// use education_platform_common::{CronSchedule, DateTime, Id};
+///
+/// let created_at = DateTime::new(2025, 3, 5, 12, 0, 0).unwrap();
+/// let subscription = ReportSubscription::new(
+///     "admin@example.com".to_string(),
+///     ReportKind::CohortCompletion { cohort_id: Id::new() },
+///     CronSchedule::new("0 8 * * *").unwrap(),
+///     created_at,
+/// )
+/// .unwrap();
+/// let mut store = InMemoryReportSubscriptionStore::new();
+///
+/// store.save(subscription);
+///
+/// assert!(store.due(created_at).is_empty());
+/// assert_eq!(store.due(DateTime::new(2025, 3, 6, 8, 0, 0).unwrap()).len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryReportSubscriptionStore {
+    subscriptions: Vec<ReportSubscription>,
+}
+
+impl InMemoryReportSubscriptionStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ReportSubscriptionStore for InMemoryReportSubscriptionStore {
+    fn save(&mut self, subscription: ReportSubscription) {
+        match self
+            .subscriptions
+            .iter_mut()
+            .find(|saved| saved.id() == subscription.id())
+        {
+            Some(saved) => *saved = subscription,
+            None => self.subscriptions.push(subscription),
+        }
+    }
+
+    fn find(&self, subscription_id: Id) -> Result<ReportSubscription, ReportError> {
+        self.subscriptions
+            .iter()
+            .find(|saved| saved.id() == subscription_id)
+            .cloned()
+            .ok_or_else(|| ReportError::SubscriptionNotFound(subscription_id.to_string()))
+    }
+
+    fn for_subscriber(&self, subscriber: &Email) -> Vec<ReportSubscription> {
+        self.subscriptions
+            .iter()
+            .filter(|saved| saved.subscriber() == subscriber)
+            .cloned()
+            .collect()
+    }
+
+    fn due(&self, now: DateTime) -> Vec<ReportSubscription> {
+        let mut due: Vec<ReportSubscription> = self
+            .subscriptions
+            .iter()
+            .filter(|saved| saved.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(ReportSubscription::next_run_at);
+        due
+    }
+
+    fn delete(&mut self, subscription_id: Id) -> Result<(), ReportError> {
+        let position = self
+            .subscriptions
+            .iter()
+            .position(|saved| saved.id() == subscription_id)
+            .ok_or_else(|| ReportError::SubscriptionNotFound(subscription_id.to_string()))?;
+        self.subscriptions.remove(position);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReportKind;
+    use education_platform_common::CronSchedule;
+
+    fn create_test_subscription(subscriber: &str, expression: &str) -> ReportSubscription {
+        ReportSubscription::new(
+            subscriber.to_string(),
+            ReportKind::CohortCompletion { cohort_id: Id::new() },
+            CronSchedule::new(expression).unwrap(),
+            DateTime::new(2025, 3, 3, 0, 0, 0).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_save_replaces_by_id() {
+        let mut store = InMemoryReportSubscriptionStore::new();
+        let mut subscription = create_test_subscription("admin@example.com", "0 8 * * *");
+        store.save(subscription.clone());
+
+        subscription.reschedule(
+            CronSchedule::new("0 9 * * *").unwrap(),
+            DateTime::new(2025, 3, 3, 0, 0, 0).unwrap(),
+        );
+        store.save(subscription.clone());
+
+        let saved = store.find(subscription.id()).unwrap();
+        assert_eq!(saved.schedule().expression(), "0 9 * * *");
+        assert_eq!(store.due(DateTime::new(2025, 3, 4, 0, 0, 0).unwrap()).len(), 1);
+    }
+
+    #[test]
+    fn test_due_is_ordered_by_next_run() {
+        let mut store = InMemoryReportSubscriptionStore::new();
+        store.save(create_test_subscription("late@example.com", "0 9 * * *"));
+        store.save(create_test_subscription("early@example.com", "0 6 * * *"));
+
+        let due = store.due(DateTime::new(2025, 3, 3, 12, 0, 0).unwrap());
+
+        assert_eq!(due[0].subscriber().address(), "early@example.com");
+        assert_eq!(due[1].subscriber().address(), "late@example.com");
+    }
+
+    #[test]
+    fn test_for_subscriber_filters_by_email() {
+        let mut store = InMemoryReportSubscriptionStore::new();
+        store.save(create_test_subscription("ana@example.com", "0 9 * * *"));
+        store.save(create_test_subscription("luis@example.com", "0 9 * * *"));
+        let ana = Email::new("ana@example.com".to_string()).unwrap();
+
+        assert_eq!(store.for_subscriber(&ana).len(), 1);
+    }
+
+    #[test]
+    fn test_delete_unknown_subscription_fails() {
+        let mut store = InMemoryReportSubscriptionStore::new();
+        let subscription_id = Id::new();
+
+        assert_eq!(
+            store.delete(subscription_id),
+            Err(ReportError::SubscriptionNotFound(subscription_id.to_string()))
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod content_chunk;
+mod course_qa;
+mod vector_store;
+
+pub use content_chunk::*;
+pub use course_qa::*;
+pub use vector_store::*;
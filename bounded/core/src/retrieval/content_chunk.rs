@@ -0,0 +1,357 @@
+use education_platform_common::Id;
+
+/// One timed line of a lesson transcript, as produced by a captioning service.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::TranscriptCue;
+///
+/// let cue = TranscriptCue::new(95, "Ownership moves the value.");
+/// assert_eq!(cue.start_seconds(), 95);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptCue {
+    start_seconds: u64,
+    text: String,
+}
+
+impl TranscriptCue {
+    /// Creates a cue starting at the given offset into the lesson video.
+    #[must_use]
+    pub fn new(start_seconds: u64, text: impl Into<String>) -> Self {
+        Self {
+            start_seconds,
+            text: text.into(),
+        }
+    }
+
+    /// Returns the offset into the lesson video, in seconds.
+    #[inline]
+    #[must_use]
+    pub const fn start_seconds(&self) -> u64 {
+        self.start_seconds
+    }
+
+    /// Returns the spoken text.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A passage of lesson content small enough to embed and return as a snippet.
+///
+/// Chunks cut from a transcript remember where they start in the video, so a
+/// learner can jump straight to the answer; chunks cut from a description
+/// have no timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{ContentChunker, TranscriptCue};
+/// use education_platform_common::Id;
+///
+/// let cues = [
+///     TranscriptCue::new(0, "Welcome."),
+///     TranscriptCue::new(4, "Today we cover borrowing."),
+/// ];
+///
+/// let chunks = ContentChunker::new(200).transcript(Id::new(), Id::new(), &cues);
+///
+/// assert_eq!(chunks.len(), 1);
+/// assert_eq!(chunks[0].text(), "Welcome. Today we cover borrowing.");
+/// assert_eq!(chunks[0].start_seconds(), Some(0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentChunk {
+    course_id: Id,
+    lesson_id: Id,
+    text: String,
+    start_seconds: Option<u64>,
+}
+
+impl ContentChunk {
+    /// Creates a chunk of the given lesson.
+    #[must_use]
+    pub fn new(
+        course_id: Id,
+        lesson_id: Id,
+        text: impl Into<String>,
+        start_seconds: Option<u64>,
+    ) -> Self {
+        Self {
+            course_id,
+            lesson_id,
+            text: text.into(),
+            start_seconds,
+        }
+    }
+
+    /// Returns the ID of the course the chunk belongs to.
+    #[inline]
+    #[must_use]
+    pub const fn course_id(&self) -> Id {
+        self.course_id
+    }
+
+    /// Returns the ID of the lesson the chunk was cut from.
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> Id {
+        self.lesson_id
+    }
+
+    /// Returns the chunk text.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns where the chunk starts in the lesson video, for transcript chunks.
+    #[inline]
+    #[must_use]
+    pub const fn start_seconds(&self) -> Option<u64> {
+        self.start_seconds
+    }
+}
+
+/// Splits transcripts and descriptions into chunks of bounded length.
+///
+/// Chunks are built from whole cues or whole sentences, so a chunk only
+/// exceeds the limit when a single cue or sentence does.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::ContentChunker;
+/// use education_platform_common::Id;
+///
+/// let chunks = ContentChunker::new(20).description(
+///     Id::new(),
+///     Id::new(),
+///     "Traits define behavior. Generics reuse it. Lifetimes scope it.",
+/// );
+///
+/// let texts: Vec<&str> = chunks.iter().map(|chunk| chunk.text()).collect();
+/// assert_eq!(texts, vec!["Traits define behavior.", "Generics reuse it.", "Lifetimes scope it."]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentChunker {
+    max_chars: usize,
+}
+
+impl ContentChunker {
+    pub const DEFAULT_MAX_CHARS: usize = 500;
+
+    /// Creates a chunker producing chunks of about `max_chars` characters.
+    ///
+    /// A limit of zero is treated as one, which puts every cue or sentence
+    /// in its own chunk.
+    #[must_use]
+    pub fn new(max_chars: usize) -> Self {
+        Self {
+            max_chars: max_chars.max(1),
+        }
+    }
+
+    /// Groups consecutive transcript cues into chunks, each starting at its first cue.
+    ///
+    /// Blank cues are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ContentChunker, TranscriptCue};
+    /// use education_platform_common::Id;
+    ///
+    /// let cues = [
+    ///     TranscriptCue::new(0, "Move semantics."),
+    ///     TranscriptCue::new(30, "Borrowing rules."),
+    /// ];
+    ///
+    /// let chunks = ContentChunker::new(16).transcript(Id::new(), Id::new(), &cues);
+    ///
+    /// assert_eq!(chunks[1].start_seconds(), Some(30));
+    /// ```
+    #[must_use]
+    pub fn transcript(
+        &self,
+        course_id: Id,
+        lesson_id: Id,
+        cues: &[TranscriptCue],
+    ) -> Vec<ContentChunk> {
+        let pieces = cues
+            .iter()
+            .map(|cue| (Some(cue.start_seconds()), cue.text().trim()));
+        self.group(course_id, lesson_id, pieces)
+    }
+
+    /// Splits a free-text description into chunks of whole sentences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::ContentChunker;
+    /// use education_platform_common::Id;
+    ///
+    /// let chunks = ContentChunker::new(500).description(Id::new(), Id::new(), "   ");
+    /// assert!(chunks.is_empty());
+    /// ```
+    #[must_use]
+    pub fn description(&self, course_id: Id, lesson_id: Id, text: &str) -> Vec<ContentChunk> {
+        let pieces = sentences(text).into_iter().map(|sentence| (None, sentence));
+        self.group(course_id, lesson_id, pieces)
+    }
+
+    fn group<'a>(
+        &self,
+        course_id: Id,
+        lesson_id: Id,
+        pieces: impl Iterator<Item = (Option<u64>, &'a str)>,
+    ) -> Vec<ContentChunk> {
+        let mut chunks = Vec::new();
+        let mut current: Option<(Option<u64>, String)> = None;
+
+        for (start, piece) in pieces.filter(|(_, piece)| !piece.is_empty()) {
+            match current.as_mut() {
+                Some((_, text))
+                    if text.chars().count() + 1 + piece.chars().count() <= self.max_chars =>
+                {
+                    text.push(' ');
+                    text.push_str(piece);
+                }
+                _ => {
+                    if let Some((chunk_start, text)) = current.replace((start, piece.to_string())) {
+                        chunks.push(ContentChunk::new(course_id, lesson_id, text, chunk_start));
+                    }
+                }
+            }
+        }
+
+        if let Some((chunk_start, text)) = current {
+            chunks.push(ContentChunk::new(course_id, lesson_id, text, chunk_start));
+        }
+        chunks
+    }
+}
+
+impl Default for ContentChunker {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_CHARS)
+    }
+}
+
+/// Splits text after `.`, `!` or `?` followed by whitespace, trimming each sentence.
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let at_boundary = matches!(c, '.' | '!' | '?')
+            && chars.peek().is_some_and(|(_, next)| next.is_whitespace());
+        if at_boundary {
+            let end = index + c.len_utf8();
+            sentences.push(text[start..end].trim());
+            start = end;
+        }
+    }
+    sentences.push(text[start..].trim());
+
+    sentences.retain(|sentence| !sentence.is_empty());
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(chunks: &[ContentChunk]) -> Vec<&str> {
+        chunks.iter().map(ContentChunk::text).collect()
+    }
+
+    mod transcript {
+        use super::*;
+
+        #[test]
+        fn test_groups_cues_up_to_limit() {
+            let cues = [
+                TranscriptCue::new(0, "aaaa"),
+                TranscriptCue::new(5, "bbbb"),
+                TranscriptCue::new(10, "cccc"),
+            ];
+
+            let chunks = ContentChunker::new(9).transcript(Id::new(), Id::new(), &cues);
+
+            assert_eq!(texts(&chunks), vec!["aaaa bbbb", "cccc"]);
+            assert_eq!(chunks[0].start_seconds(), Some(0));
+            assert_eq!(chunks[1].start_seconds(), Some(10));
+        }
+
+        #[test]
+        fn test_oversized_cue_gets_its_own_chunk() {
+            let cues = [
+                TranscriptCue::new(0, "short"),
+                TranscriptCue::new(5, "a very long cue indeed"),
+                TranscriptCue::new(10, "tail"),
+            ];
+
+            let chunks = ContentChunker::new(10).transcript(Id::new(), Id::new(), &cues);
+
+            assert_eq!(texts(&chunks), vec!["short", "a very long cue indeed", "tail"]);
+        }
+
+        #[test]
+        fn test_blank_cues_are_skipped() {
+            let cues = [TranscriptCue::new(0, "  "), TranscriptCue::new(5, "hello")];
+
+            let chunks = ContentChunker::default().transcript(Id::new(), Id::new(), &cues);
+
+            assert_eq!(texts(&chunks), vec!["hello"]);
+            assert_eq!(chunks[0].start_seconds(), Some(5));
+        }
+
+        #[test]
+        fn test_chunks_keep_their_lesson() {
+            let course_id = Id::new();
+            let lesson_id = Id::new();
+
+            let chunks = ContentChunker::default().transcript(
+                course_id,
+                lesson_id,
+                &[TranscriptCue::new(0, "hello")],
+            );
+
+            assert_eq!(chunks[0].course_id(), course_id);
+            assert_eq!(chunks[0].lesson_id(), lesson_id);
+        }
+    }
+
+    mod description {
+        use super::*;
+
+        #[test]
+        fn test_description_chunks_have_no_timestamp() {
+            let chunks =
+                ContentChunker::default().description(Id::new(), Id::new(), "One. Two? Three!");
+
+            assert_eq!(texts(&chunks), vec!["One. Two? Three!"]);
+            assert_eq!(chunks[0].start_seconds(), None);
+        }
+    }
+
+    mod sentences {
+        use super::*;
+
+        #[test]
+        fn test_keeps_decimals_together() {
+            assert_eq!(
+                sentences("Rust 1.80 is out. Update now"),
+                vec!["Rust 1.80 is out.", "Update now"]
+            );
+        }
+    }
+}
@@ -0,0 +1,424 @@
+use crate::{ContentChunk, ContentChunker, Embedder, RetrievalError, TranscriptCue, VectorStore};
+use education_platform_common::Id;
+
+/// A passage of lesson content returned as evidence for a question.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LessonSnippet {
+    lesson_id: Id,
+    text: String,
+    start_seconds: Option<u64>,
+    score: f32,
+}
+
+impl LessonSnippet {
+    /// Returns the ID of the lesson the snippet comes from.
+    #[inline]
+    #[must_use]
+    pub const fn lesson_id(&self) -> Id {
+        self.lesson_id
+    }
+
+    /// Returns the snippet text.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns where the snippet starts in the lesson video, if it comes from a transcript.
+    #[inline]
+    #[must_use]
+    pub const fn start_seconds(&self) -> Option<u64> {
+        self.start_seconds
+    }
+
+    /// Returns the similarity between the snippet and the question, higher is closer.
+    #[inline]
+    #[must_use]
+    pub const fn score(&self) -> f32 {
+        self.score
+    }
+}
+
+/// Port turning a question and its retrieved snippets into a written answer.
+///
+/// Implementations typically prompt a language model with the snippets as
+/// context. Retrieval does not depend on it, so a course can offer snippet
+/// search before any generator is wired in.
+pub trait AnswerGenerator {
+    /// Writes an answer grounded in the snippets.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RetrievalError::GenerationFailed` if the answer cannot be produced.
+    fn generate(
+        &self,
+        question: &str,
+        snippets: &[LessonSnippet],
+    ) -> Result<String, RetrievalError>;
+}
+
+/// Answers learner questions from the indexed content of their course.
+///
+/// Lesson transcripts and descriptions are chunked, embedded with `E` and
+/// stored in `S`. [`ask`](Self::ask) returns the closest snippets with their
+/// video timestamps, and [`answer`](Self::answer) hands them to an
+/// [`AnswerGenerator`].
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     CourseQuestionAnswering, Embedder, InMemoryVectorStore, RetrievalError, TranscriptCue,
+/// };
+/// use education_platform_common::Id;
+///
+/// /// Scores texts on two topics by keyword; a real embedder calls a model.
+/// struct TopicEmbedder;
+///
+/// impl Embedder for TopicEmbedder {
+///     fn embed(&self, text: &str) -> Result<Vec<f32>, RetrievalError> {
+///         let text = text.to_lowercase();
+///         Ok(vec![
+///             f32::from(u8::from(text.contains("borrow"))),
+///             f32::from(u8::from(text.contains("trait"))),
+///         ])
+///     }
+/// }
+///
+/// let course_id = Id::new();
+/// let lesson_id = Id::new();
+/// let mut qa = CourseQuestionAnswering::new(TopicEmbedder, InMemoryVectorStore::new());
+///
+/// qa.index_transcript(course_id, lesson_id, &[
+///     TranscriptCue::new(0, "Traits describe shared behavior."),
+///     TranscriptCue::new(600, "Borrowing lets you use a value without owning it."),
+/// ]).unwrap();
+///
+/// let snippets = qa.ask(course_id, "How does borrowing work?").unwrap();
+///
+/// assert_eq!(snippets[0].start_seconds(), Some(600));
+/// ```
+#[derive(Debug)]
+pub struct CourseQuestionAnswering<E, S> {
+    embedder: E,
+    store: S,
+    chunker: ContentChunker,
+    limit: usize,
+}
+
+impl<E: Embedder, S: VectorStore> CourseQuestionAnswering<E, S> {
+    pub const DEFAULT_LIMIT: usize = 3;
+
+    /// Creates a question-answering service over an embedder and a store.
+    ///
+    /// Transcript cues are indexed one per chunk so snippets point at a
+    /// precise moment of the video; use [`with_chunker`](Self::with_chunker)
+    /// to group them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     CourseQuestionAnswering, Embedder, InMemoryVectorStore, RetrievalError,
+    /// };
+    ///
+    /// struct LengthEmbedder;
+    ///
+    /// impl Embedder for LengthEmbedder {
+    ///     fn embed(&self, text: &str) -> Result<Vec<f32>, RetrievalError> {
+    ///         Ok(vec![text.len() as f32])
+    ///     }
+    /// }
+    ///
+    /// let qa = CourseQuestionAnswering::new(LengthEmbedder, InMemoryVectorStore::new());
+    /// assert!(qa.store().is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(embedder: E, store: S) -> Self {
+        Self {
+            embedder,
+            store,
+            chunker: ContentChunker::new(1),
+            limit: Self::DEFAULT_LIMIT,
+        }
+    }
+
+    /// Replaces the chunker used when indexing.
+    #[must_use]
+    pub const fn with_chunker(mut self, chunker: ContentChunker) -> Self {
+        self.chunker = chunker;
+        self
+    }
+
+    /// Sets how many snippets [`ask`](Self::ask) returns at most.
+    #[must_use]
+    pub const fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Returns the underlying vector store.
+    #[inline]
+    #[must_use]
+    pub const fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Indexes a lesson transcript, replacing anything indexed for the lesson before.
+    ///
+    /// Returns the number of chunks indexed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a chunk cannot be embedded or stored. Chunks
+    /// indexed before the failure stay in the store.
+    pub fn index_transcript(
+        &mut self,
+        course_id: Id,
+        lesson_id: Id,
+        cues: &[TranscriptCue],
+    ) -> Result<usize, RetrievalError> {
+        self.store.remove_lesson(lesson_id);
+        let chunks = self.chunker.transcript(course_id, lesson_id, cues);
+        self.insert_all(chunks)
+    }
+
+    /// Adds a lesson description to the index, alongside its transcript.
+    ///
+    /// Returns the number of chunks indexed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a chunk cannot be embedded or stored.
+    pub fn index_description(
+        &mut self,
+        course_id: Id,
+        lesson_id: Id,
+        text: &str,
+    ) -> Result<usize, RetrievalError> {
+        let chunks = self.chunker.description(course_id, lesson_id, text);
+        self.insert_all(chunks)
+    }
+
+    /// Returns the course snippets most relevant to a question, closest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RetrievalError::EmptyQuestion` if the question is blank, or
+    /// an error if the question cannot be embedded or searched.
+    pub fn ask(&self, course_id: Id, question: &str) -> Result<Vec<LessonSnippet>, RetrievalError> {
+        let question = question.trim();
+        if question.is_empty() {
+            return Err(RetrievalError::EmptyQuestion);
+        }
+
+        let query = self.embedder.embed(question)?;
+        let results = self.store.search(course_id, &query, self.limit)?;
+
+        Ok(results
+            .into_iter()
+            .map(|result| LessonSnippet {
+                lesson_id: result.chunk().lesson_id(),
+                text: result.chunk().text().to_string(),
+                start_seconds: result.chunk().start_seconds(),
+                score: result.score(),
+            })
+            .collect())
+    }
+
+    /// Retrieves snippets for a question and lets the generator write an answer from them.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`ask`](Self::ask) or from the generator.
+    pub fn answer<G: AnswerGenerator>(
+        &self,
+        course_id: Id,
+        question: &str,
+        generator: &G,
+    ) -> Result<(String, Vec<LessonSnippet>), RetrievalError> {
+        let snippets = self.ask(course_id, question)?;
+        let answer = generator.generate(question.trim(), &snippets)?;
+        Ok((answer, snippets))
+    }
+
+    fn insert_all(&mut self, chunks: Vec<ContentChunk>) -> Result<usize, RetrievalError> {
+        let count = chunks.len();
+        for chunk in chunks {
+            let embedding = self.embedder.embed(chunk.text())?;
+            self.store.insert(chunk, embedding)?;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryVectorStore;
+
+    const VOCABULARY: [&str; 4] = ["borrow", "trait", "macro", "async"];
+
+    struct KeywordEmbedder;
+
+    impl Embedder for KeywordEmbedder {
+        fn embed(&self, text: &str) -> Result<Vec<f32>, RetrievalError> {
+            let text = text.to_lowercase();
+            Ok(VOCABULARY
+                .iter()
+                .map(|word| f32::from(u8::from(text.contains(word))))
+                .collect())
+        }
+    }
+
+    struct FailingEmbedder;
+
+    impl Embedder for FailingEmbedder {
+        fn embed(&self, _text: &str) -> Result<Vec<f32>, RetrievalError> {
+            Err(RetrievalError::EmbeddingFailed("model offline".to_string()))
+        }
+    }
+
+    struct QuotingGenerator;
+
+    impl AnswerGenerator for QuotingGenerator {
+        fn generate(
+            &self,
+            _question: &str,
+            snippets: &[LessonSnippet],
+        ) -> Result<String, RetrievalError> {
+            Ok(snippets
+                .first()
+                .map(|snippet| snippet.text().to_string())
+                .unwrap_or_default())
+        }
+    }
+
+    fn create_test_qa() -> CourseQuestionAnswering<KeywordEmbedder, InMemoryVectorStore> {
+        CourseQuestionAnswering::new(KeywordEmbedder, InMemoryVectorStore::new())
+    }
+
+    fn create_test_cues() -> Vec<TranscriptCue> {
+        vec![
+            TranscriptCue::new(0, "Traits describe behavior."),
+            TranscriptCue::new(120, "Macros write code for you."),
+            TranscriptCue::new(300, "Borrowing avoids copies."),
+        ]
+    }
+
+    mod index {
+        use super::*;
+
+        #[test]
+        fn test_index_transcript_stores_one_chunk_per_cue() {
+            let mut qa = create_test_qa();
+
+            let count = qa
+                .index_transcript(Id::new(), Id::new(), &create_test_cues())
+                .unwrap();
+
+            assert_eq!(count, 3);
+            assert_eq!(qa.store().len(), 3);
+        }
+
+        #[test]
+        fn test_reindexing_transcript_replaces_lesson_chunks() {
+            let mut qa = create_test_qa();
+            let course_id = Id::new();
+            let lesson_id = Id::new();
+            qa.index_transcript(course_id, lesson_id, &create_test_cues())
+                .unwrap();
+
+            qa.index_transcript(course_id, lesson_id, &[TranscriptCue::new(0, "Async.")])
+                .unwrap();
+
+            assert_eq!(qa.store().len(), 1);
+        }
+
+        #[test]
+        fn test_index_description_adds_untimed_chunks() {
+            let mut qa = create_test_qa();
+            let course_id = Id::new();
+
+            qa.index_description(course_id, Id::new(), "All about async runtimes.")
+                .unwrap();
+
+            let snippets = qa.ask(course_id, "async").unwrap();
+            assert_eq!(snippets[0].start_seconds(), None);
+        }
+
+        #[test]
+        fn test_embedding_failure_is_reported() {
+            let mut qa = CourseQuestionAnswering::new(FailingEmbedder, InMemoryVectorStore::new());
+
+            let result = qa.index_transcript(Id::new(), Id::new(), &create_test_cues());
+
+            assert!(matches!(result, Err(RetrievalError::EmbeddingFailed(_))));
+        }
+    }
+
+    mod ask {
+        use super::*;
+
+        #[test]
+        fn test_returns_most_relevant_snippet_with_timestamp() {
+            let mut qa = create_test_qa();
+            let course_id = Id::new();
+            let lesson_id = Id::new();
+            qa.index_transcript(course_id, lesson_id, &create_test_cues())
+                .unwrap();
+
+            let snippets = qa.ask(course_id, "Why use borrowing?").unwrap();
+
+            assert_eq!(snippets[0].lesson_id(), lesson_id);
+            assert_eq!(snippets[0].text(), "Borrowing avoids copies.");
+            assert_eq!(snippets[0].start_seconds(), Some(300));
+        }
+
+        #[test]
+        fn test_respects_limit() {
+            let mut qa = create_test_qa().with_limit(2);
+            let course_id = Id::new();
+            qa.index_transcript(course_id, Id::new(), &create_test_cues())
+                .unwrap();
+
+            assert_eq!(qa.ask(course_id, "traits").unwrap().len(), 2);
+        }
+
+        #[test]
+        fn test_ignores_other_courses() {
+            let mut qa = create_test_qa();
+            qa.index_transcript(Id::new(), Id::new(), &create_test_cues())
+                .unwrap();
+
+            assert!(qa.ask(Id::new(), "traits").unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_blank_question_is_rejected() {
+            let qa = create_test_qa();
+
+            assert_eq!(qa.ask(Id::new(), "  "), Err(RetrievalError::EmptyQuestion));
+        }
+    }
+
+    mod answer {
+        use super::*;
+
+        #[test]
+        fn test_generator_receives_retrieved_snippets() {
+            let mut qa = create_test_qa();
+            let course_id = Id::new();
+            qa.index_transcript(course_id, Id::new(), &create_test_cues())
+                .unwrap();
+
+            let (answer, snippets) = qa
+                .answer(course_id, "What are macros?", &QuotingGenerator)
+                .unwrap();
+
+            assert_eq!(answer, "Macros write code for you.");
+            assert_eq!(snippets.len(), 3);
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use crate::ContentChunk;
+use education_platform_common::Id;
+use thiserror::Error;
+
+/// Error types for content retrieval failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetrievalError {
+    #[error("Question must not be empty")]
+    EmptyQuestion,
+
+    #[error("Embedding failed: {0}")]
+    EmbeddingFailed(String),
+
+    #[error("Answer generation failed: {0}")]
+    GenerationFailed(String),
+
+    #[error("Embedding has {found} dimensions, the store expects {expected}")]
+    DimensionMismatch { expected: usize, found: usize },
+}
+
+/// Port turning text into an embedding vector.
+///
+/// Implementations wrap an embedding model. Texts with similar meaning must
+/// map to vectors with a high cosine similarity, and every vector produced
+/// by one embedder must have the same number of dimensions.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{Embedder, RetrievalError};
+///
+/// /// Counts vowels and consonants; only good enough for a demo.
+/// struct LetterEmbedder;
+///
+/// impl Embedder for LetterEmbedder {
+///     fn embed(&self, text: &str) -> Result<Vec<f32>, RetrievalError> {
+///         let vowels = text.chars().filter(|c| "aeiou".contains(*c)).count();
+///         let letters = text.chars().filter(char::is_ascii_alphabetic).count();
+///         Ok(vec![vowels as f32, (letters - vowels) as f32])
+///     }
+/// }
+///
+/// assert_eq!(LetterEmbedder.embed("rust").unwrap(), vec![1.0, 3.0]);
+/// ```
+pub trait Embedder {
+    /// Embeds a text.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RetrievalError::EmbeddingFailed` if the model cannot embed the text.
+    fn embed(&self, text: &str) -> Result<Vec<f32>, RetrievalError>;
+}
+
+/// A chunk returned by a search, with its similarity to the query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredChunk {
+    chunk: ContentChunk,
+    score: f32,
+}
+
+impl ScoredChunk {
+    /// Creates a search result.
+    #[must_use]
+    pub const fn new(chunk: ContentChunk, score: f32) -> Self {
+        Self { chunk, score }
+    }
+
+    /// Returns the matching chunk.
+    #[inline]
+    #[must_use]
+    pub const fn chunk(&self) -> &ContentChunk {
+        &self.chunk
+    }
+
+    /// Returns the similarity to the query, higher is closer.
+    #[inline]
+    #[must_use]
+    pub const fn score(&self) -> f32 {
+        self.score
+    }
+}
+
+/// Port storing embedded chunks and finding the closest ones to a query.
+pub trait VectorStore {
+    /// Stores a chunk with its embedding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RetrievalError::DimensionMismatch` if the embedding does not
+    /// match the dimensions of the embeddings already stored.
+    fn insert(&mut self, chunk: ContentChunk, embedding: Vec<f32>) -> Result<(), RetrievalError>;
+
+    /// Removes every chunk of a lesson, before re-indexing it. Returns how many were removed.
+    fn remove_lesson(&mut self, lesson_id: Id) -> usize;
+
+    /// Returns up to `limit` chunks of a course, most similar to the query first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RetrievalError::DimensionMismatch` if the query does not match
+    /// the dimensions of the stored embeddings.
+    fn search(
+        &self,
+        course_id: Id,
+        query: &[f32],
+        limit: usize,
+    ) -> Result<Vec<ScoredChunk>, RetrievalError>;
+}
+
+/// In-process [`VectorStore`] ranking chunks by cosine similarity.
+///
+/// Searches scan every chunk of the course, which is fine for a course's
+/// worth of content; larger catalogs should use a dedicated vector database
+/// behind the same port.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{ContentChunk, InMemoryVectorStore, VectorStore};
+/// use education_platform_common::Id;
+///
+/// let course_id = Id::new();
+/// let mut store = InMemoryVectorStore::new();
+/// store.insert(ContentChunk::new(course_id, Id::new(), "traits", None), vec![1.0, 0.0]).unwrap();
+/// store.insert(ContentChunk::new(course_id, Id::new(), "macros", None), vec![0.0, 1.0]).unwrap();
+///
+/// let results = store.search(course_id, &[0.9, 0.1], 1).unwrap();
+/// assert_eq!(results[0].chunk().text(), "traits");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryVectorStore {
+    entries: Vec<(ContentChunk, Vec<f32>)>,
+}
+
+impl InMemoryVectorStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of stored chunks.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no chunk is stored.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn check_dimensions(&self, found: usize) -> Result<(), RetrievalError> {
+        match self.entries.first() {
+            Some((_, stored)) if stored.len() != found => Err(RetrievalError::DimensionMismatch {
+                expected: stored.len(),
+                found,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl VectorStore for InMemoryVectorStore {
+    fn insert(&mut self, chunk: ContentChunk, embedding: Vec<f32>) -> Result<(), RetrievalError> {
+        self.check_dimensions(embedding.len())?;
+        self.entries.push((chunk, embedding));
+        Ok(())
+    }
+
+    fn remove_lesson(&mut self, lesson_id: Id) -> usize {
+        let before = self.entries.len();
+        self.entries
+            .retain(|(chunk, _)| chunk.lesson_id() != lesson_id);
+        before - self.entries.len()
+    }
+
+    fn search(
+        &self,
+        course_id: Id,
+        query: &[f32],
+        limit: usize,
+    ) -> Result<Vec<ScoredChunk>, RetrievalError> {
+        self.check_dimensions(query.len())?;
+
+        let mut results: Vec<ScoredChunk> = self
+            .entries
+            .iter()
+            .filter(|(chunk, _)| chunk.course_id() == course_id)
+            .map(|(chunk, embedding)| {
+                ScoredChunk::new(chunk.clone(), cosine_similarity(query, embedding))
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.score().total_cmp(&a.score()));
+        results.truncate(limit);
+        Ok(results)
+    }
+}
+
+/// Returns the cosine of the angle between two vectors of the same length,
+/// or zero when either has no magnitude.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    match norm_a == 0.0 || norm_b == 0.0 {
+        true => 0.0,
+        false => dot / (norm_a * norm_b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_chunk(course_id: Id, text: &str) -> ContentChunk {
+        ContentChunk::new(course_id, Id::new(), text, None)
+    }
+
+    mod in_memory_vector_store {
+        use super::*;
+
+        #[test]
+        fn test_search_ranks_by_similarity() {
+            let course_id = Id::new();
+            let mut store = InMemoryVectorStore::new();
+            store
+                .insert(create_chunk(course_id, "far"), vec![0.0, 1.0])
+                .unwrap();
+            store
+                .insert(create_chunk(course_id, "near"), vec![1.0, 0.1])
+                .unwrap();
+            store
+                .insert(create_chunk(course_id, "middle"), vec![1.0, 1.0])
+                .unwrap();
+
+            let results = store.search(course_id, &[1.0, 0.0], 10).unwrap();
+
+            let texts: Vec<&str> = results.iter().map(|r| r.chunk().text()).collect();
+            assert_eq!(texts, vec!["near", "middle", "far"]);
+        }
+
+        #[test]
+        fn test_search_is_scoped_to_course() {
+            let course_id = Id::new();
+            let mut store = InMemoryVectorStore::new();
+            store
+                .insert(create_chunk(Id::new(), "other"), vec![1.0, 0.0])
+                .unwrap();
+            store
+                .insert(create_chunk(course_id, "mine"), vec![0.0, 1.0])
+                .unwrap();
+
+            let results = store.search(course_id, &[1.0, 0.0], 10).unwrap();
+
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].chunk().text(), "mine");
+        }
+
+        #[test]
+        fn test_search_respects_limit() {
+            let course_id = Id::new();
+            let mut store = InMemoryVectorStore::new();
+            for _ in 0..5 {
+                store
+                    .insert(create_chunk(course_id, "chunk"), vec![1.0])
+                    .unwrap();
+            }
+
+            assert_eq!(store.search(course_id, &[1.0], 2).unwrap().len(), 2);
+        }
+
+        #[test]
+        fn test_insert_rejects_other_dimensions() {
+            let mut store = InMemoryVectorStore::new();
+            store
+                .insert(create_chunk(Id::new(), "a"), vec![1.0, 0.0])
+                .unwrap();
+
+            let result = store.insert(create_chunk(Id::new(), "b"), vec![1.0]);
+
+            assert_eq!(
+                result,
+                Err(RetrievalError::DimensionMismatch {
+                    expected: 2,
+                    found: 1
+                })
+            );
+            assert_eq!(store.len(), 1);
+        }
+
+        #[test]
+        fn test_remove_lesson_drops_its_chunks() {
+            let course_id = Id::new();
+            let lesson_id = Id::new();
+            let mut store = InMemoryVectorStore::new();
+            store
+                .insert(ContentChunk::new(course_id, lesson_id, "a", None), vec![1.0])
+                .unwrap();
+            store
+                .insert(ContentChunk::new(course_id, lesson_id, "b", None), vec![1.0])
+                .unwrap();
+            store
+                .insert(create_chunk(course_id, "c"), vec![1.0])
+                .unwrap();
+
+            assert_eq!(store.remove_lesson(lesson_id), 2);
+            assert_eq!(store.len(), 1);
+        }
+    }
+
+    mod cosine_similarity {
+        use super::*;
+
+        #[test]
+        fn test_identical_direction_is_one() {
+            assert!((cosine_similarity(&[2.0, 0.0], &[5.0, 0.0]) - 1.0).abs() < f32::EPSILON);
+        }
+
+        #[test]
+        fn test_zero_vector_is_zero() {
+            assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod audience_segment;
+mod segment_rule;
+mod segment_store;
+
+pub use audience_segment::*;
+pub use segment_rule::*;
+pub use segment_store::*;
@@ -0,0 +1,453 @@
+use crate::{CourseProgress, SegmentRule};
+use education_platform_common::{Date, Email, Entity, Id, SimpleName, SimpleNameError};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Error types for segment definitions and storage.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SegmentError {
+    #[error("Segment name validation failed: {0}")]
+    NameError(#[from] SimpleNameError),
+
+    #[error("A group of segment rules must contain at least one rule")]
+    EmptyRuleGroup,
+
+    #[error("An activity window must span at least one day")]
+    EmptyActivityWindow,
+
+    #[error("An email domain rule needs a domain")]
+    EmptyEmailDomain,
+
+    #[error("A segment named '{0}' is already saved")]
+    DuplicateName(String),
+
+    #[error("Segment with id {0} not found")]
+    SegmentNotFound(String),
+}
+
+/// What a segment selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentScope {
+    /// Learners, judged on all of their enrollments together.
+    Users,
+    /// Individual enrollments, judged one at a time.
+    Enrollments,
+}
+
+/// One match of a segment: a learner and the enrollments that put them there.
+///
+/// For a user segment these are all of the learner's enrollments; for an
+/// enrollment segment it is the single matching enrollment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentMember {
+    user_email: Email,
+    enrollment_ids: Vec<Id>,
+}
+
+impl SegmentMember {
+    /// Returns the learner's email address.
+    #[inline]
+    #[must_use]
+    pub const fn user_email(&self) -> &Email {
+        &self.user_email
+    }
+
+    /// Returns the IDs of the course progress records behind the match.
+    #[inline]
+    #[must_use]
+    pub fn enrollment_ids(&self) -> &[Id] {
+        &self.enrollment_ids
+    }
+}
+
+/// A saved, named filter over learners or enrollments, such as "active in
+/// last 30 days AND course X incomplete".
+///
+/// Segments store the rule, not the result: evaluating one again later
+/// picks up whoever matches by then.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     AudienceSegment, Chapter, Course, CourseProgress, Lesson, NotificationChannel,
+///     NotificationTriggers, SegmentRule, SegmentScope,
+/// };
+/// use education_platform_common::{Date, Entity};
+///
+/// let lesson = Lesson::new(
+///     "Introduction".to_string(),
+///     1800,
+///     "https://example.com/intro.mp4".to_string(),
+///     0,
+/// ).unwrap();
+/// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+/// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+/// let enrollments = vec![
+///     CourseProgress::from_course(&course, "ana@example.com").unwrap(),
+///     CourseProgress::from_course(&course, "luis@example.com").unwrap(),
+/// ];
+///
+/// let segment = AudienceSegment::new(
+///     "Rust stragglers".to_string(),
+///     SegmentScope::Users,
+///     SegmentRule::IncompleteCourse(course.id()),
+/// ).unwrap();
+/// let audience = segment.audience(&enrollments, Date::new(2025, 3, 4).unwrap());
+///
+/// let triggers = NotificationTriggers::new(NotificationChannel::Email);
+/// let notifications = triggers.lesson_published(&audience, "Rust Programming", "Traits").unwrap();
+/// assert_eq!(notifications.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AudienceSegment {
+    id: Id,
+    name: SimpleName,
+    scope: SegmentScope,
+    rule: SegmentRule,
+}
+
+impl AudienceSegment {
+    /// Creates a segment with a new ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SegmentError::NameError` if the name is not valid, or the
+    /// `SegmentError` of the first rule that cannot select anyone
+    /// meaningfully: an empty group, a zero-day activity window or a blank
+    /// email domain.
+    pub fn new(name: String, scope: SegmentScope, rule: SegmentRule) -> Result<Self, SegmentError> {
+        Self::with_id(Id::new(), name, scope, rule)
+    }
+
+    /// Recreates a saved segment with its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`AudienceSegment::new`].
+    pub fn with_id(
+        id: Id,
+        name: String,
+        scope: SegmentScope,
+        rule: SegmentRule,
+    ) -> Result<Self, SegmentError> {
+        let name = SimpleName::new(name)?;
+        rule.validate()?;
+
+        Ok(Self {
+            id,
+            name,
+            scope,
+            rule,
+        })
+    }
+
+    /// Returns the segment name.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &SimpleName {
+        &self.name
+    }
+
+    /// Returns whether the segment selects users or enrollments.
+    #[inline]
+    #[must_use]
+    pub const fn scope(&self) -> SegmentScope {
+        self.scope
+    }
+
+    /// Returns the rule members must meet.
+    #[inline]
+    #[must_use]
+    pub const fn rule(&self) -> &SegmentRule {
+        &self.rule
+    }
+
+    /// Replaces the rule, keeping the segment's identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns the rule errors of [`AudienceSegment::new`]; the previous rule
+    /// is kept.
+    pub fn update_rule(&mut self, rule: SegmentRule) -> Result<(), SegmentError> {
+        rule.validate()?;
+        self.rule = rule;
+        Ok(())
+    }
+
+    /// Returns the members of the segment among the given enrollments.
+    ///
+    /// User segments list each matching learner once, ordered by email
+    /// address. Enrollment segments list matching enrollments in the order
+    /// given.
+    #[must_use]
+    pub fn evaluate(&self, enrollments: &[CourseProgress], today: Date) -> Vec<SegmentMember> {
+        match self.scope {
+            SegmentScope::Users => {
+                let mut by_user: BTreeMap<&str, Vec<&CourseProgress>> = BTreeMap::new();
+                for progress in enrollments {
+                    by_user
+                        .entry(progress.user_email().address())
+                        .or_default()
+                        .push(progress);
+                }
+                by_user
+                    .into_values()
+                    .filter(|own| self.rule.matches(own, today))
+                    .filter_map(|own| Self::member(&own))
+                    .collect()
+            }
+            SegmentScope::Enrollments => enrollments
+                .iter()
+                .filter(|progress| self.rule.matches(&[progress], today))
+                .filter_map(|progress| Self::member(&[progress]))
+                .collect(),
+        }
+    }
+
+    /// Returns the distinct email addresses of the members, ordered by
+    /// address, ready to pass as notification recipients.
+    #[must_use]
+    pub fn audience(&self, enrollments: &[CourseProgress], today: Date) -> Vec<Email> {
+        let mut recipients: Vec<Email> = self
+            .evaluate(enrollments, today)
+            .into_iter()
+            .map(|member| member.user_email)
+            .collect();
+        recipients.sort_by(|a, b| a.address().cmp(b.address()));
+        recipients.dedup();
+        recipients
+    }
+
+    /// Exports the members of the segment as CSV.
+    ///
+    /// Each row pairs a learner with one enrollment behind the match, so a
+    /// learner in a user segment gets a row per enrollment. Rows end in CRLF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{
+    ///     AudienceSegment, Chapter, Course, CourseProgress, Lesson, SegmentRule, SegmentScope,
+    /// };
+    /// use education_platform_common::{Date, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+    /// let segment = AudienceSegment::new(
+    ///     "Rust learners".to_string(),
+    ///     SegmentScope::Enrollments,
+    ///     SegmentRule::EnrolledIn(course.id()),
+    /// ).unwrap();
+    ///
+    /// let csv = segment.to_csv(&[progress.clone()], Date::new(2025, 3, 4).unwrap());
+    ///
+    /// assert_eq!(
+    ///     csv,
+    ///     format!("user_email,enrollment_id\r\nana@example.com,{}\r\n", progress.id())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_csv(&self, enrollments: &[CourseProgress], today: Date) -> String {
+        let mut csv = String::from("user_email,enrollment_id\r\n");
+        for member in self.evaluate(enrollments, today) {
+            for enrollment_id in member.enrollment_ids() {
+                csv.push_str(member.user_email().address());
+                csv.push(',');
+                csv.push_str(&enrollment_id.to_string());
+                csv.push_str("\r\n");
+            }
+        }
+        csv
+    }
+
+    fn member(enrollments: &[&CourseProgress]) -> Option<SegmentMember> {
+        let first = enrollments.first()?;
+        Some(SegmentMember {
+            user_email: first.user_email().clone(),
+            enrollment_ids: enrollments.iter().map(|progress| progress.id()).collect(),
+        })
+    }
+}
+
+impl Entity for AudienceSegment {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for AudienceSegment {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for AudienceSegment {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Course, Lesson};
+    use education_platform_common::DateTime;
+
+    fn create_test_course() -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_completed_progress(course: &Course, email: &str) -> CourseProgress {
+        let mut progress = CourseProgress::from_course(course, email).unwrap();
+        let lesson_id = progress.lesson_progress()[0].id();
+        progress
+            .mark_lesson_completed(lesson_id, DateTime::new(2025, 3, 2, 9, 0, 0).unwrap())
+            .unwrap();
+        progress
+    }
+
+    fn create_date() -> Date {
+        Date::new(2025, 3, 4).unwrap()
+    }
+
+    mod constructor {
+        use super::*;
+
+        #[test]
+        fn test_invalid_name_is_rejected() {
+            let result = AudienceSegment::new(
+                "   ".to_string(),
+                SegmentScope::Users,
+                SegmentRule::ActiveWithinDays(30),
+            );
+
+            assert!(matches!(result, Err(SegmentError::NameError(_))));
+        }
+
+        #[test]
+        fn test_invalid_rule_is_rejected() {
+            let result = AudienceSegment::new(
+                "Everyone".to_string(),
+                SegmentScope::Users,
+                SegmentRule::All(vec![]),
+            );
+
+            assert!(matches!(result, Err(SegmentError::EmptyRuleGroup)));
+        }
+
+        #[test]
+        fn test_rejected_rule_update_keeps_previous_rule() {
+            let mut segment = AudienceSegment::new(
+                "Recently active".to_string(),
+                SegmentScope::Users,
+                SegmentRule::ActiveWithinDays(30),
+            )
+            .unwrap();
+
+            let result = segment.update_rule(SegmentRule::ActiveWithinDays(0));
+
+            assert_eq!(result, Err(SegmentError::EmptyActivityWindow));
+            assert_eq!(segment.rule(), &SegmentRule::ActiveWithinDays(30));
+        }
+    }
+
+    mod evaluate {
+        use super::*;
+
+        #[test]
+        fn test_user_scope_judges_all_enrollments_together() {
+            let rust = create_test_course();
+            let go = create_test_course();
+            let enrollments = vec![
+                create_completed_progress(&rust, "ana@example.com"),
+                CourseProgress::from_course(&go, "ana@example.com").unwrap(),
+                create_completed_progress(&rust, "luis@example.com"),
+            ];
+            let rule =
+                SegmentRule::CompletedCourse(rust.id()).and(SegmentRule::EnrolledIn(go.id()));
+            let segment =
+                AudienceSegment::new("Next course".to_string(), SegmentScope::Users, rule).unwrap();
+
+            let members = segment.evaluate(&enrollments, create_date());
+
+            assert_eq!(members.len(), 1);
+            assert_eq!(members[0].user_email().address(), "ana@example.com");
+            assert_eq!(
+                members[0].enrollment_ids(),
+                &[enrollments[0].id(), enrollments[1].id()]
+            );
+        }
+
+        #[test]
+        fn test_enrollment_scope_judges_each_enrollment_alone() {
+            let rust = create_test_course();
+            let go = create_test_course();
+            let enrollments = vec![
+                create_completed_progress(&rust, "ana@example.com"),
+                CourseProgress::from_course(&go, "ana@example.com").unwrap(),
+            ];
+            let rule =
+                SegmentRule::CompletedCourse(rust.id()).and(SegmentRule::EnrolledIn(go.id()));
+            let segment =
+                AudienceSegment::new("Next course".to_string(), SegmentScope::Enrollments, rule)
+                    .unwrap();
+
+            assert!(segment.evaluate(&enrollments, create_date()).is_empty());
+        }
+
+        #[test]
+        fn test_audience_lists_each_learner_once() {
+            let course = create_test_course();
+            let other = create_test_course();
+            let enrollments = vec![
+                CourseProgress::from_course(&course, "ana@example.com").unwrap(),
+                CourseProgress::from_course(&other, "ana@example.com").unwrap(),
+                CourseProgress::from_course(&course, "luis@example.com").unwrap(),
+            ];
+            let segment = AudienceSegment::new(
+                "Any enrollment".to_string(),
+                SegmentScope::Enrollments,
+                SegmentRule::EnrolledIn(course.id()).or(SegmentRule::EnrolledIn(other.id())),
+            )
+            .unwrap();
+
+            let audience = segment.audience(&enrollments, create_date());
+
+            let addresses: Vec<&str> = audience.iter().map(Email::address).collect();
+            assert_eq!(addresses, vec!["ana@example.com", "luis@example.com"]);
+        }
+
+        #[test]
+        fn test_csv_has_a_row_per_enrollment() {
+            let course = create_test_course();
+            let other = create_test_course();
+            let enrollments = vec![
+                CourseProgress::from_course(&course, "ana@example.com").unwrap(),
+                CourseProgress::from_course(&other, "ana@example.com").unwrap(),
+            ];
+            let segment = AudienceSegment::new(
+                "Everyone".to_string(),
+                SegmentScope::Users,
+                SegmentRule::EnrolledIn(course.id()),
+            )
+            .unwrap();
+
+            let csv = segment.to_csv(&enrollments, create_date());
+
+            assert_eq!(csv.lines().count(), 3);
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{CourseProgress, SegmentError};
+use education_platform_common::{Date, Id};
+use std::fmt;
+
+/// A condition a learner or enrollment must meet to belong to a segment.
+///
+/// Rules look at a set of enrollments: every enrollment of a learner when
+/// the segment selects users, or a single enrollment when it selects
+/// enrollments. Conditions combine with [`SegmentRule::and`],
+/// [`SegmentRule::or`] and [`SegmentRule::negate`].
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::SegmentRule;
+/// use education_platform_common::Id;
+///
+/// let course_id = Id::new();
+/// let rule = SegmentRule::ActiveWithinDays(30).and(SegmentRule::IncompleteCourse(course_id));
+///
+/// assert_eq!(
+///     rule.to_string(),
+///     format!("active in last 30 days AND course {course_id} incomplete")
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SegmentRule {
+    /// Had learning activity on any of the given number of days ending today.
+    ActiveWithinDays(u32),
+    /// Is enrolled in the course.
+    EnrolledIn(Id),
+    /// Has completed the course.
+    CompletedCourse(Id),
+    /// Is enrolled in the course without having completed it.
+    IncompleteCourse(Id),
+    /// Uses an email address at the domain, compared case-insensitively.
+    EmailDomain(String),
+    /// Meets every rule.
+    All(Vec<SegmentRule>),
+    /// Meets at least one rule.
+    Any(Vec<SegmentRule>),
+    /// Does not meet the rule.
+    Not(Box<SegmentRule>),
+}
+
+impl SegmentRule {
+    /// Combines two rules so both must hold, flattening nested `All` groups.
+    #[must_use]
+    pub fn and(self, other: Self) -> Self {
+        match self {
+            Self::All(mut rules) => {
+                rules.push(other);
+                Self::All(rules)
+            }
+            rule => Self::All(vec![rule, other]),
+        }
+    }
+
+    /// Combines two rules so either may hold, flattening nested `Any` groups.
+    #[must_use]
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::Any(mut rules) => {
+                rules.push(other);
+                Self::Any(rules)
+            }
+            rule => Self::Any(vec![rule, other]),
+        }
+    }
+
+    /// Inverts the rule.
+    #[must_use]
+    pub fn negate(self) -> Self {
+        match self {
+            Self::Not(rule) => *rule,
+            rule => Self::Not(Box::new(rule)),
+        }
+    }
+
+    /// Returns whether a set of enrollments meets the rule on the given day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{Chapter, Course, CourseProgress, Lesson, SegmentRule};
+    /// use education_platform_common::{Date, Entity};
+    ///
+    /// let lesson = Lesson::new(
+    ///     "Introduction".to_string(),
+    ///     1800,
+    ///     "https://example.com/intro.mp4".to_string(),
+    ///     0,
+    /// ).unwrap();
+    /// let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+    /// let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+    /// let progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+    ///
+    /// let today = Date::new(2025, 3, 4).unwrap();
+    /// assert!(SegmentRule::IncompleteCourse(course.id()).matches(&[&progress], today));
+    /// assert!(!SegmentRule::ActiveWithinDays(30).matches(&[&progress], today));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, enrollments: &[&CourseProgress], today: Date) -> bool {
+        match self {
+            Self::ActiveWithinDays(days) => {
+                let since = today.sub_days(i64::from(*days) - 1);
+                enrollments
+                    .iter()
+                    .any(|progress| progress.active_days().range(since..=today).next().is_some())
+            }
+            Self::EnrolledIn(course_id) => enrollments
+                .iter()
+                .any(|progress| progress.course_id() == Some(*course_id)),
+            Self::CompletedCourse(course_id) => enrollments.iter().any(|progress| {
+                progress.course_id() == Some(*course_id) && progress.is_completed()
+            }),
+            Self::IncompleteCourse(course_id) => enrollments.iter().any(|progress| {
+                progress.course_id() == Some(*course_id) && !progress.is_completed()
+            }),
+            Self::EmailDomain(domain) => enrollments.iter().any(|progress| {
+                progress
+                    .user_email()
+                    .domain()
+                    .is_ok_and(|own| own.eq_ignore_ascii_case(domain))
+            }),
+            Self::All(rules) => rules.iter().all(|rule| rule.matches(enrollments, today)),
+            Self::Any(rules) => rules.iter().any(|rule| rule.matches(enrollments, today)),
+            Self::Not(rule) => !rule.matches(enrollments, today),
+        }
+    }
+
+    /// Rejects rules that cannot select anyone meaningfully.
+    pub(crate) fn validate(&self) -> Result<(), SegmentError> {
+        match self {
+            Self::ActiveWithinDays(0) => Err(SegmentError::EmptyActivityWindow),
+            Self::EmailDomain(domain) if domain.trim().is_empty() => {
+                Err(SegmentError::EmptyEmailDomain)
+            }
+            Self::All(rules) | Self::Any(rules) if rules.is_empty() => {
+                Err(SegmentError::EmptyRuleGroup)
+            }
+            Self::All(rules) | Self::Any(rules) => rules.iter().try_for_each(Self::validate),
+            Self::Not(rule) => rule.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All(_) | Self::Any(_) => write!(f, "({self})"),
+            rule => write!(f, "{rule}"),
+        }
+    }
+}
+
+impl fmt::Display for SegmentRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ActiveWithinDays(days) => write!(f, "active in last {days} days"),
+            Self::EnrolledIn(course_id) => write!(f, "enrolled in course {course_id}"),
+            Self::CompletedCourse(course_id) => write!(f, "completed course {course_id}"),
+            Self::IncompleteCourse(course_id) => write!(f, "course {course_id} incomplete"),
+            Self::EmailDomain(domain) => write!(f, "email at {domain}"),
+            Self::All(rules) | Self::Any(rules) => {
+                let separator = if matches!(self, Self::All(_)) {
+                    " AND "
+                } else {
+                    " OR "
+                };
+                for (index, rule) in rules.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(separator)?;
+                    }
+                    rule.write_operand(f)?;
+                }
+                Ok(())
+            }
+            Self::Not(rule) => {
+                f.write_str("NOT ")?;
+                rule.write_operand(f)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Chapter, Course, Lesson, LessonProgress};
+    use education_platform_common::{DateTime, Entity};
+
+    fn create_test_course() -> Course {
+        let lesson = Lesson::new(
+            "Introduction".to_string(),
+            1800,
+            "https://example.com/intro.mp4".to_string(),
+            0,
+        )
+        .unwrap();
+        let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+        Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap()
+    }
+
+    fn create_test_progress(course: &Course, email: &str) -> CourseProgress {
+        CourseProgress::from_course(course, email).unwrap()
+    }
+
+    fn create_date(day: u32) -> Date {
+        Date::new(2025, 3, day).unwrap()
+    }
+
+    mod matches {
+        use super::*;
+
+        #[test]
+        fn test_activity_window_includes_first_day() {
+            let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+            let progress = CourseProgress::builder()
+                .course_name("Rust Course")
+                .user_email("ana@example.com")
+                .lessons(vec![lesson])
+                .active_days([create_date(2)])
+                .build()
+                .unwrap();
+            let rule = SegmentRule::ActiveWithinDays(3);
+
+            assert!(rule.matches(&[&progress], create_date(4)));
+            assert!(!rule.matches(&[&progress], create_date(5)));
+        }
+
+        #[test]
+        fn test_completion_rules_check_the_named_course() {
+            let course = create_test_course();
+            let other_course = create_test_course();
+            let mut progress = create_test_progress(&course, "ana@example.com");
+            let lesson_id = progress.lesson_progress()[0].id();
+            progress
+                .mark_lesson_completed(lesson_id, DateTime::new(2025, 3, 2, 9, 0, 0).unwrap())
+                .unwrap();
+            let enrollments = [&progress];
+
+            assert!(
+                SegmentRule::CompletedCourse(course.id()).matches(&enrollments, create_date(4))
+            );
+            assert!(
+                !SegmentRule::IncompleteCourse(course.id()).matches(&enrollments, create_date(4))
+            );
+            assert!(
+                !SegmentRule::EnrolledIn(other_course.id()).matches(&enrollments, create_date(4))
+            );
+        }
+
+        #[test]
+        fn test_email_domain_ignores_case() {
+            let course = create_test_course();
+            let progress = create_test_progress(&course, "ana@Uni.edu");
+
+            assert!(
+                SegmentRule::EmailDomain("uni.EDU".to_string())
+                    .matches(&[&progress], create_date(4))
+            );
+        }
+
+        #[test]
+        fn test_groups_combine_rules() {
+            let course = create_test_course();
+            let progress = create_test_progress(&course, "ana@uni.edu");
+            let enrolled = SegmentRule::EnrolledIn(course.id());
+            let at_uni = SegmentRule::EmailDomain("uni.edu".to_string());
+
+            let both = enrolled.clone().and(at_uni.clone());
+            let neither = enrolled.or(at_uni).negate();
+
+            assert!(both.matches(&[&progress], create_date(4)));
+            assert!(!neither.matches(&[&progress], create_date(4)));
+        }
+    }
+
+    mod composition {
+        use super::*;
+
+        #[test]
+        fn test_and_flattens_groups() {
+            let rule = SegmentRule::ActiveWithinDays(7)
+                .and(SegmentRule::ActiveWithinDays(14))
+                .and(SegmentRule::ActiveWithinDays(30));
+
+            assert!(matches!(rule, SegmentRule::All(ref rules) if rules.len() == 3));
+        }
+
+        #[test]
+        fn test_double_negation_cancels() {
+            let rule = SegmentRule::ActiveWithinDays(7);
+
+            assert_eq!(rule.clone().negate().negate(), rule);
+        }
+
+        #[test]
+        fn test_nested_groups_are_parenthesized() {
+            let rule = SegmentRule::EmailDomain("uni.edu".to_string()).and(
+                SegmentRule::ActiveWithinDays(7)
+                    .or(SegmentRule::ActiveWithinDays(30))
+                    .negate(),
+            );
+
+            assert_eq!(
+                rule.to_string(),
+                "email at uni.edu AND NOT (active in last 7 days OR active in last 30 days)"
+            );
+        }
+    }
+
+    mod validate {
+        use super::*;
+
+        #[test]
+        fn test_empty_group_is_rejected_at_any_depth() {
+            let rule = SegmentRule::ActiveWithinDays(7).and(SegmentRule::Any(vec![]).negate());
+
+            assert_eq!(rule.validate(), Err(SegmentError::EmptyRuleGroup));
+        }
+
+        #[test]
+        fn test_zero_day_window_is_rejected() {
+            assert_eq!(
+                SegmentRule::ActiveWithinDays(0).validate(),
+                Err(SegmentError::EmptyActivityWindow)
+            );
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{AudienceSegment, SegmentError};
+use education_platform_common::{Entity, Id};
+
+/// Port persisting the segments admins have saved.
+pub trait SegmentStore {
+    /// Saves a new segment or replaces the saved version with the same ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SegmentError::DuplicateName` if another segment already
+    /// uses the name.
+    fn save(&mut self, segment: AudienceSegment) -> Result<(), SegmentError>;
+
+    /// Returns a saved segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SegmentError::SegmentNotFound` if no segment has the ID.
+    fn find(&self, segment_id: Id) -> Result<AudienceSegment, SegmentError>;
+
+    /// Returns every saved segment ordered by name.
+    fn all(&self) -> Vec<AudienceSegment>;
+
+    /// Deletes a saved segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SegmentError::SegmentNotFound` if no segment has the ID.
+    fn delete(&mut self, segment_id: Id) -> Result<(), SegmentError>;
+}
+
+/// In-process [`SegmentStore`].
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     AudienceSegment, InMemorySegmentStore, SegmentRule, SegmentScope, SegmentStore,
+/// };
+/// use education_platform_common::Entity;
+///
+/// let segment = AudienceSegment::new(
+///     "Recently active".to_string(),
+///     SegmentScope::Users,
+///     SegmentRule::ActiveWithinDays(30),
+/// ).unwrap();
+/// let segment_id = segment.id();
+/// let mut store = InMemorySegmentStore::new();
+///
+/// store.save(segment).unwrap();
+///
+/// assert_eq!(store.find(segment_id).unwrap().name().as_str(), "Recently active");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySegmentStore {
+    segments: Vec<AudienceSegment>,
+}
+
+impl InMemorySegmentStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SegmentStore for InMemorySegmentStore {
+    fn save(&mut self, segment: AudienceSegment) -> Result<(), SegmentError> {
+        if self
+            .segments
+            .iter()
+            .any(|saved| saved.id() != segment.id() && saved.name() == segment.name())
+        {
+            return Err(SegmentError::DuplicateName(segment.name().to_string()));
+        }
+
+        match self
+            .segments
+            .iter_mut()
+            .find(|saved| saved.id() == segment.id())
+        {
+            Some(saved) => *saved = segment,
+            None => self.segments.push(segment),
+        }
+        Ok(())
+    }
+
+    fn find(&self, segment_id: Id) -> Result<AudienceSegment, SegmentError> {
+        self.segments
+            .iter()
+            .find(|saved| saved.id() == segment_id)
+            .cloned()
+            .ok_or_else(|| SegmentError::SegmentNotFound(segment_id.to_string()))
+    }
+
+    fn all(&self) -> Vec<AudienceSegment> {
+        let mut segments = self.segments.clone();
+        segments.sort_by(|a, b| a.name().cmp(b.name()));
+        segments
+    }
+
+    fn delete(&mut self, segment_id: Id) -> Result<(), SegmentError> {
+        let position = self
+            .segments
+            .iter()
+            .position(|saved| saved.id() == segment_id)
+            .ok_or_else(|| SegmentError::SegmentNotFound(segment_id.to_string()))?;
+        self.segments.remove(position);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SegmentRule, SegmentScope};
+
+    fn create_test_segment(name: &str) -> AudienceSegment {
+        AudienceSegment::new(
+            name.to_string(),
+            SegmentScope::Users,
+            SegmentRule::ActiveWithinDays(30),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_saving_again_replaces_segment() {
+        let mut store = InMemorySegmentStore::new();
+        let mut segment = create_test_segment("Recently active");
+        store.save(segment.clone()).unwrap();
+
+        segment
+            .update_rule(SegmentRule::ActiveWithinDays(7))
+            .unwrap();
+        store.save(segment.clone()).unwrap();
+
+        assert_eq!(store.all().len(), 1);
+        assert_eq!(
+            store.find(segment.id()).unwrap().rule(),
+            &SegmentRule::ActiveWithinDays(7)
+        );
+    }
+
+    #[test]
+    fn test_name_must_be_unique() {
+        let mut store = InMemorySegmentStore::new();
+        store.save(create_test_segment("Recently active")).unwrap();
+
+        let result = store.save(create_test_segment("Recently active"));
+
+        assert_eq!(
+            result,
+            Err(SegmentError::DuplicateName("Recently active".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_all_is_ordered_by_name() {
+        let mut store = InMemorySegmentStore::new();
+        store.save(create_test_segment("Stragglers")).unwrap();
+        store.save(create_test_segment("Graduates")).unwrap();
+
+        let names: Vec<String> = store.all().iter().map(|s| s.name().to_string()).collect();
+
+        assert_eq!(names, vec!["Graduates", "Stragglers"]);
+    }
+
+    #[test]
+    fn test_deleted_segment_is_not_found() {
+        let mut store = InMemorySegmentStore::new();
+        let segment = create_test_segment("Recently active");
+        store.save(segment.clone()).unwrap();
+
+        store.delete(segment.id()).unwrap();
+
+        assert!(matches!(
+            store.find(segment.id()),
+            Err(SegmentError::SegmentNotFound(_))
+        ));
+        assert!(store.delete(segment.id()).is_err());
+    }
+}
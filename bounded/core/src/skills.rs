@@ -0,0 +1,7 @@
+mod course_skill_map;
+mod skill;
+mod track;
+
+pub use course_skill_map::*;
+pub use skill::*;
+pub use track::*;
@@ -0,0 +1,268 @@
+use crate::{ProficiencyLevel, Skill};
+use education_platform_common::Id;
+use std::collections::BTreeMap;
+
+/// Which skills each course teaches, and to what level.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill};
+/// use education_platform_common::Id;
+///
+/// let intro = Id::new();
+/// let advanced = Id::new();
+/// let rust = Skill::new("rust").unwrap();
+///
+/// let mut map = CourseSkillMap::new();
+/// map.teach(intro, rust.clone(), ProficiencyLevel::Beginner);
+/// map.teach(advanced, rust.clone(), ProficiencyLevel::Advanced);
+///
+/// assert_eq!(map.courses_teaching(&rust).len(), 2);
+/// assert_eq!(
+///     map.courses_teaching_at_least(&rust, ProficiencyLevel::Intermediate),
+///     vec![advanced],
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CourseSkillMap {
+    courses: BTreeMap<Id, BTreeMap<Skill, ProficiencyLevel>>,
+}
+
+impl CourseSkillMap {
+    /// Creates an empty skill map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::CourseSkillMap;
+    /// use education_platform_common::Id;
+    ///
+    /// assert!(CourseSkillMap::new().skills_of(Id::new()).is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that a course teaches a skill to the given level.
+    ///
+    /// Mapping the same skill again replaces its level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill};
+    /// use education_platform_common::Id;
+    ///
+    /// let course_id = Id::new();
+    /// let sql = Skill::new("sql").unwrap();
+    /// let mut map = CourseSkillMap::new();
+    ///
+    /// map.teach(course_id, sql.clone(), ProficiencyLevel::Beginner);
+    /// map.teach(course_id, sql.clone(), ProficiencyLevel::Advanced);
+    ///
+    /// assert_eq!(map.level_taught(course_id, &sql), Some(ProficiencyLevel::Advanced));
+    /// ```
+    pub fn teach(&mut self, course_id: Id, skill: Skill, level: ProficiencyLevel) {
+        self.courses
+            .entry(course_id)
+            .or_default()
+            .insert(skill, level);
+    }
+
+    /// Stops mapping a skill to a course. Returns true if it was mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill};
+    /// use education_platform_common::Id;
+    ///
+    /// let course_id = Id::new();
+    /// let sql = Skill::new("sql").unwrap();
+    /// let mut map = CourseSkillMap::new();
+    /// map.teach(course_id, sql.clone(), ProficiencyLevel::Beginner);
+    ///
+    /// assert!(map.forget(course_id, &sql));
+    /// assert!(!map.forget(course_id, &sql));
+    /// ```
+    pub fn forget(&mut self, course_id: Id, skill: &Skill) -> bool {
+        let Some(skills) = self.courses.get_mut(&course_id) else {
+            return false;
+        };
+
+        let removed = skills.remove(skill).is_some();
+        if skills.is_empty() {
+            self.courses.remove(&course_id);
+        }
+        removed
+    }
+
+    /// Returns the level to which a course teaches a skill, if it does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, Skill};
+    /// use education_platform_common::Id;
+    ///
+    /// let map = CourseSkillMap::new();
+    /// assert!(map.level_taught(Id::new(), &Skill::new("sql").unwrap()).is_none());
+    /// ```
+    #[must_use]
+    pub fn level_taught(&self, course_id: Id, skill: &Skill) -> Option<ProficiencyLevel> {
+        self.courses
+            .get(&course_id)
+            .and_then(|skills| skills.get(skill))
+            .copied()
+    }
+
+    /// Returns the skills a course teaches, in alphabetical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill};
+    /// use education_platform_common::Id;
+    ///
+    /// let course_id = Id::new();
+    /// let mut map = CourseSkillMap::new();
+    /// map.teach(course_id, Skill::new("sql").unwrap(), ProficiencyLevel::Beginner);
+    /// map.teach(course_id, Skill::new("python").unwrap(), ProficiencyLevel::Beginner);
+    ///
+    /// let names: Vec<&str> = map.skills_of(course_id).iter().map(|(s, _)| s.name()).collect();
+    /// assert_eq!(names, vec!["python", "sql"]);
+    /// ```
+    #[must_use]
+    pub fn skills_of(&self, course_id: Id) -> Vec<(&Skill, ProficiencyLevel)> {
+        self.courses
+            .get(&course_id)
+            .map(|skills| {
+                skills
+                    .iter()
+                    .map(|(skill, level)| (skill, *level))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the IDs of the courses that teach a skill, at any level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill};
+    /// use education_platform_common::Id;
+    ///
+    /// let course_id = Id::new();
+    /// let sql = Skill::new("sql").unwrap();
+    /// let mut map = CourseSkillMap::new();
+    /// map.teach(course_id, sql.clone(), ProficiencyLevel::Beginner);
+    ///
+    /// assert_eq!(map.courses_teaching(&sql), vec![course_id]);
+    /// ```
+    #[must_use]
+    pub fn courses_teaching(&self, skill: &Skill) -> Vec<Id> {
+        self.courses_teaching_at_least(skill, ProficiencyLevel::Beginner)
+    }
+
+    /// Returns the IDs of the courses that teach a skill to at least the given level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill};
+    /// use education_platform_common::Id;
+    ///
+    /// let course_id = Id::new();
+    /// let sql = Skill::new("sql").unwrap();
+    /// let mut map = CourseSkillMap::new();
+    /// map.teach(course_id, sql.clone(), ProficiencyLevel::Intermediate);
+    ///
+    /// assert!(map.courses_teaching_at_least(&sql, ProficiencyLevel::Expert).is_empty());
+    /// ```
+    #[must_use]
+    pub fn courses_teaching_at_least(&self, skill: &Skill, level: ProficiencyLevel) -> Vec<Id> {
+        self.courses
+            .iter()
+            .filter(|(_, skills)| skills.get(skill).is_some_and(|taught| *taught >= level))
+            .map(|(course_id, _)| *course_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_skill(name: &str) -> Skill {
+        Skill::new(name).unwrap()
+    }
+
+    mod mapping {
+        use super::*;
+
+        #[test]
+        fn test_teach_keeps_skills_per_course() {
+            let first = Id::new();
+            let second = Id::new();
+            let mut map = CourseSkillMap::new();
+
+            map.teach(first, create_skill("rust"), ProficiencyLevel::Beginner);
+            map.teach(second, create_skill("sql"), ProficiencyLevel::Advanced);
+
+            assert_eq!(map.skills_of(first).len(), 1);
+            assert_eq!(
+                map.level_taught(second, &create_skill("SQL")),
+                Some(ProficiencyLevel::Advanced)
+            );
+            assert!(map.level_taught(first, &create_skill("sql")).is_none());
+        }
+
+        #[test]
+        fn test_forget_last_skill_drops_course() {
+            let course_id = Id::new();
+            let mut map = CourseSkillMap::new();
+            map.teach(course_id, create_skill("rust"), ProficiencyLevel::Beginner);
+
+            map.forget(course_id, &create_skill("rust"));
+
+            assert_eq!(map, CourseSkillMap::new());
+        }
+
+        #[test]
+        fn test_forget_unknown_course_returns_false() {
+            let mut map = CourseSkillMap::new();
+
+            assert!(!map.forget(Id::new(), &create_skill("rust")));
+        }
+    }
+
+    mod queries {
+        use super::*;
+
+        #[test]
+        fn test_courses_teaching_ignores_other_skills() {
+            let rust_course = Id::new();
+            let mut map = CourseSkillMap::new();
+            map.teach(rust_course, create_skill("rust"), ProficiencyLevel::Beginner);
+            map.teach(Id::new(), create_skill("sql"), ProficiencyLevel::Beginner);
+
+            assert_eq!(map.courses_teaching(&create_skill("rust")), vec![rust_course]);
+        }
+
+        #[test]
+        fn test_courses_teaching_at_least_includes_exact_level() {
+            let course_id = Id::new();
+            let mut map = CourseSkillMap::new();
+            map.teach(course_id, create_skill("rust"), ProficiencyLevel::Advanced);
+
+            assert_eq!(
+                map.courses_teaching_at_least(&create_skill("rust"), ProficiencyLevel::Advanced),
+                vec![course_id]
+            );
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use education_platform_common::{Validator, ValidatorError};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for Skill validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SkillError {
+    #[error("Skill name validation failed: {0}")]
+    NameError(#[from] ValidatorError),
+}
+
+/// A named competence a learner can acquire, such as `"rust"` or `"sql"`.
+///
+/// Skill names are trimmed and lowercased, so `"SQL"` and `" sql "` are the
+/// same skill.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Skill;
+///
+/// let skill = Skill::new("  Rust ").unwrap();
+///
+/// assert_eq!(skill.name(), "rust");
+/// assert_eq!(skill, Skill::new("RUST").unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Skill(String);
+
+impl Skill {
+    pub const MAX_LENGTH: usize = 50;
+
+    /// Creates a new `Skill` from its name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SkillError::NameError` if the name is blank or longer than
+    /// [`Skill::MAX_LENGTH`] bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Skill;
+    ///
+    /// assert!(Skill::new("sql").is_ok());
+    /// assert!(Skill::new("   ").is_err());
+    /// ```
+    pub fn new(name: &str) -> Result<Self, SkillError> {
+        let name = name.trim().to_lowercase();
+        Validator::is_not_empty(&name)?;
+        Validator::has_max_length(&name, Self::MAX_LENGTH)?;
+        Ok(Self(name))
+    }
+
+    /// Returns the normalized skill name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Skill;
+    ///
+    /// assert_eq!(Skill::new("Docker").unwrap().name(), "docker");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Skill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// How far a course takes a learner in a skill.
+///
+/// Levels are ordered, so the higher of two levels can be found with `max`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::ProficiencyLevel;
+///
+/// assert!(ProficiencyLevel::Advanced > ProficiencyLevel::Beginner);
+/// assert_eq!(ProficiencyLevel::Intermediate.to_string(), "intermediate");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProficiencyLevel {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl fmt::Display for ProficiencyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Beginner => "beginner",
+            Self::Intermediate => "intermediate",
+            Self::Advanced => "advanced",
+            Self::Expert => "expert",
+        };
+        write!(f, "{label}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod skill {
+        use super::*;
+
+        #[test]
+        fn test_new_normalizes_name() {
+            assert_eq!(Skill::new(" Machine Learning ").unwrap().name(), "machine learning");
+        }
+
+        #[test]
+        fn test_new_rejects_blank_name() {
+            assert!(matches!(Skill::new("  "), Err(SkillError::NameError(_))));
+        }
+
+        #[test]
+        fn test_new_rejects_too_long_name() {
+            let name = "a".repeat(Skill::MAX_LENGTH + 1);
+
+            assert!(Skill::new(&name).is_err());
+        }
+
+        #[test]
+        fn test_display_shows_name() {
+            assert_eq!(Skill::new("SQL").unwrap().to_string(), "sql");
+        }
+    }
+
+    mod proficiency_level {
+        use super::*;
+
+        #[test]
+        fn test_levels_are_ordered() {
+            let mut levels = vec![
+                ProficiencyLevel::Expert,
+                ProficiencyLevel::Beginner,
+                ProficiencyLevel::Advanced,
+                ProficiencyLevel::Intermediate,
+            ];
+
+            levels.sort();
+
+            assert_eq!(
+                levels,
+                vec![
+                    ProficiencyLevel::Beginner,
+                    ProficiencyLevel::Intermediate,
+                    ProficiencyLevel::Advanced,
+                    ProficiencyLevel::Expert,
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,628 @@
+use crate::{CourseSkillMap, ProficiencyLevel, Skill};
+use education_platform_common::{Entity, Id, SimpleName, SimpleNameError};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use thiserror::Error;
+
+/// Error types for Track validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrackError {
+    #[error("Track role is not valid: {0}")]
+    RoleError(#[from] SimpleNameError),
+
+    #[error("Track must require at least one skill")]
+    NoRequiredSkills,
+}
+
+/// A skill a learner still lacks, or holds below the level a track requires.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{ProficiencyLevel, Skill, Track};
+///
+/// let track = Track::new(
+///     "Data Engineer".to_string(),
+///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Advanced)],
+/// ).unwrap();
+///
+/// let sql = Skill::new("sql").unwrap();
+/// let gaps = track.gaps(&[(&sql, ProficiencyLevel::Beginner)]);
+///
+/// assert_eq!(gaps[0].skill(), &sql);
+/// assert_eq!(gaps[0].current(), Some(ProficiencyLevel::Beginner));
+/// assert_eq!(gaps[0].required(), ProficiencyLevel::Advanced);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillGap {
+    skill: Skill,
+    current: Option<ProficiencyLevel>,
+    required: ProficiencyLevel,
+}
+
+impl SkillGap {
+    /// Returns the skill in question.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let sql = Skill::new("sql").unwrap();
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![(sql.clone(), ProficiencyLevel::Beginner)],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(track.gaps(&[])[0].skill(), &sql);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn skill(&self) -> &Skill {
+        &self.skill
+    }
+
+    /// Returns the learner's current level, or `None` if they lack the skill.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Beginner)],
+    /// ).unwrap();
+    ///
+    /// assert!(track.gaps(&[])[0].current().is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn current(&self) -> Option<ProficiencyLevel> {
+        self.current
+    }
+
+    /// Returns the level the track requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Intermediate)],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(track.gaps(&[])[0].required(), ProficiencyLevel::Intermediate);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn required(&self) -> ProficiencyLevel {
+        self.required
+    }
+}
+
+/// Courses suggested to close a learner's gaps for a track.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill, Track};
+/// use education_platform_common::Id;
+///
+/// let sql = Skill::new("sql").unwrap();
+/// let track = Track::new(
+///     "Analyst".to_string(),
+///     vec![
+///         (sql.clone(), ProficiencyLevel::Beginner),
+///         (Skill::new("statistics").unwrap(), ProficiencyLevel::Beginner),
+///     ],
+/// ).unwrap();
+///
+/// let course_id = Id::new();
+/// let mut map = CourseSkillMap::new();
+/// map.teach(course_id, sql, ProficiencyLevel::Beginner);
+///
+/// let recommendation = track.recommend_courses(&[], &map);
+/// assert_eq!(recommendation.courses(), &[course_id]);
+/// assert_eq!(recommendation.uncovered()[0].skill().name(), "statistics");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackRecommendation {
+    courses: Vec<Id>,
+    uncovered: Vec<SkillGap>,
+}
+
+impl TrackRecommendation {
+    /// Returns the IDs of the recommended courses, in the order to take them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill, Track};
+    ///
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Beginner)],
+    /// ).unwrap();
+    ///
+    /// assert!(track.recommend_courses(&[], &CourseSkillMap::new()).courses().is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn courses(&self) -> &[Id] {
+        &self.courses
+    }
+
+    /// Returns the gaps no known course closes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill, Track};
+    ///
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Beginner)],
+    /// ).unwrap();
+    ///
+    /// let recommendation = track.recommend_courses(&[], &CourseSkillMap::new());
+    /// assert_eq!(recommendation.uncovered().len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn uncovered(&self) -> &[SkillGap] {
+        &self.uncovered
+    }
+}
+
+/// A learning track towards a job role, defined by the skills the role requires.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{ProficiencyLevel, Skill, Track};
+///
+/// let rust = Skill::new("rust").unwrap();
+/// let track = Track::new(
+///     "Backend Engineer".to_string(),
+///     vec![(rust.clone(), ProficiencyLevel::Intermediate)],
+/// ).unwrap();
+///
+/// assert!(!track.is_ready(&[(&rust, ProficiencyLevel::Beginner)]));
+/// assert!(track.is_ready(&[(&rust, ProficiencyLevel::Advanced)]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Track {
+    id: Id,
+    role: SimpleName,
+    required_skills: BTreeMap<Skill, ProficiencyLevel>,
+}
+
+impl Track {
+    /// Creates a new `Track` for a role.
+    ///
+    /// If a skill is listed more than once, the highest level is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TrackError::RoleError` if the role name is not valid.
+    /// Returns `TrackError::NoRequiredSkills` if no skill is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let required = vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Beginner)];
+    ///
+    /// assert!(Track::new("Analyst".to_string(), required).is_ok());
+    /// assert!(Track::new("Analyst".to_string(), vec![]).is_err());
+    /// ```
+    pub fn new(
+        role: String,
+        required_skills: Vec<(Skill, ProficiencyLevel)>,
+    ) -> Result<Self, TrackError> {
+        Self::with_id(Id::default(), role, required_skills)
+    }
+
+    /// Creates a `Track` with a specific ID (for reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Track::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    /// use education_platform_common::{Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let track = Track::with_id(
+    ///     id,
+    ///     "Analyst".to_string(),
+    ///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Beginner)],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(track.id(), id);
+    /// ```
+    pub fn with_id(
+        id: Id,
+        role: String,
+        required_skills: Vec<(Skill, ProficiencyLevel)>,
+    ) -> Result<Self, TrackError> {
+        let role = SimpleName::new(role)?;
+
+        let mut required = BTreeMap::new();
+        for (skill, level) in required_skills {
+            required
+                .entry(skill)
+                .and_modify(|current: &mut ProficiencyLevel| *current = (*current).max(level))
+                .or_insert(level);
+        }
+
+        if required.is_empty() {
+            return Err(TrackError::NoRequiredSkills);
+        }
+
+        Ok(Self {
+            id,
+            role,
+            required_skills: required,
+        })
+    }
+
+    /// Returns the target role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Beginner)],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(track.role().as_str(), "Analyst");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn role(&self) -> &SimpleName {
+        &self.role
+    }
+
+    /// Returns the required skills and levels, in alphabetical order of skill.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![
+    ///         (Skill::new("sql").unwrap(), ProficiencyLevel::Beginner),
+    ///         (Skill::new("sql").unwrap(), ProficiencyLevel::Advanced),
+    ///     ],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(track.required_skills().len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn required_skills(&self) -> &BTreeMap<Skill, ProficiencyLevel> {
+        &self.required_skills
+    }
+
+    /// Returns the required skills the learner lacks or holds at too low a level.
+    ///
+    /// `earned` is the learner's skills, as returned by
+    /// [`crate::LearnerSkills::skills_of`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let sql = Skill::new("sql").unwrap();
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![
+    ///         (sql.clone(), ProficiencyLevel::Beginner),
+    ///         (Skill::new("excel").unwrap(), ProficiencyLevel::Beginner),
+    ///     ],
+    /// ).unwrap();
+    ///
+    /// let gaps = track.gaps(&[(&sql, ProficiencyLevel::Intermediate)]);
+    /// assert_eq!(gaps.len(), 1);
+    /// assert_eq!(gaps[0].skill().name(), "excel");
+    /// ```
+    #[must_use]
+    pub fn gaps(&self, earned: &[(&Skill, ProficiencyLevel)]) -> Vec<SkillGap> {
+        self.required_skills
+            .iter()
+            .filter_map(|(skill, required)| {
+                let current = earned
+                    .iter()
+                    .filter(|(earned_skill, _)| *earned_skill == skill)
+                    .map(|(_, level)| *level)
+                    .max();
+
+                match current.is_some_and(|level| level >= *required) {
+                    true => None,
+                    false => Some(SkillGap {
+                        skill: skill.clone(),
+                        current,
+                        required: *required,
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns true if the learner meets every requirement of the track.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{ProficiencyLevel, Skill, Track};
+    ///
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![(Skill::new("sql").unwrap(), ProficiencyLevel::Beginner)],
+    /// ).unwrap();
+    ///
+    /// assert!(!track.is_ready(&[]));
+    /// ```
+    #[must_use]
+    pub fn is_ready(&self, earned: &[(&Skill, ProficiencyLevel)]) -> bool {
+        self.gaps(earned).is_empty()
+    }
+
+    /// Recommends a short sequence of courses closing the learner's gaps.
+    ///
+    /// A course closes a gap when it teaches the skill to at least the
+    /// required level. Courses are picked greedily, each time taking the one
+    /// that closes the most remaining gaps (ties go to the lowest course ID),
+    /// which keeps the list short without searching every combination. Gaps
+    /// that no course closes are reported as uncovered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{CourseSkillMap, ProficiencyLevel, Skill, Track};
+    /// use education_platform_common::Id;
+    ///
+    /// let sql = Skill::new("sql").unwrap();
+    /// let excel = Skill::new("excel").unwrap();
+    /// let track = Track::new(
+    ///     "Analyst".to_string(),
+    ///     vec![
+    ///         (sql.clone(), ProficiencyLevel::Beginner),
+    ///         (excel.clone(), ProficiencyLevel::Beginner),
+    ///     ],
+    /// ).unwrap();
+    ///
+    /// let sql_course = Id::new();
+    /// let bundle_course = Id::new();
+    /// let mut map = CourseSkillMap::new();
+    /// map.teach(sql_course, sql.clone(), ProficiencyLevel::Beginner);
+    /// map.teach(bundle_course, sql, ProficiencyLevel::Beginner);
+    /// map.teach(bundle_course, excel, ProficiencyLevel::Beginner);
+    ///
+    /// let recommendation = track.recommend_courses(&[], &map);
+    /// assert_eq!(recommendation.courses(), &[bundle_course]);
+    /// assert!(recommendation.uncovered().is_empty());
+    /// ```
+    #[must_use]
+    pub fn recommend_courses(
+        &self,
+        earned: &[(&Skill, ProficiencyLevel)],
+        skill_map: &CourseSkillMap,
+    ) -> TrackRecommendation {
+        let mut remaining = self.gaps(earned);
+        let mut candidates: BTreeSet<Id> = remaining
+            .iter()
+            .flat_map(|gap| skill_map.courses_teaching_at_least(&gap.skill, gap.required))
+            .collect();
+        let mut courses = Vec::new();
+
+        loop {
+            let closes = |course_id: Id, gap: &SkillGap| {
+                skill_map
+                    .level_taught(course_id, &gap.skill)
+                    .is_some_and(|level| level >= gap.required)
+            };
+
+            let best = candidates
+                .iter()
+                .map(|course_id| {
+                    let closed = remaining
+                        .iter()
+                        .filter(|gap| closes(*course_id, gap))
+                        .count();
+                    (*course_id, closed)
+                })
+                .filter(|(_, closed)| *closed > 0)
+                .min_by_key(|(course_id, closed)| (Reverse(*closed), *course_id));
+
+            let Some((course_id, _)) = best else {
+                break;
+            };
+
+            remaining.retain(|gap| !closes(course_id, gap));
+            candidates.remove(&course_id);
+            courses.push(course_id);
+        }
+
+        TrackRecommendation {
+            courses,
+            uncovered: remaining,
+        }
+    }
+}
+
+impl Entity for Track {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl PartialEq for Track {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Track {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_skill(name: &str) -> Skill {
+        Skill::new(name).unwrap()
+    }
+
+    fn create_test_track() -> Track {
+        Track::new(
+            "Data Engineer".to_string(),
+            vec![
+                (create_skill("sql"), ProficiencyLevel::Advanced),
+                (create_skill("python"), ProficiencyLevel::Intermediate),
+                (create_skill("spark"), ProficiencyLevel::Beginner),
+            ],
+        )
+        .unwrap()
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_keeps_highest_duplicate_level() {
+            let track = Track::new(
+                "Analyst".to_string(),
+                vec![
+                    (create_skill("sql"), ProficiencyLevel::Advanced),
+                    (create_skill("SQL"), ProficiencyLevel::Beginner),
+                ],
+            )
+            .unwrap();
+
+            assert_eq!(
+                track.required_skills().get(&create_skill("sql")),
+                Some(&ProficiencyLevel::Advanced)
+            );
+        }
+
+        #[test]
+        fn test_new_rejects_empty_requirements() {
+            let result = Track::new("Analyst".to_string(), vec![]);
+
+            assert_eq!(result.unwrap_err(), TrackError::NoRequiredSkills);
+        }
+
+        #[test]
+        fn test_new_rejects_empty_role() {
+            let result =
+                Track::new(String::new(), vec![(create_skill("sql"), ProficiencyLevel::Beginner)]);
+
+            assert!(matches!(result, Err(TrackError::RoleError(_))));
+        }
+    }
+
+    mod gaps {
+        use super::*;
+
+        #[test]
+        fn test_gaps_list_missing_and_insufficient_skills() {
+            let track = create_test_track();
+            let sql = create_skill("sql");
+            let spark = create_skill("spark");
+
+            let gaps = track.gaps(&[
+                (&sql, ProficiencyLevel::Intermediate),
+                (&spark, ProficiencyLevel::Expert),
+            ]);
+
+            let names: Vec<&str> = gaps.iter().map(|gap| gap.skill().name()).collect();
+            assert_eq!(names, vec!["python", "sql"]);
+            assert_eq!(gaps[0].current(), None);
+            assert_eq!(gaps[1].current(), Some(ProficiencyLevel::Intermediate));
+        }
+
+        #[test]
+        fn test_is_ready_when_all_requirements_met() {
+            let track = create_test_track();
+            let sql = create_skill("sql");
+            let python = create_skill("python");
+            let spark = create_skill("spark");
+
+            assert!(track.is_ready(&[
+                (&sql, ProficiencyLevel::Expert),
+                (&python, ProficiencyLevel::Intermediate),
+                (&spark, ProficiencyLevel::Beginner),
+            ]));
+        }
+    }
+
+    mod recommend_courses {
+        use super::*;
+
+        #[test]
+        fn test_prefers_course_closing_most_gaps() {
+            let track = create_test_track();
+            let sql_only = Id::new();
+            let python_only = Id::new();
+            let bundle = Id::new();
+            let mut map = CourseSkillMap::new();
+            map.teach(sql_only, create_skill("sql"), ProficiencyLevel::Advanced);
+            map.teach(python_only, create_skill("python"), ProficiencyLevel::Expert);
+            map.teach(bundle, create_skill("sql"), ProficiencyLevel::Advanced);
+            map.teach(bundle, create_skill("spark"), ProficiencyLevel::Beginner);
+
+            let recommendation = track.recommend_courses(&[], &map);
+
+            assert_eq!(recommendation.courses(), &[bundle, python_only]);
+            assert!(recommendation.uncovered().is_empty());
+        }
+
+        #[test]
+        fn test_ignores_courses_below_required_level() {
+            let track = create_test_track();
+            let mut map = CourseSkillMap::new();
+            map.teach(Id::new(), create_skill("sql"), ProficiencyLevel::Beginner);
+
+            let recommendation = track.recommend_courses(&[], &map);
+
+            assert!(recommendation.courses().is_empty());
+            assert_eq!(recommendation.uncovered().len(), 3);
+        }
+
+        #[test]
+        fn test_skips_skills_already_earned() {
+            let track = create_test_track();
+            let sql = create_skill("sql");
+            let python = create_skill("python");
+            let spark_course = Id::new();
+            let mut map = CourseSkillMap::new();
+            map.teach(Id::new(), sql.clone(), ProficiencyLevel::Expert);
+            map.teach(spark_course, create_skill("spark"), ProficiencyLevel::Beginner);
+
+            let recommendation = track.recommend_courses(
+                &[
+                    (&sql, ProficiencyLevel::Advanced),
+                    (&python, ProficiencyLevel::Advanced),
+                ],
+                &map,
+            );
+
+            assert_eq!(recommendation.courses(), &[spark_course]);
+        }
+    }
+}
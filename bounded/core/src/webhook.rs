@@ -0,0 +1,11 @@
+mod delivery;
+mod dispatcher;
+mod endpoint;
+mod hmac_signer;
+mod retry_policy;
+
+pub use delivery::*;
+pub use dispatcher::*;
+pub use endpoint::*;
+pub use hmac_signer::*;
+pub use retry_policy::*;
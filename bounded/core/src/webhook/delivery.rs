@@ -0,0 +1,264 @@
+use education_platform_common::{DateTime, Id, Url};
+
+/// A signed HTTP POST ready to be sent by a webhook transport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookRequest {
+    url: Url,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl WebhookRequest {
+    pub(crate) const fn new(url: Url, headers: Vec<(String, String)>, body: String) -> Self {
+        Self { url, headers, body }
+    }
+
+    /// Returns the URL to post to.
+    #[inline]
+    #[must_use]
+    pub const fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the headers to send, including the signature.
+    #[inline]
+    #[must_use]
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the value of a header, matched case-insensitively.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the JSON body.
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+/// What happened when a delivery was attempted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttemptOutcome {
+    /// The endpoint answered with this HTTP status code.
+    Response(u16),
+    /// The request never got an answer, for instance on a timeout.
+    TransportError(String),
+}
+
+impl AttemptOutcome {
+    /// Returns true if the endpoint acknowledged the event with a 2xx status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::AttemptOutcome;
+    ///
+    /// assert!(AttemptOutcome::Response(204).is_success());
+    /// assert!(!AttemptOutcome::Response(503).is_success());
+    /// assert!(!AttemptOutcome::TransportError("timeout".to_string()).is_success());
+    /// ```
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(self, Self::Response(200..=299))
+    }
+}
+
+/// One try at delivering an event to an endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryAttempt {
+    at: DateTime,
+    outcome: AttemptOutcome,
+}
+
+impl DeliveryAttempt {
+    /// Returns when the attempt was made.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self) -> DateTime {
+        self.at
+    }
+
+    /// Returns the result of the attempt.
+    #[inline]
+    #[must_use]
+    pub const fn outcome(&self) -> &AttemptOutcome {
+        &self.outcome
+    }
+}
+
+/// Where a webhook delivery stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// Waiting for its next attempt, due at the given time.
+    Pending { next_attempt_at: DateTime },
+    /// Acknowledged by the endpoint.
+    Delivered { at: DateTime },
+    /// Every allowed attempt failed; the event will not be sent again.
+    Failed,
+}
+
+/// The delivery of one event to one endpoint, with its attempt history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    id: Id,
+    endpoint_id: Id,
+    event_type: String,
+    body: String,
+    attempts: Vec<DeliveryAttempt>,
+    status: DeliveryStatus,
+}
+
+impl WebhookDelivery {
+    pub(crate) fn new(endpoint_id: Id, event_type: &str, body: String, now: DateTime) -> Self {
+        Self {
+            id: Id::default(),
+            endpoint_id,
+            event_type: event_type.to_string(),
+            body,
+            attempts: Vec::new(),
+            status: DeliveryStatus::Pending { next_attempt_at: now },
+        }
+    }
+
+    /// Returns the delivery ID, sent to endpoints so they can ignore duplicates.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the ID of the endpoint the event is delivered to.
+    #[inline]
+    #[must_use]
+    pub const fn endpoint_id(&self) -> Id {
+        self.endpoint_id
+    }
+
+    /// Returns the type of the delivered event.
+    #[inline]
+    #[must_use]
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    /// Returns the JSON body posted on every attempt.
+    #[inline]
+    #[must_use]
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Returns the attempts made so far, oldest first.
+    #[inline]
+    #[must_use]
+    pub fn attempts(&self) -> &[DeliveryAttempt] {
+        &self.attempts
+    }
+
+    /// Returns the current status.
+    #[inline]
+    #[must_use]
+    pub const fn status(&self) -> DeliveryStatus {
+        self.status
+    }
+
+    /// Returns true if the delivery is pending and its next attempt is due.
+    #[must_use]
+    pub fn is_due(&self, now: DateTime) -> bool {
+        matches!(self.status, DeliveryStatus::Pending { next_attempt_at } if next_attempt_at <= now)
+    }
+
+    /// Records an attempt and moves the delivery to its next status.
+    ///
+    /// `next_attempt_at` is when to retry if the attempt failed, or `None`
+    /// if no attempts are left.
+    pub(crate) fn record(
+        &mut self,
+        at: DateTime,
+        outcome: AttemptOutcome,
+        next_attempt_at: Option<DateTime>,
+    ) {
+        self.status = match (outcome.is_success(), next_attempt_at) {
+            (true, _) => DeliveryStatus::Delivered { at },
+            (false, Some(next_attempt_at)) => DeliveryStatus::Pending { next_attempt_at },
+            (false, None) => DeliveryStatus::Failed,
+        };
+        self.attempts.push(DeliveryAttempt { at, outcome });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_time(minute: u32) -> DateTime {
+        DateTime::new(2024, 6, 1, 12, minute, 0).unwrap()
+    }
+
+    fn create_test_delivery() -> WebhookDelivery {
+        WebhookDelivery::new(Id::new(), "course.ended", "{}".to_string(), create_time(0))
+    }
+
+    #[test]
+    fn test_new_delivery_is_due_immediately() {
+        let delivery = create_test_delivery();
+
+        assert!(delivery.is_due(create_time(0)));
+        assert!(delivery.attempts().is_empty());
+    }
+
+    #[test]
+    fn test_successful_attempt_marks_delivered() {
+        let mut delivery = create_test_delivery();
+
+        delivery.record(create_time(0), AttemptOutcome::Response(200), None);
+
+        assert_eq!(delivery.status(), DeliveryStatus::Delivered { at: create_time(0) });
+        assert!(!delivery.is_due(create_time(30)));
+    }
+
+    #[test]
+    fn test_failed_attempt_with_retry_stays_pending() {
+        let mut delivery = create_test_delivery();
+
+        delivery.record(create_time(0), AttemptOutcome::Response(500), Some(create_time(5)));
+
+        assert!(!delivery.is_due(create_time(4)));
+        assert!(delivery.is_due(create_time(5)));
+        assert_eq!(delivery.attempts().len(), 1);
+    }
+
+    #[test]
+    fn test_failed_attempt_without_retry_marks_failed() {
+        let mut delivery = create_test_delivery();
+
+        delivery.record(
+            create_time(0),
+            AttemptOutcome::TransportError("connection refused".to_string()),
+            None,
+        );
+
+        assert_eq!(delivery.status(), DeliveryStatus::Failed);
+        assert!(!delivery.is_due(create_time(59)));
+    }
+
+    #[test]
+    fn test_header_lookup_ignores_case() {
+        let request = WebhookRequest::new(
+            Url::new("https://hooks.example.com".to_string()).unwrap(),
+            vec![("Content-Type".to_string(), "application/json".to_string())],
+            "{}".to_string(),
+        );
+
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        assert_eq!(request.header("x-missing"), None);
+    }
+}
@@ -0,0 +1,639 @@
+use crate::{
+    AttemptOutcome, HmacSha256Signer, RetryPolicy, WebhookDelivery, WebhookEndpoint, WebhookError,
+    WebhookRequest,
+};
+use education_platform_common::{DateTime, Id};
+use serde::Serialize;
+
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+pub const DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+
+/// A domain event that can be published to webhook endpoints.
+///
+/// The event itself is serialized as the `data` field of the payload.
+pub trait WebhookEvent: Serialize {
+    /// Returns the event type endpoints subscribe to, such as `"course.ended"`.
+    fn event_type(&self) -> &str;
+}
+
+/// Port computing the signature endpoints use to authenticate payloads.
+///
+/// [`HmacSha256Signer`] is the standard implementation: an HMAC-SHA256 of
+/// the message with the endpoint secret, hex-encoded. The message is the
+/// request timestamp and the body joined by a dot, so a captured request
+/// cannot be replayed with a fresh timestamp.
+pub trait PayloadSigner {
+    /// Signs a message with an endpoint secret.
+    fn sign(&self, secret: &str, message: &str) -> String;
+}
+
+/// Port sending webhook requests over HTTP, blocking until answered.
+pub trait WebhookTransport {
+    /// Posts the request and reports how it went.
+    fn post(&self, request: &WebhookRequest) -> AttemptOutcome;
+}
+
+/// Port sending webhook requests over HTTP without blocking.
+#[cfg(feature = "async")]
+pub trait AsyncWebhookTransport {
+    /// Posts the request and reports how it went.
+    fn post(
+        &self,
+        request: &WebhookRequest,
+    ) -> impl std::future::Future<Output = AttemptOutcome> + Send;
+}
+
+#[derive(Serialize)]
+struct Envelope<'a, E> {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: &'a str,
+    occurred_at: String,
+    data: &'a E,
+}
+
+/// Delivers domain events to registered webhook endpoints, retrying failures.
+///
+/// Publishing is split in two steps. [`enqueue`](Self::enqueue) records a
+/// pending delivery for every endpoint subscribed to the event, and is cheap
+/// enough to call from a domain event observer. Deliveries are then sent by
+/// [`deliver_due`](Self::deliver_due), which a scheduler calls periodically;
+/// failed deliveries are retried on the [`RetryPolicy`] schedule until they
+/// succeed or run out of attempts. With the `async` feature, the
+/// `*_async` variants do the same over an `AsyncWebhookTransport`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{
+///     AttemptOutcome, DeliveryStatus, WebhookDispatcher, WebhookEndpoint, WebhookEvent,
+///     WebhookRequest, WebhookTransport,
+/// };
+/// use education_platform_common::DateTime;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct CourseEnded {
+///     course: String,
+/// }
+///
+/// impl WebhookEvent for CourseEnded {
+///     fn event_type(&self) -> &str {
+///         "course.ended"
+///     }
+/// }
+///
+/// struct AlwaysAccepted;
+///
+/// impl WebhookTransport for AlwaysAccepted {
+///     fn post(&self, _request: &WebhookRequest) -> AttemptOutcome {
+///         AttemptOutcome::Response(202)
+///     }
+/// }
+///
+/// let now = DateTime::new(2024, 6, 1, 12, 0, 0).unwrap();
+/// let mut webhooks = WebhookDispatcher::new(AlwaysAccepted);
+/// webhooks.register(WebhookEndpoint::new("https://hooks.example.com", "s3cr3t-signing-key").unwrap());
+///
+/// let ids = webhooks.enqueue(&CourseEnded { course: "Rust".to_string() }, now).unwrap();
+/// assert_eq!(webhooks.deliver_due(now), 1);
+///
+/// let delivery = webhooks.delivery(ids[0]).unwrap();
+/// assert_eq!(delivery.status(), DeliveryStatus::Delivered { at: now });
+/// ```
+#[derive(Debug)]
+pub struct WebhookDispatcher<T, S = HmacSha256Signer> {
+    transport: T,
+    signer: S,
+    policy: RetryPolicy,
+    endpoints: Vec<WebhookEndpoint>,
+    deliveries: Vec<WebhookDelivery>,
+}
+
+impl<T> WebhookDispatcher<T> {
+    /// Creates a dispatcher signing payloads with [`HmacSha256Signer`], with
+    /// no endpoints and the default retry policy.
+    #[must_use]
+    pub fn new(transport: T) -> Self {
+        Self::with_signer(transport, HmacSha256Signer)
+    }
+}
+
+impl<T, S: PayloadSigner> WebhookDispatcher<T, S> {
+    /// Creates a dispatcher signing payloads with `signer`, with no
+    /// endpoints and the default retry policy.
+    #[must_use]
+    pub fn with_signer(transport: T, signer: S) -> Self {
+        Self {
+            transport,
+            signer,
+            policy: RetryPolicy::default(),
+            endpoints: Vec::new(),
+            deliveries: Vec::new(),
+        }
+    }
+
+    /// Replaces the retry policy.
+    #[must_use]
+    pub const fn with_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Registers an endpoint, replacing any endpoint with the same ID.
+    pub fn register(&mut self, endpoint: WebhookEndpoint) {
+        self.endpoints
+            .retain(|existing| existing.id() != endpoint.id());
+        self.endpoints.push(endpoint);
+    }
+
+    /// Removes an endpoint. Its pending deliveries are abandoned on their next attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::EndpointNotFound` if no endpoint has this ID.
+    pub fn unregister(&mut self, endpoint_id: Id) -> Result<WebhookEndpoint, WebhookError> {
+        let index = self
+            .endpoints
+            .iter()
+            .position(|endpoint| endpoint.id() == endpoint_id)
+            .ok_or(WebhookError::EndpointNotFound(endpoint_id))?;
+        Ok(self.endpoints.remove(index))
+    }
+
+    /// Returns the registered endpoints.
+    #[inline]
+    #[must_use]
+    pub fn endpoints(&self) -> &[WebhookEndpoint] {
+        &self.endpoints
+    }
+
+    /// Returns every delivery, oldest first.
+    #[inline]
+    #[must_use]
+    pub fn deliveries(&self) -> &[WebhookDelivery] {
+        &self.deliveries
+    }
+
+    /// Returns a delivery by ID.
+    #[must_use]
+    pub fn delivery(&self, delivery_id: Id) -> Option<&WebhookDelivery> {
+        self.deliveries
+            .iter()
+            .find(|delivery| delivery.id() == delivery_id)
+    }
+
+    /// Records a pending delivery of the event to every subscribed endpoint.
+    ///
+    /// Returns the IDs of the new deliveries, empty if no endpoint is
+    /// subscribed to the event type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::Serialization` if the event cannot be serialized.
+    pub fn enqueue<E: WebhookEvent>(
+        &mut self,
+        event: &E,
+        occurred_at: DateTime,
+    ) -> Result<Vec<Id>, WebhookError> {
+        let event_type = event.event_type();
+        let body = serde_json::to_string(&Envelope {
+            id: Id::new().to_string(),
+            event_type,
+            occurred_at: occurred_at.format_iso(),
+            data: event,
+        })?;
+
+        let deliveries: Vec<WebhookDelivery> = self
+            .endpoints
+            .iter()
+            .filter(|endpoint| endpoint.accepts(event_type))
+            .map(|endpoint| {
+                WebhookDelivery::new(endpoint.id(), event_type, body.clone(), occurred_at)
+            })
+            .collect();
+
+        let ids = deliveries.iter().map(WebhookDelivery::id).collect();
+        self.deliveries.extend(deliveries);
+        Ok(ids)
+    }
+
+    /// Builds the signed requests for every delivery due at `now`, with their positions.
+    fn due_requests(&self, now: DateTime) -> Vec<(usize, Option<WebhookRequest>)> {
+        let timestamp = now.format_iso();
+
+        self.deliveries
+            .iter()
+            .enumerate()
+            .filter(|(_, delivery)| delivery.is_due(now))
+            .map(|(index, delivery)| {
+                let request = self
+                    .endpoints
+                    .iter()
+                    .find(|endpoint| endpoint.id() == delivery.endpoint_id())
+                    .map(|endpoint| {
+                        let message = format!("{timestamp}.{}", delivery.body());
+                        let headers = vec![
+                            ("Content-Type".to_string(), "application/json".to_string()),
+                            (DELIVERY_HEADER.to_string(), delivery.id().to_string()),
+                            (EVENT_HEADER.to_string(), delivery.event_type().to_string()),
+                            (TIMESTAMP_HEADER.to_string(), timestamp.clone()),
+                            (
+                                SIGNATURE_HEADER.to_string(),
+                                self.signer.sign(endpoint.secret(), &message),
+                            ),
+                        ];
+                        WebhookRequest::new(
+                            endpoint.url().clone(),
+                            headers,
+                            delivery.body().to_string(),
+                        )
+                    });
+                (index, request)
+            })
+            .collect()
+    }
+
+    fn record(&mut self, index: usize, outcome: AttemptOutcome, now: DateTime) {
+        if let Some(delivery) = self.deliveries.get_mut(index) {
+            let attempt = u32::try_from(delivery.attempts().len() + 1).unwrap_or(u32::MAX);
+            let next_attempt_at = self.policy.backoff_after(attempt).map(|wait| {
+                now.add_seconds(i64::try_from(wait.total_seconds()).unwrap_or(i64::MAX))
+            });
+            delivery.record(now, outcome, next_attempt_at);
+        }
+    }
+
+    fn abandon(&mut self, index: usize, now: DateTime) {
+        if let Some(delivery) = self.deliveries.get_mut(index) {
+            delivery.record(
+                now,
+                AttemptOutcome::TransportError("endpoint unregistered".to_string()),
+                None,
+            );
+        }
+    }
+}
+
+impl<T: WebhookTransport, S: PayloadSigner> WebhookDispatcher<T, S> {
+    /// Attempts every delivery due at `now` and returns how many were attempted.
+    pub fn deliver_due(&mut self, now: DateTime) -> usize {
+        let due = self.due_requests(now);
+        let count = due.len();
+
+        for (index, request) in due {
+            match request {
+                Some(request) => {
+                    let outcome = self.transport.post(&request);
+                    self.record(index, outcome, now);
+                }
+                None => self.abandon(index, now),
+            }
+        }
+        count
+    }
+
+    /// Enqueues the event and immediately attempts every due delivery.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::Serialization` if the event cannot be serialized.
+    pub fn dispatch<E: WebhookEvent>(
+        &mut self,
+        event: &E,
+        now: DateTime,
+    ) -> Result<Vec<Id>, WebhookError> {
+        let ids = self.enqueue(event, now)?;
+        self.deliver_due(now);
+        Ok(ids)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncWebhookTransport, S: PayloadSigner> WebhookDispatcher<T, S> {
+    /// Attempts every delivery due at `now`, one after the other, and
+    /// returns how many were attempted.
+    pub async fn deliver_due_async(&mut self, now: DateTime) -> usize {
+        let due = self.due_requests(now);
+        let count = due.len();
+
+        for (index, request) in due {
+            match request {
+                Some(request) => {
+                    let outcome = self.transport.post(&request).await;
+                    self.record(index, outcome, now);
+                }
+                None => self.abandon(index, now),
+            }
+        }
+        count
+    }
+
+    /// Enqueues the event and immediately attempts every due delivery.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::Serialization` if the event cannot be serialized.
+    pub async fn dispatch_async<E: WebhookEvent>(
+        &mut self,
+        event: &E,
+        now: DateTime,
+    ) -> Result<Vec<Id>, WebhookError> {
+        let ids = self.enqueue(event, now)?;
+        self.deliver_due_async(now).await;
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeliveryStatus;
+    use education_platform_common::Duration;
+    use std::cell::RefCell;
+
+    const SECRET: &str = "0123456789abcdef";
+
+    #[derive(Serialize)]
+    struct TestEvent {
+        kind: &'static str,
+        value: u32,
+    }
+
+    impl WebhookEvent for TestEvent {
+        fn event_type(&self) -> &str {
+            self.kind
+        }
+    }
+
+    /// Answers with the scripted status codes in order, then 200.
+    #[derive(Default)]
+    struct ScriptedTransport {
+        responses: RefCell<Vec<u16>>,
+        sent: RefCell<Vec<WebhookRequest>>,
+    }
+
+    impl ScriptedTransport {
+        fn answering(responses: &[u16]) -> Self {
+            Self {
+                responses: RefCell::new(responses.iter().rev().copied().collect()),
+                sent: RefCell::default(),
+            }
+        }
+    }
+
+    impl WebhookTransport for ScriptedTransport {
+        fn post(&self, request: &WebhookRequest) -> AttemptOutcome {
+            self.sent.borrow_mut().push(request.clone());
+            AttemptOutcome::Response(self.responses.borrow_mut().pop().unwrap_or(200))
+        }
+    }
+
+    struct ConcatSigner;
+
+    impl PayloadSigner for ConcatSigner {
+        fn sign(&self, secret: &str, message: &str) -> String {
+            format!("{secret}|{message}")
+        }
+    }
+
+    fn create_time(minute: u32) -> DateTime {
+        DateTime::new(2024, 6, 1, 12, minute, 0).unwrap()
+    }
+
+    fn create_test_event(kind: &'static str) -> TestEvent {
+        TestEvent { kind, value: 7 }
+    }
+
+    fn create_test_endpoint() -> WebhookEndpoint {
+        WebhookEndpoint::new("https://hooks.example.com/edu", SECRET).unwrap()
+    }
+
+    fn create_test_dispatcher(
+        transport: ScriptedTransport,
+    ) -> WebhookDispatcher<ScriptedTransport, ConcatSigner> {
+        let mut dispatcher = WebhookDispatcher::with_signer(transport, ConcatSigner).with_policy(
+            RetryPolicy::new(3, Duration::from_minutes(1), Duration::from_minutes(10)),
+        );
+        dispatcher.register(create_test_endpoint());
+        dispatcher
+    }
+
+    mod enqueue {
+        use super::*;
+
+        #[test]
+        fn test_creates_delivery_per_subscribed_endpoint() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::default());
+            dispatcher.register(
+                WebhookEndpoint::new("https://other.example.com", SECRET)
+                    .unwrap()
+                    .subscribed_to(["lesson.started"]),
+            );
+
+            let ids = dispatcher
+                .enqueue(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            assert_eq!(ids.len(), 1);
+            assert_eq!(dispatcher.deliveries().len(), 1);
+        }
+
+        #[test]
+        fn test_body_wraps_event_in_envelope() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::default());
+
+            let ids = dispatcher
+                .enqueue(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            let body: serde_json::Value =
+                serde_json::from_str(dispatcher.delivery(ids[0]).unwrap().body()).unwrap();
+            assert_eq!(body["type"], "course.ended");
+            assert_eq!(body["occurred_at"], "2024-06-01T12:00:00");
+            assert_eq!(body["data"]["value"], 7);
+            assert!(body["id"].is_string());
+        }
+
+        #[test]
+        fn test_does_not_send() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::default());
+
+            dispatcher
+                .enqueue(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            assert!(dispatcher.transport.sent.borrow().is_empty());
+        }
+    }
+
+    mod deliver_due {
+        use super::*;
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        #[test]
+        fn test_sends_signed_request() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::default());
+            let ids = dispatcher
+                .dispatch(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            let sent = dispatcher.transport.sent.borrow();
+            let request = &sent[0];
+            let body = dispatcher.delivery(ids[0]).unwrap().body();
+            assert_eq!(request.url().as_str(), "https://hooks.example.com/edu");
+            assert_eq!(request.body(), body);
+            assert_eq!(request.header(EVENT_HEADER), Some("course.ended"));
+            assert_eq!(request.header(TIMESTAMP_HEADER), Some("2024-06-01T12:00:00"));
+            assert_eq!(
+                request.header(SIGNATURE_HEADER),
+                Some(format!("{SECRET}|2024-06-01T12:00:00.{body}").as_str())
+            );
+            assert_eq!(request.header(DELIVERY_HEADER), Some(ids[0].to_string().as_str()));
+        }
+
+        #[test]
+        fn test_new_signs_with_hmac_sha256() {
+            let mut dispatcher = WebhookDispatcher::new(ScriptedTransport::default());
+            dispatcher.register(create_test_endpoint());
+            let ids = dispatcher
+                .dispatch(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            let sent = dispatcher.transport.sent.borrow();
+            let body = dispatcher.delivery(ids[0]).unwrap().body();
+            let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+            mac.update(format!("2024-06-01T12:00:00.{body}").as_bytes());
+            let expected: String = mac
+                .finalize()
+                .into_bytes()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect();
+            assert_eq!(sent[0].header(SIGNATURE_HEADER), Some(expected.as_str()));
+        }
+
+        #[test]
+        fn test_failure_is_retried_after_backoff() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::answering(&[503]));
+            let ids = dispatcher
+                .dispatch(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            assert_eq!(
+                dispatcher.delivery(ids[0]).unwrap().status(),
+                DeliveryStatus::Pending {
+                    next_attempt_at: create_time(1)
+                }
+            );
+            assert_eq!(dispatcher.deliver_due(create_time(0)), 0);
+
+            assert_eq!(dispatcher.deliver_due(create_time(1)), 1);
+            assert_eq!(
+                dispatcher.delivery(ids[0]).unwrap().status(),
+                DeliveryStatus::Delivered { at: create_time(1) }
+            );
+        }
+
+        #[test]
+        fn test_gives_up_after_max_attempts() {
+            let mut dispatcher =
+                create_test_dispatcher(ScriptedTransport::answering(&[500, 500, 500]));
+            let ids = dispatcher
+                .dispatch(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            dispatcher.deliver_due(create_time(1));
+            dispatcher.deliver_due(create_time(3));
+
+            let delivery = dispatcher.delivery(ids[0]).unwrap();
+            assert_eq!(delivery.status(), DeliveryStatus::Failed);
+            assert_eq!(delivery.attempts().len(), 3);
+            assert_eq!(dispatcher.deliver_due(create_time(59)), 0);
+        }
+
+        #[test]
+        fn test_unregistered_endpoint_abandons_pending_delivery() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::default());
+            let endpoint_id = dispatcher.endpoints()[0].id();
+            let ids = dispatcher
+                .enqueue(&create_test_event("course.ended"), create_time(0))
+                .unwrap();
+
+            dispatcher.unregister(endpoint_id).unwrap();
+            dispatcher.deliver_due(create_time(0));
+
+            assert_eq!(dispatcher.delivery(ids[0]).unwrap().status(), DeliveryStatus::Failed);
+            assert!(dispatcher.transport.sent.borrow().is_empty());
+        }
+    }
+
+    mod endpoints {
+        use super::*;
+
+        #[test]
+        fn test_register_replaces_same_id() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::default());
+            let id = dispatcher.endpoints()[0].id();
+
+            dispatcher
+                .register(WebhookEndpoint::with_id(id, "https://new.example.com", SECRET).unwrap());
+
+            assert_eq!(dispatcher.endpoints().len(), 1);
+            assert_eq!(dispatcher.endpoints()[0].url().as_str(), "https://new.example.com");
+        }
+
+        #[test]
+        fn test_unregister_unknown_endpoint_fails() {
+            let mut dispatcher = create_test_dispatcher(ScriptedTransport::default());
+            let id = Id::new();
+
+            assert_eq!(dispatcher.unregister(id), Err(WebhookError::EndpointNotFound(id)));
+        }
+    }
+
+    #[cfg(feature = "async")]
+    mod deliver_due_async {
+        use super::*;
+        use std::future::Future;
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        struct ReadyTransport;
+
+        impl AsyncWebhookTransport for ReadyTransport {
+            async fn post(&self, _request: &WebhookRequest) -> AttemptOutcome {
+                AttemptOutcome::Response(204)
+            }
+        }
+
+        fn block_on<F: Future>(future: F) -> F::Output {
+            let mut future = pin!(future);
+            let mut context = Context::from_waker(Waker::noop());
+            loop {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                    return output;
+                }
+            }
+        }
+
+        #[test]
+        fn test_dispatch_async_delivers() {
+            let mut dispatcher = WebhookDispatcher::with_signer(ReadyTransport, ConcatSigner);
+            dispatcher.register(create_test_endpoint());
+
+            let ids = block_on(
+                dispatcher.dispatch_async(&create_test_event("course.ended"), create_time(0)),
+            )
+            .unwrap();
+
+            assert_eq!(
+                dispatcher.delivery(ids[0]).unwrap().status(),
+                DeliveryStatus::Delivered { at: create_time(0) }
+            );
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use education_platform_common::{Id, Url, UrlError};
+use std::fmt;
+use thiserror::Error;
+
+/// Error types for webhook configuration failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WebhookError {
+    #[error("Webhook URL validation failed: {0}")]
+    UrlError(#[from] UrlError),
+
+    #[error("Webhook endpoints must use HTTPS")]
+    InsecureUrl,
+
+    #[error("Webhook signing secret must be at least {min} characters")]
+    SecretTooShort { min: usize },
+
+    #[error("Webhook endpoint not found: {0}")]
+    EndpointNotFound(Id),
+
+    #[error("Webhook payload could not be serialized: {0}")]
+    Serialization(String),
+}
+
+impl From<serde_json::Error> for WebhookError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Serialization(error.to_string())
+    }
+}
+
+/// A receiver registered to get domain events as signed HTTP POSTs.
+///
+/// An endpoint subscribed to no event types receives every event. Its
+/// `Debug` output leaves out the signing secret.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::WebhookEndpoint;
+///
+/// let endpoint = WebhookEndpoint::new("https://hooks.example.com/edu", "s3cr3t-signing-key")
+///     .unwrap()
+///     .subscribed_to(["course.ended"]);
+///
+/// assert!(endpoint.accepts("course.ended"));
+/// assert!(!endpoint.accepts("recording.published"));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct WebhookEndpoint {
+    id: Id,
+    url: Url,
+    secret: String,
+    event_types: Vec<String>,
+}
+
+impl WebhookEndpoint {
+    pub const MIN_SECRET_LENGTH: usize = 16;
+
+    /// Creates an endpoint receiving every event type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebhookError::UrlError` if the URL is invalid,
+    /// `WebhookError::InsecureUrl` if it is not HTTPS, or
+    /// `WebhookError::SecretTooShort` if the secret is shorter than
+    /// [`WebhookEndpoint::MIN_SECRET_LENGTH`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::{WebhookEndpoint, WebhookError};
+    ///
+    /// assert_eq!(
+    ///     WebhookEndpoint::new("http://hooks.example.com", "s3cr3t-signing-key"),
+    ///     Err(WebhookError::InsecureUrl)
+    /// );
+    /// ```
+    pub fn new(url: &str, secret: &str) -> Result<Self, WebhookError> {
+        Self::with_id(Id::default(), url, secret)
+    }
+
+    /// Creates an endpoint with a specific ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`WebhookEndpoint::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::WebhookEndpoint;
+    /// use education_platform_common::Id;
+    ///
+    /// let id = Id::new();
+    /// let endpoint = WebhookEndpoint::with_id(id, "https://hooks.example.com", "s3cr3t-signing-key")
+    ///     .unwrap();
+    /// assert_eq!(endpoint.id(), id);
+    /// ```
+    pub fn with_id(id: Id, url: &str, secret: &str) -> Result<Self, WebhookError> {
+        let url = Url::new(url.to_string())?;
+        if !url.is_secure() {
+            return Err(WebhookError::InsecureUrl);
+        }
+        if secret.chars().count() < Self::MIN_SECRET_LENGTH {
+            return Err(WebhookError::SecretTooShort {
+                min: Self::MIN_SECRET_LENGTH,
+            });
+        }
+
+        Ok(Self {
+            id,
+            url,
+            secret: secret.to_string(),
+            event_types: Vec::new(),
+        })
+    }
+
+    /// Restricts the endpoint to the given event types.
+    #[must_use]
+    pub fn subscribed_to<I, S>(mut self, event_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.event_types = event_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the endpoint ID.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the URL payloads are posted to.
+    #[inline]
+    #[must_use]
+    pub const fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the secret payloads are signed with.
+    #[inline]
+    #[must_use]
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// Returns the subscribed event types; empty means all of them.
+    #[inline]
+    #[must_use]
+    pub fn event_types(&self) -> &[String] {
+        &self.event_types
+    }
+
+    /// Returns true if events of this type should be delivered to the endpoint.
+    #[must_use]
+    pub fn accepts(&self, event_type: &str) -> bool {
+        self.event_types.is_empty() || self.event_types.iter().any(|t| t == event_type)
+    }
+}
+
+impl fmt::Debug for WebhookEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookEndpoint")
+            .field("id", &self.id)
+            .field("url", &self.url)
+            .field("secret", &"<redacted>")
+            .field("event_types", &self.event_types)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef";
+
+    #[test]
+    fn test_new_accepts_https_url() {
+        let endpoint = WebhookEndpoint::new("https://hooks.example.com/edu", SECRET).unwrap();
+
+        assert_eq!(endpoint.url().as_str(), "https://hooks.example.com/edu");
+        assert_eq!(endpoint.secret(), SECRET);
+    }
+
+    #[test]
+    fn test_invalid_url_is_rejected() {
+        assert!(matches!(
+            WebhookEndpoint::new("not a url", SECRET),
+            Err(WebhookError::UrlError(_))
+        ));
+    }
+
+    #[test]
+    fn test_short_secret_is_rejected() {
+        assert_eq!(
+            WebhookEndpoint::new("https://hooks.example.com", "short"),
+            Err(WebhookError::SecretTooShort { min: 16 })
+        );
+    }
+
+    #[test]
+    fn test_endpoint_without_subscriptions_accepts_everything() {
+        let endpoint = WebhookEndpoint::new("https://hooks.example.com", SECRET).unwrap();
+
+        assert!(endpoint.accepts("anything"));
+    }
+
+    #[test]
+    fn test_subscribed_endpoint_filters_event_types() {
+        let endpoint = WebhookEndpoint::new("https://hooks.example.com", SECRET)
+            .unwrap()
+            .subscribed_to(["course.ended", "recording.published"]);
+
+        assert!(endpoint.accepts("recording.published"));
+        assert!(!endpoint.accepts("lesson.started"));
+        assert_eq!(endpoint.event_types().len(), 2);
+    }
+
+    #[test]
+    fn test_debug_hides_secret() {
+        let endpoint = WebhookEndpoint::new("https://hooks.example.com", SECRET).unwrap();
+
+        let output = format!("{endpoint:?}");
+
+        assert!(output.contains("hooks.example.com"));
+        assert!(!output.contains(SECRET));
+    }
+}
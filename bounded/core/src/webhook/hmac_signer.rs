@@ -0,0 +1,73 @@
+use crate::PayloadSigner;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt::Write;
+
+/// Signs webhook payloads with HMAC-SHA256, hex-encoded in lowercase.
+///
+/// This is the signer [`WebhookDispatcher::new`](crate::WebhookDispatcher::new)
+/// uses. Receivers verify a request by computing the same HMAC over
+/// `"{timestamp}.{body}"` with their copy of the endpoint secret.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::{HmacSha256Signer, PayloadSigner};
+///
+/// let signature = HmacSha256Signer.sign("Jefe", "what do ya want for nothing?");
+/// assert_eq!(
+///     signature,
+///     "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HmacSha256Signer;
+
+impl PayloadSigner for HmacSha256Signer {
+    fn sign(&self, secret: &str, message: &str) -> String {
+        // HMAC accepts keys of any length, so creating the MAC cannot fail.
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .map(|mut mac| {
+                mac.update(message.as_bytes());
+                mac.finalize().into_bytes().iter().fold(
+                    String::with_capacity(64),
+                    |mut hex, byte| {
+                        let _ = write!(hex, "{byte:02x}");
+                        hex
+                    },
+                )
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod sign {
+        use super::*;
+
+        #[test]
+        fn test_matches_rfc_4231_vectors() {
+            assert_eq!(
+                HmacSha256Signer.sign("Jefe", "what do ya want for nothing?"),
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+            );
+            let key = String::from_utf8(vec![0x0b; 20]).unwrap();
+            assert_eq!(
+                HmacSha256Signer.sign(&key, "Hi There"),
+                "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+            );
+        }
+
+        #[test]
+        fn test_depends_on_secret_and_message() {
+            let signature = HmacSha256Signer.sign("secret", "message");
+
+            assert_ne!(HmacSha256Signer.sign("secret2", "message"), signature);
+            assert_ne!(HmacSha256Signer.sign("secret", "message2"), signature);
+            assert_eq!(signature.len(), 64);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use education_platform_common::Duration;
+
+/// How often and how long to retry a webhook delivery that failed.
+///
+/// The wait doubles after each failed attempt, starting at
+/// `initial_backoff` and capped at `max_backoff`.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::RetryPolicy;
+/// use education_platform_common::Duration;
+///
+/// let policy = RetryPolicy::new(4, Duration::from_seconds(30), Duration::from_minutes(1));
+///
+/// assert_eq!(policy.backoff_after(1), Some(Duration::from_seconds(30)));
+/// assert_eq!(policy.backoff_after(2), Some(Duration::from_minutes(1)));
+/// assert_eq!(policy.backoff_after(3), Some(Duration::from_minutes(1)));
+/// assert_eq!(policy.backoff_after(4), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making at most `max_attempts` attempts, the first included.
+    ///
+    /// At least one attempt is always made.
+    #[must_use]
+    pub fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Returns the maximum number of attempts.
+    #[inline]
+    #[must_use]
+    pub const fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns how long to wait after the given failed attempt, or `None`
+    /// once no attempts are left.
+    ///
+    /// Attempts are numbered from 1.
+    #[must_use]
+    pub fn backoff_after(&self, attempt: u32) -> Option<Duration> {
+        match attempt >= self.max_attempts {
+            true => None,
+            false => {
+                let factor = 1_u64
+                    .checked_shl(attempt.saturating_sub(1))
+                    .unwrap_or(u64::MAX);
+                let seconds = self
+                    .initial_backoff
+                    .total_seconds()
+                    .saturating_mul(factor)
+                    .min(self.max_backoff.total_seconds());
+                Some(Duration::from_seconds(seconds))
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Five attempts spread over about an hour and a half.
+    fn default() -> Self {
+        Self::new(5, Duration::from_minutes(1), Duration::from_hours(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_until_cap() {
+        let policy = RetryPolicy::new(10, Duration::from_seconds(10), Duration::from_seconds(60));
+
+        let waits: Vec<u64> = (1..10)
+            .filter_map(|attempt| policy.backoff_after(attempt))
+            .map(|wait| wait.total_seconds())
+            .collect();
+
+        assert_eq!(waits, vec![10, 20, 40, 60, 60, 60, 60, 60, 60]);
+    }
+
+    #[test]
+    fn test_single_attempt_policy_never_retries() {
+        let policy = RetryPolicy::new(0, Duration::from_seconds(10), Duration::from_seconds(60));
+
+        assert_eq!(policy.max_attempts(), 1);
+        assert_eq!(policy.backoff_after(1), None);
+    }
+
+    #[test]
+    fn test_large_attempt_numbers_do_not_overflow() {
+        let policy = RetryPolicy::new(u32::MAX, Duration::from_hours(1), Duration::from_hours(2));
+
+        assert_eq!(policy.backoff_after(200), Some(Duration::from_hours(2)));
+    }
+
+    #[test]
+    fn test_default_allows_five_attempts() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(policy.backoff_after(4), Some(Duration::from_minutes(8)));
+        assert_eq!(policy.backoff_after(5), None);
+    }
+}
@@ -0,0 +1,3 @@
+mod wishlist;
+
+pub use wishlist::*;
@@ -0,0 +1,431 @@
+use crate::CourseId;
+use education_platform_common::{Auditable, DateTime, Email, Entity, Id, Index, Timestamps};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Error types for Wishlist validation failures.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WishlistError {
+    #[error("Course {0} is already in the wishlist")]
+    DuplicateCourse(String),
+
+    #[error("Course is not in the wishlist")]
+    CourseNotInWishlist,
+}
+
+/// The courses a user would like to take, in the order they rank them.
+///
+/// `Wishlist` is an aggregate root with one instance per user. It only
+/// refers to courses by ID and never lists a course twice; the user may
+/// reorder it freely. Courses the user is already taking are marked as
+/// favorites on their [`CourseProgress`](crate::CourseProgress) instead.
+///
+/// # Examples
+///
+/// ```
+/// use education_platform_core::Wishlist;
+/// use education_platform_common::{Email, Id};
+///
+/// let user = Email::new("ana@example.com".to_string()).unwrap();
+/// let rust = Id::new().cast();
+/// let sql = Id::new().cast();
+///
+/// let mut wishlist = Wishlist::new(user);
+/// wishlist.add_course(rust, None).unwrap();
+/// wishlist.add_course(sql, None).unwrap();
+/// wishlist.move_course_up(sql).unwrap();
+///
+/// assert_eq!(wishlist.courses(), &[sql, rust]);
+/// assert!(wishlist.add_course(rust, None).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Wishlist {
+    id: Id,
+    user: Email,
+    courses: Vec<CourseId>,
+    timestamps: Timestamps,
+}
+
+/// Identifier of a [`Wishlist`].
+pub type WishlistId = Id<Wishlist>;
+
+impl Wishlist {
+    /// Creates an empty wishlist for a user.
+    #[must_use]
+    pub fn new(user: Email) -> Self {
+        Self {
+            id: Id::default(),
+            user,
+            courses: Vec::new(),
+            timestamps: Timestamps::now(),
+        }
+    }
+
+    /// Creates a `Wishlist` with a specific ID and courses (for
+    /// reconstruction from persistence).
+    ///
+    /// # Errors
+    ///
+    /// Returns `WishlistError::DuplicateCourse` if a course is listed twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Wishlist;
+    /// use education_platform_common::{Email, Entity, Id};
+    ///
+    /// let id = Id::new();
+    /// let user = Email::new("ana@example.com".to_string()).unwrap();
+    /// let course = Id::new().cast();
+    ///
+    /// let wishlist = Wishlist::with_id(id, user.clone(), vec![course]).unwrap();
+    ///
+    /// assert_eq!(wishlist.id(), id);
+    /// assert!(Wishlist::with_id(id, user, vec![course, course]).is_err());
+    /// ```
+    pub fn with_id(id: Id, user: Email, courses: Vec<CourseId>) -> Result<Self, WishlistError> {
+        let mut seen = HashSet::with_capacity(courses.len());
+        if let Some(duplicate) = courses.iter().find(|course| !seen.insert(**course)) {
+            return Err(WishlistError::DuplicateCourse(duplicate.to_string()));
+        }
+
+        Ok(Self {
+            id,
+            user,
+            courses,
+            timestamps: Timestamps::now(),
+        })
+    }
+
+    /// Returns the wishlist ID typed as a [`WishlistId`].
+    #[inline]
+    #[must_use]
+    pub const fn wishlist_id(&self) -> WishlistId {
+        self.id.cast()
+    }
+
+    /// Returns the user the wishlist belongs to.
+    #[inline]
+    #[must_use]
+    pub const fn user(&self) -> &Email {
+        &self.user
+    }
+
+    /// Returns the wished courses, highest ranked first.
+    #[inline]
+    #[must_use]
+    pub fn courses(&self) -> &[CourseId] {
+        &self.courses
+    }
+
+    /// Returns the number of courses in the wishlist.
+    #[inline]
+    #[must_use]
+    pub fn course_quantity(&self) -> usize {
+        self.courses.len()
+    }
+
+    /// Returns `true` if the wishlist has no courses.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.courses.is_empty()
+    }
+
+    /// Returns `true` if the course is in the wishlist.
+    #[inline]
+    #[must_use]
+    pub fn contains_course(&self, course_id: CourseId) -> bool {
+        self.courses.contains(&course_id)
+    }
+
+    /// Adds a course to the wishlist at the specified position.
+    ///
+    /// If `index` is `None`, the course is appended at the end; an index past
+    /// the end appends as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WishlistError::DuplicateCourse` if the course is already in
+    /// the wishlist.
+    pub fn add_course(
+        &mut self,
+        course_id: CourseId,
+        index: Option<Index>,
+    ) -> Result<(), WishlistError> {
+        if self.contains_course(course_id) {
+            return Err(WishlistError::DuplicateCourse(course_id.to_string()));
+        }
+
+        let position = index
+            .map(|idx| idx.value().min(self.courses.len()))
+            .unwrap_or(self.courses.len());
+        self.courses.insert(position, course_id);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Removes a course from the wishlist, typically once the user enrolls.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WishlistError::CourseNotInWishlist` if the course is not in
+    /// the wishlist.
+    pub fn remove_course(&mut self, course_id: CourseId) -> Result<(), WishlistError> {
+        let position = self.position_of(course_id)?;
+        self.courses.remove(position);
+        self.timestamps.touch();
+        Ok(())
+    }
+
+    /// Moves a course to a specific index position in the wishlist; an
+    /// index past the end moves it last.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WishlistError::CourseNotInWishlist` if the course is not in
+    /// the wishlist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use education_platform_core::Wishlist;
+    /// use education_platform_common::{Email, Id, Index};
+    ///
+    /// let user = Email::new("ana@example.com".to_string()).unwrap();
+    /// let courses: Vec<_> = (0..3).map(|_| Id::new().cast()).collect();
+    /// let mut wishlist = Wishlist::with_id(Id::new(), user, courses.clone()).unwrap();
+    ///
+    /// wishlist.move_course(courses[2], Index::new(0)).unwrap();
+    ///
+    /// assert_eq!(wishlist.courses(), &[courses[2], courses[0], courses[1]]);
+    /// ```
+    pub fn move_course(
+        &mut self,
+        course_id: CourseId,
+        to_index: Index,
+    ) -> Result<(), WishlistError> {
+        let position = self.position_of(course_id)?;
+
+        self.courses.remove(position);
+        let target = to_index.value().min(self.courses.len());
+        self.courses.insert(target, course_id);
+        if target != position {
+            self.timestamps.touch();
+        }
+        Ok(())
+    }
+
+    /// Moves a course one position up (towards the top) in the wishlist.
+    ///
+    /// The first course stays where it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WishlistError::CourseNotInWishlist` if the course is not in
+    /// the wishlist.
+    pub fn move_course_up(&mut self, course_id: CourseId) -> Result<(), WishlistError> {
+        let current_position = self.position_of(course_id)?;
+
+        if current_position == 0 {
+            return Ok(());
+        }
+
+        self.move_course(course_id, Index::new(current_position - 1))
+    }
+
+    /// Moves a course one position down (towards the bottom) in the
+    /// wishlist.
+    ///
+    /// The last course stays where it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WishlistError::CourseNotInWishlist` if the course is not in
+    /// the wishlist.
+    pub fn move_course_down(&mut self, course_id: CourseId) -> Result<(), WishlistError> {
+        let current_position = self.position_of(course_id)?;
+
+        if current_position >= self.courses.len() - 1 {
+            return Ok(());
+        }
+
+        self.move_course(course_id, Index::new(current_position + 1))
+    }
+
+    fn position_of(&self, course_id: CourseId) -> Result<usize, WishlistError> {
+        self.courses
+            .iter()
+            .position(|course| *course == course_id)
+            .ok_or(WishlistError::CourseNotInWishlist)
+    }
+}
+
+impl Entity for Wishlist {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Auditable for Wishlist {
+    fn created_at(&self) -> DateTime {
+        self.timestamps.created_at()
+    }
+
+    fn updated_at(&self) -> DateTime {
+        self.timestamps.updated_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> Email {
+        Email::new("ana@example.com".to_string()).unwrap()
+    }
+
+    fn create_test_wishlist(quantity: usize) -> (Wishlist, Vec<CourseId>) {
+        let courses: Vec<CourseId> = (0..quantity).map(|_| Id::new().cast()).collect();
+        let wishlist = Wishlist::with_id(Id::new(), user(), courses.clone()).unwrap();
+        (wishlist, courses)
+    }
+
+    mod constructors {
+        use super::*;
+
+        #[test]
+        fn test_new_is_empty() {
+            let wishlist = Wishlist::new(user());
+
+            assert!(wishlist.is_empty());
+            assert_eq!(wishlist.user(), &user());
+            assert_eq!(wishlist.wishlist_id().untyped(), wishlist.id());
+        }
+
+        #[test]
+        fn test_with_id_rejects_duplicates() {
+            let course = Id::new().cast();
+
+            let result = Wishlist::with_id(Id::new(), user(), vec![course, course]);
+
+            assert!(matches!(
+                result,
+                Err(WishlistError::DuplicateCourse(id)) if id == course.to_string()
+            ));
+        }
+    }
+
+    mod add_course {
+        use super::*;
+
+        #[test]
+        fn test_appends_without_index() {
+            let (mut wishlist, courses) = create_test_wishlist(2);
+            let new_course = Id::new().cast();
+
+            wishlist.add_course(new_course, None).unwrap();
+
+            assert_eq!(wishlist.courses(), &[courses[0], courses[1], new_course]);
+        }
+
+        #[test]
+        fn test_inserts_at_index() {
+            let (mut wishlist, courses) = create_test_wishlist(2);
+            let new_course = Id::new().cast();
+
+            wishlist
+                .add_course(new_course, Some(Index::new(0)))
+                .unwrap();
+
+            assert_eq!(wishlist.courses(), &[new_course, courses[0], courses[1]]);
+        }
+
+        #[test]
+        fn test_duplicate_is_rejected() {
+            let (mut wishlist, courses) = create_test_wishlist(2);
+
+            let result = wishlist.add_course(courses[0], Some(Index::new(1)));
+
+            assert_eq!(result, Err(WishlistError::DuplicateCourse(courses[0].to_string())));
+            assert_eq!(wishlist.courses(), courses.as_slice());
+        }
+    }
+
+    mod remove_course {
+        use super::*;
+
+        #[test]
+        fn test_removes_the_last_course() {
+            let (mut wishlist, courses) = create_test_wishlist(1);
+
+            wishlist.remove_course(courses[0]).unwrap();
+
+            assert!(wishlist.is_empty());
+            assert!(!wishlist.contains_course(courses[0]));
+        }
+
+        #[test]
+        fn test_unknown_course() {
+            let (mut wishlist, _) = create_test_wishlist(1);
+
+            let result = wishlist.remove_course(Id::new().cast());
+
+            assert_eq!(result, Err(WishlistError::CourseNotInWishlist));
+            assert_eq!(wishlist.course_quantity(), 1);
+        }
+    }
+
+    mod reorder {
+        use super::*;
+
+        #[test]
+        fn test_move_course_past_end_moves_last() {
+            let (mut wishlist, courses) = create_test_wishlist(3);
+
+            wishlist.move_course(courses[0], Index::new(99)).unwrap();
+
+            assert_eq!(wishlist.courses(), &[courses[1], courses[2], courses[0]]);
+        }
+
+        #[test]
+        fn test_move_course_up_and_down() {
+            let (mut wishlist, courses) = create_test_wishlist(3);
+
+            wishlist.move_course_up(courses[2]).unwrap();
+            wishlist.move_course_down(courses[0]).unwrap();
+
+            assert_eq!(wishlist.courses(), &[courses[2], courses[0], courses[1]]);
+        }
+
+        #[test]
+        fn test_ends_stay_in_place() {
+            let (mut wishlist, courses) = create_test_wishlist(2);
+
+            wishlist.move_course_up(courses[0]).unwrap();
+            wishlist.move_course_down(courses[1]).unwrap();
+
+            assert_eq!(wishlist.courses(), courses.as_slice());
+        }
+
+        #[test]
+        fn test_unknown_course() {
+            let (mut wishlist, _) = create_test_wishlist(2);
+            let unknown = Id::new().cast();
+
+            assert_eq!(
+                wishlist.move_course(unknown, Index::new(0)),
+                Err(WishlistError::CourseNotInWishlist)
+            );
+            assert_eq!(
+                wishlist.move_course_up(unknown),
+                Err(WishlistError::CourseNotInWishlist)
+            );
+            assert_eq!(
+                wishlist.move_course_down(unknown),
+                Err(WishlistError::CourseNotInWishlist)
+            );
+        }
+    }
+}
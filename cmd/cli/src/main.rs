@@ -0,0 +1,654 @@
+use argon2::{
+    Argon2, Params, Version,
+    password_hash::{PasswordHasher, SaltString, rand_core::OsRng},
+};
+use clap::{Args, Parser, Subcommand};
+use education_platform::prelude::{
+    Chapter, Course, CourseProgress, DateTime, Entity, Lesson, User,
+};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+type CliResult = Result<String, Box<dyn Error>>;
+
+/// Non-interactive entry point to the education platform, for scripts and CI.
+///
+/// Every command validates its input through the domain crates and works on
+/// files named on the command line: courses are kept in the course JSON
+/// format, progress and users in small JSON documents of their own.
+#[derive(Debug, Parser)]
+#[command(name = "edu", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Manage courses.
+    #[command(subcommand)]
+    Course(CourseCommand),
+
+    /// Manage lessons.
+    #[command(subcommand)]
+    Lesson(LessonCommand),
+
+    /// Track learner progress.
+    #[command(subcommand)]
+    Progress(ProgressCommand),
+
+    /// Manage users.
+    #[command(subcommand)]
+    User(UserCommand),
+}
+
+#[derive(Debug, Subcommand)]
+enum CourseCommand {
+    /// Create a course with a single chapter and write it to a file.
+    Create(CourseCreateArgs),
+}
+
+#[derive(Debug, Subcommand)]
+enum LessonCommand {
+    /// Add a lesson to a chapter of a course file.
+    Add(LessonAddArgs),
+}
+
+#[derive(Debug, Subcommand)]
+enum ProgressCommand {
+    /// Complete a lesson and save the learner's progress.
+    Complete(ProgressCompleteArgs),
+}
+
+#[derive(Debug, Subcommand)]
+enum UserCommand {
+    /// Register a user in a users file.
+    Register(UserRegisterArgs),
+}
+
+#[derive(Debug, Args)]
+struct CourseCreateArgs {
+    /// Course name.
+    #[arg(long)]
+    name: String,
+
+    /// Name of the course's first chapter.
+    #[arg(long, default_value = "Chapter 1")]
+    chapter: String,
+
+    /// Lesson as `name|duration_seconds|video_url`. Repeat for more lessons.
+    #[arg(long = "lesson", required = true, value_parser = parse_lesson_spec)]
+    lessons: Vec<LessonSpec>,
+
+    /// Course JSON file to write; an existing file is replaced.
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct LessonAddArgs {
+    /// Course JSON file to update.
+    #[arg(long)]
+    course: PathBuf,
+
+    /// Name of the chapter receiving the lesson.
+    #[arg(long)]
+    chapter: String,
+
+    /// Lesson name.
+    #[arg(long)]
+    name: String,
+
+    /// Lesson duration in seconds.
+    #[arg(long)]
+    duration: u64,
+
+    /// URL of the lesson video.
+    #[arg(long)]
+    video_url: String,
+}
+
+#[derive(Debug, Args)]
+struct ProgressCompleteArgs {
+    /// Course JSON file the learner is enrolled in.
+    #[arg(long)]
+    course: PathBuf,
+
+    /// Progress file to update; created on the first completion.
+    #[arg(long)]
+    progress: PathBuf,
+
+    /// Learner email.
+    #[arg(long)]
+    user: String,
+
+    /// Name of the lesson to complete.
+    #[arg(long)]
+    complete: String,
+}
+
+#[derive(Debug, Args)]
+struct UserRegisterArgs {
+    /// JSON file holding the user's registration data.
+    #[arg(long)]
+    from_json: PathBuf,
+
+    /// Users file to add the user to; created on the first registration.
+    #[arg(long)]
+    users: PathBuf,
+}
+
+/// A lesson given on the command line.
+#[derive(Debug, Clone)]
+struct LessonSpec {
+    name: String,
+    duration_seconds: u64,
+    video_url: String,
+}
+
+/// Registration data read from a JSON file.
+#[derive(Debug, Deserialize)]
+struct UserRegistration {
+    first_name: String,
+    middle_name: Option<String>,
+    last_name: String,
+    second_last_name: Option<String>,
+    document: String,
+    email: String,
+    password: Option<String>,
+}
+
+/// A learner's completed lessons, saved between runs.
+///
+/// Lessons are matched by name because a course read from JSON gets fresh
+/// IDs every time.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProgressRecord {
+    course: String,
+    user: String,
+    completed: Vec<CompletedLesson>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CompletedLesson {
+    lesson: String,
+    at: String,
+}
+
+/// A registered user as saved in the users file.
+#[derive(Debug, Serialize, Deserialize)]
+struct UserRecord {
+    id: String,
+    first_name: String,
+    middle_name: Option<String>,
+    last_name: String,
+    second_last_name: Option<String>,
+    document: String,
+    email: String,
+    password_hash: Option<String>,
+}
+
+fn parse_lesson_spec(value: &str) -> Result<LessonSpec, String> {
+    let mut parts = value.splitn(3, '|');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(name), Some(duration), Some(video_url)) => Ok(LessonSpec {
+            name: name.to_string(),
+            duration_seconds: parse_duration(duration)?,
+            video_url: video_url.to_string(),
+        }),
+        _ => Err("expected `name|duration_seconds|video_url`".to_string()),
+    }
+}
+
+fn parse_duration(value: &str) -> Result<u64, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("`{value}` is not a duration in seconds"))
+}
+
+fn read_course(path: &Path) -> Result<Course, Box<dyn Error>> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| format!("cannot read course file {}: {e}", path.display()))?;
+    Ok(Course::from_json(&json)?)
+}
+
+fn write_course(path: &Path, course: &Course) -> Result<(), Box<dyn Error>> {
+    fs::write(path, course.to_json()?)
+        .map_err(|e| format!("cannot write course file {}: {e}", path.display()))?;
+    Ok(())
+}
+
+/// Reads a JSON document, or returns `None` if the file does not exist yet.
+fn read_optional_json<T: for<'de> Deserialize<'de>>(
+    path: &Path,
+) -> Result<Option<T>, Box<dyn Error>> {
+    match fs::read_to_string(path) {
+        Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("cannot read {}: {e}", path.display()).into()),
+    }
+}
+
+fn write_json(path: &Path, value: &impl Serialize) -> Result<(), Box<dyn Error>> {
+    fs::write(path, serde_json::to_string_pretty(value)?)
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    Ok(())
+}
+
+fn create_course(args: CourseCreateArgs) -> CliResult {
+    let lessons = args
+        .lessons
+        .into_iter()
+        .enumerate()
+        .map(|(index, spec)| Lesson::new(spec.name, spec.duration_seconds, spec.video_url, index))
+        .collect::<Result<Vec<_>, _>>()?;
+    let chapter = Chapter::new(args.chapter, 0, lessons)?;
+    let course = Course::new(args.name, None, 0, vec![chapter])?;
+    write_course(&args.out, &course)?;
+
+    Ok(format!(
+        "Created course '{}' with {} lesson(s), {} second(s) in total, in {}",
+        course.name().as_str(),
+        course.number_of_lessons(),
+        course.duration().total_seconds(),
+        args.out.display(),
+    ))
+}
+
+fn add_lesson(args: LessonAddArgs) -> CliResult {
+    let mut course = read_course(&args.course)?;
+    let chapter_id = course
+        .chapters()
+        .iter()
+        .find(|chapter| chapter.name().as_str() == args.chapter)
+        .map(Entity::id)
+        .ok_or_else(|| format!("chapter '{}' is not part of the course", args.chapter))?;
+
+    let lesson = Lesson::new(args.name, args.duration, args.video_url, 0)?;
+    let name = lesson.name().as_str().to_string();
+    course.add_lesson(chapter_id, lesson, None)?;
+    write_course(&args.course, &course)?;
+
+    Ok(format!(
+        "Added lesson '{name}' to chapter '{}'; the course now has {} lesson(s)",
+        args.chapter,
+        course.number_of_lessons(),
+    ))
+}
+
+fn complete_lesson(args: ProgressCompleteArgs) -> CliResult {
+    let course = read_course(&args.course)?;
+    let mut progress = CourseProgress::from_course(&course, args.user.as_str())?;
+    let course_name = course.name().as_str().to_string();
+
+    let mut record = read_optional_json(&args.progress)?.unwrap_or_else(|| ProgressRecord {
+        course: course_name.clone(),
+        user: progress.user_email().address().to_string(),
+        completed: Vec::new(),
+    });
+    if record.course != course_name || record.user != progress.user_email().address() {
+        return Err(format!(
+            "{} holds the progress of {} in '{}'",
+            args.progress.display(),
+            record.user,
+            record.course,
+        )
+        .into());
+    }
+
+    let lesson_id = |name: &str| {
+        progress
+            .lesson_progress()
+            .iter()
+            .find(|lesson| lesson.lesson_name().as_str() == name)
+            .map(Entity::id)
+            .ok_or_else(|| format!("lesson '{name}' is not part of the course"))
+    };
+    let mut completions = record
+        .completed
+        .iter()
+        .map(|done| Ok((lesson_id(&done.lesson)?, DateTime::from_iso(&done.at)?)))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    let now = DateTime::today();
+    completions.push((lesson_id(&args.complete)?, now));
+
+    let mut newly_completed = false;
+    for (lesson_id, at) in completions {
+        newly_completed = progress.mark_lesson_completed(lesson_id, at)?;
+    }
+    if newly_completed {
+        record.completed.push(CompletedLesson {
+            lesson: args.complete.clone(),
+            at: now.format_iso(),
+        });
+        write_json(&args.progress, &record)?;
+    }
+
+    Ok(format!(
+        "Completed '{}': {} of '{}' done",
+        args.complete,
+        progress.percentage_completed(),
+        course_name,
+    ))
+}
+
+fn register_user(args: UserRegisterArgs) -> CliResult {
+    let content = fs::read_to_string(&args.from_json)?;
+    let registration: UserRegistration = serde_json::from_str(&content)?;
+
+    let password_hash = registration
+        .password
+        .filter(|password| !password.trim().is_empty())
+        .map(|password| hash_password_argon2id(&password))
+        .transpose()
+        .map_err(|e| format!("Password hashing failed: {e}"))?;
+
+    let user = User::new(
+        registration.first_name.clone(),
+        registration.middle_name.clone(),
+        registration.last_name.clone(),
+        registration.second_last_name.clone(),
+        registration.document.clone(),
+        registration.email,
+        password_hash.clone(),
+    )?;
+
+    let mut users: Vec<UserRecord> = read_optional_json(&args.users)?.unwrap_or_default();
+    let email = user.email().address().to_string();
+    if users.iter().any(|record| record.email == email) {
+        return Err(format!("a user with email {email} is already registered").into());
+    }
+    users.push(UserRecord {
+        id: user.id().to_string(),
+        first_name: registration.first_name,
+        middle_name: registration.middle_name,
+        last_name: registration.last_name,
+        second_last_name: registration.second_last_name,
+        document: user.document().to_string(),
+        email,
+        password_hash,
+    });
+    write_json(&args.users, &users)?;
+
+    Ok(format!(
+        "User '{}' registered with email {}",
+        user.name().full_name(),
+        user.email().address(),
+    ))
+}
+
+/// Hashes a password using Argon2id algorithm.
+///
+/// Uses the same parameters as the terminal UI: memory=65536 KB,
+/// iterations=3, parallelism=4.
+fn hash_password_argon2id(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let params = Params::new(65536, 3, 4, None)?;
+    let argon2 = Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params);
+    let hash = argon2.hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Course(CourseCommand::Create(args)) => create_course(args),
+        Command::Lesson(LessonCommand::Add(args)) => add_lesson(args),
+        Command::Progress(ProgressCommand::Complete(args)) => complete_lesson(args),
+        Command::User(UserCommand::Register(args)) => register_user(args),
+    };
+
+    match result {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A scratch directory removed when the test ends.
+    struct Scratch(PathBuf);
+
+    impl Scratch {
+        fn new(test: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("edu-cli-{}-{test}", std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        fn path(&self, file: &str) -> PathBuf {
+            self.0.join(file)
+        }
+    }
+
+    impl Drop for Scratch {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn lesson(name: &str) -> LessonSpec {
+        LessonSpec {
+            name: name.to_string(),
+            duration_seconds: 600,
+            video_url: format!("https://example.com/{name}.mp4"),
+        }
+    }
+
+    fn create_test_course(scratch: &Scratch) -> PathBuf {
+        let out = scratch.path("course.json");
+        create_course(CourseCreateArgs {
+            name: "Rust Basics".to_string(),
+            chapter: "Chapter 1".to_string(),
+            lessons: vec![lesson("intro"), lesson("ownership")],
+            out: out.clone(),
+        })
+        .unwrap();
+        out
+    }
+
+    fn complete(scratch: &Scratch, course: &Path, lesson: &str) -> CliResult {
+        complete_lesson(ProgressCompleteArgs {
+            course: course.to_path_buf(),
+            progress: scratch.path("progress.json"),
+            user: "ana@example.com".to_string(),
+            complete: lesson.to_string(),
+        })
+    }
+
+    mod course_create {
+        use super::*;
+
+        #[test]
+        fn test_writes_course_json() {
+            let scratch = Scratch::new("create");
+
+            let course = read_course(&create_test_course(&scratch)).unwrap();
+
+            assert_eq!(course.name().as_str(), "Rust Basics");
+            assert_eq!(course.number_of_lessons(), 2);
+        }
+    }
+
+    mod lesson_add {
+        use super::*;
+
+        fn add(course: &Path, chapter: &str) -> CliResult {
+            add_lesson(LessonAddArgs {
+                course: course.to_path_buf(),
+                chapter: chapter.to_string(),
+                name: "borrowing".to_string(),
+                duration: 900,
+                video_url: "https://example.com/borrowing.mp4".to_string(),
+            })
+        }
+
+        #[test]
+        fn test_saves_lesson_in_course_file() {
+            let scratch = Scratch::new("add");
+            let path = create_test_course(&scratch);
+
+            add(&path, "Chapter 1").unwrap();
+
+            let course = read_course(&path).unwrap();
+            assert_eq!(course.number_of_lessons(), 3);
+            assert_eq!(course.duration().total_seconds(), 2100);
+        }
+
+        #[test]
+        fn test_unknown_chapter_leaves_file_alone() {
+            let scratch = Scratch::new("add-unknown");
+            let path = create_test_course(&scratch);
+            let before = fs::read_to_string(&path).unwrap();
+
+            let result = add(&path, "Chapter 9");
+
+            assert!(result.unwrap_err().to_string().contains("Chapter 9"));
+            assert_eq!(fs::read_to_string(&path).unwrap(), before);
+        }
+
+        #[test]
+        fn test_missing_course_file() {
+            let scratch = Scratch::new("add-missing");
+
+            assert!(add(&scratch.path("nope.json"), "Chapter 1").is_err());
+        }
+    }
+
+    mod progress_complete {
+        use super::*;
+
+        #[test]
+        fn test_progress_carries_over_between_runs() {
+            let scratch = Scratch::new("progress");
+            let course = create_test_course(&scratch);
+
+            let first = complete(&scratch, &course, "intro").unwrap();
+            let second = complete(&scratch, &course, "ownership").unwrap();
+
+            assert!(first.contains("50"), "{first}");
+            assert!(second.contains("100"), "{second}");
+            let record: ProgressRecord =
+                serde_json::from_str(&fs::read_to_string(scratch.path("progress.json")).unwrap())
+                    .unwrap();
+            assert_eq!(record.completed.len(), 2);
+        }
+
+        #[test]
+        fn test_completing_twice_is_recorded_once() {
+            let scratch = Scratch::new("progress-twice");
+            let course = create_test_course(&scratch);
+
+            complete(&scratch, &course, "intro").unwrap();
+            complete(&scratch, &course, "intro").unwrap();
+
+            let record: ProgressRecord =
+                serde_json::from_str(&fs::read_to_string(scratch.path("progress.json")).unwrap())
+                    .unwrap();
+            assert_eq!(record.completed.len(), 1);
+        }
+
+        #[test]
+        fn test_unknown_lesson_saves_nothing() {
+            let scratch = Scratch::new("progress-unknown");
+            let course = create_test_course(&scratch);
+
+            let result = complete(&scratch, &course, "lifetimes");
+
+            assert!(result.unwrap_err().to_string().contains("lifetimes"));
+            assert!(!scratch.path("progress.json").exists());
+        }
+
+        #[test]
+        fn test_progress_of_another_learner_is_refused() {
+            let scratch = Scratch::new("progress-other");
+            let course = create_test_course(&scratch);
+            complete(&scratch, &course, "intro").unwrap();
+
+            let result = complete_lesson(ProgressCompleteArgs {
+                course,
+                progress: scratch.path("progress.json"),
+                user: "bob@example.com".to_string(),
+                complete: "ownership".to_string(),
+            });
+
+            assert!(result.is_err());
+        }
+    }
+
+    mod user_register {
+        use super::*;
+
+        fn register(scratch: &Scratch, email: &str) -> CliResult {
+            let from_json = scratch.path(&format!("{email}.json"));
+            fs::write(
+                &from_json,
+                format!(
+                    r#"{{
+                        "first_name": "Ana",
+                        "last_name": "Torres",
+                        "document": "12345678-1",
+                        "email": "{email}"
+                    }}"#
+                ),
+            )
+            .unwrap();
+            register_user(UserRegisterArgs {
+                from_json,
+                users: scratch.path("users.json"),
+            })
+        }
+
+        fn stored_users(scratch: &Scratch) -> Vec<UserRecord> {
+            serde_json::from_str(&fs::read_to_string(scratch.path("users.json")).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_saves_users() {
+            let scratch = Scratch::new("register");
+
+            register(&scratch, "ana@example.com").unwrap();
+            register(&scratch, "bea@example.com").unwrap();
+
+            let users = stored_users(&scratch);
+            assert_eq!(users.len(), 2);
+            assert_eq!(users[0].email, "ana@example.com");
+            assert_eq!(users[0].password_hash, None);
+        }
+
+        #[test]
+        fn test_duplicate_email_is_refused() {
+            let scratch = Scratch::new("register-duplicate");
+            register(&scratch, "ana@example.com").unwrap();
+
+            let result = register(&scratch, "ana@example.com");
+
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("already registered")
+            );
+            assert_eq!(stored_users(&scratch).len(), 1);
+        }
+
+        #[test]
+        fn test_invalid_user_saves_nothing() {
+            let scratch = Scratch::new("register-invalid");
+
+            assert!(register(&scratch, "not-an-email").is_err());
+            assert!(!scratch.path("users.json").exists());
+        }
+    }
+}
@@ -0,0 +1,471 @@
+use crate::{
+    keymap::{Action, KeyChord, KeyMap},
+    theme::{Theme, ThemePreset},
+};
+use argon2::Params;
+use education_platform::prelude::{Locale, LocaleError};
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use thiserror::Error;
+
+/// Location of the config file below the user's config directory.
+const CONFIG_FILE: &str = "education-platform/config.toml";
+
+/// Problems with the config file, reported on startup.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("cannot read {path}: {source}")]
+    Read { path: PathBuf, source: io::Error },
+
+    #[error("{path} is not valid TOML: {message}")]
+    Parse { path: PathBuf, message: String },
+
+    #[error("theme.preset = \"{0}\" is not one of dark, light or high-contrast")]
+    UnknownThemePreset(String),
+
+    #[error("theme.{key} = \"{value}\" is not a color name, index or #rrggbb value")]
+    ColorNotValid { key: &'static str, value: String },
+
+    #[error("keys.{0} is not an action the terminal knows")]
+    UnknownAction(String),
+
+    #[error(
+        "keys.{action} = \"{key}\" is not a key such as \"q\", \"enter\", \"f2\" or \"ctrl+s\""
+    )]
+    KeyNotValid { action: String, key: String },
+
+    #[error("argon2 parameters are not usable: {0}")]
+    Argon2NotValid(argon2::Error),
+
+    #[error("locale is not valid: {0}")]
+    LocaleNotValid(#[from] LocaleError),
+
+    #[error("storage_path must not be empty")]
+    EmptyStoragePath,
+}
+
+/// Settings for the terminal UI, read from
+/// `$XDG_CONFIG_HOME/education-platform/config.toml` (by default
+/// `~/.config/education-platform/config.toml`).
+///
+/// Every key is optional; missing keys keep their defaults:
+///
+/// ```toml
+/// locale = "es-PE"       # UI language: en or es, others fall back to en
+/// storage_path = "~/.local/share/education-platform"
+/// vim_navigation = false  # j/k, gg/G and Ctrl+d/u in the menu and lists
+///
+/// [theme]
+/// preset = "dark"        # dark, light or high-contrast
+/// accent = "cyan"        # each color overrides the preset's
+/// form = "green"
+/// focus = "yellow"
+/// inactive = "gray"
+/// muted = "darkgray"
+/// highlight = "darkgray"
+/// highlight_text = "reset"
+/// error = "red"
+/// success = "#00aa00"
+///
+/// [argon2]
+/// memory_kib = 65536
+/// iterations = 3
+/// parallelism = 4
+///
+/// [keys]                 # one key or a list; an empty list unbinds
+/// quit = ["q", "ctrl+c"]
+/// submit = "ctrl+s"
+/// move_up = ["up", "k"]
+/// ```
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub theme: Theme,
+    pub storage_path: PathBuf,
+    pub argon2: Params,
+    pub locale: Locale,
+    pub keymap: KeyMap,
+    /// Whether vim's list keys are bound; `gg` needs the app to track it.
+    pub vim_navigation: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            storage_path: default_storage_path(),
+            argon2: default_argon2(),
+            locale: Locale::new("en").unwrap_or_else(|_| unreachable!("\"en\" is a valid tag")),
+            keymap: KeyMap::default(),
+            vim_navigation: false,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawConfig {
+    locale: Option<String>,
+    storage_path: Option<String>,
+    vim_navigation: Option<bool>,
+    #[serde(default)]
+    theme: RawTheme,
+    #[serde(default)]
+    argon2: RawArgon2,
+    #[serde(default)]
+    keys: HashMap<String, RawKeys>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawTheme {
+    preset: Option<String>,
+    accent: Option<String>,
+    form: Option<String>,
+    focus: Option<String>,
+    inactive: Option<String>,
+    muted: Option<String>,
+    highlight: Option<String>,
+    highlight_text: Option<String>,
+    error: Option<String>,
+    success: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawArgon2 {
+    memory_kib: Option<u32>,
+    iterations: Option<u32>,
+    parallelism: Option<u32>,
+}
+
+/// A `[keys]` entry: either one chord or several.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawKeys {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Config {
+    /// Loads the config file, falling back to defaults when there is none.
+    pub fn load() -> Result<Self, ConfigError> {
+        match config_path() {
+            Some(path) if path.exists() => Self::from_file(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text = fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let raw: RawConfig = toml::from_str(&text).map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message: e.message().to_string(),
+        })?;
+        raw.validate()
+    }
+}
+
+impl RawConfig {
+    fn validate(self) -> Result<Config, ConfigError> {
+        let defaults = Config::default();
+
+        let locale = match self.locale {
+            Some(tag) => Locale::new(&tag)?,
+            None => defaults.locale,
+        };
+
+        let storage_path = match self.storage_path {
+            Some(path) if path.trim().is_empty() => return Err(ConfigError::EmptyStoragePath),
+            Some(path) => expand_home(path.trim()),
+            None => defaults.storage_path,
+        };
+
+        let argon2 = Params::new(
+            self.argon2.memory_kib.unwrap_or(defaults.argon2.m_cost()),
+            self.argon2.iterations.unwrap_or(defaults.argon2.t_cost()),
+            self.argon2.parallelism.unwrap_or(defaults.argon2.p_cost()),
+            None,
+        )
+        .map_err(ConfigError::Argon2NotValid)?;
+
+        // Vim keys go in first so that [keys] entries replace them too.
+        let vim_navigation = self.vim_navigation.unwrap_or(defaults.vim_navigation);
+        let mut keymap = if vim_navigation {
+            defaults.keymap.with_vim_navigation()
+        } else {
+            defaults.keymap
+        };
+        for (name, keys) in self.keys {
+            let action = Action::from_name(&name).ok_or(ConfigError::UnknownAction(name))?;
+            keymap.bind(action, keys.parse(action)?);
+        }
+
+        Ok(Config {
+            theme: self.theme.validate()?,
+            storage_path,
+            argon2,
+            locale,
+            keymap,
+            vim_navigation,
+        })
+    }
+}
+
+impl RawKeys {
+    fn parse(self, action: Action) -> Result<Vec<KeyChord>, ConfigError> {
+        let keys = match self {
+            Self::One(key) => vec![key],
+            Self::Many(keys) => keys,
+        };
+        keys.into_iter()
+            .map(|key| {
+                KeyChord::parse(&key).ok_or_else(|| ConfigError::KeyNotValid {
+                    action: action.name().to_string(),
+                    key,
+                })
+            })
+            .collect()
+    }
+}
+
+impl RawTheme {
+    fn validate(self) -> Result<Theme, ConfigError> {
+        let preset = match self.preset {
+            Some(name) => {
+                ThemePreset::from_name(&name).ok_or(ConfigError::UnknownThemePreset(name))?
+            }
+            None => ThemePreset::default(),
+        };
+        let defaults = Theme::preset(preset);
+
+        Ok(Theme {
+            preset,
+            accent: parse_color("accent", self.accent, defaults.accent)?,
+            form: parse_color("form", self.form, defaults.form)?,
+            focus: parse_color("focus", self.focus, defaults.focus)?,
+            inactive: parse_color("inactive", self.inactive, defaults.inactive)?,
+            muted: parse_color("muted", self.muted, defaults.muted)?,
+            highlight: parse_color("highlight", self.highlight, defaults.highlight)?,
+            highlight_text: parse_color(
+                "highlight_text",
+                self.highlight_text,
+                defaults.highlight_text,
+            )?,
+            error: parse_color("error", self.error, defaults.error)?,
+            success: parse_color("success", self.success, defaults.success)?,
+        })
+    }
+}
+
+fn parse_color(
+    key: &'static str,
+    value: Option<String>,
+    default: Color,
+) -> Result<Color, ConfigError> {
+    match value {
+        Some(value) => {
+            Color::from_str(value.trim()).map_err(|_| ConfigError::ColorNotValid { key, value })
+        }
+        None => Ok(default),
+    }
+}
+
+/// The parameters recommended for Argon2id: 64 MiB, 3 iterations, 4 lanes.
+fn default_argon2() -> Params {
+    Params::new(65536, 3, 4, None).unwrap_or_default()
+}
+
+fn config_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".config")))?;
+    Some(base.join(CONFIG_FILE))
+}
+
+fn default_storage_path() -> PathBuf {
+    env::var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".local/share")))
+        .unwrap_or_default()
+        .join("education-platform")
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Expands a leading `~/` to the home directory.
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(text: &str) -> Result<Config, ConfigError> {
+        toml::from_str::<RawConfig>(text)
+            .expect("test config should be valid TOML")
+            .validate()
+    }
+
+    mod validate {
+        use super::*;
+
+        #[test]
+        fn test_empty_file_keeps_defaults() {
+            let config = load("").unwrap();
+
+            assert_eq!(config.locale.to_string(), "en");
+            assert_eq!(config.argon2, default_argon2());
+            assert!(!config.vim_navigation);
+        }
+
+        #[test]
+        fn test_reads_every_section() {
+            let config = load(
+                r##"
+                locale = "es-PE"
+                storage_path = "/tmp/education-platform"
+
+                [theme]
+                preset = "light"
+                success = "#00aa00"
+
+                [argon2]
+                memory_kib = 19456
+                iterations = 2
+                parallelism = 1
+                "##,
+            )
+            .unwrap();
+
+            assert_eq!(config.locale.to_string(), "es-PE");
+            assert_eq!(config.storage_path, PathBuf::from("/tmp/education-platform"));
+            assert_eq!(config.theme.preset, ThemePreset::Light);
+            assert_eq!(config.theme.success, Color::Rgb(0x00, 0xaa, 0x00));
+            assert_eq!(config.argon2.m_cost(), 19456);
+            assert_eq!(config.argon2.t_cost(), 2);
+        }
+
+        #[test]
+        fn test_unknown_locale_is_rejected() {
+            assert!(matches!(
+                load(r#"locale = "not a tag""#),
+                Err(ConfigError::LocaleNotValid(_))
+            ));
+        }
+
+        #[test]
+        fn test_blank_storage_path_is_rejected() {
+            assert!(matches!(
+                load(r#"storage_path = "  ""#),
+                Err(ConfigError::EmptyStoragePath)
+            ));
+        }
+
+        #[test]
+        fn test_unusable_argon2_parameters_are_rejected() {
+            assert!(matches!(
+                load("[argon2]\niterations = 0"),
+                Err(ConfigError::Argon2NotValid(_))
+            ));
+        }
+
+        #[test]
+        fn test_unknown_theme_preset_is_rejected() {
+            assert!(matches!(
+                load("[theme]\npreset = \"solarized\""),
+                Err(ConfigError::UnknownThemePreset(name)) if name == "solarized"
+            ));
+        }
+
+        #[test]
+        fn test_bad_color_names_the_key() {
+            assert!(matches!(
+                load("[theme]\naccent = \"blurple\""),
+                Err(ConfigError::ColorNotValid { key: "accent", value }) if value == "blurple"
+            ));
+        }
+    }
+
+    mod keys {
+        use super::*;
+        use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+        #[test]
+        fn test_binds_one_or_many_chords() {
+            let config = load("[keys]\nquit = [\"q\", \"ctrl+c\"]\nsubmit = \"ctrl+s\"").unwrap();
+            let ctrl_c = KeyChord::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+            let enter = KeyChord::new(KeyCode::Enter, KeyModifiers::NONE);
+
+            assert_eq!(config.keymap.action(ctrl_c, &[Action::Quit]), Some(Action::Quit));
+            assert_eq!(config.keymap.action(enter, &[Action::Submit]), None);
+        }
+
+        #[test]
+        fn test_entries_replace_vim_keys() {
+            let config = load("vim_navigation = true\n[keys]\nmove_down = \"down\"").unwrap();
+
+            assert_eq!(config.keymap.label(Action::MoveDown, "/"), "Down");
+            assert_eq!(config.keymap.label(Action::MoveUp, "/"), "Up/k");
+        }
+
+        #[test]
+        fn test_unknown_action_is_rejected() {
+            assert!(matches!(
+                load("[keys]\nfly = \"f\""),
+                Err(ConfigError::UnknownAction(name)) if name == "fly"
+            ));
+        }
+
+        #[test]
+        fn test_bad_chord_names_the_action() {
+            assert!(matches!(
+                load("[keys]\nquit = [\"q\", \"hyper+q\"]"),
+                Err(ConfigError::KeyNotValid { action, key }) if action == "quit" && key == "hyper+q"
+            ));
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_unknown_keys_are_rejected() {
+            assert!(toml::from_str::<RawConfig>("colour = \"red\"").is_err());
+            assert!(toml::from_str::<RawConfig>("[theme]\nbackground = \"red\"").is_err());
+        }
+
+        #[test]
+        fn test_wrong_value_type_is_rejected() {
+            assert!(toml::from_str::<RawConfig>("vim_navigation = \"yes\"").is_err());
+            assert!(toml::from_str::<RawConfig>("[argon2]\nmemory_kib = -1").is_err());
+        }
+    }
+
+    mod expand_home {
+        use super::*;
+
+        #[test]
+        fn test_absolute_path_is_kept() {
+            assert_eq!(expand_home("/srv/data"), PathBuf::from("/srv/data"));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use education_platform::prelude::{CourseProgress, Date, Email, ProgressReport};
+
+/// Days of activity shown in the dashboard's sparkline, ending today.
+pub const ACTIVITY_DAYS: usize = 30;
+
+/// One course on the dashboard.
+#[derive(Debug)]
+pub struct CourseSummary {
+    pub name: String,
+    pub favorite: bool,
+    pub report: ProgressReport,
+}
+
+/// Progress figures for everything a user is enrolled in.
+#[derive(Debug)]
+pub struct Dashboard {
+    /// Favorite courses first, then the rest in enrollment order.
+    pub courses: Vec<CourseSummary>,
+    /// Consecutive days up to today with activity in any course.
+    pub current_streak: u32,
+    /// Longest run of active days within a single course.
+    pub longest_streak: u32,
+    /// Courses active on each of the last [`ACTIVITY_DAYS`] days, oldest first.
+    pub activity: Vec<u64>,
+}
+
+impl Dashboard {
+    /// Summarizes the progress belonging to `email` as of `today`.
+    pub fn new(progress: &[CourseProgress], email: &Email, today: Date) -> Self {
+        let own: Vec<&CourseProgress> = progress
+            .iter()
+            .filter(|course| course.user_email() == email)
+            .collect();
+
+        let active_on = |day: Date| {
+            own.iter()
+                .filter(|course| course.was_active_on(day))
+                .count()
+        };
+        let current_streak = (0..)
+            .map(|offset| today.sub_days(offset))
+            .take_while(|&day| active_on(day) > 0)
+            .count();
+
+        let mut courses: Vec<CourseSummary> = own
+            .iter()
+            .map(|course| CourseSummary {
+                name: course.course_name().to_string(),
+                favorite: course.is_favorite(),
+                report: ProgressReport::from_progress(course, today),
+            })
+            .collect();
+        courses.sort_by_key(|course| !course.favorite);
+
+        Self {
+            current_streak: u32::try_from(current_streak).unwrap_or(u32::MAX),
+            longest_streak: courses
+                .iter()
+                .map(|course| course.report.longest_streak())
+                .max()
+                .unwrap_or(0),
+            activity: (0..ACTIVITY_DAYS)
+                .rev()
+                .map(|offset| today.sub_days(i64::try_from(offset).unwrap_or(0)))
+                .map(|day| u64::try_from(active_on(day)).unwrap_or(u64::MAX))
+                .collect(),
+            courses,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use education_platform::prelude::LessonProgress;
+
+    fn day(n: u32) -> Date {
+        Date::new(2024, 3, n).unwrap()
+    }
+
+    fn email(address: &str) -> Email {
+        Email::new(address.to_string()).unwrap()
+    }
+
+    fn progress(name: &str, user: &str, favorite: bool, days: &[u32]) -> CourseProgress {
+        let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+        CourseProgress::builder()
+            .course_name(name)
+            .user_email(user)
+            .lessons(vec![lesson])
+            .favorite(favorite)
+            .active_days(days.iter().map(|&n| day(n)))
+            .build()
+            .unwrap()
+    }
+
+    mod new {
+        use super::*;
+
+        #[test]
+        fn test_only_includes_the_users_courses() {
+            let all = [
+                progress("Rust", "ana@example.com", false, &[]),
+                progress("Gleam", "luis@example.com", false, &[]),
+            ];
+
+            let dashboard = Dashboard::new(&all, &email("ana@example.com"), day(10));
+
+            assert_eq!(dashboard.courses.len(), 1);
+            assert_eq!(dashboard.courses[0].name, "Rust");
+        }
+
+        #[test]
+        fn test_favorites_come_first_in_enrollment_order() {
+            let all = [
+                progress("Rust", "ana@example.com", false, &[]),
+                progress("Gleam", "ana@example.com", true, &[]),
+                progress("Zig", "ana@example.com", false, &[]),
+                progress("Elm", "ana@example.com", true, &[]),
+            ];
+
+            let dashboard = Dashboard::new(&all, &email("ana@example.com"), day(10));
+
+            let names: Vec<&str> = dashboard.courses.iter().map(|c| c.name.as_str()).collect();
+            assert_eq!(names, ["Gleam", "Elm", "Rust", "Zig"]);
+        }
+
+        #[test]
+        fn test_current_streak_spans_courses() {
+            let all = [
+                progress("Rust", "ana@example.com", false, &[8, 10]),
+                progress("Gleam", "ana@example.com", false, &[9]),
+                progress("Zig", "luis@example.com", false, &[7]),
+            ];
+
+            let dashboard = Dashboard::new(&all, &email("ana@example.com"), day(10));
+
+            assert_eq!(dashboard.current_streak, 3);
+        }
+
+        #[test]
+        fn test_longest_streak_is_the_best_single_course() {
+            let all = [
+                progress("Rust", "ana@example.com", false, &[1, 2, 3]),
+                progress("Gleam", "ana@example.com", false, &[5, 6]),
+            ];
+
+            let dashboard = Dashboard::new(&all, &email("ana@example.com"), day(10));
+
+            assert_eq!(dashboard.current_streak, 0);
+            assert_eq!(dashboard.longest_streak, 3);
+        }
+
+        #[test]
+        fn test_activity_counts_courses_per_day_oldest_first() {
+            let all = [
+                progress("Rust", "ana@example.com", false, &[9, 10]),
+                progress("Gleam", "ana@example.com", false, &[10]),
+            ];
+
+            let dashboard = Dashboard::new(&all, &email("ana@example.com"), day(10));
+
+            assert_eq!(dashboard.activity.len(), ACTIVITY_DAYS);
+            assert_eq!(dashboard.activity[ACTIVITY_DAYS - 1], 2);
+            assert_eq!(dashboard.activity[ACTIVITY_DAYS - 2], 1);
+            assert_eq!(dashboard.activity.iter().sum::<u64>(), 3);
+        }
+
+        #[test]
+        fn test_no_courses_is_empty() {
+            let dashboard = Dashboard::new(&[], &email("ana@example.com"), day(10));
+
+            assert!(dashboard.courses.is_empty());
+            assert_eq!(dashboard.longest_streak, 0);
+            assert!(dashboard.activity.iter().all(|&count| count == 0));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use argon2::{
+    Argon2, Params, Version,
+    password_hash::{
+        PasswordHash, PasswordHasher, PasswordVerifier as _, SaltString, rand_core::OsRng,
+    },
+};
+use education_platform::prelude::{HashedPassword, PasswordVerifier};
+
+/// Checks passwords against the Argon2 hashes produced at registration.
+pub struct Argon2Verifier;
+
+impl PasswordVerifier for Argon2Verifier {
+    fn verify(&self, password: &str, hash: &HashedPassword) -> bool {
+        // The hash string carries its own parameters, so defaults suffice here.
+        PasswordHash::new(hash.value()).is_ok_and(|parsed| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .is_ok()
+        })
+    }
+}
+
+/// Hashes a password using Argon2id algorithm with the configured cost parameters.
+pub fn hash_password_argon2id(
+    password: &str,
+    params: Params,
+) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let argon2 = Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params);
+    let hash = argon2.hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
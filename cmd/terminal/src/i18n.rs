@@ -0,0 +1,408 @@
+use education_platform::prelude::{Locale, PasswordStrength};
+use std::fmt::Display;
+
+/// Languages the terminal has strings for.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    /// The bundle for a locale's language; regional variants such as
+    /// `es-PE` share their language's strings and unknown languages get English.
+    pub fn for_locale(locale: &Locale) -> Self {
+        match locale.language() {
+            "es" => Self::Spanish,
+            _ => Self::English,
+        }
+    }
+}
+
+/// Every piece of text the terminal shows, looked up in a [`Catalog`].
+///
+/// Entries containing `{}` are templates for [`Catalog::format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Text {
+    AppTitle,
+    MenuHeading,
+    LogIn,
+    RegisterUser,
+    ManageUsers,
+    LogOut,
+    Exit,
+    SettingsSummary,
+    SignedInAs,
+    HashingPassword,
+    CheckingPassword,
+    PleaseWait,
+    ErrorTitle,
+    SuccessTitle,
+    PressAnyKey,
+    ItemPosition,
+
+    Navigate,
+    Select,
+    Theme,
+    Quit,
+    Search,
+    Details,
+    Delete,
+    Back,
+    Done,
+    StopSearching,
+    Close,
+    Cancel,
+    NextField,
+    PreviousField,
+    Submit,
+    ShowOrHidePassword,
+    MoveInField,
+    MoveInFieldSpoken,
+    KeyOr,
+    TypeToFilter,
+
+    LoginHeading,
+    RegisterHeading,
+    FirstName,
+    MiddleName,
+    LastName,
+    SecondLastName,
+    Document,
+    Email,
+    Password,
+    PasswordShowHint,
+    PasswordHideHint,
+    FieldRequired,
+    FieldOptional,
+    FieldBlank,
+    FieldError,
+    PasswordShown,
+    CharactersEntered,
+    Strength,
+    StrengthSuffix,
+    VeryWeak,
+    Weak,
+    Fair,
+    Strong,
+    VeryStrong,
+    Required,
+    DocumentFormat,
+    DocumentCheckDigit,
+
+    Users,
+    UserManagementHeading,
+    UserDetails,
+    DeleteUser,
+    ConfirmDeleteUser,
+    Name,
+    Created,
+    NoUsersYet,
+    NoUsersMatch,
+    NoUsersFound,
+    PasswordSet,
+    PasswordNotSet,
+
+    MyProgress,
+    ProgressHeading,
+    CurrentStreak,
+    LongestStreak,
+    Activity,
+    CourseProgressLabel,
+    NotEstimated,
+    NoCourses,
+    FavoriteCourse,
+    FavoriteCourseSpoken,
+
+    Welcome,
+    InvalidLogin,
+    NoPasswordSet,
+    UserRegistered,
+    UserDeleted,
+    CannotDeleteSelf,
+    HashingFailed,
+    TaskStopped,
+    ConfigIgnored,
+    IdError,
+    NameError,
+    DocumentError,
+    EmailError,
+    PasswordError,
+    UnknownError,
+}
+
+impl Text {
+    /// The entry naming a password score.
+    pub fn strength(strength: PasswordStrength) -> Self {
+        match strength {
+            PasswordStrength::VeryWeak => Self::VeryWeak,
+            PasswordStrength::Weak => Self::Weak,
+            PasswordStrength::Fair => Self::Fair,
+            PasswordStrength::Strong => Self::Strong,
+            PasswordStrength::VeryStrong => Self::VeryStrong,
+        }
+    }
+}
+
+/// The strings of one language.
+///
+/// Each bundle is an exhaustive match, so adding a [`Text`] entry does not
+/// compile until every language has it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Catalog {
+    language: Language,
+}
+
+impl Catalog {
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            language: Language::for_locale(locale),
+        }
+    }
+
+    pub fn get(self, text: Text) -> &'static str {
+        match self.language {
+            Language::English => english(text),
+            Language::Spanish => spanish(text),
+        }
+    }
+
+    /// Fills the `{}` placeholders of a template with `args`, in order.
+    pub fn format(self, text: Text, args: &[&dyn Display]) -> String {
+        let mut args = args.iter();
+        let mut pieces = self.get(text).split("{}");
+        let mut result = pieces.next().unwrap_or_default().to_string();
+        for piece in pieces {
+            if let Some(arg) = args.next() {
+                result.push_str(&arg.to_string());
+            }
+            result.push_str(piece);
+        }
+        result
+    }
+}
+
+fn english(text: Text) -> &'static str {
+    match text {
+        Text::AppTitle => "Education Platform",
+        Text::MenuHeading => "Education Platform, main menu.",
+        Text::LogIn => "Log In",
+        Text::RegisterUser => "Register User",
+        Text::ManageUsers => "Manage Users",
+        Text::LogOut => "Log Out",
+        Text::Exit => "Exit",
+        Text::SettingsSummary => "Theme: {} | Locale: {} | Data: {}",
+        Text::SignedInAs => "Signed in as {}",
+        Text::HashingPassword => "Hashing password",
+        Text::CheckingPassword => "Checking password",
+        Text::PleaseWait => "{}, please wait.",
+        Text::ErrorTitle => "Error",
+        Text::SuccessTitle => "Success",
+        Text::PressAnyKey => "Press any key to continue",
+        Text::ItemPosition => "{}, {} of {}",
+
+        Text::Navigate => "Navigate",
+        Text::Select => "Select",
+        Text::Theme => "Theme",
+        Text::Quit => "Quit",
+        Text::Search => "Search",
+        Text::Details => "Details",
+        Text::Delete => "Delete",
+        Text::Back => "Back",
+        Text::Done => "Done",
+        Text::StopSearching => "Stop Searching",
+        Text::Close => "Close",
+        Text::Cancel => "Cancel",
+        Text::NextField => "Next Field",
+        Text::PreviousField => "Previous Field",
+        Text::Submit => "Submit",
+        Text::ShowOrHidePassword => "Show or Hide the Password",
+        Text::MoveInField => "←→ Home End: Move",
+        Text::MoveInFieldSpoken => "Left, Right, Home and End: move in the field.",
+        Text::KeyOr => "or",
+        Text::TypeToFilter => "Type to filter",
+
+        Text::LoginHeading => "Log in form.",
+        Text::RegisterHeading => "Register user form.",
+        Text::FirstName => "First Name",
+        Text::MiddleName => "Middle Name",
+        Text::LastName => "Last Name",
+        Text::SecondLastName => "Second Last Name",
+        Text::Document => "Document (DNI)",
+        Text::Email => "Email",
+        Text::Password => "Password",
+        Text::PasswordShowHint => "Password ({}: show)",
+        Text::PasswordHideHint => "Password ({}: hide)",
+        Text::FieldRequired => "{}, required",
+        Text::FieldOptional => "{}, optional",
+        Text::FieldBlank => "{}: blank",
+        Text::FieldError => "{}. Error: {}",
+        Text::PasswordShown => "{}, shown: {}",
+        Text::CharactersEntered => "{}: {} characters entered",
+        Text::Strength => "Strength: {}",
+        Text::StrengthSuffix => ", strength {}",
+        Text::VeryWeak => "very weak",
+        Text::Weak => "weak",
+        Text::Fair => "fair",
+        Text::Strong => "strong",
+        Text::VeryStrong => "very strong",
+        Text::Required => "Required",
+        Text::DocumentFormat => "Use 8 digits, a dash and the check character, as in 12345678-K",
+        Text::DocumentCheckDigit => "The check character doesn't match the number; look for a typo",
+
+        Text::Users => "Users",
+        Text::UserManagementHeading => "User management.",
+        Text::UserDetails => "User Details",
+        Text::DeleteUser => "Delete User",
+        Text::ConfirmDeleteUser => "Delete {}?",
+        Text::Name => "Name",
+        Text::Created => "Created",
+        Text::NoUsersYet => "No users registered yet.",
+        Text::NoUsersMatch => "No users match the search.",
+        Text::NoUsersFound => "No users found.",
+        Text::PasswordSet => "set",
+        Text::PasswordNotSet => "not set",
+
+        Text::MyProgress => "My Progress",
+        Text::ProgressHeading => "Progress dashboard.",
+        Text::CurrentStreak => "Current streak: {} days",
+        Text::LongestStreak => "Longest streak: {} days",
+        Text::Activity => "Activity, last {} days",
+        Text::CourseProgressLabel => "{} | {} of {} lessons | Finish: {}",
+        Text::NotEstimated => "not estimated yet",
+        Text::NoCourses => "No courses in progress yet.",
+        Text::FavoriteCourse => "★ {}",
+        Text::FavoriteCourseSpoken => "{} (favorite)",
+
+        Text::Welcome => "Welcome, {}!",
+        Text::InvalidLogin => "Invalid email or password.",
+        Text::NoPasswordSet => "This account has no password and cannot log in here.",
+        Text::UserRegistered => "User '{}' registered!",
+        Text::UserDeleted => "User '{}' deleted.",
+        Text::CannotDeleteSelf => "You cannot delete the account you are signed in with.",
+        Text::HashingFailed => "Password hashing failed: {}",
+        Text::TaskStopped => "background task stopped unexpectedly",
+        Text::ConfigIgnored => "Config ignored, using defaults: {}",
+        Text::IdError => "ID error: {}",
+        Text::NameError => "Name error: {}",
+        Text::DocumentError => "Document error: {}",
+        Text::EmailError => "Email error: {}",
+        Text::PasswordError => "Password error: {}",
+        Text::UnknownError => "Unknown error: {}",
+    }
+}
+
+fn spanish(text: Text) -> &'static str {
+    match text {
+        Text::AppTitle => "Plataforma Educativa",
+        Text::MenuHeading => "Plataforma Educativa, menú principal.",
+        Text::LogIn => "Iniciar sesión",
+        Text::RegisterUser => "Registrar usuario",
+        Text::ManageUsers => "Gestionar usuarios",
+        Text::LogOut => "Cerrar sesión",
+        Text::Exit => "Salir",
+        Text::SettingsSummary => "Tema: {} | Idioma: {} | Datos: {}",
+        Text::SignedInAs => "Sesión iniciada como {}",
+        Text::HashingPassword => "Cifrando la contraseña",
+        Text::CheckingPassword => "Verificando la contraseña",
+        Text::PleaseWait => "{}, espere por favor.",
+        Text::ErrorTitle => "Error",
+        Text::SuccessTitle => "Listo",
+        Text::PressAnyKey => "Pulse cualquier tecla para continuar",
+        Text::ItemPosition => "{}, {} de {}",
+
+        Text::Navigate => "Navegar",
+        Text::Select => "Seleccionar",
+        Text::Theme => "Tema",
+        Text::Quit => "Salir",
+        Text::Search => "Buscar",
+        Text::Details => "Detalles",
+        Text::Delete => "Eliminar",
+        Text::Back => "Volver",
+        Text::Done => "Listo",
+        Text::StopSearching => "Dejar de buscar",
+        Text::Close => "Cerrar",
+        Text::Cancel => "Cancelar",
+        Text::NextField => "Campo siguiente",
+        Text::PreviousField => "Campo anterior",
+        Text::Submit => "Enviar",
+        Text::ShowOrHidePassword => "Mostrar u ocultar la contraseña",
+        Text::MoveInField => "←→ Inicio Fin: Mover",
+        Text::MoveInFieldSpoken => "Izquierda, Derecha, Inicio y Fin: moverse en el campo.",
+        Text::KeyOr => "o",
+        Text::TypeToFilter => "Escriba para filtrar",
+
+        Text::LoginHeading => "Formulario de inicio de sesión.",
+        Text::RegisterHeading => "Formulario de registro de usuario.",
+        Text::FirstName => "Nombre",
+        Text::MiddleName => "Segundo nombre",
+        Text::LastName => "Apellido paterno",
+        Text::SecondLastName => "Apellido materno",
+        Text::Document => "Documento (DNI)",
+        Text::Email => "Correo electrónico",
+        Text::Password => "Contraseña",
+        Text::PasswordShowHint => "Contraseña ({}: mostrar)",
+        Text::PasswordHideHint => "Contraseña ({}: ocultar)",
+        Text::FieldRequired => "{}, obligatorio",
+        Text::FieldOptional => "{}, opcional",
+        Text::FieldBlank => "{}: vacío",
+        Text::FieldError => "{}. Error: {}",
+        Text::PasswordShown => "{}, visible: {}",
+        Text::CharactersEntered => "{}: {} caracteres escritos",
+        Text::Strength => "Seguridad: {}",
+        Text::StrengthSuffix => ", seguridad {}",
+        Text::VeryWeak => "muy débil",
+        Text::Weak => "débil",
+        Text::Fair => "aceptable",
+        Text::Strong => "fuerte",
+        Text::VeryStrong => "muy fuerte",
+        Text::Required => "Obligatorio",
+        Text::DocumentFormat => {
+            "Use 8 dígitos, un guion y el carácter de verificación, como en 12345678-K"
+        }
+        Text::DocumentCheckDigit => {
+            "El carácter de verificación no coincide con el número; revise si hay un error de tipeo"
+        }
+
+        Text::Users => "Usuarios",
+        Text::UserManagementHeading => "Gestión de usuarios.",
+        Text::UserDetails => "Detalles del usuario",
+        Text::DeleteUser => "Eliminar usuario",
+        Text::ConfirmDeleteUser => "¿Eliminar a {}?",
+        Text::Name => "Nombre",
+        Text::Created => "Creado",
+        Text::NoUsersYet => "Aún no hay usuarios registrados.",
+        Text::NoUsersMatch => "Ningún usuario coincide con la búsqueda.",
+        Text::NoUsersFound => "No se encontraron usuarios.",
+        Text::PasswordSet => "establecida",
+        Text::PasswordNotSet => "sin establecer",
+
+        Text::MyProgress => "Mi progreso",
+        Text::ProgressHeading => "Panel de progreso.",
+        Text::CurrentStreak => "Racha actual: {} días",
+        Text::LongestStreak => "Racha más larga: {} días",
+        Text::Activity => "Actividad, últimos {} días",
+        Text::CourseProgressLabel => "{} | {} de {} lecciones | Fin: {}",
+        Text::NotEstimated => "sin estimar aún",
+        Text::NoCourses => "Aún no hay cursos en progreso.",
+        Text::FavoriteCourse => "★ {}",
+        Text::FavoriteCourseSpoken => "{} (favorito)",
+
+        Text::Welcome => "¡Te damos la bienvenida, {}!",
+        Text::InvalidLogin => "Correo electrónico o contraseña incorrectos.",
+        Text::NoPasswordSet => "Esta cuenta no tiene contraseña y no puede iniciar sesión aquí.",
+        Text::UserRegistered => "¡Usuario '{}' registrado!",
+        Text::UserDeleted => "Usuario '{}' eliminado.",
+        Text::CannotDeleteSelf => "No puede eliminar la cuenta con la que inició sesión.",
+        Text::HashingFailed => "No se pudo cifrar la contraseña: {}",
+        Text::TaskStopped => "la tarea en segundo plano se detuvo inesperadamente",
+        Text::ConfigIgnored => "Configuración ignorada, se usan los valores predeterminados: {}",
+        Text::IdError => "Error en el ID: {}",
+        Text::NameError => "Error en el nombre: {}",
+        Text::DocumentError => "Error en el documento: {}",
+        Text::EmailError => "Error en el correo electrónico: {}",
+        Text::PasswordError => "Error en la contraseña: {}",
+        Text::UnknownError => "Error desconocido: {}",
+    }
+}
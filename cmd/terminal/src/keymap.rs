@@ -0,0 +1,447 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::{collections::HashMap, fmt};
+
+/// Things a key press can ask the app to do.
+///
+/// Which actions a screen listens for depends on the screen, so one key
+/// may serve several actions, such as Enter for both `Select` and `Submit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    PageUp,
+    PageDown,
+    /// Jumps to the first entry of a list.
+    First,
+    /// Jumps to the last entry of a list.
+    Last,
+    /// Opens the highlighted menu entry or list row.
+    Select,
+    /// Leaves the screen, closes a popup or cancels background work.
+    Back,
+    Quit,
+    NextField,
+    PreviousField,
+    /// Sends the form being edited.
+    Submit,
+    TogglePassword,
+    CycleTheme,
+    Search,
+    Delete,
+    /// Answers yes to a confirmation.
+    Confirm,
+    /// Answers no to a confirmation.
+    Cancel,
+}
+
+impl Action {
+    pub const ALL: [Self; 18] = [
+        Self::MoveUp,
+        Self::MoveDown,
+        Self::PageUp,
+        Self::PageDown,
+        Self::First,
+        Self::Last,
+        Self::Select,
+        Self::Back,
+        Self::Quit,
+        Self::NextField,
+        Self::PreviousField,
+        Self::Submit,
+        Self::TogglePassword,
+        Self::CycleTheme,
+        Self::Search,
+        Self::Delete,
+        Self::Confirm,
+        Self::Cancel,
+    ];
+
+    /// Name used as the key of the `[keys]` table in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::MoveUp => "move_up",
+            Self::MoveDown => "move_down",
+            Self::PageUp => "page_up",
+            Self::PageDown => "page_down",
+            Self::First => "first",
+            Self::Last => "last",
+            Self::Select => "select",
+            Self::Back => "back",
+            Self::Quit => "quit",
+            Self::NextField => "next_field",
+            Self::PreviousField => "previous_field",
+            Self::Submit => "submit",
+            Self::TogglePassword => "toggle_password",
+            Self::CycleTheme => "cycle_theme",
+            Self::Search => "search",
+            Self::Delete => "delete",
+            Self::Confirm => "confirm",
+            Self::Cancel => "cancel",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    fn default_chords(self) -> Vec<KeyChord> {
+        let keys: &[KeyCode] = match self {
+            Self::MoveUp => &[KeyCode::Up],
+            Self::MoveDown => &[KeyCode::Down],
+            Self::PageUp => &[KeyCode::PageUp],
+            Self::PageDown => &[KeyCode::PageDown],
+            Self::First => &[KeyCode::Home],
+            Self::Last => &[KeyCode::End],
+            Self::Select | Self::Submit => &[KeyCode::Enter],
+            Self::Back => &[KeyCode::Esc],
+            Self::Quit => &[KeyCode::Char('q')],
+            Self::NextField => &[KeyCode::Tab],
+            Self::PreviousField => &[KeyCode::BackTab],
+            Self::TogglePassword => &[KeyCode::F(2)],
+            Self::CycleTheme => &[KeyCode::Char('t')],
+            Self::Search => &[KeyCode::Char('/')],
+            Self::Delete => &[KeyCode::Char('d'), KeyCode::Delete],
+            Self::Confirm => &[KeyCode::Char('y')],
+            Self::Cancel => &[KeyCode::Char('n'), KeyCode::Esc],
+        };
+        keys.iter()
+            .map(|&code| KeyChord::new(code, KeyModifiers::NONE))
+            .collect()
+    }
+}
+
+/// A key together with the Ctrl, Alt and Shift modifiers held with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Creates a chord, dropping Shift where the key already implies it:
+    /// on characters, whose case carries it, and on BackTab.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers =
+            modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Parses chords such as `q`, `enter`, `shift+tab`, `ctrl+s` or `f2`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts: Vec<&str> = text.split('+').collect();
+        let key = parts.pop()?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers |= match part.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+
+        let code = parse_key(key)?;
+        let code = match code {
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            code => code,
+        };
+        Some(Self::new(code, modifiers))
+    }
+
+    /// Returns `true` for a plain character, which text fields take as typing.
+    pub fn is_character(&self) -> bool {
+        matches!(self.editing_key(), Some(KeyCode::Char(_)))
+    }
+
+    /// The key to hand to a text field, or `None` when Ctrl or Alt is held.
+    pub fn editing_key(&self) -> Option<KeyCode> {
+        let commands = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        (!self.modifiers.intersects(commands)).then_some(self.code)
+    }
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(event: KeyEvent) -> Self {
+        Self::new(event.code, event.modifiers)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            KeyCode::PageUp => f.write_str("PageUp"),
+            KeyCode::PageDown => f.write_str("PageDown"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+fn parse_key(key: &str) -> Option<KeyCode> {
+    let key = key.trim();
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    let code = match key.to_ascii_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "space" => KeyCode::Char(' '),
+        name => {
+            let number = name.strip_prefix('f')?.parse().ok()?;
+            return (1..=12).contains(&number).then_some(KeyCode::F(number));
+        }
+    };
+    Some(code)
+}
+
+/// The key chords bound to each action.
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: HashMap<Action, Vec<KeyChord>>,
+}
+
+impl KeyMap {
+    /// Replaces the chords bound to an action; an empty list unbinds it.
+    pub fn bind(&mut self, action: Action, chords: Vec<KeyChord>) {
+        self.bindings.insert(action, chords);
+    }
+
+    /// Returns the first of `candidates` bound to the chord.
+    ///
+    /// Screens pass the actions they handle, in priority order, so a chord
+    /// bound to several actions resolves by where it is pressed.
+    pub fn action(&self, chord: KeyChord, candidates: &[Action]) -> Option<Action> {
+        candidates.iter().copied().find(|action| {
+            self.bindings
+                .get(action)
+                .is_some_and(|chords| chords.contains(&chord))
+        })
+    }
+
+    /// Adds vim's list keys on top of the current bindings: `j`/`k` to
+    /// move, `Ctrl+d`/`Ctrl+u` to page and `G` for the last entry. `/`
+    /// already searches; `gg` is a two-key sequence the app tracks itself.
+    pub fn with_vim_navigation(mut self) -> Self {
+        let extra = [
+            (
+                Action::MoveDown,
+                KeyChord::new(KeyCode::Char('j'), KeyModifiers::NONE),
+            ),
+            (Action::MoveUp, KeyChord::new(KeyCode::Char('k'), KeyModifiers::NONE)),
+            (
+                Action::PageDown,
+                KeyChord::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+            ),
+            (
+                Action::PageUp,
+                KeyChord::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
+            ),
+            (Action::Last, KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE)),
+        ];
+        for (action, chord) in extra {
+            let chords = self.bindings.entry(action).or_default();
+            if !chords.contains(&chord) {
+                chords.push(chord);
+            }
+        }
+        self
+    }
+
+    /// Names the chords bound to an action for help lines, joined by `separator`.
+    pub fn label(&self, action: Action, separator: &str) -> String {
+        match self.bindings.get(&action) {
+            Some(chords) if !chords.is_empty() => chords
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(separator),
+            _ => "(unbound)".to_string(),
+        }
+    }
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL
+                .into_iter()
+                .map(|action| (action, action.default_chords()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(code: KeyCode, modifiers: KeyModifiers) -> KeyChord {
+        KeyChord::new(code, modifiers)
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_named_keys_and_modifiers() {
+            assert_eq!(
+                KeyChord::parse("q"),
+                Some(chord(KeyCode::Char('q'), KeyModifiers::NONE))
+            );
+            assert_eq!(
+                KeyChord::parse("Ctrl + s"),
+                Some(chord(KeyCode::Char('s'), KeyModifiers::CONTROL))
+            );
+            assert_eq!(
+                KeyChord::parse("ENTER"),
+                Some(chord(KeyCode::Enter, KeyModifiers::NONE))
+            );
+            assert_eq!(
+                KeyChord::parse("f12"),
+                Some(chord(KeyCode::F(12), KeyModifiers::NONE))
+            );
+            assert_eq!(
+                KeyChord::parse("space"),
+                Some(chord(KeyCode::Char(' '), KeyModifiers::NONE))
+            );
+        }
+
+        #[test]
+        fn test_shift_tab_is_backtab() {
+            assert_eq!(
+                KeyChord::parse("shift+tab"),
+                Some(chord(KeyCode::BackTab, KeyModifiers::NONE))
+            );
+        }
+
+        #[test]
+        fn test_shift_is_dropped_on_characters() {
+            assert_eq!(KeyChord::parse("shift+G"), KeyChord::parse("G"));
+        }
+
+        #[test]
+        fn test_invalid_chords_are_rejected() {
+            for text in [
+                "",
+                "ctrl+",
+                "hyper+q",
+                "ctrl+shift",
+                "f0",
+                "f13",
+                "fx",
+                "pgup",
+                "enterr",
+            ] {
+                assert_eq!(KeyChord::parse(text), None, "{text:?} should be rejected");
+            }
+        }
+    }
+
+    mod key_chord {
+        use super::*;
+
+        #[test]
+        fn test_ignores_unsupported_modifiers() {
+            assert_eq!(
+                chord(KeyCode::Enter, KeyModifiers::SUPER),
+                chord(KeyCode::Enter, KeyModifiers::NONE)
+            );
+        }
+
+        #[test]
+        fn test_editing_key_is_withheld_with_ctrl_or_alt() {
+            assert!(chord(KeyCode::Char('a'), KeyModifiers::NONE).is_character());
+            assert!(!chord(KeyCode::Char('a'), KeyModifiers::CONTROL).is_character());
+            assert_eq!(chord(KeyCode::Left, KeyModifiers::ALT).editing_key(), None);
+            assert!(!chord(KeyCode::Enter, KeyModifiers::NONE).is_character());
+        }
+
+        #[test]
+        fn test_display() {
+            assert_eq!(chord(KeyCode::Char('s'), KeyModifiers::CONTROL).to_string(), "Ctrl+s");
+            assert_eq!(chord(KeyCode::BackTab, KeyModifiers::NONE).to_string(), "Shift+Tab");
+            assert_eq!(chord(KeyCode::Char(' '), KeyModifiers::NONE).to_string(), "Space");
+            assert_eq!(chord(KeyCode::F(2), KeyModifiers::NONE).to_string(), "F2");
+        }
+    }
+
+    mod key_map {
+        use super::*;
+
+        #[test]
+        fn test_every_action_name_round_trips() {
+            for action in Action::ALL {
+                assert_eq!(Action::from_name(action.name()), Some(action));
+            }
+            assert_eq!(Action::from_name("fly"), None);
+        }
+
+        #[test]
+        fn test_shared_chord_resolves_by_candidate_order() {
+            let keymap = KeyMap::default();
+            let enter = chord(KeyCode::Enter, KeyModifiers::NONE);
+
+            assert_eq!(
+                keymap.action(enter, &[Action::Submit, Action::Select]),
+                Some(Action::Submit)
+            );
+            assert_eq!(keymap.action(enter, &[Action::Back]), None);
+        }
+
+        #[test]
+        fn test_bind_replaces_and_unbinds() {
+            let mut keymap = KeyMap::default();
+            let ctrl_q = chord(KeyCode::Char('q'), KeyModifiers::CONTROL);
+
+            keymap.bind(Action::Quit, vec![ctrl_q]);
+            keymap.bind(Action::Search, Vec::new());
+
+            assert_eq!(keymap.action(ctrl_q, &[Action::Quit]), Some(Action::Quit));
+            let q = chord(KeyCode::Char('q'), KeyModifiers::NONE);
+            assert_eq!(keymap.action(q, &[Action::Quit]), None);
+            assert_eq!(keymap.label(Action::Search, ", "), "(unbound)");
+        }
+
+        #[test]
+        fn test_vim_navigation_adds_without_duplicates() {
+            let keymap = KeyMap::default()
+                .with_vim_navigation()
+                .with_vim_navigation();
+
+            assert_eq!(keymap.label(Action::MoveDown, "/"), "Down/j");
+            assert_eq!(keymap.label(Action::PageUp, "/"), "PageUp/Ctrl+u");
+        }
+    }
+}
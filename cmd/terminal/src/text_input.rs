@@ -0,0 +1,202 @@
+use ratatui::crossterm::event::KeyCode;
+
+/// A single-line text value with a cursor that can sit anywhere in it.
+///
+/// The cursor counts characters, not bytes, so multi-byte input such as
+/// accented names edits one character at a time.
+#[derive(Debug, Default, Clone)]
+pub struct TextInput {
+    value: String,
+    cursor: usize,
+}
+
+impl TextInput {
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    /// Number of characters between the cursor and the end of the value.
+    pub fn chars_after_cursor(&self) -> usize {
+        self.char_count() - self.cursor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    /// Applies an editing or cursor key. Returns `false` for keys it does not handle.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char(c) => self.insert(c),
+            KeyCode::Backspace => self.delete_before_cursor(),
+            KeyCode::Delete => self.delete_at_cursor(),
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.char_count()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.char_count(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the characters that fit in `width` columns and the cursor's
+    /// column among them, scrolled so the cursor is always visible.
+    pub fn window(&self, width: usize) -> (String, usize) {
+        let start = (self.cursor + 1).saturating_sub(width.max(1));
+        let visible = self.value.chars().skip(start).take(width).collect();
+        (visible, self.cursor - start)
+    }
+
+    fn insert(&mut self, c: char) {
+        let at = self.byte_index(self.cursor);
+        self.value.insert(at, c);
+        self.cursor += 1;
+    }
+
+    fn delete_before_cursor(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.delete_at_cursor();
+        }
+    }
+
+    fn delete_at_cursor(&mut self) {
+        if self.cursor < self.char_count() {
+            self.value.remove(self.byte_index(self.cursor));
+        }
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(text: &str) -> TextInput {
+        let mut input = TextInput::default();
+        for c in text.chars() {
+            input.handle_key(KeyCode::Char(c));
+        }
+        input
+    }
+
+    mod handle_key {
+        use super::*;
+
+        #[test]
+        fn test_counts_characters_not_bytes() {
+            let input = typed("Peña");
+
+            assert_eq!(input.value(), "Peña");
+            assert_eq!(input.char_count(), 4);
+            assert_eq!(input.chars_after_cursor(), 0);
+        }
+
+        #[test]
+        fn test_inserts_between_multibyte_characters() {
+            let mut input = typed("Ñu");
+            input.handle_key(KeyCode::Left);
+
+            input.handle_key(KeyCode::Char('é'));
+
+            assert_eq!(input.value(), "Ñéu");
+            assert_eq!(input.chars_after_cursor(), 1);
+        }
+
+        #[test]
+        fn test_backspace_removes_whole_character() {
+            let mut input = typed("José");
+
+            input.handle_key(KeyCode::Backspace);
+
+            assert_eq!(input.value(), "Jos");
+        }
+
+        #[test]
+        fn test_delete_removes_character_at_cursor() {
+            let mut input = typed("añb");
+            input.handle_key(KeyCode::Home);
+            input.handle_key(KeyCode::Right);
+
+            input.handle_key(KeyCode::Delete);
+
+            assert_eq!(input.value(), "ab");
+            assert_eq!(input.chars_after_cursor(), 1);
+        }
+
+        #[test]
+        fn test_cursor_stays_within_value() {
+            let mut input = typed("ü");
+
+            input.handle_key(KeyCode::Right);
+            assert_eq!(input.chars_after_cursor(), 0);
+
+            input.handle_key(KeyCode::Left);
+            input.handle_key(KeyCode::Left);
+            input.handle_key(KeyCode::Backspace);
+            assert_eq!(input.value(), "ü");
+            assert_eq!(input.chars_after_cursor(), 1);
+
+            input.handle_key(KeyCode::End);
+            input.handle_key(KeyCode::Delete);
+            assert_eq!(input.value(), "ü");
+        }
+
+        #[test]
+        fn test_unhandled_key_is_reported() {
+            let mut input = typed("a");
+
+            assert!(!input.handle_key(KeyCode::Enter));
+            assert_eq!(input.value(), "a");
+        }
+    }
+
+    mod window {
+        use super::*;
+
+        #[test]
+        fn test_short_value_fits() {
+            let mut input = typed("día");
+            input.handle_key(KeyCode::Home);
+
+            assert_eq!(input.window(10), ("día".to_string(), 0));
+        }
+
+        #[test]
+        fn test_scrolls_to_keep_cursor_visible() {
+            let input = typed("ñandú");
+
+            assert_eq!(input.window(3), ("dú".to_string(), 2));
+        }
+    }
+
+    mod clear {
+        use super::*;
+
+        #[test]
+        fn test_resets_value_and_cursor() {
+            let mut input = typed("Ana");
+
+            input.clear();
+            input.handle_key(KeyCode::Char('é'));
+
+            assert!(!input.is_empty());
+            assert_eq!(input.value(), "é");
+        }
+    }
+}
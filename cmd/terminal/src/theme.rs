@@ -0,0 +1,113 @@
+use ratatui::style::Color;
+
+/// Built-in color schemes, switchable while the app runs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ThemePreset {
+    /// Bright accents for dark terminal backgrounds.
+    #[default]
+    Dark,
+    /// Deeper accents that stay readable on light backgrounds.
+    Light,
+    /// Black, white and yellow only, with no meaning carried by hue alone.
+    HighContrast,
+}
+
+impl ThemePreset {
+    /// Presets in the order they are cycled through.
+    pub const ALL: [Self; 3] = [Self::Dark, Self::Light, Self::HighContrast];
+
+    /// Name used in the config file and shown on screen.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+            Self::HighContrast => "high-contrast",
+        }
+    }
+
+    /// Looks a preset up by its config name, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// The preset after this one, wrapping around.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Colors used across screens.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// The preset the colors started from; overrides may have changed some.
+    pub preset: ThemePreset,
+    /// Borders of the menu.
+    pub accent: Color,
+    /// Borders of forms.
+    pub form: Color,
+    /// Border of the field being edited.
+    pub focus: Color,
+    /// Borders of the other fields.
+    pub inactive: Color,
+    /// Help lines and the status bar.
+    pub muted: Color,
+    /// Background of the selected menu entry.
+    pub highlight: Color,
+    /// Text of the selected menu entry.
+    pub highlight_text: Color,
+    pub error: Color,
+    pub success: Color,
+}
+
+impl Theme {
+    /// Returns the colors of a built-in preset.
+    pub fn preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Dark => Self {
+                preset,
+                accent: Color::Cyan,
+                form: Color::Green,
+                focus: Color::Yellow,
+                inactive: Color::Gray,
+                muted: Color::DarkGray,
+                highlight: Color::DarkGray,
+                highlight_text: Color::Reset,
+                error: Color::Red,
+                success: Color::Green,
+            },
+            ThemePreset::Light => Self {
+                preset,
+                accent: Color::Blue,
+                form: Color::Indexed(28),
+                focus: Color::Magenta,
+                inactive: Color::DarkGray,
+                muted: Color::Indexed(242),
+                highlight: Color::Indexed(153),
+                highlight_text: Color::Black,
+                error: Color::Indexed(124),
+                success: Color::Indexed(28),
+            },
+            ThemePreset::HighContrast => Self {
+                preset,
+                accent: Color::White,
+                form: Color::White,
+                focus: Color::Yellow,
+                inactive: Color::White,
+                muted: Color::White,
+                highlight: Color::Yellow,
+                highlight_text: Color::Black,
+                error: Color::Yellow,
+                success: Color::White,
+            },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::preset(ThemePreset::default())
+    }
+}
@@ -0,0 +1,300 @@
+//! Screens of the terminal UI and the stack that runs them.
+//!
+//! Each screen implements [`Screen`] in its own module and asks for the next
+//! one through a [`Transition`]; popups are screens too, pushed over the one
+//! they belong to and popped when dismissed.
+
+mod app;
+mod dashboard;
+mod login;
+mod menu;
+mod message;
+mod registration;
+mod users;
+
+pub use app::{App, RenderMode};
+pub use menu::MenuScreen;
+pub use message::MessageScreen;
+
+use crate::{i18n::Text, keymap::Action, keymap::KeyChord};
+use education_platform::prelude::DateTime;
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyEventKind},
+    layout::{Position, Rect},
+    style::Style,
+    text::Line,
+    widgets::Paragraph,
+};
+use std::{
+    io,
+    sync::mpsc::{Receiver, TryRecvError},
+    time::{Duration, Instant},
+};
+
+/// How often the screen is redrawn and background work is checked when no key is pressed.
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// Frames of the busy indicator, one per tick.
+const SPINNER: [&str; 4] = ["|", "/", "-", "\\"];
+
+/// One screen of the terminal UI, or a popup over one.
+pub trait Screen {
+    /// Draws the screen in the standard render mode. `focused` is `false`
+    /// while a popup covers it, so it leaves the terminal cursor alone.
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, focused: bool);
+
+    /// Adds the screen to the accessible view, one plain line per item in
+    /// focus order, and returns where the focus is.
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus;
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition;
+
+    /// Runs every tick while the screen is on top, to pick up background work.
+    fn on_tick(&mut self, _app: &mut App) -> Transition {
+        Transition::Stay
+    }
+
+    /// Called when the screen is pushed onto the stack.
+    fn on_enter(&mut self, _app: &mut App) {}
+
+    /// Called when the screen leaves the stack.
+    fn on_exit(&mut self, _app: &mut App) {}
+
+    /// Names the background task the screen is waiting for, if any.
+    fn busy(&self, _app: &App) -> Option<&'static str> {
+        None
+    }
+
+    /// Popups draw over the screen below them instead of replacing it.
+    fn is_modal(&self) -> bool {
+        false
+    }
+}
+
+/// What the stack does after a screen handled a key or a tick.
+pub enum Transition {
+    Stay,
+    /// Opens a screen over the current one.
+    Push(Box<dyn Screen>),
+    /// Closes the current screen, returning to the one below.
+    Pop,
+    /// Closes the current screen and opens another in its place, such as
+    /// a confirmation popup over the screen the form came from.
+    Replace(Box<dyn Screen>),
+    Quit,
+}
+
+/// The focused line of the accessible view.
+#[derive(Debug, Clone, Copy)]
+pub struct Focus {
+    pub line: usize,
+    /// Characters between the edit position and the end of the line, so
+    /// the cursor sits where the next character goes in a text field.
+    pub after_cursor: usize,
+}
+
+impl Focus {
+    pub fn line(line: usize) -> Self {
+        Self {
+            line,
+            after_cursor: 0,
+        }
+    }
+}
+
+/// Open screens, the one receiving keys on top.
+pub struct ScreenStack {
+    screens: Vec<Box<dyn Screen>>,
+}
+
+impl ScreenStack {
+    pub fn new(first: Box<dyn Screen>, app: &mut App) -> Self {
+        let mut stack = Self { screens: Vec::new() };
+        stack.push(first, app);
+        stack
+    }
+
+    pub fn push(&mut self, mut screen: Box<dyn Screen>, app: &mut App) {
+        screen.on_enter(app);
+        self.screens.push(screen);
+    }
+
+    fn pop(&mut self, app: &mut App) {
+        if let Some(mut screen) = self.screens.pop() {
+            screen.on_exit(app);
+        }
+    }
+
+    /// Applies a transition. Returns `false` once there is nothing left to show.
+    fn apply(&mut self, transition: Transition, app: &mut App) -> bool {
+        match transition {
+            Transition::Stay => {}
+            Transition::Push(screen) => self.push(screen, app),
+            Transition::Pop => self.pop(app),
+            Transition::Replace(screen) => {
+                self.pop(app);
+                self.push(screen, app);
+            }
+            Transition::Quit => self.screens.clear(),
+        }
+        !self.screens.is_empty()
+    }
+
+    /// Index of the lowest visible screen: the top one and the popups over it.
+    fn base(&self) -> usize {
+        self.screens
+            .iter()
+            .rposition(|screen| !screen.is_modal())
+            .unwrap_or(0)
+    }
+
+    fn busy(&self, app: &App) -> Option<&'static str> {
+        self.screens.iter().find_map(|screen| screen.busy(app))
+    }
+
+    /// Redraws and handles keys and ticks until a screen quits or the last one closes.
+    pub fn run(mut self, mut app: App, mut terminal: DefaultTerminal) -> io::Result<()> {
+        let mut last_tick = Instant::now();
+        loop {
+            terminal.draw(|frame| self.draw(frame, &app))?;
+
+            let timeout = TICK_RATE.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)?
+                && let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && let Some(top) = self.screens.last_mut()
+            {
+                let transition = top.handle_key(KeyChord::from(key), &mut app);
+                if !self.apply(transition, &mut app) {
+                    return Ok(());
+                }
+            }
+
+            if last_tick.elapsed() >= TICK_RATE {
+                app.ticks = app.ticks.wrapping_add(1);
+                if let Some(top) = self.screens.last_mut() {
+                    let transition = top.on_tick(&mut app);
+                    if !self.apply(transition, &mut app) {
+                        return Ok(());
+                    }
+                }
+                last_tick = Instant::now();
+            }
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame, app: &App) {
+        let area = frame.area();
+        if app.mode == RenderMode::Accessible {
+            self.draw_accessible(frame, area, app);
+            return;
+        }
+
+        let busy = self.busy(app);
+        let base = self.base();
+        let top = self.screens.len().saturating_sub(1);
+        for (index, screen) in self.screens.iter_mut().enumerate().skip(base) {
+            screen.draw(frame, area, app, index == top && busy.is_none());
+            if index == base {
+                draw_status_bar(frame, area, app, busy);
+            }
+        }
+    }
+
+    /// Lists the visible screens top first, so a popup is read before what it covers.
+    fn draw_accessible(&self, frame: &mut Frame, area: Rect, app: &App) {
+        // No clock here: a line that changes every second would keep screen
+        // readers announcing it.
+        let mut lines = Vec::new();
+        if let Some(user) = &app.session {
+            let signed_in = app
+                .catalog
+                .format(Text::SignedInAs, &[&user.name().full_name()]);
+            lines.push(format!("{}.", signed_in));
+        }
+        if let Some(label) = self.busy(app) {
+            lines.push(format!(
+                "{} {}",
+                app.catalog.format(Text::PleaseWait, &[&label]),
+                app.spoken_key_help(&[(&[Action::Back], Text::Cancel)])
+            ));
+            lines.push(String::new());
+        }
+
+        let mut focus = None;
+        for screen in self.screens[self.base()..].iter().rev() {
+            let described = screen.describe(app, &mut lines);
+            focus.get_or_insert(described);
+            lines.push(String::new());
+        }
+        lines.pop();
+
+        let text: Vec<Line> = lines.iter().map(|line| Line::raw(line.as_str())).collect();
+        frame.render_widget(Paragraph::new(text), area);
+
+        // Screen readers follow the terminal cursor, so park it on the focused line.
+        let Some(focus) = focus else {
+            return;
+        };
+        let column = lines
+            .get(focus.line)
+            .map_or(0, |line| line.chars().count().saturating_sub(focus.after_cursor));
+        if let (Ok(x), Ok(y)) = (u16::try_from(column), u16::try_from(focus.line))
+            && x < area.width
+            && y < area.height
+        {
+            frame.set_cursor_position(Position::new(area.x + x, area.y + y));
+        }
+    }
+}
+
+/// Draws who is signed in, background task status and the clock over the top border.
+fn draw_status_bar(frame: &mut Frame, area: Rect, app: &App, busy: Option<&str>) {
+    let mut status = String::from(" ");
+    if let Some(user) = &app.session {
+        let signed_in = app
+            .catalog
+            .format(Text::SignedInAs, &[&user.name().full_name()]);
+        status.push_str(&format!("{} | ", signed_in));
+    }
+    if let Some(label) = busy {
+        let frame_index = app.ticks % SPINNER.len();
+        status.push_str(&format!("{} {}… | ", SPINNER[frame_index], label));
+    }
+    status.push_str(&format!("{} ", DateTime::today().format("%H:%M:%S")));
+
+    let status_area = Rect::new(area.x + 1, area.y, area.width.saturating_sub(2), 1);
+    let paragraph = Paragraph::new(status)
+        .style(Style::default().fg(app.config.theme.muted))
+        .right_aligned();
+    frame.render_widget(paragraph, status_area);
+}
+
+/// A `width` by `height` rectangle in the middle of `area`, shrunk to fit.
+pub fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Takes the result of a finished background task, clearing its slot.
+///
+/// A task that ended without sending anything yields `stopped` as its error.
+pub fn take_finished<T>(
+    task: &mut Option<Receiver<Result<T, String>>>,
+    stopped: &str,
+) -> Option<Result<T, String>> {
+    let result = match task.as_ref()?.try_recv() {
+        Err(TryRecvError::Empty) => return None,
+        Ok(result) => result,
+        Err(TryRecvError::Disconnected) => Err(stopped.to_string()),
+    };
+    *task = None;
+    Some(result)
+}
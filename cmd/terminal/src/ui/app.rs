@@ -0,0 +1,258 @@
+use crate::{
+    config::Config,
+    i18n::{Catalog, Text},
+    keymap::{Action, KeyChord},
+    text_input::TextInput,
+    theme::Theme,
+};
+use education_platform::prelude::{CourseProgress, User};
+use ratatui::{
+    Frame,
+    crossterm::event::{KeyCode, KeyModifiers},
+    layout::{Position, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+};
+use std::{env, mem};
+
+/// Environment variable selecting the render mode when no flag is given.
+const RENDER_MODE_VAR: &str = "EDUCATION_PLATFORM_TUI_MODE";
+
+/// Actions sharing one description in a help line.
+pub type HelpEntry = (&'static [Action], Text);
+
+/// Spoken help for the forms' password fields.
+pub const TOGGLE_PASSWORD_HELP: HelpEntry = (&[Action::TogglePassword], Text::ShowOrHidePassword);
+
+/// How screens are drawn.
+///
+/// `Accessible` draws one plain line per item in focus order, without
+/// borders, colors as the only cue or glyph cursors, so screen readers can
+/// follow the text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    #[default]
+    Standard,
+    Accessible,
+}
+
+impl RenderMode {
+    /// Reads the mode from `--accessible`/`--standard`, falling back to
+    /// `EDUCATION_PLATFORM_TUI_MODE=accessible`.
+    pub fn from_env() -> Self {
+        let flag = env::args().skip(1).find_map(|arg| match arg.as_str() {
+            "--accessible" => Some(Self::Accessible),
+            "--standard" => Some(Self::Standard),
+            _ => None,
+        });
+        flag.unwrap_or_else(|| match env::var(RENDER_MODE_VAR) {
+            Ok(value) if value.trim().eq_ignore_ascii_case("accessible") => Self::Accessible,
+            _ => Self::Standard,
+        })
+    }
+}
+
+/// State shared by every screen: settings, the session and the data they show.
+#[derive(Debug)]
+pub struct App {
+    pub mode: RenderMode,
+    pub config: Config,
+    /// Strings in the configured locale's language.
+    pub catalog: Catalog,
+    /// Users registered since the app started; nothing is persisted.
+    pub users: Vec<User>,
+    /// Course progress of every user. Nothing in the terminal records any
+    /// yet, so the menu holds back the progress dashboard.
+    pub progress: Vec<CourseProgress>,
+    pub session: Option<User>,
+    /// Ticks since start, driving the busy indicator.
+    pub ticks: usize,
+    /// A `g` was pressed in a list and a second one jumps to the top.
+    pending_g: bool,
+}
+
+impl App {
+    pub fn new(mode: RenderMode, config: Config) -> Self {
+        Self {
+            mode,
+            catalog: Catalog::new(&config.locale),
+            config,
+            users: Vec::new(),
+            progress: Vec::new(),
+            session: None,
+            ticks: 0,
+            pending_g: false,
+        }
+    }
+
+    /// Describes the theme, locale and data directory in use.
+    pub fn settings_summary(&self) -> String {
+        self.catalog.format(
+            Text::SettingsSummary,
+            &[
+                &self.config.theme.preset.name(),
+                &self.config.locale.as_str(),
+                &self.config.storage_path.display(),
+            ],
+        )
+    }
+
+    /// Switches to the next built-in theme, dropping any colors overridden in the config.
+    pub fn cycle_theme(&mut self) {
+        self.config.theme = Theme::preset(self.config.theme.preset.next());
+    }
+
+    /// Returns the first of `candidates` bound to the chord.
+    pub fn action(&self, key: KeyChord, candidates: &[Action]) -> Option<Action> {
+        self.config.keymap.action(key, candidates)
+    }
+
+    /// Resolves a chord on a list screen, including vim's two-key `gg`
+    /// when vim navigation is on: the first `g` is held back and the
+    /// second one means [`Action::First`].
+    pub fn list_action(&mut self, key: KeyChord, candidates: &[Action]) -> Option<Action> {
+        let g = KeyChord::new(KeyCode::Char('g'), KeyModifiers::NONE);
+        let pending_g = mem::take(&mut self.pending_g);
+        if self.config.vim_navigation && key == g && candidates.contains(&Action::First) {
+            self.pending_g = !pending_g;
+            return pending_g.then_some(Action::First);
+        }
+        self.action(key, candidates)
+    }
+
+    /// Lists keys for a help line with the current bindings, as in
+    /// `Up/Down: Navigate | Enter: Select`.
+    pub fn key_help(&self, entries: &[HelpEntry]) -> String {
+        entries
+            .iter()
+            .map(|&(actions, what)| {
+                format!("{}: {}", self.keys_for(actions, "/"), self.catalog.get(what))
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    /// The accessible wording of [`App::key_help`], as in
+    /// `Up or Down: navigate. Enter: select.`
+    pub fn spoken_key_help(&self, entries: &[HelpEntry]) -> String {
+        entries
+            .iter()
+            .map(|&(actions, what)| {
+                let or = format!(" {} ", self.catalog.get(Text::KeyOr));
+                format!(
+                    "{}: {}.",
+                    self.keys_for(actions, &or),
+                    self.catalog.get(what).to_lowercase()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn keys_for(&self, actions: &[Action], separator: &str) -> String {
+        actions
+            .iter()
+            .map(|&action| self.config.keymap.label(action, separator))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// Draws a help line in the muted color.
+    pub fn draw_help(&self, frame: &mut Frame, area: Rect, help: String) {
+        let paragraph = Paragraph::new(help).style(Style::default().fg(self.config.theme.muted));
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Draws a text field; `is_active` highlights it and places the cursor in it.
+    pub fn draw_input_field(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        label: &str,
+        input: &TextInput,
+        is_active: bool,
+        error: Option<&str>,
+    ) {
+        let (visible, column) = input.window(usize::from(area.width.saturating_sub(2)));
+        let block = self.field_block(label, is_active, error);
+        draw_text_field(frame, area, block, visible, column, is_active);
+    }
+
+    /// Draws a password masked unless `revealed`; the title shows the key
+    /// that flips it while the field is focused.
+    pub fn draw_password_field(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        input: &TextInput,
+        is_active: bool,
+        error: Option<&str>,
+        revealed: bool,
+    ) {
+        let toggle = self.config.keymap.label(Action::TogglePassword, "/");
+        let label = match (is_active, revealed) {
+            (true, false) => self.catalog.format(Text::PasswordShowHint, &[&toggle]),
+            (true, true) => self.catalog.format(Text::PasswordHideHint, &[&toggle]),
+            (false, _) => self.catalog.get(Text::Password).to_string(),
+        };
+        let (visible, column) = input.window(usize::from(area.width.saturating_sub(2)));
+        let text = if revealed {
+            visible
+        } else {
+            "*".repeat(visible.chars().count())
+        };
+        let block = self.field_block(&label, is_active, error);
+        draw_text_field(frame, area, block, text, column, is_active);
+    }
+
+    /// Border of a field, with its validation error on the bottom edge.
+    fn field_block<'a>(
+        &self,
+        label: &'a str,
+        is_active: bool,
+        error: Option<&'a str>,
+    ) -> Block<'a> {
+        let theme = &self.config.theme;
+        let border_color = match (is_active, error) {
+            (true, _) => theme.focus,
+            (false, Some(_)) => theme.error,
+            (false, None) => theme.inactive,
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", label))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color));
+
+        match error {
+            Some(error) => block.title_bottom(Line::styled(
+                format!(" {} ", error),
+                Style::default().fg(theme.error),
+            )),
+            None => block,
+        }
+    }
+}
+
+/// Draws a field's text and, when it is being edited, places the
+/// terminal cursor at `column` within it.
+fn draw_text_field(
+    frame: &mut Frame,
+    area: Rect,
+    block: Block,
+    visible: String,
+    column: usize,
+    is_active: bool,
+) {
+    let paragraph = Paragraph::new(visible).block(block);
+    frame.render_widget(paragraph, area);
+
+    if is_active
+        && let Ok(x) = u16::try_from(column)
+        && x + 2 < area.width
+        && area.height > 2
+    {
+        frame.set_cursor_position(Position::new(area.x + 1 + x, area.y + 1));
+    }
+}
@@ -0,0 +1,158 @@
+use super::{App, Focus, Screen, Transition, app::HelpEntry};
+use crate::{
+    dashboard::{ACTIVITY_DAYS, CourseSummary, Dashboard},
+    i18n::Text,
+    keymap::{Action, KeyChord},
+};
+use education_platform::prelude::{Date, ProgressReport};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Gauge, Paragraph, Sparkline},
+};
+
+const DASHBOARD_HELP: &[HelpEntry] = &[(&[Action::Back], Text::Back)];
+
+/// The signed-in user's streaks, recent activity and course progress.
+#[derive(Debug)]
+pub struct DashboardScreen;
+
+/// The signed-in user's dashboard as of today.
+fn dashboard(app: &App) -> Option<Dashboard> {
+    let user = app.session.as_ref()?;
+    Some(Dashboard::new(&app.progress, user.email(), Date::today()))
+}
+
+/// Percentage, lesson count and estimated finish of one course.
+fn course_label(app: &App, report: &ProgressReport) -> String {
+    let finish = report.estimated_finish_date().map_or_else(
+        || app.catalog.get(Text::NotEstimated).to_string(),
+        |date| date.format_iso(),
+    );
+    app.catalog.format(
+        Text::CourseProgressLabel,
+        &[
+            &report.percentage_completed(),
+            &report.lessons_completed(),
+            &report.total_lessons(),
+            &finish,
+        ],
+    )
+}
+
+/// Course name, marked when it is one of the user's favorites; the spoken
+/// form says so in words instead of a star.
+fn course_title(app: &App, course: &CourseSummary, spoken: bool) -> String {
+    match (course.favorite, spoken) {
+        (false, _) => course.name.clone(),
+        (true, false) => app.catalog.format(Text::FavoriteCourse, &[&course.name]),
+        (true, true) => app
+            .catalog
+            .format(Text::FavoriteCourseSpoken, &[&course.name]),
+    }
+}
+
+fn streaks(app: &App, dashboard: &Dashboard) -> String {
+    format!(
+        "{} | {}",
+        app.catalog
+            .format(Text::CurrentStreak, &[&dashboard.current_streak]),
+        app.catalog
+            .format(Text::LongestStreak, &[&dashboard.longest_streak])
+    )
+}
+
+impl Screen for DashboardScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, _focused: bool) {
+        let theme = &app.config.theme;
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(Text::MyProgress)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.form));
+        frame.render_widget(block, area);
+
+        let Some(dashboard) = dashboard(app) else {
+            return;
+        };
+        let inner_area = Rect::new(area.x + 2, area.y + 2, area.width - 4, area.height - 4);
+        let chunks = Layout::vertical([
+            Constraint::Length(1), // Streaks
+            Constraint::Length(4), // Activity
+            Constraint::Min(3),    // Courses
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner_area);
+
+        frame.render_widget(Paragraph::new(streaks(app, &dashboard)), chunks[0]);
+
+        let activity = Sparkline::default()
+            .block(
+                Block::default()
+                    .title(app.catalog.format(Text::Activity, &[&ACTIVITY_DAYS]))
+                    .borders(Borders::TOP)
+                    .border_style(Style::default().fg(theme.inactive)),
+            )
+            .data(&dashboard.activity)
+            .style(Style::default().fg(theme.accent));
+        frame.render_widget(activity, chunks[1]);
+
+        if dashboard.courses.is_empty() {
+            frame.render_widget(
+                Paragraph::new(app.catalog.get(Text::NoCourses))
+                    .style(Style::default().fg(theme.muted)),
+                chunks[2],
+            );
+        } else {
+            let rows = Layout::vertical(vec![Constraint::Length(3); dashboard.courses.len()])
+                .split(chunks[2]);
+            for (course, &row) in dashboard.courses.iter().zip(rows.iter()) {
+                let color = if course.report.percentage_completed().is_full() {
+                    theme.success
+                } else {
+                    theme.focus
+                };
+                let gauge = Gauge::default()
+                    .block(
+                        Block::default()
+                            .title(format!(" {} ", course_title(app, course, false)))
+                            .borders(Borders::ALL)
+                            .border_style(Style::default().fg(theme.inactive)),
+                    )
+                    .gauge_style(Style::default().fg(color))
+                    .ratio(course.report.percentage_completed().ratio().clamp(0.0, 1.0))
+                    .label(course_label(app, &course.report));
+                frame.render_widget(gauge, row);
+            }
+        }
+
+        app.draw_help(frame, chunks[3], app.key_help(DASHBOARD_HELP));
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        lines.push(app.catalog.get(Text::ProgressHeading).to_string());
+        let focus = Focus::line(lines.len());
+        if let Some(dashboard) = dashboard(app) {
+            lines.push(format!("{}.", streaks(app, &dashboard)));
+            if dashboard.courses.is_empty() {
+                lines.push(app.catalog.get(Text::NoCourses).to_string());
+            }
+            for course in &dashboard.courses {
+                lines.push(format!(
+                    "{}: {}.",
+                    course_title(app, course, true),
+                    course_label(app, &course.report)
+                ));
+            }
+        }
+        lines.push(app.spoken_key_help(DASHBOARD_HELP));
+        focus
+    }
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition {
+        match app.action(key, &[Action::Back]) {
+            Some(_) => Transition::Pop,
+            None => Transition::Stay,
+        }
+    }
+}
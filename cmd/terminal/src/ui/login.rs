@@ -0,0 +1,278 @@
+use super::{
+    App, Focus, MessageScreen, Screen, Transition,
+    app::{HelpEntry, TOGGLE_PASSWORD_HELP},
+    take_finished,
+};
+use crate::{
+    hashing::Argon2Verifier,
+    i18n::{Catalog, Text},
+    keymap::{Action, KeyChord},
+    text_input::TextInput,
+};
+use education_platform::prelude::{User, UserError};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders},
+};
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+const LOGIN_FIELD_HELP: &[HelpEntry] = &[(&[Action::NextField], Text::NextField)];
+
+const LOGIN_SUBMIT_HELP: &[HelpEntry] = &[
+    (&[Action::Submit], Text::LogIn),
+    (&[Action::Back], Text::Back),
+];
+
+/// Outcome of checking a password on a background thread: the signed-in user.
+type LoginResult = Result<User, String>;
+
+/// Form data for logging in.
+#[derive(Debug, Default)]
+struct LoginForm {
+    email: TextInput,
+    password: TextInput,
+    active_field: LoginField,
+    /// Shows the password in plain text until focus leaves it.
+    show_password: bool,
+}
+
+/// Fields in the login form.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum LoginField {
+    #[default]
+    Email,
+    Password,
+}
+
+impl LoginField {
+    /// Fields in focus order.
+    const ALL: [Self; 2] = [Self::Email, Self::Password];
+
+    fn position(self) -> usize {
+        match self {
+            Self::Email => 0,
+            Self::Password => 1,
+        }
+    }
+}
+
+impl LoginForm {
+    /// Describes a field as one line of plain text; the password reports only its length.
+    fn describe(&self, field: LoginField, catalog: Catalog) -> String {
+        let email = catalog.get(Text::Email);
+        let password = catalog.get(Text::Password);
+        match field {
+            LoginField::Email if self.email.is_empty() => {
+                catalog.format(Text::FieldBlank, &[&email])
+            }
+            LoginField::Email => format!("{}: {}", email, self.email.value()),
+            LoginField::Password if self.show_password => {
+                catalog.format(Text::PasswordShown, &[&password, &self.password.value()])
+            }
+            LoginField::Password => {
+                catalog.format(Text::CharactersEntered, &[&password, &self.password.char_count()])
+            }
+        }
+    }
+
+    fn toggle_field(&mut self) {
+        self.show_password = false;
+        self.active_field = match self.active_field {
+            LoginField::Email => LoginField::Password,
+            LoginField::Password => LoginField::Email,
+        };
+    }
+
+    fn active_input_mut(&mut self) -> &mut TextInput {
+        match self.active_field {
+            LoginField::Email => &mut self.email,
+            LoginField::Password => &mut self.password,
+        }
+    }
+
+    /// Characters after the edit position of the focused field in the
+    /// accessible view; a hidden password only gives a count, so none.
+    fn chars_after_cursor(&self) -> usize {
+        match self.active_field {
+            LoginField::Email => self.email.chars_after_cursor(),
+            LoginField::Password if self.show_password => self.password.chars_after_cursor(),
+            LoginField::Password => 0,
+        }
+    }
+}
+
+/// Signs a registered user in.
+#[derive(Debug, Default)]
+pub struct LoginScreen {
+    form: LoginForm,
+    /// The password check running in the background, if submitted.
+    verifying: Option<Receiver<LoginResult>>,
+}
+
+impl LoginScreen {
+    /// Looks up the user by email and checks the password on a background
+    /// thread, since verifying costs as much as hashing.
+    fn submit(&mut self, app: &mut App) -> Transition {
+        let email = self.form.email.value().trim();
+        let Some(user) = app
+            .users
+            .iter()
+            .find(|user| user.email().address().eq_ignore_ascii_case(email))
+            .cloned()
+        else {
+            return self.finish(Err(app.catalog.get(Text::InvalidLogin).to_string()), app);
+        };
+
+        let password = self.form.password.value().to_string();
+        let catalog = app.catalog;
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let result = match user.verify_password(&password, &Argon2Verifier) {
+                Ok(()) => Ok(user),
+                Err(UserError::NoPasswordSet) => Err(catalog.get(Text::NoPasswordSet).to_string()),
+                Err(_) => Err(catalog.get(Text::InvalidLogin).to_string()),
+            };
+            // The receiver is gone if the user cancelled; the result is simply dropped.
+            let _ = sender.send(result);
+        });
+        self.verifying = Some(receiver);
+        Transition::Stay
+    }
+
+    fn finish(&mut self, result: LoginResult, app: &mut App) -> Transition {
+        match result {
+            Ok(user) => {
+                let welcome = app
+                    .catalog
+                    .format(Text::Welcome, &[&user.name().full_name()]);
+                app.session = Some(user);
+                Transition::Replace(MessageScreen::success(welcome))
+            }
+            Err(e) => {
+                self.form.password.clear();
+                Transition::Push(MessageScreen::error(e))
+            }
+        }
+    }
+}
+
+impl Screen for LoginScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, focused: bool) {
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(Text::LogIn)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.config.theme.form));
+
+        frame.render_widget(block, area);
+
+        let inner_area = Rect::new(area.x + 2, area.y + 2, area.width - 4, area.height - 4);
+
+        let chunks = Layout::vertical([
+            Constraint::Length(3), // Email
+            Constraint::Length(3), // Password
+            Constraint::Min(1),    // Help text
+        ])
+        .split(inner_area);
+
+        let form = &self.form;
+        app.draw_input_field(
+            frame,
+            chunks[0],
+            app.catalog.get(Text::Email),
+            &form.email,
+            focused && form.active_field == LoginField::Email,
+            None,
+        );
+        app.draw_password_field(
+            frame,
+            chunks[1],
+            &form.password,
+            focused && form.active_field == LoginField::Password,
+            None,
+            form.show_password,
+        );
+
+        let help = format!(
+            "{} | {} | {}",
+            app.key_help(LOGIN_FIELD_HELP),
+            app.catalog.get(Text::MoveInField),
+            app.key_help(LOGIN_SUBMIT_HELP),
+        );
+        app.draw_help(frame, chunks[2], help);
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        lines.push(app.catalog.get(Text::LoginHeading).to_string());
+        let focus = Focus {
+            line: lines.len() + self.form.active_field.position(),
+            after_cursor: self.form.chars_after_cursor(),
+        };
+        for field in LoginField::ALL {
+            let marker = if self.form.active_field == field {
+                "> "
+            } else {
+                "  "
+            };
+            lines.push(format!("{}{}", marker, self.form.describe(field, app.catalog)));
+        }
+        lines.push(format!(
+            "{} {} {} {}",
+            app.spoken_key_help(LOGIN_FIELD_HELP),
+            app.catalog.get(Text::MoveInFieldSpoken),
+            app.spoken_key_help(&[TOGGLE_PASSWORD_HELP]),
+            app.spoken_key_help(LOGIN_SUBMIT_HELP),
+        ));
+        focus
+    }
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition {
+        if self.verifying.is_some() {
+            // The form stays as submitted until the check finishes; Back
+            // abandons it and lets the user edit again.
+            if app.action(key, &[Action::Back]).is_some() {
+                self.verifying = None;
+            }
+            return Transition::Stay;
+        }
+
+        let handled = [
+            Action::Back,
+            Action::NextField,
+            Action::PreviousField,
+            Action::Submit,
+            Action::TogglePassword,
+        ];
+        match app.action(key, &handled) {
+            Some(Action::Back) => return Transition::Pop,
+            Some(Action::NextField | Action::PreviousField) => self.form.toggle_field(),
+            Some(Action::TogglePassword) if self.form.active_field == LoginField::Password => {
+                self.form.show_password = !self.form.show_password;
+            }
+            Some(Action::Submit) => return self.submit(app),
+            _ => {
+                if let Some(code) = key.editing_key() {
+                    self.form.active_input_mut().handle_key(code);
+                }
+            }
+        }
+        Transition::Stay
+    }
+
+    fn on_tick(&mut self, app: &mut App) -> Transition {
+        match take_finished(&mut self.verifying, app.catalog.get(Text::TaskStopped)) {
+            Some(result) => self.finish(result, app),
+            None => Transition::Stay,
+        }
+    }
+
+    fn busy(&self, app: &App) -> Option<&'static str> {
+        self.verifying
+            .as_ref()
+            .map(|_| app.catalog.get(Text::CheckingPassword))
+    }
+}
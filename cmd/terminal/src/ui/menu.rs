@@ -0,0 +1,200 @@
+use super::{
+    App, Focus, Screen, Transition, app::HelpEntry, dashboard::DashboardScreen, login::LoginScreen,
+    registration::RegistrationScreen, users::UsersScreen,
+};
+use crate::{
+    i18n::Text,
+    keymap::{Action, KeyChord},
+};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState},
+};
+
+const MENU_HELP: &[HelpEntry] = &[
+    (&[Action::MoveUp, Action::MoveDown], Text::Navigate),
+    (&[Action::Select], Text::Select),
+    (&[Action::CycleTheme], Text::Theme),
+    (&[Action::Quit], Text::Quit),
+];
+
+/// Entries of the main menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MenuAction {
+    LogIn,
+    Progress,
+    RegisterUser,
+    ManageUsers,
+    LogOut,
+    Exit,
+}
+
+impl MenuAction {
+    fn label(self) -> Text {
+        match self {
+            Self::LogIn => Text::LogIn,
+            Self::Progress => Text::MyProgress,
+            Self::RegisterUser => Text::RegisterUser,
+            Self::ManageUsers => Text::ManageUsers,
+            Self::LogOut => Text::LogOut,
+            Self::Exit => Text::Exit,
+        }
+    }
+}
+
+/// The main menu, at the bottom of the screen stack.
+#[derive(Debug, Default)]
+pub struct MenuScreen {
+    state: ListState,
+    /// Entries last shown; the highlight goes back to the top when they change.
+    actions: Vec<MenuAction>,
+}
+
+impl MenuScreen {
+    /// Picks up entry changes from signing in or out and returns the entries.
+    fn refresh(&mut self, app: &App) -> Vec<MenuAction> {
+        let actions = menu_actions(app);
+        if actions != self.actions {
+            self.actions.clone_from(&actions);
+            self.state.select(Some(0));
+        }
+        actions
+    }
+}
+
+/// Menu entries for the current session.
+///
+/// Registering users needs a signed-in user, except for the very first
+/// account, when there is nobody to sign in as. The progress dashboard is
+/// only offered once the signed-in user has course progress to show.
+fn menu_actions(app: &App) -> Vec<MenuAction> {
+    match (&app.session, app.users.is_empty()) {
+        (Some(user), _) => {
+            let has_progress = app
+                .progress
+                .iter()
+                .any(|course| course.user_email() == user.email());
+            has_progress
+                .then_some(MenuAction::Progress)
+                .into_iter()
+                .chain([
+                    MenuAction::RegisterUser,
+                    MenuAction::ManageUsers,
+                    MenuAction::LogOut,
+                    MenuAction::Exit,
+                ])
+                .collect()
+        }
+        (None, true) => vec![MenuAction::RegisterUser, MenuAction::Exit],
+        (None, false) => vec![MenuAction::LogIn, MenuAction::Exit],
+    }
+}
+
+impl Screen for MenuScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, _focused: bool) {
+        let actions = self.refresh(app);
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(Text::AppTitle)))
+            .title_bottom(Line::from(format!(" {} ", app.settings_summary())).right_aligned())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.config.theme.accent));
+
+        let items: Vec<ListItem> = actions
+            .into_iter()
+            .map(|action| ListItem::new(app.catalog.get(action.label())))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(app.config.theme.highlight_text)
+                    .bg(app.config.theme.highlight),
+            )
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(list, area, &mut self.state);
+
+        let help_area = Rect::new(area.x + 1, area.bottom() - 1, area.width - 2, 1);
+        app.draw_help(frame, help_area, app.key_help(MENU_HELP));
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        lines.push(app.catalog.get(Text::MenuHeading).to_string());
+        lines.push(format!("{}.", app.settings_summary()));
+        let actions = menu_actions(app);
+        let selected = self
+            .state
+            .selected()
+            .unwrap_or(0)
+            .min(actions.len().saturating_sub(1));
+        let focus = Focus::line(lines.len() + selected);
+        for (index, action) in actions.iter().enumerate() {
+            let marker = if index == selected { "> " } else { "  " };
+            let item = app.catalog.format(
+                Text::ItemPosition,
+                &[
+                    &app.catalog.get(action.label()),
+                    &(index + 1),
+                    &actions.len(),
+                ],
+            );
+            lines.push(format!("{}{}", marker, item));
+        }
+        lines.push(app.spoken_key_help(MENU_HELP));
+        focus
+    }
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition {
+        let actions = self.refresh(app);
+        let handled = [
+            Action::MoveUp,
+            Action::MoveDown,
+            Action::First,
+            Action::Last,
+            Action::Select,
+            Action::CycleTheme,
+            Action::Quit,
+        ];
+        match app.list_action(key, &handled) {
+            Some(Action::MoveUp) => {
+                let i = self.state.selected().unwrap_or(0);
+                let new_index = if i == 0 { actions.len() - 1 } else { i - 1 };
+                self.state.select(Some(new_index));
+            }
+            Some(Action::MoveDown) => {
+                let i = self.state.selected().unwrap_or(0);
+                let new_index = (i + 1) % actions.len();
+                self.state.select(Some(new_index));
+            }
+            Some(Action::First) => self.state.select(Some(0)),
+            Some(Action::Last) => self.state.select(Some(actions.len() - 1)),
+            Some(Action::Select) => {
+                let selected = self.state.selected().unwrap_or(0);
+                return match actions.get(selected) {
+                    Some(MenuAction::LogIn) => Transition::Push(Box::<LoginScreen>::default()),
+                    Some(MenuAction::Progress) => Transition::Push(Box::new(DashboardScreen)),
+                    Some(MenuAction::RegisterUser) => {
+                        Transition::Push(Box::<RegistrationScreen>::default())
+                    }
+                    Some(MenuAction::ManageUsers) => {
+                        Transition::Push(Box::<UsersScreen>::default())
+                    }
+                    Some(MenuAction::LogOut) => {
+                        app.session = None;
+                        Transition::Stay
+                    }
+                    Some(MenuAction::Exit) | None => Transition::Quit,
+                };
+            }
+            Some(Action::CycleTheme) => app.cycle_theme(),
+            Some(Action::Quit) => return Transition::Quit,
+            _ => {}
+        }
+        Transition::Stay
+    }
+}
@@ -0,0 +1,95 @@
+use super::{App, Focus, Screen, Transition, centered};
+use crate::{i18n::Text, keymap::KeyChord};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// A popup reporting an outcome; any key closes it.
+#[derive(Debug)]
+pub struct MessageScreen {
+    text: String,
+    is_error: bool,
+}
+
+impl MessageScreen {
+    pub fn error(text: impl Into<String>) -> Box<Self> {
+        Box::new(Self {
+            text: text.into(),
+            is_error: true,
+        })
+    }
+
+    pub fn success(text: impl Into<String>) -> Box<Self> {
+        Box::new(Self {
+            text: text.into(),
+            is_error: false,
+        })
+    }
+
+    fn title(&self) -> Text {
+        if self.is_error {
+            Text::ErrorTitle
+        } else {
+            Text::SuccessTitle
+        }
+    }
+}
+
+impl Screen for MessageScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, _focused: bool) {
+        let popup_width = 50.min(area.width - 4);
+        let text_width = popup_width.saturating_sub(2).max(1);
+        let text_lines = u16::try_from(self.text.chars().count())
+            .unwrap_or(u16::MAX)
+            .div_ceil(text_width);
+        let popup_height = (text_lines + 4).clamp(5, area.height);
+
+        let popup_area = centered(area, popup_width, popup_height);
+
+        frame.render_widget(Clear, popup_area);
+
+        let border_color = if self.is_error {
+            app.config.theme.error
+        } else {
+            app.config.theme.success
+        };
+
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(self.title())))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color));
+
+        let text = vec![
+            Line::from(Span::raw(&self.text)),
+            Line::from(Span::styled(
+                app.catalog.get(Text::PressAnyKey),
+                Style::default().fg(app.config.theme.muted),
+            )),
+        ];
+
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .centered()
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, popup_area);
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        let focus = Focus::line(lines.len());
+        lines.push(format!("{}: {}", app.catalog.get(self.title()), self.text));
+        lines.push(format!("{}.", app.catalog.get(Text::PressAnyKey)));
+        focus
+    }
+
+    fn handle_key(&mut self, _key: KeyChord, _app: &mut App) -> Transition {
+        Transition::Pop
+    }
+
+    fn is_modal(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,538 @@
+use super::{
+    App, Focus, MessageScreen, Screen, Transition,
+    app::{HelpEntry, TOGGLE_PASSWORD_HELP},
+    take_finished,
+};
+use crate::{
+    hashing::hash_password_argon2id,
+    i18n::{Catalog, Text},
+    keymap::{Action, KeyChord},
+    text_input::TextInput,
+};
+use education_platform::prelude::{
+    Document, DocumentFailure, DocumentKind, Email, Name, PasswordPolicy, PasswordStrength, User,
+    UserError,
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, LineGauge},
+};
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+const REGISTER_FIELD_HELP: &[HelpEntry] = &[
+    (&[Action::NextField], Text::NextField),
+    (&[Action::PreviousField], Text::PreviousField),
+];
+
+const REGISTER_SUBMIT_HELP: &[HelpEntry] = &[
+    (&[Action::Submit], Text::Submit),
+    (&[Action::Back], Text::Back),
+];
+
+/// Outcome of hashing a password on a background thread.
+type HashResult = Result<String, String>;
+
+/// Form data for user registration.
+#[derive(Debug, Default)]
+struct RegistrationForm {
+    first_name: TextInput,
+    middle_name: TextInput,
+    last_name: TextInput,
+    second_last_name: TextInput,
+    document: TextInput,
+    email: TextInput,
+    password: TextInput,
+    active_field: FormField,
+    /// Shows the password in plain text until focus leaves it.
+    show_password: bool,
+    /// Validation messages, indexed by `FormField::position`.
+    errors: [Option<FieldError>; 7],
+}
+
+/// Why a registration field was rejected.
+#[derive(Debug, Clone)]
+enum FieldError {
+    /// A required field was left blank.
+    Required,
+    /// The document was rejected for its layout or its check character.
+    Document(DocumentFailure),
+    /// The value object refused the value, with its reason.
+    NotValid(String),
+}
+
+impl FieldError {
+    fn not_valid(reason: impl ToString) -> Self {
+        Self::NotValid(reason.to_string())
+    }
+}
+
+/// Fields in the registration form.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum FormField {
+    #[default]
+    FirstName,
+    MiddleName,
+    LastName,
+    SecondLastName,
+    Document,
+    Email,
+    Password,
+}
+
+/// Registers a new user.
+#[derive(Debug, Default)]
+pub struct RegistrationScreen {
+    form: RegistrationForm,
+    /// The password being hashed in the background, if submitted.
+    hashing: Option<Receiver<HashResult>>,
+}
+
+impl RegistrationScreen {
+    /// Registers the user, hashing the password on a background thread so
+    /// the screen keeps updating during the slow Argon2 run.
+    ///
+    /// Invalid fields are flagged in place and focus moves to the first of
+    /// them instead of submitting.
+    fn submit(&mut self, app: &mut App) -> Transition {
+        if let Some(field) = self.form.validate_all() {
+            self.form.active_field = field;
+            return Transition::Stay;
+        }
+
+        if self.form.password.value().trim().is_empty() {
+            return self.finish(None, app);
+        }
+
+        let password = self.form.password.value().to_string();
+        let params = app.config.argon2.clone();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let result = hash_password_argon2id(&password, params).map_err(|e| e.to_string());
+            // The receiver is gone if the user cancelled; the hash is simply dropped.
+            let _ = sender.send(result);
+        });
+        self.hashing = Some(receiver);
+        Transition::Stay
+    }
+
+    fn finish(&mut self, hashed_password: Option<String>, app: &mut App) -> Transition {
+        let form = &self.form;
+        match User::new(
+            form.first_name.value().to_string(),
+            optional_value(&form.middle_name),
+            form.last_name.value().to_string(),
+            optional_value(&form.second_last_name),
+            form.document.value().to_string(),
+            form.email.value().to_string(),
+            hashed_password,
+        ) {
+            Ok(user) => {
+                let registered = app
+                    .catalog
+                    .format(Text::UserRegistered, &[&user.name().full_name()]);
+                app.users.push(user);
+                Transition::Replace(MessageScreen::success(registered))
+            }
+            Err(e) => Transition::Push(MessageScreen::error(format_user_error(&e, app.catalog))),
+        }
+    }
+}
+
+impl Screen for RegistrationScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, focused: bool) {
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(Text::RegisterUser)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.config.theme.form));
+
+        frame.render_widget(block, area);
+
+        let inner_area = Rect::new(area.x + 2, area.y + 2, area.width - 4, area.height - 4);
+
+        let chunks = Layout::vertical([
+            Constraint::Length(3), // First Name
+            Constraint::Length(3), // Middle Name
+            Constraint::Length(3), // Last Name
+            Constraint::Length(3), // Second Last Name
+            Constraint::Length(3), // Document
+            Constraint::Length(3), // Email
+            Constraint::Length(3), // Password
+            Constraint::Length(1), // Password strength
+            Constraint::Min(1),    // Help text
+        ])
+        .split(inner_area);
+
+        let form = &self.form;
+        for (field, &area) in FormField::ALL.iter().zip(chunks.iter()) {
+            if *field == FormField::Password {
+                continue;
+            }
+            let name = app.catalog.get(field.label());
+            let label = if field.is_required() {
+                format!("{} *", name)
+            } else {
+                name.to_string()
+            };
+            app.draw_input_field(
+                frame,
+                area,
+                &label,
+                form.input(*field),
+                focused && form.active_field == *field,
+                form.error(*field, app.catalog),
+            );
+        }
+        app.draw_password_field(
+            frame,
+            chunks[6],
+            &form.password,
+            focused && form.active_field == FormField::Password,
+            form.error(FormField::Password, app.catalog),
+            form.show_password,
+        );
+        draw_strength_meter(app, frame, chunks[7], &form.password);
+
+        let help = format!(
+            "{} | {} | {}",
+            app.key_help(REGISTER_FIELD_HELP),
+            app.catalog.get(Text::MoveInField),
+            app.key_help(REGISTER_SUBMIT_HELP),
+        );
+        app.draw_help(frame, chunks[8], help);
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        lines.push(app.catalog.get(Text::RegisterHeading).to_string());
+        let focus = Focus {
+            line: lines.len() + self.form.active_field.position(),
+            after_cursor: self.form.chars_after_cursor(),
+        };
+        for field in FormField::ALL {
+            let marker = if self.form.active_field == field {
+                "> "
+            } else {
+                "  "
+            };
+            lines.push(format!("{}{}", marker, self.form.describe(field, app.catalog)));
+        }
+        lines.push(format!(
+            "{} {} {} {}",
+            app.spoken_key_help(REGISTER_FIELD_HELP),
+            app.catalog.get(Text::MoveInFieldSpoken),
+            app.spoken_key_help(&[TOGGLE_PASSWORD_HELP]),
+            app.spoken_key_help(REGISTER_SUBMIT_HELP),
+        ));
+        focus
+    }
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition {
+        if self.hashing.is_some() {
+            // The form stays as submitted until hashing finishes; Back
+            // abandons the hash and lets the user edit again.
+            if app.action(key, &[Action::Back]).is_some() {
+                self.hashing = None;
+            }
+            return Transition::Stay;
+        }
+
+        let handled = [
+            Action::Back,
+            Action::NextField,
+            Action::PreviousField,
+            Action::Submit,
+            Action::TogglePassword,
+        ];
+        match app.action(key, &handled) {
+            Some(Action::Back) => return Transition::Pop,
+            Some(Action::NextField) => self.form.next_field(),
+            Some(Action::PreviousField) => self.form.previous_field(),
+            Some(Action::Submit) => return self.submit(app),
+            Some(Action::TogglePassword) if self.form.active_field == FormField::Password => {
+                self.form.show_password = !self.form.show_password;
+            }
+            _ => {
+                if let Some(code) = key.editing_key()
+                    && self.form.active_input_mut().handle_key(code)
+                {
+                    self.form.revalidate_active();
+                }
+            }
+        }
+        Transition::Stay
+    }
+
+    fn on_tick(&mut self, app: &mut App) -> Transition {
+        match take_finished(&mut self.hashing, app.catalog.get(Text::TaskStopped)) {
+            Some(Ok(hash)) => self.finish(Some(hash), app),
+            Some(Err(e)) => Transition::Push(MessageScreen::error(
+                app.catalog.format(Text::HashingFailed, &[&e]),
+            )),
+            None => Transition::Stay,
+        }
+    }
+
+    fn busy(&self, app: &App) -> Option<&'static str> {
+        self.hashing
+            .as_ref()
+            .map(|_| app.catalog.get(Text::HashingPassword))
+    }
+}
+
+/// Draws a bar filling with the password's score, blank until something is typed.
+fn draw_strength_meter(app: &App, frame: &mut Frame, area: Rect, password: &TextInput) {
+    if password.is_empty() {
+        return;
+    }
+
+    let strength = PasswordPolicy::default().score(password.value());
+    let theme = &app.config.theme;
+    let color = match strength {
+        PasswordStrength::VeryWeak | PasswordStrength::Weak => theme.error,
+        PasswordStrength::Fair => theme.focus,
+        PasswordStrength::Strong | PasswordStrength::VeryStrong => theme.success,
+    };
+    let gauge = LineGauge::default()
+        .ratio(f64::from(strength.level()) / f64::from(PasswordStrength::MAX_LEVEL))
+        .label(app.catalog.format(
+            Text::Strength,
+            &[&format!(
+                "{:<11}",
+                app.catalog.get(Text::strength(strength))
+            )],
+        ))
+        .filled_style(Style::default().fg(color))
+        .unfilled_style(Style::default().fg(theme.muted));
+    frame.render_widget(gauge, area);
+}
+
+/// Returns the field's value, or `None` when it is blank.
+fn optional_value(input: &TextInput) -> Option<String> {
+    if input.value().trim().is_empty() {
+        None
+    } else {
+        Some(input.value().to_string())
+    }
+}
+
+impl FormField {
+    /// Fields in focus order.
+    const ALL: [Self; 7] = [
+        Self::FirstName,
+        Self::MiddleName,
+        Self::LastName,
+        Self::SecondLastName,
+        Self::Document,
+        Self::Email,
+        Self::Password,
+    ];
+
+    fn position(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&field| field == self)
+            .unwrap_or(0)
+    }
+
+    fn label(self) -> Text {
+        match self {
+            Self::FirstName => Text::FirstName,
+            Self::MiddleName => Text::MiddleName,
+            Self::LastName => Text::LastName,
+            Self::SecondLastName => Text::SecondLastName,
+            Self::Document => Text::Document,
+            Self::Email => Text::Email,
+            Self::Password => Text::Password,
+        }
+    }
+
+    fn is_required(self) -> bool {
+        matches!(self, Self::FirstName | Self::LastName | Self::Document | Self::Email)
+    }
+
+    /// The label with whether the field is required, for the accessible view.
+    fn spoken_label(self, catalog: Catalog) -> String {
+        let presence = if self.is_required() {
+            Text::FieldRequired
+        } else {
+            Text::FieldOptional
+        };
+        catalog.format(presence, &[&catalog.get(self.label())])
+    }
+}
+
+impl RegistrationForm {
+    /// Describes a field as one line of plain text; passwords report only their length.
+    fn describe(&self, field: FormField, catalog: Catalog) -> String {
+        let label = field.spoken_label(catalog);
+        let value = match field {
+            FormField::FirstName => &self.first_name,
+            FormField::MiddleName => &self.middle_name,
+            FormField::LastName => &self.last_name,
+            FormField::SecondLastName => &self.second_last_name,
+            FormField::Document => &self.document,
+            FormField::Email => &self.email,
+            FormField::Password if self.show_password => &self.password,
+            FormField::Password => {
+                let mut description =
+                    catalog.format(Text::CharactersEntered, &[&label, &self.password.char_count()]);
+                if !self.password.is_empty() {
+                    let strength = PasswordPolicy::default().score(self.password.value());
+                    let strength = catalog.get(Text::strength(strength));
+                    description.push_str(&catalog.format(Text::StrengthSuffix, &[&strength]));
+                }
+                return description;
+            }
+        };
+        let description = if value.is_empty() {
+            catalog.format(Text::FieldBlank, &[&label])
+        } else {
+            format!("{}: {}", label, value.value())
+        };
+        match self.error(field, catalog) {
+            Some(error) => catalog.format(Text::FieldError, &[&description, &error]),
+            None => description,
+        }
+    }
+
+    /// Checks a field against the value object it becomes on submit.
+    fn check(&self, field: FormField) -> Option<FieldError> {
+        let value = self.input(field).value().to_string();
+        if value.trim().is_empty() {
+            return field.is_required().then_some(FieldError::Required);
+        }
+
+        match field {
+            FormField::FirstName
+            | FormField::MiddleName
+            | FormField::LastName
+            | FormField::SecondLastName => Name::new(value).err().map(FieldError::not_valid),
+            FormField::Document => Document::parse(DocumentKind::Dni, &value)
+                .err()
+                .map(|e| FieldError::Document(e.failure())),
+            FormField::Email => Email::new(value).err().map(FieldError::not_valid),
+            // Any password is accepted; it only has to hash.
+            FormField::Password => None,
+        }
+    }
+
+    /// The field's validation message in the catalog's language.
+    fn error(&self, field: FormField, catalog: Catalog) -> Option<&str> {
+        match self.errors[field.position()].as_ref()? {
+            FieldError::Required => Some(catalog.get(Text::Required)),
+            FieldError::Document(DocumentFailure::CheckDigit) => {
+                Some(catalog.get(Text::DocumentCheckDigit))
+            }
+            FieldError::Document(_) => Some(catalog.get(Text::DocumentFormat)),
+            FieldError::NotValid(reason) => Some(reason),
+        }
+    }
+
+    fn has_error(&self, field: FormField) -> bool {
+        self.errors[field.position()].is_some()
+    }
+
+    fn validate(&mut self, field: FormField) {
+        self.errors[field.position()] = self.check(field);
+    }
+
+    /// Rechecks the field being typed in once it has shown an error, so the
+    /// message updates and clears as the user fixes it.
+    fn revalidate_active(&mut self) {
+        if self.has_error(self.active_field) {
+            self.validate(self.active_field);
+        }
+    }
+
+    /// Checks every field and returns the first invalid one.
+    fn validate_all(&mut self) -> Option<FormField> {
+        for field in FormField::ALL {
+            self.validate(field);
+        }
+        FormField::ALL
+            .into_iter()
+            .find(|&field| self.has_error(field))
+    }
+
+    /// Moves to the next field, checking the one being left.
+    fn next_field(&mut self) {
+        self.validate(self.active_field);
+        self.show_password = false;
+        self.active_field = match self.active_field {
+            FormField::FirstName => FormField::MiddleName,
+            FormField::MiddleName => FormField::LastName,
+            FormField::LastName => FormField::SecondLastName,
+            FormField::SecondLastName => FormField::Document,
+            FormField::Document => FormField::Email,
+            FormField::Email => FormField::Password,
+            FormField::Password => FormField::FirstName,
+        };
+    }
+
+    /// Moves to the previous field, checking the one being left.
+    fn previous_field(&mut self) {
+        self.validate(self.active_field);
+        self.show_password = false;
+        self.active_field = match self.active_field {
+            FormField::FirstName => FormField::Password,
+            FormField::MiddleName => FormField::FirstName,
+            FormField::LastName => FormField::MiddleName,
+            FormField::SecondLastName => FormField::LastName,
+            FormField::Document => FormField::SecondLastName,
+            FormField::Email => FormField::Document,
+            FormField::Password => FormField::Email,
+        };
+    }
+
+    fn input(&self, field: FormField) -> &TextInput {
+        match field {
+            FormField::FirstName => &self.first_name,
+            FormField::MiddleName => &self.middle_name,
+            FormField::LastName => &self.last_name,
+            FormField::SecondLastName => &self.second_last_name,
+            FormField::Document => &self.document,
+            FormField::Email => &self.email,
+            FormField::Password => &self.password,
+        }
+    }
+
+    fn active_input_mut(&mut self) -> &mut TextInput {
+        match self.active_field {
+            FormField::FirstName => &mut self.first_name,
+            FormField::MiddleName => &mut self.middle_name,
+            FormField::LastName => &mut self.last_name,
+            FormField::SecondLastName => &mut self.second_last_name,
+            FormField::Document => &mut self.document,
+            FormField::Email => &mut self.email,
+            FormField::Password => &mut self.password,
+        }
+    }
+
+    /// Characters after the edit position of the focused field in the
+    /// accessible view; a hidden password only gives a count, so none.
+    fn chars_after_cursor(&self) -> usize {
+        if self.active_field == FormField::Password && !self.show_password {
+            0
+        } else {
+            self.input(self.active_field).chars_after_cursor()
+        }
+    }
+}
+
+fn format_user_error(error: &UserError, catalog: Catalog) -> String {
+    match error {
+        UserError::IdError(e) => catalog.format(Text::IdError, &[e]),
+        UserError::PersonNameError(e) => catalog.format(Text::NameError, &[e]),
+        UserError::DniError(e) => catalog.format(Text::DocumentError, &[e]),
+        UserError::EmailError(e) => catalog.format(Text::EmailError, &[e]),
+        UserError::HashedPasswordError(e) => catalog.format(Text::PasswordError, &[e]),
+        UserError::NoPasswordSet | UserError::CredentialsNotValid => {
+            catalog.get(Text::InvalidLogin).to_string()
+        }
+        _ => catalog.format(Text::UnknownError, &[error]),
+    }
+}
@@ -0,0 +1,422 @@
+use super::{App, Focus, MessageScreen, Screen, Transition, app::HelpEntry, centered};
+use crate::{
+    i18n::{Catalog, Text},
+    keymap::{Action, KeyChord},
+    user_list::{self, UserList, UserListMode},
+};
+use education_platform::prelude::{Entity, Id, User};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Row, Table, Wrap},
+};
+
+const USER_LIST_HELP: &[HelpEntry] = &[
+    (&[Action::MoveUp, Action::MoveDown], Text::Navigate),
+    (&[Action::Search], Text::Search),
+    (&[Action::Select], Text::Details),
+    (&[Action::Delete], Text::Delete),
+    (&[Action::Back], Text::Back),
+];
+
+const SEARCH_HELP: &[HelpEntry] = &[
+    (&[Action::Submit], Text::Done),
+    (&[Action::Back], Text::StopSearching),
+];
+
+const USER_DETAIL_HELP: &[HelpEntry] = &[
+    (&[Action::Delete], Text::Delete),
+    (&[Action::Back], Text::Close),
+];
+
+const CONFIRM_DELETE_HELP: &[HelpEntry] = &[
+    (&[Action::Confirm], Text::Delete),
+    (&[Action::Cancel], Text::Cancel),
+];
+
+/// Lists, searches and deletes registered users.
+#[derive(Debug, Default)]
+pub struct UsersScreen {
+    list: UserList,
+}
+
+impl UsersScreen {
+    /// The user highlighted in the table.
+    fn selected_user<'a>(&self, app: &'a App) -> Option<&'a User> {
+        self.list
+            .selected(&app.users)
+            .and_then(|index| app.users.get(index))
+    }
+}
+
+impl Screen for UsersScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, focused: bool) {
+        // Users may have been deleted from a popup since the last frame.
+        self.list
+            .clamp_selection(self.list.matches(&app.users).len());
+
+        let theme = app.config.theme;
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(Text::Users)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.form));
+
+        frame.render_widget(block, area);
+
+        let inner_area = Rect::new(area.x + 2, area.y + 2, area.width - 4, area.height - 4);
+
+        let chunks = Layout::vertical([
+            Constraint::Length(3), // Search
+            Constraint::Min(3),    // Table
+            Constraint::Length(1), // Help text
+        ])
+        .split(inner_area);
+
+        app.draw_input_field(
+            frame,
+            chunks[0],
+            app.catalog.get(Text::Search),
+            &self.list.query,
+            focused && self.list.mode == UserListMode::Search,
+            None,
+        );
+
+        let rows: Vec<Row> = self
+            .list
+            .matches(&app.users)
+            .into_iter()
+            .filter_map(|index| app.users.get(index))
+            .map(|user| {
+                Row::new([
+                    user.name().full_name(),
+                    user.email().address().to_string(),
+                    user.document().to_string(),
+                    user_list::created_at(user),
+                ])
+            })
+            .collect();
+
+        if rows.is_empty() {
+            let empty = if app.users.is_empty() {
+                app.catalog.get(Text::NoUsersYet)
+            } else {
+                app.catalog.get(Text::NoUsersMatch)
+            };
+            frame.render_widget(
+                Paragraph::new(empty).style(Style::default().fg(theme.muted)),
+                chunks[1],
+            );
+        } else {
+            let header = Row::new(
+                [Text::Name, Text::Email, Text::Document, Text::Created]
+                    .map(|column| app.catalog.get(column)),
+            )
+            .style(Style::default().add_modifier(Modifier::BOLD));
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Percentage(35),
+                    Constraint::Percentage(35),
+                    Constraint::Length(12),
+                    Constraint::Length(16),
+                ],
+            )
+            .header(header)
+            .row_highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(theme.highlight_text)
+                    .bg(theme.highlight),
+            )
+            .highlight_symbol(">> ");
+            frame.render_stateful_widget(table, chunks[1], &mut self.list.table);
+        }
+
+        let help = match self.list.mode {
+            UserListMode::Search => format!(
+                "{} | {}",
+                app.catalog.get(Text::TypeToFilter),
+                app.key_help(SEARCH_HELP)
+            ),
+            UserListMode::Browse => app.key_help(USER_LIST_HELP),
+        };
+        app.draw_help(frame, chunks[2], help);
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        let list = &self.list;
+        lines.push(app.catalog.get(Text::UserManagementHeading).to_string());
+
+        let search_line = lines.len();
+        let search = app.catalog.get(Text::Search);
+        if list.query.is_empty() {
+            lines.push(app.catalog.format(Text::FieldBlank, &[&search]));
+        } else {
+            lines.push(format!("{}: {}", search, list.query.value()));
+        }
+
+        let matches = list.matches(&app.users);
+        let selected = list.table.selected().unwrap_or(0);
+        let first_row = lines.len();
+        if matches.is_empty() {
+            lines.push(app.catalog.get(Text::NoUsersFound).to_string());
+        }
+        for (row, user) in matches
+            .iter()
+            .filter_map(|&index| app.users.get(index))
+            .enumerate()
+        {
+            let marker = if row == selected { "> " } else { "  " };
+            let who = format!("{}, {}", user.name().full_name(), user.email().address());
+            let item = app
+                .catalog
+                .format(Text::ItemPosition, &[&who, &(row + 1), &matches.len()]);
+            lines.push(format!("{}{}", marker, item));
+        }
+
+        match list.mode {
+            UserListMode::Search => {
+                lines.push(format!(
+                    "{}. {}",
+                    app.catalog.get(Text::TypeToFilter),
+                    app.spoken_key_help(SEARCH_HELP)
+                ));
+                Focus {
+                    line: search_line,
+                    after_cursor: list.query.chars_after_cursor(),
+                }
+            }
+            UserListMode::Browse => {
+                lines.push(app.spoken_key_help(USER_LIST_HELP));
+                Focus::line(first_row + selected.min(matches.len().saturating_sub(1)))
+            }
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition {
+        let row_count = self.list.matches(&app.users).len();
+        let candidates: &[Action] = match self.list.mode {
+            // Characters always type into the query, even ones bound to
+            // moving, such as vim's `j`.
+            UserListMode::Search if key.is_character() => &[],
+            UserListMode::Search => &[Action::Back, Action::Submit, Action::MoveDown],
+            UserListMode::Browse => &[
+                Action::Back,
+                Action::MoveUp,
+                Action::MoveDown,
+                Action::PageUp,
+                Action::PageDown,
+                Action::First,
+                Action::Last,
+                Action::Search,
+                Action::Select,
+                Action::Delete,
+            ],
+        };
+        let action = app.list_action(key, candidates);
+
+        let list = &mut self.list;
+        match (list.mode, action) {
+            (UserListMode::Search, Some(_)) => list.mode = UserListMode::Browse,
+            (UserListMode::Search, None) => {
+                if let Some(code) = key.editing_key()
+                    && list.query.handle_key(code)
+                {
+                    list.table.select(Some(0));
+                    let row_count = list.matches(&app.users).len();
+                    list.clamp_selection(row_count);
+                }
+            }
+            (UserListMode::Browse, Some(Action::Back)) => return Transition::Pop,
+            (UserListMode::Browse, Some(Action::MoveUp)) => list.move_selection(-1, row_count),
+            (UserListMode::Browse, Some(Action::MoveDown)) => list.move_selection(1, row_count),
+            (UserListMode::Browse, Some(Action::PageUp)) => list.move_selection(-10, row_count),
+            (UserListMode::Browse, Some(Action::PageDown)) => list.move_selection(10, row_count),
+            (UserListMode::Browse, Some(Action::First)) => {
+                list.move_selection(isize::MIN, row_count);
+            }
+            (UserListMode::Browse, Some(Action::Last)) => {
+                list.move_selection(isize::MAX, row_count);
+            }
+            (UserListMode::Browse, Some(Action::Search)) => list.mode = UserListMode::Search,
+            (UserListMode::Browse, Some(Action::Select)) => {
+                if let Some(user) = self.selected_user(app) {
+                    return Transition::Push(Box::new(UserDetailScreen { user: user.id() }));
+                }
+            }
+            (UserListMode::Browse, Some(Action::Delete)) => {
+                if let Some(user) = self.selected_user(app) {
+                    return Transition::Push(Box::new(DeleteUserScreen { user: user.id() }));
+                }
+            }
+            _ => {}
+        }
+        Transition::Stay
+    }
+}
+
+/// Finds a user by ID; the popups hold IDs, not positions, so deletions
+/// elsewhere cannot make them point at someone else.
+fn find_user(app: &App, id: Id) -> Option<&User> {
+    app.users.iter().find(|user| user.id() == id)
+}
+
+/// Popup with every detail of one user.
+#[derive(Debug)]
+struct UserDetailScreen {
+    user: Id,
+}
+
+impl Screen for UserDetailScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, _focused: bool) {
+        let Some(user) = find_user(app, self.user) else {
+            return;
+        };
+        let popup_area = centered(area, 60, 10);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(Text::UserDetails)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.config.theme.accent));
+
+        let text: Vec<Line> = user_details(user, app.catalog)
+            .into_iter()
+            .map(Line::raw)
+            .chain([Line::styled(
+                app.key_help(USER_DETAIL_HELP),
+                Style::default().fg(app.config.theme.muted),
+            )])
+            .collect();
+
+        frame.render_widget(Paragraph::new(text).block(block), popup_area);
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        let focus = Focus::line(lines.len());
+        if let Some(user) = find_user(app, self.user) {
+            lines.extend(user_details(user, app.catalog));
+        }
+        lines.push(app.spoken_key_help(USER_DETAIL_HELP));
+        focus
+    }
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition {
+        match app.action(key, &[Action::Back, Action::Select, Action::Delete]) {
+            Some(Action::Delete) => {
+                Transition::Replace(Box::new(DeleteUserScreen { user: self.user }))
+            }
+            Some(_) => Transition::Pop,
+            None => Transition::Stay,
+        }
+    }
+
+    fn is_modal(&self) -> bool {
+        true
+    }
+}
+
+/// Popup asking before a user is deleted.
+#[derive(Debug)]
+struct DeleteUserScreen {
+    user: Id,
+}
+
+impl DeleteUserScreen {
+    /// Deletes the user, except the one signed in.
+    fn delete(&self, app: &mut App) -> Transition {
+        if app
+            .session
+            .as_ref()
+            .is_some_and(|user| user.id() == self.user)
+        {
+            let refused = app.catalog.get(Text::CannotDeleteSelf);
+            return Transition::Replace(MessageScreen::error(refused));
+        }
+
+        let Some(index) = app.users.iter().position(|user| user.id() == self.user) else {
+            return Transition::Pop;
+        };
+        let user = app.users.remove(index);
+        let deleted = app
+            .catalog
+            .format(Text::UserDeleted, &[&user.name().full_name()]);
+        Transition::Replace(MessageScreen::success(deleted))
+    }
+}
+
+impl Screen for DeleteUserScreen {
+    fn draw(&mut self, frame: &mut Frame, area: Rect, app: &App, _focused: bool) {
+        let Some(user) = find_user(app, self.user) else {
+            return;
+        };
+        let popup_area = centered(area, 50, 5);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(format!(" {} ", app.catalog.get(Text::DeleteUser)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.config.theme.error));
+
+        let text = vec![
+            Line::raw(
+                app.catalog
+                    .format(Text::ConfirmDeleteUser, &[&user.name().full_name()]),
+            ),
+            Line::styled(
+                app.key_help(CONFIRM_DELETE_HELP),
+                Style::default().fg(app.config.theme.muted),
+            ),
+        ];
+
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .centered()
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, popup_area);
+    }
+
+    fn describe(&self, app: &App, lines: &mut Vec<String>) -> Focus {
+        let focus = Focus::line(lines.len());
+        if let Some(user) = find_user(app, self.user) {
+            lines.push(format!(
+                "{} {}",
+                app.catalog
+                    .format(Text::ConfirmDeleteUser, &[&user.name().full_name()]),
+                app.spoken_key_help(CONFIRM_DELETE_HELP)
+            ));
+        }
+        focus
+    }
+
+    fn handle_key(&mut self, key: KeyChord, app: &mut App) -> Transition {
+        match app.action(key, &[Action::Confirm, Action::Cancel]) {
+            Some(Action::Confirm) => self.delete(app),
+            Some(_) => Transition::Pop,
+            None => Transition::Stay,
+        }
+    }
+
+    fn is_modal(&self) -> bool {
+        true
+    }
+}
+
+/// One line per detail of a user, for the detail popup and the accessible view.
+fn user_details(user: &User, catalog: Catalog) -> Vec<String> {
+    let password = if user.password().is_some() {
+        Text::PasswordSet
+    } else {
+        Text::PasswordNotSet
+    };
+    vec![
+        format!("{}: {}", catalog.get(Text::Name), user.name().full_name()),
+        format!("{}: {}", catalog.get(Text::Email), user.email().address()),
+        format!("{}: {}", catalog.get(Text::Document), user.document()),
+        format!("{}: {}", catalog.get(Text::Created), user_list::created_at(user)),
+        format!("{}: {}", catalog.get(Text::Password), catalog.get(password)),
+        format!("ID: {}", user.id()),
+    ]
+}
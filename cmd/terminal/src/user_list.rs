@@ -0,0 +1,75 @@
+use crate::text_input::TextInput;
+use education_platform::prelude::{Auditable, User};
+use ratatui::widgets::TableState;
+
+/// What the user list is doing with the keyboard.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UserListMode {
+    /// Arrow keys move through the table.
+    #[default]
+    Browse,
+    /// Keys edit the search query.
+    Search,
+}
+
+/// State of the user management screen.
+#[derive(Debug, Default)]
+pub struct UserList {
+    pub query: TextInput,
+    pub table: TableState,
+    pub mode: UserListMode,
+}
+
+impl UserList {
+    /// Positions in `users` of the users matching the query, in registration order.
+    ///
+    /// The query matches any part of the full name, email or document, ignoring case.
+    pub fn matches(&self, users: &[User]) -> Vec<usize> {
+        let query = self.query.value().trim().to_lowercase();
+        users
+            .iter()
+            .enumerate()
+            .filter(|(_, user)| {
+                query.is_empty()
+                    || [
+                        user.name().full_name(),
+                        user.email().address().to_string(),
+                        user.document().to_string(),
+                    ]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&query))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Position in `users` of the highlighted row, if any row matches.
+    pub fn selected(&self, users: &[User]) -> Option<usize> {
+        let matches = self.matches(users);
+        matches
+            .get(self.table.selected().unwrap_or(0))
+            .or_else(|| matches.last())
+            .copied()
+    }
+
+    /// Moves the highlight by `offset` rows, stopping at either end.
+    pub fn move_selection(&mut self, offset: isize, row_count: usize) {
+        if row_count == 0 {
+            self.table.select(None);
+            return;
+        }
+        let current = self.table.selected().unwrap_or(0);
+        let next = current.saturating_add_signed(offset).min(row_count - 1);
+        self.table.select(Some(next));
+    }
+
+    /// Keeps the highlight on an existing row after the rows changed.
+    pub fn clamp_selection(&mut self, row_count: usize) {
+        self.move_selection(0, row_count);
+    }
+}
+
+/// When the account was created, to the minute.
+pub fn created_at(user: &User) -> String {
+    Auditable::created_at(user).format("%Y-%m-%d %H:%M")
+}
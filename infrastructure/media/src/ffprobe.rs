@@ -0,0 +1,146 @@
+use education_platform_common::{Duration, Url};
+use education_platform_core::{ProbeError, ProbedVideo, VideoMetadata};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time;
+
+/// Reads video lengths by running the `ffprobe` tool from FFmpeg.
+///
+/// `ffprobe` only downloads the container headers, so probing a long
+/// video is cheap. A video it cannot open is reported as unreachable; not
+/// being able to start `ffprobe` at all is a probe failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use education_platform_common::Url;
+/// use education_platform_core::VideoMetadata;
+/// use education_platform_media::FfprobeProbe;
+///
+/// let probe = FfprobeProbe::new().with_program("/usr/local/bin/ffprobe");
+/// let url = Url::new("https://example.com/intro.mp4".to_string()).unwrap();
+///
+/// let video = probe.probe(&url).unwrap();
+/// println!("{:?}", video.duration());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FfprobeProbe {
+    program: PathBuf,
+    timeout: time::Duration,
+}
+
+impl FfprobeProbe {
+    /// Creates a probe running `ffprobe` from the `PATH`, giving up on
+    /// servers that stay silent for 30 seconds.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            program: PathBuf::from("ffprobe"),
+            timeout: time::Duration::from_secs(30),
+        }
+    }
+
+    /// Runs the given `ffprobe` executable instead.
+    #[must_use]
+    pub fn with_program(mut self, program: impl Into<PathBuf>) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    /// Sets how long a network read may stall before the probe gives up.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Default for FfprobeProbe {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VideoMetadata for FfprobeProbe {
+    fn probe(&self, url: &Url) -> Result<ProbedVideo, ProbeError> {
+        let output = Command::new(&self.program)
+            .args(["-v", "error", "-rw_timeout"])
+            .arg(self.timeout.as_micros().to_string())
+            .args([
+                "-show_entries",
+                "format=duration",
+                "-of",
+                "default=noprint_wrappers=1:nokey=1",
+            ])
+            .arg(url.as_str())
+            .output()
+            .map_err(|e| ProbeError::Failed(format!("{}: {e}", self.program.display())))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ProbeError::Unreachable(stderr.trim().to_string()));
+        }
+
+        parse_duration(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Reads the `format=duration` entry, which is fractional seconds or `N/A`
+/// for live streams and some containers.
+fn parse_duration(stdout: &str) -> Result<ProbedVideo, ProbeError> {
+    let value = stdout.trim();
+    if value == "N/A" {
+        return Ok(ProbedVideo::reachable());
+    }
+
+    let unexpected = || ProbeError::Failed(format!("unexpected ffprobe output: {value:?}"));
+    let seconds: f64 = value.parse().map_err(|_| unexpected())?;
+    if !seconds.is_finite() || seconds < 0.0 {
+        return Err(unexpected());
+    }
+
+    Ok(ProbedVideo::with_duration(Duration::from_seconds(
+        seconds.round() as u64
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rounds_to_whole_seconds() {
+        assert_eq!(
+            parse_duration("599.520000\n"),
+            Ok(ProbedVideo::with_duration(Duration::from_seconds(600)))
+        );
+        assert_eq!(
+            parse_duration("12.4"),
+            Ok(ProbedVideo::with_duration(Duration::from_seconds(12)))
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_duration() {
+        assert_eq!(parse_duration("N/A\n"), Ok(ProbedVideo::reachable()));
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        for output in ["", "abc", "-1.0", "inf", "NaN"] {
+            assert!(
+                matches!(parse_duration(output), Err(ProbeError::Failed(_))),
+                "output {output:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_program_is_a_probe_failure() {
+        let probe = FfprobeProbe::new().with_program("/nonexistent/ffprobe");
+        let url = Url::new("https://example.com/intro.mp4".to_string()).unwrap();
+
+        assert!(matches!(probe.probe(&url), Err(ProbeError::Failed(_))));
+    }
+}
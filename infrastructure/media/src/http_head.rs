@@ -0,0 +1,149 @@
+use education_platform_common::Url;
+use education_platform_core::{ProbeError, ProbedVideo, VideoMetadata};
+use std::time;
+use ureq::Agent;
+
+/// Checks that lesson videos are reachable with an HTTP `HEAD` request.
+///
+/// Redirects are followed and any final `2xx` status counts as reachable.
+/// The response carries no reliable media length, so durations are left
+/// unknown; use [`FfprobeProbe`](crate::FfprobeProbe) to verify those.
+///
+/// # Examples
+///
+/// ```no_run
+/// use education_platform_common::Url;
+/// use education_platform_core::VideoMetadata;
+/// use education_platform_media::HttpHeadProbe;
+///
+/// let probe = HttpHeadProbe::new();
+/// let url = Url::new("https://example.com/intro.mp4".to_string()).unwrap();
+///
+/// assert!(probe.probe(&url).is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpHeadProbe {
+    agent: Agent,
+}
+
+impl HttpHeadProbe {
+    /// Creates a probe giving each request 10 seconds to complete.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            agent: agent(time::Duration::from_secs(10)),
+        }
+    }
+
+    /// Sets how long a request may take, redirects included.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: time::Duration) -> Self {
+        self.agent = agent(timeout);
+        self
+    }
+}
+
+impl Default for HttpHeadProbe {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VideoMetadata for HttpHeadProbe {
+    fn probe(&self, url: &Url) -> Result<ProbedVideo, ProbeError> {
+        let response = self
+            .agent
+            .head(url.as_str())
+            .call()
+            .map_err(|e| ProbeError::Unreachable(e.to_string()))?;
+
+        check_status(response.status().as_u16())
+    }
+}
+
+/// Builds an agent that reports error statuses as responses, so the status
+/// alone decides reachability.
+fn agent(timeout: time::Duration) -> Agent {
+    Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .http_status_as_error(false)
+        .build()
+        .into()
+}
+
+fn check_status(status: u16) -> Result<ProbedVideo, ProbeError> {
+    match status {
+        200..=299 => Ok(ProbedVideo::reachable()),
+        code => Err(ProbeError::Unreachable(format!("HTTP {code}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves one request on a local port, answering with `status_line`,
+    /// and returns the URL to request.
+    fn serve_once(status_line: &'static str) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            let response = format!("HTTP/1.1 {status_line}\r\nContent-Length: 0\r\n\r\n");
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        Url::new(format!("http://127.0.0.1:{port}/intro.mp4")).unwrap()
+    }
+
+    #[test]
+    fn test_success_statuses_are_reachable() {
+        for status in [200, 204, 206] {
+            assert_eq!(check_status(status), Ok(ProbedVideo::reachable()));
+        }
+    }
+
+    #[test]
+    fn test_error_statuses_are_unreachable() {
+        assert_eq!(
+            check_status(404),
+            Err(ProbeError::Unreachable("HTTP 404".to_string()))
+        );
+        assert_eq!(
+            check_status(503),
+            Err(ProbeError::Unreachable("HTTP 503".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_probes_a_live_server() {
+        let probe = HttpHeadProbe::new();
+
+        assert_eq!(probe.probe(&serve_once("200 OK")), Ok(ProbedVideo::reachable()));
+        assert_eq!(
+            probe.probe(&serve_once("404 Not Found")),
+            Err(ProbeError::Unreachable("HTTP 404".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_connection_failure_is_unreachable() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let url = Url::new(format!("http://127.0.0.1:{port}/intro.mp4")).unwrap();
+
+        assert!(matches!(
+            HttpHeadProbe::new().probe(&url),
+            Err(ProbeError::Unreachable(_))
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+//! Adapters that probe lesson videos for the core's
+//! [`VideoMetadata`](education_platform_core::VideoMetadata) port.
+//!
+//! They reach out to the network and to external tools, so they live
+//! outside the domain crates, which stay free of I/O.
+
+mod ffprobe;
+mod http_head;
+
+pub use ffprobe::*;
+pub use http_head::*;
@@ -0,0 +1,38 @@
+//! One dependency for applications built on the education platform.
+//!
+//! The [`prelude`] holds the types most applications need: the course
+//! aggregate, learner progress, users, the shared value objects and the
+//! storage ports. Its contents follow semver: within a major version items
+//! are only ever added, never renamed or removed.
+//!
+//! The bounded-context crates are re-exported whole as [`common`],
+//! [`learning`] and [`auth`] for anything the prelude leaves out. Those
+//! modules track the contexts as they evolve and carry no stability
+//! promise beyond the prelude's. With the `media-probe` feature, the video
+//! probe adapters are re-exported as `media`.
+//!
+//! # Examples
+//!
+//! ```
+//! use education_platform::prelude::*;
+//!
+//! let lesson = Lesson::new(
+//!     "Introduction".to_string(),
+//!     Duration::from_minutes(30),
+//!     "https://example.com/intro.mp4".to_string(),
+//!     0,
+//! ).unwrap();
+//! let chapter = Chapter::new("Getting Started".to_string(), 0, vec![lesson]).unwrap();
+//! let course = Course::new("Rust Programming".to_string(), None, 0, vec![chapter]).unwrap();
+//!
+//! let progress = CourseProgress::from_course(&course, "ana@example.com").unwrap();
+//! assert_eq!(progress.course_id(), Some(course.id()));
+//! ```
+
+pub mod prelude;
+
+pub use education_platform_auth as auth;
+pub use education_platform_common as common;
+pub use education_platform_core as learning;
+#[cfg(feature = "media-probe")]
+pub use education_platform_media as media;
@@ -0,0 +1,68 @@
+//! The stable, commonly used API of the platform.
+//!
+//! Glob-import it with `use education_platform::prelude::*;`. Removing or
+//! renaming anything listed here is a breaking change.
+
+pub use education_platform_auth::{PasswordVerifier, User, UserError, UserId};
+
+pub use education_platform_common::{
+    Auditable, Blocklist, CarneExtranjeria, CarneExtranjeriaError, Cedula, CedulaError, Cpf,
+    CpfError, Date, DateError, DateTime, DateTimeError, Dni, DniError, Document, DocumentError,
+    DocumentFailure, DocumentKind, Duration, DurationError, Email, EmailError, Entity,
+    HashedPassword, Id, IdError, IdFormat, Locale, LocaleError, Name, NameError, NameFormat,
+    Passport, PassportError, PasswordPolicy, PasswordStrength, Percentage, PercentageError,
+    PersonName, PersonNameError, Rut, RutError, SimpleName, SimpleNameConfig, SimpleNameError,
+    Timestamps, TimestampsError, Untyped, Url, UrlError, WordBlocklist,
+};
+
+pub use education_platform_core::{
+    Chapter, ChapterError, ChapterId, Course, CourseError, CourseId, CourseProgress,
+    CourseProgressError, LearningPath, LearningPathError, LearningPathId, Lesson, LessonError,
+    LessonId, LessonProgress, LessonProgressError, ProgressEvent, ProgressReport,
+};
+
+pub use education_platform_core::{
+    EventStore, EventStoreError, InMemoryEventStore, InMemoryReportSubscriptionStore,
+    InMemorySegmentStore, ReportSubscriptionStore, SegmentStore,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_and_progress_share_value_objects() {
+        let email = Email::new("ana@example.com".to_string()).unwrap();
+        let user = User::new(
+            "Ana".to_string(),
+            None,
+            "Lopez".to_string(),
+            None,
+            "12345678-1".to_string(),
+            email.address().to_string(),
+            None,
+        )
+        .unwrap();
+        let lesson = LessonProgress::new("Intro".to_string(), 1800, None, None).unwrap();
+        let progress = CourseProgress::builder()
+            .course_name("Rust Course")
+            .user_email(user.email().address())
+            .lessons(vec![lesson])
+            .build()
+            .unwrap();
+
+        assert_eq!(progress.user_email(), &email);
+        assert_eq!(progress.percentage_completed(), Percentage::from_fraction(0, 1));
+    }
+
+    #[test]
+    fn test_stores_are_usable_through_their_ports() {
+        fn assert_event_store(_: &impl EventStore) {}
+        fn assert_segment_store(_: &impl SegmentStore) {}
+        fn assert_report_store(_: &impl ReportSubscriptionStore) {}
+
+        assert_event_store(&InMemoryEventStore::new());
+        assert_segment_store(&InMemorySegmentStore::new());
+        assert_report_store(&InMemoryReportSubscriptionStore::new());
+    }
+}